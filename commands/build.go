@@ -0,0 +1,269 @@
+package commands
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/spin"
+	"github.com/fastertools/ftl/synthesis"
+	"github.com/fastertools/ftl/validation"
+)
+
+// BuildOptions configure Build
+type BuildOptions struct {
+	// Dir is the project directory, the current directory when empty
+	Dir string
+	// ConfigFile is the FTL config, relative to Dir. It is looked up with
+	// FindConfig when empty, and the existing spin.toml is built when the
+	// project has no config.
+	ConfigFile string
+	// SkipSynth builds the existing spin.toml without synthesizing it
+	SkipSynth bool
+	// Profile is the build profile to build with, declared under profiles:
+	// in the FTL config or built in (size, speed)
+	Profile string
+	// Strict fails the build when a URL a component is configured to call is
+	// not covered by its allowed_outbound_hosts, which otherwise only warns
+	Strict bool
+	// Reproducible builds deterministically and records the sha256 of each
+	// built component in .ftl/build-manifest.json
+	Reproducible bool
+	// Mirror resolves registry components from an offline mirror, when set
+	Mirror *oci.Mirror
+	// ExportFormat also exports a module deploying the application once it
+	// is built: terraform or opentofu. Nothing is exported when empty.
+	ExportFormat string
+	// ExportDir is the directory the module is written to, relative to Dir.
+	// It defaults to terraform.
+	ExportDir string
+	// Env is added to the environment of the build commands
+	Env []string
+	// Stdout and Stderr receive the output of the build commands, which is
+	// discarded when nil
+	Stdout io.Writer
+	Stderr io.Writer
+	// Progress receives the progress of the build
+	Progress Progress
+}
+
+// BuildResult describes a finished build
+type BuildResult struct {
+	// ConfigFile is the FTL config of the project, empty when it has none
+	ConfigFile string
+	// Manifest is the path of the spin.toml that was built
+	Manifest string
+	// Application is the application declared in ConfigFile, nil for
+	// configs other than YAML and JSON
+	Application *validation.Application
+	// Built are the components of a reproducible build, with their digests
+	Built []BuiltComponent
+	// Sizes are the sizes of the built components, recorded in
+	// .ftl/build-sizes.json for the next build to compare against
+	Sizes []ComponentSize
+	// Exported are the files of the exported module
+	Exported []string
+}
+
+// Build synthesizes the project's spin.toml from its FTL config and builds
+// its components with spin build, as 'ftl build' does. Components using the
+// pull-or-build strategy are pulled when they are published, and the rest
+// are built with the environment and wasm-opt pass of the build profile.
+func Build(ctx context.Context, opts BuildOptions, deps Deps) (*BuildResult, error) {
+	if err := validateExportFormat(opts.ExportFormat); err != nil {
+		return nil, err
+	}
+	dir := projectDir(opts.Dir)
+	result := &BuildResult{Manifest: filepath.Join(dir, "spin.toml")}
+
+	configFile, err := configPath(dir, opts.ConfigFile)
+	if err != nil && !errors.Is(err, ErrNoConfig) {
+		return nil, err
+	}
+	result.ConfigFile = configFile
+
+	switch {
+	case opts.SkipSynth:
+		if _, err := os.Stat(result.Manifest); err != nil {
+			return nil, fmt.Errorf("no spin.toml found. Run 'ftl synth' or 'ftl build' without --skip-synth first")
+		}
+		opts.Progress.info(StageSynth, "Using existing spin.toml")
+	case configFile == "":
+		if _, err := os.Stat(result.Manifest); err != nil {
+			return nil, fmt.Errorf("no ftl.yaml, ftl.json, app.cue, or spin.toml found. Run 'ftl init' first")
+		}
+		opts.Progress.info(StageSynth, "No FTL config found, using existing spin.toml")
+	default:
+		manifest, err := synth(dir, configFile, opts.Mirror, opts.Progress, deps)
+		if err != nil {
+			return nil, err
+		}
+		if err := os.WriteFile(result.Manifest, []byte(manifest), 0600); err != nil {
+			return nil, fmt.Errorf("failed to write spin.toml: %w", err)
+		}
+		opts.Progress.success(StageSynth, "Generated spin.toml")
+	}
+
+	if configFile != "" {
+		if result.Application, err = LoadApplication(configFile); err != nil {
+			return nil, err
+		}
+	}
+	app := result.Application
+	if err := CheckOutboundHosts(app, configFile, nil, opts.Strict, opts.Progress); err != nil {
+		return nil, err
+	}
+	if opts.ExportFormat != "" && app == nil {
+		return nil, fmt.Errorf("export needs an ftl.yaml or ftl.json config")
+	}
+
+	var profile validation.BuildProfile
+	if opts.Profile != "" {
+		if profile, err = resolveBuildProfile(app, opts.Profile); err != nil {
+			return nil, err
+		}
+		opts.Progress.info(StageBuild, "Using build profile %s", opts.Profile)
+	}
+
+	// Pull prebuilt artifacts for components using the pull-or-build strategy
+	var built []string
+	if app != nil && usesPullOrBuild(app) {
+		toBuild, err := pullPrebuiltComponents(ctx, filepath.Dir(configFile), app, deps.puller(opts.Mirror), opts.Progress)
+		if err != nil {
+			return nil, err
+		}
+		if len(toBuild) == 0 {
+			opts.Progress.success(StagePull, "All components pulled, nothing to build")
+			return export(dir, result, opts)
+		}
+		built = toBuild
+	}
+
+	opts.Progress.info(StageBuild, "Building FTL application...")
+
+	// Build with the profile's flags in the environment
+	env := append(profileEnv(profile), opts.Env...)
+	var epoch int64
+	if opts.Reproducible {
+		absDir, err := filepath.Abs(dir)
+		if err != nil {
+			return nil, fmt.Errorf("failed to resolve %s: %w", dir, err)
+		}
+		epoch = sourceDateEpoch(dir, opts.Progress)
+		env = reproducibleEnv(env, absDir, epoch)
+		opts.Progress.info(StageBuild, "Reproducible build (SOURCE_DATE_EPOCH=%d)", epoch)
+	}
+
+	args := []string{"build"}
+	for _, id := range built {
+		args = append(args, "--component-id", id)
+	}
+	stdout, stderr := orDiscard(opts.Stdout), orDiscard(opts.Stderr)
+	executor := deps.spin(spin.WithDir(dir), spin.WithEnv(env), spin.WithOutput(stdout, stderr))
+	if err := executor.Run(ctx, args...); err != nil {
+		return nil, fmt.Errorf("failed to build: %w", err)
+	}
+
+	if len(profile.WasmOpt) > 0 || opts.Reproducible {
+		sources, err := BuiltSources(dir, built...)
+		if err != nil {
+			return nil, err
+		}
+		if err := optimizeBuiltComponents(ctx, profile, sources, stdout, stderr, opts.Progress); err != nil {
+			return nil, err
+		}
+		if opts.Reproducible {
+			if result.Built, err = recordBuildDigests(dir, sources, opts.Profile, epoch, opts.Progress); err != nil {
+				return nil, err
+			}
+		}
+	}
+
+	// Check every component spin.toml builds against its size budget
+	sources, err := BuiltSources(dir)
+	if err != nil {
+		return nil, err
+	}
+	if result.Sizes, err = checkBuildSizes(dir, app, sources, opts.Progress); err != nil {
+		return nil, err
+	}
+
+	opts.Progress.success(StageBuild, "Build completed successfully")
+	return export(dir, result, opts)
+}
+
+// export writes the module of opts.ExportFormat once the components are built
+func export(dir string, result *BuildResult, opts BuildOptions) (*BuildResult, error) {
+	if opts.ExportFormat == "" {
+		return result, nil
+	}
+	exportDir := opts.ExportDir
+	if exportDir == "" {
+		exportDir = "terraform"
+	}
+	exportDir = projectPath(dir, exportDir)
+
+	paths, err := exportTerraform(result.Application, result.ConfigFile, exportDir)
+	if err != nil {
+		return nil, fmt.Errorf("export failed: %w", err)
+	}
+	for _, path := range paths {
+		opts.Progress.info(StageExport, "Wrote %s", path)
+	}
+	opts.Progress.success(StageExport, "Exported %s module to %s", opts.ExportFormat, exportDir)
+	result.Exported = paths
+	return result, nil
+}
+
+// BuiltSources returns the Wasm paths of the components spin.toml in dir
+// builds, by component ID, limited to ids when given
+func BuiltSources(dir string, ids ...string) (map[string]string, error) {
+	manifest, err := os.ReadFile(filepath.Join(dir, "spin.toml"))
+	if err != nil {
+		return nil, fmt.Errorf("failed to read spin.toml: %w", err)
+	}
+	sources, err := synthesis.BuiltSources(string(manifest))
+	if err != nil {
+		return nil, err
+	}
+	for id, source := range sources {
+		sources[id] = projectPath(dir, source)
+	}
+	if len(ids) == 0 {
+		return sources, nil
+	}
+
+	limited := make(map[string]string, len(ids))
+	for _, id := range ids {
+		if source, ok := sources[id]; ok {
+			limited[id] = source
+		}
+	}
+	return limited, nil
+}
+
+// orDiscard returns w, or a writer discarding its output when w is nil
+func orDiscard(w io.Writer) io.Writer {
+	if w == nil {
+		return io.Discard
+	}
+	return w
+}
+
+// FormatSize renders a byte count with binary units
+func FormatSize(size int64) string {
+	const unit = 1024
+	if size < unit {
+		return fmt.Sprintf("%d B", size)
+	}
+	div, exp := int64(unit), 0
+	for n := size / unit; n >= unit; n /= unit {
+		div *= unit
+		exp++
+	}
+	return fmt.Sprintf("%.1f %ciB", float64(size)/float64(div), "KMGTPE"[exp])
+}
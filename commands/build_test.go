@@ -0,0 +1,201 @@
+package commands
+
+import (
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/spin"
+)
+
+// fakeSpin records the spin commands it is asked to run
+type fakeSpin struct {
+	spin.Executor
+	runs [][]string
+	err  error
+}
+
+func (f *fakeSpin) Run(_ context.Context, args ...string) error {
+	f.runs = append(f.runs, args)
+	return f.err
+}
+
+// fakeDeps returns dependencies running the fake spin and synthesizing a
+// fixed manifest
+func fakeDeps(fake *fakeSpin) Deps {
+	return Deps{
+		Spin: func(...spin.Option) spin.Executor { return fake },
+		Synthesize: func(configPath, outputDir string) (string, error) {
+			return "# synthesized from " + filepath.Base(configPath) + "\n", nil
+		},
+	}
+}
+
+func TestBuild(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+	fake := &fakeSpin{}
+
+	var events []Event
+	result, err := Build(context.Background(), BuildOptions{
+		Dir:      dir,
+		Progress: recordProgress(&events),
+	}, fakeDeps(fake))
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(dir, "ftl.yaml"), result.ConfigFile)
+	assert.Equal(t, filepath.Join(dir, "spin.toml"), result.Manifest)
+	require.NotNil(t, result.Application)
+	assert.Equal(t, "test-app", result.Application.Name)
+
+	manifest, err := os.ReadFile(result.Manifest)
+	require.NoError(t, err)
+	assert.Equal(t, "# synthesized from ftl.yaml\n", string(manifest))
+	assert.Equal(t, [][]string{{"build"}}, fake.runs)
+
+	var stages []Stage
+	for _, e := range events {
+		stages = append(stages, e.Stage)
+	}
+	assert.Equal(t, []Stage{StageSynth, StageSynth, StageBuild, StageBuild}, stages)
+	assert.Equal(t, LevelSuccess, events[len(events)-1].Level)
+}
+
+func TestBuild_ExistingSpinToml(t *testing.T) {
+	for _, skipSynth := range []bool{false, true} {
+		dir := writeProject(t, map[string]string{"spin.toml": "# existing\n"})
+		fake := &fakeSpin{}
+
+		result, err := Build(context.Background(), BuildOptions{Dir: dir, SkipSynth: skipSynth}, fakeDeps(fake))
+		require.NoError(t, err)
+		assert.Empty(t, result.ConfigFile)
+		assert.Nil(t, result.Application)
+		assert.Equal(t, [][]string{{"build"}}, fake.runs)
+
+		manifest, err := os.ReadFile(result.Manifest)
+		require.NoError(t, err)
+		assert.Equal(t, "# existing\n", string(manifest))
+	}
+}
+
+func TestBuild_Errors(t *testing.T) {
+	fake := &fakeSpin{}
+	_, err := Build(context.Background(), BuildOptions{Dir: t.TempDir()}, fakeDeps(fake))
+	assert.ErrorContains(t, err, "or spin.toml found")
+	_, err = Build(context.Background(), BuildOptions{Dir: t.TempDir(), SkipSynth: true}, fakeDeps(fake))
+	assert.ErrorContains(t, err, "no spin.toml found")
+	assert.Empty(t, fake.runs)
+
+	// The gateway is a registry component, which the empty mirror lacks
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+	_, err = Build(context.Background(), BuildOptions{Dir: dir, Mirror: oci.NewMirror(t.TempDir())}, Deps{
+		Spin: func(...spin.Option) spin.Executor { return fake },
+	})
+	assert.ErrorIs(t, err, oci.ErrNotMirrored)
+	assert.Empty(t, fake.runs)
+
+	_, err = Build(context.Background(), BuildOptions{Dir: dir, Profile: "tiny"}, fakeDeps(fake))
+	assert.ErrorContains(t, err, `unknown build profile "tiny"`)
+	assert.Empty(t, fake.runs)
+
+	fake.err = errors.New("exit status 1")
+	_, err = Build(context.Background(), BuildOptions{Dir: dir}, fakeDeps(fake))
+	assert.ErrorContains(t, err, "failed to build: exit status 1")
+}
+
+func TestBuild_PullOrBuild(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": `name: test-app
+components:
+  - id: echo
+    source: ./echo/echo.wasm
+    build:
+      command: cargo build
+      workdir: echo
+      strategy: pull-or-build
+      registry:
+        registry: ghcr.io
+        package: acme:echo
+        version: 1.0.0
+`})
+	published := filepath.Join(t.TempDir(), "echo.wasm")
+	require.NoError(t, os.WriteFile(published, []byte("\x00asm"), 0600))
+
+	fake := &fakeSpin{}
+	deps := fakeDeps(fake)
+	deps.Puller = &fakePuller{artifacts: map[string]string{"ghcr.io/acme:echo:1.0.0": published}}
+
+	var events []Event
+	_, err := Build(context.Background(), BuildOptions{Dir: dir, Progress: recordProgress(&events)}, deps)
+	require.NoError(t, err)
+	assert.Empty(t, fake.runs, "nothing is left to build")
+	assert.FileExists(t, filepath.Join(dir, "echo", "echo.wasm"))
+	assert.Equal(t, StagePull, events[len(events)-1].Stage)
+
+	// Components that could not be pulled are built
+	deps.Puller = &fakePuller{}
+	_, err = Build(context.Background(), BuildOptions{Dir: dir}, deps)
+	require.NoError(t, err)
+	assert.Equal(t, [][]string{{"build", "--component-id", "echo"}}, fake.runs)
+}
+
+func TestBuild_Reproducible(t *testing.T) {
+	dir := writeProject(t, map[string]string{
+		"ftl.yaml":       testConfig,
+		"echo/echo.wasm": "\x00asm",
+	})
+	t.Setenv("SOURCE_DATE_EPOCH", "1700000000")
+
+	fake := &fakeSpin{}
+	result, err := Build(context.Background(), BuildOptions{Dir: dir, Reproducible: true}, Deps{
+		Spin: func(...spin.Option) spin.Executor { return fake },
+	})
+	require.NoError(t, err)
+	require.Len(t, result.Built, 1)
+	assert.Equal(t, "echo", result.Built[0].ID)
+	assert.Equal(t, filepath.Join("echo", "echo.wasm"), result.Built[0].Path)
+	assert.FileExists(t, filepath.Join(dir, ".ftl", "build-manifest.json"))
+
+	require.Len(t, result.Sizes, 1)
+	assert.Equal(t, int64(4), result.Sizes[0].Size)
+	assert.FileExists(t, filepath.Join(dir, ".ftl", "build-sizes.json"))
+}
+
+func TestBuild_Export(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+	fake := &fakeSpin{}
+
+	var events []Event
+	result, err := Build(context.Background(), BuildOptions{
+		Dir:          dir,
+		ExportFormat: "opentofu",
+		Progress:     recordProgress(&events),
+	}, fakeDeps(fake))
+	require.NoError(t, err)
+	assert.Equal(t, []string{
+		filepath.Join(dir, "terraform", "main.tf"),
+		filepath.Join(dir, "terraform", "outputs.tf"),
+		filepath.Join(dir, "terraform", "variables.tf"),
+	}, result.Exported)
+	assert.Equal(t, Event{
+		Stage:   StageExport,
+		Level:   LevelSuccess,
+		Message: "Exported opentofu module to " + filepath.Join(dir, "terraform"),
+	}, events[len(events)-1])
+
+	_, err = Build(context.Background(), BuildOptions{Dir: dir, ExportFormat: "pulumi"}, fakeDeps(fake))
+	assert.ErrorContains(t, err, "unknown export format")
+
+	dir = writeProject(t, map[string]string{"spin.toml": "# existing\n"})
+	_, err = Build(context.Background(), BuildOptions{Dir: dir, ExportFormat: "terraform"}, fakeDeps(fake))
+	assert.ErrorContains(t, err, "export needs an ftl.yaml or ftl.json config")
+}
+
+func TestFormatSize(t *testing.T) {
+	assert.Equal(t, "512 B", FormatSize(512))
+	assert.Equal(t, "1.5 KiB", FormatSize(1536))
+	assert.Equal(t, "2.0 GiB", FormatSize(2*1024*1024*1024))
+}
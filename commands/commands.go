@@ -0,0 +1,131 @@
+// Package commands runs the work behind 'ftl synth', 'ftl build' and the
+// planning step of 'ftl deploy' as library functions, so other tools can drive
+// FTL builds without shelling out to the CLI.
+//
+// Nothing in this package prints, prompts or exits the process. Spin, the
+// synthesizer and the registry a build pulls from are injected through Deps,
+// and progress is reported to an optional Progress callback instead of the
+// terminal.
+//
+// Example usage:
+//
+//	result, err := commands.Build(ctx, commands.BuildOptions{
+//	    Dir: "path/to/project",
+//	    Progress: func(e commands.Event) {
+//	        log.Printf("[%s] %s", e.Stage, e.Message)
+//	    },
+//	}, commands.Deps{})
+package commands
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/spin"
+	"github.com/fastertools/ftl/synthesis"
+)
+
+// Stage names the step of a command an Event belongs to
+type Stage string
+
+const (
+	// StageSynth is the synthesis of spin.toml from the FTL config
+	StageSynth Stage = "synth"
+	// StageCheck is the checking of the FTL config before a build or deploy
+	StageCheck Stage = "check"
+	// StagePull is the pulling of prebuilt components
+	StagePull Stage = "pull"
+	// StageBuild is the build of the components
+	StageBuild Stage = "build"
+	// StageExport is the export of a deployment module
+	StageExport Stage = "export"
+	// StagePlan is the planning of a deploy
+	StagePlan Stage = "plan"
+)
+
+// Level is the severity of an Event
+type Level string
+
+const (
+	// LevelInfo reports a step of a command
+	LevelInfo Level = "info"
+	// LevelSuccess reports a finished step
+	LevelSuccess Level = "success"
+	// LevelWarn reports a problem the command carries on after
+	LevelWarn Level = "warn"
+)
+
+// Event reports the progress of a command
+type Event struct {
+	Stage   Stage
+	Level   Level
+	Message string
+}
+
+// Progress receives the events of a running command. It is called on the
+// goroutine running the command, so it should not block.
+type Progress func(Event)
+
+// report sends an event, if a callback is set
+func (p Progress) report(stage Stage, level Level, format string, args ...interface{}) {
+	if p != nil {
+		p(Event{Stage: stage, Level: level, Message: fmt.Sprintf(format, args...)})
+	}
+}
+
+func (p Progress) info(stage Stage, format string, args ...interface{}) {
+	p.report(stage, LevelInfo, format, args...)
+}
+
+func (p Progress) success(stage Stage, format string, args ...interface{}) {
+	p.report(stage, LevelSuccess, format, args...)
+}
+
+func (p Progress) warn(stage Stage, format string, args ...interface{}) {
+	p.report(stage, LevelWarn, format, args...)
+}
+
+// Puller pulls a published component and returns the path of its Wasm
+type Puller interface {
+	Pull(ctx context.Context, registry, packageName, version string) (string, error)
+}
+
+// Deps are the external tools the commands run. Nil fields use the same tools
+// as the CLI.
+type Deps struct {
+	// Spin creates the executor running spin, spin.NewExecutor by default
+	Spin func(options ...spin.Option) spin.Executor
+	// Synthesize synthesizes the Spin manifest of a config file, with local
+	// component paths rewritten relative to outputDir
+	Synthesize func(configPath, outputDir string) (string, error)
+	// Puller pulls the prebuilt artifacts of pull-or-build components. By
+	// default they are pulled from their registry through the component
+	// store, or from the offline mirror of the build.
+	Puller Puller
+}
+
+func (d Deps) spin(options ...spin.Option) spin.Executor {
+	if d.Spin != nil {
+		return d.Spin(options...)
+	}
+	return spin.NewExecutor(options...)
+}
+
+func (d Deps) synthesize(configPath, outputDir string) (string, error) {
+	if d.Synthesize != nil {
+		return d.Synthesize(configPath, outputDir)
+	}
+	return synthesis.SynthesizeFromConfigTo(configPath, outputDir)
+}
+
+func (d Deps) puller(mirror *oci.Mirror) Puller {
+	if d.Puller != nil {
+		return d.Puller
+	}
+	puller := oci.NewWASMPuller()
+	if mirror != nil {
+		return puller.WithMirror(mirror)
+	}
+	return puller.WithStore(oci.NewStore(oci.DefaultStoreDir()))
+}
@@ -0,0 +1,283 @@
+package commands
+
+import (
+	"fmt"
+
+	"github.com/fastertools/ftl/internal/deploy"
+	"github.com/fastertools/ftl/validation"
+)
+
+// DefaultVersion is the version an application without one is deployed as
+const DefaultVersion = "0.1.0"
+
+// DeployOptions are the deployment settings given besides the manifest
+type DeployOptions struct {
+	// AllowedRoles are the roles allowed to call an app with org access
+	AllowedRoles []string
+	// Variables override the defaults of the application variables
+	Variables map[string]string
+	// ChangeReason is recorded with the deployment
+	ChangeReason string
+}
+
+// PlanOptions configure PlanDeploy
+type PlanOptions struct {
+	DeployOptions
+	// Registry is the registry of the engine the components are pushed to
+	Registry string
+	// Namespace is the namespace of the components in Registry
+	Namespace string
+	// Progress receives the progress of the planning
+	Progress Progress
+}
+
+// Push describes a component copied to the registry of the engine
+type Push struct {
+	// Component is the ID of the component
+	Component string
+	// Local is true when the component is built from a local source, and
+	// false when it is pulled from another registry
+	Local bool
+	// From is the local source path or the registry reference of the component
+	From string
+	// To is the reference the component is pushed to
+	To string
+	// FilesTo is the reference the files mounted into the component are
+	// pushed to, empty when it mounts none
+	FilesTo string
+}
+
+// DeployPlan is what deploying an application does, worked out without
+// calling the engine
+type DeployPlan struct {
+	// Pushes are the components copied to the registry of the engine
+	Pushes []Push
+	// Application is the application as deployed, with every component
+	// sourced from the registry of the engine
+	Application *validation.Application
+	// Request is the body of the deployment request sent to the engine
+	Request map[string]interface{}
+	// MissingVariables are the required variables without a value, which
+	// the engine rejects
+	MissingVariables []string
+}
+
+// PlanDeploy works out the pushes and the deployment request of deploying app
+func PlanDeploy(app *validation.Application, opts PlanOptions) (*DeployPlan, error) {
+	version := AppVersion(app)
+	deployed := &validation.Application{
+		Name:        app.Name,
+		Version:     app.Version,
+		Description: app.Description,
+		Access:      app.Access,
+		Auth:        app.Auth,
+		Variables:   app.Variables,
+		Federation:  app.Federation,
+		Components:  make([]*validation.Component, 0, len(app.Components)),
+	}
+	plan := &DeployPlan{
+		Application:      deployed,
+		MissingVariables: app.MissingVariables(opts.Variables),
+	}
+
+	for _, comp := range app.Components {
+		push := Push{Component: comp.ID}
+		switch src := comp.Source.(type) {
+		case *validation.LocalSource:
+			push.Local = true
+			push.From = src.Path
+		case *validation.RegistrySource:
+			push.From = fmt.Sprintf("%s/%s:%s", src.Registry, src.Package, src.Version)
+		default:
+			return nil, fmt.Errorf("invalid source for component %s", comp.ID)
+		}
+
+		repository := fmt.Sprintf("%s/%s", opts.Namespace, comp.ID)
+		push.To = fmt.Sprintf("%s/%s:%s", opts.Registry, repository, version)
+		if len(comp.Files) > 0 {
+			push.FilesTo = fmt.Sprintf("%s/%s:%s", opts.Registry, repository, deploy.FilesVersion(version))
+		}
+		opts.Progress.info(StagePlan, "Component %s is pushed to %s", comp.ID, push.To)
+
+		plan.Pushes = append(plan.Pushes, push)
+		deployed.Components = append(deployed.Components, RegistryComponent(comp, PushedSource(opts.Registry, opts.Namespace, comp.ID, version)))
+	}
+
+	req, err := DeploymentRequest(deployed, opts.DeployOptions)
+	if err != nil {
+		return nil, err
+	}
+	plan.Request = req
+	return plan, nil
+}
+
+// AppVersion returns the version the components of an application are pushed
+// with
+func AppVersion(app *validation.Application) string {
+	if app.Version != "" {
+		return app.Version
+	}
+	return DefaultVersion
+}
+
+// PushedSource returns the source of a component pushed to the registry of
+// the engine, in the repository namespace/component
+func PushedSource(registry, namespace, componentID, version string) *validation.RegistrySource {
+	return &validation.RegistrySource{
+		Registry: registry,
+		// Spin names packages namespace:component
+		Package: namespace + ":" + componentID,
+		Version: version,
+	}
+}
+
+// RegistryComponent returns a component as deployed from source
+func RegistryComponent(comp *validation.Component, source *validation.RegistrySource) *validation.Component {
+	return &validation.Component{
+		ID:                   comp.ID,
+		Source:               source,
+		Build:                comp.Build,
+		Variables:            comp.Variables,
+		AllowedOutboundHosts: comp.AllowedOutboundHosts,
+		Capabilities:         comp.Capabilities,
+		Limits:               comp.Limits,
+		Schedules:            comp.Schedules,
+		Files:                comp.Files,
+		KeyValueStores:       comp.KeyValueStores,
+	}
+}
+
+// DeploymentRequest creates the flat deployment request sent to the engine
+// (no "application" wrapper). Every component must be sourced from a
+// registry, as it is once pushed.
+func DeploymentRequest(app *validation.Application, opts DeployOptions) (map[string]interface{}, error) {
+	req := map[string]interface{}{
+		"name":    app.Name,
+		"version": AppVersion(app),
+	}
+
+	if app.Description != "" {
+		req["description"] = app.Description
+	}
+
+	// Set access control
+	if app.Access != "" {
+		req["access"] = app.Access
+	} else {
+		req["access"] = "public"
+	}
+
+	// Set auth configuration if needed
+	if app.Auth != nil && (app.Access == "org" || app.Access == "custom") {
+		auth := map[string]interface{}{}
+		if app.Auth.JWTIssuer != "" {
+			auth["jwt_issuer"] = app.Auth.JWTIssuer
+		}
+		if app.Auth.JWTAudience != "" {
+			auth["jwt_audience"] = app.Auth.JWTAudience
+		}
+		if len(auth) > 0 {
+			req["auth"] = auth
+		}
+	}
+
+	// Add allowed_roles for org mode
+	if app.Access == "org" && len(opts.AllowedRoles) > 0 {
+		req["allowed_roles"] = opts.AllowedRoles
+	}
+
+	if opts.ChangeReason != "" {
+		req["change_reason"] = opts.ChangeReason
+	}
+
+	// Add components
+	components := make([]map[string]interface{}, 0, len(app.Components))
+	for _, comp := range app.Components {
+		regSrc, ok := comp.Source.(*validation.RegistrySource)
+		if !ok {
+			return nil, fmt.Errorf("component %s must be pushed to a registry before it is deployed", comp.ID)
+		}
+
+		deployComp := map[string]interface{}{
+			"id": comp.ID,
+			"source": map[string]interface{}{
+				"registry": regSrc.Registry,
+				"package":  regSrc.Package,
+				"version":  regSrc.Version,
+			},
+		}
+
+		if len(comp.Variables) > 0 {
+			deployComp["variables"] = comp.Variables
+		}
+
+		if len(comp.AllowedOutboundHosts) > 0 {
+			deployComp["allowed_outbound_hosts"] = comp.AllowedOutboundHosts
+		}
+
+		if len(comp.Capabilities) > 0 {
+			deployComp["capabilities"] = comp.Capabilities
+		}
+
+		if comp.Limits != nil {
+			deployComp["limits"] = comp.Limits
+		}
+
+		if len(comp.Schedules) > 0 {
+			deployComp["schedules"] = comp.Schedules
+		}
+
+		if len(comp.KeyValueStores) > 0 {
+			deployComp["key_value_stores"] = comp.KeyValueStores
+		}
+
+		// The files artifact has one layer per mount, in order
+		if len(comp.Files) > 0 {
+			destinations := make([]string, 0, len(comp.Files))
+			for _, f := range comp.Files {
+				destinations = append(destinations, f.Destination)
+			}
+			deployComp["files"] = map[string]interface{}{
+				"registry": regSrc.Registry,
+				"package":  regSrc.Package,
+				"version":  deploy.FilesVersion(regSrc.Version),
+				"mounts":   destinations,
+			}
+		}
+
+		components = append(components, deployComp)
+	}
+	req["components"] = components
+
+	// Add application variable defaults
+	if defaults := app.VariableDefaults(); len(defaults) > 0 {
+		req["variables"] = defaults
+	}
+
+	// Variables resolved by the platform from its stored secrets
+	if sources := app.VariableSources(); len(sources) > 0 {
+		req["variable_sources"] = sources
+	}
+	if secrets := app.SecretVariables(); len(secrets) > 0 {
+		req["secret_variables"] = secrets
+	}
+
+	// Remote MCP servers federated into the gateway, whose tokens come from
+	// the secret variables
+	if len(app.Federation) > 0 {
+		req["federation"] = app.Federation
+	}
+
+	// Merge deployment variables from options
+	if len(opts.Variables) > 0 {
+		if existing, ok := req["variables"].(map[string]string); ok {
+			for k, v := range opts.Variables {
+				existing[k] = v
+			}
+		} else {
+			req["variables"] = opts.Variables
+		}
+	}
+
+	return req, nil
+}
@@ -0,0 +1,91 @@
+package commands
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+func TestPlanDeploy(t *testing.T) {
+	app := &validation.Application{
+		Name:   "test-app",
+		Access: "private",
+		Variables: map[string]validation.Variable{
+			"region":       {Required: true},
+			"github_token": {ValueFrom: "engine-secret:GITHUB_TOKEN"},
+		},
+		Federation: map[string]validation.FederatedServer{
+			"github": {URL: "https://api.githubcopilot.com/mcp/", TokenVariable: "github_token"},
+		},
+		Components: []*validation.Component{
+			{
+				ID:     "echo",
+				Source: &validation.LocalSource{Path: "echo/echo.wasm"},
+				Files:  []validation.FileMount{{Source: "data", Destination: "/data"}},
+			},
+			{
+				ID:     "search",
+				Source: &validation.RegistrySource{Registry: "ghcr.io", Package: "acme:search", Version: "2.0.0"},
+			},
+		},
+	}
+
+	var events []Event
+	plan, err := PlanDeploy(app, PlanOptions{
+		DeployOptions: DeployOptions{ChangeReason: "Add search"},
+		Registry:      "registry.example.com",
+		Namespace:     "ns",
+		Progress:      recordProgress(&events),
+	})
+	require.NoError(t, err)
+	assert.Len(t, events, 2)
+
+	assert.Equal(t, []Push{
+		{
+			Component: "echo",
+			Local:     true,
+			From:      "echo/echo.wasm",
+			To:        "registry.example.com/ns/echo:0.1.0",
+			FilesTo:   "registry.example.com/ns/echo:0.1.0-files",
+		},
+		{
+			Component: "search",
+			From:      "ghcr.io/acme:search:2.0.0",
+			To:        "registry.example.com/ns/search:0.1.0",
+		},
+	}, plan.Pushes)
+	assert.Equal(t, []string{"region"}, plan.MissingVariables)
+
+	// The manifest is left as is
+	assert.IsType(t, &validation.LocalSource{}, app.Components[0].Source)
+	assert.Equal(t, &validation.RegistrySource{
+		Registry: "registry.example.com",
+		Package:  "ns:echo",
+		Version:  "0.1.0",
+	}, plan.Application.Components[0].Source)
+
+	assert.Equal(t, "Add search", plan.Request["change_reason"])
+	components := plan.Request["components"].([]map[string]interface{})
+	require.Len(t, components, 2)
+	assert.Equal(t, map[string]interface{}{
+		"registry": "registry.example.com",
+		"package":  "ns:search",
+		"version":  "0.1.0",
+	}, components[1]["source"])
+	assert.Equal(t, []string{"/data"}, components[0]["files"].(map[string]interface{})["mounts"])
+	assert.Equal(t, app.Federation, plan.Request["federation"])
+}
+
+func TestDeploymentRequest_RequiresRegistrySources(t *testing.T) {
+	app := &validation.Application{
+		Name: "test-app",
+		Components: []*validation.Component{
+			{ID: "echo", Source: &validation.LocalSource{Path: "echo/echo.wasm"}},
+		},
+	}
+	_, err := DeploymentRequest(app, DeployOptions{})
+	assert.ErrorContains(t, err, "component echo must be pushed to a registry")
+}
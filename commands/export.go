@@ -0,0 +1,401 @@
+package commands
+
+import (
+	"encoding/json"
+	"fmt"
+	"maps"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// Export formats of BuildOptions.ExportFormat. OpenTofu reads the same module.
+const (
+	exportFormatTerraform = "terraform"
+	exportFormatOpenTofu  = "opentofu"
+)
+
+func validateExportFormat(format string) error {
+	switch format {
+	case "", exportFormatTerraform, exportFormatOpenTofu:
+		return nil
+	default:
+		return fmt.Errorf("unknown export format %q (supported: terraform, opentofu)", format)
+	}
+}
+
+// exportTerraform writes the Terraform module deploying app to dir and
+// returns the paths of the written files
+func exportTerraform(app *validation.Application, configFile, dir string) ([]string, error) {
+	files, err := terraformModule(app, filepath.Dir(configFile), filepath.Base(configFile))
+	if err != nil {
+		return nil, err
+	}
+	if err := os.MkdirAll(dir, 0750); err != nil {
+		return nil, fmt.Errorf("failed to create %s: %w", dir, err)
+	}
+
+	paths := make([]string, 0, len(files))
+	for _, name := range slices.Sorted(maps.Keys(files)) {
+		path := filepath.Join(dir, name)
+		if err := os.WriteFile(path, []byte(files[name]), 0600); err != nil {
+			return nil, fmt.Errorf("failed to write %s: %w", path, err)
+		}
+		paths = append(paths, path)
+	}
+	return paths, nil
+}
+
+// terraformModule renders the module files, by name, for the deployment
+// 'ftl deploy' would make of app. Local components are referenced where
+// 'ftl component publish' pushes them, under var.registry and var.namespace.
+func terraformModule(app *validation.Application, configDir, configName string) (map[string]string, error) {
+	// Stand in registry sources for local components, so the request is
+	// built exactly as deploy builds it
+	deployed := *app
+	deployed.Components = make([]*validation.Component, 0, len(app.Components))
+	localVersions := make(map[string]string)
+	for _, comp := range app.Components {
+		if len(comp.Files) > 0 {
+			return nil, fmt.Errorf("component %s mounts local files, which only 'ftl deploy' can push", comp.ID)
+		}
+		switch comp.Source.(type) {
+		case *validation.LocalSource:
+			version, _ := PublishVersion(comp, configDir, app.Version)
+			localVersions[comp.ID] = version
+			c := *comp
+			c.Source = &validation.RegistrySource{Version: version}
+			deployed.Components = append(deployed.Components, &c)
+		case *validation.RegistrySource:
+			deployed.Components = append(deployed.Components, comp)
+		default:
+			return nil, fmt.Errorf("invalid source for component %s", comp.ID)
+		}
+	}
+
+	// Round-trip through JSON so the renderer only sees plain values
+	built, err := DeploymentRequest(&deployed, DeployOptions{})
+	if err != nil {
+		return nil, err
+	}
+	data, err := json.Marshal(built)
+	if err != nil {
+		return nil, err
+	}
+	var req map[string]interface{}
+	if err := json.Unmarshal(data, &req); err != nil {
+		return nil, err
+	}
+
+	components, _ := req["components"].([]interface{})
+	for _, c := range components {
+		comp, _ := c.(map[string]interface{})
+		id, _ := comp["id"].(string)
+		if version, ok := localVersions[id]; ok {
+			comp["source"] = []hclAttr{
+				{"registry", hclExpr("var.registry")},
+				{"package", hclExpr(fmt.Sprintf(`"${var.namespace}:%s"`, hclEscape(id)))},
+				{"version", version},
+			}
+		}
+	}
+
+	variables := hclExpr("var.variables")
+	if defaults, ok := req["variables"].(map[string]interface{}); ok {
+		variables = hclExpr(fmt.Sprintf("merge(%s, var.variables)", renderHCLValue(defaults, 1)))
+	}
+
+	appAttrs := []hclAttr{
+		{"name", req["name"]},
+		{"access", req["access"]},
+	}
+	deployment := []hclAttr{
+		{"app_id", hclExpr("ftl_app.this.id")},
+		{"version", req["version"]},
+	}
+	if description, ok := req["description"]; ok {
+		deployment = append(deployment, hclAttr{"description", description})
+	}
+	deployment = append(deployment, hclAttr{"access", req["access"]})
+	if auth, ok := req["auth"]; ok {
+		deployment = append(deployment, hclAttr{"auth", auth})
+	}
+	if app.Access == "org" {
+		deployment = append(deployment, hclAttr{"allowed_roles", hclExpr("var.allowed_roles")})
+	}
+	deployment = append(deployment,
+		hclAttr{"change_reason", hclExpr("var.change_reason")},
+		hclAttr{"components", components},
+		hclAttr{"variables", variables},
+	)
+	if sources, ok := req["variable_sources"]; ok {
+		deployment = append(deployment, hclAttr{"variable_sources", sources})
+	}
+	if secrets, ok := req["secret_variables"]; ok {
+		deployment = append(deployment, hclAttr{"secret_variables", secrets})
+	}
+
+	header := fmt.Sprintf("# Generated by 'ftl build --export' from %s.\n# Re-export after changing the FTL config instead of editing this file.\n\n", configName)
+
+	var mainTF strings.Builder
+	mainTF.WriteString(header)
+	writeHCLBlock(&mainTF, "terraform", []hclAttr{
+		{"required_providers", hclBlock{Type: "required_providers", Body: []hclAttr{
+			{"ftl", []hclAttr{{"source", "fastertools/ftl"}}},
+		}}},
+	})
+	mainTF.WriteString("\n")
+	writeHCLBlock(&mainTF, `resource "ftl_app" "this"`, appAttrs)
+	mainTF.WriteString("\n")
+	writeHCLBlock(&mainTF, `resource "ftl_deployment" "this"`, deployment)
+
+	outputs := renderHCLBlocks(
+		hclBlock{Type: `output "app_id"`, Body: []hclAttr{
+			{"description", "ID of the FTL Engine application"},
+			{"value", hclExpr("ftl_app.this.id")},
+		}},
+		hclBlock{Type: `output "url"`, Body: []hclAttr{
+			{"description", "URL of the deployed MCP server"},
+			{"value", hclExpr("ftl_deployment.this.url")},
+		}},
+	)
+
+	return map[string]string{
+		"main.tf":      mainTF.String(),
+		"variables.tf": header + terraformVariables(app, len(localVersions) > 0),
+		"outputs.tf":   header + outputs,
+	}, nil
+}
+
+// terraformVariables declares the module inputs: where local components were
+// published, and what 'ftl deploy' takes as flags
+func terraformVariables(app *validation.Application, hasLocal bool) string {
+	var blocks []hclBlock
+	if hasLocal {
+		blocks = append(blocks,
+			hclBlock{Type: `variable "registry"`, Body: []hclAttr{
+				{"description", "Registry the local components were published to with 'ftl component publish'"},
+				{"type", hclExpr("string")},
+				{"default", DefaultRegistry},
+			}},
+			hclBlock{Type: `variable "namespace"`, Body: []hclAttr{
+				{"description", "Registry namespace the local components were published under"},
+				{"type", hclExpr("string")},
+			}},
+		)
+	}
+
+	variables := []hclAttr{
+		{"description", "Application variable values, as given to 'ftl deploy --var'"},
+		{"type", hclExpr("map(string)")},
+		{"default", hclExpr("{}")},
+	}
+	if len(app.SecretVariables()) > 0 {
+		variables = append(variables, hclAttr{"sensitive", true})
+	}
+	if required := app.MissingVariables(nil); len(required) > 0 {
+		names := make([]interface{}, 0, len(required))
+		for _, name := range required {
+			names = append(names, name)
+		}
+		variables = append(variables, hclAttr{"validation", hclBlock{Type: "validation", Body: []hclAttr{
+			{"condition", hclExpr(fmt.Sprintf("alltrue([for name in %s : contains(keys(var.variables), name)])", renderHCLValue(names, 2)))},
+			{"error_message", fmt.Sprintf("Required variables must be set: %s.", strings.Join(required, ", "))},
+		}}})
+	}
+	blocks = append(blocks, hclBlock{Type: `variable "variables"`, Body: variables})
+
+	if app.Access == "org" {
+		blocks = append(blocks, hclBlock{Type: `variable "allowed_roles"`, Body: []hclAttr{
+			{"description", "Organization roles allowed to access the application"},
+			{"type", hclExpr("list(string)")},
+			{"default", hclExpr("[]")},
+		}})
+	}
+	blocks = append(blocks, hclBlock{Type: `variable "change_reason"`, Body: []hclAttr{
+		{"description", "Reason recorded with the deployment"},
+		{"type", hclExpr("string")},
+		{"default", hclExpr("null")},
+	}})
+
+	return renderHCLBlocks(blocks...)
+}
+
+// hclExpr is an HCL expression written as is
+type hclExpr string
+
+// hclAttr is an attribute of an HCL body or object, in order
+type hclAttr struct {
+	Key   string
+	Value interface{}
+}
+
+// hclBlock is a nested block; Type includes its labels
+type hclBlock struct {
+	Type string
+	Body []hclAttr
+}
+
+func renderHCLBlocks(blocks ...hclBlock) string {
+	var b strings.Builder
+	for i, block := range blocks {
+		if i > 0 {
+			b.WriteString("\n")
+		}
+		writeHCLBlock(&b, block.Type, block.Body)
+	}
+	return b.String()
+}
+
+func writeHCLBlock(b *strings.Builder, header string, body []hclAttr) {
+	b.WriteString(header + " {\n")
+	writeHCLBody(b, body, 1)
+	b.WriteString("}\n")
+}
+
+// writeHCLBody writes attributes one per line, aligning the equals signs of
+// consecutive single-line attributes as 'terraform fmt' does
+func writeHCLBody(b *strings.Builder, attrs []hclAttr, depth int) {
+	indent := strings.Repeat("  ", depth)
+	rendered := make([]string, len(attrs))
+	for i, attr := range attrs {
+		if block, ok := attr.Value.(hclBlock); ok {
+			var nested strings.Builder
+			nested.WriteString(block.Type + " {\n")
+			writeHCLBody(&nested, block.Body, depth+1)
+			nested.WriteString(indent + "}")
+			rendered[i] = nested.String()
+			continue
+		}
+		rendered[i] = renderHCLValue(attr.Value, depth)
+	}
+
+	for start := 0; start < len(attrs); {
+		if _, ok := attrs[start].Value.(hclBlock); ok {
+			b.WriteString(indent + rendered[start] + "\n")
+			start++
+			continue
+		}
+		// A multi-line value ends the group it belongs to
+		end, width := start, 0
+		for end < len(attrs) {
+			if _, ok := attrs[end].Value.(hclBlock); ok {
+				break
+			}
+			width = max(width, len(hclKey(attrs[end].Key)))
+			end++
+			if strings.Contains(rendered[end-1], "\n") {
+				break
+			}
+		}
+		for i := start; i < end; i++ {
+			key := hclKey(attrs[i].Key)
+			fmt.Fprintf(b, "%s%s%s = %s\n", indent, key, strings.Repeat(" ", width-len(key)), rendered[i])
+		}
+		start = end
+	}
+}
+
+// renderHCLValue renders a value nested depth levels deep
+func renderHCLValue(value interface{}, depth int) string {
+	indent := strings.Repeat("  ", depth)
+	switch v := value.(type) {
+	case nil:
+		return "null"
+	case hclExpr:
+		return string(v)
+	case string:
+		return `"` + hclEscape(v) + `"`
+	case bool, float64, int:
+		return fmt.Sprint(v)
+	case []hclAttr:
+		if len(v) == 0 {
+			return "{}"
+		}
+		var b strings.Builder
+		b.WriteString("{\n")
+		writeHCLBody(&b, v, depth+1)
+		b.WriteString(indent + "}")
+		return b.String()
+	case map[string]interface{}:
+		keys := slices.Sorted(maps.Keys(v))
+		// IDs lead, the rest is sorted
+		if i := slices.Index(keys, "id"); i > 0 {
+			keys = append([]string{"id"}, slices.Delete(keys, i, i+1)...)
+		}
+		attrs := make([]hclAttr, 0, len(keys))
+		for _, k := range keys {
+			attrs = append(attrs, hclAttr{k, v[k]})
+		}
+		return renderHCLValue(attrs, depth)
+	case []interface{}:
+		if len(v) == 0 {
+			return "[]"
+		}
+		items := make([]string, 0, len(v))
+		multiline := false
+		for _, item := range v {
+			switch item.(type) {
+			case map[string]interface{}, []hclAttr, []interface{}:
+				multiline = true
+			}
+			items = append(items, renderHCLValue(item, depth+1))
+		}
+		if !multiline {
+			return "[" + strings.Join(items, ", ") + "]"
+		}
+		var b strings.Builder
+		b.WriteString("[\n")
+		for _, item := range items {
+			b.WriteString(indent + "  " + item + ",\n")
+		}
+		b.WriteString(indent + "]")
+		return b.String()
+	default:
+		return `"` + hclEscape(fmt.Sprint(v)) + `"`
+	}
+}
+
+// hclKey quotes object keys that aren't identifiers
+func hclKey(key string) string {
+	for i, r := range key {
+		if r == '_' || (r >= 'a' && r <= 'z') || (r >= 'A' && r <= 'Z') || (i > 0 && (r == '-' || (r >= '0' && r <= '9'))) {
+			continue
+		}
+		return `"` + hclEscape(key) + `"`
+	}
+	if key == "" {
+		return `""`
+	}
+	return key
+}
+
+// hclEscape escapes s for a quoted HCL string, including template sequences
+func hclEscape(s string) string {
+	var b strings.Builder
+	for i, r := range s {
+		switch {
+		case r == '\\':
+			b.WriteString(`\\`)
+		case r == '"':
+			b.WriteString(`\"`)
+		case r == '\n':
+			b.WriteString(`\n`)
+		case r == '\r':
+			b.WriteString(`\r`)
+		case r == '\t':
+			b.WriteString(`\t`)
+		case (r == '$' || r == '%') && strings.HasPrefix(s[i+1:], "{"):
+			b.WriteRune(r)
+			b.WriteRune(r)
+		case r < 0x20:
+			fmt.Fprintf(&b, `\u%04X`, r)
+		default:
+			b.WriteRune(r)
+		}
+	}
+	return b.String()
+}
@@ -0,0 +1,111 @@
+package commands
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+func exportTestApp() *validation.Application {
+	apiURL := "https://api.example.com"
+	return &validation.Application{
+		Name:    "weather-app",
+		Version: "1.2.0",
+		Access:  "org",
+		Auth:    &validation.AuthConfig{JWTIssuer: "https://auth.example.com"},
+		Components: []*validation.Component{
+			{
+				ID:                   "weather",
+				Source:               &validation.LocalSource{Path: "weather/target/wasm32-wasip1/release/weather.wasm"},
+				Build:                &validation.BuildConfig{Command: "cargo build", Workdir: "weather"},
+				Variables:            map[string]string{"api_url": "{{ api_url }}"},
+				AllowedOutboundHosts: []string{"https://api.example.com"},
+			},
+			{
+				ID:     "mcp-gateway",
+				Source: &validation.RegistrySource{Registry: "ghcr.io", Package: "fastertools:mcp-gateway", Version: "0.0.13"},
+			},
+		},
+		Variables: map[string]validation.Variable{
+			"api_url":   {Default: &apiURL},
+			"api_token": {Required: true, Secret: true},
+		},
+	}
+}
+
+func TestTerraformModule(t *testing.T) {
+	dir := t.TempDir()
+	require.NoError(t, os.MkdirAll(filepath.Join(dir, "weather"), 0750))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "weather", "Cargo.toml"), []byte("[package]\nname = \"weather\"\nversion = \"0.3.1\"\n"), 0600))
+
+	files, err := terraformModule(exportTestApp(), dir, "ftl.yaml")
+	require.NoError(t, err)
+
+	main := files["main.tf"]
+	assert.Contains(t, main, "# Generated by 'ftl build --export' from ftl.yaml.")
+	assert.Contains(t, main, `source = "fastertools/ftl"`)
+	assert.Contains(t, main, "resource \"ftl_app\" \"this\" {\n  name   = \"weather-app\"\n  access = \"org\"\n}")
+	assert.Contains(t, main, "  app_id  = ftl_app.this.id\n  version = \"1.2.0\"\n")
+	assert.Contains(t, main, "jwt_issuer = \"https://auth.example.com\"")
+	assert.Contains(t, main, "allowed_roles = var.allowed_roles")
+
+	// Local components are referenced where 'ftl component publish' puts them
+	assert.Contains(t, main, "        registry = var.registry\n        package  = \"${var.namespace}:weather\"\n        version  = \"0.3.1\"\n")
+	assert.Contains(t, main, "        registry = \"ghcr.io\"\n        package  = \"fastertools:mcp-gateway\"\n        version  = \"0.0.13\"\n")
+	// Spin template expressions are kept literally
+	assert.Contains(t, main, `api_url = "{{ api_url }}"`)
+	assert.Contains(t, main, "  variables = merge({\n    api_url = \"https://api.example.com\"\n  }, var.variables)\n")
+	assert.Contains(t, main, `secret_variables = ["api_token"]`)
+
+	variables := files["variables.tf"]
+	assert.Contains(t, variables, "variable \"namespace\" {")
+	assert.Contains(t, variables, "  default     = \"ghcr.io\"\n")
+	assert.Contains(t, variables, "  sensitive   = true\n")
+	assert.Contains(t, variables, `condition     = alltrue([for name in ["api_token"] : contains(keys(var.variables), name)])`)
+	assert.Contains(t, variables, "variable \"allowed_roles\" {")
+
+	assert.Contains(t, files["outputs.tf"], "value       = ftl_deployment.this.url")
+}
+
+func TestTerraformModule_Rejects(t *testing.T) {
+	app := exportTestApp()
+	app.Components[0].Files = []validation.FileMount{{Source: "data", Destination: "/data"}}
+	_, err := terraformModule(app, t.TempDir(), "ftl.yaml")
+	assert.ErrorContains(t, err, "component weather mounts local files")
+
+	assert.NoError(t, validateExportFormat("opentofu"))
+	assert.ErrorContains(t, validateExportFormat("pulumi"), "unknown export format")
+}
+
+func TestExportTerraform(t *testing.T) {
+	app := exportTestApp()
+	app.Access = "public"
+	app.Components = app.Components[1:]
+
+	dir := filepath.Join(t.TempDir(), "infra")
+	paths, err := exportTerraform(app, filepath.Join(t.TempDir(), "ftl.yaml"), dir)
+	require.NoError(t, err)
+	assert.Equal(t, []string{
+		filepath.Join(dir, "main.tf"),
+		filepath.Join(dir, "outputs.tf"),
+		filepath.Join(dir, "variables.tf"),
+	}, paths)
+
+	// Without local components there is nothing published to point at
+	variables, err := os.ReadFile(filepath.Join(dir, "variables.tf"))
+	require.NoError(t, err)
+	assert.NotContains(t, string(variables), `variable "namespace"`)
+	assert.NotContains(t, string(variables), `variable "allowed_roles"`)
+}
+
+func TestHCLEscape(t *testing.T) {
+	assert.Equal(t, `say \"hi\"\n`, hclEscape("say \"hi\"\n"))
+	assert.Equal(t, `$${HOME} %%{if} $5 100%`, hclEscape("${HOME} %{if} $5 100%"))
+	assert.Equal(t, `"api.url"`, hclKey("api.url"))
+	assert.Equal(t, "api_url", hclKey("api_url"))
+}
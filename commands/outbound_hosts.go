@@ -0,0 +1,260 @@
+package commands
+
+import (
+	"fmt"
+	"io/fs"
+	"maps"
+	"net"
+	"net/url"
+	"os"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// outboundHostIssue is a URL a component is configured to call that its
+// allowed_outbound_hosts don't cover
+type outboundHostIssue struct {
+	Component string
+	// Origin is where the URL was found, e.g. "variable api_url"
+	Origin string
+	URL    string
+}
+
+// Suggestion returns the allowed_outbound_hosts entry that would cover the URL
+func (i outboundHostIssue) Suggestion() string {
+	u, err := url.Parse(i.URL)
+	if err != nil {
+		return i.URL
+	}
+	return u.Scheme + "://" + u.Host
+}
+
+var (
+	// variableTemplate matches Spin variable references, e.g. {{ api_url }}
+	variableTemplate = regexp.MustCompile(`\{\{\s*([a-zA-Z0-9_]+)\s*\}\}`)
+
+	// sdkBaseURL matches base URLs given to the Rust SDK's HTTP client,
+	// e.g. Client::new().base_url("https://api.weather.example")
+	sdkBaseURL = regexp.MustCompile(`\.base_url\(\s*"(https?://[^"\s]+)"`)
+)
+
+// Directories never scanned for SDK HTTP client calls
+var skippedSourceDirs = []string{"target", "node_modules", "dist", "build", "vendor"}
+
+// CheckOutboundHosts warns about URLs the components of app are configured
+// to call that their allowed_outbound_hosts don't cover. In strict mode they
+// are an error instead. values are variable values overriding the defaults.
+// app may be nil for configs that cannot be inspected.
+func CheckOutboundHosts(app *validation.Application, configFile string, values map[string]string, strict bool, progress Progress) error {
+	if app == nil {
+		return nil
+	}
+
+	issues := findOutboundHostIssues(app, filepath.Dir(configFile), values)
+	for _, issue := range issues {
+		progress.warn(StageCheck, "Component '%s' calls %s (%s), which is not in its allowed_outbound_hosts; add %q",
+			issue.Component, issue.URL, issue.Origin, issue.Suggestion())
+	}
+	if strict && len(issues) > 0 {
+		return fmt.Errorf("%d outbound URL(s) not covered by allowed_outbound_hosts", len(issues))
+	}
+	return nil
+}
+
+// findOutboundHostIssues scans the variables of each component, and the
+// sources of local components for SDK HTTP client base URLs, for URLs that
+// allowed_outbound_hosts don't cover
+func findOutboundHostIssues(app *validation.Application, projectDir string, values map[string]string) []outboundHostIssue {
+	appValues := app.VariableDefaults()
+	for name, value := range values {
+		appValues[name] = value
+	}
+
+	var issues []outboundHostIssue
+	for _, comp := range app.Components {
+		check := func(origin, rawURL string) {
+			if !outboundHostAllowed(comp.AllowedOutboundHosts, rawURL) {
+				issues = append(issues, outboundHostIssue{Component: comp.ID, Origin: origin, URL: rawURL})
+			}
+		}
+
+		for _, name := range slices.Sorted(maps.Keys(comp.Variables)) {
+			if value, ok := resolveVariableTemplate(comp.Variables[name], appValues); ok && isHTTPURL(value) {
+				check("variable "+name, value)
+			}
+		}
+
+		if dir := componentSourceDir(comp); dir != "" {
+			for _, found := range scanSDKBaseURLs(filepath.Join(projectDir, dir)) {
+				origin, err := filepath.Rel(projectDir, found.file)
+				if err != nil {
+					origin = found.file
+				}
+				check(filepath.ToSlash(origin), found.url)
+			}
+		}
+	}
+	return issues
+}
+
+// resolveVariableTemplate substitutes application variable references in a
+// component variable, failing when one has no known value
+func resolveVariableTemplate(value string, appValues map[string]string) (string, bool) {
+	resolved := true
+	value = variableTemplate.ReplaceAllStringFunc(value, func(ref string) string {
+		name := variableTemplate.FindStringSubmatch(ref)[1]
+		v, ok := appValues[name]
+		if !ok {
+			resolved = false
+		}
+		return v
+	})
+	return strings.TrimSpace(value), resolved
+}
+
+func isHTTPURL(value string) bool {
+	u, err := url.Parse(value)
+	return err == nil && (u.Scheme == "http" || u.Scheme == "https") && u.Hostname() != ""
+}
+
+// componentSourceDir returns the project directory holding a local
+// component's sources: its build workdir, or the top directory of its source
+// when named after the component
+func componentSourceDir(comp *validation.Component) string {
+	src, ok := comp.Source.(*validation.LocalSource)
+	if !ok {
+		return ""
+	}
+
+	var dir string
+	if comp.Build != nil && comp.Build.Workdir != "" {
+		dir = filepath.ToSlash(filepath.Clean(comp.Build.Workdir))
+	} else if top, _, _ := strings.Cut(filepath.ToSlash(filepath.Clean(src.Path)), "/"); top == comp.ID {
+		dir = top
+	}
+
+	if dir == "." || dir == ".." || strings.HasPrefix(dir, "../") || filepath.IsAbs(dir) {
+		return ""
+	}
+	return dir
+}
+
+type sdkBaseURLMatch struct {
+	file string
+	url  string
+}
+
+// scanSDKBaseURLs finds literal base URLs of the Rust SDK's HTTP client in
+// the Rust sources under dir
+func scanSDKBaseURLs(dir string) []sdkBaseURLMatch {
+	var matches []sdkBaseURLMatch
+	_ = filepath.WalkDir(dir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return nil
+		}
+		if d.IsDir() {
+			if path != dir && (strings.HasPrefix(d.Name(), ".") || slices.Contains(skippedSourceDirs, d.Name())) {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+		if filepath.Ext(path) != ".rs" {
+			return nil
+		}
+		data, err := os.ReadFile(filepath.Clean(path))
+		if err != nil {
+			return nil
+		}
+		for _, m := range sdkBaseURL.FindAllSubmatch(data, -1) {
+			matches = append(matches, sdkBaseURLMatch{file: path, url: string(m[1])})
+		}
+		return nil
+	})
+	return matches
+}
+
+// outboundHostAllowed reports whether a URL is covered by Spin
+// allowed_outbound_hosts entries. Entries using variable templates can't be
+// checked statically and are assumed to cover it.
+func outboundHostAllowed(allowed []string, rawURL string) bool {
+	u, err := url.Parse(rawURL)
+	if err != nil {
+		return true
+	}
+	host := strings.ToLower(u.Hostname())
+	port := u.Port()
+	if port == "" {
+		port = defaultPort(u.Scheme)
+	}
+
+	for _, entry := range allowed {
+		if strings.Contains(entry, "{{") {
+			return true
+		}
+
+		scheme, rest, ok := strings.Cut(entry, "://")
+		if !ok || (scheme != "*" && !strings.EqualFold(scheme, u.Scheme)) {
+			continue
+		}
+		if i := strings.Index(rest, "/"); i >= 0 {
+			rest = rest[:i]
+		}
+
+		entryHost, entryPort := rest, ""
+		if h, p, err := net.SplitHostPort(rest); err == nil {
+			entryHost, entryPort = h, p
+		}
+		if entryPort == "" {
+			entryPort = defaultPort(scheme)
+		}
+
+		if hostMatches(strings.ToLower(entryHost), host) && portMatches(entryPort, port) {
+			return true
+		}
+	}
+	return false
+}
+
+func defaultPort(scheme string) string {
+	switch strings.ToLower(scheme) {
+	case "http":
+		return "80"
+	case "https":
+		return "443"
+	}
+	// Wildcard schemes need an explicit port
+	return "*"
+}
+
+func hostMatches(pattern, host string) bool {
+	if pattern == "*" || pattern == host {
+		return true
+	}
+	if suffix, ok := strings.CutPrefix(pattern, "*."); ok {
+		return strings.HasSuffix(host, "."+suffix)
+	}
+	return false
+}
+
+// portMatches matches a port against a port, a range (8000..8010) or *
+func portMatches(pattern, port string) bool {
+	if pattern == "*" || pattern == port {
+		return true
+	}
+	lo, hi, ok := strings.Cut(pattern, "..")
+	if !ok {
+		return false
+	}
+	p, err := strconv.Atoi(port)
+	if err != nil {
+		return false
+	}
+	low, errLow := strconv.Atoi(lo)
+	high, errHigh := strconv.Atoi(hi)
+	return errLow == nil && errHigh == nil && p >= low && p <= high
+}
@@ -0,0 +1,114 @@
+package commands
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+func TestOutboundHostAllowed(t *testing.T) {
+	tests := []struct {
+		name    string
+		allowed []string
+		url     string
+		want    bool
+	}{
+		{"exact host", []string{"https://api.example.com"}, "https://api.example.com/v1", true},
+		{"other host", []string{"https://api.example.com"}, "https://example.com", false},
+		{"scheme mismatch", []string{"https://api.example.com"}, "http://api.example.com", false},
+		{"wildcard scheme", []string{"*://api.example.com:443"}, "https://api.example.com", true},
+		{"subdomain wildcard", []string{"https://*.example.com"}, "https://api.example.com", true},
+		{"subdomain wildcard excludes apex", []string{"https://*.example.com"}, "https://example.com", false},
+		{"any host", []string{"https://*"}, "https://anything.example", true},
+		{"default port", []string{"https://api.example.com"}, "https://api.example.com:8443", false},
+		{"explicit port", []string{"https://api.example.com:8443"}, "https://api.example.com:8443/x", true},
+		{"port range", []string{"http://localhost:8000..8010"}, "http://localhost:8005", true},
+		{"any port", []string{"http://localhost:*"}, "http://localhost:3000", true},
+		{"templated entry", []string{"https://{{ api_host }}"}, "https://api.example.com", true},
+		{"nothing allowed", nil, "https://api.example.com", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			assert.Equal(t, tt.want, outboundHostAllowed(tt.allowed, tt.url))
+		})
+	}
+}
+
+func TestFindOutboundHostIssues(t *testing.T) {
+	projectDir := t.TempDir()
+	srcDir := filepath.Join(projectDir, "weather", "src")
+	require.NoError(t, os.MkdirAll(srcDir, 0755))
+	require.NoError(t, os.WriteFile(filepath.Join(srcDir, "lib.rs"), []byte(`
+let client = Client::new()
+    .base_url("https://api.weather.example")
+    .context(&ctx);
+let geo = Client::new().base_url("https://geo.example.com/v2");
+`), 0600))
+	// Build output is not scanned
+	targetDir := filepath.Join(projectDir, "weather", "target")
+	require.NoError(t, os.MkdirAll(targetDir, 0755))
+	require.NoError(t, os.WriteFile(filepath.Join(targetDir, "gen.rs"), []byte(`.base_url("https://ignored.example")`), 0600))
+
+	region := "https://eu.api.example"
+	app := &validation.Application{
+		Variables: map[string]validation.Variable{
+			"region_url": validation.VariableWithDefault(region),
+			"token":      {Required: true},
+		},
+		Components: []*validation.Component{
+			{
+				ID:     "weather",
+				Source: &validation.LocalSource{Path: "weather/target/wasm32-wasip1/release/weather.wasm"},
+				Build:  &validation.BuildConfig{Command: "cargo build", Workdir: "weather"},
+				Variables: map[string]string{
+					"api_url":    "https://api.weather.example",
+					"region_url": "{{ region_url }}",
+					"token":      "{{ token }}",
+					"log_level":  "info",
+				},
+				AllowedOutboundHosts: []string{"https://api.weather.example"},
+			},
+			{
+				ID:        "calc",
+				Source:    &validation.RegistrySource{Registry: "ghcr.io", Package: "acme:calc", Version: "1.0.0"},
+				Variables: map[string]string{"backend": "http://calc-backend.internal:8080"},
+			},
+		},
+	}
+
+	issues := findOutboundHostIssues(app, projectDir, nil)
+	assert.Equal(t, []outboundHostIssue{
+		{Component: "weather", Origin: "variable region_url", URL: "https://eu.api.example"},
+		{Component: "weather", Origin: "weather/src/lib.rs", URL: "https://geo.example.com/v2"},
+		{Component: "calc", Origin: "variable backend", URL: "http://calc-backend.internal:8080"},
+	}, issues)
+	assert.Equal(t, "http://calc-backend.internal:8080", issues[2].Suggestion())
+
+	// Deploy-time values replace the defaults
+	issues = findOutboundHostIssues(app, projectDir, map[string]string{"region_url": "https://api.weather.example/eu"})
+	assert.Len(t, issues, 2)
+}
+
+func TestCheckOutboundHosts_Strict(t *testing.T) {
+	app := &validation.Application{
+		Components: []*validation.Component{{
+			ID:        "tool",
+			Source:    &validation.LocalSource{Path: "tool.wasm"},
+			Variables: map[string]string{"api_url": "https://api.example.com"},
+		}},
+	}
+
+	assert.NoError(t, CheckOutboundHosts(app, "ftl.yaml", nil, false, nil))
+	assert.EqualError(t, CheckOutboundHosts(app, "ftl.yaml", nil, true, nil),
+		"1 outbound URL(s) not covered by allowed_outbound_hosts")
+
+	app.Components[0].AllowedOutboundHosts = []string{"https://api.example.com"}
+	assert.NoError(t, CheckOutboundHosts(app, "ftl.yaml", nil, true, nil))
+	assert.NoError(t, CheckOutboundHosts(nil, "ftl.yaml", nil, true, nil))
+}
@@ -0,0 +1,155 @@
+package commands
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"sort"
+	"strings"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// builtinProfiles are available without being declared in the FTL config.
+// A profile declared with the same name replaces the built-in one.
+var builtinProfiles = map[string]validation.BuildProfile{
+	"size":  {Optimize: validation.OptimizeSize, WasmOpt: []string{"-Oz"}},
+	"speed": {Optimize: validation.OptimizeSpeed, WasmOpt: []string{"-O3"}},
+}
+
+// optimizeEnv holds the default flags each optimization goal sets, per
+// toolchain. They only affect their own toolchain, so all of them are set
+// whatever language the components are written in.
+var optimizeEnv = map[string]map[string][]string{
+	validation.OptimizeSize: {
+		"rust": {
+			"CARGO_PROFILE_RELEASE_OPT_LEVEL=z",
+			"CARGO_PROFILE_RELEASE_LTO=true",
+			"CARGO_PROFILE_RELEASE_CODEGEN_UNITS=1",
+			"CARGO_PROFILE_RELEASE_STRIP=true",
+		},
+	},
+	validation.OptimizeSpeed: {
+		"rust": {
+			"CARGO_PROFILE_RELEASE_OPT_LEVEL=3",
+			"CARGO_PROFILE_RELEASE_LTO=true",
+			"CARGO_PROFILE_RELEASE_CODEGEN_UNITS=1",
+		},
+	},
+}
+
+var (
+	// For testing - allows overriding how wasm-opt is found and run
+	lookWasmOpt = func() (string, error) {
+		return exec.LookPath("wasm-opt")
+	}
+	runWasmOpt = func(ctx context.Context, stdout, stderr io.Writer, binary string, args ...string) error {
+		cmd := exec.CommandContext(ctx, binary, args...) // #nosec G204 -- binary is wasm-opt found on PATH
+		cmd.Stdout = stdout
+		cmd.Stderr = stderr
+		return cmd.Run()
+	}
+)
+
+// resolveBuildProfile returns the named profile declared by app, falling back
+// to the built-in profiles. app may be nil for configs that cannot declare
+// profiles.
+func resolveBuildProfile(app *validation.Application, name string) (validation.BuildProfile, error) {
+	if app != nil {
+		if profile, ok := app.Profiles[name]; ok {
+			return profile, nil
+		}
+	}
+	if profile, ok := builtinProfiles[name]; ok {
+		return profile, nil
+	}
+
+	names := make(map[string]bool)
+	for builtin := range builtinProfiles {
+		names[builtin] = true
+	}
+	if app != nil {
+		for declared := range app.Profiles {
+			names[declared] = true
+		}
+	}
+	available := make([]string, 0, len(names))
+	for profile := range names {
+		available = append(available, profile)
+	}
+	sort.Strings(available)
+	return validation.BuildProfile{}, fmt.Errorf("unknown build profile %q (available: %s)", name, strings.Join(available, ", "))
+}
+
+// profileEnv returns the environment a profile adds to build commands.
+// Toolchain defaults give way to variables already set in the environment,
+// while variables set by the profile itself always apply.
+func profileEnv(profile validation.BuildProfile) []string {
+	var env []string
+
+	toolchains := optimizeEnv[profile.Optimize]
+	names := make([]string, 0, len(toolchains))
+	for name := range toolchains {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	for _, name := range names {
+		for _, entry := range toolchains[name] {
+			key, _, _ := strings.Cut(entry, "=")
+			if _, set := os.LookupEnv(key); !set {
+				env = append(env, entry)
+			}
+		}
+	}
+
+	keys := make([]string, 0, len(profile.Env))
+	for key := range profile.Env {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	for _, key := range keys {
+		env = append(env, key+"="+profile.Env[key])
+	}
+	return env
+}
+
+// optimizeBuiltComponents runs the profile's wasm-opt pass over each built
+// component in place. A missing wasm-opt is not an error since the build
+// itself succeeded.
+func optimizeBuiltComponents(ctx context.Context, profile validation.BuildProfile, sources map[string]string, stdout, stderr io.Writer, progress Progress) error {
+	if len(profile.WasmOpt) == 0 || len(sources) == 0 {
+		return nil
+	}
+
+	binary, err := lookWasmOpt()
+	if err != nil {
+		progress.warn(StageBuild, "wasm-opt not found on PATH; skipping the post-build optimization")
+		return nil
+	}
+
+	ids := make([]string, 0, len(sources))
+	for id := range sources {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	for _, id := range ids {
+		path := sources[id]
+		before, err := os.Stat(path)
+		if err != nil {
+			return fmt.Errorf("built component %s not found at %s: %w", id, path, err)
+		}
+
+		args := append(append([]string{}, profile.WasmOpt...), path, "-o", path)
+		if err := runWasmOpt(ctx, stdout, stderr, binary, args...); err != nil {
+			return fmt.Errorf("wasm-opt failed for %s: %w", id, err)
+		}
+
+		if after, err := os.Stat(path); err == nil {
+			progress.info(StageBuild, "Optimized %s: %s → %s", id, FormatSize(before.Size()), FormatSize(after.Size()))
+		}
+	}
+	return nil
+}
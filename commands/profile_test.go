@@ -0,0 +1,124 @@
+package commands
+
+import (
+	"context"
+	"errors"
+	"io"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+func TestResolveBuildProfile(t *testing.T) {
+	// Built-in profiles need no config
+	profile, err := resolveBuildProfile(nil, "size")
+	require.NoError(t, err)
+	assert.Equal(t, validation.OptimizeSize, profile.Optimize)
+	assert.Equal(t, []string{"-Oz"}, profile.WasmOpt)
+
+	app := &validation.Application{Profiles: map[string]validation.BuildProfile{
+		"size": {Optimize: validation.OptimizeSize},
+		"ci":   {Env: map[string]string{"RUSTFLAGS": "-D warnings"}},
+	}}
+
+	// Declared profiles replace built-in ones
+	profile, err = resolveBuildProfile(app, "size")
+	require.NoError(t, err)
+	assert.Empty(t, profile.WasmOpt)
+
+	profile, err = resolveBuildProfile(app, "ci")
+	require.NoError(t, err)
+	assert.Equal(t, "-D warnings", profile.Env["RUSTFLAGS"])
+
+	_, err = resolveBuildProfile(app, "tiny")
+	assert.EqualError(t, err, `unknown build profile "tiny" (available: ci, size, speed)`)
+}
+
+func TestProfileEnv(t *testing.T) {
+	t.Setenv("CARGO_PROFILE_RELEASE_LTO", "false")
+
+	env := profileEnv(validation.BuildProfile{
+		Optimize: validation.OptimizeSize,
+		Env:      map[string]string{"RUSTFLAGS": "-C target-cpu=mvp", "CARGO_PROFILE_RELEASE_STRIP": "false"},
+	})
+
+	assert.Contains(t, env, "CARGO_PROFILE_RELEASE_OPT_LEVEL=z")
+	// The user's environment wins over toolchain defaults
+	assert.NotContains(t, env, "CARGO_PROFILE_RELEASE_LTO=true")
+	// Profile variables come last so they win over toolchain defaults
+	assert.Equal(t, []string{"CARGO_PROFILE_RELEASE_STRIP=false", "RUSTFLAGS=-C target-cpu=mvp"}, env[len(env)-2:])
+
+	assert.Empty(t, profileEnv(validation.BuildProfile{}))
+}
+
+func TestOptimizeBuiltComponents(t *testing.T) {
+	dir := t.TempDir()
+	wasm := filepath.Join(dir, "echo.wasm")
+	require.NoError(t, os.WriteFile(wasm, []byte("\x00asm"), 0600))
+
+	oldLook, oldRun := lookWasmOpt, runWasmOpt
+	t.Cleanup(func() { lookWasmOpt, runWasmOpt = oldLook, oldRun })
+
+	var calls [][]string
+	lookWasmOpt = func() (string, error) { return "/usr/bin/wasm-opt", nil }
+	runWasmOpt = func(_ context.Context, _, _ io.Writer, binary string, args ...string) error {
+		calls = append(calls, append([]string{binary}, args...))
+		return nil
+	}
+
+	profile := validation.BuildProfile{WasmOpt: []string{"-Oz"}}
+	require.NoError(t, optimizeBuiltComponents(context.Background(), profile, map[string]string{"echo": wasm}, io.Discard, io.Discard, nil))
+	assert.Equal(t, [][]string{{"/usr/bin/wasm-opt", "-Oz", wasm, "-o", wasm}}, calls)
+
+	// Skipped without a wasm-opt pass or without wasm-opt installed
+	calls = nil
+	require.NoError(t, optimizeBuiltComponents(context.Background(), validation.BuildProfile{}, map[string]string{"echo": wasm}, io.Discard, io.Discard, nil))
+	lookWasmOpt = func() (string, error) { return "", errors.New("not found") }
+	require.NoError(t, optimizeBuiltComponents(context.Background(), profile, map[string]string{"echo": wasm}, io.Discard, io.Discard, nil))
+	assert.Empty(t, calls)
+
+	// A component that was not built is an error
+	lookWasmOpt = func() (string, error) { return "/usr/bin/wasm-opt", nil }
+	err := optimizeBuiltComponents(context.Background(), profile, map[string]string{"missing": filepath.Join(dir, "missing.wasm")}, io.Discard, io.Discard, nil)
+	assert.ErrorContains(t, err, "built component missing not found")
+}
+
+func TestLoadApplication_Profiles(t *testing.T) {
+	dir := t.TempDir()
+	configFile := filepath.Join(dir, "ftl.yaml")
+	require.NoError(t, os.WriteFile(configFile, []byte(`name: test-app
+components:
+  - id: echo
+    source: ./echo.wasm
+    build:
+      command: cargo build --release
+profiles:
+  size:
+    optimize: size
+    env:
+      RUSTFLAGS: "-C target-feature=+bulk-memory"
+    wasm_opt: ["-Oz", "--strip-debug"]
+`), 0600))
+
+	app, err := LoadApplication(configFile)
+	require.NoError(t, err)
+	require.Contains(t, app.Profiles, "size")
+	assert.Equal(t, validation.BuildProfile{
+		Optimize: validation.OptimizeSize,
+		Env:      map[string]string{"RUSTFLAGS": "-C target-feature=+bulk-memory"},
+		WasmOpt:  []string{"-Oz", "--strip-debug"},
+	}, app.Profiles["size"])
+
+	require.NoError(t, os.WriteFile(configFile, []byte(`name: test-app
+profiles:
+  size:
+    optimize: tiny
+`), 0600))
+	_, err = LoadApplication(configFile)
+	assert.Error(t, err)
+}
@@ -0,0 +1,62 @@
+package commands
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/validation"
+)
+
+// usesPullOrBuild reports whether any component opts into the pull-or-build strategy
+func usesPullOrBuild(app *validation.Application) bool {
+	for _, comp := range app.Components {
+		if comp.Build != nil && comp.Build.Strategy == validation.BuildStrategyPullOrBuild {
+			return true
+		}
+	}
+	return false
+}
+
+// pullPrebuiltComponents pulls the published artifact for every component using
+// the pull-or-build strategy and places it where the local build would have
+// written it, relative to configDir. It returns the IDs of the components that
+// still need a local build. A failed pull is not fatal: the component simply
+// falls back to building.
+func pullPrebuiltComponents(ctx context.Context, configDir string, app *validation.Application, puller Puller, progress Progress) ([]string, error) {
+	var toBuild []string
+
+	for _, comp := range app.Components {
+		src, ok := comp.Source.(*validation.LocalSource)
+		if !ok || comp.Build == nil || comp.Build.Command == "" {
+			continue
+		}
+
+		if comp.Build.Strategy != validation.BuildStrategyPullOrBuild {
+			toBuild = append(toBuild, comp.ID)
+			continue
+		}
+
+		reg := comp.Build.Registry
+		if reg == nil {
+			progress.warn(StagePull, "Component %s uses pull-or-build but has no build.registry; building locally", comp.ID)
+			toBuild = append(toBuild, comp.ID)
+			continue
+		}
+
+		progress.info(StagePull, "Pulling prebuilt %s from %s/%s:%s", comp.ID, reg.Registry, reg.Package, reg.Version)
+		wasmPath, err := puller.Pull(ctx, reg.Registry, reg.Package, reg.Version)
+		if err != nil {
+			progress.warn(StagePull, "Could not pull %s (%v); building locally", comp.ID, err)
+			toBuild = append(toBuild, comp.ID)
+			continue
+		}
+
+		if err := oci.LinkOrCopy(wasmPath, projectPath(configDir, src.Path)); err != nil {
+			return nil, fmt.Errorf("failed to place pulled artifact for %s: %w", comp.ID, err)
+		}
+		progress.success(StagePull, "Using prebuilt %s", comp.ID)
+	}
+
+	return toBuild, nil
+}
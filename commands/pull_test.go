@@ -0,0 +1,129 @@
+package commands
+
+import (
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+type fakePuller struct {
+	artifacts map[string]string
+	calls     []string
+}
+
+func (f *fakePuller) Pull(_ context.Context, registry, packageName, version string) (string, error) {
+	ref := registry + "/" + packageName + ":" + version
+	f.calls = append(f.calls, ref)
+	if path, ok := f.artifacts[ref]; ok {
+		return path, nil
+	}
+	return "", errors.New("not found")
+}
+
+func TestPullPrebuiltComponents(t *testing.T) {
+	tmpDir := t.TempDir()
+	cached := filepath.Join(tmpDir, "cache", "tool.wasm")
+	require.NoError(t, os.MkdirAll(filepath.Dir(cached), 0750))
+	require.NoError(t, os.WriteFile(cached, []byte("wasm"), 0600))
+
+	pulledDest := filepath.Join(tmpDir, "go-tool", "main.wasm")
+	app := &validation.Application{
+		Name: "test-app",
+		Components: []*validation.Component{
+			{
+				ID:     "go-tool",
+				Source: &validation.LocalSource{Path: pulledDest},
+				Build: &validation.BuildConfig{
+					Command:  "tinygo build",
+					Strategy: validation.BuildStrategyPullOrBuild,
+					Registry: &validation.RegistrySource{Registry: "ghcr.io", Package: "acme:go-tool", Version: "1.0.0"},
+				},
+			},
+			{
+				ID:     "missing-tool",
+				Source: &validation.LocalSource{Path: filepath.Join(tmpDir, "missing", "main.wasm")},
+				Build: &validation.BuildConfig{
+					Command:  "cargo build",
+					Strategy: validation.BuildStrategyPullOrBuild,
+					Registry: &validation.RegistrySource{Registry: "ghcr.io", Package: "acme:missing-tool", Version: "1.0.0"},
+				},
+			},
+			{
+				ID:     "no-registry",
+				Source: &validation.LocalSource{Path: "no-registry/main.wasm"},
+				Build:  &validation.BuildConfig{Command: "make", Strategy: validation.BuildStrategyPullOrBuild},
+			},
+			{
+				ID:     "rust-tool",
+				Source: &validation.LocalSource{Path: "rust-tool/main.wasm"},
+				Build:  &validation.BuildConfig{Command: "cargo build"},
+			},
+			{
+				ID:     "remote",
+				Source: &validation.RegistrySource{Registry: "ghcr.io", Package: "acme:remote", Version: "1.0.0"},
+			},
+		},
+	}
+
+	puller := &fakePuller{artifacts: map[string]string{"ghcr.io/acme:go-tool:1.0.0": cached}}
+	toBuild, err := pullPrebuiltComponents(context.Background(), tmpDir, app, puller, nil)
+	require.NoError(t, err)
+
+	assert.Equal(t, []string{"missing-tool", "no-registry", "rust-tool"}, toBuild)
+	assert.Equal(t, []string{"ghcr.io/acme:go-tool:1.0.0", "ghcr.io/acme:missing-tool:1.0.0"}, puller.calls)
+
+	data, err := os.ReadFile(pulledDest)
+	require.NoError(t, err)
+	assert.Equal(t, "wasm", string(data))
+}
+
+func TestUsesPullOrBuild(t *testing.T) {
+	app := &validation.Application{
+		Components: []*validation.Component{
+			{ID: "a", Build: &validation.BuildConfig{Command: "make"}},
+		},
+	}
+	assert.False(t, usesPullOrBuild(app))
+
+	app.Components = append(app.Components, &validation.Component{
+		ID:    "b",
+		Build: &validation.BuildConfig{Command: "make", Strategy: validation.BuildStrategyPullOrBuild},
+	})
+	assert.True(t, usesPullOrBuild(app))
+}
+
+func TestLoadApplication_PullOrBuild(t *testing.T) {
+	tmpDir := t.TempDir()
+	configPath := filepath.Join(tmpDir, "ftl.yaml")
+	yamlContent := `name: test-app
+components:
+  - id: go-tool
+    source: go-tool/main.wasm
+    build:
+      command: tinygo build -o main.wasm
+      strategy: pull-or-build
+      registry:
+        registry: ghcr.io
+        package: acme:go-tool
+        version: 1.0.0
+`
+	require.NoError(t, os.WriteFile(configPath, []byte(yamlContent), 0600))
+
+	app, err := LoadApplication(configPath)
+	require.NoError(t, err)
+	require.Len(t, app.Components, 1)
+
+	build := app.Components[0].Build
+	require.NotNil(t, build)
+	assert.Equal(t, validation.BuildStrategyPullOrBuild, build.Strategy)
+	require.NotNil(t, build.Registry)
+	assert.Equal(t, "acme:go-tool", build.Registry.Package)
+	assert.Equal(t, "1.0.0", build.Registry.Version)
+}
@@ -0,0 +1,185 @@
+package commands
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+// buildManifestPath is where reproducible builds record the digests of the
+// built components, relative to the project root
+var buildManifestPath = filepath.Join(".ftl", "build-manifest.json")
+
+// buildManifest records what a reproducible build produced, so the deployed
+// Wasm can be checked against a build from the same source
+type buildManifest struct {
+	SourceDateEpoch int64            `json:"source_date_epoch"`
+	Profile         string           `json:"profile,omitempty"`
+	Components      []BuiltComponent `json:"components"`
+}
+
+// BuiltComponent is a component as produced by a reproducible build
+type BuiltComponent struct {
+	ID string `json:"id"`
+	// Path is the path of the built Wasm, relative to the project directory
+	Path   string `json:"path"`
+	SHA256 string `json:"sha256"`
+	Size   int64  `json:"size"`
+}
+
+// For testing - allows overriding how the commit time of HEAD is found
+var headCommitTime = func(dir string) (int64, error) {
+	cmd := exec.Command("git", "log", "-1", "--format=%ct")
+	cmd.Dir = dir
+	out, err := cmd.Output()
+	if err != nil {
+		return 0, err
+	}
+	return strconv.ParseInt(strings.TrimSpace(string(out)), 10, 64)
+}
+
+// sourceDateEpoch returns the timestamp builds of the project in dir embed
+// instead of the current time: SOURCE_DATE_EPOCH when set, else the commit
+// time of HEAD, else 0
+func sourceDateEpoch(dir string, progress Progress) int64 {
+	if value, ok := os.LookupEnv("SOURCE_DATE_EPOCH"); ok {
+		if epoch, err := strconv.ParseInt(value, 10, 64); err == nil {
+			return epoch
+		}
+		progress.warn(StageBuild, "Ignoring invalid SOURCE_DATE_EPOCH %q", value)
+	}
+	if epoch, err := headCommitTime(dir); err == nil {
+		return epoch
+	}
+	return 0
+}
+
+// reproducibleEnv extends env, the environment build commands already get,
+// with the settings that keep machine-specific data out of the output: local
+// paths are remapped, timestamps fixed and incremental compilation disabled.
+func reproducibleEnv(env []string, projectDir string, epoch int64) []string {
+	remap := []string{"--remap-path-prefix=" + projectDir + "=."}
+	if cargoHome := cargoHomeDir(); cargoHome != "" {
+		remap = append(remap, "--remap-path-prefix="+cargoHome+"=/cargo")
+	}
+
+	return append(append([]string{}, env...),
+		"RUSTFLAGS="+joinFlags(envValue(env, "RUSTFLAGS"), remap...),
+		"GOFLAGS="+joinFlags(envValue(env, "GOFLAGS"), "-trimpath"),
+		"CARGO_INCREMENTAL=0",
+		"SOURCE_DATE_EPOCH="+strconv.FormatInt(epoch, 10),
+	)
+}
+
+// envValue returns the value key has for build commands: its last entry in
+// env, else the current environment
+func envValue(env []string, key string) string {
+	for i := len(env) - 1; i >= 0; i-- {
+		if k, value, _ := strings.Cut(env[i], "="); k == key {
+			return value
+		}
+	}
+	return os.Getenv(key)
+}
+
+// joinFlags appends flags to a space-separated flag list
+func joinFlags(existing string, flags ...string) string {
+	return strings.TrimSpace(existing + " " + strings.Join(flags, " "))
+}
+
+// cargoHomeDir returns the directory Cargo keeps dependency sources in
+func cargoHomeDir() string {
+	if dir := os.Getenv("CARGO_HOME"); dir != "" {
+		return dir
+	}
+	if home, err := os.UserHomeDir(); err == nil {
+		return filepath.Join(home, ".cargo")
+	}
+	return ""
+}
+
+// digestBuiltComponents computes the sha256 of each component built in dir,
+// sorted by id
+func digestBuiltComponents(dir string, sources map[string]string) ([]BuiltComponent, error) {
+	ids := make([]string, 0, len(sources))
+	for id := range sources {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	components := make([]BuiltComponent, 0, len(ids))
+	for _, id := range ids {
+		digest, size, err := fileSHA256(sources[id])
+		if err != nil {
+			return nil, fmt.Errorf("built component %s not found at %s: %w", id, sources[id], err)
+		}
+		path, err := filepath.Rel(dir, sources[id])
+		if err != nil {
+			path = sources[id]
+		}
+		components = append(components, BuiltComponent{ID: id, Path: path, SHA256: digest, Size: size})
+	}
+	return components, nil
+}
+
+func fileSHA256(path string) (string, int64, error) {
+	f, err := os.Open(filepath.Clean(path))
+	if err != nil {
+		return "", 0, err
+	}
+	defer func() { _ = f.Close() }()
+
+	h := sha256.New()
+	size, err := io.Copy(h, f)
+	if err != nil {
+		return "", 0, err
+	}
+	return hex.EncodeToString(h.Sum(nil)), size, nil
+}
+
+// writeBuildManifest writes the manifest to buildManifestPath in dir
+func writeBuildManifest(dir string, manifest *buildManifest) error {
+	path := filepath.Join(dir, buildManifestPath)
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+
+	data, err := json.MarshalIndent(manifest, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode build manifest: %w", err)
+	}
+	if err := os.WriteFile(path, append(data, '\n'), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
+
+// recordBuildDigests reports the sha256 of each component built in dir and
+// writes them to its build manifest
+func recordBuildDigests(dir string, sources map[string]string, profile string, epoch int64, progress Progress) ([]BuiltComponent, error) {
+	components, err := digestBuiltComponents(dir, sources)
+	if err != nil {
+		return nil, err
+	}
+	for _, c := range components {
+		progress.info(StageBuild, "%s sha256:%s (%s)", c.ID, c.SHA256, FormatSize(c.Size))
+	}
+
+	if err := writeBuildManifest(dir, &buildManifest{
+		SourceDateEpoch: epoch,
+		Profile:         profile,
+		Components:      components,
+	}); err != nil {
+		return nil, err
+	}
+	progress.success(StageBuild, "Wrote %s", buildManifestPath)
+	return components, nil
+}
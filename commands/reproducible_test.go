@@ -0,0 +1,87 @@
+package commands
+
+import (
+	"encoding/json"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestReproducibleEnv(t *testing.T) {
+	t.Setenv("CARGO_HOME", "/home/dev/.cargo")
+	t.Setenv("RUSTFLAGS", "-C opt-level=s")
+	t.Setenv("GOFLAGS", "")
+
+	env := reproducibleEnv([]string{"CARGO_PROFILE_RELEASE_LTO=true"}, "/home/dev/app", 1700000000)
+	assert.Equal(t, []string{
+		"CARGO_PROFILE_RELEASE_LTO=true",
+		"RUSTFLAGS=-C opt-level=s --remap-path-prefix=/home/dev/app=. --remap-path-prefix=/home/dev/.cargo=/cargo",
+		"GOFLAGS=-trimpath",
+		"CARGO_INCREMENTAL=0",
+		"SOURCE_DATE_EPOCH=1700000000",
+	}, env)
+
+	// Flags set by the profile are kept
+	env = reproducibleEnv([]string{"RUSTFLAGS=-C target-cpu=mvp"}, "/home/dev/app", 0)
+	assert.Contains(t, env, "RUSTFLAGS=-C target-cpu=mvp --remap-path-prefix=/home/dev/app=. --remap-path-prefix=/home/dev/.cargo=/cargo")
+}
+
+func TestSourceDateEpoch(t *testing.T) {
+	oldHead := headCommitTime
+	t.Cleanup(func() { headCommitTime = oldHead })
+	headCommitTime = func(string) (int64, error) { return 1600000000, nil }
+
+	t.Setenv("SOURCE_DATE_EPOCH", "1700000000")
+	assert.Equal(t, int64(1700000000), sourceDateEpoch(".", nil))
+
+	// Falls back to the commit time of HEAD, then to 0
+	require.NoError(t, os.Unsetenv("SOURCE_DATE_EPOCH"))
+	assert.Equal(t, int64(1600000000), sourceDateEpoch(".", nil))
+
+	headCommitTime = func(string) (int64, error) { return 0, errors.New("not a git repository") }
+	assert.Equal(t, int64(0), sourceDateEpoch(".", nil))
+
+	// An invalid value is ignored with a warning
+	var events []Event
+	t.Setenv("SOURCE_DATE_EPOCH", "yesterday")
+	assert.Equal(t, int64(0), sourceDateEpoch(".", recordProgress(&events)))
+	require.Len(t, events, 1)
+	assert.Equal(t, LevelWarn, events[0].Level)
+}
+
+func TestRecordBuildDigests(t *testing.T) {
+	dir := t.TempDir()
+
+	echo := filepath.Join(dir, "echo.wasm")
+	require.NoError(t, os.WriteFile(echo, []byte("\x00asm"), 0600))
+	weather := filepath.Join(dir, "weather.wasm")
+	require.NoError(t, os.WriteFile(weather, []byte("weather"), 0600))
+
+	built, err := recordBuildDigests(dir, map[string]string{"weather": weather, "echo": echo}, "size", 1700000000, nil)
+	require.NoError(t, err)
+
+	data, err := os.ReadFile(filepath.Join(dir, ".ftl", "build-manifest.json"))
+	require.NoError(t, err)
+	var manifest buildManifest
+	require.NoError(t, json.Unmarshal(data, &manifest))
+
+	assert.Equal(t, int64(1700000000), manifest.SourceDateEpoch)
+	assert.Equal(t, "size", manifest.Profile)
+	require.Len(t, manifest.Components, 2)
+	assert.Equal(t, built, manifest.Components)
+	assert.Equal(t, BuiltComponent{
+		ID:     "echo",
+		Path:   "echo.wasm",
+		SHA256: "cd5d4935a48c0672cb06407bb443bc0087aff947c6b864bac886982c73b3027f",
+		Size:   4,
+	}, manifest.Components[0])
+	assert.Equal(t, "weather", manifest.Components[1].ID)
+
+	// A missing component fails the build
+	_, err = recordBuildDigests(dir, map[string]string{"gone": filepath.Join(dir, "gone.wasm")}, "", 0, nil)
+	assert.ErrorContains(t, err, "built component gone not found")
+}
@@ -0,0 +1,171 @@
+package commands
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// buildSizesPath is where builds keep the size of each component they built,
+// relative to the project root, so that the next build can report how they
+// changed
+var buildSizesPath = filepath.Join(".ftl", "build-sizes.json")
+
+// buildSizes records the size of each component as of its last build
+type buildSizes struct {
+	Components map[string]int64 `json:"components"`
+}
+
+// ComponentSize is the size of a built component
+type ComponentSize struct {
+	ID string
+	// Path is the built Wasm, as spin.toml locates it
+	Path string
+	Size int64
+	// Previous is the size as of the previous build, if HasPrevious
+	Previous    int64
+	HasPrevious bool
+	// Budget is the size budget of the component in bytes, 0 when it has none
+	Budget int64
+}
+
+// SizeBudgetError is returned when built components exceed their size budget
+type SizeBudgetError struct {
+	// Exceeded describes each component over its budget
+	Exceeded []string
+	// Sizes are the sizes of all the built components
+	Sizes []ComponentSize
+}
+
+func (e *SizeBudgetError) Error() string {
+	return fmt.Sprintf("size budget exceeded:\n  %s", strings.Join(e.Exceeded, "\n  "))
+}
+
+var sizePattern = regexp.MustCompile(`^([0-9]+(?:\.[0-9]+)?) ?([KMG]i?B|B)?$`)
+
+var sizeUnits = map[string]float64{
+	"": 1, "B": 1,
+	"KB": 1e3, "MB": 1e6, "GB": 1e9,
+	"KiB": 1 << 10, "MiB": 1 << 20, "GiB": 1 << 30,
+}
+
+// parseSize parses a number of bytes or a size such as "1.5MB" or "800KiB"
+func parseSize(s string) (int64, error) {
+	m := sizePattern.FindStringSubmatch(strings.TrimSpace(s))
+	if m == nil {
+		return 0, fmt.Errorf("invalid size %q: expected bytes or a size such as 1.5MB or 800KiB", s)
+	}
+	n, err := strconv.ParseFloat(m[1], 64)
+	if err != nil {
+		return 0, fmt.Errorf("invalid size %q: %w", s, err)
+	}
+	return int64(n * sizeUnits[m[2]]), nil
+}
+
+// checkBuildSizes checks the components built in dir against their size
+// budgets and records their sizes for the next build. Components over a
+// budget fail the check with a *SizeBudgetError, unless their budget only
+// warns.
+func checkBuildSizes(dir string, app *validation.Application, sources map[string]string, progress Progress) ([]ComponentSize, error) {
+	budgets := map[string]*validation.SizeBudget{}
+	if app != nil {
+		for _, comp := range app.Components {
+			if comp.SizeBudget != nil {
+				budgets[comp.ID] = comp.SizeBudget
+			}
+		}
+	}
+
+	ids := make([]string, 0, len(sources))
+	for id := range sources {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	previous := readBuildSizes(dir)
+	recorded := make(map[string]int64, len(previous)+len(ids))
+	for id, size := range previous {
+		recorded[id] = size
+	}
+
+	var sizes []ComponentSize
+	var exceeded []string
+	for _, id := range ids {
+		info, err := os.Stat(sources[id])
+		if err != nil {
+			// Only components with a budget must be where spin.toml says
+			if budgets[id] != nil {
+				return nil, fmt.Errorf("built component %s not found at %s: %w", id, sources[id], err)
+			}
+			continue
+		}
+		size := ComponentSize{ID: id, Path: sources[id], Size: info.Size()}
+		size.Previous, size.HasPrevious = previous[id]
+		recorded[id] = size.Size
+
+		if b := budgets[id]; b != nil {
+			limit, err := parseSize(b.Max)
+			if err != nil {
+				return nil, fmt.Errorf("size budget of component %s: %w", id, err)
+			}
+			size.Budget = limit
+			if size.Size > limit {
+				msg := fmt.Sprintf("component %s is %s, %s over its size budget of %s",
+					id, FormatSize(size.Size), FormatSize(size.Size-limit), FormatSize(limit))
+				if b.OnExceed == validation.SizeBudgetWarn {
+					progress.warn(StageBuild, "%s", msg)
+				} else {
+					exceeded = append(exceeded, msg)
+				}
+			}
+		}
+		sizes = append(sizes, size)
+	}
+
+	if err := writeBuildSizes(dir, &buildSizes{Components: recorded}); err != nil {
+		progress.warn(StageBuild, "Component sizes not recorded: %v", err)
+	}
+
+	if len(exceeded) > 0 {
+		return nil, &SizeBudgetError{Exceeded: exceeded, Sizes: sizes}
+	}
+	return sizes, nil
+}
+
+// readBuildSizes returns the component sizes of the previous builds in dir,
+// if any
+func readBuildSizes(dir string) map[string]int64 {
+	data, err := os.ReadFile(filepath.Join(dir, buildSizesPath))
+	if err != nil {
+		return map[string]int64{}
+	}
+	var sizes buildSizes
+	if err := json.Unmarshal(data, &sizes); err != nil || sizes.Components == nil {
+		return map[string]int64{}
+	}
+	return sizes.Components
+}
+
+// writeBuildSizes writes the component sizes to buildSizesPath in dir
+func writeBuildSizes(dir string, sizes *buildSizes) error {
+	path := filepath.Join(dir, buildSizesPath)
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+
+	data, err := json.MarshalIndent(sizes, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode component sizes: %w", err)
+	}
+	if err := os.WriteFile(path, append(data, '\n'), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
@@ -0,0 +1,108 @@
+package commands
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+func TestParseSize(t *testing.T) {
+	tests := []struct {
+		size string
+		want int64
+	}{
+		{"2000", 2000},
+		{"512B", 512},
+		{"1.5MB", 1500000},
+		{"800 KiB", 819200},
+		{"2GiB", 2 << 30},
+	}
+	for _, tt := range tests {
+		got, err := parseSize(tt.size)
+		require.NoError(t, err, tt.size)
+		assert.Equal(t, tt.want, got, tt.size)
+	}
+
+	_, err := parseSize("2 megabytes")
+	assert.EqualError(t, err, `invalid size "2 megabytes": expected bytes or a size such as 1.5MB or 800KiB`)
+}
+
+// writeComponents writes components of the given sizes to dir
+func writeComponents(t *testing.T, dir string, sizes map[string]int) map[string]string {
+	t.Helper()
+	sources := map[string]string{}
+	for id, size := range sizes {
+		sources[id] = filepath.Join(dir, id+".wasm")
+		require.NoError(t, os.WriteFile(sources[id], make([]byte, size), 0600))
+	}
+	return sources
+}
+
+func TestCheckBuildSizes_Budgets(t *testing.T) {
+	dir := t.TempDir()
+	sources := writeComponents(t, dir, map[string]int{"weather": 3000, "calc": 1500})
+	app := &validation.Application{Components: []*validation.Component{
+		{ID: "weather", SizeBudget: &validation.SizeBudget{Max: "2KB"}},
+		{ID: "calc", SizeBudget: &validation.SizeBudget{Max: "1KB", OnExceed: validation.SizeBudgetWarn}},
+	}}
+
+	var events []Event
+	_, err := checkBuildSizes(dir, app, sources, recordProgress(&events))
+	assert.EqualError(t, err, "size budget exceeded:\n  component weather is 2.9 KiB, 1000 B over its size budget of 2.0 KiB")
+	var budgetErr *SizeBudgetError
+	require.True(t, errors.As(err, &budgetErr))
+	assert.Len(t, budgetErr.Sizes, 2)
+	require.Len(t, events, 1)
+	assert.Equal(t, LevelWarn, events[0].Level)
+	assert.Contains(t, events[0].Message, "component calc is 1.5 KiB")
+
+	// Warnings only, and sizes within budgets pass
+	app.Components[0].SizeBudget.Max = "3000"
+	sizes, err := checkBuildSizes(dir, app, sources, nil)
+	require.NoError(t, err)
+	assert.Equal(t, ComponentSize{
+		ID: "weather", Path: sources["weather"], Size: 3000,
+		Previous: 3000, HasPrevious: true, Budget: 3000,
+	}, sizes[1])
+
+	app.Components[0].SizeBudget.Max = "huge"
+	_, err = checkBuildSizes(dir, app, sources, nil)
+	assert.ErrorContains(t, err, `size budget of component weather: invalid size "huge"`)
+
+	// Missing components only fail when they have a budget
+	gone := map[string]string{"gone": filepath.Join(t.TempDir(), "gone.wasm")}
+	_, err = checkBuildSizes(dir, nil, gone, nil)
+	assert.NoError(t, err)
+	app.Components = append(app.Components, &validation.Component{ID: "gone", SizeBudget: &validation.SizeBudget{Max: "1MB"}})
+	_, err = checkBuildSizes(dir, app, gone, nil)
+	assert.ErrorContains(t, err, "built component gone not found")
+}
+
+func TestCheckBuildSizes_Records(t *testing.T) {
+	dir := t.TempDir()
+	sources := writeComponents(t, dir, map[string]int{"weather": 2048})
+
+	sizes, err := checkBuildSizes(dir, nil, sources, nil)
+	require.NoError(t, err)
+	require.Len(t, sizes, 1)
+	assert.False(t, sizes[0].HasPrevious)
+
+	// The next build knows the previous size
+	require.NoError(t, os.WriteFile(sources["weather"], make([]byte, 2050), 0600))
+	sizes, err = checkBuildSizes(dir, nil, sources, nil)
+	require.NoError(t, err)
+	assert.Equal(t, int64(2048), sizes[0].Previous)
+	assert.True(t, sizes[0].HasPrevious)
+
+	// Sizes of components not rebuilt are kept
+	_, err = checkBuildSizes(dir, nil, map[string]string{}, nil)
+	require.NoError(t, err)
+	assert.Equal(t, map[string]int64{"weather": 2050}, readBuildSizes(dir))
+	assert.FileExists(t, filepath.Join(dir, ".ftl", "build-sizes.json"))
+}
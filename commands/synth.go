@@ -0,0 +1,176 @@
+package commands
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"cuelang.org/go/cue"
+
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/synthesis"
+	"github.com/fastertools/ftl/validation"
+)
+
+// DataConfigFiles are the ConfigFiles declaring the application as data, in
+// priority order. Commands reading the project's settings look them up, as the
+// other formats only declare it once run.
+var DataConfigFiles = []string{
+	"ftl.yaml",
+	"ftl.yml",
+	"ftl.json",
+}
+
+// ConfigFiles are the FTL config file names looked up in a project, in
+// priority order
+var ConfigFiles = slices.Concat(DataConfigFiles, []string{
+	"main.go",
+	"platform.go",
+	"ftl.cue",
+	"app.cue",
+})
+
+// ErrNoConfig is returned when a project has none of the ConfigFiles
+var ErrNoConfig = errors.New("no FTL configuration file found")
+
+// FindConfig returns the path of the FTL config file in dir
+func FindConfig(dir string) (string, error) {
+	if path := findFile(dir, ConfigFiles); path != "" {
+		return path, nil
+	}
+	return "", fmt.Errorf("%w in %s, looked for %v", ErrNoConfig, dir, ConfigFiles)
+}
+
+// FindDataConfig returns the path of the first of DataConfigFiles in dir, or
+// "" when the project has none
+func FindDataConfig(dir string) string {
+	return findFile(dir, DataConfigFiles)
+}
+
+// findFile returns the path of the first of names that is a file in dir
+func findFile(dir string, names []string) string {
+	for _, name := range names {
+		path := filepath.Join(dir, name)
+		if info, err := os.Stat(path); err == nil && !info.IsDir() {
+			return path
+		}
+	}
+	return ""
+}
+
+// LoadApplication loads and validates the application declared in an FTL
+// config. Only YAML and JSON configs are inspected, other formats return nil
+// without error.
+func LoadApplication(configFile string) (*validation.Application, error) {
+	configFile = filepath.Clean(configFile)
+
+	v := validation.New()
+	var validate func([]byte) (cue.Value, error)
+	switch strings.ToLower(filepath.Ext(configFile)) {
+	case ".yaml", ".yml":
+		validate = v.ValidateYAML
+	case ".json":
+		validate = v.ValidateJSON
+	default:
+		return nil, nil
+	}
+
+	data, err := os.ReadFile(configFile)
+	if err != nil {
+		return nil, err
+	}
+
+	validatedValue, err := validate(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to validate manifest: %w", err)
+	}
+
+	return validation.ExtractApplication(validatedValue)
+}
+
+// SynthOptions configure Synth
+type SynthOptions struct {
+	// Dir is the project directory, the current directory when empty
+	Dir string
+	// ConfigFile is the FTL config, relative to Dir. It is looked up with
+	// FindConfig when empty.
+	ConfigFile string
+	// Mirror resolves registry components from an offline mirror, when set
+	Mirror *oci.Mirror
+	// Progress receives the progress of the synthesis
+	Progress Progress
+}
+
+// Synth synthesizes the Spin manifest of a project and returns it. Local
+// component paths are written relative to the project directory, where
+// spin.toml lives.
+func Synth(opts SynthOptions, deps Deps) (string, error) {
+	dir := projectDir(opts.Dir)
+	configFile, err := configPath(dir, opts.ConfigFile)
+	if err != nil {
+		return "", err
+	}
+	return synth(dir, configFile, opts.Mirror, opts.Progress, deps)
+}
+
+// synth synthesizes the Spin manifest of the project in dir from configFile
+func synth(dir, configFile string, mirror *oci.Mirror, progress Progress, deps Deps) (string, error) {
+	progress.info(StageSynth, "Synthesizing spin.toml from %s", configFile)
+	manifest, err := deps.synthesize(configFile, dir)
+	if err != nil {
+		return "", fmt.Errorf("synthesis failed: %w", err)
+	}
+	if mirror != nil {
+		if manifest, err = ResolveOfflineSources(manifest, mirror); err != nil {
+			return "", err
+		}
+	}
+	return manifest, nil
+}
+
+// ResolveOfflineSources points the registry components of a synthesized
+// manifest at their copies in an offline mirror
+func ResolveOfflineSources(manifest string, mirror *oci.Mirror) (string, error) {
+	return synthesis.ResolveRegistrySources(manifest, func(registry, packageName, version string) (string, error) {
+		path, err := mirror.Resolve(registry, packageName, version)
+		if errors.Is(err, oci.ErrNotMirrored) {
+			return "", fmt.Errorf("%w. Add it to your refs file and run 'ftl registry mirror' with network access", err)
+		}
+		if err != nil {
+			return "", err
+		}
+		// The manifest may be written to another directory
+		return filepath.Abs(path)
+	})
+}
+
+// projectDir returns the project directory, defaulting to the current one
+func projectDir(dir string) string {
+	if dir == "" {
+		return "."
+	}
+	return filepath.Clean(dir)
+}
+
+// configPath returns the path of the config file of the project in dir
+func configPath(dir, configFile string) (string, error) {
+	if configFile == "" {
+		return FindConfig(dir)
+	}
+	configFile = projectPath(dir, configFile)
+	if _, err := os.Stat(configFile); err != nil {
+		return "", fmt.Errorf("config file not found: %w", err)
+	}
+	return configFile, nil
+}
+
+// projectPath resolves a path relative to dir
+func projectPath(dir, path string) string {
+	if filepath.IsAbs(path) {
+		return path
+	}
+	return filepath.Join(dir, path)
+}
@@ -0,0 +1,119 @@
+package commands
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/oci"
+)
+
+const testConfig = `name: test-app
+access: private
+components:
+  - id: echo
+    source: ./echo/echo.wasm
+    build:
+      command: cargo build
+      workdir: echo
+`
+
+// writeProject creates a project directory holding the named files
+func writeProject(t *testing.T, files map[string]string) string {
+	t.Helper()
+	dir := t.TempDir()
+	for name, content := range files {
+		path := filepath.Join(dir, name)
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0750))
+		require.NoError(t, os.WriteFile(path, []byte(content), 0600))
+	}
+	return dir
+}
+
+// recordProgress returns a callback collecting the reported events
+func recordProgress(events *[]Event) Progress {
+	return func(e Event) {
+		*events = append(*events, e)
+	}
+}
+
+func TestFindConfig(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.json": "{}", "ftl.yaml": testConfig, "app.cue": ""})
+	path, err := FindConfig(dir)
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(dir, "ftl.yaml"), path)
+
+	_, err = FindConfig(t.TempDir())
+	assert.ErrorIs(t, err, ErrNoConfig)
+}
+
+func TestFindDataConfig(t *testing.T) {
+	dir := writeProject(t, map[string]string{"main.go": "package main\n", "ftl.json": "{}"})
+	assert.Equal(t, filepath.Join(dir, "ftl.json"), FindDataConfig(dir))
+
+	// Go and CUE configs only declare the application once run
+	dir = writeProject(t, map[string]string{"main.go": "package main\n", "app.cue": ""})
+	assert.Empty(t, FindDataConfig(dir))
+}
+
+func TestLoadApplication(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+	app, err := LoadApplication(filepath.Join(dir, "ftl.yaml"))
+	require.NoError(t, err)
+	assert.Equal(t, "test-app", app.Name)
+	require.Len(t, app.Components, 1)
+	assert.Equal(t, "echo", app.Components[0].ID)
+
+	// Non YAML/JSON configs are not inspected
+	app, err = LoadApplication(filepath.Join(dir, "app.cue"))
+	assert.NoError(t, err)
+	assert.Nil(t, app)
+}
+
+func TestSynth(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+
+	var events []Event
+	manifest, err := Synth(SynthOptions{Dir: dir, Progress: recordProgress(&events)}, Deps{})
+	require.NoError(t, err)
+	assert.Contains(t, manifest, "spin_manifest_version")
+	assert.Contains(t, manifest, `source = "echo/echo.wasm"`)
+
+	require.Len(t, events, 1)
+	assert.Equal(t, StageSynth, events[0].Stage)
+	assert.Equal(t, LevelInfo, events[0].Level)
+	assert.Contains(t, events[0].Message, "ftl.yaml")
+}
+
+func TestSynth_Offline(t *testing.T) {
+	dir := writeProject(t, map[string]string{"ftl.yaml": testConfig})
+
+	// The gateway is a registry component, which the empty mirror lacks
+	_, err := Synth(SynthOptions{Dir: dir, Mirror: oci.NewMirror(t.TempDir())}, Deps{})
+	assert.ErrorIs(t, err, oci.ErrNotMirrored)
+}
+
+func TestResolveOfflineSources_NotMirrored(t *testing.T) {
+	manifest := `spin_manifest_version = 2
+
+[application]
+name = "app"
+
+[component.mcp]
+source = { registry = "ghcr.io", package = "fastertools:mcp-gateway", version = "0.0.13" }
+`
+	_, err := ResolveOfflineSources(manifest, oci.NewMirror(t.TempDir()))
+	assert.ErrorIs(t, err, oci.ErrNotMirrored)
+	assert.ErrorContains(t, err, "ftl registry mirror")
+}
+
+func TestSynth_MissingConfig(t *testing.T) {
+	_, err := Synth(SynthOptions{Dir: t.TempDir(), ConfigFile: "ftl.yaml"}, Deps{})
+	assert.ErrorContains(t, err, "config file not found")
+
+	_, err = Synth(SynthOptions{Dir: t.TempDir()}, Deps{})
+	assert.ErrorIs(t, err, ErrNoConfig)
+}
@@ -0,0 +1,95 @@
+package commands
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/BurntSushi/toml"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// DefaultRegistry is the registry components are published to when none is
+// given
+const DefaultRegistry = "ghcr.io"
+
+// ComponentWorkdir returns the directory a component is built in
+func ComponentWorkdir(comp *validation.Component, configDir string) string {
+	if comp.Build != nil && comp.Build.Workdir != "" {
+		return filepath.Join(configDir, comp.Build.Workdir)
+	}
+	return configDir
+}
+
+// PublishVersion resolves the version a local component is published with:
+// the version file of its language, else the project version, else
+// DefaultVersion. It returns the version with its version file, or "" for
+// the project version.
+func PublishVersion(comp *validation.Component, configDir, projectVersion string) (string, string) {
+	if version, file := ComponentVersionFile(ComponentWorkdir(comp, configDir)); version != "" {
+		return version, file
+	}
+	if projectVersion != "" {
+		return projectVersion, ""
+	}
+	return DefaultVersion, ""
+}
+
+// ComponentVersion reads a component's version from the version file of its
+// language in dir, or returns "" when there is none
+func ComponentVersion(dir string) string {
+	version, _ := ComponentVersionFile(dir)
+	return version
+}
+
+// ComponentVersionFile reads a component's version from the version file of
+// its language in dir, and returns it with the path of the file
+func ComponentVersionFile(dir string) (string, string) {
+	if data, err := os.ReadFile(filepath.Join(dir, "VERSION")); err == nil {
+		if version := strings.TrimSpace(string(data)); version != "" {
+			return version, filepath.Join(dir, "VERSION")
+		}
+	}
+
+	var cargo struct {
+		Package map[string]interface{} `toml:"package"`
+	}
+	if _, err := toml.DecodeFile(filepath.Join(dir, "Cargo.toml"), &cargo); err == nil {
+		// Workspace-inherited versions (version.workspace = true) aren't strings
+		if version, ok := cargo.Package["version"].(string); ok && version != "" {
+			return version, filepath.Join(dir, "Cargo.toml")
+		}
+	}
+
+	if data, err := os.ReadFile(filepath.Join(dir, "package.json")); err == nil {
+		var pkg struct {
+			Version string `json:"version"`
+		}
+		if json.Unmarshal(data, &pkg) == nil && pkg.Version != "" {
+			return pkg.Version, filepath.Join(dir, "package.json")
+		}
+	}
+
+	var pyproject struct {
+		Project struct {
+			Version string `toml:"version"`
+		} `toml:"project"`
+		Tool struct {
+			Poetry struct {
+				Version string `toml:"version"`
+			} `toml:"poetry"`
+		} `toml:"tool"`
+	}
+	if _, err := toml.DecodeFile(filepath.Join(dir, "pyproject.toml"), &pyproject); err == nil {
+		if pyproject.Project.Version != "" {
+			return pyproject.Project.Version, filepath.Join(dir, "pyproject.toml")
+		}
+		if pyproject.Tool.Poetry.Version != "" {
+			return pyproject.Tool.Poetry.Version, filepath.Join(dir, "pyproject.toml")
+		}
+	}
+
+	return "", ""
+}
@@ -0,0 +1,30 @@
+package commands
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestComponentVersion(t *testing.T) {
+	dir := t.TempDir()
+	assert.Equal(t, "", ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "pyproject.toml"),
+		[]byte("[project]\nname = \"tool\"\nversion = \"0.4.0\"\n"), 0600))
+	assert.Equal(t, "0.4.0", ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "package.json"), []byte(`{"version": "2.0.0"}`), 0600))
+	assert.Equal(t, "2.0.0", ComponentVersion(dir))
+
+	// Workspace-inherited Cargo versions fall through to the next file
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "Cargo.toml"),
+		[]byte("[package]\nname = \"tool\"\nversion.workspace = true\n"), 0600))
+	assert.Equal(t, "2.0.0", ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "VERSION"), []byte("3.1.4\n"), 0600))
+	assert.Equal(t, "3.1.4", ComponentVersion(dir))
+}
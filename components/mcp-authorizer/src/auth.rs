@@ -21,9 +21,11 @@ pub struct Context {
     /// Raw bearer token (for forwarding if needed)
     pub raw_token: String,
 
-    /// Additional claims from the token (for generic authorization and forwarding)
-    #[allow(dead_code)] // Will be used for claim forwarding in future
+    /// All verified claims from the token (for projection into headers)
     pub additional_claims: std::collections::HashMap<String, serde_json::Value>,
+
+    /// ID of the policy decision that allowed the request, when decisions are identified
+    pub policy_decision_id: Option<String>,
 }
 
 /// Extract bearer token from request
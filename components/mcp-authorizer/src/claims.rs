@@ -0,0 +1,308 @@
+//! Projection of verified token claims into `x-ftl-*` headers
+//!
+//! Tools often need a few claims (an organization ID, an email) without
+//! receiving the whole token. The `mcp_claim_headers` variable maps claim
+//! paths to header names, and only those claims are forwarded.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::hash::BuildHasher;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Prefix every projected header name must start with
+pub const HEADER_PREFIX: &str = "x-ftl-";
+
+/// Maximum length of a single projected header value, after escaping
+pub const MAX_VALUE_LEN: usize = 1024;
+
+/// Maximum combined length of all projected header values
+pub const MAX_TOTAL_LEN: usize = 8192;
+
+/// A claim forwarded in a header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimHeader {
+    /// Claim path, with `.` separating the keys of nested objects
+    pub claim: String,
+    /// Lowercase header name, starting with `x-ftl-`
+    pub header: String,
+}
+
+/// Parse the `mcp_claim_headers` variable, a JSON object mapping claim paths
+/// to header names: `{"org_id": "x-ftl-org-id", "org.name": "x-ftl-org-name"}`
+pub fn parse(json: &str) -> Result<Vec<ClaimHeader>> {
+    let map: serde_json::Map<String, Value> = serde_json::from_str(json).map_err(|e| {
+        anyhow::anyhow!(
+            "mcp_claim_headers must be a JSON object of claim paths to header names: {e}"
+        )
+    })?;
+
+    let mut seen = HashSet::new();
+    let mut mappings = Vec::with_capacity(map.len());
+    for (claim, header) in map {
+        if claim.is_empty() || claim.split('.').any(str::is_empty) {
+            return Err(anyhow::anyhow!(
+                "mcp_claim_headers has an invalid claim path: '{claim}'"
+            ));
+        }
+
+        let header = header
+            .as_str()
+            .ok_or_else(|| {
+                anyhow::anyhow!("mcp_claim_headers: header for claim '{claim}' must be a string")
+            })?
+            .trim()
+            .to_ascii_lowercase();
+        if !is_valid_header_name(&header) {
+            return Err(anyhow::anyhow!(
+                "mcp_claim_headers: header '{header}' must start with {HEADER_PREFIX} and contain only letters, digits and hyphens"
+            ));
+        }
+        if !seen.insert(header.clone()) {
+            return Err(anyhow::anyhow!(
+                "mcp_claim_headers: header '{header}' is used for more than one claim"
+            ));
+        }
+
+        mappings.push(ClaimHeader { claim, header });
+    }
+
+    Ok(mappings)
+}
+
+/// Whether a header name is a lowercase `x-ftl-*` token
+fn is_valid_header_name(name: &str) -> bool {
+    name.strip_prefix(HEADER_PREFIX).is_some_and(|rest| {
+        !rest.is_empty()
+            && rest
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    })
+}
+
+/// Whether a request header is reserved for projected claims, so
+/// client-supplied values must be dropped
+pub fn is_projected_header(name: &str) -> bool {
+    name.get(..HEADER_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(HEADER_PREFIX))
+}
+
+/// Build the headers for the configured claims.
+///
+/// Missing and null claims are skipped, as are values over [`MAX_VALUE_LEN`]
+/// and any headers past [`MAX_TOTAL_LEN`], so a large claim is never forwarded
+/// truncated.
+pub fn project<S: BuildHasher>(
+    mappings: &[ClaimHeader],
+    claims: &HashMap<String, Value, S>,
+) -> Vec<(String, String)> {
+    let mut headers = Vec::new();
+    let mut total = 0;
+
+    for mapping in mappings {
+        let Some(value) = lookup(claims, &mapping.claim).and_then(header_value) else {
+            continue;
+        };
+        if value.len() > MAX_VALUE_LEN {
+            log::warn!(
+                "Claim '{}' is too large for header {} ({} bytes)",
+                mapping.claim,
+                mapping.header,
+                value.len()
+            );
+            continue;
+        }
+        if total + value.len() > MAX_TOTAL_LEN {
+            log::warn!(
+                "Claim headers exceed {MAX_TOTAL_LEN} bytes, skipping {}",
+                mapping.header
+            );
+            continue;
+        }
+
+        total += value.len();
+        headers.push((mapping.header.clone(), value));
+    }
+
+    headers
+}
+
+/// Find a claim by path. A claim named with dots (such as a namespaced URL
+/// claim) takes precedence over a nested lookup.
+fn lookup<'a, S: BuildHasher>(
+    claims: &'a HashMap<String, Value, S>,
+    path: &str,
+) -> Option<&'a Value> {
+    if let Some(value) = claims.get(path) {
+        return Some(value);
+    }
+
+    let mut segments = path.split('.');
+    let mut value = claims.get(segments.next()?)?;
+    for segment in segments {
+        value = value.as_object()?.get(segment)?;
+    }
+    Some(value)
+}
+
+/// Format a claim as a header value: strings and scalars as text, arrays of
+/// scalars comma-separated and objects as compact JSON. Non-printable and
+/// non-ASCII characters, `%` and (within arrays) `,` are percent-encoded.
+fn header_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(escape(s, false)),
+        Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        Value::Array(items)
+            if items
+                .iter()
+                .all(|item| !item.is_array() && !item.is_object()) =>
+        {
+            let items: Vec<String> = items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::Null => None,
+                    Value::String(s) => Some(escape(s, true)),
+                    other => Some(other.to_string()),
+                })
+                .collect();
+            Some(items.join(","))
+        }
+        Value::Array(_) | Value::Object(_) => Some(escape(&value.to_string(), false)),
+    }
+}
+
+/// Percent-encode the bytes of `s` that can't appear in a header value
+fn escape(s: &str, in_list: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for &b in s.as_bytes() {
+        if (b' '..=b'~').contains(&b) && b != b'%' && !(in_list && b == b',') {
+            escaped.push(char::from(b));
+        } else {
+            let _ = write!(escaped, "%{b:02X}");
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn claims() -> HashMap<String, Value> {
+        HashMap::from([
+            ("sub".to_string(), json!("user_123")),
+            ("org_id".to_string(), json!("org_42")),
+            ("email".to_string(), json!("José <jose@example.com>")),
+            ("roles".to_string(), json!(["admin", "a,b", null])),
+            ("org".to_string(), json!({"name": "Acme", "tier": 2})),
+            ("https://example.com/plan".to_string(), json!("pro")),
+            ("verified".to_string(), json!(true)),
+            ("nothing".to_string(), Value::Null),
+        ])
+    }
+
+    fn header(claim: &str, header: &str) -> ClaimHeader {
+        ClaimHeader {
+            claim: claim.to_string(),
+            header: header.to_string(),
+        }
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_parse() {
+        let mappings = parse(r#"{"org_id": "X-FTL-Org-Id", "org.name": " x-ftl-org-name "}"#)
+            .expect("claim mappings should parse");
+        assert_eq!(
+            mappings,
+            vec![
+                header("org.name", "x-ftl-org-name"),
+                header("org_id", "x-ftl-org-id"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_mappings() {
+        for json in [
+            "[]",
+            r#"{"org_id": 1}"#,
+            r#"{"org_id": "x-org-id"}"#,
+            r#"{"org_id": "authorization"}"#,
+            r#"{"org_id": "x-ftl-"}"#,
+            r#"{"org_id": "x-ftl-org id"}"#,
+            r#"{"org..id": "x-ftl-org-id"}"#,
+            r#"{"org_id": "x-ftl-org", "org.id": "X-FTL-ORG"}"#,
+        ] {
+            assert!(parse(json).is_err(), "{json} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_project() {
+        let mappings = vec![
+            header("org_id", "x-ftl-org-id"),
+            header("email", "x-ftl-email"),
+            header("roles", "x-ftl-roles"),
+            header("org.name", "x-ftl-org-name"),
+            header("org", "x-ftl-org"),
+            header("https://example.com/plan", "x-ftl-plan"),
+            header("verified", "x-ftl-verified"),
+            header("nothing", "x-ftl-nothing"),
+            header("missing.claim", "x-ftl-missing"),
+        ];
+
+        let headers = project(&mappings, &claims());
+        let headers: HashMap<_, _> = headers.into_iter().collect();
+        let get = |name: &str| headers.get(name).map(String::as_str);
+        assert_eq!(get("x-ftl-org-id"), Some("org_42"));
+        assert_eq!(get("x-ftl-email"), Some("Jos%C3%A9 <jose@example.com>"));
+        assert_eq!(get("x-ftl-roles"), Some("admin,a%2Cb"));
+        assert_eq!(get("x-ftl-org-name"), Some("Acme"));
+        assert_eq!(get("x-ftl-org"), Some(r#"{"name":"Acme","tier":2}"#));
+        assert_eq!(get("x-ftl-plan"), Some("pro"));
+        assert_eq!(get("x-ftl-verified"), Some("true"));
+        assert_eq!(get("x-ftl-nothing"), None);
+        assert_eq!(get("x-ftl-missing"), None);
+    }
+
+    #[test]
+    fn test_project_size_limits() {
+        let claims = HashMap::from([
+            ("big".to_string(), json!("a".repeat(MAX_VALUE_LEN + 1))),
+            ("fits".to_string(), json!("b".repeat(MAX_VALUE_LEN))),
+            ("newline".to_string(), json!("a\r\nx-ftl-admin: true")),
+        ]);
+        let mappings = vec![
+            header("big", "x-ftl-big"),
+            header("newline", "x-ftl-newline"),
+        ];
+        let headers = project(&mappings, &claims);
+        assert_eq!(
+            headers,
+            vec![(
+                "x-ftl-newline".to_string(),
+                "a%0D%0Ax-ftl-admin: true".to_string()
+            )]
+        );
+
+        // Headers past the total limit are skipped
+        let mappings: Vec<ClaimHeader> = (0..=MAX_TOTAL_LEN / MAX_VALUE_LEN)
+            .map(|i| header("fits", &format!("x-ftl-fits-{i}")))
+            .collect();
+        let headers = project(&mappings, &claims);
+        assert_eq!(headers.len(), MAX_TOTAL_LEN / MAX_VALUE_LEN);
+    }
+
+    #[test]
+    fn test_is_projected_header() {
+        assert!(is_projected_header("x-ftl-org-id"));
+        assert!(is_projected_header("X-FTL-Org-Id"));
+        assert!(!is_projected_header("x-ft"));
+        assert!(!is_projected_header("x-auth-user-id"));
+    }
+}
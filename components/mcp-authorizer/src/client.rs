@@ -0,0 +1,365 @@
+//! Client metadata for policy evaluation
+//!
+//! Policies can't see the connection a request arrived on, so the authorizer
+//! adds the client IP, user agent and request time to `input.request`. Behind
+//! a load balancer or CDN, the client IP is read from the headers listed in
+//! `mcp_trusted_proxy_headers`. Only list headers that a proxy in front of the
+//! authorizer always sets, since clients can send any header themselves.
+//!
+//! Named CIDR lists from `mcp_policy_cidr_lists` are added to the policy data
+//! as `data.cidr_lists`, and `input.request.client_ip_lists` names the lists
+//! the client IP belongs to, so policies can restrict tools to known networks
+//! without parsing addresses in Rego.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use spin_sdk::http::{HeaderValue, Request};
+
+/// Header Spin sets to the address of the connected peer
+const CLIENT_ADDR_HEADER: &str = "spin-client-addr";
+
+/// An IPv4 or IPv6 network in CIDR notation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Whether the network contains `ip`. IPv4-mapped IPv6 addresses match
+    /// IPv4 networks.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            IpAddr::V4(_) => ip,
+        };
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                same_prefix(u32::from(net).into(), u32::from(ip).into(), 32, self.prefix)
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                same_prefix(u128::from(net), u128::from(ip), 128, self.prefix)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether two addresses of `bits` bits share their first `prefix` bits
+fn same_prefix(a: u128, b: u128, bits: u8, prefix: u8) -> bool {
+    let host_bits = u32::from(bits.saturating_sub(prefix));
+    a.checked_shr(host_bits).unwrap_or(0) == b.checked_shr(host_bits).unwrap_or(0)
+}
+
+impl FromStr for Cidr {
+    type Err = anyhow::Error;
+
+    /// Parse `10.0.0.0/8` or `2001:db8::/32`. A bare address is a single host.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (addr, prefix) = s
+            .split_once('/')
+            .map_or((s, None), |(addr, prefix)| (addr, Some(prefix)));
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid CIDR '{s}'"))?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            None => max,
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max)
+                .ok_or_else(|| anyhow::anyhow!("invalid prefix length in CIDR '{s}'"))?,
+        };
+        Ok(Self { addr, prefix })
+    }
+}
+
+impl TryFrom<String> for Cidr {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<Cidr> for String {
+    fn from(cidr: Cidr) -> Self {
+        cidr.to_string()
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+/// Parse the `mcp_policy_cidr_lists` variable, a JSON object mapping list
+/// names to networks: `{"corporate": ["10.0.0.0/8", "2001:db8::/32"]}`
+pub fn parse_cidr_lists(json: &str) -> Result<BTreeMap<String, Vec<Cidr>>> {
+    let lists: BTreeMap<String, Vec<String>> = serde_json::from_str(json).map_err(|e| {
+        anyhow::anyhow!(
+            "mcp_policy_cidr_lists must be a JSON object of list names to arrays of CIDRs: {e}"
+        )
+    })?;
+
+    lists
+        .into_iter()
+        .map(|(name, cidrs)| {
+            if name.trim().is_empty() {
+                return Err(anyhow::anyhow!(
+                    "mcp_policy_cidr_lists has a list without a name"
+                ));
+            }
+            let cidrs = cidrs
+                .iter()
+                .map(|cidr| {
+                    cidr.parse::<Cidr>()
+                        .map_err(|e| anyhow::anyhow!("mcp_policy_cidr_lists: list '{name}': {e}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok((name, cidrs))
+        })
+        .collect()
+}
+
+/// Parse the `mcp_trusted_proxy_headers` variable, a comma-separated list of
+/// header names checked in order: `cf-connecting-ip, x-forwarded-for`
+pub fn parse_proxy_headers(value: &str) -> Result<Vec<String>> {
+    value
+        .split(',')
+        .map(|header| header.trim().to_ascii_lowercase())
+        .filter(|header| !header.is_empty())
+        .map(|header| {
+            if header
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                Ok(header)
+            } else {
+                Err(anyhow::anyhow!(
+                    "mcp_trusted_proxy_headers has an invalid header name: '{header}'"
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Metadata about the client of a request, added to `input.request`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// Client IP, if one could be determined
+    pub ip: Option<IpAddr>,
+    /// Sorted names of the CIDR lists containing the client IP
+    pub ip_lists: Vec<String>,
+    /// `User-Agent` header
+    pub user_agent: Option<String>,
+    /// When the request was received
+    pub time: DateTime<Utc>,
+}
+
+impl ClientInfo {
+    /// Collect the client metadata of a request
+    pub fn from_request(
+        req: &Request,
+        proxy_headers: &[String],
+        cidr_lists: &BTreeMap<String, Vec<Cidr>>,
+    ) -> Self {
+        let ip = client_ip(
+            |name| req.header(name).and_then(HeaderValue::as_str),
+            proxy_headers,
+        );
+        let ip_lists = ip
+            .map(|ip| {
+                cidr_lists
+                    .iter()
+                    .filter(|(_, cidrs)| cidrs.iter().any(|cidr| cidr.contains(ip)))
+                    .map(|(name, _)| name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            ip,
+            ip_lists,
+            user_agent: req
+                .header("user-agent")
+                .and_then(HeaderValue::as_str)
+                .map(str::to_string),
+            time: Utc::now(),
+        }
+    }
+
+    /// Add the metadata to the `request` object of a policy input
+    pub fn extend(&self, request: &mut serde_json::Map<String, Value>) {
+        request.insert(
+            "client_ip".to_string(),
+            json!(self.ip.map(|ip| ip.to_string())),
+        );
+        request.insert("client_ip_lists".to_string(), json!(self.ip_lists));
+        request.insert("user_agent".to_string(), json!(self.user_agent));
+        request.insert(
+            "time".to_string(),
+            json!(self.time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        );
+        request.insert("timestamp".to_string(), json!(self.time.timestamp()));
+    }
+}
+
+/// Determine the client IP from the first trusted proxy header present,
+/// falling back to the connected peer.
+///
+/// A header holding a list (`X-Forwarded-For: client, proxy`) is read from
+/// the right, where the trusted proxy appended the address it saw, because
+/// the entries before it are whatever the client sent. A header with an
+/// invalid address is not skipped in favor of the next, so a client can't
+/// steer which header is used.
+fn client_ip<'a>(
+    header: impl Fn(&str) -> Option<&'a str>,
+    proxy_headers: &[String],
+) -> Option<IpAddr> {
+    if let Some(value) = proxy_headers.iter().find_map(|name| header(name)) {
+        return value.rsplit(',').next().and_then(parse_ip);
+    }
+    header(CLIENT_ADDR_HEADER).and_then(parse_ip)
+}
+
+/// Parse an address with or without a port: `203.0.113.7`, `203.0.113.7:443`,
+/// `2001:db8::1` or `[2001:db8::1]:443`
+fn parse_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim();
+    value
+        .parse::<IpAddr>()
+        .or_else(|_| value.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[allow(clippy::expect_used)]
+    fn cidr(s: &str) -> Cidr {
+        s.parse().expect("CIDR should parse")
+    }
+
+    #[allow(clippy::expect_used)]
+    fn ip(s: &str) -> IpAddr {
+        s.parse().expect("IP address should parse")
+    }
+
+    #[test]
+    fn test_cidr_contains() {
+        let corporate = cidr("10.20.0.0/16");
+        assert!(corporate.contains(ip("10.20.255.1")));
+        assert!(!corporate.contains(ip("10.21.0.1")));
+        assert!(corporate.contains(ip("::ffff:10.20.0.9")));
+        assert!(!corporate.contains(ip("2001:db8::1")));
+
+        let v6 = cidr("2001:db8::/32");
+        assert!(v6.contains(ip("2001:db8:ffff::1")));
+        assert!(!v6.contains(ip("2001:db9::1")));
+
+        assert!(cidr("0.0.0.0/0").contains(ip("198.51.100.1")));
+        assert!(cidr("::/0").contains(ip("2001:db8::1")));
+        assert!(cidr("198.51.100.7").contains(ip("198.51.100.7")));
+        assert!(!cidr("198.51.100.7").contains(ip("198.51.100.8")));
+        assert_eq!(cidr(" 198.51.100.7 ").to_string(), "198.51.100.7/32");
+    }
+
+    #[test]
+    fn test_cidr_invalid() {
+        assert!("10.0.0.0/33".parse::<Cidr>().is_err());
+        assert!("2001:db8::/129".parse::<Cidr>().is_err());
+        assert!("10.0.0/8".parse::<Cidr>().is_err());
+        assert!("corp".parse::<Cidr>().is_err());
+        assert!("10.0.0.0/".parse::<Cidr>().is_err());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_parse_cidr_lists() {
+        let lists =
+            parse_cidr_lists(r#"{"corporate": ["10.0.0.0/8", "2001:db8::/32"], "vpn": []}"#)
+                .expect("CIDR lists should parse");
+        assert_eq!(lists.len(), 2);
+        assert_eq!(
+            lists.get("corporate"),
+            Some(&vec![cidr("10.0.0.0/8"), cidr("2001:db8::/32")])
+        );
+
+        let err = parse_cidr_lists(r#"{"corporate": ["10.0.0.0/40"]}"#)
+            .err()
+            .map(|e| e.to_string());
+        assert!(err.is_some_and(|e| e.contains("list 'corporate'")));
+        assert!(parse_cidr_lists(r#"["10.0.0.0/8"]"#).is_err());
+        assert!(parse_cidr_lists(r#"{"": ["10.0.0.0/8"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_proxy_headers() {
+        assert_eq!(
+            parse_proxy_headers(" CF-Connecting-IP, x-forwarded-for ,").ok(),
+            Some(vec![
+                "cf-connecting-ip".to_string(),
+                "x-forwarded-for".to_string()
+            ])
+        );
+        assert_eq!(parse_proxy_headers("").ok(), Some(vec![]));
+        assert!(parse_proxy_headers("x forwarded for").is_err());
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let headers = HashMap::from([
+            ("x-forwarded-for", "198.51.100.1, 203.0.113.7"),
+            ("x-real-ip", "not an ip"),
+            ("spin-client-addr", "192.0.2.10:53124"),
+        ]);
+        let header = |name: &str| headers.get(name).copied();
+        let trusted = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| (*name).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // The right-most entry is the one added by the trusted proxy
+        assert_eq!(
+            client_ip(header, &trusted(&["x-forwarded-for"])),
+            Some(ip("203.0.113.7"))
+        );
+        // Headers are checked in order, missing ones are skipped
+        assert_eq!(
+            client_ip(header, &trusted(&["cf-connecting-ip", "x-forwarded-for"])),
+            Some(ip("203.0.113.7"))
+        );
+        // An invalid value doesn't fall through to the next header
+        assert_eq!(
+            client_ip(header, &trusted(&["x-real-ip", "x-forwarded-for"])),
+            None
+        );
+        // Without trusted headers, the connected peer is the client
+        assert_eq!(client_ip(header, &[]), Some(ip("192.0.2.10")));
+    }
+
+    #[test]
+    fn test_parse_ip() {
+        assert_eq!(parse_ip(" 203.0.113.7 "), Some(ip("203.0.113.7")));
+        assert_eq!(parse_ip("203.0.113.7:443"), Some(ip("203.0.113.7")));
+        assert_eq!(parse_ip("2001:db8::1"), Some(ip("2001:db8::1")));
+        assert_eq!(parse_ip("[2001:db8::1]:443"), Some(ip("2001:db8::1")));
+        assert_eq!(parse_ip("unknown"), None);
+    }
+}
@@ -1,9 +1,56 @@
 //! Configuration management for the MCP Authorizer
 
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use spin_sdk::variables;
 
+use crate::claims::{self, ClaimHeader};
+use crate::client::{self, Cidr};
+use crate::headers::ResponseHeaders;
+use crate::issuers::{self, ISSUER_PLACEHOLDER};
+use crate::pseudonym::Pseudonyms;
+
+/// Default TTL for cached discovery documents in seconds (1 hour)
+pub const DEFAULT_DISCOVERY_CACHE_TTL: u64 = 3600;
+
+/// Default interval for refreshing OIDC provider metadata in seconds (1 hour)
+pub const DEFAULT_OIDC_REFRESH_INTERVAL: u64 = 3600;
+
+/// Default lifetime of forwarded identity assertions in seconds
+pub const DEFAULT_IDENTITY_ASSERTION_TTL: u64 = 60;
+
+/// Minimum length of the identity assertion signing key in bytes (HS256)
+pub const MIN_IDENTITY_ASSERTION_KEY_LEN: usize = 32;
+
+/// Minimum length of the subject pseudonym salt in bytes
+pub const MIN_SUBJECT_PSEUDONYM_SALT_LEN: usize = 32;
+
+/// Default clock skew tolerated when checking token times, in seconds
+pub const DEFAULT_CLOCK_SKEW: u64 = 60;
+
+/// Default maximum lifetime of development tokens in seconds
+pub const DEFAULT_DEV_TOKEN_TTL: u64 = 900;
+
+/// Audience of development tokens when `mcp_jwt_audience` is not set
+pub const DEFAULT_DEV_TOKEN_AUDIENCE: &str = "ftl-dev";
+
+/// Paths of the gateway's health endpoints, passed without authentication
+pub const DEFAULT_HEALTH_PATHS: &str = "/healthz,/readyz";
+
+/// Default time in seconds a streamed gateway response may go without data
+pub const DEFAULT_STREAM_IDLE_TIMEOUT: u64 = 300;
+
+/// Longest time in seconds a verified token may be reused from the cache
+pub const MAX_TOKEN_CACHE_TTL: u64 = 300;
+
+/// Supported JWT signing algorithms
+pub const SUPPORTED_ALGORITHMS: [&str; 11] = [
+    "HS256", "HS384", "HS512", "RS256", "RS384", "RS512", "ES256", "ES384", "PS256", "PS384",
+    "PS512",
+];
+
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -13,11 +60,178 @@ pub struct Config {
     /// Header name for request tracing
     pub trace_header: String,
 
+    /// Which gateway response headers are passed through to clients
+    pub response_headers: ResponseHeaders,
+
+    /// Serve error responses as HTML pages to browsers
+    pub html_error_pages: bool,
+
+    /// Paths whose GET and HEAD requests reach the gateway unauthenticated
+    pub health_paths: Vec<String>,
+
+    /// Seconds a streamed gateway response may go without data before it is
+    /// closed (0 keeps the host's timeout)
+    pub stream_idle_timeout: u64,
+
     /// JWT provider configuration (optional - if not set, all requests pass through)
     pub provider: Option<Provider>,
 
     /// Policy-based authorization configuration
     pub authorization: Option<PolicyAuthorization>,
+
+    /// How policy authorization treats JSON bodies that are not JSON-RPC
+    pub malformed_body: MalformedBody,
+
+    /// Log the evaluation trace of policy denials
+    pub policy_debug: bool,
+
+    /// Response header echoing the ID of the policy decision
+    pub policy_decision_header: Option<String>,
+
+    /// Headers set by trusted proxies that hold the client IP, checked in order
+    pub trusted_proxy_headers: Vec<String>,
+
+    /// Named CIDR lists available to policies as `data.cidr_lists`
+    pub cidr_lists: BTreeMap<String, Vec<Cidr>>,
+
+    /// TTL in seconds for cached discovery documents (0 disables caching)
+    pub discovery_cache_ttl: u64,
+
+    /// Interval in seconds between refreshes of the issuer's OIDC metadata
+    pub oidc_refresh_interval: u64,
+
+    /// How the time claims of tokens (exp, nbf, iat) are validated
+    pub token_timing: TokenTiming,
+
+    /// TTL in seconds for cached token verifications (0 disables caching)
+    pub token_cache_ttl: u64,
+
+    /// Check verified tokens against the revocation deny-list
+    pub revocation_enabled: bool,
+
+    /// Scope required to manage the deny-list (the admin endpoint is disabled when unset)
+    pub revocation_admin_scope: Option<String>,
+
+    /// How the verified identity is forwarded to the gateway
+    pub identity_format: IdentityFormat,
+
+    /// Shared key for signing identity assertions (required when assertions are forwarded)
+    #[serde(skip_serializing)]
+    pub identity_assertion_key: Option<String>,
+
+    /// Lifetime in seconds of forwarded identity assertions
+    pub identity_assertion_ttl: u64,
+
+    /// Verified claims projected into `x-ftl-*` headers for downstream tools
+    pub claim_headers: Vec<ClaimHeader>,
+
+    /// Pseudonymization of token subjects (raw subjects are forwarded when unset)
+    pub subject_pseudonyms: Option<Pseudonyms>,
+
+    /// How the tenant of a request is resolved in multi-tenant deployments
+    pub tenant_resolution: TenantResolution,
+
+    /// Where per-tenant configuration is loaded from (single tenant when unset)
+    pub tenants: Option<TenantSource>,
+
+    /// ID of the tenant the request resolved to
+    pub tenant: Option<String>,
+
+    /// Path prefix of the resolved tenant, stripped before forwarding
+    pub path_prefix: String,
+
+    /// Development token settings (only when `mcp_dev_tokens` is enabled)
+    pub dev_tokens: Option<DevTokens>,
+}
+
+/// Tenant resolution strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TenantResolution {
+    /// Match the request host against each tenant's `hosts`
+    Host,
+    /// Use the first path segment as the tenant ID (`/{tenant}/mcp`)
+    Path,
+}
+
+impl std::str::FromStr for TenantResolution {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "host" => Ok(Self::Host),
+            "path" => Ok(Self::Path),
+            other => Err(anyhow::anyhow!(
+                "mcp_tenant_resolution must be either host or path: {other}"
+            )),
+        }
+    }
+}
+
+/// Source of per-tenant configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TenantSource {
+    /// JSON object from the `mcp_tenants` variable
+    Inline(String),
+    /// Key in the default key-value store holding the JSON object, so tenants
+    /// can be added without redeploying
+    KeyValue(String),
+}
+
+/// Settings of the development token endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevTokens {
+    /// Shared HS256 key development tokens are signed with
+    #[serde(skip_serializing, default)]
+    pub secret: String,
+
+    /// Audience of issued tokens
+    pub audience: String,
+
+    /// Scopes every token must carry
+    pub required_scopes: Option<Vec<String>>,
+
+    /// Maximum lifetime of issued tokens in seconds
+    pub max_ttl: u64,
+}
+
+/// Identity forwarding format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentityFormat {
+    /// Structured `x-auth-*` headers
+    Headers,
+    /// A short-lived signed JWT in the `x-auth-assertion` header
+    Assertion,
+    /// Both the headers and the signed assertion
+    Both,
+}
+
+impl IdentityFormat {
+    /// Whether the structured `x-auth-*` headers are forwarded
+    pub const fn forwards_headers(self) -> bool {
+        matches!(self, Self::Headers | Self::Both)
+    }
+
+    /// Whether a signed identity assertion is forwarded
+    pub const fn forwards_assertion(self) -> bool {
+        matches!(self, Self::Assertion | Self::Both)
+    }
+}
+
+impl std::str::FromStr for IdentityFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "headers" => Ok(Self::Headers),
+            "assertion" => Ok(Self::Assertion),
+            "both" => Ok(Self::Both),
+            other => Err(anyhow::anyhow!(
+                "mcp_identity_format must be one of headers, assertion or both: {other}"
+            )),
+        }
+    }
 }
 
 /// Provider type enumeration
@@ -41,6 +255,10 @@ pub struct JwtProvider {
     /// Static public key (PEM format)
     pub public_key: Option<String>,
 
+    /// Shared HS256 key, only set for development tokens
+    #[serde(skip_serializing, default)]
+    pub secret: Option<String>,
+
     /// Expected audience(s)
     pub audience: Option<Vec<String>>,
 
@@ -52,6 +270,35 @@ pub struct JwtProvider {
 
     /// OAuth 2.0 endpoints (optional)
     pub oauth_endpoints: Option<OAuthEndpoints>,
+
+    /// Bootstrap the JWKS URI and OAuth endpoints from the issuer's
+    /// `/.well-known/openid-configuration`
+    #[serde(default)]
+    pub oidc_discovery: bool,
+
+    /// Issuers accepted in addition to `issuer`, exactly or as
+    /// `https://*.<domain>` patterns
+    #[serde(default)]
+    pub issuers: Vec<String>,
+
+    /// How the keys of tokens from the additional issuers are located
+    #[serde(default)]
+    pub issuer_keys: IssuerKeys,
+}
+
+/// Key source of tokens from the issuers of `mcp_jwt_issuers`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssuerKeys {
+    /// The configured JWKS URI or public key, shared by all issuers
+    #[default]
+    Shared,
+    /// A JWKS URI with an `{issuer}` placeholder for the token issuer
+    Template(String),
+    /// The `jwks_uri` of each issuer's OIDC discovery document
+    Discovery,
+    /// The JWKS URI derived for issuers of known providers
+    Derived,
 }
 
 /// Policy-based authorization configuration
@@ -64,6 +311,119 @@ pub struct PolicyAuthorization {
     pub data: Option<String>,
 }
 
+/// Handling of JSON request bodies that are not valid JSON-RPC when a policy
+/// is configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MalformedBody {
+    /// Evaluate the policy without `input.mcp`
+    Pass,
+    /// Reject the request with `400 invalid_request`
+    Deny,
+}
+
+impl std::str::FromStr for MalformedBody {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "pass" => Ok(Self::Pass),
+            "deny" => Ok(Self::Deny),
+            other => Err(anyhow::anyhow!(
+                "mcp_policy_malformed_body must be either pass or deny: {other}"
+            )),
+        }
+    }
+}
+
+/// How the `nbf` (not before) claim of tokens is enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NbfPolicy {
+    /// Reject tokens used before their `nbf` when the claim is present
+    Enforce,
+    /// Additionally reject tokens without an `nbf` claim
+    Require,
+    /// Do not check `nbf`
+    Ignore,
+}
+
+impl std::str::FromStr for NbfPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "enforce" => Ok(Self::Enforce),
+            "require" => Ok(Self::Require),
+            "ignore" => Ok(Self::Ignore),
+            other => Err(anyhow::anyhow!(
+                "mcp_jwt_nbf must be one of enforce, require or ignore: {other}"
+            )),
+        }
+    }
+}
+
+/// Validation of the time claims of tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenTiming {
+    /// Clock skew in seconds tolerated when checking `exp`, `nbf` and `iat`
+    pub clock_skew: u64,
+
+    /// Maximum age in seconds of a token according to its `iat` claim,
+    /// regardless of `exp` (no limit when unset)
+    pub max_token_age: Option<u64>,
+
+    /// How the `nbf` claim is enforced
+    pub nbf: NbfPolicy,
+}
+
+impl Default for TokenTiming {
+    fn default() -> Self {
+        Self {
+            clock_skew: DEFAULT_CLOCK_SKEW,
+            max_token_age: None,
+            nbf: NbfPolicy::Enforce,
+        }
+    }
+}
+
+impl TokenTiming {
+    /// Load token time validation settings from Spin variables
+    fn load() -> Result<Self> {
+        let clock_skew = variables::get("mcp_jwt_clock_skew")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim().parse::<u64>().map_err(|_| {
+                    anyhow::anyhow!("mcp_jwt_clock_skew must be a number of seconds: {s}")
+                })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_CLOCK_SKEW);
+
+        let max_token_age = variables::get("mcp_jwt_max_token_age")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.trim().parse::<u64>() {
+                Ok(age) if age > 0 => Ok(age),
+                _ => Err(anyhow::anyhow!(
+                    "mcp_jwt_max_token_age must be a positive number of seconds: {s}"
+                )),
+            })
+            .transpose()?;
+
+        let nbf = variables::get("mcp_jwt_nbf")
+            .unwrap_or_default()
+            .parse::<NbfPolicy>()?;
+
+        Ok(Self {
+            clock_skew,
+            max_token_age,
+            nbf,
+        })
+    }
+}
+
 /// OAuth 2.0 endpoint configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OAuthEndpoints {
@@ -74,6 +434,7 @@ pub struct OAuthEndpoints {
 
 impl Config {
     /// Load configuration from Spin variables
+    #[allow(clippy::too_many_lines)] // Each setting is loaded and validated in turn
     pub fn load() -> Result<Self> {
         let gateway_url = variables::get("mcp_gateway_url")
             .unwrap_or_else(|_| "http://mcp-gateway.spin.internal".to_string());
@@ -82,6 +443,35 @@ impl Config {
             .unwrap_or_else(|_| "x-trace-id".to_string())
             .to_lowercase();
 
+        // Load gateway response header filtering (optional, all headers pass by default)
+        let response_headers = ResponseHeaders::parse(
+            &variables::get("mcp_response_headers_allow").unwrap_or_default(),
+            &variables::get("mcp_response_headers_deny").unwrap_or_default(),
+        )?;
+
+        // Load browser error pages setting (optional, enabled by default)
+        let html_error_pages = variables::get("mcp_html_error_pages")
+            .ok()
+            .is_none_or(|s| !matches!(s.trim().to_lowercase().as_str(), "false" | "0" | "no"));
+
+        // Load health check paths (optional, empty disables them)
+        let health_paths = parse_health_paths(
+            &variables::get("mcp_health_paths")
+                .unwrap_or_else(|_| DEFAULT_HEALTH_PATHS.to_string()),
+        )?;
+
+        // Load the idle timeout of streamed responses (optional, 5 minutes by default)
+        let stream_idle_timeout = variables::get("mcp_stream_idle_timeout")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim().parse::<u64>().map_err(|_| {
+                    anyhow::anyhow!("mcp_stream_idle_timeout must be a number of seconds: {s}")
+                })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_STREAM_IDLE_TIMEOUT);
+
         // Load provider configuration - propagate errors for invalid configs
         // but allow missing provider (returns None)
         let provider = match Provider::load() {
@@ -101,6 +491,10 @@ impl Config {
                     || variables::get("mcp_jwt_public_key")
                         .ok()
                         .filter(|s| !s.is_empty())
+                        .is_some()
+                    || variables::get("mcp_jwt_issuers")
+                        .ok()
+                        .filter(|s| !s.trim().is_empty())
                         .is_some();
 
                 if has_provider_config {
@@ -112,16 +506,231 @@ impl Config {
             }
         };
 
+        // Development tokens replace the provider entirely
+        let dev_tokens = DevTokens::load()?;
+        let provider = dev_tokens.as_ref().map_or(provider, |dev| {
+            log::warn!("Development tokens are enabled; do not use this mode in production");
+            Some(Provider::Jwt(crate::dev::provider(dev)))
+        });
+
         // Load policy authorization if configured
         let authorization = PolicyAuthorization::load().ok();
+        let malformed_body = variables::get("mcp_policy_malformed_body")
+            .unwrap_or_default()
+            .parse::<MalformedBody>()?;
+
+        // Load policy debugging settings (optional, disabled by default)
+        let policy_debug = variables::get("mcp_policy_debug")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"));
+        let policy_decision_header = variables::get("mcp_policy_decision_header")
+            .ok()
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty());
+
+        // Load client metadata settings (optional, the connected peer is the client by default)
+        let trusted_proxy_headers = variables::get("mcp_trusted_proxy_headers")
+            .ok()
+            .map(|s| client::parse_proxy_headers(&s))
+            .transpose()?
+            .unwrap_or_default();
+        let cidr_lists = variables::get("mcp_policy_cidr_lists")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| client::parse_cidr_lists(&s))
+            .transpose()?
+            .unwrap_or_default();
+
+        // Load discovery cache TTL (optional, defaults to 1 hour)
+        let discovery_cache_ttl = variables::get("mcp_discovery_cache_ttl")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim().parse::<u64>().map_err(|_| {
+                    anyhow::anyhow!("mcp_discovery_cache_ttl must be a number of seconds: {s}")
+                })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_DISCOVERY_CACHE_TTL);
+
+        // Load OIDC metadata refresh interval (optional, defaults to 1 hour)
+        let oidc_refresh_interval = variables::get("mcp_oidc_refresh_interval")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim().parse::<u64>().map_err(|_| {
+                    anyhow::anyhow!("mcp_oidc_refresh_interval must be a number of seconds: {s}")
+                })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_OIDC_REFRESH_INTERVAL);
+
+        // Load token time validation (60s clock skew, no maximum age by default)
+        let token_timing = TokenTiming::load()?;
+
+        // Load token verification cache TTL (optional, disabled by default
+        // and always disabled in strict mode)
+        let strict_token_verification = variables::get("mcp_strict_token_verification")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"));
+        let token_cache_ttl = variables::get("mcp_token_cache_ttl")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim().parse::<u64>().map_err(|_| {
+                    anyhow::anyhow!("mcp_token_cache_ttl must be a number of seconds: {s}")
+                })
+            })
+            .transpose()?
+            .unwrap_or(0);
+        if token_cache_ttl > MAX_TOKEN_CACHE_TTL {
+            return Err(anyhow::anyhow!(
+                "mcp_token_cache_ttl must be at most {MAX_TOKEN_CACHE_TTL} seconds: {token_cache_ttl}"
+            ));
+        }
+        let token_cache_ttl = if strict_token_verification {
+            0
+        } else {
+            token_cache_ttl
+        };
+
+        // Load revocation settings (optional, disabled by default)
+        let revocation_enabled = variables::get("mcp_revocation_enabled")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"));
+        let revocation_admin_scope = variables::get("mcp_revocation_admin_scope")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        // Load identity forwarding settings (optional, structured headers by default)
+        let identity_format = variables::get("mcp_identity_format")
+            .unwrap_or_default()
+            .parse::<IdentityFormat>()?;
+        let identity_assertion_key = variables::get("mcp_identity_assertion_key")
+            .ok()
+            .filter(|s| !s.is_empty());
+        if identity_format.forwards_assertion() {
+            match &identity_assertion_key {
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "mcp_identity_assertion_key is required when mcp_identity_format forwards assertions"
+                    ));
+                }
+                Some(key) if key.len() < MIN_IDENTITY_ASSERTION_KEY_LEN => {
+                    return Err(anyhow::anyhow!(
+                        "mcp_identity_assertion_key must be at least {MIN_IDENTITY_ASSERTION_KEY_LEN} bytes"
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        let identity_assertion_ttl = variables::get("mcp_identity_assertion_ttl")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.trim().parse::<u64>() {
+                Ok(ttl) if ttl > 0 => Ok(ttl),
+                _ => Err(anyhow::anyhow!(
+                    "mcp_identity_assertion_ttl must be a positive number of seconds: {s}"
+                )),
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_IDENTITY_ASSERTION_TTL);
+
+        // Load claim-to-header projection (optional, no claims by default)
+        let claim_headers = variables::get("mcp_claim_headers")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| claims::parse(&s))
+            .transpose()?
+            .unwrap_or_default();
+
+        // Load subject pseudonymization (optional, disabled by default)
+        let subject_pseudonyms = if variables::get("mcp_subject_pseudonyms")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"))
+        {
+            let salt = variables::get("mcp_subject_pseudonym_salt").unwrap_or_default();
+            if salt.len() < MIN_SUBJECT_PSEUDONYM_SALT_LEN {
+                return Err(anyhow::anyhow!(
+                    "mcp_subject_pseudonym_salt must be at least {MIN_SUBJECT_PSEUDONYM_SALT_LEN} bytes when mcp_subject_pseudonyms is enabled"
+                ));
+            }
+            Some(Pseudonyms::new(
+                salt,
+                &variables::get("mcp_subject_pseudonym_allow").unwrap_or_default(),
+            ))
+        } else {
+            None
+        };
+
+        // Load multi-tenant settings (optional, single tenant by default)
+        let tenant_resolution = variables::get("mcp_tenant_resolution")
+            .unwrap_or_default()
+            .parse::<TenantResolution>()?;
+        let tenants_json = variables::get("mcp_tenants")
+            .ok()
+            .filter(|s| !s.trim().is_empty());
+        let tenants_kv_key = variables::get("mcp_tenants_kv_key")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        let tenants = match (tenants_json, tenants_kv_key) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Cannot specify both mcp_tenants and mcp_tenants_kv_key"
+                ));
+            }
+            (Some(json), None) => Some(TenantSource::Inline(json)),
+            (None, Some(key)) => Some(TenantSource::KeyValue(key)),
+            (None, None) => None,
+        };
 
         Ok(Self {
             gateway_url,
             trace_header,
+            response_headers,
+            html_error_pages,
+            health_paths,
+            stream_idle_timeout,
             provider,
             authorization,
+            malformed_body,
+            policy_debug,
+            policy_decision_header,
+            trusted_proxy_headers,
+            cidr_lists,
+            discovery_cache_ttl,
+            oidc_refresh_interval,
+            token_timing,
+            token_cache_ttl,
+            revocation_enabled,
+            revocation_admin_scope,
+            identity_format,
+            identity_assertion_key,
+            identity_assertion_ttl,
+            claim_headers,
+            subject_pseudonyms,
+            tenant_resolution,
+            tenants,
+            tenant: None,
+            path_prefix: String::new(),
+            dev_tokens,
         })
     }
+
+    /// Path of a request relative to its tenant's path prefix, as seen by the
+    /// gateway
+    pub fn resource_path<'a>(&self, path: &'a str) -> &'a str {
+        if self.path_prefix.is_empty() {
+            return path;
+        }
+        match path.strip_prefix(self.path_prefix.as_str()) {
+            Some("") => "/",
+            Some(rest) => rest,
+            None => path,
+        }
+    }
 }
 
 impl Provider {
@@ -139,6 +748,10 @@ impl Provider {
             || variables::get("mcp_jwt_public_key")
                 .ok()
                 .filter(|s| !s.is_empty())
+                .is_some()
+            || variables::get("mcp_jwt_issuers")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
                 .is_some();
 
         // If no provider configuration at all, return error (no provider configured)
@@ -150,6 +763,7 @@ impl Provider {
     }
 
     /// Load JWT provider configuration
+    #[allow(clippy::too_many_lines)] // Each provider setting is loaded and validated in turn
     fn load_jwt_provider() -> Result<Self> {
         // Load issuer (optional - empty means no issuer validation)
         let issuer = variables::get("mcp_jwt_issuer")
@@ -164,37 +778,96 @@ impl Provider {
             .ok()
             .filter(|s| !s.is_empty());
 
-        // Load JWKS URI or auto-derive it (but only if no public key is set)
-        let jwks_uri = variables::get("mcp_jwt_jwks_uri")
+        // Load additional issuers (optional, exact or https://*.<domain>)
+        let issuers = variables::get("mcp_jwt_issuers")
             .ok()
-            .filter(|s| !s.is_empty())
-            .or_else(|| {
-                // Auto-derive JWKS URI for known providers only if:
-                // 1. Issuer is set
-                // 2. No public key is configured
-                if !issuer.is_empty() && public_key.is_none() {
-                    if issuer.contains(".authkit.app") || issuer.contains(".workos.com") {
-                        // WorkOS AuthKit uses /oauth2/jwks endpoint
-                        Some(format!("{issuer}/oauth2/jwks"))
-                    } else {
-                        None
-                    }
+            .map(|s| issuers::parse_patterns(&s))
+            .transpose()?
+            .unwrap_or_default();
+
+        // A JWKS URI with an {issuer} placeholder is resolved per token issuer
+        let configured_jwks_uri = variables::get("mcp_jwt_jwks_uri")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let jwks_template = configured_jwks_uri
+            .clone()
+            .filter(|uri| uri.contains(ISSUER_PLACEHOLDER));
+        if jwks_template.is_some() && issuers.is_empty() {
+            return Err(anyhow::anyhow!(
+                "mcp_jwt_jwks_uri can only contain {ISSUER_PLACEHOLDER} when mcp_jwt_issuers is set"
+            ));
+        }
+
+        // Load JWKS URI or auto-derive it (but only if no public key is set)
+        let jwks_uri = match &jwks_template {
+            Some(template) if !issuer.is_empty() => {
+                Some(template.replace(ISSUER_PLACEHOLDER, &issuer))
+            }
+            Some(_) => None,
+            None => configured_jwks_uri.clone().or_else(|| {
+                // Auto-derive JWKS URI for known providers only if no public
+                // key is configured
+                if public_key.is_none() {
+                    derive_jwks_uri(&issuer)
                 } else {
                     None
                 }
-            })
-            .map(|uri| normalize_url(&uri))
-            .transpose()?;
+            }),
+        }
+        .map(|uri| normalize_url(&uri))
+        .transpose()?;
+
+        // Load OIDC discovery flag (optional, defaults to false)
+        let oidc_discovery = variables::get("mcp_jwt_oidc_discovery")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"));
+
+        // Discovery needs an HTTPS issuer to locate the metadata document
+        if oidc_discovery
+            && !issuer.starts_with("https://")
+            && (!issuer.is_empty() || issuers.is_empty())
+        {
+            return Err(anyhow::anyhow!(
+                "mcp_jwt_oidc_discovery requires mcp_jwt_issuer to be an HTTPS URL"
+            ));
+        }
 
-        // Validate we have at least one key source
-        if jwks_uri.is_none() && public_key.is_none() {
+        // Locate the keys of the additional issuers
+        let issuer_keys = if issuers.is_empty() {
+            IssuerKeys::Shared
+        } else if let Some(template) = jwks_template {
+            IssuerKeys::Template(template)
+        } else if configured_jwks_uri.is_some() || public_key.is_some() {
+            IssuerKeys::Shared
+        } else if oidc_discovery {
+            if let Some(pattern) = issuers.iter().find(|p| !p.starts_with("https://")) {
+                return Err(anyhow::anyhow!(
+                    "mcp_jwt_oidc_discovery requires HTTPS issuers in mcp_jwt_issuers: {pattern}"
+                ));
+            }
+            IssuerKeys::Discovery
+        } else if issuers.iter().all(|p| derive_jwks_uri(p).is_some()) {
+            IssuerKeys::Derived
+        } else {
+            return Err(anyhow::anyhow!(
+                "mcp_jwt_issuers requires mcp_jwt_jwks_uri, mcp_jwt_public_key or mcp_jwt_oidc_discovery"
+            ));
+        };
+
+        // Validate we have at least one key source (discovery provides the
+        // JWKS URI, and tokens from the additional issuers get theirs per issuer)
+        if jwks_uri.is_none()
+            && public_key.is_none()
+            && !oidc_discovery
+            && (!issuer.is_empty() || issuers.is_empty())
+        {
             return Err(anyhow::anyhow!(
                 "Either mcp_jwt_jwks_uri or mcp_jwt_public_key must be provided"
             ));
         }
 
         // Validate we don't have both
-        if jwks_uri.is_some() && public_key.is_some() {
+        if configured_jwks_uri.is_some() && public_key.is_some() {
             return Err(anyhow::anyhow!(
                 "Cannot specify both mcp_jwt_jwks_uri and mcp_jwt_public_key"
             ));
@@ -226,12 +899,7 @@ impl Provider {
             .filter(|s| !s.is_empty())
             .map(|alg| {
                 // Validate algorithm
-                let valid_algorithms = [
-                    "HS256", "HS384", "HS512", "RS256", "RS384", "RS512", "ES256", "ES384",
-                    "PS256", "PS384", "PS512",
-                ];
-
-                if !valid_algorithms.contains(&alg.as_str()) {
+                if !SUPPORTED_ALGORITHMS.contains(&alg.as_str()) {
                     return Err(anyhow::anyhow!("Unsupported algorithm: {}", alg));
                 }
                 Ok(alg)
@@ -251,10 +919,69 @@ impl Provider {
             issuer,
             jwks_uri,
             public_key,
+            secret: None,
             audience,
             algorithm,
             required_scopes,
             oauth_endpoints,
+            oidc_discovery,
+            issuers,
+            issuer_keys,
+        }))
+    }
+}
+
+impl DevTokens {
+    /// Load development token settings, `None` unless `mcp_dev_tokens` is enabled
+    fn load() -> Result<Option<Self>> {
+        let enabled = variables::get("mcp_dev_tokens")
+            .ok()
+            .is_some_and(|s| matches!(s.trim().to_lowercase().as_str(), "true" | "1" | "yes"));
+        if !enabled {
+            return Ok(None);
+        }
+
+        let secret = variables::get("mcp_dev_token_secret").unwrap_or_default();
+        if secret.len() < MIN_IDENTITY_ASSERTION_KEY_LEN {
+            return Err(anyhow::anyhow!(
+                "mcp_dev_token_secret must be at least {MIN_IDENTITY_ASSERTION_KEY_LEN} bytes when mcp_dev_tokens is enabled"
+            ));
+        }
+
+        // Tokens are issued for the configured audience, so switching between
+        // development tokens and the real provider needs no other change
+        let audience = variables::get("mcp_jwt_audience")
+            .ok()
+            .and_then(|s| {
+                s.split(',')
+                    .map(str::trim)
+                    .find(|aud| !aud.is_empty())
+                    .map(String::from)
+            })
+            .unwrap_or_else(|| DEFAULT_DEV_TOKEN_AUDIENCE.to_string());
+
+        let required_scopes = variables::get("mcp_jwt_required_scopes")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.split(',').map(|scope| scope.trim().to_string()).collect());
+
+        let max_ttl = variables::get("mcp_dev_token_ttl")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.trim().parse::<u64>() {
+                Ok(ttl) if ttl > 0 => Ok(ttl),
+                _ => Err(anyhow::anyhow!(
+                    "mcp_dev_token_ttl must be a positive number of seconds: {s}"
+                )),
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_DEV_TOKEN_TTL);
+
+        Ok(Some(Self {
+            secret,
+            audience,
+            required_scopes,
+            max_ttl,
         }))
     }
 }
@@ -290,8 +1017,36 @@ fn load_oauth_endpoints() -> Result<Option<OAuthEndpoints>> {
     }
 }
 
+/// Parse the comma-separated health check paths
+fn parse_health_paths(value: &str) -> Result<Vec<String>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(|path| {
+            if path.starts_with('/') {
+                Ok(path.to_string())
+            } else {
+                Err(anyhow::anyhow!(
+                    "mcp_health_paths must be paths starting with /: {path}"
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Derive the JWKS URI for issuers of known providers
+pub(crate) fn derive_jwks_uri(issuer: &str) -> Option<String> {
+    if issuer.contains(".authkit.app") || issuer.contains(".workos.com") {
+        // WorkOS AuthKit uses /oauth2/jwks endpoint
+        Some(format!("{issuer}/oauth2/jwks"))
+    } else {
+        None
+    }
+}
+
 /// Normalize issuer (handle both URLs and plain strings)
-fn normalize_issuer(mut issuer: String) -> Result<String> {
+pub(crate) fn normalize_issuer(mut issuer: String) -> Result<String> {
     // Check if it looks like a URL
     if issuer.starts_with("http://") || issuer.starts_with("https://") {
         // For URLs, validate HTTPS
@@ -310,7 +1065,7 @@ fn normalize_issuer(mut issuer: String) -> Result<String> {
 }
 
 /// Normalize URL (ensure HTTPS, validate format)
-fn normalize_url(url: &str) -> Result<String> {
+pub(crate) fn normalize_url(url: &str) -> Result<String> {
     // Add https:// if no protocol
     let normalized = if !url.starts_with("http://") && !url.starts_with("https://") {
         format!("https://{url}")
@@ -0,0 +1,188 @@
+//! Development tokens
+//!
+//! Testing an auth-enabled app locally otherwise requires a real identity
+//! provider. When `mcp_dev_tokens` is enabled, the authorizer replaces the
+//! configured provider with one verifying HS256 tokens signed with
+//! `mcp_dev_token_secret`, and issues such tokens at `/dev/token`:
+//!
+//! ```text
+//! curl -X POST http://localhost:3000/dev/token -d '{"scope": "read write"}'
+//! ```
+//!
+//! Anyone who can reach the endpoint can mint tokens, so this mode must never
+//! be enabled outside local development.
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+
+use crate::config::{Config, DevTokens, IssuerKeys, JwtProvider};
+use crate::error::{AuthError, Result};
+
+/// Path of the token endpoint
+pub const TOKEN_PATH: &str = "/dev/token";
+
+/// Issuer of development tokens
+pub const DEV_ISSUER: &str = "ftl-dev";
+
+/// Subject of tokens requested without one
+const DEFAULT_SUBJECT: &str = "dev-user";
+
+/// Claims set by the authorizer that requests can't override
+const RESERVED_CLAIMS: [&str; 7] = ["iss", "aud", "sub", "exp", "iat", "nbf", "scope"];
+
+/// Body of a token request (every field is optional)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TokenRequest {
+    /// Subject, `dev-user` by default
+    sub: Option<String>,
+    /// Space-separated scopes
+    scope: Option<String>,
+    /// Lifetime in seconds, at most the configured `mcp_dev_token_ttl`
+    ttl: Option<u64>,
+    /// Additional claims, e.g. `client_id` or `org_id`
+    claims: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Token endpoint response, shaped like an OAuth 2.0 token response
+#[derive(Debug, Serialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: &'static str,
+    expires_in: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+/// Provider verifying development tokens
+pub fn provider(dev: &DevTokens) -> JwtProvider {
+    JwtProvider {
+        issuer: DEV_ISSUER.to_string(),
+        jwks_uri: None,
+        public_key: None,
+        secret: Some(dev.secret.clone()),
+        audience: Some(vec![dev.audience.clone()]),
+        algorithm: Some("HS256".to_string()),
+        required_scopes: dev.required_scopes.clone(),
+        oauth_endpoints: None,
+        oidc_discovery: false,
+        issuers: Vec::new(),
+        issuer_keys: IssuerKeys::Shared,
+    }
+}
+
+/// Handle requests to the token endpoint.
+///
+/// Returns `None` when development tokens are disabled or the path does not
+/// match, so the request is handled like any other.
+pub fn handle_token_request(req: &Request, config: &Config) -> Option<Result<Response>> {
+    let dev = config.dev_tokens.as_ref()?;
+    if config.resource_path(req.path()) != TOKEN_PATH {
+        return None;
+    }
+
+    if *req.method() != Method::Post {
+        return Some(Ok(json_response(
+            405,
+            &serde_json::json!({
+                "error": "method_not_allowed",
+                "error_description": format!("{} is not supported on {TOKEN_PATH}", req.method())
+            }),
+        )));
+    }
+
+    Some(issue(req, dev))
+}
+
+fn issue(req: &Request, dev: &DevTokens) -> Result<Response> {
+    let request = if req.body().is_empty() {
+        TokenRequest::default()
+    } else {
+        match serde_json::from_slice::<TokenRequest>(req.body()) {
+            Ok(request) => request,
+            Err(e) => return Ok(invalid_request(&format!("Invalid token request: {e}"))),
+        }
+    };
+
+    let sub = request
+        .sub
+        .map_or_else(|| DEFAULT_SUBJECT.to_string(), |sub| sub.trim().to_string());
+    if sub.is_empty() {
+        return Ok(invalid_request("sub must not be empty"));
+    }
+
+    let ttl = request.ttl.unwrap_or(dev.max_ttl);
+    if ttl == 0 || ttl > dev.max_ttl {
+        return Ok(invalid_request(&format!(
+            "ttl must be between 1 and {} seconds",
+            dev.max_ttl
+        )));
+    }
+
+    if let Some(claim) = RESERVED_CLAIMS
+        .iter()
+        .find(|claim| request.claims.contains_key(**claim))
+    {
+        return Ok(invalid_request(&format!(
+            "claims must not contain {claim}, it is set by the authorizer"
+        )));
+    }
+
+    let scope = request
+        .scope
+        .map(|scope| scope.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|scope| !scope.is_empty());
+
+    let now = chrono::Utc::now().timestamp();
+    let mut claims = request.claims;
+    claims.insert("iss".to_string(), DEV_ISSUER.into());
+    claims.insert("aud".to_string(), dev.audience.clone().into());
+    claims.insert("sub".to_string(), sub.clone().into());
+    claims.insert("iat".to_string(), now.into());
+    claims.insert(
+        "exp".to_string(),
+        now.saturating_add(i64::try_from(ttl).unwrap_or(i64::MAX))
+            .into(),
+    );
+    if let Some(scope) = &scope {
+        claims.insert("scope".to_string(), scope.clone().into());
+    }
+
+    let access_token = encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(dev.secret.as_bytes()),
+    )
+    .map_err(|e| AuthError::Internal(format!("Failed to sign development token: {e}")))?;
+
+    log::warn!("Issued development token for {sub}");
+
+    let response = TokenResponse {
+        access_token,
+        token_type: "Bearer",
+        expires_in: ttl,
+        scope,
+    };
+    Ok(json_response(200, &serde_json::to_value(response)?))
+}
+
+fn invalid_request(description: &str) -> Response {
+    json_response(
+        400,
+        &serde_json::json!({
+            "error": "invalid_request",
+            "error_description": description
+        }),
+    )
+}
+
+fn json_response(status: u16, body: &serde_json::Value) -> Response {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .header("cache-control", "no-store")
+        .header("access-control-allow-origin", "*")
+        .body(body.to_string())
+        .build()
+}
@@ -1,12 +1,41 @@
 //! OAuth 2.0 discovery endpoints implementation
+//!
+//! Discovery documents are served with `ETag` and `Cache-Control` headers so
+//! polling clients can revalidate cheaply. The authorization server and `OpenID`
+//! documents only depend on configuration and are cached in the key-value store.
 
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use spin_sdk::http::{Request, Response};
+use spin_sdk::key_value::Store;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::config::Config;
 
-/// Get resource URLs based on the request
-fn get_resource_urls(req: &Request) -> Vec<String> {
+/// A rendered discovery document
+#[derive(Debug, Serialize, Deserialize)]
+struct Document {
+    body: String,
+    etag: String,
+}
+
+/// Cached discovery document with expiration
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedDocument {
+    document: Document,
+    expires_at: u64,
+}
+
+impl Document {
+    fn new(metadata: &serde_json::Value) -> Self {
+        let body = metadata.to_string();
+        let etag = format!("\"{:016x}\"", fnv1a(body.as_bytes()));
+        Self { body, etag }
+    }
+}
+
+/// Get resource URLs based on the request and the path prefix of its tenant
+fn get_resource_urls(req: &Request, path_prefix: &str) -> Vec<String> {
     // Try multiple header names for the host
     let host = req
         .headers()
@@ -25,8 +54,8 @@ fn get_resource_urls(req: &Request) -> Vec<String> {
         || {
             // Fallback to localhost for development
             vec![
-                "http://localhost:3000/mcp".to_string(),
-                "http://127.0.0.1:3000/mcp".to_string(),
+                format!("http://localhost:3000{path_prefix}/mcp"),
+                format!("http://127.0.0.1:3000{path_prefix}/mcp"),
             ]
         },
         |host_header| {
@@ -44,7 +73,7 @@ fn get_resource_urls(req: &Request) -> Vec<String> {
                     }
                 });
 
-            vec![format!("{scheme}://{host_header}/mcp")]
+            vec![format!("{scheme}://{host_header}{path_prefix}/mcp")]
         },
     )
 }
@@ -74,7 +103,7 @@ pub fn oauth_protected_resource(
             };
 
             json!({
-                "resource": get_resource_urls(req),
+                "resource": get_resource_urls(req, &config.path_prefix),
                 "authorization_servers": authorization_servers,
                 "bearer_methods_supported": ["header"],
                 "authentication_methods": {
@@ -88,7 +117,7 @@ pub fn oauth_protected_resource(
         None => {
             // Public mode - no authentication required
             json!({
-                "resource": get_resource_urls(req),
+                "resource": get_resource_urls(req, &config.path_prefix),
                 "authorization_servers": [],
                 "bearer_methods_supported": [],
                 "authentication_methods": {},
@@ -96,17 +125,34 @@ pub fn oauth_protected_resource(
         }
     };
 
-    build_success_response(&metadata, trace_id, &config.trace_header)
+    // Depends on the request host, so only revalidation via ETag applies
+    build_success_response(req, &Document::new(&metadata), config, trace_id)
 }
 
 /// Handle OAuth authorization server metadata endpoint
 pub fn oauth_authorization_server(
-    _req: &Request,
+    req: &Request,
     config: &Config,
     trace_id: Option<&String>,
 ) -> Response {
+    let document = cached_document(
+        "oauth-authorization-server",
+        config,
+        authorization_server_metadata,
+    );
+    build_success_response(req, &document, config, trace_id)
+}
+
+/// Handle `OpenID` configuration endpoint
+pub fn openid_configuration(req: &Request, config: &Config, trace_id: Option<&String>) -> Response {
+    let document = cached_document("openid-configuration", config, openid_metadata);
+    build_success_response(req, &document, config, trace_id)
+}
+
+/// Build OAuth authorization server metadata
+fn authorization_server_metadata(config: &Config) -> serde_json::Value {
     // Build metadata based on provider type
-    let metadata = match &config.provider {
+    match &config.provider {
         Some(crate::config::Provider::Jwt(jwt_provider)) => {
             // For AuthKit domains, return comprehensive metadata
             if !jwt_provider.issuer.is_empty()
@@ -168,21 +214,15 @@ pub fn oauth_authorization_server(
                 "error_description": "Public mode does not require OAuth authorization"
             })
         }
-    };
-
-    build_success_response(&metadata, trace_id, &config.trace_header)
+    }
 }
 
-/// Handle `OpenID` configuration endpoint
-pub fn openid_configuration(
-    _req: &Request,
-    config: &Config,
-    trace_id: Option<&String>,
-) -> Response {
+/// Build `OpenID` configuration metadata
+fn openid_metadata(config: &Config) -> serde_json::Value {
     // OpenID configuration is similar to OAuth authorization server metadata
     // but with some additional fields
     // Build metadata based on provider type
-    let metadata = match &config.provider {
+    match &config.provider {
         Some(crate::config::Provider::Jwt(jwt_provider)) => {
             // For AuthKit, return AuthKit-specific OpenID metadata
             if !jwt_provider.issuer.is_empty()
@@ -258,23 +298,97 @@ pub fn openid_configuration(
                 "error_description": "Public mode does not require OpenID Connect"
             })
         }
+    }
+}
+
+/// Get a configuration-derived document from the KV cache, rendering and
+/// caching it on a miss. Caching is skipped when the TTL is 0.
+fn cached_document(
+    kind: &str,
+    config: &Config,
+    build: fn(&Config) -> serde_json::Value,
+) -> Document {
+    if config.discovery_cache_ttl == 0 {
+        return Document::new(&build(config));
+    }
+
+    // Key on the provider configuration so config changes invalidate the cache
+    let provider = serde_json::to_string(&config.provider).unwrap_or_default();
+    let cache_key = format!("discovery:{kind}:{:016x}", fnv1a(provider.as_bytes()));
+    let store = Store::open_default().ok();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    if let Some(store) = &store
+        && let Ok(Some(cached_data)) = store.get(&cache_key)
+        && let Ok(cached) = serde_json::from_slice::<CachedDocument>(&cached_data)
+        && now < cached.expires_at
+    {
+        return cached.document;
+    }
+
+    let cached = CachedDocument {
+        document: Document::new(&build(config)),
+        expires_at: now.saturating_add(config.discovery_cache_ttl),
     };
 
-    build_success_response(&metadata, trace_id, &config.trace_header)
+    if let Some(store) = &store
+        && let Ok(data) = serde_json::to_vec(&cached)
+    {
+        let _ = store.set(&cache_key, &data);
+    }
+
+    cached.document
 }
 
-/// Build a successful response with optional trace header
+/// Check whether an `If-None-Match` header matches the document `ETag`
+fn is_not_modified(req: &Request, etag: &str) -> bool {
+    req.headers()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("if-none-match"))
+        .filter_map(|(_, value)| value.as_str())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+/// 64-bit FNV-1a hash, stable across instances for `ETag`s and cache keys
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Build a successful response with caching headers, or a 304 when the
+/// client already has the current document
 fn build_success_response(
-    metadata: &serde_json::Value,
+    req: &Request,
+    document: &Document,
+    config: &Config,
     _trace_id: Option<&String>,
-    _trace_header: &str,
 ) -> Response {
-    let body = metadata.to_string();
+    let cache_control = if config.discovery_cache_ttl == 0 {
+        "no-cache".to_string()
+    } else {
+        format!("public, max-age={}", config.discovery_cache_ttl)
+    };
+
+    if is_not_modified(req, &document.etag) {
+        return Response::builder()
+            .status(304)
+            .header("etag", document.etag.as_str())
+            .header("cache-control", cache_control)
+            .header("access-control-allow-origin", "*")
+            .build();
+    }
 
     // Try a different approach - build response with body first, then add headers
     Response::builder()
         .status(200)
         .header("content-type", "application/json")
+        .header("etag", document.etag.as_str())
+        .header("cache-control", cache_control)
         .header("access-control-allow-origin", "*")
         .header(
             "access-control-allow-methods",
@@ -282,8 +396,9 @@ fn build_success_response(
         )
         .header(
             "access-control-allow-headers",
-            "Content-Type, Authorization",
+            "Content-Type, Authorization, If-None-Match",
         )
-        .body(body)
+        .header("access-control-expose-headers", "ETag")
+        .body(document.body.clone())
         .build()
 }
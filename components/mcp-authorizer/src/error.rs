@@ -17,6 +17,15 @@ pub enum AuthError {
     /// Token has expired
     ExpiredToken,
 
+    /// Token is used before its `nbf` (not before) time
+    TokenNotYetValid,
+
+    /// Token is older than the configured maximum token age
+    TokenTooOld,
+
+    /// Token `iat` (issued at) is in the future
+    TokenIssuedInFuture,
+
     /// Token issuer doesn't match expected
     InvalidIssuer,
 
@@ -26,6 +35,18 @@ pub enum AuthError {
     /// Token signature verification failed
     InvalidSignature,
 
+    /// The authorization policy denied the request
+    PolicyDenied {
+        /// ID of the policy decision, when decisions are identified
+        decision_id: Option<String>,
+    },
+
+    /// Request does not belong to a configured tenant
+    UnknownTenant(String),
+
+    /// Request body is not a valid MCP request
+    BadRequest(String),
+
     /// Configuration error
     Configuration(String),
 
@@ -39,9 +60,17 @@ impl fmt::Display for AuthError {
             Self::Unauthorized(msg) => write!(f, "Unauthorized: {msg}"),
             Self::InvalidToken(msg) => write!(f, "Invalid token: {msg}"),
             Self::ExpiredToken => write!(f, "Token has expired"),
+            Self::TokenNotYetValid => write!(f, "Token is not valid yet"),
+            Self::TokenTooOld => write!(f, "Token exceeds the maximum token age"),
+            Self::TokenIssuedInFuture => write!(f, "Token is issued in the future"),
             Self::InvalidIssuer => write!(f, "Invalid issuer"),
             Self::InvalidAudience => write!(f, "Invalid audience"),
             Self::InvalidSignature => write!(f, "Invalid signature"),
+            Self::PolicyDenied { .. } => {
+                write!(f, "Unauthorized: Access denied by authorization policy")
+            }
+            Self::UnknownTenant(msg) => write!(f, "Unknown tenant: {msg}"),
+            Self::BadRequest(msg) => write!(f, "Bad request: {msg}"),
             Self::Configuration(msg) => write!(f, "Configuration error: {msg}"),
             Self::Internal(msg) => write!(f, "Internal error: {msg}"),
         }
@@ -68,6 +97,7 @@ impl From<jsonwebtoken::errors::Error> for AuthError {
 
         match err.kind() {
             ErrorKind::ExpiredSignature => Self::ExpiredToken,
+            ErrorKind::ImmatureSignature => Self::TokenNotYetValid,
             ErrorKind::InvalidSignature => Self::InvalidSignature,
             ErrorKind::InvalidIssuer => Self::InvalidIssuer,
             ErrorKind::InvalidAudience => Self::InvalidAudience,
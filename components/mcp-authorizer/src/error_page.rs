@@ -0,0 +1,197 @@
+//! HTML error pages for browsers
+//!
+//! Developers often open the MCP URL in a browser, where a JSON 401 is of
+//! little help. When the `Accept` header ranks `text/html` above JSON, errors
+//! are rendered as a small page explaining how to connect, with links to the
+//! discovery documents. API clients keep getting the JSON error, and
+//! `mcp_html_error_pages = "false"` turns the pages off for API-only
+//! deployments.
+
+use std::fmt::Write;
+
+use spin_sdk::http::Request;
+
+/// Whether the request prefers an HTML response over JSON
+pub fn prefers_html(req: &Request) -> bool {
+    req.headers()
+        .find(|(name, _)| name.eq_ignore_ascii_case("accept"))
+        .and_then(|(_, value)| value.as_str())
+        .is_some_and(accept_prefers_html)
+}
+
+/// Whether an `Accept` header ranks `text/html` strictly above
+/// `application/json`. Ties go to JSON, so `*/*` keeps API clients on JSON.
+fn accept_prefers_html(accept: &str) -> bool {
+    let html = quality(accept, "text", "html");
+    html > 0.0 && html > quality(accept, "application", "json")
+}
+
+/// Quality of a media type in an `Accept` header, taken from the most
+/// specific range matching it (RFC 9110, section 12.5.1)
+fn quality(accept: &str, kind: &str, subtype: &str) -> f32 {
+    let mut best: Option<(u8, f32)> = None;
+    for range in accept.split(',') {
+        let mut parts = range.split(';');
+        let media = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let Some((range_kind, range_subtype)) = media.split_once('/') else {
+            continue;
+        };
+
+        let specificity = if range_kind == kind && range_subtype == subtype {
+            2
+        } else if range_kind == kind && range_subtype == "*" {
+            1
+        } else if range_kind == "*" && range_subtype == "*" {
+            0
+        } else {
+            continue;
+        };
+        let q = parts
+            .find_map(|param| {
+                let (name, value) = param.split_once('=')?;
+                name.trim()
+                    .eq_ignore_ascii_case("q")
+                    .then(|| value.trim().parse::<f32>().ok())
+                    .flatten()
+            })
+            .unwrap_or(1.0);
+
+        if best.is_none_or(|(current, _)| specificity > current) {
+            best = Some((specificity, q));
+        }
+    }
+    best.map_or(0.0, |(_, q)| q)
+}
+
+/// Render the HTML page of an error response. Authentication errors explain
+/// how to connect and link to the protected resource metadata and the
+/// authorization server.
+pub fn render(
+    status: u16,
+    error_code: &str,
+    description: &str,
+    resource_metadata: Option<&str>,
+    issuer: Option<&str>,
+) -> String {
+    let title = match status {
+        400 => "Bad request",
+        401 => "Authentication required",
+        404 => "Not found",
+        500 => "Server error",
+        _ => "Error",
+    };
+
+    let mut body = String::new();
+    let _ = write!(body, "<h1>{title}</h1>\n<p>{}</p>\n", escape(description));
+    if status == 401 {
+        body.push_str(
+            "<p>This is a Model Context Protocol (MCP) server, which a browser can't use \
+             directly. Add this URL to an MCP client: it discovers the authorization server \
+             below, signs you in and sends the access token with every request.</p>\n",
+        );
+        if resource_metadata.is_some() || issuer.is_some() {
+            body.push_str("<h2>Discovery</h2>\n<ul>\n");
+            if let Some(url) = resource_metadata {
+                let url = escape(url);
+                let _ = writeln!(
+                    body,
+                    "<li>Protected resource metadata: <a href=\"{url}\">{url}</a></li>"
+                );
+            }
+            if let Some(issuer) = issuer {
+                let issuer = escape(issuer);
+                let _ = writeln!(
+                    body,
+                    "<li>Authorization server: <a href=\"{issuer}\">{issuer}</a></li>"
+                );
+            }
+            body.push_str("</ul>\n");
+        }
+    }
+    let _ = writeln!(
+        body,
+        "<p><small>Error code: <code>{}</code></small></p>",
+        escape(error_code)
+    );
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{status} {title}</title>\n\
+         <style>body{{font-family:system-ui,sans-serif;max-width:40rem;margin:4rem auto;\
+         padding:0 1rem;line-height:1.5;color:#222}}code{{background:#f2f2f2;\
+         padding:0 .25rem;border-radius:3px}}a{{word-break:break-all}}</style>\n\
+         </head>\n<body>\n{body}</body>\n</html>\n"
+    )
+}
+
+/// Escape text for HTML content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browsers_prefer_html() {
+        // Chrome and Firefox navigation requests
+        assert!(accept_prefers_html(
+            "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,*/*;q=0.8"
+        ));
+        assert!(accept_prefers_html(
+            "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"
+        ));
+        assert!(accept_prefers_html("text/*"));
+    }
+
+    #[test]
+    fn test_api_clients_prefer_json() {
+        assert!(!accept_prefers_html(""));
+        assert!(!accept_prefers_html("*/*"));
+        assert!(!accept_prefers_html("application/json, text/event-stream"));
+        assert!(!accept_prefers_html("text/html, application/json"));
+        assert!(!accept_prefers_html("text/html;q=0.5, application/json"));
+        assert!(!accept_prefers_html("text/html;q=0, */*"));
+        assert!(!accept_prefers_html("application/*, text/html; Q=0.9"));
+    }
+
+    #[test]
+    fn test_render_escapes_and_links() {
+        let page = render(
+            401,
+            "invalid_token",
+            "Token <script>alert(1)</script>",
+            Some("https://example.com/.well-known/oauth-protected-resource"),
+            Some("https://auth.example.com/?a=1&b=2"),
+        );
+        assert!(page.contains("<title>401 Authentication required</title>"));
+        assert!(page.contains("Token &lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!page.contains("<script>"));
+        assert!(
+            page.contains("<a href=\"https://example.com/.well-known/oauth-protected-resource\">")
+        );
+        assert!(page.contains("<a href=\"https://auth.example.com/?a=1&amp;b=2\">"));
+        assert!(page.contains("<code>invalid_token</code>"));
+    }
+
+    #[test]
+    fn test_render_without_links() {
+        let page = render(400, "invalid_request", "Malformed JSON-RPC", None, None);
+        assert!(page.contains("<title>400 Bad request</title>"));
+        assert!(!page.contains("Discovery"));
+        assert!(!page.contains("MCP client"));
+    }
+}
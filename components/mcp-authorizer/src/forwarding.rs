@@ -1,22 +1,243 @@
 //! Request forwarding to the MCP gateway
+//!
+//! Gateway responses are read in full before they are answered, except event
+//! streams (`text/event-stream`), which are forwarded chunk by chunk as they
+//! arrive. A stream that goes `mcp_stream_idle_timeout` seconds without data
+//! is closed, so clients reconnect instead of waiting on a dead connection.
 
-use spin_sdk::http::{Headers, Request, Response};
+use std::task::Poll;
+
+use futures::{SinkExt, StreamExt};
+use spin_sdk::http::{
+    Headers, IncomingResponse, IntoResponse, Method, OutgoingResponse, Request, Response,
+    ResponseOutparam,
+};
+use spin_sdk::wit::wasi::http0_2_0::outgoing_handler;
+use spin_sdk::wit::wasi::http0_2_0::types::{FutureIncomingResponse, RequestOptions};
 
 use crate::auth::Context as AuthContext;
+use crate::claims;
 use crate::config::Config;
+use crate::headers;
+use crate::identity;
+
+/// A gateway response whose body has not been read yet
+struct GatewayResponse {
+    status: u16,
+    /// The response headers that pass through to the client
+    headers: Vec<(String, String)>,
+    /// Whether the body is an event stream, forwarded as it arrives
+    streamed: bool,
+    response: IncomingResponse,
+}
+
+impl GatewayResponse {
+    /// Read the whole body, and return it with the status and headers
+    async fn read(self) -> anyhow::Result<(u16, Vec<(String, String)>, Vec<u8>)> {
+        let body = self.response.into_body().await.map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to read the gateway response: {}",
+                e.to_debug_string()
+            )
+        })?;
+        Ok((self.status, self.headers, body))
+    }
+}
 
-/// Forward request to the MCP gateway
+/// Forward request to the MCP gateway and its response to the client
 pub async fn forward_to_gateway(
     req: Request,
     config: &Config,
     auth_context: AuthContext,
     trace_id: Option<String>,
-) -> anyhow::Result<Response> {
-    // Parse gateway URL to set the scheme and authority
-    let gateway_url = url::Url::parse(&config.gateway_url)?;
+    response_out: ResponseOutparam,
+) {
+    // Spin answers every request itself, so connections can't be handed over
+    // to the gateway
+    if is_websocket_handshake(&req) {
+        let body = serde_json::json!({
+            "error": "unsupported_transport",
+            "error_description": "WebSocket connections are not supported, use the Streamable HTTP transport",
+        });
+        let headers = vec![("content-type".to_string(), "application/json".to_string())];
+        let response = build_gateway_response(
+            501,
+            headers,
+            body.to_string().into_bytes(),
+            trace_id,
+            &config.trace_header,
+        );
+        return respond(response_out, response).await;
+    }
 
     // Build headers with authentication context
-    let headers = build_forwarding_headers(&req, &auth_context, trace_id.as_ref(), config)?;
+    let gateway = match build_forwarding_headers(&req, &auth_context, trace_id.as_ref(), config) {
+        Ok(headers) => send_to_gateway(req, config, headers).await,
+        Err(e) => Err(e),
+    };
+    let mut gateway = match gateway {
+        Ok(gateway) => gateway,
+        Err(e) => return respond(response_out, e.into_response()).await,
+    };
+
+    // Echo the ID of the policy decision that allowed the request
+    if let (Some(header), Some(id)) = (
+        &config.policy_decision_header,
+        auth_context.policy_decision_id,
+    ) {
+        gateway.headers.push((header.clone(), id));
+    }
+
+    if gateway.streamed {
+        let mut headers = std::mem::take(&mut gateway.headers);
+        // Keep proxies in front of the application from buffering the stream
+        headers.push(("x-accel-buffering".to_string(), "no".to_string()));
+        let head = build_gateway_response(
+            gateway.status,
+            headers,
+            Vec::new(),
+            trace_id,
+            &config.trace_header,
+        );
+        stream_response(response_out, head, &gateway.response).await;
+        return;
+    }
+
+    // Build response with proper headers
+    let response = match gateway.read().await {
+        Ok((status, headers_vec, body)) => {
+            build_gateway_response(status, headers_vec, body, trace_id, &config.trace_header)
+        }
+        Err(e) => e.into_response(),
+    };
+    respond(response_out, response).await;
+}
+
+/// Send a complete response to the client
+pub async fn respond(response_out: ResponseOutparam, mut response: Response) {
+    let body = std::mem::take(response.body_mut());
+    match OutgoingResponse::try_from(response) {
+        Ok(outgoing) => {
+            if let Err(e) = response_out.set_with_body(outgoing, body).await {
+                log::error!("Failed to send the response: {e}");
+            }
+        }
+        Err(e) => log::error!("Failed to build the response: {e}"),
+    }
+}
+
+/// Forward the body of a gateway response to the client as it arrives. The
+/// stream ends when the gateway closes it, goes idle for longer than the
+/// idle timeout, or the client disconnects.
+async fn stream_response(
+    response_out: ResponseOutparam,
+    head: Response,
+    gateway: &IncomingResponse,
+) {
+    let outgoing = match OutgoingResponse::try_from(head) {
+        Ok(outgoing) => outgoing,
+        Err(e) => {
+            log::error!("Failed to build the response: {e}");
+            return;
+        }
+    };
+    let mut body = outgoing.take_body();
+    response_out.set(outgoing);
+
+    let mut chunks = gateway.take_body_stream();
+    while let Some(chunk) = chunks.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                log::info!("Gateway event stream ended: {}", e.to_debug_string());
+                break;
+            }
+        };
+        if let Err(e) = body.send(chunk).await {
+            log::info!("Client closed the event stream: {e}");
+            break;
+        }
+    }
+}
+
+/// Whether a request is a WebSocket handshake. Spin drops the `Upgrade` and
+/// `Connection` headers of incoming requests, so the handshake is recognized
+/// by its key.
+fn is_websocket_handshake(req: &Request) -> bool {
+    req.headers()
+        .any(|(name, _)| name.eq_ignore_ascii_case("sec-websocket-key"))
+}
+
+/// Whether a request is a health check of the gateway, which passes without
+/// authentication
+pub fn is_health_check(req: &Request, config: &Config) -> bool {
+    matches!(req.method(), Method::Get | Method::Head)
+        && config.health_paths.iter().any(|path| path == req.path())
+}
+
+/// Forward a health check to the MCP gateway. None of the client's headers
+/// are forwarded, so credentials never reach the gateway unverified. An
+/// unreachable gateway is reported as a failing dependency.
+pub async fn forward_health_check(
+    req: Request,
+    config: &Config,
+    trace_id: Option<String>,
+) -> Response {
+    // Without a gateway the authorizer only reports its own health
+    if config.gateway_url.is_empty() || config.gateway_url == "none" {
+        return health_response(200, &serde_json::json!({ "status": "ok" }));
+    }
+
+    let headers = Headers::new();
+    if let Some(trace_id) = &trace_id
+        && let Err(e) = headers.append(&config.trace_header, &trace_id.as_bytes().to_vec())
+    {
+        log::warn!("Failed to forward the trace ID of a health check: {e}");
+    }
+
+    let gateway = match send_to_gateway(req, config, headers).await {
+        Ok(gateway) => gateway.read().await,
+        Err(e) => Err(e),
+    };
+    match gateway {
+        Ok((status, headers_vec, body)) => {
+            build_gateway_response(status, headers_vec, body, trace_id, &config.trace_header)
+        }
+        Err(e) => {
+            log::error!("Health check failed to reach the gateway: {e}");
+            health_response(
+                503,
+                &serde_json::json!({
+                    "status": "not_ready",
+                    "failing": [{
+                        "dependency": "gateway",
+                        "ok": false,
+                        "error": format!("Gateway unreachable: {e}"),
+                    }],
+                }),
+            )
+        }
+    }
+}
+
+fn health_response(status: u16, body: &serde_json::Value) -> Response {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .header("cache-control", "no-store")
+        .body(body.to_string())
+        .build()
+}
+
+/// Send a request to the MCP gateway with the given headers, and return its
+/// response once the headers have arrived
+async fn send_to_gateway(
+    req: Request,
+    config: &Config,
+    headers: Headers,
+) -> anyhow::Result<GatewayResponse> {
+    // Parse gateway URL to set the scheme and authority
+    let gateway_url = url::Url::parse(&config.gateway_url)?;
 
     // Build outgoing request with the headers
     let outgoing = spin_sdk::http::OutgoingRequest::new(headers);
@@ -45,8 +266,9 @@ pub async fn forward_to_gateway(
             .map_err(|()| anyhow::anyhow!("Failed to set authority"))?;
     }
 
-    // Preserve the incoming request's path and append it to the gateway URL's base path
-    let incoming_path = req.path();
+    // Preserve the incoming request's path and append it to the gateway URL's
+    // base path. Path-based tenants are served at the gateway's root.
+    let incoming_path = config.resource_path(req.path());
     let gateway_base_path = gateway_url.path();
 
     // Combine the gateway base path with the incoming request path
@@ -71,48 +293,84 @@ pub async fn forward_to_gateway(
         .set_path_with_query(Some(&path_with_query))
         .map_err(|()| anyhow::anyhow!("Failed to set path"))?;
 
-    // Transfer request body
+    // Close streamed responses that go idle
+    let options = RequestOptions::new();
+    if config.stream_idle_timeout > 0 {
+        options
+            .set_between_bytes_timeout(Some(
+                config.stream_idle_timeout.saturating_mul(1_000_000_000),
+            ))
+            .map_err(|()| anyhow::anyhow!("Failed to set the stream idle timeout"))?;
+    }
+
+    // Send the request, then transfer its body
     let body_bytes = req.into_body();
-    if !body_bytes.is_empty() {
-        use futures::SinkExt;
-        let mut outgoing_body = outgoing.take_body();
+    let outgoing_body = (!body_bytes.is_empty()).then(|| outgoing.take_body());
+    let future = outgoing_handler::handle(outgoing, Some(options))?;
+    if let Some(mut outgoing_body) = outgoing_body {
         outgoing_body
             .send(body_bytes)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to send body: {:?}", e))?;
     }
-
-    // Send request
-    let incoming_response: spin_sdk::http::Response = spin_sdk::http::send(outgoing).await?;
+    let incoming_response = incoming_response(future).await?;
 
     // Extract status
-    let status = *incoming_response.status();
+    let status = incoming_response.status();
 
-    // Collect headers from the gateway response
+    // Collect the gateway response headers that pass through to the client,
+    // never its hop-by-hop headers
+    let entries = incoming_response.headers().entries();
+    let scoped = headers::connection_scoped(
+        entries
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_slice())),
+    );
     let mut headers_vec: Vec<(String, String)> = Vec::new();
-    for (name, value) in incoming_response.headers() {
-        if let Ok(value_str) = std::str::from_utf8(value.as_bytes()) {
-            // Skip certain headers that we'll override
-            if !name.eq_ignore_ascii_case("access-control-allow-origin")
-                && !name.eq_ignore_ascii_case("access-control-allow-methods")
-                && !name.eq_ignore_ascii_case("access-control-allow-headers")
-            {
-                headers_vec.push((name.to_string(), value_str.to_string()));
-            }
+    let mut streamed = false;
+    for (name, value) in entries {
+        let name = name.to_ascii_lowercase();
+        if scoped.contains(&name)
+            || !config.response_headers.passes(&name)
+            || config.policy_decision_header.as_ref() == Some(&name)
+        {
+            continue;
+        }
+        if let Ok(value_str) = String::from_utf8(value) {
+            streamed |= name == "content-type" && is_event_stream(&value_str);
+            headers_vec.push((name, value_str));
         }
     }
 
-    // Extract body
-    let body = incoming_response.into_body();
-
-    // Build response with proper headers
-    Ok(build_gateway_response(
+    Ok(GatewayResponse {
         status,
-        headers_vec,
-        body,
-        trace_id,
-        &config.trace_header,
-    ))
+        headers: headers_vec,
+        streamed,
+        response: incoming_response,
+    })
+}
+
+/// Wait for the response to a request sent to the gateway, without blocking
+/// the executor
+async fn incoming_response(future: FutureIncomingResponse) -> anyhow::Result<IncomingResponse> {
+    std::future::poll_fn(|cx| match future.get() {
+        Some(Ok(Ok(response))) => Poll::Ready(Ok(response)),
+        Some(Ok(Err(code))) => Poll::Ready(Err(anyhow::anyhow!("Gateway request failed: {code}"))),
+        Some(Err(())) => Poll::Ready(Err(anyhow::anyhow!("Gateway response was already taken"))),
+        None => {
+            spin_executor::push_waker(future.subscribe(), cx.waker().clone());
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Whether a content type is that of a server-sent event stream
+fn is_event_stream(content_type: &str) -> bool {
+    content_type
+        .split(';')
+        .next()
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/event-stream"))
 }
 
 /// Build headers for forwarding request
@@ -124,35 +382,69 @@ fn build_forwarding_headers(
 ) -> anyhow::Result<Headers> {
     let headers = Headers::new();
 
-    // Copy request headers
+    // Copy request headers, dropping client-supplied auth context and claim
+    // headers so downstream components only ever see the values verified here.
+    // Hop-by-hop headers and the host belong to the client's connection.
+    let scoped =
+        headers::connection_scoped(req.headers().map(|(name, value)| (name, value.as_bytes())));
     for (name, value) in req.headers() {
+        let lower = name.to_ascii_lowercase();
+        if lower.starts_with("x-auth-")
+            || claims::is_projected_header(name)
+            || lower == "host"
+            || scoped.contains(&lower)
+        {
+            continue;
+        }
         headers.append(&name.to_string(), &value.as_bytes().to_vec())?;
     }
 
     // Add standard authentication context headers
-    headers.append(
-        &"x-auth-client-id".to_string(),
-        &auth_context.client_id.as_bytes().to_vec(),
-    )?;
-    headers.append(
-        &"x-auth-user-id".to_string(),
-        &auth_context.user_id.as_bytes().to_vec(),
-    )?;
-    headers.append(
-        &"x-auth-issuer".to_string(),
-        &auth_context.issuer.as_bytes().to_vec(),
-    )?;
+    if config.identity_format.forwards_headers() {
+        headers.append(
+            &"x-auth-client-id".to_string(),
+            &auth_context.client_id.as_bytes().to_vec(),
+        )?;
+        headers.append(
+            &"x-auth-user-id".to_string(),
+            &auth_context.user_id.as_bytes().to_vec(),
+        )?;
+        headers.append(
+            &"x-auth-issuer".to_string(),
+            &auth_context.issuer.as_bytes().to_vec(),
+        )?;
 
-    if !auth_context.scopes.is_empty() {
+        if !auth_context.scopes.is_empty() {
+            headers.append(
+                &"x-auth-scopes".to_string(),
+                &auth_context.scopes.join(" ").as_bytes().to_vec(),
+            )?;
+        }
+    }
+
+    // Identify the tenant so downstream components can isolate its data
+    if let Some(tenant) = &config.tenant {
+        headers.append(&"x-auth-tenant".to_string(), &tenant.as_bytes().to_vec())?;
+    }
+
+    // Add the signed identity assertion so downstream components can verify
+    // the request came through the authorizer
+    if config.identity_format.forwards_assertion() {
+        let key = config
+            .identity_assertion_key
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Identity assertion key is not configured"))?;
+        let assertion = identity::sign(auth_context, key, config.identity_assertion_ttl)?;
         headers.append(
-            &"x-auth-scopes".to_string(),
-            &auth_context.scopes.join(" ").as_bytes().to_vec(),
+            &identity::ASSERTION_HEADER.to_string(),
+            &assertion.as_bytes().to_vec(),
         )?;
     }
 
-    // Note: Claim forwarding has been removed in favor of policy-based authorization
-    // If specific claims need to be forwarded, they should be added as explicit headers
-    // in the policy evaluation result or as part of the auth context
+    // Project the configured claims, never the whole token
+    for (name, value) in claims::project(&config.claim_headers, &auth_context.additional_claims) {
+        headers.append(&name, &value.into_bytes())?;
+    }
 
     // Forward the original authorization header
     headers.append(
@@ -170,7 +462,9 @@ fn build_forwarding_headers(
     Ok(headers)
 }
 
-/// Build gateway response with CORS headers
+/// Build gateway response with CORS headers. A body sent in full has its
+/// length replace the gateway's framing headers, streamed bodies (passed
+/// empty here) are framed by the host as they are written.
 fn build_gateway_response(
     status: u16,
     headers_vec: Vec<(String, String)>,
@@ -186,6 +480,10 @@ fn build_gateway_response(
         response_builder = response_builder.header(&name, &value);
     }
 
+    if !body.is_empty() {
+        response_builder = response_builder.header("content-length", body.len().to_string());
+    }
+
     // Add/override CORS headers
     response_builder = response_builder
         .header("Access-Control-Allow-Origin", "*")
@@ -206,3 +504,16 @@ fn build_gateway_response(
     // Build the response with body
     response_builder.body(body).build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_event_stream() {
+        assert!(is_event_stream("text/event-stream"));
+        assert!(is_event_stream("Text/Event-Stream; charset=utf-8"));
+        assert!(!is_event_stream("application/json"));
+        assert!(!is_event_stream("text/event-stream-like"));
+    }
+}
@@ -0,0 +1,186 @@
+//! Header hygiene between clients, the authorizer and the gateway
+//!
+//! Hop-by-hop headers (RFC 7230, section 6.1) describe a single connection,
+//! so they are never forwarded in either direction, and neither is any header
+//! the `Connection` header names. Which of the gateway's remaining response
+//! headers reach the client is configured with `mcp_response_headers_allow`
+//! and `mcp_response_headers_deny`.
+//!
+//! The authorizer buffers gateway responses, so their framing is rebuilt:
+//! `Transfer-Encoding` is dropped and `Content-Length` is set to the length of
+//! the body that is actually sent. Event streams are not buffered and are sent
+//! without a length.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Hop-by-hop headers, plus `proxy-connection` which clients still send
+pub const HOP_BY_HOP: [&str; 9] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Headers describing the response body, forwarded regardless of the
+/// allowlist and denylist since the body is always forwarded
+pub const BODY_HEADERS: [&str; 2] = ["content-type", "content-encoding"];
+
+/// Headers the authorizer sets itself on every gateway response
+const AUTHORIZER_HEADERS: [&str; 4] = [
+    "access-control-allow-origin",
+    "access-control-allow-methods",
+    "access-control-allow-headers",
+    "content-length",
+];
+
+/// Names of the headers that must not be forwarded past this hop: the
+/// hop-by-hop headers and the headers listed in `Connection`
+pub fn connection_scoped<'a>(
+    headers: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+) -> Vec<String> {
+    let mut names: Vec<String> = HOP_BY_HOP.iter().map(|h| (*h).to_string()).collect();
+    for (name, value) in headers {
+        if !name.eq_ignore_ascii_case("connection") {
+            continue;
+        }
+        let Ok(value) = std::str::from_utf8(value) else {
+            continue;
+        };
+        names.extend(
+            value
+                .split(',')
+                .map(|token| token.trim().to_ascii_lowercase())
+                .filter(|token| !token.is_empty()),
+        );
+    }
+    names
+}
+
+/// Which gateway response headers are passed through to the client
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseHeaders {
+    /// Header patterns that pass, all headers when empty
+    pub allow: Vec<String>,
+    /// Header patterns that never pass, checked after the allowlist
+    pub deny: Vec<String>,
+}
+
+impl ResponseHeaders {
+    /// Parse the `mcp_response_headers_allow` and `mcp_response_headers_deny`
+    /// variables
+    pub fn parse(allow: &str, deny: &str) -> Result<Self> {
+        Ok(Self {
+            allow: parse_patterns("mcp_response_headers_allow", allow)?,
+            deny: parse_patterns("mcp_response_headers_deny", deny)?,
+        })
+    }
+
+    /// Whether a gateway response header is forwarded to the client. `name`
+    /// must already be lowercase.
+    pub fn passes(&self, name: &str) -> bool {
+        if AUTHORIZER_HEADERS.contains(&name) {
+            return false;
+        }
+        if BODY_HEADERS.contains(&name) {
+            return true;
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|p| matches(p, name)) {
+            return false;
+        }
+        !self.deny.iter().any(|p| matches(p, name))
+    }
+}
+
+/// Parse a comma-separated list of header names, each optionally ending in
+/// `*` to match a prefix: `x-ratelimit-*, etag`
+fn parse_patterns(variable: &str, value: &str) -> Result<Vec<String>> {
+    value
+        .split(',')
+        .map(|pattern| pattern.trim().to_ascii_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .map(|pattern| {
+            let name = pattern.strip_suffix('*').unwrap_or(&pattern);
+            if name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                Ok(pattern)
+            } else {
+                Err(anyhow::anyhow!(
+                    "{variable} has an invalid header name: '{pattern}'"
+                ))
+            }
+        })
+        .collect()
+}
+
+fn matches(pattern: &str, name: &str) -> bool {
+    pattern
+        .strip_suffix('*')
+        .map_or(pattern == name, |prefix| name.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_scoped() {
+        let headers = [
+            ("content-type", b"text/plain".as_slice()),
+            ("Connection", b"keep-alive, X-Session-Hint".as_slice()),
+        ];
+        let names = connection_scoped(headers);
+        assert!(names.contains(&"transfer-encoding".to_string()));
+        assert!(names.contains(&"x-session-hint".to_string()));
+        assert!(!names.contains(&"content-type".to_string()));
+    }
+
+    #[test]
+    fn test_all_headers_pass_by_default() {
+        let headers = ResponseHeaders::default();
+        assert!(headers.passes("x-gateway-response"));
+        assert!(headers.passes("etag"));
+        // Set by the authorizer itself
+        assert!(!headers.passes("content-length"));
+        assert!(!headers.passes("access-control-allow-origin"));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_allow_and_deny() {
+        let headers = ResponseHeaders::parse("x-ratelimit-*, ETag, x-internal-*", "x-internal-*")
+            .expect("header patterns should parse");
+        assert_eq!(headers.allow, vec!["x-ratelimit-*", "etag", "x-internal-*"]);
+        assert!(headers.passes("x-ratelimit-remaining"));
+        assert!(headers.passes("etag"));
+        assert!(!headers.passes("x-internal-trace"));
+        assert!(!headers.passes("server"));
+        // The body is always described
+        assert!(headers.passes("content-type"));
+        assert!(headers.passes("content-encoding"));
+
+        let headers = ResponseHeaders::parse("", "server, x-powered-by")
+            .expect("header patterns should parse");
+        assert!(!headers.passes("server"));
+        assert!(headers.passes("etag"));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_invalid_pattern() {
+        let err = ResponseHeaders::parse("x-ok, x bad", "")
+            .expect_err("invalid pattern should be rejected");
+        assert!(
+            err.to_string()
+                .contains("mcp_response_headers_allow has an invalid header name: 'x bad'")
+        );
+        assert!(ResponseHeaders::parse("", "x-*-id").is_err());
+    }
+}
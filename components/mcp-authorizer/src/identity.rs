@@ -0,0 +1,64 @@
+//! Signed identity assertions forwarded to the MCP gateway
+//!
+//! Structured `x-auth-*` headers can be set by anything that reaches a
+//! downstream component directly. A signed assertion lets the gateway and tool
+//! components verify that the request went through the authorizer: it is a
+//! short-lived HS256 JWT signed with a key shared through Spin variables.
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::Context as AuthContext;
+
+/// Header carrying the signed identity assertion
+pub const ASSERTION_HEADER: &str = "x-auth-assertion";
+
+/// Issuer of identity assertions
+pub const ASSERTION_ISSUER: &str = "mcp-authorizer";
+
+/// Audience of identity assertions
+pub const ASSERTION_AUDIENCE: &str = "mcp-gateway";
+
+/// Claims of an identity assertion
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssertionClaims {
+    /// Always `mcp-authorizer`
+    pub iss: String,
+    /// Always `mcp-gateway`
+    pub aud: String,
+    /// User ID (subject) of the verified token
+    pub sub: String,
+    /// Client ID of the verified token
+    pub client_id: String,
+    /// Space-separated scopes of the verified token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Issuer of the verified token
+    pub token_iss: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Sign an identity assertion for the verified request
+pub fn sign(auth_context: &AuthContext, key: &str, ttl: u64) -> anyhow::Result<String> {
+    let now = chrono::Utc::now().timestamp();
+    let ttl = i64::try_from(ttl)?;
+
+    let claims = AssertionClaims {
+        iss: ASSERTION_ISSUER.to_string(),
+        aud: ASSERTION_AUDIENCE.to_string(),
+        sub: auth_context.user_id.clone(),
+        client_id: auth_context.client_id.clone(),
+        scope: (!auth_context.scopes.is_empty()).then(|| auth_context.scopes.join(" ")),
+        token_iss: auth_context.issuer.clone(),
+        iat: now,
+        exp: now.saturating_add(ttl),
+    };
+
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(key.as_bytes()),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to sign identity assertion: {e}"))
+}
@@ -0,0 +1,205 @@
+//! Issuer allow-list for identity providers with several issuers
+//!
+//! Some identity providers issue tokens from region-specific issuer URLs,
+//! such as `https://eu.idp.example.com` and `https://us.idp.example.com`.
+//! `mcp_jwt_issuers` lists the issuers accepted in addition to
+//! `mcp_jwt_issuer`, either exactly or as a pattern with a wildcard for the
+//! first host label (`https://*.idp.example.com`). The wildcard matches a
+//! single label, so `https://a.b.idp.example.com` is not accepted.
+//!
+//! The issuer of a token is read before its signature is verified, only to
+//! pick the keys to verify it with. The token is then verified against that
+//! issuer like any other.
+
+use std::borrow::Cow;
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
+use serde::Deserialize;
+
+use crate::config::{self, IssuerKeys, JwtProvider};
+use crate::error::{AuthError, Result};
+use crate::oidc;
+
+/// Placeholder for the token issuer in a JWKS URI template
+pub const ISSUER_PLACEHOLDER: &str = "{issuer}";
+
+/// Prefix of wildcard patterns
+const WILDCARD_PREFIX: &str = "https://*.";
+
+/// The only claim read from tokens before verification
+#[derive(Debug, Deserialize)]
+struct IssuerClaim {
+    iss: Option<String>,
+}
+
+/// Parse the comma-separated issuer patterns of `mcp_jwt_issuers`
+pub fn parse_patterns(value: &str) -> anyhow::Result<Vec<String>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .map(|pattern| {
+            let pattern = config::normalize_issuer(pattern.to_string())
+                .map_err(|e| anyhow::anyhow!("Invalid issuer in mcp_jwt_issuers: {e}"))?;
+            if pattern.contains('*') && !is_valid_wildcard(&pattern) {
+                return Err(anyhow::anyhow!(
+                    "Invalid issuer pattern in mcp_jwt_issuers: {pattern} (only https://*.<domain> is supported)"
+                ));
+            }
+            Ok(pattern)
+        })
+        .collect()
+}
+
+/// Whether a wildcard pattern only replaces the first host label of an HTTPS
+/// issuer, with the rest naming a domain rather than a public suffix
+fn is_valid_wildcard(pattern: &str) -> bool {
+    pattern
+        .strip_prefix(WILDCARD_PREFIX)
+        .filter(|rest| !rest.contains('*'))
+        .and_then(|rest| rest.split('/').next())
+        .is_some_and(|host| {
+            host.split('.').count() >= 2 && host.split('.').all(|label| !label.is_empty())
+        })
+}
+
+/// Whether an issuer matches a pattern of `mcp_jwt_issuers`
+pub fn matches(pattern: &str, issuer: &str) -> bool {
+    let Some(suffix) = pattern.strip_prefix(WILDCARD_PREFIX) else {
+        return pattern == issuer;
+    };
+
+    issuer
+        .strip_prefix("https://")
+        .and_then(|rest| rest.split_once('.'))
+        .is_some_and(|(label, rest)| {
+            !label.is_empty()
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                && rest == suffix
+        })
+}
+
+/// Read the issuer of a token without verifying it
+fn unverified_issuer(token: &str) -> Result<Option<String>> {
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.insecure_disable_signature_validation();
+    validation.validate_exp = false;
+    validation.validate_aud = false;
+    validation.required_spec_claims.clear();
+
+    let data = decode::<IssuerClaim>(token, &DecodingKey::from_secret(&[]), &validation)?;
+    Ok(data.claims.iss)
+}
+
+/// The provider to verify a token with
+///
+/// Tokens from the configured issuer, or any token when no allow-list is
+/// configured, are verified with the provider itself. Tokens from an allowed
+/// issuer are verified against that issuer, with keys resolved according to
+/// `issuer_keys`. Tokens from any other issuer are rejected.
+pub async fn resolve<'a>(
+    token: &str,
+    provider: &'a JwtProvider,
+    refresh_interval: u64,
+) -> Result<Cow<'a, JwtProvider>> {
+    if provider.issuers.is_empty() {
+        return Ok(Cow::Borrowed(provider));
+    }
+
+    let issuer = unverified_issuer(token)?.ok_or(AuthError::InvalidIssuer)?;
+    if !provider.issuer.is_empty() && issuer == provider.issuer {
+        return Ok(Cow::Borrowed(provider));
+    }
+
+    // Trailing slashes are ignored when matching, like for `mcp_jwt_issuer`
+    let normalized = issuer.trim_end_matches('/');
+    if !provider
+        .issuers
+        .iter()
+        .any(|pattern| matches(pattern, normalized))
+    {
+        return Err(AuthError::InvalidIssuer);
+    }
+
+    let jwks_uri = match &provider.issuer_keys {
+        IssuerKeys::Shared => provider.jwks_uri.clone(),
+        IssuerKeys::Template(template) => Some(
+            config::normalize_url(&template.replace(ISSUER_PLACEHOLDER, normalized))
+                .map_err(|e| AuthError::Configuration(format!("Invalid JWKS URI: {e}")))?,
+        ),
+        IssuerKeys::Discovery => Some(oidc::discover_jwks_uri(normalized, refresh_interval).await?),
+        IssuerKeys::Derived => Some(config::derive_jwks_uri(normalized).ok_or_else(|| {
+            AuthError::Configuration(format!("Cannot derive the JWKS URI of {normalized}"))
+        })?),
+    };
+
+    Ok(Cow::Owned(JwtProvider {
+        issuer,
+        jwks_uri,
+        ..provider.clone()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_parse_patterns() {
+        let patterns =
+            parse_patterns(" https://eu.idp.example.com/, https://*.idp.example.com ,,my-service")
+                .expect("issuer patterns should parse");
+        assert_eq!(
+            patterns,
+            vec![
+                "https://eu.idp.example.com",
+                "https://*.idp.example.com",
+                "my-service",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_patterns_rejects_invalid_wildcards() {
+        for value in [
+            "http://eu.idp.example.com",
+            "https://*.com",
+            "https://*",
+            "https://*.*.example.com",
+            "https://eu.*.example.com",
+            "https://*idp.example.com",
+            "https://*.idp..com",
+            "*.idp.example.com",
+        ] {
+            assert!(parse_patterns(value).is_err(), "{value} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches(
+            "https://eu.idp.example.com",
+            "https://eu.idp.example.com"
+        ));
+        assert!(!matches(
+            "https://eu.idp.example.com",
+            "https://us.idp.example.com"
+        ));
+
+        let pattern = "https://*.idp.example.com";
+        assert!(matches(pattern, "https://eu.idp.example.com"));
+        assert!(matches(pattern, "https://us-east-1.idp.example.com"));
+        assert!(!matches(pattern, "https://idp.example.com"));
+        assert!(!matches(pattern, "https://a.b.idp.example.com"));
+        assert!(!matches(pattern, "https://.idp.example.com"));
+        assert!(!matches(pattern, "https://eu.idp.example.com.evil.com"));
+        assert!(!matches(pattern, "https://eu.idp.example.com/other"));
+        assert!(!matches(pattern, "http://eu.idp.example.com"));
+        assert!(!matches(pattern, "https://eu_1.idp.example.com"));
+
+        let pattern = "https://*.idp.example.com/tenant";
+        assert!(matches(pattern, "https://eu.idp.example.com/tenant"));
+        assert!(!matches(pattern, "https://eu.idp.example.com"));
+    }
+}
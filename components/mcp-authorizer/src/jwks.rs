@@ -47,58 +47,75 @@ pub struct Jwk {
 struct CachedJwks {
     jwks: Jwks,
     expires_at: u64,
+    /// Upstream `ETag`, used to revalidate an expired entry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
 }
 
 /// Fetch JWKS from URI with caching
+///
+/// Expired entries are revalidated with `If-None-Match` when the provider
+/// returned an `ETag`, so unchanged key sets are not downloaded again.
 pub async fn fetch_jwks(jwks_uri: &str, store: &Store) -> Result<Jwks> {
     let cache_key = format!("jwks:{jwks_uri}");
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
 
     // Check cache first
-    if let Ok(Some(cached_data)) = store.get(&cache_key)
-        && let Ok(cached_str) = String::from_utf8(cached_data)
-        && let Ok(cached) = serde_json::from_str::<CachedJwks>(&cached_str)
+    let cached = store
+        .get(&cache_key)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_slice::<CachedJwks>(&data).ok());
+
+    if let Some(cached) = &cached
+        && now < cached.expires_at
     {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
-        if now < cached.expires_at {
-            return Ok(cached.jwks);
-        }
+        return Ok(cached.jwks.clone());
     }
 
     // Fetch JWKS from URI
-    let request = spin_sdk::http::Request::builder()
+    let mut builder = spin_sdk::http::Request::builder();
+    builder
         .method(spin_sdk::http::Method::Get)
         .uri(jwks_uri)
-        .header("Accept", "application/json")
-        .build();
+        .header("Accept", "application/json");
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_deref()) {
+        builder.header("If-None-Match", etag);
+    }
+    let request = builder.build();
 
     let response: Response = spin_sdk::http::send(request)
         .await
         .map_err(|e| AuthError::Internal(format!("Failed to fetch JWKS: {e}")))?;
 
-    if *response.status() != 200 {
-        return Err(AuthError::Internal(format!(
-            "JWKS fetch failed with status: {}",
-            response.status()
-        )));
-    }
-
-    let body = response.body();
-    let jwks: Jwks = serde_json::from_slice(body)?;
+    let mut etag = response
+        .header("etag")
+        .and_then(|value| value.as_str())
+        .map(ToString::to_string);
+
+    let jwks = match (*response.status(), cached) {
+        // Unchanged upstream - keep the cached keys, and their ETag unless a
+        // new one was sent
+        (304, Some(cached)) => {
+            etag = etag.or(cached.etag);
+            cached.jwks
+        }
+        (200, _) => serde_json::from_slice(response.body())?,
+        (status, _) => {
+            return Err(AuthError::Internal(format!(
+                "JWKS fetch failed with status: {status}"
+            )));
+        }
+    };
 
     // Cache the JWKS
-    let expires_at = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-        + JWKS_CACHE_TTL;
-
     let cached = CachedJwks {
         jwks: jwks.clone(),
-        expires_at,
+        expires_at: now + JWKS_CACHE_TTL,
+        etag,
     };
 
     let _ = store.set(&cache_key, serde_json::to_string(&cached)?.as_bytes());
@@ -3,41 +3,113 @@
 //! This component implements OAuth 2.0 Bearer Token authentication with JWKS support,
 //! providing a secure gateway to MCP (Model Context Protocol) servers.
 
-use spin_sdk::http::{IntoResponse, Request, Response};
+use spin_sdk::http::{Request, Response, ResponseOutparam};
 use spin_sdk::key_value::Store;
 
 mod auth;
+mod claims;
+mod client;
 mod config;
+mod dev;
 mod discovery;
 mod error;
+mod error_page;
 mod forwarding;
+mod headers;
+mod identity;
+mod issuers;
 mod jwks;
+mod oidc;
 mod policy;
+mod pseudonym;
+mod revocation;
+mod tenant;
 mod token;
+mod token_cache;
 
+use client::ClientInfo;
 use config::{Config, PolicyAuthorization};
 use error::{AuthError, Result};
 use policy::PolicyEngine;
 
-/// Main HTTP component handler
+/// What the authorizer does with a request
+enum Outcome {
+    /// Answer the request directly
+    Respond(Response),
+    /// Forward the authenticated request to the gateway
+    Forward(Box<Forward>),
+}
+
+/// An authenticated request for the gateway
+struct Forward {
+    req: Request,
+    config: Config,
+    auth_context: auth::Context,
+    trace_id: Option<String>,
+}
+
+/// Main HTTP component handler. Gateway responses are forwarded through the
+/// response outparam so streamed bodies reach the client as they arrive.
 #[spin_sdk::http_component]
-async fn handle_request(req: Request) -> anyhow::Result<impl IntoResponse> {
+async fn handle_request(req: Request, response_out: ResponseOutparam) {
+    match route_request(req).await {
+        Outcome::Respond(response) => forwarding::respond(response_out, response).await,
+        Outcome::Forward(forward) => {
+            let Forward {
+                req,
+                config,
+                auth_context,
+                trace_id,
+            } = *forward;
+            forwarding::forward_to_gateway(req, &config, auth_context, trace_id, response_out)
+                .await;
+        }
+    }
+}
+
+/// Authenticate a request and decide how it is answered
+async fn route_request(req: Request) -> Outcome {
     // Handle CORS preflight requests immediately
     if *req.method() == spin_sdk::http::Method::Options {
-        return Ok(create_cors_response());
+        return Outcome::Respond(create_cors_response());
     }
 
     // Load configuration and handle errors properly
-    let config = match Config::load() {
+    let mut config = match Config::load() {
         Ok(c) => c,
         Err(e) => {
             log::error!("Configuration failed: {e}");
             // Return configuration error as a proper HTTP response
             let error = AuthError::Configuration(format!("Configuration error: {e}"));
-            return Ok(create_config_error_response(&error));
+            return Outcome::Respond(create_config_error_response(&error));
         }
     };
 
+    // Health checks of the gateway pass without authentication, so
+    // orchestrators and load balancers need no credentials
+    if forwarding::is_health_check(&req, &config) {
+        let trace_id = extract_trace_id(&req, &config.trace_header);
+        return Outcome::Respond(forwarding::forward_health_check(req, &config, trace_id).await);
+    }
+
+    // Apply the provider and policy of the tenant the request is for
+    if let Err(e) = tenant::apply(&req, &mut config) {
+        log::info!("Tenant resolution failed: {e}");
+        return Outcome::Respond(match e {
+            AuthError::UnknownTenant(_) => {
+                let trace_id = extract_trace_id(&req, &config.trace_header);
+                create_error_response(&e, &req, &config, trace_id)
+            }
+            _ => create_config_error_response(&e),
+        });
+    }
+
+    // Bootstrap provider configuration from OIDC discovery if enabled
+    if let Err(e) = oidc::resolve(&mut config).await {
+        log::error!("OIDC discovery failed: {e}");
+        return Outcome::Respond(create_config_error_response(&e));
+    }
+
     // Extract trace ID for request tracking
     let trace_id = extract_trace_id(&req, &config.trace_header);
 
@@ -50,7 +122,23 @@ async fn handle_request(req: Request) -> anyhow::Result<impl IntoResponse> {
 
     // Handle OAuth discovery endpoints (no auth required)
     if let Some(response) = handle_discovery(&req, &config, trace_id.as_ref()) {
-        return Ok(response);
+        return Outcome::Respond(response);
+    }
+
+    // Handle the development token endpoint (only when explicitly enabled)
+    if let Some(result) = dev::handle_token_request(&req, &config) {
+        return Outcome::Respond(result.unwrap_or_else(|e| {
+            log::error!("Development token request failed: {e}");
+            create_error_response(&e, &req, &config, trace_id)
+        }));
+    }
+
+    // Handle the revocation admin endpoint (authenticated separately)
+    if let Some(result) = revocation::handle_admin(&req, &config).await {
+        return Outcome::Respond(result.unwrap_or_else(|e| {
+            log::info!("Revocation admin request failed: {e}");
+            create_error_response(&e, &req, &config, trace_id)
+        }));
     }
 
     // For POST requests with JSON content, capture the body for potential policy evaluation
@@ -73,35 +161,20 @@ async fn handle_request(req: Request) -> anyhow::Result<impl IntoResponse> {
 
     // Authentication is always required for an auth gateway
     // The presence of a provider configuration determines the auth method
-    match authenticate_with_policy(&req, &config, body_bytes.as_deref()).await {
+    match authenticate_with_policy(&req, &config, body_bytes.as_deref(), trace_id.as_ref()).await {
         Ok(auth_context) => {
             // Only forward if gateway URL is configured and valid
             // This allows tests to run without forwarding
             if !config.gateway_url.is_empty() && config.gateway_url != "none" {
-                // Reconstruct request with body if we consumed it
-                let req_to_forward = if let Some(body) = body_bytes {
-                    // Collect headers first
-                    let headers: Vec<(String, String)> = req
-                        .headers()
-                        .map(|(name, value)| {
-                            (name.to_string(), value.as_str().unwrap_or("").to_string())
-                        })
-                        .collect();
-
-                    // Create a new request with headers and body
-                    Request::builder()
-                        .method(req.method().clone())
-                        .uri(req.uri())
-                        .headers(headers)
-                        .body(body)
-                        .build()
-                } else {
-                    req
-                };
-                forward_request(req_to_forward, &config, auth_context, trace_id).await
+                Outcome::Forward(Box::new(Forward {
+                    req: request_to_forward(req, body_bytes),
+                    config,
+                    auth_context,
+                    trace_id,
+                }))
             } else {
                 // No gateway configured - return success directly (for testing)
-                Ok(Response::new(200, "OK"))
+                Outcome::Respond(Response::new(200, "OK"))
             }
         }
         Err(auth_error) => {
@@ -112,16 +185,38 @@ async fn handle_request(req: Request) -> anyhow::Result<impl IntoResponse> {
                 log::info!("Auth failed: {auth_error}");
             }
             // Return authentication error
-            Ok(create_error_response(&auth_error, &req, &config, trace_id))
+            Outcome::Respond(create_error_response(&auth_error, &req, &config, trace_id))
         }
     }
 }
 
+/// Reconstruct request with body if we consumed it
+fn request_to_forward(req: Request, body: Option<Vec<u8>>) -> Request {
+    let Some(body) = body else {
+        return req;
+    };
+
+    // Collect headers first
+    let headers: Vec<(String, String)> = req
+        .headers()
+        .map(|(name, value)| (name.to_string(), value.as_str().unwrap_or("").to_string()))
+        .collect();
+
+    // Create a new request with headers and body
+    Request::builder()
+        .method(req.method().clone())
+        .uri(req.uri())
+        .headers(headers)
+        .body(body)
+        .build()
+}
+
 /// Authenticate the incoming request with policy-based authorization
 async fn authenticate_with_policy(
     req: &Request,
     config: &Config,
     body: Option<&[u8]>,
+    trace_id: Option<&String>,
 ) -> Result<auth::Context> {
     // Extract bearer token
     let token = auth::extract_bearer_token(req)?;
@@ -132,7 +227,7 @@ async fn authenticate_with_policy(
     })?;
 
     // Verify token using JWT provider
-    let token_info = match provider {
+    let mut token_info = match provider {
         config::Provider::Jwt(jwt_provider) => {
             // Open KV store for JWKS caching
             let store = Store::open_default()
@@ -142,16 +237,57 @@ async fn authenticate_with_policy(
                     AuthError::Internal("KV store access denied. Ensure component has key_value_stores permission in spin.toml".to_string())
                 })?;
 
-            // Verify JWT token (signature, expiry, issuer, audience)
-            token::verify(token, jwt_provider, &store).await?
+            // Reuse a recent verification of the same token, if cached
+            let cached = token_cache::get(token, jwt_provider, config, &store);
+            let token_info = if let Some(token_info) = cached {
+                token_info
+            } else {
+                // Verify tokens from allowed issuers against their own issuer
+                let issuer_provider =
+                    issuers::resolve(token, jwt_provider, config.oidc_refresh_interval).await?;
+
+                // Verify JWT token (signature, expiry, issuer, audience)
+                let token_info =
+                    token::verify(token, &issuer_provider, &config.token_timing, &store).await?;
+                token_cache::put(token, jwt_provider, config, &store, &token_info);
+                token_info
+            };
+
+            // Reject revoked tokens once the signature is known to be valid,
+            // whether or not the verification was cached
+            if config.revocation_enabled {
+                revocation::check(&token_info, &store)?;
+            }
+
+            token_info
         }
     };
 
-    // Apply policy-based authorization if configured
-    if let Some(policy_config) = &config.authorization {
-        apply_policy_authorization(&token_info, req, body, policy_config)?;
+    // Hide the raw subject from the policy and downstream components, unless
+    // the request only reaches components allowed to see it
+    if let Some(pseudonyms) = &config.subject_pseudonyms
+        && !pseudonyms.exempts(config.resource_path(req.path()), body)
+    {
+        pseudonyms.apply(&mut token_info)?;
     }
 
+    // Apply policy-based authorization if configured
+    let policy_decision_id = match &config.authorization {
+        Some(policy_config) => {
+            let path = config.resource_path(req.path());
+            apply_policy_authorization(
+                &token_info,
+                req,
+                path,
+                body,
+                policy_config,
+                config,
+                trace_id,
+            )?
+        }
+        None => None,
+    };
+
     // Build auth context with all available claims
     Ok(auth::Context {
         client_id: token_info.client_id,
@@ -160,16 +296,23 @@ async fn authenticate_with_policy(
         issuer: token_info.iss,
         raw_token: token.to_string(),
         additional_claims: token_info.claims,
+        policy_decision_id,
     })
 }
 
 /// Apply policy-based authorization using Regorous
+///
+/// Returns the ID of the decision when decisions are identified, either for
+/// `mcp_policy_debug` or `mcp_policy_decision_header`.
 fn apply_policy_authorization(
     token_info: &token::TokenInfo,
     req: &Request,
+    path: &str,
     body: Option<&[u8]>,
     policy_config: &PolicyAuthorization,
-) -> Result<()> {
+    config: &Config,
+    trace_id: Option<&String>,
+) -> Result<Option<String>> {
     log::debug!("Applying policy-based authorization");
 
     // Create policy engine with the configured policy and data
@@ -181,21 +324,44 @@ fn apply_policy_authorization(
         log::error!("Failed to initialize policy engine: {e}");
         AuthError::Configuration(format!("Failed to initialize policy engine: {e}"))
     })?;
+    engine.add_cidr_lists(&config.cidr_lists).map_err(|e| {
+        log::error!("Failed to add CIDR lists to the policy data: {e}");
+        AuthError::Configuration(format!("Failed to add CIDR lists to the policy data: {e}"))
+    })?;
 
     log::trace!("Policy engine created, evaluating authorization");
 
     // Evaluate policy
-    let allowed = engine.evaluate(token_info, req, body)?;
+    let client = ClientInfo::from_request(req, &config.trusted_proxy_headers, &config.cidr_lists);
+    let allowed = engine.evaluate(token_info, req, path, body, config.malformed_body, &client)?;
+
+    // Identify the decision so logs and responses can be correlated
+    let decision_id = (config.policy_debug || config.policy_decision_header.is_some())
+        .then(|| uuid::Uuid::new_v4().simple().to_string());
+
+    if config.policy_debug {
+        let prefix = trace_id.map(|id| format!("[{id}] ")).unwrap_or_default();
+        let id = decision_id.as_deref().unwrap_or_default();
+        if allowed {
+            log::info!("{prefix}Policy decision {id}: ALLOW");
+        } else {
+            match engine.explain_denial() {
+                Ok(explanation) => log::info!(
+                    "{prefix}Policy decision {id}: DENY {}",
+                    serde_json::to_string(&explanation).unwrap_or_default()
+                ),
+                Err(e) => log::info!("{prefix}Policy decision {id}: DENY (no explanation: {e})"),
+            }
+        }
+    }
 
     if !allowed {
         log::debug!("Authorization denied by policy");
-        return Err(AuthError::Unauthorized(
-            "Access denied by authorization policy".to_string(),
-        ));
+        return Err(AuthError::PolicyDenied { decision_id });
     }
 
     log::debug!("Authorization granted by policy");
-    Ok(())
+    Ok(decision_id)
 }
 
 /// Handle OAuth discovery endpoints
@@ -214,14 +380,18 @@ fn handle_discovery(req: &Request, config: &Config, trace_id: Option<&String>) -
     }
 }
 
-/// Forward request to the MCP gateway
-async fn forward_request(
-    req: Request,
-    config: &Config,
-    auth_context: auth::Context,
-    trace_id: Option<String>,
-) -> anyhow::Result<Response> {
-    forwarding::forward_to_gateway(req, config, auth_context, trace_id).await
+/// Machine-readable reason of a token validation failure
+const fn token_error_reason(error: &AuthError) -> Option<&'static str> {
+    match error {
+        AuthError::ExpiredToken => Some("token_expired"),
+        AuthError::TokenNotYetValid => Some("token_not_yet_valid"),
+        AuthError::TokenTooOld => Some("token_too_old"),
+        AuthError::TokenIssuedInFuture => Some("token_issued_in_future"),
+        AuthError::InvalidIssuer => Some("invalid_issuer"),
+        AuthError::InvalidAudience => Some("invalid_audience"),
+        AuthError::InvalidSignature => Some("invalid_signature"),
+        _ => None,
+    }
 }
 
 /// Create authentication error response
@@ -235,28 +405,59 @@ fn create_error_response(
         AuthError::Unauthorized(msg) => (401, "unauthorized", msg.as_str()),
         AuthError::InvalidToken(msg) => (401, "invalid_token", msg.as_str()),
         AuthError::ExpiredToken => (401, "invalid_token", "Token has expired"),
+        AuthError::TokenNotYetValid => (401, "invalid_token", "Token is not valid yet"),
+        AuthError::TokenTooOld => (401, "invalid_token", "Token exceeds the maximum token age"),
+        AuthError::TokenIssuedInFuture => (401, "invalid_token", "Token is issued in the future"),
         AuthError::InvalidIssuer => (401, "invalid_token", "Invalid issuer"),
         AuthError::InvalidAudience => (401, "invalid_token", "Invalid audience"),
         AuthError::InvalidSignature => (401, "invalid_token", "Invalid signature"),
+        AuthError::PolicyDenied { .. } => {
+            (401, "unauthorized", "Access denied by authorization policy")
+        }
+        AuthError::UnknownTenant(msg) => (404, "unknown_tenant", msg.as_str()),
+        AuthError::BadRequest(msg) => (400, "invalid_request", msg.as_str()),
         AuthError::Configuration(msg) | AuthError::Internal(msg) => {
             (500, "server_error", msg.as_str())
         }
     };
 
-    // Build JSON error body
-    let body = serde_json::json!({
+    // Build JSON error body, with a specific code for each token validation failure
+    let mut body = serde_json::json!({
         "error": error_code,
         "error_description": description
     });
+    if let (Some(reason), Some(fields)) = (token_error_reason(error), body.as_object_mut()) {
+        fields.insert("error_reason".to_string(), reason.into());
+    }
+
+    let resource_metadata = resource_metadata_url(req, config);
+
+    // Browsers get an HTML page, API clients the JSON error
+    let status_u16 = u16::try_from(status).unwrap_or(500);
+    let (content_type, body) = if config.html_error_pages && error_page::prefers_html(req) {
+        let issuer = match &config.provider {
+            Some(config::Provider::Jwt(jwt)) if !jwt.issuer.is_empty() => Some(jwt.issuer.as_str()),
+            _ => None,
+        };
+        let page = error_page::render(
+            status_u16,
+            error_code,
+            description,
+            resource_metadata.as_deref(),
+            issuer,
+        );
+        ("text/html; charset=utf-8", page)
+    } else {
+        ("application/json", body.to_string())
+    };
 
     // Build response with appropriate headers
     let mut binding = Response::builder();
-    let status_u16 = u16::try_from(status).unwrap_or(500);
     let mut builder = binding.status(status_u16);
 
     // Add common headers
     let cors_headers = [
-        ("content-type", "application/json"),
+        ("content-type", content_type),
         ("access-control-allow-origin", "*"),
         (
             "access-control-allow-methods",
@@ -271,23 +472,18 @@ fn create_error_response(
     for (key, value) in cors_headers {
         builder = builder.header(key, value);
     }
+    if config.html_error_pages {
+        builder = builder.header("vary", "accept");
+    }
 
     // Add WWW-Authenticate header for 401 responses
     if status == 401 {
         let www_auth = format!(r#"Bearer error="{error_code}", error_description="{description}""#);
 
         // Add resource metadata if we have a host
-        let www_auth_value = if let Some(host) = extract_host(req) {
-            // Use http for local development (localhost/127.0.0.1)
-            let scheme = if host.starts_with("localhost") || host.starts_with("127.0.0.1") {
-                "http"
-            } else {
-                "https"
-            };
-            let resource_url = format!("{scheme}://{host}/.well-known/oauth-protected-resource");
-            format!("{www_auth}, resource_metadata=\"{resource_url}\"")
-        } else {
-            www_auth
+        let www_auth_value = match &resource_metadata {
+            Some(resource_url) => format!("{www_auth}, resource_metadata=\"{resource_url}\""),
+            None => www_auth,
         };
 
         builder = builder.header("www-authenticate", www_auth_value);
@@ -298,7 +494,34 @@ fn create_error_response(
         builder = builder.header(&config.trace_header, trace_id);
     }
 
-    builder.body(body.to_string()).build()
+    // Echo the ID of the policy decision that denied the request
+    if let (
+        AuthError::PolicyDenied {
+            decision_id: Some(id),
+        },
+        Some(header),
+    ) = (error, &config.policy_decision_header)
+    {
+        builder = builder.header(header, id);
+    }
+
+    builder.body(body).build()
+}
+
+/// URL of the protected resource metadata, when the request has a host
+fn resource_metadata_url(req: &Request, config: &Config) -> Option<String> {
+    extract_host(req).map(|host| {
+        // Use http for local development (localhost/127.0.0.1)
+        let scheme = if host.starts_with("localhost") || host.starts_with("127.0.0.1") {
+            "http"
+        } else {
+            "https"
+        };
+        format!(
+            "{scheme}://{host}/.well-known/oauth-protected-resource{}",
+            config.path_prefix
+        )
+    })
 }
 
 /// Create CORS preflight response
@@ -0,0 +1,222 @@
+//! Provider configuration bootstrapped from OIDC discovery
+//!
+//! When `mcp_jwt_oidc_discovery` is enabled, the JWKS URI and OAuth endpoints
+//! are read from the issuer's `/.well-known/openid-configuration` instead of
+//! being configured by hand. The fetched metadata is pinned in the key-value
+//! store and refreshed every `mcp_oidc_refresh_interval` seconds, so endpoint
+//! rotations at the identity provider are picked up without redeploying. If a
+//! refresh fails, the last pinned metadata keeps being used.
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::Response;
+use spin_sdk::key_value::Store;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::{self, Config, JwtProvider, OAuthEndpoints, Provider};
+use crate::error::{AuthError, Result};
+
+/// Delay before retrying a failed refresh while serving pinned metadata
+const REFRESH_RETRY_INTERVAL: u64 = 60;
+
+/// The subset of `OpenID` provider metadata the authorizer uses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    jwks_uri: Option<String>,
+    authorization_endpoint: Option<String>,
+    token_endpoint: Option<String>,
+    userinfo_endpoint: Option<String>,
+}
+
+/// Provider metadata pinned in the key-value store
+#[derive(Debug, Serialize, Deserialize)]
+struct PinnedMetadata {
+    metadata: ProviderMetadata,
+    refresh_at: u64,
+    /// Upstream `ETag`, used to revalidate on refresh
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
+}
+
+/// Fill in provider configuration from the issuer's discovery document
+///
+/// Explicitly configured values take precedence over discovered ones.
+pub async fn resolve(config: &mut Config) -> Result<()> {
+    let refresh_interval = config.oidc_refresh_interval;
+    let Some(Provider::Jwt(provider)) = &mut config.provider else {
+        return Ok(());
+    };
+    // Without a primary issuer, discovery only locates the keys of the
+    // issuers of `mcp_jwt_issuers`, per token
+    if !provider.oidc_discovery || provider.issuer.is_empty() {
+        return Ok(());
+    }
+
+    let store = Store::open_default().ok();
+    let metadata = load_metadata(&provider.issuer, refresh_interval, store.as_ref()).await?;
+    apply(provider, metadata)
+}
+
+/// The JWKS URI from an issuer's discovery document
+pub async fn discover_jwks_uri(issuer: &str, refresh_interval: u64) -> Result<String> {
+    let store = Store::open_default().ok();
+    let metadata = load_metadata(issuer, refresh_interval, store.as_ref()).await?;
+    let jwks_uri = metadata.jwks_uri.ok_or_else(|| {
+        AuthError::Configuration("OIDC discovery document has no jwks_uri".to_string())
+    })?;
+    config::normalize_url(&jwks_uri)
+        .map_err(|e| AuthError::Configuration(format!("Invalid OIDC discovery document: {e}")))
+}
+
+/// Get the pinned metadata for an issuer, refreshing it when due
+async fn load_metadata(
+    issuer: &str,
+    refresh_interval: u64,
+    store: Option<&Store>,
+) -> Result<ProviderMetadata> {
+    let cache_key = format!("oidc:{issuer}");
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let pinned = store
+        .and_then(|store| store.get(&cache_key).ok().flatten())
+        .and_then(|data| serde_json::from_slice::<PinnedMetadata>(&data).ok());
+
+    if let Some(pinned) = &pinned
+        && now < pinned.refresh_at
+    {
+        return Ok(pinned.metadata.clone());
+    }
+
+    let etag = pinned.as_ref().and_then(|p| p.etag.as_deref());
+    let (metadata, etag, refresh_at) = match fetch_metadata(issuer, etag).await {
+        Ok(Some((metadata, etag))) => (metadata, etag, now + refresh_interval),
+        // Unchanged upstream - keep the pinned metadata
+        Ok(None) => match pinned {
+            Some(pinned) => (pinned.metadata, pinned.etag, now + refresh_interval),
+            None => {
+                return Err(AuthError::Configuration(
+                    "OIDC discovery returned 304 without pinned metadata".to_string(),
+                ));
+            }
+        },
+        Err(e) => match pinned {
+            Some(pinned) => {
+                log::warn!(
+                    "OIDC discovery refresh for {issuer} failed, using pinned metadata: {e}"
+                );
+                (
+                    pinned.metadata,
+                    pinned.etag,
+                    now + refresh_interval.min(REFRESH_RETRY_INTERVAL),
+                )
+            }
+            None => return Err(e),
+        },
+    };
+
+    if let Some(store) = store {
+        let pinned = PinnedMetadata {
+            metadata: metadata.clone(),
+            refresh_at,
+            etag,
+        };
+        if let Ok(data) = serde_json::to_vec(&pinned) {
+            let _ = store.set(&cache_key, &data);
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Fetch and validate the discovery document
+///
+/// Returns `None` when the document is unchanged since `etag`.
+async fn fetch_metadata(
+    issuer: &str,
+    etag: Option<&str>,
+) -> Result<Option<(ProviderMetadata, Option<String>)>> {
+    let url = format!("{issuer}/.well-known/openid-configuration");
+
+    let mut builder = spin_sdk::http::Request::builder();
+    builder
+        .method(spin_sdk::http::Method::Get)
+        .uri(&url)
+        .header("Accept", "application/json");
+    if let Some(etag) = etag {
+        builder.header("If-None-Match", etag);
+    }
+
+    let response: Response = spin_sdk::http::send(builder.build())
+        .await
+        .map_err(|e| AuthError::Configuration(format!("Failed to fetch {url}: {e}")))?;
+
+    match *response.status() {
+        304 if etag.is_some() => return Ok(None),
+        200 => {}
+        status => {
+            return Err(AuthError::Configuration(format!(
+                "OIDC discovery at {url} failed with status: {status}"
+            )));
+        }
+    }
+
+    let metadata: ProviderMetadata = serde_json::from_slice(response.body()).map_err(|e| {
+        AuthError::Configuration(format!("Invalid OIDC discovery document at {url}: {e}"))
+    })?;
+
+    // OpenID Connect Discovery 1.0 section 4.3: the issuer must match the configured one
+    if metadata.issuer.trim_end_matches('/') != issuer {
+        return Err(AuthError::Configuration(format!(
+            "OIDC discovery issuer '{}' does not match configured issuer '{issuer}'",
+            metadata.issuer
+        )));
+    }
+
+    let etag = response
+        .header("etag")
+        .and_then(|value| value.as_str())
+        .map(ToString::to_string);
+
+    Ok(Some((metadata, etag)))
+}
+
+/// Merge discovered metadata into the provider, keeping configured values
+fn apply(provider: &mut JwtProvider, metadata: ProviderMetadata) -> Result<()> {
+    let normalize = |url: Option<String>| {
+        url.map(|url| config::normalize_url(&url))
+            .transpose()
+            .map_err(|e| AuthError::Configuration(format!("Invalid OIDC discovery document: {e}")))
+    };
+
+    if provider.jwks_uri.is_none() && provider.public_key.is_none() {
+        provider.jwks_uri = Some(normalize(metadata.jwks_uri)?.ok_or_else(|| {
+            AuthError::Configuration("OIDC discovery document has no jwks_uri".to_string())
+        })?);
+    }
+
+    let configured = provider.oauth_endpoints.take();
+    let configured = configured.as_ref();
+    let endpoints = OAuthEndpoints {
+        authorize: match configured.and_then(|e| e.authorize.clone()) {
+            Some(url) => Some(url),
+            None => normalize(metadata.authorization_endpoint)?,
+        },
+        token: match configured.and_then(|e| e.token.clone()) {
+            Some(url) => Some(url),
+            None => normalize(metadata.token_endpoint)?,
+        },
+        userinfo: match configured.and_then(|e| e.userinfo.clone()) {
+            Some(url) => Some(url),
+            None => normalize(metadata.userinfo_endpoint)?,
+        },
+    };
+
+    if endpoints.authorize.is_some() || endpoints.token.is_some() || endpoints.userinfo.is_some() {
+        provider.oauth_endpoints = Some(endpoints);
+    }
+
+    Ok(())
+}
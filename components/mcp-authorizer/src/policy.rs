@@ -5,25 +5,52 @@ use regorus::{Engine, Value};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use spin_sdk::http::Request;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use crate::client::{Cidr, ClientInfo};
+use crate::config::MalformedBody;
 use crate::error::AuthError;
 use crate::token::TokenInfo;
 
+/// Rule that decides whether a request is allowed
+const ALLOW_RULE: &str = "data.mcp.authorization.allow";
+
+/// Package of the authorization policy
+const POLICY_PACKAGE: &str = "data.mcp.authorization";
+
 /// Policy engine for authorization decisions
 pub struct PolicyEngine {
     engine: Engine,
 }
 
-/// MCP context extracted from request body
+/// Why the policy denied a request, logged when `mcp_policy_debug` is enabled
+#[derive(Debug, Serialize)]
+pub struct Explanation {
+    /// Policy lines evaluated for the allow rule, as `file:line: source`
+    pub evaluated: Vec<String>,
+    /// Output of the policy's `print` calls
+    pub prints: Vec<String>,
+    /// Values of the rules in the policy package
+    pub rules: serde_json::Value,
+}
+
+/// MCP context extracted from request body, available to policies as `input.mcp`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpContext {
     /// JSON-RPC method (e.g., "tools/call", "tools/list")
     pub method: String,
+    /// JSON-RPC request id, absent for notifications
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<serde_json::Value>,
     /// Tool name for tools/call requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tool: Option<String>,
     /// Tool arguments if present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub arguments: Option<serde_json::Value>,
+    /// Sorted names of the tool arguments
+    #[serde(default)]
+    pub argument_keys: Vec<String>,
 }
 
 /// JSON-RPC request structure for MCP
@@ -31,7 +58,6 @@ pub struct McpContext {
 struct JsonRpcRequest {
     #[allow(dead_code)]
     jsonrpc: String,
-    #[allow(dead_code)]
     id: Option<serde_json::Value>,
     method: String,
     params: Option<serde_json::Value>,
@@ -71,25 +97,60 @@ impl PolicyEngine {
         Ok(Self { engine })
     }
 
+    /// Add the configured CIDR lists to the policy data as `data.cidr_lists`
+    pub fn add_cidr_lists(&mut self, lists: &BTreeMap<String, Vec<Cidr>>) -> Result<()> {
+        if lists.is_empty() {
+            return Ok(());
+        }
+        let data = Value::from_json_str(&json!({ "cidr_lists": lists }).to_string())
+            .map_err(|e| anyhow!("Failed to encode CIDR lists: {e}"))?;
+        self.engine
+            .add_data(data)
+            .map_err(|e| anyhow!("Failed to add CIDR lists: {e}"))
+    }
+
     /// Evaluate authorization policy
+    ///
+    /// `path` is the request path relative to the tenant, as the gateway sees it.
+    /// Each message of a JSON-RPC batch is evaluated with its own `input.mcp`,
+    /// and the request is allowed only if all of them are, so a batch can't
+    /// slip a denied call past the policy.
     pub fn evaluate(
         &mut self,
         token_info: &TokenInfo,
         req: &Request,
+        path: &str,
         body: Option<&[u8]>,
+        malformed_body: MalformedBody,
+        client: &ClientInfo,
     ) -> Result<bool, AuthError> {
-        // Build the input for policy evaluation
-        let input = Self::build_policy_input(token_info, req, body)?;
+        let messages = match body.map(parse_mcp_messages) {
+            Some(Ok(messages)) => messages,
+            Some(Err(e)) if malformed_body == MalformedBody::Deny => {
+                log::debug!("Rejecting malformed MCP request: {e}");
+                return Err(AuthError::BadRequest(format!("Malformed MCP request: {e}")));
+            }
+            // Without MCP context, the policy decides how to handle the request
+            None | Some(Err(_)) => vec![None],
+        };
 
+        for mcp in &messages {
+            let input = Self::build_policy_input(token_info, req, path, mcp.as_ref(), client)?;
+            if !self.eval_allow(input)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Evaluate the allow rule against one policy input
+    fn eval_allow(&mut self, input: Value) -> Result<bool, AuthError> {
         // Set the input
         self.engine.set_input(input);
 
         // Evaluate the allow rule
-        log::trace!("Evaluating authorization rule: data.mcp.authorization.allow");
-        match self
-            .engine
-            .eval_rule("data.mcp.authorization.allow".to_string())
-        {
+        log::trace!("Evaluating authorization rule: {ALLOW_RULE}");
+        match self.engine.eval_rule(ALLOW_RULE.to_string()) {
             Ok(value) => {
                 // Check if the result is a boolean true
                 match value {
@@ -120,14 +181,58 @@ impl PolicyEngine {
         }
     }
 
+    /// Explain the denial of the last evaluated input by evaluating the allow
+    /// rule again with coverage and print gathering enabled
+    ///
+    /// For a batch, this is the first message the policy denied.
+    pub fn explain_denial(&mut self) -> Result<Explanation> {
+        self.engine.set_enable_coverage(true);
+        self.engine.set_gather_prints(true);
+        let report = self
+            .engine
+            .eval_rule(ALLOW_RULE.to_string())
+            .and_then(|_| self.engine.get_coverage_report());
+        self.engine.set_enable_coverage(false);
+
+        let mut evaluated = Vec::new();
+        for file in report?.files {
+            let lines: Vec<&str> = file.code.lines().collect();
+            for line in file.covered {
+                let source = usize::try_from(line)
+                    .ok()
+                    .and_then(|line| lines.get(line.checked_sub(1)?))
+                    .map_or("", |source| source.trim());
+                evaluated.push(format!("{}:{line}: {source}", file.path));
+            }
+        }
+        let prints = self.engine.take_prints()?;
+
+        // Evaluating the package evaluates its helper rules too, so the
+        // values show which conditions did not hold
+        let results = self.engine.eval_query(POLICY_PACKAGE.to_string(), false)?;
+        self.engine.take_prints()?;
+        let rules = serde_json::to_value(results)?
+            .pointer("/result/0/expressions/0/value")
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(Explanation {
+            evaluated,
+            prints,
+            rules,
+        })
+    }
+
     /// Build policy input from request context
     fn build_policy_input(
         token_info: &TokenInfo,
         req: &Request,
-        body: Option<&[u8]>,
+        path: &str,
+        mcp: Option<&serde_json::Value>,
+        client: &ClientInfo,
     ) -> Result<Value, AuthError> {
         // Extract component from path
-        let component = extract_component_from_path(req.path());
+        let component = extract_component_from_path(path);
 
         // Build base input
         let mut input = json!({
@@ -139,21 +244,28 @@ impl PolicyEngine {
             },
             "request": {
                 "method": req.method().to_string(),
-                "path": req.path(),
+                "path": path,
                 "component": component,
                 "headers": headers_to_json(req.headers())
             }
         });
 
-        // Always try to add MCP context if we have a body
+        // Add the client IP, user agent and request time
+        if let Some(request) = input
+            .get_mut("request")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            client.extend(request);
+        }
+
+        // Add the MCP context when the body is a JSON-RPC request
         // The policy decides whether to use this information
-        if let Some(body_bytes) = body
-            && let Ok(mcp_context) = parse_mcp_request(body_bytes)
+        if let Some(mcp_context) = mcp
             && let Some(obj) = input.as_object_mut()
         {
-            obj.insert("mcp".to_string(), mcp_context);
+            obj.insert("mcp".to_string(), mcp_context.clone());
         }
-        // If parsing fails, we just don't add the mcp field
+        // Otherwise there is no mcp field
         // This allows policies to handle both MCP and non-MCP requests
 
         Value::from_json_str(&input.to_string())
@@ -166,7 +278,7 @@ impl PolicyEngine {
 /// - `/mcp` -> None (all components)
 /// - `/mcp/x/{component}` -> Some(component)
 /// - `/mcp/x/{component}/readonly` -> Some(component)
-fn extract_component_from_path(path: &str) -> Option<String> {
+pub fn extract_component_from_path(path: &str) -> Option<String> {
     let path = path.trim_start_matches('/').trim_end_matches('/');
 
     // Check for component scoping pattern
@@ -197,52 +309,72 @@ fn headers_to_json<'a>(
     serde_json::to_value(header_map).unwrap_or_else(|_| json!({}))
 }
 
-/// Parse MCP request from body
-fn parse_mcp_request(body: &[u8]) -> Result<serde_json::Value> {
-    let json_rpc: JsonRpcRequest = serde_json::from_slice(body)?;
-
-    let mcp_context = match json_rpc.method.as_str() {
-        "tools/call" => {
-            // Extract tool name and arguments from params
-            json_rpc.params.map_or_else(
-                || {
-                    json!({
-                        "method": "tools/call",
-                        "tool": null,
-                        "arguments": null
-                    })
-                },
-                |params| {
-                    json!({
-                        "method": "tools/call",
-                        "tool": params.get("name"),
-                        "arguments": params.get("arguments")
-                    })
-                },
-            )
-        }
-        "tools/list" => {
-            json!({ "method": "tools/list" })
-        }
-        "prompts/list" => {
-            json!({ "method": "prompts/list" })
-        }
-        "resources/list" => {
-            json!({ "method": "resources/list" })
-        }
-        method => {
-            // Pass through other methods
-            json!({ "method": method })
+/// Parse the MCP messages of a request body, a single JSON-RPC message or a
+/// batch. Responses sent by the client carry no MCP context and are `None`.
+fn parse_mcp_messages(body: &[u8]) -> Result<Vec<Option<serde_json::Value>>> {
+    let messages = match serde_json::from_slice::<serde_json::Value>(body)? {
+        serde_json::Value::Array(batch) if batch.is_empty() => {
+            return Err(anyhow!("empty JSON-RPC batch"));
         }
+        serde_json::Value::Array(batch) => batch,
+        message => vec![message],
     };
 
-    Ok(mcp_context)
+    messages
+        .into_iter()
+        .map(|message| {
+            let is_response = message.get("method").is_none()
+                && (message.get("result").is_some() || message.get("error").is_some());
+            if is_response {
+                return Ok(None);
+            }
+            mcp_context(serde_json::from_value(message)?).map(Some)
+        })
+        .collect()
+}
+
+/// Build the MCP context of a JSON-RPC request
+fn mcp_context(request: JsonRpcRequest) -> Result<serde_json::Value> {
+    // Tool name and arguments only exist for tool calls
+    let (tool, arguments) = match (request.method.as_str(), &request.params) {
+        ("tools/call", Some(params)) => (
+            params
+                .get("name")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+            params.get("arguments").cloned(),
+        ),
+        _ => (None, None),
+    };
+
+    let mut argument_keys: Vec<String> = arguments
+        .as_ref()
+        .and_then(serde_json::Value::as_object)
+        .map(|args| args.keys().cloned().collect())
+        .unwrap_or_default();
+    argument_keys.sort();
+
+    Ok(serde_json::to_value(McpContext {
+        method: request.method,
+        id: request.id,
+        tool,
+        arguments,
+        argument_keys,
+    })?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // Parse a body holding a single JSON-RPC request
+    fn parse_mcp_request(body: &[u8]) -> Result<serde_json::Value> {
+        parse_mcp_messages(body)?
+            .pop()
+            .flatten()
+            .ok_or_else(|| anyhow!("not a request"))
+    }
+
     #[test]
     fn test_extract_component_from_path() {
         assert_eq!(extract_component_from_path("/mcp"), None);
@@ -295,4 +427,92 @@ mod tests {
         let result = parse_mcp_request(body.as_bytes()).expect("should parse valid JSON-RPC");
         assert_eq!(result.get("method"), Some(&json!("tools/list")));
     }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_parse_mcp_request_id_and_argument_keys() {
+        let body = r#"{
+            "jsonrpc": "2.0",
+            "id": "req-7",
+            "method": "tools/call",
+            "params": {
+                "name": "transfer",
+                "arguments": {"to": "acct_2", "amount": 10}
+            }
+        }"#;
+
+        let result = parse_mcp_request(body.as_bytes()).expect("should parse valid JSON-RPC");
+        assert_eq!(result.get("id"), Some(&json!("req-7")));
+        assert_eq!(result.get("argument_keys"), Some(&json!(["amount", "to"])));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_parse_mcp_messages_batch() {
+        let body = r#"[
+            {"jsonrpc": "2.0", "id": 1, "method": "tools/list"},
+            {"jsonrpc": "2.0", "id": 2, "method": "tools/call", "params": {"name": "echo"}},
+            {"jsonrpc": "2.0", "id": 9, "result": {}}
+        ]"#;
+
+        let messages = parse_mcp_messages(body.as_bytes()).expect("should parse batch");
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages
+                .get(1)
+                .and_then(Option::as_ref)
+                .and_then(|m| m.get("tool")),
+            Some(&json!("echo"))
+        );
+        // Client responses carry no MCP context
+        assert!(messages.get(2).is_some_and(Option::is_none));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_explain_denial() {
+        let policy = r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+allow if {
+    print("tool:", input.mcp.tool)
+    input.mcp.tool in data.safe_tools
+}
+
+default admin := false
+
+admin if "admin" in input.token.scopes
+"#;
+        let mut engine =
+            PolicyEngine::new_with_policy_and_data(policy, Some(r#"{"safe_tools": ["echo"]}"#))
+                .expect("should load policy");
+        let input = Value::from_json_str(
+            r#"{"token": {"scopes": ["read"]}, "mcp": {"tool": "delete_database"}}"#,
+        )
+        .expect("should build input");
+        assert!(!engine.eval_allow(input).expect("should evaluate"));
+
+        let explanation = engine.explain_denial().expect("should explain");
+        assert!(
+            explanation
+                .evaluated
+                .contains(&"authorization.rego:9: input.mcp.tool in data.safe_tools".to_string())
+        );
+        assert_eq!(
+            explanation.prints,
+            vec!["authorization.rego:8: tool: delete_database"]
+        );
+        assert_eq!(explanation.rules, json!({"allow": false, "admin": false}));
+    }
+
+    #[test]
+    fn test_parse_mcp_messages_malformed() {
+        assert!(parse_mcp_messages(b"not json").is_err());
+        assert!(parse_mcp_messages(b"[]").is_err());
+        assert!(parse_mcp_messages(br#"{"jsonrpc": "2.0", "id": 1}"#).is_err());
+        assert!(parse_mcp_messages(br#"{"id": 1, "method": "tools/list"}"#).is_err());
+    }
 }
@@ -0,0 +1,258 @@
+//! Pairwise subject pseudonyms
+//!
+//! With `mcp_subject_pseudonyms` enabled, the subject of a verified token is
+//! replaced with a pseudonym before the policy runs, so neither the policy
+//! nor anything downstream sees the raw user ID. The pseudonym is the
+//! unpadded base64url HMAC-SHA256 of `<iss>\n<sub>`, keyed with the app's
+//! `mcp_subject_pseudonym_salt`: it is stable for a user within the app, but
+//! differs between apps with different salts and can't be reversed without
+//! the salt.
+//!
+//! Requests that only reach components listed in
+//! `mcp_subject_pseudonym_allow` keep the raw subject. A request reaches a
+//! single component when it is scoped to it (`/mcp/x/{component}`), or when
+//! every message it carries calls one of its tools. Anything else, such as
+//! `tools/list` at `/mcp`, may reach every component and is pseudonymized.
+
+use jsonwebtoken::{Algorithm, EncodingKey, crypto};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AuthError, Result};
+use crate::policy;
+use crate::token::TokenInfo;
+
+/// Separator of the component and tool names of prefixed tools
+const TOOL_PREFIX_SEPARATOR: &str = "__";
+
+/// Settings of subject pseudonymization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pseudonyms {
+    /// HMAC key of the pseudonyms
+    #[serde(skip_serializing, default)]
+    pub salt: String,
+
+    /// Components that receive raw subjects, in `snake_case`
+    pub allowed_components: Vec<String>,
+}
+
+impl Pseudonyms {
+    /// Create the settings from a salt and a comma-separated component list
+    pub fn new(salt: String, allowed_components: &str) -> Self {
+        let allowed_components = allowed_components
+            .split(',')
+            .map(str::trim)
+            .filter(|component| !component.is_empty())
+            .map(normalize_component)
+            .collect();
+        Self {
+            salt,
+            allowed_components,
+        }
+    }
+
+    /// Whether a request only reaches allowlisted components, and keeps the
+    /// raw subject
+    pub fn exempts(&self, path: &str, body: Option<&[u8]>) -> bool {
+        if self.allowed_components.is_empty() {
+            return false;
+        }
+        target_components(path, body).is_some_and(|components| {
+            components
+                .iter()
+                .all(|component| self.allowed_components.contains(component))
+        })
+    }
+
+    /// Replace the subject of a verified token with its pseudonym, in the
+    /// claims too, and the client ID when it falls back to the subject
+    pub fn apply(&self, token_info: &mut TokenInfo) -> Result<()> {
+        let pseudonym = pseudonym(&self.salt, &token_info.iss, &token_info.sub)?;
+        if token_info.client_id == token_info.sub {
+            token_info.client_id.clone_from(&pseudonym);
+        }
+        token_info.claims.insert(
+            "sub".to_string(),
+            serde_json::Value::String(pseudonym.clone()),
+        );
+        token_info.sub = pseudonym;
+        Ok(())
+    }
+}
+
+/// Pseudonym of a subject of an issuer
+pub fn pseudonym(salt: &str, issuer: &str, subject: &str) -> Result<String> {
+    crypto::sign(
+        format!("{issuer}\n{subject}").as_bytes(),
+        &EncodingKey::from_secret(salt.as_bytes()),
+        Algorithm::HS256,
+    )
+    .map_err(|e| AuthError::Internal(format!("Failed to derive subject pseudonym: {e}")))
+}
+
+/// Components a request reaches, or `None` when it may reach any of them
+fn target_components(path: &str, body: Option<&[u8]>) -> Option<Vec<String>> {
+    if let Some(component) = policy::extract_component_from_path(path) {
+        return Some(vec![normalize_component(&component)]);
+    }
+
+    let messages = match serde_json::from_slice::<serde_json::Value>(body?).ok()? {
+        serde_json::Value::Array(batch) => batch,
+        message => vec![message],
+    };
+    let mut components = Vec::new();
+    for message in &messages {
+        let Some(method) = message.get("method").and_then(serde_json::Value::as_str) else {
+            // Responses sent by the client reach no component
+            continue;
+        };
+        if method != "tools/call" {
+            return None;
+        }
+        let (component, _) = message
+            .pointer("/params/name")
+            .and_then(serde_json::Value::as_str)?
+            .split_once(TOOL_PREFIX_SEPARATOR)?;
+        components.push(normalize_component(component));
+    }
+    (!components.is_empty()).then_some(components)
+}
+
+/// Component names as the gateway prefixes tools with them
+fn normalize_component(component: &str) -> String {
+    component.to_lowercase().replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(tool: &str) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": tool, "arguments": {} }
+        })
+    }
+
+    #[test]
+    fn test_scoped_requests_reach_their_component() {
+        assert_eq!(
+            target_components("/mcp/x/billing-api/readonly", None),
+            Some(vec!["billing_api".to_string()])
+        );
+        assert_eq!(target_components("/mcp", None), None);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_tool_calls_reach_their_components() {
+        let body =
+            serde_json::to_vec(&call("billing_api__charge")).expect("request should serialize");
+        assert_eq!(
+            target_components("/mcp", Some(&body)),
+            Some(vec!["billing_api".to_string()])
+        );
+
+        let batch = serde_json::to_vec(&serde_json::json!([
+            call("billing_api__charge"),
+            call("audit__record"),
+            { "jsonrpc": "2.0", "id": 7, "result": {} }
+        ]))
+        .expect("batch should serialize");
+        assert_eq!(
+            target_components("/mcp", Some(&batch)),
+            Some(vec!["billing_api".to_string(), "audit".to_string()])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_other_requests_may_reach_any_component() {
+        let list = br#"{"jsonrpc": "2.0", "id": 1, "method": "tools/list"}"#;
+        assert_eq!(target_components("/mcp", Some(list)), None);
+
+        let batch = serde_json::to_vec(&serde_json::json!([
+            call("billing_api__charge"),
+            { "jsonrpc": "2.0", "id": 2, "method": "tools/list" }
+        ]))
+        .expect("batch should serialize");
+        assert_eq!(target_components("/mcp", Some(&batch)), None);
+
+        // Unprefixed tools can't be attributed to a component
+        let body = serde_json::to_vec(&call("charge")).expect("request should serialize");
+        assert_eq!(target_components("/mcp", Some(&body)), None);
+        assert_eq!(target_components("/mcp", Some(b"not json")), None);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_exempts_only_allowlisted_components() {
+        let pseudonyms = Pseudonyms::new("salt".to_string(), " Billing-API, ,audit");
+        assert_eq!(pseudonyms.allowed_components, ["billing_api", "audit"]);
+
+        assert!(pseudonyms.exempts("/mcp/x/billing-api", None));
+        assert!(!pseudonyms.exempts("/mcp/x/weather", None));
+        assert!(!pseudonyms.exempts("/mcp", None));
+
+        let body = serde_json::to_vec(&serde_json::json!([
+            call("audit__record"),
+            call("weather__forecast")
+        ]))
+        .expect("batch should serialize");
+        assert!(!pseudonyms.exempts("/mcp", Some(&body)));
+
+        let none = Pseudonyms::new("salt".to_string(), "");
+        assert!(!none.exempts("/mcp/x/billing-api", None));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_pseudonyms_are_stable_and_pairwise() {
+        let a = pseudonym("salt-a", "https://auth.example.com", "user_123")
+            .expect("pseudonym should derive");
+        assert_eq!(a.len(), 43);
+        assert!(!a.contains("user_123"));
+        assert_eq!(
+            a,
+            pseudonym("salt-a", "https://auth.example.com", "user_123")
+                .expect("pseudonym should derive")
+        );
+        assert_ne!(
+            a,
+            pseudonym("salt-b", "https://auth.example.com", "user_123")
+                .expect("pseudonym should derive")
+        );
+        assert_ne!(
+            a,
+            pseudonym("salt-a", "https://other.example.com", "user_123")
+                .expect("pseudonym should derive")
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_apply_replaces_every_copy_of_the_subject() {
+        let pseudonyms = Pseudonyms::new("salt".to_string(), "");
+        let mut token_info = TokenInfo {
+            client_id: "user_123".to_string(),
+            sub: "user_123".to_string(),
+            iss: "https://auth.example.com".to_string(),
+            scopes: vec![],
+            claims: std::collections::HashMap::from([(
+                "sub".to_string(),
+                serde_json::json!("user_123"),
+            )]),
+        };
+        assert!(pseudonyms.apply(&mut token_info).is_ok());
+
+        let expected = pseudonym("salt", "https://auth.example.com", "user_123")
+            .expect("pseudonym should derive");
+        assert_eq!(token_info.sub, expected);
+        assert_eq!(token_info.client_id, expected);
+        assert_eq!(
+            token_info.claims.get("sub"),
+            Some(&serde_json::json!(expected))
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Token revocation deny-list
+//!
+//! Revoked token IDs (`jti`) and subjects (`sub`) are stored in the KV store
+//! and checked after signature verification. Revoking a subject rejects every
+//! token issued to it up to the time of revocation, so tokens issued after a
+//! fresh login keep working. Entries may carry an expiry, after which they are
+//! ignored and removed.
+//!
+//! The deny-list is managed through an admin endpoint at
+//! `/_authorizer/revocations`, which requires a valid token carrying the
+//! configured admin scope.
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::auth;
+use crate::config::{self, Config};
+use crate::error::{AuthError, Result};
+use crate::issuers;
+use crate::token::{self, TokenInfo};
+
+/// Path of the revocation admin endpoint
+pub const ADMIN_PATH: &str = "/_authorizer/revocations";
+
+/// KV key prefix for deny-list entries
+const KEY_PREFIX: &str = "revoked:";
+
+/// What a deny-list entry matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    /// A single token, by its `jti` claim
+    Jti,
+    /// Every token issued to a subject before the revocation
+    Sub,
+}
+
+impl Kind {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Jti => "jti",
+            Self::Sub => "sub",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "jti" => Some(Self::Jti),
+            "sub" => Some(Self::Sub),
+            _ => None,
+        }
+    }
+}
+
+/// A deny-list entry as stored in the KV store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// Unix time the entry was added
+    revoked_at: u64,
+
+    /// Unix time after which the entry is ignored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<u64>,
+
+    /// Free-form reason recorded by the administrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+/// Body of a revocation request
+#[derive(Debug, Deserialize)]
+struct RevokeRequest {
+    #[serde(rename = "type")]
+    kind: Kind,
+    value: String,
+    expires_at: Option<u64>,
+    reason: Option<String>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn entry_key(kind: Kind, value: &str) -> String {
+    format!("{KEY_PREFIX}{}:{value}", kind.as_str())
+}
+
+/// Load an entry, deleting it if it has expired
+fn load_entry(store: &Store, key: &str, now: u64) -> Option<Entry> {
+    let data = store.get(key).ok().flatten()?;
+    let entry = serde_json::from_slice::<Entry>(&data).ok()?;
+    if entry.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        let _ = store.delete(key);
+        return None;
+    }
+    Some(entry)
+}
+
+/// Reject tokens on the deny-list
+pub fn check(token_info: &TokenInfo, store: &Store) -> Result<()> {
+    let now = now();
+
+    if let Some(jti) = token_info.claims.get("jti").and_then(|v| v.as_str())
+        && load_entry(store, &entry_key(Kind::Jti, jti), now).is_some()
+    {
+        return Err(AuthError::InvalidToken(
+            "Token has been revoked".to_string(),
+        ));
+    }
+
+    if let Some(entry) = load_entry(store, &entry_key(Kind::Sub, &token_info.sub), now) {
+        // Tokens without iat cannot prove they were issued after the revocation
+        let issued_at = token_info
+            .claims
+            .get("iat")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        if issued_at <= entry.revoked_at {
+            return Err(AuthError::InvalidToken(
+                "Token has been revoked".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Open the default KV store, mapping failures to an internal error
+pub fn open_store() -> Result<Store> {
+    Store::open_default().map_err(|e| {
+        log::error!("Failed to open KV store: {e}");
+        log::error!(
+            "HINT: Ensure the mcp-authorizer component has 'key_value_stores = [\"default\"]' in spin.toml"
+        );
+        AuthError::Internal(
+            "KV store access denied. Ensure component has key_value_stores permission in spin.toml"
+                .to_string(),
+        )
+    })
+}
+
+/// Handle requests to the revocation admin endpoint.
+///
+/// Returns `None` when the endpoint is disabled or the path does not match,
+/// so the request is handled like any other.
+pub async fn handle_admin(req: &Request, config: &Config) -> Option<Result<Response>> {
+    let path = config.resource_path(req.path());
+    let rest = path.strip_prefix(ADMIN_PATH)?;
+    if !(rest.is_empty() || rest.starts_with('/')) {
+        return None;
+    }
+    let admin_scope = config.revocation_admin_scope.as_deref()?;
+    if !config.revocation_enabled {
+        return None;
+    }
+
+    Some(handle_admin_request(req, config, admin_scope, rest).await)
+}
+
+async fn handle_admin_request(
+    req: &Request,
+    config: &Config,
+    admin_scope: &str,
+    rest: &str,
+) -> Result<Response> {
+    let store = open_store()?;
+    authorize_admin(req, config, admin_scope, &store).await?;
+
+    match (req.method(), rest.trim_start_matches('/')) {
+        (Method::Get, "") => list_entries(&store),
+        (Method::Post, "") => add_entry(req, &store),
+        (Method::Delete, target) if !target.is_empty() => remove_entry(target, &store),
+        _ => Ok(json_response(
+            405,
+            &serde_json::json!({
+                "error": "method_not_allowed",
+                "error_description": format!("{} is not supported on {}", req.method(), req.path())
+            }),
+        )),
+    }
+}
+
+/// Verify the caller's token and require the admin scope
+async fn authorize_admin(
+    req: &Request,
+    config: &Config,
+    admin_scope: &str,
+    store: &Store,
+) -> Result<()> {
+    let token = auth::extract_bearer_token(req)?;
+    let provider = config.provider.as_ref().ok_or_else(|| {
+        AuthError::Unauthorized("No authentication provider configured".to_string())
+    })?;
+    let token_info = match provider {
+        config::Provider::Jwt(jwt_provider) => {
+            let jwt_provider =
+                issuers::resolve(token, jwt_provider, config.oidc_refresh_interval).await?;
+            token::verify(token, &jwt_provider, &config.token_timing, store).await?
+        }
+    };
+    check(&token_info, store)?;
+
+    if !token_info.scopes.iter().any(|s| s == admin_scope) {
+        return Err(AuthError::Unauthorized(format!(
+            "Token missing required scopes: [\"{admin_scope}\"]"
+        )));
+    }
+    Ok(())
+}
+
+fn list_entries(store: &Store) -> Result<Response> {
+    let now = now();
+    let keys = store
+        .get_keys()
+        .map_err(|e| AuthError::Internal(format!("Failed to list revocations: {e}")))?;
+
+    let mut entries = Vec::new();
+    for key in keys {
+        let Some((kind, value)) = key
+            .strip_prefix(KEY_PREFIX)
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(kind, value)| Kind::parse(kind).map(|kind| (kind, value.to_string())))
+        else {
+            continue;
+        };
+        if let Some(entry) = load_entry(store, &key, now) {
+            entries.push(serde_json::json!({
+                "type": kind,
+                "value": value,
+                "revoked_at": entry.revoked_at,
+                "expires_at": entry.expires_at,
+                "reason": entry.reason,
+            }));
+        }
+    }
+
+    Ok(json_response(
+        200,
+        &serde_json::json!({ "revocations": entries }),
+    ))
+}
+
+fn add_entry(req: &Request, store: &Store) -> Result<Response> {
+    let request = match serde_json::from_slice::<RevokeRequest>(req.body()) {
+        Ok(request) if !request.value.trim().is_empty() => request,
+        Ok(_) => return Ok(invalid_request("value must not be empty")),
+        Err(e) => return Ok(invalid_request(&format!("Invalid revocation request: {e}"))),
+    };
+
+    let entry = Entry {
+        revoked_at: now(),
+        expires_at: request.expires_at,
+        reason: request.reason,
+    };
+    let data = serde_json::to_vec(&entry)
+        .map_err(|e| AuthError::Internal(format!("Failed to encode revocation: {e}")))?;
+    store
+        .set(&entry_key(request.kind, &request.value), &data)
+        .map_err(|e| AuthError::Internal(format!("Failed to store revocation: {e}")))?;
+
+    log::info!("Revoked {} {}", request.kind.as_str(), request.value);
+
+    Ok(json_response(
+        201,
+        &serde_json::json!({
+            "type": request.kind,
+            "value": request.value,
+            "revoked_at": entry.revoked_at,
+            "expires_at": entry.expires_at,
+            "reason": entry.reason,
+        }),
+    ))
+}
+
+fn remove_entry(target: &str, store: &Store) -> Result<Response> {
+    let Some((kind, value)) = target
+        .split_once('/')
+        .and_then(|(kind, value)| Kind::parse(kind).map(|kind| (kind, value)))
+        .filter(|(_, value)| !value.is_empty())
+    else {
+        return Ok(json_response(
+            404,
+            &serde_json::json!({
+                "error": "not_found",
+                "error_description": "Expected /_authorizer/revocations/{jti|sub}/{value}"
+            }),
+        ));
+    };
+
+    let key = entry_key(kind, value);
+    let existed = store.exists(&key).unwrap_or(false);
+    store
+        .delete(&key)
+        .map_err(|e| AuthError::Internal(format!("Failed to delete revocation: {e}")))?;
+
+    if existed {
+        log::info!("Removed revocation for {} {value}", kind.as_str());
+        Ok(Response::builder()
+            .status(204)
+            .header("access-control-allow-origin", "*")
+            .build())
+    } else {
+        Ok(json_response(
+            404,
+            &serde_json::json!({
+                "error": "not_found",
+                "error_description": format!("No revocation for {} {value}", kind.as_str())
+            }),
+        ))
+    }
+}
+
+fn invalid_request(description: &str) -> Response {
+    json_response(
+        400,
+        &serde_json::json!({
+            "error": "invalid_request",
+            "error_description": description
+        }),
+    )
+}
+
+fn json_response(status: u16, body: &serde_json::Value) -> Response {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .header("access-control-allow-origin", "*")
+        .body(body.to_string())
+        .build()
+}
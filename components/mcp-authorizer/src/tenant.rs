@@ -0,0 +1,282 @@
+//! Tenant resolution for multi-tenant deployments
+//!
+//! One authorizer can serve several customer tenants, each with its own
+//! issuer, audience and policy. The tenant is resolved from the request host
+//! or the first segment of its path, and its settings replace the provider and
+//! policy of the loaded configuration for that request.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use spin_sdk::http::Request;
+use spin_sdk::key_value::Store;
+
+use crate::config::{
+    self, Config, IssuerKeys, JwtProvider, OAuthEndpoints, PolicyAuthorization, Provider,
+    TenantResolution, TenantSource,
+};
+use crate::error::{AuthError, Result};
+
+/// Configuration of a single tenant
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TenantConfig {
+    /// Hosts served for the tenant (host resolution only)
+    #[serde(default)]
+    pub hosts: Vec<String>,
+
+    /// JWT issuer (empty disables issuer validation)
+    #[serde(default)]
+    pub issuer: String,
+
+    /// JWKS URI for key discovery
+    pub jwks_uri: Option<String>,
+
+    /// Static public key (PEM format)
+    pub public_key: Option<String>,
+
+    /// Expected audience(s), required
+    #[serde(default)]
+    pub audience: Vec<String>,
+
+    /// JWT signing algorithm (defaults to RS256)
+    pub algorithm: Option<String>,
+
+    /// Required scopes for all requests
+    #[serde(default)]
+    pub required_scopes: Vec<String>,
+
+    /// OAuth 2.0 endpoints
+    pub oauth_endpoints: Option<OAuthEndpoints>,
+
+    /// Bootstrap the JWKS URI and OAuth endpoints from OIDC discovery
+    #[serde(default)]
+    pub oidc_discovery: bool,
+
+    /// Rego policy, replacing `mcp_policy` for the tenant
+    pub policy: Option<String>,
+
+    /// Policy data, replacing `mcp_policy_data` for the tenant
+    pub policy_data: Option<serde_json::Value>,
+}
+
+/// Apply the configuration of the tenant a request is for
+///
+/// Does nothing in single-tenant deployments. Requests that match no tenant
+/// are rejected rather than falling back to the default provider.
+pub fn apply(req: &Request, config: &mut Config) -> Result<()> {
+    let Some(source) = &config.tenants else {
+        return Ok(());
+    };
+    let tenants = load(source)?;
+
+    let (id, tenant) = match config.tenant_resolution {
+        TenantResolution::Host => {
+            let host = crate::extract_host(req)
+                .ok_or_else(|| AuthError::UnknownTenant("Missing host header".to_string()))?;
+            find_by_host(tenants, &host)
+                .ok_or_else(|| AuthError::UnknownTenant(format!("No tenant for host {host}")))?
+        }
+        TenantResolution::Path => {
+            let segment = tenant_segment(req.path()).unwrap_or_default();
+            tenants
+                .into_iter()
+                .find(|(id, _)| id == segment)
+                .ok_or_else(|| AuthError::UnknownTenant(format!("No tenant at /{segment}")))?
+        }
+    };
+
+    config.provider = Some(Provider::Jwt(tenant.provider(&id)?));
+    if let Some(policy) = tenant.policy.filter(|p| !p.is_empty()) {
+        config.authorization = Some(PolicyAuthorization {
+            policy,
+            data: tenant.policy_data.map(|data| data.to_string()),
+        });
+    }
+    if config.tenant_resolution == TenantResolution::Path {
+        config.path_prefix = format!("/{id}");
+    }
+    config.tenant = Some(id);
+    Ok(())
+}
+
+/// Load the tenant configuration from its source
+fn load(source: &TenantSource) -> Result<HashMap<String, TenantConfig>> {
+    let json = match source {
+        TenantSource::Inline(json) => json.as_bytes().to_vec(),
+        TenantSource::KeyValue(key) => {
+            let store = Store::open_default()?;
+            store.get(key)?.ok_or_else(|| {
+                AuthError::Configuration(format!(
+                    "Tenant configuration {key} not found in the key-value store"
+                ))
+            })?
+        }
+    };
+
+    serde_json::from_slice(&json)
+        .map_err(|e| AuthError::Configuration(format!("Invalid tenant configuration: {e}")))
+}
+
+/// Find the tenant serving a host, with or without its port
+fn find_by_host(
+    tenants: HashMap<String, TenantConfig>,
+    host: &str,
+) -> Option<(String, TenantConfig)> {
+    let hostname = host.split(':').next().unwrap_or(host);
+    tenants.into_iter().find(|(_, tenant)| {
+        tenant
+            .hosts
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case(host) || h.eq_ignore_ascii_case(hostname))
+    })
+}
+
+/// First segment of the resource path, which names the tenant in path
+/// resolution. Discovery requests carry the resource path after the
+/// well-known prefix (`/.well-known/oauth-protected-resource/{tenant}/mcp`).
+fn tenant_segment(path: &str) -> Option<&str> {
+    let path = match path.strip_prefix("/.well-known/") {
+        Some(rest) => rest.split_once('/').map(|(_, resource)| resource)?,
+        None => path.strip_prefix('/')?,
+    };
+    path.split('/').next().filter(|segment| !segment.is_empty())
+}
+
+impl TenantConfig {
+    /// Build and validate the tenant's JWT provider
+    fn provider(&self, id: &str) -> Result<JwtProvider> {
+        let invalid = |msg: String| AuthError::Configuration(format!("Tenant {id}: {msg}"));
+
+        let issuer = if self.issuer.is_empty() {
+            String::new()
+        } else {
+            config::normalize_issuer(self.issuer.clone()).map_err(|e| invalid(e.to_string()))?
+        };
+
+        let public_key = self.public_key.clone().filter(|key| !key.is_empty());
+        let jwks_uri = self
+            .jwks_uri
+            .clone()
+            .filter(|uri| !uri.is_empty())
+            .or_else(|| {
+                if public_key.is_none() {
+                    config::derive_jwks_uri(&issuer)
+                } else {
+                    None
+                }
+            })
+            .map(|uri| config::normalize_url(&uri))
+            .transpose()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        if self.oidc_discovery && !issuer.starts_with("https://") {
+            return Err(invalid(
+                "oidc_discovery requires issuer to be an HTTPS URL".to_string(),
+            ));
+        }
+        if jwks_uri.is_none() && public_key.is_none() && !self.oidc_discovery {
+            return Err(invalid(
+                "Either jwks_uri or public_key must be provided".to_string(),
+            ));
+        }
+        if jwks_uri.is_some() && public_key.is_some() {
+            return Err(invalid(
+                "Cannot specify both jwks_uri and public_key".to_string(),
+            ));
+        }
+
+        let audience: Vec<String> = self
+            .audience
+            .iter()
+            .map(|aud| aud.trim().to_string())
+            .filter(|aud| !aud.is_empty())
+            .collect();
+        if audience.is_empty() {
+            return Err(invalid("audience is required".to_string()));
+        }
+
+        if let Some(alg) = &self.algorithm
+            && !config::SUPPORTED_ALGORITHMS.contains(&alg.as_str())
+        {
+            return Err(invalid(format!("Unsupported algorithm: {alg}")));
+        }
+
+        let oauth_endpoints = self
+            .oauth_endpoints
+            .as_ref()
+            .map(|endpoints| {
+                let normalize = |url: Option<&str>| {
+                    url.filter(|url| !url.is_empty())
+                        .map(config::normalize_url)
+                        .transpose()
+                };
+                Ok::<_, anyhow::Error>(OAuthEndpoints {
+                    authorize: normalize(endpoints.authorize.as_deref())?,
+                    token: normalize(endpoints.token.as_deref())?,
+                    userinfo: normalize(endpoints.userinfo.as_deref())?,
+                })
+            })
+            .transpose()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        Ok(JwtProvider {
+            issuer,
+            jwks_uri,
+            public_key,
+            secret: None,
+            audience: Some(audience),
+            algorithm: self.algorithm.clone(),
+            required_scopes: (!self.required_scopes.is_empty())
+                .then(|| self.required_scopes.clone()),
+            oauth_endpoints,
+            oidc_discovery: self.oidc_discovery,
+            issuers: Vec::new(),
+            issuer_keys: IssuerKeys::Shared,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tenant_segment() {
+        assert_eq!(tenant_segment("/acme/mcp"), Some("acme"));
+        assert_eq!(tenant_segment("/acme"), Some("acme"));
+        assert_eq!(tenant_segment("/"), None);
+        assert_eq!(
+            tenant_segment("/.well-known/oauth-protected-resource/acme/mcp"),
+            Some("acme")
+        );
+        assert_eq!(
+            tenant_segment("/.well-known/oauth-authorization-server/acme"),
+            Some("acme")
+        );
+        assert_eq!(tenant_segment("/.well-known/openid-configuration"), None);
+    }
+
+    #[test]
+    fn test_find_by_host() {
+        let tenants: HashMap<String, TenantConfig> = HashMap::from([(
+            "acme".to_string(),
+            TenantConfig {
+                hosts: vec!["acme.example.com".to_string()],
+                issuer: String::new(),
+                jwks_uri: None,
+                public_key: None,
+                audience: Vec::new(),
+                algorithm: None,
+                required_scopes: Vec::new(),
+                oauth_endpoints: None,
+                oidc_discovery: false,
+                policy: None,
+                policy_data: None,
+            },
+        )]);
+
+        assert!(find_by_host(tenants.clone(), "ACME.example.com:8443").is_some());
+        assert!(find_by_host(tenants, "globex.example.com").is_none());
+    }
+}
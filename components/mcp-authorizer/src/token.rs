@@ -1,15 +1,17 @@
 //! JWT token verification with JWKS support
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
 use serde::{Deserialize, Serialize};
 use spin_sdk::key_value::Store;
 
-use crate::config::JwtProvider;
+use crate::config::{JwtProvider, NbfPolicy, TokenTiming};
 use crate::error::{AuthError, Result};
 use crate::jwks;
 
 /// Token information extracted from a verified JWT
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenInfo {
     /// Client ID (from `client_id` claim or sub)
     pub client_id: String,
@@ -77,13 +79,21 @@ enum ScopeValue {
 
 /// Verify a JWT token using the provided configuration
 #[allow(clippy::too_many_lines)]
-pub async fn verify(token: &str, provider: &JwtProvider, store: &Store) -> Result<TokenInfo> {
+pub async fn verify(
+    token: &str,
+    provider: &JwtProvider,
+    timing: &TokenTiming,
+    store: &Store,
+) -> Result<TokenInfo> {
     // Decode header to get KID if present
     let header = decode_header(token)?;
     let kid = header.kid.as_deref();
 
     // Get decoding key
-    let decoding_key = if let Some(public_key) = &provider.public_key {
+    let decoding_key = if let Some(secret) = &provider.secret {
+        // Shared key of development tokens
+        DecodingKey::from_secret(secret.as_bytes())
+    } else if let Some(public_key) = &provider.public_key {
         // Use static public key
         DecodingKey::from_rsa_pem(public_key.as_bytes())
             .map_err(|e| AuthError::Configuration(format!("Invalid public key: {e}")))?
@@ -133,16 +143,20 @@ pub async fn verify(token: &str, provider: &JwtProvider, store: &Store) -> Resul
         ));
     }
 
-    // Enable nbf (not before) validation if present in token
-    validation.validate_nbf = true;
+    // Enable nbf (not before) validation unless disabled, the claim is only
+    // checked when present unless it is required
+    validation.validate_nbf = timing.nbf != NbfPolicy::Ignore;
 
-    // Add leeway for clock skew tolerance (60 seconds is reasonable for distributed systems)
-    // This helps with slight time differences between the token issuer and validator
-    validation.leeway = 60;
+    // Add leeway for clock skew tolerance between the token issuer and validator
+    validation.leeway = timing.clock_skew;
 
     // Set required claims - we always require exp (default), sub, and iss
     // The jsonwebtoken library will ensure these claims are present before validation
-    validation.set_required_spec_claims(&["exp", "sub", "iss"]);
+    if timing.nbf == NbfPolicy::Require {
+        validation.set_required_spec_claims(&["exp", "sub", "iss", "nbf"]);
+    } else {
+        validation.set_required_spec_claims(&["exp", "sub", "iss"]);
+    }
 
     // Decode and validate token
     let token_data = match decode::<Claims>(token, &decoding_key, &validation) {
@@ -151,6 +165,9 @@ pub async fn verify(token: &str, provider: &JwtProvider, store: &Store) -> Resul
             // Provide more specific error messages for common issues
             return match e.kind() {
                 jsonwebtoken::errors::ErrorKind::ExpiredSignature => Err(AuthError::ExpiredToken),
+                jsonwebtoken::errors::ErrorKind::ImmatureSignature => {
+                    Err(AuthError::TokenNotYetValid)
+                }
                 jsonwebtoken::errors::ErrorKind::InvalidIssuer => Err(AuthError::InvalidIssuer),
                 jsonwebtoken::errors::ErrorKind::InvalidAudience => Err(AuthError::InvalidAudience),
                 jsonwebtoken::errors::ErrorKind::InvalidSignature => {
@@ -162,6 +179,11 @@ pub async fn verify(token: &str, provider: &JwtProvider, store: &Store) -> Resul
     };
     let claims = token_data.claims;
 
+    // Reject tokens older than the maximum token age, regardless of exp
+    if let Some(max_age) = timing.max_token_age {
+        check_token_age(claims.iat, max_age, timing.clock_skew)?;
+    }
+
     // Extract scopes
     let scopes = extract_scopes(&claims);
 
@@ -236,6 +258,24 @@ pub async fn verify(token: &str, provider: &JwtProvider, store: &Store) -> Resul
     })
 }
 
+/// Check the age of a token from its `iat` claim
+fn check_token_age(iat: i64, max_age: u64, clock_skew: u64) -> Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let now = i64::try_from(now).unwrap_or(i64::MAX);
+    let clock_skew = i64::try_from(clock_skew).unwrap_or(i64::MAX);
+    let max_age = i64::try_from(max_age).unwrap_or(i64::MAX);
+
+    if iat > now.saturating_add(clock_skew) {
+        return Err(AuthError::TokenIssuedInFuture);
+    }
+    if now.saturating_sub(iat) > max_age.saturating_add(clock_skew) {
+        return Err(AuthError::TokenTooOld);
+    }
+    Ok(())
+}
+
 /// Extract scopes from claims
 fn extract_scopes(claims: &Claims) -> Vec<String> {
     // OAuth2 'scope' claim takes precedence
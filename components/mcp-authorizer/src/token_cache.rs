@@ -0,0 +1,223 @@
+//! Short-lived cache of verified tokens
+//!
+//! Chatty MCP clients send many requests with the same token, each of which
+//! would otherwise verify its signature and claims again. With
+//! `mcp_token_cache_ttl` set, successful verifications are kept in the KV
+//! store and reused for at most that many seconds, and never past the token's
+//! `exp` or its maximum age. Entries are keyed by the unpadded base64url
+//! HMAC-SHA256 of the token, keyed with the provider and token timing
+//! settings, so raw tokens are never stored and a configuration change
+//! invalidates the cache. Failed verifications are not cached, and revocation
+//! is still checked on every request.
+//!
+//! `mcp_strict_token_verification` disables the cache regardless of the TTL.
+
+use jsonwebtoken::{Algorithm, EncodingKey, crypto};
+use serde::{Deserialize, Serialize};
+use spin_sdk::key_value::Store;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::{Config, JwtProvider, TokenTiming};
+use crate::token::TokenInfo;
+
+/// KV key prefix for cached verifications
+const KEY_PREFIX: &str = "verified:";
+
+/// A cached verification as stored in the KV store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// Unix time after which the token must be verified again
+    expires_at: u64,
+
+    /// Result of the verification
+    token_info: TokenInfo,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Cached verification of a token, if any and still fresh
+pub fn get(
+    token: &str,
+    provider: &JwtProvider,
+    config: &Config,
+    store: &Store,
+) -> Option<TokenInfo> {
+    if config.token_cache_ttl == 0 {
+        return None;
+    }
+    let key = cache_key(token, provider, &config.token_timing)?;
+    let data = store.get(&key).ok().flatten()?;
+
+    match serde_json::from_slice::<Entry>(&data) {
+        Ok(entry) if entry.expires_at > now() => {
+            log::debug!(
+                "Using cached verification of token of {}",
+                entry.token_info.sub
+            );
+            Some(entry.token_info)
+        }
+        _ => {
+            let _ = store.delete(&key);
+            None
+        }
+    }
+}
+
+/// Cache a successful verification of a token
+pub fn put(
+    token: &str,
+    provider: &JwtProvider,
+    config: &Config,
+    store: &Store,
+    token_info: &TokenInfo,
+) {
+    if config.token_cache_ttl == 0 {
+        return;
+    }
+    let now = now();
+    let expires_at = entry_expiry(
+        token_info,
+        &config.token_timing,
+        config.token_cache_ttl,
+        now,
+    );
+    if expires_at <= now {
+        return;
+    }
+    let Some(key) = cache_key(token, provider, &config.token_timing) else {
+        return;
+    };
+
+    let entry = Entry {
+        expires_at,
+        token_info: token_info.clone(),
+    };
+    match serde_json::to_vec(&entry) {
+        Ok(data) => {
+            if let Err(e) = store.set(&key, &data) {
+                log::warn!("Failed to cache token verification: {e}");
+            }
+        }
+        Err(e) => log::warn!("Failed to encode token verification: {e}"),
+    }
+}
+
+/// KV key of the cached verification of a token under a provider
+fn cache_key(token: &str, provider: &JwtProvider, timing: &TokenTiming) -> Option<String> {
+    // The secret is not serialized with the provider, so it is added separately
+    let mut settings = serde_json::to_vec(&(provider, timing)).ok()?;
+    settings.extend_from_slice(provider.secret.as_deref().unwrap_or_default().as_bytes());
+
+    let mac = crypto::sign(
+        token.as_bytes(),
+        &EncodingKey::from_secret(&settings),
+        Algorithm::HS256,
+    )
+    .ok()?;
+    Some(format!("{KEY_PREFIX}{mac}"))
+}
+
+/// Unix time until which a verification may be reused: `ttl` seconds from
+/// `now`, but no later than the token expires or reaches its maximum age
+fn entry_expiry(token_info: &TokenInfo, timing: &TokenTiming, ttl: u64, now: u64) -> u64 {
+    let claim = |name: &str| {
+        token_info
+            .claims
+            .get(name)
+            .and_then(serde_json::Value::as_u64)
+    };
+
+    let mut expires_at = now.saturating_add(ttl);
+    if let Some(exp) = claim("exp") {
+        expires_at = expires_at.min(exp);
+    }
+    if let (Some(max_age), Some(iat)) = (timing.max_token_age, claim("iat")) {
+        expires_at = expires_at.min(iat.saturating_add(max_age));
+    }
+    expires_at
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn token_info(claims: &[(&str, serde_json::Value)]) -> TokenInfo {
+        TokenInfo {
+            client_id: "client".to_string(),
+            sub: "user123".to_string(),
+            iss: "https://auth.example.com".to_string(),
+            scopes: vec![],
+            claims: claims
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), value.clone()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_entry_expiry_respects_token_lifetime() {
+        let timing = TokenTiming::default();
+
+        let long_lived = token_info(&[("exp", json!(10_000))]);
+        assert_eq!(entry_expiry(&long_lived, &timing, 30, 1_000), 1_030);
+
+        let expiring = token_info(&[("exp", json!(1_010))]);
+        assert_eq!(entry_expiry(&expiring, &timing, 30, 1_000), 1_010);
+
+        let aging = token_info(&[("exp", json!(10_000)), ("iat", json!(900))]);
+        let max_age = TokenTiming {
+            max_token_age: Some(105),
+            ..timing
+        };
+        assert_eq!(entry_expiry(&aging, &max_age, 30, 1_000), 1_005);
+        assert_eq!(entry_expiry(&aging, &timing, 30, 1_000), 1_030);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_cache_key_depends_on_token_and_settings() {
+        let provider: JwtProvider = serde_json::from_value(json!({
+            "jwks_uri": "https://auth.example.com/.well-known/jwks.json",
+            "issuer": "https://auth.example.com",
+        }))
+        .expect("provider should deserialize");
+        let timing = TokenTiming::default();
+
+        let key = cache_key("token-a", &provider, &timing).expect("cache key should derive");
+        assert!(key.starts_with(KEY_PREFIX));
+        assert!(!key.contains("token-a"));
+        assert_eq!(
+            cache_key("token-a", &provider, &timing).expect("cache key should derive"),
+            key
+        );
+        assert_ne!(
+            cache_key("token-b", &provider, &timing).expect("cache key should derive"),
+            key
+        );
+
+        let strict = TokenTiming {
+            clock_skew: 0,
+            ..timing
+        };
+        assert_ne!(
+            cache_key("token-a", &provider, &strict).expect("cache key should derive"),
+            key
+        );
+
+        let other_secret = JwtProvider {
+            secret: Some("other".to_string()),
+            ..provider
+        };
+        assert_ne!(
+            cache_key("token-a", &other_secret, &timing).expect("cache key should derive"),
+            key
+        );
+    }
+}
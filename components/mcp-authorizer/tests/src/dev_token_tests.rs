@@ -0,0 +1,176 @@
+// Development token tests
+
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+const DEV_SECRET: &str = "dev-secret-0123456789abcdef0123456789";
+
+fn setup_dev_tokens() {
+    setup_default_test_config();
+    variables::set("mcp_dev_tokens", "true");
+    variables::set("mcp_dev_token_secret", DEV_SECRET);
+}
+
+fn request(
+    method: http::types::Method,
+    path: &str,
+    token: Option<&str>,
+    body: Option<&str>,
+) -> ResponseData {
+    let headers = http::types::Headers::new();
+    if let Some(token) = token {
+        headers
+            .append("authorization", format!("Bearer {token}").as_bytes())
+            .unwrap();
+    }
+    if body.is_some() {
+        headers.append("content-type", b"application/json").unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    if let Some(body) = body {
+        let outgoing = request.body().unwrap();
+        outgoing.write_bytes(body.as_bytes());
+    }
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn issue_token(body: &str) -> ResponseData {
+    request(http::types::Method::Post, "/dev/token", None, Some(body))
+}
+
+fn access_token(response: &ResponseData) -> String {
+    assert_eq!(response.status, 200, "Token request should succeed");
+    response.body_json().unwrap()["access_token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn decode_claims(token: &str) -> serde_json::Value {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.set_audience(&["test-audience"]);
+    decode::<serde_json::Value>(
+        token,
+        &DecodingKey::from_secret(DEV_SECRET.as_bytes()),
+        &validation,
+    )
+    .unwrap()
+    .claims
+}
+
+#[spin_test]
+fn test_dev_tokens_disabled_by_default() {
+    setup_default_test_config();
+    let response = issue_token("{}");
+    assert_eq!(
+        response.status, 401,
+        "The token endpoint should not exist unless enabled"
+    );
+}
+
+#[spin_test]
+fn test_issued_token_is_accepted() {
+    setup_dev_tokens();
+    let response = issue_token(r#"{"sub": "alice", "scope": "read write", "ttl": 60}"#);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["token_type"], "Bearer");
+    assert_eq!(body["expires_in"], 60);
+    assert_eq!(body["scope"], "read write");
+    let token = access_token(&response);
+
+    let claims = decode_claims(&token);
+    assert_eq!(claims["iss"], "ftl-dev");
+    assert_eq!(claims["sub"], "alice");
+    assert_eq!(claims["scope"], "read write");
+
+    let response = request(http::types::Method::Get, "/mcp", Some(&token), None);
+    assert_eq!(response.status, 200, "Dev tokens should be accepted");
+}
+
+#[spin_test]
+fn test_extra_claims_are_included() {
+    setup_dev_tokens();
+    let token = access_token(&issue_token(
+        r#"{"claims": {"client_id": "inspector", "org_id": "org_1"}}"#,
+    ));
+
+    let claims = decode_claims(&token);
+    assert_eq!(claims["sub"], "dev-user");
+    assert_eq!(claims["client_id"], "inspector");
+    assert_eq!(claims["org_id"], "org_1");
+}
+
+#[spin_test]
+fn test_required_scopes_apply_to_dev_tokens() {
+    setup_dev_tokens();
+    variables::set("mcp_jwt_required_scopes", "read");
+
+    let token = access_token(&issue_token(r#"{"scope": "write"}"#));
+    let response = request(http::types::Method::Get, "/mcp", Some(&token), None);
+    assert_eq!(response.status, 401, "Missing required scopes should fail");
+
+    let token = access_token(&issue_token(r#"{"scope": "read"}"#));
+    let response = request(http::types::Method::Get, "/mcp", Some(&token), None);
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_token_signed_with_other_key_is_rejected() {
+    setup_dev_tokens();
+    let now = chrono::Utc::now().timestamp();
+    let forged = jsonwebtoken::encode(
+        &Header::new(Algorithm::HS256),
+        &json!({
+            "iss": "ftl-dev",
+            "aud": "test-audience",
+            "sub": "mallory",
+            "iat": now,
+            "exp": now + 60,
+        }),
+        &EncodingKey::from_secret(b"some-other-secret-0123456789abcdef"),
+    )
+    .unwrap();
+
+    let response = request(http::types::Method::Get, "/mcp", Some(&forged), None);
+    assert_eq!(response.status, 401);
+}
+
+#[spin_test]
+fn test_invalid_token_requests() {
+    setup_dev_tokens();
+    variables::set("mcp_dev_token_ttl", "300");
+
+    let response = issue_token(r#"{"ttl": 301}"#);
+    assert_eq!(response.status, 400, "TTL above the maximum should fail");
+    assert_eq!(response.body_json().unwrap()["error"], "invalid_request");
+
+    let response = issue_token(r#"{"claims": {"iss": "https://evil.example.com"}}"#);
+    assert_eq!(response.status, 400, "Reserved claims should be rejected");
+
+    let response = issue_token(r#"{"scopes": ["read"]}"#);
+    assert_eq!(response.status, 400, "Unknown fields should be rejected");
+
+    let response = request(http::types::Method::Get, "/dev/token", None, None);
+    assert_eq!(response.status, 405);
+}
+
+#[spin_test]
+fn test_dev_tokens_require_a_strong_secret() {
+    setup_dev_tokens();
+    variables::set("mcp_dev_token_secret", "short");
+
+    let response = issue_token("{}");
+    assert_eq!(
+        response.status, 500,
+        "A short secret is a configuration error"
+    );
+}
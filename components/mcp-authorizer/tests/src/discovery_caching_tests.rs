@@ -0,0 +1,147 @@
+use crate::ResponseData;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+/// Configure an AuthKit provider for discovery tests
+fn setup_provider() {
+    variables::set("mcp_gateway_url", "none");
+    variables::set("mcp_jwt_issuer", "https://test.authkit.app");
+    variables::set("mcp_jwt_audience", "test-audience");
+}
+
+/// Request a discovery document, optionally with an If-None-Match header
+fn get_discovery(path: &str, if_none_match: Option<&str>) -> ResponseData {
+    let headers = http::types::Headers::new();
+    if let Some(etag) = if_none_match {
+        headers.append("if-none-match", etag.as_bytes()).unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some(path)).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn header_str(response: &ResponseData, name: &str) -> Option<String> {
+    response
+        .find_header(name)
+        .map(|v| String::from_utf8_lossy(v).to_string())
+}
+
+// Test: discovery documents carry ETag and Cache-Control headers
+#[spin_test]
+fn test_discovery_cache_headers() {
+    setup_provider();
+
+    for path in [
+        "/.well-known/oauth-authorization-server",
+        "/.well-known/openid-configuration",
+        "/.well-known/oauth-protected-resource",
+    ] {
+        let response = get_discovery(path, None);
+        assert_eq!(response.status, 200, "{path} should return 200");
+        assert!(
+            header_str(&response, "etag").is_some(),
+            "{path} should return an ETag"
+        );
+        assert_eq!(
+            header_str(&response, "cache-control").as_deref(),
+            Some("public, max-age=3600"),
+            "{path} should default to a one hour max-age"
+        );
+    }
+}
+
+// Test: repeated requests are served from the cache with a stable ETag
+#[spin_test]
+fn test_discovery_cached_document_is_stable() {
+    setup_provider();
+
+    let first = get_discovery("/.well-known/openid-configuration", None);
+    let second = get_discovery("/.well-known/openid-configuration", None);
+
+    assert_eq!(first.status, 200);
+    assert_eq!(second.status, 200);
+    assert_eq!(header_str(&first, "etag"), header_str(&second, "etag"));
+    assert_eq!(first.body, second.body);
+}
+
+// Test: a matching If-None-Match returns 304 without a body
+#[spin_test]
+fn test_discovery_if_none_match_not_modified() {
+    setup_provider();
+
+    let first = get_discovery("/.well-known/oauth-authorization-server", None);
+    let etag = header_str(&first, "etag").expect("ETag header");
+
+    let revalidated = get_discovery("/.well-known/oauth-authorization-server", Some(&etag));
+    assert_eq!(revalidated.status, 304);
+    assert!(revalidated.body.is_empty());
+    assert_eq!(header_str(&revalidated, "etag"), Some(etag.clone()));
+
+    // Weak comparison and lists of tags are accepted
+    let weak = format!("\"other\", W/{etag}");
+    let revalidated = get_discovery("/.well-known/oauth-authorization-server", Some(&weak));
+    assert_eq!(revalidated.status, 304);
+}
+
+// Test: a stale ETag returns the full document
+#[spin_test]
+fn test_discovery_stale_etag_returns_document() {
+    setup_provider();
+
+    let response = get_discovery(
+        "/.well-known/oauth-authorization-server",
+        Some("\"0000000000000000\""),
+    );
+    assert_eq!(response.status, 200);
+
+    let json = response.body_json().expect("valid JSON");
+    assert_eq!(json["issuer"], "https://test.authkit.app");
+}
+
+// Test: a new configuration is not served a document cached for the old one
+#[spin_test]
+fn test_discovery_cache_follows_config() {
+    setup_provider();
+    let first = get_discovery("/.well-known/oauth-authorization-server", None);
+    assert_eq!(
+        first.body_json().unwrap()["issuer"],
+        "https://test.authkit.app"
+    );
+
+    variables::set("mcp_jwt_issuer", "https://other.authkit.app");
+    let second = get_discovery("/.well-known/oauth-authorization-server", None);
+    assert_eq!(
+        second.body_json().unwrap()["issuer"],
+        "https://other.authkit.app"
+    );
+    assert_ne!(header_str(&first, "etag"), header_str(&second, "etag"));
+}
+
+// Test: a TTL of 0 disables caching
+#[spin_test]
+fn test_discovery_cache_disabled() {
+    setup_provider();
+    variables::set("mcp_discovery_cache_ttl", "0");
+
+    let response = get_discovery("/.well-known/openid-configuration", None);
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        header_str(&response, "cache-control").as_deref(),
+        Some("no-cache")
+    );
+    // ETags still allow revalidation
+    assert!(header_str(&response, "etag").is_some());
+}
+
+// Test: an invalid TTL is a configuration error
+#[spin_test]
+fn test_discovery_cache_invalid_ttl() {
+    setup_provider();
+    variables::set("mcp_discovery_cache_ttl", "one hour");
+
+    let response = get_discovery("/.well-known/openid-configuration", None);
+    assert_eq!(response.status, 500);
+}
@@ -201,6 +201,236 @@ fn test_gateway_error_passthrough() {
     assert_eq!(json["message"], "Gateway failed");
 }
 
+/// Forward an authenticated request to a gateway answering with `headers`
+fn forward_with_gateway_headers(gateway_headers: &[(&str, &[u8])]) -> ResponseData {
+    forward_with_gateway_response(
+        gateway_headers,
+        b"{\"jsonrpc\":\"2.0\",\"result\":{},\"id\":1}",
+        &[],
+    )
+}
+
+/// Forward an authenticated request with `request_headers` to a gateway
+/// answering with `gateway_headers` and `gateway_body`
+fn forward_with_gateway_response(
+    gateway_headers: &[(&str, &[u8])],
+    gateway_body: &[u8],
+    request_headers: &[(&str, &[u8])],
+) -> ResponseData {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set("mcp_gateway_url", "https://test-gateway.spin.internal");
+    variables::set("mcp_jwt_issuer", "https://test.authkit.app");
+    variables::set(
+        "mcp_jwt_jwks_uri",
+        "https://test.authkit.app/.well-known/jwks.json",
+    );
+    variables::set("mcp_jwt_audience", "test-audience");
+
+    let (private_key, public_key) = crate::jwt_verification_tests::generate_test_key_pair();
+    let kid = "test-key";
+    let jwks = crate::jwt_verification_tests::create_jwks_response(&public_key, kid);
+    crate::jwt_verification_tests::mock_jwks_endpoint(
+        "https://test.authkit.app/.well-known/jwks.json",
+        jwks,
+    );
+
+    let headers = http::types::Headers::new();
+    for (name, value) in gateway_headers {
+        headers.append(name, value).unwrap();
+    }
+    let gateway_response = http::types::OutgoingResponse::new(headers);
+    gateway_response.set_status_code(200).unwrap();
+    let body = gateway_response.body().unwrap();
+    body.write_bytes(gateway_body);
+    http_handler::set_response(
+        "https://test-gateway.spin.internal/mcp",
+        http_handler::ResponseHandler::Response(gateway_response),
+    );
+
+    let token = create_test_token(
+        &private_key,
+        Claims {
+            sub: "user123".to_string(),
+            iss: "https://test.authkit.app".to_string(),
+            aud: Some(AudienceValue::Single("test-audience".to_string())),
+            exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp(),
+            iat: chrono::Utc::now().timestamp(),
+            scope: None,
+            scp: None,
+            client_id: None,
+            additional: serde_json::Map::new(),
+        },
+        Some(kid),
+    );
+
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {}", token).as_bytes())
+        .unwrap();
+    for (name, value) in request_headers {
+        headers.append(name, value).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some("/mcp")).unwrap();
+
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+// Test: Event streams are forwarded as they arrive, without a length
+#[spin_test]
+fn test_gateway_event_stream_passthrough() {
+    let events = b"event: message\ndata: {\"jsonrpc\":\"2.0\",\"result\":{},\"id\":1}\n\n";
+    let response_data = forward_with_gateway_response(
+        &[
+            ("content-type", b"text/event-stream"),
+            ("cache-control", b"no-cache"),
+            ("mcp-session-id", b"session-1"),
+        ],
+        events,
+        &[("accept", b"application/json, text/event-stream")],
+    );
+
+    assert_eq!(response_data.status, 200);
+    assert_eq!(response_data.body, events.to_vec());
+    assert_eq!(
+        response_data.find_header("content-type"),
+        Some(&b"text/event-stream".to_vec())
+    );
+    assert_eq!(
+        response_data.find_header("mcp-session-id"),
+        Some(&b"session-1".to_vec())
+    );
+    assert_eq!(
+        response_data.find_header("x-accel-buffering"),
+        Some(&b"no".to_vec())
+    );
+    assert!(response_data.find_header("content-length").is_none());
+    assert!(response_data
+        .find_header("access-control-allow-origin")
+        .is_some());
+}
+
+// Test: WebSocket handshakes are refused once authenticated
+#[spin_test]
+fn test_websocket_handshake_not_supported() {
+    let response_data = forward_with_gateway_response(
+        &[("content-type", b"application/json")],
+        b"{}",
+        &[
+            ("sec-websocket-key", b"dGhlIHNhbXBsZSBub25jZQ=="),
+            ("sec-websocket-version", b"13"),
+        ],
+    );
+
+    assert_eq!(response_data.status, 501);
+    let json = response_data.body_json().unwrap();
+    assert_eq!(json["error"], "unsupported_transport");
+}
+
+#[spin_test]
+fn test_invalid_stream_idle_timeout() {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set("mcp_stream_idle_timeout", "5m");
+
+    let response_data = forward_with_gateway_headers(&[("content-type", b"application/json")]);
+
+    assert_eq!(response_data.status, 500);
+}
+
+// Test: Gateway response headers on the denylist are dropped
+#[spin_test]
+fn test_gateway_response_header_denylist() {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set("mcp_response_headers_deny", "server, x-internal-*");
+
+    let response_data = forward_with_gateway_headers(&[
+        ("content-type", b"application/json"),
+        ("server", b"gateway/1.0"),
+        ("x-internal-route", b"tools-7"),
+        ("x-ratelimit-remaining", b"42"),
+    ]);
+
+    assert_eq!(response_data.status, 200);
+    assert!(response_data.find_header("server").is_none());
+    assert!(response_data.find_header("x-internal-route").is_none());
+    assert_eq!(
+        response_data.find_header("x-ratelimit-remaining"),
+        Some(&b"42".to_vec())
+    );
+
+    // The body is forwarded in full with a matching length
+    assert_eq!(
+        response_data.find_header("content-type"),
+        Some(&b"application/json".to_vec())
+    );
+    assert_eq!(
+        response_data.find_header("content-length"),
+        Some(&response_data.body.len().to_string().into_bytes())
+    );
+    assert!(response_data.body_json().is_some());
+}
+
+// Test: Only gateway response headers on the allowlist pass
+#[spin_test]
+fn test_gateway_response_header_allowlist() {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set("mcp_response_headers_allow", "x-ratelimit-*, etag");
+
+    let response_data = forward_with_gateway_headers(&[
+        ("content-type", b"application/json"),
+        ("etag", b"\"v1\""),
+        ("server", b"gateway/1.0"),
+        ("x-ratelimit-remaining", b"42"),
+    ]);
+
+    assert_eq!(response_data.status, 200);
+    assert!(response_data.find_header("server").is_none());
+    assert!(response_data.find_header("etag").is_some());
+    assert!(response_data.find_header("x-ratelimit-remaining").is_some());
+    // Headers describing the body always pass, as do the authorizer's own
+    assert!(response_data.find_header("content-type").is_some());
+    assert!(response_data
+        .find_header("access-control-allow-origin")
+        .is_some());
+}
+
+// Test: The ID of the policy decision replaces any the gateway sent
+#[spin_test]
+fn test_gateway_response_policy_decision_header() {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set(
+        "mcp_policy",
+        "package mcp.authorization\ndefault allow := true\n",
+    );
+    variables::set("mcp_policy_decision_header", "X-Policy-Decision-Id");
+
+    let response_data = forward_with_gateway_headers(&[
+        ("content-type", b"application/json"),
+        ("x-policy-decision-id", b"from-gateway"),
+    ]);
+
+    assert_eq!(response_data.status, 200);
+    let decisions: Vec<_> = response_data
+        .headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("x-policy-decision-id"))
+        .collect();
+    assert_eq!(decisions.len(), 1);
+    let decision_id = String::from_utf8_lossy(&decisions[0].1);
+    assert_eq!(decision_id.len(), 32);
+    assert!(decision_id.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[spin_test]
+fn test_gateway_response_header_invalid_pattern() {
+    use spin_test_sdk::bindings::fermyon::spin_test_virt::variables;
+    variables::set("mcp_response_headers_deny", "x bad");
+
+    let response_data = forward_with_gateway_headers(&[("content-type", b"application/json")]);
+
+    assert_eq!(response_data.status, 500);
+}
+
 // Test: Verify successful auth with all token variations
 #[spin_test]
 fn test_various_token_scenarios() {
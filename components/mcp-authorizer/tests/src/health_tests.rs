@@ -0,0 +1,108 @@
+// Unauthenticated health checks of the gateway
+
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{spin_test_virt::variables, spin_wasi_virt::http_handler},
+        wasi::http,
+    },
+    spin_test,
+};
+
+/// Send a request without credentials
+fn unauthenticated_request(method: &http::types::Method, path: &str) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers.append("host", b"example.com").unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+/// Mock the gateway's readiness endpoint
+fn mock_gateway_readiness(status: u16, body: &str) {
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    let response = http::types::OutgoingResponse::new(headers);
+    response.set_status_code(status).unwrap();
+    response.body().unwrap().write_bytes(body.as_bytes());
+    http_handler::set_response(
+        "https://test-gateway.spin.internal/readyz",
+        http_handler::ResponseHandler::Response(response),
+    );
+}
+
+#[spin_test]
+fn test_health_paths_need_no_token() {
+    setup_default_test_config();
+
+    for path in ["/healthz", "/readyz"] {
+        let response = unauthenticated_request(&http::types::Method::Get, path);
+        assert_eq!(response.status, 200, "{path}");
+        assert_eq!(
+            response.body_json().unwrap(),
+            serde_json::json!({ "status": "ok" })
+        );
+        assert_eq!(
+            unauthenticated_request(&http::types::Method::Head, path).status,
+            200
+        );
+    }
+}
+
+#[spin_test]
+fn test_other_requests_still_need_a_token() {
+    setup_default_test_config();
+
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/mcp").status,
+        401
+    );
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Post, "/healthz").status,
+        401
+    );
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/healthz/extra").status,
+        401
+    );
+}
+
+#[spin_test]
+fn test_health_paths_are_configurable() {
+    setup_default_test_config();
+    variables::set("mcp_health_paths", "/status");
+
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/status").status,
+        200
+    );
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/healthz").status,
+        401
+    );
+
+    variables::set("mcp_health_paths", "");
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/status").status,
+        401
+    );
+
+    variables::set("mcp_health_paths", "healthz");
+    assert_eq!(
+        unauthenticated_request(&http::types::Method::Get, "/healthz").status,
+        500
+    );
+}
+
+#[spin_test]
+fn test_gateway_readiness_passes_through() {
+    setup_default_test_config();
+    variables::set("mcp_gateway_url", "https://test-gateway.spin.internal");
+    mock_gateway_readiness(503, r#"{"status":"not_ready"}"#);
+
+    let response = unauthenticated_request(&http::types::Method::Get, "/readyz");
+    assert_eq!(response.status, 503);
+    assert_eq!(response.body_json().unwrap()["status"], "not_ready");
+}
@@ -0,0 +1,80 @@
+// HTML error pages for browser requests
+
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+/// Send an unauthenticated request to /mcp with the given Accept header
+fn unauthenticated_request(accept: Option<&str>) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers.append("host", b"example.com").unwrap();
+    if let Some(accept) = accept {
+        headers.append("accept", accept.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Get).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn content_type(response: &ResponseData) -> String {
+    response
+        .find_header("content-type")
+        .map(|value| String::from_utf8_lossy(value).to_string())
+        .unwrap_or_default()
+}
+
+#[spin_test]
+fn test_browser_gets_html_error_page() {
+    setup_default_test_config();
+
+    let response = unauthenticated_request(Some(
+        "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+    ));
+
+    assert_eq!(response.status, 401);
+    assert!(content_type(&response).starts_with("text/html"));
+    assert!(response.find_header("www-authenticate").is_some());
+    assert_eq!(
+        response.find_header("vary").map(|v| v.as_slice()),
+        Some(b"accept".as_slice())
+    );
+
+    let body = String::from_utf8_lossy(&response.body);
+    assert!(body.contains("Authentication required"));
+    assert!(body.contains("https://example.com/.well-known/oauth-protected-resource"));
+    assert!(body.contains("https://test.authkit.app"));
+}
+
+#[spin_test]
+fn test_api_client_gets_json_error() {
+    setup_default_test_config();
+
+    for accept in [
+        None,
+        Some("*/*"),
+        Some("application/json, text/event-stream"),
+    ] {
+        let response = unauthenticated_request(accept);
+
+        assert_eq!(response.status, 401);
+        assert_eq!(content_type(&response), "application/json");
+        let body = response.body_json().expect("JSON error body");
+        assert_eq!(body["error"], "unauthorized");
+    }
+}
+
+#[spin_test]
+fn test_html_error_pages_disabled() {
+    setup_default_test_config();
+    variables::set("mcp_html_error_pages", "false");
+
+    let response = unauthenticated_request(Some("text/html"));
+
+    assert_eq!(response.status, 401);
+    assert_eq!(content_type(&response), "application/json");
+    assert!(response.find_header("vary").is_none());
+}
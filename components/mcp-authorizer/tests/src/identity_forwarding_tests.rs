@@ -0,0 +1,160 @@
+// Identity forwarding format tests
+
+use crate::policy_test_helpers::*;
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{spin_test_virt::variables, spin_wasi_virt::http_handler},
+        wasi::http,
+    },
+    spin_test,
+};
+
+const ASSERTION_KEY: &str = "0123456789abcdef0123456789abcdef";
+
+/// Configure static-key JWT validation that forwards to a mocked gateway
+fn setup_forwarding() -> rsa::RsaPrivateKey {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    variables::set("mcp_gateway_url", "https://test-gateway.spin.internal");
+
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    response.set_status_code(200).unwrap();
+    response.body().unwrap().write_bytes(b"{}");
+    http_handler::set_response(
+        "https://test-gateway.spin.internal/mcp",
+        http_handler::ResponseHandler::Response(response),
+    );
+
+    private_key
+}
+
+fn request(token: &str) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    // A forged assertion must never reach the gateway
+    headers.append("x-auth-assertion", b"forged").unwrap();
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_assertion_format_forwards_request() {
+    let private_key = setup_forwarding();
+    variables::set("mcp_identity_format", "assertion");
+    variables::set("mcp_identity_assertion_key", ASSERTION_KEY);
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec!["read"], vec![]);
+    let response = request(&token);
+    assert_eq!(response.status, 200, "Request should be forwarded");
+}
+
+#[spin_test]
+fn test_both_format_forwards_request() {
+    let private_key = setup_forwarding();
+    variables::set("mcp_identity_format", "both");
+    variables::set("mcp_identity_assertion_key", ASSERTION_KEY);
+    variables::set("mcp_identity_assertion_ttl", "30");
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let response = request(&token);
+    assert_eq!(response.status, 200, "Request should be forwarded");
+}
+
+#[spin_test]
+fn test_assertion_format_requires_key() {
+    let private_key = setup_forwarding();
+    variables::set("mcp_identity_format", "assertion");
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let response = request(&token);
+    assert_eq!(response.status, 500);
+    let body = response.body_json().unwrap();
+    assert!(body["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("mcp_identity_assertion_key is required"));
+}
+
+#[spin_test]
+fn test_assertion_key_minimum_length() {
+    let private_key = setup_forwarding();
+    variables::set("mcp_identity_format", "both");
+    variables::set("mcp_identity_assertion_key", "too-short");
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let response = request(&token);
+    assert_eq!(response.status, 500);
+    let body = response.body_json().unwrap();
+    assert!(body["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("at least 32 bytes"));
+}
+
+#[spin_test]
+fn test_invalid_identity_settings() {
+    let private_key = setup_forwarding();
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    variables::set("mcp_identity_format", "cookies");
+    let response = request(&token);
+    assert_eq!(response.status, 500, "Unknown format should be rejected");
+
+    variables::set("mcp_identity_format", "assertion");
+    variables::set("mcp_identity_assertion_key", ASSERTION_KEY);
+    variables::set("mcp_identity_assertion_ttl", "0");
+    let response = request(&token);
+    assert_eq!(response.status, 500, "Zero TTL should be rejected");
+}
+
+#[spin_test]
+fn test_claim_headers_forward_request() {
+    let private_key = setup_forwarding();
+    variables::set(
+        "mcp_claim_headers",
+        r#"{"org_id": "x-ftl-org-id", "email": "X-FTL-Email", "org.name": "x-ftl-org-name"}"#,
+    );
+
+    let token = create_policy_test_token_with_key(
+        &private_key,
+        "user123",
+        vec![],
+        vec![
+            ("org_id", serde_json::json!("org_42")),
+            ("email", serde_json::json!("José <jose@example.com>")),
+            ("org", serde_json::json!({ "name": "Acme" })),
+        ],
+    );
+    let response = request(&token);
+    assert_eq!(response.status, 200, "Request should be forwarded");
+}
+
+#[spin_test]
+fn test_invalid_claim_headers() {
+    let private_key = setup_forwarding();
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    for (mapping, message) in [
+        (r#"{"org_id": "x-org-id"}"#, "must start with x-ftl-"),
+        (r#"{"org_id": "authorization"}"#, "must start with x-ftl-"),
+        (r#"["org_id"]"#, "must be a JSON object"),
+    ] {
+        variables::set("mcp_claim_headers", mapping);
+        let response = request(&token);
+        assert_eq!(response.status, 500, "{mapping} should be rejected");
+        let body = response.body_json().unwrap();
+        assert!(
+            body["error_description"]
+                .as_str()
+                .unwrap()
+                .contains(message),
+            "unexpected error for {mapping}: {body}"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// Issuer allow-list tests (mcp_jwt_issuers)
+
+use crate::test_token_utils::{TestKeyPair, TestTokenBuilder};
+use crate::ResponseData;
+use base64::Engine;
+use rsa::traits::PublicKeyParts;
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{spin_test_virt::variables, spin_wasi_virt::http_handler},
+        wasi::http::types,
+    },
+    spin_test,
+};
+
+/// Configure static-key validation for the EU issuer and the regional pattern
+fn setup_shared_key() -> TestKeyPair {
+    let key_pair = TestKeyPair::generate();
+    variables::set("mcp_provider_type", "jwt");
+    variables::set("mcp_jwt_issuer", "https://eu.idp.example.com");
+    variables::set("mcp_jwt_issuers", "https://*.idp.example.com");
+    variables::set("mcp_jwt_audience", "test-api");
+    variables::set("mcp_jwt_public_key", &key_pair.public_key_pem());
+    variables::set("mcp_gateway_url", "none");
+    key_pair
+}
+
+fn token_from(key_pair: &TestKeyPair, issuer: &str) -> String {
+    key_pair.create_token(
+        TestTokenBuilder::new()
+            .issuer(issuer)
+            .audience("test-api")
+            .kid("regional-key"),
+    )
+}
+
+fn request_with_token(token: &str) -> ResponseData {
+    let headers = types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    let request = types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn assert_invalid_issuer(response: &ResponseData) {
+    assert_eq!(response.status, 401);
+    assert_eq!(
+        response.body_json().unwrap()["error_reason"],
+        "invalid_issuer"
+    );
+}
+
+/// Serve a JWKS with the key pair's public key at `url`
+fn mock_jwks(url: &str, key_pair: &TestKeyPair) {
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let jwks = json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": "regional-key",
+            "n": engine.encode(key_pair.public_key.n().to_bytes_be()),
+            "e": engine.encode(key_pair.public_key.e().to_bytes_be()),
+        }]
+    });
+
+    let response = types::OutgoingResponse::new(types::Headers::new());
+    response.set_status_code(200).unwrap();
+    response
+        .headers()
+        .append("content-type", b"application/json")
+        .unwrap();
+    let body = response.body().unwrap();
+    body.write_bytes(jwks.to_string().as_bytes());
+    http_handler::set_response(url, http_handler::ResponseHandler::Response(response));
+}
+
+#[spin_test]
+fn test_regional_issuers_are_accepted() {
+    let key_pair = setup_shared_key();
+
+    for issuer in [
+        "https://eu.idp.example.com",
+        "https://us.idp.example.com",
+        "https://ap-southeast-2.idp.example.com",
+    ] {
+        let response = request_with_token(&token_from(&key_pair, issuer));
+        assert_eq!(response.status, 200, "{issuer} should be accepted");
+    }
+}
+
+#[spin_test]
+fn test_unlisted_issuers_are_rejected() {
+    let key_pair = setup_shared_key();
+
+    for issuer in [
+        "https://idp.example.com",
+        "https://a.b.idp.example.com",
+        "https://eu.idp.example.com.evil.com",
+        "https://eu.idp.other.com",
+    ] {
+        let response = request_with_token(&token_from(&key_pair, issuer));
+        assert_invalid_issuer(&response);
+    }
+}
+
+#[spin_test]
+fn test_explicit_issuer_list() {
+    let key_pair = setup_shared_key();
+    variables::set("mcp_jwt_issuer", "");
+    variables::set(
+        "mcp_jwt_issuers",
+        "https://eu.idp.example.com, https://us.idp.example.com",
+    );
+
+    let response = request_with_token(&token_from(&key_pair, "https://us.idp.example.com"));
+    assert_eq!(response.status, 200);
+
+    let response = request_with_token(&token_from(&key_pair, "https://ap.idp.example.com"));
+    assert_invalid_issuer(&response);
+}
+
+#[spin_test]
+fn test_jwks_uri_template_resolves_keys_per_issuer() {
+    let eu_keys = TestKeyPair::generate();
+    let us_keys = TestKeyPair::generate();
+    variables::set("mcp_provider_type", "jwt");
+    variables::set("mcp_jwt_issuers", "https://*.idp.example.com");
+    variables::set("mcp_jwt_jwks_uri", "{issuer}/.well-known/jwks.json");
+    variables::set("mcp_jwt_audience", "test-api");
+    variables::set("mcp_gateway_url", "none");
+    mock_jwks("https://eu.idp.example.com/.well-known/jwks.json", &eu_keys);
+    mock_jwks("https://us.idp.example.com/.well-known/jwks.json", &us_keys);
+
+    let response = request_with_token(&token_from(&eu_keys, "https://eu.idp.example.com"));
+    assert_eq!(response.status, 200);
+
+    let response = request_with_token(&token_from(&us_keys, "https://us.idp.example.com"));
+    assert_eq!(response.status, 200);
+
+    // Keys of one region don't verify tokens claiming another
+    let response = request_with_token(&token_from(&eu_keys, "https://us.idp.example.com"));
+    assert_eq!(response.status, 401);
+    assert_eq!(
+        response.body_json().unwrap()["error_reason"],
+        "invalid_signature"
+    );
+}
+
+#[spin_test]
+fn test_invalid_issuer_patterns_are_rejected() {
+    for pattern in [
+        "https://*.com",
+        "https://eu.*.example.com",
+        "http://eu.idp.example.com",
+    ] {
+        setup_shared_key();
+        variables::set("mcp_jwt_issuers", pattern);
+
+        let response = request_with_token("token");
+        assert_eq!(response.status, 500, "{pattern} should be rejected");
+    }
+}
+
+#[spin_test]
+fn test_issuers_require_a_key_source() {
+    variables::set("mcp_provider_type", "jwt");
+    variables::set("mcp_jwt_issuers", "https://*.idp.example.com");
+    variables::set("mcp_jwt_audience", "test-api");
+    variables::set("mcp_gateway_url", "none");
+
+    let response = request_with_token("token");
+    assert_eq!(response.status, 500);
+    assert!(response.body_json().unwrap()["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("mcp_jwt_issuers requires"));
+}
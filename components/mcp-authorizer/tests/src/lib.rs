@@ -6,7 +6,13 @@ use spin_test_sdk::{
 mod authkit_integration_tests;
 mod critical_audit_test;
 mod critical_verification_test;
+mod dev_token_tests;
+mod discovery_caching_tests;
 mod gateway_forwarding_tests;
+mod health_tests;
+mod html_error_page_tests;
+mod identity_forwarding_tests;
+mod issuer_allowlist_tests;
 mod jwks_caching_tests;
 mod jwt_test_utils_tests;
 mod jwt_tests;
@@ -14,8 +20,10 @@ mod jwt_verification_tests;
 mod kid_validation_tests;
 mod multiple_audiences_tests;
 mod oauth_discovery_tests;
+mod oidc_discovery_tests;
 mod optional_issuer_tests;
 mod policy_basic_tests;
+mod policy_client_tests;
 mod policy_complex_tests;
 mod policy_component_tests;
 mod policy_data_tests;
@@ -24,8 +32,13 @@ mod policy_org_mode_tests;
 mod policy_private_mode_tests;
 mod policy_test_helpers;
 mod provider_config_tests;
+mod pseudonym_tests;
+mod revocation_tests;
 mod scope_validation_tests;
 mod simple_test;
+mod tenant_tests;
+mod token_cache_tests;
+mod token_timing_tests;
 mod test_audience_required;
 mod test_config_loading;
 mod test_helpers;
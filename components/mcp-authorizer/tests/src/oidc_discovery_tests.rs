@@ -0,0 +1,188 @@
+use crate::ResponseData;
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        fermyon::spin_wasi_virt::http_handler,
+        wasi::http,
+    },
+    spin_test,
+};
+
+const ISSUER: &str = "https://login.example.com";
+const DISCOVERY_URL: &str = "https://login.example.com/.well-known/openid-configuration";
+
+/// Configure a provider that bootstraps from OIDC discovery
+fn setup_discovery_provider() {
+    variables::set("mcp_gateway_url", "none");
+    variables::set("mcp_jwt_issuer", ISSUER);
+    variables::set("mcp_jwt_audience", "test-audience");
+    variables::set("mcp_jwt_oidc_discovery", "true");
+
+    let kv = key_value::Store::open("default");
+    kv.delete(&format!("oidc:{ISSUER}"));
+}
+
+/// Mock the issuer's discovery document
+fn mock_discovery_document(document: serde_json::Value) {
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    response.set_status_code(200).unwrap();
+    let headers = response.headers();
+    headers.append("content-type", b"application/json").unwrap();
+    headers.append("etag", b"\"v1\"").unwrap();
+
+    let body = response.body().unwrap();
+    body.write_bytes(document.to_string().as_bytes());
+
+    http_handler::set_response(
+        DISCOVERY_URL,
+        http_handler::ResponseHandler::Response(response),
+    );
+}
+
+fn discovery_document() -> serde_json::Value {
+    json!({
+        "issuer": ISSUER,
+        "jwks_uri": "https://keys.example.com/jwks.json",
+        "authorization_endpoint": "https://login.example.com/authorize",
+        "token_endpoint": "https://login.example.com/token",
+        "userinfo_endpoint": "https://login.example.com/userinfo",
+        "response_types_supported": ["code"]
+    })
+}
+
+/// Fetch the authorizer's own authorization server metadata
+fn get_authorization_server_metadata() -> ResponseData {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request
+        .set_path_with_query(Some("/.well-known/oauth-authorization-server"))
+        .unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+// Test: jwks_uri and OAuth endpoints are bootstrapped from the issuer
+#[spin_test]
+fn test_oidc_discovery_populates_provider() {
+    setup_discovery_provider();
+    mock_discovery_document(discovery_document());
+
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 200);
+
+    let metadata = response.body_json().unwrap();
+    assert_eq!(metadata["issuer"], ISSUER);
+    assert_eq!(metadata["jwks_uri"], "https://keys.example.com/jwks.json");
+    assert_eq!(
+        metadata["authorization_endpoint"],
+        "https://login.example.com/authorize"
+    );
+    assert_eq!(
+        metadata["token_endpoint"],
+        "https://login.example.com/token"
+    );
+
+    // The metadata is pinned for later requests
+    let kv = key_value::Store::open("default");
+    let pinned = kv.get(&format!("oidc:{ISSUER}")).expect("pinned metadata");
+    let pinned: serde_json::Value = serde_json::from_slice(&pinned).unwrap();
+    assert_eq!(pinned["etag"], "\"v1\"");
+}
+
+// Test: pinned metadata is used until it is due for refresh
+#[spin_test]
+fn test_oidc_discovery_uses_pinned_metadata() {
+    setup_discovery_provider();
+
+    let pinned = json!({
+        "metadata": {
+            "issuer": ISSUER,
+            "jwks_uri": "https://keys.example.com/pinned.json",
+            "authorization_endpoint": null,
+            "token_endpoint": null,
+            "userinfo_endpoint": null
+        },
+        "refresh_at": u64::MAX
+    });
+    let kv = key_value::Store::open("default");
+    kv.set(&format!("oidc:{ISSUER}"), pinned.to_string().as_bytes());
+
+    // No discovery mock: the pinned metadata must be served without a fetch
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body_json().unwrap()["jwks_uri"],
+        "https://keys.example.com/pinned.json"
+    );
+}
+
+// Test: explicit configuration takes precedence over discovered values
+#[spin_test]
+fn test_oidc_discovery_keeps_configured_values() {
+    setup_discovery_provider();
+    variables::set("mcp_jwt_jwks_uri", "https://login.example.com/custom-jwks");
+    variables::set(
+        "mcp_oauth_token_endpoint",
+        "https://login.example.com/custom-token",
+    );
+    mock_discovery_document(discovery_document());
+
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 200);
+
+    let metadata = response.body_json().unwrap();
+    assert_eq!(
+        metadata["jwks_uri"],
+        "https://login.example.com/custom-jwks"
+    );
+    assert_eq!(
+        metadata["token_endpoint"],
+        "https://login.example.com/custom-token"
+    );
+    assert_eq!(
+        metadata["authorization_endpoint"],
+        "https://login.example.com/authorize"
+    );
+}
+
+// Test: a discovery document for a different issuer is rejected
+#[spin_test]
+fn test_oidc_discovery_rejects_issuer_mismatch() {
+    setup_discovery_provider();
+
+    let mut document = discovery_document();
+    document["issuer"] = json!("https://evil.example.com");
+    mock_discovery_document(document);
+
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 500);
+    let body = response.body_json().unwrap();
+    assert!(body["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("does not match configured issuer"));
+}
+
+// Test: discovered URLs must use HTTPS
+#[spin_test]
+fn test_oidc_discovery_rejects_insecure_jwks_uri() {
+    setup_discovery_provider();
+
+    let mut document = discovery_document();
+    document["jwks_uri"] = json!("http://keys.example.com/jwks.json");
+    mock_discovery_document(document);
+
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 500);
+}
+
+// Test: discovery requires an HTTPS issuer URL
+#[spin_test]
+fn test_oidc_discovery_requires_https_issuer() {
+    variables::set("mcp_gateway_url", "none");
+    variables::set("mcp_jwt_issuer", "my-issuer");
+    variables::set("mcp_jwt_audience", "test-audience");
+    variables::set("mcp_jwt_oidc_discovery", "true");
+
+    let response = get_authorization_server_metadata();
+    assert_eq!(response.status, 500);
+}
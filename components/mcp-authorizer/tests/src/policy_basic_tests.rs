@@ -66,6 +66,59 @@ fn test_policy_deny_all() {
     );
 }
 
+#[spin_test]
+fn test_policy_decision_header_on_denial() {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    setup_deny_all_policy();
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set("mcp_policy_debug", "true");
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set(
+        "mcp_policy_decision_header",
+        "x-policy-decision-id",
+    );
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {}", token).as_bytes())
+        .unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Get).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+
+    let response = crate::ResponseData::from_response(spin_test_sdk::perform_request(request));
+
+    // Debugging doesn't change the decision
+    assert_eq!(response.status, 401);
+    let decision_id = response
+        .find_header("x-policy-decision-id")
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .expect("Denials should carry the decision ID");
+    assert_eq!(decision_id.len(), 32);
+    assert!(decision_id.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[spin_test]
+fn test_policy_decision_header_disabled_by_default() {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    setup_deny_all_policy();
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {}", token).as_bytes())
+        .unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Get).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+
+    let response = crate::ResponseData::from_response(spin_test_sdk::perform_request(request));
+
+    assert_eq!(response.status, 401);
+    assert!(response.find_header("x-policy-decision-id").is_none());
+}
+
 #[spin_test]
 fn test_policy_subject_check() {
     setup_default_test_config();
@@ -0,0 +1,154 @@
+// Client metadata (IP, user agent, time) and CIDR lists in policy input
+
+use crate::policy_test_helpers::*;
+use crate::test_setup::setup_default_test_config;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+/// Admin tools are only allowed from the corporate network
+const NETWORK_POLICY: &str = r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+admin_tool if startswith(input.mcp.tool, "admin_")
+
+allow if {
+    input.mcp.method == "tools/call"
+    not admin_tool
+}
+
+allow if {
+    admin_tool
+    "corporate" in input.request.client_ip_lists
+}
+"#;
+
+fn setup_network_policy(policy: &str) -> String {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    variables::set("mcp_policy", policy);
+    variables::set("mcp_trusted_proxy_headers", "x-forwarded-for");
+    variables::set(
+        "mcp_policy_cidr_lists",
+        r#"{"corporate": ["10.0.0.0/8", "2001:db8::/32"], "partners": ["198.51.100.0/24"]}"#,
+    );
+    create_policy_test_token_with_key(&private_key, "user", vec![], vec![])
+}
+
+fn call_tool(token: &str, tool: &str, extra_headers: &[(&str, &str)]) -> u16 {
+    let body = format!(
+        r#"{{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{{"name":"{tool}","arguments":{{}}}}}}"#
+    );
+
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {}", token).as_bytes())
+        .unwrap();
+    headers.append("content-type", b"application/json").unwrap();
+    for (name, value) in extra_headers {
+        headers.append(name, value.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    request.body().unwrap().write_bytes(body.as_bytes());
+
+    spin_test_sdk::perform_request(request).status()
+}
+
+#[spin_test]
+fn test_admin_tools_require_corporate_ip() {
+    let token = setup_network_policy(NETWORK_POLICY);
+
+    // The proxy appends the address it saw, so the right-most entry counts
+    assert_eq!(
+        call_tool(&token, "admin_reset", &[("x-forwarded-for", "10.1.2.3")]),
+        200
+    );
+    assert_eq!(
+        call_tool(&token, "admin_reset", &[("x-forwarded-for", "2001:db8::7")]),
+        200
+    );
+    assert_eq!(
+        call_tool(
+            &token,
+            "admin_reset",
+            &[("x-forwarded-for", "10.1.2.3, 203.0.113.9")]
+        ),
+        401,
+        "A spoofed corporate address before the proxy's entry must not count"
+    );
+    assert_eq!(
+        call_tool(
+            &token,
+            "admin_reset",
+            &[("x-forwarded-for", "198.51.100.4")]
+        ),
+        401
+    );
+    assert_eq!(call_tool(&token, "admin_reset", &[]), 401);
+
+    // Other tools are allowed from anywhere
+    assert_eq!(
+        call_tool(&token, "search", &[("x-forwarded-for", "203.0.113.9")]),
+        200
+    );
+}
+
+#[spin_test]
+fn test_untrusted_proxy_headers_ignored() {
+    let token = setup_network_policy(NETWORK_POLICY);
+    variables::set("mcp_trusted_proxy_headers", "");
+
+    assert_eq!(
+        call_tool(&token, "admin_reset", &[("x-forwarded-for", "10.1.2.3")]),
+        401
+    );
+}
+
+#[spin_test]
+fn test_client_metadata_in_input() {
+    let policy = r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+allow if {
+    input.request.client_ip == "198.51.100.4"
+    input.request.client_ip_lists == ["partners"]
+    "198.51.100.0/24" in data.cidr_lists.partners
+    not contains(lower(input.request.user_agent), "curl")
+    input.request.timestamp > 1700000000
+    endswith(input.request.time, "Z")
+}
+"#;
+    let token = setup_network_policy(policy);
+
+    let browser = [
+        ("x-forwarded-for", "198.51.100.4"),
+        ("user-agent", "Mozilla/5.0"),
+    ];
+    assert_eq!(call_tool(&token, "search", &browser), 200);
+
+    let curl = [
+        ("x-forwarded-for", "198.51.100.4"),
+        ("user-agent", "curl/8.5.0"),
+    ];
+    assert_eq!(call_tool(&token, "search", &curl), 401);
+}
+
+#[spin_test]
+fn test_invalid_cidr_lists_rejected() {
+    let token = setup_network_policy(NETWORK_POLICY);
+    variables::set("mcp_policy_cidr_lists", r#"{"corporate": ["10.0.0.0/33"]}"#);
+
+    assert_eq!(
+        call_tool(&token, "search", &[("x-forwarded-for", "10.1.2.3")]),
+        500
+    );
+}
@@ -444,3 +444,118 @@ allow if {
     let response = spin_test_sdk::perform_request(request);
     assert_eq!(response.status(), 401, "Should deny DROP operation");
 }
+
+// POST a JSON body to a tool component with the given token
+fn post_mcp_body(token: &str, body: &str) -> http::types::IncomingResponse {
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {}", token).as_bytes())
+        .unwrap();
+    headers.append("content-type", b"application/json").unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request
+        .set_path_with_query(Some("/mcp/x/data-processor"))
+        .unwrap();
+    let body_stream = request.body().unwrap();
+    body_stream.write_bytes(body.as_bytes());
+
+    spin_test_sdk::perform_request(request)
+}
+
+#[spin_test]
+fn test_tool_argument_keys() {
+    setup_default_test_config();
+
+    // Only allow calls that pass no arguments besides the expected ones
+    let policy = r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+allow if {
+    input.mcp.method == "tools/call"
+    input.mcp.id == "req-1"
+    every key in input.mcp.argument_keys {
+        key in ["dataset", "limit"]
+    }
+}
+"#;
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set("mcp_policy", policy);
+
+    let token = create_policy_test_token_with_key(&private_key, "user", vec![], vec![]);
+
+    let body = r#"{"jsonrpc":"2.0","id":"req-1","method":"tools/call","params":{"name":"process","arguments":{"limit":10,"dataset":"sales"}}}"#;
+    let response = post_mcp_body(&token, body);
+    assert_eq!(response.status(), 200, "Should allow expected arguments");
+
+    let body = r#"{"jsonrpc":"2.0","id":"req-1","method":"tools/call","params":{"name":"process","arguments":{"dataset":"sales","raw_sql":"DROP TABLE"}}}"#;
+    let response = post_mcp_body(&token, body);
+    assert_eq!(response.status(), 401, "Should deny unexpected arguments");
+}
+
+#[spin_test]
+fn test_batch_with_denied_tool_call() {
+    setup_default_test_config();
+    let (private_key, _public_key) =
+        setup_tool_authorization_policy(vec!["read_data"], vec!["delete_database"]);
+
+    let token = create_policy_test_token_with_key(&private_key, "user", vec!["user"], vec![]);
+
+    // Every message of the batch is allowed
+    let body = r#"[
+        {"jsonrpc":"2.0","id":1,"method":"tools/list"},
+        {"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"read_data","arguments":{}}}
+    ]"#;
+    let response = post_mcp_body(&token, body);
+    assert_eq!(
+        response.status(),
+        200,
+        "Should allow batch of allowed calls"
+    );
+
+    // A dangerous tool call hidden next to an allowed one
+    let body = r#"[
+        {"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"read_data","arguments":{}}},
+        {"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"delete_database","arguments":{}}}
+    ]"#;
+    let response = post_mcp_body(&token, body);
+    assert_eq!(
+        response.status(),
+        401,
+        "Should deny batch containing a denied call"
+    );
+}
+
+#[spin_test]
+fn test_malformed_body_denied() {
+    setup_default_test_config();
+
+    // Would allow requests without MCP context
+    let policy = r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+allow if {
+    not input.mcp
+}
+"#;
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set("mcp_policy", policy);
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set(
+        "mcp_policy_malformed_body",
+        "deny",
+    );
+
+    let token = create_policy_test_token_with_key(&private_key, "user", vec![], vec![]);
+
+    let response = post_mcp_body(&token, r#"{"invalid json": }"#);
+    assert_eq!(response.status(), 400, "Should reject invalid JSON");
+
+    let response = post_mcp_body(&token, "[]");
+    assert_eq!(response.status(), 400, "Should reject empty batch");
+}
@@ -0,0 +1,121 @@
+// Subject pseudonym tests
+
+use crate::policy_test_helpers::*;
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+const SALT: &str = "0123456789abcdef0123456789abcdef";
+
+/// Pseudonym of a subject of the test issuer
+fn pseudonym(subject: &str) -> String {
+    jsonwebtoken::crypto::sign(
+        format!("https://test.example.com\n{subject}").as_bytes(),
+        &jsonwebtoken::EncodingKey::from_secret(SALT.as_bytes()),
+        jsonwebtoken::Algorithm::HS256,
+    )
+    .unwrap()
+}
+
+/// Enable pseudonyms with a policy that only allows requests whose subject,
+/// in the token and its claims, is `subject`
+fn setup_pseudonyms(subject: &str) -> rsa::RsaPrivateKey {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    variables::set("mcp_subject_pseudonyms", "true");
+    variables::set("mcp_subject_pseudonym_salt", SALT);
+    variables::set(
+        "mcp_policy",
+        &format!(
+            r#"
+package mcp.authorization
+import rego.v1
+
+default allow := false
+
+allow if {{
+    input.token.sub == "{subject}"
+    input.token.claims.sub == "{subject}"
+}}
+"#
+        ),
+    );
+    private_key
+}
+
+fn request(token: &str, path: &str, body: Option<&str>) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    headers.append("content-type", b"application/json").unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    if let Some(body) = body {
+        request.body().unwrap().write_bytes(body.as_bytes());
+    }
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+const LIST_TOOLS: &str = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+const CALL_BILLING: &str = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"billing__charge","arguments":{}}}"#;
+
+#[spin_test]
+fn test_policy_sees_the_pseudonym() {
+    let private_key = setup_pseudonyms(&pseudonym("user123"));
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    assert_eq!(request(&token, "/mcp", Some(LIST_TOOLS)).status, 200);
+    assert_eq!(request(&token, "/mcp", Some(CALL_BILLING)).status, 200);
+
+    // Other users get other pseudonyms
+    let other = create_policy_test_token_with_key(&private_key, "user456", vec![], vec![]);
+    assert_eq!(request(&other, "/mcp", Some(LIST_TOOLS)).status, 401);
+}
+
+#[spin_test]
+fn test_raw_subject_without_pseudonyms() {
+    let private_key = setup_pseudonyms("user123");
+    variables::set("mcp_subject_pseudonyms", "false");
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    assert_eq!(request(&token, "/mcp", Some(LIST_TOOLS)).status, 200);
+}
+
+#[spin_test]
+fn test_allowlisted_components_see_the_raw_subject() {
+    let private_key = setup_pseudonyms("user123");
+    variables::set("mcp_subject_pseudonym_allow", "billing, audit-log");
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    assert_eq!(request(&token, "/mcp/x/billing", None).status, 200);
+    assert_eq!(request(&token, "/mcp/x/audit-log", None).status, 200);
+    assert_eq!(request(&token, "/mcp", Some(CALL_BILLING)).status, 200);
+
+    // Requests that can reach other components carry the pseudonym
+    assert_eq!(request(&token, "/mcp/x/weather", None).status, 401);
+    assert_eq!(request(&token, "/mcp", Some(LIST_TOOLS)).status, 401);
+    let batch = format!(
+        r#"[{CALL_BILLING}, {{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{{"name":"weather__forecast"}}}}]"#
+    );
+    assert_eq!(request(&token, "/mcp", Some(&batch)).status, 401);
+}
+
+#[spin_test]
+fn test_pseudonym_salt_minimum_length() {
+    let private_key = setup_pseudonyms("user123");
+    variables::set("mcp_subject_pseudonym_salt", "too-short");
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    let response = request(&token, "/mcp", Some(LIST_TOOLS));
+    assert_eq!(response.status, 500);
+    let body = response.body_json().unwrap();
+    assert!(body["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("mcp_subject_pseudonym_salt must be at least 32 bytes"));
+}
@@ -0,0 +1,254 @@
+// Token revocation deny-list tests
+
+use crate::policy_test_helpers::*;
+use crate::test_setup::setup_default_test_config;
+use crate::ResponseData;
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+/// Configure static-key JWT validation with revocation checks enabled
+fn setup_revocation() -> rsa::RsaPrivateKey {
+    setup_default_test_config();
+    let (private_key, _public_key) = setup_test_jwt_validation();
+    variables::set("mcp_revocation_enabled", "true");
+    variables::set("mcp_revocation_admin_scope", "authorizer:admin");
+    private_key
+}
+
+/// Write a deny-list entry directly to the KV store
+fn revoke(kind: &str, value: &str, revoked_at: i64, expires_at: Option<i64>) {
+    let kv = key_value::Store::open("default");
+    let entry = json!({ "revoked_at": revoked_at, "expires_at": expires_at });
+    kv.set(
+        &format!("revoked:{kind}:{value}"),
+        entry.to_string().as_bytes(),
+    );
+}
+
+fn request(
+    method: http::types::Method,
+    path: &str,
+    token: &str,
+    body: Option<&str>,
+) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    if body.is_some() {
+        headers.append("content-type", b"application/json").unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    if let Some(body) = body {
+        let outgoing = request.body().unwrap();
+        outgoing.write_bytes(body.as_bytes());
+    }
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_revoked_jti_is_rejected() {
+    let private_key = setup_revocation();
+    revoke("jti", "token-1", now(), None);
+
+    let revoked = create_policy_test_token_with_key(
+        &private_key,
+        "user123",
+        vec![],
+        vec![("jti", json!("token-1"))],
+    );
+    let response = request(http::types::Method::Get, "/mcp", &revoked, None);
+    assert_eq!(response.status, 401, "Revoked jti should be rejected");
+    let body = response.body_json().unwrap();
+    assert_eq!(body["error"], "invalid_token");
+    assert_eq!(body["error_description"], "Token has been revoked");
+
+    let other = create_policy_test_token_with_key(
+        &private_key,
+        "user123",
+        vec![],
+        vec![("jti", json!("token-2"))],
+    );
+    let response = request(http::types::Method::Get, "/mcp", &other, None);
+    assert_eq!(response.status, 200, "Other tokens should be accepted");
+}
+
+#[spin_test]
+fn test_revoked_subject_rejects_tokens_issued_before_revocation() {
+    let private_key = setup_revocation();
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+
+    // Revoked after the token was issued
+    revoke("sub", "user123", now() + 10, None);
+    let response = request(http::types::Method::Get, "/mcp", &token, None);
+    assert_eq!(
+        response.status, 401,
+        "Tokens issued before revocation should be rejected"
+    );
+
+    // Revoked before the token was issued, e.g. the user logged in again
+    revoke("sub", "user123", now() - 60, None);
+    let response = request(http::types::Method::Get, "/mcp", &token, None);
+    assert_eq!(
+        response.status, 200,
+        "Tokens issued after revocation should be accepted"
+    );
+}
+
+#[spin_test]
+fn test_expired_revocation_is_ignored() {
+    let private_key = setup_revocation();
+    revoke("sub", "user123", now() + 10, Some(now() - 1));
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let response = request(http::types::Method::Get, "/mcp", &token, None);
+    assert_eq!(response.status, 200, "Expired entries should be ignored");
+
+    let kv = key_value::Store::open("default");
+    assert!(
+        kv.get("revoked:sub:user123").is_none(),
+        "Expired entries should be removed"
+    );
+}
+
+#[spin_test]
+fn test_revocation_disabled_ignores_deny_list() {
+    let private_key = setup_revocation();
+    variables::set("mcp_revocation_enabled", "false");
+    revoke("sub", "user123", now() + 10, None);
+
+    let token = create_policy_test_token_with_key(&private_key, "user123", vec![], vec![]);
+    let response = request(http::types::Method::Get, "/mcp", &token, None);
+    assert_eq!(
+        response.status, 200,
+        "Deny-list should not be checked when disabled"
+    );
+}
+
+#[spin_test]
+fn test_admin_endpoint_requires_admin_scope() {
+    let private_key = setup_revocation();
+    let token = create_policy_test_token_with_key(
+        &private_key,
+        "user123",
+        vec![],
+        vec![("scope", json!("mcp:read"))],
+    );
+
+    let response = request(
+        http::types::Method::Post,
+        "/_authorizer/revocations",
+        &token,
+        Some(r#"{"type":"sub","value":"victim"}"#),
+    );
+    assert_eq!(response.status, 401, "Admin scope should be required");
+
+    let kv = key_value::Store::open("default");
+    assert!(kv.get("revoked:sub:victim").is_none());
+}
+
+#[spin_test]
+fn test_admin_endpoint_manages_deny_list() {
+    let private_key = setup_revocation();
+    let admin = create_policy_test_token_with_key(
+        &private_key,
+        "admin",
+        vec![],
+        vec![("scope", json!("authorizer:admin"))],
+    );
+
+    // Revoke a token by jti
+    let response = request(
+        http::types::Method::Post,
+        "/_authorizer/revocations",
+        &admin,
+        Some(r#"{"type":"jti","value":"leaked","reason":"leaked in logs"}"#),
+    );
+    assert_eq!(response.status, 201);
+
+    let leaked = create_policy_test_token_with_key(
+        &private_key,
+        "user123",
+        vec![],
+        vec![("jti", json!("leaked"))],
+    );
+    let response = request(http::types::Method::Get, "/mcp", &leaked, None);
+    assert_eq!(
+        response.status, 401,
+        "Token revoked via the API should be rejected"
+    );
+
+    // List entries
+    let response = request(
+        http::types::Method::Get,
+        "/_authorizer/revocations",
+        &admin,
+        None,
+    );
+    assert_eq!(response.status, 200);
+    let body = response.body_json().unwrap();
+    let revocations = body["revocations"].as_array().unwrap();
+    assert_eq!(revocations.len(), 1);
+    assert_eq!(revocations[0]["type"], "jti");
+    assert_eq!(revocations[0]["value"], "leaked");
+    assert_eq!(revocations[0]["reason"], "leaked in logs");
+
+    // Remove the entry
+    let response = request(
+        http::types::Method::Delete,
+        "/_authorizer/revocations/jti/leaked",
+        &admin,
+        None,
+    );
+    assert_eq!(response.status, 204);
+
+    let response = request(http::types::Method::Get, "/mcp", &leaked, None);
+    assert_eq!(
+        response.status, 200,
+        "Token should be accepted once un-revoked"
+    );
+}
+
+#[spin_test]
+fn test_admin_endpoint_rejects_invalid_requests() {
+    let private_key = setup_revocation();
+    let admin = create_policy_test_token_with_key(
+        &private_key,
+        "admin",
+        vec![],
+        vec![("scope", json!("authorizer:admin"))],
+    );
+
+    let response = request(
+        http::types::Method::Post,
+        "/_authorizer/revocations",
+        &admin,
+        Some(r#"{"type":"email","value":"a@example.com"}"#),
+    );
+    assert_eq!(response.status, 400);
+
+    let response = request(
+        http::types::Method::Delete,
+        "/_authorizer/revocations/jti/unknown",
+        &admin,
+        None,
+    );
+    assert_eq!(response.status, 404);
+}
@@ -0,0 +1,216 @@
+// Multi-tenant configuration tests
+
+use crate::test_setup::setup_default_test_config;
+use crate::test_token_utils::{TestKeyPair, TestTokenBuilder};
+use crate::ResponseData;
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{
+            spin_test_virt::{key_value, variables},
+            spin_wasi_virt::http_handler,
+        },
+        wasi::http,
+    },
+    spin_test,
+};
+
+/// Per-tenant settings with a static key
+fn tenant(key_pair: &TestKeyPair, name: &str) -> serde_json::Value {
+    json!({
+        "hosts": [format!("{name}.example.com")],
+        "issuer": format!("https://{name}.example.com"),
+        "public_key": key_pair.public_key_pem(),
+        "audience": [format!("{name}-api")],
+    })
+}
+
+fn token(key_pair: &TestKeyPair, name: &str) -> String {
+    key_pair.create_token(
+        TestTokenBuilder::new()
+            .subject("user123")
+            .issuer(format!("https://{name}.example.com"))
+            .audience(format!("{name}-api")),
+    )
+}
+
+fn request(host: &str, path: &str, token: Option<&str>) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers.append("host", host.as_bytes()).unwrap();
+    if let Some(token) = token {
+        headers
+            .append("authorization", format!("Bearer {token}").as_bytes())
+            .unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some(path)).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_host_resolution_isolates_tenants() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    let globex = TestKeyPair::generate();
+    variables::set(
+        "mcp_tenants",
+        &json!({"acme": tenant(&acme, "acme"), "globex": tenant(&globex, "globex")}).to_string(),
+    );
+
+    let acme_token = token(&acme, "acme");
+    let response = request("acme.example.com", "/mcp", Some(&acme_token));
+    assert_eq!(response.status, 200, "Tenant token should be accepted");
+
+    // The port is ignored when matching hosts
+    let response = request("acme.example.com:443", "/mcp", Some(&acme_token));
+    assert_eq!(response.status, 200);
+
+    // Another tenant's token is rejected
+    let response = request("globex.example.com", "/mcp", Some(&acme_token));
+    assert_eq!(
+        response.status, 401,
+        "Cross-tenant token should be rejected"
+    );
+
+    let response = request(
+        "globex.example.com",
+        "/mcp",
+        Some(&token(&globex, "globex")),
+    );
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_unknown_tenant_is_rejected() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    variables::set(
+        "mcp_tenants",
+        &json!({"acme": tenant(&acme, "acme")}).to_string(),
+    );
+
+    let response = request("initech.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 404);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["error"], "unknown_tenant");
+}
+
+#[spin_test]
+fn test_path_resolution_strips_tenant_prefix() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    variables::set("mcp_tenant_resolution", "path");
+    variables::set(
+        "mcp_tenants",
+        &json!({"acme": tenant(&acme, "acme")}).to_string(),
+    );
+    variables::set("mcp_gateway_url", "https://test-gateway.spin.internal");
+
+    // The gateway serves every tenant at its root
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    response.set_status_code(200).unwrap();
+    response.body().unwrap().write_bytes(b"{}");
+    http_handler::set_response(
+        "https://test-gateway.spin.internal/mcp",
+        http_handler::ResponseHandler::Response(response),
+    );
+
+    let response = request("api.example.com", "/acme/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 200, "Request should be forwarded to /mcp");
+
+    let response = request(
+        "api.example.com",
+        "/globex/mcp",
+        Some(&token(&acme, "acme")),
+    );
+    assert_eq!(response.status, 404);
+
+    // Discovery advertises the tenant's resource URL
+    let response = request(
+        "api.example.com",
+        "/.well-known/oauth-protected-resource/acme/mcp",
+        None,
+    );
+    assert_eq!(response.status, 200);
+    let body = response.body_json().unwrap();
+    assert_eq!(
+        body["resource"],
+        json!(["https://api.example.com/acme/mcp"])
+    );
+    assert_eq!(
+        body["authorization_servers"][0]["issuer"],
+        "https://acme.example.com"
+    );
+}
+
+#[spin_test]
+fn test_tenant_policy_replaces_default() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    let globex = TestKeyPair::generate();
+    variables::set(
+        "mcp_policy",
+        "package mcp.authorization\nimport rego.v1\n\ndefault allow := true\n",
+    );
+
+    let mut locked = tenant(&globex, "globex");
+    locked["policy"] =
+        json!("package mcp.authorization\nimport rego.v1\n\ndefault allow := false\n");
+    variables::set(
+        "mcp_tenants",
+        &json!({"acme": tenant(&acme, "acme"), "globex": locked}).to_string(),
+    );
+
+    let response = request("acme.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 200, "Default policy should apply");
+
+    let response = request(
+        "globex.example.com",
+        "/mcp",
+        Some(&token(&globex, "globex")),
+    );
+    assert_eq!(response.status, 401, "Tenant policy should deny");
+}
+
+#[spin_test]
+fn test_tenants_loaded_from_key_value_store() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    variables::set("mcp_tenants_kv_key", "tenants");
+
+    // Missing configuration is a server error
+    let response = request("acme.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 500);
+
+    let kv = key_value::Store::open("default");
+    kv.set(
+        "tenants",
+        json!({"acme": tenant(&acme, "acme")})
+            .to_string()
+            .as_bytes(),
+    );
+    let response = request("acme.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_invalid_tenant_configuration() {
+    setup_default_test_config();
+    let acme = TestKeyPair::generate();
+    let mut invalid = tenant(&acme, "acme");
+    invalid["audience"] = json!([]);
+    variables::set("mcp_tenants", &json!({"acme": invalid}).to_string());
+
+    let response = request("acme.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 500);
+    let body = response.body_json().unwrap();
+    assert!(body["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("Tenant acme: audience is required"));
+
+    variables::set("mcp_tenants", "{\"acme\": ");
+    let response = request("acme.example.com", "/mcp", Some(&token(&acme, "acme")));
+    assert_eq!(response.status, 500);
+}
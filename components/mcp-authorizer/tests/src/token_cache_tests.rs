@@ -0,0 +1,172 @@
+// Token verification cache tests
+
+use crate::jwt_verification_tests::{AudienceValue, Claims};
+use crate::ResponseData;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use rsa::pkcs1::EncodeRsaPrivateKey;
+use rsa::traits::PublicKeyParts;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        fermyon::spin_wasi_virt::http_handler,
+        wasi::http,
+    },
+    spin_test,
+};
+
+const ISSUER: &str = "https://test.authkit.app";
+const JWKS_URI: &str = "https://test.authkit.app/.well-known/jwks.json";
+const KID: &str = "token-cache-key";
+
+/// Mock the JWKS endpoint, serving `public_key` or failing without one
+fn mock_jwks(public_key: Option<&RsaPublicKey>) {
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    match public_key {
+        Some(public_key) => {
+            let n = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .encode(public_key.n().to_bytes_be());
+            let e = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .encode(public_key.e().to_bytes_be());
+            let jwks = json!({
+                "keys": [{ "kty": "RSA", "use": "sig", "alg": "RS256", "kid": KID, "n": n, "e": e }]
+            });
+            response.set_status_code(200).unwrap();
+            response
+                .headers()
+                .append("content-type", b"application/json")
+                .unwrap();
+            response
+                .body()
+                .unwrap()
+                .write_bytes(jwks.to_string().as_bytes());
+        }
+        None => response.set_status_code(500).unwrap(),
+    }
+    http_handler::set_response(JWKS_URI, http_handler::ResponseHandler::Response(response));
+}
+
+/// Configure JWKS validation with the verification cache enabled
+fn setup_token_cache() -> RsaPrivateKey {
+    variables::set("mcp_gateway_url", "none");
+    variables::set("mcp_jwt_issuer", ISSUER);
+    variables::set("mcp_jwt_jwks_uri", JWKS_URI);
+    variables::set("mcp_jwt_audience", "test-audience");
+    variables::set("mcp_token_cache_ttl", "60");
+    key_value::Store::open("default").delete(&format!("jwks:{JWKS_URI}"));
+
+    let private_key =
+        RsaPrivateKey::new(&mut rand::thread_rng(), 2048).expect("failed to generate key");
+    mock_jwks(Some(&RsaPublicKey::from(&private_key)));
+    private_key
+}
+
+/// Make the JWKS unavailable, so that only cached verifications succeed
+fn break_jwks() {
+    key_value::Store::open("default").delete(&format!("jwks:{JWKS_URI}"));
+    mock_jwks(None);
+}
+
+fn create_token(private_key: &RsaPrivateKey, jti: &str) -> String {
+    let now = Utc::now();
+    let mut additional = serde_json::Map::new();
+    additional.insert("jti".to_string(), json!(jti));
+    let claims = Claims {
+        sub: "user123".to_string(),
+        iss: ISSUER.to_string(),
+        aud: Some(AudienceValue::Single("test-audience".to_string())),
+        exp: (now + Duration::hours(1)).timestamp(),
+        iat: now.timestamp(),
+        scope: Some("read".to_string()),
+        scp: None,
+        client_id: None,
+        additional,
+    };
+    let header = Header {
+        alg: Algorithm::RS256,
+        kid: Some(KID.to_string()),
+        ..Default::default()
+    };
+    let pem = private_key
+        .to_pkcs1_pem(rsa::pkcs1::LineEnding::LF)
+        .unwrap();
+    let key = EncodingKey::from_rsa_pem(pem.as_bytes()).unwrap();
+    jsonwebtoken::encode(&header, &claims, &key).unwrap()
+}
+
+fn request(token: &str) -> ResponseData {
+    let headers = http::types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Get).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_verified_tokens_are_reused() {
+    let private_key = setup_token_cache();
+    let token = create_token(&private_key, "token-1");
+    assert_eq!(request(&token).status, 200);
+
+    break_jwks();
+    assert_eq!(
+        request(&token).status,
+        200,
+        "Cached verification should be reused"
+    );
+
+    let other = create_token(&private_key, "token-2");
+    assert_eq!(
+        request(&other).status,
+        401,
+        "Tokens not verified before should be verified"
+    );
+}
+
+#[spin_test]
+fn test_strict_mode_disables_cache() {
+    let private_key = setup_token_cache();
+    variables::set("mcp_strict_token_verification", "true");
+    let token = create_token(&private_key, "token-1");
+    assert_eq!(request(&token).status, 200);
+
+    break_jwks();
+    assert_eq!(
+        request(&token).status,
+        401,
+        "Strict mode should verify every request"
+    );
+}
+
+#[spin_test]
+fn test_cached_tokens_are_checked_for_revocation() {
+    let private_key = setup_token_cache();
+    variables::set("mcp_revocation_enabled", "true");
+    let token = create_token(&private_key, "token-1");
+    assert_eq!(request(&token).status, 200);
+
+    let entry = json!({ "revoked_at": Utc::now().timestamp(), "expires_at": null });
+    key_value::Store::open("default").set("revoked:jti:token-1", entry.to_string().as_bytes());
+
+    let response = request(&token);
+    assert_eq!(response.status, 401, "Revoked tokens should be rejected");
+    assert_eq!(
+        response.body_json().unwrap()["error_description"],
+        "Token has been revoked"
+    );
+}
+
+#[spin_test]
+fn test_token_cache_ttl_is_bounded() {
+    let private_key = setup_token_cache();
+    variables::set("mcp_token_cache_ttl", "3600");
+
+    let token = create_token(&private_key, "token-1");
+    assert_eq!(request(&token).status, 500);
+}
@@ -0,0 +1,157 @@
+// Token age, not-before and clock skew tests
+
+use crate::test_token_utils::{TestKeyPair, TestTokenBuilder};
+use crate::ResponseData;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http::types},
+    spin_test,
+};
+
+/// Configure static-key JWT validation without forwarding
+fn setup_timing_test() -> TestKeyPair {
+    let key_pair = TestKeyPair::generate();
+    variables::set("mcp_provider_type", "jwt");
+    variables::set("mcp_jwt_issuer", "https://test.example.com");
+    variables::set("mcp_jwt_audience", "test-api");
+    variables::set("mcp_jwt_public_key", &key_pair.public_key_pem());
+    variables::set("mcp_gateway_url", "none");
+    key_pair
+}
+
+/// Token issued `issued_secs_ago` seconds ago, valid for another hour
+fn token_issued_ago(key_pair: &TestKeyPair, issued_secs_ago: i64) -> String {
+    let iat = Utc::now().timestamp() - issued_secs_ago;
+    key_pair.create_token(
+        TestTokenBuilder::new()
+            .audience("test-api")
+            .claim("iat", json!(iat)),
+    )
+}
+
+fn request_with_token(token: &str) -> ResponseData {
+    let headers = types::Headers::new();
+    headers
+        .append("authorization", format!("Bearer {token}").as_bytes())
+        .unwrap();
+    let request = types::OutgoingRequest::new(headers);
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn assert_rejected(response: &ResponseData, reason: &str) {
+    assert_eq!(response.status, 401);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["error"], "invalid_token");
+    assert_eq!(body["error_reason"], reason);
+}
+
+#[spin_test]
+fn test_token_older_than_max_age_is_rejected() {
+    let key_pair = setup_timing_test();
+    variables::set("mcp_jwt_max_token_age", "86400");
+
+    // exp is still an hour away, but the token was issued 25 hours ago
+    let response = request_with_token(&token_issued_ago(&key_pair, 25 * 3600));
+    assert_rejected(&response, "token_too_old");
+    assert_eq!(
+        response.body_json().unwrap()["error_description"],
+        "Token exceeds the maximum token age"
+    );
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 23 * 3600));
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_max_age_allows_clock_skew() {
+    let key_pair = setup_timing_test();
+    variables::set("mcp_jwt_max_token_age", "3600");
+    variables::set("mcp_jwt_clock_skew", "300");
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 3600 + 200));
+    assert_eq!(response.status, 200);
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 3600 + 400));
+    assert_rejected(&response, "token_too_old");
+
+    // Tokens issued in the future beyond the skew are rejected
+    let response = request_with_token(&token_issued_ago(&key_pair, -600));
+    assert_rejected(&response, "token_issued_in_future");
+}
+
+#[spin_test]
+fn test_old_tokens_are_accepted_without_max_age() {
+    let key_pair = setup_timing_test();
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 30 * 24 * 3600));
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_not_before_is_enforced() {
+    let key_pair = setup_timing_test();
+
+    let nbf = Utc::now().timestamp() + 600;
+    let token = key_pair.create_token(
+        TestTokenBuilder::new()
+            .audience("test-api")
+            .claim("nbf", json!(nbf)),
+    );
+    let response = request_with_token(&token);
+    assert_rejected(&response, "token_not_yet_valid");
+
+    // A larger clock skew tolerates the difference
+    variables::set("mcp_jwt_clock_skew", "900");
+    let response = request_with_token(&token);
+    assert_eq!(response.status, 200);
+
+    variables::set("mcp_jwt_clock_skew", "0");
+    variables::set("mcp_jwt_nbf", "ignore");
+    let response = request_with_token(&token);
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_required_not_before() {
+    let key_pair = setup_timing_test();
+    variables::set("mcp_jwt_nbf", "require");
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 0));
+    assert_eq!(response.status, 401);
+    assert!(response.body_json().unwrap()["error_description"]
+        .as_str()
+        .unwrap()
+        .contains("nbf"));
+
+    let nbf = Utc::now().timestamp() - 60;
+    let token = key_pair.create_token(
+        TestTokenBuilder::new()
+            .audience("test-api")
+            .claim("nbf", json!(nbf)),
+    );
+    let response = request_with_token(&token);
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_expired_token_reason() {
+    let key_pair = setup_timing_test();
+
+    let token = key_pair.create_token(
+        TestTokenBuilder::new()
+            .audience("test-api")
+            .expires_in(Duration::seconds(-3600)),
+    );
+    assert_rejected(&request_with_token(&token), "token_expired");
+}
+
+#[spin_test]
+fn test_invalid_timing_config_fails() {
+    let key_pair = setup_timing_test();
+    variables::set("mcp_jwt_max_token_age", "0");
+
+    let response = request_with_token(&token_issued_ago(&key_pair, 0));
+    assert_eq!(response.status, 500);
+}
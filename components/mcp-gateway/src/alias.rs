@@ -0,0 +1,110 @@
+//! Aliases routing deprecated tool names to their replacements
+//!
+//! Aliases are configured through the `tool_aliases` variable as a JSON
+//! document mapping deprecated tool names (`component__tool`) to the tools
+//! they were renamed to:
+//!
+//! ```json
+//! {
+//!   "weather__get_forecast": "weather__forecast",
+//!   "weather__alerts": "alerts__active"
+//! }
+//! ```
+//!
+//! Calls to a deprecated name are routed to its replacement, which is
+//! validated, transformed and rate limited as if it had been called directly.
+//! Deprecated names are not listed by `tools/list`. Results of calls through
+//! an alias carry a deprecation warning in `_meta`, and their responses the
+//! replacement in the `X-MCP-Tool-Replaced-By` header.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::gateway::{is_component_name, is_tool_name};
+
+/// Response header naming the tool a call to a deprecated name was routed to
+pub const REPLACED_BY_HEADER: &str = "X-MCP-Tool-Replaced-By";
+
+/// Key of the deprecation warning in the `_meta` of tool results
+pub const DEPRECATION_META_KEY: &str = "ftl/deprecation";
+
+/// Compiled alias configuration for all tools
+#[derive(Debug, Clone, Default)]
+pub struct ToolAliases {
+    aliases: BTreeMap<String, String>,
+}
+
+/// Whether a name is a prefixed tool name routable by the gateway
+fn is_prefixed_tool_name(name: &str) -> bool {
+    name.split_once("__")
+        .is_some_and(|(component, tool)| is_component_name(component) && is_tool_name(tool))
+}
+
+impl ToolAliases {
+    /// Parse an alias configuration document
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let aliases: BTreeMap<String, String> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid tool_aliases: {e}"))?;
+        for (alias, target) in &aliases {
+            if !is_prefixed_tool_name(alias) {
+                return Err(format!(
+                    "Invalid tool_aliases: '{alias}' is not a tool name of the form 'component__tool'"
+                ));
+            }
+            if !is_prefixed_tool_name(target) {
+                return Err(format!(
+                    "Invalid tool_aliases for '{alias}': '{target}' is not a tool name of the form 'component__tool'"
+                ));
+            }
+            // Aliases resolve in a single step
+            if aliases.contains_key(target) {
+                return Err(format!(
+                    "Invalid tool_aliases for '{alias}': '{target}' is itself an alias"
+                ));
+            }
+        }
+        Ok(Self { aliases })
+    }
+
+    /// Load the alias configuration from the `tool_aliases` variable
+    pub fn load() -> Result<Self, String> {
+        Self::parse(&spin_sdk::variables::get("tool_aliases").unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Component and tool a deprecated tool is routed to, `None` when the
+    /// tool has no alias
+    pub fn resolve(&self, component: &str, tool: &str) -> Option<(&str, &str)> {
+        self.aliases
+            .get(&format!("{component}__{tool}"))
+            .and_then(|target| target.split_once("__"))
+    }
+}
+
+/// Add a deprecation warning to the `_meta` of a serialized tool result
+pub fn add_deprecation_warning(result: &mut Value, alias: &str, replacement: &str) {
+    let Some(result) = result.as_object_mut() else {
+        return;
+    };
+    let meta = result
+        .entry("_meta")
+        .or_insert_with(|| Value::Object(serde_json::Map::new()));
+    if let Some(meta) = meta.as_object_mut() {
+        meta.insert(
+            DEPRECATION_META_KEY.to_string(),
+            serde_json::json!({
+                "tool": alias,
+                "replacement": replacement,
+                "message": format!("Tool '{alias}' is deprecated, use '{replacement}' instead"),
+            }),
+        );
+    }
+}
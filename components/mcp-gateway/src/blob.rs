@@ -0,0 +1,514 @@
+//! Out-of-band uploads of large tool arguments
+//!
+//! Arguments sent inline in `tools/call` are buffered and parsed by the
+//! gateway, so a large file content argument is held in memory several times.
+//! Clients can instead upload it to `POST /mcp/blobs`: the body is streamed
+//! into the default KV store in chunks and a handle is returned, which is
+//! passed in place of the argument as `{"$blob": "<handle>"}`. The gateway
+//! streams the blob into the request sent to the tool component as a JSON
+//! string, so memory use stays bounded by the chunk size.
+//!
+//! Text uploads are passed to tools as the text itself and anything else as
+//! base64. Handles are unguessable, bound to the authenticated caller that
+//! uploaded them, and expire after an hour.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use spin_sdk::http::{Headers, IncomingRequest, Method, OutgoingRequest, Response, Scheme};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+/// Path blobs are uploaded to
+pub const UPLOAD_PATH: &str = "/mcp/blobs";
+
+/// Key of the object standing in for an argument with an uploaded blob
+pub const BLOB_REF_KEY: &str = "$blob";
+
+/// KV key prefix of stored blobs
+const BLOB_KEY_PREFIX: &str = "gateway:blob:";
+
+/// Bytes per stored chunk, a multiple of 3 so base64 chunks can be concatenated
+const CHUNK_BYTES: usize = 3 * 64 * 1024;
+
+/// Upload size limit when `max_blob_bytes` is not set
+const DEFAULT_MAX_BLOB_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Blobs are forgotten this long after their upload
+const BLOB_TTL_SECS: u64 = 60 * 60;
+
+/// Stored description of an uploaded blob, written once all of its chunks are
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlobMeta {
+    pub size: u64,
+    pub chunks: u32,
+    /// Passed to tools as text rather than base64
+    pub text: bool,
+    /// Authenticated caller that uploaded the blob
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Unix timestamp (seconds) after which the blob is gone
+    pub expires_at: u64,
+}
+
+/// A blob reference found in tool arguments
+#[derive(Debug, Clone)]
+pub struct BlobArgument {
+    /// JSON pointer of the argument the blob stands in for
+    pub pointer: String,
+    pub handle: String,
+}
+
+/// A blob reference checked against the store
+#[derive(Debug, Clone)]
+pub struct ResolvedBlob {
+    pub pointer: String,
+    pub handle: String,
+    pub meta: BlobMeta,
+}
+
+/// Why an upload was rejected
+enum UploadError {
+    TooLarge(u64),
+    InvalidText,
+    Read,
+    Storage,
+}
+
+impl UploadError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::TooLarge(max) => error_response(413, &format!("Blob exceeds {max} bytes")),
+            Self::InvalidText => error_response(400, "Text blobs must be valid UTF-8"),
+            Self::Read => error_response(400, "Failed to read the upload body"),
+            Self::Storage => error_response(500, "Failed to store the blob"),
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn meta_key(handle: &str) -> String {
+    format!("{BLOB_KEY_PREFIX}{handle}")
+}
+
+fn chunk_key(handle: &str, index: u32) -> String {
+    format!("{BLOB_KEY_PREFIX}{handle}:{index}")
+}
+
+fn error_response(status: u16, message: &str) -> Response {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .header("Access-Control-Allow-Origin", "*")
+        .body(
+            serde_json::to_vec(&serde_json::json!({ "error": message }))
+                .unwrap_or_else(|_| b"{\"error\":\"Upload failed\"}".to_vec()),
+        )
+        .build()
+}
+
+/// Upload size limit from the `max_blob_bytes` variable
+fn max_blob_bytes() -> u64 {
+    variables::get("max_blob_bytes")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_MAX_BLOB_BYTES)
+}
+
+/// Whether uploads with a content type are passed to tools as text
+fn is_text_type(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime.starts_with("text/")
+        || mime == "application/json"
+        || mime == "application/xml"
+        || mime.ends_with("+json")
+        || mime.ends_with("+xml")
+}
+
+/// Handles are the simple form of a v4 UUID
+fn is_valid_handle(handle: &str) -> bool {
+    handle.len() == 32 && handle.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Last value of a header, which is the authorizer's verified copy
+fn last_header<'a>(headers: &'a [(String, Vec<u8>)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .rev()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .and_then(|(_, value)| std::str::from_utf8(value).ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Identity of the authenticated caller a blob is bound to: the verified user
+/// id, or the client id for machine callers
+pub fn owner<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Option<String> {
+    let mut user = None;
+    let mut client = None;
+    for (name, value) in headers {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        if name.eq_ignore_ascii_case("x-auth-user-id") {
+            user = Some(value);
+        } else if name.eq_ignore_ascii_case("x-auth-client-id") {
+            client = Some(value);
+        }
+    }
+    user.or(client).map(ToString::to_string)
+}
+
+/// Whether a request is a blob upload
+pub fn is_upload(req: &IncomingRequest) -> bool {
+    matches!(req.method(), Method::Post)
+        && req
+            .path_with_query()
+            .is_some_and(|path| path.split('?').next() == Some(UPLOAD_PATH))
+}
+
+/// Writes an upload to the store one chunk at a time
+struct ChunkWriter<'a> {
+    store: &'a Store,
+    handle: &'a str,
+    text: bool,
+    chunks: u32,
+    pending: Vec<u8>,
+}
+
+impl ChunkWriter<'_> {
+    fn write(&mut self, data: &[u8]) -> Result<(), UploadError> {
+        self.pending.extend_from_slice(data);
+        while self.pending.len() >= CHUNK_BYTES {
+            let end = self.chunk_end()?;
+            let rest = self.pending.split_off(end);
+            let chunk = std::mem::replace(&mut self.pending, rest);
+            self.store_chunk(&chunk)?;
+        }
+        Ok(())
+    }
+
+    /// End of the next full chunk. Text chunks end on a character boundary so
+    /// each can be escaped on its own.
+    fn chunk_end(&self) -> Result<usize, UploadError> {
+        if !self.text {
+            return Ok(CHUNK_BYTES);
+        }
+        let head = self.pending.get(..CHUNK_BYTES).unwrap_or_default();
+        match std::str::from_utf8(head) {
+            Ok(_) => Ok(CHUNK_BYTES),
+            // A character split at the end of the chunk moves to the next one
+            Err(e) if e.error_len().is_none() && e.valid_up_to() > 0 => Ok(e.valid_up_to()),
+            Err(_) => Err(UploadError::InvalidText),
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), UploadError> {
+        if self.text && std::str::from_utf8(&self.pending).is_err() {
+            return Err(UploadError::InvalidText);
+        }
+        if !self.pending.is_empty() {
+            let chunk = std::mem::take(&mut self.pending);
+            self.store_chunk(&chunk)?;
+        }
+        Ok(())
+    }
+
+    fn store_chunk(&mut self, chunk: &[u8]) -> Result<(), UploadError> {
+        self.store
+            .set(&chunk_key(self.handle, self.chunks), chunk)
+            .map_err(|_| UploadError::Storage)?;
+        self.chunks += 1;
+        Ok(())
+    }
+
+    /// Remove the chunks written so far
+    fn discard(&self) {
+        delete_chunks(self.store, self.handle, self.chunks);
+    }
+}
+
+fn delete_chunks(store: &Store, handle: &str, chunks: u32) {
+    for index in 0..chunks {
+        let _ = store.delete(&chunk_key(handle, index));
+    }
+}
+
+/// Handle `POST /mcp/blobs`, streaming the body into the store
+pub async fn handle_upload(req: IncomingRequest) -> Response {
+    let headers = req.headers().entries();
+    let text = last_header(&headers, "content-type").is_some_and(is_text_type);
+    let owner = owner(
+        headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), std::str::from_utf8(value).ok()?))),
+    );
+
+    let max = max_blob_bytes();
+    if last_header(&headers, "content-length")
+        .and_then(|len| len.parse::<u64>().ok())
+        .is_some_and(|len| len > max)
+    {
+        return UploadError::TooLarge(max).into_response();
+    }
+
+    let Ok(store) = Store::open_default() else {
+        return UploadError::Storage.into_response();
+    };
+    let handle = uuid::Uuid::new_v4().simple().to_string();
+    let mut writer = ChunkWriter {
+        store: &store,
+        handle: &handle,
+        text,
+        chunks: 0,
+        pending: Vec::new(),
+    };
+
+    let mut size: u64 = 0;
+    let mut body = req.into_body_stream();
+    while let Some(data) = body.next().await {
+        let written = data.map_or(Err(UploadError::Read), |data| {
+            size = size.saturating_add(u64::try_from(data.len()).unwrap_or(u64::MAX));
+            if size > max {
+                Err(UploadError::TooLarge(max))
+            } else {
+                writer.write(&data)
+            }
+        });
+        if let Err(e) = written {
+            writer.discard();
+            return e.into_response();
+        }
+    }
+    if let Err(e) = writer.finish() {
+        writer.discard();
+        return e.into_response();
+    }
+
+    // The blob only becomes visible once all of its chunks are stored
+    let meta = BlobMeta {
+        size,
+        chunks: writer.chunks,
+        text,
+        owner,
+        expires_at: now() + BLOB_TTL_SECS,
+    };
+    let stored = serde_json::to_vec(&meta)
+        .ok()
+        .is_some_and(|data| store.set(&meta_key(&handle), &data).is_ok());
+    if !stored {
+        writer.discard();
+        return UploadError::Storage.into_response();
+    }
+
+    Response::builder()
+        .status(201)
+        .header("Content-Type", "application/json")
+        .header("Access-Control-Allow-Origin", "*")
+        .body(
+            serde_json::to_vec(&serde_json::json!({
+                "handle": handle,
+                "size": meta.size,
+                "encoding": if meta.text { "text" } else { "base64" },
+                "expires_at": meta.expires_at,
+            }))
+            .unwrap_or_default(),
+        )
+        .build()
+}
+
+/// Handle of a `{"$blob": "<handle>"}` reference
+fn blob_handle(value: &Value) -> Option<String> {
+    let map = value.as_object()?;
+    if map.len() != 1 {
+        return None;
+    }
+    map.get(BLOB_REF_KEY)?.as_str().map(ToString::to_string)
+}
+
+fn collect_references(value: &mut Value, pointer: String, found: &mut Vec<BlobArgument>) {
+    if let Some(handle) = blob_handle(value) {
+        *value = Value::String(String::new());
+        found.push(BlobArgument { pointer, handle });
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                collect_references(child, format!("{pointer}/{key}"), found);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter_mut().enumerate() {
+                collect_references(child, format!("{pointer}/{index}"), found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace blob references in tool arguments with empty strings, returning
+/// where they were so the blobs can be streamed in their place
+pub fn take_references(arguments: &mut Value) -> Vec<BlobArgument> {
+    let mut found = Vec::new();
+    collect_references(arguments, String::new(), &mut found);
+    found
+}
+
+/// Look up the blobs referenced by a tool call. Blobs uploaded by an
+/// authenticated caller can only be used by the same caller.
+pub fn resolve(
+    arguments: Vec<BlobArgument>,
+    caller: Option<&str>,
+) -> Result<Vec<ResolvedBlob>, String> {
+    if arguments.is_empty() {
+        return Ok(Vec::new());
+    }
+    let store = Store::open_default().map_err(|_| "Blob storage is unavailable".to_string())?;
+
+    arguments
+        .into_iter()
+        .map(|argument| {
+            let not_found = || format!("Blob '{}' not found or expired", argument.handle);
+            if !is_valid_handle(&argument.handle) {
+                return Err(not_found());
+            }
+            let meta: BlobMeta = store
+                .get(&meta_key(&argument.handle))
+                .ok()
+                .flatten()
+                .and_then(|data| serde_json::from_slice(&data).ok())
+                .ok_or_else(not_found)?;
+
+            if meta.expires_at < now() {
+                delete_chunks(&store, &argument.handle, meta.chunks);
+                let _ = store.delete(&meta_key(&argument.handle));
+                return Err(not_found());
+            }
+            if meta.owner.is_some() && meta.owner.as_deref() != caller {
+                return Err(not_found());
+            }
+
+            Ok(ResolvedBlob {
+                pointer: argument.pointer,
+                handle: argument.handle,
+                meta,
+            })
+        })
+        .collect()
+}
+
+/// Encode a stored chunk as the inside of a JSON string
+fn encode_chunk(chunk: &[u8], text: bool) -> Vec<u8> {
+    if !text {
+        return STANDARD.encode(chunk).into_bytes();
+    }
+    let escaped = serde_json::to_string(&String::from_utf8_lossy(chunk)).unwrap_or_default();
+    escaped
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or_default()
+        .as_bytes()
+        .to_vec()
+}
+
+/// Send a tool request whose body is the arguments with each blob spliced in
+/// as a JSON string, streamed from the store chunk by chunk
+pub async fn send_with_blobs(
+    authority: &str,
+    path: &str,
+    headers: &[(String, String)],
+    mut arguments: Value,
+    blobs: &[ResolvedBlob],
+) -> Result<Response, String> {
+    let store = Store::open_default().map_err(|_| "Blob storage is unavailable".to_string())?;
+
+    // Serialize the arguments with a unique marker where each blob goes
+    let nonce = uuid::Uuid::new_v4().simple().to_string();
+    let markers: Vec<String> = (0..blobs.len())
+        .map(|index| format!("ftl-blob:{nonce}:{index}"))
+        .collect();
+    for (blob, marker) in blobs.iter().zip(&markers) {
+        if let Some(slot) = arguments.pointer_mut(&blob.pointer) {
+            *slot = Value::String(marker.clone());
+        }
+    }
+    let json = serde_json::to_string(&arguments).map_err(|e| e.to_string())?;
+    let mut splices: Vec<(usize, usize, &ResolvedBlob)> = markers
+        .iter()
+        .zip(blobs)
+        .filter_map(|(marker, blob)| {
+            let quoted = format!("\"{marker}\"");
+            json.find(&quoted)
+                .map(|start| (start, start + quoted.len(), blob))
+        })
+        .collect();
+    splices.sort_unstable_by_key(|(start, _, _)| *start);
+
+    let mut fields = vec![("content-type".to_string(), b"application/json".to_vec())];
+    fields.extend(
+        headers
+            .iter()
+            .map(|(name, value)| (name.clone(), value.as_bytes().to_vec())),
+    );
+    let request = OutgoingRequest::new(
+        Headers::from_list(&fields).map_err(|_| "Invalid forwarded headers".to_string())?,
+    );
+    request
+        .set_method(&Method::Post)
+        .and_then(|()| request.set_scheme(Some(&Scheme::Http)))
+        .and_then(|()| request.set_authority(Some(authority)))
+        .and_then(|()| request.set_path_with_query(Some(path)))
+        .map_err(|()| "Invalid tool request".to_string())?;
+
+    let mut body = request.take_body();
+    let response = spin_sdk::http::send::<_, Response>(request);
+    let write = async move {
+        let failed = |_| "Failed to stream the request body".to_string();
+        let mut offset = 0;
+        for (start, end, blob) in splices {
+            let json_part = json.get(offset..start).unwrap_or_default();
+            body.send(json_part.as_bytes().to_vec())
+                .await
+                .map_err(failed)?;
+            body.send(b"\"".to_vec()).await.map_err(failed)?;
+            for index in 0..blob.meta.chunks {
+                let chunk = store
+                    .get(&chunk_key(&blob.handle, index))
+                    .ok()
+                    .flatten()
+                    .ok_or_else(|| format!("Blob '{}' is incomplete", blob.handle))?;
+                body.send(encode_chunk(&chunk, blob.meta.text))
+                    .await
+                    .map_err(failed)?;
+            }
+            body.send(b"\"".to_vec()).await.map_err(failed)?;
+            offset = end;
+        }
+        let rest = json.get(offset..).unwrap_or_default();
+        body.send(rest.as_bytes().to_vec()).await.map_err(failed)?;
+        // Dropping the sink finishes the body
+        drop(body);
+        Ok::<(), String>(())
+    };
+
+    let (response, written) = futures::join!(response, write);
+    written?;
+    response.map_err(|e| e.to_string())
+}
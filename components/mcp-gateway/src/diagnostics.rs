@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Request, Response};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+use crate::alias::ToolAliases;
+use crate::gateway::{GatewayConfig, McpGateway};
+use crate::metrics;
+use crate::rate_limit::RateLimits;
+use crate::transform::Transforms;
+
+/// Path of the gateway self-diagnostics endpoint
+pub const DIAGNOSTICS_PATH: &str = "/_ftl/diagnostics";
+
+/// KV key holding the gateway error counters
+const ERROR_COUNTERS_KEY: &str = "gateway:diagnostics:errors";
+
+/// Error counters persisted in the default KV store
+///
+/// Each gateway request runs in a fresh instance, so counters have to live
+/// outside the component to survive between requests.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ErrorCounters {
+    pub total: u64,
+    /// Count per JSON-RPC error code
+    pub by_code: BTreeMap<i32, u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Unix timestamp (seconds) of the last recorded error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_at: Option<u64>,
+}
+
+fn load_counters(store: &Store) -> ErrorCounters {
+    store
+        .get(ERROR_COUNTERS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Record a JSON-RPC error returned by the gateway
+///
+/// Best effort: failures to reach the KV store are ignored so diagnostics
+/// never affect request handling.
+pub fn record_error(code: i32, message: &str) {
+    let Ok(store) = Store::open_default() else {
+        return;
+    };
+
+    let mut counters = load_counters(&store);
+    counters.total = counters.total.saturating_add(1);
+    let count = counters.by_code.entry(code).or_insert(0);
+    *count = count.saturating_add(1);
+    counters.last_error = Some(message.to_string());
+    counters.last_error_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs());
+
+    if let Ok(data) = serde_json::to_vec(&counters) {
+        let _ = store.set(ERROR_COUNTERS_KEY, &data);
+    }
+}
+
+/// Check the request's bearer token against a configured admin token
+pub(crate) fn is_authorized(req: &Request, expected: &str) -> bool {
+    req.header("authorization")
+        .and_then(|value| value.as_str())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| constant_time_eq(token.trim().as_bytes(), expected.as_bytes()))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+pub(crate) fn json_response(status: u16, body: &serde_json::Value) -> Response {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .header("Cache-Control", "no-store")
+        .body(serde_json::to_vec(body).unwrap_or_else(|_| b"{}".to_vec()))
+        .build()
+}
+
+/// Handle `GET /_ftl/diagnostics`
+///
+/// The endpoint is disabled (404) unless the `diagnostics_token` variable is
+/// set, and requires `Authorization: Bearer <diagnostics_token>`.
+pub async fn handle_diagnostics(req: &Request, config: GatewayConfig) -> Response {
+    let token = variables::get("diagnostics_token").unwrap_or_default();
+    if token.trim().is_empty() {
+        return json_response(404, &serde_json::json!({ "error": "Not found" }));
+    }
+
+    if !is_authorized(req, token.trim()) {
+        return Response::builder()
+            .status(401)
+            .header("Content-Type", "application/json")
+            .header("WWW-Authenticate", "Bearer")
+            .body(br#"{"error":"Unauthorized"}"#.to_vec())
+            .build();
+    }
+
+    if *req.method() != spin_sdk::http::Method::Get {
+        return Response::builder()
+            .status(405)
+            .header("Allow", "GET")
+            .body(b"Method not allowed".to_vec())
+            .build();
+    }
+
+    let component_names = config.components().unwrap_or_default();
+
+    let alias_stage = match ToolAliases::load() {
+        Ok(aliases) => serde_json::json!({ "stage": "alias", "enabled": !aliases.is_empty() }),
+        Err(e) => serde_json::json!({ "stage": "alias", "enabled": false, "error": e }),
+    };
+    let transform_stage = match Transforms::load() {
+        Ok(transforms) => {
+            serde_json::json!({ "stage": "transform", "enabled": !transforms.is_empty() })
+        }
+        Err(e) => serde_json::json!({ "stage": "transform", "enabled": false, "error": e }),
+    };
+    let rate_limit_stage = match RateLimits::load() {
+        Ok(rate_limits) => {
+            serde_json::json!({ "stage": "rate_limit", "enabled": !rate_limits.is_empty() })
+        }
+        Err(e) => serde_json::json!({ "stage": "rate_limit", "enabled": false, "error": e }),
+    };
+
+    let server_info = config.server_info.clone();
+    let validate_arguments = config.validate_arguments;
+    let debug_errors = config.debug_errors;
+    let config_version = config.config_version;
+    let gateway = &McpGateway::new(config, None, None);
+    let tool_futures: Vec<_> = component_names
+        .iter()
+        .map(|name| async move {
+            let tools = gateway.fetch_component_tools(name).await;
+            serde_json::json!({
+                "name": name,
+                "tool_count": tools.len(),
+            })
+        })
+        .collect();
+    let components = futures::future::join_all(tool_futures).await;
+
+    let (kv, errors) = match Store::open_default() {
+        Ok(store) => {
+            let keys = store.get_keys().map(|keys| keys.len()).ok();
+            (
+                serde_json::json!({ "available": true, "keys": keys }),
+                load_counters(&store),
+            )
+        }
+        Err(e) => (
+            serde_json::json!({ "available": false, "error": e.to_string() }),
+            ErrorCounters::default(),
+        ),
+    };
+
+    json_response(
+        200,
+        &serde_json::json!({
+            "server_info": server_info,
+            "components": components,
+            "pipeline": [
+                alias_stage,
+                { "stage": "scope", "enabled": true },
+                { "stage": "toolsets_filter", "enabled": true },
+                { "stage": "readonly", "enabled": true },
+                transform_stage,
+                { "stage": "argument_validation", "enabled": validate_arguments },
+                rate_limit_stage,
+                { "stage": "metrics", "enabled": metrics::enabled() },
+            ],
+            "debug_errors": debug_errors,
+            "config_version": config_version,
+            "kv": kv,
+            "errors": errors,
+        }),
+    )
+}
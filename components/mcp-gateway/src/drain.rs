@@ -0,0 +1,322 @@
+//! Graceful shutdown
+//!
+//! Before Spin recycles the gateway, an operator (or the platform) starts a
+//! drain with `POST /_ftl/drain`: new tool calls are then rejected with a
+//! retryable `503`, and the request waits until the tool calls already in
+//! flight have finished, up to a timeout. Setting the `draining` variable has
+//! the same effect on new calls.
+//!
+//! Each request runs in a fresh instance, so in-flight tool calls and the
+//! drain flag are kept in the default KV store. A call registers itself
+//! before checking the flag, so a drain either waits for it or the call is
+//! rejected. Entries left behind by instances that were killed mid-call are
+//! ignored once they are older than any call could be.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+use crate::diagnostics::{is_authorized, json_response};
+use crate::mcp_types::{ErrorCode, JsonRpcResponse};
+
+/// Path of the drain endpoint
+pub const DRAIN_PATH: &str = "/_ftl/drain";
+
+/// KV key prefix of in-flight tool calls
+const INVOCATION_KEY_PREFIX: &str = "gateway:inflight:";
+
+/// KV key set while the gateway drains
+const DRAINING_KEY: &str = "gateway:draining";
+
+/// In-flight entries older than this were left by killed instances
+const STALE_INVOCATION_SECS: u64 = 10 * 60;
+
+/// How long `POST /_ftl/drain` waits for in-flight calls by default
+const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 30;
+
+/// Upper bound of the `timeout_secs` query parameter
+const MAX_DRAIN_TIMEOUT_SECS: u64 = 300;
+
+/// How often a drain checks the in-flight calls
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Retry delay suggested to callers rejected during a drain
+const RETRY_AFTER_SECS: u64 = 5;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// A tool call being handled by some gateway instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InFlightCall {
+    pub tool: String,
+    /// Unix timestamp (seconds) at which the call started
+    pub started_at: u64,
+}
+
+/// Drain state stored in the KV store
+#[derive(Debug, Serialize, Deserialize)]
+struct Draining {
+    /// Unix timestamp (seconds) at which the drain started
+    since: u64,
+}
+
+/// Tracks the tool calls in flight across gateway instances
+pub struct InvocationTracker {
+    store: Store,
+}
+
+/// Registration of an in-flight tool call, removed when dropped
+pub struct Invocation {
+    tracker: InvocationTracker,
+    key: Option<String>,
+}
+
+impl Drop for Invocation {
+    fn drop(&mut self) {
+        if let Some(key) = &self.key {
+            let _ = self.tracker.store.delete(key);
+        }
+    }
+}
+
+impl InvocationTracker {
+    /// Open the tracker, or `None` when the KV store is unavailable
+    pub fn open() -> Option<Self> {
+        Store::open_default().ok().map(|store| Self { store })
+    }
+
+    /// Register a tool call and return its key
+    fn register(&self, tool: &str) -> Option<String> {
+        let key = format!("{INVOCATION_KEY_PREFIX}{}", uuid::Uuid::new_v4().simple());
+        let call = InFlightCall {
+            tool: tool.to_string(),
+            started_at: now(),
+        };
+        let data = serde_json::to_vec(&call).ok()?;
+        self.store.set(&key, &data).ok()?;
+        Some(key)
+    }
+
+    /// Tool calls currently in flight. Stale entries are removed.
+    pub fn in_flight(&self) -> Vec<InFlightCall> {
+        let now = now();
+        self.store
+            .get_keys()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|key| key.starts_with(INVOCATION_KEY_PREFIX))
+            .filter_map(|key| {
+                let call = self
+                    .store
+                    .get(&key)
+                    .ok()
+                    .flatten()
+                    .and_then(|data| serde_json::from_slice::<InFlightCall>(&data).ok());
+                match call {
+                    Some(call) if now.saturating_sub(call.started_at) <= STALE_INVOCATION_SECS => {
+                        Some(call)
+                    }
+                    _ => {
+                        let _ = self.store.delete(&key);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Unix timestamp at which the current drain started through the endpoint
+    fn draining_since(&self) -> Option<u64> {
+        self.store
+            .get(DRAINING_KEY)
+            .ok()
+            .flatten()
+            .and_then(|data| serde_json::from_slice::<Draining>(&data).ok())
+            .map(|draining| draining.since)
+    }
+}
+
+/// Whether the `draining` variable is set
+fn draining_variable() -> bool {
+    variables::get("draining")
+        .ok()
+        .and_then(|value| value.trim().parse::<bool>().ok())
+        .unwrap_or(false)
+}
+
+/// Whether new tool calls are rejected
+fn is_draining(tracker: &InvocationTracker) -> bool {
+    draining_variable() || tracker.draining_since().is_some()
+}
+
+/// Whether the gateway is draining, from the variable or a drain started
+/// through the endpoint
+pub fn is_draining_now() -> bool {
+    draining_variable()
+        || InvocationTracker::open().is_some_and(|tracker| tracker.draining_since().is_some())
+}
+
+/// Register a tool call unless the gateway is draining
+///
+/// The call stays in flight until the returned registration is dropped.
+/// Calls are let through untracked when the KV store is unavailable.
+pub fn begin_tool_call(
+    id: Option<serde_json::Value>,
+    tool: &str,
+) -> Result<Option<Invocation>, Response> {
+    if draining_variable() {
+        return Err(rejected(id));
+    }
+    let Some(tracker) = InvocationTracker::open() else {
+        return Ok(None);
+    };
+
+    // Register before checking the flag, so a drain starting in between
+    // waits for this call
+    let key = tracker.register(tool);
+    let invocation = Invocation { tracker, key };
+    if invocation.tracker.draining_since().is_some() {
+        return Err(rejected(id));
+    }
+    Ok(Some(invocation))
+}
+
+/// Retryable error for tool calls received while the gateway drains
+fn rejected(id: Option<serde_json::Value>) -> Response {
+    let error = JsonRpcResponse::error_with_data(
+        id,
+        ErrorCode::UNAVAILABLE.0,
+        "The gateway is shutting down, retry the call",
+        serde_json::json!({
+            "retryable": true,
+            "retry_after_secs": RETRY_AFTER_SECS,
+        }),
+    );
+    Response::builder()
+        .status(503)
+        .header("Content-Type", "application/json")
+        .header("Access-Control-Allow-Origin", "*")
+        .header("Access-Control-Expose-Headers", "Retry-After")
+        .header("Retry-After", RETRY_AFTER_SECS.to_string())
+        .body(serde_json::to_vec(&error).unwrap_or_else(|_| {
+            br#"{"jsonrpc":"2.0","error":{"code":-32603,"message":"Internal serialization error"}}"#.to_vec()
+        }))
+        .build()
+}
+
+/// Handle requests to `/_ftl/drain`
+///
+/// The endpoint is disabled (404) unless the `config_token` variable is set,
+/// and requires `Authorization: Bearer <config_token>`.
+pub fn handle_drain(req: &Request) -> Response {
+    let token = variables::get("config_token").unwrap_or_default();
+    if token.trim().is_empty() {
+        return json_response(404, &serde_json::json!({ "error": "Not found" }));
+    }
+
+    if !is_authorized(req, token.trim()) {
+        return Response::builder()
+            .status(401)
+            .header("Content-Type", "application/json")
+            .header("WWW-Authenticate", "Bearer")
+            .body(br#"{"error":"Unauthorized"}"#.to_vec())
+            .build();
+    }
+
+    let Some(tracker) = InvocationTracker::open() else {
+        return json_response(
+            503,
+            &serde_json::json!({ "error": "Drain storage is unavailable" }),
+        );
+    };
+
+    match req.method() {
+        Method::Get => json_response(200, &describe(&tracker, &tracker.in_flight())),
+        Method::Post => start_drain(req, &tracker),
+        Method::Delete => {
+            let _ = tracker.store.delete(DRAINING_KEY);
+            eprintln!("Drain cancelled, accepting tool calls");
+            json_response(200, &describe(&tracker, &tracker.in_flight()))
+        }
+        _ => Response::builder()
+            .status(405)
+            .header("Allow", "GET, POST, DELETE")
+            .body(b"Method not allowed".to_vec())
+            .build(),
+    }
+}
+
+/// Start draining and wait for the in-flight calls, up to `timeout_secs`
+///
+/// Returns `200` once no call is in flight, or `202` with the remaining calls
+/// when the timeout expires first.
+fn start_drain(req: &Request, tracker: &InvocationTracker) -> Response {
+    let timeout_secs = match drain_timeout(req.query()) {
+        Ok(timeout_secs) => timeout_secs,
+        Err(e) => return json_response(400, &serde_json::json!({ "error": e })),
+    };
+
+    if tracker.draining_since().is_none() {
+        let stored = serde_json::to_vec(&Draining { since: now() })
+            .ok()
+            .is_some_and(|data| tracker.store.set(DRAINING_KEY, &data).is_ok());
+        if !stored {
+            return json_response(
+                503,
+                &serde_json::json!({ "error": "Drain storage is unavailable" }),
+            );
+        }
+        eprintln!("Draining: rejecting new tool calls");
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    loop {
+        let calls = tracker.in_flight();
+        if calls.is_empty() {
+            eprintln!("Drained: no tool calls in flight");
+            return json_response(200, &describe(tracker, &calls));
+        }
+        if Instant::now() >= deadline {
+            eprintln!(
+                "Drain timed out after {timeout_secs}s with {} tool call(s) in flight",
+                calls.len()
+            );
+            return json_response(202, &describe(tracker, &calls));
+        }
+        std::thread::sleep(DRAIN_POLL_INTERVAL);
+    }
+}
+
+/// Parse the `timeout_secs` query parameter
+fn drain_timeout(query: &str) -> Result<u64, String> {
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("timeout_secs="))
+        .map_or(Ok(DEFAULT_DRAIN_TIMEOUT_SECS), |value| {
+            value
+                .parse::<u64>()
+                .ok()
+                .filter(|secs| *secs <= MAX_DRAIN_TIMEOUT_SECS)
+                .ok_or_else(|| {
+                    format!("Invalid timeout_secs: expected 0 to {MAX_DRAIN_TIMEOUT_SECS}")
+                })
+        })
+}
+
+/// The drain state and the calls in flight
+fn describe(tracker: &InvocationTracker, calls: &[InFlightCall]) -> serde_json::Value {
+    serde_json::json!({
+        "draining": is_draining(tracker),
+        "since": tracker.draining_since(),
+        "variable": draining_variable(),
+        "in_flight": calls.len(),
+        "calls": calls,
+    })
+}
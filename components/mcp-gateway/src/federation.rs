@@ -0,0 +1,622 @@
+//! Tools of remote MCP servers
+//!
+//! Existing MCP servers can be federated into the gateway, so their tools are
+//! served alongside the tool components. Servers are configured through the
+//! `federated_servers` variable as a JSON document keyed by namespace:
+//!
+//! ```json
+//! {
+//!   "github": {
+//!     "url": "https://api.githubcopilot.com/mcp/",
+//!     "token": "ghp_...",
+//!     "headers": { "X-Org": "acme" },
+//!     "timeout_secs": 30
+//!   }
+//! }
+//! ```
+//!
+//! - `url`: streamable HTTP endpoint of the server
+//! - `token`: sent as a bearer token, none when omitted
+//! - `headers`: sent with every request to the server, e.g. API keys
+//! - `timeout_secs`: listing or calling tools still running after this long
+//!   fails, including the session handshake (default 30)
+//!
+//! A namespace stands in for a component name: the server's tools are listed
+//! as `github__<tool>` at `/mcp` and unprefixed at `/mcp/x/github`, and
+//! aliases, transforms, rate limits, toolsets and `component_limits` refer to
+//! them by namespace. Calls are checked like calls to component tools: the
+//! required scopes of the tool must be granted, and its content must be valid.
+//! The caller context headers of the authorizer are not forwarded to remote
+//! servers.
+//!
+//! The session negotiated with each server is kept in the default KV store
+//! and renewed when the server expires it, and so are the tools it last
+//! listed, which calls are checked against for up to a minute. A server that
+//! fails or times out is left out of `tools/list` and only fails the calls to
+//! its own tools.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::{self, Either};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+
+use crate::gateway::{MAX_ERROR_BODY_BYTES, is_component_name, truncate_utf8};
+use crate::limits::sleep;
+use crate::mcp_types::{
+    ErrorCode, JsonRpcError, JsonRpcResponse, McpProtocolVersion, ToolMetadata,
+};
+use crate::session::{PROTOCOL_VERSION_HEADER, SESSION_HEADER};
+
+/// KV key prefix of the sessions negotiated with remote servers
+const SESSION_KEY_PREFIX: &str = "gateway:federation:";
+
+/// KV key prefix of the tools last listed by remote servers
+const TOOLS_KEY_PREFIX: &str = "gateway:federation-tools:";
+
+/// Tools listed this recently are not listed again to check a call
+const TOOLS_CACHE_SECS: u64 = 60;
+
+/// Pages of tools fetched from a server at most
+const MAX_LIST_PAGES: usize = 10;
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// A remote MCP server
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteServer {
+    pub url: String,
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+/// Federated servers by namespace
+#[derive(Debug, Clone, Default)]
+pub struct Federation {
+    servers: BTreeMap<String, RemoteServer>,
+}
+
+impl Federation {
+    /// Parse a federation configuration document. Namespaces share the
+    /// tool name prefixes of components, so they can't name one.
+    pub fn parse(json: &str, components: &[String]) -> Result<Self, String> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let servers: BTreeMap<String, RemoteServer> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid federated_servers: {e}"))?;
+        for (namespace, server) in &servers {
+            if !is_component_name(namespace) || namespace.contains("__") {
+                return Err(format!(
+                    "Invalid federated_servers: '{namespace}' is not a valid namespace"
+                ));
+            }
+            if components.contains(namespace) {
+                return Err(format!(
+                    "Invalid federated_servers: '{namespace}' is already a component"
+                ));
+            }
+            if !server.url.starts_with("https://") && !server.url.starts_with("http://") {
+                return Err(format!(
+                    "Invalid federated_servers for '{namespace}': url must be an http(s) URL"
+                ));
+            }
+            if server.timeout_secs == 0 {
+                return Err(format!(
+                    "Invalid federated_servers for '{namespace}': timeout_secs must be at least 1"
+                ));
+            }
+        }
+        Ok(Self { servers })
+    }
+
+    /// Load the federation configuration from the `federated_servers` variable
+    pub fn load(components: &[String]) -> Result<Self, String> {
+        Self::parse(
+            &spin_sdk::variables::get("federated_servers").unwrap_or_default(),
+            components,
+        )
+    }
+
+    /// The server federated under a namespace
+    pub fn get(&self, namespace: &str) -> Option<&RemoteServer> {
+        self.servers.get(namespace)
+    }
+
+    /// Whether a namespace names a federated server
+    pub fn contains(&self, namespace: &str) -> bool {
+        self.servers.contains_key(namespace)
+    }
+
+    /// Federated servers with their namespaces
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RemoteServer)> {
+        self.servers
+            .iter()
+            .map(|(namespace, server)| (namespace.as_str(), server))
+    }
+}
+
+/// A failed request to a remote server
+#[derive(Debug)]
+pub enum RemoteError {
+    /// The server answered with a JSON-RPC error
+    Rpc(JsonRpcError),
+    /// The server did not answer within `timeout_secs`
+    Timeout { secs: u64 },
+    /// The server could not be reached or did not answer with a JSON-RPC
+    /// response
+    Failed {
+        reason: String,
+        /// HTTP status returned by the server, if it responded
+        status: Option<u16>,
+        /// Response body or transport error detail
+        detail: String,
+    },
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rpc(error) => write!(f, "error {}: {}", error.code, error.message),
+            Self::Timeout { secs } => write!(f, "no response within {secs}s"),
+            Self::Failed { reason, .. } => f.write_str(reason),
+        }
+    }
+}
+
+impl RemoteError {
+    fn failed(reason: impl Into<String>, status: Option<u16>, body: &[u8]) -> Self {
+        Self::Failed {
+            reason: reason.into(),
+            status,
+            detail: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+
+    /// Convert into a JSON-RPC error. Errors of the server are passed on, as
+    /// they are meant for its clients, while details about failed requests
+    /// are only included in debug mode.
+    pub fn into_response(
+        self,
+        id: Option<Value>,
+        tool_name: &str,
+        namespace: &str,
+        debug: bool,
+    ) -> JsonRpcResponse {
+        eprintln!("Tool '{tool_name}' failed in remote server '{namespace}': {self}");
+
+        match self {
+            Self::Rpc(error) => JsonRpcResponse::error_with_data(
+                id,
+                error.code,
+                &format!("Remote server '{namespace}': {}", error.message),
+                serde_json::json!({
+                    "server": namespace,
+                    "data": error.data,
+                }),
+            ),
+            Self::Timeout { secs } => JsonRpcResponse::error_with_data(
+                id,
+                ErrorCode::INTERNAL_ERROR.0,
+                &format!(
+                    "Tool '{tool_name}' timed out after {secs}s in remote server '{namespace}'"
+                ),
+                serde_json::json!({
+                    "server": namespace,
+                    "timeout_secs": secs,
+                    "retryable": true,
+                }),
+            ),
+            Self::Failed {
+                reason,
+                status,
+                detail,
+            } => {
+                let message = format!("Tool '{tool_name}' failed in remote server '{namespace}'");
+                if !debug {
+                    return JsonRpcResponse::error_with_data(
+                        id,
+                        ErrorCode::INTERNAL_ERROR.0,
+                        &message,
+                        serde_json::json!({ "server": namespace }),
+                    );
+                }
+
+                let (excerpt, truncated) = truncate_utf8(&detail, MAX_ERROR_BODY_BYTES);
+                JsonRpcResponse::error_with_data(
+                    id,
+                    ErrorCode::INTERNAL_ERROR.0,
+                    &format!("{message}: {reason}"),
+                    serde_json::json!({
+                        "server": namespace,
+                        "tool": tool_name,
+                        "upstream_status": status,
+                        "body": excerpt,
+                        "body_truncated": truncated,
+                    }),
+                )
+            }
+        }
+    }
+}
+
+/// Session negotiated with a remote server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RemoteSession {
+    /// Endpoint the session was negotiated with, so a changed `url` starts
+    /// a new one
+    url: String,
+    /// `Mcp-Session-Id` assigned by the server, none for stateless servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    protocol_version: String,
+}
+
+/// Tools last listed by a remote server
+#[derive(Debug, Serialize, Deserialize)]
+struct ListedTools {
+    /// Endpoint that listed the tools, so a changed `url` lists them again
+    url: String,
+    /// Unix time the tools were listed at
+    listed_at: u64,
+    tools: Vec<ToolMetadata>,
+}
+
+/// A page of `tools/list` results
+#[derive(Debug, Deserialize)]
+struct ToolsPage {
+    #[serde(default)]
+    tools: Vec<Value>,
+    #[serde(rename = "nextCursor", default)]
+    next_cursor: Option<String>,
+}
+
+impl RemoteServer {
+    /// List the tools of the server. Tools with metadata the gateway can't
+    /// represent are skipped.
+    pub async fn list_tools(&self, namespace: &str) -> Result<Vec<ToolMetadata>, RemoteError> {
+        let tools = self.with_timeout(async {
+            let mut tools = Vec::new();
+            let mut cursor: Option<String> = None;
+            for _ in 0..MAX_LIST_PAGES {
+                let params = cursor.take().map_or_else(
+                    || serde_json::json!({}),
+                    |cursor| serde_json::json!({ "cursor": cursor }),
+                );
+                let result = self.request(namespace, "tools/list", params).await?;
+                let page: ToolsPage = serde_json::from_value(result).map_err(|e| {
+                    RemoteError::failed(format!("invalid tools/list result: {e}"), None, &[])
+                })?;
+                for tool in page.tools {
+                    match serde_json::from_value::<ToolMetadata>(tool) {
+                        Ok(tool) => tools.push(tool),
+                        Err(e) => {
+                            eprintln!("Skipping tool with invalid metadata in remote server '{namespace}': {e}");
+                        }
+                    }
+                }
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => return Ok(tools),
+                }
+            }
+            eprintln!(
+                "Remote server '{namespace}' lists more than {MAX_LIST_PAGES} pages of tools, leaving out the rest"
+            );
+            Ok(tools)
+        })
+        .await?;
+        self.save_tools(namespace, &tools);
+        Ok(tools)
+    }
+
+    /// Metadata of a tool of the server, as it was listed less than
+    /// `TOOLS_CACHE_SECS` ago, or as the server lists it now
+    pub async fn tool(
+        &self,
+        namespace: &str,
+        tool_name: &str,
+    ) -> Result<Option<ToolMetadata>, RemoteError> {
+        if let Some(tool) = self
+            .load_tools(namespace)
+            .and_then(|tools| tools.into_iter().find(|tool| tool.name == tool_name))
+        {
+            return Ok(Some(tool));
+        }
+        Ok(self
+            .list_tools(namespace)
+            .await?
+            .into_iter()
+            .find(|tool| tool.name == tool_name))
+    }
+
+    /// Call a tool of the server, returning its result as is
+    pub async fn call_tool(
+        &self,
+        namespace: &str,
+        tool_name: &str,
+        arguments: Value,
+    ) -> Result<Value, RemoteError> {
+        let params = serde_json::json!({ "name": tool_name, "arguments": arguments });
+        self.with_timeout(self.request(namespace, "tools/call", params))
+            .await
+    }
+
+    async fn with_timeout<T>(
+        &self,
+        call: impl Future<Output = Result<T, RemoteError>>,
+    ) -> Result<T, RemoteError> {
+        let secs = self.timeout_secs;
+        match future::select(pin!(call), pin!(sleep(Duration::from_secs(secs)))).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(RemoteError::Timeout { secs }),
+        }
+    }
+
+    /// Send a request in the session with the server, negotiating one first
+    /// when there is none
+    async fn request(
+        &self,
+        namespace: &str,
+        method: &str,
+        params: Value,
+    ) -> Result<Value, RemoteError> {
+        let message = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "method": method,
+            "params": params,
+        });
+
+        let session = match self.load_session(namespace) {
+            Some(session) => session,
+            None => self.initialize(namespace).await?,
+        };
+        let response = self.send(&message, Some(&session)).await?;
+
+        // Servers answer 404 to sessions they expired, which takes a new one
+        if *response.status() == 404 && session.id.is_some() {
+            delete_session(namespace);
+            let session = self.initialize(namespace).await?;
+            let response = self.send(&message, Some(&session)).await?;
+            return read_response(&response);
+        }
+        read_response(&response)
+    }
+
+    /// Negotiate a session with the server
+    async fn initialize(&self, namespace: &str) -> Result<RemoteSession, RemoteError> {
+        let latest = McpProtocolVersion::V2025_06_18.as_str();
+        let message = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "method": "initialize",
+            "params": {
+                "protocolVersion": latest,
+                "capabilities": {},
+                "clientInfo": {
+                    "name": "ftl-mcp-gateway",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            },
+        });
+        let response = self.send(&message, None).await?;
+        let result = read_response(&response)?;
+
+        let session = RemoteSession {
+            url: self.url.clone(),
+            id: response
+                .header(SESSION_HEADER)
+                .and_then(|value| value.as_str())
+                .map(ToString::to_string),
+            protocol_version: result
+                .get("protocolVersion")
+                .and_then(Value::as_str)
+                .unwrap_or(latest)
+                .to_string(),
+        };
+
+        // Servers accept the notification without a JSON-RPC response
+        let initialized = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/initialized",
+        });
+        match self.send(&initialized, Some(&session)).await {
+            Ok(response) if !(200..300).contains(response.status()) => {
+                eprintln!(
+                    "Remote server '{namespace}' returned status {} for notifications/initialized",
+                    response.status()
+                );
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!(
+                    "Remote server '{namespace}' did not accept notifications/initialized: {e}"
+                );
+            }
+        }
+
+        save_session(namespace, &session);
+        Ok(session)
+    }
+
+    async fn send(
+        &self,
+        message: &Value,
+        session: Option<&RemoteSession>,
+    ) -> Result<Response, RemoteError> {
+        let mut builder = Request::builder();
+        builder
+            .method(Method::Post)
+            .uri(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json, text/event-stream");
+        if let Some(session) = session {
+            builder.header(PROTOCOL_VERSION_HEADER, session.protocol_version.as_str());
+            if let Some(ref id) = session.id {
+                builder.header(SESSION_HEADER, id.as_str());
+            }
+        }
+        if let Some(ref token) = self.token {
+            builder.header("Authorization", format!("Bearer {token}"));
+        }
+        for (name, value) in &self.headers {
+            builder.header(name.as_str(), value.as_str());
+        }
+        let req = builder
+            .body(serde_json::to_vec(message).unwrap_or_default())
+            .build();
+
+        spin_sdk::http::send::<_, Response>(req)
+            .await
+            .map_err(|e| RemoteError::Failed {
+                reason: "server could not be reached".to_string(),
+                status: None,
+                detail: e.to_string(),
+            })
+    }
+
+    fn load_session(&self, namespace: &str) -> Option<RemoteSession> {
+        let store = Store::open_default().ok()?;
+        let data = store.get(&session_key(namespace)).ok()??;
+        serde_json::from_slice::<RemoteSession>(&data)
+            .ok()
+            .filter(|session| session.url == self.url)
+    }
+
+    fn load_tools(&self, namespace: &str) -> Option<Vec<ToolMetadata>> {
+        let store = Store::open_default().ok()?;
+        let data = store.get(&tools_key(namespace)).ok()??;
+        serde_json::from_slice::<ListedTools>(&data)
+            .ok()
+            .filter(|listed| {
+                listed.url == self.url && now().saturating_sub(listed.listed_at) < TOOLS_CACHE_SECS
+            })
+            .map(|listed| listed.tools)
+    }
+
+    fn save_tools(&self, namespace: &str, tools: &[ToolMetadata]) {
+        let listed = ListedTools {
+            url: self.url.clone(),
+            listed_at: now(),
+            tools: tools.to_vec(),
+        };
+        if let (Ok(store), Ok(data)) = (Store::open_default(), serde_json::to_vec(&listed)) {
+            let _ = store.set(&tools_key(namespace), &data);
+        }
+    }
+}
+
+fn tools_key(namespace: &str) -> String {
+    format!("{TOOLS_KEY_PREFIX}{namespace}")
+}
+
+fn session_key(namespace: &str) -> String {
+    format!("{SESSION_KEY_PREFIX}{namespace}")
+}
+
+fn save_session(namespace: &str, session: &RemoteSession) {
+    if let (Ok(store), Ok(data)) = (Store::open_default(), serde_json::to_vec(session)) {
+        let _ = store.set(&session_key(namespace), &data);
+    }
+}
+
+fn delete_session(namespace: &str) {
+    if let Ok(store) = Store::open_default() {
+        let _ = store.delete(&session_key(namespace));
+    }
+}
+
+/// Read the result of a request from the server's response, sent as JSON or
+/// as an event stream
+fn read_response(response: &Response) -> Result<Value, RemoteError> {
+    let status = *response.status();
+    let body = response.body();
+    if !(200..300).contains(&status) {
+        return Err(RemoteError::failed(
+            format!("server returned status {status}"),
+            Some(status),
+            body,
+        ));
+    }
+
+    let event_stream = response
+        .header("content-type")
+        .and_then(|value| value.as_str())
+        .is_some_and(|content_type| content_type.trim_start().starts_with("text/event-stream"));
+    let message = if event_stream {
+        event_stream_response(body)
+    } else {
+        serde_json::from_slice::<Value>(body)
+            .ok()
+            .filter(is_response)
+    };
+    let Some(mut message) = message else {
+        return Err(RemoteError::failed(
+            "server did not answer with a JSON-RPC response",
+            Some(status),
+            body,
+        ));
+    };
+
+    if let Some(error) = message.get_mut("error").map(Value::take) {
+        return Err(match serde_json::from_value::<JsonRpcError>(error) {
+            Ok(error) => RemoteError::Rpc(error),
+            Err(e) => {
+                RemoteError::failed(format!("invalid JSON-RPC error: {e}"), Some(status), body)
+            }
+        });
+    }
+    Ok(message
+        .get_mut("result")
+        .map(Value::take)
+        .unwrap_or_default())
+}
+
+fn is_response(message: &Value) -> bool {
+    message.get("result").is_some() || message.get("error").is_some()
+}
+
+/// The first JSON-RPC response among the events of a stream. Servers may
+/// send notifications and requests before it, which are skipped.
+fn event_stream_response(body: &[u8]) -> Option<Value> {
+    let text = String::from_utf8_lossy(body);
+    let mut data = String::new();
+    // A trailing blank line dispatches an event the stream ended without
+    for line in text.lines().chain(std::iter::once("")) {
+        if line.is_empty() {
+            if let Ok(message) = serde_json::from_str::<Value>(&data)
+                && is_response(&message)
+            {
+                return Some(message);
+            }
+            data.clear();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            if !data.is_empty() {
+                data.push('\n');
+            }
+            data.push_str(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+    None
+}
@@ -1,24 +1,93 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Instant;
+
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{Method, Request, Response};
 use spin_sdk::variables;
 
+use crate::alias::{self, ToolAliases};
+use crate::blob::{self, ResolvedBlob};
+use crate::diagnostics;
+use crate::drain;
+use crate::federation::Federation;
+use crate::health;
+use crate::limits::{ComponentLimits, DEADLINE_HEADER, LimitExceeded, Limits, Slot};
+use crate::live_config::{self, LiveConfig};
 use crate::mcp_types::{
     CallToolRequest, ErrorCode, InitializeRequest, InitializeResponse, JsonRpcRequest,
-    JsonRpcResponse, ListToolsResponse, McpProtocolVersion, ServerCapabilities, ServerInfo,
-    ToolContent, ToolMetadata, ToolResponse,
+    JsonRpcResponse, JsonRpcResult, ListToolsResponse, McpProtocolVersion, ServerCapabilities,
+    ServerInfo, ToolMetadata, ToolResponse,
 };
+use crate::negotiation;
+use crate::rate_limit::{Quota, RateLimits};
+use crate::session::{self, PROTOCOL_VERSION_HEADER, SESSION_HEADER, SESSION_KEY_HEADER, Session};
+use crate::transform::Transforms;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GatewayConfig {
     pub server_info: ServerInfo,
     #[serde(default = "default_validate_arguments")]
     pub validate_arguments: bool,
+    /// Include upstream status, body excerpts and timings in tool call errors
+    #[serde(default)]
+    pub debug_errors: bool,
+    /// Components from the live configuration, replacing `component_names`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_names: Option<Vec<String>>,
+    /// Version of the live configuration in effect, 0 without one
+    #[serde(default)]
+    pub config_version: u64,
 }
 
 fn default_validate_arguments() -> bool {
     true
 }
 
+impl GatewayConfig {
+    /// Build the gateway configuration from Spin variables, with the live
+    /// configuration overrides applied
+    pub(crate) fn load_with(live: &LiveConfig) -> Self {
+        let validate_arguments = live.validate_arguments.unwrap_or_else(|| {
+            variables::get("validate_arguments")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse::<bool>()
+                .unwrap_or(true)
+        });
+
+        let debug_errors = live.debug_errors.unwrap_or_else(|| {
+            variables::get("debug_errors")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false)
+        });
+
+        Self {
+            server_info: ServerInfo {
+                name: "mcp-gateway".to_string(),
+                version: "0.0.1".to_string(),
+            },
+            validate_arguments,
+            debug_errors,
+            component_names: live.component_names.clone(),
+            config_version: live.version,
+        }
+    }
+
+    /// Components the gateway routes to
+    pub(crate) fn components(&self) -> Result<Vec<String>, String> {
+        self.component_names
+            .clone()
+            .map_or_else(configured_components, Ok)
+    }
+
+    /// Whether a configured component routes tool calls
+    fn routes_to(&self, name: &str) -> bool {
+        self.components()
+            .is_ok_and(|components| components.iter().any(|c| c == name))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ToolScope {
     pub component: Option<String>,
@@ -84,10 +153,151 @@ impl ToolScope {
     }
 }
 
+/// Caller context headers set by the authorizer that are passed on to tool
+/// components, so tools can see the verified identity and trace id
+const FORWARDED_CONTEXT_HEADERS: &[&str] = &[
+    "x-auth-client-id",
+    "x-auth-user-id",
+    "x-auth-issuer",
+    "x-auth-scopes",
+    "x-auth-assertion",
+    "x-trace-id",
+];
+
+/// Maximum number of upstream response body bytes included in debug errors
+pub(crate) const MAX_ERROR_BODY_BYTES: usize = 1024;
+
+/// A failed call to a tool component
+#[derive(Debug)]
+struct UpstreamError {
+    /// What went wrong, e.g. the status the component returned
+    reason: String,
+    /// HTTP status returned by the component, if it responded
+    status: Option<u16>,
+    /// Response body or transport error detail
+    detail: String,
+    elapsed_ms: u64,
+    /// Limit of the component the call ran into
+    limit: Option<LimitExceeded>,
+}
+
+impl UpstreamError {
+    /// Convert into a JSON-RPC error. Details about the upstream failure are
+    /// only included in debug mode so production responses do not leak
+    /// component internals.
+    fn into_response(
+        self,
+        id: Option<serde_json::Value>,
+        tool_name: &str,
+        component_name: &str,
+        debug: bool,
+    ) -> JsonRpcResponse {
+        eprintln!(
+            "Tool '{tool_name}' failed in component '{component_name}' after {}ms: {}",
+            self.elapsed_ms, self.reason
+        );
+
+        // Limits are part of the configuration, so callers learn which one
+        // they ran into
+        if let Some(limit) = self.limit {
+            return limit.into_response(id, tool_name);
+        }
+
+        let message = format!("Tool '{tool_name}' failed in component '{component_name}'");
+        if !debug {
+            return JsonRpcResponse::error_with_data(
+                id,
+                ErrorCode::INTERNAL_ERROR.0,
+                &message,
+                serde_json::json!({ "component": component_name }),
+            );
+        }
+
+        let (excerpt, truncated) = truncate_utf8(&self.detail, MAX_ERROR_BODY_BYTES);
+        JsonRpcResponse::error_with_data(
+            id,
+            ErrorCode::INTERNAL_ERROR.0,
+            &format!("{message}: {}", self.reason),
+            serde_json::json!({
+                "component": component_name,
+                "tool": tool_name,
+                "upstream_status": self.status,
+                "elapsed_ms": self.elapsed_ms,
+                "body": excerpt,
+                "body_truncated": truncated,
+            }),
+        )
+    }
+}
+
+/// Truncate to at most `max` bytes on a character boundary
+pub(crate) fn truncate_utf8(s: &str, max: usize) -> (&str, bool) {
+    if s.len() <= max {
+        return (s, false);
+    }
+    let end = (0..=max)
+        .rev()
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(0);
+    (s.get(..end).unwrap_or_default(), true)
+}
+
+/// Components listed in the `component_names` variable.
+/// Names that are not valid component ids are skipped, so they can never
+/// end up in a `*.spin.internal` URL.
+fn configured_components() -> Result<Vec<String>, String> {
+    let names = variables::get("component_names")
+        .map_err(|e| format!("Failed to get components configuration: {e}"))?;
+    Ok(names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter(|name| {
+            let valid = is_component_name(name);
+            if !valid {
+                eprintln!("Ignoring invalid component name in component_names: {name}");
+            }
+            valid
+        })
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Whether a name is a valid component id: lowercase letters, digits, `-`
+/// and `_` (mapped to `-` in the component's host name)
+pub(crate) fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Whether a name is a valid MCP tool name (1-128 of `A-Z a-z 0-9 _ - .`).
+/// Tool names become the path of the call to the component, so this keeps
+/// them to a single path segment.
+pub(crate) fn is_tool_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 128
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
 pub struct McpGateway {
     config: GatewayConfig,
     scope: Option<ToolScope>,
     allowed_toolsets: Option<Vec<String>>,
+    forwarded_headers: Vec<(String, String)>,
+    aliases: ToolAliases,
+    transforms: Transforms,
+    rate_limits: RateLimits,
+    limits: ComponentLimits,
+    federation: Federation,
+    /// Budget of the caller after the last rate-limited tool call
+    quota: Cell<Option<Quota>>,
+    /// Tool the last call to a deprecated name was routed to
+    replaced_by: RefCell<Option<String>>,
 }
 
 impl McpGateway {
@@ -100,7 +310,132 @@ impl McpGateway {
             config,
             scope,
             allowed_toolsets,
+            forwarded_headers: Vec::new(),
+            aliases: ToolAliases::default(),
+            transforms: Transforms::default(),
+            rate_limits: RateLimits::default(),
+            limits: ComponentLimits::default(),
+            federation: Federation::default(),
+            quota: Cell::new(None),
+            replaced_by: RefCell::new(None),
+        }
+    }
+
+    /// Forward the caller context headers of an incoming request to tool calls
+    #[must_use]
+    pub fn with_forwarded_headers(mut self, req: &Request) -> Self {
+        self.forwarded_headers = forwarded_context_headers(req);
+        self
+    }
+
+    /// Forward the session key of the caller to tool calls, derived from the
+    /// session the request belongs to or the forwarded caller identity
+    #[must_use]
+    pub fn with_session_key(mut self, session_id: Option<&str>) -> Self {
+        let caller = self.caller();
+        let issuer = self
+            .forwarded_headers
+            .iter()
+            .find(|(name, _)| name == "x-auth-issuer")
+            .map(|(_, value)| value.as_str());
+        if let Some(key) = session::session_key(session_id, issuer, caller.as_deref()) {
+            self.forwarded_headers
+                .push((SESSION_KEY_HEADER.to_string(), key));
         }
+        self
+    }
+
+    /// Route calls to deprecated tool names to their replacements
+    #[must_use]
+    pub fn with_aliases(mut self, aliases: ToolAliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Apply per-tool request and response transforms to tool calls
+    #[must_use]
+    pub fn with_transforms(mut self, transforms: Transforms) -> Self {
+        self.transforms = transforms;
+        self
+    }
+
+    /// Limit the tool calls of each caller
+    #[must_use]
+    pub fn with_rate_limits(mut self, rate_limits: RateLimits) -> Self {
+        self.rate_limits = rate_limits;
+        self
+    }
+
+    /// Enforce the duration, response size and concurrency limits of each
+    /// component on its tool calls
+    #[must_use]
+    pub fn with_limits(mut self, limits: ComponentLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Serve the tools of remote MCP servers under their namespaces
+    #[must_use]
+    pub fn with_federation(mut self, federation: Federation) -> Self {
+        self.federation = federation;
+        self
+    }
+
+    /// Budget of the caller after the last rate-limited tool call
+    pub fn quota(&self) -> Option<Quota> {
+        self.quota.get()
+    }
+
+    /// Tool the last call to a deprecated name was routed to
+    pub fn replaced_by(&self) -> Option<String> {
+        self.replaced_by.borrow().clone()
+    }
+
+    /// Identity of the authenticated caller, forwarded by the authorizer
+    fn caller(&self) -> Option<String> {
+        blob::owner(
+            self.forwarded_headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+    }
+
+    /// Scopes granted to the caller, forwarded by the authorizer
+    fn granted_scopes(&self) -> Vec<&str> {
+        self.forwarded_headers
+            .iter()
+            .filter(|(name, _)| name == "x-auth-scopes")
+            .flat_map(|(_, value)| value.split_whitespace())
+            .collect()
+    }
+
+    /// Reject callers missing any scope the tool requires in its metadata
+    fn check_required_scopes(
+        &self,
+        request_id: Option<serde_json::Value>,
+        tool_name: &str,
+        metadata: &ToolMetadata,
+    ) -> Result<(), JsonRpcResponse> {
+        let required = metadata.required_scopes();
+        let granted = self.granted_scopes();
+        let missing: Vec<&str> = required
+            .iter()
+            .copied()
+            .filter(|scope| !granted.contains(scope))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(JsonRpcResponse::error_with_data(
+            request_id,
+            ErrorCode::PERMISSION_DENIED.0,
+            &format!("Permission denied for tool '{tool_name}'"),
+            serde_json::json!({
+                "tool": tool_name,
+                "required_scopes": required,
+                "missing_scopes": missing,
+            }),
+        ))
     }
 
     /// Convert `snake_case` to kebab-case for component names
@@ -109,7 +444,7 @@ impl McpGateway {
     }
 
     /// Fetch metadata for all tools in a component
-    async fn fetch_component_tools(&self, component_name: &str) -> Vec<ToolMetadata> {
+    pub(crate) async fn fetch_component_tools(&self, component_name: &str) -> Vec<ToolMetadata> {
         let component_name_kebab = Self::snake_to_kebab(component_name);
         let component_url = format!("http://{component_name_kebab}.spin.internal/");
 
@@ -147,16 +482,27 @@ impl McpGateway {
     }
 
     /// Validate tool arguments against the tool's input schema
+    ///
+    /// Errors under `skip` (JSON pointers of arguments replaced by uploaded
+    /// blobs) are ignored, as the blob content is only seen by the tool.
     fn validate_arguments(
         tool_name: &str,
         schema: &serde_json::Value,
         arguments: &serde_json::Value,
+        skip: &[&str],
     ) -> Result<(), String> {
         match jsonschema::validator_for(schema) {
             Ok(validator) => {
                 // Use iter_errors which returns an iterator
-                let errors: Vec<jsonschema::ValidationError<'_>> =
-                    validator.iter_errors(arguments).collect();
+                let errors: Vec<jsonschema::ValidationError<'_>> = validator
+                    .iter_errors(arguments)
+                    .filter(|error| {
+                        let path = error.instance_path.to_string();
+                        !skip.iter().any(|pointer| {
+                            path == *pointer || path.starts_with(&format!("{pointer}/"))
+                        })
+                    })
+                    .collect();
                 if errors.is_empty() {
                     Ok(())
                 } else {
@@ -178,7 +524,7 @@ impl McpGateway {
     pub async fn handle_request(&self, request: JsonRpcRequest) -> Option<JsonRpcResponse> {
         match request.method.as_str() {
             "initialize" => Some(self.handle_initialize(request)),
-            "initialized" => {
+            method if method == "initialized" || method.starts_with("notifications/") => {
                 // This is a notification, no response needed
                 None
             }
@@ -216,27 +562,38 @@ impl McpGateway {
             }
         };
 
-        if params.protocol_version != McpProtocolVersion::V1 {
-            return JsonRpcResponse::error(
+        let Some(protocol_version) = McpProtocolVersion::parse(&params.protocol_version) else {
+            return JsonRpcResponse::error_with_data(
                 request.id,
-                ErrorCode::INVALID_REQUEST.0,
+                ErrorCode::INVALID_PARAMS.0,
                 "Unsupported protocol version",
+                serde_json::json!({
+                    "supported": McpProtocolVersion::supported_strs(),
+                    "requested": params.protocol_version,
+                }),
             );
-        }
+        };
 
+        // Tools are always served; resources and prompts only when a
+        // component in scope declares them
+        let declared = self.declared_capabilities();
         let response = InitializeResponse {
-            protocol_version: McpProtocolVersion::V1,
+            protocol_version,
             capabilities: ServerCapabilities {
                 tools: Some(serde_json::json!({
                     "listChanged": true
                 })),
-                resources: Some(serde_json::json!({
-                    "subscribe": false,
-                    "listChanged": false
-                })),
-                prompts: Some(serde_json::json!({
-                    "listChanged": false
-                })),
+                resources: declared.contains("resources").then(|| {
+                    serde_json::json!({
+                        "subscribe": false,
+                        "listChanged": false
+                    })
+                }),
+                prompts: declared.contains("prompts").then(|| {
+                    serde_json::json!({
+                        "listChanged": false
+                    })
+                }),
                 experimental_capabilities: Some(serde_json::json!({
                     "logging": {}
                 })),
@@ -260,30 +617,56 @@ impl McpGateway {
         }
     }
 
+    /// Whether a component is visible through the request's path scope and
+    /// `X-MCP-Toolsets` header
+    fn component_in_scope(&self, component: &str) -> bool {
+        let scoped = self
+            .scope
+            .as_ref()
+            .and_then(|scope| scope.component.as_deref());
+        scoped.is_none_or(|scoped| scoped == component)
+            && self
+                .allowed_toolsets
+                .as_ref()
+                .is_none_or(|allowed| allowed.iter().any(|a| a == component))
+    }
+
+    /// Capabilities beyond tools declared by the components in scope through
+    /// the `component_capabilities` variable, e.g. `{"docs": ["resources"]}`
+    fn declared_capabilities(&self) -> BTreeSet<String> {
+        let raw = variables::get("component_capabilities").unwrap_or_default();
+        if raw.trim().is_empty() {
+            return BTreeSet::new();
+        }
+        match serde_json::from_str::<BTreeMap<String, Vec<String>>>(&raw) {
+            Ok(declared) => declared
+                .into_iter()
+                .filter(|(component, _)| self.component_in_scope(component))
+                .flat_map(|(_, capabilities)| capabilities)
+                .collect(),
+            Err(e) => {
+                eprintln!("Ignoring invalid component_capabilities: {e}");
+                BTreeSet::new()
+            }
+        }
+    }
+
     async fn handle_list_tools(&self, request: JsonRpcRequest) -> JsonRpcResponse {
-        // Get the list of components from the spin variable
-        let component_names_str = match variables::get("component_names") {
+        // Get the list of components from the configuration
+        let all_component_names = match self.config.components() {
             Ok(components) => components,
             Err(e) => {
-                return JsonRpcResponse::error(
-                    request.id,
-                    ErrorCode::INTERNAL_ERROR.0,
-                    &format!("Failed to get components configuration: {e}"),
-                );
+                return JsonRpcResponse::error(request.id, ErrorCode::INTERNAL_ERROR.0, &e);
             }
         };
 
-        // Parse the comma-separated list of component names
-        let all_component_names: Vec<&str> =
-            component_names_str.split(',').map(str::trim).collect();
-
         // Filter components based on scope and allowed_toolsets header
         let mut component_names = if let Some(ref scope) = self.scope {
             if let Some(ref component_filter) = scope.component {
                 // Only include the specified component if it exists
                 all_component_names
                     .into_iter()
-                    .filter(|name| *name == component_filter)
+                    .filter(|name| name == component_filter)
                     .collect()
             } else {
                 all_component_names
@@ -302,12 +685,32 @@ impl McpGateway {
             .iter()
             .map(|component_name| async move {
                 let tools = self.fetch_component_tools(component_name).await;
-                ((*component_name).to_string(), tools)
+                (component_name.clone(), tools)
+            })
+            .collect();
+
+        // Remote servers are listed alongside, and one that fails is left out
+        // instead of failing the whole list
+        let remote_futures: Vec<_> = self
+            .federation
+            .iter()
+            .filter(|(namespace, _)| self.component_in_scope(namespace))
+            .map(|(namespace, server)| async move {
+                let tools = server.list_tools(namespace).await.unwrap_or_else(|e| {
+                    eprintln!("Failed to list tools of remote server '{namespace}': {e}");
+                    vec![]
+                });
+                (namespace.to_string(), tools)
             })
             .collect();
 
         // Execute all futures concurrently and collect results
-        let results = futures::future::join_all(metadata_futures).await;
+        let (mut results, remote_results) = futures::future::join(
+            futures::future::join_all(metadata_futures),
+            futures::future::join_all(remote_futures),
+        )
+        .await;
+        results.extend(remote_results);
 
         // Process results - only prefix tool names when unscoped
         let mut tools: Vec<ToolMetadata> = Vec::new();
@@ -315,6 +718,14 @@ impl McpGateway {
 
         for (component_name, component_tools) in results {
             for mut tool in component_tools {
+                // Tools with names that cannot be routed could never be called
+                if !is_tool_name(&tool.name) {
+                    eprintln!(
+                        "Skipping tool with invalid name '{}' in component '{component_name}'",
+                        tool.name
+                    );
+                    continue;
+                }
                 // Only prefix tool names when unscoped (at /mcp root)
                 if !is_scoped {
                     tool.name = format!("{}__{}", component_name, tool.name);
@@ -339,44 +750,113 @@ impl McpGateway {
         component_name: &str,
         tool_name: &str,
         tool_arguments: serde_json::Value,
-    ) -> Result<ToolResponse, String> {
+        blobs: &[ResolvedBlob],
+        limits: Limits,
+    ) -> Result<ToolResponse, UpstreamError> {
         let component_name_kebab = Self::snake_to_kebab(component_name);
         let tool_url = format!("http://{component_name_kebab}.spin.internal/{tool_name}");
 
-        let req = Request::builder()
-            .method(Method::Post)
-            .uri(&tool_url)
-            .header("Content-Type", "application/json")
-            .body(
-                serde_json::to_vec(&tool_arguments)
-                    .unwrap_or_else(|_| br#"{"error":"Failed to serialize request"}"#.to_vec()),
-            )
-            .build();
+        // Tools can stop once the gateway gives up on the call
+        let mut headers = self.forwarded_headers.clone();
+        if let Some(deadline) = limits.deadline_ms() {
+            headers.push((DEADLINE_HEADER.to_string(), deadline.to_string()));
+        }
 
-        match spin_sdk::http::send::<_, spin_sdk::http::Response>(req).await {
-            Ok(resp) => {
-                let status = resp.status();
+        let started = Instant::now();
+        let send = async {
+            if blobs.is_empty() {
+                let mut builder = Request::builder();
+                builder
+                    .method(Method::Post)
+                    .uri(&tool_url)
+                    .header("Content-Type", "application/json");
+                for (name, value) in &headers {
+                    builder.header(name.as_str(), value.as_str());
+                }
+                let req =
+                    builder
+                        .body(serde_json::to_vec(&tool_arguments).unwrap_or_else(|_| {
+                            br#"{"error":"Failed to serialize request"}"#.to_vec()
+                        }))
+                        .build();
+                spin_sdk::http::send::<_, spin_sdk::http::Response>(req)
+                    .await
+                    .map_err(|e| e.to_string())
+            } else {
+                // Stream uploaded blobs into the body instead of buffering them
+                blob::send_with_blobs(
+                    &format!("{component_name_kebab}.spin.internal"),
+                    &format!("/{tool_name}"),
+                    &headers,
+                    tool_arguments,
+                    blobs,
+                )
+                .await
+            }
+        };
+        let result = limits.with_timeout(send).await;
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        match result {
+            Ok(Ok(resp)) => {
+                let status = *resp.status();
                 let body = resp.body();
 
-                if *status == 200 {
-                    serde_json::from_slice::<ToolResponse>(body)
-                        .map_err(|e| format!("Tool returned invalid response format: {e}"))
+                if let Err(limit) = limits.check_response(body) {
+                    return Err(UpstreamError {
+                        reason: "response over max_response_bytes".to_string(),
+                        status: Some(status),
+                        detail: String::new(),
+                        elapsed_ms,
+                        limit: Some(limit),
+                    });
+                }
+                if status == 200 {
+                    serde_json::from_slice::<ToolResponse>(body).map_err(|e| UpstreamError {
+                        reason: format!("invalid response format: {e}"),
+                        status: Some(status),
+                        detail: String::from_utf8_lossy(body).into_owned(),
+                        elapsed_ms,
+                        limit: None,
+                    })
                 } else {
-                    let error_text = String::from_utf8_lossy(body);
-                    Ok(ToolResponse {
-                        content: vec![ToolContent::Text {
-                            text: format!("Tool execution failed (status {status}): {error_text}"),
-                            annotations: None,
-                        }],
-                        structured_content: None,
-                        is_error: Some(true),
+                    Err(UpstreamError {
+                        reason: format!("component returned status {status}"),
+                        status: Some(status),
+                        detail: String::from_utf8_lossy(body).into_owned(),
+                        elapsed_ms,
+                        limit: None,
                     })
                 }
             }
-            Err(e) => Err(format!("Failed to call tool '{tool_name}': {e}")),
+            Ok(Err(detail)) => Err(UpstreamError {
+                reason: "component could not be reached".to_string(),
+                status: None,
+                detail,
+                elapsed_ms,
+                limit: None,
+            }),
+            Err(limit) => Err(UpstreamError {
+                reason: "call over max_duration_secs".to_string(),
+                status: None,
+                detail: String::new(),
+                elapsed_ms,
+                limit: Some(limit),
+            }),
         }
     }
 
+    /// Look up the uploaded blobs referenced by tool arguments, which must
+    /// belong to the caller
+    fn resolve_blobs(
+        &self,
+        request_id: Option<serde_json::Value>,
+        arguments: Vec<blob::BlobArgument>,
+    ) -> Result<Vec<ResolvedBlob>, JsonRpcResponse> {
+        blob::resolve(arguments, self.caller().as_deref())
+            .map_err(|e| JsonRpcResponse::error(request_id, ErrorCode::INVALID_PARAMS.0, &e))
+    }
+
     /// Parse and validate the tool call parameters
     fn parse_tool_params(
         request_id: Option<serde_json::Value>,
@@ -415,6 +895,35 @@ impl McpGateway {
         )
     }
 
+    /// Route a call to a deprecated tool name to its replacement, returning
+    /// the deprecated name the call was made with
+    fn apply_alias(
+        &self,
+        name: &mut String,
+        component_name: &mut String,
+        tool_name: &mut String,
+    ) -> Option<String> {
+        let (component, tool) = self.aliases.resolve(component_name, tool_name)?;
+        let replaced_by = format!("{component}__{tool}");
+
+        // Scoped endpoints name tools without the component prefix
+        let scoped = self
+            .scope
+            .as_ref()
+            .and_then(|scope| scope.component.as_deref())
+            == Some(component);
+        let replacement = if scoped {
+            tool.to_string()
+        } else {
+            replaced_by.clone()
+        };
+
+        *component_name = component.to_string();
+        *tool_name = tool.to_string();
+        *self.replaced_by.borrow_mut() = Some(replaced_by);
+        Some(std::mem::replace(name, replacement))
+    }
+
     async fn handle_call_tool(&self, request: JsonRpcRequest) -> JsonRpcResponse {
         // Check if in readonly mode
         if let Some(ref scope) = self.scope
@@ -428,109 +937,394 @@ impl McpGateway {
         }
 
         // Parse and validate parameters
-        let params = match Self::parse_tool_params(request.id.clone(), request.params) {
+        let mut params = match Self::parse_tool_params(request.id.clone(), request.params) {
             Ok(p) => p,
             Err(e) => return e,
         };
 
-        // Determine component and tool names based on scope
-        let (component_name, actual_tool_name) = if let Some(ref scope) = self.scope {
-            if let Some(ref scoped_component) = scope.component {
-                // When scoped, tool names aren't prefixed, use scope's component
-                (scoped_component.clone(), params.name.clone())
-            } else {
-                // Unscoped but might have readonly - parse prefixed name
-                match Self::parse_tool_name(request.id.clone(), &params.name) {
-                    Ok(names) => names,
-                    Err(e) => return e,
-                }
-            }
-        } else {
-            // Unscoped - parse prefixed name
-            match Self::parse_tool_name(request.id.clone(), &params.name) {
+        // When scoped, tool names aren't prefixed, use scope's component
+        let scoped_component = self
+            .scope
+            .as_ref()
+            .and_then(|scope| scope.component.clone());
+        let (mut component_name, mut actual_tool_name) = match scoped_component {
+            Some(component) => (component, params.name.clone()),
+            None => match Self::parse_tool_name(request.id.clone(), &params.name) {
                 Ok(names) => names,
                 Err(e) => return e,
+            },
+        };
+
+        // Deprecated names are routed to the tool they were renamed to, and
+        // the rest of the call refers to the replacement
+        let deprecated_name =
+            self.apply_alias(&mut params.name, &mut component_name, &mut actual_tool_name);
+
+        if let Err(e) = self.check_access(request.id.clone(), &component_name, &params.name) {
+            return e;
+        }
+
+        // Tools of federated servers are proxied to their server
+        if self.federation.contains(&component_name) {
+            return self
+                .call_remote_tool(
+                    request.id,
+                    &component_name,
+                    &actual_tool_name,
+                    params,
+                    deprecated_name,
+                )
+                .await;
+        }
+        self.call_component_tool(
+            request.id,
+            &component_name,
+            &actual_tool_name,
+            params,
+            deprecated_name,
+        )
+        .await
+    }
+
+    /// Call a tool of a tool component
+    async fn call_component_tool(
+        &self,
+        request_id: Option<serde_json::Value>,
+        component_name: &str,
+        tool_name: &str,
+        params: CallToolRequest,
+        deprecated_name: Option<String>,
+    ) -> JsonRpcResponse {
+        // Only configured components are routed to, and tool names must stay a
+        // single path segment of the component's URL
+        if !self.config.routes_to(component_name) {
+            return JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Unknown component '{component_name}'"),
+            );
+        }
+        if !is_tool_name(tool_name) {
+            return JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Invalid tool name '{tool_name}'"),
+            );
+        }
+
+        let mut tool_arguments = params.arguments.unwrap_or_else(|| serde_json::json!({}));
+
+        // Rewrite arguments before validation so injected defaults are checked too
+        self.transforms
+            .apply_request(component_name, tool_name, &mut tool_arguments);
+
+        // Uploaded blobs stand in for arguments as {"$blob": "<handle>"}
+        let blob_arguments = blob::take_references(&mut tool_arguments);
+
+        // Components are only called for tools they declare
+        let tools = self.fetch_component_tools(component_name).await;
+        let Some(metadata) = tools.into_iter().find(|t| t.name == tool_name) else {
+            return JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Unknown tool '{tool_name}' in component '{component_name}'"),
+            );
+        };
+
+        // Scopes declared by the tool are enforced even for callers the
+        // authorizer let through
+        if let Err(e) = self.check_required_scopes(request_id.clone(), &params.name, &metadata) {
+            return e;
+        }
+
+        // Validate arguments against the tool's input schema if enabled
+        if self.config.validate_arguments {
+            let blob_pointers: Vec<&str> = blob_arguments
+                .iter()
+                .map(|blob| blob.pointer.as_str())
+                .collect();
+            if let Err(validation_error) = Self::validate_arguments(
+                &params.name,
+                &metadata.input_schema,
+                &tool_arguments,
+                &blob_pointers,
+            ) {
+                return JsonRpcResponse::error(
+                    request_id,
+                    ErrorCode::INVALID_PARAMS.0,
+                    &format!("Invalid params: {validation_error}"),
+                );
             }
+        }
+
+        let blobs = match self.resolve_blobs(request_id.clone(), blob_arguments) {
+            Ok(blobs) => blobs,
+            Err(e) => return e,
         };
 
-        // Validate scope access if scoped
+        // Only calls that reach the component count against the caller's budget
+        if let Err(e) =
+            self.check_rate_limit(request_id.clone(), component_name, tool_name, &params.name)
+        {
+            return e;
+        }
+
+        // The concurrency limits of the component and the tool hold until
+        // the call returns
+        let limits = self.limits.get(component_name);
+        let _slots = match Self::acquire_slots(limits, &metadata, component_name, tool_name) {
+            Ok(slots) => slots,
+            Err(limit) => return limit.into_response(request_id, &params.name),
+        };
+
+        // Execute the tool call
+        match self
+            .execute_tool_call(component_name, tool_name, tool_arguments, &blobs, limits)
+            .await
+        {
+            Ok(tool_response) => self.tool_result(
+                request_id,
+                component_name,
+                tool_name,
+                &params.name,
+                tool_response,
+                deprecated_name.as_deref(),
+            ),
+            Err(e) => e.into_response(
+                request_id,
+                &params.name,
+                component_name,
+                self.config.debug_errors,
+            ),
+        }
+    }
+
+    /// Build the result of a tool call from the tool's response, with
+    /// response transforms and the deprecation warning of aliased calls
+    fn tool_result(
+        &self,
+        request_id: Option<serde_json::Value>,
+        component_name: &str,
+        tool_name: &str,
+        name: &str,
+        tool_response: ToolResponse,
+        deprecated_name: Option<&str>,
+    ) -> JsonRpcResponse {
+        // Reject malformed content instead of forwarding it to the client
+        if let Err(e) = tool_response.validate() {
+            return JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INTERNAL_ERROR.0,
+                &format!("Tool '{name}' returned invalid content: {e}"),
+            );
+        }
+        match serde_json::to_value(tool_response) {
+            Ok(mut value) => {
+                self.transforms
+                    .apply_response(component_name, tool_name, &mut value);
+                if let Some(deprecated_name) = deprecated_name {
+                    alias::add_deprecation_warning(&mut value, deprecated_name, name);
+                }
+                JsonRpcResponse::success(request_id, value)
+            }
+            Err(e) => JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INTERNAL_ERROR.0,
+                &format!("Internal error: {e}"),
+            ),
+        }
+    }
+
+    /// Reject calls to components outside the endpoint's scope or the
+    /// `X-MCP-Toolsets` header
+    fn check_access(
+        &self,
+        request_id: Option<serde_json::Value>,
+        component_name: &str,
+        name: &str,
+    ) -> Result<(), JsonRpcResponse> {
         if let Some(ref scope) = self.scope
             && let Some(ref scope_component) = scope.component
-            && &component_name != scope_component
+            && component_name != scope_component
         {
+            return Err(JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Tool '{name}' is not accessible in the current scope"),
+            ));
+        }
+        if let Some(ref allowed) = self.allowed_toolsets
+            && !allowed.iter().any(|component| component == component_name)
+        {
+            return Err(JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Component '{component_name}' is not in the allowed toolsets"),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Take the concurrency slots of a call under the component's
+    /// `max_concurrent` and the tool's own `maxConcurrency`
+    fn acquire_slots(
+        limits: Limits,
+        metadata: &ToolMetadata,
+        component_name: &str,
+        tool_name: &str,
+    ) -> Result<[Option<Slot>; 2], LimitExceeded> {
+        let component = limits.acquire(component_name)?;
+        let tool = Limits::of_tool(metadata).acquire(&format!("{component_name}__{tool_name}"))?;
+        Ok([component, tool])
+    }
+
+    /// Count a call against the caller's budget for the tool, rejecting it
+    /// once the budget is spent
+    fn check_rate_limit(
+        &self,
+        request_id: Option<serde_json::Value>,
+        component_name: &str,
+        tool_name: &str,
+        name: &str,
+    ) -> Result<(), JsonRpcResponse> {
+        let Some(quota) =
+            self.rate_limits
+                .check(component_name, tool_name, self.caller().as_deref())
+        else {
+            return Ok(());
+        };
+        self.quota.set(Some(quota));
+        if !quota.exceeded {
+            return Ok(());
+        }
+        Err(JsonRpcResponse::error_with_data(
+            request_id,
+            ErrorCode::RATE_LIMITED.0,
+            &format!(
+                "Rate limit exceeded for tool '{name}', retry in {}s",
+                quota.reset_secs
+            ),
+            serde_json::json!({
+                "tool": name,
+                "limit": quota.limit,
+                "window_secs": quota.window_secs,
+                "retry_after_secs": quota.reset_secs,
+            }),
+        ))
+    }
+
+    /// Proxy a tool call to the federated server of a namespace. Arguments
+    /// are validated by the server, while required scopes, transforms, rate
+    /// limits, aliases, the namespace's limits and content validation apply
+    /// as to component tools.
+    async fn call_remote_tool(
+        &self,
+        request_id: Option<serde_json::Value>,
+        namespace: &str,
+        tool_name: &str,
+        params: CallToolRequest,
+        deprecated_name: Option<String>,
+    ) -> JsonRpcResponse {
+        let Some(server) = self.federation.get(namespace) else {
             return JsonRpcResponse::error(
-                request.id,
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Unknown component '{namespace}'"),
+            );
+        };
+        if !is_tool_name(tool_name) {
+            return JsonRpcResponse::error(
+                request_id,
+                ErrorCode::INVALID_PARAMS.0,
+                &format!("Invalid tool name '{tool_name}'"),
+            );
+        }
+
+        let mut arguments = params.arguments.unwrap_or_else(|| serde_json::json!({}));
+        self.transforms
+            .apply_request(namespace, tool_name, &mut arguments);
+
+        // Uploaded blobs are only streamed to tool components
+        if !blob::take_references(&mut arguments).is_empty() {
+            return JsonRpcResponse::error(
+                request_id,
                 ErrorCode::INVALID_PARAMS.0,
                 &format!(
-                    "Tool '{}' is not accessible in the current scope",
+                    "Tool '{}' of a remote server can't take blob arguments",
                     params.name
                 ),
             );
         }
 
-        // Validate against X-MCP-Toolsets header if present
-        if let Some(ref allowed) = self.allowed_toolsets
-            && !allowed.contains(&component_name)
-        {
+        // Servers are only called for tools they list, whose required
+        // scopes are enforced like those of component tools
+        let metadata = match server.tool(namespace, tool_name).await {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                return e.into_response(
+                    request_id,
+                    &params.name,
+                    namespace,
+                    self.config.debug_errors,
+                );
+            }
+        };
+        let Some(metadata) = metadata else {
             return JsonRpcResponse::error(
-                request.id,
+                request_id,
                 ErrorCode::INVALID_PARAMS.0,
-                &format!("Component '{component_name}' is not in the allowed toolsets"),
+                &format!("Unknown tool '{tool_name}' in component '{namespace}'"),
             );
+        };
+        if let Err(e) = self.check_required_scopes(request_id.clone(), &params.name, &metadata) {
+            return e;
         }
 
-        // Validate arguments if validation is enabled
-        let tool_arguments = params.arguments.unwrap_or_else(|| serde_json::json!({}));
-
-        if self.config.validate_arguments {
-            // Fetch the tool metadata from the specific component for validation
-            let tools = self.fetch_component_tools(&component_name).await;
-            let tool_metadata = tools.into_iter().find(|t| t.name == actual_tool_name);
-
-            match tool_metadata {
-                Some(metadata) => {
-                    // Validate arguments against the tool's input schema
-                    if let Err(validation_error) = Self::validate_arguments(
-                        &params.name,
-                        &metadata.input_schema,
-                        &tool_arguments,
-                    ) {
-                        return JsonRpcResponse::error(
-                            request.id,
-                            ErrorCode::INVALID_PARAMS.0,
-                            &format!("Invalid params: {validation_error}"),
-                        );
-                    }
-                }
-                None => {
-                    return JsonRpcResponse::error(
-                        request.id,
-                        ErrorCode::INVALID_PARAMS.0,
-                        &format!(
-                            "Unknown tool '{actual_tool_name}' in component '{component_name}'"
-                        ),
-                    );
-                }
-            }
+        if let Err(e) =
+            self.check_rate_limit(request_id.clone(), namespace, tool_name, &params.name)
+        {
+            return e;
         }
 
-        // Execute the tool call
-        match self
-            .execute_tool_call(&component_name, &actual_tool_name, tool_arguments)
+        let limits = self.limits.get(namespace);
+        let _slots = match Self::acquire_slots(limits, &metadata, namespace, tool_name) {
+            Ok(slots) => slots,
+            Err(limit) => return limit.into_response(request_id, &params.name),
+        };
+        let value = match limits
+            .with_timeout(server.call_tool(namespace, tool_name, arguments))
             .await
         {
-            Ok(tool_response) => match serde_json::to_value(tool_response) {
-                Ok(value) => JsonRpcResponse::success(request.id, value),
-                Err(e) => JsonRpcResponse::error(
-                    request.id,
-                    ErrorCode::INTERNAL_ERROR.0,
-                    &format!("Internal error: {e}"),
-                ),
-            },
+            Ok(Ok(value)) => value,
+            Ok(Err(e)) => {
+                return e.into_response(
+                    request_id,
+                    &params.name,
+                    namespace,
+                    self.config.debug_errors,
+                );
+            }
+            Err(limit) => return limit.into_response(request_id, &params.name),
+        };
+
+        let size_check =
+            serde_json::to_vec(&value).map_or(Ok(()), |body| limits.check_response(&body));
+        if let Err(limit) = size_check {
+            return limit.into_response(request_id, &params.name);
+        }
+        match serde_json::from_value::<ToolResponse>(value) {
+            Ok(tool_response) => self.tool_result(
+                request_id,
+                namespace,
+                tool_name,
+                &params.name,
+                tool_response,
+                deprecated_name.as_deref(),
+            ),
             Err(e) => JsonRpcResponse::error(
-                request.id,
+                request_id,
                 ErrorCode::INTERNAL_ERROR.0,
-                &format!("Internal error: {e}"),
+                &format!("Tool '{}' returned invalid content: {e}", params.name),
             ),
         }
     }
@@ -560,51 +1354,107 @@ impl McpGateway {
     }
 }
 
+/// Collect the caller context headers to forward to tools. The authorizer
+/// appends its verified values after any client-supplied copies, so the last
+/// occurrence of each header wins.
+fn forwarded_context_headers(req: &Request) -> Vec<(String, String)> {
+    FORWARDED_CONTEXT_HEADERS
+        .iter()
+        .filter_map(|name| {
+            req.headers()
+                .filter(|(header, _)| header.eq_ignore_ascii_case(name))
+                .filter_map(|(_, value)| value.as_str())
+                .last()
+                .map(|value| ((*name).to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Load the gateway configuration. The live configuration is read once per
+/// request, so all messages of a batch see the same version.
+fn load_config() -> GatewayConfig {
+    GatewayConfig::load_with(&live_config::load())
+}
+
 #[allow(clippy::too_many_lines)] // This function handles the entire MCP request flow
 pub async fn handle_mcp_request(req: Request) -> Response {
+    let config = load_config();
+
+    // Health endpoints are unauthenticated, for orchestrators and load balancers
+    if req.path() == health::LIVENESS_PATH {
+        return health::handle_liveness(&req);
+    }
+    if req.path() == health::READINESS_PATH {
+        return health::handle_readiness(&req, &config).await;
+    }
+
+    // Self-diagnostics and live configuration endpoints have their own
+    // authentication and methods
+    if req.path() == diagnostics::DIAGNOSTICS_PATH {
+        return diagnostics::handle_diagnostics(&req, config).await;
+    }
+    if req.path() == live_config::CONFIG_PATH {
+        return live_config::handle_config(&req, &config);
+    }
+    if req.path() == drain::DRAIN_PATH {
+        return drain::handle_drain(&req);
+    }
+
     // Handle CORS preflight first
     if *req.method() == Method::Options {
         return Response::builder()
             .status(200)
             .header("Access-Control-Allow-Origin", "*")
-            .header("Access-Control-Allow-Methods", "POST, OPTIONS")
+            .header("Access-Control-Allow-Methods", "POST, DELETE, OPTIONS")
             .header(
                 "Access-Control-Allow-Headers",
-                "Content-Type, X-MCP-Toolsets, X-MCP-Readonly",
+                "Content-Type, X-MCP-Toolsets, X-MCP-Readonly, Mcp-Session-Id, MCP-Protocol-Version",
             )
             .build();
     }
 
+    // Clients terminate their session with DELETE
+    if *req.method() == Method::Delete {
+        return handle_session_delete(&req);
+    }
+
     // Only accept POST requests for MCP operations
     if *req.method() != Method::Post {
         return Response::builder()
             .status(405)
-            .header("Allow", "POST, OPTIONS")
+            .header("Allow", "POST, DELETE, OPTIONS")
             .header("Access-Control-Allow-Origin", "*")
             .body(b"Method not allowed. MCP requires POST requests".to_vec())
             .build();
     }
 
+    // Responses are always JSON
+    if let Some(response) = negotiation::reject_unacceptable(&req) {
+        return response;
+    }
+
     // Extract scope from path
     let path = req.path();
     let mut scope = match ToolScope::from_path(path) {
         Ok(s) => s,
-        Err(err) => {
-            // Invalid path - return 404
-            return Response::builder()
-                .status(404)
-                .header("Content-Type", "application/json")
-                .header("Access-Control-Allow-Origin", "*")
-                .body(
-                    serde_json::to_vec(&serde_json::json!({
-                        "error": err
-                    }))
-                    .unwrap_or_else(|_| b"{\"error\":\"Not found\"}".to_vec()),
-                )
-                .build();
-        }
+        // Invalid path - return 404
+        Err(err) => return not_found(&err),
     };
 
+    // Federated servers are scoped like components, so their namespaces are
+    // known before the scope is checked
+    let federation = Federation::load(&config.components().unwrap_or_default());
+
+    // Component scopes only name configured components and federated servers
+    if let Some(component) = scope.as_ref().and_then(|s| s.component.as_deref())
+        && !config.routes_to(component)
+        && !federation
+            .as_ref()
+            .is_ok_and(|federation| federation.contains(component))
+    {
+        return not_found(&format!("Unknown component: {component}"));
+    }
+
     // Parse headers to augment scope
     let mut allowed_toolsets: Option<Vec<String>> = None;
 
@@ -658,11 +1508,9 @@ pub async fn handle_mcp_request(req: Request) -> Response {
             r
         }
         Err(e) => {
-            let error_response = JsonRpcResponse::error(
-                None,
-                ErrorCode::PARSE_ERROR.0,
-                &format!("Invalid JSON-RPC request: {e}"),
-            );
+            let message = format!("Invalid JSON-RPC request: {e}");
+            diagnostics::record_error(ErrorCode::PARSE_ERROR.0, &message);
+            let error_response = JsonRpcResponse::error(None, ErrorCode::PARSE_ERROR.0, &message);
             return Response::builder()
                 .status(200)
                 .header("Content-Type", "application/json")
@@ -674,24 +1522,86 @@ pub async fn handle_mcp_request(req: Request) -> Response {
         }
     };
 
-    // Create gateway with config
-    let validate_arguments = variables::get("validate_arguments")
-        .unwrap_or_else(|_| "true".to_string())
-        .parse::<bool>()
-        .unwrap_or(true);
+    // Requests after initialize must match the negotiated session
+    let session = if request.method == "initialize" {
+        None
+    } else {
+        match resolve_session(&req, &request) {
+            Ok(session) => session,
+            Err(response) => return response,
+        }
+    };
+    let session_id = session.as_ref().map(|(id, _)| id.clone());
+    if let Some((id, mut session)) = session
+        && matches!(
+            request.method.as_str(),
+            "initialized" | "notifications/initialized"
+        )
+    {
+        session.initialized = true;
+        session::save(&id, &mut session);
+    }
+    let initialize_params = if request.method == "initialize" {
+        request.params.clone()
+    } else {
+        None
+    };
 
-    let config = GatewayConfig {
-        server_info: ServerInfo {
-            name: "mcp-gateway".to_string(),
-            version: "0.0.1".to_string(),
-        },
-        validate_arguments,
+    // Tool calls are tracked while in flight so a drain can wait for them,
+    // and rejected once a drain has started
+    let _invocation = if request.method == "tools/call" {
+        let tool = request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("name"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+        match drain::begin_tool_call(request.id.clone(), tool) {
+            Ok(invocation) => invocation,
+            Err(response) => return response,
+        }
+    } else {
+        None
     };
 
-    let gateway = McpGateway::new(config, scope, allowed_toolsets);
+    // Transforms may redact sensitive fields and rate limits guard expensive
+    // tools, so an invalid configuration fails requests instead of silently
+    // skipping them
+    let (response, quota, replaced_by) = match (
+        ToolAliases::load(),
+        Transforms::load(),
+        RateLimits::load(),
+        ComponentLimits::load(),
+        federation,
+    ) {
+        (Ok(aliases), Ok(transforms), Ok(rate_limits), Ok(limits), Ok(federation)) => {
+            let gateway = McpGateway::new(config, scope, allowed_toolsets)
+                .with_forwarded_headers(&req)
+                .with_session_key(session_id.as_deref())
+                .with_aliases(aliases)
+                .with_transforms(transforms)
+                .with_rate_limits(rate_limits)
+                .with_limits(limits)
+                .with_federation(federation);
+            let response = gateway.handle_request(request).await;
+            (response, gateway.quota(), gateway.replaced_by())
+        }
+        (Err(e), _, _, _, _)
+        | (_, Err(e), _, _, _)
+        | (_, _, Err(e), _, _)
+        | (_, _, _, Err(e), _)
+        | (_, _, _, _, Err(e)) => (
+            Some(JsonRpcResponse::error(
+                request.id,
+                ErrorCode::INTERNAL_ERROR.0,
+                &e,
+            )),
+            None,
+            None,
+        ),
+    };
 
-    // Handle the request
-    gateway.handle_request(request).await.map_or_else(
+    response.map_or_else(
         || {
             // Notification - return empty response
             Response::builder()
@@ -702,10 +1612,35 @@ pub async fn handle_mcp_request(req: Request) -> Response {
                 .build()
         },
         |response| {
-            Response::builder()
+            if let JsonRpcResult::Error { ref error } = response.result {
+                diagnostics::record_error(error.code, &error.message);
+            }
+            let session_id =
+                initialize_params.and_then(|params| start_session(params, &response));
+            let mut builder = Response::builder();
+            builder
                 .status(200)
                 .header("Content-Type", "application/json")
-                .header("Access-Control-Allow-Origin", "*")
+                .header("Access-Control-Allow-Origin", "*");
+            let mut exposed = Vec::new();
+            if let Some(id) = session_id {
+                builder.header("Mcp-Session-Id", id);
+                exposed.push("Mcp-Session-Id");
+            }
+            if let Some(quota) = quota {
+                for (name, value) in quota.headers() {
+                    builder.header(name, value);
+                    exposed.push(name);
+                }
+            }
+            if let Some(replaced_by) = replaced_by {
+                builder.header(alias::REPLACED_BY_HEADER, replaced_by);
+                exposed.push(alias::REPLACED_BY_HEADER);
+            }
+            if !exposed.is_empty() {
+                builder.header("Access-Control-Expose-Headers", exposed.join(", "));
+            }
+            builder
                 .body(serde_json::to_vec(&response).unwrap_or_else(|_| {
                     br#"{"jsonrpc":"2.0","error":{"code":-32603,"message":"Internal serialization error"}}"#.to_vec()
                 }))
@@ -713,3 +1648,117 @@ pub async fn handle_mcp_request(req: Request) -> Response {
         },
     )
 }
+
+/// 404 response for paths that are not MCP endpoints
+fn not_found(error: &str) -> Response {
+    Response::builder()
+        .status(404)
+        .header("Content-Type", "application/json")
+        .header("Access-Control-Allow-Origin", "*")
+        .body(
+            serde_json::to_vec(&serde_json::json!({
+                "error": error
+            }))
+            .unwrap_or_else(|_| b"{\"error\":\"Not found\"}".to_vec()),
+        )
+        .build()
+}
+
+/// First value of a request header as a string
+fn header_str<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.header(name)
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+}
+
+/// JSON-RPC error returned with a transport-level HTTP status
+fn http_error(status: u16, error: &JsonRpcResponse) -> Response {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .header("Access-Control-Allow-Origin", "*")
+        .body(serde_json::to_vec(error).unwrap_or_else(|_| {
+            br#"{"jsonrpc":"2.0","error":{"code":-32603,"message":"Internal serialization error"}}"#.to_vec()
+        }))
+        .build()
+}
+
+/// Check the `Mcp-Session-Id` and `MCP-Protocol-Version` headers of a request
+///
+/// Unknown or expired sessions get a 404 so the client starts a new one, and
+/// unsupported or mismatched protocol versions a 400.
+fn resolve_session(
+    req: &Request,
+    request: &JsonRpcRequest,
+) -> Result<Option<(String, Session)>, Response> {
+    let session = if let Some(id) = header_str(req, SESSION_HEADER) {
+        let Some(loaded) = session::load(id) else {
+            return Err(http_error(
+                404,
+                &JsonRpcResponse::error(
+                    request.id.clone(),
+                    ErrorCode::INVALID_REQUEST.0,
+                    "Session not found or expired, send a new initialize request",
+                ),
+            ));
+        };
+        Some((id.to_string(), loaded))
+    } else {
+        None
+    };
+
+    if let Some(version) = header_str(req, PROTOCOL_VERSION_HEADER) {
+        let negotiated = session
+            .as_ref()
+            .map(|(_, session)| session.protocol_version);
+        let accepted = McpProtocolVersion::parse(version)
+            .is_some_and(|version| negotiated.is_none_or(|negotiated| negotiated == version));
+        if !accepted {
+            return Err(http_error(
+                400,
+                &JsonRpcResponse::error_with_data(
+                    request.id.clone(),
+                    ErrorCode::INVALID_REQUEST.0,
+                    &format!("Unsupported MCP-Protocol-Version: {version}"),
+                    serde_json::json!({
+                        "supported": McpProtocolVersion::supported_strs(),
+                        "negotiated": negotiated.map(McpProtocolVersion::as_str),
+                    }),
+                ),
+            ));
+        }
+    }
+
+    Ok(session)
+}
+
+/// Store the state negotiated by a successful `initialize` and return the
+/// new session id
+fn start_session(params: serde_json::Value, response: &JsonRpcResponse) -> Option<String> {
+    let JsonRpcResult::Result { result } = &response.result else {
+        return None;
+    };
+    let protocol_version = result
+        .get("protocolVersion")
+        .and_then(serde_json::Value::as_str)
+        .and_then(McpProtocolVersion::parse)?;
+    let params: InitializeRequest = serde_json::from_value(params).ok()?;
+    session::create(&Session::new(
+        protocol_version,
+        params.client_info,
+        params.capabilities,
+    ))
+}
+
+/// Handle `DELETE` requests terminating a session
+fn handle_session_delete(req: &Request) -> Response {
+    let status = match header_str(req, SESSION_HEADER) {
+        Some(id) if session::delete(id) => 204,
+        Some(_) => 404,
+        None => 400,
+    };
+    Response::builder()
+        .status(status)
+        .header("Access-Control-Allow-Origin", "*")
+        .build()
+}
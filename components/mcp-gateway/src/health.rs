@@ -0,0 +1,226 @@
+//! Health endpoints for orchestrators and load balancers
+//!
+//! `GET /healthz` reports that the gateway serves requests at all, and
+//! `GET /readyz` whether it can serve them well: its configuration parses,
+//! the default KV store opens, every configured component answers its
+//! metadata request, and no drain is in progress. Neither endpoint needs
+//! credentials, as the authorizer lets them through unauthenticated.
+//!
+//! Probing components costs a request to each of them, so readiness results
+//! are kept in the default KV store for `readiness_cache_secs` and shared by
+//! every instance. The drain state is always checked live, so a drain takes
+//! the gateway out of rotation immediately.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+use crate::alias::ToolAliases;
+use crate::diagnostics::json_response;
+use crate::drain;
+use crate::gateway::GatewayConfig;
+use crate::limits::ComponentLimits;
+use crate::mcp_types::ToolMetadata;
+use crate::rate_limit::RateLimits;
+use crate::transform::Transforms;
+
+/// Path of the liveness endpoint
+pub const LIVENESS_PATH: &str = "/healthz";
+
+/// Path of the readiness endpoint
+pub const READINESS_PATH: &str = "/readyz";
+
+/// KV key of the last readiness result
+const READINESS_KEY: &str = "gateway:readiness";
+
+/// How long readiness results are reused by default
+const DEFAULT_READINESS_CACHE_SECS: u64 = 5;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Result of checking one dependency of the gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Check {
+    /// Kind of dependency: `config`, `kv`, `component` or `drain`
+    pub dependency: String,
+    /// Variable or component checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Check {
+    fn new(dependency: &str, name: Option<&str>, result: Result<(), String>) -> Self {
+        Self {
+            dependency: dependency.to_string(),
+            name: name.map(ToString::to_string),
+            ok: result.is_ok(),
+            error: result.err(),
+        }
+    }
+}
+
+/// Readiness result stored in the KV store
+#[derive(Debug, Serialize, Deserialize)]
+struct Readiness {
+    /// Unix timestamp (seconds) of the checks
+    checked_at: u64,
+    /// Version of the live configuration the checks ran with
+    config_version: u64,
+    checks: Vec<Check>,
+}
+
+/// Reject methods other than GET and HEAD
+fn check_method(req: &Request) -> Option<Response> {
+    if matches!(req.method(), Method::Get | Method::Head) {
+        return None;
+    }
+    Some(
+        Response::builder()
+            .status(405)
+            .header("Allow", "GET, HEAD")
+            .body(b"Method not allowed".to_vec())
+            .build(),
+    )
+}
+
+/// Handle `GET /healthz`
+pub fn handle_liveness(req: &Request) -> Response {
+    if let Some(response) = check_method(req) {
+        return response;
+    }
+    json_response(200, &serde_json::json!({ "status": "ok" }))
+}
+
+/// Handle `GET /readyz`
+///
+/// Returns `200` when every check passes, else `503` with the failing checks.
+pub async fn handle_readiness(req: &Request, config: &GatewayConfig) -> Response {
+    if let Some(response) = check_method(req) {
+        return response;
+    }
+
+    let store = Store::open_default().ok();
+    let (mut readiness, cached) = if let Some(readiness) = cached_readiness(store.as_ref(), config)
+    {
+        (readiness, true)
+    } else {
+        let readiness = check_readiness(store.as_ref(), config).await;
+        if let (Some(store), Ok(data)) = (&store, serde_json::to_vec(&readiness)) {
+            let _ = store.set(READINESS_KEY, &data);
+        }
+        (readiness, false)
+    };
+
+    let drain = if drain::is_draining_now() {
+        Err("The gateway is draining".to_string())
+    } else {
+        Ok(())
+    };
+    readiness.checks.push(Check::new("drain", None, drain));
+
+    let failing: Vec<&Check> = readiness.checks.iter().filter(|check| !check.ok).collect();
+    let (status, label) = if failing.is_empty() {
+        (200, "ready")
+    } else {
+        (503, "not_ready")
+    };
+    json_response(
+        status,
+        &serde_json::json!({
+            "status": label,
+            "checked_at": readiness.checked_at,
+            "cached": cached,
+            "config_version": readiness.config_version,
+            "checks": readiness.checks,
+            "failing": failing,
+        }),
+    )
+}
+
+/// Seconds readiness results are reused for, from `readiness_cache_secs`
+fn cache_secs() -> u64 {
+    variables::get("readiness_cache_secs")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_READINESS_CACHE_SECS)
+}
+
+/// The last readiness result, unless it is too old or was checked with
+/// another configuration
+fn cached_readiness(store: Option<&Store>, config: &GatewayConfig) -> Option<Readiness> {
+    let ttl = cache_secs();
+    if ttl == 0 {
+        return None;
+    }
+    let readiness: Readiness = store?
+        .get(READINESS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_slice(&data).ok())?;
+    (readiness.config_version == config.config_version
+        && now().saturating_sub(readiness.checked_at) < ttl)
+        .then_some(readiness)
+}
+
+/// Check the configuration, the KV store and the components
+async fn check_readiness(store: Option<&Store>, config: &GatewayConfig) -> Readiness {
+    let components = config.components();
+    let config_results = [
+        (
+            "component_names",
+            components.as_ref().map(|_| ()).map_err(Clone::clone),
+        ),
+        ("tool_aliases", ToolAliases::load().map(|_| ())),
+        ("tool_transforms", Transforms::load().map(|_| ())),
+        ("tool_rate_limits", RateLimits::load().map(|_| ())),
+        ("component_limits", ComponentLimits::load().map(|_| ())),
+    ];
+    let mut checks: Vec<Check> = config_results
+        .into_iter()
+        .map(|(variable, result)| Check::new("config", Some(variable), result))
+        .collect();
+    let kv = store
+        .map(|_| ())
+        .ok_or_else(|| "The default KV store is unavailable".to_string());
+    checks.push(Check::new("kv", Some("default"), kv));
+
+    let names = components.unwrap_or_default();
+    let probes = names.iter().map(|name| async move {
+        Check::new("component", Some(name), probe_component(name).await)
+    });
+    checks.extend(futures::future::join_all(probes).await);
+
+    Readiness {
+        checked_at: now(),
+        config_version: config.config_version,
+        checks,
+    }
+}
+
+/// Request the tool metadata of a component, as tool discovery does
+async fn probe_component(name: &str) -> Result<(), String> {
+    let url = format!("http://{}.spin.internal/", name.replace('_', "-"));
+    let req = Request::builder().method(Method::Get).uri(&url).build();
+    let resp = spin_sdk::http::send::<_, Response>(req)
+        .await
+        .map_err(|e| format!("Unreachable: {e}"))?;
+    if *resp.status() != 200 {
+        return Err(format!(
+            "Metadata request returned status {}",
+            resp.status()
+        ));
+    }
+    serde_json::from_slice::<Vec<ToolMetadata>>(resp.body())
+        .map(|_| ())
+        .map_err(|e| format!("Invalid tool metadata: {e}"))
+}
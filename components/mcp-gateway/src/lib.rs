@@ -1,10 +1,38 @@
+mod alias;
+mod blob;
+mod diagnostics;
+mod drain;
+mod federation;
 mod gateway;
+mod health;
+mod limits;
+mod live_config;
 mod mcp_types;
+mod metrics;
+mod negotiation;
+mod rate_limit;
+mod session;
+mod transform;
 
-use spin_sdk::http::{IntoResponse, Request};
+use spin_sdk::http::conversions::TryFromIncomingRequest;
+use spin_sdk::http::{IncomingRequest, IntoResponse, Request};
 use spin_sdk::http_component;
 
 #[http_component]
-async fn handle_mcp_gateway(req: Request) -> anyhow::Result<impl IntoResponse> {
-    Ok(gateway::handle_mcp_request(req).await)
+async fn handle_mcp_gateway(req: IncomingRequest) -> anyhow::Result<impl IntoResponse> {
+    // Blob uploads are streamed to the KV store instead of being buffered
+    if blob::is_upload(&req) {
+        return Ok(blob::handle_upload(req).await);
+    }
+    let req = Request::try_from_incoming_request(req)
+        .await
+        .map_err(|_| anyhow::anyhow!("Failed to read the request"))?;
+
+    let metrics = metrics::RequestMetrics::start(&req);
+    let encoding = negotiation::response_encoding(&req);
+    let response = gateway::handle_mcp_request(req).await;
+    if let Some(metrics) = metrics {
+        metrics.finish(&response).await;
+    }
+    Ok(negotiation::compress(response, encoding))
 }
@@ -0,0 +1,297 @@
+//! Per-component tool call limits
+//!
+//! Limits are configured through the `component_limits` variable as a JSON
+//! document keyed by component, which FTL generates from the `limits` of each
+//! component in `ftl.yaml`:
+//!
+//! ```json
+//! {
+//!   "research": { "max_duration_secs": 30, "max_response_bytes": 1048576, "max_concurrent": 4 }
+//! }
+//! ```
+//!
+//! - `max_duration_secs`: calls still running after this long are cancelled
+//! - `max_response_bytes`: larger tool responses are discarded
+//! - `max_concurrent`: calls beyond this many in flight are rejected as
+//!   retryable
+//!
+//! Tools can also cap their own concurrent calls with `maxConcurrency` in
+//! their `_meta`, which is counted per tool like `max_concurrent`. Components
+//! with a `max_duration_secs` limit are sent the call's deadline in the
+//! `x-ftl-deadline` header, so tools can stop once it passes.
+//!
+//! Each request runs in a fresh instance, so the calls in flight are counted
+//! in the default KV store. A call takes its slot before counting the others,
+//! so calls racing for the last slot may all be rejected, but none gets in
+//! over the limit. Slots left behind by instances that were killed mid-call
+//! are ignored once they are older than any call could be.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::{self, Either};
+use serde::Deserialize;
+use spin_executor::CancelOnDropToken;
+use spin_sdk::key_value::Store;
+use spin_sdk::wit::wasi::clocks0_2_0::monotonic_clock;
+
+use crate::mcp_types::{ErrorCode, JsonRpcResponse, ToolMetadata};
+
+/// Header carrying the Unix time in milliseconds after which a call is
+/// abandoned, read by the SDK's `CancellationToken`
+pub const DEADLINE_HEADER: &str = "x-ftl-deadline";
+
+/// KV key prefix of the concurrency slots
+const SLOT_KEY_PREFIX: &str = "gateway:concurrency:";
+
+/// Slots older than this were left by killed instances
+const STALE_SLOT_SECS: u64 = 10 * 60;
+
+/// Retry delay suggested to callers rejected over `max_concurrent`
+const RETRY_AFTER_SECS: u64 = 1;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Limits of the tool calls to one component
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::struct_field_names)] // Named after the settings in ftl.yaml
+pub struct Limits {
+    pub max_duration_secs: Option<u64>,
+    pub max_response_bytes: Option<u64>,
+    pub max_concurrent: Option<u64>,
+}
+
+/// Compiled limit configuration for all components
+#[derive(Debug, Clone, Default)]
+pub struct ComponentLimits {
+    components: BTreeMap<String, Limits>,
+}
+
+/// A limit a tool call ran into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitExceeded {
+    Duration { max_secs: u64 },
+    ResponseBytes { size: u64, max: u64 },
+    Concurrency { max: u64 },
+}
+
+impl LimitExceeded {
+    /// JSON-RPC error telling the caller which limit the call ran into
+    pub fn into_response(self, id: Option<serde_json::Value>, tool_name: &str) -> JsonRpcResponse {
+        let (message, data) = match self {
+            Self::Duration { max_secs } => (
+                format!("Tool '{tool_name}' was cancelled after its time limit of {max_secs}s"),
+                serde_json::json!({
+                    "tool": tool_name,
+                    "limit": "max_duration_secs",
+                    "max": max_secs,
+                }),
+            ),
+            Self::ResponseBytes { size, max } => (
+                format!("Tool '{tool_name}' returned {size} bytes, over its limit of {max} bytes"),
+                serde_json::json!({
+                    "tool": tool_name,
+                    "limit": "max_response_bytes",
+                    "max": max,
+                    "size": size,
+                }),
+            ),
+            Self::Concurrency { max } => (
+                format!(
+                    "Tool '{tool_name}' is at its limit of {max} concurrent calls, retry the call"
+                ),
+                serde_json::json!({
+                    "tool": tool_name,
+                    "limit": "max_concurrent",
+                    "max": max,
+                    "retryable": true,
+                    "retry_after_secs": RETRY_AFTER_SECS,
+                }),
+            ),
+        };
+        eprintln!("{message}");
+        JsonRpcResponse::error_with_data(id, ErrorCode::LIMIT_EXCEEDED.0, &message, data)
+    }
+}
+
+/// Concurrency slot of a tool call, released when dropped
+pub struct Slot {
+    store: Store,
+    key: String,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        let _ = self.store.delete(&self.key);
+    }
+}
+
+impl ComponentLimits {
+    /// Parse a limit configuration document
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let components: BTreeMap<String, Limits> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid component_limits: {e}"))?;
+        for (name, limits) in &components {
+            for (field, value) in [
+                ("max_duration_secs", limits.max_duration_secs),
+                ("max_response_bytes", limits.max_response_bytes),
+                ("max_concurrent", limits.max_concurrent),
+            ] {
+                if value == Some(0) {
+                    return Err(format!(
+                        "Invalid component_limits for '{name}': {field} must be at least 1"
+                    ));
+                }
+            }
+        }
+        Ok(Self { components })
+    }
+
+    /// Load the limit configuration from the `component_limits` variable
+    pub fn load() -> Result<Self, String> {
+        Self::parse(&spin_sdk::variables::get("component_limits").unwrap_or_default())
+    }
+
+    /// Limits of the tool calls to a component
+    pub fn get(&self, component: &str) -> Limits {
+        self.components.get(component).copied().unwrap_or_default()
+    }
+}
+
+impl Limits {
+    /// Limits a tool sets on its own calls in its metadata
+    pub fn of_tool(metadata: &ToolMetadata) -> Self {
+        Self {
+            max_concurrent: metadata.max_concurrency(),
+            ..Self::default()
+        }
+    }
+
+    /// Unix time in milliseconds after which a call starting now runs over
+    /// `max_duration_secs`
+    pub fn deadline_ms(&self) -> Option<u64> {
+        let max_secs = self.max_duration_secs?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+        let deadline = now.checked_add(Duration::from_secs(max_secs))?;
+        u64::try_from(deadline.as_millis()).ok()
+    }
+
+    /// Take a concurrency slot for a tool call to the component
+    ///
+    /// Returns `None` when the component has no `max_concurrent`. Calls are
+    /// let through when the KV store is unavailable.
+    pub fn acquire(&self, component: &str) -> Result<Option<Slot>, LimitExceeded> {
+        let Some(max) = self.max_concurrent else {
+            return Ok(None);
+        };
+        let Ok(store) = Store::open_default() else {
+            eprintln!("Concurrency limits are not enforced: the KV store is unavailable");
+            return Ok(None);
+        };
+
+        let prefix = format!("{SLOT_KEY_PREFIX}{component}:");
+        let key = format!("{prefix}{}", uuid::Uuid::new_v4().simple());
+        let now = now();
+        if store.set(&key, now.to_string().as_bytes()).is_err() {
+            return Ok(None);
+        }
+        let slot = Slot { store, key };
+
+        let taken = slot
+            .store
+            .get_keys()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|key| key.starts_with(&prefix))
+            .filter(|key| {
+                let started_at = slot
+                    .store
+                    .get(key)
+                    .ok()
+                    .flatten()
+                    .and_then(|data| String::from_utf8(data).ok())
+                    .and_then(|data| data.parse::<u64>().ok());
+                match started_at {
+                    Some(started_at) if now.saturating_sub(started_at) <= STALE_SLOT_SECS => true,
+                    _ => {
+                        let _ = slot.store.delete(key);
+                        false
+                    }
+                }
+            })
+            .count();
+
+        if u64::try_from(taken).unwrap_or(u64::MAX) > max {
+            return Err(LimitExceeded::Concurrency { max });
+        }
+        Ok(Some(slot))
+    }
+
+    /// Run a tool call, cancelling it once it runs over `max_duration_secs`
+    ///
+    /// Dropping the call cancels its outbound request to the component.
+    pub async fn with_timeout<T>(&self, call: impl Future<Output = T>) -> Result<T, LimitExceeded> {
+        let Some(max_secs) = self.max_duration_secs else {
+            return Ok(call.await);
+        };
+        match future::select(pin!(call), pin!(sleep(Duration::from_secs(max_secs)))).await {
+            Either::Left((result, _)) => Ok(result),
+            Either::Right(((), _)) => Err(LimitExceeded::Duration { max_secs }),
+        }
+    }
+
+    /// Check the size of a tool response against `max_response_bytes`
+    pub fn check_response(&self, body: &[u8]) -> Result<(), LimitExceeded> {
+        let size = u64::try_from(body.len()).unwrap_or(u64::MAX);
+        match self.max_response_bytes {
+            Some(max) if size > max => Err(LimitExceeded::ResponseBytes { size, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Timer resolving at a monotonic clock deadline
+///
+/// The Spin executor only wakes futures for registered pollables, so each
+/// poll registers a pollable for the deadline.
+pub(crate) struct Sleep {
+    deadline: monotonic_clock::Instant,
+    /// Registration of the last poll, cancelled when replaced or dropped
+    _registration: Option<CancelOnDropToken>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let pollable = monotonic_clock::subscribe_instant(self.deadline);
+        if pollable.ready() {
+            return Poll::Ready(());
+        }
+        self._registration = Some(CancelOnDropToken::from(
+            spin_executor::push_waker_and_get_token(pollable, context.waker().clone()),
+        ));
+        Poll::Pending
+    }
+}
+
+/// Resolve once `duration` has passed
+pub(crate) fn sleep(duration: Duration) -> Sleep {
+    let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+    Sleep {
+        deadline: monotonic_clock::now().saturating_add(nanos),
+        _registration: None,
+    }
+}
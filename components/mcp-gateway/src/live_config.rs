@@ -0,0 +1,202 @@
+//! Live configuration
+//!
+//! Spin variables only change when the app restarts. To add or remove tool
+//! components and toggle validation without downtime, the gateway also reads
+//! an overrides document from the default KV store. Each request reads it
+//! once, so every message of a batch is handled with the same configuration.
+//!
+//! Updates go through `PUT /_ftl/config` and are version-stamped: an update
+//! must carry the next version, so concurrent writers can't silently
+//! overwrite each other.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+use crate::diagnostics::{is_authorized, json_response};
+use crate::gateway::{GatewayConfig, is_component_name};
+
+/// Path of the live configuration endpoint
+pub const CONFIG_PATH: &str = "/_ftl/config";
+
+/// KV key holding the overrides document
+const CONFIG_KEY: &str = "gateway:config";
+
+/// Overrides of the gateway's Spin variables, stored in the default KV store
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LiveConfig {
+    /// Incremented by every update, 0 before the first one
+    #[serde(default)]
+    pub version: u64,
+    /// Replaces `component_names`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_names: Option<Vec<String>>,
+    /// Replaces `validate_arguments`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate_arguments: Option<bool>,
+    /// Replaces `debug_errors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_errors: Option<bool>,
+    /// Unix timestamp (seconds) of the last update
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+/// Body of `PUT /_ftl/config`. Fields left out fall back to the Spin variables.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigUpdate {
+    version: u64,
+    component_names: Option<Vec<String>>,
+    validate_arguments: Option<bool>,
+    debug_errors: Option<bool>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn load_from(store: &Store) -> LiveConfig {
+    match store.get(CONFIG_KEY) {
+        Ok(Some(data)) => serde_json::from_slice(&data).unwrap_or_else(|e| {
+            eprintln!("Ignoring invalid live configuration in {CONFIG_KEY}: {e}");
+            LiveConfig::default()
+        }),
+        _ => LiveConfig::default(),
+    }
+}
+
+/// Load the current overrides, or none when the KV store is unavailable
+pub fn load() -> LiveConfig {
+    Store::open_default()
+        .map(|store| load_from(&store))
+        .unwrap_or_default()
+}
+
+/// Handle requests to `/_ftl/config`
+///
+/// The endpoint is disabled (404) unless the `config_token` variable is set,
+/// and requires `Authorization: Bearer <config_token>`.
+pub fn handle_config(req: &Request, config: &GatewayConfig) -> Response {
+    let token = variables::get("config_token").unwrap_or_default();
+    if token.trim().is_empty() {
+        return json_response(404, &serde_json::json!({ "error": "Not found" }));
+    }
+
+    if !is_authorized(req, token.trim()) {
+        return Response::builder()
+            .status(401)
+            .header("Content-Type", "application/json")
+            .header("WWW-Authenticate", "Bearer")
+            .body(br#"{"error":"Unauthorized"}"#.to_vec())
+            .build();
+    }
+
+    match req.method() {
+        Method::Get => json_response(200, &describe(config)),
+        Method::Put => update(req),
+        Method::Delete => reset(),
+        _ => Response::builder()
+            .status(405)
+            .header("Allow", "GET, PUT, DELETE")
+            .body(b"Method not allowed".to_vec())
+            .build(),
+    }
+}
+
+/// The configuration in effect and its version
+fn describe(config: &GatewayConfig) -> serde_json::Value {
+    serde_json::json!({
+        "version": config.config_version,
+        "effective": {
+            "component_names": config.components().unwrap_or_default(),
+            "validate_arguments": config.validate_arguments,
+            "debug_errors": config.debug_errors,
+        },
+    })
+}
+
+fn update(req: &Request) -> Response {
+    let update = match serde_json::from_slice::<ConfigUpdate>(req.body()) {
+        Ok(update) => update,
+        Err(e) => {
+            return json_response(
+                400,
+                &serde_json::json!({ "error": format!("Invalid configuration: {e}") }),
+            );
+        }
+    };
+
+    if let Some(invalid) = update
+        .component_names
+        .iter()
+        .flatten()
+        .find(|name| !is_component_name(name))
+    {
+        return json_response(
+            400,
+            &serde_json::json!({ "error": format!("Invalid component name: {invalid}") }),
+        );
+    }
+
+    store_next(&LiveConfig {
+        version: update.version,
+        component_names: update.component_names,
+        validate_arguments: update.validate_arguments,
+        debug_errors: update.debug_errors,
+        updated_at: now(),
+    })
+}
+
+/// Drop every override, going back to the Spin variables. The version keeps
+/// increasing so that writers holding the previous one are rejected.
+fn reset() -> Response {
+    let Ok(store) = Store::open_default() else {
+        return unavailable();
+    };
+    store_next(&LiveConfig {
+        version: load_from(&store).version.saturating_add(1),
+        updated_at: now(),
+        ..LiveConfig::default()
+    })
+}
+
+/// Store the overrides if their version follows the stored one
+fn store_next(live: &LiveConfig) -> Response {
+    let Ok(store) = Store::open_default() else {
+        return unavailable();
+    };
+
+    let current = load_from(&store).version;
+    if live.version != current.saturating_add(1) {
+        return json_response(
+            409,
+            &serde_json::json!({
+                "error": format!("Version conflict: expected version {}", current.saturating_add(1)),
+                "version": current,
+            }),
+        );
+    }
+
+    let stored = serde_json::to_vec(live)
+        .ok()
+        .is_some_and(|data| store.set(CONFIG_KEY, &data).is_ok());
+    if !stored {
+        return unavailable();
+    }
+
+    eprintln!("Live configuration updated to version {}", live.version);
+    json_response(200, &describe(&GatewayConfig::load_with(live)))
+}
+
+fn unavailable() -> Response {
+    json_response(
+        503,
+        &serde_json::json!({ "error": "Configuration storage is unavailable" }),
+    )
+}
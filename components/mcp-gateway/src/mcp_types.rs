@@ -1,5 +1,5 @@
 // Re-export types from ftl-sdk that we use
-pub use ftl_sdk::{ToolContent, ToolMetadata, ToolResponse};
+pub use ftl_sdk::{ToolMetadata, ToolResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -58,6 +58,20 @@ impl JsonRpcResponse {
             },
         }
     }
+
+    pub fn error_with_data(id: Option<Value>, code: i32, message: &str, data: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: JsonRpcResult::Error {
+                error: JsonRpcError {
+                    code,
+                    message: message.to_string(),
+                    data: Some(data),
+                },
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -69,28 +83,76 @@ impl ErrorCode {
     pub const METHOD_NOT_FOUND: Self = Self(-32601);
     pub const INVALID_PARAMS: Self = Self(-32602);
     pub const INTERNAL_ERROR: Self = Self(-32603);
+    /// Implementation-defined server error for calls over a tool's rate limit
+    pub const RATE_LIMITED: Self = Self(-32029);
+    /// Implementation-defined server error for calls rejected while the
+    /// gateway drains before shutting down
+    pub const UNAVAILABLE: Self = Self(-32030);
+    /// Implementation-defined server error for calls from callers missing a
+    /// scope the tool requires
+    pub const PERMISSION_DENIED: Self = Self(-32031);
+    /// Implementation-defined server error for calls over one of their
+    /// component's limits
+    pub const LIMIT_EXCEEDED: Self = Self(-32032);
 }
 
 // MCP Protocol types not in ftl-sdk
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum McpProtocolVersion {
     #[serde(rename = "2025-06-18")]
-    V1,
+    V2025_06_18,
+    #[serde(rename = "2025-03-26")]
+    V2025_03_26,
+    #[serde(rename = "2024-11-05")]
+    V2024_11_05,
+}
+
+impl McpProtocolVersion {
+    /// Protocol versions supported by the gateway, newest first
+    pub const SUPPORTED: [Self; 3] = [Self::V2025_06_18, Self::V2025_03_26, Self::V2024_11_05];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::V2025_06_18 => "2025-06-18",
+            Self::V2025_03_26 => "2025-03-26",
+            Self::V2024_11_05 => "2024-11-05",
+        }
+    }
+
+    /// Parse a supported protocol version
+    pub fn parse(version: &str) -> Option<Self> {
+        Self::SUPPORTED
+            .into_iter()
+            .find(|supported| supported.as_str() == version)
+    }
+
+    /// Supported versions as strings, for error data
+    pub fn supported_strs() -> Vec<&'static str> {
+        Self::SUPPORTED.into_iter().map(Self::as_str).collect()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitializeRequest {
+    /// Requested version, kept as a string so unsupported versions can be
+    /// reported back to the client
     #[serde(rename = "protocolVersion")]
-    pub protocol_version: McpProtocolVersion,
+    pub protocol_version: String,
     pub capabilities: ClientCapabilities,
     #[serde(rename = "clientInfo")]
     pub client_info: ClientInfo,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientCapabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roots: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elicitation: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,328 @@
+//! Per-request metrics sent to the metrics collector component
+//!
+//! Every MCP request handled by the gateway produces a [`MetricEvent`]. Events
+//! are buffered in the default KV store and posted in batches to the
+//! collector component over service chaining
+//! (`http://<metrics_collector>.spin.internal/events`). Metrics are off until
+//! `metrics_collector` names the collector; set the `metrics_enabled` variable
+//! to `false` to turn them off regardless.
+
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::key_value::Store;
+use spin_sdk::variables;
+
+use crate::alias;
+use crate::diagnostics;
+use crate::gateway::is_component_name;
+
+/// Events buffered before a batch is sent
+const DEFAULT_BATCH_SIZE: usize = 10;
+
+/// Buffered events older than this are sent with the next request
+const DEFAULT_FLUSH_INTERVAL_MS: u64 = 5000;
+
+/// Events kept while the collector is unreachable; older ones are dropped
+const MAX_BUFFERED_EVENTS: usize = 1000;
+
+/// KV key holding events not yet sent to the collector
+const BUFFER_KEY: &str = "gateway:metrics:buffer";
+
+/// Outcome of a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricStatus {
+    Ok,
+    Error,
+}
+
+/// A single MCP request, as sent to the collector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricEvent {
+    /// Unix timestamp (milliseconds) the request was received at
+    pub timestamp_ms: u64,
+    /// JSON-RPC method, empty when the body could not be parsed
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    /// Tool name without the component prefix, for `tools/call`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    pub duration_ms: u64,
+    pub status: MetricStatus,
+    /// JSON-RPC error code of failed requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    pub http_status: u16,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    /// Calls allowed per window for rate-limited tools
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota_limit: Option<u64>,
+    /// Calls left in the caller's window, 0 once a call was throttled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota_remaining: Option<u64>,
+    /// Tool a call to the deprecated `component`/`tool` was routed to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+}
+
+/// Metrics settings from Spin variables
+struct MetricsConfig {
+    collector: String,
+    batch_size: usize,
+    flush_interval_ms: u64,
+}
+
+/// Component receiving metric batches, `None` while `metrics_collector` is
+/// not set
+fn collector() -> Option<String> {
+    variables::get("metrics_collector")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Whether a collector is set and the `metrics_enabled` kill-switch leaves
+/// metrics on
+pub fn enabled() -> bool {
+    collector().is_some()
+        && variables::get("metrics_enabled")
+            .ok()
+            .and_then(|value| value.trim().parse::<bool>().ok())
+            .unwrap_or(true)
+}
+
+fn load_config() -> Option<MetricsConfig> {
+    let collector = collector()?;
+
+    let batch_size = variables::get("metrics_batch_size")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .unwrap_or(DEFAULT_BATCH_SIZE)
+        .clamp(1, MAX_BUFFERED_EVENTS);
+
+    let flush_interval_ms = variables::get("metrics_flush_interval_ms")
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_FLUSH_INTERVAL_MS);
+
+    Some(MetricsConfig {
+        collector,
+        batch_size,
+        flush_interval_ms,
+    })
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|d| u64::try_from(d.as_millis()).ok())
+        .unwrap_or(0)
+}
+
+fn len_u64(len: usize) -> u64 {
+    u64::try_from(len).unwrap_or(u64::MAX)
+}
+
+/// Numeric value of a response header
+fn header_u64(response: &Response, name: &str) -> Option<u64> {
+    response
+        .header(name)
+        .and_then(|value| value.as_str())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Measurement of a request in flight
+pub struct RequestMetrics {
+    started: Instant,
+    timestamp_ms: u64,
+    method: String,
+    component: Option<String>,
+    tool: Option<String>,
+    bytes_in: u64,
+}
+
+impl RequestMetrics {
+    /// Start measuring an MCP request. Returns `None` when metrics are
+    /// disabled and for requests that are not MCP operations.
+    pub fn start(req: &Request) -> Option<Self> {
+        if *req.method() != Method::Post
+            || req.path() == diagnostics::DIAGNOSTICS_PATH
+            || !enabled()
+        {
+            return None;
+        }
+
+        let body: serde_json::Value = serde_json::from_slice(req.body()).unwrap_or_default();
+        let method = body
+            .get("method")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        // Scoped endpoints name the component in the path, otherwise tool
+        // names are prefixed with it
+        let mut component = req
+            .path()
+            .trim_matches('/')
+            .strip_prefix("mcp/x/")
+            .and_then(|rest| rest.split('/').next())
+            .map(ToString::to_string);
+        let mut tool = None;
+        if method == "tools/call"
+            && let Some(name) = body
+                .get("params")
+                .and_then(|params| params.get("name"))
+                .and_then(serde_json::Value::as_str)
+        {
+            match name.split_once("__") {
+                Some((prefix, name)) if component.is_none() => {
+                    component = Some(prefix.to_string());
+                    tool = Some(name.to_string());
+                }
+                _ => tool = Some(name.to_string()),
+            }
+        }
+
+        Some(Self {
+            started: Instant::now(),
+            timestamp_ms: now_ms(),
+            method,
+            component,
+            tool,
+            bytes_in: len_u64(req.body().len()),
+        })
+    }
+
+    /// Record the request's outcome and send it to the collector. Best
+    /// effort: failures never affect the response.
+    pub async fn finish(self, response: &Response) {
+        let http_status = *response.status();
+        let error_code = serde_json::from_slice::<serde_json::Value>(response.body())
+            .ok()
+            .and_then(|body| body.get("error")?.get("code")?.as_i64())
+            .and_then(|code| i32::try_from(code).ok());
+        let status = if http_status == 200 && error_code.is_none() {
+            MetricStatus::Ok
+        } else {
+            MetricStatus::Error
+        };
+
+        let event = MetricEvent {
+            timestamp_ms: self.timestamp_ms,
+            method: self.method,
+            component: self.component,
+            tool: self.tool,
+            duration_ms: u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            status,
+            error_code,
+            http_status,
+            bytes_in: self.bytes_in,
+            bytes_out: len_u64(response.body().len()),
+            quota_limit: header_u64(response, "ratelimit-limit"),
+            quota_remaining: header_u64(response, "ratelimit-remaining"),
+            replaced_by: response
+                .header(alias::REPLACED_BY_HEADER)
+                .and_then(|value| value.as_str())
+                .map(ToString::to_string),
+        };
+        emit(event).await;
+    }
+}
+
+/// Events waiting for the next batch
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Buffer {
+    events: Vec<MetricEvent>,
+    /// After a failed send, no batch is sent before this time (Unix ms)
+    #[serde(default)]
+    retry_at_ms: u64,
+}
+
+/// Buffer an event, sending the batch once it is full or its oldest event is
+/// older than the flush interval
+///
+/// Each request runs in a fresh instance, so the buffer lives in the KV
+/// store. Concurrent requests may occasionally overwrite each other's events.
+async fn emit(event: MetricEvent) {
+    let Some(config) = load_config() else {
+        return;
+    };
+    if !is_component_name(&config.collector) {
+        eprintln!(
+            "Ignoring invalid metrics_collector component name: {}",
+            config.collector
+        );
+        return;
+    }
+
+    if config.batch_size == 1 {
+        send(&config.collector, &[event]).await;
+        return;
+    }
+
+    let Ok(store) = Store::open_default() else {
+        return;
+    };
+    let mut buffer: Buffer = store
+        .get(BUFFER_KEY)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default();
+    buffer.events.push(event);
+
+    let now = now_ms();
+    let oldest = buffer.events.first().map_or(now, |e| e.timestamp_ms);
+    let due = buffer.events.len() >= config.batch_size
+        || now.saturating_sub(oldest) >= config.flush_interval_ms;
+    if due && now >= buffer.retry_at_ms {
+        if send(&config.collector, &buffer.events).await {
+            let _ = store.delete(BUFFER_KEY);
+            return;
+        }
+        // Back off instead of retrying on every request while the collector
+        // is down
+        buffer.retry_at_ms = now.saturating_add(config.flush_interval_ms.max(1000));
+    }
+
+    if buffer.events.len() > MAX_BUFFERED_EVENTS {
+        let excess = buffer.events.len() - MAX_BUFFERED_EVENTS;
+        buffer.events.drain(..excess);
+    }
+    if let Ok(data) = serde_json::to_vec(&buffer) {
+        let _ = store.set(BUFFER_KEY, &data);
+    }
+}
+
+/// Post a batch of events to the collector, returning whether it was accepted
+async fn send(collector: &str, events: &[MetricEvent]) -> bool {
+    let Ok(body) = serde_json::to_vec(&serde_json::json!({
+        "source": "mcp-gateway",
+        "events": events,
+    })) else {
+        return false;
+    };
+
+    let req = Request::builder()
+        .method(Method::Post)
+        .uri(format!("http://{collector}.spin.internal/events"))
+        .header("Content-Type", "application/json")
+        .body(body)
+        .build();
+    match spin_sdk::http::send::<_, Response>(req).await {
+        Ok(resp) if (200..300).contains(resp.status()) => true,
+        Ok(resp) => {
+            eprintln!("Metrics collector returned status {}", resp.status());
+            false
+        }
+        Err(e) => {
+            eprintln!("Metrics collector could not be reached: {e}");
+            false
+        }
+    }
+}
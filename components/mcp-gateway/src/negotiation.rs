@@ -0,0 +1,211 @@
+//! Content negotiation and response compression
+//!
+//! The gateway only answers in JSON, so requests whose `Accept` header rules
+//! out `application/json` are rejected with `406`. JSON responses of at least
+//! `compression_min_bytes` (default 1 KiB) are compressed with gzip or deflate
+//! when the client's `Accept-Encoding` allows it, which shrinks large
+//! `tools/list` responses considerably. Set `compression_enabled` to `false`
+//! to turn compression off, e.g. behind a proxy that already compresses.
+
+use std::io::Write;
+
+use flate2::Compression;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use spin_sdk::http::{Request, Response};
+use spin_sdk::variables;
+
+/// Responses smaller than this are sent uncompressed when
+/// `compression_min_bytes` is not set
+const DEFAULT_COMPRESSION_MIN_BYTES: usize = 1024;
+
+/// Quality values are compared in thousandths
+const MAX_QUALITY: u16 = 1000;
+
+/// A content coding the gateway can compress responses with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Gzip,
+    /// The zlib format, as `deflate` is defined for HTTP
+    Deflate,
+}
+
+impl Encoding {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+        }
+    }
+
+    fn encode(self, body: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(body)?;
+                encoder.finish()
+            }
+            Self::Deflate => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(body)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// Parse a quality value (`q=0.5`) into thousandths
+fn parse_quality(value: &str) -> Option<u16> {
+    let (int, fraction) = value
+        .trim()
+        .split_once('.')
+        .unwrap_or_else(|| (value.trim(), ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let thousandths = format!("{fraction:0<3}").parse::<u16>().ok()?;
+    match int {
+        "0" => Some(thousandths),
+        "1" if thousandths == 0 => Some(MAX_QUALITY),
+        _ => None,
+    }
+}
+
+/// Split a header listing weighted values into lowercase values and
+/// qualities. Entries with an invalid quality are skipped.
+fn weighted(header: &str) -> impl Iterator<Item = (String, u16)> + '_ {
+    header.split(',').filter_map(|entry| {
+        let mut parts = entry.split(';');
+        let value = parts.next()?.trim().to_ascii_lowercase();
+        if value.is_empty() {
+            return None;
+        }
+        let mut quality = MAX_QUALITY;
+        for param in parts {
+            if let Some((name, q)) = param.split_once('=')
+                && name.trim().eq_ignore_ascii_case("q")
+            {
+                quality = parse_quality(q)?;
+            }
+        }
+        Some((value, quality))
+    })
+}
+
+/// The encoding to compress the response with according to `Accept-Encoding`
+///
+/// The acceptable coding with the highest quality wins, gzip on a tie.
+/// Codings that are not listed get the quality of `*`, if present.
+pub fn response_encoding(req: &Request) -> Option<Encoding> {
+    let header = req.header("accept-encoding")?.as_str()?;
+
+    let mut gzip = None;
+    let mut deflate = None;
+    let mut any = None;
+    for (coding, quality) in weighted(header) {
+        match coding.as_str() {
+            "gzip" | "x-gzip" => gzip = Some(quality),
+            "deflate" => deflate = Some(quality),
+            "*" => any = Some(quality),
+            _ => {}
+        }
+    }
+
+    let gzip = gzip.or(any).unwrap_or(0);
+    let deflate = deflate.or(any).unwrap_or(0);
+    if gzip > 0 && gzip >= deflate {
+        Some(Encoding::Gzip)
+    } else if deflate > 0 {
+        Some(Encoding::Deflate)
+    } else {
+        None
+    }
+}
+
+/// `406` for requests whose `Accept` header rules out JSON responses
+///
+/// Requests without an `Accept` header accept anything.
+pub fn reject_unacceptable(req: &Request) -> Option<Response> {
+    let header = req.header("accept")?.as_str()?;
+    if header.trim().is_empty() {
+        return None;
+    }
+
+    let accepts_json = weighted(header).any(|(range, quality)| {
+        quality > 0 && matches!(range.as_str(), "*/*" | "application/*" | "application/json")
+    });
+    if accepts_json {
+        return None;
+    }
+
+    Some(
+        Response::builder()
+            .status(406)
+            .header("Content-Type", "application/json")
+            .header("Access-Control-Allow-Origin", "*")
+            .body(
+                serde_json::to_vec(&serde_json::json!({
+                    "error": "Not acceptable: the gateway responds with application/json",
+                    "accept": ["application/json"],
+                }))
+                .unwrap_or_default(),
+            )
+            .build(),
+    )
+}
+
+/// Whether the `compression_enabled` variable leaves compression on
+fn compression_enabled() -> bool {
+    variables::get("compression_enabled")
+        .ok()
+        .and_then(|value| value.trim().parse::<bool>().ok())
+        .unwrap_or(true)
+}
+
+/// Size threshold from the `compression_min_bytes` variable
+fn compression_min_bytes() -> usize {
+    variables::get("compression_min_bytes")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_COMPRESSION_MIN_BYTES)
+}
+
+/// Compress a JSON response with the negotiated encoding
+///
+/// Responses that are not JSON, already encoded, smaller than the threshold
+/// or that would not shrink are returned unchanged. Compressible responses
+/// carry `Vary: Accept-Encoding` either way, so caches keep the variants apart.
+pub fn compress(mut response: Response, encoding: Option<Encoding>) -> Response {
+    let is_json = response
+        .header("content-type")
+        .and_then(|value| value.as_str())
+        .is_some_and(|value| {
+            value
+                .trim()
+                .to_ascii_lowercase()
+                .starts_with("application/json")
+        });
+    if !is_json || response.header("content-encoding").is_some() || !compression_enabled() {
+        return response;
+    }
+    response.set_header("vary", "Accept-Encoding");
+
+    let Some(encoding) = encoding else {
+        return response;
+    };
+    if response.body().len() < compression_min_bytes() {
+        return response;
+    }
+
+    match encoding.encode(response.body()) {
+        Ok(compressed) if compressed.len() < response.body().len() => {
+            *response.body_mut() = compressed;
+            response.set_header("content-encoding", encoding.as_str());
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!(
+            "Failed to compress the response with {}: {e}",
+            encoding.as_str()
+        ),
+    }
+    response
+}
@@ -0,0 +1,179 @@
+//! Per-caller tool rate limits
+//!
+//! Limits are configured through the `tool_rate_limits` variable as a JSON
+//! document keyed by tool name (`component__tool`), `component__*` or `*`:
+//!
+//! ```json
+//! {
+//!   "research__summarize": { "requests": 20, "window_secs": 3600 },
+//!   "research__*": { "requests": 100, "window_secs": 60 }
+//! }
+//! ```
+//!
+//! Only the most specific entry matching a tool applies, and each entry is a
+//! single budget: `research__*` is shared by all tools of the component that
+//! have no entry of their own. Callers are told apart by the identity the
+//! authorizer forwards (user id, else client id); unauthenticated callers
+//! share one budget.
+//!
+//! Counters use fixed windows kept in the default KV store. Each request runs
+//! in a fresh instance and KV updates are not atomic, so concurrent calls may
+//! occasionally exceed a limit slightly.
+
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::key_value::Store;
+
+/// KV key prefix of the rate limit counters
+const COUNTER_KEY_PREFIX: &str = "gateway:ratelimit:";
+
+/// Budget name of callers without a forwarded identity
+const ANONYMOUS_CALLER: &str = "anonymous";
+
+/// Calls allowed per window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Limit {
+    pub requests: u64,
+    pub window_secs: u64,
+}
+
+/// Compiled rate limit configuration for all tools
+#[derive(Debug, Clone, Default)]
+pub struct RateLimits {
+    tools: BTreeMap<String, Limit>,
+}
+
+/// A caller's budget after a call was counted or rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    pub limit: u64,
+    pub remaining: u64,
+    pub window_secs: u64,
+    /// Seconds until the window resets
+    pub reset_secs: u64,
+    /// Whether the call was rejected
+    pub exceeded: bool,
+}
+
+impl Quota {
+    /// Response headers describing the caller's budget
+    pub fn headers(&self) -> Vec<(&'static str, String)> {
+        let mut headers = vec![
+            ("RateLimit-Limit", self.limit.to_string()),
+            ("RateLimit-Remaining", self.remaining.to_string()),
+            ("RateLimit-Reset", self.reset_secs.to_string()),
+        ];
+        if self.exceeded {
+            headers.push(("Retry-After", self.reset_secs.to_string()));
+        }
+        headers
+    }
+}
+
+/// Calls counted in the current window of a budget
+#[derive(Debug, Serialize, Deserialize)]
+struct Counter {
+    window_start: u64,
+    count: u64,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl RateLimits {
+    /// Parse a rate limit configuration document
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let tools: BTreeMap<String, Limit> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid tool_rate_limits: {e}"))?;
+        if let Some((name, _)) = tools.iter().find(|(_, limit)| limit.window_secs == 0) {
+            return Err(format!(
+                "Invalid tool_rate_limits for '{name}': window_secs must be at least 1"
+            ));
+        }
+        Ok(Self { tools })
+    }
+
+    /// Load the rate limit configuration from the `tool_rate_limits` variable
+    pub fn load() -> Result<Self, String> {
+        Self::parse(&spin_sdk::variables::get("tool_rate_limits").unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// The most specific entry matching a tool and its key
+    fn matching(&self, component: &str, tool: &str) -> Option<(String, Limit)> {
+        [
+            format!("{component}__{tool}"),
+            format!("{component}__*"),
+            "*".to_string(),
+        ]
+        .into_iter()
+        .find_map(|key| self.tools.get(&key).map(|limit| (key, *limit)))
+    }
+
+    /// Count a call against the caller's budget for the tool
+    ///
+    /// Returns `None` when the tool has no limit. Calls are let through when
+    /// the KV store is unavailable.
+    pub fn check(&self, component: &str, tool: &str, caller: Option<&str>) -> Option<Quota> {
+        let (key, limit) = self.matching(component, tool)?;
+        let caller = caller.unwrap_or(ANONYMOUS_CALLER);
+
+        let Ok(store) = Store::open_default() else {
+            eprintln!("Rate limits are not enforced: the KV store is unavailable");
+            return None;
+        };
+        let counter_key = format!("{COUNTER_KEY_PREFIX}{key}:{caller}");
+
+        let now = now();
+        let window_start = now - now % limit.window_secs;
+        let mut counter: Counter = store
+            .get(&counter_key)
+            .ok()
+            .flatten()
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .filter(|counter: &Counter| counter.window_start == window_start)
+            .unwrap_or(Counter {
+                window_start,
+                count: 0,
+            });
+
+        let reset_secs = window_start
+            .saturating_add(limit.window_secs)
+            .saturating_sub(now)
+            .max(1);
+        if counter.count >= limit.requests {
+            return Some(Quota {
+                limit: limit.requests,
+                remaining: 0,
+                window_secs: limit.window_secs,
+                reset_secs,
+                exceeded: true,
+            });
+        }
+
+        counter.count += 1;
+        if let Ok(data) = serde_json::to_vec(&counter) {
+            let _ = store.set(&counter_key, &data);
+        }
+        Some(Quota {
+            limit: limit.requests,
+            remaining: limit.requests - counter.count,
+            window_secs: limit.window_secs,
+            reset_secs,
+            exceeded: false,
+        })
+    }
+}
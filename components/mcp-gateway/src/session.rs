@@ -0,0 +1,155 @@
+//! MCP sessions negotiated by `initialize`
+//!
+//! Each gateway request runs in a fresh instance, so the protocol version and
+//! client capabilities agreed during `initialize` are kept in the default KV
+//! store under the `Mcp-Session-Id` returned to the client. Clients that do
+//! not send the session header keep working statelessly.
+//!
+//! Tool calls carry an opaque `x-session-key` that stateful tools can scope
+//! their own KV data with. It is derived from the session, or from the
+//! authenticated caller for clients without one, and is always set by the
+//! gateway: a client-supplied value is never forwarded.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use spin_sdk::key_value::Store;
+
+use crate::mcp_types::{ClientCapabilities, ClientInfo, McpProtocolVersion};
+
+/// Header carrying the session id assigned on `initialize`
+pub const SESSION_HEADER: &str = "mcp-session-id";
+
+/// Header carrying the negotiated protocol version on later requests
+pub const PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+
+/// Header carrying the session key forwarded to tool components
+pub const SESSION_KEY_HEADER: &str = "x-session-key";
+
+/// Namespace of the name-based UUIDs used as session keys
+const SESSION_KEY_NAMESPACE: uuid::Uuid =
+    uuid::Uuid::from_u128(0x6f3a_9c41_52e8_4d1b_a7c0_3b9e_e21f_0d58);
+
+/// KV key prefix of stored sessions
+const SESSION_KEY_PREFIX: &str = "gateway:session:";
+
+/// Sessions unused for longer than this are forgotten
+const SESSION_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// How often the last use of an active session is written back
+const SESSION_REFRESH_SECS: u64 = 60 * 60;
+
+/// State negotiated during `initialize`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub protocol_version: McpProtocolVersion,
+    pub client_info: ClientInfo,
+    #[serde(default)]
+    pub client_capabilities: ClientCapabilities,
+    /// Set once the client sends `notifications/initialized`
+    #[serde(default)]
+    pub initialized: bool,
+    /// Unix timestamp (seconds) of the last request in the session
+    pub last_seen: u64,
+}
+
+impl Session {
+    pub fn new(
+        protocol_version: McpProtocolVersion,
+        client_info: ClientInfo,
+        client_capabilities: ClientCapabilities,
+    ) -> Self {
+        Self {
+            protocol_version,
+            client_info,
+            client_capabilities,
+            initialized: false,
+            last_seen: now(),
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn key(id: &str) -> String {
+    format!("{SESSION_KEY_PREFIX}{id}")
+}
+
+/// Derive the session key of a tool call
+///
+/// Calls within a session share its key, other calls get the key of the
+/// authenticated caller. The key is a UUID derived from the session id or the
+/// caller's issuer and subject, so it is stable without revealing either.
+/// Anonymous calls outside a session have no key.
+pub fn session_key(
+    session_id: Option<&str>,
+    issuer: Option<&str>,
+    caller: Option<&str>,
+) -> Option<String> {
+    let name = match (session_id, caller) {
+        (Some(id), _) => format!("session\n{id}"),
+        (None, Some(caller)) => format!("caller\n{}\n{caller}", issuer.unwrap_or_default()),
+        (None, None) => return None,
+    };
+    Some(
+        uuid::Uuid::new_v5(&SESSION_KEY_NAMESPACE, name.as_bytes())
+            .simple()
+            .to_string(),
+    )
+}
+
+/// Store a new session and return its id
+///
+/// Returns `None` when the KV store is unavailable, in which case the client
+/// continues without a session.
+pub fn create(session: &Session) -> Option<String> {
+    let store = Store::open_default().ok()?;
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    let data = serde_json::to_vec(session).ok()?;
+    store.set(&key(&id), &data).ok()?;
+    Some(id)
+}
+
+/// Load a live session, refreshing its last use from time to time
+pub fn load(id: &str) -> Option<Session> {
+    let store = Store::open_default().ok()?;
+    let mut session: Session = store
+        .get(&key(id))
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_slice(&data).ok())?;
+
+    let idle = now().saturating_sub(session.last_seen);
+    if idle > SESSION_TTL_SECS {
+        let _ = store.delete(&key(id));
+        return None;
+    }
+    if idle > SESSION_REFRESH_SECS {
+        save(id, &mut session);
+    }
+    Some(session)
+}
+
+/// Persist an updated session, refreshing its last use
+///
+/// Best effort: a failed write only shortens the session's lifetime.
+pub fn save(id: &str, session: &mut Session) {
+    session.last_seen = now();
+    if let (Ok(store), Ok(data)) = (Store::open_default(), serde_json::to_vec(session)) {
+        let _ = store.set(&key(id), &data);
+    }
+}
+
+/// Terminate a session, returning whether it existed
+pub fn delete(id: &str) -> bool {
+    let Ok(store) = Store::open_default() else {
+        return false;
+    };
+    let existed = store.exists(&key(id)).unwrap_or(false);
+    let _ = store.delete(&key(id));
+    existed
+}
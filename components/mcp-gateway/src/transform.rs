@@ -0,0 +1,346 @@
+//! Per-tool request and response transforms
+//!
+//! Transforms are configured through the `tool_transforms` variable as a JSON
+//! document keyed by tool name (`component__tool`), `component__*` or `*`:
+//!
+//! ```json
+//! {
+//!   "weather__forecast": {
+//!     "request": [
+//!       { "op": "default", "path": "$.units", "value": "metric" },
+//!       { "op": "rename", "path": "$.city", "to": "location" }
+//!     ],
+//!     "response": [
+//!       { "op": "remove", "path": "$.structuredContent.owner.email" }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Request rules rewrite the tool arguments before validation, response rules
+//! rewrite the tool result (`content`, `structuredContent`, `isError`) before
+//! it is returned to the client.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// One segment of a `JSONPath` expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// A parsed `JSONPath` expression
+///
+/// Supports the subset needed to address fields: `$`, `.name`, `['name']`,
+/// `[0]`, `[*]` and `.*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct JsonPath {
+    parent: Vec<Segment>,
+    last: Segment,
+}
+
+impl JsonPath {
+    fn parse(path: &str) -> Result<Self, String> {
+        let rest = path
+            .trim()
+            .strip_prefix('$')
+            .ok_or_else(|| format!("JSONPath '{path}' must start with '$'"))?;
+
+        let mut segments = Vec::new();
+        let mut chars = rest.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    let mut key = String::new();
+                    while let Some(&next) = chars.peek() {
+                        if next == '.' || next == '[' {
+                            break;
+                        }
+                        key.push(next);
+                        chars.next();
+                    }
+                    match key.as_str() {
+                        "" => return Err(format!("JSONPath '{path}' has an empty field name")),
+                        "*" => segments.push(Segment::Wildcard),
+                        _ => segments.push(Segment::Key(key)),
+                    }
+                }
+                '[' => {
+                    let quote = chars.peek().copied().filter(|q| *q == '\'' || *q == '"');
+                    if let Some(quote) = quote {
+                        chars.next();
+                        let key: String =
+                            chars.by_ref().take_while(|next| *next != quote).collect();
+                        if chars.next() != Some(']') {
+                            return Err(format!("JSONPath '{path}' has an unclosed '['"));
+                        }
+                        segments.push(Segment::Key(key));
+                        continue;
+                    }
+
+                    let mut inner = String::new();
+                    let mut closed = false;
+                    for next in chars.by_ref() {
+                        if next == ']' {
+                            closed = true;
+                            break;
+                        }
+                        inner.push(next);
+                    }
+                    if !closed {
+                        return Err(format!("JSONPath '{path}' has an unclosed '['"));
+                    }
+                    let inner = inner.trim();
+                    if inner == "*" {
+                        segments.push(Segment::Wildcard);
+                    } else {
+                        let index = inner.parse::<usize>().map_err(|_| {
+                            format!("JSONPath '{path}' has an invalid index '[{inner}]'")
+                        })?;
+                        segments.push(Segment::Index(index));
+                    }
+                }
+                _ => return Err(format!("JSONPath '{path}' has an unexpected '{c}'")),
+            }
+        }
+
+        let last = segments
+            .pop()
+            .ok_or_else(|| format!("JSONPath '{path}' must address a field, not the root"))?;
+        Ok(Self {
+            parent: segments,
+            last,
+        })
+    }
+
+    /// Ensure the path ends with a field name, as required by `rename` and `default`
+    fn require_key(&self, op: &str, path: &str) -> Result<(), String> {
+        match self.last {
+            Segment::Key(_) => Ok(()),
+            _ => Err(format!(
+                "'{op}' requires JSONPath '{path}' to end with a field name"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum RuleSpec {
+    Remove { path: String },
+    Rename { path: String, to: String },
+    Default { path: String, value: Value },
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ToolTransformSpec {
+    #[serde(default)]
+    request: Vec<RuleSpec>,
+    #[serde(default)]
+    response: Vec<RuleSpec>,
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Remove,
+    Rename(String),
+    Default(Value),
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    path: JsonPath,
+    op: Op,
+}
+
+impl Rule {
+    fn compile(spec: RuleSpec) -> Result<Self, String> {
+        match spec {
+            RuleSpec::Remove { path } => Ok(Self {
+                path: JsonPath::parse(&path)?,
+                op: Op::Remove,
+            }),
+            RuleSpec::Rename { path, to } => {
+                let parsed = JsonPath::parse(&path)?;
+                parsed.require_key("rename", &path)?;
+                if to.is_empty() {
+                    return Err(format!("'rename' of '{path}' requires a non-empty 'to'"));
+                }
+                Ok(Self {
+                    path: parsed,
+                    op: Op::Rename(to),
+                })
+            }
+            RuleSpec::Default { path, value } => {
+                let parsed = JsonPath::parse(&path)?;
+                parsed.require_key("default", &path)?;
+                Ok(Self {
+                    path: parsed,
+                    op: Op::Default(value),
+                })
+            }
+        }
+    }
+
+    fn apply(&self, value: &mut Value) {
+        // Defaults create missing parent objects so nested fields can be seeded
+        let create = matches!(self.op, Op::Default(_));
+        for_each_parent(value, &self.path.parent, create, &mut |parent| {
+            self.apply_to_parent(parent);
+        });
+    }
+
+    fn apply_to_parent(&self, parent: &mut Value) {
+        match (&self.op, &self.path.last, parent) {
+            (Op::Remove, Segment::Key(key), Value::Object(map)) => {
+                map.remove(key);
+            }
+            (Op::Remove, Segment::Index(index), Value::Array(items)) if *index < items.len() => {
+                items.remove(*index);
+            }
+            (Op::Remove, Segment::Wildcard, Value::Object(map)) => map.clear(),
+            (Op::Remove, Segment::Wildcard, Value::Array(items)) => items.clear(),
+            (Op::Rename(to), Segment::Key(key), Value::Object(map)) => {
+                if let Some(moved) = map.remove(key) {
+                    map.insert(to.clone(), moved);
+                }
+            }
+            (Op::Default(default), Segment::Key(key), Value::Object(map)) => {
+                let entry = map.entry(key.clone()).or_insert(Value::Null);
+                if entry.is_null() {
+                    *entry = default.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Visit every value addressed by `segments`
+fn for_each_parent(
+    value: &mut Value,
+    segments: &[Segment],
+    create: bool,
+    f: &mut dyn FnMut(&mut Value),
+) {
+    let Some((first, rest)) = segments.split_first() else {
+        f(value);
+        return;
+    };
+
+    match first {
+        Segment::Key(key) => {
+            if let Value::Object(map) = value {
+                if create && !map.contains_key(key) {
+                    map.insert(key.clone(), Value::Object(Map::new()));
+                }
+                if let Some(child) = map.get_mut(key) {
+                    for_each_parent(child, rest, create, f);
+                }
+            }
+        }
+        Segment::Index(index) => {
+            if let Some(child) = value.as_array_mut().and_then(|items| items.get_mut(*index)) {
+                for_each_parent(child, rest, create, f);
+            }
+        }
+        Segment::Wildcard => match value {
+            Value::Array(items) => {
+                for child in items {
+                    for_each_parent(child, rest, create, f);
+                }
+            }
+            Value::Object(map) => {
+                for child in map.values_mut() {
+                    for_each_parent(child, rest, create, f);
+                }
+            }
+            _ => {}
+        },
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ToolTransform {
+    request: Vec<Rule>,
+    response: Vec<Rule>,
+}
+
+/// Compiled transform configuration for all tools
+#[derive(Debug, Clone, Default)]
+pub struct Transforms {
+    tools: BTreeMap<String, ToolTransform>,
+}
+
+impl Transforms {
+    /// Parse and compile a transform configuration document
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let specs: BTreeMap<String, ToolTransformSpec> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid tool_transforms: {e}"))?;
+
+        let mut tools = BTreeMap::new();
+        for (name, spec) in specs {
+            let compile = |rules: Vec<RuleSpec>| {
+                rules
+                    .into_iter()
+                    .map(Rule::compile)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("Invalid tool_transforms for '{name}': {e}"))
+            };
+            let transform = ToolTransform {
+                request: compile(spec.request)?,
+                response: compile(spec.response)?,
+            };
+            tools.insert(name, transform);
+        }
+        Ok(Self { tools })
+    }
+
+    /// Load the transform configuration from the `tool_transforms` variable
+    pub fn load() -> Result<Self, String> {
+        Self::parse(&spin_sdk::variables::get("tool_transforms").unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// Transforms matching a tool, from the most general to the most specific
+    fn matching(&self, component: &str, tool: &str) -> impl Iterator<Item = &ToolTransform> {
+        [
+            "*".to_string(),
+            format!("{component}__*"),
+            format!("{component}__{tool}"),
+        ]
+        .into_iter()
+        .filter_map(|key| self.tools.get(&key))
+    }
+
+    /// Rewrite tool arguments before validation
+    pub fn apply_request(&self, component: &str, tool: &str, arguments: &mut Value) {
+        for transform in self.matching(component, tool) {
+            for rule in &transform.request {
+                rule.apply(arguments);
+            }
+        }
+    }
+
+    /// Rewrite a serialized tool result before it is returned to the client
+    pub fn apply_response(&self, component: &str, tool: &str, result: &mut Value) {
+        for transform in self.matching(component, tool) {
+            for rule in &transform.response {
+                rule.apply(result);
+            }
+        }
+    }
+}
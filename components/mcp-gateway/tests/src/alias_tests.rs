@@ -0,0 +1,148 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+
+const METRICS_BUFFER_KEY: &str = "gateway:metrics:buffer";
+
+fn setup_aliased_echo(aliases: &str) {
+    setup_default_test_env();
+    variables::set("tool_aliases", aliases);
+    variables::set("metrics_batch_size", "100");
+    variables::set("metrics_flush_interval_ms", "3600000");
+    key_value::Store::open("default").delete(METRICS_BUFFER_KEY);
+    mock_tool_component("echo", vec![simple_tool("echo")]);
+    mock_tool_execution(
+        "echo",
+        "echo",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "ok".to_string(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        },
+    );
+}
+
+fn send(path: &str, method: &str, params: serde_json::Value) -> ResponseData {
+    let request_json = create_json_rpc_request(method, Some(params), Some(serde_json::json!(1)));
+
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    request
+        .body()
+        .unwrap()
+        .write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 200);
+    response_data
+}
+
+fn call_tool(path: &str, name: &str) -> ResponseData {
+    send(
+        path,
+        "tools/call",
+        serde_json::json!({ "name": name, "arguments": {} }),
+    )
+}
+
+fn header(response: &ResponseData, name: &str) -> Option<String> {
+    response
+        .find_header(name)
+        .map(|value| String::from_utf8(value.clone()).unwrap())
+}
+
+#[spin_test]
+fn test_deprecated_name_is_routed_to_replacement() {
+    setup_aliased_echo(r#"{"echo__say": "echo__echo"}"#);
+
+    let response = call_tool("/mcp", "echo__say");
+    let json = response.body_json().unwrap();
+    assert_json_rpc_success(&json, Some(serde_json::json!(1)));
+    assert_eq!(json["result"]["content"][0]["text"], "ok");
+
+    let deprecation = &json["result"]["_meta"]["ftl/deprecation"];
+    assert_eq!(deprecation["tool"], "echo__say");
+    assert_eq!(deprecation["replacement"], "echo__echo");
+    assert!(deprecation["message"]
+        .as_str()
+        .unwrap()
+        .contains("deprecated"));
+    assert_eq!(
+        header(&response, "x-mcp-tool-replaced-by").as_deref(),
+        Some("echo__echo")
+    );
+
+    // Alias usage is counted in the metric events
+    let buffer: serde_json::Value = serde_json::from_slice(
+        &key_value::Store::open("default")
+            .get(METRICS_BUFFER_KEY)
+            .expect("Expected buffered metrics"),
+    )
+    .unwrap();
+    let event = &buffer["events"][0];
+    assert_eq!(event["tool"], "say");
+    assert_eq!(event["replaced_by"], "echo__echo");
+}
+
+#[spin_test]
+fn test_direct_calls_have_no_deprecation_warning() {
+    setup_aliased_echo(r#"{"echo__say": "echo__echo"}"#);
+
+    let response = call_tool("/mcp", "echo__echo");
+    let json = response.body_json().unwrap();
+    assert_json_rpc_success(&json, Some(serde_json::json!(1)));
+    assert!(json["result"].get("_meta").is_none());
+    assert!(header(&response, "x-mcp-tool-replaced-by").is_none());
+}
+
+#[spin_test]
+fn test_scoped_endpoint_resolves_unprefixed_aliases() {
+    setup_aliased_echo(r#"{"echo__say": "echo__echo"}"#);
+
+    let response = call_tool("/mcp/x/echo", "say");
+    let json = response.body_json().unwrap();
+    assert_json_rpc_success(&json, Some(serde_json::json!(1)));
+    let deprecation = &json["result"]["_meta"]["ftl/deprecation"];
+    assert_eq!(deprecation["tool"], "say");
+    assert_eq!(deprecation["replacement"], "echo");
+}
+
+#[spin_test]
+fn test_deprecated_names_are_not_listed() {
+    setup_aliased_echo(r#"{"echo__say": "echo__echo"}"#);
+
+    let response = send("/mcp", "tools/list", serde_json::json!({}));
+    let json = response.body_json().unwrap();
+    let names: Vec<&str> = json["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["echo__echo"]);
+}
+
+#[spin_test]
+fn test_invalid_aliases_fail_requests() {
+    setup_aliased_echo(r#"{"echo__say": "echo__speak", "echo__speak": "echo__echo"}"#);
+
+    let response = call_tool("/mcp", "echo__echo");
+    let json = response.body_json().unwrap();
+    assert_json_rpc_error(&json, -32603, Some(serde_json::json!(1)));
+    assert!(json["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("is itself an alias"));
+}
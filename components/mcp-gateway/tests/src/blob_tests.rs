@@ -0,0 +1,181 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+
+// Upload a blob with extra headers
+fn upload(body: &[u8], extra_headers: &[(&str, &str)]) -> ResponseData {
+    let headers = http::types::Headers::new();
+    for (name, value) in extra_headers {
+        headers.append(name, value.as_bytes()).unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp/blobs")).unwrap();
+    request.body().unwrap().write_bytes(body);
+
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn upload_handle(body: &[u8], extra_headers: &[(&str, &str)]) -> String {
+    let response = upload(body, extra_headers);
+    assert_eq!(response.status, 201);
+    response.body_json().unwrap()["handle"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+// Call the import tool with the blob as its content argument
+fn call_import(handle: &str, extra_headers: &[(&str, &str)]) -> serde_json::Value {
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({
+            "name": "files__import",
+            "arguments": {
+                "filename": "report.csv",
+                "content": { "$blob": handle }
+            }
+        })),
+        Some(serde_json::json!(1)),
+    );
+
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    for (name, value) in extra_headers {
+        headers.append(name, value.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    request
+        .body()
+        .unwrap()
+        .write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().expect("Expected JSON response")
+}
+
+fn setup_files_component() {
+    variables::set("component_names", "files");
+    variables::set("validate_arguments", "true");
+
+    mock_tool_component(
+        "files",
+        vec![ToolMetadata {
+            name: "import".to_string(),
+            title: None,
+            description: Some("Imports a file".to_string()),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "filename": { "type": "string" },
+                    "content": { "type": "string", "minLength": 1 }
+                },
+                "required": ["filename", "content"]
+            }),
+            output_schema: None,
+            annotations: None,
+            meta: None,
+        }],
+    );
+    mock_tool_execution(
+        "files",
+        "import",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "Imported".to_string(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        },
+    );
+}
+
+#[spin_test]
+fn test_blob_upload_and_tool_call() {
+    setup_files_component();
+
+    let response = upload(b"id,name\n1,Alice\n", &[("content-type", "text/csv")]);
+    assert_eq!(response.status, 201);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["size"], 16);
+    assert_eq!(body["encoding"], "text");
+
+    let handle = body["handle"].as_str().unwrap();
+    let store = key_value::Store::open("default");
+    assert!(store.get(&format!("gateway:blob:{handle}")).is_some());
+    assert!(store.get(&format!("gateway:blob:{handle}:0")).is_some());
+
+    // The blob argument passes validation and is streamed to the tool
+    let response_json = call_import(handle, &[]);
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+    assert_eq!(response_json["result"]["content"][0]["text"], "Imported");
+}
+
+#[spin_test]
+fn test_binary_blob_encoding() {
+    setup_files_component();
+
+    let response = upload(&[0, 159, 146, 150], &[]);
+    assert_eq!(response.status, 201);
+    assert_eq!(response.body_json().unwrap()["encoding"], "base64");
+
+    // Invalid UTF-8 is rejected for text uploads
+    let response = upload(&[0, 159, 146, 150], &[("content-type", "text/plain")]);
+    assert_eq!(response.status, 400);
+}
+
+#[spin_test]
+fn test_unknown_blob_is_rejected() {
+    setup_files_component();
+
+    for handle in ["0123456789abcdef0123456789abcdef", "../diagnostics:errors"] {
+        let response_json = call_import(handle, &[]);
+        assert_json_rpc_error(&response_json, -32602, Some(serde_json::json!(1)));
+        assert!(response_json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("not found or expired"));
+    }
+}
+
+#[spin_test]
+fn test_blob_is_bound_to_uploader() {
+    setup_files_component();
+
+    let handle = upload_handle(
+        b"private notes",
+        &[("content-type", "text/plain"), ("x-auth-user-id", "alice")],
+    );
+
+    let response_json = call_import(&handle, &[("x-auth-user-id", "bob")]);
+    assert_json_rpc_error(&response_json, -32602, Some(serde_json::json!(1)));
+
+    let response_json = call_import(&handle, &[]);
+    assert_json_rpc_error(&response_json, -32602, Some(serde_json::json!(1)));
+
+    let response_json = call_import(&handle, &[("x-auth-user-id", "alice")]);
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_blob_size_limit() {
+    setup_files_component();
+    variables::set("max_blob_bytes", "16");
+
+    let response = upload(&[b'a'; 32], &[("content-type", "text/plain")]);
+    assert_eq!(response.status, 413);
+
+    let response = upload(&[b'a'; 16], &[("content-type", "text/plain")]);
+    assert_eq!(response.status, 201);
+}
@@ -137,6 +137,7 @@ fn test_scoped_tool_call_unprefixed() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -194,6 +195,7 @@ fn test_unscoped_tool_call_requires_prefix() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -251,6 +253,7 @@ fn test_unscoped_tool_call_with_prefix() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -0,0 +1,169 @@
+// Response compression and content negotiation tests
+
+use crate::{test_helpers::*, ResponseData};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+use std::io::Read;
+
+/// Register enough tools for tools/list to exceed the default threshold
+fn setup_compression_env() {
+    setup_default_test_env();
+    let tools = (0..50)
+        .map(|i| ToolMetadata {
+            description: Some(format!("Tool number {i} of the compression test suite")),
+            ..simple_tool(&format!("tool_{i}"))
+        })
+        .collect();
+    mock_tool_component("echo", tools);
+}
+
+fn list_tools(headers: &[(&str, &str)]) -> ResponseData {
+    let request_json = create_json_rpc_request("tools/list", None, Some(serde_json::json!(1)));
+
+    let request_headers = http::types::Headers::new();
+    request_headers
+        .append("content-type", b"application/json")
+        .unwrap();
+    for (name, value) in headers {
+        request_headers.append(name, value.as_bytes()).unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(request_headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    let body = request.body().unwrap();
+    body.write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn header(response: &ResponseData, name: &str) -> Option<String> {
+    response
+        .find_header(name)
+        .map(|value| String::from_utf8_lossy(value).into_owned())
+}
+
+fn tool_count(body: &[u8]) -> usize {
+    let json: serde_json::Value = serde_json::from_slice(body).unwrap();
+    json["result"]["tools"].as_array().unwrap().len()
+}
+
+#[spin_test]
+fn test_gzip_compressed_when_accepted() {
+    setup_compression_env();
+
+    let response = list_tools(&[("accept-encoding", "gzip, deflate, br")]);
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        header(&response, "content-encoding").as_deref(),
+        Some("gzip")
+    );
+    assert_eq!(
+        header(&response, "vary").as_deref(),
+        Some("Accept-Encoding")
+    );
+
+    let mut body = Vec::new();
+    GzDecoder::new(response.body.as_slice())
+        .read_to_end(&mut body)
+        .unwrap();
+    assert!(response.body.len() < body.len());
+    assert_eq!(tool_count(&body), 50);
+}
+
+#[spin_test]
+fn test_deflate_used_when_preferred() {
+    setup_compression_env();
+
+    let response = list_tools(&[("accept-encoding", "gzip;q=0.5, deflate")]);
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        header(&response, "content-encoding").as_deref(),
+        Some("deflate")
+    );
+
+    let mut body = Vec::new();
+    ZlibDecoder::new(response.body.as_slice())
+        .read_to_end(&mut body)
+        .unwrap();
+    assert_eq!(tool_count(&body), 50);
+}
+
+#[spin_test]
+fn test_uncompressed_without_accept_encoding() {
+    setup_compression_env();
+
+    for accept_encoding in [None, Some("identity"), Some("gzip;q=0, br")] {
+        let headers: Vec<_> = accept_encoding
+            .map(|value| ("accept-encoding", value))
+            .into_iter()
+            .collect();
+        let response = list_tools(&headers);
+
+        assert_eq!(response.status, 200);
+        assert!(response.find_header("content-encoding").is_none());
+        assert_eq!(
+            header(&response, "vary").as_deref(),
+            Some("Accept-Encoding")
+        );
+        assert_eq!(tool_count(&response.body), 50);
+    }
+}
+
+#[spin_test]
+fn test_small_responses_not_compressed() {
+    setup_compression_env();
+    variables::set("compression_min_bytes", "1000000");
+
+    let response = list_tools(&[("accept-encoding", "gzip")]);
+
+    assert_eq!(response.status, 200);
+    assert!(response.find_header("content-encoding").is_none());
+    assert_eq!(tool_count(&response.body), 50);
+}
+
+#[spin_test]
+fn test_compression_disabled() {
+    setup_compression_env();
+    variables::set("compression_enabled", "false");
+
+    let response = list_tools(&[("accept-encoding", "gzip")]);
+
+    assert_eq!(response.status, 200);
+    assert!(response.find_header("content-encoding").is_none());
+    assert!(response.find_header("vary").is_none());
+    assert_eq!(tool_count(&response.body), 50);
+}
+
+#[spin_test]
+fn test_json_accept_headers_allowed() {
+    setup_compression_env();
+
+    for accept in [
+        "application/json",
+        "application/json, text/event-stream",
+        "*/*",
+        "text/html;q=0.9, application/*;q=0.1",
+    ] {
+        let response = list_tools(&[("accept", accept)]);
+        assert_eq!(response.status, 200, "{accept} should be accepted");
+    }
+}
+
+#[spin_test]
+fn test_non_json_accept_rejected() {
+    setup_compression_env();
+
+    for accept in ["text/html", "text/event-stream", "application/json;q=0"] {
+        let response = list_tools(&[("accept", accept)]);
+
+        assert_eq!(response.status, 406, "{accept} should be rejected");
+        let json = response.body_json().unwrap();
+        assert_eq!(json["accept"], serde_json::json!(["application/json"]));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{test_helpers::*, ResponseData};
+use ftl_sdk::ResourceContents;
+use spin_test_sdk::{bindings::fermyon::spin_test_virt::variables, spin_test};
+
+const PNG_BASE64: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+fn call_ocr(response: ToolResponse) -> serde_json::Value {
+    variables::set("component_names", "ocr");
+    variables::set("validate_arguments", "false");
+    mock_tool_component("ocr", vec![simple_tool("scan")]);
+    mock_tool_execution("ocr", "scan", response);
+
+    let request = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({
+            "name": "ocr__scan",
+            "arguments": {}
+        })),
+        Some(serde_json::json!(1)),
+    );
+
+    let response = spin_test_sdk::perform_request(create_mcp_request(request));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().expect("Expected JSON response")
+}
+
+#[spin_test]
+fn test_image_content_passes_through() {
+    let response_json = call_ocr(ToolResponse::with_content(vec![
+        ToolContent::text("Scanned 1 page"),
+        ToolContent::image(PNG_BASE64, "image/png"),
+    ]));
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+
+    let content = &response_json["result"]["content"];
+    assert_eq!(content[0]["type"], "text");
+    assert_eq!(content[1]["type"], "image");
+    assert_eq!(content[1]["data"], PNG_BASE64);
+    assert_eq!(content[1]["mimeType"], "image/png");
+}
+
+#[spin_test]
+fn test_audio_and_resource_content_pass_through() {
+    let response_json = call_ocr(ToolResponse::with_content(vec![
+        ToolContent::audio("UklGRiQAAABXQVZF", "audio/wav"),
+        ToolContent::Resource {
+            resource: ResourceContents {
+                uri: "file:///scan.txt".to_string(),
+                mime_type: Some("text/plain".to_string()),
+                text: Some("Hello".to_string()),
+                blob: None,
+            },
+            annotations: None,
+        },
+        ToolContent::resource_blob(
+            "file:///scan.pdf",
+            "JVBERi0xLjQ=",
+            Some("application/pdf".to_string()),
+        ),
+        ToolContent::resource_link("https://example.com/scans/1", "scan-1"),
+    ]));
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+
+    let content = &response_json["result"]["content"];
+    assert_eq!(content[0]["type"], "audio");
+    assert_eq!(content[0]["mimeType"], "audio/wav");
+    assert_eq!(content[1]["type"], "resource");
+    assert_eq!(content[1]["resource"]["text"], "Hello");
+    assert_eq!(content[2]["resource"]["blob"], "JVBERi0xLjQ=");
+    assert_eq!(content[3]["type"], "resource_link");
+    assert_eq!(content[3]["uri"], "https://example.com/scans/1");
+    assert_eq!(content[3]["name"], "scan-1");
+}
+
+#[spin_test]
+fn test_invalid_image_content_is_rejected() {
+    let response_json = call_ocr(ToolResponse::with_content(vec![ToolContent::image(
+        "not base64!",
+        "image/png",
+    )]));
+    assert_json_rpc_error(&response_json, -32603, Some(serde_json::json!(1)));
+
+    let message = response_json["error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("content[0]"),
+        "unexpected message: {message}"
+    );
+    assert!(message.contains("base64"), "unexpected message: {message}");
+}
+
+#[spin_test]
+fn test_mismatched_mime_type_is_rejected() {
+    let response_json = call_ocr(ToolResponse::with_content(vec![ToolContent::audio(
+        "UklGRiQAAABXQVZF",
+        "image/png",
+    )]));
+    assert_json_rpc_error(&response_json, -32603, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_meta_and_structured_content_pass_through() {
+    let response_json = call_ocr(
+        ToolResponse::with_structured("Scanned page 1 of 3", serde_json::json!({ "page": 1 }))
+            .with_meta("nextCursor", serde_json::json!("page-2"))
+            .with_meta("provenance", serde_json::json!({ "scanner": "sc-7" })),
+    );
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+
+    let result = &response_json["result"];
+    assert_eq!(
+        result["structuredContent"],
+        serde_json::json!({ "page": 1 })
+    );
+    assert_eq!(
+        result["_meta"],
+        serde_json::json!({
+            "nextCursor": "page-2",
+            "provenance": { "scanner": "sc-7" }
+        })
+    );
+}
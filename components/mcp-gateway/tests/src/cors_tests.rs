@@ -31,11 +31,14 @@ fn test_cors_preflight_options() {
     );
     assert_eq!(
         response_data.find_header("access-control-allow-methods"),
-        Some(&b"POST, OPTIONS".to_vec())
+        Some(&b"POST, DELETE, OPTIONS".to_vec())
     );
     assert_eq!(
         response_data.find_header("access-control-allow-headers"),
-        Some(&b"Content-Type, X-MCP-Toolsets, X-MCP-Readonly".to_vec())
+        Some(
+            &b"Content-Type, X-MCP-Toolsets, X-MCP-Readonly, Mcp-Session-Id, MCP-Protocol-Version"
+                .to_vec()
+        )
     );
 }
 
@@ -130,7 +133,7 @@ fn test_non_post_methods_rejected() {
     assert_eq!(response_data.status, 405);
     assert_eq!(
         response_data.find_header("allow"),
-        Some(&b"POST, OPTIONS".to_vec())
+        Some(&b"POST, DELETE, OPTIONS".to_vec())
     );
 
     // Test PUT request
@@ -143,7 +146,7 @@ fn test_non_post_methods_rejected() {
 
     assert_eq!(response_data.status, 405);
 
-    // Test DELETE request
+    // DELETE only terminates sessions, so it needs an Mcp-Session-Id
     let request = http::types::OutgoingRequest::new(http::types::Headers::new());
     request.set_method(&http::types::Method::Delete).unwrap();
     request.set_path_with_query(Some("/mcp")).unwrap();
@@ -151,5 +154,5 @@ fn test_non_post_methods_rejected() {
     let response = spin_test_sdk::perform_request(request);
     let response_data = ResponseData::from_response(response);
 
-    assert_eq!(response_data.status, 405);
+    assert_eq!(response_data.status, 400);
 }
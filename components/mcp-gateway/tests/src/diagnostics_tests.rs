@@ -0,0 +1,118 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+
+fn create_diagnostics_request(token: Option<&str>) -> http::types::OutgoingRequest {
+    let headers = http::types::Headers::new();
+    if let Some(token) = token {
+        headers
+            .append("authorization", format!("Bearer {token}").as_bytes())
+            .unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Get).unwrap();
+    request
+        .set_path_with_query(Some("/_ftl/diagnostics"))
+        .unwrap();
+    request
+}
+
+#[spin_test]
+fn test_diagnostics_disabled_without_token() {
+    setup_default_test_env();
+
+    let response = spin_test_sdk::perform_request(create_diagnostics_request(Some("anything")));
+    let response_data = ResponseData::from_response(response);
+
+    assert_eq!(response_data.status, 404);
+}
+
+#[spin_test]
+fn test_diagnostics_requires_bearer_token() {
+    setup_default_test_env();
+    variables::set("diagnostics_token", "secret-token");
+
+    let response = spin_test_sdk::perform_request(create_diagnostics_request(None));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 401);
+    assert_eq!(
+        response_data.find_header("www-authenticate"),
+        Some(&b"Bearer".to_vec())
+    );
+
+    let response = spin_test_sdk::perform_request(create_diagnostics_request(Some("wrong")));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 401);
+}
+
+#[spin_test]
+fn test_diagnostics_reports_components_and_config() {
+    variables::set("component_names", "echo,calculator");
+    variables::set("validate_arguments", "false");
+    variables::set("diagnostics_token", "secret-token");
+
+    mock_tool_component(
+        "echo",
+        vec![ToolMetadata {
+            name: "echo".to_string(),
+            title: None,
+            description: None,
+            input_schema: serde_json::json!({"type": "object"}),
+            output_schema: None,
+            annotations: None,
+            meta: None,
+        }],
+    );
+    mock_tool_component("calculator", vec![]);
+
+    let response = spin_test_sdk::perform_request(create_diagnostics_request(Some("secret-token")));
+    let response_data = ResponseData::from_response(response);
+
+    assert_eq!(response_data.status, 200);
+    let json = response_data.body_json().expect("Expected JSON response");
+
+    assert_eq!(json["server_info"]["name"], "mcp-gateway");
+    assert_eq!(json["components"][0]["name"], "echo");
+    assert_eq!(json["components"][0]["tool_count"], 1);
+    assert_eq!(json["components"][1]["name"], "calculator");
+    assert_eq!(json["components"][1]["tool_count"], 0);
+
+    let validation_stage = json["pipeline"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|stage| stage["stage"] == "argument_validation")
+        .unwrap();
+    assert_eq!(validation_stage["enabled"], false);
+    assert_eq!(json["kv"]["available"], true);
+}
+
+#[spin_test]
+fn test_diagnostics_counts_errors() {
+    setup_default_test_env();
+    variables::set("diagnostics_token", "secret-token");
+    key_value::Store::open("default").delete("gateway:diagnostics:errors");
+
+    // Unknown method produces a METHOD_NOT_FOUND error
+    let request_json = create_json_rpc_request("unknown/method", None, Some(serde_json::json!(1)));
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    assert_eq!(response.status(), 200);
+
+    let response = spin_test_sdk::perform_request(create_diagnostics_request(Some("secret-token")));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 200);
+
+    let json = response_data.body_json().expect("Expected JSON response");
+    assert_eq!(json["errors"]["total"], 1);
+    assert_eq!(json["errors"]["by_code"]["-32601"], 1);
+    assert!(json["errors"]["last_error"]
+        .as_str()
+        .unwrap()
+        .contains("unknown/method"));
+}
@@ -0,0 +1,206 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+
+fn create_drain_request(
+    method: &http::types::Method,
+    token: Option<&str>,
+    query: &str,
+) -> http::types::OutgoingRequest {
+    let headers = http::types::Headers::new();
+    if let Some(token) = token {
+        headers
+            .append("authorization", format!("Bearer {token}").as_bytes())
+            .unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(method).unwrap();
+    request
+        .set_path_with_query(Some(&format!("/_ftl/drain{query}")))
+        .unwrap();
+    request
+}
+
+fn drain(method: &http::types::Method, query: &str) -> ResponseData {
+    ResponseData::from_response(spin_test_sdk::perform_request(create_drain_request(
+        method,
+        Some("config-secret"),
+        query,
+    )))
+}
+
+fn setup_drain_env() {
+    setup_default_test_env();
+    variables::set("config_token", "config-secret");
+    key_value::Store::open("default").delete("gateway:draining");
+    mock_tool_component("echo", vec![simple_tool("echo")]);
+    mock_tool_execution(
+        "echo",
+        "echo",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "ok".to_string(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        },
+    );
+}
+
+fn call_echo() -> ResponseData {
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": "echo__echo", "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+    ResponseData::from_response(spin_test_sdk::perform_request(create_mcp_request(
+        request_json,
+    )))
+}
+
+fn in_flight_entry(key: &str, tool: &str, started_at: i64) {
+    key_value::Store::open("default").set(
+        &format!("gateway:inflight:{key}"),
+        &serde_json::to_vec(&serde_json::json!({ "tool": tool, "started_at": started_at }))
+            .unwrap(),
+    );
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+#[spin_test]
+fn test_drain_disabled_without_token() {
+    setup_default_test_env();
+
+    let response = spin_test_sdk::perform_request(create_drain_request(
+        &http::types::Method::Post,
+        Some("anything"),
+        "",
+    ));
+    assert_eq!(response.status(), 404);
+}
+
+#[spin_test]
+fn test_drain_requires_bearer_token() {
+    setup_drain_env();
+
+    let response = spin_test_sdk::perform_request(create_drain_request(
+        &http::types::Method::Post,
+        Some("wrong"),
+        "",
+    ));
+    assert_eq!(response.status(), 401);
+}
+
+#[spin_test]
+fn test_drain_rejects_new_tool_calls() {
+    setup_drain_env();
+
+    let response = drain(&http::types::Method::Post, "");
+    assert_eq!(response.status, 200);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["draining"], true);
+    assert_eq!(body["in_flight"], 0);
+
+    let response = call_echo();
+    assert_eq!(response.status, 503);
+    assert_eq!(response.find_header("retry-after").unwrap(), b"5");
+    let json = response.body_json().unwrap();
+    assert_json_rpc_error(&json, -32030, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["retryable"], true);
+
+    // Other methods are still served
+    let request_json = create_json_rpc_request("tools/list", None, Some(serde_json::json!(2)));
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    assert_eq!(response.status(), 200);
+
+    // Cancelling the drain accepts calls again
+    let response = drain(&http::types::Method::Delete, "");
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body_json().unwrap()["draining"], false);
+
+    let response = call_echo();
+    assert_eq!(response.status, 200);
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_completed_calls_are_not_in_flight() {
+    setup_drain_env();
+
+    let response = call_echo();
+    assert_eq!(response.status, 200);
+
+    let response = drain(&http::types::Method::Get, "");
+    assert_eq!(response.status, 200);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["draining"], false);
+    assert_eq!(body["in_flight"], 0);
+}
+
+#[spin_test]
+fn test_drain_times_out_with_calls_in_flight() {
+    setup_drain_env();
+    in_flight_entry("running", "echo__echo", now() - 5);
+    // Left behind by an instance killed mid-call
+    in_flight_entry("stale", "echo__echo", now() - 3600);
+
+    let response = drain(&http::types::Method::Post, "?timeout_secs=0");
+    assert_eq!(response.status, 202);
+    let body = response.body_json().unwrap();
+    assert_eq!(body["draining"], true);
+    assert_eq!(body["in_flight"], 1);
+    assert_eq!(body["calls"][0]["tool"], "echo__echo");
+    assert!(key_value::Store::open("default")
+        .get("gateway:inflight:stale")
+        .is_none());
+
+    // Draining completes once the call has finished
+    key_value::Store::open("default").delete("gateway:inflight:running");
+    let response = drain(&http::types::Method::Post, "?timeout_secs=1");
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body_json().unwrap()["in_flight"], 0);
+}
+
+#[spin_test]
+fn test_invalid_drain_timeout() {
+    setup_drain_env();
+
+    let response = drain(&http::types::Method::Post, "?timeout_secs=3600");
+    assert_eq!(response.status, 400);
+
+    let response = drain(&http::types::Method::Get, "");
+    assert_eq!(response.body_json().unwrap()["draining"], false);
+}
+
+#[spin_test]
+fn test_draining_variable_rejects_tool_calls() {
+    setup_drain_env();
+    variables::set("draining", "true");
+
+    let response = call_echo();
+    assert_eq!(response.status, 503);
+    assert_json_rpc_error(
+        &response.body_json().unwrap(),
+        -32030,
+        Some(serde_json::json!(1)),
+    );
+
+    let response = drain(&http::types::Method::Get, "");
+    let body = response.body_json().unwrap();
+    assert_eq!(body["draining"], true);
+    assert_eq!(body["variable"], true);
+}
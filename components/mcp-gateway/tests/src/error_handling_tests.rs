@@ -1,5 +1,11 @@
 use crate::{test_helpers::*, ResponseData};
-use spin_test_sdk::{bindings::wasi::http, spin_test};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{spin_test_virt::variables, spin_wasi_virt::http_handler},
+        wasi::http,
+    },
+    spin_test,
+};
 
 #[spin_test]
 fn test_invalid_json_rpc_request() {
@@ -94,3 +100,73 @@ fn test_json_rpc_batch_not_supported() {
     // Should return parse error for batch requests
     assert_json_rpc_error(&response_json, -32700, None);
 }
+
+/// Mock a tool that fails with the given status and body, then call it
+fn call_failing_tool(status: u16, body: &str) -> serde_json::Value {
+    variables::set("component_names", "flaky");
+    variables::set("validate_arguments", "false");
+    mock_tool_component("flaky", vec![simple_tool("fetch")]);
+
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    response.set_status_code(status).unwrap();
+    response.body().unwrap().write_bytes(body.as_bytes());
+    http_handler::set_response(
+        "http://flaky.spin.internal/fetch",
+        http_handler::ResponseHandler::Response(response),
+    );
+
+    let request = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": "flaky__fetch", "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+    let response_data =
+        ResponseData::from_response(spin_test_sdk::perform_request(create_mcp_request(request)));
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().expect("Expected JSON response")
+}
+
+#[spin_test]
+fn test_upstream_failure_hides_details_by_default() {
+    let response_json = call_failing_tool(500, "panic at src/db.rs:42: password=hunter2");
+
+    assert_json_rpc_error(&response_json, -32603, Some(serde_json::json!(1)));
+    let error = &response_json["error"];
+    assert_eq!(
+        error["message"],
+        "Tool 'flaky__fetch' failed in component 'flaky'"
+    );
+    assert_eq!(error["data"], serde_json::json!({ "component": "flaky" }));
+    assert!(!response_json.to_string().contains("hunter2"));
+}
+
+#[spin_test]
+fn test_upstream_failure_includes_diagnostics_in_debug_mode() {
+    variables::set("debug_errors", "true");
+    let response_json = call_failing_tool(503, "database unavailable");
+
+    assert_json_rpc_error(&response_json, -32603, Some(serde_json::json!(1)));
+    let error = &response_json["error"];
+    assert_eq!(
+        error["message"],
+        "Tool 'flaky__fetch' failed in component 'flaky': component returned status 503"
+    );
+    let data = &error["data"];
+    assert_eq!(data["component"], "flaky");
+    assert_eq!(data["tool"], "flaky__fetch");
+    assert_eq!(data["upstream_status"], 503);
+    assert_eq!(data["body"], "database unavailable");
+    assert_eq!(data["body_truncated"], false);
+    assert!(data["elapsed_ms"].is_u64());
+}
+
+#[spin_test]
+fn test_upstream_failure_body_is_truncated() {
+    variables::set("debug_errors", "true");
+    let body = "x".repeat(4096);
+    let response_json = call_failing_tool(500, &body);
+
+    let data = &response_json["error"]["data"];
+    assert_eq!(data["body"].as_str().unwrap().len(), 1024);
+    assert_eq!(data["body_truncated"], true);
+}
@@ -0,0 +1,321 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{
+            spin_test_virt::{key_value, variables},
+            spin_wasi_virt::http_handler,
+        },
+        wasi::http,
+    },
+    spin_test,
+};
+
+const REMOTE_URL: &str = "http://remote.spin.internal/mcp";
+
+// Federate the remote server as "remote" with a session already negotiated
+// and its tools already listed, so each request makes a single call to the
+// server
+fn setup_federation() {
+    setup_default_test_env();
+    mock_tool_component("echo", vec![simple_tool("echo")]);
+    variables::set(
+        "federated_servers",
+        &serde_json::json!({
+            "remote": { "url": REMOTE_URL, "token": "secret", "timeout_secs": 5 }
+        })
+        .to_string(),
+    );
+    key_value::Store::open("default").set(
+        "gateway:federation:remote",
+        &serde_json::to_vec(&serde_json::json!({
+            "url": REMOTE_URL,
+            "id": "remote-session",
+            "protocol_version": "2025-06-18",
+        }))
+        .unwrap(),
+    );
+    set_listed_tools(serde_json::json!([
+        { "name": "search", "inputSchema": { "type": "object" } }
+    ]));
+}
+
+fn set_listed_tools(tools: serde_json::Value) {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    key_value::Store::open("default").set(
+        "gateway:federation-tools:remote",
+        &serde_json::to_vec(&serde_json::json!({
+            "url": REMOTE_URL,
+            "listed_at": now,
+            "tools": tools,
+        }))
+        .unwrap(),
+    );
+}
+
+// Answer the next request to the remote server
+fn mock_remote(status: u16, content_type: &str, body: &[u8]) {
+    let headers = http::types::Headers::new();
+    headers
+        .append("content-type", content_type.as_bytes())
+        .unwrap();
+    let response = http::types::OutgoingResponse::new(headers);
+    response.set_status_code(status).unwrap();
+    response.body().unwrap().write_bytes(body);
+    http_handler::set_response(
+        REMOTE_URL,
+        http_handler::ResponseHandler::Response(response),
+    );
+}
+
+fn mock_remote_result(result: serde_json::Value) {
+    let message = serde_json::json!({ "jsonrpc": "2.0", "id": "1", "result": result });
+    mock_remote(
+        200,
+        "application/json",
+        &serde_json::to_vec(&message).unwrap(),
+    );
+}
+
+fn send(path: &str, method: &str, params: serde_json::Value) -> serde_json::Value {
+    let request_json = create_json_rpc_request(method, Some(params), Some(serde_json::json!(1)));
+
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    request
+        .body()
+        .unwrap()
+        .write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().unwrap()
+}
+
+fn tool_names(response: &serde_json::Value) -> Vec<String> {
+    response["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn call_remote_tool() -> serde_json::Value {
+    send(
+        "/mcp",
+        "tools/call",
+        serde_json::json!({ "name": "remote__search", "arguments": { "query": "ftl" } }),
+    )
+}
+
+#[spin_test]
+fn test_remote_tools_are_listed_under_namespace() {
+    setup_federation();
+    mock_remote_result(serde_json::json!({
+        "tools": [
+            { "name": "search", "description": "Search", "inputSchema": { "type": "object" } },
+            { "name": "bad/name", "inputSchema": { "type": "object" } },
+        ]
+    }));
+
+    let response = send("/mcp", "tools/list", serde_json::json!({}));
+    assert_json_rpc_success(&response, Some(serde_json::json!(1)));
+
+    let names = tool_names(&response);
+    assert!(names.contains(&"echo__echo".to_string()));
+    assert!(names.contains(&"remote__search".to_string()));
+    assert!(!names.iter().any(|name| name.contains("bad")));
+}
+
+#[spin_test]
+fn test_namespace_scope_lists_remote_tools_unprefixed() {
+    setup_federation();
+    mock_remote_result(serde_json::json!({
+        "tools": [{ "name": "search", "inputSchema": { "type": "object" } }]
+    }));
+
+    let response = send("/mcp/x/remote", "tools/list", serde_json::json!({}));
+    assert_json_rpc_success(&response, Some(serde_json::json!(1)));
+    assert_eq!(tool_names(&response), vec!["search".to_string()]);
+}
+
+#[spin_test]
+fn test_failing_remote_server_is_left_out_of_list() {
+    setup_federation();
+    mock_remote(500, "text/plain", b"upstream exploded");
+
+    let response = send("/mcp", "tools/list", serde_json::json!({}));
+    assert_json_rpc_success(&response, Some(serde_json::json!(1)));
+    assert_eq!(tool_names(&response), vec!["echo__echo".to_string()]);
+}
+
+#[spin_test]
+fn test_remote_tool_call_is_proxied() {
+    setup_federation();
+    mock_remote_result(serde_json::json!({
+        "content": [{ "type": "text", "text": "found 3 results" }],
+        "isError": false,
+    }));
+
+    let response = call_remote_tool();
+    assert_json_rpc_success(&response, Some(serde_json::json!(1)));
+    assert_eq!(response["result"]["content"][0]["text"], "found 3 results");
+}
+
+#[spin_test]
+fn test_remote_event_stream_response_is_read() {
+    setup_federation();
+    let stream = concat!(
+        "event: message\n",
+        "data: {\"jsonrpc\":\"2.0\",\"method\":\"notifications/progress\",\"params\":{}}\n",
+        "\n",
+        "event: message\n",
+        "data: {\"jsonrpc\":\"2.0\",\"id\":\"1\",\"result\":",
+        "{\"content\":[{\"type\":\"text\",\"text\":\"streamed\"}]}}\n",
+        "\n",
+    );
+    mock_remote(200, "text/event-stream", stream.as_bytes());
+
+    let response = call_remote_tool();
+    assert_json_rpc_success(&response, Some(serde_json::json!(1)));
+    assert_eq!(response["result"]["content"][0]["text"], "streamed");
+}
+
+#[spin_test]
+fn test_remote_json_rpc_error_is_passed_on() {
+    setup_federation();
+    let message = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "error": { "code": -32602, "message": "query is required" },
+    });
+    mock_remote(
+        200,
+        "application/json",
+        &serde_json::to_vec(&message).unwrap(),
+    );
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32602, Some(serde_json::json!(1)));
+    assert_eq!(
+        response["error"]["message"],
+        "Remote server 'remote': query is required"
+    );
+    assert_eq!(response["error"]["data"]["server"], "remote");
+}
+
+#[spin_test]
+fn test_failed_remote_call_hides_details() {
+    setup_federation();
+    mock_remote(502, "text/plain", b"internal stack trace");
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32603, Some(serde_json::json!(1)));
+    assert_eq!(
+        response["error"]["message"],
+        "Tool 'remote__search' failed in remote server 'remote'"
+    );
+    assert!(!response.to_string().contains("stack trace"));
+}
+
+#[spin_test]
+fn test_failed_remote_call_details_in_debug_mode() {
+    setup_federation();
+    variables::set("debug_errors", "true");
+    mock_remote(502, "text/plain", b"internal stack trace");
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32603, Some(serde_json::json!(1)));
+    let data = &response["error"]["data"];
+    assert_eq!(data["upstream_status"], 502);
+    assert_eq!(data["body"], "internal stack trace");
+}
+
+#[spin_test]
+fn test_namespace_colliding_with_component_fails_requests() {
+    setup_federation();
+    variables::set(
+        "federated_servers",
+        &serde_json::json!({ "echo": { "url": REMOTE_URL } }).to_string(),
+    );
+
+    let response = send("/mcp", "tools/list", serde_json::json!({}));
+    assert_json_rpc_error(&response, -32603, Some(serde_json::json!(1)));
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid federated_servers"));
+}
+
+#[spin_test]
+fn test_unlisted_remote_tool_is_not_called() {
+    setup_federation();
+    mock_remote_result(serde_json::json!({
+        "tools": [{ "name": "search", "inputSchema": { "type": "object" } }]
+    }));
+
+    let response = send(
+        "/mcp",
+        "tools/call",
+        serde_json::json!({ "name": "remote__delete", "arguments": {} }),
+    );
+    assert_json_rpc_error(&response, -32602, Some(serde_json::json!(1)));
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown tool 'delete' in component 'remote'"
+    );
+}
+
+#[spin_test]
+fn test_remote_tool_required_scopes_are_enforced() {
+    setup_federation();
+    set_listed_tools(serde_json::json!([{
+        "name": "search",
+        "inputSchema": { "type": "object" },
+        "_meta": { "requiredScopes": ["search:read"] },
+    }]));
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32031, Some(serde_json::json!(1)));
+    assert_eq!(
+        response["error"]["data"]["missing_scopes"],
+        serde_json::json!(["search:read"])
+    );
+}
+
+#[spin_test]
+fn test_remote_tool_response_limit_applies() {
+    setup_federation();
+    variables::set(
+        "component_limits",
+        r#"{"remote": {"max_response_bytes": 16}}"#,
+    );
+    mock_remote_result(serde_json::json!({
+        "content": [{ "type": "text", "text": "found 3 results" }],
+    }));
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32032, Some(serde_json::json!(1)));
+    assert_eq!(response["error"]["data"]["limit"], "max_response_bytes");
+}
+
+#[spin_test]
+fn test_remote_tool_invalid_content_is_rejected() {
+    setup_federation();
+    mock_remote_result(serde_json::json!({
+        "content": [{ "type": "image", "data": "aGk=", "mimeType": "text/plain" }],
+    }));
+
+    let response = call_remote_tool();
+    assert_json_rpc_error(&response, -32603, Some(serde_json::json!(1)));
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Tool 'remote__search' returned invalid content"));
+}
@@ -0,0 +1,142 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{
+            spin_test_virt::{key_value, variables},
+            spin_wasi_virt::http_handler,
+        },
+        wasi::http,
+    },
+    spin_test,
+};
+
+fn get(path: &str, method: &http::types::Method) -> ResponseData {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_method(method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+fn readyz() -> (u16, serde_json::Value) {
+    let response = get("/readyz", &http::types::Method::Get);
+    let json = response.body_json().expect("Expected JSON response");
+    (response.status, json)
+}
+
+fn setup_health_env() {
+    setup_default_test_env();
+    key_value::Store::open("default").delete("gateway:readiness");
+    key_value::Store::open("default").delete("gateway:draining");
+}
+
+/// Make a component fail its metadata requests
+fn mock_broken_component(name: &str) {
+    for url in [
+        format!("http://{name}.spin.internal/"),
+        format!("http://{name}.spin.internal"),
+    ] {
+        let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+        response.set_status_code(500).unwrap();
+        http_handler::set_response(&url, http_handler::ResponseHandler::Response(response));
+    }
+}
+
+#[spin_test]
+fn test_liveness() {
+    setup_health_env();
+
+    let response = get("/healthz", &http::types::Method::Get);
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body_json().unwrap(),
+        serde_json::json!({ "status": "ok" })
+    );
+
+    let response = get("/healthz", &http::types::Method::Post);
+    assert_eq!(response.status, 405);
+    assert_eq!(response.find_header("allow"), Some(&b"GET, HEAD".to_vec()));
+}
+
+#[spin_test]
+fn test_ready_when_every_check_passes() {
+    setup_health_env();
+
+    let (status, json) = readyz();
+    assert_eq!(status, 200);
+    assert_eq!(json["status"], "ready");
+    assert_eq!(json["cached"], false);
+    assert_eq!(json["failing"], serde_json::json!([]));
+    let checks = json["checks"].as_array().unwrap();
+    assert!(checks.contains(&serde_json::json!({
+        "dependency": "component", "name": "echo", "ok": true
+    })));
+    assert!(checks.contains(&serde_json::json!({
+        "dependency": "config", "name": "component_names", "ok": true
+    })));
+    assert!(checks.contains(&serde_json::json!({ "dependency": "drain", "ok": true })));
+
+    // Results are reused until readiness_cache_secs have passed
+    let (status, json) = readyz();
+    assert_eq!(status, 200);
+    assert_eq!(json["cached"], true);
+
+    variables::set("readiness_cache_secs", "0");
+    let (_, json) = readyz();
+    assert_eq!(json["cached"], false);
+}
+
+#[spin_test]
+fn test_unreachable_components_fail_readiness() {
+    setup_health_env();
+    mock_broken_component("calculator");
+
+    let (status, json) = readyz();
+    assert_eq!(status, 503);
+    assert_eq!(json["status"], "not_ready");
+    assert_eq!(
+        json["failing"],
+        serde_json::json!([{
+            "dependency": "component",
+            "name": "calculator",
+            "ok": false,
+            "error": "Metadata request returned status 500"
+        }])
+    );
+}
+
+#[spin_test]
+fn test_invalid_config_fails_readiness() {
+    setup_health_env();
+    variables::set("component_limits", r#"{"echo": {"max_concurrent": 0}}"#);
+
+    let (status, json) = readyz();
+    assert_eq!(status, 503);
+    let failing = json["failing"].as_array().unwrap();
+    assert_eq!(failing.len(), 1);
+    assert_eq!(failing[0]["dependency"], "config");
+    assert_eq!(failing[0]["name"], "component_limits");
+    assert!(failing[0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("max_concurrent must be at least 1"));
+}
+
+#[spin_test]
+fn test_draining_fails_readiness_immediately() {
+    setup_health_env();
+    let (status, _) = readyz();
+    assert_eq!(status, 200);
+
+    // The drain state is not cached
+    variables::set("draining", "true");
+    let (status, json) = readyz();
+    assert_eq!(status, 503);
+    assert_eq!(json["cached"], true);
+    assert_eq!(
+        json["failing"],
+        serde_json::json!([{ "dependency": "drain", "ok": false, "error": "The gateway is draining" }])
+    );
+
+    // Liveness is unaffected
+    assert_eq!(get("/healthz", &http::types::Method::Get).status, 200);
+}
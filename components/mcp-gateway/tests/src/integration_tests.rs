@@ -59,6 +59,7 @@ fn test_full_mcp_session_flow() {
                 "operation": "add"
             })),
             is_error: None,
+            meta: None,
         },
     );
 
@@ -75,6 +76,7 @@ fn test_full_mcp_session_flow() {
                 "operation": "subtract"
             })),
             is_error: None,
+            meta: None,
         },
     );
 
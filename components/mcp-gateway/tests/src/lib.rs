@@ -3,17 +3,33 @@ use spin_test_sdk::{
     spin_test,
 };
 
+mod alias_tests;
 mod basic_test;
+mod blob_tests;
 mod clean_scoping_tests;
+mod compression_tests;
+mod content_tests;
 mod cors_tests;
+mod diagnostics_tests;
+mod drain_tests;
 mod error_handling_tests;
+mod federation_tests;
+mod health_tests;
 mod integration_tests;
 mod json_rpc_tests;
+mod limit_tests;
+mod live_config_tests;
+mod metrics_tests;
 mod performance_tests;
 mod protocol_tests;
+mod rate_limit_tests;
+mod required_scopes_tests;
+mod route_isolation_tests;
 mod routing_tests;
+mod session_tests;
 mod test_helpers;
 mod tool_discovery_tests;
+mod transform_tests;
 mod validation_tests;
 
 // Response data helper to extract all needed information
@@ -0,0 +1,132 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::fermyon::spin_test_virt::{key_value, variables},
+    spin_test,
+};
+
+fn setup_limited_echo(limits: &str) {
+    setup_default_test_env();
+    variables::set("component_limits", limits);
+    mock_tool_component("echo", vec![simple_tool("echo")]);
+    mock_tool_execution(
+        "echo",
+        "echo",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "a".repeat(100),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        },
+    );
+}
+
+fn call_echo() -> serde_json::Value {
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": "echo__echo", "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+    let response = ResponseData::from_response(spin_test_sdk::perform_request(create_mcp_request(
+        request_json,
+    )));
+    assert_eq!(response.status, 200);
+    response.body_json().unwrap()
+}
+
+fn slot(key: &str, started_at: i64) {
+    key_value::Store::open("default").set(
+        &format!("gateway:concurrency:echo:{key}"),
+        started_at.to_string().as_bytes(),
+    );
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+#[spin_test]
+fn test_calls_within_limits_succeed() {
+    setup_limited_echo(
+        r#"{"echo": {"max_duration_secs": 30, "max_response_bytes": 65536, "max_concurrent": 1}}"#,
+    );
+
+    assert_json_rpc_success(&call_echo(), Some(serde_json::json!(1)));
+    // The first call released its slot
+    assert_json_rpc_success(&call_echo(), Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_large_responses_are_discarded() {
+    setup_limited_echo(r#"{"echo": {"max_response_bytes": 64}}"#);
+
+    let json = call_echo();
+    assert_json_rpc_error(&json, -32032, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["tool"], "echo__echo");
+    assert_eq!(json["error"]["data"]["limit"], "max_response_bytes");
+    assert_eq!(json["error"]["data"]["max"], 64);
+    assert!(json["error"]["data"]["size"].as_u64().unwrap() > 64);
+    assert!(!json.to_string().contains("aaaa"));
+}
+
+#[spin_test]
+fn test_calls_over_max_concurrent_are_rejected() {
+    setup_limited_echo(r#"{"echo": {"max_concurrent": 1}}"#);
+    slot("running", now());
+
+    let json = call_echo();
+    assert_json_rpc_error(&json, -32032, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["limit"], "max_concurrent");
+    assert_eq!(json["error"]["data"]["max"], 1);
+    assert_eq!(json["error"]["data"]["retryable"], true);
+
+    // Stale slots of killed instances don't count
+    key_value::Store::open("default").delete("gateway:concurrency:echo:running");
+    slot("stale", now() - 3600);
+    assert_json_rpc_success(&call_echo(), Some(serde_json::json!(1)));
+    assert!(key_value::Store::open("default")
+        .get("gateway:concurrency:echo:stale")
+        .is_none());
+}
+
+#[spin_test]
+fn test_tool_max_concurrency_is_enforced() {
+    setup_limited_echo("");
+    mock_tool_component("echo", vec![simple_tool("echo").with_max_concurrency(1)]);
+    key_value::Store::open("default").set(
+        "gateway:concurrency:echo__echo:running",
+        now().to_string().as_bytes(),
+    );
+
+    let json = call_echo();
+    assert_json_rpc_error(&json, -32032, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["limit"], "max_concurrent");
+    assert_eq!(json["error"]["data"]["max"], 1);
+
+    key_value::Store::open("default").delete("gateway:concurrency:echo__echo:running");
+    assert_json_rpc_success(&call_echo(), Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_limits_only_apply_to_their_component() {
+    setup_limited_echo(r#"{"calculator": {"max_response_bytes": 1}}"#);
+
+    assert_json_rpc_success(&call_echo(), Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_invalid_limits_fail_requests() {
+    setup_limited_echo(r#"{"echo": {"max_concurrent": 0}}"#);
+
+    let json = call_echo();
+    assert_json_rpc_error(&json, -32603, Some(serde_json::json!(1)));
+    assert!(json["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("max_concurrent must be at least 1"));
+}
@@ -0,0 +1,198 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{
+        fermyon::spin_test_virt::{key_value, variables},
+        wasi::http,
+    },
+    spin_test,
+};
+
+fn create_config_request(
+    method: &http::types::Method,
+    token: Option<&str>,
+    body: Option<serde_json::Value>,
+) -> http::types::OutgoingRequest {
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    if let Some(token) = token {
+        headers
+            .append("authorization", format!("Bearer {token}").as_bytes())
+            .unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(method).unwrap();
+    request.set_path_with_query(Some("/_ftl/config")).unwrap();
+    if let Some(body) = body {
+        request
+            .body()
+            .unwrap()
+            .write_bytes(&serde_json::to_vec(&body).unwrap());
+    }
+    request
+}
+
+fn setup_live_config_env() {
+    setup_default_test_env();
+    variables::set("config_token", "config-secret");
+    key_value::Store::open("default").delete("gateway:config");
+    mock_tool_component("echo", vec![simple_tool("say")]);
+    mock_tool_component("calculator", vec![simple_tool("add")]);
+    mock_tool_component("weather", vec![simple_tool("forecast")]);
+}
+
+fn put_config(body: serde_json::Value) -> ResponseData {
+    let response = spin_test_sdk::perform_request(create_config_request(
+        &http::types::Method::Put,
+        Some("config-secret"),
+        Some(body),
+    ));
+    ResponseData::from_response(response)
+}
+
+fn listed_tools() -> Vec<String> {
+    let request_json = create_json_rpc_request("tools/list", None, Some(serde_json::json!(1)));
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    let json = ResponseData::from_response(response)
+        .body_json()
+        .expect("Expected JSON response");
+    json["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_config_disabled_without_token() {
+    setup_default_test_env();
+
+    let response = spin_test_sdk::perform_request(create_config_request(
+        &http::types::Method::Get,
+        Some("anything"),
+        None,
+    ));
+    assert_eq!(response.status(), 404);
+}
+
+#[spin_test]
+fn test_config_requires_bearer_token() {
+    setup_live_config_env();
+
+    let response = spin_test_sdk::perform_request(create_config_request(
+        &http::types::Method::Put,
+        Some("wrong"),
+        Some(serde_json::json!({ "version": 1, "component_names": ["weather"] })),
+    ));
+    assert_eq!(response.status(), 401);
+}
+
+#[spin_test]
+fn test_config_update_changes_components_without_restart() {
+    setup_live_config_env();
+    assert_eq!(listed_tools(), vec!["echo__say", "calculator__add"]);
+
+    let response_data = put_config(serde_json::json!({
+        "version": 1,
+        "component_names": ["echo", "weather"],
+    }));
+    assert_eq!(response_data.status, 200);
+    let json = response_data.body_json().expect("Expected JSON response");
+    assert_eq!(json["version"], 1);
+    assert_eq!(
+        json["effective"]["component_names"],
+        serde_json::json!(["echo", "weather"])
+    );
+
+    assert_eq!(listed_tools(), vec!["echo__say", "weather__forecast"]);
+
+    // Removed components are no longer routed to
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": "calculator__add", "arguments": {} })),
+        Some(serde_json::json!(2)),
+    );
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    let json = ResponseData::from_response(response)
+        .body_json()
+        .expect("Expected JSON response");
+    assert_json_rpc_error(&json, -32602, Some(serde_json::json!(2)));
+}
+
+#[spin_test]
+fn test_config_update_toggles_validation() {
+    setup_live_config_env();
+
+    let response_data = put_config(serde_json::json!({
+        "version": 1,
+        "validate_arguments": false,
+    }));
+    assert_eq!(response_data.status, 200);
+
+    let response = spin_test_sdk::perform_request(create_config_request(
+        &http::types::Method::Get,
+        Some("config-secret"),
+        None,
+    ));
+    let json = ResponseData::from_response(response)
+        .body_json()
+        .expect("Expected JSON response");
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["effective"]["validate_arguments"], false);
+    // Components still come from the variables
+    assert_eq!(
+        json["effective"]["component_names"],
+        serde_json::json!(["echo", "calculator"])
+    );
+}
+
+#[spin_test]
+fn test_config_update_requires_next_version() {
+    setup_live_config_env();
+
+    let response_data = put_config(serde_json::json!({ "version": 2, "debug_errors": true }));
+    assert_eq!(response_data.status, 409);
+    let json = response_data.body_json().expect("Expected JSON response");
+    assert_eq!(json["version"], 0);
+
+    assert_eq!(
+        put_config(serde_json::json!({ "version": 1, "debug_errors": true })).status,
+        200
+    );
+    // A writer still holding the old version is rejected
+    assert_eq!(
+        put_config(serde_json::json!({ "version": 1, "debug_errors": false })).status,
+        409
+    );
+}
+
+#[spin_test]
+fn test_config_rejects_invalid_component_names() {
+    setup_live_config_env();
+
+    let response_data = put_config(serde_json::json!({
+        "version": 1,
+        "component_names": ["evil.example.com/"],
+    }));
+    assert_eq!(response_data.status, 400);
+}
+
+#[spin_test]
+fn test_config_delete_restores_variables() {
+    setup_live_config_env();
+    put_config(serde_json::json!({ "version": 1, "component_names": ["weather"] }));
+    assert_eq!(listed_tools(), vec!["weather__forecast"]);
+
+    let response = spin_test_sdk::perform_request(create_config_request(
+        &http::types::Method::Delete,
+        Some("config-secret"),
+        None,
+    ));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 200);
+    let json = response_data.body_json().expect("Expected JSON response");
+    assert_eq!(json["version"], 2);
+
+    assert_eq!(listed_tools(), vec!["echo__say", "calculator__add"]);
+}
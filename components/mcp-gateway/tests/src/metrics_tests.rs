@@ -0,0 +1,142 @@
+use crate::test_helpers::*;
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{
+            spin_test_virt::{key_value, variables},
+            spin_wasi_virt::http_handler,
+        },
+        wasi::http,
+    },
+    spin_test,
+};
+
+const BUFFER_KEY: &str = "gateway:metrics:buffer";
+
+/// Buffer events without sending them
+fn setup_metrics_env() {
+    setup_default_test_env();
+    variables::set("metrics_collector", "metrics-collector");
+    variables::set("metrics_batch_size", "100");
+    variables::set("metrics_flush_interval_ms", "3600000");
+    key_value::Store::open("default").delete(BUFFER_KEY);
+}
+
+fn mock_collector(status: u16) {
+    let response = http::types::OutgoingResponse::new(http::types::Headers::new());
+    response.set_status_code(status).unwrap();
+    http_handler::set_response(
+        "http://metrics-collector.spin.internal/events",
+        http_handler::ResponseHandler::Response(response),
+    );
+}
+
+fn buffered() -> Option<serde_json::Value> {
+    key_value::Store::open("default")
+        .get(BUFFER_KEY)
+        .map(|data| serde_json::from_slice(&data).unwrap())
+}
+
+fn ping() {
+    let request = create_json_rpc_request("ping", None, Some(serde_json::json!(1)));
+    let response = spin_test_sdk::perform_request(create_mcp_request(request));
+    assert_eq!(response.status(), 200);
+}
+
+#[spin_test]
+fn test_tool_call_event() {
+    setup_metrics_env();
+    mock_tool_component("echo", vec![simple_tool("echo")]);
+    mock_tool_execution(
+        "echo",
+        "echo",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "hello".to_string(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        },
+    );
+
+    let request = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": "echo__echo", "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+    let response = spin_test_sdk::perform_request(create_mcp_request(request));
+    assert_eq!(response.status(), 200);
+
+    let buffer = buffered().expect("Expected buffered metrics");
+    let event = &buffer["events"][0];
+    assert_eq!(event["method"], "tools/call");
+    assert_eq!(event["component"], "echo");
+    assert_eq!(event["tool"], "echo");
+    assert_eq!(event["status"], "ok");
+    assert_eq!(event["http_status"], 200);
+    assert!(event["bytes_in"].as_u64().unwrap() > 0);
+    assert!(event["bytes_out"].as_u64().unwrap() > 0);
+    assert!(event["duration_ms"].is_u64());
+    assert!(event.get("error_code").is_none());
+}
+
+#[spin_test]
+fn test_error_event() {
+    setup_metrics_env();
+
+    let request = create_json_rpc_request("unknown/method", None, Some(serde_json::json!(1)));
+    let response = spin_test_sdk::perform_request(create_mcp_request(request));
+    assert_eq!(response.status(), 200);
+
+    let buffer = buffered().expect("Expected buffered metrics");
+    let event = &buffer["events"][0];
+    assert_eq!(event["method"], "unknown/method");
+    assert_eq!(event["status"], "error");
+    assert_eq!(event["error_code"], -32601);
+}
+
+#[spin_test]
+fn test_metrics_kill_switch() {
+    setup_metrics_env();
+    variables::set("metrics_enabled", "false");
+
+    ping();
+    assert!(buffered().is_none());
+}
+
+#[spin_test]
+fn test_metrics_off_without_collector() {
+    setup_metrics_env();
+    variables::set("metrics_collector", "");
+
+    ping();
+    assert!(buffered().is_none());
+}
+
+#[spin_test]
+fn test_full_batch_is_sent() {
+    setup_metrics_env();
+    variables::set("metrics_batch_size", "2");
+    mock_collector(202);
+
+    ping();
+    assert_eq!(buffered().unwrap()["events"].as_array().unwrap().len(), 1);
+
+    ping();
+    assert!(buffered().is_none());
+}
+
+#[spin_test]
+fn test_events_kept_while_collector_fails() {
+    setup_metrics_env();
+    variables::set("metrics_batch_size", "2");
+    mock_collector(503);
+
+    ping();
+    ping();
+
+    let buffer = buffered().expect("Expected buffered metrics");
+    assert_eq!(buffer["events"].as_array().unwrap().len(), 2);
+    assert!(buffer["retry_at_ms"].as_u64().unwrap() > 0);
+}
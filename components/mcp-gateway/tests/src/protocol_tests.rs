@@ -61,10 +61,43 @@ fn test_initialize_unsupported_protocol_version() {
 
     let response_json = response_data.body_json().expect("Expected JSON response");
     assert_json_rpc_error(&response_json, -32602, Some(serde_json::json!(1)));
-    assert!(response_json["error"]["message"]
-        .as_str()
-        .unwrap()
-        .contains("Invalid initialize parameters"));
+    assert_eq!(
+        response_json["error"]["message"],
+        "Unsupported protocol version"
+    );
+    assert_eq!(response_json["error"]["data"]["requested"], "1.0.0");
+    assert_eq!(
+        response_json["error"]["data"]["supported"],
+        serde_json::json!(["2025-06-18", "2025-03-26", "2024-11-05"])
+    );
+}
+
+#[spin_test]
+fn test_initialize_older_protocol_version() {
+    setup_default_test_env();
+
+    for version in ["2025-03-26", "2024-11-05"] {
+        let request_json = create_json_rpc_request(
+            "initialize",
+            Some(serde_json::json!({
+                "protocolVersion": version,
+                "capabilities": {},
+                "clientInfo": {
+                    "name": "test-client",
+                    "version": "1.0.0"
+                }
+            })),
+            Some(serde_json::json!(1)),
+        );
+
+        let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+        let response_data = ResponseData::from_response(response);
+        let response_json = response_data.body_json().expect("Expected JSON response");
+
+        // A supported version is echoed back
+        assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+        assert_eq!(response_json["result"]["protocolVersion"], version);
+    }
 }
 
 #[spin_test]
@@ -149,10 +182,47 @@ fn test_server_capabilities() {
     let response_json = response_data.body_json().expect("Expected JSON response");
     let capabilities = &response_json["result"]["capabilities"];
 
-    // Verify all required capabilities are present
+    // Tools are always advertised
     assert!(capabilities["tools"].is_object());
     assert_eq!(capabilities["tools"]["listChanged"], true);
 
+    // Resources and prompts only when a component declares them
+    assert!(capabilities.get("resources").is_none());
+    assert!(capabilities.get("prompts").is_none());
+
+    assert!(capabilities["experimental_capabilities"].is_object());
+    assert!(capabilities["experimental_capabilities"]["logging"].is_object());
+}
+
+#[spin_test]
+fn test_declared_component_capabilities() {
+    setup_default_test_env();
+    spin_test_sdk::bindings::fermyon::spin_test_virt::variables::set(
+        "component_capabilities",
+        r#"{"echo": ["resources", "prompts"]}"#,
+    );
+
+    let request_json = create_json_rpc_request(
+        "initialize",
+        Some(serde_json::json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": {
+                "name": "test-client",
+                "version": "1.0.0"
+            }
+        })),
+        Some(serde_json::json!(1)),
+    );
+
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    let response_data = ResponseData::from_response(response);
+
+    let response_json = response_data.body_json().expect("Expected JSON response");
+    let capabilities = &response_json["result"]["capabilities"];
+
+    assert!(capabilities["tools"].is_object());
+
     assert!(capabilities["resources"].is_object());
     assert_eq!(capabilities["resources"]["subscribe"], false);
     assert_eq!(capabilities["resources"]["listChanged"], false);
@@ -0,0 +1,157 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+fn setup_rate_limited_echo(limits: &str) {
+    setup_default_test_env();
+    variables::set("tool_rate_limits", limits);
+    mock_tool_component("echo", vec![simple_tool("echo"), simple_tool("reverse")]);
+    for tool in ["echo", "reverse"] {
+        mock_tool_execution(
+            "echo",
+            tool,
+            ToolResponse {
+                content: vec![ToolContent::Text {
+                    text: "ok".to_string(),
+                    annotations: None,
+                }],
+                structured_content: None,
+                is_error: None,
+                meta: None,
+            },
+        );
+    }
+}
+
+fn call_tool(name: &str, user: Option<&str>) -> ResponseData {
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": name, "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    if let Some(user) = user {
+        headers.append("x-auth-user-id", user.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    request
+        .body()
+        .unwrap()
+        .write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 200);
+    response_data
+}
+
+fn header(response: &ResponseData, name: &str) -> Option<String> {
+    response
+        .find_header(name)
+        .map(|value| String::from_utf8(value.clone()).unwrap())
+}
+
+#[spin_test]
+fn test_calls_over_limit_are_throttled() {
+    setup_rate_limited_echo(r#"{"echo__echo": {"requests": 2, "window_secs": 3600}}"#);
+
+    let response = call_tool("echo__echo", Some("alice"));
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+    assert_eq!(header(&response, "ratelimit-limit").as_deref(), Some("2"));
+    assert_eq!(
+        header(&response, "ratelimit-remaining").as_deref(),
+        Some("1")
+    );
+    assert!(header(&response, "retry-after").is_none());
+
+    let response = call_tool("echo__echo", Some("alice"));
+    assert_eq!(
+        header(&response, "ratelimit-remaining").as_deref(),
+        Some("0")
+    );
+
+    let response = call_tool("echo__echo", Some("alice"));
+    let json = response.body_json().unwrap();
+    assert_json_rpc_error(&json, -32029, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["tool"], "echo__echo");
+    assert_eq!(json["error"]["data"]["limit"], 2);
+    assert_eq!(json["error"]["data"]["window_secs"], 3600);
+    let retry_after = json["error"]["data"]["retry_after_secs"].as_u64().unwrap();
+    assert!((1..=3600).contains(&retry_after));
+    assert_eq!(
+        header(&response, "retry-after"),
+        Some(retry_after.to_string())
+    );
+}
+
+#[spin_test]
+fn test_budgets_are_per_caller() {
+    setup_rate_limited_echo(r#"{"echo__*": {"requests": 1, "window_secs": 3600}}"#);
+
+    let response = call_tool("echo__echo", Some("alice"));
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+
+    // The component entry is one budget for all its tools
+    let response = call_tool("echo__reverse", Some("alice"));
+    assert_json_rpc_error(
+        &response.body_json().unwrap(),
+        -32029,
+        Some(serde_json::json!(1)),
+    );
+
+    let response = call_tool("echo__reverse", Some("bob"));
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+
+    // Unauthenticated callers share a budget
+    let response = call_tool("echo__echo", None);
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+    let response = call_tool("echo__echo", None);
+    assert_json_rpc_error(
+        &response.body_json().unwrap(),
+        -32029,
+        Some(serde_json::json!(1)),
+    );
+}
+
+#[spin_test]
+fn test_most_specific_limit_applies() {
+    setup_rate_limited_echo(
+        r#"{"*": {"requests": 1, "window_secs": 3600}, "echo__reverse": {"requests": 3, "window_secs": 3600}}"#,
+    );
+
+    for remaining in ["2", "1", "0"] {
+        let response = call_tool("echo__reverse", Some("carol"));
+        assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+        assert_eq!(
+            header(&response, "ratelimit-remaining").as_deref(),
+            Some(remaining)
+        );
+    }
+}
+
+#[spin_test]
+fn test_unlimited_tools_have_no_quota_headers() {
+    setup_rate_limited_echo(r#"{"echo__reverse": {"requests": 1, "window_secs": 3600}}"#);
+
+    let response = call_tool("echo__echo", Some("dave"));
+    assert_json_rpc_success(&response.body_json().unwrap(), Some(serde_json::json!(1)));
+    assert!(header(&response, "ratelimit-limit").is_none());
+}
+
+#[spin_test]
+fn test_invalid_rate_limits_fail_requests() {
+    setup_rate_limited_echo(r#"{"echo__echo": {"requests": 1, "window_secs": 0}}"#);
+
+    let response = call_tool("echo__echo", Some("erin"));
+    let json = response.body_json().unwrap();
+    assert_json_rpc_error(&json, -32603, Some(serde_json::json!(1)));
+    assert!(json["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("window_secs must be at least 1"));
+}
@@ -0,0 +1,104 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+fn setup_admin_tools() {
+    setup_default_test_env();
+    variables::set("component_names", "admin");
+    mock_tool_component(
+        "admin",
+        vec![
+            simple_tool("status"),
+            simple_tool("delete_user").with_required_scopes(["users:write", "admin"]),
+        ],
+    );
+    for tool in ["status", "delete_user"] {
+        mock_tool_execution(
+            "admin",
+            tool,
+            ToolResponse {
+                content: vec![ToolContent::Text {
+                    text: "ok".to_string(),
+                    annotations: None,
+                }],
+                structured_content: None,
+                is_error: None,
+                meta: None,
+            },
+        );
+    }
+}
+
+fn call_tool(name: &str, scopes: &[&str]) -> serde_json::Value {
+    let request_json = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": name, "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    headers.append("x-auth-user-id", b"alice").unwrap();
+    for scopes in scopes {
+        headers.append("x-auth-scopes", scopes.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+    request
+        .body()
+        .unwrap()
+        .write_bytes(&serde_json::to_vec(&request_json).unwrap());
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().unwrap()
+}
+
+#[spin_test]
+fn test_caller_with_required_scopes_can_call() {
+    setup_admin_tools();
+
+    let json = call_tool("admin__delete_user", &["read admin users:write"]);
+    assert_json_rpc_success(&json, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_caller_missing_a_scope_is_denied() {
+    setup_admin_tools();
+
+    let json = call_tool("admin__delete_user", &["read users:write"]);
+    assert_json_rpc_error(&json, -32031, Some(serde_json::json!(1)));
+    assert_eq!(json["error"]["data"]["tool"], "admin__delete_user");
+    assert_eq!(
+        json["error"]["data"]["required_scopes"],
+        serde_json::json!(["users:write", "admin"])
+    );
+    assert_eq!(
+        json["error"]["data"]["missing_scopes"],
+        serde_json::json!(["admin"])
+    );
+
+    // Without any forwarded scopes every required scope is missing
+    let json = call_tool("admin__delete_user", &[]);
+    assert_json_rpc_error(&json, -32031, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_only_the_last_forwarded_scopes_count() {
+    setup_admin_tools();
+
+    // The authorizer appends its verified scopes after any client-supplied copy
+    let json = call_tool("admin__delete_user", &["admin users:write", "read"]);
+    assert_json_rpc_error(&json, -32031, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_tools_without_required_scopes_are_unaffected() {
+    setup_admin_tools();
+
+    let json = call_tool("admin__status", &[]);
+    assert_json_rpc_success(&json, Some(serde_json::json!(1)));
+}
@@ -0,0 +1,107 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{bindings::fermyon::spin_test_virt::variables, spin_test};
+
+/// Call a tool through the unscoped endpoint with validation disabled
+fn call_tool(name: &str) -> serde_json::Value {
+    let request = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({ "name": name, "arguments": {} })),
+        Some(serde_json::json!(1)),
+    );
+    let response_data =
+        ResponseData::from_response(spin_test_sdk::perform_request(create_mcp_request(request)));
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().expect("Expected JSON response")
+}
+
+fn assert_rejected(response_json: &serde_json::Value, message: &str) {
+    assert_json_rpc_error(response_json, -32602, Some(serde_json::json!(1)));
+    let error = response_json["error"]["message"].as_str().unwrap();
+    assert!(error.contains(message), "unexpected error: {error}");
+}
+
+fn setup_calc() {
+    variables::set("component_names", "calc");
+    variables::set("validate_arguments", "false");
+    mock_tool_component("calc", vec![simple_tool("add")]);
+    mock_tool_execution("calc", "add", ToolResponse::text("3"));
+}
+
+#[spin_test]
+fn test_call_to_unconfigured_component_rejected() {
+    setup_calc();
+
+    // The gateway and authorizer are components too, but never tool routes
+    let response_json = call_tool("mcp-gateway__mcp");
+    assert_rejected(&response_json, "Unknown component 'mcp-gateway'");
+
+    let response_json = call_tool("mcp_authorizer__mcp");
+    assert_rejected(&response_json, "Unknown component 'mcp_authorizer'");
+}
+
+#[spin_test]
+fn test_undeclared_tool_rejected_without_validation() {
+    setup_calc();
+
+    let response_json = call_tool("calc__internal_reset");
+    assert_rejected(
+        &response_json,
+        "Unknown tool 'internal_reset' in component 'calc'",
+    );
+
+    // Declared tools still work
+    let response_json = call_tool("calc__add");
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+    assert_eq!(response_json["result"]["content"][0]["text"], "3");
+}
+
+#[spin_test]
+fn test_path_style_tool_names_rejected() {
+    setup_calc();
+
+    for name in [
+        "calc__../admin",
+        "calc__add/../reset",
+        "calc__add?debug=true",
+        "calc__add%2F..",
+    ] {
+        let response_json = call_tool(name);
+        assert_rejected(&response_json, "Invalid tool name");
+    }
+}
+
+#[spin_test]
+fn test_scope_of_unconfigured_component_not_found() {
+    setup_calc();
+
+    let request_json = create_json_rpc_request("tools/list", None, Some(serde_json::json!(1)));
+    let request = create_mcp_request(request_json);
+    request
+        .set_path_with_query(Some("/mcp/x/mcp-gateway"))
+        .unwrap();
+
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response_data.status, 404);
+    let response_json = response_data.body_json().expect("Expected JSON response");
+    assert_eq!(response_json["error"], "Unknown component: mcp-gateway");
+}
+
+#[spin_test]
+fn test_invalid_component_names_ignored() {
+    variables::set("component_names", "calc, evil.example.com/x ,Upper");
+    variables::set("validate_arguments", "false");
+    mock_tool_component("calc", vec![simple_tool("add"), simple_tool("../reset")]);
+
+    let request_json = create_json_rpc_request("tools/list", None, Some(serde_json::json!(1)));
+    let response_data = ResponseData::from_response(spin_test_sdk::perform_request(
+        create_mcp_request(request_json),
+    ));
+    assert_eq!(response_data.status, 200);
+    let response_json = response_data.body_json().expect("Expected JSON response");
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+
+    // Only routable tools of valid components are listed
+    let tools = response_json["result"]["tools"].as_array().unwrap();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["calc__add"]);
+}
@@ -49,6 +49,7 @@ fn test_tool_routing_snake_to_kebab_case() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -107,6 +108,7 @@ fn test_tool_routing_snake_to_kebab_case() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -0,0 +1,149 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{bindings::wasi::http, spin_test};
+
+// Create an MCP request with extra headers
+fn request_with_headers(
+    method: &http::types::Method,
+    json_rpc: Option<serde_json::Value>,
+    extra_headers: &[(&str, &str)],
+) -> http::types::OutgoingRequest {
+    let headers = http::types::Headers::new();
+    headers.append("content-type", b"application/json").unwrap();
+    for (name, value) in extra_headers {
+        headers.append(name, value.as_bytes()).unwrap();
+    }
+
+    let request = http::types::OutgoingRequest::new(headers);
+    request.set_method(method).unwrap();
+    request.set_path_with_query(Some("/mcp")).unwrap();
+
+    if let Some(json_rpc) = json_rpc {
+        let body = request.body().unwrap();
+        body.write_bytes(&serde_json::to_vec(&json_rpc).unwrap());
+    }
+
+    request
+}
+
+// Initialize a session and return its id
+fn initialize(version: &str) -> String {
+    let request_json = create_json_rpc_request(
+        "initialize",
+        Some(serde_json::json!({
+            "protocolVersion": version,
+            "capabilities": {},
+            "clientInfo": {
+                "name": "test-client",
+                "version": "1.0.0"
+            }
+        })),
+        Some(serde_json::json!(1)),
+    );
+
+    let response = spin_test_sdk::perform_request(create_mcp_request(request_json));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 200);
+
+    let session_id = response_data
+        .find_header("mcp-session-id")
+        .expect("initialize should assign a session id");
+    String::from_utf8(session_id.clone()).unwrap()
+}
+
+fn ping(extra_headers: &[(&str, &str)]) -> ResponseData {
+    let request_json = create_json_rpc_request("ping", None, Some(serde_json::json!(2)));
+    let request = request_with_headers(
+        &http::types::Method::Post,
+        Some(request_json),
+        extra_headers,
+    );
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_session_requests_with_negotiated_version() {
+    setup_default_test_env();
+    let session_id = initialize("2025-06-18");
+
+    let response = ping(&[
+        ("mcp-session-id", session_id.as_str()),
+        ("mcp-protocol-version", "2025-06-18"),
+    ]);
+    assert_eq!(response.status, 200);
+    let response_json = response.body_json().expect("Expected JSON response");
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(2)));
+
+    // The protocol version header is optional within a session
+    let response = ping(&[("mcp-session-id", session_id.as_str())]);
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_initialized_notification_in_session() {
+    setup_default_test_env();
+    let session_id = initialize("2025-06-18");
+
+    let request_json = create_json_rpc_request("notifications/initialized", None, None);
+    let request = request_with_headers(
+        &http::types::Method::Post,
+        Some(request_json),
+        &[("mcp-session-id", session_id.as_str())],
+    );
+    let response = ResponseData::from_response(spin_test_sdk::perform_request(request));
+
+    assert_eq!(response.status, 200);
+    assert!(response.body.is_empty());
+}
+
+#[spin_test]
+fn test_protocol_version_mismatch_rejected() {
+    setup_default_test_env();
+    let session_id = initialize("2025-03-26");
+
+    let response = ping(&[
+        ("mcp-session-id", session_id.as_str()),
+        ("mcp-protocol-version", "2025-06-18"),
+    ]);
+    assert_eq!(response.status, 400);
+    let response_json = response.body_json().expect("Expected JSON response");
+    assert_json_rpc_error(&response_json, -32600, Some(serde_json::json!(2)));
+    assert_eq!(response_json["error"]["data"]["negotiated"], "2025-03-26");
+}
+
+#[spin_test]
+fn test_unsupported_protocol_version_header_rejected() {
+    setup_default_test_env();
+
+    let response = ping(&[("mcp-protocol-version", "1999-01-01")]);
+    assert_eq!(response.status, 400);
+
+    // Stateless requests with a supported version are accepted
+    let response = ping(&[("mcp-protocol-version", "2024-11-05")]);
+    assert_eq!(response.status, 200);
+}
+
+#[spin_test]
+fn test_unknown_session_returns_404() {
+    setup_default_test_env();
+
+    let response = ping(&[("mcp-session-id", "not-a-session")]);
+    assert_eq!(response.status, 404);
+}
+
+#[spin_test]
+fn test_delete_terminates_session() {
+    setup_default_test_env();
+    let session_id = initialize("2025-06-18");
+
+    let request = request_with_headers(
+        &http::types::Method::Delete,
+        None,
+        &[("mcp-session-id", session_id.as_str())],
+    );
+    let response = ResponseData::from_response(spin_test_sdk::perform_request(request));
+    assert_eq!(response.status, 204);
+
+    // The terminated session is no longer recognized
+    let response = ping(&[("mcp-session-id", session_id.as_str())]);
+    assert_eq!(response.status, 404);
+}
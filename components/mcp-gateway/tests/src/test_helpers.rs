@@ -114,6 +114,19 @@ pub fn mock_tool_component(component_name: &str, tools: Vec<ToolMetadata>) {
     );
 }
 
+// Metadata of a tool accepting any object
+pub fn simple_tool(name: &str) -> ToolMetadata {
+    ToolMetadata {
+        name: name.to_string(),
+        title: None,
+        description: None,
+        input_schema: serde_json::json!({"type": "object"}),
+        output_schema: None,
+        annotations: None,
+        meta: None,
+    }
+}
+
 // Mock a tool execution response
 pub fn mock_tool_execution(component_name: &str, tool_name: &str, response_data: ToolResponse) {
     use spin_test_sdk::bindings::fermyon::spin_wasi_virt::http_handler;
@@ -0,0 +1,159 @@
+use crate::{test_helpers::*, ResponseData};
+use spin_test_sdk::{bindings::fermyon::spin_test_virt::variables, spin_test};
+
+fn mock_lookup_tool() {
+    mock_tool_component(
+        "directory",
+        vec![ToolMetadata {
+            name: "lookup".to_string(),
+            title: None,
+            description: Some("Looks up a user".to_string()),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "user": { "type": "string" },
+                    "region": { "type": "string" }
+                },
+                "required": ["user", "region"],
+                "additionalProperties": false
+            }),
+            output_schema: None,
+            annotations: None,
+            meta: None,
+        }],
+    );
+
+    mock_tool_execution(
+        "directory",
+        "lookup",
+        ToolResponse {
+            content: vec![ToolContent::Text {
+                text: "Found user".to_string(),
+                annotations: None,
+            }],
+            structured_content: Some(serde_json::json!({
+                "name": "Alice",
+                "email": "alice@example.com",
+                "accounts": [
+                    { "id": "a1", "ssn": "123-45-6789" },
+                    { "id": "a2", "ssn": "987-65-4321" }
+                ]
+            })),
+            is_error: None,
+            meta: None,
+        },
+    );
+}
+
+fn call_lookup(arguments: serde_json::Value) -> serde_json::Value {
+    let request = create_json_rpc_request(
+        "tools/call",
+        Some(serde_json::json!({
+            "name": "directory__lookup",
+            "arguments": arguments
+        })),
+        Some(serde_json::json!(1)),
+    );
+
+    let response = spin_test_sdk::perform_request(create_mcp_request(request));
+    let response_data = ResponseData::from_response(response);
+    assert_eq!(response_data.status, 200);
+    response_data.body_json().expect("Expected JSON response")
+}
+
+#[spin_test]
+fn test_request_transforms_run_before_validation() {
+    variables::set("component_names", "directory");
+    variables::set("validate_arguments", "true");
+    variables::set(
+        "tool_transforms",
+        r#"{
+            "directory__lookup": {
+                "request": [
+                    { "op": "rename", "path": "$.username", "to": "user" },
+                    { "op": "default", "path": "$.region", "value": "eu" },
+                    { "op": "remove", "path": "$.debug" }
+                ]
+            }
+        }"#,
+    );
+    mock_lookup_tool();
+
+    // Without the transforms these arguments would fail schema validation
+    let response_json = call_lookup(serde_json::json!({ "username": "alice", "debug": true }));
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_request_without_transforms_fails_validation() {
+    variables::set("component_names", "directory");
+    variables::set("validate_arguments", "true");
+    mock_lookup_tool();
+
+    let response_json = call_lookup(serde_json::json!({ "username": "alice" }));
+    assert_json_rpc_error(&response_json, -32602, Some(serde_json::json!(1)));
+}
+
+#[spin_test]
+fn test_response_transforms_redact_fields() {
+    variables::set("component_names", "directory");
+    variables::set("validate_arguments", "false");
+    variables::set(
+        "tool_transforms",
+        r#"{
+            "*": {
+                "response": [{ "op": "remove", "path": "$.structuredContent.accounts[*].ssn" }]
+            },
+            "directory__*": {
+                "response": [{ "op": "remove", "path": "$['structuredContent']['email']" }]
+            }
+        }"#,
+    );
+    mock_lookup_tool();
+
+    let response_json = call_lookup(serde_json::json!({ "user": "alice", "region": "us" }));
+    assert_json_rpc_success(&response_json, Some(serde_json::json!(1)));
+
+    let structured = &response_json["result"]["structuredContent"];
+    assert_eq!(structured["name"], "Alice");
+    assert!(structured.get("email").is_none());
+    assert_eq!(structured["accounts"][0]["id"], "a1");
+    assert!(structured["accounts"][0].get("ssn").is_none());
+    assert!(structured["accounts"][1].get("ssn").is_none());
+    assert_eq!(response_json["result"]["content"][0]["text"], "Found user");
+}
+
+#[spin_test]
+fn test_transforms_only_apply_to_matching_tools() {
+    variables::set("component_names", "directory");
+    variables::set("validate_arguments", "false");
+    variables::set(
+        "tool_transforms",
+        r#"{ "other__lookup": { "response": [{ "op": "remove", "path": "$.structuredContent.email" }] } }"#,
+    );
+    mock_lookup_tool();
+
+    let response_json = call_lookup(serde_json::json!({ "user": "alice", "region": "us" }));
+    assert_eq!(
+        response_json["result"]["structuredContent"]["email"],
+        "alice@example.com"
+    );
+}
+
+#[spin_test]
+fn test_invalid_transform_config_fails_closed() {
+    variables::set("component_names", "directory");
+    variables::set("validate_arguments", "false");
+    variables::set(
+        "tool_transforms",
+        r#"{ "directory__lookup": { "response": [{ "op": "remove", "path": "structuredContent.email" }] } }"#,
+    );
+    mock_lookup_tool();
+
+    let response_json = call_lookup(serde_json::json!({ "user": "alice", "region": "us" }));
+    assert_json_rpc_error(&response_json, -32603, Some(serde_json::json!(1)));
+    assert!(response_json["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("must start with '$'"));
+}
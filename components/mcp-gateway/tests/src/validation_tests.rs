@@ -45,6 +45,7 @@ fn test_argument_validation_enabled() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -162,6 +163,7 @@ fn test_argument_validation_disabled() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -245,6 +247,7 @@ fn test_missing_arguments_defaults_to_empty_object() {
             }],
             structured_content: None,
             is_error: None,
+            meta: None,
         },
     );
 
@@ -0,0 +1,86 @@
+mod routes;
+
+use spin_sdk::http::{Method, Request, Response};
+use spin_sdk::http_component;
+use spin_sdk::variables;
+
+#[http_component]
+#[allow(clippy::needless_pass_by_value)]
+fn handle_upstream_mock(req: Request) -> Response {
+    let routes = match variables::get("routes")
+        .map_err(anyhow::Error::from)
+        .and_then(|encoded| routes::parse(&encoded))
+    {
+        Ok(routes) => routes,
+        Err(e) => {
+            return json_response(
+                500,
+                &serde_json::json!({ "error": format!("Invalid mock routes: {e}") }),
+            );
+        }
+    };
+
+    let method = method_name(req.method());
+    let mock_req = routes::MockRequest {
+        method: &method,
+        path: req.path(),
+        query: req.query(),
+        headers: req
+            .headers()
+            .filter_map(|(name, value)| Some((name.to_string(), value.as_str()?.to_string())))
+            .collect(),
+        body: req.body(),
+    };
+
+    let Some(matched) = routes::find(&routes, &mock_req) else {
+        eprintln!("No mock route for {method} {}", mock_req.path);
+        return json_response(
+            404,
+            &serde_json::json!({
+                "error": "No mock route matches the request",
+                "method": method,
+                "path": mock_req.path,
+            }),
+        );
+    };
+
+    let body = match routes::render(&matched, &mock_req) {
+        Ok(body) => body,
+        Err(e) => {
+            return json_response(
+                500,
+                &serde_json::json!({ "error": format!("Invalid mock response: {e}") }),
+            );
+        }
+    };
+
+    let mut response = Response::builder();
+    response.status(matched.route.status);
+    for (name, value) in &matched.route.headers {
+        response.header(name.as_str(), value.as_str());
+    }
+    response.body(body).build()
+}
+
+fn method_name(method: &Method) -> String {
+    match method {
+        Method::Get => "GET".to_string(),
+        Method::Post => "POST".to_string(),
+        Method::Put => "PUT".to_string(),
+        Method::Delete => "DELETE".to_string(),
+        Method::Patch => "PATCH".to_string(),
+        Method::Head => "HEAD".to_string(),
+        Method::Options => "OPTIONS".to_string(),
+        Method::Connect => "CONNECT".to_string(),
+        Method::Trace => "TRACE".to_string(),
+        Method::Other(other) => other.to_uppercase(),
+    }
+}
+
+fn json_response(status: u16, body: &serde_json::Value) -> Response {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_vec(body).unwrap_or_else(|_| b"{}".to_vec()))
+        .build()
+}
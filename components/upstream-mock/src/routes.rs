@@ -0,0 +1,188 @@
+use std::collections::{BTreeMap, HashMap};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A stub response served for requests matching a method and path
+#[derive(Debug, Deserialize)]
+pub struct Route {
+    /// HTTP method to match, any method when absent
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Path pattern: literal segments, `{name}` segments and a trailing `*`
+    pub path: String,
+    #[serde(default = "default_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Response body, rendered as a template when `template` is set
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub template: bool,
+    /// Binary response body, used instead of `body` when set
+    #[serde(default)]
+    pub body_base64: Option<String>,
+}
+
+const fn default_status() -> u16 {
+    200
+}
+
+/// Decode the `routes` variable: a base64-encoded JSON array of routes.
+/// The encoding keeps `{{ }}` templates away from Spin's own variable
+/// templating.
+pub fn parse(encoded: &str) -> anyhow::Result<Vec<Route>> {
+    let json = STANDARD.decode(encoded.trim())?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+/// The parts of a request used for matching and templates
+pub struct MockRequest<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub query: &'a str,
+    pub headers: Vec<(String, String)>,
+    pub body: &'a [u8],
+}
+
+/// A route matched by a request, with the values of its `{name}` segments
+pub struct Match<'a> {
+    pub route: &'a Route,
+    pub params: HashMap<String, String>,
+}
+
+/// Find the first route matching the request
+pub fn find<'a>(routes: &'a [Route], req: &MockRequest) -> Option<Match<'a>> {
+    routes.iter().find_map(|route| {
+        let method_matches = route
+            .method
+            .as_deref()
+            .is_none_or(|method| method.eq_ignore_ascii_case(req.method));
+        if !method_matches {
+            return None;
+        }
+        match_path(&route.path, req.path).map(|params| Match { route, params })
+    })
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    path.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+fn match_path(pattern: &str, path: &str) -> Option<HashMap<String, String>> {
+    let pattern = segments(pattern);
+    let mut path = segments(path).into_iter();
+    let mut params = HashMap::new();
+
+    for (i, expected) in pattern.iter().enumerate() {
+        if *expected == "*" && i + 1 == pattern.len() {
+            return Some(params);
+        }
+        let actual = path.next()?;
+        if let Some(name) = expected
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+        {
+            params.insert(name.to_string(), percent_decode(actual));
+        } else if *expected != actual {
+            return None;
+        }
+    }
+    path.next().is_none().then_some(params)
+}
+
+/// Render the response body of a matched route
+pub fn render(matched: &Match, req: &MockRequest) -> anyhow::Result<Vec<u8>> {
+    let route = matched.route;
+    if let Some(encoded) = &route.body_base64 {
+        return Ok(STANDARD.decode(encoded)?);
+    }
+    if !route.template {
+        return Ok(route.body.clone().into_bytes());
+    }
+
+    let mut out = String::with_capacity(route.body.len());
+    let mut rest = route.body.as_str();
+    while let Some(start) = rest.find("{{") {
+        let (literal, tail) = rest.split_at(start);
+        out.push_str(literal);
+        let Some(end) = tail.find("}}") else {
+            // An unterminated expression is kept as written
+            out.push_str(tail);
+            return Ok(out.into_bytes());
+        };
+        let expr = tail.get(2..end).unwrap_or_default().trim();
+        out.push_str(&lookup(expr, &matched.params, req));
+        rest = tail.get(end + 2..).unwrap_or_default();
+    }
+    out.push_str(rest);
+    Ok(out.into_bytes())
+}
+
+/// Resolve a template expression. Unknown values render as empty strings.
+fn lookup(expr: &str, params: &HashMap<String, String>, req: &MockRequest) -> String {
+    let (root, key) = expr.split_once('.').unwrap_or((expr, ""));
+    match (root, key) {
+        ("method", "") => req.method.to_string(),
+        ("path", "") => req.path.to_string(),
+        ("path", name) => params.get(name).cloned().unwrap_or_default(),
+        ("query", name) => query_param(req.query, name).unwrap_or_default(),
+        ("header", name) => req
+            .headers
+            .iter()
+            .rev()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default(),
+        ("body", "") => String::from_utf8_lossy(req.body).into_owned(),
+        ("body", pointer) => serde_json::from_slice::<Value>(req.body)
+            .ok()
+            .and_then(|body| {
+                pointer.split('.').try_fold(body, |value, key| match value {
+                    Value::Object(mut map) => map.remove(key),
+                    Value::Array(mut items) => key
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|i| *i < items.len())
+                        .map(|i| items.swap_remove(i)),
+                    _ => None,
+                })
+            })
+            .map(|value| match value {
+                Value::String(s) => s,
+                other => other.to_string(),
+            })
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (percent_decode(key) == name).then(|| percent_decode(value))
+    })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while let Some(&b) = bytes.get(i) {
+        let escaped = (b == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(decoded) = escaped {
+            out.push(decoded);
+            i += 3;
+        } else {
+            out.push(if b == b'+' { b' ' } else { b });
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,168 @@
+use base64::Engine;
+use spin_test_sdk::{
+    bindings::{fermyon::spin_test_virt::variables, wasi::http},
+    spin_test,
+};
+
+pub struct ResponseData {
+    pub status: u16,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub body: Vec<u8>,
+}
+
+impl ResponseData {
+    pub fn from_response(response: http::types::IncomingResponse) -> Self {
+        let status = response.status();
+        let headers = response
+            .headers()
+            .entries()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_vec()))
+            .collect();
+        let body = response.body().unwrap_or_else(|_| Vec::new());
+
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn find_header(&self, name: &str) -> Option<&Vec<u8>> {
+        self.headers
+            .iter()
+            .find(|(h_name, _)| h_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+fn set_routes(routes: serde_json::Value) {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(routes.to_string());
+    variables::set("routes", &encoded);
+}
+
+fn send(
+    method: http::types::Method,
+    path_with_query: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> ResponseData {
+    let request_headers = http::types::Headers::new();
+    for (name, value) in headers {
+        request_headers.append(name, value.as_bytes()).unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(request_headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path_with_query)).unwrap();
+    if let Some(body) = body {
+        request.body().unwrap().write_bytes(body.as_bytes());
+    }
+    ResponseData::from_response(spin_test_sdk::perform_request(request))
+}
+
+#[spin_test]
+fn test_static_route() {
+    set_routes(serde_json::json!([{
+        "method": "GET",
+        "path": "/v1/forecast",
+        "headers": { "content-type": "application/json" },
+        "body": "{\"temperature\":21}"
+    }]));
+
+    let response = send(
+        http::types::Method::Get,
+        "/v1/forecast?city=Oslo",
+        &[],
+        None,
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.find_header("content-type").unwrap(),
+        b"application/json"
+    );
+    assert_eq!(response.body_text(), "{\"temperature\":21}");
+}
+
+#[spin_test]
+fn test_first_matching_route_wins() {
+    set_routes(serde_json::json!([
+        { "method": "POST", "path": "/users", "status": 201, "body": "created" },
+        { "path": "/users/me", "body": "me" },
+        { "path": "/users/{id}", "body": "user" },
+        { "path": "/files/*", "body": "file" }
+    ]));
+
+    let response = send(http::types::Method::Post, "/users", &[], Some("{}"));
+    assert_eq!(response.status, 201);
+    assert_eq!(response.body_text(), "created");
+
+    assert_eq!(
+        send(http::types::Method::Get, "/users/me", &[], None).body_text(),
+        "me"
+    );
+    assert_eq!(
+        send(http::types::Method::Get, "/users/42", &[], None).body_text(),
+        "user"
+    );
+    assert_eq!(
+        send(http::types::Method::Get, "/files/a/b.txt", &[], None).body_text(),
+        "file"
+    );
+}
+
+#[spin_test]
+fn test_templated_body() {
+    set_routes(serde_json::json!([{
+        "path": "/users/{id}",
+        "template": true,
+        "body": "{{ method }} {{ path.id }} q={{ query.q }} by={{ header.x-user }} name={{ body.user.name }} tag={{ body.tags.1 }} missing={{ body.nope }}"
+    }]));
+
+    let response = send(
+        http::types::Method::Put,
+        "/users/a%20b?q=hello+world",
+        &[("x-user", "alice")],
+        Some(r#"{"user":{"name":"Ada"},"tags":["x","y"]}"#),
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body_text(),
+        "PUT a b q=hello world by=alice name=Ada tag=y missing="
+    );
+}
+
+#[spin_test]
+fn test_binary_body() {
+    set_routes(serde_json::json!([{
+        "path": "/logo.png",
+        "headers": { "content-type": "image/png" },
+        "body_base64": "iVBORw0KGgo="
+    }]));
+
+    let response = send(http::types::Method::Get, "/logo.png", &[], None);
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, b"\x89PNG\r\n\x1a\n");
+}
+
+#[spin_test]
+fn test_unmatched_request_is_not_found() {
+    set_routes(serde_json::json!([{ "method": "GET", "path": "/users", "body": "[]" }]));
+
+    let response = send(http::types::Method::Delete, "/users", &[], None);
+    assert_eq!(response.status, 404);
+    let json: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+    assert_eq!(json["method"], "DELETE");
+    assert_eq!(json["path"], "/users");
+}
+
+#[spin_test]
+fn test_invalid_routes_are_a_server_error() {
+    variables::set("routes", "not base64!");
+
+    let response = send(http::types::Method::Get, "/", &[], None);
+    assert_eq!(response.status, 500);
+}
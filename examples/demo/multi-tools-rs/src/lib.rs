@@ -14,12 +14,6 @@ struct ReverseInput {
     text: String,
 }
 
-#[derive(Deserialize, JsonSchema)]
-struct UppercaseInput {
-    /// The text to convert to uppercase
-    text: String,
-}
-
 #[derive(Deserialize, JsonSchema)]
 struct WordCountInput {
     /// The text to count words in
@@ -39,8 +33,11 @@ tools! {
     }
     
     /// Convert text to uppercase
-    fn uppercase(input: UppercaseInput) -> ToolResponse {
-        text!("{}", input.text.to_uppercase())
+    fn uppercase(
+        /// The text to convert to uppercase
+        text: String,
+    ) -> ToolResponse {
+        text!("{}", text.to_uppercase())
     }
     
     /// Count the number of words in the input text
@@ -12,6 +12,7 @@ import (
 	"net/http"
 	"net/url"
 	"strings"
+	"time"
 
 	"github.com/oapi-codegen/runtime"
 	openapi_types "github.com/oapi-codegen/runtime/types"
@@ -99,6 +100,27 @@ const (
 	PENDING  ListAppsResponseBodyAppsStatus = "PENDING"
 )
 
+// Defines values for NotificationDeliveryStatus.
+const (
+	NotificationDeliveryStatusDelivered NotificationDeliveryStatus = "delivered"
+	NotificationDeliveryStatusFailed    NotificationDeliveryStatus = "failed"
+	NotificationDeliveryStatusPending   NotificationDeliveryStatus = "pending"
+)
+
+// Defines values for NotificationEvent.
+const (
+	NotificationEventDeployFailed    NotificationEvent = "deploy.failed"
+	NotificationEventDeployStarted   NotificationEvent = "deploy.started"
+	NotificationEventDeploySucceeded NotificationEvent = "deploy.succeeded"
+)
+
+// Defines values for OrgMemberStatus.
+const (
+	OrgMemberStatusActive   OrgMemberStatus = "active"
+	OrgMemberStatusInactive OrgMemberStatus = "inactive"
+	OrgMemberStatusPending  OrgMemberStatus = "pending"
+)
+
 // Defines values for ListAppsParamsIncludeDeleted.
 const (
 	False ListAppsParamsIncludeDeleted = "false"
@@ -127,7 +149,10 @@ type App struct {
 		Status             AppLatestDeploymentStatus `json:"status"`
 		StatusMessage      *string                   `json:"statusMessage,omitempty"`
 	} `json:"latestDeployment"`
-	OrgId         *string   `json:"orgId,omitempty"`
+	OrgId *string `json:"orgId,omitempty"`
+
+	// Protected Whether deploys and deletes require typed confirmation of the app name
+	Protected     *bool     `json:"protected,omitempty"`
 	ProviderError *string   `json:"providerError,omitempty"`
 	ProviderUrl   *string   `json:"providerUrl,omitempty"`
 	Status        AppStatus `json:"status"`
@@ -143,6 +168,57 @@ type AppLatestDeploymentStatus string
 // AppStatus defines model for App.Status.
 type AppStatus string
 
+// AppNotification Webhook notified of the deployment lifecycle events of an app
+type AppNotification struct {
+	// CreatedAt When the notification was added (RFC3339)
+	CreatedAt string `json:"createdAt"`
+
+	// Events Events that trigger the notification
+	Events []NotificationEvent `json:"events"`
+
+	// NotificationId Notification ID
+	NotificationId string `json:"notificationId"`
+
+	// Url Webhook URL the events are posted to
+	Url string `json:"url"`
+}
+
+// AppVariable A variable of a deployed application
+type AppVariable struct {
+	// Name Variable name
+	Name string `json:"name"`
+
+	// Secret Whether the variable is a secret, whose value is never returned
+	Secret bool `json:"secret"`
+
+	// Value Variable value, omitted for secrets
+	Value *string `json:"value,omitempty"`
+}
+
+// ComponentUsage Usage of a single component over the requested period
+type ComponentUsage struct {
+	// ComponentName Component name
+	ComponentName string `json:"componentName"`
+
+	// ComputeMs Compute time in milliseconds
+	ComputeMs int64 `json:"computeMs"`
+
+	// EgressBytes Outbound network traffic in bytes
+	EgressBytes int64 `json:"egressBytes"`
+
+	// Requests Number of requests handled
+	Requests int64 `json:"requests"`
+}
+
+// CreateAppNotificationRequest Request body for adding a webhook notification
+type CreateAppNotificationRequest struct {
+	// Events Events that trigger the notification
+	Events []NotificationEvent `json:"events"`
+
+	// Url Webhook URL the events are posted to
+	Url string `json:"url"`
+}
+
 // CreateAppRequest Request body for creating an app
 type CreateAppRequest struct {
 	// AccessControl Access control mode for the application
@@ -261,6 +337,33 @@ type GetAppLogsResponseBody struct {
 	} `json:"metadata"`
 }
 
+// GetAppUsageResponseBody Application usage response
+type GetAppUsageResponseBody struct {
+	// AppId Application ID
+	AppId openapi_types.UUID `json:"appId"`
+
+	// Components Usage per component
+	Components []ComponentUsage `json:"components"`
+
+	// Since Start of the reported period (RFC3339)
+	Since string `json:"since"`
+
+	// Totals Usage totals over the requested period
+	Totals UsageTotals `json:"totals"`
+
+	// Until End of the reported period (RFC3339)
+	Until string `json:"until"`
+}
+
+// GetAppVariablesResponseBody Application variables response
+type GetAppVariablesResponseBody struct {
+	// AppId Application ID
+	AppId openapi_types.UUID `json:"appId"`
+
+	// Variables Variables of the latest deployment, sorted by name
+	Variables []AppVariable `json:"variables"`
+}
+
 // GetUserInfoResponseBody User information with organizations
 type GetUserInfoResponseBody struct {
 	// Organizations List of organizations the user belongs to
@@ -285,6 +388,32 @@ type GetUserInfoResponseBody struct {
 	} `json:"user"`
 }
 
+// InviteOrgMemberRequest Request to invite a user to an organization
+type InviteOrgMemberRequest struct {
+	// Email Email address to send the invitation to
+	Email openapi_types.Email `json:"email"`
+
+	// Role Role slug to assign, defaults to the organization's default role
+	Role *string `json:"role,omitempty"`
+}
+
+// InviteOrgMemberResponseBody Response for a successful invitation
+type InviteOrgMemberResponseBody struct {
+	Email string `json:"email"`
+
+	// ExpiresAt When the invitation expires
+	ExpiresAt *time.Time `json:"expiresAt,omitempty"`
+
+	// InvitationId Invitation ID from WorkOS
+	InvitationId string `json:"invitationId"`
+	Role         string `json:"role"`
+}
+
+// ListAppNotificationsResponseBody List of webhook notifications of an app
+type ListAppNotificationsResponseBody struct {
+	Notifications []AppNotification `json:"notifications"`
+}
+
 // ListAppsResponseBody List of applications
 type ListAppsResponseBody struct {
 	Apps []struct {
@@ -308,7 +437,10 @@ type ListAppsResponseBody struct {
 			Status             ListAppsResponseBodyAppsLatestDeploymentStatus `json:"status"`
 			StatusMessage      *string                                        `json:"statusMessage,omitempty"`
 		} `json:"latestDeployment"`
-		OrgId         *string                        `json:"orgId,omitempty"`
+		OrgId *string `json:"orgId,omitempty"`
+
+		// Protected Whether deploys and deletes require typed confirmation of the app name
+		Protected     *bool                          `json:"protected,omitempty"`
 		ProviderError *string                        `json:"providerError,omitempty"`
 		ProviderUrl   *string                        `json:"providerUrl,omitempty"`
 		Status        ListAppsResponseBodyAppsStatus `json:"status"`
@@ -337,6 +469,123 @@ type ListComponentsResponseBody struct {
 	} `json:"components"`
 }
 
+// ListDeploymentNotificationsResponseBody Notification deliveries of a deployment
+type ListDeploymentNotificationsResponseBody struct {
+	Deliveries []NotificationDelivery `json:"deliveries"`
+
+	// DeploymentId Deployment ID
+	DeploymentId string `json:"deploymentId"`
+}
+
+// ListOrgMembersResponseBody List of organization members
+type ListOrgMembersResponseBody struct {
+	Members []OrgMember `json:"members"`
+}
+
+// ListOrgRolesResponseBody List of organization roles
+type ListOrgRolesResponseBody struct {
+	Roles []OrgRole `json:"roles"`
+}
+
+// ListUserSessionsResponseBody List of login sessions
+type ListUserSessionsResponseBody struct {
+	Sessions []UserSession `json:"sessions"`
+}
+
+// NotificationDelivery Delivery of a deployment lifecycle event to a webhook
+type NotificationDelivery struct {
+	// AttemptedAt When the last delivery attempt was made (RFC3339)
+	AttemptedAt *string `json:"attemptedAt,omitempty"`
+
+	// Error Error of the last failed delivery attempt
+	Error *string `json:"error,omitempty"`
+
+	// Event Deployment lifecycle event
+	Event NotificationEvent `json:"event"`
+
+	// NotificationId Notification ID
+	NotificationId string `json:"notificationId"`
+
+	// ResponseStatus HTTP status code returned by the webhook
+	ResponseStatus *int `json:"responseStatus,omitempty"`
+
+	// Status Delivery status of a notification
+	Status NotificationDeliveryStatus `json:"status"`
+
+	// Url Webhook URL the event was posted to
+	Url string `json:"url"`
+}
+
+// NotificationDeliveryStatus Delivery status of a notification
+type NotificationDeliveryStatus string
+
+// NotificationEvent Deployment lifecycle event
+type NotificationEvent string
+
+// OrgMember Organization member
+type OrgMember struct {
+	// Email User email address
+	Email *string `json:"email"`
+
+	// JoinedAt When the member joined the organization
+	JoinedAt *time.Time `json:"joinedAt,omitempty"`
+
+	// Name User full name
+	Name *string `json:"name"`
+
+	// Role Role slug assigned to the member
+	Role string `json:"role"`
+
+	// Status Membership status
+	Status OrgMemberStatus `json:"status"`
+
+	// UserId User ID from WorkOS
+	UserId string `json:"userId"`
+}
+
+// OrgMemberStatus Membership status
+type OrgMemberStatus string
+
+// OrgRole Role that can be assigned to organization members
+type OrgRole struct {
+	// Description Role description
+	Description *string `json:"description,omitempty"`
+
+	// Name Role display name
+	Name string `json:"name"`
+
+	// Permissions Permissions granted by the role
+	Permissions *[]string `json:"permissions,omitempty"`
+
+	// Slug Role identifier
+	Slug string `json:"slug"`
+}
+
+// RemoveOrgMemberResponseBody Response for successful member removal
+type RemoveOrgMemberResponseBody struct {
+	Message string `json:"message"`
+}
+
+// RevokeUserSessionResponseBody Response for successful session revocation
+type RevokeUserSessionResponseBody struct {
+	Message string `json:"message"`
+}
+
+// UpdateAppNotificationRequest Request body for updating a webhook notification
+type UpdateAppNotificationRequest struct {
+	// Events Events that trigger the notification
+	Events *[]NotificationEvent `json:"events,omitempty"`
+
+	// Url Webhook URL the events are posted to
+	Url *string `json:"url,omitempty"`
+}
+
+// UpdateAppRequest Request body for updating application settings
+type UpdateAppRequest struct {
+	// Protected Whether deploys and deletes require typed confirmation of the app name
+	Protected *bool `json:"protected,omitempty"`
+}
+
 // UpdateComponentsRequest Request body for updating components
 type UpdateComponentsRequest struct {
 	Components []struct {
@@ -360,6 +609,36 @@ type UpdateComponentsResponseBody struct {
 	} `json:"components"`
 }
 
+// UsageTotals Usage totals over the requested period
+type UsageTotals struct {
+	// ComputeMs Compute time in milliseconds
+	ComputeMs int64 `json:"computeMs"`
+
+	// EgressBytes Outbound network traffic in bytes
+	EgressBytes int64 `json:"egressBytes"`
+
+	// Requests Number of requests handled
+	Requests int64 `json:"requests"`
+}
+
+// UserSession Login session of a user on a device
+type UserSession struct {
+	// CreatedAt When the session was started
+	CreatedAt time.Time `json:"createdAt"`
+
+	// Device Device or client the session was started from
+	Device *string `json:"device"`
+
+	// Id Session ID
+	Id string `json:"id"`
+
+	// IpAddress IP address the session was started from
+	IpAddress *string `json:"ipAddress"`
+
+	// LastUsedAt When the session's refresh token was last used
+	LastUsedAt *time.Time `json:"lastUsedAt,omitempty"`
+}
+
 // ListAppsParams defines parameters for ListApps.
 type ListAppsParams struct {
 	// Name Filter by app name (partial match)
@@ -393,6 +672,12 @@ type GetAppParams struct {
 	Authorization string `json:"Authorization"`
 }
 
+// UpdateAppParams defines parameters for UpdateApp.
+type UpdateAppParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
 // ListAppComponentsParams defines parameters for ListAppComponents.
 type ListAppComponentsParams struct {
 	// Authorization Bearer token for authentication
@@ -411,6 +696,12 @@ type CreateDeployCredentialsParams struct {
 	Authorization string `json:"Authorization"`
 }
 
+// ListDeploymentNotificationsParams defines parameters for ListDeploymentNotifications.
+type ListDeploymentNotificationsParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
 // GetAppLogsParams defines parameters for GetAppLogs.
 type GetAppLogsParams struct {
 	// Since Time range for logs (e.g., "30m", "1h", "7d", or RFC3339/Unix timestamp)
@@ -423,21 +714,108 @@ type GetAppLogsParams struct {
 	Authorization string `json:"Authorization"`
 }
 
+// ListAppNotificationsParams defines parameters for ListAppNotifications.
+type ListAppNotificationsParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// CreateAppNotificationParams defines parameters for CreateAppNotification.
+type CreateAppNotificationParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// DeleteAppNotificationParams defines parameters for DeleteAppNotification.
+type DeleteAppNotificationParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// UpdateAppNotificationParams defines parameters for UpdateAppNotification.
+type UpdateAppNotificationParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// GetAppUsageParams defines parameters for GetAppUsage.
+type GetAppUsageParams struct {
+	// Since Time range for usage (e.g., "24h", "7d", "30d", or RFC3339/Unix timestamp)
+	Since *string `form:"since,omitempty" json:"since,omitempty"`
+
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// GetAppVariablesParams defines parameters for GetAppVariables.
+type GetAppVariablesParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// ListOrgMembersParams defines parameters for ListOrgMembers.
+type ListOrgMembersParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// InviteOrgMemberParams defines parameters for InviteOrgMember.
+type InviteOrgMemberParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// RemoveOrgMemberParams defines parameters for RemoveOrgMember.
+type RemoveOrgMemberParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// ListOrgRolesParams defines parameters for ListOrgRoles.
+type ListOrgRolesParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
 // GetUserInfoParams defines parameters for GetUserInfo.
 type GetUserInfoParams struct {
 	// Authorization Bearer token for authentication
 	Authorization string `json:"Authorization"`
 }
 
+// ListUserSessionsParams defines parameters for ListUserSessions.
+type ListUserSessionsParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
+// RevokeUserSessionParams defines parameters for RevokeUserSession.
+type RevokeUserSessionParams struct {
+	// Authorization Bearer token for authentication
+	Authorization string `json:"Authorization"`
+}
+
 // CreateAppJSONRequestBody defines body for CreateApp for application/json ContentType.
 type CreateAppJSONRequestBody = CreateAppRequest
 
+// UpdateAppJSONRequestBody defines body for UpdateApp for application/json ContentType.
+type UpdateAppJSONRequestBody = UpdateAppRequest
+
 // UpdateComponentsJSONRequestBody defines body for UpdateComponents for application/json ContentType.
 type UpdateComponentsJSONRequestBody = UpdateComponentsRequest
 
 // CreateDeployCredentialsJSONRequestBody defines body for CreateDeployCredentials for application/json ContentType.
 type CreateDeployCredentialsJSONRequestBody = CreateDeployCredentialsRequest
 
+// CreateAppNotificationJSONRequestBody defines body for CreateAppNotification for application/json ContentType.
+type CreateAppNotificationJSONRequestBody = CreateAppNotificationRequest
+
+// UpdateAppNotificationJSONRequestBody defines body for UpdateAppNotification for application/json ContentType.
+type UpdateAppNotificationJSONRequestBody = UpdateAppNotificationRequest
+
+// InviteOrgMemberJSONRequestBody defines body for InviteOrgMember for application/json ContentType.
+type InviteOrgMemberJSONRequestBody = InviteOrgMemberRequest
+
 // RequestEditorFn  is the function signature for the RequestEditor callback function
 type RequestEditorFn func(ctx context.Context, req *http.Request) error
 
@@ -525,6 +903,11 @@ type ClientInterface interface {
 	// GetApp request
 	GetApp(ctx context.Context, appId openapi_types.UUID, params *GetAppParams, reqEditors ...RequestEditorFn) (*http.Response, error)
 
+	// UpdateAppWithBody request with any body
+	UpdateAppWithBody(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	UpdateApp(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, body UpdateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error)
+
 	// ListAppComponents request
 	ListAppComponents(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*http.Response, error)
 
@@ -538,11 +921,56 @@ type ClientInterface interface {
 
 	CreateDeployCredentials(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, body CreateDeployCredentialsJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error)
 
+	// ListDeploymentNotifications request
+	ListDeploymentNotifications(ctx context.Context, appId openapi_types.UUID, deploymentId string, params *ListDeploymentNotificationsParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
 	// GetAppLogs request
 	GetAppLogs(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*http.Response, error)
 
+	// ListAppNotifications request
+	ListAppNotifications(ctx context.Context, appId openapi_types.UUID, params *ListAppNotificationsParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// CreateAppNotificationWithBody request with any body
+	CreateAppNotificationWithBody(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	CreateAppNotification(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, body CreateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// DeleteAppNotification request
+	DeleteAppNotification(ctx context.Context, appId openapi_types.UUID, notificationId string, params *DeleteAppNotificationParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// UpdateAppNotificationWithBody request with any body
+	UpdateAppNotificationWithBody(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	UpdateAppNotification(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, body UpdateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// GetAppUsage request
+	GetAppUsage(ctx context.Context, appId openapi_types.UUID, params *GetAppUsageParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// GetAppVariables request
+	GetAppVariables(ctx context.Context, appId openapi_types.UUID, params *GetAppVariablesParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// ListOrgMembers request
+	ListOrgMembers(ctx context.Context, orgId string, params *ListOrgMembersParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// InviteOrgMemberWithBody request with any body
+	InviteOrgMemberWithBody(ctx context.Context, orgId string, params *InviteOrgMemberParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	InviteOrgMember(ctx context.Context, orgId string, params *InviteOrgMemberParams, body InviteOrgMemberJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// RemoveOrgMember request
+	RemoveOrgMember(ctx context.Context, orgId string, userId string, params *RemoveOrgMemberParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// ListOrgRoles request
+	ListOrgRoles(ctx context.Context, orgId string, params *ListOrgRolesParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
 	// GetUserInfo request
 	GetUserInfo(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// ListUserSessions request
+	ListUserSessions(ctx context.Context, params *ListUserSessionsParams, reqEditors ...RequestEditorFn) (*http.Response, error)
+
+	// RevokeUserSession request
+	RevokeUserSession(ctx context.Context, sessionId string, params *RevokeUserSessionParams, reqEditors ...RequestEditorFn) (*http.Response, error)
 }
 
 func (c *Client) ListApps(ctx context.Context, params *ListAppsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
@@ -605,6 +1033,30 @@ func (c *Client) GetApp(ctx context.Context, appId openapi_types.UUID, params *G
 	return c.Client.Do(req)
 }
 
+func (c *Client) UpdateAppWithBody(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewUpdateAppRequestWithBody(c.Server, appId, params, contentType, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) UpdateApp(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, body UpdateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewUpdateAppRequest(c.Server, appId, params, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
 func (c *Client) ListAppComponents(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
 	req, err := NewListAppComponentsRequest(c.Server, appId, params)
 	if err != nil {
@@ -665,6 +1117,18 @@ func (c *Client) CreateDeployCredentials(ctx context.Context, appId openapi_type
 	return c.Client.Do(req)
 }
 
+func (c *Client) ListDeploymentNotifications(ctx context.Context, appId openapi_types.UUID, deploymentId string, params *ListDeploymentNotificationsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewListDeploymentNotificationsRequest(c.Server, appId, deploymentId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
 func (c *Client) GetAppLogs(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
 	req, err := NewGetAppLogsRequest(c.Server, appId, params)
 	if err != nil {
@@ -677,8 +1141,8 @@ func (c *Client) GetAppLogs(ctx context.Context, appId openapi_types.UUID, param
 	return c.Client.Do(req)
 }
 
-func (c *Client) GetUserInfo(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
-	req, err := NewGetUserInfoRequest(c.Server, params)
+func (c *Client) ListAppNotifications(ctx context.Context, appId openapi_types.UUID, params *ListAppNotificationsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewListAppNotificationsRequest(c.Server, appId, params)
 	if err != nil {
 		return nil, err
 	}
@@ -689,29 +1153,209 @@ func (c *Client) GetUserInfo(ctx context.Context, params *GetUserInfoParams, req
 	return c.Client.Do(req)
 }
 
-// NewListAppsRequest generates requests for ListApps
-func NewListAppsRequest(server string, params *ListAppsParams) (*http.Request, error) {
-	var err error
-
-	serverURL, err := url.Parse(server)
+func (c *Client) CreateAppNotificationWithBody(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewCreateAppNotificationRequestWithBody(c.Server, appId, params, contentType, body)
 	if err != nil {
 		return nil, err
 	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
 
-	operationPath := fmt.Sprintf("/v1/apps")
-	if operationPath[0] == '/' {
-		operationPath = "." + operationPath
+func (c *Client) CreateAppNotification(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, body CreateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewCreateAppNotificationRequest(c.Server, appId, params, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
 	}
+	return c.Client.Do(req)
+}
 
-	queryURL, err := serverURL.Parse(operationPath)
+func (c *Client) DeleteAppNotification(ctx context.Context, appId openapi_types.UUID, notificationId string, params *DeleteAppNotificationParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewDeleteAppNotificationRequest(c.Server, appId, notificationId, params)
 	if err != nil {
 		return nil, err
 	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
 
-	if params != nil {
-		queryValues := queryURL.Query()
+func (c *Client) UpdateAppNotificationWithBody(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewUpdateAppNotificationRequestWithBody(c.Server, appId, notificationId, params, contentType, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
 
-		if params.Name != nil {
+func (c *Client) UpdateAppNotification(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, body UpdateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewUpdateAppNotificationRequest(c.Server, appId, notificationId, params, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) GetAppUsage(ctx context.Context, appId openapi_types.UUID, params *GetAppUsageParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewGetAppUsageRequest(c.Server, appId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) GetAppVariables(ctx context.Context, appId openapi_types.UUID, params *GetAppVariablesParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewGetAppVariablesRequest(c.Server, appId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) ListOrgMembers(ctx context.Context, orgId string, params *ListOrgMembersParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewListOrgMembersRequest(c.Server, orgId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) InviteOrgMemberWithBody(ctx context.Context, orgId string, params *InviteOrgMemberParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewInviteOrgMemberRequestWithBody(c.Server, orgId, params, contentType, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) InviteOrgMember(ctx context.Context, orgId string, params *InviteOrgMemberParams, body InviteOrgMemberJSONRequestBody, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewInviteOrgMemberRequest(c.Server, orgId, params, body)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) RemoveOrgMember(ctx context.Context, orgId string, userId string, params *RemoveOrgMemberParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewRemoveOrgMemberRequest(c.Server, orgId, userId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) ListOrgRoles(ctx context.Context, orgId string, params *ListOrgRolesParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewListOrgRolesRequest(c.Server, orgId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) GetUserInfo(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewGetUserInfoRequest(c.Server, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) ListUserSessions(ctx context.Context, params *ListUserSessionsParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewListUserSessionsRequest(c.Server, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+func (c *Client) RevokeUserSession(ctx context.Context, sessionId string, params *RevokeUserSessionParams, reqEditors ...RequestEditorFn) (*http.Response, error) {
+	req, err := NewRevokeUserSessionRequest(c.Server, sessionId, params)
+	if err != nil {
+		return nil, err
+	}
+	req = req.WithContext(ctx)
+	if err := c.applyEditors(ctx, req, reqEditors); err != nil {
+		return nil, err
+	}
+	return c.Client.Do(req)
+}
+
+// NewListAppsRequest generates requests for ListApps
+func NewListAppsRequest(server string, params *ListAppsParams) (*http.Request, error) {
+	var err error
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/apps")
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+		queryValues := queryURL.Query()
+
+		if params.Name != nil {
 
 			if queryFrag, err := runtime.StyleParamWithLocation("form", true, "name", runtime.ParamLocationQuery, *params.Name); err != nil {
 				return nil, err
@@ -914,6 +1558,66 @@ func NewGetAppRequest(server string, appId openapi_types.UUID, params *GetAppPar
 	return req, nil
 }
 
+// NewUpdateAppRequest calls the generic UpdateApp builder with application/json body
+func NewUpdateAppRequest(server string, appId openapi_types.UUID, params *UpdateAppParams, body UpdateAppJSONRequestBody) (*http.Request, error) {
+	var bodyReader io.Reader
+	buf, err := json.Marshal(body)
+	if err != nil {
+		return nil, err
+	}
+	bodyReader = bytes.NewReader(buf)
+	return NewUpdateAppRequestWithBody(server, appId, params, "application/json", bodyReader)
+}
+
+// NewUpdateAppRequestWithBody generates requests for UpdateApp with any type of body
+func NewUpdateAppRequestWithBody(server string, appId openapi_types.UUID, params *UpdateAppParams, contentType string, body io.Reader) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/apps/%s", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("PATCH", queryURL.String(), body)
+	if err != nil {
+		return nil, err
+	}
+
+	req.Header.Add("Content-Type", contentType)
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
 // NewListAppComponentsRequest generates requests for ListAppComponents
 func NewListAppComponentsRequest(server string, appId openapi_types.UUID, params *ListAppComponentsParams) (*http.Request, error) {
 	var err error
@@ -1081,6 +1785,60 @@ func NewCreateDeployCredentialsRequestWithBody(server string, appId openapi_type
 	return req, nil
 }
 
+// NewListDeploymentNotificationsRequest generates requests for ListDeploymentNotifications
+func NewListDeploymentNotificationsRequest(server string, appId openapi_types.UUID, deploymentId string, params *ListDeploymentNotificationsParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
+	}
+
+	var pathParam1 string
+
+	pathParam1, err = runtime.StyleParamWithLocation("simple", false, "deploymentId", runtime.ParamLocationPath, deploymentId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/apps/%s/deployments/%s/notifications", pathParam0, pathParam1)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
 // NewGetAppLogsRequest generates requests for GetAppLogs
 func NewGetAppLogsRequest(server string, appId openapi_types.UUID, params *GetAppLogsParams) (*http.Request, error) {
 	var err error
@@ -1166,16 +1924,23 @@ func NewGetAppLogsRequest(server string, appId openapi_types.UUID, params *GetAp
 	return req, nil
 }
 
-// NewGetUserInfoRequest generates requests for GetUserInfo
-func NewGetUserInfoRequest(server string, params *GetUserInfoParams) (*http.Request, error) {
+// NewListAppNotificationsRequest generates requests for ListAppNotifications
+func NewListAppNotificationsRequest(server string, appId openapi_types.UUID, params *ListAppNotificationsParams) (*http.Request, error) {
 	var err error
 
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
+	}
+
 	serverURL, err := url.Parse(server)
 	if err != nil {
 		return nil, err
 	}
 
-	operationPath := fmt.Sprintf("/v1/user/info")
+	operationPath := fmt.Sprintf("/v1/apps/%s/notifications", pathParam0)
 	if operationPath[0] == '/' {
 		operationPath = "." + operationPath
 	}
@@ -1206,433 +1971,2319 @@ func NewGetUserInfoRequest(server string, params *GetUserInfoParams) (*http.Requ
 	return req, nil
 }
 
-func (c *Client) applyEditors(ctx context.Context, req *http.Request, additionalEditors []RequestEditorFn) error {
-	for _, r := range c.RequestEditors {
-		if err := r(ctx, req); err != nil {
-			return err
-		}
-	}
-	for _, r := range additionalEditors {
-		if err := r(ctx, req); err != nil {
-			return err
-		}
+// NewCreateAppNotificationRequest calls the generic CreateAppNotification builder with application/json body
+func NewCreateAppNotificationRequest(server string, appId openapi_types.UUID, params *CreateAppNotificationParams, body CreateAppNotificationJSONRequestBody) (*http.Request, error) {
+	var bodyReader io.Reader
+	buf, err := json.Marshal(body)
+	if err != nil {
+		return nil, err
 	}
-	return nil
+	bodyReader = bytes.NewReader(buf)
+	return NewCreateAppNotificationRequestWithBody(server, appId, params, "application/json", bodyReader)
 }
 
-// ClientWithResponses builds on ClientInterface to offer response payloads
-type ClientWithResponses struct {
-	ClientInterface
-}
+// NewCreateAppNotificationRequestWithBody generates requests for CreateAppNotification with any type of body
+func NewCreateAppNotificationRequestWithBody(server string, appId openapi_types.UUID, params *CreateAppNotificationParams, contentType string, body io.Reader) (*http.Request, error) {
+	var err error
 
-// NewClientWithResponses creates a new ClientWithResponses, which wraps
-// Client with return type handling
-func NewClientWithResponses(server string, opts ...ClientOption) (*ClientWithResponses, error) {
-	client, err := NewClient(server, opts...)
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
 	if err != nil {
 		return nil, err
 	}
-	return &ClientWithResponses{client}, nil
-}
 
-// WithBaseURL overrides the baseURL.
-func WithBaseURL(baseURL string) ClientOption {
-	return func(c *Client) error {
-		newBaseURL, err := url.Parse(baseURL)
-		if err != nil {
-			return err
-		}
-		c.Server = newBaseURL.String()
-		return nil
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
 	}
-}
-
-// ClientWithResponsesInterface is the interface specification for the client with responses above.
-type ClientWithResponsesInterface interface {
-	// ListAppsWithResponse request
-	ListAppsWithResponse(ctx context.Context, params *ListAppsParams, reqEditors ...RequestEditorFn) (*ListAppsWithResponse, error)
 
-	// CreateAppWithBodyWithResponse request with any body
-	CreateAppWithBodyWithResponse(ctx context.Context, params *CreateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error)
-
-	CreateAppWithResponse(ctx context.Context, params *CreateAppParams, body CreateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error)
+	operationPath := fmt.Sprintf("/v1/apps/%s/notifications", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
 
-	// DeleteAppWithResponse request
-	DeleteAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *DeleteAppParams, reqEditors ...RequestEditorFn) (*DeleteAppWithResponse, error)
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
 
-	// GetAppWithResponse request
-	GetAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppParams, reqEditors ...RequestEditorFn) (*GetAppWithResponse, error)
+	req, err := http.NewRequest("POST", queryURL.String(), body)
+	if err != nil {
+		return nil, err
+	}
 
-	// ListAppComponentsWithResponse request
-	ListAppComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*ListAppComponentsWithResponse, error)
+	req.Header.Add("Content-Type", contentType)
 
-	// UpdateComponentsWithBodyWithResponse request with any body
-	UpdateComponentsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error)
+	if params != nil {
 
-	UpdateComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, body UpdateComponentsJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error)
+		var headerParam0 string
 
-	// CreateDeployCredentialsWithBodyWithResponse request with any body
-	CreateDeployCredentialsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error)
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
 
-	CreateDeployCredentialsWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, body CreateDeployCredentialsJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error)
+		req.Header.Set("Authorization", headerParam0)
 
-	// GetAppLogsWithResponse request
-	GetAppLogsWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*GetAppLogsWithResponse, error)
+	}
 
-	// GetUserInfoWithResponse request
-	GetUserInfoWithResponse(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*GetUserInfoWithResponse, error)
+	return req, nil
 }
 
-type ListAppsWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *ListAppsResponseBody
-	JSON400      *ErrorResponse
-	JSON401      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+// NewDeleteAppNotificationRequest generates requests for DeleteAppNotification
+func NewDeleteAppNotificationRequest(server string, appId openapi_types.UUID, notificationId string, params *DeleteAppNotificationParams) (*http.Request, error) {
+	var err error
 
-// Status returns HTTPResponse.Status
-func (r ListAppsWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
-	}
-	return http.StatusText(0)
-}
+	var pathParam0 string
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r ListAppsWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type CreateAppWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON201      *CreateAppResponseBody
-	JSON400      *ErrorResponse
-	JSON401      *ErrorResponse
-	JSON409      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	var pathParam1 string
 
-// Status returns HTTPResponse.Status
-func (r CreateAppWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+	pathParam1, err = runtime.StyleParamWithLocation("simple", false, "notificationId", runtime.ParamLocationPath, notificationId)
+	if err != nil {
+		return nil, err
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r CreateAppWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type DeleteAppWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON202      *DeleteAppResponseBody
-	JSON401      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	operationPath := fmt.Sprintf("/v1/apps/%s/notifications/%s", pathParam0, pathParam1)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
 
-// Status returns HTTPResponse.Status
-func (r DeleteAppWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r DeleteAppWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	req, err := http.NewRequest("DELETE", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type GetAppWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *App
-	JSON401      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
 
-// Status returns HTTPResponse.Status
-func (r GetAppWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
 	}
-	return http.StatusText(0)
+
+	return req, nil
 }
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r GetAppWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+// NewUpdateAppNotificationRequest calls the generic UpdateAppNotification builder with application/json body
+func NewUpdateAppNotificationRequest(server string, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, body UpdateAppNotificationJSONRequestBody) (*http.Request, error) {
+	var bodyReader io.Reader
+	buf, err := json.Marshal(body)
+	if err != nil {
+		return nil, err
 	}
-	return 0
+	bodyReader = bytes.NewReader(buf)
+	return NewUpdateAppNotificationRequestWithBody(server, appId, notificationId, params, "application/json", bodyReader)
 }
 
-type ListAppComponentsWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *ListComponentsResponseBody
-	JSON401      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+// NewUpdateAppNotificationRequestWithBody generates requests for UpdateAppNotification with any type of body
+func NewUpdateAppNotificationRequestWithBody(server string, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, contentType string, body io.Reader) (*http.Request, error) {
+	var err error
 
-// Status returns HTTPResponse.Status
-func (r ListAppComponentsWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r ListAppComponentsWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	var pathParam1 string
+
+	pathParam1, err = runtime.StyleParamWithLocation("simple", false, "notificationId", runtime.ParamLocationPath, notificationId)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type UpdateComponentsWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *UpdateComponentsResponseBody
-	JSON400      *ErrorResponse
-	JSON401      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
 
-// Status returns HTTPResponse.Status
-func (r UpdateComponentsWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+	operationPath := fmt.Sprintf("/v1/apps/%s/notifications/%s", pathParam0, pathParam1)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r UpdateComponentsWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type CreateDeployCredentialsWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *CreateDeployCredentialsResponseBody
-	JSON401      *ErrorResponse
-	JSON403      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	req, err := http.NewRequest("PATCH", queryURL.String(), body)
+	if err != nil {
+		return nil, err
+	}
+
+	req.Header.Add("Content-Type", contentType)
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
 
-// Status returns HTTPResponse.Status
-func (r CreateDeployCredentialsWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
 	}
-	return http.StatusText(0)
+
+	return req, nil
 }
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r CreateDeployCredentialsWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+// NewGetAppUsageRequest generates requests for GetAppUsage
+func NewGetAppUsageRequest(server string, appId openapi_types.UUID, params *GetAppUsageParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type GetAppLogsWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *GetAppLogsResponseBody
-	JSON400      *ErrorResponse
-	JSON401      *ErrorResponse
-	JSON403      *ErrorResponse
-	JSON404      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
 
-// Status returns HTTPResponse.Status
-func (r GetAppLogsWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+	operationPath := fmt.Sprintf("/v1/apps/%s/usage", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r GetAppLogsWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
 	}
-	return 0
-}
 
-type GetUserInfoWithResponse struct {
-	Body         []byte
-	HTTPResponse *http.Response
-	JSON200      *GetUserInfoResponseBody
-	JSON401      *ErrorResponse
-	JSON500      *ErrorResponse
-}
+	if params != nil {
+		queryValues := queryURL.Query()
 
-// Status returns HTTPResponse.Status
-func (r GetUserInfoWithResponse) Status() string {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.Status
+		if params.Since != nil {
+
+			if queryFrag, err := runtime.StyleParamWithLocation("form", true, "since", runtime.ParamLocationQuery, *params.Since); err != nil {
+				return nil, err
+			} else if parsed, err := url.ParseQuery(queryFrag); err != nil {
+				return nil, err
+			} else {
+				for k, v := range parsed {
+					for _, v2 := range v {
+						queryValues.Add(k, v2)
+					}
+				}
+			}
+
+		}
+
+		queryURL.RawQuery = queryValues.Encode()
 	}
-	return http.StatusText(0)
-}
 
-// StatusCode returns HTTPResponse.StatusCode
-func (r GetUserInfoWithResponse) StatusCode() int {
-	if r.HTTPResponse != nil {
-		return r.HTTPResponse.StatusCode
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
 	}
-	return 0
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
 }
 
-// ListAppsWithResponse request returning *ListAppsWithResponse
-func (c *ClientWithResponses) ListAppsWithResponse(ctx context.Context, params *ListAppsParams, reqEditors ...RequestEditorFn) (*ListAppsWithResponse, error) {
-	rsp, err := c.ListApps(ctx, params, reqEditors...)
+// NewGetAppVariablesRequest generates requests for GetAppVariables
+func NewGetAppVariablesRequest(server string, appId openapi_types.UUID, params *GetAppVariablesParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "appId", runtime.ParamLocationPath, appId)
 	if err != nil {
 		return nil, err
 	}
-	return ParseListAppsWithResponse(rsp)
-}
 
-// CreateAppWithBodyWithResponse request with arbitrary body returning *CreateAppWithResponse
-func (c *ClientWithResponses) CreateAppWithBodyWithResponse(ctx context.Context, params *CreateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error) {
-	rsp, err := c.CreateAppWithBody(ctx, params, contentType, body, reqEditors...)
+	serverURL, err := url.Parse(server)
 	if err != nil {
 		return nil, err
 	}
-	return ParseCreateAppWithResponse(rsp)
+
+	operationPath := fmt.Sprintf("/v1/apps/%s/variables", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
 }
 
-func (c *ClientWithResponses) CreateAppWithResponse(ctx context.Context, params *CreateAppParams, body CreateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error) {
-	rsp, err := c.CreateApp(ctx, params, body, reqEditors...)
+// NewListOrgMembersRequest generates requests for ListOrgMembers
+func NewListOrgMembersRequest(server string, orgId string, params *ListOrgMembersParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "orgId", runtime.ParamLocationPath, orgId)
 	if err != nil {
 		return nil, err
 	}
-	return ParseCreateAppWithResponse(rsp)
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/orgs/%s/members", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
 }
 
-// DeleteAppWithResponse request returning *DeleteAppWithResponse
-func (c *ClientWithResponses) DeleteAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *DeleteAppParams, reqEditors ...RequestEditorFn) (*DeleteAppWithResponse, error) {
-	rsp, err := c.DeleteApp(ctx, appId, params, reqEditors...)
+// NewInviteOrgMemberRequest calls the generic InviteOrgMember builder with application/json body
+func NewInviteOrgMemberRequest(server string, orgId string, params *InviteOrgMemberParams, body InviteOrgMemberJSONRequestBody) (*http.Request, error) {
+	var bodyReader io.Reader
+	buf, err := json.Marshal(body)
 	if err != nil {
 		return nil, err
 	}
-	return ParseDeleteAppWithResponse(rsp)
+	bodyReader = bytes.NewReader(buf)
+	return NewInviteOrgMemberRequestWithBody(server, orgId, params, "application/json", bodyReader)
 }
 
-// GetAppWithResponse request returning *GetAppWithResponse
-func (c *ClientWithResponses) GetAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppParams, reqEditors ...RequestEditorFn) (*GetAppWithResponse, error) {
-	rsp, err := c.GetApp(ctx, appId, params, reqEditors...)
-	if err != nil {
-		return nil, err
+// NewInviteOrgMemberRequestWithBody generates requests for InviteOrgMember with any type of body
+func NewInviteOrgMemberRequestWithBody(server string, orgId string, params *InviteOrgMemberParams, contentType string, body io.Reader) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "orgId", runtime.ParamLocationPath, orgId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/orgs/%s/members", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("POST", queryURL.String(), body)
+	if err != nil {
+		return nil, err
+	}
+
+	req.Header.Add("Content-Type", contentType)
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+// NewRemoveOrgMemberRequest generates requests for RemoveOrgMember
+func NewRemoveOrgMemberRequest(server string, orgId string, userId string, params *RemoveOrgMemberParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "orgId", runtime.ParamLocationPath, orgId)
+	if err != nil {
+		return nil, err
+	}
+
+	var pathParam1 string
+
+	pathParam1, err = runtime.StyleParamWithLocation("simple", false, "userId", runtime.ParamLocationPath, userId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/orgs/%s/members/%s", pathParam0, pathParam1)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("DELETE", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+// NewListOrgRolesRequest generates requests for ListOrgRoles
+func NewListOrgRolesRequest(server string, orgId string, params *ListOrgRolesParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "orgId", runtime.ParamLocationPath, orgId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/orgs/%s/roles", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+// NewGetUserInfoRequest generates requests for GetUserInfo
+func NewGetUserInfoRequest(server string, params *GetUserInfoParams) (*http.Request, error) {
+	var err error
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/user/info")
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+// NewListUserSessionsRequest generates requests for ListUserSessions
+func NewListUserSessionsRequest(server string, params *ListUserSessionsParams) (*http.Request, error) {
+	var err error
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/user/sessions")
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("GET", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+// NewRevokeUserSessionRequest generates requests for RevokeUserSession
+func NewRevokeUserSessionRequest(server string, sessionId string, params *RevokeUserSessionParams) (*http.Request, error) {
+	var err error
+
+	var pathParam0 string
+
+	pathParam0, err = runtime.StyleParamWithLocation("simple", false, "sessionId", runtime.ParamLocationPath, sessionId)
+	if err != nil {
+		return nil, err
+	}
+
+	serverURL, err := url.Parse(server)
+	if err != nil {
+		return nil, err
+	}
+
+	operationPath := fmt.Sprintf("/v1/user/sessions/%s", pathParam0)
+	if operationPath[0] == '/' {
+		operationPath = "." + operationPath
+	}
+
+	queryURL, err := serverURL.Parse(operationPath)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequest("DELETE", queryURL.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if params != nil {
+
+		var headerParam0 string
+
+		headerParam0, err = runtime.StyleParamWithLocation("simple", false, "Authorization", runtime.ParamLocationHeader, params.Authorization)
+		if err != nil {
+			return nil, err
+		}
+
+		req.Header.Set("Authorization", headerParam0)
+
+	}
+
+	return req, nil
+}
+
+func (c *Client) applyEditors(ctx context.Context, req *http.Request, additionalEditors []RequestEditorFn) error {
+	for _, r := range c.RequestEditors {
+		if err := r(ctx, req); err != nil {
+			return err
+		}
+	}
+	for _, r := range additionalEditors {
+		if err := r(ctx, req); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// ClientWithResponses builds on ClientInterface to offer response payloads
+type ClientWithResponses struct {
+	ClientInterface
+}
+
+// NewClientWithResponses creates a new ClientWithResponses, which wraps
+// Client with return type handling
+func NewClientWithResponses(server string, opts ...ClientOption) (*ClientWithResponses, error) {
+	client, err := NewClient(server, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return &ClientWithResponses{client}, nil
+}
+
+// WithBaseURL overrides the baseURL.
+func WithBaseURL(baseURL string) ClientOption {
+	return func(c *Client) error {
+		newBaseURL, err := url.Parse(baseURL)
+		if err != nil {
+			return err
+		}
+		c.Server = newBaseURL.String()
+		return nil
+	}
+}
+
+// ClientWithResponsesInterface is the interface specification for the client with responses above.
+type ClientWithResponsesInterface interface {
+	// ListAppsWithResponse request
+	ListAppsWithResponse(ctx context.Context, params *ListAppsParams, reqEditors ...RequestEditorFn) (*ListAppsWithResponse, error)
+
+	// CreateAppWithBodyWithResponse request with any body
+	CreateAppWithBodyWithResponse(ctx context.Context, params *CreateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error)
+
+	CreateAppWithResponse(ctx context.Context, params *CreateAppParams, body CreateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error)
+
+	// DeleteAppWithResponse request
+	DeleteAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *DeleteAppParams, reqEditors ...RequestEditorFn) (*DeleteAppWithResponse, error)
+
+	// GetAppWithResponse request
+	GetAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppParams, reqEditors ...RequestEditorFn) (*GetAppWithResponse, error)
+
+	// UpdateAppWithBodyWithResponse request with any body
+	UpdateAppWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateAppWithResponse, error)
+
+	UpdateAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, body UpdateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateAppWithResponse, error)
+
+	// ListAppComponentsWithResponse request
+	ListAppComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*ListAppComponentsWithResponse, error)
+
+	// UpdateComponentsWithBodyWithResponse request with any body
+	UpdateComponentsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error)
+
+	UpdateComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, body UpdateComponentsJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error)
+
+	// CreateDeployCredentialsWithBodyWithResponse request with any body
+	CreateDeployCredentialsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error)
+
+	CreateDeployCredentialsWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, body CreateDeployCredentialsJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error)
+
+	// ListDeploymentNotificationsWithResponse request
+	ListDeploymentNotificationsWithResponse(ctx context.Context, appId openapi_types.UUID, deploymentId string, params *ListDeploymentNotificationsParams, reqEditors ...RequestEditorFn) (*ListDeploymentNotificationsWithResponse, error)
+
+	// GetAppLogsWithResponse request
+	GetAppLogsWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*GetAppLogsWithResponse, error)
+
+	// ListAppNotificationsWithResponse request
+	ListAppNotificationsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppNotificationsParams, reqEditors ...RequestEditorFn) (*ListAppNotificationsWithResponse, error)
+
+	// CreateAppNotificationWithBodyWithResponse request with any body
+	CreateAppNotificationWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppNotificationWithResponse, error)
+
+	CreateAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, body CreateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppNotificationWithResponse, error)
+
+	// DeleteAppNotificationWithResponse request
+	DeleteAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *DeleteAppNotificationParams, reqEditors ...RequestEditorFn) (*DeleteAppNotificationWithResponse, error)
+
+	// UpdateAppNotificationWithBodyWithResponse request with any body
+	UpdateAppNotificationWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateAppNotificationWithResponse, error)
+
+	UpdateAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, body UpdateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateAppNotificationWithResponse, error)
+
+	// GetAppUsageWithResponse request
+	GetAppUsageWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppUsageParams, reqEditors ...RequestEditorFn) (*GetAppUsageWithResponse, error)
+
+	// GetAppVariablesWithResponse request
+	GetAppVariablesWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppVariablesParams, reqEditors ...RequestEditorFn) (*GetAppVariablesWithResponse, error)
+
+	// ListOrgMembersWithResponse request
+	ListOrgMembersWithResponse(ctx context.Context, orgId string, params *ListOrgMembersParams, reqEditors ...RequestEditorFn) (*ListOrgMembersWithResponse, error)
+
+	// InviteOrgMemberWithBodyWithResponse request with any body
+	InviteOrgMemberWithBodyWithResponse(ctx context.Context, orgId string, params *InviteOrgMemberParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*InviteOrgMemberWithResponse, error)
+
+	InviteOrgMemberWithResponse(ctx context.Context, orgId string, params *InviteOrgMemberParams, body InviteOrgMemberJSONRequestBody, reqEditors ...RequestEditorFn) (*InviteOrgMemberWithResponse, error)
+
+	// RemoveOrgMemberWithResponse request
+	RemoveOrgMemberWithResponse(ctx context.Context, orgId string, userId string, params *RemoveOrgMemberParams, reqEditors ...RequestEditorFn) (*RemoveOrgMemberWithResponse, error)
+
+	// ListOrgRolesWithResponse request
+	ListOrgRolesWithResponse(ctx context.Context, orgId string, params *ListOrgRolesParams, reqEditors ...RequestEditorFn) (*ListOrgRolesWithResponse, error)
+
+	// GetUserInfoWithResponse request
+	GetUserInfoWithResponse(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*GetUserInfoWithResponse, error)
+
+	// ListUserSessionsWithResponse request
+	ListUserSessionsWithResponse(ctx context.Context, params *ListUserSessionsParams, reqEditors ...RequestEditorFn) (*ListUserSessionsWithResponse, error)
+
+	// RevokeUserSessionWithResponse request
+	RevokeUserSessionWithResponse(ctx context.Context, sessionId string, params *RevokeUserSessionParams, reqEditors ...RequestEditorFn) (*RevokeUserSessionWithResponse, error)
+}
+
+type ListAppsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListAppsResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListAppsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListAppsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type CreateAppWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON201      *CreateAppResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON409      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r CreateAppWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r CreateAppWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type DeleteAppWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON202      *DeleteAppResponseBody
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r DeleteAppWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r DeleteAppWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type GetAppWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *App
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r GetAppWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r GetAppWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type UpdateAppWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *App
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r UpdateAppWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r UpdateAppWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListAppComponentsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListComponentsResponseBody
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListAppComponentsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListAppComponentsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type UpdateComponentsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *UpdateComponentsResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r UpdateComponentsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r UpdateComponentsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type CreateDeployCredentialsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *CreateDeployCredentialsResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r CreateDeployCredentialsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r CreateDeployCredentialsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListDeploymentNotificationsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListDeploymentNotificationsResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListDeploymentNotificationsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListDeploymentNotificationsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type GetAppLogsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *GetAppLogsResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r GetAppLogsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r GetAppLogsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListAppNotificationsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListAppNotificationsResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListAppNotificationsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListAppNotificationsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type CreateAppNotificationWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON201      *AppNotification
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r CreateAppNotificationWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r CreateAppNotificationWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type DeleteAppNotificationWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r DeleteAppNotificationWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r DeleteAppNotificationWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type UpdateAppNotificationWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *AppNotification
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r UpdateAppNotificationWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r UpdateAppNotificationWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type GetAppUsageWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *GetAppUsageResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r GetAppUsageWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r GetAppUsageWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type GetAppVariablesWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *GetAppVariablesResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r GetAppVariablesWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r GetAppVariablesWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListOrgMembersWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListOrgMembersResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListOrgMembersWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListOrgMembersWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type InviteOrgMemberWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON201      *InviteOrgMemberResponseBody
+	JSON400      *ErrorResponse
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON409      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r InviteOrgMemberWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r InviteOrgMemberWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type RemoveOrgMemberWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *RemoveOrgMemberResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r RemoveOrgMemberWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r RemoveOrgMemberWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListOrgRolesWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListOrgRolesResponseBody
+	JSON401      *ErrorResponse
+	JSON403      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListOrgRolesWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListOrgRolesWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type GetUserInfoWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *GetUserInfoResponseBody
+	JSON401      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r GetUserInfoWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r GetUserInfoWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type ListUserSessionsWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *ListUserSessionsResponseBody
+	JSON401      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r ListUserSessionsWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r ListUserSessionsWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+type RevokeUserSessionWithResponse struct {
+	Body         []byte
+	HTTPResponse *http.Response
+	JSON200      *RevokeUserSessionResponseBody
+	JSON401      *ErrorResponse
+	JSON404      *ErrorResponse
+	JSON500      *ErrorResponse
+}
+
+// Status returns HTTPResponse.Status
+func (r RevokeUserSessionWithResponse) Status() string {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.Status
+	}
+	return http.StatusText(0)
+}
+
+// StatusCode returns HTTPResponse.StatusCode
+func (r RevokeUserSessionWithResponse) StatusCode() int {
+	if r.HTTPResponse != nil {
+		return r.HTTPResponse.StatusCode
+	}
+	return 0
+}
+
+// ListAppsWithResponse request returning *ListAppsWithResponse
+func (c *ClientWithResponses) ListAppsWithResponse(ctx context.Context, params *ListAppsParams, reqEditors ...RequestEditorFn) (*ListAppsWithResponse, error) {
+	rsp, err := c.ListApps(ctx, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListAppsWithResponse(rsp)
+}
+
+// CreateAppWithBodyWithResponse request with arbitrary body returning *CreateAppWithResponse
+func (c *ClientWithResponses) CreateAppWithBodyWithResponse(ctx context.Context, params *CreateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error) {
+	rsp, err := c.CreateAppWithBody(ctx, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateAppWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) CreateAppWithResponse(ctx context.Context, params *CreateAppParams, body CreateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppWithResponse, error) {
+	rsp, err := c.CreateApp(ctx, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateAppWithResponse(rsp)
+}
+
+// DeleteAppWithResponse request returning *DeleteAppWithResponse
+func (c *ClientWithResponses) DeleteAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *DeleteAppParams, reqEditors ...RequestEditorFn) (*DeleteAppWithResponse, error) {
+	rsp, err := c.DeleteApp(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseDeleteAppWithResponse(rsp)
+}
+
+// GetAppWithResponse request returning *GetAppWithResponse
+func (c *ClientWithResponses) GetAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppParams, reqEditors ...RequestEditorFn) (*GetAppWithResponse, error) {
+	rsp, err := c.GetApp(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseGetAppWithResponse(rsp)
+}
+
+// UpdateAppWithBodyWithResponse request with arbitrary body returning *UpdateAppWithResponse
+func (c *ClientWithResponses) UpdateAppWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateAppWithResponse, error) {
+	rsp, err := c.UpdateAppWithBody(ctx, appId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateAppWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) UpdateAppWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateAppParams, body UpdateAppJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateAppWithResponse, error) {
+	rsp, err := c.UpdateApp(ctx, appId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateAppWithResponse(rsp)
+}
+
+// ListAppComponentsWithResponse request returning *ListAppComponentsWithResponse
+func (c *ClientWithResponses) ListAppComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*ListAppComponentsWithResponse, error) {
+	rsp, err := c.ListAppComponents(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListAppComponentsWithResponse(rsp)
+}
+
+// UpdateComponentsWithBodyWithResponse request with arbitrary body returning *UpdateComponentsWithResponse
+func (c *ClientWithResponses) UpdateComponentsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error) {
+	rsp, err := c.UpdateComponentsWithBody(ctx, appId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateComponentsWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) UpdateComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, body UpdateComponentsJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error) {
+	rsp, err := c.UpdateComponents(ctx, appId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateComponentsWithResponse(rsp)
+}
+
+// CreateDeployCredentialsWithBodyWithResponse request with arbitrary body returning *CreateDeployCredentialsWithResponse
+func (c *ClientWithResponses) CreateDeployCredentialsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error) {
+	rsp, err := c.CreateDeployCredentialsWithBody(ctx, appId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateDeployCredentialsWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) CreateDeployCredentialsWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, body CreateDeployCredentialsJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error) {
+	rsp, err := c.CreateDeployCredentials(ctx, appId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateDeployCredentialsWithResponse(rsp)
+}
+
+// ListDeploymentNotificationsWithResponse request returning *ListDeploymentNotificationsWithResponse
+func (c *ClientWithResponses) ListDeploymentNotificationsWithResponse(ctx context.Context, appId openapi_types.UUID, deploymentId string, params *ListDeploymentNotificationsParams, reqEditors ...RequestEditorFn) (*ListDeploymentNotificationsWithResponse, error) {
+	rsp, err := c.ListDeploymentNotifications(ctx, appId, deploymentId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListDeploymentNotificationsWithResponse(rsp)
+}
+
+// GetAppLogsWithResponse request returning *GetAppLogsWithResponse
+func (c *ClientWithResponses) GetAppLogsWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*GetAppLogsWithResponse, error) {
+	rsp, err := c.GetAppLogs(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseGetAppLogsWithResponse(rsp)
+}
+
+// ListAppNotificationsWithResponse request returning *ListAppNotificationsWithResponse
+func (c *ClientWithResponses) ListAppNotificationsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppNotificationsParams, reqEditors ...RequestEditorFn) (*ListAppNotificationsWithResponse, error) {
+	rsp, err := c.ListAppNotifications(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListAppNotificationsWithResponse(rsp)
+}
+
+// CreateAppNotificationWithBodyWithResponse request with arbitrary body returning *CreateAppNotificationWithResponse
+func (c *ClientWithResponses) CreateAppNotificationWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateAppNotificationWithResponse, error) {
+	rsp, err := c.CreateAppNotificationWithBody(ctx, appId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateAppNotificationWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) CreateAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateAppNotificationParams, body CreateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateAppNotificationWithResponse, error) {
+	rsp, err := c.CreateAppNotification(ctx, appId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseCreateAppNotificationWithResponse(rsp)
+}
+
+// DeleteAppNotificationWithResponse request returning *DeleteAppNotificationWithResponse
+func (c *ClientWithResponses) DeleteAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *DeleteAppNotificationParams, reqEditors ...RequestEditorFn) (*DeleteAppNotificationWithResponse, error) {
+	rsp, err := c.DeleteAppNotification(ctx, appId, notificationId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseDeleteAppNotificationWithResponse(rsp)
+}
+
+// UpdateAppNotificationWithBodyWithResponse request with arbitrary body returning *UpdateAppNotificationWithResponse
+func (c *ClientWithResponses) UpdateAppNotificationWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateAppNotificationWithResponse, error) {
+	rsp, err := c.UpdateAppNotificationWithBody(ctx, appId, notificationId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateAppNotificationWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) UpdateAppNotificationWithResponse(ctx context.Context, appId openapi_types.UUID, notificationId string, params *UpdateAppNotificationParams, body UpdateAppNotificationJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateAppNotificationWithResponse, error) {
+	rsp, err := c.UpdateAppNotification(ctx, appId, notificationId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseUpdateAppNotificationWithResponse(rsp)
+}
+
+// GetAppUsageWithResponse request returning *GetAppUsageWithResponse
+func (c *ClientWithResponses) GetAppUsageWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppUsageParams, reqEditors ...RequestEditorFn) (*GetAppUsageWithResponse, error) {
+	rsp, err := c.GetAppUsage(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseGetAppUsageWithResponse(rsp)
+}
+
+// GetAppVariablesWithResponse request returning *GetAppVariablesWithResponse
+func (c *ClientWithResponses) GetAppVariablesWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppVariablesParams, reqEditors ...RequestEditorFn) (*GetAppVariablesWithResponse, error) {
+	rsp, err := c.GetAppVariables(ctx, appId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseGetAppVariablesWithResponse(rsp)
+}
+
+// ListOrgMembersWithResponse request returning *ListOrgMembersWithResponse
+func (c *ClientWithResponses) ListOrgMembersWithResponse(ctx context.Context, orgId string, params *ListOrgMembersParams, reqEditors ...RequestEditorFn) (*ListOrgMembersWithResponse, error) {
+	rsp, err := c.ListOrgMembers(ctx, orgId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListOrgMembersWithResponse(rsp)
+}
+
+// InviteOrgMemberWithBodyWithResponse request with arbitrary body returning *InviteOrgMemberWithResponse
+func (c *ClientWithResponses) InviteOrgMemberWithBodyWithResponse(ctx context.Context, orgId string, params *InviteOrgMemberParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*InviteOrgMemberWithResponse, error) {
+	rsp, err := c.InviteOrgMemberWithBody(ctx, orgId, params, contentType, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseInviteOrgMemberWithResponse(rsp)
+}
+
+func (c *ClientWithResponses) InviteOrgMemberWithResponse(ctx context.Context, orgId string, params *InviteOrgMemberParams, body InviteOrgMemberJSONRequestBody, reqEditors ...RequestEditorFn) (*InviteOrgMemberWithResponse, error) {
+	rsp, err := c.InviteOrgMember(ctx, orgId, params, body, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseInviteOrgMemberWithResponse(rsp)
+}
+
+// RemoveOrgMemberWithResponse request returning *RemoveOrgMemberWithResponse
+func (c *ClientWithResponses) RemoveOrgMemberWithResponse(ctx context.Context, orgId string, userId string, params *RemoveOrgMemberParams, reqEditors ...RequestEditorFn) (*RemoveOrgMemberWithResponse, error) {
+	rsp, err := c.RemoveOrgMember(ctx, orgId, userId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseRemoveOrgMemberWithResponse(rsp)
+}
+
+// ListOrgRolesWithResponse request returning *ListOrgRolesWithResponse
+func (c *ClientWithResponses) ListOrgRolesWithResponse(ctx context.Context, orgId string, params *ListOrgRolesParams, reqEditors ...RequestEditorFn) (*ListOrgRolesWithResponse, error) {
+	rsp, err := c.ListOrgRoles(ctx, orgId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListOrgRolesWithResponse(rsp)
+}
+
+// GetUserInfoWithResponse request returning *GetUserInfoWithResponse
+func (c *ClientWithResponses) GetUserInfoWithResponse(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*GetUserInfoWithResponse, error) {
+	rsp, err := c.GetUserInfo(ctx, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseGetUserInfoWithResponse(rsp)
+}
+
+// ListUserSessionsWithResponse request returning *ListUserSessionsWithResponse
+func (c *ClientWithResponses) ListUserSessionsWithResponse(ctx context.Context, params *ListUserSessionsParams, reqEditors ...RequestEditorFn) (*ListUserSessionsWithResponse, error) {
+	rsp, err := c.ListUserSessions(ctx, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseListUserSessionsWithResponse(rsp)
+}
+
+// RevokeUserSessionWithResponse request returning *RevokeUserSessionWithResponse
+func (c *ClientWithResponses) RevokeUserSessionWithResponse(ctx context.Context, sessionId string, params *RevokeUserSessionParams, reqEditors ...RequestEditorFn) (*RevokeUserSessionWithResponse, error) {
+	rsp, err := c.RevokeUserSession(ctx, sessionId, params, reqEditors...)
+	if err != nil {
+		return nil, err
+	}
+	return ParseRevokeUserSessionWithResponse(rsp)
+}
+
+// ParseListAppsWithResponse parses an HTTP response from a ListAppsWithResponse call
+func ParseListAppsWithResponse(rsp *http.Response) (*ListAppsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &ListAppsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest ListAppsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseCreateAppWithResponse parses an HTTP response from a CreateAppWithResponse call
+func ParseCreateAppWithResponse(rsp *http.Response) (*CreateAppWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &CreateAppWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 201:
+		var dest CreateAppResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON201 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 409:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON409 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseDeleteAppWithResponse parses an HTTP response from a DeleteAppWithResponse call
+func ParseDeleteAppWithResponse(rsp *http.Response) (*DeleteAppWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &DeleteAppWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 202:
+		var dest DeleteAppResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON202 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseGetAppWithResponse parses an HTTP response from a GetAppWithResponse call
+func ParseGetAppWithResponse(rsp *http.Response) (*GetAppWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &GetAppWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest App
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseUpdateAppWithResponse parses an HTTP response from a UpdateAppWithResponse call
+func ParseUpdateAppWithResponse(rsp *http.Response) (*UpdateAppWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &UpdateAppWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest App
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseListAppComponentsWithResponse parses an HTTP response from a ListAppComponentsWithResponse call
+func ParseListAppComponentsWithResponse(rsp *http.Response) (*ListAppComponentsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &ListAppComponentsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest ListComponentsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseUpdateComponentsWithResponse parses an HTTP response from a UpdateComponentsWithResponse call
+func ParseUpdateComponentsWithResponse(rsp *http.Response) (*UpdateComponentsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &UpdateComponentsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest UpdateComponentsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseCreateDeployCredentialsWithResponse parses an HTTP response from a CreateDeployCredentialsWithResponse call
+func ParseCreateDeployCredentialsWithResponse(rsp *http.Response) (*CreateDeployCredentialsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &CreateDeployCredentialsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest CreateDeployCredentialsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseListDeploymentNotificationsWithResponse parses an HTTP response from a ListDeploymentNotificationsWithResponse call
+func ParseListDeploymentNotificationsWithResponse(rsp *http.Response) (*ListDeploymentNotificationsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &ListDeploymentNotificationsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest ListDeploymentNotificationsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseGetAppLogsWithResponse parses an HTTP response from a GetAppLogsWithResponse call
+func ParseGetAppLogsWithResponse(rsp *http.Response) (*GetAppLogsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &GetAppLogsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest GetAppLogsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseListAppNotificationsWithResponse parses an HTTP response from a ListAppNotificationsWithResponse call
+func ParseListAppNotificationsWithResponse(rsp *http.Response) (*ListAppNotificationsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &ListAppNotificationsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest ListAppNotificationsResponseBody
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
+	}
+
+	return response, nil
+}
+
+// ParseCreateAppNotificationWithResponse parses an HTTP response from a CreateAppNotificationWithResponse call
+func ParseCreateAppNotificationWithResponse(rsp *http.Response) (*CreateAppNotificationWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &CreateAppNotificationWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 201:
+		var dest AppNotification
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON201 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
+
 	}
-	return ParseGetAppWithResponse(rsp)
-}
 
-// ListAppComponentsWithResponse request returning *ListAppComponentsWithResponse
-func (c *ClientWithResponses) ListAppComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *ListAppComponentsParams, reqEditors ...RequestEditorFn) (*ListAppComponentsWithResponse, error) {
-	rsp, err := c.ListAppComponents(ctx, appId, params, reqEditors...)
-	if err != nil {
-		return nil, err
-	}
-	return ParseListAppComponentsWithResponse(rsp)
+	return response, nil
 }
 
-// UpdateComponentsWithBodyWithResponse request with arbitrary body returning *UpdateComponentsWithResponse
-func (c *ClientWithResponses) UpdateComponentsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error) {
-	rsp, err := c.UpdateComponentsWithBody(ctx, appId, params, contentType, body, reqEditors...)
+// ParseDeleteAppNotificationWithResponse parses an HTTP response from a DeleteAppNotificationWithResponse call
+func ParseDeleteAppNotificationWithResponse(rsp *http.Response) (*DeleteAppNotificationWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
-	return ParseUpdateComponentsWithResponse(rsp)
-}
 
-func (c *ClientWithResponses) UpdateComponentsWithResponse(ctx context.Context, appId openapi_types.UUID, params *UpdateComponentsParams, body UpdateComponentsJSONRequestBody, reqEditors ...RequestEditorFn) (*UpdateComponentsWithResponse, error) {
-	rsp, err := c.UpdateComponents(ctx, appId, params, body, reqEditors...)
-	if err != nil {
-		return nil, err
+	response := &DeleteAppNotificationWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
 	}
-	return ParseUpdateComponentsWithResponse(rsp)
-}
 
-// CreateDeployCredentialsWithBodyWithResponse request with arbitrary body returning *CreateDeployCredentialsWithResponse
-func (c *ClientWithResponses) CreateDeployCredentialsWithBodyWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, contentType string, body io.Reader, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error) {
-	rsp, err := c.CreateDeployCredentialsWithBody(ctx, appId, params, contentType, body, reqEditors...)
-	if err != nil {
-		return nil, err
-	}
-	return ParseCreateDeployCredentialsWithResponse(rsp)
-}
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON401 = &dest
 
-func (c *ClientWithResponses) CreateDeployCredentialsWithResponse(ctx context.Context, appId openapi_types.UUID, params *CreateDeployCredentialsParams, body CreateDeployCredentialsJSONRequestBody, reqEditors ...RequestEditorFn) (*CreateDeployCredentialsWithResponse, error) {
-	rsp, err := c.CreateDeployCredentials(ctx, appId, params, body, reqEditors...)
-	if err != nil {
-		return nil, err
-	}
-	return ParseCreateDeployCredentialsWithResponse(rsp)
-}
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
 
-// GetAppLogsWithResponse request returning *GetAppLogsWithResponse
-func (c *ClientWithResponses) GetAppLogsWithResponse(ctx context.Context, appId openapi_types.UUID, params *GetAppLogsParams, reqEditors ...RequestEditorFn) (*GetAppLogsWithResponse, error) {
-	rsp, err := c.GetAppLogs(ctx, appId, params, reqEditors...)
-	if err != nil {
-		return nil, err
-	}
-	return ParseGetAppLogsWithResponse(rsp)
-}
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON500 = &dest
 
-// GetUserInfoWithResponse request returning *GetUserInfoWithResponse
-func (c *ClientWithResponses) GetUserInfoWithResponse(ctx context.Context, params *GetUserInfoParams, reqEditors ...RequestEditorFn) (*GetUserInfoWithResponse, error) {
-	rsp, err := c.GetUserInfo(ctx, params, reqEditors...)
-	if err != nil {
-		return nil, err
 	}
-	return ParseGetUserInfoWithResponse(rsp)
+
+	return response, nil
 }
 
-// ParseListAppsWithResponse parses an HTTP response from a ListAppsWithResponse call
-func ParseListAppsWithResponse(rsp *http.Response) (*ListAppsWithResponse, error) {
+// ParseUpdateAppNotificationWithResponse parses an HTTP response from a UpdateAppNotificationWithResponse call
+func ParseUpdateAppNotificationWithResponse(rsp *http.Response) (*UpdateAppNotificationWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &ListAppsWithResponse{
+	response := &UpdateAppNotificationWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest ListAppsResponseBody
+		var dest AppNotification
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
@@ -1652,6 +4303,20 @@ func ParseListAppsWithResponse(rsp *http.Response) (*ListAppsWithResponse, error
 		}
 		response.JSON401 = &dest
 
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
@@ -1664,26 +4329,26 @@ func ParseListAppsWithResponse(rsp *http.Response) (*ListAppsWithResponse, error
 	return response, nil
 }
 
-// ParseCreateAppWithResponse parses an HTTP response from a CreateAppWithResponse call
-func ParseCreateAppWithResponse(rsp *http.Response) (*CreateAppWithResponse, error) {
+// ParseGetAppUsageWithResponse parses an HTTP response from a GetAppUsageWithResponse call
+func ParseGetAppUsageWithResponse(rsp *http.Response) (*GetAppUsageWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &CreateAppWithResponse{
+	response := &GetAppUsageWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 201:
-		var dest CreateAppResponseBody
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest GetAppUsageResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON201 = &dest
+		response.JSON200 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
 		var dest ErrorResponse
@@ -1699,12 +4364,19 @@ func ParseCreateAppWithResponse(rsp *http.Response) (*CreateAppWithResponse, err
 		}
 		response.JSON401 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 409:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON409 = &dest
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
@@ -1718,26 +4390,33 @@ func ParseCreateAppWithResponse(rsp *http.Response) (*CreateAppWithResponse, err
 	return response, nil
 }
 
-// ParseDeleteAppWithResponse parses an HTTP response from a DeleteAppWithResponse call
-func ParseDeleteAppWithResponse(rsp *http.Response) (*DeleteAppWithResponse, error) {
+// ParseGetAppVariablesWithResponse parses an HTTP response from a GetAppVariablesWithResponse call
+func ParseGetAppVariablesWithResponse(rsp *http.Response) (*GetAppVariablesWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &DeleteAppWithResponse{
+	response := &GetAppVariablesWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 202:
-		var dest DeleteAppResponseBody
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest GetAppVariablesResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON202 = &dest
+		response.JSON200 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
 		var dest ErrorResponse
@@ -1746,6 +4425,13 @@ func ParseDeleteAppWithResponse(rsp *http.Response) (*DeleteAppWithResponse, err
 		}
 		response.JSON401 = &dest
 
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
@@ -1765,22 +4451,22 @@ func ParseDeleteAppWithResponse(rsp *http.Response) (*DeleteAppWithResponse, err
 	return response, nil
 }
 
-// ParseGetAppWithResponse parses an HTTP response from a GetAppWithResponse call
-func ParseGetAppWithResponse(rsp *http.Response) (*GetAppWithResponse, error) {
+// ParseListOrgMembersWithResponse parses an HTTP response from a ListOrgMembersWithResponse call
+func ParseListOrgMembersWithResponse(rsp *http.Response) (*ListOrgMembersWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &GetAppWithResponse{
+	response := &ListOrgMembersWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest App
+		var dest ListOrgMembersResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
@@ -1793,6 +4479,13 @@ func ParseGetAppWithResponse(rsp *http.Response) (*GetAppWithResponse, error) {
 		}
 		response.JSON401 = &dest
 
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON403 = &dest
+
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
@@ -1812,26 +4505,33 @@ func ParseGetAppWithResponse(rsp *http.Response) (*GetAppWithResponse, error) {
 	return response, nil
 }
 
-// ParseListAppComponentsWithResponse parses an HTTP response from a ListAppComponentsWithResponse call
-func ParseListAppComponentsWithResponse(rsp *http.Response) (*ListAppComponentsWithResponse, error) {
+// ParseInviteOrgMemberWithResponse parses an HTTP response from a InviteOrgMemberWithResponse call
+func ParseInviteOrgMemberWithResponse(rsp *http.Response) (*InviteOrgMemberWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &ListAppComponentsWithResponse{
+	response := &InviteOrgMemberWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest ListComponentsResponseBody
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 201:
+		var dest InviteOrgMemberResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON200 = &dest
+		response.JSON201 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON400 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
 		var dest ErrorResponse
@@ -1840,12 +4540,19 @@ func ParseListAppComponentsWithResponse(rsp *http.Response) (*ListAppComponentsW
 		}
 		response.JSON401 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON404 = &dest
+		response.JSON403 = &dest
+
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 409:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON409 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
@@ -1859,40 +4566,40 @@ func ParseListAppComponentsWithResponse(rsp *http.Response) (*ListAppComponentsW
 	return response, nil
 }
 
-// ParseUpdateComponentsWithResponse parses an HTTP response from a UpdateComponentsWithResponse call
-func ParseUpdateComponentsWithResponse(rsp *http.Response) (*UpdateComponentsWithResponse, error) {
+// ParseRemoveOrgMemberWithResponse parses an HTTP response from a RemoveOrgMemberWithResponse call
+func ParseRemoveOrgMemberWithResponse(rsp *http.Response) (*RemoveOrgMemberWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &UpdateComponentsWithResponse{
+	response := &RemoveOrgMemberWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest UpdateComponentsResponseBody
+		var dest RemoveOrgMemberResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
 		response.JSON200 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON400 = &dest
+		response.JSON401 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON401 = &dest
+		response.JSON403 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
 		var dest ErrorResponse
@@ -1913,22 +4620,22 @@ func ParseUpdateComponentsWithResponse(rsp *http.Response) (*UpdateComponentsWit
 	return response, nil
 }
 
-// ParseCreateDeployCredentialsWithResponse parses an HTTP response from a CreateDeployCredentialsWithResponse call
-func ParseCreateDeployCredentialsWithResponse(rsp *http.Response) (*CreateDeployCredentialsWithResponse, error) {
+// ParseListOrgRolesWithResponse parses an HTTP response from a ListOrgRolesWithResponse call
+func ParseListOrgRolesWithResponse(rsp *http.Response) (*ListOrgRolesWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &CreateDeployCredentialsWithResponse{
+	response := &ListOrgRolesWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest CreateDeployCredentialsResponseBody
+		var dest ListOrgRolesResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
@@ -1967,54 +4674,73 @@ func ParseCreateDeployCredentialsWithResponse(rsp *http.Response) (*CreateDeploy
 	return response, nil
 }
 
-// ParseGetAppLogsWithResponse parses an HTTP response from a GetAppLogsWithResponse call
-func ParseGetAppLogsWithResponse(rsp *http.Response) (*GetAppLogsWithResponse, error) {
+// ParseGetUserInfoWithResponse parses an HTTP response from a GetUserInfoWithResponse call
+func ParseGetUserInfoWithResponse(rsp *http.Response) (*GetUserInfoWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &GetAppLogsWithResponse{
+	response := &GetUserInfoWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest GetAppLogsResponseBody
+		var dest GetUserInfoResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
 		response.JSON200 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 400:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON400 = &dest
+		response.JSON401 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON401 = &dest
+		response.JSON500 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 403:
-		var dest ErrorResponse
+	}
+
+	return response, nil
+}
+
+// ParseListUserSessionsWithResponse parses an HTTP response from a ListUserSessionsWithResponse call
+func ParseListUserSessionsWithResponse(rsp *http.Response) (*ListUserSessionsWithResponse, error) {
+	bodyBytes, err := io.ReadAll(rsp.Body)
+	defer func() { _ = rsp.Body.Close() }()
+	if err != nil {
+		return nil, err
+	}
+
+	response := &ListUserSessionsWithResponse{
+		Body:         bodyBytes,
+		HTTPResponse: rsp,
+	}
+
+	switch {
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
+		var dest ListUserSessionsResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON403 = &dest
+		response.JSON200 = &dest
 
-	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 401:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
-		response.JSON404 = &dest
+		response.JSON401 = &dest
 
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
@@ -2028,22 +4754,22 @@ func ParseGetAppLogsWithResponse(rsp *http.Response) (*GetAppLogsWithResponse, e
 	return response, nil
 }
 
-// ParseGetUserInfoWithResponse parses an HTTP response from a GetUserInfoWithResponse call
-func ParseGetUserInfoWithResponse(rsp *http.Response) (*GetUserInfoWithResponse, error) {
+// ParseRevokeUserSessionWithResponse parses an HTTP response from a RevokeUserSessionWithResponse call
+func ParseRevokeUserSessionWithResponse(rsp *http.Response) (*RevokeUserSessionWithResponse, error) {
 	bodyBytes, err := io.ReadAll(rsp.Body)
 	defer func() { _ = rsp.Body.Close() }()
 	if err != nil {
 		return nil, err
 	}
 
-	response := &GetUserInfoWithResponse{
+	response := &RevokeUserSessionWithResponse{
 		Body:         bodyBytes,
 		HTTPResponse: rsp,
 	}
 
 	switch {
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 200:
-		var dest GetUserInfoResponseBody
+		var dest RevokeUserSessionResponseBody
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
 			return nil, err
 		}
@@ -2056,6 +4782,13 @@ func ParseGetUserInfoWithResponse(rsp *http.Response) (*GetUserInfoWithResponse,
 		}
 		response.JSON401 = &dest
 
+	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 404:
+		var dest ErrorResponse
+		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
+			return nil, err
+		}
+		response.JSON404 = &dest
+
 	case strings.Contains(rsp.Header.Get("Content-Type"), "json") && rsp.StatusCode == 500:
 		var dest ErrorResponse
 		if err := json.Unmarshal(bodyBytes, &dest); err != nil {
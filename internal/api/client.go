@@ -122,7 +122,7 @@ func (c *FTLClient) ListApps(ctx context.Context, params *ListAppsParams) (*List
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON200 == nil {
@@ -141,7 +141,7 @@ func (c *FTLClient) CreateApp(ctx context.Context, request CreateAppRequest) (*C
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusCreated {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON201 == nil {
@@ -164,7 +164,7 @@ func (c *FTLClient) GetApp(ctx context.Context, appID string) (*App, error) {
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON200 == nil {
@@ -187,12 +187,35 @@ func (c *FTLClient) DeleteApp(ctx context.Context, appID string) error {
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusAccepted && resp.HTTPResponse.StatusCode != http.StatusNoContent {
-		return fmt.Errorf("API error: %s", string(resp.Body))
+		return NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	return nil
 }
 
+// UpdateApp updates the settings of an application
+func (c *FTLClient) UpdateApp(ctx context.Context, appID string, request UpdateAppRequest) (*App, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &UpdateAppParams{}
+	resp, err := c.client.UpdateAppWithResponse(ctx, appUUID, params, request)
+	if err != nil {
+		return nil, fmt.Errorf("failed to update app: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
 // Deployment Credentials API methods
 
 // CreateDeployCredentials creates temporary credentials for deployment (ECR and Lambda)
@@ -213,7 +236,7 @@ func (c *FTLClient) CreateDeployCredentials(ctx context.Context, appID string, c
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON200 == nil {
@@ -239,7 +262,175 @@ func (c *FTLClient) UpdateComponents(ctx context.Context, appID string, request
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// Usage API methods
+
+// GetAppUsage retrieves the per-component usage of an app over a time range
+// such as "30d", or the API default when since is empty
+func (c *FTLClient) GetAppUsage(ctx context.Context, appID, since string) (*GetAppUsageResponseBody, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &GetAppUsageParams{}
+	if since != "" {
+		params.Since = &since
+	}
+
+	resp, err := c.client.GetAppUsageWithResponse(ctx, appUUID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get usage: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// GetAppVariables retrieves the variables of an app's latest deployment.
+// Secret variables are listed without their value.
+func (c *FTLClient) GetAppVariables(ctx context.Context, appID string) (*GetAppVariablesResponseBody, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &GetAppVariablesParams{}
+	resp, err := c.client.GetAppVariablesWithResponse(ctx, appUUID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get variables: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// Notification API methods
+
+// ListAppNotifications lists the webhooks notified of an app's deployment lifecycle events
+func (c *FTLClient) ListAppNotifications(ctx context.Context, appID string) (*ListAppNotificationsResponseBody, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &ListAppNotificationsParams{}
+	resp, err := c.client.ListAppNotificationsWithResponse(ctx, appUUID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list notifications: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// CreateAppNotification adds a webhook notified of an app's deployment lifecycle events
+func (c *FTLClient) CreateAppNotification(ctx context.Context, appID string, request CreateAppNotificationRequest) (*AppNotification, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &CreateAppNotificationParams{}
+	resp, err := c.client.CreateAppNotificationWithResponse(ctx, appUUID, params, request)
+	if err != nil {
+		return nil, fmt.Errorf("failed to add notification: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusCreated {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON201 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON201, nil
+}
+
+// UpdateAppNotification updates the URL or events of a webhook notification
+func (c *FTLClient) UpdateAppNotification(ctx context.Context, appID, notificationID string, request UpdateAppNotificationRequest) (*AppNotification, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &UpdateAppNotificationParams{}
+	resp, err := c.client.UpdateAppNotificationWithResponse(ctx, appUUID, notificationID, params, request)
+	if err != nil {
+		return nil, fmt.Errorf("failed to update notification: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// DeleteAppNotification removes a webhook notification from an app
+func (c *FTLClient) DeleteAppNotification(ctx context.Context, appID, notificationID string) error {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &DeleteAppNotificationParams{}
+	resp, err := c.client.DeleteAppNotificationWithResponse(ctx, appUUID, notificationID, params)
+	if err != nil {
+		return fmt.Errorf("failed to remove notification: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK && resp.HTTPResponse.StatusCode != http.StatusNoContent {
+		return NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	return nil
+}
+
+// ListDeploymentNotifications retrieves the delivery status of the webhook
+// notifications triggered by a deployment
+func (c *FTLClient) ListDeploymentNotifications(ctx context.Context, appID, deploymentID string) (*ListDeploymentNotificationsResponseBody, error) {
+	appUUID, err := parseUUID(appID)
+	if err != nil {
+		return nil, fmt.Errorf("invalid app ID: %w", err)
+	}
+	params := &ListDeploymentNotificationsParams{}
+	resp, err := c.client.ListDeploymentNotificationsWithResponse(ctx, appUUID, deploymentID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list notification deliveries: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON200 == nil {
@@ -263,7 +454,115 @@ func (c *FTLClient) GetUserInfo(ctx context.Context) (*GetUserInfoResponseBody,
 	}
 
 	if resp.HTTPResponse.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("API error: %s", string(resp.Body))
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// ListUserSessions lists the login sessions of the current user
+func (c *FTLClient) ListUserSessions(ctx context.Context) (*ListUserSessionsResponseBody, error) {
+	params := &ListUserSessionsParams{}
+	resp, err := c.client.ListUserSessionsWithResponse(ctx, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list sessions: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// RevokeUserSession revokes a login session of the current user
+func (c *FTLClient) RevokeUserSession(ctx context.Context, sessionID string) error {
+	params := &RevokeUserSessionParams{}
+	resp, err := c.client.RevokeUserSessionWithResponse(ctx, sessionID, params)
+	if err != nil {
+		return fmt.Errorf("failed to revoke session: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK && resp.HTTPResponse.StatusCode != http.StatusNoContent {
+		return NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	return nil
+}
+
+// Organization API methods
+
+// ListOrgMembers lists the members of an organization, including pending invitations
+func (c *FTLClient) ListOrgMembers(ctx context.Context, orgID string) (*ListOrgMembersResponseBody, error) {
+	params := &ListOrgMembersParams{}
+	resp, err := c.client.ListOrgMembersWithResponse(ctx, orgID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list organization members: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON200 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON200, nil
+}
+
+// InviteOrgMember invites a user to an organization by email
+func (c *FTLClient) InviteOrgMember(ctx context.Context, orgID string, request InviteOrgMemberRequest) (*InviteOrgMemberResponseBody, error) {
+	params := &InviteOrgMemberParams{}
+	resp, err := c.client.InviteOrgMemberWithResponse(ctx, orgID, params, request)
+	if err != nil {
+		return nil, fmt.Errorf("failed to invite organization member: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusCreated {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	if resp.JSON201 == nil {
+		return nil, fmt.Errorf("unexpected response format")
+	}
+
+	return resp.JSON201, nil
+}
+
+// RemoveOrgMember removes a member from an organization
+func (c *FTLClient) RemoveOrgMember(ctx context.Context, orgID, userID string) error {
+	params := &RemoveOrgMemberParams{}
+	resp, err := c.client.RemoveOrgMemberWithResponse(ctx, orgID, userID, params)
+	if err != nil {
+		return fmt.Errorf("failed to remove organization member: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK && resp.HTTPResponse.StatusCode != http.StatusNoContent {
+		return NewAPIError(resp.StatusCode(), resp.Body)
+	}
+
+	return nil
+}
+
+// ListOrgRoles lists the roles that can be assigned to organization members
+func (c *FTLClient) ListOrgRoles(ctx context.Context, orgID string) (*ListOrgRolesResponseBody, error) {
+	params := &ListOrgRolesParams{}
+	resp, err := c.client.ListOrgRolesWithResponse(ctx, orgID, params)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list organization roles: %w", err)
+	}
+
+	if resp.HTTPResponse.StatusCode != http.StatusOK {
+		return nil, NewAPIError(resp.StatusCode(), resp.Body)
 	}
 
 	if resp.JSON200 == nil {
@@ -279,6 +279,197 @@ func TestFTLClient_DeleteApp(t *testing.T) {
 	assert.NoError(t, err)
 }
 
+func TestFTLClient_UpdateApp(t *testing.T) {
+	testID := uuid.New().String()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, fmt.Sprintf("/v1/apps/%s", testID), r.URL.Path)
+		assert.Equal(t, "PATCH", r.Method)
+
+		var request UpdateAppRequest
+		require.NoError(t, json.NewDecoder(r.Body).Decode(&request))
+		require.NotNil(t, request.Protected)
+
+		response := App{
+			AppId:     openapi_types.UUID(uuid.MustParse(testID)),
+			AppName:   "Test App",
+			CreatedAt: "2024-01-01",
+			UpdatedAt: "2024-01-02",
+			Status:    AppStatusACTIVE,
+			Protected: request.Protected,
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusOK)
+		_ = json.NewEncoder(w).Encode(response)
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	protected := true
+	app, err := client.UpdateApp(context.Background(), testID, UpdateAppRequest{Protected: &protected})
+	require.NoError(t, err)
+	require.NotNil(t, app.Protected)
+	assert.True(t, *app.Protected)
+}
+
+func TestFTLClient_GetAppUsage(t *testing.T) {
+	testID := uuid.New().String()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, fmt.Sprintf("/v1/apps/%s/usage", testID), r.URL.Path)
+		assert.Equal(t, "GET", r.Method)
+		assert.Equal(t, "30d", r.URL.Query().Get("since"))
+
+		response := GetAppUsageResponseBody{
+			AppId: openapi_types.UUID(uuid.MustParse(testID)),
+			Since: "2024-01-01T00:00:00Z",
+			Until: "2024-01-31T00:00:00Z",
+			Totals: UsageTotals{
+				Requests:    150,
+				ComputeMs:   4200,
+				EgressBytes: 2048,
+			},
+			Components: []ComponentUsage{
+				{ComponentName: "weather", Requests: 100, ComputeMs: 4000, EgressBytes: 2048},
+				{ComponentName: "calc", Requests: 50, ComputeMs: 200},
+			},
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusOK)
+		_ = json.NewEncoder(w).Encode(response)
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	usage, err := client.GetAppUsage(context.Background(), testID, "30d")
+	require.NoError(t, err)
+	assert.Equal(t, int64(150), usage.Totals.Requests)
+	require.Len(t, usage.Components, 2)
+	assert.Equal(t, "weather", usage.Components[0].ComponentName)
+	assert.Equal(t, int64(4000), usage.Components[0].ComputeMs)
+}
+
+func TestFTLClient_GetAppVariables(t *testing.T) {
+	testID := uuid.New().String()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, fmt.Sprintf("/v1/apps/%s/variables", testID), r.URL.Path)
+		assert.Equal(t, "GET", r.Method)
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusOK)
+		_, _ = fmt.Fprintf(w, `{"appId":%q,"variables":[{"name":"api_key","secret":true},{"name":"log_level","value":"warn","secret":false}]}`, testID)
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	variables, err := client.GetAppVariables(context.Background(), testID)
+	require.NoError(t, err)
+	require.Len(t, variables.Variables, 2)
+	assert.True(t, variables.Variables[0].Secret)
+	assert.Nil(t, variables.Variables[0].Value)
+	require.NotNil(t, variables.Variables[1].Value)
+	assert.Equal(t, "warn", *variables.Variables[1].Value)
+}
+
+func TestFTLClient_CreateAppNotification(t *testing.T) {
+	testID := uuid.New().String()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, fmt.Sprintf("/v1/apps/%s/notifications", testID), r.URL.Path)
+		assert.Equal(t, "POST", r.Method)
+
+		var request CreateAppNotificationRequest
+		require.NoError(t, json.NewDecoder(r.Body).Decode(&request))
+
+		response := AppNotification{
+			NotificationId: "ntf_123",
+			Url:            request.Url,
+			Events:         request.Events,
+			CreatedAt:      "2024-01-01T00:00:00Z",
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusCreated)
+		_ = json.NewEncoder(w).Encode(response)
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	notification, err := client.CreateAppNotification(context.Background(), testID, CreateAppNotificationRequest{
+		Url:    "https://hooks.slack.com/services/T0/B0/XYZ",
+		Events: []NotificationEvent{NotificationEventDeploySucceeded, NotificationEventDeployFailed},
+	})
+	require.NoError(t, err)
+	assert.Equal(t, "ntf_123", notification.NotificationId)
+	assert.Equal(t, []NotificationEvent{NotificationEventDeploySucceeded, NotificationEventDeployFailed}, notification.Events)
+}
+
+func TestFTLClient_ListDeploymentNotifications(t *testing.T) {
+	testID := uuid.New().String()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, fmt.Sprintf("/v1/apps/%s/deployments/dep-1/notifications", testID), r.URL.Path)
+		assert.Equal(t, "GET", r.Method)
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusOK)
+		_, _ = fmt.Fprint(w, `{"deploymentId":"dep-1","deliveries":[{"notificationId":"ntf_123","url":"https://example.com/hook","event":"deploy.succeeded","status":"failed","responseStatus":502,"error":"bad gateway"}]}`)
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	deliveries, err := client.ListDeploymentNotifications(context.Background(), testID, "dep-1")
+	require.NoError(t, err)
+	require.Len(t, deliveries.Deliveries, 1)
+	assert.Equal(t, NotificationEventDeploySucceeded, deliveries.Deliveries[0].Event)
+	assert.Equal(t, NotificationDeliveryStatusFailed, deliveries.Deliveries[0].Status)
+	require.NotNil(t, deliveries.Deliveries[0].ResponseStatus)
+	assert.Equal(t, 502, *deliveries.Deliveries[0].ResponseStatus)
+}
+
 func TestFTLClient_ErrorHandling(t *testing.T) {
 	// Create test server that returns errors
 	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
@@ -305,7 +496,11 @@ func TestFTLClient_ErrorHandling(t *testing.T) {
 
 	t.Run("ListApps error", func(t *testing.T) {
 		_, err := client.ListApps(ctx, nil)
-		assert.Error(t, err)
+		var apiErr *APIError
+		require.ErrorAs(t, err, &apiErr)
+		assert.Equal(t, http.StatusInternalServerError, apiErr.StatusCode)
+		assert.Equal(t, ErrorKindOther, apiErr.Kind)
+		assert.Equal(t, "internal server error", apiErr.Message)
 	})
 
 	t.Run("GetApp error", func(t *testing.T) {
@@ -317,6 +512,132 @@ func TestFTLClient_ErrorHandling(t *testing.T) {
 		err := client.DeleteApp(ctx, "test-app")
 		assert.Error(t, err)
 	})
+
+	t.Run("RemoveOrgMember error", func(t *testing.T) {
+		err := client.RemoveOrgMember(ctx, "org_123", "user_1")
+		assert.Error(t, err)
+	})
+}
+
+func TestFTLClient_OrgMembers(t *testing.T) {
+	// Create test server
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch {
+		case r.Method == "GET" && r.URL.Path == "/v1/orgs/org_123/members":
+			email := "alice@example.com"
+			_ = json.NewEncoder(w).Encode(ListOrgMembersResponseBody{
+				Members: []OrgMember{
+					{UserId: "user_1", Email: &email, Role: "admin", Status: OrgMemberStatusActive},
+				},
+			})
+		case r.Method == "POST" && r.URL.Path == "/v1/orgs/org_123/members":
+			var req InviteOrgMemberRequest
+			_ = json.NewDecoder(r.Body).Decode(&req)
+			assert.Equal(t, "bob@example.com", string(req.Email))
+			require.NotNil(t, req.Role)
+
+			w.WriteHeader(http.StatusCreated)
+			_ = json.NewEncoder(w).Encode(InviteOrgMemberResponseBody{
+				InvitationId: "invitation_1",
+				Email:        string(req.Email),
+				Role:         *req.Role,
+			})
+		case r.Method == "DELETE" && r.URL.Path == "/v1/orgs/org_123/members/user_1":
+			_ = json.NewEncoder(w).Encode(RemoveOrgMemberResponseBody{Message: "removed"})
+		case r.Method == "GET" && r.URL.Path == "/v1/orgs/org_123/roles":
+			_ = json.NewEncoder(w).Encode(ListOrgRolesResponseBody{
+				Roles: []OrgRole{{Slug: "admin", Name: "Admin"}, {Slug: "member", Name: "Member"}},
+			})
+		default:
+			t.Errorf("unexpected request %s %s", r.Method, r.URL.Path)
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+	defer server.Close()
+
+	// Create auth manager with mock store
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	ctx := context.Background()
+
+	members, err := client.ListOrgMembers(ctx, "org_123")
+	require.NoError(t, err)
+	require.Len(t, members.Members, 1)
+	assert.Equal(t, "user_1", members.Members[0].UserId)
+	assert.Equal(t, OrgMemberStatusActive, members.Members[0].Status)
+
+	role := "member"
+	invitation, err := client.InviteOrgMember(ctx, "org_123", InviteOrgMemberRequest{
+		Email: openapi_types.Email("bob@example.com"),
+		Role:  &role,
+	})
+	require.NoError(t, err)
+	assert.Equal(t, "invitation_1", invitation.InvitationId)
+	assert.Equal(t, "member", invitation.Role)
+
+	assert.NoError(t, client.RemoveOrgMember(ctx, "org_123", "user_1"))
+
+	roles, err := client.ListOrgRoles(ctx, "org_123")
+	require.NoError(t, err)
+	assert.Len(t, roles.Roles, 2)
+}
+
+func TestFTLClient_UserSessions(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch {
+		case r.Method == "GET" && r.URL.Path == "/v1/user/sessions":
+			device := "ftl-cli on darwin"
+			_ = json.NewEncoder(w).Encode(ListUserSessionsResponseBody{
+				Sessions: []UserSession{
+					{Id: "session_1", Device: &device, CreatedAt: time.Date(2024, 1, 1, 0, 0, 0, 0, time.UTC)},
+					{Id: "session_2", CreatedAt: time.Date(2024, 2, 1, 0, 0, 0, 0, time.UTC)},
+				},
+			})
+		case r.Method == "DELETE" && r.URL.Path == "/v1/user/sessions/session_2":
+			_ = json.NewEncoder(w).Encode(RevokeUserSessionResponseBody{Message: "revoked"})
+		case r.Method == "DELETE":
+			w.WriteHeader(http.StatusNotFound)
+			_ = json.NewEncoder(w).Encode(map[string]string{"error": "session not found"})
+		default:
+			t.Errorf("unexpected request %s %s", r.Method, r.URL.Path)
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+	defer server.Close()
+
+	mockStore := &mockCredentialStore{
+		creds: &auth.Credentials{
+			AccessToken: "test-token",
+			ExpiresAt:   timePtr(time.Now().Add(time.Hour)),
+		},
+	}
+	authManager := auth.NewManager(mockStore, nil)
+	client, err := NewFTLClient(authManager, server.URL)
+	require.NoError(t, err)
+
+	ctx := context.Background()
+
+	sessions, err := client.ListUserSessions(ctx)
+	require.NoError(t, err)
+	require.Len(t, sessions.Sessions, 2)
+	assert.Equal(t, "session_1", sessions.Sessions[0].Id)
+	require.NotNil(t, sessions.Sessions[0].Device)
+	assert.Nil(t, sessions.Sessions[1].Device)
+
+	assert.NoError(t, client.RevokeUserSession(ctx, "session_2"))
+	err = client.RevokeUserSession(ctx, "session_unknown")
+	assert.ErrorIs(t, err, ErrNotFound)
+	assert.ErrorContains(t, err, "session not found")
 }
 
 func TestAuthHTTPClient(t *testing.T) {
@@ -0,0 +1,102 @@
+package api
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"strings"
+)
+
+// ErrorKind classifies the errors returned by the FTL API
+type ErrorKind string
+
+const (
+	// ErrorKindAuth is a missing, expired or insufficient credential (401, 403)
+	ErrorKindAuth ErrorKind = "unauthorized"
+	// ErrorKindQuota is a rate limit or plan limit (402, 429)
+	ErrorKindQuota ErrorKind = "quota exceeded"
+	// ErrorKindValidation is a request the API rejected as invalid (400, 409, 422)
+	ErrorKindValidation ErrorKind = "invalid request"
+	// ErrorKindNotFound is a resource that doesn't exist (404)
+	ErrorKindNotFound ErrorKind = "not found"
+	// ErrorKindOther is any other failure, such as a server error
+	ErrorKindOther ErrorKind = "error"
+)
+
+// Sentinel errors matched by errors.Is against an *APIError of the same kind
+var (
+	ErrUnauthorized  = errors.New("unauthorized")
+	ErrQuotaExceeded = errors.New("quota exceeded")
+	ErrInvalid       = errors.New("invalid request")
+	ErrNotFound      = errors.New("not found")
+)
+
+// APIError is a non-success response of the FTL API. Message is the message
+// of the ErrorResponse payload, or the raw body when there is none.
+type APIError struct {
+	StatusCode int
+	Kind       ErrorKind
+	Message    string
+	Details    []interface{}
+}
+
+// NewAPIError builds the error of a response with an unexpected status code
+func NewAPIError(statusCode int, body []byte) *APIError {
+	apiErr := &APIError{
+		StatusCode: statusCode,
+		Kind:       errorKind(statusCode),
+	}
+
+	var payload ErrorResponse
+	if err := json.Unmarshal(body, &payload); err == nil && (payload.Message != "" || payload.Error != nil) {
+		apiErr.Message = payload.Message
+		if apiErr.Message == "" {
+			apiErr.Message = *payload.Error
+		}
+		if payload.Details != nil {
+			apiErr.Details = *payload.Details
+		}
+	} else {
+		apiErr.Message = strings.TrimSpace(string(body))
+	}
+	if apiErr.Message == "" {
+		apiErr.Message = http.StatusText(statusCode)
+	}
+	return apiErr
+}
+
+// Error implements the error interface
+func (e *APIError) Error() string {
+	return fmt.Sprintf("API error: %s (%d): %s", e.Kind, e.StatusCode, e.Message)
+}
+
+// Is reports whether target is the sentinel error of the kind of e
+func (e *APIError) Is(target error) bool {
+	switch target {
+	case ErrUnauthorized:
+		return e.Kind == ErrorKindAuth
+	case ErrQuotaExceeded:
+		return e.Kind == ErrorKindQuota
+	case ErrInvalid:
+		return e.Kind == ErrorKindValidation
+	case ErrNotFound:
+		return e.Kind == ErrorKindNotFound
+	}
+	return false
+}
+
+// errorKind classifies a status code
+func errorKind(statusCode int) ErrorKind {
+	switch statusCode {
+	case http.StatusUnauthorized, http.StatusForbidden:
+		return ErrorKindAuth
+	case http.StatusPaymentRequired, http.StatusTooManyRequests:
+		return ErrorKindQuota
+	case http.StatusBadRequest, http.StatusConflict, http.StatusUnprocessableEntity:
+		return ErrorKindValidation
+	case http.StatusNotFound:
+		return ErrorKindNotFound
+	}
+	return ErrorKindOther
+}
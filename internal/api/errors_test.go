@@ -0,0 +1,91 @@
+package api
+
+import (
+	"errors"
+	"net/http"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestNewAPIError(t *testing.T) {
+	tests := []struct {
+		name        string
+		statusCode  int
+		body        string
+		wantKind    ErrorKind
+		wantMessage string
+		wantIs      error
+	}{
+		{
+			name:        "unauthorized",
+			statusCode:  http.StatusUnauthorized,
+			body:        `{"error":"Unauthorized","message":"Token expired"}`,
+			wantKind:    ErrorKindAuth,
+			wantMessage: "Token expired",
+			wantIs:      ErrUnauthorized,
+		},
+		{
+			name:        "forbidden",
+			statusCode:  http.StatusForbidden,
+			body:        `{"message":"Not a member of the organization"}`,
+			wantKind:    ErrorKindAuth,
+			wantMessage: "Not a member of the organization",
+			wantIs:      ErrUnauthorized,
+		},
+		{
+			name:        "rate limited",
+			statusCode:  http.StatusTooManyRequests,
+			body:        `{"message":"Too many requests"}`,
+			wantKind:    ErrorKindQuota,
+			wantMessage: "Too many requests",
+			wantIs:      ErrQuotaExceeded,
+		},
+		{
+			name:        "validation",
+			statusCode:  http.StatusBadRequest,
+			body:        `{"message":"Invalid app name","details":[{"field":"appName"}]}`,
+			wantKind:    ErrorKindValidation,
+			wantMessage: "Invalid app name",
+			wantIs:      ErrInvalid,
+		},
+		{
+			name:        "not found with error only",
+			statusCode:  http.StatusNotFound,
+			body:        `{"error":"app not found"}`,
+			wantKind:    ErrorKindNotFound,
+			wantMessage: "app not found",
+			wantIs:      ErrNotFound,
+		},
+		{
+			name:        "plain text body",
+			statusCode:  http.StatusBadGateway,
+			body:        "upstream unavailable\n",
+			wantKind:    ErrorKindOther,
+			wantMessage: "upstream unavailable",
+		},
+		{
+			name:        "empty body",
+			statusCode:  http.StatusInternalServerError,
+			wantKind:    ErrorKindOther,
+			wantMessage: "Internal Server Error",
+		},
+	}
+
+	sentinels := []error{ErrUnauthorized, ErrQuotaExceeded, ErrInvalid, ErrNotFound}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := NewAPIError(tt.statusCode, []byte(tt.body))
+			assert.Equal(t, tt.wantKind, err.Kind)
+			assert.Equal(t, tt.wantMessage, err.Message)
+			for _, sentinel := range sentinels {
+				assert.Equal(t, sentinel == tt.wantIs, errors.Is(err, sentinel), sentinel.Error())
+			}
+		})
+	}
+}
+
+func TestAPIError_Error(t *testing.T) {
+	err := NewAPIError(http.StatusUnauthorized, []byte(`{"message":"Token expired"}`))
+	assert.Equal(t, "API error: unauthorized (401): Token expired", err.Error())
+}
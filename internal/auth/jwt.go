@@ -22,6 +22,7 @@ type JWTClaims struct {
 	Organizations  []string `json:"org_ids"`
 	ActorType      string   `json:"actor_type"`
 	UserID         string   `json:"user_id"`
+	SessionID      string   `json:"sid"`
 
 	// Additional user info
 	EmailVerified bool   `json:"email_verified"`
@@ -93,6 +94,11 @@ func ExtractUserInfo(tokenString string) (*JWTClaims, error) {
 		}
 	}
 
+	// Extract the login session the token was issued for
+	if sid, ok := (*claims)["sid"].(string); ok {
+		jwtClaims.SessionID = sid
+	}
+
 	// Extract actor type (user or machine)
 	if actorType, ok := (*claims)["actor_type"].(string); ok {
 		jwtClaims.ActorType = actorType
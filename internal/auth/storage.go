@@ -36,6 +36,16 @@ func NewKeyringStore() (*KeyringStore, error) {
 	return &KeyringStore{}, nil
 }
 
+// KeyringAvailable reports whether the OS keyring can be reached, by
+// reading an entry that normally does not exist
+func KeyringAvailable() error {
+	_, err := keyring.Get(KeyringService, "availability-probe")
+	if err == nil || err == keyring.ErrNotFound {
+		return nil
+	}
+	return err
+}
+
 // Load retrieves stored credentials from the keyring
 func (s *KeyringStore) Load() (*Credentials, error) {
 	data, err := keyring.Get(KeyringService, KeyringUsername)
@@ -14,6 +14,14 @@ import (
 type AddOptions struct {
 	Name     string
 	Language string
+
+	// FromOpenAPI is an OpenAPI spec to generate the component's tools from
+	FromOpenAPI string
+	// Operations are the IDs of the spec operations to generate tools for,
+	// all operations when empty
+	Operations []string
+	// ServerURL overrides the spec's server URL
+	ServerURL string
 }
 
 // newAddCmd creates the add command
@@ -42,7 +50,17 @@ Examples:
   ftl add my-tool
 
   # With name and language
-  ftl add my-tool --language rust`,
+  ftl add my-tool --language rust
+
+  # Generate a Rust tool per operation of an OpenAPI spec
+  ftl add petstore --from-openapi spec.yaml --operations getPetById,findPetsByStatus
+
+With --from-openapi, each selected operation (all operations when
+--operations is omitted) becomes a tool of a Rust component. Its input is
+generated from the operation's parameters and JSON request body, the tool
+calls the operation with the SDK's HTTP client, and the API's host is added
+to the component's allowed_outbound_hosts. The first server URL of the spec
+is called unless --server-url is set.`,
 		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
 			if len(args) > 0 {
@@ -53,6 +71,9 @@ Examples:
 	}
 
 	cmd.Flags().StringVarP(&opts.Language, "language", "l", "", "programming language (rust, typescript, python, go)")
+	cmd.Flags().StringVar(&opts.FromOpenAPI, "from-openapi", "", "generate tools from an OpenAPI 3 spec (YAML or JSON)")
+	cmd.Flags().StringSliceVar(&opts.Operations, "operations", nil, "operation IDs to generate tools for with --from-openapi (default all)")
+	cmd.Flags().StringVar(&opts.ServerURL, "server-url", "", "base URL of the API, instead of the spec's first server")
 
 	return cmd
 }
@@ -80,6 +101,13 @@ func runAdd(opts *AddOptions) error {
 		return err
 	}
 
+	if opts.FromOpenAPI != "" {
+		return runAddFromOpenAPI(scaffolder, opts)
+	}
+	if len(opts.Operations) > 0 || opts.ServerURL != "" {
+		return fmt.Errorf("--operations and --server-url require --from-openapi")
+	}
+
 	// Get language if not provided
 	if opts.Language == "" {
 		languageOptions := []string{
@@ -130,6 +158,37 @@ func runAdd(opts *AddOptions) error {
 	return nil
 }
 
+// runAddFromOpenAPI generates a Rust component with a tool per selected
+// operation of an OpenAPI spec
+func runAddFromOpenAPI(scaffolder *scaffold.Scaffolder, opts *AddOptions) error {
+	if opts.Language != "" && opts.Language != "rust" {
+		return fmt.Errorf("--from-openapi generates Rust components, --language %s is not supported", opts.Language)
+	}
+
+	spec, err := scaffold.LoadOpenAPISpec(opts.FromOpenAPI)
+	if err != nil {
+		return err
+	}
+	operations, err := spec.SelectOperations(opts.Operations)
+	if err != nil {
+		return err
+	}
+
+	Info("Creating rust component '%s' with %d tools from %s", opts.Name, len(operations), opts.FromOpenAPI)
+
+	if err := scaffolder.GenerateOpenAPIComponent(opts.Name, spec, operations, opts.ServerURL); err != nil {
+		return fmt.Errorf("failed to generate component: %w", err)
+	}
+
+	for _, op := range operations {
+		fmt.Printf("  • %s  %s %s\n", op.ID, strings.ToUpper(op.Method), op.Path)
+	}
+	fmt.Println()
+	printSuccessMessage(opts.Name, "rust")
+
+	return nil
+}
+
 func printSuccessMessage(name, language string) {
 	// Determine main file based on language
 	var mainFile string
@@ -149,6 +208,9 @@ func printSuccessMessage(name, language string) {
 	fmt.Println("📁 Component structure:")
 	fmt.Printf("  %s/\n", name)
 	fmt.Printf("  ├── %s\n", getMainFileName(language))
+	if language == "typescript" {
+		fmt.Printf("  ├── src/schemas.ts\n")
+	}
 	fmt.Printf("  ├── Makefile\n")
 	fmt.Printf("  └── %s\n", getConfigFileName(language))
 	fmt.Println()
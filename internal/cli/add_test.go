@@ -129,6 +129,24 @@ func TestRunAdd_ValidationErrors(t *testing.T) {
 				_ = os.WriteFile("ftl.yaml", []byte("application:\n  name: test\n"), 0600)
 			},
 		},
+		{
+			name: "openapi_flags_without_spec",
+			opts: &AddOptions{
+				Name:       "valid-name",
+				Language:   "rust",
+				Operations: []string{"getPetById"},
+			},
+			wantErr: "--operations and --server-url require --from-openapi",
+		},
+		{
+			name: "openapi_unsupported_language",
+			opts: &AddOptions{
+				Name:        "valid-name",
+				Language:    "python",
+				FromOpenAPI: "spec.yaml",
+			},
+			wantErr: "--from-openapi generates Rust components",
+		},
 	}
 
 	for _, tt := range tests {
@@ -215,6 +233,9 @@ func TestNewAddCmd(t *testing.T) {
 	languageFlag := cmd.Flags().Lookup("language")
 	assert.NotNil(t, languageFlag)
 	assert.Equal(t, "l", languageFlag.Shorthand)
+	assert.NotNil(t, cmd.Flags().Lookup("from-openapi"))
+	assert.NotNil(t, cmd.Flags().Lookup("operations"))
+	assert.NotNil(t, cmd.Flags().Lookup("server-url"))
 
 	// Test args handling
 	cmd.SetArgs([]string{"test-component"})
@@ -0,0 +1,115 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/migrate"
+)
+
+// AdoptOptions holds options for the adopt command
+type AdoptOptions struct {
+	File   string
+	DryRun bool
+	Yes    bool
+}
+
+func newAdoptCmd() *cobra.Command {
+	opts := &AdoptOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "adopt [spin.toml]",
+		Short: "Convert an existing spin.toml into ftl.yaml",
+		Long: `Convert the manifest of an existing Spin application into an FTL configuration.
+
+The components run by HTTP triggers are adopted with their build, variables,
+allowed_outbound_hosts, files and key_value_stores, along with the
+application variables, and written to ftl.yaml next to the manifest.
+Anything FTL can't represent, such as public HTTP routes, other triggers or
+component environment variables, is listed and left out.
+
+'ftl build' and 'ftl deploy' regenerate spin.toml from ftl.yaml, so keep a
+copy of the original manifest until the adopted configuration builds.
+
+Examples:
+  # Preview the configuration adopted from ./spin.toml
+  ftl adopt --dry-run
+
+  # Adopt a manifest in another directory, replacing its ftl.yaml
+  ftl adopt ../legacy/spin.toml --yes`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if len(args) > 0 {
+				opts.File = args[0]
+			}
+			return runAdopt(opts)
+		},
+	}
+
+	cmd.Flags().BoolVar(&opts.DryRun, "dry-run", false, "Show the configuration without writing it")
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Replace an existing ftl.yaml without confirming")
+
+	return cmd
+}
+
+func runAdopt(opts *AdoptOptions) error {
+	path := opts.File
+	if path == "" {
+		path = "spin.toml"
+	}
+	path = filepath.Clean(path)
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	adoption, err := migrate.FromSpin(data)
+	if err != nil {
+		return fmt.Errorf("failed to adopt %s: %w", path, err)
+	}
+	target := filepath.Join(filepath.Dir(path), "ftl.yaml")
+
+	if len(adoption.Manifest.Components) == 0 {
+		Warn("No components of %s could be adopted", path)
+	} else {
+		Info("Adopting %d component(s) from %s", len(adoption.Manifest.Components), path)
+		for _, component := range adoption.Manifest.Components {
+			fmt.Printf("  • %s\n", component.ID)
+		}
+	}
+
+	if len(adoption.Notes) > 0 {
+		fmt.Println()
+		Warn("Left out, needs manual attention:")
+		for _, note := range adoption.Notes {
+			fmt.Printf("  • %s\n", note)
+		}
+	}
+
+	if opts.DryRun {
+		fmt.Println()
+		fmt.Print(string(adoption.Data))
+		fmt.Println()
+		Info("Dry run, %s was not written", target)
+		return nil
+	}
+
+	if _, err := os.Stat(target); err == nil {
+		fmt.Println()
+		confirmed, err := confirmChange(opts.Yes, fmt.Sprintf("Replace the existing %s?", target))
+		if err != nil || !confirmed {
+			return err
+		}
+	}
+
+	if err := os.WriteFile(target, adoption.Data, 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", target, err)
+	}
+	fmt.Println()
+	Success("Wrote %s", target)
+	fmt.Println("Run 'ftl build' to check it, which regenerates spin.toml from it")
+	return nil
+}
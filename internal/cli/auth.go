@@ -24,6 +24,8 @@ func newAuthCmd() *cobra.Command {
 		newAuthLoginCmd(),
 		newAuthLogoutCmd(),
 		newAuthStatusCmd(),
+		newAuthSessionsCmd(),
+		newAuthRevokeCmd(),
 	)
 
 	return cmd
@@ -240,13 +242,7 @@ func newAuthLogoutCmd() *cobra.Command {
 			}
 
 			// Clear user info and org list from config
-			if cfg, err := config.Load(); err == nil {
-				_ = cfg.ClearCurrentUser()
-				_ = cfg.SetCurrentOrg("")
-				// Clear all cached org info
-				cfg.Organizations = make(map[string]config.OrgInfo)
-				_ = cfg.Save()
-			}
+			clearCachedUser()
 
 			color.Green("✅ Successfully logged out")
 			return nil
@@ -0,0 +1,207 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"sort"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// sessionClient is the subset of the FTL API used to manage login sessions
+type sessionClient interface {
+	ListUserSessions(ctx context.Context) (*api.ListUserSessionsResponseBody, error)
+	RevokeUserSession(ctx context.Context, sessionID string) error
+}
+
+// Allow overriding for tests
+var newSessionClient = newSessionClientImpl
+
+func newSessionClientImpl(ctx context.Context, store auth.CredentialStore) (sessionClient, error) {
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// sessionInfo is a login session as listed by 'ftl auth sessions'
+type sessionInfo struct {
+	api.UserSession
+	// Current is set for the session of the stored credentials
+	Current bool `json:"current"`
+}
+
+// newAuthSessionsCmd creates the 'auth sessions' command
+func newAuthSessionsCmd() *cobra.Command {
+	var format string
+
+	cmd := &cobra.Command{
+		Use:   "sessions",
+		Short: "List active login sessions",
+		Long: `List the active login sessions of your account.
+
+Each device you logged in from holds a refresh token tied to a session. The
+session of this machine is marked as current. Use 'ftl auth revoke' to end a
+session, e.g. on a lost or retired device.`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			store, err := auth.NewKeyringStore()
+			if err != nil {
+				return fmt.Errorf("failed to initialize credential store: %w", err)
+			}
+			client, err := newSessionClient(cmd.Context(), store)
+			if err != nil {
+				return err
+			}
+			return runAuthSessions(cmd.Context(), client, store, cmd.OutOrStdout(), format)
+		},
+	}
+
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+// newAuthRevokeCmd creates the 'auth revoke' command
+func newAuthRevokeCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:   "revoke SESSION_ID",
+		Short: "Revoke a login session",
+		Long: `Revoke a login session and its refresh token.
+
+The device holding the session has to log in again once its access token
+expires. Revoking the current session also removes the credentials stored on
+this machine, like 'ftl auth logout'.
+
+Example:
+  ftl auth sessions
+  ftl auth revoke session_01HXYZ`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			store, err := auth.NewKeyringStore()
+			if err != nil {
+				return fmt.Errorf("failed to initialize credential store: %w", err)
+			}
+			client, err := newSessionClient(cmd.Context(), store)
+			if err != nil {
+				return err
+			}
+
+			loggedOut, err := runAuthRevoke(cmd.Context(), client, store, args[0])
+			if err != nil {
+				return err
+			}
+			if loggedOut {
+				clearCachedUser()
+			}
+			return nil
+		},
+	}
+}
+
+func runAuthSessions(ctx context.Context, client sessionClient, store auth.CredentialStore, w io.Writer, format string) error {
+	resp, err := client.ListUserSessions(ctx)
+	if err != nil {
+		return fmt.Errorf("failed to list sessions: %w", err)
+	}
+
+	current := currentSessionID(store)
+	sessions := make([]sessionInfo, 0, len(resp.Sessions))
+	for _, s := range resp.Sessions {
+		sessions = append(sessions, sessionInfo{UserSession: s, Current: current != "" && s.Id == current})
+	}
+	// Most recently started first
+	sort.SliceStable(sessions, func(i, j int) bool {
+		return sessions[i].CreatedAt.After(sessions[j].CreatedAt)
+	})
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(sessions)
+	}
+
+	if len(sessions) == 0 {
+		Info("No active sessions")
+		return nil
+	}
+
+	tb := NewTableBuilder("SESSION ID", "DEVICE", "IP ADDRESS", "CREATED", "LAST USED", "CURRENT")
+	for _, s := range sessions {
+		lastUsed := "-"
+		if s.LastUsedAt != nil {
+			lastUsed = s.LastUsedAt.Format("2006-01-02 15:04")
+		}
+		currentMark := ""
+		if s.Current {
+			currentMark = "*"
+		}
+		tb.AddRow(
+			s.Id,
+			stringOr(s.Device, "-"),
+			stringOr(s.IpAddress, "-"),
+			s.CreatedAt.Format("2006-01-02 15:04"),
+			lastUsed,
+			currentMark,
+		)
+	}
+	return tb.Write(dw)
+}
+
+// runAuthRevoke revokes a session. When it is the session of the stored
+// credentials, they are deleted and loggedOut is true.
+func runAuthRevoke(ctx context.Context, client sessionClient, store auth.CredentialStore, sessionID string) (loggedOut bool, err error) {
+	current := currentSessionID(store)
+
+	if err := client.RevokeUserSession(ctx, sessionID); err != nil {
+		return false, fmt.Errorf("failed to revoke session %s: %w", sessionID, err)
+	}
+	Success("Revoked session %s", sessionID)
+
+	if sessionID != current {
+		return false, nil
+	}
+
+	// The stored refresh token can no longer be used
+	if err := store.Delete(); err != nil {
+		return false, fmt.Errorf("failed to remove local credentials: %w", err)
+	}
+	Info("This was the current session; removed the local credentials. Run 'ftl auth login' to log in again")
+	return true, nil
+}
+
+// currentSessionID returns the session of the stored credentials, or an empty
+// string when it can't be determined (e.g. machine tokens)
+func currentSessionID(store auth.CredentialStore) string {
+	creds, err := store.Load()
+	if err != nil || creds == nil || creds.AccessToken == "" {
+		return ""
+	}
+	claims, err := auth.ExtractUserInfo(creds.AccessToken)
+	if err != nil {
+		return ""
+	}
+	return claims.SessionID
+}
+
+// clearCachedUser removes the user info and organizations cached in the
+// config after logging out
+func clearCachedUser() {
+	if cfg, err := config.Load(); err == nil {
+		_ = cfg.ClearCurrentUser()
+		_ = cfg.SetCurrentOrg("")
+		// Clear all cached org info
+		cfg.Organizations = make(map[string]config.OrgInfo)
+		_ = cfg.Save()
+	}
+}
@@ -0,0 +1,111 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/golang-jwt/jwt/v5"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+)
+
+type fakeSessionClient struct {
+	sessions []api.UserSession
+	revoked  []string
+}
+
+func (f *fakeSessionClient) ListUserSessions(_ context.Context) (*api.ListUserSessionsResponseBody, error) {
+	return &api.ListUserSessionsResponseBody{Sessions: f.sessions}, nil
+}
+
+func (f *fakeSessionClient) RevokeUserSession(_ context.Context, sessionID string) error {
+	for _, s := range f.sessions {
+		if s.Id == sessionID {
+			f.revoked = append(f.revoked, sessionID)
+			return nil
+		}
+	}
+	return assert.AnError
+}
+
+func newFakeSessionClient() *fakeSessionClient {
+	laptop := "ftl-cli on darwin"
+	return &fakeSessionClient{
+		sessions: []api.UserSession{
+			{Id: "session_old", CreatedAt: time.Date(2026, 1, 5, 9, 0, 0, 0, time.UTC)},
+			{Id: "session_laptop", Device: &laptop, CreatedAt: time.Date(2026, 10, 1, 9, 0, 0, 0, time.UTC)},
+		},
+	}
+}
+
+// storeWithSession returns a credential store holding an access token issued
+// for the given session
+func storeWithSession(t *testing.T, sessionID string) *auth.MockStore {
+	token, err := jwt.NewWithClaims(jwt.SigningMethodHS256, jwt.MapClaims{
+		"sub": "user_1",
+		"sid": sessionID,
+	}).SignedString([]byte("test-secret"))
+	require.NoError(t, err)
+	return auth.NewMockStore(&auth.Credentials{AccessToken: token, RefreshToken: "refresh"}, nil)
+}
+
+func TestRunAuthSessions_Table(t *testing.T) {
+	var buf bytes.Buffer
+	store := storeWithSession(t, "session_laptop")
+	require.NoError(t, runAuthSessions(context.Background(), newFakeSessionClient(), store, &buf, "table"))
+
+	out := buf.String()
+	assert.Contains(t, out, "ftl-cli on darwin")
+	assert.Contains(t, out, "2026-10-01 09:00")
+	// Most recent session first
+	assert.Less(t, bytes.Index(buf.Bytes(), []byte("session_laptop")), bytes.Index(buf.Bytes(), []byte("session_old")))
+}
+
+func TestRunAuthSessions_JSONMarksCurrent(t *testing.T) {
+	var buf bytes.Buffer
+	store := storeWithSession(t, "session_old")
+	require.NoError(t, runAuthSessions(context.Background(), newFakeSessionClient(), store, &buf, "json"))
+
+	var sessions []map[string]interface{}
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &sessions))
+	require.Len(t, sessions, 2)
+	assert.Equal(t, "session_laptop", sessions[0]["id"])
+	assert.Equal(t, false, sessions[0]["current"])
+	assert.Equal(t, "session_old", sessions[1]["id"])
+	assert.Equal(t, true, sessions[1]["current"])
+}
+
+func TestRunAuthRevoke_OtherSessionKeepsCredentials(t *testing.T) {
+	client := newFakeSessionClient()
+	store := storeWithSession(t, "session_laptop")
+
+	loggedOut, err := runAuthRevoke(context.Background(), client, store, "session_old")
+	require.NoError(t, err)
+	assert.False(t, loggedOut)
+	assert.Equal(t, []string{"session_old"}, client.revoked)
+	assert.True(t, store.Exists())
+}
+
+func TestRunAuthRevoke_CurrentSessionRemovesCredentials(t *testing.T) {
+	client := newFakeSessionClient()
+	store := storeWithSession(t, "session_laptop")
+
+	loggedOut, err := runAuthRevoke(context.Background(), client, store, "session_laptop")
+	require.NoError(t, err)
+	assert.True(t, loggedOut)
+	assert.False(t, store.Exists())
+}
+
+func TestRunAuthRevoke_UnknownSession(t *testing.T) {
+	store := storeWithSession(t, "session_laptop")
+
+	_, err := runAuthRevoke(context.Background(), newFakeSessionClient(), store, "session_unknown")
+	assert.ErrorContains(t, err, "failed to revoke session session_unknown")
+	assert.True(t, store.Exists())
+}
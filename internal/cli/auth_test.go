@@ -16,7 +16,7 @@ func TestAuthCommand(t *testing.T) {
 	assert.Contains(t, cmd.Short, "Manage authentication")
 
 	// Test subcommands exist
-	expectedSubcommands := []string{"login", "logout", "status"}
+	expectedSubcommands := []string{"login", "logout", "status", "sessions", "revoke"}
 	for _, subcmd := range expectedSubcommands {
 		t.Run("has_"+subcmd, func(t *testing.T) {
 			found := false
@@ -2,96 +2,138 @@ package cli
 
 import (
 	"context"
-	"fmt"
+	"errors"
 	"os"
 
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
 	"github.com/fastertools/ftl/spin"
-	"github.com/fastertools/ftl/synthesis"
-	"github.com/fatih/color"
 	"github.com/spf13/cobra"
 )
 
 func newBuildCmd() *cobra.Command {
 	var skipSynth bool
 	var configFile string
+	var profileName string
+	var strict bool
+	var reproducible bool
+	var sizeReport bool
+	var exportFormat string
+	var exportDir string
 
 	cmd := &cobra.Command{
 		Use:   "build",
 		Short: "Build the FTL application",
-		Long:  `Build compiles the FTL application and its components.`,
+		Long: `Build compiles the FTL application and its components.
+
+Use --profile to build with a named profile. The built-in "size" and "speed"
+profiles set Cargo's release opt-level, LTO and codegen units and run a
+wasm-opt pass (-Oz or -O3) over each built component when wasm-opt is
+installed. Declare your own under profiles: in ftl.yaml:
+
+  profiles:
+    size:
+      optimize: size
+      env:
+        RUSTFLAGS: "-C target-feature=+bulk-memory"
+      wasm_opt: ["-Oz", "--strip-debug"]
+
+Use --reproducible to verify that deployed Wasm matches its source. Local paths
+are remapped out of the output and timestamps are fixed to SOURCE_DATE_EPOCH
+(defaulting to the commit time of HEAD). The sha256 of each built component is
+printed and recorded in .ftl/build-manifest.json.
+
+Components may declare a size_budget for their built Wasm. A build exceeding
+it fails, or only warns with on_exceed: warn:
+
+  components:
+    - id: weather
+      size_budget:
+        max: 2MB
+        on_exceed: warn
+
+Use --size-report to print the size of each built component, its change since
+the previous build and its largest sections.
+
+Use --export terraform (or opentofu) to also write a Terraform module to
+--export-dir that deploys the application to FTL Engine as 'ftl deploy' would.
+Local components are referenced where 'ftl component publish' pushes them, so
+deployments can be reviewed through a standard IaC pipeline:
+
+  ftl component publish --all-components --namespace my-org
+  tofu apply -var namespace=my-org`,
+		Example: `  ftl build
+  ftl build --profile size
+  ftl build --reproducible
+  ftl build --size-report
+  ftl build --export terraform --export-dir infra/ftl`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			ctx := context.Background()
 
-			// Color helpers
-			blue := color.New(color.FgBlue).SprintFunc()
-			green := color.New(color.FgGreen).SprintFunc()
-			yellow := color.New(color.FgYellow).SprintFunc()
-
 			// Ensure spin is installed
 			if err := spin.EnsureInstalled(); err != nil {
 				return err
 			}
-
-			// Auto-detect config file if not specified
-			if configFile == "" {
-				// Try to detect the config format
-				if _, err := os.Stat("ftl.yaml"); err == nil {
-					configFile = "ftl.yaml"
-				} else if _, err := os.Stat("ftl.json"); err == nil {
-					configFile = "ftl.json"
-				} else if _, err := os.Stat("app.cue"); err == nil {
-					configFile = "app.cue"
-				} else if _, err := os.Stat("main.go"); err == nil {
-					configFile = "main.go"
-				}
+			warnToolchainMismatches(ctx)
+
+			opts := commands.BuildOptions{
+				ConfigFile:   configFile,
+				SkipSynth:    skipSynth,
+				Profile:      profileName,
+				Strict:       strict,
+				Reproducible: reproducible,
+				ExportFormat: exportFormat,
+				ExportDir:    exportDir,
+				Stdout:       os.Stdout,
+				Stderr:       os.Stderr,
+				Progress:     printProgress,
 			}
-
-			// Check if config file exists
-			if configFile != "" && !skipSynth {
-				if _, err := os.Stat(configFile); err == nil {
-					fmt.Printf("%s Synthesizing spin.toml from %s\n", blue("→"), configFile)
-
-					// Use unified synthesis helper
-					manifest, err := synthesis.SynthesizeFromConfig(configFile)
-					if err != nil {
-						return fmt.Errorf("synthesis failed: %w", err)
-					}
-
-					// Write spin.toml
-					if err := os.WriteFile("spin.toml", []byte(manifest), 0600); err != nil {
-						return fmt.Errorf("failed to write spin.toml: %w", err)
-					}
-
-					fmt.Printf("%s Generated spin.toml\n", green("✓"))
-				}
-			} else if configFile == "" && !skipSynth {
-				// No config file found, check for spin.toml
-				if _, err := os.Stat("spin.toml"); os.IsNotExist(err) {
-					return fmt.Errorf("no ftl.yaml, ftl.json, app.cue, or spin.toml found. Run 'ftl init' first")
-				}
-				fmt.Printf("%s No FTL config found, using existing spin.toml\n", yellow("ℹ"))
-			} else if skipSynth {
-				// When skipping synthesis, just check if spin.toml exists
-				if _, err := os.Stat("spin.toml"); os.IsNotExist(err) {
-					return fmt.Errorf("no spin.toml found. Run 'ftl synth' or 'ftl build' without --skip-synth first")
-				}
-				fmt.Printf("%s Using existing spin.toml\n", yellow("ℹ"))
+			if offlineMode() {
+				opts.Mirror = oci.NewMirror(oci.DefaultMirrorDir())
+				Info("Offline: resolving registry components from %s", opts.Mirror.Dir())
 			}
 
-			fmt.Printf("%s Building FTL application...\n", blue("→"))
-
-			// Use spin build
-			if err := spin.Build(ctx); err != nil {
-				return fmt.Errorf("failed to build: %w", err)
+			result, err := commands.Build(ctx, opts, commands.Deps{})
+			if !sizeReport {
+				return err
 			}
 
-			fmt.Printf("%s Build completed successfully\n", green("✓"))
-			return nil
+			// Components over their budget are reported too
+			var budgetErr *commands.SizeBudgetError
+			switch {
+			case errors.As(err, &budgetErr):
+				result = &commands.BuildResult{Sizes: budgetErr.Sizes}
+			case err != nil:
+				return err
+			}
+			if reportErr := writeSizeReport(cmd.OutOrStdout(), result.Sizes); reportErr != nil {
+				return reportErr
+			}
+			return err
 		},
 	}
 
 	cmd.Flags().BoolVar(&skipSynth, "skip-synth", false, "Skip synthesis of spin.toml from FTL config")
 	cmd.Flags().StringVarP(&configFile, "config", "c", "", "Configuration file to synthesize (auto-detects if not specified)")
+	cmd.Flags().StringVar(&profileName, "profile", "", "Build profile to use (built-in: size, speed)")
+	cmd.Flags().BoolVar(&strict, "strict", false, "Fail when a configured URL is not covered by allowed_outbound_hosts")
+	cmd.Flags().BoolVar(&reproducible, "reproducible", false, "Build deterministically and record the sha256 of each component in .ftl/build-manifest.json")
+	cmd.Flags().BoolVar(&sizeReport, "size-report", false, "Print the size of each component, its change since the previous build and its largest sections")
+	cmd.Flags().StringVar(&exportFormat, "export", "", "Also export a deployment module (terraform, opentofu)")
+	cmd.Flags().StringVar(&exportDir, "export-dir", "terraform", "Directory to write the exported module to")
 
 	return cmd
 }
+
+// printProgress prints the progress of a library command as the CLI prints
+// its own
+func printProgress(e commands.Event) {
+	switch e.Level {
+	case commands.LevelSuccess:
+		Success("%s", e.Message)
+	case commands.LevelWarn:
+		Warn("%s", e.Message)
+	default:
+		Info("%s", e.Message)
+	}
+}
@@ -0,0 +1,78 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+)
+
+// sizeReportSections is the number of largest sections listed per component
+const sizeReportSections = 3
+
+// formatSizeDelta renders the change of a component's size since its
+// previous build
+func formatSizeDelta(size, previous int64, known bool) string {
+	switch {
+	case !known:
+		return "new"
+	case size == previous:
+		return "unchanged"
+	}
+	delta := size - previous
+	sign, magnitude := "+", delta
+	if delta < 0 {
+		sign, magnitude = "-", -delta
+	}
+	if previous == 0 {
+		return sign + commands.FormatSize(magnitude)
+	}
+	return fmt.Sprintf("%s%s (%+.1f%%)", sign, commands.FormatSize(magnitude), float64(delta)*100/float64(previous))
+}
+
+// writeSizeReport writes the size of each built component, its change since
+// the previous build, its budget and its largest sections to w
+func writeSizeReport(w io.Writer, sizes []commands.ComponentSize) error {
+	table := NewTableBuilder("COMPONENT", "SIZE", "DELTA", "BUDGET")
+	for _, size := range sizes {
+		budget := "-"
+		if size.Budget > 0 {
+			budget = commands.FormatSize(size.Budget)
+		}
+		table.AddRow(size.ID, commands.FormatSize(size.Size), formatSizeDelta(size.Size, size.Previous, size.HasPrevious), budget)
+	}
+
+	dw := NewDataWriter(w, "table")
+	if err := table.Write(dw); err != nil {
+		return err
+	}
+	return largestSectionsTable(sizes).Write(dw)
+}
+
+// largestSectionsTable lists the largest sections of each component
+func largestSectionsTable(sizes []commands.ComponentSize) *TableBuilder {
+	table := NewTableBuilder("COMPONENT", "SECTION", "SIZE")
+	for _, size := range sizes {
+		data, err := os.ReadFile(filepath.Clean(size.Path))
+		if err != nil {
+			Warn("Failed to read component %s: %v", size.ID, err)
+			continue
+		}
+		sections, err := oci.WASMSections(data)
+		if err != nil {
+			Warn("Failed to list the sections of component %s: %v", size.ID, err)
+			continue
+		}
+
+		sort.SliceStable(sections, func(i, j int) bool { return sections[i].Size > sections[j].Size })
+		for _, section := range sections[:min(len(sections), sizeReportSections)] {
+			share := float64(section.Size) * 100 / float64(len(data))
+			table.AddRow(size.ID, section.Name, fmt.Sprintf("%s (%.1f%%)", commands.FormatSize(int64(section.Size)), share))
+		}
+	}
+	return table
+}
@@ -0,0 +1,52 @@
+package cli
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/commands"
+)
+
+func TestFormatSizeDelta(t *testing.T) {
+	assert.Equal(t, "new", formatSizeDelta(2048, 0, false))
+	assert.Equal(t, "unchanged", formatSizeDelta(2048, 2048, true))
+	assert.Equal(t, "+1.0 KiB (+50.0%)", formatSizeDelta(3072, 2048, true))
+	assert.Equal(t, "-512 B (-25.0%)", formatSizeDelta(1536, 2048, true))
+}
+
+func TestWriteSizeReport(t *testing.T) {
+	// A module with code, data, a custom section and a small type section
+	wasm := bytes.Join([][]byte{
+		{0x00, 'a', 's', 'm', 0x01, 0x00, 0x00, 0x00},
+		{10, 100}, make([]byte, 100),
+		{11, 20}, make([]byte, 20),
+		{0, 12, 11}, []byte(".debug_info"), {0x00},
+		{1, 2}, make([]byte, 2),
+	}, nil)
+	path := filepath.Join(t.TempDir(), "weather.wasm")
+	require.NoError(t, os.WriteFile(path, wasm, 0600))
+
+	var buf bytes.Buffer
+	require.NoError(t, writeSizeReport(&buf, []commands.ComponentSize{
+		{ID: "weather", Path: path, Size: int64(len(wasm)), Budget: 2048},
+	}))
+	assert.Contains(t, buf.String(), "weather")
+	assert.Contains(t, buf.String(), "new")
+	assert.Contains(t, buf.String(), "2.0 KiB")
+	assert.Contains(t, buf.String(), "code")
+	assert.Contains(t, buf.String(), "data")
+	assert.Contains(t, buf.String(), "custom .debug_info")
+	assert.NotContains(t, buf.String(), "type")
+
+	// The change since the previous build is reported
+	buf.Reset()
+	require.NoError(t, writeSizeReport(&buf, []commands.ComponentSize{
+		{ID: "weather", Path: path, Size: int64(len(wasm)), Previous: int64(len(wasm) - 2), HasPrevious: true},
+	}))
+	assert.Contains(t, buf.String(), "+2 B")
+}
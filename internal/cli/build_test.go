@@ -27,6 +27,10 @@ func TestBuildCommand(t *testing.T) {
 	assert.NotNil(t, configFlag)
 	assert.Equal(t, "c", configFlag.Shorthand)
 	assert.Equal(t, "", configFlag.DefValue)
+
+	exportDirFlag := cmd.Flags().Lookup("export-dir")
+	assert.NotNil(t, exportDirFlag)
+	assert.Equal(t, "terraform", exportDirFlag.DefValue)
 }
 
 func TestBuildCommand_Help(t *testing.T) {
@@ -363,3 +367,9 @@ func TestBuildCommand_AllFlags(t *testing.T) {
 		})
 	}
 }
+
+func TestBuildCommand_ProfileFlag(t *testing.T) {
+	flag := newBuildCmd().Flags().Lookup("profile")
+	require.NotNil(t, flag)
+	assert.Equal(t, "", flag.DefValue)
+}
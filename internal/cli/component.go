@@ -3,7 +3,6 @@ package cli
 import (
 	"fmt"
 
-	"github.com/AlecAivazis/survey/v2"
 	"github.com/fastertools/ftl/internal/manifest"
 	"github.com/fatih/color"
 	"github.com/spf13/cobra"
@@ -13,27 +12,55 @@ func newComponentCmd() *cobra.Command {
 	cmd := &cobra.Command{
 		Use:   "component",
 		Short: "Manage FTL components",
-		Long:  `Manage FTL components including adding, removing, and listing components.`,
+		Long:  `Manage FTL components including adding, removing, listing, inspecting, publishing, versioning and pulling components.`,
 	}
 
 	// Add subcommands
 	cmd.AddCommand(
 		newComponentAddCmd(),
+		newComponentInspectCmd(),
 		newComponentListCmd(),
+		newComponentPublishCmd(),
+		newComponentPullCmd(),
 		newComponentRemoveCmd(),
+		newComponentStoreCmd(),
+		newComponentVersionCmd(),
 	)
 
 	return cmd
 }
 
 func newComponentListCmd() *cobra.Command {
-	return &cobra.Command{
-		Use:   "list",
-		Short: "List all components",
+	opts := tagListOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "list [repository]",
+		Short: "List project components, or the published tags of a component",
+		Long: `Without arguments, list the components of the current project.
+
+With a repository, list its published tags with digest, size and creation time.
+Tags are sorted newest version first with "latest" on top. The repository is
+either a full reference (ghcr.io/namespace/package) or a package name
+(namespace:package) in the registry given by --registry.`,
+		Example: `  ftl component list
+  ftl component list ghcr.io/fastertools/mcp-gateway
+  ftl component list fastertools:mcp-gateway --limit 5
+  ftl component list fastertools:mcp-gateway --all -o json`,
+		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
-			return listComponents()
+			if len(args) == 0 {
+				return listComponents()
+			}
+			return listComponentTags(cmd.Context(), NewDataWriter(cmd.OutOrStdout(), opts.format), args[0], opts)
 		},
 	}
+
+	cmd.Flags().StringVar(&opts.registry, "registry", defaultRegistry, "registry for package names without one")
+	cmd.Flags().IntVar(&opts.limit, "limit", 20, "maximum number of tags to show")
+	cmd.Flags().BoolVar(&opts.all, "all", false, "show all tags")
+	cmd.Flags().StringVarP(&opts.format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
 }
 
 func listComponents() error {
@@ -99,79 +126,24 @@ func listComponents() error {
 }
 
 func newComponentRemoveCmd() *cobra.Command {
-	return &cobra.Command{
+	opts := &RemoveOptions{}
+
+	cmd := &cobra.Command{
 		Use:   "remove [name]",
 		Short: "Remove a component",
 		Args:  cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
-			var name string
 			if len(args) > 0 {
-				name = args[0]
+				opts.Name = args[0]
 			}
-			return removeComponent(name)
+			return runRemove(opts)
 		},
 	}
-}
 
-func removeComponent(name string) error {
-	// Load manifest (tries ftl.yaml, ftl.yml, ftl.json)
-	m, err := manifest.LoadAuto()
-	if err != nil {
-		return fmt.Errorf("failed to load manifest: %w", err)
-	}
-
-	if len(m.Components) == 0 {
-		return fmt.Errorf("no components to remove")
-	}
-
-	// If no name provided, show interactive selection
-	if name == "" {
-		var options []string
-		for _, comp := range m.Components {
-			options = append(options, comp.ID)
-		}
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Skip the confirmation prompt")
+	cmd.Flags().BoolVar(&opts.KeepFiles, "keep-files", false, "Keep the component's directory")
 
-		prompt := &survey.Select{
-			Message: "Select component to remove:",
-			Options: options,
-		}
-		if err := survey.AskOne(prompt, &name); err != nil {
-			return err
-		}
-	}
-
-	// Check if component exists
-	if _, idx := m.FindComponent(name); idx == -1 {
-		return fmt.Errorf("component '%s' not found", name)
-	}
-
-	// Confirm removal
-	confirm := false
-	prompt := &survey.Confirm{
-		Message: fmt.Sprintf("Remove component '%s'?", name),
-		Default: false,
-	}
-	if err := survey.AskOne(prompt, &confirm); err != nil {
-		return err
-	}
-
-	if !confirm {
-		fmt.Println("Cancelled")
-		return nil
-	}
-
-	// Remove component
-	if err := m.RemoveComponent(name); err != nil {
-		return err
-	}
-
-	// Save manifest (to the same file format)
-	if err := m.SaveAuto(); err != nil {
-		return fmt.Errorf("failed to save manifest: %w", err)
-	}
-
-	color.Green("✓ Component '%s' removed", name)
-	return nil
+	return cmd
 }
 
 // Removed - now using manifest package
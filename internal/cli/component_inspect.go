@@ -0,0 +1,242 @@
+package cli
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"maps"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+)
+
+// For testing - allows replacing the registry client
+var newComponentPuller = func() commands.Puller {
+	puller := oci.NewWASMPuller()
+	if offlineMode() {
+		puller.WithMirror(oci.NewMirror(oci.DefaultMirrorDir()))
+	} else {
+		puller.WithStore(oci.NewStore(oci.DefaultStoreDir()))
+	}
+	return puller
+}
+
+type inspectOptions struct {
+	registry string
+	wit      bool
+	format   string
+}
+
+// componentInspection is the JSON output of ftl component inspect
+type componentInspection struct {
+	Source string `json:"source"`
+	Digest string `json:"digest"`
+	Size   int64  `json:"size"`
+	*oci.WASMInfo
+}
+
+func newComponentInspectCmd() *cobra.Command {
+	opts := inspectOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "inspect <reference|path>",
+		Short: "Show the imports, exports and capabilities of a component",
+		Long: `Inspect a WASM component pulled from a registry or read from a local file.
+
+Shows the component's world: the interfaces it imports and exports, the WASI
+and Spin capabilities those imports require (outbound HTTP, key-value, ...)
+and its embedded custom sections, including the producers section.
+
+A reference is a package name with an optional version
+(namespace:package@version, in the registry given by --registry) or a full
+reference (ghcr.io/namespace/package:version). The version defaults to latest.
+
+With --wit, the full WIT world definition is printed instead. This requires
+wasm-tools (cargo install --locked wasm-tools).`,
+		Example: `  ftl component inspect fastertools:mcp-gateway@0.0.13
+  ftl component inspect ghcr.io/fastertools/mcp-gateway:latest -o json
+  ftl component inspect target/wasm32-wasip1/release/my_tool.wasm
+  ftl component inspect fastertools:mcp-gateway --wit`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return inspectComponent(cmd.Context(), NewDataWriter(cmd.OutOrStdout(), opts.format), args[0], opts)
+		},
+	}
+
+	cmd.Flags().StringVar(&opts.registry, "registry", defaultRegistry, "registry for package names without one")
+	cmd.Flags().BoolVar(&opts.wit, "wit", false, "print the full WIT world definition (requires wasm-tools)")
+	cmd.Flags().StringVarP(&opts.format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+// parseComponentRef splits a component reference into registry, package and
+// version. Package names (namespace:package@version) use the given registry,
+// full references are either registry/namespace:package@version or
+// registry/namespace/package:version.
+func parseComponentRef(ref, registry string) (string, string, string, error) {
+	location, version := ref, "latest"
+	if at := strings.LastIndex(ref, "@"); at >= 0 {
+		location, version = ref[:at], ref[at+1:]
+	} else if colon := strings.LastIndex(ref, ":"); colon > strings.LastIndex(ref, "/") && strings.Count(ref, "/") >= 2 {
+		location, version = ref[:colon], ref[colon+1:]
+	}
+
+	if !strings.Contains(location, "/") {
+		location = registry + "/" + location
+	}
+	slash := strings.Index(location, "/")
+	registry, packageName := location[:slash], location[slash+1:]
+	if registry == "" || packageName == "" || version == "" {
+		return "", "", "", fmt.Errorf("invalid component reference %q", ref)
+	}
+	// Registry components are keyed by their Spin package name (namespace:package)
+	if !strings.Contains(packageName, ":") {
+		packageName = strings.Replace(packageName, "/", ":", 1)
+	}
+	return registry, packageName, version, nil
+}
+
+// isLocalComponent reports whether the argument names a local WASM file
+func isLocalComponent(arg string) bool {
+	if strings.HasSuffix(arg, ".wasm") {
+		return true
+	}
+	info, err := os.Stat(arg)
+	return err == nil && info.Mode().IsRegular()
+}
+
+// resolveComponent returns the local path of a component file or reference
+func resolveComponent(ctx context.Context, arg string, opts inspectOptions) (string, error) {
+	if isLocalComponent(arg) {
+		return filepath.Clean(arg), nil
+	}
+
+	registry, packageName, version, err := parseComponentRef(arg, opts.registry)
+	if err != nil {
+		return "", err
+	}
+	return newComponentPuller().Pull(ctx, registry, packageName, version)
+}
+
+func inspectComponent(ctx context.Context, dw *DataWriter, arg string, opts inspectOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	path, err := resolveComponent(ctx, arg, opts)
+	if err != nil {
+		return err
+	}
+
+	if opts.wit {
+		return printComponentWIT(dw, path)
+	}
+
+	data, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return fmt.Errorf("failed to read component: %w", err)
+	}
+	info, err := oci.InspectWASM(data)
+	if err != nil {
+		return fmt.Errorf("failed to inspect %s: %w", arg, err)
+	}
+
+	sum := sha256.Sum256(data)
+	inspection := componentInspection{
+		Source:   arg,
+		Digest:   "sha256:" + hex.EncodeToString(sum[:]),
+		Size:     int64(len(data)),
+		WASMInfo: info,
+	}
+
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(inspection)
+	}
+	return writeComponentInspection(dw, inspection)
+}
+
+func writeComponentInspection(dw *DataWriter, inspection componentInspection) error {
+	capabilities := "none"
+	if len(inspection.Capabilities) > 0 {
+		capabilities = strings.Join(inspection.Capabilities, ", ")
+	}
+
+	_, _ = fmt.Fprintf(dw.output, "Source:        %s\n", inspection.Source)
+	_, _ = fmt.Fprintf(dw.output, "Kind:          %s\n", inspection.Kind)
+	_, _ = fmt.Fprintf(dw.output, "Digest:        %s\n", inspection.Digest)
+	_, _ = fmt.Fprintf(dw.output, "Size:          %s\n", commands.FormatSize(inspection.Size))
+	_, _ = fmt.Fprintf(dw.output, "Capabilities:  %s\n", capabilities)
+	for _, field := range slices.Sorted(maps.Keys(inspection.Producers)) {
+		_, _ = fmt.Fprintf(dw.output, "%-15s%s\n", producerLabel(field)+":", strings.Join(inspection.Producers[field], ", "))
+	}
+
+	sections := []struct {
+		title string
+		items []oci.WASMItem
+	}{
+		{"Imports", inspection.Imports},
+		{"Exports", inspection.Exports},
+	}
+	for _, section := range sections {
+		_, _ = fmt.Fprintf(dw.output, "\n%s:\n", section.title)
+		if len(section.items) == 0 {
+			_, _ = fmt.Fprintln(dw.output, "  none")
+			continue
+		}
+		table := NewTableBuilder("NAME", "KIND")
+		for _, item := range section.items {
+			table.AddRow(item.Name, item.Kind)
+		}
+		if err := table.Write(dw); err != nil {
+			return err
+		}
+	}
+
+	_, _ = fmt.Fprintln(dw.output, "\nCustom sections:")
+	if len(inspection.CustomSections) == 0 {
+		_, _ = fmt.Fprintln(dw.output, "  none")
+		return nil
+	}
+	table := NewTableBuilder("NAME", "SIZE")
+	for _, section := range inspection.CustomSections {
+		table.AddRow(section.Name, commands.FormatSize(int64(section.Size)))
+	}
+	return table.Write(dw)
+}
+
+// producerLabel turns a producers field (language, processed-by, sdk) into a label
+func producerLabel(field string) string {
+	label := strings.ReplaceAll(field, "-", " ")
+	if label == "" {
+		return label
+	}
+	return strings.ToUpper(label[:1]) + label[1:]
+}
+
+// printComponentWIT prints the WIT world of a component using wasm-tools
+func printComponentWIT(dw *DataWriter, path string) error {
+	cmd := ExecCommand("wasm-tools", "component", "wit", path) // #nosec G204 - path is a local file or the puller cache
+	output, err := cmd.Output()
+	if errors.Is(err, exec.ErrNotFound) {
+		return fmt.Errorf("--wit requires wasm-tools: install it with 'cargo install --locked wasm-tools'")
+	}
+	if err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) && len(exitErr.Stderr) > 0 {
+			return fmt.Errorf("wasm-tools failed: %s", strings.TrimSpace(string(exitErr.Stderr)))
+		}
+		return fmt.Errorf("wasm-tools failed: %w", err)
+	}
+	_, err = dw.output.Write(output)
+	return err
+}
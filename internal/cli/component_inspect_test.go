@@ -0,0 +1,118 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/commands"
+)
+
+type fakeComponentPuller struct {
+	path   string
+	pulled []string
+}
+
+func (f *fakeComponentPuller) Pull(_ context.Context, registry, packageName, version string) (string, error) {
+	f.pulled = append(f.pulled, registry, packageName, version)
+	return f.path, nil
+}
+
+// writeTestComponent writes a component importing outbound HTTP
+func writeTestComponent(t *testing.T) string {
+	t.Helper()
+	name := "wasi:http/outgoing-handler@0.2.0"
+	imports := append([]byte{0x01, 0x00, byte(len(name))}, name...)
+	imports = append(imports, 0x05, 0x00)
+	data := append([]byte{0x00, 'a', 's', 'm', 0x0d, 0x00, 0x01, 0x00, 10, byte(len(imports))}, imports...)
+
+	path := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(path, data, 0600))
+	return path
+}
+
+func TestParseComponentRef(t *testing.T) {
+	tests := []struct {
+		ref                    string
+		registry, pkg, version string
+	}{
+		{"fastertools:mcp-gateway", "ghcr.io", "fastertools:mcp-gateway", "latest"},
+		{"fastertools:mcp-gateway@0.0.13", "ghcr.io", "fastertools:mcp-gateway", "0.0.13"},
+		{"registry.example.com/ns:tool@1.0.0", "registry.example.com", "ns:tool", "1.0.0"},
+		{"ghcr.io/fastertools/mcp-gateway:0.0.13", "ghcr.io", "fastertools:mcp-gateway", "0.0.13"},
+		{"localhost:5000/ns/tool", "localhost:5000", "ns:tool", "latest"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.ref, func(t *testing.T) {
+			registry, pkg, version, err := parseComponentRef(tt.ref, "ghcr.io")
+			require.NoError(t, err)
+			assert.Equal(t, tt.registry, registry)
+			assert.Equal(t, tt.pkg, pkg)
+			assert.Equal(t, tt.version, version)
+		})
+	}
+
+	_, _, _, err := parseComponentRef("ns:tool@", "ghcr.io")
+	assert.Error(t, err)
+}
+
+func TestInspectComponent_LocalFile(t *testing.T) {
+	path := writeTestComponent(t)
+
+	var buf bytes.Buffer
+	opts := inspectOptions{registry: "ghcr.io", format: "table"}
+	require.NoError(t, inspectComponent(context.Background(), NewDataWriter(&buf, "table"), path, opts))
+
+	out := buf.String()
+	assert.Contains(t, out, "Kind:          component")
+	assert.Contains(t, out, "Capabilities:  outbound-http")
+	assert.Contains(t, out, "wasi:http/outgoing-handler@0.2.0")
+	assert.Contains(t, out, "Exports:\n  none")
+}
+
+func TestInspectComponent_ReferenceJSON(t *testing.T) {
+	fake := &fakeComponentPuller{path: writeTestComponent(t)}
+	old := newComponentPuller
+	newComponentPuller = func() commands.Puller { return fake }
+	t.Cleanup(func() { newComponentPuller = old })
+
+	var buf bytes.Buffer
+	opts := inspectOptions{registry: "ghcr.io", format: "json"}
+	require.NoError(t, inspectComponent(context.Background(), NewDataWriter(&buf, "json"), "ns:tool@1.0.0", opts))
+
+	assert.Equal(t, []string{"ghcr.io", "ns:tool", "1.0.0"}, fake.pulled)
+
+	var result map[string]interface{}
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &result))
+	assert.Equal(t, "ns:tool@1.0.0", result["source"])
+	assert.Equal(t, "component", result["kind"])
+	assert.Equal(t, []interface{}{"outbound-http"}, result["capabilities"])
+	assert.Contains(t, result["digest"], "sha256:")
+}
+
+func TestInspectComponent_WIT(t *testing.T) {
+	oldExecCommand := ExecCommand
+	ExecCommand = MockExecCommandHelper
+	defer func() { ExecCommand = oldExecCommand }()
+
+	var buf bytes.Buffer
+	opts := inspectOptions{registry: "ghcr.io", wit: true, format: "table"}
+	require.NoError(t, inspectComponent(context.Background(), NewDataWriter(&buf, "table"), writeTestComponent(t), opts))
+
+	assert.Contains(t, buf.String(), "world root")
+}
+
+func TestInspectComponent_NotWASM(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(path, []byte("not wasm"), 0600))
+
+	var buf bytes.Buffer
+	err := inspectComponent(context.Background(), NewDataWriter(&buf, "table"), path, inspectOptions{format: "table"})
+	assert.ErrorContains(t, err, "not a WASM binary")
+}
@@ -0,0 +1,336 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/validation"
+)
+
+// componentPublisher pushes a built component to a registry
+type componentPublisher interface {
+	PushWithDigest(ctx context.Context, wasmPath, packageName, version string) (string, error)
+	Exists(ctx context.Context, packageName, version string) (bool, error)
+}
+
+// For testing - allows replacing the registry client
+var newComponentPublisher = func(registry string) componentPublisher {
+	return oci.NewKeychainPusher(registry)
+}
+
+// PublishOptions holds options for the component publish command
+type PublishOptions struct {
+	Components    []string
+	AllComponents bool
+	Registry      string
+	Namespace     string
+	NoBuild       bool
+	Bump          string
+	Format        string
+}
+
+// publishedComponent is one entry of the publish summary
+type publishedComponent struct {
+	ID        string `json:"id"`
+	Reference string `json:"reference"`
+	Version   string `json:"version"`
+	Digest    string `json:"digest"`
+}
+
+// publishTarget is a component ready to be pushed
+type publishTarget struct {
+	id       string
+	wasmPath string
+	version  string
+}
+
+func newComponentPublishCmd() *cobra.Command {
+	opts := &PublishOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "publish [component...]",
+		Short: "Publish project components to a registry",
+		Long: `Build and publish local components of the current project to an OCI registry.
+
+Each component is pushed as <registry>/<namespace>/<component>:<version>. The
+version comes from the component's own version file, looked up in its build
+workdir: VERSION, Cargo.toml, package.json or pyproject.toml, falling back to
+the project version. --bump increments the patch, minor or major part of each
+version in its version file before building; see 'ftl component version'.
+
+Components are built with their build command first, unless --no-build is
+given, in which case their built WASM is only verified. Nothing is pushed
+unless every selected component is ready, and a version that is already in
+the registry is never published again.
+
+Without --registry and --namespace, components are published to the default
+registry and namespace set with 'ftl registry set', falling back to ghcr.io.
+Registry credentials come from the registry's credential helper, or from
+'docker login'.`,
+		Example: `  ftl component publish --all-components --namespace my-org
+  ftl component publish --all-components
+  ftl component publish weather --namespace my-org --registry registry.example.com
+  ftl component publish weather --namespace my-org --bump minor
+  ftl component publish --all-components --namespace my-org --no-build -o json`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.Components = args
+			return runComponentPublish(cmd.Context(), NewDataWriter(cmd.OutOrStdout(), opts.Format), opts)
+		},
+	}
+
+	cmd.Flags().BoolVar(&opts.AllComponents, "all-components", false, "publish every local component of the project")
+	cmd.Flags().StringVar(&opts.Registry, "registry", "", "registry to publish to (default from 'ftl registry set', or ghcr.io)")
+	cmd.Flags().StringVar(&opts.Namespace, "namespace", "", "registry namespace of the published packages (default from 'ftl registry set')")
+	cmd.Flags().BoolVar(&opts.NoBuild, "no-build", false, "verify the built WASM instead of building components")
+	cmd.Flags().StringVar(&opts.Bump, "bump", "", "version part to increment before publishing (patch, minor, major)")
+	cmd.Flags().StringVarP(&opts.Format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+func runComponentPublish(ctx context.Context, dw *DataWriter, opts *PublishOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	if opts.AllComponents == (len(opts.Components) > 0) {
+		return fmt.Errorf("specify the components to publish or --all-components")
+	}
+	if opts.Bump != "" && !slices.Contains(bumpParts, opts.Bump) {
+		return fmt.Errorf("invalid --bump %q: expected one of %s", opts.Bump, strings.Join(bumpParts, ", "))
+	}
+	registry, namespace, err := publishDestination(opts)
+	if err != nil {
+		return err
+	}
+
+	configFile := commands.FindDataConfig(".")
+	if configFile == "" {
+		return fmt.Errorf("no ftl.yaml or ftl.json found. Run 'ftl init' first")
+	}
+	app, err := commands.LoadApplication(configFile)
+	if err != nil {
+		return err
+	}
+
+	components, err := publishableComponents(app, opts.Components)
+	if err != nil {
+		return err
+	}
+	if len(components) == 0 {
+		return fmt.Errorf("no local components to publish")
+	}
+
+	// Resolve the versions and check none is published yet before touching
+	// anything
+	configDir := filepath.Dir(configFile)
+	publisher := newComponentPublisher(registry)
+	versions := make([]publishVersionInfo, 0, len(components))
+	for _, comp := range components {
+		v, err := resolvePublishVersion(ctx, publisher, comp, configDir, app.Version, namespace, opts.Bump)
+		if err != nil {
+			return fmt.Errorf("component %s: %w", comp.ID, err)
+		}
+		versions = append(versions, v)
+	}
+	if opts.Bump != "" {
+		for i, comp := range components {
+			v := versions[i]
+			if _, err := setComponentVersion(comp, configDir, v.file, v.current, v.version); err != nil {
+				return fmt.Errorf("component %s: %w", comp.ID, err)
+			}
+			Info("Bumped %s from %s to %s", comp.ID, v.current, v.version)
+		}
+	}
+
+	// Build and verify everything first, so a failing component doesn't
+	// leave the project half published
+	targets := make([]publishTarget, 0, len(components))
+	for i, comp := range components {
+		target, err := preparePublish(comp, configDir, versions[i].version, !opts.NoBuild)
+		if err != nil {
+			return fmt.Errorf("component %s: %w", comp.ID, err)
+		}
+		targets = append(targets, target)
+	}
+
+	published := make([]publishedComponent, 0, len(targets))
+	for _, target := range targets {
+		packageName := namespace + "/" + target.id
+		Info("Publishing %s %s", target.id, target.version)
+		digest, err := publisher.PushWithDigest(ctx, target.wasmPath, packageName, target.version)
+		if err != nil {
+			if len(published) > 0 {
+				Warn("Published before the failure: %s", publishedIDs(published))
+			}
+			return fmt.Errorf("failed to publish %s: %w", target.id, err)
+		}
+		published = append(published, publishedComponent{
+			ID:        target.id,
+			Reference: fmt.Sprintf("%s/%s:%s", registry, packageName, target.version),
+			Version:   target.version,
+			Digest:    digest,
+		})
+	}
+
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(published)
+	}
+	table := NewTableBuilder("COMPONENT", "REFERENCE", "DIGEST")
+	for _, p := range published {
+		table.AddRow(p.ID, p.Reference, shortDigest(p.Digest))
+	}
+	if err := table.Write(dw); err != nil {
+		return err
+	}
+	Success("Published %d component(s)", len(published))
+	return nil
+}
+
+// publishVersionInfo is the version a component is published with
+type publishVersionInfo struct {
+	// current is the version in the component's version file, or the
+	// project version when file is ""
+	current string
+	file    string
+	// version is current, bumped when asked to
+	version string
+}
+
+// resolvePublishVersion resolves the version to publish a component with,
+// bumping it when asked to, and refuses versions already in the registry
+func resolvePublishVersion(ctx context.Context, publisher componentPublisher, comp *validation.Component, configDir, projectVersion, namespace, bump string) (publishVersionInfo, error) {
+	v := publishVersionInfo{}
+	v.current, v.file = commands.PublishVersion(comp, configDir, projectVersion)
+	v.version = v.current
+	if bump != "" {
+		bumped, err := bumpVersion(v.current, bump)
+		if err != nil {
+			return v, err
+		}
+		v.version = bumped
+		if v.file == "" {
+			if _, err := newVersionFile(comp, configDir); err != nil {
+				return v, err
+			}
+		}
+	}
+
+	packageName := namespace + "/" + comp.ID
+	exists, err := publisher.Exists(ctx, packageName, v.version)
+	if err != nil {
+		return v, err
+	}
+	if exists {
+		hint := "bump it with --bump or 'ftl component version'"
+		if bump != "" {
+			hint = "bump a larger part of the version"
+		}
+		return v, fmt.Errorf("version %s of %s is already published: %s", v.version, packageName, hint)
+	}
+	return v, nil
+}
+
+// publishDestination returns the registry and namespace to publish to: the
+// flags, falling back to the default registry and its namespace configured
+// with ftl registry set
+func publishDestination(opts *PublishOptions) (string, string, error) {
+	registries, err := loadRegistries()
+	if err != nil {
+		return "", "", err
+	}
+
+	registry := opts.Registry
+	if registry == "" {
+		registry = registries.Default
+	}
+	if registry == "" {
+		registry = defaultRegistry
+	}
+	namespace := strings.Trim(opts.Namespace, "/")
+	if namespace == "" {
+		namespace = registries.Registries[registry].Namespace
+	}
+	if namespace == "" {
+		return "", "", fmt.Errorf("--namespace is required: no namespace is configured for %s", registry)
+	}
+	return registry, namespace, nil
+}
+
+// publishableComponents selects the local components to publish, all of them
+// when no IDs are given
+func publishableComponents(app *validation.Application, ids []string) ([]*validation.Component, error) {
+	for _, id := range ids {
+		found := false
+		for _, comp := range app.Components {
+			if comp.ID != id {
+				continue
+			}
+			found = true
+			if _, ok := comp.Source.(*validation.LocalSource); !ok {
+				return nil, fmt.Errorf("component %s comes from a registry and can't be published", id)
+			}
+		}
+		if !found {
+			return nil, fmt.Errorf("component %q not found in the FTL config", id)
+		}
+	}
+
+	var components []*validation.Component
+	for _, comp := range app.Components {
+		if _, ok := comp.Source.(*validation.LocalSource); !ok {
+			continue
+		}
+		if len(ids) == 0 || slices.Contains(ids, comp.ID) {
+			components = append(components, comp)
+		}
+	}
+	return components, nil
+}
+
+// preparePublish builds a component when asked to, then locates and verifies
+// its WASM
+func preparePublish(comp *validation.Component, configDir, version string, build bool) (publishTarget, error) {
+	src, _ := comp.Source.(*validation.LocalSource)
+	dir := commands.ComponentWorkdir(comp, configDir)
+
+	if build && comp.Build != nil && comp.Build.Command != "" {
+		Info("Building %s: %s", comp.ID, comp.Build.Command)
+		cmd := ExecCommand("sh", "-c", comp.Build.Command) // #nosec G204 - build command from the project's own config
+		cmd.Dir = dir
+		cmd.Stdout = os.Stderr
+		cmd.Stderr = os.Stderr
+		if err := cmd.Run(); err != nil {
+			return publishTarget{}, fmt.Errorf("build failed: %w", err)
+		}
+	}
+
+	wasmPath, err := findBuiltWASM(filepath.Join(configDir, src.Path), comp.ID)
+	if err != nil {
+		return publishTarget{}, fmt.Errorf("%w (build it first, or drop --no-build)", err)
+	}
+	data, err := os.ReadFile(filepath.Clean(wasmPath))
+	if err != nil {
+		return publishTarget{}, err
+	}
+	if _, err := oci.InspectWASM(data); err != nil {
+		return publishTarget{}, fmt.Errorf("%s is not a valid WASM binary: %w", wasmPath, err)
+	}
+
+	return publishTarget{id: comp.ID, wasmPath: wasmPath, version: version}, nil
+}
+
+func publishedIDs(published []publishedComponent) string {
+	ids := make([]string, 0, len(published))
+	for _, p := range published {
+		ids = append(ids, p.ID)
+	}
+	return strings.Join(ids, ", ")
+}
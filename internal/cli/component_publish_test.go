@@ -0,0 +1,296 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/config"
+)
+
+const publishTestManifest = `name: test-app
+version: "0.3.0"
+access: public
+components:
+  - id: weather
+    source: weather/weather.wasm
+    build:
+      command: make build
+      workdir: weather
+  - id: calc
+    source: calc/calc.wasm
+    build:
+      command: make build
+      workdir: calc
+  - id: published
+    source:
+      registry: ghcr.io
+      package: acme/published
+      version: 1.0.0
+`
+
+// Smallest valid WASM core module
+var emptyWASMModule = []byte{0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00}
+
+type publishCall struct {
+	wasmPath    string
+	packageName string
+	version     string
+}
+
+type fakePublisher struct {
+	calls  []publishCall
+	failOn string
+
+	// existing holds the published references, as package:version
+	existing map[string]bool
+}
+
+func (f *fakePublisher) PushWithDigest(_ context.Context, wasmPath, packageName, version string) (string, error) {
+	if packageName == f.failOn {
+		return "", errors.New("denied")
+	}
+	f.calls = append(f.calls, publishCall{wasmPath, packageName, version})
+	return "sha256:0123456789abcdef0123456789abcdef", nil
+}
+
+func (f *fakePublisher) Exists(_ context.Context, packageName, version string) (bool, error) {
+	return f.existing[packageName+":"+version], nil
+}
+
+func withFakePublisher(t *testing.T) *fakePublisher {
+	t.Helper()
+	fake := &fakePublisher{}
+	old := newComponentPublisher
+	newComponentPublisher = func(string) componentPublisher { return fake }
+	t.Cleanup(func() { newComponentPublisher = old })
+	return fake
+}
+
+// setupPublishTest creates a project with built components in a temp dir and
+// changes into it
+func setupPublishTest(t *testing.T) {
+	t.Helper()
+
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	t.Cleanup(func() { _ = os.Chdir(oldWd) })
+	require.NoError(t, os.Chdir(tmpDir))
+
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte(publishTestManifest), 0600))
+	for _, id := range []string{"weather", "calc"} {
+		require.NoError(t, os.MkdirAll(id, 0755))
+		require.NoError(t, os.WriteFile(filepath.Join(id, id+".wasm"), emptyWASMModule, 0600))
+	}
+	require.NoError(t, os.WriteFile(filepath.Join("weather", "Cargo.toml"),
+		[]byte("[package]\nname = \"weather\"\nversion = \"1.2.0\"\n"), 0600))
+}
+
+func TestRunComponentPublish_AllComponents(t *testing.T) {
+	setupPublishTest(t)
+	fake := withFakePublisher(t)
+
+	var buf bytes.Buffer
+	err := runComponentPublish(context.Background(), NewDataWriter(&buf, "json"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+	})
+	require.NoError(t, err)
+
+	assert.Equal(t, []publishCall{
+		{filepath.Join("weather", "weather.wasm"), "acme/weather", "1.2.0"},
+		{filepath.Join("calc", "calc.wasm"), "acme/calc", "0.3.0"},
+	}, fake.calls)
+
+	var published []publishedComponent
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &published))
+	require.Len(t, published, 2)
+	assert.Equal(t, "ghcr.io/acme/weather:1.2.0", published[0].Reference)
+	assert.Equal(t, "sha256:0123456789abcdef0123456789abcdef", published[0].Digest)
+}
+
+func TestRunComponentPublish_VerifiesBeforePushing(t *testing.T) {
+	setupPublishTest(t)
+	fake := withFakePublisher(t)
+	require.NoError(t, os.WriteFile(filepath.Join("calc", "calc.wasm"), []byte("not wasm"), 0600))
+
+	err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+	})
+	assert.ErrorContains(t, err, "component calc")
+	assert.ErrorContains(t, err, "not a valid WASM binary")
+	assert.Empty(t, fake.calls)
+}
+
+func TestRunComponentPublish_ConfiguredRegistry(t *testing.T) {
+	cfg, _ := withConfigDirs(t)
+	cfg.Registries = &config.RegistriesConfig{
+		Default:    "registry.example.com",
+		Registries: map[string]config.RegistryConfig{"registry.example.com": {Namespace: "me"}},
+	}
+	setupPublishTest(t)
+	withFakePublisher(t)
+
+	publish := func(opts *PublishOptions) []publishedComponent {
+		t.Helper()
+		var buf bytes.Buffer
+		opts.Components = []string{"calc"}
+		opts.NoBuild = true
+		require.NoError(t, runComponentPublish(context.Background(), NewDataWriter(&buf, "json"), opts))
+		var published []publishedComponent
+		require.NoError(t, json.Unmarshal(buf.Bytes(), &published))
+		require.Len(t, published, 1)
+		return published
+	}
+
+	assert.Equal(t, "registry.example.com/me/calc:0.3.0", publish(&PublishOptions{})[0].Reference)
+	assert.Equal(t, "registry.example.com/acme/calc:0.3.0", publish(&PublishOptions{Namespace: "acme"})[0].Reference)
+
+	// The project registry config overrides the user's
+	require.NoError(t, config.SaveProjectRegistries(config.RegistriesFile, &config.RegistriesConfig{
+		Registries: map[string]config.RegistryConfig{"registry.example.com": {Namespace: "team"}},
+	}))
+	assert.Equal(t, "registry.example.com/team/calc:0.3.0", publish(&PublishOptions{})[0].Reference)
+
+	// Registries without a configured namespace still need --namespace
+	err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		Components: []string{"calc"},
+		Registry:   "ghcr.io",
+		NoBuild:    true,
+	})
+	assert.ErrorContains(t, err, "--namespace is required: no namespace is configured for ghcr.io")
+}
+
+func TestRunComponentPublish_Errors(t *testing.T) {
+	setupPublishTest(t)
+	withFakePublisher(t)
+
+	tests := []struct {
+		name    string
+		opts    PublishOptions
+		wantErr string
+	}{
+		{"no selection", PublishOptions{Namespace: "acme"}, "specify the components to publish or --all-components"},
+		{"both selections", PublishOptions{Components: []string{"calc"}, AllComponents: true, Namespace: "acme"}, "specify the components to publish or --all-components"},
+		{"no namespace", PublishOptions{AllComponents: true}, "--namespace is required"},
+		{"unknown component", PublishOptions{Components: []string{"missing"}, Namespace: "acme"}, `component "missing" not found in the FTL config`},
+		{"registry component", PublishOptions{Components: []string{"published"}, Namespace: "acme"}, "component published comes from a registry and can't be published"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			tt.opts.Registry = "ghcr.io"
+			tt.opts.NoBuild = true
+			err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &tt.opts)
+			assert.EqualError(t, err, tt.wantErr)
+		})
+	}
+}
+
+func TestRunComponentPublish_PushFailure(t *testing.T) {
+	setupPublishTest(t)
+	fake := withFakePublisher(t)
+	fake.failOn = "acme/calc"
+
+	err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+	})
+	assert.EqualError(t, err, "failed to publish calc: denied")
+	assert.Len(t, fake.calls, 1)
+}
+
+func TestRunComponentPublish_RefusesPublishedVersions(t *testing.T) {
+	setupPublishTest(t)
+	fake := withFakePublisher(t)
+	fake.existing = map[string]bool{"acme/calc:0.3.0": true}
+
+	err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+	})
+	assert.EqualError(t, err, "component calc: version 0.3.0 of acme/calc is already published: bump it with --bump or 'ftl component version'")
+	assert.Empty(t, fake.calls)
+}
+
+func TestRunComponentPublish_Bump(t *testing.T) {
+	setupPublishTest(t)
+	fake := withFakePublisher(t)
+	fake.existing = map[string]bool{"acme/weather:1.2.0": true}
+
+	err := runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "json"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+		Bump:          "minor",
+	})
+	require.NoError(t, err)
+	assert.Equal(t, []publishCall{
+		{filepath.Join("weather", "weather.wasm"), "acme/weather", "1.3.0"},
+		{filepath.Join("calc", "calc.wasm"), "acme/calc", "0.4.0"},
+	}, fake.calls)
+
+	// The bumped versions are written back
+	assert.Equal(t, "1.3.0", commands.ComponentVersion("weather"))
+	assert.Equal(t, "0.4.0", commands.ComponentVersion("calc"))
+
+	// Nothing is bumped when a bumped version is already published
+	fake.calls = nil
+	fake.existing["acme/calc:0.4.1"] = true
+	err = runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		NoBuild:       true,
+		Bump:          "patch",
+	})
+	assert.EqualError(t, err, "component calc: version 0.4.1 of acme/calc is already published: bump a larger part of the version")
+	assert.Empty(t, fake.calls)
+	assert.Equal(t, "1.3.0", commands.ComponentVersion("weather"))
+
+	err = runComponentPublish(context.Background(), NewDataWriter(&bytes.Buffer{}, "table"), &PublishOptions{
+		AllComponents: true,
+		Registry:      "ghcr.io",
+		Namespace:     "acme",
+		Bump:          "huge",
+	})
+	assert.EqualError(t, err, `invalid --bump "huge": expected one of patch, minor, major`)
+}
+
+func TestComponentVersion(t *testing.T) {
+	dir := t.TempDir()
+	assert.Equal(t, "", commands.ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "pyproject.toml"),
+		[]byte("[project]\nname = \"tool\"\nversion = \"0.4.0\"\n"), 0600))
+	assert.Equal(t, "0.4.0", commands.ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "package.json"), []byte(`{"version": "2.0.0"}`), 0600))
+	assert.Equal(t, "2.0.0", commands.ComponentVersion(dir))
+
+	// Workspace-inherited Cargo versions fall through to the next file
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "Cargo.toml"),
+		[]byte("[package]\nname = \"tool\"\nversion.workspace = true\n"), 0600))
+	assert.Equal(t, "2.0.0", commands.ComponentVersion(dir))
+
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "VERSION"), []byte("3.1.4\n"), 0600))
+	assert.Equal(t, "3.1.4", commands.ComponentVersion(dir))
+}
@@ -0,0 +1,206 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+)
+
+// componentStore keeps pulled components in the local content-addressed store
+type componentStore interface {
+	Fetch(ctx context.Context, ref oci.MirrorRef) (*oci.StoreEntry, error)
+	BlobPath(entry *oci.StoreEntry) string
+	Entries() ([]oci.StoreEntry, error)
+	GC(unusedFor time.Duration, dryRun bool) (*oci.StoreGCResult, error)
+}
+
+// For testing - allows replacing the store
+var newComponentStore = func(dir string) componentStore {
+	return oci.NewStore(dir)
+}
+
+const storeDirUsage = "Store directory (defaults to FTL_STORE_DIR or ~/.ftl/store)"
+
+func storeDir(dir string) string {
+	if dir == "" {
+		return oci.DefaultStoreDir()
+	}
+	return dir
+}
+
+// pulledComponent is a stored component and the path of its WASM blob
+type pulledComponent struct {
+	*oci.StoreEntry
+	Path string `json:"path"`
+}
+
+func newComponentPullCmd() *cobra.Command {
+	var dir, to, format string
+
+	cmd := &cobra.Command{
+		Use:   "pull REF",
+		Short: "Pull a component into the local component store",
+		Long: `Pull a registry component into the local component store.
+
+REF is registry/namespace:package@version. Components are stored once by
+digest in the store directory (FTL_STORE_DIR, or ~/.ftl/store by default) and
+shared by every project; builds, deploys and 'ftl component inspect' pull
+through the same store. A component already in the store is verified and reused rather than
+downloaded again.
+
+With --to, the component is also hardlinked (or copied, across filesystems) to
+the given path.
+
+Example:
+  ftl component pull ghcr.io/fastertools:mcp-gateway@0.0.13
+  ftl component pull ghcr.io/fastertools:mcp-gateway@0.0.13 --to build/gateway.wasm`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runComponentPull(cmd.Context(), newComponentStore(storeDir(dir)), cmd.OutOrStdout(), args[0], to, format)
+		},
+	}
+
+	cmd.Flags().StringVar(&dir, "dir", "", storeDirUsage)
+	cmd.Flags().StringVar(&to, "to", "", "Also place the component at this path")
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+func runComponentPull(ctx context.Context, store componentStore, w io.Writer, rawRef, to, format string) error {
+	ref, err := oci.ParseMirrorRef(rawRef)
+	if err != nil {
+		return err
+	}
+
+	Info("Pulling %s", ref)
+	entry, err := store.Fetch(ctx, ref)
+	if err != nil {
+		return err
+	}
+	pulled := pulledComponent{StoreEntry: entry, Path: store.BlobPath(entry)}
+
+	if to != "" {
+		if err := oci.LinkOrCopy(pulled.Path, to); err != nil {
+			return fmt.Errorf("failed to place component at %s: %w", to, err)
+		}
+		pulled.Path = to
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(pulled)
+	}
+	tb := NewTableBuilder("COMPONENT", "DIGEST", "SIZE", "PATH")
+	tb.AddRow(entry.Ref, entry.Digest, commands.FormatSize(entry.Size), pulled.Path)
+	return tb.Write(dw)
+}
+
+func newComponentStoreCmd() *cobra.Command {
+	var dir string
+
+	cmd := &cobra.Command{
+		Use:   "store",
+		Short: "Manage the local component store",
+		Long: `Manage the local component store.
+
+Registry components pulled by builds, deploys and 'ftl component pull' are
+kept once by digest in the store directory (FTL_STORE_DIR, or ~/.ftl/store by
+default) and linked into project build directories.`,
+	}
+
+	cmd.PersistentFlags().StringVar(&dir, "dir", "", storeDirUsage)
+
+	var format string
+	listCmd := &cobra.Command{
+		Use:   "list",
+		Short: "List the components in the store",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runComponentStoreList(newComponentStore(storeDir(dir)), cmd.OutOrStdout(), format)
+		},
+	}
+	listCmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	var unusedFor time.Duration
+	var dryRun bool
+	gcCmd := &cobra.Command{
+		Use:   "gc",
+		Short: "Remove components no longer needed from the store",
+		Long: `Remove components no longer needed from the store.
+
+Blobs no stored reference points to, e.g. after a tag moved to a new digest,
+are removed along with files left by interrupted pulls. With --unused-for,
+references not used by a pull, build or deploy within that duration are
+forgotten first, so their blobs are removed too.
+
+Example:
+  ftl component store gc
+  ftl component store gc --unused-for 720h --dry-run`,
+		Args: cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runComponentStoreGC(newComponentStore(storeDir(dir)), cmd.OutOrStdout(), unusedFor, dryRun, format)
+		},
+	}
+	gcCmd.Flags().DurationVar(&unusedFor, "unused-for", 0, "Also forget references unused for this long (e.g. 720h)")
+	gcCmd.Flags().BoolVar(&dryRun, "dry-run", false, "Show what would be removed without removing it")
+	gcCmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	cmd.AddCommand(listCmd, gcCmd)
+
+	return cmd
+}
+
+func runComponentStoreList(store componentStore, w io.Writer, format string) error {
+	entries, err := store.Entries()
+	if err != nil {
+		return err
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(entries)
+	}
+	if len(entries) == 0 {
+		Info("The component store is empty")
+		return nil
+	}
+	tb := NewTableBuilder("COMPONENT", "DIGEST", "SIZE", "LAST USED")
+	for _, entry := range entries {
+		tb.AddRow(entry.Ref, entry.Digest, commands.FormatSize(entry.Size), entry.UsedAt.Local().Format(time.DateTime))
+	}
+	return tb.Write(dw)
+}
+
+func runComponentStoreGC(store componentStore, w io.Writer, unusedFor time.Duration, dryRun bool, format string) error {
+	if unusedFor < 0 {
+		return fmt.Errorf("--unused-for must not be negative")
+	}
+
+	result, err := store.GC(unusedFor, dryRun)
+	if err != nil {
+		return err
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(result)
+	}
+	for _, ref := range result.RemovedRefs {
+		if _, err := fmt.Fprintf(w, "  %s\n", ref); err != nil {
+			return err
+		}
+	}
+	verb := "Removed"
+	if dryRun {
+		verb = "Would remove"
+	}
+	Success("%s %d reference(s) and %d blob(s), freeing %s", verb, len(result.RemovedRefs), result.RemovedBlobs, commands.FormatSize(result.FreedBytes))
+	return nil
+}
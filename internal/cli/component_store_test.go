@@ -0,0 +1,106 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/oci"
+)
+
+type fakeComponentStore struct {
+	dir       string
+	fetched   []string
+	entries   []oci.StoreEntry
+	gcCalls   []time.Duration
+	gcDryRuns []bool
+}
+
+func (f *fakeComponentStore) Fetch(_ context.Context, ref oci.MirrorRef) (*oci.StoreEntry, error) {
+	f.fetched = append(f.fetched, ref.String())
+	return &oci.StoreEntry{Ref: ref.String(), Digest: "sha256:abc", Layer: "sha256:def", Size: 2048}, nil
+}
+
+func (f *fakeComponentStore) BlobPath(_ *oci.StoreEntry) string {
+	return filepath.Join(f.dir, "def.wasm")
+}
+
+func (f *fakeComponentStore) Entries() ([]oci.StoreEntry, error) {
+	return f.entries, nil
+}
+
+func (f *fakeComponentStore) GC(unusedFor time.Duration, dryRun bool) (*oci.StoreGCResult, error) {
+	f.gcCalls = append(f.gcCalls, unusedFor)
+	f.gcDryRuns = append(f.gcDryRuns, dryRun)
+	return &oci.StoreGCResult{RemovedRefs: []string{"ghcr.io/acme:old@0.1.0"}, RemovedBlobs: 1, FreedBytes: 4096}, nil
+}
+
+func TestComponentStoreCommands(t *testing.T) {
+	cmd := newComponentCmd()
+	pull, _, err := cmd.Find([]string{"pull"})
+	require.NoError(t, err)
+	assert.Equal(t, "pull", pull.Name())
+	assert.NotNil(t, pull.Flags().Lookup("to"))
+
+	gc, _, err := cmd.Find([]string{"store", "gc"})
+	require.NoError(t, err)
+	assert.Equal(t, "gc", gc.Name())
+	assert.NotNil(t, gc.Flags().Lookup("unused-for"))
+	assert.NotNil(t, gc.Flags().Lookup("dry-run"))
+	assert.NotNil(t, gc.InheritedFlags().Lookup("dir"))
+}
+
+func TestRunComponentPull(t *testing.T) {
+	store := &fakeComponentStore{dir: t.TempDir()}
+	require.NoError(t, os.WriteFile(filepath.Join(store.dir, "def.wasm"), []byte("wasm"), 0444))
+
+	var buf bytes.Buffer
+	require.NoError(t, runComponentPull(context.Background(), store, &buf, "ghcr.io/fastertools:mcp-gateway@0.0.13", "", "json"))
+	assert.Equal(t, []string{"ghcr.io/fastertools:mcp-gateway@0.0.13"}, store.fetched)
+
+	var pulled map[string]interface{}
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &pulled))
+	assert.Equal(t, "sha256:abc", pulled["digest"])
+	assert.Equal(t, filepath.Join(store.dir, "def.wasm"), pulled["path"])
+
+	// --to places the component in the project
+	to := filepath.Join(t.TempDir(), "build", "gateway.wasm")
+	buf.Reset()
+	require.NoError(t, runComponentPull(context.Background(), store, &buf, "ghcr.io/fastertools:mcp-gateway@0.0.13", to, "table"))
+	assert.Contains(t, buf.String(), to)
+	content, err := os.ReadFile(to)
+	require.NoError(t, err)
+	assert.Equal(t, []byte("wasm"), content)
+
+	err = runComponentPull(context.Background(), store, &buf, "mcp-gateway", "", "table")
+	assert.ErrorContains(t, err, "invalid reference")
+}
+
+func TestRunComponentStoreListAndGC(t *testing.T) {
+	store := &fakeComponentStore{entries: []oci.StoreEntry{
+		{Ref: "ghcr.io/fastertools:mcp-gateway@0.0.13", Digest: "sha256:abc", Size: 2048, UsedAt: time.Now()},
+	}}
+
+	var buf bytes.Buffer
+	require.NoError(t, runComponentStoreList(store, &buf, "table"))
+	assert.Contains(t, buf.String(), "LAST USED")
+	assert.Contains(t, buf.String(), "mcp-gateway@0.0.13")
+
+	buf.Reset()
+	require.NoError(t, runComponentStoreGC(store, &buf, 720*time.Hour, true, "json"))
+	assert.Equal(t, []time.Duration{720 * time.Hour}, store.gcCalls)
+	assert.Equal(t, []bool{true}, store.gcDryRuns)
+	var result oci.StoreGCResult
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &result))
+	assert.Equal(t, int64(4096), result.FreedBytes)
+
+	err := runComponentStoreGC(store, &buf, -time.Hour, false, "table")
+	assert.ErrorContains(t, err, "must not be negative")
+}
@@ -0,0 +1,106 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+)
+
+// tagLister lists tags and their metadata in a registry repository
+type tagLister interface {
+	ListTags(ctx context.Context, repository string) ([]string, error)
+	Describe(ctx context.Context, repository string, tags []string) []oci.TagInfo
+}
+
+// For testing - allows replacing the registry client
+var newTagLister = func() tagLister { return oci.NewTagLister() }
+
+type tagListOptions struct {
+	registry string
+	limit    int
+	all      bool
+	format   string
+}
+
+// componentTagList is the JSON output of ftl component list <repository>
+type componentTagList struct {
+	Repository string        `json:"repository"`
+	Total      int           `json:"total"`
+	Tags       []oci.TagInfo `json:"tags"`
+}
+
+// componentRepository turns a package name (namespace:package) or a full
+// reference into an OCI repository
+func componentRepository(ref, registry string) string {
+	if !strings.Contains(ref, "/") {
+		return registry + "/" + strings.Replace(ref, ":", "/", 1)
+	}
+	return ref
+}
+
+func listComponentTags(ctx context.Context, dw *DataWriter, ref string, opts tagListOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	if !opts.all && opts.limit <= 0 {
+		return fmt.Errorf("--limit must be positive")
+	}
+
+	repository := componentRepository(ref, opts.registry)
+	lister := newTagLister()
+
+	tags, err := lister.ListTags(ctx, repository)
+	if err != nil {
+		return err
+	}
+	total := len(tags)
+	if !opts.all && len(tags) > opts.limit {
+		tags = tags[:opts.limit]
+	}
+
+	// Only the tags shown are described, repositories can have hundreds
+	infos := lister.Describe(ctx, repository, tags)
+
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(componentTagList{Repository: repository, Total: total, Tags: infos})
+	}
+
+	if total == 0 {
+		_, _ = fmt.Fprintf(dw.output, "No tags found in %s\n", repository)
+		return nil
+	}
+
+	table := NewTableBuilder("TAG", "DIGEST", "SIZE", "CREATED")
+	for _, info := range infos {
+		if info.Error != "" {
+			table.AddRow(info.Tag, "error: "+info.Error, "-", "-")
+			continue
+		}
+		created := "-"
+		if info.Created != nil {
+			created = info.Created.Local().Format(time.DateTime)
+		}
+		table.AddRow(info.Tag, shortDigest(info.Digest), commands.FormatSize(info.Size), created)
+	}
+	if err := table.Write(dw); err != nil {
+		return err
+	}
+
+	if len(infos) < total {
+		_, _ = fmt.Fprintf(dw.output, "\nShowing %d of %d tags. Use --limit or --all to see more.\n", len(infos), total)
+	}
+	return nil
+}
+
+// shortDigest abbreviates a digest to its algorithm and first 12 hex characters
+func shortDigest(digest string) string {
+	algorithm, hex, ok := strings.Cut(digest, ":")
+	if !ok || len(hex) <= 12 {
+		return digest
+	}
+	return algorithm + ":" + hex[:12]
+}
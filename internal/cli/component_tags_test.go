@@ -0,0 +1,87 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/oci"
+)
+
+type fakeTagLister struct {
+	tags      []string
+	described []string
+}
+
+func (f *fakeTagLister) ListTags(_ context.Context, _ string) ([]string, error) {
+	tags := append([]string(nil), f.tags...)
+	oci.SortTags(tags)
+	return tags, nil
+}
+
+func (f *fakeTagLister) Describe(_ context.Context, _ string, tags []string) []oci.TagInfo {
+	f.described = tags
+	created := time.Date(2025, 8, 1, 12, 0, 0, 0, time.UTC)
+	infos := make([]oci.TagInfo, 0, len(tags))
+	for _, tag := range tags {
+		infos = append(infos, oci.TagInfo{
+			Tag:     tag,
+			Digest:  "sha256:0123456789abcdef0123456789abcdef",
+			Size:    3 * 1024 * 1024,
+			Created: &created,
+		})
+	}
+	return infos
+}
+
+func withFakeTagLister(t *testing.T, tags ...string) *fakeTagLister {
+	t.Helper()
+	fake := &fakeTagLister{tags: tags}
+	old := newTagLister
+	newTagLister = func() tagLister { return fake }
+	t.Cleanup(func() { newTagLister = old })
+	return fake
+}
+
+func TestComponentRepository(t *testing.T) {
+	assert.Equal(t, "ghcr.io/fastertools/mcp-gateway", componentRepository("fastertools:mcp-gateway", "ghcr.io"))
+	assert.Equal(t, "registry.example.com/ns/tool", componentRepository("registry.example.com/ns/tool", "ghcr.io"))
+}
+
+func TestListComponentTags_Table(t *testing.T) {
+	fake := withFakeTagLister(t, "0.1.0", "0.10.0", "latest", "0.2.0")
+
+	var buf bytes.Buffer
+	opts := tagListOptions{registry: "ghcr.io", limit: 2, format: "table"}
+	require.NoError(t, listComponentTags(context.Background(), NewDataWriter(&buf, "table"), "ns:tool", opts))
+
+	// Only the shown tags are described
+	assert.Equal(t, []string{"latest", "0.10.0"}, fake.described)
+
+	out := buf.String()
+	assert.Contains(t, out, "sha256:0123456789ab")
+	assert.NotContains(t, out, "sha256:0123456789abcdef0123")
+	assert.Contains(t, out, "3.0 MiB")
+	assert.Contains(t, out, "Showing 2 of 4 tags")
+}
+
+func TestListComponentTags_JSONAll(t *testing.T) {
+	withFakeTagLister(t, "0.1.0", "latest", "0.2.0")
+
+	var buf bytes.Buffer
+	opts := tagListOptions{registry: "ghcr.io", limit: 1, all: true, format: "json"}
+	require.NoError(t, listComponentTags(context.Background(), NewDataWriter(&buf, "json"), "ns:tool", opts))
+
+	var result componentTagList
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &result))
+	assert.Equal(t, "ghcr.io/ns/tool", result.Repository)
+	assert.Equal(t, 3, result.Total)
+	require.Len(t, result.Tags, 3)
+	assert.Equal(t, "latest", result.Tags[0].Tag)
+	assert.Equal(t, "0.2.0", result.Tags[1].Tag)
+}
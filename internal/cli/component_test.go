@@ -18,7 +18,7 @@ func TestComponentCommands(t *testing.T) {
 	assert.Equal(t, "component", cmd.Use)
 
 	// Verify subcommands
-	subcommands := []string{"add", "list", "remove"}
+	subcommands := []string{"add", "list", "publish", "remove"}
 	for _, name := range subcommands {
 		found := false
 		for _, sub := range cmd.Commands() {
@@ -0,0 +1,306 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/validation"
+)
+
+// Version parts accepted by --bump
+var bumpParts = []string{"patch", "minor", "major"}
+
+// VersionOptions holds options for the component version command
+type VersionOptions struct {
+	Components    []string
+	AllComponents bool
+	Bump          string
+	Set           string
+	Format        string
+}
+
+// componentVersionInfo is one entry of the component version listing
+type componentVersionInfo struct {
+	ID       string `json:"id"`
+	Version  string `json:"version"`
+	Previous string `json:"previous,omitempty"`
+	File     string `json:"file,omitempty"`
+}
+
+func newComponentVersionCmd() *cobra.Command {
+	opts := &VersionOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "version [component...]",
+		Short: "Show or bump the versions of project components",
+		Long: `Show the versions local components are published with, or update them.
+
+A component's version lives in the version file of its build workdir: VERSION,
+Cargo.toml, package.json or pyproject.toml. Components without one, such as Go
+components, get a VERSION file when their version is first updated.
+
+--bump increments the major, minor or patch part of the version. A pre-release
+is promoted to its release instead: a patch bump of 1.3.0-rc.1 gives 1.3.0.
+--set writes an exact version. Without either, the versions are listed.`,
+		Example: `  ftl component version
+  ftl component version weather --bump minor
+  ftl component version --all-components --bump patch
+  ftl component version calc --set 2.0.0-rc.1`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.Components = args
+			return runComponentVersion(NewDataWriter(cmd.OutOrStdout(), opts.Format), opts)
+		},
+	}
+
+	cmd.Flags().BoolVar(&opts.AllComponents, "all-components", false, "update every local component of the project")
+	cmd.Flags().StringVar(&opts.Bump, "bump", "", "version part to increment (patch, minor, major)")
+	cmd.Flags().StringVar(&opts.Set, "set", "", "version to set")
+	cmd.Flags().StringVarP(&opts.Format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+func runComponentVersion(dw *DataWriter, opts *VersionOptions) error {
+	update := opts.Bump != "" || opts.Set != ""
+	switch {
+	case opts.Bump != "" && opts.Set != "":
+		return fmt.Errorf("--bump and --set are mutually exclusive")
+	case opts.AllComponents && len(opts.Components) > 0:
+		return fmt.Errorf("specify the components or --all-components, not both")
+	case update && !opts.AllComponents && len(opts.Components) == 0:
+		return fmt.Errorf("specify the components to update or --all-components")
+	}
+	if opts.Set != "" {
+		if _, ok := parseVersion(opts.Set); !ok {
+			return fmt.Errorf("invalid version %q: expected MAJOR.MINOR.PATCH", opts.Set)
+		}
+	}
+
+	configFile := commands.FindDataConfig(".")
+	if configFile == "" {
+		return fmt.Errorf("no ftl.yaml or ftl.json found. Run 'ftl init' first")
+	}
+	app, err := commands.LoadApplication(configFile)
+	if err != nil {
+		return err
+	}
+	components, err := publishableComponents(app, opts.Components)
+	if err != nil {
+		return err
+	}
+	if len(components) == 0 {
+		return fmt.Errorf("no local components found")
+	}
+
+	configDir := filepath.Dir(configFile)
+	infos := make([]componentVersionInfo, 0, len(components))
+	for _, comp := range components {
+		info := componentVersionInfo{ID: comp.ID}
+		info.Version, info.File = commands.PublishVersion(comp, configDir, app.Version)
+		if update {
+			version := opts.Set
+			if opts.Bump != "" {
+				if version, err = bumpVersion(info.Version, opts.Bump); err != nil {
+					return fmt.Errorf("component %s: %w", comp.ID, err)
+				}
+			}
+			file, err := setComponentVersion(comp, configDir, info.File, info.Version, version)
+			if err != nil {
+				return fmt.Errorf("component %s: %w", comp.ID, err)
+			}
+			info = componentVersionInfo{ID: comp.ID, Version: version, Previous: info.Version, File: file}
+		}
+		if info.File != "" {
+			if rel, err := filepath.Rel(configDir, info.File); err == nil {
+				info.File = rel
+			}
+		}
+		infos = append(infos, info)
+	}
+
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(infos)
+	}
+	table := NewTableBuilder("COMPONENT", "VERSION", "FILE")
+	for _, info := range infos {
+		version := info.Version
+		if info.Previous != "" {
+			version = info.Previous + " -> " + info.Version
+		}
+		file := info.File
+		if file == "" {
+			file = "(project version)"
+		}
+		table.AddRow(info.ID, version, file)
+	}
+	if err := table.Write(dw); err != nil {
+		return err
+	}
+	if update {
+		Success("Updated %d component version(s)", len(infos))
+	}
+	return nil
+}
+
+// semVersion is a parsed MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD] version
+type semVersion struct {
+	prefix              string
+	major, minor, patch int
+	prerelease          string
+}
+
+// parseVersion parses a full semantic version, optionally prefixed with v
+func parseVersion(s string) (semVersion, bool) {
+	var v semVersion
+	rest := s
+	if strings.HasPrefix(rest, "v") {
+		v.prefix, rest = "v", rest[1:]
+	}
+	if i := strings.IndexByte(rest, '+'); i >= 0 {
+		rest = rest[:i]
+	}
+	if i := strings.IndexByte(rest, '-'); i >= 0 {
+		rest, v.prerelease = rest[:i], rest[i+1:]
+		if v.prerelease == "" {
+			return semVersion{}, false
+		}
+	}
+
+	parts := strings.Split(rest, ".")
+	if len(parts) != 3 {
+		return semVersion{}, false
+	}
+	nums := make([]int, 3)
+	for i, part := range parts {
+		n, err := strconv.Atoi(part)
+		if err != nil || n < 0 || (len(part) > 1 && part[0] == '0') {
+			return semVersion{}, false
+		}
+		nums[i] = n
+	}
+	v.major, v.minor, v.patch = nums[0], nums[1], nums[2]
+	return v, true
+}
+
+// bumpVersion increments a part of a semantic version. Pre-releases of the
+// version the bump leads to are promoted to it, and build metadata is dropped.
+func bumpVersion(current, part string) (string, error) {
+	v, ok := parseVersion(current)
+	if !ok {
+		return "", fmt.Errorf("can't bump %q: it is not a MAJOR.MINOR.PATCH version", current)
+	}
+
+	pre := v.prerelease != ""
+	switch part {
+	case "patch":
+		if !pre {
+			v.patch++
+		}
+	case "minor":
+		if !pre || v.patch != 0 {
+			v.minor++
+		}
+		v.patch = 0
+	case "major":
+		if !pre || v.minor != 0 || v.patch != 0 {
+			v.major++
+		}
+		v.minor, v.patch = 0, 0
+	default:
+		return "", fmt.Errorf("invalid version part %q: expected one of %s", part, strings.Join(bumpParts, ", "))
+	}
+	return fmt.Sprintf("%s%d.%d.%d", v.prefix, v.major, v.minor, v.patch), nil
+}
+
+// setComponentVersion replaces the version of a component in its version
+// file, where it is currently old, and returns the path of the file. A
+// component without a version file gets a VERSION file in its workdir.
+func setComponentVersion(comp *validation.Component, configDir, file, old, updated string) (string, error) {
+	if file == "" {
+		path, err := newVersionFile(comp, configDir)
+		if err != nil {
+			return "", err
+		}
+		return path, os.WriteFile(path, []byte(updated+"\n"), 0600)
+	}
+
+	data, err := os.ReadFile(filepath.Clean(file))
+	if err != nil {
+		return "", err
+	}
+	var replaced []byte
+	switch filepath.Base(file) {
+	case "VERSION":
+		replaced = []byte(updated + "\n")
+	case "Cargo.toml":
+		replaced = setTOMLVersion(data, []string{"package"}, old, updated)
+	case "pyproject.toml":
+		replaced = setTOMLVersion(data, []string{"project", "tool.poetry"}, old, updated)
+	case "package.json":
+		replaced = setJSONVersion(data, old, updated)
+	}
+	if replaced == nil {
+		return "", fmt.Errorf("version %s not found in %s", old, file)
+	}
+
+	info, err := os.Stat(file)
+	if err != nil {
+		return "", err
+	}
+	return file, os.WriteFile(file, replaced, info.Mode().Perm())
+}
+
+// newVersionFile returns the VERSION file to create for a component without
+// a version file. Components built in the project directory share the
+// project version, so they can't get one.
+func newVersionFile(comp *validation.Component, configDir string) (string, error) {
+	dir := commands.ComponentWorkdir(comp, configDir)
+	if filepath.Clean(dir) == filepath.Clean(configDir) {
+		return "", fmt.Errorf("it has no version file of its own and uses the project version: set its build.workdir to version it separately")
+	}
+	return filepath.Join(dir, "VERSION"), nil
+}
+
+var tomlVersionLine = regexp.MustCompile(`^(\s*version\s*=\s*)(["'])([^"']*)(["'])(.*)$`)
+
+// setTOMLVersion replaces the version key of the first of tables holding
+// old, keeping the rest of the file as is. Returns nil when none does.
+func setTOMLVersion(data []byte, tables []string, old, updated string) []byte {
+	lines := strings.SplitAfter(string(data), "\n")
+	table := ""
+	for i, line := range lines {
+		trimmed := strings.TrimSpace(line)
+		if strings.HasPrefix(trimmed, "[") {
+			table = strings.Trim(strings.SplitN(trimmed, "#", 2)[0], "[] \t")
+			continue
+		}
+		m := tomlVersionLine.FindStringSubmatch(strings.TrimRight(line, "\r\n"))
+		if m == nil || m[3] != old || m[2] != m[4] || !slices.Contains(tables, table) {
+			continue
+		}
+		lines[i] = m[1] + m[2] + updated + m[4] + m[5] + line[len(strings.TrimRight(line, "\r\n")):]
+		return []byte(strings.Join(lines, ""))
+	}
+	return nil
+}
+
+// setJSONVersion replaces the first "version": "<old>" member of a
+// package.json, keeping its formatting. Returns nil when there is none.
+func setJSONVersion(data []byte, old, updated string) []byte {
+	re := regexp.MustCompile(`("version"\s*:\s*")` + regexp.QuoteMeta(old) + `"`)
+	loc := re.FindSubmatchIndex(data)
+	if loc == nil {
+		return nil
+	}
+	replaced := append([]byte{}, data[:loc[3]]...)
+	replaced = append(replaced, updated+`"`...)
+	return append(replaced, data[loc[1]:]...)
+}
@@ -0,0 +1,156 @@
+package cli
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestBumpVersion(t *testing.T) {
+	tests := []struct {
+		current string
+		part    string
+		want    string
+	}{
+		{"1.2.3", "patch", "1.2.4"},
+		{"1.2.3", "minor", "1.3.0"},
+		{"1.2.3", "major", "2.0.0"},
+		{"v0.9.9", "minor", "v0.10.0"},
+		{"1.2.3+build.5", "patch", "1.2.4"},
+		// Pre-releases are promoted to the release they lead to
+		{"1.3.0-rc.1", "patch", "1.3.0"},
+		{"1.3.0-rc.1", "minor", "1.3.0"},
+		{"1.3.0-rc.1", "major", "2.0.0"},
+		{"1.3.1-beta", "minor", "1.4.0"},
+		{"2.0.0-alpha", "major", "2.0.0"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.current+" "+tt.part, func(t *testing.T) {
+			got, err := bumpVersion(tt.current, tt.part)
+			require.NoError(t, err)
+			assert.Equal(t, tt.want, got)
+		})
+	}
+
+	_, err := bumpVersion("1.2", "patch")
+	assert.EqualError(t, err, `can't bump "1.2": it is not a MAJOR.MINOR.PATCH version`)
+	_, err = bumpVersion("01.2.3", "patch")
+	assert.Error(t, err)
+	_, err = bumpVersion("1.2.3", "huge")
+	assert.EqualError(t, err, `invalid version part "huge": expected one of patch, minor, major`)
+}
+
+func TestSetTOMLVersion(t *testing.T) {
+	cargo := `[package]
+name = "weather"
+version = "1.2.0" # released
+edition = "2021"
+
+[dependencies]
+serde = { version = "1.2.0" }
+
+[workspace.package]
+version = "1.2.0"
+`
+	got := setTOMLVersion([]byte(cargo), []string{"package"}, "1.2.0", "1.3.0")
+	assert.Equal(t, `[package]
+name = "weather"
+version = "1.3.0" # released
+edition = "2021"
+
+[dependencies]
+serde = { version = "1.2.0" }
+
+[workspace.package]
+version = "1.2.0"
+`, string(got))
+
+	poetry := "[tool.poetry]\r\nname = 'tool'\r\nversion = '0.4.0'\r\n"
+	got = setTOMLVersion([]byte(poetry), []string{"project", "tool.poetry"}, "0.4.0", "0.5.0")
+	assert.Equal(t, "[tool.poetry]\r\nname = 'tool'\r\nversion = '0.5.0'\r\n", string(got))
+
+	assert.Nil(t, setTOMLVersion([]byte(cargo), []string{"package"}, "9.9.9", "1.3.0"))
+}
+
+func TestSetJSONVersion(t *testing.T) {
+	pkg := "{\n  \"name\": \"tool\",\n  \"version\" : \"2.0.0\",\n  \"dependencies\": {\"version\": \"2.0.0\"}\n}\n"
+	got := setJSONVersion([]byte(pkg), "2.0.0", "2.0.1")
+	assert.Equal(t, "{\n  \"name\": \"tool\",\n  \"version\" : \"2.0.1\",\n  \"dependencies\": {\"version\": \"2.0.0\"}\n}\n", string(got))
+
+	assert.Nil(t, setJSONVersion([]byte(pkg), "1.0.0", "2.0.1"))
+}
+
+func TestRunComponentVersion(t *testing.T) {
+	setupPublishTest(t)
+
+	list := func() []componentVersionInfo {
+		t.Helper()
+		var buf bytes.Buffer
+		require.NoError(t, runComponentVersion(NewDataWriter(&buf, "json"), &VersionOptions{}))
+		var infos []componentVersionInfo
+		require.NoError(t, json.Unmarshal(buf.Bytes(), &infos))
+		return infos
+	}
+	assert.Equal(t, []componentVersionInfo{
+		{ID: "weather", Version: "1.2.0", File: filepath.Join("weather", "Cargo.toml")},
+		{ID: "calc", Version: "0.3.0"},
+	}, list())
+
+	var buf bytes.Buffer
+	require.NoError(t, runComponentVersion(NewDataWriter(&buf, "table"), &VersionOptions{
+		Components: []string{"weather"},
+		Bump:       "patch",
+	}))
+	assert.Contains(t, buf.String(), "1.2.0 -> 1.2.1")
+	cargo, err := os.ReadFile(filepath.Join("weather", "Cargo.toml"))
+	require.NoError(t, err)
+	assert.Equal(t, "[package]\nname = \"weather\"\nversion = \"1.2.1\"\n", string(cargo))
+
+	// Components on the project version get a VERSION file of their own
+	require.NoError(t, runComponentVersion(NewDataWriter(&bytes.Buffer{}, "table"), &VersionOptions{
+		Components: []string{"calc"},
+		Set:        "1.0.0-rc.1",
+	}))
+	assert.Equal(t, []componentVersionInfo{
+		{ID: "weather", Version: "1.2.1", File: filepath.Join("weather", "Cargo.toml")},
+		{ID: "calc", Version: "1.0.0-rc.1", File: filepath.Join("calc", "VERSION")},
+	}, list())
+}
+
+func TestRunComponentVersion_Errors(t *testing.T) {
+	setupPublishTest(t)
+
+	tests := []struct {
+		name    string
+		opts    VersionOptions
+		wantErr string
+	}{
+		{"bump and set", VersionOptions{AllComponents: true, Bump: "patch", Set: "1.0.0"}, "--bump and --set are mutually exclusive"},
+		{"both selections", VersionOptions{Components: []string{"calc"}, AllComponents: true}, "specify the components or --all-components, not both"},
+		{"no selection", VersionOptions{Bump: "patch"}, "specify the components to update or --all-components"},
+		{"invalid version", VersionOptions{AllComponents: true, Set: "next"}, `invalid version "next": expected MAJOR.MINOR.PATCH`},
+		{"registry component", VersionOptions{Components: []string{"published"}}, "component published comes from a registry and can't be published"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := runComponentVersion(NewDataWriter(&bytes.Buffer{}, "table"), &tt.opts)
+			assert.EqualError(t, err, tt.wantErr)
+		})
+	}
+
+	// Components built in the project directory share the project version
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte(`name: test-app
+version: "0.3.0"
+access: public
+components:
+  - id: root
+    source: root.wasm
+`), 0600))
+	err := runComponentVersion(NewDataWriter(&bytes.Buffer{}, "table"), &VersionOptions{Components: []string{"root"}, Bump: "patch"})
+	assert.ErrorContains(t, err, "component root: it has no version file of its own and uses the project version")
+}
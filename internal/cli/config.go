@@ -0,0 +1,354 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/spf13/pflag"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+var (
+	// For testing - allows overriding the user config and project config location
+	loadUserConfig    = config.Load
+	projectConfigPath = func() string {
+		if path := config.FindProject("."); path != "" {
+			return path
+		}
+		return config.ProjectFile
+	}
+)
+
+// configScope selects the config file `ftl config` reads and writes
+type configScope struct {
+	global  bool
+	project bool
+}
+
+func newConfigCmd() *cobra.Command {
+	var scope configScope
+
+	var settings strings.Builder
+	for _, setting := range config.Settings {
+		_, _ = fmt.Fprintf(&settings, "  %-28s %-8s %s\n", setting.Key, setting.Type, setting.Description)
+	}
+
+	cmd := &cobra.Command{
+		Use:   "config",
+		Short: "Get and set user and project configuration",
+		Long: `Get and set FTL configuration without editing files by hand.
+
+The user config applies to every project on this machine (--global, the
+default for changes). The project config in .ftl/project.toml is shared by
+everyone working on the project (--project) and only holds command defaults.
+
+Settings:
+` + settings.String() + `
+Command defaults are set with defaults.<command>.<flag> keys, e.g.
+defaults.component.list.registry. Values are checked against the flag's type.
+User defaults take precedence over project defaults.`,
+		Example: `  ftl config set preferences.color_output false
+  ftl config set defaults.component.list.registry ghcr.io/acme
+  ftl config set --project defaults.deploy.access-control private
+  ftl config get defaults.deploy.access-control
+  ftl config unset tunnel.provider
+  ftl config list --project`,
+	}
+
+	cmd.PersistentFlags().BoolVar(&scope.global, "global", false, "Use the user config")
+	cmd.PersistentFlags().BoolVar(&scope.project, "project", false, "Use the project config")
+	cmd.MarkFlagsMutuallyExclusive("global", "project")
+
+	cmd.AddCommand(
+		newConfigGetCmd(&scope),
+		newConfigSetCmd(&scope),
+		newConfigUnsetCmd(&scope),
+		newConfigListCmd(&scope),
+	)
+
+	return cmd
+}
+
+func newConfigGetCmd(scope *configScope) *cobra.Command {
+	return &cobra.Command{
+		Use:   "get <key>",
+		Short: "Print a config value",
+		Long: `Print a config value. Without --global or --project, prints the value in
+effect: the user config, falling back to the project config.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			key := args[0]
+			if _, _, err := resolveConfigKey(cmd.Root(), key); err != nil {
+				return err
+			}
+
+			value, ok, err := getConfigValue(*scope, key)
+			if err != nil {
+				return err
+			}
+			if !ok {
+				return fmt.Errorf("%s is not set", key)
+			}
+			_, _ = fmt.Fprintln(cmd.OutOrStdout(), config.FormatValue(value))
+			return nil
+		},
+	}
+}
+
+func newConfigSetCmd(scope *configScope) *cobra.Command {
+	return &cobra.Command{
+		Use:   "set <key> <value>...",
+		Short: "Set a config value",
+		Long: `Set a config value in the user config, or the project config with --project.
+List values may be given as several arguments or comma-separated.`,
+		Args: cobra.MinimumNArgs(2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			key := args[0]
+			valueType, projectKey, err := resolveConfigKey(cmd.Root(), key)
+			if err != nil {
+				return err
+			}
+			if scope.project && !projectKey {
+				return fmt.Errorf("%s can only be set in the user config", key)
+			}
+
+			value, err := config.ParseValue(valueType, args[1:])
+			if err != nil {
+				return fmt.Errorf("invalid value for %s: %w", key, err)
+			}
+
+			path, err := updateConfig(*scope, key, func(tree map[string]interface{}, key string) error {
+				return config.SetKey(tree, key, value)
+			})
+			if err != nil {
+				return err
+			}
+			Success("Set %s = %s in %s", key, config.FormatValue(value), path)
+			return nil
+		},
+	}
+}
+
+func newConfigUnsetCmd(scope *configScope) *cobra.Command {
+	return &cobra.Command{
+		Use:   "unset <key>",
+		Short: "Remove a config value",
+		Long: `Remove a config value from the user config, or the project config with
+--project. User settings go back to their defaults.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			key := args[0]
+			_, projectKey, err := resolveConfigKey(cmd.Root(), key)
+			if err != nil {
+				return err
+			}
+			if scope.project && !projectKey {
+				return fmt.Errorf("%s can only be set in the user config", key)
+			}
+
+			found := false
+			path, err := updateConfig(*scope, key, func(tree map[string]interface{}, key string) error {
+				found = config.UnsetKey(tree, key)
+				return nil
+			})
+			if err != nil {
+				return err
+			}
+			if !found {
+				Info("%s is not set in %s", key, path)
+				return nil
+			}
+			Success("Unset %s in %s", key, path)
+			return nil
+		},
+	}
+}
+
+func newConfigListCmd(scope *configScope) *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List config values",
+		Long:  `List the values set in the user and project config, or only one of them.`,
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			out := cmd.OutOrStdout()
+			if !scope.project {
+				values, err := userConfigValues()
+				if err != nil {
+					return err
+				}
+				path, _ := config.Path()
+				writeConfigValues(out, "User config", path, values)
+			}
+			if !scope.global {
+				project, err := config.LoadProject(".")
+				if err != nil {
+					return err
+				}
+				if !scope.project {
+					_, _ = fmt.Fprintln(out)
+				}
+				writeConfigValues(out, "Project config", projectConfigPath(), projectConfigValues(project.Defaults))
+			}
+			return nil
+		},
+	}
+}
+
+// resolveConfigKey returns the value type of key and whether it can be
+// stored in the project config
+func resolveConfigKey(root *cobra.Command, key string) (config.ValueType, bool, error) {
+	if !strings.HasPrefix(key, config.DefaultsKey+".") {
+		setting, ok := config.LookupSetting(key)
+		if !ok {
+			return "", false, fmt.Errorf("unknown setting %q (see 'ftl config --help')", key)
+		}
+		return setting.Type, false, nil
+	}
+
+	path := strings.Split(strings.TrimPrefix(key, config.DefaultsKey+"."), ".")
+	if len(path) < 2 {
+		return "", false, fmt.Errorf("command defaults are set as %s.<command>.<flag>", config.DefaultsKey)
+	}
+
+	cmd := root
+	for _, name := range path[:len(path)-1] {
+		var next *cobra.Command
+		for _, sub := range cmd.Commands() {
+			if sub.Name() == name {
+				next = sub
+				break
+			}
+		}
+		if next == nil {
+			return "", false, fmt.Errorf("unknown command 'ftl %s'", strings.Join(path[:len(path)-1], " "))
+		}
+		cmd = next
+	}
+
+	name := path[len(path)-1]
+	flag := cmd.Flags().Lookup(name)
+	if flag == nil {
+		flag = cmd.InheritedFlags().Lookup(name)
+	}
+	if flag == nil || name == "help" {
+		return "", false, fmt.Errorf("unknown flag --%s for '%s'", name, cmd.CommandPath())
+	}
+	return flagValueType(flag), true, nil
+}
+
+// flagValueType returns the config value type that sets flag
+func flagValueType(flag *pflag.Flag) config.ValueType {
+	if _, ok := flag.Value.(pflag.SliceValue); ok {
+		return config.TypeList
+	}
+	switch flag.Value.Type() {
+	case "bool":
+		return config.TypeBool
+	case "int", "int8", "int16", "int32", "int64", "uint", "uint8", "uint16", "uint32", "uint64", "count":
+		return config.TypeInt
+	case "float32", "float64":
+		return config.TypeFloat
+	case "duration":
+		return config.TypeDuration
+	default:
+		return config.TypeString
+	}
+}
+
+// getConfigValue looks key up in the config selected by scope
+func getConfigValue(scope configScope, key string) (interface{}, bool, error) {
+	if !scope.project {
+		values, err := userConfigValues()
+		if err != nil {
+			return nil, false, err
+		}
+		if value, ok := values[key]; ok || scope.global {
+			return value, ok, nil
+		}
+	}
+
+	project, err := config.LoadProject(".")
+	if err != nil {
+		return nil, false, err
+	}
+	value, ok := projectConfigValues(project.Defaults)[key]
+	return value, ok, nil
+}
+
+// updateConfig applies change to key in the config selected by scope, saves
+// it and returns its path
+func updateConfig(scope configScope, key string, change func(tree map[string]interface{}, key string) error) (string, error) {
+	if scope.project {
+		project, err := config.LoadProject(".")
+		if err != nil {
+			return "", err
+		}
+		defaults := project.Defaults
+		if defaults == nil {
+			defaults = map[string]interface{}{}
+		}
+		if err := change(defaults, strings.TrimPrefix(key, config.DefaultsKey+".")); err != nil {
+			return "", err
+		}
+		path := projectConfigPath()
+		return path, config.SaveProject(path, defaults)
+	}
+
+	cfg, err := loadUserConfig()
+	if err != nil {
+		return "", err
+	}
+	tree, err := cfg.Values()
+	if err != nil {
+		return "", err
+	}
+	if err := change(tree, key); err != nil {
+		return "", err
+	}
+	if err := cfg.SetValues(tree); err != nil {
+		return "", err
+	}
+	path, _ := config.Path()
+	return path, nil
+}
+
+// userConfigValues returns the user settings and command defaults by key,
+// leaving out state managed by other commands
+func userConfigValues() (map[string]interface{}, error) {
+	cfg, err := loadUserConfig()
+	if err != nil {
+		return nil, err
+	}
+	tree, err := cfg.Values()
+	if err != nil {
+		return nil, err
+	}
+
+	values := map[string]interface{}{}
+	for key, value := range config.Flatten(tree) {
+		if _, ok := config.LookupSetting(key); ok || strings.HasPrefix(key, config.DefaultsKey+".") {
+			values[key] = value
+		}
+	}
+	return values, nil
+}
+
+// projectConfigValues returns project command defaults by key
+func projectConfigValues(defaults map[string]interface{}) map[string]interface{} {
+	values := map[string]interface{}{}
+	for key, value := range config.Flatten(defaults) {
+		values[config.DefaultsKey+"."+key] = value
+	}
+	return values
+}
+
+func writeConfigValues(out io.Writer, title, path string, values map[string]interface{}) {
+	_, _ = fmt.Fprintf(out, "# %s (%s)\n", title, path)
+	for _, key := range config.SortedKeys(values) {
+		_, _ = fmt.Fprintf(out, "%s = %s\n", key, config.FormatValue(values[key]))
+	}
+}
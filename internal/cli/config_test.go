@@ -0,0 +1,172 @@
+package cli
+
+import (
+	"bytes"
+	"os"
+	"testing"
+
+	"github.com/spf13/cobra"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// withConfigDirs points the user config and project at temporary directories
+func withConfigDirs(t *testing.T) (*config.Config, string) {
+	t.Helper()
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+
+	project := t.TempDir()
+	oldWd, err := os.Getwd()
+	require.NoError(t, err)
+	require.NoError(t, os.Chdir(project))
+
+	cfg := &config.Config{Version: "1.0"}
+	oldLoad := loadUserConfig
+	loadUserConfig = func() (*config.Config, error) { return cfg, nil }
+	t.Cleanup(func() {
+		loadUserConfig = oldLoad
+		_ = os.Chdir(oldWd)
+	})
+	return cfg, project
+}
+
+// runConfigCmd runs "ftl config" under a root with a deploy command
+func runConfigCmd(t *testing.T, args ...string) (string, error) {
+	t.Helper()
+	root := &cobra.Command{Use: "ftl"}
+	deploy := &cobra.Command{Use: "deploy", RunE: func(*cobra.Command, []string) error { return nil }}
+	deploy.Flags().String("access-control", "", "")
+	deploy.Flags().Bool("yes", false, "")
+	deploy.Flags().Int("jobs", 1, "")
+	deploy.Flags().StringSlice("allowed-roles", nil, "")
+	root.AddCommand(deploy, newConfigCmd())
+
+	var out bytes.Buffer
+	root.SetOut(&out)
+	root.SetErr(&out)
+	root.SetArgs(append([]string{"config"}, args...))
+	err := root.Execute()
+	return out.String(), err
+}
+
+func TestConfigCommand(t *testing.T) {
+	cmd := newConfigCmd()
+	assert.Equal(t, "config", cmd.Use)
+
+	names := make([]string, 0, len(cmd.Commands()))
+	for _, sub := range cmd.Commands() {
+		names = append(names, sub.Name())
+	}
+	assert.ElementsMatch(t, []string{"get", "set", "unset", "list"}, names)
+	assert.Contains(t, cmd.Long, "telemetry.disabled")
+}
+
+func TestConfigSet_Global(t *testing.T) {
+	cfg, _ := withConfigDirs(t)
+
+	_, err := runConfigCmd(t, "set", "preferences.color_output", "false")
+	require.NoError(t, err)
+	assert.False(t, cfg.Preferences.ColorOutput)
+
+	_, err = runConfigCmd(t, "set", "tunnel.command", "bore,local", "{port}")
+	require.NoError(t, err)
+	require.NotNil(t, cfg.Tunnel)
+	assert.Equal(t, []string{"bore", "local", "{port}"}, cfg.Tunnel.Command)
+
+	_, err = runConfigCmd(t, "set", "defaults.deploy.jobs", "4")
+	require.NoError(t, err)
+	value, ok := config.LookupDefault(cfg.Defaults, []string{"deploy"}, "jobs")
+	assert.True(t, ok)
+	assert.EqualValues(t, 4, value)
+
+	out, err := runConfigCmd(t, "get", "defaults.deploy.jobs")
+	require.NoError(t, err)
+	assert.Equal(t, "4\n", out)
+
+	// Saved to the user config file
+	path, err := config.Path()
+	require.NoError(t, err)
+	assert.NoError(t, config.Validate(path))
+}
+
+func TestConfigSet_Validation(t *testing.T) {
+	withConfigDirs(t)
+
+	tests := []struct {
+		args []string
+		err  string
+	}{
+		{[]string{"set", "preferences.color_output", "sometimes"}, `invalid bool "sometimes"`},
+		{[]string{"set", "defaults.deploy.jobs", "many"}, `invalid integer "many"`},
+		{[]string{"set", "unknown.key", "x"}, `unknown setting "unknown.key"`},
+		{[]string{"set", "defaults.deploy.force", "true"}, "unknown flag --force for 'ftl deploy'"},
+		{[]string{"set", "defaults.publish.yes", "true"}, "unknown command 'ftl publish'"},
+		{[]string{"set", "--project", "tunnel.provider", "bore"}, "can only be set in the user config"},
+		{[]string{"get", "--global", "--project", "tunnel.provider"}, "none of the others can be"},
+	}
+
+	for _, tt := range tests {
+		_, err := runConfigCmd(t, tt.args...)
+		require.Error(t, err, "%v", tt.args)
+		assert.Contains(t, err.Error(), tt.err)
+	}
+}
+
+func TestConfigSet_Project(t *testing.T) {
+	cfg, project := withConfigDirs(t)
+
+	_, err := runConfigCmd(t, "set", "--project", "defaults.deploy.access-control", "private")
+	require.NoError(t, err)
+	_, err = runConfigCmd(t, "set", "--project", "defaults.deploy.allowed-roles", "admin,dev")
+	require.NoError(t, err)
+	assert.Nil(t, cfg.Defaults)
+
+	loaded, err := config.LoadProject(project)
+	require.NoError(t, err)
+	value, _ := loaded.Lookup([]string{"deploy"}, "allowed-roles")
+	assert.Equal(t, []interface{}{"admin", "dev"}, value)
+
+	// The value in effect falls back to the project config
+	out, err := runConfigCmd(t, "get", "defaults.deploy.access-control")
+	require.NoError(t, err)
+	assert.Equal(t, "private\n", out)
+
+	// User defaults take precedence
+	_, err = runConfigCmd(t, "set", "defaults.deploy.access-control", "org")
+	require.NoError(t, err)
+	out, err = runConfigCmd(t, "get", "defaults.deploy.access-control")
+	require.NoError(t, err)
+	assert.Equal(t, "org\n", out)
+	out, err = runConfigCmd(t, "get", "--project", "defaults.deploy.access-control")
+	require.NoError(t, err)
+	assert.Equal(t, "private\n", out)
+
+	out, err = runConfigCmd(t, "list")
+	require.NoError(t, err)
+	assert.Contains(t, out, "# User config")
+	assert.Contains(t, out, "defaults.deploy.access-control = org")
+	assert.Contains(t, out, "defaults.deploy.allowed-roles = admin,dev")
+	assert.NotContains(t, out, "version")
+
+	_, err = runConfigCmd(t, "unset", "--project", "defaults.deploy.access-control")
+	require.NoError(t, err)
+	_, err = runConfigCmd(t, "get", "--project", "defaults.deploy.access-control")
+	assert.ErrorContains(t, err, "is not set")
+}
+
+func TestConfigUnset_RestoresDefault(t *testing.T) {
+	cfg, _ := withConfigDirs(t)
+
+	_, err := runConfigCmd(t, "set", "telemetry.disabled", "true")
+	require.NoError(t, err)
+	require.NotNil(t, cfg.Telemetry)
+	assert.True(t, cfg.Telemetry.Disabled)
+
+	_, err = runConfigCmd(t, "set", "preferences.confirm_deploy", "false")
+	require.NoError(t, err)
+	_, err = runConfigCmd(t, "unset", "preferences.confirm_deploy")
+	require.NoError(t, err)
+	assert.True(t, cfg.Preferences.ConfirmDeploy)
+}
@@ -0,0 +1,144 @@
+package cli
+
+import (
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/spf13/pflag"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// defaultsLayer is a source of command flag defaults
+type defaultsLayer struct {
+	source   string
+	defaults map[string]interface{}
+}
+
+var (
+	// For testing - allows overriding where command defaults are loaded from
+	loadProjectConfig = func() (*config.ProjectConfig, error) {
+		return config.LoadProject(".")
+	}
+	userCommandDefaults = func() map[string]interface{} {
+		cfg, err := config.Load()
+		if err != nil {
+			return nil
+		}
+		return cfg.Defaults
+	}
+)
+
+// applyCommandDefaults sets flags that were not given on the command line
+// from the user config, falling back to the project config. Flags always win,
+// then user defaults, then project defaults.
+func applyCommandDefaults(cmd *cobra.Command) error {
+	project, err := loadProjectConfig()
+	if err != nil {
+		return err
+	}
+
+	// Highest precedence first
+	layers := []defaultsLayer{
+		{source: "user config", defaults: userCommandDefaults()},
+		{source: project.Path, defaults: project.Defaults},
+	}
+
+	path := strings.Fields(cmd.CommandPath())[1:]
+	flags := cmd.Flags()
+
+	for _, layer := range layers {
+		for _, name := range defaultFlagNames(layer.defaults, path) {
+			if flags.Lookup(name) == nil {
+				Warn("Ignoring unknown flag %q for 'ftl %s' in %s", name, strings.Join(path, " "), layer.source)
+			}
+		}
+	}
+
+	var applyErr error
+	flags.VisitAll(func(flag *pflag.Flag) {
+		if applyErr != nil || flag.Changed || flag.Name == "help" {
+			return
+		}
+		for _, layer := range layers {
+			value, ok := config.LookupDefault(layer.defaults, path, flag.Name)
+			if !ok {
+				continue
+			}
+			if err := setFlagDefault(flag, value); err != nil {
+				applyErr = fmt.Errorf("invalid default for --%s in %s: %w", flag.Name, layer.source, err)
+				return
+			}
+			Debug("Using --%s=%s from %s", flag.Name, flag.Value.String(), layer.source)
+			return
+		}
+	})
+	return applyErr
+}
+
+// defaultFlagNames returns the flag names set for the command at path
+func defaultFlagNames(defaults map[string]interface{}, path []string) []string {
+	table := defaults
+	for _, name := range path {
+		next, ok := table[name].(map[string]interface{})
+		if !ok {
+			return nil
+		}
+		table = next
+	}
+
+	var names []string
+	for name, value := range table {
+		if _, isTable := value.(map[string]interface{}); !isTable {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+	return names
+}
+
+// setFlagDefault sets a flag from a TOML or JSON value without marking it as
+// changed, so commands can still tell whether it was given explicitly
+func setFlagDefault(flag *pflag.Flag, value interface{}) error {
+	if list, ok := value.([]interface{}); ok {
+		values := make([]string, 0, len(list))
+		for _, item := range list {
+			s, err := defaultString(item)
+			if err != nil {
+				return err
+			}
+			values = append(values, s)
+		}
+		if slice, ok := flag.Value.(pflag.SliceValue); ok {
+			return slice.Replace(values)
+		}
+		return flag.Value.Set(strings.Join(values, ","))
+	}
+
+	s, err := defaultString(value)
+	if err != nil {
+		return err
+	}
+	if slice, ok := flag.Value.(pflag.SliceValue); ok {
+		return slice.Replace([]string{s})
+	}
+	return flag.Value.Set(s)
+}
+
+func defaultString(value interface{}) (string, error) {
+	switch v := value.(type) {
+	case string:
+		return v, nil
+	case bool:
+		return strconv.FormatBool(v), nil
+	case int64:
+		return strconv.FormatInt(v, 10), nil
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64), nil
+	default:
+		return "", fmt.Errorf("unsupported value %v", value)
+	}
+}
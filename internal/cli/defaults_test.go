@@ -0,0 +1,114 @@
+package cli
+
+import (
+	"testing"
+
+	"github.com/spf13/cobra"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+func withCommandDefaults(t *testing.T, user, project map[string]interface{}) {
+	t.Helper()
+	oldProject, oldUser := loadProjectConfig, userCommandDefaults
+	loadProjectConfig = func() (*config.ProjectConfig, error) {
+		return &config.ProjectConfig{Path: ".ftl/project.toml", Defaults: project}, nil
+	}
+	userCommandDefaults = func() map[string]interface{} { return user }
+	t.Cleanup(func() {
+		loadProjectConfig, userCommandDefaults = oldProject, oldUser
+	})
+}
+
+// defaultsTestCmd builds "ftl deploy" with a few flags of different types
+func defaultsTestCmd() (*cobra.Command, *cobra.Command) {
+	root := &cobra.Command{Use: "ftl"}
+	deploy := &cobra.Command{Use: "deploy", RunE: func(*cobra.Command, []string) error { return nil }}
+	deploy.Flags().String("access-control", "", "")
+	deploy.Flags().String("environment", "production", "")
+	deploy.Flags().Bool("yes", false, "")
+	deploy.Flags().Int("jobs", 1, "")
+	deploy.Flags().StringSlice("allowed-roles", nil, "")
+	root.AddCommand(deploy)
+	return root, deploy
+}
+
+func TestApplyCommandDefaults_Project(t *testing.T) {
+	withCommandDefaults(t, nil, map[string]interface{}{
+		"deploy": map[string]interface{}{
+			"access-control": "private",
+			"yes":            true,
+			"jobs":           int64(4),
+			"allowed-roles":  []interface{}{"admin", "dev"},
+		},
+	})
+
+	_, deploy := defaultsTestCmd()
+	require.NoError(t, applyCommandDefaults(deploy))
+
+	flags := deploy.Flags()
+	access, _ := flags.GetString("access-control")
+	assert.Equal(t, "private", access)
+	yes, _ := flags.GetBool("yes")
+	assert.True(t, yes)
+	jobs, _ := flags.GetInt("jobs")
+	assert.Equal(t, 4, jobs)
+	roles, _ := flags.GetStringSlice("allowed-roles")
+	assert.Equal(t, []string{"admin", "dev"}, roles)
+
+	// Defaults are not reported as explicitly set
+	assert.False(t, flags.Changed("access-control"))
+	environment, _ := flags.GetString("environment")
+	assert.Equal(t, "production", environment)
+}
+
+func TestApplyCommandDefaults_Precedence(t *testing.T) {
+	withCommandDefaults(t,
+		map[string]interface{}{
+			"deploy": map[string]interface{}{"environment": "staging", "jobs": float64(8)},
+		},
+		map[string]interface{}{
+			"deploy": map[string]interface{}{"environment": "production", "access-control": "org", "jobs": int64(4)},
+		},
+	)
+
+	root, deploy := defaultsTestCmd()
+	root.SetArgs([]string{"deploy", "--access-control", "public"})
+	root.PersistentPreRunE = func(cmd *cobra.Command, args []string) error {
+		return applyCommandDefaults(cmd)
+	}
+	require.NoError(t, root.Execute())
+
+	flags := deploy.Flags()
+	access, _ := flags.GetString("access-control")
+	assert.Equal(t, "public", access, "flags override all defaults")
+	environment, _ := flags.GetString("environment")
+	assert.Equal(t, "staging", environment, "user config overrides project config")
+	jobs, _ := flags.GetInt("jobs")
+	assert.Equal(t, 8, jobs)
+}
+
+func TestApplyCommandDefaults_InvalidValue(t *testing.T) {
+	withCommandDefaults(t, nil, map[string]interface{}{
+		"deploy": map[string]interface{}{"jobs": "many"},
+	})
+
+	_, deploy := defaultsTestCmd()
+	err := applyCommandDefaults(deploy)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "--jobs")
+	assert.Contains(t, err.Error(), ".ftl/project.toml")
+}
+
+func TestApplyCommandDefaults_OtherCommands(t *testing.T) {
+	withCommandDefaults(t, nil, map[string]interface{}{
+		"build": map[string]interface{}{"yes": true},
+	})
+
+	_, deploy := defaultsTestCmd()
+	require.NoError(t, applyCommandDefaults(deploy))
+	yes, _ := deploy.Flags().GetBool("yes")
+	assert.False(t, yes)
+}
@@ -2,6 +2,7 @@ package cli
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"os"
 
@@ -16,19 +17,24 @@ import (
 
 func newDeleteCmd() *cobra.Command {
 	var force bool
+	var confirm string
 
 	cmd := &cobra.Command{
 		Use:   "delete <app-id|app-name>",
 		Short: "Delete an FTL application",
-		Long:  `Delete an FTL application from the platform.`,
+		Long: `Delete an FTL application from the platform.
+
+Deleting a protected engine (see 'ftl eng protect') always requires typing its
+name, or passing it with --confirm. --force does not skip this confirmation.`,
 		Args:  cobra.ExactArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
 			ctx := context.Background()
-			return runDelete(ctx, args[0], force)
+			return runDelete(ctx, args[0], force, confirm)
 		},
 	}
 
 	cmd.Flags().BoolVarP(&force, "force", "f", false, "Force deletion without confirmation")
+	cmd.Flags().StringVar(&confirm, "confirm", "", "Application name, to delete a protected engine without a prompt")
 
 	return cmd
 }
@@ -36,7 +42,7 @@ func newDeleteCmd() *cobra.Command {
 // Allow overriding for tests
 var runDelete = runDeleteImpl
 
-func runDeleteImpl(ctx context.Context, appIdentifier string, force bool) error {
+func runDeleteImpl(ctx context.Context, appIdentifier string, force bool, confirm string) error {
 	// Initialize auth manager
 	store, err := auth.NewKeyringStore()
 	if err != nil {
@@ -62,6 +68,9 @@ func runDeleteImpl(ctx context.Context, appIdentifier string, force bool) error
 	if _, err := uuid.Parse(appIdentifier); err == nil {
 		// It's a UUID, get app directly
 		app, err = apiClient.GetApp(ctx, appIdentifier)
+		if errors.Is(err, api.ErrNotFound) {
+			return fmt.Errorf("application '%s' not found", appIdentifier)
+		}
 		if err != nil {
 			return fmt.Errorf("failed to get app: %w", err)
 		}
@@ -95,13 +104,22 @@ func runDeleteImpl(ctx context.Context, appIdentifier string, force bool) error
 	if app.ProviderUrl != nil && *app.ProviderUrl != "" {
 		kvb.Add("URL", *app.ProviderUrl)
 	}
+	protected := app.Protected != nil && *app.Protected
+	if protected {
+		kvb.Add("Protected", "yes")
+	}
 	if err := kvb.Write(dw); err != nil {
 		return fmt.Errorf("failed to display app details: %w", err)
 	}
 
-	// Ask for confirmation unless --force is used
-	if !force {
-		// Check if we're in an interactive terminal
+	if protected {
+		// Protected engines always need the engine name, even with --force
+		if err := confirmProtectedEngine(app.AppName, confirm, "delete"); err != nil {
+			return err
+		}
+	} else if !force {
+		// Ask for confirmation unless --force is used. Check if we're in an
+		// interactive terminal
 		if !isInteractive() {
 			return fmt.Errorf("deletion requires confirmation. Use --force to skip confirmation in non-interactive mode")
 		}
@@ -14,11 +14,13 @@ import (
 	"github.com/fatih/color"
 	"github.com/spf13/cobra"
 
+	"github.com/fastertools/ftl/commands"
 	"github.com/fastertools/ftl/internal/api"
 	"github.com/fastertools/ftl/internal/auth"
 	"github.com/fastertools/ftl/internal/config"
 	"github.com/fastertools/ftl/internal/deploy"
 	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/spin"
 	"github.com/fastertools/ftl/validation"
 )
 
@@ -34,6 +36,15 @@ type DeployOptions struct {
 	AllowedRoles  []string
 	Variables     map[string]string
 	OrgID         string // Explicitly specify organization ID
+	ChangeReason  string // Recorded with the deployment
+	Confirm       string // Engine name, confirms deploying to a protected engine
+	AllowPublic   bool   // Allow --access-control public on a protected engine
+	Strict        bool   // Fail when a configured URL is not covered by allowed_outbound_hosts
+	Strategy      string // direct or blue-green
+	ProbeCalls    []string
+	ProbeTimeout  time.Duration
+	AutoRollback  bool   // Roll back the changes of a failed deploy without asking
+	Name          string // Engine to deploy to instead of the configured application name
 }
 
 func newDeployCmd() *cobra.Command {
@@ -54,12 +65,25 @@ This command:
 5. Sends the FTL config to the platform for deployment
 6. Platform synthesizes Spin manifest and deploys
 
+Each step that changes the platform is logged. When a deploy fails, it offers
+to roll them back: the app is deleted if the deploy created it, and the
+previous deployment is restored if the deploy changed the app's access control
+or auth config. --auto-rollback rolls back without asking. What could not be
+rolled back is reported and kept in .ftl/transactions/<app>.json.
+
 Example:
   ftl deploy
   ftl deploy --access-control private
   ftl deploy --jwt-issuer https://auth.example.com --jwt-audience api.example.com
-  ftl deploy --dry-run`,
+  ftl deploy --dry-run
+  ftl deploy --confirm my-app --change-reason "Rotate signing keys"
+  ftl deploy --strategy blue-green --probe-call 'echo__ping={"message":"hi"}'
+  ftl deploy --yes --auto-rollback`,
 		RunE: func(cmd *cobra.Command, args []string) error {
+			probeFlagsSet := len(opts.ProbeCalls) > 0 || cmd.Flags().Changed("probe-timeout")
+			if err := validateDeployStrategy(opts, probeFlagsSet); err != nil {
+				return err
+			}
 			ctx := context.Background()
 			return runDeploy(ctx, opts)
 		},
@@ -75,11 +99,19 @@ Example:
 	cmd.Flags().StringSliceVar(&opts.AllowedRoles, "allowed-roles", nil, "Allowed roles for org mode")
 	cmd.Flags().StringToStringVar(&opts.Variables, "var", nil, "Set variable (can be used multiple times)")
 	cmd.Flags().StringVar(&opts.OrgID, "org", "", "Organization ID for deployment (uses interactive selection if not specified)")
+	cmd.Flags().StringVar(&opts.ChangeReason, "change-reason", "", "Reason for the change, recorded with the deployment")
+	cmd.Flags().StringVar(&opts.Confirm, "confirm", "", "Engine name, to deploy to a protected engine without a prompt")
+	cmd.Flags().BoolVar(&opts.AllowPublic, "allow-public", false, "Allow --access-control public on a protected engine")
+	cmd.Flags().BoolVar(&opts.Strict, "strict", false, "Fail when a configured URL is not covered by allowed_outbound_hosts")
+	cmd.Flags().StringVar(&opts.Strategy, "strategy", strategyDirect, "Deployment strategy (direct, blue-green)")
+	cmd.Flags().StringArrayVar(&opts.ProbeCalls, "probe-call", nil, "Tool call the new deployment must pass before taking traffic, as TOOL or TOOL=JSON_ARGS (blue-green, repeatable)")
+	cmd.Flags().DurationVar(&opts.ProbeTimeout, "probe-timeout", defaultBlueGreenProbeTimeout, "How long the new deployment has to become healthy (blue-green)")
+	cmd.Flags().BoolVar(&opts.AutoRollback, "auto-rollback", false, "Roll back the changes of a failed deploy without asking")
 
 	return cmd
 }
 
-func runDeploy(ctx context.Context, opts *DeployOptions) error {
+func runDeploy(ctx context.Context, opts *DeployOptions) (err error) {
 	// Auto-detect config file if not specified
 	if opts.ConfigFile == "" {
 		for _, file := range []string{"ftl.yaml", "ftl.yml", "ftl.json", "app.cue"} {
@@ -89,6 +121,9 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 			}
 		}
 		if opts.ConfigFile == "" {
+			if _, err := os.Stat("spin.toml"); err == nil {
+				return fmt.Errorf("no FTL configuration file found (ftl.yaml, ftl.json, or app.cue), run 'ftl adopt' to convert spin.toml into ftl.yaml")
+			}
 			return fmt.Errorf("no FTL configuration file found (ftl.yaml, ftl.json, or app.cue)")
 		}
 	}
@@ -107,6 +142,9 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 	}
 
 	// Apply command-line overrides
+	if opts.Name != "" {
+		manifest.Name = opts.Name
+	}
 	if opts.AccessControl != "" {
 		manifest.Access = opts.AccessControl
 	}
@@ -120,10 +158,20 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 		}
 	}
 
+	// Every required variable must be provided with --var
+	if missing := manifest.MissingVariables(opts.Variables); len(missing) > 0 {
+		return fmt.Errorf("missing required variables: %s (set them with --var name=value)", strings.Join(missing, ", "))
+	}
+
+	// Tools can't reach hosts missing from allowed_outbound_hosts at runtime
+	if err := commands.CheckOutboundHosts(manifest, opts.ConfigFile, opts.Variables, opts.Strict, printProgress); err != nil {
+		return err
+	}
+
 	// Run spin build to build all local components
 	if !opts.DryRun {
 		Info("Building local components with 'spin build'")
-		cmd := ExecCommand("spin", "build")
+		cmd := ExecCommand(spin.DefaultBinary(), "build")
 		cmd.Stdout = os.Stdout
 		cmd.Stderr = os.Stderr
 		if err := cmd.Run(); err != nil {
@@ -175,9 +223,33 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 		return fmt.Errorf("failed to create API client: %w", err)
 	}
 
+	// Blue-green deploys go to a standby engine next to the live one
+	var blueGreen *blueGreenDeploy
+	if opts.Strategy == strategyBlueGreen {
+		blueGreen, err = prepareBlueGreen(ctx, apiClient, manifest.Name, opts)
+		if err != nil {
+			return err
+		}
+		if manifest.Access != "" && manifest.Access != "public" {
+			if blueGreen.token, err = authManager.GetToken(ctx); err != nil {
+				return fmt.Errorf("failed to get token for health probes: %w", err)
+			}
+		}
+		manifest.Name = blueGreen.green
+		Info("Deploying to %s, %s is switched to it once healthy", blueGreen.green, blueGreen.live)
+	}
+
 	// Check if app exists
 	appName := manifest.Name
 
+	// Log the steps that change the platform, to roll them back if the deploy fails
+	txn := newDeployTransaction(".", appName)
+	defer func() {
+		if err != nil {
+			err = txn.rollback(ctx, apiClient, err, opts.AutoRollback)
+		}
+	}()
+
 	// Add spinner for potentially slow API call (cold starts)
 	sp := spinner.New(spinner.CharSets[14], 100*time.Millisecond)
 	sp.Suffix = " Checking for existing app..."
@@ -194,12 +266,21 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 
 	var appID string
 	var existingAccess string
+	var protected bool
+	var createdApp bool
 	appExists := len(apps.Apps) > 0
 	if appExists {
 		appID = apps.Apps[0].AppId.String()
 		if apps.Apps[0].AccessControl != nil {
 			existingAccess = string(*apps.Apps[0].AccessControl)
 		}
+		protected = apps.Apps[0].Protected != nil && *apps.Apps[0].Protected
+	}
+
+	if protected {
+		if err := checkProtectedAccess(appName, opts.AccessControl, opts.AllowPublic); err != nil {
+			return err
+		}
 	}
 
 	// For org-scoped apps, we need to select the org BEFORE showing preview
@@ -228,6 +309,8 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 			}
 			appID = createResp.AppId.String()
 			appExists = true // Mark as exists now
+			createdApp = true
+			txn.record(stepAppCreated, appID, fmt.Sprintf("created app %s (%s)", appName, appID))
 			Success("App prepared with ID: %s", appID)
 		}
 
@@ -293,6 +376,7 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 
 	// NOW build deployment preview with complete information
 	preview := BuildDeploymentPreviewWithOrg(manifest, opts, appID, existingAccess, selectedOrgID, selectedOrgName)
+	preview.Protected = protected
 
 	// Show preview and get confirmation
 	confirmed, err := ConfirmDeployment(preview, opts.Yes)
@@ -303,6 +387,13 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 		return fmt.Errorf("deployment cancelled by user")
 	}
 
+	// Protected engines always need the engine name, even with --yes
+	if protected {
+		if err := confirmProtectedEngine(appName, opts.Confirm, "deploy to"); err != nil {
+			return err
+		}
+	}
+
 	// Create app if it doesn't exist and wasn't already created for org selection
 	if !appExists {
 		Info("Creating app on FTL platform...")
@@ -325,6 +416,8 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 			return fmt.Errorf("failed to create app: %w", err)
 		}
 		appID = createResp.AppId.String()
+		createdApp = true
+		txn.record(stepAppCreated, appID, fmt.Sprintf("created app %s (%s)", appName, appID))
 		Success("App created with ID: %s", appID)
 	}
 
@@ -359,7 +452,7 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 	namespace := creds.Registry.PackageNamespace
 
 	Info("Processing components...")
-	processedManifest, err := processComponents(ctx, manifest, ecrAuth, namespace)
+	processedManifest, pins, err := processComponents(ctx, manifest, ecrAuth, namespace, txn)
 	if err != nil {
 		return fmt.Errorf("failed to process components: %w", err)
 	}
@@ -370,12 +463,22 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 	Info("Deploying application...")
 
 	// Create flat deployment request
-	deploymentReq := createDeploymentRequest(processedManifest, opts)
+	deploymentReq, err := createDeploymentRequest(processedManifest, opts)
+	if err != nil {
+		return err
+	}
 	deploymentJSON, err := json.Marshal(deploymentReq)
 	if err != nil {
 		return fmt.Errorf("failed to marshal deployment request: %w", err)
 	}
 
+	// The deployment replaces the access control and auth config of an existing app
+	if !createdApp {
+		if change := authChangeDescription(appName, txn.previous, existingAccess, deploymentReq); change != "" {
+			txn.record(stepAuthChanged, appID, change)
+		}
+	}
+
 	// Create streaming deployer
 	deployer := deploy.NewStreamingDeployer()
 
@@ -385,6 +488,7 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 	sp.Start()
 
 	var deploymentURL string
+	var deploymentID string
 
 	// Prepare deployment options with org context
 	deployOpts := deploy.DeployOptions{
@@ -398,6 +502,7 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 			sp.Suffix = fmt.Sprintf(" %s", event.Message)
 		case "complete":
 			deploymentURL = event.URL
+			deploymentID = event.DeploymentID
 			sp.Stop()
 			Success("Deployment completed successfully!")
 			if event.DeploymentID != "" {
@@ -406,14 +511,51 @@ func runDeploy(ctx context.Context, opts *DeployOptions) error {
 		case "error":
 			sp.Stop()
 			Error("Deployment failed: %s", event.Message)
+			if event.DeploymentID != "" {
+				deploymentID = event.DeploymentID
+			}
 		}
 	})
 
+	// Report the webhook notifications of the deployment's lifecycle events
+	if deploymentID != "" {
+		showNotificationDeliveries(ctx, apiClient, appID, deploymentID)
+	}
+
 	if err != nil {
 		sp.Stop()
 		return fmt.Errorf("deployment failed: %w", err)
 	}
 
+	// Record the pinned components so the deployment can be promoted with 'ftl eng promote'
+	record := &deploy.Record{
+		App:          appName,
+		AppID:        appID,
+		OrgID:        selectedOrgID,
+		Environment:  opts.Environment,
+		DeploymentID: deploymentID,
+		DeployedAt:   time.Now().UTC(),
+		ChangeReason: opts.ChangeReason,
+		Components:   pins,
+		Request:      deploymentReq,
+	}
+	recordPath, err := deploy.SaveRecord(".", record)
+	if err != nil {
+		Warn("Failed to save deployment record: %v", err)
+	} else {
+		Debug("Saved deployment record to %s", recordPath)
+	}
+	txn.commit()
+
+	// Switch the live engine to the new deployment once it passes its probes
+	if blueGreen != nil {
+		fmt.Println()
+		deploymentURL, err = runBlueGreenCutover(ctx, apiClient, blueGreen, record, deploymentURL)
+		if err != nil {
+			return err
+		}
+	}
+
 	if deploymentURL != "" {
 		// Display MCP URLs for the deployed application
 		displayMCPUrls(deploymentURL, processedManifest.Components)
@@ -434,7 +576,7 @@ func loadDeployManifest(configFile string) (*validation.Application, error) {
 	v := validation.New()
 	validatedValue, err := v.ValidateYAML(data)
 	if err != nil {
-		return nil, fmt.Errorf("failed to validate manifest: %w", err)
+		return nil, migrationHint(data, fmt.Errorf("failed to validate manifest: %w", err))
 	}
 
 	return validation.ExtractApplication(validatedValue)
@@ -448,8 +590,9 @@ func runSynth(ctx context.Context, configFile string) error {
 	return cmd.Run()
 }
 
-// processComponents handles pulling registry components and pushing everything to ECR
-func processComponents(ctx context.Context, manifest *validation.Application, ecrAuth *oci.ECRAuth, namespace string) (*validation.Application, error) {
+// processComponents handles pulling registry components and pushing everything to ECR.
+// It also returns the digest each component was pushed with.
+func processComponents(ctx context.Context, manifest *validation.Application, ecrAuth *oci.ECRAuth, namespace string, txn *deployTransaction) (*validation.Application, []deploy.ComponentPin, error) {
 	// Create output manifest with ECR references
 	processedManifest := &validation.Application{
 		Name:        manifest.Name,
@@ -460,9 +603,10 @@ func processComponents(ctx context.Context, manifest *validation.Application, ec
 		Variables:   manifest.Variables,
 		Components:  make([]*validation.Component, 0, len(manifest.Components)),
 	}
+	pins := make([]deploy.ComponentPin, 0, len(manifest.Components))
 
 	// Create a WASMPuller for pulling registry components
-	puller := oci.NewWASMPuller()
+	puller := oci.NewWASMPuller().WithStore(oci.NewStore(oci.DefaultStoreDir()))
 
 	// Create a WASMPusher for pushing to ECR
 	pusher := oci.NewWASMPusher(ecrAuth)
@@ -478,7 +622,7 @@ func processComponents(ctx context.Context, manifest *validation.Application, ec
 			// Local component - find the built WASM file
 			wasmPath, err = findBuiltWASM(src.Path, comp.ID)
 			if err != nil {
-				return nil, fmt.Errorf("failed to find built WASM for %s: %w", comp.ID, err)
+				return nil, nil, fmt.Errorf("failed to find built WASM for %s: %w", comp.ID, err)
 			}
 			Info("Found local component %s at %s", comp.ID, wasmPath)
 		case *validation.RegistrySource:
@@ -486,44 +630,58 @@ func processComponents(ctx context.Context, manifest *validation.Application, ec
 			Info("Pulling component %s from %s", comp.ID, src.Registry)
 			wasmPath, err = puller.Pull(ctx, src.Registry, src.Package, src.Version)
 			if err != nil {
-				return nil, fmt.Errorf("failed to pull component %s: %w", comp.ID, err)
+				return nil, nil, fmt.Errorf("failed to pull component %s: %w", comp.ID, err)
 			}
 			Success("Pulled %s", comp.ID)
 		default:
-			return nil, fmt.Errorf("invalid source for component %s", comp.ID)
+			return nil, nil, fmt.Errorf("invalid source for component %s", comp.ID)
 		}
 
 		// Push to ECR
 		// Package name should use / not : for the repository path
 		packageName := fmt.Sprintf("%s/%s", namespace, comp.ID)
-		version := manifest.Version
-		if version == "" {
-			version = "0.1.0"
-		}
+		version := commands.AppVersion(manifest)
 
 		Info("Pushing %s to FTL Engine Registry", comp.ID)
-		if err := pusher.Push(ctx, wasmPath, packageName, version); err != nil {
-			return nil, fmt.Errorf("failed to push component %s: %w", comp.ID, err)
+		digest, err := pusher.PushWithDigest(ctx, wasmPath, packageName, version)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to push component %s: %w", comp.ID, err)
 		}
 		Success("Pushed %s", comp.ID)
+		ref := fmt.Sprintf("%s/%s:%s", ecrAuth.Registry, packageName, version)
+		txn.record(stepComponentPushed, ref+"@"+digest, fmt.Sprintf("pushed component %s to %s", comp.ID, ref))
+
+		// Directories mounted into the component ship as a separate artifact
+		// in the component's repository
+		var filesDigest string
+		if len(comp.Files) > 0 {
+			mounts := make([]oci.FileMount, 0, len(comp.Files))
+			for _, f := range comp.Files {
+				mounts = append(mounts, oci.FileMount{Source: f.Source, Destination: f.Destination})
+			}
+			Info("Pushing files of %s", comp.ID)
+			filesDigest, err = pusher.PushFiles(ctx, mounts, packageName, deploy.FilesVersion(version))
+			if err != nil {
+				return nil, nil, fmt.Errorf("failed to push files of component %s: %w", comp.ID, err)
+			}
+			filesRef := fmt.Sprintf("%s/%s:%s", ecrAuth.Registry, packageName, deploy.FilesVersion(version))
+			txn.record(stepComponentPushed, filesRef+"@"+filesDigest, fmt.Sprintf("pushed the files of component %s to %s", comp.ID, filesRef))
+		}
 
 		// Create processed component with ECR reference
-		// Convert package name from namespace/component to namespace:component for Spin compatibility
-		spinPackageName := strings.Replace(packageName, "/", ":", 1)
-		processedComp := &validation.Component{
-			ID: comp.ID,
-			Source: &validation.RegistrySource{
-				Registry: ecrAuth.Registry,
-				Package:  spinPackageName,
-				Version:  version,
-			},
-			Build:     comp.Build,
-			Variables: comp.Variables,
-		}
-		processedManifest.Components = append(processedManifest.Components, processedComp)
-	}
-
-	return processedManifest, nil
+		source := commands.PushedSource(ecrAuth.Registry, namespace, comp.ID, version)
+		processedManifest.Components = append(processedManifest.Components, commands.RegistryComponent(comp, source))
+		pins = append(pins, deploy.ComponentPin{
+			ID:          comp.ID,
+			Registry:    ecrAuth.Registry,
+			Package:     source.Package,
+			Version:     version,
+			Digest:      digest,
+			FilesDigest: filesDigest,
+		})
+	}
+
+	return processedManifest, pins, nil
 }
 
 // findBuiltWASM locates the built WASM file for a local component
@@ -555,96 +713,12 @@ func findBuiltWASM(sourcePath, componentID string) (string, error) {
 }
 
 // createDeploymentRequest creates a flat FTL deployment request (no "application" wrapper)
-func createDeploymentRequest(manifest *validation.Application, opts *DeployOptions) map[string]interface{} {
-	// Build flat FTL deployment request
-	req := map[string]interface{}{
-		"name": manifest.Name,
-	}
-
-	// Add version
-	if manifest.Version != "" {
-		req["version"] = manifest.Version
-	} else {
-		req["version"] = "0.1.0"
-	}
-
-	// Add description if present
-	if manifest.Description != "" {
-		req["description"] = manifest.Description
-	}
-
-	// Set access control
-	if manifest.Access != "" {
-		req["access"] = manifest.Access
-	} else {
-		req["access"] = "public"
-	}
-
-	// Set auth configuration if needed
-	if manifest.Auth != nil && (manifest.Access == "org" || manifest.Access == "custom") {
-		auth := map[string]interface{}{}
-		if manifest.Auth.JWTIssuer != "" {
-			auth["jwt_issuer"] = manifest.Auth.JWTIssuer
-		}
-		if manifest.Auth.JWTAudience != "" {
-			auth["jwt_audience"] = manifest.Auth.JWTAudience
-		}
-		if len(auth) > 0 {
-			req["auth"] = auth
-		}
-	}
-
-	// Add allowed_roles for org mode
-	if manifest.Access == "org" && len(opts.AllowedRoles) > 0 {
-		req["allowed_roles"] = opts.AllowedRoles
-	}
-
-	// Add components
-	components := make([]map[string]interface{}, 0, len(manifest.Components))
-	for _, comp := range manifest.Components {
-		deployComp := map[string]interface{}{
-			"id": comp.ID,
-		}
-
-		// Parse component source - should be registry at this point
-		if regSrc, ok := comp.Source.(*validation.RegistrySource); ok {
-			deployComp["source"] = map[string]interface{}{
-				"registry": regSrc.Registry,
-				"package":  regSrc.Package,
-				"version":  regSrc.Version,
-			}
-		} else {
-			// This shouldn't happen after processing
-			Error("Component %s has non-registry source after processing", comp.ID)
-			continue
-		}
-
-		// Add component variables if any
-		if len(comp.Variables) > 0 {
-			deployComp["variables"] = comp.Variables
-		}
-
-		components = append(components, deployComp)
-	}
-	req["components"] = components
-
-	// Add application variables
-	if len(manifest.Variables) > 0 {
-		req["variables"] = manifest.Variables
-	}
-
-	// Merge deployment variables from options
-	if len(opts.Variables) > 0 {
-		if existing, ok := req["variables"].(map[string]string); ok {
-			for k, v := range opts.Variables {
-				existing[k] = v
-			}
-		} else {
-			req["variables"] = opts.Variables
-		}
-	}
-
-	return req
+func createDeploymentRequest(manifest *validation.Application, opts *DeployOptions) (map[string]interface{}, error) {
+	return commands.DeploymentRequest(manifest, commands.DeployOptions{
+		AllowedRoles: opts.AllowedRoles,
+		Variables:    opts.Variables,
+		ChangeReason: opts.ChangeReason,
+	})
 }
 
 // Note: Deployment is now done via streaming Lambda Function URLs
@@ -0,0 +1,271 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/fastertools/ftl/internal/deploy"
+)
+
+// Deployment strategies of ftl deploy
+const (
+	strategyDirect    = "direct"
+	strategyBlueGreen = "blue-green"
+)
+
+const (
+	// greenSuffix names the standby engine of blue-green deploys
+	greenSuffix = "-green"
+
+	// defaultBlueGreenProbeTimeout is how long a new deployment has to become healthy
+	defaultBlueGreenProbeTimeout = 2 * time.Minute
+
+	// rollbackChangeReason is recorded with the deployment restored by a rollback
+	rollbackChangeReason = "Rollback of a failed blue-green cutover"
+)
+
+// probeInterval is the delay between tools/list probes of a deployment that
+// isn't serving yet. Allow overriding for tests.
+var probeInterval = 2 * time.Second
+
+// probeCall is a synthetic tools/call a deployment must answer before it
+// takes traffic
+type probeCall struct {
+	Tool      string
+	Arguments json.RawMessage
+}
+
+// blueGreenDeploy deploys to a standby engine next to the live one, and only
+// switches the live engine to the new deployment once it is healthy
+type blueGreenDeploy struct {
+	live    string
+	liveID  string
+	green   string
+	dir     string // Project directory holding deployment records
+	token   string // Sent to the probed engines, empty for public apps
+	calls   []probeCall
+	timeout time.Duration
+}
+
+// validateDeployStrategy checks the strategy flags of ftl deploy
+func validateDeployStrategy(opts *DeployOptions, probeFlagsSet bool) error {
+	switch opts.Strategy {
+	case "", strategyDirect:
+		if probeFlagsSet {
+			return fmt.Errorf("--probe-call and --probe-timeout require --strategy %s", strategyBlueGreen)
+		}
+	case strategyBlueGreen:
+		if opts.ProbeTimeout <= 0 {
+			return fmt.Errorf("--probe-timeout must be positive")
+		}
+	default:
+		return fmt.Errorf("unknown --strategy %q (use %s or %s)", opts.Strategy, strategyDirect, strategyBlueGreen)
+	}
+	return nil
+}
+
+// prepareBlueGreen checks the live engine a blue-green deploy replaces. It
+// must already exist, and when protected needs the same confirmation as a
+// direct deploy.
+func prepareBlueGreen(ctx context.Context, client promoteClient, live string, opts *DeployOptions) (*blueGreenDeploy, error) {
+	calls, err := parseProbeCalls(opts.ProbeCalls)
+	if err != nil {
+		return nil, err
+	}
+
+	liveID, protected, err := findApp(ctx, client, live)
+	if err != nil {
+		return nil, fmt.Errorf("blue-green deploys replace a running engine: %w", err)
+	}
+	if protected {
+		if err := checkProtectedAccess(live, opts.AccessControl, opts.AllowPublic); err != nil {
+			return nil, err
+		}
+		if err := confirmProtectedEngine(live, opts.Confirm, "deploy to"); err != nil {
+			return nil, err
+		}
+	}
+
+	return &blueGreenDeploy{
+		live:    live,
+		liveID:  liveID,
+		green:   live + greenSuffix,
+		dir:     ".",
+		calls:   calls,
+		timeout: opts.ProbeTimeout,
+	}, nil
+}
+
+// runBlueGreenCutover probes the deployment of the standby engine and, once
+// healthy, deploys the same component digests to the live engine. When the
+// live engine fails its probes after the switch, its previous deployment is
+// restored. Returns the URL of the live engine.
+func runBlueGreenCutover(ctx context.Context, client promoteClient, bg *blueGreenDeploy, green *deploy.Record, greenURL string) (string, error) {
+	if greenURL == "" {
+		return "", fmt.Errorf("the deployment of %s has no URL to probe, %s was not changed", bg.green, bg.live)
+	}
+	Info("Probing %s before switching %s to it", bg.green, bg.live)
+	if err := probeDeployment(ctx, greenURL, bg.token, bg.calls, bg.timeout); err != nil {
+		return "", fmt.Errorf("%s failed its health probes, %s was not changed: %w", bg.green, bg.live, err)
+	}
+	Success("%s is healthy", bg.green)
+
+	previous, err := deploy.LoadRecord(deploy.RecordPath(bg.dir, bg.live))
+	if err != nil {
+		Warn("No deployment record of %s (%v): it can't be rolled back automatically", bg.live, err)
+		previous = nil
+	}
+
+	Info("Switching %s to the new deployment", bg.live)
+	record, liveURL, err := promoteRecord(ctx, client, green, promotionTarget{
+		Name:         bg.live,
+		AppID:        bg.liveID,
+		OrgID:        green.OrgID,
+		Environment:  green.Environment,
+		ChangeReason: green.ChangeReason,
+	})
+	if err == nil {
+		if liveURL == "" {
+			Warn("The deployment of %s has no URL to probe", bg.live)
+		} else {
+			err = probeDeployment(ctx, liveURL, bg.token, bg.calls, bg.timeout)
+		}
+	}
+	if err != nil {
+		return "", rollbackBlueGreen(ctx, client, bg, previous, err)
+	}
+
+	if _, err := deploy.SaveRecord(bg.dir, record); err != nil {
+		Warn("Failed to save deployment record: %v", err)
+	}
+	Success("Switched %s to the new deployment", bg.live)
+	return liveURL, nil
+}
+
+// rollbackBlueGreen restores the previous deployment of the live engine after
+// a failed cutover
+func rollbackBlueGreen(ctx context.Context, client promoteClient, bg *blueGreenDeploy, previous *deploy.Record, cause error) error {
+	if previous == nil {
+		return fmt.Errorf("cutover of %s failed and there is no previous deployment to roll back to: %w", bg.live, cause)
+	}
+
+	Warn("Cutover of %s failed, rolling back: %v", bg.live, cause)
+	record, _, err := promoteRecord(ctx, client, previous, promotionTarget{
+		Name:         bg.live,
+		AppID:        bg.liveID,
+		OrgID:        previous.OrgID,
+		Environment:  previous.Environment,
+		ChangeReason: rollbackChangeReason,
+	})
+	if err != nil {
+		return fmt.Errorf("cutover of %s failed (%v) and so did the rollback: %w", bg.live, cause, err)
+	}
+	if _, err := deploy.SaveRecord(bg.dir, record); err != nil {
+		Warn("Failed to save deployment record: %v", err)
+	}
+	return fmt.Errorf("cutover of %s failed and it was rolled back to its previous deployment: %w", bg.live, cause)
+}
+
+// parseProbeCalls parses --probe-call values of the form TOOL or
+// TOOL=JSON_ARGUMENTS
+func parseProbeCalls(specs []string) ([]probeCall, error) {
+	calls := make([]probeCall, 0, len(specs))
+	for _, spec := range specs {
+		tool, arguments, hasArguments := strings.Cut(spec, "=")
+		tool = strings.TrimSpace(tool)
+		if tool == "" {
+			return nil, fmt.Errorf("invalid --probe-call %q: missing tool name", spec)
+		}
+
+		call := probeCall{Tool: tool, Arguments: json.RawMessage(`{}`)}
+		if hasArguments {
+			var object map[string]interface{}
+			if err := json.Unmarshal([]byte(arguments), &object); err != nil {
+				return nil, fmt.Errorf("invalid --probe-call %q: arguments must be a JSON object: %w", spec, err)
+			}
+			call.Arguments = json.RawMessage(arguments)
+		}
+		calls = append(calls, call)
+	}
+	return calls, nil
+}
+
+// probeDeployment waits until the MCP endpoint of a deployment lists its
+// tools, then runs each synthetic call once
+func probeDeployment(ctx context.Context, url, token string, calls []probeCall, timeout time.Duration) error {
+	endpoint := strings.TrimRight(url, "/") + "/mcp"
+
+	ctx, cancel := context.WithTimeout(ctx, timeout)
+	defer cancel()
+	for {
+		tools, err := listMCPTools(ctx, endpoint, token)
+		if err == nil {
+			Debug("%s lists %d tool(s)", endpoint, len(tools))
+			break
+		}
+		select {
+		case <-ctx.Done():
+			return fmt.Errorf("tools/list did not succeed within %s: %w", timeout, err)
+		case <-time.After(probeInterval):
+		}
+	}
+
+	client := &http.Client{Timeout: 30 * time.Second}
+	for i, call := range calls {
+		if err := runProbeCall(ctx, client, endpoint, token, i+1, call); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// runProbeCall sends a synthetic tools/call, which passes when the tool
+// returns a result that isn't an error
+func runProbeCall(ctx context.Context, client *http.Client, endpoint, token string, id int, call probeCall) error {
+	request, err := json.Marshal(map[string]interface{}{
+		"jsonrpc": "2.0",
+		"id":      id,
+		"method":  "tools/call",
+		"params": map[string]interface{}{
+			"name":      call.Tool,
+			"arguments": call.Arguments,
+		},
+	})
+	if err != nil {
+		return err
+	}
+
+	status, body, err := sendRecordedCall(ctx, client, endpoint, token, request)
+	if err != nil {
+		return fmt.Errorf("probe call %s: %w", call.Tool, err)
+	}
+	if status != http.StatusOK {
+		return fmt.Errorf("probe call %s: HTTP %d", call.Tool, status)
+	}
+
+	var response struct {
+		Result *struct {
+			IsError bool `json:"isError"`
+		} `json:"result"`
+		Error *struct {
+			Message string `json:"message"`
+		} `json:"error"`
+	}
+	if err := json.Unmarshal(body, &response); err != nil {
+		return fmt.Errorf("probe call %s: invalid response: %w", call.Tool, err)
+	}
+	switch {
+	case response.Error != nil:
+		return fmt.Errorf("probe call %s failed: %s", call.Tool, response.Error.Message)
+	case response.Result == nil:
+		return fmt.Errorf("probe call %s: response has no result", call.Tool)
+	case response.Result.IsError:
+		return fmt.Errorf("probe call %s returned an error result", call.Tool)
+	}
+	Debug("Probe call %s passed", call.Tool)
+	return nil
+}
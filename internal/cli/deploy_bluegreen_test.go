@@ -0,0 +1,215 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/deploy"
+	"github.com/fastertools/ftl/oci"
+)
+
+const greenAppID = "33333333-3333-3333-3333-333333333333"
+
+// newProbedServer serves an MCP endpoint that fails tools/list until it has
+// been called unhealthyLists times, and answers tools/call with isError when
+// failCalls is set
+func newProbedServer(t *testing.T, unhealthyLists int32, failCalls bool) (*httptest.Server, *[]string) {
+	t.Helper()
+	var lists atomic.Int32
+	calls := &[]string{}
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "/mcp", r.URL.Path)
+		var request struct {
+			Method string `json:"method"`
+			Params struct {
+				Name      string          `json:"name"`
+				Arguments json.RawMessage `json:"arguments"`
+			} `json:"params"`
+		}
+		require.NoError(t, json.NewDecoder(r.Body).Decode(&request))
+
+		w.Header().Set("Content-Type", "application/json")
+		switch request.Method {
+		case "tools/list":
+			if lists.Add(1) <= unhealthyLists {
+				w.WriteHeader(http.StatusServiceUnavailable)
+				return
+			}
+			_, _ = w.Write([]byte(`{"jsonrpc":"2.0","id":1,"result":{"tools":[{"name":"echo__ping","inputSchema":{"type":"object"}}]}}`))
+		case "tools/call":
+			*calls = append(*calls, request.Params.Name+" "+string(request.Params.Arguments))
+			result := map[string]interface{}{"content": []interface{}{}, "isError": failCalls}
+			_ = json.NewEncoder(w).Encode(map[string]interface{}{"jsonrpc": "2.0", "id": 1, "result": result})
+		}
+	}))
+	t.Cleanup(server.Close)
+	return server, calls
+}
+
+func fastProbes(t *testing.T) {
+	t.Helper()
+	orig := probeInterval
+	probeInterval = 10 * time.Millisecond
+	t.Cleanup(func() { probeInterval = orig })
+}
+
+// stubCutover deploys promotions to the given URL and records the names of
+// the engines deployed to
+func stubCutover(t *testing.T, url string) *[]string {
+	t.Helper()
+	origCopy, origDeploy := copyComponent, deployPromotion
+	t.Cleanup(func() {
+		copyComponent, deployPromotion = origCopy, origDeploy
+	})
+
+	deployed := &[]string{}
+	copyComponent = func(context.Context, string, *oci.ECRAuth, string, *oci.ECRAuth) error {
+		return nil
+	}
+	deployPromotion = func(_ context.Context, body []byte, _ *api.CreateDeployCredentialsResponseBody, _ deploy.DeployOptions, callback func(deploy.StreamEvent)) error {
+		var request map[string]interface{}
+		if err := json.Unmarshal(body, &request); err != nil {
+			return err
+		}
+		*deployed = append(*deployed, request["name"].(string))
+		callback(deploy.StreamEvent{Type: "complete", DeploymentID: "dep-live", URL: url})
+		return nil
+	}
+	return deployed
+}
+
+func blueGreenRecord(app, appID, digest string) *deploy.Record {
+	return &deploy.Record{
+		App:          app,
+		AppID:        appID,
+		Environment:  "production",
+		DeployedAt:   time.Now().UTC(),
+		ChangeReason: "Release 1.3.0",
+		Components: []deploy.ComponentPin{{
+			ID:       "echo",
+			Registry: testRegistry,
+			Package:  "ns-" + appID[:4] + ":echo",
+			Version:  "1.3.0",
+			Digest:   digest,
+		}},
+		Request: map[string]interface{}{
+			"name": app,
+			"components": []interface{}{
+				map[string]interface{}{"id": "echo", "source": map[string]interface{}{}},
+			},
+		},
+	}
+}
+
+func TestValidateDeployStrategy(t *testing.T) {
+	assert.NoError(t, validateDeployStrategy(&DeployOptions{Strategy: strategyDirect}, false))
+	assert.NoError(t, validateDeployStrategy(&DeployOptions{Strategy: strategyBlueGreen, ProbeTimeout: time.Minute}, true))
+	assert.ErrorContains(t, validateDeployStrategy(&DeployOptions{Strategy: strategyDirect}, true), "require --strategy blue-green")
+	assert.ErrorContains(t, validateDeployStrategy(&DeployOptions{Strategy: "canary"}, false), "unknown --strategy")
+	assert.ErrorContains(t, validateDeployStrategy(&DeployOptions{Strategy: strategyBlueGreen}, false), "must be positive")
+}
+
+func TestParseProbeCalls(t *testing.T) {
+	calls, err := parseProbeCalls([]string{"echo__ping", `echo__say={"text":"a=b"}`})
+	require.NoError(t, err)
+	assert.Equal(t, []probeCall{
+		{Tool: "echo__ping", Arguments: json.RawMessage(`{}`)},
+		{Tool: "echo__say", Arguments: json.RawMessage(`{"text":"a=b"}`)},
+	}, calls)
+
+	_, err = parseProbeCalls([]string{"=1"})
+	assert.ErrorContains(t, err, "missing tool name")
+	_, err = parseProbeCalls([]string{"echo__say=[1]"})
+	assert.ErrorContains(t, err, "must be a JSON object")
+}
+
+func TestProbeDeployment(t *testing.T) {
+	fastProbes(t)
+
+	// tools/list is retried until the deployment serves it
+	server, calls := newProbedServer(t, 2, false)
+	probes := []probeCall{{Tool: "echo__ping", Arguments: json.RawMessage(`{"n":1}`)}}
+	require.NoError(t, probeDeployment(context.Background(), server.URL+"/", "", probes, time.Second))
+	assert.Equal(t, []string{`echo__ping {"n":1}`}, *calls)
+
+	// Error results fail the probe
+	server, _ = newProbedServer(t, 0, true)
+	err := probeDeployment(context.Background(), server.URL, "", probes, time.Second)
+	assert.ErrorContains(t, err, "returned an error result")
+
+	// Deployments that never list their tools time out
+	server, _ = newProbedServer(t, 1000, false)
+	err = probeDeployment(context.Background(), server.URL, "", nil, 50*time.Millisecond)
+	assert.ErrorContains(t, err, "did not succeed within")
+}
+
+func TestRunBlueGreenCutover(t *testing.T) {
+	fastProbes(t)
+	dir := t.TempDir()
+	server, _ := newProbedServer(t, 0, false)
+	deployed := stubCutover(t, server.URL)
+
+	_, err := deploy.SaveRecord(dir, blueGreenRecord("my-app", prodAppID, "sha256:old"))
+	require.NoError(t, err)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app": prodAppID, "my-app-green": greenAppID}}
+	bg := &blueGreenDeploy{live: "my-app", liveID: prodAppID, green: "my-app-green", dir: dir, timeout: time.Second}
+	url, err := runBlueGreenCutover(context.Background(), client, bg, blueGreenRecord("my-app-green", greenAppID, "sha256:new"), server.URL)
+	require.NoError(t, err)
+	assert.Equal(t, server.URL, url)
+	assert.Equal(t, []string{"my-app"}, *deployed)
+
+	record, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app"))
+	require.NoError(t, err)
+	assert.Equal(t, "sha256:new", record.Components[0].Digest)
+	assert.Equal(t, "Release 1.3.0", record.ChangeReason)
+}
+
+func TestRunBlueGreenCutover_UnhealthyGreen(t *testing.T) {
+	fastProbes(t)
+	dir := t.TempDir()
+	server, _ := newProbedServer(t, 0, true)
+	deployed := stubCutover(t, server.URL)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app": prodAppID}}
+	bg := &blueGreenDeploy{
+		live: "my-app", liveID: prodAppID, green: "my-app-green", dir: dir, timeout: time.Second,
+		calls: []probeCall{{Tool: "echo__ping", Arguments: json.RawMessage(`{}`)}},
+	}
+	_, err := runBlueGreenCutover(context.Background(), client, bg, blueGreenRecord("my-app-green", greenAppID, "sha256:new"), server.URL)
+	assert.ErrorContains(t, err, "my-app was not changed")
+	assert.Empty(t, *deployed)
+}
+
+func TestRunBlueGreenCutover_RollsBack(t *testing.T) {
+	fastProbes(t)
+	dir := t.TempDir()
+	green, _ := newProbedServer(t, 0, false)
+	// The live engine keeps failing its probes after the switch
+	live, _ := newProbedServer(t, 1000, false)
+	deployed := stubCutover(t, live.URL)
+
+	_, err := deploy.SaveRecord(dir, blueGreenRecord("my-app", prodAppID, "sha256:old"))
+	require.NoError(t, err)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app": prodAppID}}
+	bg := &blueGreenDeploy{live: "my-app", liveID: prodAppID, green: "my-app-green", dir: dir, timeout: 50 * time.Millisecond}
+	_, err = runBlueGreenCutover(context.Background(), client, bg, blueGreenRecord("my-app-green", greenAppID, "sha256:new"), green.URL)
+	assert.ErrorContains(t, err, "rolled back to its previous deployment")
+
+	// The new deployment, then the previous one
+	assert.Equal(t, []string{"my-app", "my-app"}, *deployed)
+	record, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app"))
+	require.NoError(t, err)
+	assert.Equal(t, "sha256:old", record.Components[0].Digest)
+	assert.Equal(t, rollbackChangeReason, record.ChangeReason)
+}
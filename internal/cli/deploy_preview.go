@@ -3,6 +3,7 @@ package cli
 import (
 	"fmt"
 	"os"
+	"slices"
 	"strings"
 	"text/tabwriter"
 
@@ -14,17 +15,20 @@ import (
 
 // DeploymentPreview represents the deployment preview information
 type DeploymentPreview struct {
-	IsUpdate       bool
-	AppName        string
-	AppID          string
-	AccessMode     string
-	Environment    string
-	Organization   string
-	Components     []ComponentPreview
-	Variables      map[string]string
-	ExistingAppID  string
-	ExistingAccess string
-	Changes        *DeploymentChanges
+	IsUpdate        bool
+	AppName         string
+	AppID           string
+	AccessMode      string
+	Environment     string
+	Organization    string
+	Components      []ComponentPreview
+	Variables       map[string]string
+	SecretVariables []string
+	ExistingAppID   string
+	ExistingAccess  string
+	Protected       bool
+	ChangeReason    string
+	Changes         *DeploymentChanges
 }
 
 // ComponentPreview represents a component in the preview
@@ -105,6 +109,15 @@ func ShowDeploymentPreview(preview *DeploymentPreview) {
 			color.New(color.FgMagenta).Sprint(preview.Organization))
 	}
 
+	if preview.Protected {
+		fmt.Printf("  %s %s\n",
+			color.New(color.Bold).Sprint("Protected:"),
+			color.New(color.FgRed, color.Bold).Sprint("🛡  yes, typed confirmation required"))
+	}
+	if preview.ChangeReason != "" {
+		fmt.Printf("  %s %s\n", color.New(color.Bold).Sprint("Change Reason:"), preview.ChangeReason)
+	}
+
 	fmt.Println(strings.Repeat("─", 60))
 
 	// Components Section
@@ -141,7 +154,7 @@ func ShowDeploymentPreview(preview *DeploymentPreview) {
 		for key, value := range preview.Variables {
 			// Mask sensitive values
 			displayValue := value
-			if isSensitiveKey(key) {
+			if isSensitiveKey(key) || slices.Contains(preview.SecretVariables, key) {
 				displayValue = maskValue(value)
 			}
 			fmt.Printf("  %s = %s\n",
@@ -430,14 +443,16 @@ func BuildDeploymentPreview(
 	orgID string,
 ) *DeploymentPreview {
 	preview := &DeploymentPreview{
-		IsUpdate:       existingAppID != "",
-		AppName:        manifest.Name,
-		AccessMode:     manifest.Access,
-		Environment:    opts.Environment,
-		Organization:   orgID,
-		Variables:      opts.Variables,
-		ExistingAppID:  existingAppID,
-		ExistingAccess: existingAccess,
+		IsUpdate:        existingAppID != "",
+		AppName:         manifest.Name,
+		AccessMode:      manifest.Access,
+		Environment:     opts.Environment,
+		Organization:    orgID,
+		Variables:       opts.Variables,
+		SecretVariables: manifest.SecretVariables(),
+		ChangeReason:    opts.ChangeReason,
+		ExistingAppID:   existingAppID,
+		ExistingAccess:  existingAccess,
 	}
 
 	// Add existing app info
@@ -47,6 +47,9 @@ description: "Test application"
 components:
   - id: component1
     source: "./component1"
+    files:
+      - source: data
+        destination: /data
 access: public
 `
 
@@ -59,6 +62,7 @@ access: public
 	assert.Equal(t, "test-app", loaded.Name)
 	assert.Equal(t, "1.0.0", loaded.Version)
 	assert.Len(t, loaded.Components, 1)
+	assert.Equal(t, []validation.FileMount{{Source: "data", Destination: "/data"}}, loaded.Components[0].Files)
 }
 
 // TestParseECRToken tests are now in pkg/oci/ecr_auth_test.go
@@ -227,6 +231,52 @@ func TestProcessComponents(t *testing.T) {
 	}
 }
 
+func TestProcessComponentsFiles(t *testing.T) {
+	s := httptest.NewServer(registry.New())
+	defer s.Close()
+	regURL := strings.TrimPrefix(s.URL, "http://")
+
+	dir := t.TempDir()
+	wasmPath := filepath.Join(dir, "spell.wasm")
+	require.NoError(t, os.WriteFile(wasmPath, []byte{0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00}, 0600))
+	dataDir := filepath.Join(dir, "dictionaries")
+	require.NoError(t, os.MkdirAll(dataDir, 0750))
+	require.NoError(t, os.WriteFile(filepath.Join(dataDir, "en.txt"), []byte("apple"), 0600))
+
+	manifest := &validation.Application{
+		Name:    "test-app",
+		Version: "1.0.0",
+		Components: []*validation.Component{{
+			ID:     "spell",
+			Source: &validation.LocalSource{Path: wasmPath},
+			Files:  []validation.FileMount{{Source: dataDir, Destination: "/dictionaries"}},
+		}},
+	}
+
+	processed, pins, err := processComponents(context.Background(), manifest, &oci.ECRAuth{Registry: regURL}, "ns", nil)
+	require.NoError(t, err)
+	require.Len(t, pins, 1)
+	assert.True(t, strings.HasPrefix(pins[0].FilesDigest, "sha256:"))
+	assert.Equal(t, manifest.Components[0].Files, processed.Components[0].Files)
+
+	// The files artifact is tagged alongside the component
+	ref, err := name.ParseReference(regURL + "/ns/spell:1.0.0-files")
+	require.NoError(t, err)
+	desc, err := remote.Head(ref)
+	require.NoError(t, err)
+	assert.Equal(t, pins[0].FilesDigest, desc.Digest.String())
+
+	req, err := createDeploymentRequest(processed, &DeployOptions{})
+	require.NoError(t, err)
+	components := req["components"].([]map[string]interface{})
+	assert.Equal(t, map[string]interface{}{
+		"registry": regURL,
+		"package":  "ns:spell",
+		"version":  "1.0.0-files",
+		"mounts":   []string{"/dictionaries"},
+	}, components[0]["files"])
+}
+
 func TestDeployRunSynthWritesFile(t *testing.T) {
 	// Test that runSynth actually writes spin.toml to disk, not just prints it
 	tmpDir := t.TempDir()
@@ -378,8 +428,8 @@ func TestCreateDeploymentRequest(t *testing.T) {
 			JWTIssuer:   "https://auth.example.com",
 			JWTAudience: "api.example.com",
 		},
-		Variables: map[string]string{
-			"GLOBAL_VAR": "global_value",
+		Variables: map[string]validation.Variable{
+			"GLOBAL_VAR": validation.VariableWithDefault("global_value"),
 		},
 	}
 
@@ -390,7 +440,8 @@ func TestCreateDeploymentRequest(t *testing.T) {
 		},
 	}
 
-	req := createDeploymentRequest(manifest, opts)
+	req, err := createDeploymentRequest(manifest, opts)
+	require.NoError(t, err)
 
 	// Verify flat request structure (no nested "application" field)
 	assert.Equal(t, "test-app", req["name"])
@@ -407,6 +458,63 @@ func TestCreateDeploymentRequest(t *testing.T) {
 	variables, ok := req["variables"].(map[string]string)
 	assert.True(t, ok)
 	assert.Equal(t, "deploy_value", variables["DEPLOY_VAR"])
+
+	// The change reason is only sent when given
+	_, ok = req["change_reason"]
+	assert.False(t, ok)
+	opts.ChangeReason = "Rotate signing keys"
+	req, err = createDeploymentRequest(manifest, opts)
+	require.NoError(t, err)
+	assert.Equal(t, "Rotate signing keys", req["change_reason"])
+}
+
+func TestLoadDeployManifestVariables(t *testing.T) {
+	manifestPath := filepath.Join(t.TempDir(), "ftl.yaml")
+	manifestYAML := `
+name: test-app
+variables:
+  log_level: info
+  region:
+    required: true
+  api_key:
+    value_from: engine-secret:API_KEY
+`
+	require.NoError(t, os.WriteFile(manifestPath, []byte(manifestYAML), 0600))
+
+	loaded, err := loadDeployManifest(manifestPath)
+	require.NoError(t, err)
+	require.Len(t, loaded.Variables, 3)
+	assert.Equal(t, validation.VariableWithDefault("info"), loaded.Variables["log_level"])
+	assert.True(t, loaded.Variables["region"].Required)
+	assert.Equal(t, "engine-secret:API_KEY", loaded.Variables["api_key"].ValueFrom)
+
+	// Required variables must be provided with --var
+	assert.Equal(t, []string{"region"}, loaded.MissingVariables(nil))
+	assert.Empty(t, loaded.MissingVariables(map[string]string{"region": "eu-west-1"}))
+}
+
+func TestCreateDeploymentRequestVariables(t *testing.T) {
+	manifest := &validation.Application{
+		Name: "test-app",
+		Variables: map[string]validation.Variable{
+			"log_level":     validation.VariableWithDefault("info"),
+			"region":        {Required: true},
+			"api_key":       {ValueFrom: "engine-secret:API_KEY"},
+			"webhook_token": {Required: true, Secret: true},
+		},
+	}
+	opts := &DeployOptions{Variables: map[string]string{"region": "eu-west-1", "webhook_token": "t0ken"}}
+
+	req, err := createDeploymentRequest(manifest, opts)
+	require.NoError(t, err)
+
+	assert.Equal(t, map[string]string{
+		"log_level":     "info",
+		"region":        "eu-west-1",
+		"webhook_token": "t0ken",
+	}, req["variables"])
+	assert.Equal(t, map[string]string{"api_key": "engine-secret:API_KEY"}, req["variable_sources"])
+	assert.Equal(t, []string{"api_key", "webhook_token"}, req["secret_variables"])
 }
 
 func TestDisplayDryRunSummary(t *testing.T) {
@@ -0,0 +1,271 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/AlecAivazis/survey/v2"
+
+	"github.com/fastertools/ftl/internal/deploy"
+)
+
+// deployTransactionDir is where the transaction logs of failed deploys are
+// kept, relative to the project root
+var deployTransactionDir = filepath.Join(".ftl", "transactions")
+
+// Kinds of the mutating steps of a deploy
+const (
+	stepAppCreated      = "app_created"
+	stepComponentPushed = "component_pushed"
+	stepAuthChanged     = "auth_changed"
+)
+
+// Status of a step once its deploy failed
+const (
+	stepDone       = "done"
+	stepRolledBack = "rolled_back"
+	stepLeftBehind = "left_behind"
+)
+
+// deployRollbackChangeReason is recorded with the deployment restored by the
+// rollback of a failed deploy
+const deployRollbackChangeReason = "Rollback of a failed deploy"
+
+// deployStep is a mutating step of a deploy
+type deployStep struct {
+	Kind        string    `json:"kind"`
+	// Target is the ID of the created app, the pushed artifact or the app
+	// whose auth config changed
+	Target      string    `json:"target"`
+	Description string    `json:"description"`
+	At          time.Time `json:"at"`
+	Status      string    `json:"status"`
+	Note        string    `json:"note,omitempty"`
+}
+
+// deployTransaction logs the mutating steps of a deploy, so that a failed
+// deploy can be compensated and what it left behind reported. The log is
+// written to deployTransactionDir as the deploy goes and removed once it
+// succeeds.
+type deployTransaction struct {
+	App       string       `json:"app"`
+	StartedAt time.Time    `json:"started_at"`
+	Error     string       `json:"error,omitempty"`
+	Steps     []deployStep `json:"steps"`
+
+	dir       string // Project directory holding deployment records
+	path      string
+	previous  *deploy.Record // Last deployment of the app, restores its auth config
+	committed bool
+}
+
+// deployTransactionClient is the platform API used to compensate failed
+// deploys
+type deployTransactionClient interface {
+	promoteClient
+	DeleteApp(ctx context.Context, appID string) error
+}
+
+// confirmDeployRollback asks whether to roll back a failed deploy. Allow
+// overriding for tests.
+var confirmDeployRollback = func() (bool, error) {
+	if !isDeployInteractive() {
+		return false, nil
+	}
+	rollback := false
+	prompt := &survey.Confirm{
+		Message: "Roll back the changes of the failed deploy?",
+		Default: false,
+	}
+	if err := survey.AskOne(prompt, &rollback); err != nil {
+		return false, err
+	}
+	return rollback, nil
+}
+
+// newDeployTransaction starts the transaction log of a deploy of app within dir
+func newDeployTransaction(dir, app string) *deployTransaction {
+	txn := &deployTransaction{
+		App:       app,
+		StartedAt: time.Now().UTC(),
+		Steps:     []deployStep{},
+		dir:       dir,
+		path:      filepath.Join(dir, deployTransactionDir, app+".json"),
+	}
+	if previous, err := deploy.LoadRecord(deploy.RecordPath(dir, app)); err == nil {
+		txn.previous = previous
+	}
+	return txn
+}
+
+// record logs a mutating step that succeeded
+func (t *deployTransaction) record(kind, target, description string) {
+	if t == nil {
+		return
+	}
+	t.Steps = append(t.Steps, deployStep{
+		Kind:        kind,
+		Target:      target,
+		Description: description,
+		At:          time.Now().UTC(),
+		Status:      stepDone,
+	})
+	if err := t.save(); err != nil {
+		Warn("Failed to write the deploy transaction log: %v", err)
+	}
+}
+
+// commit marks the deploy as successful and removes its log
+func (t *deployTransaction) commit() {
+	t.committed = true
+	if err := os.Remove(t.path); err != nil && !errors.Is(err, os.ErrNotExist) {
+		Warn("Failed to remove %s: %v", t.path, err)
+	}
+}
+
+// rollback compensates the steps of a failed deploy in reverse order, when
+// auto is set or the user agrees to it, and reports what was left behind.
+// Returns the error of the deploy, noting the outcome.
+func (t *deployTransaction) rollback(ctx context.Context, client deployTransactionClient, cause error, auto bool) error {
+	if t.committed || len(t.Steps) == 0 {
+		return cause
+	}
+	t.Error = cause.Error()
+
+	Error("Deploy of %s failed: %v", t.App, cause)
+	rollback := auto
+	if !auto {
+		confirmed, err := confirmDeployRollback()
+		if err != nil {
+			Warn("Not rolling back: %v", err)
+		}
+		rollback = confirmed
+	}
+
+	for i := len(t.Steps) - 1; i >= 0; i-- {
+		step := &t.Steps[i]
+		if !rollback {
+			step.Status, step.Note = stepLeftBehind, "not rolled back, rerun with --auto-rollback to roll back failed deploys"
+			continue
+		}
+		if err := t.compensate(ctx, client, step); err != nil {
+			step.Status, step.Note = stepLeftBehind, err.Error()
+			continue
+		}
+		step.Status = stepRolledBack
+	}
+
+	if err := t.save(); err != nil {
+		Warn("Failed to write the deploy transaction log: %v", err)
+	}
+	left := t.report()
+	if left == 0 {
+		_ = os.Remove(t.path)
+		return fmt.Errorf("%w (the changes of the deploy were rolled back)", cause)
+	}
+	return fmt.Errorf("%w (%d change(s) left behind, see %s)", cause, left, t.path)
+}
+
+// compensate undoes a step
+func (t *deployTransaction) compensate(ctx context.Context, client deployTransactionClient, step *deployStep) error {
+	switch step.Kind {
+	case stepAppCreated:
+		Info("Deleting app %s", t.App)
+		if err := client.DeleteApp(ctx, step.Target); err != nil {
+			return fmt.Errorf("failed to delete the app: %w", err)
+		}
+		return nil
+	case stepAuthChanged:
+		if t.previous == nil {
+			return fmt.Errorf("no deployment record of %s to restore its auth config from", t.App)
+		}
+		Info("Restoring the previous deployment of %s", t.App)
+		record, _, err := promoteRecord(ctx, client, t.previous, promotionTarget{
+			Name:         t.App,
+			AppID:        step.Target,
+			OrgID:        t.previous.OrgID,
+			Environment:  t.previous.Environment,
+			ChangeReason: deployRollbackChangeReason,
+		})
+		if err != nil {
+			return fmt.Errorf("failed to restore the previous deployment: %w", err)
+		}
+		if _, err := deploy.SaveRecord(t.dir, record); err != nil {
+			Warn("Failed to save deployment record: %v", err)
+		}
+		return nil
+	case stepComponentPushed:
+		return fmt.Errorf("the platform has no API to delete pushed artifacts, the next deploy replaces them")
+	default:
+		return fmt.Errorf("unknown step %q", step.Kind)
+	}
+}
+
+// report lists the rolled back steps and those left behind, and returns the
+// number left behind
+func (t *deployTransaction) report() int {
+	left := 0
+	for _, step := range t.Steps {
+		if step.Status == stepRolledBack {
+			Info("Rolled back: %s", step.Description)
+		}
+	}
+	for _, step := range t.Steps {
+		if step.Status == stepLeftBehind {
+			left++
+			Warn("Left behind: %s (%s)", step.Description, step.Note)
+		}
+	}
+	return left
+}
+
+func (t *deployTransaction) save() error {
+	if err := os.MkdirAll(filepath.Dir(t.path), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(t.path), err)
+	}
+	data, err := json.MarshalIndent(t, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode deploy transaction: %w", err)
+	}
+	if err := os.WriteFile(t.path, append(data, '\n'), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", t.path, err)
+	}
+	return nil
+}
+
+// deployAuthConfig is the part of a deployment request that configures who
+// may call the app
+func deployAuthConfig(request map[string]interface{}) string {
+	data, _ := json.Marshal(map[string]interface{}{
+		"access":        request["access"],
+		"auth":          request["auth"],
+		"allowed_roles": request["allowed_roles"],
+	})
+	return string(data)
+}
+
+// authChangeDescription describes how a deployment request changes the auth
+// config of an existing app, or returns "" when it doesn't. The previous
+// config comes from the app's last deployment record, or else its access
+// control as listed by the platform.
+func authChangeDescription(app string, previous *deploy.Record, existingAccess string, request map[string]interface{}) string {
+	access, _ := request["access"].(string)
+	if previous != nil {
+		if deployAuthConfig(previous.Request) == deployAuthConfig(request) {
+			return ""
+		}
+		if before, _ := previous.Request["access"].(string); before != access {
+			return fmt.Sprintf("changed the access control of %s from %s to %s", app, before, access)
+		}
+		return fmt.Sprintf("changed the auth config of %s", app)
+	}
+	if existingAccess != "" && existingAccess != access {
+		return fmt.Sprintf("changed the access control of %s from %s to %s", app, existingAccess, access)
+	}
+	return ""
+}
@@ -0,0 +1,154 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"os"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/deploy"
+)
+
+type fakeTransactionClient struct {
+	fakePromoteClient
+	deleted []string
+}
+
+func (f *fakeTransactionClient) DeleteApp(_ context.Context, appID string) error {
+	f.deleted = append(f.deleted, appID)
+	return nil
+}
+
+// stubRollbackPrompt answers the rollback prompt of failed deploys
+func stubRollbackPrompt(t *testing.T, answer bool) *int {
+	t.Helper()
+	orig := confirmDeployRollback
+	t.Cleanup(func() { confirmDeployRollback = orig })
+	asked := new(int)
+	confirmDeployRollback = func() (bool, error) {
+		*asked++
+		return answer, nil
+	}
+	return asked
+}
+
+func readDeployTransaction(t *testing.T, txn *deployTransaction) *deployTransaction {
+	t.Helper()
+	data, err := os.ReadFile(txn.path)
+	require.NoError(t, err)
+	var logged deployTransaction
+	require.NoError(t, json.Unmarshal(data, &logged))
+	return &logged
+}
+
+func TestDeployTransaction_DeletesCreatedApp(t *testing.T) {
+	asked := stubRollbackPrompt(t, false)
+	txn := newDeployTransaction(t.TempDir(), "my-app")
+	txn.record(stepAppCreated, prodAppID, "created app my-app")
+	txn.record(stepComponentPushed, testRegistry+"/ns/echo:0.1.0@sha256:abc", "pushed component echo")
+	assert.FileExists(t, txn.path)
+
+	client := &fakeTransactionClient{}
+	err := txn.rollback(context.Background(), client, errors.New("deployment failed"), true)
+	assert.EqualError(t, err, "deployment failed (1 change(s) left behind, see "+txn.path+")")
+	assert.Equal(t, []string{prodAppID}, client.deleted)
+	assert.Zero(t, *asked)
+
+	logged := readDeployTransaction(t, txn)
+	assert.Equal(t, "deployment failed", logged.Error)
+	assert.Equal(t, stepRolledBack, logged.Steps[0].Status)
+	assert.Equal(t, stepLeftBehind, logged.Steps[1].Status)
+	assert.Contains(t, logged.Steps[1].Note, "no API to delete pushed artifacts")
+}
+
+func TestDeployTransaction_RestoresAuthConfig(t *testing.T) {
+	stubRollbackPrompt(t, true)
+	dir := t.TempDir()
+	deployed := stubCutover(t, "https://my-app.example.com")
+	previous := blueGreenRecord("my-app", prodAppID, "sha256:old")
+	previous.Request["access"] = "private"
+	_, err := deploy.SaveRecord(dir, previous)
+	require.NoError(t, err)
+
+	txn := newDeployTransaction(dir, "my-app")
+	txn.record(stepAuthChanged, prodAppID, "changed the access control of my-app from private to public")
+
+	client := &fakeTransactionClient{fakePromoteClient: fakePromoteClient{apps: map[string]string{"my-app": prodAppID}}}
+	err = txn.rollback(context.Background(), client, errors.New("deployment failed"), false)
+	assert.EqualError(t, err, "deployment failed (the changes of the deploy were rolled back)")
+	assert.Equal(t, []string{"my-app"}, *deployed)
+	assert.Empty(t, client.deleted)
+	assert.NoFileExists(t, txn.path)
+
+	record, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app"))
+	require.NoError(t, err)
+	assert.Equal(t, "private", record.Request["access"])
+	assert.Equal(t, deployRollbackChangeReason, record.ChangeReason)
+}
+
+func TestDeployTransaction_LeftBehind(t *testing.T) {
+	asked := stubRollbackPrompt(t, false)
+	txn := newDeployTransaction(t.TempDir(), "my-app")
+	txn.record(stepAppCreated, prodAppID, "created app my-app")
+	txn.record(stepAuthChanged, prodAppID, "changed the auth config of my-app")
+
+	client := &fakeTransactionClient{}
+	err := txn.rollback(context.Background(), client, errors.New("deployment failed"), false)
+	assert.ErrorContains(t, err, "2 change(s) left behind")
+	assert.Equal(t, 1, *asked)
+	assert.Empty(t, client.deleted)
+	for _, step := range readDeployTransaction(t, txn).Steps {
+		assert.Equal(t, stepLeftBehind, step.Status)
+		assert.Contains(t, step.Note, "--auto-rollback")
+	}
+
+	// Without a deployment record the auth config can't be restored
+	err = txn.rollback(context.Background(), client, errors.New("deployment failed"), true)
+	assert.ErrorContains(t, err, "1 change(s) left behind")
+	assert.Contains(t, readDeployTransaction(t, txn).Steps[1].Note, "no deployment record of my-app")
+}
+
+func TestDeployTransaction_Commit(t *testing.T) {
+	stubRollbackPrompt(t, true)
+	txn := newDeployTransaction(t.TempDir(), "my-app")
+	cause := errors.New("cutover failed")
+
+	// Deploys that changed nothing have nothing to roll back
+	assert.Equal(t, cause, txn.rollback(context.Background(), &fakeTransactionClient{}, cause, true))
+
+	txn.record(stepAppCreated, prodAppID, "created app my-app")
+	txn.commit()
+	assert.NoFileExists(t, txn.path)
+
+	client := &fakeTransactionClient{}
+	assert.Equal(t, cause, txn.rollback(context.Background(), client, cause, true))
+	assert.Empty(t, client.deleted)
+
+	// A nil transaction records nothing
+	var none *deployTransaction
+	none.record(stepAppCreated, prodAppID, "created app my-app")
+}
+
+func TestAuthChangeDescription(t *testing.T) {
+	request := map[string]interface{}{"access": "custom", "auth": map[string]interface{}{"jwt_issuer": "https://auth.example.com"}}
+
+	assert.Equal(t, "changed the access control of my-app from private to custom",
+		authChangeDescription("my-app", nil, "private", request))
+	assert.Equal(t, "", authChangeDescription("my-app", nil, "custom", request))
+	assert.Equal(t, "", authChangeDescription("my-app", nil, "", request))
+
+	previous := &deploy.Record{Request: map[string]interface{}{
+		"access": "custom",
+		"auth":   map[string]interface{}{"jwt_issuer": "https://auth.example.com"},
+	}}
+	assert.Equal(t, "", authChangeDescription("my-app", previous, "custom", request))
+	previous.Request["auth"] = map[string]interface{}{"jwt_issuer": "https://old.example.com"}
+	assert.Equal(t, "changed the auth config of my-app", authChangeDescription("my-app", previous, "custom", request))
+	previous.Request["access"] = "org"
+	assert.Equal(t, "changed the access control of my-app from org to custom",
+		authChangeDescription("my-app", previous, "custom", request))
+}
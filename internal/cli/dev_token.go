@@ -0,0 +1,165 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+)
+
+// devTokenPath is where the authorizer issues development tokens
+const devTokenPath = "/dev/token"
+
+// DevTokenOptions holds options for the dev-token command
+type DevTokenOptions struct {
+	URL     string
+	Subject string
+	Scopes  []string
+	TTL     time.Duration
+	Claims  []string
+	Format  string
+}
+
+// devTokenResponse is the authorizer's token response
+type devTokenResponse struct {
+	AccessToken string `json:"access_token"`
+	TokenType   string `json:"token_type"`
+	ExpiresIn   int64  `json:"expires_in"`
+	Scope       string `json:"scope,omitempty"`
+}
+
+func newDevTokenCmd() *cobra.Command {
+	opts := &DevTokenOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "dev-token",
+		Short: "Mint a development token from the local authorizer",
+		Long: `Mint a short-lived token from the authorizer of a locally running app, for
+testing auth-enabled apps with curl or the MCP Inspector without a real
+identity provider.
+
+The authorizer only issues development tokens when its mcp_dev_tokens and
+mcp_dev_token_secret variables are set. Never enable them in a deployed app.
+
+The token is printed on its own, so it can be used directly:
+
+  curl -H "Authorization: Bearer $(ftl dev-token --scope mcp:read)" ...`,
+		Example: `  ftl dev-token
+  ftl dev-token --scope mcp:read --scope mcp:write --sub alice
+  ftl dev-token --claim org_id=org_123 --ttl 5m -o json`,
+		Args: cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runDevToken(cmd.Context(), cmd.OutOrStdout(), opts)
+		},
+	}
+
+	cmd.Flags().StringVar(&opts.URL, "url", "http://"+defaultListenAddress, "URL of the running app")
+	cmd.Flags().StringVar(&opts.Subject, "sub", "", "token subject (default: dev-user)")
+	cmd.Flags().StringSliceVar(&opts.Scopes, "scope", nil, "scope to grant. Can be specified multiple times")
+	cmd.Flags().DurationVar(&opts.TTL, "ttl", 0, "token lifetime (default: the authorizer's maximum)")
+	cmd.Flags().StringArrayVar(&opts.Claims, "claim", nil, "additional claim (key=value, JSON values allowed). Can be specified multiple times")
+	cmd.Flags().StringVarP(&opts.Format, "output", "o", "text", "Output format (text, json)")
+
+	return cmd
+}
+
+func runDevToken(ctx context.Context, w io.Writer, opts *DevTokenOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	body := map[string]interface{}{}
+	if opts.Subject != "" {
+		body["sub"] = opts.Subject
+	}
+	if len(opts.Scopes) > 0 {
+		body["scope"] = strings.Join(opts.Scopes, " ")
+	}
+	if opts.TTL > 0 {
+		if opts.TTL < time.Second {
+			return fmt.Errorf("--ttl must be at least 1s")
+		}
+		body["ttl"] = int64(opts.TTL / time.Second)
+	}
+	claims, err := parseDevTokenClaims(opts.Claims)
+	if err != nil {
+		return err
+	}
+	if len(claims) > 0 {
+		body["claims"] = claims
+	}
+
+	data, err := json.Marshal(body)
+	if err != nil {
+		return err
+	}
+	url := strings.TrimSuffix(opts.URL, "/") + devTokenPath
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, url, bytes.NewReader(data))
+	if err != nil {
+		return fmt.Errorf("invalid --url: %w", err)
+	}
+	req.Header.Set("Content-Type", "application/json")
+
+	client := &http.Client{Timeout: 10 * time.Second}
+	resp, err := client.Do(req)
+	if err != nil {
+		return fmt.Errorf("could not reach the app at %s, is 'ftl up' running? %w", opts.URL, err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	respBody, err := io.ReadAll(io.LimitReader(resp.Body, 1<<20))
+	if err != nil {
+		return fmt.Errorf("failed to read the token response: %w", err)
+	}
+
+	switch {
+	case resp.StatusCode == http.StatusOK:
+	case resp.StatusCode == http.StatusBadRequest:
+		var e struct {
+			Description string `json:"error_description"`
+		}
+		_ = json.Unmarshal(respBody, &e)
+		return fmt.Errorf("token request rejected: %s", e.Description)
+	case resp.StatusCode == http.StatusUnauthorized || resp.StatusCode == http.StatusNotFound:
+		return fmt.Errorf("the app at %s does not issue development tokens: set the authorizer's mcp_dev_tokens and mcp_dev_token_secret variables", opts.URL)
+	default:
+		return fmt.Errorf("token request failed with status %d: %s", resp.StatusCode, strings.TrimSpace(string(respBody)))
+	}
+
+	var token devTokenResponse
+	if err := json.Unmarshal(respBody, &token); err != nil || token.AccessToken == "" {
+		return fmt.Errorf("unexpected token response from %s", url)
+	}
+
+	if opts.Format == "json" {
+		return NewDataWriter(w, opts.Format).WriteStruct(token)
+	}
+	_, err = fmt.Fprintln(w, token.AccessToken)
+	return err
+}
+
+// parseDevTokenClaims parses key=value claims, keeping values that are valid
+// JSON (numbers, booleans, arrays, objects) typed and the rest as strings
+func parseDevTokenClaims(pairs []string) (map[string]interface{}, error) {
+	claims := make(map[string]interface{}, len(pairs))
+	for _, pair := range pairs {
+		key, value, ok := strings.Cut(pair, "=")
+		key = strings.TrimSpace(key)
+		if !ok || key == "" {
+			return nil, fmt.Errorf("invalid claim %q, expected key=value", pair)
+		}
+		var parsed interface{}
+		if err := json.Unmarshal([]byte(value), &parsed); err == nil {
+			claims[key] = parsed
+		} else {
+			claims[key] = value
+		}
+	}
+	return claims, nil
+}
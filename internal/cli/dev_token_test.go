@@ -0,0 +1,113 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// fakeAuthorizer serves /dev/token, recording the last request body
+func fakeAuthorizer(t *testing.T, status int, response string) (*httptest.Server, *map[string]interface{}) {
+	t.Helper()
+	received := map[string]interface{}{}
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, http.MethodPost, r.Method)
+		assert.Equal(t, "/dev/token", r.URL.Path)
+		_ = json.NewDecoder(r.Body).Decode(&received)
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(status)
+		_, _ = w.Write([]byte(response))
+	}))
+	t.Cleanup(server.Close)
+	return server, &received
+}
+
+func TestRunDevToken(t *testing.T) {
+	server, received := fakeAuthorizer(t, http.StatusOK,
+		`{"access_token":"eyJ.test.token","token_type":"Bearer","expires_in":300,"scope":"mcp:read mcp:write"}`)
+
+	var buf bytes.Buffer
+	err := runDevToken(context.Background(), &buf, &DevTokenOptions{
+		URL:     server.URL + "/",
+		Subject: "alice",
+		Scopes:  []string{"mcp:read", "mcp:write"},
+		TTL:     5 * time.Minute,
+		Claims:  []string{"org_id=org_123", "admin=true"},
+		Format:  "text",
+	})
+	require.NoError(t, err)
+
+	assert.Equal(t, "eyJ.test.token\n", buf.String())
+	assert.Equal(t, map[string]interface{}{
+		"sub":    "alice",
+		"scope":  "mcp:read mcp:write",
+		"ttl":    float64(300),
+		"claims": map[string]interface{}{"org_id": "org_123", "admin": true},
+	}, *received)
+}
+
+func TestRunDevToken_JSON(t *testing.T) {
+	server, received := fakeAuthorizer(t, http.StatusOK,
+		`{"access_token":"eyJ.test.token","token_type":"Bearer","expires_in":900}`)
+
+	var buf bytes.Buffer
+	err := runDevToken(context.Background(), &buf, &DevTokenOptions{URL: server.URL, Format: "json"})
+	require.NoError(t, err)
+	assert.Empty(t, *received, "Defaults are left to the authorizer")
+
+	var token devTokenResponse
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &token))
+	assert.Equal(t, "eyJ.test.token", token.AccessToken)
+	assert.Equal(t, int64(900), token.ExpiresIn)
+}
+
+func TestRunDevToken_Errors(t *testing.T) {
+	tests := []struct {
+		name     string
+		status   int
+		response string
+		wantErr  string
+	}{
+		{
+			name:     "rejected",
+			status:   http.StatusBadRequest,
+			response: `{"error":"invalid_request","error_description":"ttl must be between 1 and 900 seconds"}`,
+			wantErr:  "token request rejected: ttl must be between 1 and 900 seconds",
+		},
+		{
+			name:     "not enabled",
+			status:   http.StatusUnauthorized,
+			response: `{"error":"unauthorized"}`,
+			wantErr:  "does not issue development tokens",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			server, _ := fakeAuthorizer(t, tt.status, tt.response)
+			err := runDevToken(context.Background(), &bytes.Buffer{}, &DevTokenOptions{URL: server.URL})
+			assert.ErrorContains(t, err, tt.wantErr)
+		})
+	}
+}
+
+func TestParseDevTokenClaims(t *testing.T) {
+	claims, err := parseDevTokenClaims([]string{"org_id=org_123", "level=3", "roles=[\"admin\"]", "note=a=b"})
+	require.NoError(t, err)
+	assert.Equal(t, map[string]interface{}{
+		"org_id": "org_123",
+		"level":  float64(3),
+		"roles":  []interface{}{"admin"},
+		"note":   "a=b",
+	}, claims)
+
+	_, err = parseDevTokenClaims([]string{"novalue"})
+	assert.EqualError(t, err, `invalid claim "novalue", expected key=value`)
+}
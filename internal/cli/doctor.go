@@ -0,0 +1,175 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/internal/config"
+	"github.com/fastertools/ftl/internal/doctor"
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/internal/toolchain"
+)
+
+// defaultRegistry is the registry FTL pulls its own components from
+const defaultRegistry = commands.DefaultRegistry
+
+func newDoctorCmd() *cobra.Command {
+	var (
+		fix    bool
+		output string
+	)
+
+	cmd := &cobra.Command{
+		Use:   "doctor",
+		Short: "Diagnose the local development environment",
+		Long: `Run a set of checks against the local development environment:
+
+  - required tools (spin, cargo, wkg, docker) and the project's toolchain pins
+  - the wasm32-wasip1 Rust target
+  - connectivity to the component registries the project uses (skipped
+    when offline)
+  - availability of the OS keyring used to store credentials
+  - validity of the FTL user config and the project manifest
+
+Each problem comes with a suggested fix. With --fix, automatic remediations
+are applied, such as adding the Rust target or installing pinned tools.`,
+		Example: `  ftl doctor
+  ftl doctor --fix
+  ftl doctor -o json`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			checks, err := doctorChecks()
+			if err != nil {
+				return err
+			}
+
+			if fix {
+				Info("Applying automatic fixes where possible")
+			}
+			results := doctor.Run(cmd.Context(), checks, fix)
+			if err := writeDoctorResults(NewDataWriter(cmd.OutOrStdout(), output), results); err != nil {
+				return err
+			}
+
+			if doctor.Failed(results) {
+				return fmt.Errorf("environment has problems, see the suggestions above")
+			}
+			return nil
+		},
+	}
+
+	cmd.Flags().BoolVar(&fix, "fix", false, "apply automatic fixes")
+	cmd.Flags().StringVarP(&output, "output", "o", "table", "output format (table, json)")
+
+	return cmd
+}
+
+// doctorChecks assembles the checks for the current directory
+func doctorChecks() ([]doctor.Check, error) {
+	mgr, err := toolchain.NewManager()
+	if err != nil {
+		return nil, err
+	}
+	env := doctor.DefaultEnv(mgr)
+
+	// The project manifest is optional, doctor also runs outside a project
+	var project *manifest.Manifest
+	manifestPath := commands.FindDataConfig(".")
+	if manifestPath != "" {
+		if m, err := manifest.Load(manifestPath); err == nil {
+			project = m
+		}
+	}
+
+	tools := doctor.DefaultTools()
+	if project != nil {
+		tools = doctor.MergeRequirements(tools, toolchain.Requirements(project.Toolchain))
+	}
+
+	checks := make([]doctor.Check, 0, len(tools)+6)
+	for _, spec := range tools {
+		checks = append(checks, doctor.ToolCheck(env, spec))
+	}
+	checks = append(checks, doctor.WasmTargetCheck(env))
+	for _, registry := range doctorRegistries(project) {
+		if offlineMode() {
+			checks = append(checks, doctor.SkippedCheck("registry: "+registry, "offline"))
+			continue
+		}
+		checks = append(checks, doctor.RegistryCheck(env, registry))
+	}
+	checks = append(checks, doctor.KeyringCheck(auth.KeyringAvailable))
+
+	if path, err := config.Path(); err == nil {
+		checks = append(checks, doctor.FileCheck("config: user", path, config.Validate))
+	}
+	if manifestPath != "" {
+		checks = append(checks, doctor.FileCheck("config: project", manifestPath, func(path string) error {
+			_, err := manifest.Load(path)
+			return err
+		}))
+	}
+
+	return checks, nil
+}
+
+// doctorRegistries returns the default registry plus any registry the project pulls components from
+func doctorRegistries(project *manifest.Manifest) []string {
+	registries := []string{defaultRegistry}
+	if project == nil {
+		return registries
+	}
+
+	seen := map[string]bool{defaultRegistry: true}
+	for _, comp := range project.Components {
+		src, ok := comp.Source.(manifest.SourceRegistry)
+		if !ok || src.Registry == "" || seen[src.Registry] {
+			continue
+		}
+		seen[src.Registry] = true
+		registries = append(registries, src.Registry)
+	}
+	return registries
+}
+
+func writeDoctorResults(dw *DataWriter, results []doctor.Result) error {
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(results)
+	}
+
+	tb := NewTableBuilder("CHECK", "STATUS", "DETAILS")
+	for _, r := range results {
+		status := string(r.Severity)
+		if r.Fixed {
+			status = "fixed"
+		}
+		tb.AddRow(r.Name, status, r.Message)
+	}
+	if err := tb.Write(dw); err != nil {
+		return err
+	}
+
+	return writeDoctorSuggestions(dw.output, results)
+}
+
+func writeDoctorSuggestions(w io.Writer, results []doctor.Result) error {
+	header := false
+	for _, r := range results {
+		if r.Severity == doctor.SeverityOK || r.Suggestion == "" {
+			continue
+		}
+		if !header {
+			if _, err := fmt.Fprintln(w, "\nSuggested fixes:"); err != nil {
+				return err
+			}
+			header = true
+		}
+		if _, err := fmt.Fprintf(w, "  %s: %s\n", r.Name, r.Suggestion); err != nil {
+			return err
+		}
+	}
+	return nil
+}
@@ -0,0 +1,52 @@
+package cli
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/doctor"
+	"github.com/fastertools/ftl/internal/manifest"
+)
+
+func TestDoctorCommand(t *testing.T) {
+	cmd := newDoctorCmd()
+	assert.Equal(t, "doctor", cmd.Use)
+	assert.NotNil(t, cmd.Flags().Lookup("fix"))
+	assert.NotNil(t, cmd.Flags().Lookup("output"))
+}
+
+func TestDoctorRegistries(t *testing.T) {
+	assert.Equal(t, []string{"ghcr.io"}, doctorRegistries(nil))
+
+	project := &manifest.Manifest{
+		Components: []manifest.Component{
+			{ID: "local", Source: "./local"},
+			{ID: "a", Source: manifest.SourceRegistry{Registry: "registry.example.com", Package: "ns:a", Version: "1.0.0"}},
+			{ID: "b", Source: manifest.SourceRegistry{Registry: "ghcr.io", Package: "ns:b", Version: "1.0.0"}},
+			{ID: "c", Source: manifest.SourceRegistry{Registry: "registry.example.com", Package: "ns:c", Version: "1.0.0"}},
+		},
+	}
+	assert.Equal(t, []string{"ghcr.io", "registry.example.com"}, doctorRegistries(project))
+}
+
+func TestWriteDoctorResults(t *testing.T) {
+	results := []doctor.Result{
+		{Name: "tool: spin", Severity: doctor.SeverityOK, Message: "spin 3.3.1"},
+		{Name: "rust target: wasm32-wasip1", Severity: doctor.SeverityOK, Message: "installed", Fixed: true},
+		{Name: "keyring", Severity: doctor.SeverityFail, Message: "locked", Suggestion: "unlock the keychain"},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, writeDoctorResults(NewDataWriter(&buf, "table"), results))
+	assert.Contains(t, buf.String(), "fixed")
+	assert.Contains(t, buf.String(), "Suggested fixes:")
+	assert.Contains(t, buf.String(), "keyring: unlock the keychain")
+
+	buf.Reset()
+	require.NoError(t, writeDoctorResults(NewDataWriter(&buf, "json"), results))
+	assert.Contains(t, buf.String(), `"status": "fail"`)
+	assert.NotContains(t, buf.String(), "Suggested fixes:")
+}
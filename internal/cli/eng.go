@@ -0,0 +1,369 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/mail"
+	"strings"
+
+	"github.com/AlecAivazis/survey/v2"
+	openapi_types "github.com/oapi-codegen/runtime/types"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// orgAdminClient is the subset of the FTL API used to administer organizations
+type orgAdminClient interface {
+	ListOrgMembers(ctx context.Context, orgID string) (*api.ListOrgMembersResponseBody, error)
+	InviteOrgMember(ctx context.Context, orgID string, request api.InviteOrgMemberRequest) (*api.InviteOrgMemberResponseBody, error)
+	RemoveOrgMember(ctx context.Context, orgID, userID string) error
+	ListOrgRoles(ctx context.Context, orgID string) (*api.ListOrgRolesResponseBody, error)
+}
+
+// Allow overriding for tests
+var newOrgAdminClient = newOrgAdminClientImpl
+
+func newOrgAdminClientImpl(ctx context.Context) (orgAdminClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// newEngCmd creates the eng command group
+func newEngCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "eng",
+		Short: "Administer FTL Engine platform resources",
+		Long: `Administer FTL Engine platform resources.
+
+The eng commands manage platform-side resources that would otherwise require
+the web console, such as organization membership for org-mode access control.`,
+	}
+
+	cmd.AddCommand(
+		newEngNotificationsCmd(),
+		newEngOrgCmd(),
+		newEngPromoteCmd(),
+		newEngProtectCmd(),
+		newEngUnprotectCmd(),
+		newEngUsageCmd(),
+	)
+
+	return cmd
+}
+
+// newEngOrgCmd creates the 'eng org' command group
+func newEngOrgCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "org",
+		Short: "Manage organization membership and roles",
+		Long: `Manage organization membership and roles.
+
+Apps deployed with --access-control org are reachable by every member of the
+organization. These commands list, invite and remove members, and show the
+roles that can be assigned to them.
+
+Commands act on the current organization (see 'ftl org set') unless --org is given.`,
+	}
+
+	cmd.AddCommand(
+		newEngOrgListCmd(),
+		newEngOrgMembersCmd(),
+		newEngOrgInviteCmd(),
+		newEngOrgRemoveMemberCmd(),
+		newEngOrgRolesCmd(),
+	)
+
+	return cmd
+}
+
+// newEngOrgListCmd creates the 'eng org list' command
+func newEngOrgListCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:     "list",
+		Short:   "List organizations you belong to",
+		Aliases: []string{"ls"},
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runOrgList(cmd.Context(), true)
+		},
+	}
+}
+
+// newEngOrgMembersCmd creates the 'eng org members' command
+func newEngOrgMembersCmd() *cobra.Command {
+	var orgID string
+	var format string
+
+	cmd := &cobra.Command{
+		Use:   "members",
+		Short: "List organization members",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			org, err := resolveOrgID(orgID)
+			if err != nil {
+				return err
+			}
+			client, err := newOrgAdminClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngOrgMembers(cmd.Context(), client, cmd.OutOrStdout(), org, format)
+		},
+	}
+
+	cmd.Flags().StringVar(&orgID, "org", "", "Organization ID (defaults to the current organization)")
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+// newEngOrgInviteCmd creates the 'eng org invite' command
+func newEngOrgInviteCmd() *cobra.Command {
+	var orgID string
+	var role string
+
+	cmd := &cobra.Command{
+		Use:   "invite EMAIL",
+		Short: "Invite a user to the organization",
+		Long: `Invite a user to the organization by email.
+
+The invitee receives an email to join the organization. Use --role to assign a
+role other than the organization's default; 'ftl eng org roles' lists them.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			org, err := resolveOrgID(orgID)
+			if err != nil {
+				return err
+			}
+			client, err := newOrgAdminClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngOrgInvite(cmd.Context(), client, org, args[0], role)
+		},
+	}
+
+	cmd.Flags().StringVar(&orgID, "org", "", "Organization ID (defaults to the current organization)")
+	cmd.Flags().StringVar(&role, "role", "", "Role to assign to the new member")
+
+	return cmd
+}
+
+// newEngOrgRemoveMemberCmd creates the 'eng org remove-member' command
+func newEngOrgRemoveMemberCmd() *cobra.Command {
+	var orgID string
+	var yes bool
+
+	cmd := &cobra.Command{
+		Use:   "remove-member USER_ID|EMAIL",
+		Short: "Remove a member from the organization",
+		Long: `Remove a member from the organization.
+
+The member immediately loses access to the organization's org-mode apps.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			org, err := resolveOrgID(orgID)
+			if err != nil {
+				return err
+			}
+			client, err := newOrgAdminClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngOrgRemoveMember(cmd.Context(), client, org, args[0], yes)
+		},
+	}
+
+	cmd.Flags().StringVar(&orgID, "org", "", "Organization ID (defaults to the current organization)")
+	cmd.Flags().BoolVarP(&yes, "yes", "y", false, "Skip the confirmation prompt")
+
+	return cmd
+}
+
+// newEngOrgRolesCmd creates the 'eng org roles' command
+func newEngOrgRolesCmd() *cobra.Command {
+	var orgID string
+	var format string
+
+	cmd := &cobra.Command{
+		Use:   "roles",
+		Short: "List the roles available in the organization",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			org, err := resolveOrgID(orgID)
+			if err != nil {
+				return err
+			}
+			client, err := newOrgAdminClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngOrgRoles(cmd.Context(), client, cmd.OutOrStdout(), org, format)
+		},
+	}
+
+	cmd.Flags().StringVar(&orgID, "org", "", "Organization ID (defaults to the current organization)")
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+// resolveOrgID returns the explicit org ID or falls back to the current organization
+func resolveOrgID(orgID string) (string, error) {
+	if orgID != "" {
+		return orgID, nil
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return "", fmt.Errorf("failed to load config: %w", err)
+	}
+	if current := cfg.GetCurrentOrg(); current != "" {
+		return current, nil
+	}
+	return "", fmt.Errorf("no organization selected. Use --org or run 'ftl org set'")
+}
+
+func runEngOrgMembers(ctx context.Context, client orgAdminClient, w io.Writer, orgID, format string) error {
+	resp, err := client.ListOrgMembers(ctx, orgID)
+	if err != nil {
+		return fmt.Errorf("failed to list members: %w", err)
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(resp.Members)
+	}
+
+	if len(resp.Members) == 0 {
+		Info("No members found in organization %s", orgID)
+		return nil
+	}
+
+	tb := NewTableBuilder("USER ID", "EMAIL", "NAME", "ROLE", "STATUS", "JOINED")
+	for _, m := range resp.Members {
+		joined := "-"
+		if m.JoinedAt != nil {
+			joined = m.JoinedAt.Format("2006-01-02")
+		}
+		tb.AddRow(m.UserId, stringOr(m.Email, "-"), stringOr(m.Name, "-"), m.Role, string(m.Status), joined)
+	}
+	return tb.Write(dw)
+}
+
+func runEngOrgInvite(ctx context.Context, client orgAdminClient, orgID, email, role string) error {
+	addr, err := mail.ParseAddress(email)
+	if err != nil {
+		return fmt.Errorf("invalid email address '%s'", email)
+	}
+
+	request := api.InviteOrgMemberRequest{Email: openapi_types.Email(addr.Address)}
+	if role != "" {
+		request.Role = &role
+	}
+
+	invitation, err := client.InviteOrgMember(ctx, orgID, request)
+	if err != nil {
+		return fmt.Errorf("failed to invite member: %w", err)
+	}
+
+	Success("Invited %s to organization %s as %s", invitation.Email, orgID, invitation.Role)
+	if invitation.ExpiresAt != nil {
+		Info("The invitation expires on %s", invitation.ExpiresAt.Format("2006-01-02 15:04 MST"))
+	}
+	return nil
+}
+
+func runEngOrgRemoveMember(ctx context.Context, client orgAdminClient, orgID, member string, yes bool) error {
+	userID := member
+	display := member
+
+	// Members can also be addressed by email, resolved to a user ID via the member list
+	if strings.Contains(member, "@") {
+		resp, err := client.ListOrgMembers(ctx, orgID)
+		if err != nil {
+			return fmt.Errorf("failed to list members: %w", err)
+		}
+		userID = ""
+		for _, m := range resp.Members {
+			if m.Email != nil && strings.EqualFold(*m.Email, member) {
+				userID = m.UserId
+				display = fmt.Sprintf("%s (%s)", *m.Email, m.UserId)
+				break
+			}
+		}
+		if userID == "" {
+			return fmt.Errorf("no member with email '%s' in organization %s", member, orgID)
+		}
+	}
+
+	if !yes {
+		if !isInteractive() {
+			return fmt.Errorf("removing a member requires confirmation. Use --yes to skip confirmation in non-interactive mode")
+		}
+
+		confirm := false
+		prompt := &survey.Confirm{
+			Message: fmt.Sprintf("Remove %s from organization %s?", display, orgID),
+			Default: false,
+		}
+		if err := survey.AskOne(prompt, &confirm); err != nil {
+			return err
+		}
+		if !confirm {
+			Info("Removal cancelled")
+			return nil
+		}
+	}
+
+	if err := client.RemoveOrgMember(ctx, orgID, userID); err != nil {
+		return fmt.Errorf("failed to remove member: %w", err)
+	}
+
+	Success("Removed %s from organization %s", display, orgID)
+	return nil
+}
+
+func runEngOrgRoles(ctx context.Context, client orgAdminClient, w io.Writer, orgID, format string) error {
+	resp, err := client.ListOrgRoles(ctx, orgID)
+	if err != nil {
+		return fmt.Errorf("failed to list roles: %w", err)
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(resp.Roles)
+	}
+
+	tb := NewTableBuilder("ROLE", "NAME", "DESCRIPTION", "PERMISSIONS")
+	for _, r := range resp.Roles {
+		permissions := "-"
+		if r.Permissions != nil && len(*r.Permissions) > 0 {
+			permissions = strings.Join(*r.Permissions, ", ")
+		}
+		tb.AddRow(r.Slug, r.Name, stringOr(r.Description, "-"), permissions)
+	}
+	return tb.Write(dw)
+}
+
+// stringOr dereferences an optional string, using fallback when it is unset or empty
+func stringOr(s *string, fallback string) string {
+	if s == nil || *s == "" {
+		return fallback
+	}
+	return *s
+}
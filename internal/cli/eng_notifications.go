@@ -0,0 +1,416 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/url"
+	"strings"
+
+	"github.com/AlecAivazis/survey/v2"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+)
+
+// notificationEvents are the deployment lifecycle events webhooks can be notified of
+var notificationEvents = []api.NotificationEvent{
+	api.NotificationEventDeployStarted,
+	api.NotificationEventDeploySucceeded,
+	api.NotificationEventDeployFailed,
+}
+
+// notificationsClient is the subset of the FTL API used to manage webhook notifications
+type notificationsClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	ListAppNotifications(ctx context.Context, appID string) (*api.ListAppNotificationsResponseBody, error)
+	CreateAppNotification(ctx context.Context, appID string, request api.CreateAppNotificationRequest) (*api.AppNotification, error)
+	UpdateAppNotification(ctx context.Context, appID, notificationID string, request api.UpdateAppNotificationRequest) (*api.AppNotification, error)
+	DeleteAppNotification(ctx context.Context, appID, notificationID string) error
+}
+
+// deliveryClient is the subset of the FTL API used to report notification deliveries
+type deliveryClient interface {
+	ListDeploymentNotifications(ctx context.Context, appID, deploymentID string) (*api.ListDeploymentNotificationsResponseBody, error)
+}
+
+// Allow overriding for tests
+var newNotificationsClient = newNotificationsClientImpl
+
+func newNotificationsClientImpl(ctx context.Context) (notificationsClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// newEngNotificationsCmd creates the 'eng notifications' command group
+func newEngNotificationsCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "notifications",
+		Short: "Manage webhook notifications of deployment events",
+		Long: `Manage the webhooks notified when an engine is deployed.
+
+The platform posts an event to each webhook when a deployment of the engine
+starts (deploy.started), succeeds (deploy.succeeded) or fails (deploy.failed).
+Slack incoming webhook URLs receive Slack formatted messages, other URLs a
+JSON payload. 'ftl deploy' reports whether the events of a deployment were
+delivered.
+
+Example:
+  ftl eng notifications add my-app-prod --url https://hooks.slack.com/services/... --events deploy.succeeded,deploy.failed
+  ftl eng notifications list my-app-prod
+  ftl eng notifications remove my-app-prod ntf_123`,
+	}
+
+	cmd.AddCommand(
+		newEngNotificationsAddCmd(),
+		newEngNotificationsListCmd(),
+		newEngNotificationsUpdateCmd(),
+		newEngNotificationsRemoveCmd(),
+	)
+
+	return cmd
+}
+
+// newEngNotificationsAddCmd creates the 'eng notifications add' command
+func newEngNotificationsAddCmd() *cobra.Command {
+	var webhookURL string
+	var events []string
+
+	cmd := &cobra.Command{
+		Use:   "add ENGINE",
+		Short: "Notify a webhook of an engine's deployment events",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newNotificationsClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngNotificationsAdd(cmd.Context(), client, args[0], webhookURL, events)
+		},
+	}
+
+	cmd.Flags().StringVar(&webhookURL, "url", "", "Webhook URL to post events to")
+	cmd.Flags().StringSliceVar(&events, "events", nil, "Events to notify of (deploy.started, deploy.succeeded, deploy.failed; default all)")
+	_ = cmd.MarkFlagRequired("url")
+
+	return cmd
+}
+
+// newEngNotificationsListCmd creates the 'eng notifications list' command
+func newEngNotificationsListCmd() *cobra.Command {
+	var format string
+
+	cmd := &cobra.Command{
+		Use:     "list ENGINE",
+		Short:   "List the webhooks notified of an engine's deployment events",
+		Aliases: []string{"ls"},
+		Args:    cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newNotificationsClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngNotificationsList(cmd.Context(), client, cmd.OutOrStdout(), args[0], format)
+		},
+	}
+
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+// newEngNotificationsUpdateCmd creates the 'eng notifications update' command
+func newEngNotificationsUpdateCmd() *cobra.Command {
+	var webhookURL string
+	var events []string
+
+	cmd := &cobra.Command{
+		Use:   "update ENGINE NOTIFICATION_ID",
+		Short: "Change the URL or events of a webhook notification",
+		Args:  cobra.ExactArgs(2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newNotificationsClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngNotificationsUpdate(cmd.Context(), client, args[0], args[1], webhookURL, events)
+		},
+	}
+
+	cmd.Flags().StringVar(&webhookURL, "url", "", "New webhook URL")
+	cmd.Flags().StringSliceVar(&events, "events", nil, "New events to notify of (deploy.started, deploy.succeeded, deploy.failed)")
+
+	return cmd
+}
+
+// newEngNotificationsRemoveCmd creates the 'eng notifications remove' command
+func newEngNotificationsRemoveCmd() *cobra.Command {
+	var yes bool
+
+	cmd := &cobra.Command{
+		Use:     "remove ENGINE NOTIFICATION_ID",
+		Short:   "Stop notifying a webhook of an engine's deployment events",
+		Aliases: []string{"rm"},
+		Args:    cobra.ExactArgs(2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newNotificationsClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngNotificationsRemove(cmd.Context(), client, args[0], args[1], yes)
+		},
+	}
+
+	cmd.Flags().BoolVarP(&yes, "yes", "y", false, "Skip the confirmation prompt")
+
+	return cmd
+}
+
+func runEngNotificationsAdd(ctx context.Context, client notificationsClient, name, webhookURL string, events []string) error {
+	if err := validateWebhookURL(webhookURL); err != nil {
+		return err
+	}
+	parsed, err := parseNotificationEvents(events)
+	if err != nil {
+		return err
+	}
+	if len(parsed) == 0 {
+		parsed = notificationEvents
+	}
+
+	appID, err := findNotificationEngine(ctx, client, name)
+	if err != nil {
+		return err
+	}
+
+	notification, err := client.CreateAppNotification(ctx, appID, api.CreateAppNotificationRequest{
+		Url:    webhookURL,
+		Events: parsed,
+	})
+	if err != nil {
+		return fmt.Errorf("failed to add notification: %w", err)
+	}
+
+	Success("Notifying %s of %s deployments (%s)", redactWebhookURL(webhookURL), name, joinEvents(notification.Events))
+	Info("Notification ID: %s", notification.NotificationId)
+	return nil
+}
+
+func runEngNotificationsList(ctx context.Context, client notificationsClient, w io.Writer, name, format string) error {
+	appID, err := findNotificationEngine(ctx, client, name)
+	if err != nil {
+		return err
+	}
+
+	resp, err := client.ListAppNotifications(ctx, appID)
+	if err != nil {
+		return fmt.Errorf("failed to list notifications of %s: %w", name, err)
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(resp.Notifications)
+	}
+
+	if len(resp.Notifications) == 0 {
+		Info("No notifications for %s. Add one with 'ftl eng notifications add'", name)
+		return nil
+	}
+
+	tb := NewTableBuilder("ID", "URL", "EVENTS", "CREATED")
+	for _, n := range resp.Notifications {
+		tb.AddRow(n.NotificationId, redactWebhookURL(n.Url), joinEvents(n.Events), n.CreatedAt)
+	}
+	return tb.Write(dw)
+}
+
+func runEngNotificationsUpdate(ctx context.Context, client notificationsClient, name, notificationID, webhookURL string, events []string) error {
+	if webhookURL == "" && len(events) == 0 {
+		return fmt.Errorf("nothing to update. Use --url or --events")
+	}
+
+	request := api.UpdateAppNotificationRequest{}
+	if webhookURL != "" {
+		if err := validateWebhookURL(webhookURL); err != nil {
+			return err
+		}
+		request.Url = &webhookURL
+	}
+	if len(events) > 0 {
+		parsed, err := parseNotificationEvents(events)
+		if err != nil {
+			return err
+		}
+		request.Events = &parsed
+	}
+
+	appID, err := findNotificationEngine(ctx, client, name)
+	if err != nil {
+		return err
+	}
+
+	notification, err := client.UpdateAppNotification(ctx, appID, notificationID, request)
+	if err != nil {
+		return fmt.Errorf("failed to update notification: %w", err)
+	}
+
+	Success("Notifying %s of %s deployments (%s)", redactWebhookURL(notification.Url), name, joinEvents(notification.Events))
+	return nil
+}
+
+func runEngNotificationsRemove(ctx context.Context, client notificationsClient, name, notificationID string, yes bool) error {
+	appID, err := findNotificationEngine(ctx, client, name)
+	if err != nil {
+		return err
+	}
+
+	if !yes {
+		if !isInteractive() {
+			return fmt.Errorf("removing a notification requires confirmation. Use --yes to skip confirmation in non-interactive mode")
+		}
+
+		confirm := false
+		prompt := &survey.Confirm{
+			Message: fmt.Sprintf("Stop notifying %s of %s deployments?", notificationID, name),
+			Default: false,
+		}
+		if err := survey.AskOne(prompt, &confirm); err != nil {
+			return err
+		}
+		if !confirm {
+			Info("Removal cancelled")
+			return nil
+		}
+	}
+
+	if err := client.DeleteAppNotification(ctx, appID, notificationID); err != nil {
+		return fmt.Errorf("failed to remove notification: %w", err)
+	}
+
+	Success("Removed notification %s from %s", notificationID, name)
+	return nil
+}
+
+// findNotificationEngine returns the app ID of the engine named name
+func findNotificationEngine(ctx context.Context, client notificationsClient, name string) (string, error) {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &name})
+	if err != nil {
+		return "", fmt.Errorf("failed to look up %s: %w", name, err)
+	}
+	for _, app := range apps.Apps {
+		if app.AppName == name {
+			return app.AppId.String(), nil
+		}
+	}
+	return "", fmt.Errorf("engine %s not found", name)
+}
+
+// validateWebhookURL requires an absolute http(s) URL
+func validateWebhookURL(webhookURL string) error {
+	u, err := url.Parse(webhookURL)
+	if err != nil || (u.Scheme != "https" && u.Scheme != "http") || u.Host == "" {
+		return fmt.Errorf("invalid webhook URL %q: must be an http or https URL", webhookURL)
+	}
+	return nil
+}
+
+// parseNotificationEvents validates event names given as flags, dropping duplicates
+func parseNotificationEvents(events []string) ([]api.NotificationEvent, error) {
+	var parsed []api.NotificationEvent
+	seen := make(map[api.NotificationEvent]bool)
+	for _, e := range events {
+		event := api.NotificationEvent(strings.TrimSpace(e))
+		valid := false
+		for _, known := range notificationEvents {
+			if event == known {
+				valid = true
+				break
+			}
+		}
+		if !valid {
+			return nil, fmt.Errorf("unknown event %q (expected one of %s)", e, joinEvents(notificationEvents))
+		}
+		if !seen[event] {
+			seen[event] = true
+			parsed = append(parsed, event)
+		}
+	}
+	return parsed, nil
+}
+
+func joinEvents(events []api.NotificationEvent) string {
+	names := make([]string, len(events))
+	for i, e := range events {
+		names[i] = string(e)
+	}
+	return strings.Join(names, ", ")
+}
+
+// redactWebhookURL hides the path of a webhook URL, which for Slack and most
+// other services is the secret that authorizes posting to it
+func redactWebhookURL(webhookURL string) string {
+	u, err := url.Parse(webhookURL)
+	if err != nil || u.Host == "" {
+		return webhookURL
+	}
+	if u.Path == "" || u.Path == "/" {
+		return u.Scheme + "://" + u.Host
+	}
+	return u.Scheme + "://" + u.Host + "/…"
+}
+
+// showNotificationDeliveries reports the delivery of the webhook notifications
+// triggered by a deployment. Deliveries are made by the platform in the
+// background, so some may still be pending when the deployment completes.
+// Failing to fetch them never fails the deploy.
+func showNotificationDeliveries(ctx context.Context, client deliveryClient, appID, deploymentID string) {
+	resp, err := client.ListDeploymentNotifications(ctx, appID, deploymentID)
+	if err != nil {
+		Debug("Failed to get notification deliveries: %v", err)
+		return
+	}
+	for _, d := range resp.Deliveries {
+		switch d.Status {
+		case api.NotificationDeliveryStatusDelivered:
+			Success("%s", describeDelivery(d))
+		case api.NotificationDeliveryStatusFailed:
+			Warn("%s", describeDelivery(d))
+		default:
+			Info("%s", describeDelivery(d))
+		}
+	}
+}
+
+// describeDelivery renders a notification delivery as a status line
+func describeDelivery(d api.NotificationDelivery) string {
+	target := redactWebhookURL(d.Url)
+	switch d.Status {
+	case api.NotificationDeliveryStatusDelivered:
+		return fmt.Sprintf("Notified %s of %s", target, d.Event)
+	case api.NotificationDeliveryStatusFailed:
+		reason := "delivery failed"
+		switch {
+		case d.Error != nil && *d.Error != "":
+			reason = *d.Error
+		case d.ResponseStatus != nil:
+			reason = fmt.Sprintf("webhook returned %d", *d.ResponseStatus)
+		}
+		return fmt.Sprintf("Failed to notify %s of %s: %s", target, d.Event, reason)
+	default:
+		return fmt.Sprintf("Notifying %s of %s (pending)", target, d.Event)
+	}
+}
@@ -0,0 +1,178 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+)
+
+type fakeNotificationsClient struct {
+	notifications []api.AppNotification
+	created       []api.CreateAppNotificationRequest
+	updated       []api.UpdateAppNotificationRequest
+	deleted       []string
+}
+
+func (f *fakeNotificationsClient) ListApps(_ context.Context, _ *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	data, err := json.Marshal(map[string]interface{}{
+		"apps": []map[string]interface{}{
+			{"appId": prodAppID, "appName": "my-app-prod"},
+		},
+	})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakeNotificationsClient) ListAppNotifications(_ context.Context, appID string) (*api.ListAppNotificationsResponseBody, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	return &api.ListAppNotificationsResponseBody{Notifications: f.notifications}, nil
+}
+
+func (f *fakeNotificationsClient) CreateAppNotification(_ context.Context, appID string, request api.CreateAppNotificationRequest) (*api.AppNotification, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	f.created = append(f.created, request)
+	return &api.AppNotification{NotificationId: "ntf_1", Url: request.Url, Events: request.Events}, nil
+}
+
+func (f *fakeNotificationsClient) UpdateAppNotification(_ context.Context, appID, notificationID string, request api.UpdateAppNotificationRequest) (*api.AppNotification, error) {
+	if appID != prodAppID || notificationID != "ntf_1" {
+		return nil, assert.AnError
+	}
+	f.updated = append(f.updated, request)
+	notification := &api.AppNotification{NotificationId: notificationID, Url: "https://example.com/hook"}
+	if request.Url != nil {
+		notification.Url = *request.Url
+	}
+	if request.Events != nil {
+		notification.Events = *request.Events
+	}
+	return notification, nil
+}
+
+func (f *fakeNotificationsClient) DeleteAppNotification(_ context.Context, appID, notificationID string) error {
+	if appID != prodAppID {
+		return assert.AnError
+	}
+	f.deleted = append(f.deleted, notificationID)
+	return nil
+}
+
+func TestEngNotificationsCommands(t *testing.T) {
+	cmd := newEngCmd()
+	for _, name := range []string{"add", "list", "update", "remove"} {
+		sub, _, err := cmd.Find([]string{"notifications", name})
+		require.NoError(t, err)
+		assert.Equal(t, name, sub.Name())
+	}
+
+	add, _, err := cmd.Find([]string{"notifications", "add"})
+	require.NoError(t, err)
+	assert.NotNil(t, add.Flags().Lookup("url"))
+	assert.NotNil(t, add.Flags().Lookup("events"))
+}
+
+func TestRunEngNotificationsAdd(t *testing.T) {
+	client := &fakeNotificationsClient{}
+	ctx := context.Background()
+
+	require.NoError(t, runEngNotificationsAdd(ctx, client, "my-app-prod", "https://hooks.slack.com/services/T0/B0/XYZ",
+		[]string{"deploy.succeeded", " deploy.failed", "deploy.succeeded"}))
+	require.Len(t, client.created, 1)
+	assert.Equal(t, "https://hooks.slack.com/services/T0/B0/XYZ", client.created[0].Url)
+	assert.Equal(t, []api.NotificationEvent{api.NotificationEventDeploySucceeded, api.NotificationEventDeployFailed}, client.created[0].Events)
+
+	// Every event by default
+	require.NoError(t, runEngNotificationsAdd(ctx, client, "my-app-prod", "https://example.com/hook", nil))
+	assert.Equal(t, notificationEvents, client.created[1].Events)
+
+	err := runEngNotificationsAdd(ctx, client, "my-app-prod", "https://example.com/hook", []string{"deploy.finished"})
+	assert.ErrorContains(t, err, `unknown event "deploy.finished"`)
+
+	err = runEngNotificationsAdd(ctx, client, "my-app-prod", "hooks.slack.com/services/T0", nil)
+	assert.ErrorContains(t, err, "invalid webhook URL")
+
+	err = runEngNotificationsAdd(ctx, client, "my-app", "https://example.com/hook", nil)
+	assert.ErrorContains(t, err, "engine my-app not found")
+	assert.Len(t, client.created, 2)
+}
+
+func TestRunEngNotificationsList(t *testing.T) {
+	client := &fakeNotificationsClient{
+		notifications: []api.AppNotification{{
+			NotificationId: "ntf_1",
+			Url:            "https://hooks.slack.com/services/T0/B0/XYZ",
+			Events:         []api.NotificationEvent{api.NotificationEventDeployFailed},
+			CreatedAt:      "2026-10-01T00:00:00Z",
+		}},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, runEngNotificationsList(context.Background(), client, &buf, "my-app-prod", "table"))
+	out := buf.String()
+	assert.Contains(t, out, "ntf_1")
+	assert.Contains(t, out, "https://hooks.slack.com/…")
+	assert.NotContains(t, out, "XYZ", "webhook secrets should not be printed")
+	assert.Contains(t, out, "deploy.failed")
+
+	buf.Reset()
+	require.NoError(t, runEngNotificationsList(context.Background(), client, &buf, "my-app-prod", "json"))
+	var notifications []api.AppNotification
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &notifications))
+	require.Len(t, notifications, 1)
+	assert.Equal(t, "https://hooks.slack.com/services/T0/B0/XYZ", notifications[0].Url)
+}
+
+func TestRunEngNotificationsUpdateAndRemove(t *testing.T) {
+	client := &fakeNotificationsClient{}
+	ctx := context.Background()
+
+	err := runEngNotificationsUpdate(ctx, client, "my-app-prod", "ntf_1", "", nil)
+	assert.ErrorContains(t, err, "nothing to update")
+
+	require.NoError(t, runEngNotificationsUpdate(ctx, client, "my-app-prod", "ntf_1", "", []string{"deploy.started"}))
+	require.Len(t, client.updated, 1)
+	assert.Nil(t, client.updated[0].Url)
+	assert.Equal(t, []api.NotificationEvent{api.NotificationEventDeployStarted}, *client.updated[0].Events)
+
+	require.NoError(t, runEngNotificationsRemove(ctx, client, "my-app-prod", "ntf_1", true))
+	assert.Equal(t, []string{"ntf_1"}, client.deleted)
+}
+
+func TestDescribeDelivery(t *testing.T) {
+	url := "https://hooks.slack.com/services/T0/B0/XYZ"
+	status := 502
+	message := "connection refused"
+
+	assert.Equal(t, "Notified https://hooks.slack.com/… of deploy.succeeded", describeDelivery(api.NotificationDelivery{
+		Url: url, Event: api.NotificationEventDeploySucceeded, Status: api.NotificationDeliveryStatusDelivered,
+	}))
+	assert.Equal(t, "Failed to notify https://hooks.slack.com/… of deploy.failed: webhook returned 502", describeDelivery(api.NotificationDelivery{
+		Url: url, Event: api.NotificationEventDeployFailed, Status: api.NotificationDeliveryStatusFailed, ResponseStatus: &status,
+	}))
+	assert.Equal(t, "Failed to notify https://hooks.slack.com/… of deploy.failed: connection refused", describeDelivery(api.NotificationDelivery{
+		Url: url, Event: api.NotificationEventDeployFailed, Status: api.NotificationDeliveryStatusFailed, Error: &message,
+	}))
+	assert.Equal(t, "Notifying https://hooks.slack.com/… of deploy.succeeded (pending)", describeDelivery(api.NotificationDelivery{
+		Url: url, Event: api.NotificationEventDeploySucceeded, Status: api.NotificationDeliveryStatusPending,
+	}))
+}
+
+func TestRedactWebhookURL(t *testing.T) {
+	assert.Equal(t, "https://example.com", redactWebhookURL("https://example.com"))
+	assert.Equal(t, "https://example.com/…", redactWebhookURL("https://example.com/hooks/abc?token=1"))
+	assert.Equal(t, "not a url", redactWebhookURL("not a url"))
+}
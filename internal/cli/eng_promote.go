@@ -0,0 +1,418 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/AlecAivazis/survey/v2"
+	"github.com/briandowns/spinner"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/internal/deploy"
+	"github.com/fastertools/ftl/oci"
+)
+
+// promoteClient is the subset of the FTL API used to promote deployments
+type promoteClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	CreateDeployCredentials(ctx context.Context, appID string, components []string) (*api.CreateDeployCredentialsResponseBody, error)
+}
+
+// Allow overriding for tests
+var (
+	newPromoteClient = newPromoteClientImpl
+	copyComponent    = oci.CopyByDigest
+	deployPromotion  = func(ctx context.Context, request []byte, creds *api.CreateDeployCredentialsResponseBody, opts deploy.DeployOptions, callback func(deploy.StreamEvent)) error {
+		return deploy.NewStreamingDeployer().Deploy(ctx, request, creds, opts, callback)
+	}
+)
+
+func newPromoteClientImpl(ctx context.Context) (promoteClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	// Promotions typically run in CI, so accept M2M credentials like 'ftl deploy'
+	if auth.IsM2MConfigured() {
+		if err := authManager.LoginMachine(ctx); err != nil {
+			return nil, fmt.Errorf("failed to authenticate with M2M credentials: %w", err)
+		}
+	}
+	if _, err := authManager.GetToken(ctx); err != nil {
+		token := auth.GetM2MTokenFromEnv()
+		if token == "" {
+			return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+		}
+		if err := authManager.LoginMachineWithToken(ctx, token); err != nil {
+			return nil, fmt.Errorf("failed to authenticate with M2M token: %w", err)
+		}
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// PromoteOptions holds options for the eng promote command
+type PromoteOptions struct {
+	From         string
+	To           string
+	Dir          string // Project directory holding deployment records
+	RecordFile   string // Explicit source record, instead of the one in Dir
+	Environment  string
+	Variables    map[string]string
+	OrgID        string
+	ChangeReason string // Recorded with the deployment
+	Confirm      string // Target name, confirms promoting to a protected engine
+	DryRun       bool
+	Yes          bool
+}
+
+// newEngPromoteCmd creates the 'eng promote' command
+func newEngPromoteCmd() *cobra.Command {
+	opts := &PromoteOptions{Dir: "."}
+
+	cmd := &cobra.Command{
+		Use:   "promote FROM_ENGINE TO_ENGINE",
+		Short: "Deploy the components of one engine's last deployment to another",
+		Long: `Deploy the components of one engine's last deployment to another.
+
+'ftl deploy' records the digest of every component it pushes in
+.ftl/deployments/<app>.json. Promote copies exactly those digests into the
+target engine's registry namespace and deploys them with the same
+configuration, without rebuilding or re-pushing anything from this machine.
+
+Variables can be overridden for the target with --var. The target engine must
+already exist. Promoting to a protected engine (see 'ftl eng protect') requires
+typing its name, or passing it with --confirm.
+
+Example:
+  ftl deploy                                 # deploys my-app-staging
+  ftl eng promote my-app-staging my-app-prod
+  ftl eng promote my-app-staging my-app-prod --var LOG_LEVEL=warn --yes`,
+		Args: cobra.ExactArgs(2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.From, opts.To = args[0], args[1]
+			client, err := newPromoteClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngPromote(cmd.Context(), client, cmd.OutOrStdout(), opts)
+		},
+	}
+
+	cmd.Flags().StringVarP(&opts.Environment, "environment", "e", "production", "Deployment environment of the target engine")
+	cmd.Flags().StringToStringVar(&opts.Variables, "var", nil, "Override a variable on the target (can be used multiple times)")
+	cmd.Flags().StringVar(&opts.RecordFile, "record", "", "Deployment record to promote (defaults to .ftl/deployments/FROM_ENGINE.json)")
+	cmd.Flags().StringVar(&opts.OrgID, "org", "", "Organization ID for org-scoped engines (defaults to the source deployment's)")
+	cmd.Flags().StringVar(&opts.ChangeReason, "change-reason", "", "Reason for the change, recorded with the deployment")
+	cmd.Flags().StringVar(&opts.Confirm, "confirm", "", "Target engine name, to promote to a protected engine without a prompt")
+	cmd.Flags().BoolVar(&opts.DryRun, "dry-run", false, "Show what would be promoted without deploying")
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Skip the confirmation prompt")
+
+	return cmd
+}
+
+func runEngPromote(ctx context.Context, client promoteClient, w io.Writer, opts *PromoteOptions) error {
+	if opts.From == opts.To {
+		return fmt.Errorf("source and target engines must be different")
+	}
+
+	recordPath := opts.RecordFile
+	if recordPath == "" {
+		recordPath = deploy.RecordPath(opts.Dir, opts.From)
+	}
+	source, err := deploy.LoadRecord(recordPath)
+	if err != nil {
+		return fmt.Errorf("no deployment of %s to promote (%w). Deploy it with 'ftl deploy' first", opts.From, err)
+	}
+	if source.App != opts.From {
+		return fmt.Errorf("deployment record %s is for %s, not %s", recordPath, source.App, opts.From)
+	}
+
+	targetID, protected, err := findApp(ctx, client, opts.To)
+	if err != nil {
+		return err
+	}
+
+	orgID := opts.OrgID
+	if orgID == "" {
+		orgID = source.OrgID
+	}
+	if access, _ := source.Request["access"].(string); access == "org" && orgID == "" {
+		return fmt.Errorf("%s is org-scoped. Use --org to select the organization to deploy to", opts.From)
+	}
+
+	Info("Promoting %s (deployed %s) to %s", opts.From, source.DeployedAt.Local().Format("2006-01-02 15:04"), opts.To)
+	tb := NewTableBuilder("COMPONENT", "VERSION", "DIGEST")
+	for _, pin := range source.Components {
+		tb.AddRow(pin.ID, pin.Version, pin.Digest)
+	}
+	if err := tb.Write(NewDataWriter(w, "table")); err != nil {
+		return err
+	}
+
+	if opts.DryRun {
+		Info("Dry run: nothing was promoted")
+		return nil
+	}
+
+	if !opts.Yes {
+		if !isInteractive() {
+			return fmt.Errorf("promotion requires confirmation. Use --yes to skip confirmation in non-interactive mode")
+		}
+
+		confirm := false
+		prompt := &survey.Confirm{
+			Message: fmt.Sprintf("Deploy these components to %s (%s)?", opts.To, opts.Environment),
+			Default: false,
+		}
+		if err := survey.AskOne(prompt, &confirm); err != nil {
+			return err
+		}
+		if !confirm {
+			Info("Promotion cancelled")
+			return nil
+		}
+	}
+
+	// Protected engines always need the engine name, even with --yes
+	if protected {
+		if err := confirmProtectedEngine(opts.To, opts.Confirm, "promote to"); err != nil {
+			return err
+		}
+	}
+
+	record, deploymentURL, err := promoteRecord(ctx, client, source, promotionTarget{
+		Name:         opts.To,
+		AppID:        targetID,
+		OrgID:        orgID,
+		Environment:  opts.Environment,
+		Variables:    opts.Variables,
+		ChangeReason: opts.ChangeReason,
+	})
+	if err != nil {
+		return err
+	}
+
+	Success("Promoted %s to %s", opts.From, opts.To)
+	if record.DeploymentID != "" {
+		Info("Deployment ID: %s", record.DeploymentID)
+	}
+	if deploymentURL != "" {
+		Info("URL: %s", deploymentURL)
+	}
+
+	// Record the target too, so promotions can be chained (e.g. staging -> canary -> prod)
+	if _, err := deploy.SaveRecord(opts.Dir, record); err != nil {
+		Warn("Failed to save deployment record: %v", err)
+	}
+
+	return nil
+}
+
+// promotionTarget is the engine a recorded deployment is promoted to
+type promotionTarget struct {
+	Name         string
+	AppID        string
+	OrgID        string
+	Environment  string
+	Variables    map[string]string
+	ChangeReason string
+}
+
+// promoteRecord copies the pinned components of a recorded deployment into
+// the target engine's registry namespace and deploys them there. It returns
+// the record of the new deployment, not yet saved, and its URL.
+func promoteRecord(ctx context.Context, client promoteClient, source *deploy.Record, target promotionTarget) (*deploy.Record, string, error) {
+	componentIDs := make([]string, 0, len(source.Components))
+	for _, pin := range source.Components {
+		componentIDs = append(componentIDs, pin.ID)
+	}
+
+	sourceAuth, _, err := registryCredentials(ctx, client, source.AppID, componentIDs)
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to get registry credentials for %s: %w", source.App, err)
+	}
+	targetAuth, creds, err := registryCredentials(ctx, client, target.AppID, componentIDs)
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to get deployment credentials for %s: %w", target.Name, err)
+	}
+
+	// Copy each pinned artifact into the target's namespace, preserving its digest
+	pins := make([]deploy.ComponentPin, 0, len(source.Components))
+	for _, pin := range source.Components {
+		pinned := deploy.ComponentPin{
+			ID:          pin.ID,
+			Registry:    targetAuth.Registry,
+			Package:     fmt.Sprintf("%s:%s", creds.Registry.PackageNamespace, pin.ID),
+			Version:     pin.Version,
+			Digest:      pin.Digest,
+			FilesDigest: pin.FilesDigest,
+		}
+
+		Info("Copying %s@%s", pin.ID, pin.Digest)
+		src := fmt.Sprintf("%s@%s", pin.Repository(), pin.Digest)
+		dst := fmt.Sprintf("%s:%s", pinned.Repository(), pinned.Version)
+		if err := copyComponent(ctx, src, sourceAuth, dst, targetAuth); err != nil {
+			return nil, "", fmt.Errorf("failed to copy component %s: %w", pin.ID, err)
+		}
+		if pin.FilesDigest != "" {
+			src := fmt.Sprintf("%s@%s", pin.Repository(), pin.FilesDigest)
+			dst := fmt.Sprintf("%s:%s", pinned.Repository(), deploy.FilesVersion(pinned.Version))
+			if err := copyComponent(ctx, src, sourceAuth, dst, targetAuth); err != nil {
+				return nil, "", fmt.Errorf("failed to copy files of component %s: %w", pin.ID, err)
+			}
+		}
+		pins = append(pins, pinned)
+	}
+	Success("All components copied to %s", target.Name)
+
+	request, err := promotionRequest(source.Request, target.Name, pins, target.Variables)
+	if err != nil {
+		return nil, "", err
+	}
+	// The source's change reason describes the source deployment
+	delete(request, "change_reason")
+	if target.ChangeReason != "" {
+		request["change_reason"] = target.ChangeReason
+	}
+	requestJSON, err := json.Marshal(request)
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to marshal deployment request: %w", err)
+	}
+
+	sp := spinner.New(spinner.CharSets[14], 100*time.Millisecond)
+	sp.Suffix = " Starting deployment..."
+	sp.Start()
+
+	var deploymentID, deploymentURL string
+	err = deployPromotion(ctx, requestJSON, creds, deploy.DeployOptions{
+		Environment: target.Environment,
+		OrgID:       target.OrgID,
+	}, func(event deploy.StreamEvent) {
+		switch event.Type {
+		case "progress":
+			sp.Suffix = fmt.Sprintf(" %s", event.Message)
+		case "complete":
+			sp.Stop()
+			deploymentID = event.DeploymentID
+			deploymentURL = event.URL
+		case "error":
+			sp.Stop()
+			Error("Deployment failed: %s", event.Message)
+		}
+	})
+	sp.Stop()
+	if err != nil {
+		return nil, "", fmt.Errorf("deployment failed: %w", err)
+	}
+
+	return &deploy.Record{
+		App:          target.Name,
+		AppID:        target.AppID,
+		OrgID:        target.OrgID,
+		Environment:  target.Environment,
+		DeploymentID: deploymentID,
+		DeployedAt:   time.Now().UTC(),
+		ChangeReason: target.ChangeReason,
+		Components:   pins,
+		Request:      request,
+	}, deploymentURL, nil
+}
+
+// findApp returns the ID of the app with exactly the given name and whether
+// it is protected
+func findApp(ctx context.Context, client promoteClient, name string) (string, bool, error) {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &name})
+	if err != nil {
+		return "", false, fmt.Errorf("failed to look up %s: %w", name, err)
+	}
+	for _, app := range apps.Apps {
+		if app.AppName == name {
+			return app.AppId.String(), app.Protected != nil && *app.Protected, nil
+		}
+	}
+	return "", false, fmt.Errorf("engine %s not found. Create it with 'ftl deploy' before promoting to it", name)
+}
+
+// registryCredentials requests deployment credentials for an app and parses its registry auth
+func registryCredentials(ctx context.Context, client promoteClient, appID string, components []string) (*oci.ECRAuth, *api.CreateDeployCredentialsResponseBody, error) {
+	creds, err := client.CreateDeployCredentials(ctx, appID, components)
+	if err != nil {
+		return nil, nil, err
+	}
+	ecrAuth, err := oci.ParseECRToken(creds.Registry.RegistryUri, creds.Registry.AuthorizationToken)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to parse ECR credentials: %w", err)
+	}
+	return ecrAuth, creds, nil
+}
+
+// promotionRequest rewrites a recorded deployment request for the target
+// engine, pointing every component at its copied artifact
+func promotionRequest(recorded map[string]interface{}, target string, pins []deploy.ComponentPin, variables map[string]string) (map[string]interface{}, error) {
+	// Round-trip through JSON so the recorded request is never modified
+	data, err := json.Marshal(recorded)
+	if err != nil {
+		return nil, fmt.Errorf("failed to copy deployment request: %w", err)
+	}
+	var request map[string]interface{}
+	if err := json.Unmarshal(data, &request); err != nil {
+		return nil, fmt.Errorf("failed to copy deployment request: %w", err)
+	}
+
+	request["name"] = target
+
+	byID := make(map[string]deploy.ComponentPin, len(pins))
+	for _, pin := range pins {
+		byID[pin.ID] = pin
+	}
+	components, _ := request["components"].([]interface{})
+	for _, c := range components {
+		comp, ok := c.(map[string]interface{})
+		if !ok {
+			continue
+		}
+		id, _ := comp["id"].(string)
+		pin, ok := byID[id]
+		if !ok {
+			return nil, fmt.Errorf("deployment record has no pinned artifact for component %s", id)
+		}
+		comp["source"] = map[string]interface{}{
+			"registry": pin.Registry,
+			"package":  pin.Package,
+			"version":  pin.Version,
+		}
+		if files, ok := comp["files"].(map[string]interface{}); ok {
+			if pin.FilesDigest == "" {
+				return nil, fmt.Errorf("deployment record has no pinned files artifact for component %s", id)
+			}
+			files["registry"] = pin.Registry
+			files["package"] = pin.Package
+			files["version"] = deploy.FilesVersion(pin.Version)
+		}
+	}
+
+	if len(variables) > 0 {
+		merged, _ := request["variables"].(map[string]interface{})
+		if merged == nil {
+			merged = make(map[string]interface{}, len(variables))
+		}
+		for k, v := range variables {
+			merged[k] = v
+		}
+		request["variables"] = merged
+	}
+
+	return request, nil
+}
@@ -0,0 +1,280 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/deploy"
+	"github.com/fastertools/ftl/oci"
+)
+
+const (
+	stagingAppID = "11111111-1111-1111-1111-111111111111"
+	prodAppID    = "22222222-2222-2222-2222-222222222222"
+	testRegistry = "123456789.dkr.ecr.us-west-2.amazonaws.com"
+)
+
+type fakePromoteClient struct {
+	apps        map[string]string // name -> ID
+	protected   map[string]bool   // names of protected apps
+	credentials []string          // app IDs credentials were requested for
+}
+
+func (f *fakePromoteClient) ListApps(_ context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	var apps []map[string]interface{}
+	for name, id := range f.apps {
+		if params.Name == nil || *params.Name == name {
+			apps = append(apps, map[string]interface{}{"appId": id, "appName": name, "protected": f.protected[name]})
+		}
+	}
+	data, err := json.Marshal(map[string]interface{}{"apps": apps})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakePromoteClient) CreateDeployCredentials(_ context.Context, appID string, _ []string) (*api.CreateDeployCredentialsResponseBody, error) {
+	f.credentials = append(f.credentials, appID)
+	creds := &api.CreateDeployCredentialsResponseBody{}
+	creds.Registry.RegistryUri = testRegistry
+	creds.Registry.AuthorizationToken = base64.StdEncoding.EncodeToString([]byte("AWS:token-" + appID))
+	creds.Registry.PackageNamespace = "ns-" + appID[:4]
+	return creds, nil
+}
+
+func writeStagingRecord(t *testing.T, dir string) {
+	t.Helper()
+	_, err := deploy.SaveRecord(dir, &deploy.Record{
+		App:         "my-app-staging",
+		AppID:       stagingAppID,
+		Environment: "production",
+		DeployedAt:  time.Now().UTC(),
+		Components: []deploy.ComponentPin{{
+			ID:       "weather",
+			Registry: testRegistry,
+			Package:  "ns-1111:weather",
+			Version:  "1.2.0",
+			Digest:   "sha256:abc",
+		}},
+		Request: map[string]interface{}{
+			"name":    "my-app-staging",
+			"version": "1.2.0",
+			"access":  "public",
+			"components": []interface{}{
+				map[string]interface{}{
+					"id":        "weather",
+					"source":    map[string]interface{}{"registry": testRegistry, "package": "ns-1111:weather", "version": "1.2.0"},
+					"variables": map[string]interface{}{"units": "metric"},
+				},
+			},
+			"variables": map[string]interface{}{"LOG_LEVEL": "debug", "REGION": "us"},
+		},
+	})
+	require.NoError(t, err)
+}
+
+// stubPromotion replaces the registry copy and deployment with recorders
+func stubPromotion(t *testing.T) (copies *[]string, request *map[string]interface{}, deployOpts *deploy.DeployOptions) {
+	t.Helper()
+	origCopy, origDeploy := copyComponent, deployPromotion
+	t.Cleanup(func() {
+		copyComponent, deployPromotion = origCopy, origDeploy
+	})
+
+	copies = &[]string{}
+	request = &map[string]interface{}{}
+	deployOpts = &deploy.DeployOptions{}
+
+	copyComponent = func(_ context.Context, src string, srcAuth *oci.ECRAuth, dst string, dstAuth *oci.ECRAuth) error {
+		*copies = append(*copies, fmt.Sprintf("%s -> %s (%s -> %s)", src, dst, srcAuth.Password, dstAuth.Password))
+		return nil
+	}
+	deployPromotion = func(_ context.Context, body []byte, _ *api.CreateDeployCredentialsResponseBody, opts deploy.DeployOptions, callback func(deploy.StreamEvent)) error {
+		*deployOpts = opts
+		if err := json.Unmarshal(body, request); err != nil {
+			return err
+		}
+		callback(deploy.StreamEvent{Type: "complete", DeploymentID: "dep-789", URL: "https://prod.example.com"})
+		return nil
+	}
+	return copies, request, deployOpts
+}
+
+func TestEngPromoteCommand(t *testing.T) {
+	cmd := newEngCmd()
+	promote, _, err := cmd.Find([]string{"promote"})
+	require.NoError(t, err)
+	assert.Equal(t, "promote", promote.Name())
+
+	for _, flag := range []string{"environment", "var", "record", "org", "change-reason", "confirm", "dry-run", "yes"} {
+		assert.NotNil(t, promote.Flags().Lookup(flag), flag)
+	}
+}
+
+func TestRunEngPromote(t *testing.T) {
+	dir := t.TempDir()
+	writeStagingRecord(t, dir)
+	copies, request, deployOpts := stubPromotion(t)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app-staging": stagingAppID, "my-app-prod": prodAppID}}
+	var buf bytes.Buffer
+	err := runEngPromote(context.Background(), client, &buf, &PromoteOptions{
+		From:        "my-app-staging",
+		To:          "my-app-prod",
+		Dir:         dir,
+		Environment: "production",
+		Variables:   map[string]string{"LOG_LEVEL": "warn"},
+		Yes:         true,
+	})
+	require.NoError(t, err)
+
+	assert.Contains(t, buf.String(), "sha256:abc")
+	assert.Equal(t, []string{stagingAppID, prodAppID}, client.credentials)
+
+	// The pinned digest is copied, not the tag
+	assert.Equal(t, []string{
+		testRegistry + "/ns-1111/weather@sha256:abc -> " + testRegistry + "/ns-2222/weather:1.2.0 (token-" + stagingAppID + " -> token-" + prodAppID + ")",
+	}, *copies)
+
+	assert.Equal(t, "production", deployOpts.Environment)
+	assert.Equal(t, "my-app-prod", (*request)["name"])
+	assert.Equal(t, map[string]interface{}{"LOG_LEVEL": "warn", "REGION": "us"}, (*request)["variables"])
+	comp := (*request)["components"].([]interface{})[0].(map[string]interface{})
+	assert.Equal(t, map[string]interface{}{"registry": testRegistry, "package": "ns-2222:weather", "version": "1.2.0"}, comp["source"])
+	assert.Equal(t, map[string]interface{}{"units": "metric"}, comp["variables"])
+
+	// The target gets its own record so promotions can be chained
+	record, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app-prod"))
+	require.NoError(t, err)
+	assert.Equal(t, prodAppID, record.AppID)
+	assert.Equal(t, "dep-789", record.DeploymentID)
+	assert.Equal(t, "sha256:abc", record.Components[0].Digest)
+	assert.Equal(t, "ns-2222:weather", record.Components[0].Package)
+
+	// The source record is left untouched
+	source, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app-staging"))
+	require.NoError(t, err)
+	assert.Equal(t, "my-app-staging", source.Request["name"])
+}
+
+func TestRunEngPromote_DryRun(t *testing.T) {
+	dir := t.TempDir()
+	writeStagingRecord(t, dir)
+	copies, _, _ := stubPromotion(t)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app-prod": prodAppID}}
+	var buf bytes.Buffer
+	err := runEngPromote(context.Background(), client, &buf, &PromoteOptions{
+		From: "my-app-staging", To: "my-app-prod", Dir: dir, DryRun: true,
+	})
+	require.NoError(t, err)
+
+	assert.Contains(t, buf.String(), "weather")
+	assert.Empty(t, client.credentials)
+	assert.Empty(t, *copies)
+}
+
+func TestRunEngPromote_ProtectedTarget(t *testing.T) {
+	dir := t.TempDir()
+	writeStagingRecord(t, dir)
+	_, request, _ := stubPromotion(t)
+
+	client := &fakePromoteClient{
+		apps:      map[string]string{"my-app-prod": prodAppID},
+		protected: map[string]bool{"my-app-prod": true},
+	}
+	run := func(opts PromoteOptions) error {
+		opts.From, opts.To, opts.Dir, opts.Yes = "my-app-staging", "my-app-prod", dir, true
+		var buf bytes.Buffer
+		return runEngPromote(context.Background(), client, &buf, &opts)
+	}
+
+	// --yes does not skip the typed confirmation
+	err := run(PromoteOptions{Confirm: "my-app-staging"})
+	assert.ErrorContains(t, err, "does not match protected engine my-app-prod")
+	assert.Empty(t, client.credentials)
+
+	err = run(PromoteOptions{Confirm: "my-app-prod", ChangeReason: "Ship weather v1.2"})
+	require.NoError(t, err)
+	assert.Equal(t, "Ship weather v1.2", (*request)["change_reason"])
+
+	record, err := deploy.LoadRecord(deploy.RecordPath(dir, "my-app-prod"))
+	require.NoError(t, err)
+	assert.Equal(t, "Ship weather v1.2", record.ChangeReason)
+}
+
+func TestRunEngPromote_Errors(t *testing.T) {
+	dir := t.TempDir()
+	writeStagingRecord(t, dir)
+	stubPromotion(t)
+
+	client := &fakePromoteClient{apps: map[string]string{"my-app-prod": prodAppID}}
+	run := func(opts PromoteOptions) error {
+		opts.Dir = dir
+		opts.Yes = true
+		var buf bytes.Buffer
+		return runEngPromote(context.Background(), client, &buf, &opts)
+	}
+
+	err := run(PromoteOptions{From: "my-app-prod", To: "my-app-prod"})
+	assert.ErrorContains(t, err, "must be different")
+
+	err = run(PromoteOptions{From: "my-app-dev", To: "my-app-prod"})
+	assert.ErrorContains(t, err, "no deployment of my-app-dev to promote")
+
+	err = run(PromoteOptions{From: "my-app-staging", To: "my-app-qa"})
+	assert.ErrorContains(t, err, "engine my-app-qa not found")
+
+	err = run(PromoteOptions{From: "other-app", To: "my-app-prod", RecordFile: deploy.RecordPath(dir, "my-app-staging")})
+	assert.ErrorContains(t, err, "is for my-app-staging, not other-app")
+}
+
+func TestPromotionRequest_MissingPin(t *testing.T) {
+	recorded := map[string]interface{}{
+		"components": []interface{}{map[string]interface{}{"id": "weather"}},
+	}
+	_, err := promotionRequest(recorded, "my-app-prod", nil, nil)
+	assert.ErrorContains(t, err, "no pinned artifact for component weather")
+}
+
+func TestPromotionRequest_Files(t *testing.T) {
+	recorded := map[string]interface{}{
+		"components": []interface{}{map[string]interface{}{
+			"id":     "spell",
+			"source": map[string]interface{}{"registry": testRegistry, "package": "ns-1111:spell", "version": "1.2.0"},
+			"files": map[string]interface{}{
+				"registry": testRegistry,
+				"package":  "ns-1111:spell",
+				"version":  "1.2.0-files",
+				"mounts":   []interface{}{"/dictionaries"},
+			},
+		}},
+	}
+	pin := deploy.ComponentPin{ID: "spell", Registry: "prod.example.com", Package: "ns-2222:spell", Version: "1.2.0", Digest: "sha256:abc"}
+
+	_, err := promotionRequest(recorded, "my-app-prod", []deploy.ComponentPin{pin}, nil)
+	assert.ErrorContains(t, err, "no pinned files artifact for component spell")
+
+	pin.FilesDigest = "sha256:def"
+	request, err := promotionRequest(recorded, "my-app-prod", []deploy.ComponentPin{pin}, nil)
+	require.NoError(t, err)
+	comp := request["components"].([]interface{})[0].(map[string]interface{})
+	assert.Equal(t, map[string]interface{}{
+		"registry": "prod.example.com",
+		"package":  "ns-2222:spell",
+		"version":  "1.2.0-files",
+		"mounts":   []interface{}{"/dictionaries"},
+	}, comp["files"])
+}
@@ -0,0 +1,177 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/AlecAivazis/survey/v2"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+)
+
+// protectClient is the subset of the FTL API used to protect engines
+type protectClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	UpdateApp(ctx context.Context, appID string, request api.UpdateAppRequest) (*api.App, error)
+}
+
+// Allow overriding for tests
+var newProtectClient = newProtectClientImpl
+
+func newProtectClientImpl(ctx context.Context) (protectClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// newEngProtectCmd creates the 'eng protect' command
+func newEngProtectCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "protect ENGINE",
+		Short: "Require typed confirmation to deploy or delete an engine",
+		Long: `Mark an engine as protected.
+
+Deploying, promoting to or deleting a protected engine requires typing the
+engine name, or passing it with --confirm in non-interactive mode. --yes and
+--force do not skip this confirmation. Protected engines also refuse
+--access-control public unless --allow-public is given.
+
+Example:
+  ftl eng protect my-app-prod
+  ftl deploy --confirm my-app-prod --change-reason "Fix token refresh"
+  ftl eng unprotect my-app-prod`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newProtectClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngProtect(cmd.Context(), client, args[0], true, "")
+		},
+	}
+
+	return cmd
+}
+
+// newEngUnprotectCmd creates the 'eng unprotect' command
+func newEngUnprotectCmd() *cobra.Command {
+	var confirm string
+
+	cmd := &cobra.Command{
+		Use:   "unprotect ENGINE",
+		Short: "Remove deployment protection from an engine",
+		Long: `Remove deployment protection from an engine.
+
+Like deploying to a protected engine, this requires typing the engine name,
+or passing it with --confirm in non-interactive mode.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newProtectClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngProtect(cmd.Context(), client, args[0], false, confirm)
+		},
+	}
+
+	cmd.Flags().StringVar(&confirm, "confirm", "", "Engine name, to confirm without a prompt")
+
+	return cmd
+}
+
+func runEngProtect(ctx context.Context, client protectClient, name string, protect bool, confirm string) error {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &name})
+	if err != nil {
+		return fmt.Errorf("failed to look up %s: %w", name, err)
+	}
+
+	var appID string
+	var protected bool
+	for _, app := range apps.Apps {
+		if app.AppName == name {
+			appID = app.AppId.String()
+			protected = app.Protected != nil && *app.Protected
+			break
+		}
+	}
+	if appID == "" {
+		return fmt.Errorf("engine %s not found", name)
+	}
+
+	if protected == protect {
+		if protect {
+			Info("%s is already protected", name)
+		} else {
+			Info("%s is not protected", name)
+		}
+		return nil
+	}
+
+	if !protect {
+		if err := confirmProtectedEngine(name, confirm, "unprotect"); err != nil {
+			return err
+		}
+	}
+
+	if _, err := client.UpdateApp(ctx, appID, api.UpdateAppRequest{Protected: &protect}); err != nil {
+		return fmt.Errorf("failed to update %s: %w", name, err)
+	}
+
+	if protect {
+		Success("%s is now protected", name)
+	} else {
+		Success("%s is no longer protected", name)
+	}
+	return nil
+}
+
+// confirmProtectedEngine requires the name of a protected engine to be typed,
+// or given with --confirm, before the engine is changed
+func confirmProtectedEngine(name, confirm, action string) error {
+	if confirm != "" {
+		if confirm != name {
+			return fmt.Errorf("--confirm %s does not match protected engine %s", confirm, name)
+		}
+		return nil
+	}
+
+	if !isInteractive() {
+		return fmt.Errorf("%s is protected. Use --confirm %s to %s it in non-interactive mode", name, name, action)
+	}
+
+	Warn("%s is a protected engine", name)
+	var typed string
+	prompt := &survey.Input{
+		Message: fmt.Sprintf("Type '%s' to %s it:", name, action),
+	}
+	if err := survey.AskOne(prompt, &typed); err != nil {
+		return fmt.Errorf("failed to get confirmation: %w", err)
+	}
+	if typed != name {
+		return fmt.Errorf("confirmation did not match %s, nothing was changed", name)
+	}
+	return nil
+}
+
+// checkProtectedAccess refuses to make a protected engine public unless
+// explicitly allowed
+func checkProtectedAccess(name, accessControl string, allowPublic bool) error {
+	if accessControl == "public" && !allowPublic {
+		return fmt.Errorf("%s is protected: refusing --access-control public without --allow-public", name)
+	}
+	return nil
+}
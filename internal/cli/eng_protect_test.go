@@ -0,0 +1,95 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+)
+
+type fakeProtectClient struct {
+	protected bool
+	updates   []bool // protected values sent with UpdateApp
+}
+
+func (f *fakeProtectClient) ListApps(_ context.Context, _ *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	data, err := json.Marshal(map[string]interface{}{
+		"apps": []map[string]interface{}{
+			{"appId": prodAppID, "appName": "my-app-prod", "protected": f.protected},
+			{"appId": stagingAppID, "appName": "my-app-prod-staging"},
+		},
+	})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakeProtectClient) UpdateApp(_ context.Context, appID string, request api.UpdateAppRequest) (*api.App, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	f.protected = *request.Protected
+	f.updates = append(f.updates, *request.Protected)
+	return &api.App{AppName: "my-app-prod", Protected: request.Protected}, nil
+}
+
+func TestEngProtectCommands(t *testing.T) {
+	cmd := newEngCmd()
+	for _, name := range []string{"protect", "unprotect"} {
+		sub, _, err := cmd.Find([]string{name})
+		require.NoError(t, err)
+		assert.Equal(t, name, sub.Name())
+	}
+
+	unprotect, _, err := cmd.Find([]string{"unprotect"})
+	require.NoError(t, err)
+	assert.NotNil(t, unprotect.Flags().Lookup("confirm"))
+}
+
+func TestRunEngProtect(t *testing.T) {
+	client := &fakeProtectClient{}
+	ctx := context.Background()
+
+	require.NoError(t, runEngProtect(ctx, client, "my-app-prod", true, ""))
+	assert.Equal(t, []bool{true}, client.updates)
+
+	// Protecting again is a no-op
+	require.NoError(t, runEngProtect(ctx, client, "my-app-prod", true, ""))
+	assert.Len(t, client.updates, 1)
+
+	// Unprotecting requires the engine name
+	err := runEngProtect(ctx, client, "my-app-prod", false, "my-app")
+	assert.ErrorContains(t, err, "does not match protected engine my-app-prod")
+	assert.True(t, client.protected)
+
+	require.NoError(t, runEngProtect(ctx, client, "my-app-prod", false, "my-app-prod"))
+	assert.Equal(t, []bool{true, false}, client.updates)
+
+	err = runEngProtect(ctx, client, "my-app", true, "")
+	assert.ErrorContains(t, err, "engine my-app not found")
+}
+
+func TestConfirmProtectedEngine_NonInteractive(t *testing.T) {
+	if isInteractive() {
+		t.Skip("test requires a non-interactive stdin")
+	}
+	err := confirmProtectedEngine("my-app-prod", "", "deploy to")
+	assert.ErrorContains(t, err, "Use --confirm my-app-prod to deploy to it")
+
+	assert.NoError(t, confirmProtectedEngine("my-app-prod", "my-app-prod", "deploy to"))
+}
+
+func TestCheckProtectedAccess(t *testing.T) {
+	assert.NoError(t, checkProtectedAccess("my-app-prod", "private", false))
+	assert.NoError(t, checkProtectedAccess("my-app-prod", "public", true))
+
+	err := checkProtectedAccess("my-app-prod", "public", false)
+	assert.ErrorContains(t, err, "without --allow-public")
+}
@@ -0,0 +1,143 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+)
+
+type fakeOrgAdminClient struct {
+	members []api.OrgMember
+	roles   []api.OrgRole
+	invited []api.InviteOrgMemberRequest
+	removed []string
+	err     error
+}
+
+func (f *fakeOrgAdminClient) ListOrgMembers(_ context.Context, _ string) (*api.ListOrgMembersResponseBody, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	return &api.ListOrgMembersResponseBody{Members: f.members}, nil
+}
+
+func (f *fakeOrgAdminClient) InviteOrgMember(_ context.Context, _ string, request api.InviteOrgMemberRequest) (*api.InviteOrgMemberResponseBody, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	f.invited = append(f.invited, request)
+	role := "member"
+	if request.Role != nil {
+		role = *request.Role
+	}
+	return &api.InviteOrgMemberResponseBody{InvitationId: "inv_1", Email: string(request.Email), Role: role}, nil
+}
+
+func (f *fakeOrgAdminClient) RemoveOrgMember(_ context.Context, _ string, userID string) error {
+	if f.err != nil {
+		return f.err
+	}
+	f.removed = append(f.removed, userID)
+	return nil
+}
+
+func (f *fakeOrgAdminClient) ListOrgRoles(_ context.Context, _ string) (*api.ListOrgRolesResponseBody, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	return &api.ListOrgRolesResponseBody{Roles: f.roles}, nil
+}
+
+func TestEngCommand(t *testing.T) {
+	cmd := newEngCmd()
+	assert.Equal(t, "eng", cmd.Use)
+
+	org, _, err := cmd.Find([]string{"org"})
+	require.NoError(t, err)
+
+	names := make([]string, 0, len(org.Commands()))
+	for _, sub := range org.Commands() {
+		names = append(names, sub.Name())
+	}
+	assert.ElementsMatch(t, []string{"list", "members", "invite", "remove-member", "roles"}, names)
+}
+
+func TestRunEngOrgMembers(t *testing.T) {
+	client := &fakeOrgAdminClient{
+		members: []api.OrgMember{
+			{UserId: "user_1", Email: ptr("alice@example.com"), Role: "admin", Status: api.OrgMemberStatusActive},
+			{UserId: "user_2", Role: "member", Status: api.OrgMemberStatusPending},
+		},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, runEngOrgMembers(context.Background(), client, &buf, "org_1", "table"))
+	assert.Contains(t, buf.String(), "alice@example.com")
+	assert.Contains(t, buf.String(), "pending")
+
+	buf.Reset()
+	require.NoError(t, runEngOrgMembers(context.Background(), client, &buf, "org_1", "json"))
+	assert.Contains(t, buf.String(), `"userId": "user_2"`)
+}
+
+func TestRunEngOrgInvite(t *testing.T) {
+	client := &fakeOrgAdminClient{}
+
+	require.NoError(t, runEngOrgInvite(context.Background(), client, "org_1", "Bob <bob@example.com>", "admin"))
+	require.Len(t, client.invited, 1)
+	assert.Equal(t, "bob@example.com", string(client.invited[0].Email))
+	require.NotNil(t, client.invited[0].Role)
+	assert.Equal(t, "admin", *client.invited[0].Role)
+
+	require.NoError(t, runEngOrgInvite(context.Background(), client, "org_1", "carol@example.com", ""))
+	assert.Nil(t, client.invited[1].Role)
+
+	err := runEngOrgInvite(context.Background(), client, "org_1", "not-an-email", "")
+	assert.ErrorContains(t, err, "invalid email address")
+}
+
+func TestRunEngOrgRemoveMember(t *testing.T) {
+	client := &fakeOrgAdminClient{
+		members: []api.OrgMember{
+			{UserId: "user_1", Email: ptr("alice@example.com"), Role: "admin", Status: api.OrgMemberStatusActive},
+		},
+	}
+
+	require.NoError(t, runEngOrgRemoveMember(context.Background(), client, "org_1", "user_9", true))
+	require.NoError(t, runEngOrgRemoveMember(context.Background(), client, "org_1", "Alice@Example.com", true))
+	assert.Equal(t, []string{"user_9", "user_1"}, client.removed)
+
+	err := runEngOrgRemoveMember(context.Background(), client, "org_1", "nobody@example.com", true)
+	assert.ErrorContains(t, err, "no member with email")
+
+	client.err = errors.New("forbidden")
+	err = runEngOrgRemoveMember(context.Background(), client, "org_1", "user_1", true)
+	assert.ErrorContains(t, err, "forbidden")
+}
+
+func TestRunEngOrgRoles(t *testing.T) {
+	permissions := []string{"apps:read", "apps:write"}
+	client := &fakeOrgAdminClient{
+		roles: []api.OrgRole{
+			{Slug: "admin", Name: "Admin", Permissions: &permissions},
+			{Slug: "member", Name: "Member"},
+		},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, runEngOrgRoles(context.Background(), client, &buf, "org_1", "table"))
+	assert.Contains(t, buf.String(), "apps:read, apps:write")
+	assert.Contains(t, buf.String(), "member")
+}
+
+func TestResolveOrgID(t *testing.T) {
+	org, err := resolveOrgID("org_explicit")
+	require.NoError(t, err)
+	assert.Equal(t, "org_explicit", org)
+}
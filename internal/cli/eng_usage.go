@@ -0,0 +1,155 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"sort"
+	"strconv"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+)
+
+// usageClient is the subset of the FTL API used to report engine usage
+type usageClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	GetAppUsage(ctx context.Context, appID, since string) (*api.GetAppUsageResponseBody, error)
+}
+
+// Allow overriding for tests
+var newUsageClient = newUsageClientImpl
+
+func newUsageClientImpl(ctx context.Context) (usageClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+// newEngUsageCmd creates the 'eng usage' command
+func newEngUsageCmd() *cobra.Command {
+	var since string
+	var format string
+
+	cmd := &cobra.Command{
+		Use:   "usage ENGINE",
+		Short: "Show request, compute and egress usage per component",
+		Long: `Show the usage of an engine over a time range, broken down per component.
+
+Requests, compute time and outbound traffic are reported for each tool
+component, along with its share of the engine's compute time, so cost can be
+attributed to specific tools.
+
+Example:
+  ftl eng usage my-app-prod
+  ftl eng usage my-app-prod --since 7d -o json`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			client, err := newUsageClient(cmd.Context())
+			if err != nil {
+				return err
+			}
+			return runEngUsage(cmd.Context(), client, cmd.OutOrStdout(), args[0], since, format)
+		},
+	}
+
+	cmd.Flags().StringVar(&since, "since", "30d", "Time range for usage (e.g., '24h', '7d', '30d', or RFC3339/Unix timestamp)")
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+func runEngUsage(ctx context.Context, client usageClient, w io.Writer, name, since, format string) error {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &name})
+	if err != nil {
+		return fmt.Errorf("failed to look up %s: %w", name, err)
+	}
+
+	var appID string
+	for _, app := range apps.Apps {
+		if app.AppName == name {
+			appID = app.AppId.String()
+			break
+		}
+	}
+	if appID == "" {
+		return fmt.Errorf("engine %s not found", name)
+	}
+
+	usage, err := client.GetAppUsage(ctx, appID, since)
+	if err != nil {
+		return fmt.Errorf("failed to get usage of %s: %w", name, err)
+	}
+
+	// Most expensive components first
+	components := append([]api.ComponentUsage(nil), usage.Components...)
+	sort.SliceStable(components, func(i, j int) bool {
+		if components[i].ComputeMs != components[j].ComputeMs {
+			return components[i].ComputeMs > components[j].ComputeMs
+		}
+		return components[i].ComponentName < components[j].ComponentName
+	})
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		usage.Components = components
+		return dw.WriteStruct(usage)
+	}
+
+	if _, err := fmt.Fprintf(w, "Usage of %s from %s to %s\n\n", name, usage.Since, usage.Until); err != nil {
+		return err
+	}
+	tb := NewTableBuilder("COMPONENT", "REQUESTS", "COMPUTE", "EGRESS", "COMPUTE SHARE")
+	for _, c := range components {
+		tb.AddRow(
+			c.ComponentName,
+			strconv.FormatInt(c.Requests, 10),
+			formatComputeMs(c.ComputeMs),
+			commands.FormatSize(c.EgressBytes),
+			computeShare(c.ComputeMs, usage.Totals.ComputeMs),
+		)
+	}
+	tb.AddRow(
+		"TOTAL",
+		strconv.FormatInt(usage.Totals.Requests, 10),
+		formatComputeMs(usage.Totals.ComputeMs),
+		commands.FormatSize(usage.Totals.EgressBytes),
+		"",
+	)
+	return tb.Write(dw)
+}
+
+// formatComputeMs renders compute time in the largest unit that keeps it readable
+func formatComputeMs(ms int64) string {
+	switch {
+	case ms < 1000:
+		return fmt.Sprintf("%d ms", ms)
+	case ms < 60*60*1000:
+		return fmt.Sprintf("%.1f s", float64(ms)/1000)
+	default:
+		return fmt.Sprintf("%.1f h", float64(ms)/(60*60*1000))
+	}
+}
+
+// computeShare renders a component's percentage of the total compute time
+func computeShare(ms, total int64) string {
+	if total <= 0 {
+		return "-"
+	}
+	return fmt.Sprintf("%.1f%%", float64(ms)*100/float64(total))
+}
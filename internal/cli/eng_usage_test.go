@@ -0,0 +1,92 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+)
+
+type fakeUsageClient struct {
+	since string // since requested with GetAppUsage
+}
+
+func (f *fakeUsageClient) ListApps(_ context.Context, _ *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	data, err := json.Marshal(map[string]interface{}{
+		"apps": []map[string]interface{}{
+			{"appId": prodAppID, "appName": "my-app-prod"},
+		},
+	})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakeUsageClient) GetAppUsage(_ context.Context, appID, since string) (*api.GetAppUsageResponseBody, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	f.since = since
+	return &api.GetAppUsageResponseBody{
+		Since:  "2026-09-16T00:00:00Z",
+		Until:  "2026-10-16T00:00:00Z",
+		Totals: api.UsageTotals{Requests: 1200, ComputeMs: 40000, EgressBytes: 3 * 1024 * 1024},
+		Components: []api.ComponentUsage{
+			{ComponentName: "calc", Requests: 1000, ComputeMs: 10000},
+			{ComponentName: "weather", Requests: 200, ComputeMs: 30000, EgressBytes: 3 * 1024 * 1024},
+		},
+	}, nil
+}
+
+func TestEngUsageCommand(t *testing.T) {
+	usage, _, err := newEngCmd().Find([]string{"usage"})
+	require.NoError(t, err)
+	assert.Equal(t, "usage", usage.Name())
+	assert.Equal(t, "30d", usage.Flags().Lookup("since").DefValue)
+}
+
+func TestRunEngUsage_Table(t *testing.T) {
+	client := &fakeUsageClient{}
+
+	var buf bytes.Buffer
+	require.NoError(t, runEngUsage(context.Background(), client, &buf, "my-app-prod", "7d", "table"))
+	assert.Equal(t, "7d", client.since)
+
+	out := buf.String()
+	assert.Contains(t, out, "Usage of my-app-prod from 2026-09-16T00:00:00Z to 2026-10-16T00:00:00Z")
+	assert.Contains(t, out, "75.0%")
+	assert.Contains(t, out, "3.0 MiB")
+	assert.Contains(t, out, "TOTAL")
+	// Sorted by compute time
+	assert.Less(t, bytes.Index(buf.Bytes(), []byte("weather")), bytes.Index(buf.Bytes(), []byte("calc")))
+}
+
+func TestRunEngUsage_JSON(t *testing.T) {
+	var buf bytes.Buffer
+	require.NoError(t, runEngUsage(context.Background(), &fakeUsageClient{}, &buf, "my-app-prod", "30d", "json"))
+
+	var usage api.GetAppUsageResponseBody
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &usage))
+	require.Len(t, usage.Components, 2)
+	assert.Equal(t, "weather", usage.Components[0].ComponentName)
+	assert.Equal(t, int64(1200), usage.Totals.Requests)
+}
+
+func TestRunEngUsage_UnknownEngine(t *testing.T) {
+	err := runEngUsage(context.Background(), &fakeUsageClient{}, &bytes.Buffer{}, "my-app", "30d", "table")
+	assert.ErrorContains(t, err, "engine my-app not found")
+}
+
+func TestFormatComputeMs(t *testing.T) {
+	assert.Equal(t, "850 ms", formatComputeMs(850))
+	assert.Equal(t, "12.5 s", formatComputeMs(12500))
+	assert.Equal(t, "2.0 h", formatComputeMs(2*60*60*1000))
+}
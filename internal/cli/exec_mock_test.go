@@ -40,6 +40,8 @@ func TestHelperProcess(t *testing.T) {
 		handleGoCommand(cmdArgs)
 	case "ftl":
 		handleFTLCommand(cmdArgs)
+	case "wasm-tools":
+		fmt.Println("package root:component;\n\nworld root {\n  import wasi:http/outgoing-handler@0.2.0;\n}")
 	default:
 		fmt.Fprintf(os.Stderr, "Unknown command: %s", cmd)
 		os.Exit(1)
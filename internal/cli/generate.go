@@ -0,0 +1,263 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/clientgen"
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/spin"
+)
+
+// localAppTimeout bounds how long a temporary 'spin up' may take to serve tools
+const localAppTimeout = 60 * time.Second
+
+type generateClientOptions struct {
+	language string
+	out      string
+	name     string
+	url      string
+	token    string
+	check    bool
+}
+
+func newGenerateCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "generate",
+		Short: "Generate code from the application's tools",
+	}
+
+	cmd.AddCommand(newGenerateClientCmd())
+
+	return cmd
+}
+
+func newGenerateClientCmd() *cobra.Command {
+	opts := generateClientOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "client",
+		Short: "Generate a typed client library for the application's tools",
+		Long: `Generate a typed client library for calling the application's tools
+through its MCP endpoint.
+
+The tool names and schemas are read with tools/list from the built
+application: 'spin up' is started on a free local port with the spin.toml
+written by 'ftl build' and stopped once the tools are listed. With --url, the
+tools are listed from a running application instead, such as 'ftl up' or a
+deployed endpoint.
+
+Each tool becomes a method taking its input type. Tools with an output schema
+return their typed structured content. Regenerate the client whenever the
+tools change, or use --check in CI to fail when the client is out of date.`,
+		Example: `  ftl build && ftl generate client --language typescript --out clients/ts
+  ftl generate client --language rust --out clients/rust --name weather-client
+  ftl generate client --language rust --url http://localhost:3000/mcp
+  ftl generate client --language typescript --out clients/ts --check`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return generateClient(cmd.Context(), opts)
+		},
+	}
+
+	cmd.Flags().StringVarP(&opts.language, "language", "l", "", "client language (rust, typescript)")
+	cmd.Flags().StringVar(&opts.out, "out", "", "output directory (default clients/<language>)")
+	cmd.Flags().StringVar(&opts.name, "name", "", "package name (default <app>-client)")
+	cmd.Flags().StringVar(&opts.url, "url", "", "list the tools from a running MCP endpoint instead of the built application")
+	cmd.Flags().StringVar(&opts.token, "token", "", "bearer token for --url")
+	cmd.Flags().BoolVar(&opts.check, "check", false, "fail if the client in the output directory is out of date instead of writing it")
+	_ = cmd.MarkFlagRequired("language")
+
+	return cmd
+}
+
+func generateClient(ctx context.Context, opts generateClientOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	language := clientgen.Language(strings.ToLower(opts.language))
+	if language == "ts" {
+		language = clientgen.TypeScript
+	}
+	if opts.out == "" {
+		opts.out = filepath.Join("clients", string(language))
+	}
+	if opts.name == "" {
+		m, err := manifest.LoadAuto()
+		if err != nil {
+			return fmt.Errorf("failed to load project config: %w", err)
+		}
+		opts.name = m.Name + "-client"
+	}
+
+	tools, err := loadAppTools(ctx, opts.url, opts.token)
+	if err != nil {
+		return err
+	}
+	if len(tools) == 0 {
+		return fmt.Errorf("the application serves no tools")
+	}
+
+	files, err := clientgen.Generate(clientgen.Options{Language: language, Package: opts.name}, tools)
+	if err != nil {
+		return err
+	}
+
+	if opts.check {
+		return checkGeneratedFiles(opts.out, files)
+	}
+
+	for _, f := range files {
+		path := filepath.Join(opts.out, filepath.FromSlash(f.Path))
+		if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+			return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+		}
+		if err := os.WriteFile(path, f.Content, 0600); err != nil {
+			return fmt.Errorf("failed to write %s: %w", path, err)
+		}
+	}
+	Success("Generated %s client for %d tool(s) in %s", language, len(tools), opts.out)
+	return nil
+}
+
+// checkGeneratedFiles fails when the files on disk differ from the generated ones
+func checkGeneratedFiles(dir string, files []clientgen.File) error {
+	var stale []string
+	for _, f := range files {
+		path := filepath.Join(dir, filepath.FromSlash(f.Path))
+		existing, err := os.ReadFile(path) // #nosec G304 -- path inside the chosen output directory
+		if err != nil || !bytes.Equal(existing, f.Content) {
+			stale = append(stale, path)
+		}
+	}
+	if len(stale) > 0 {
+		return fmt.Errorf("client is out of date, run 'ftl generate client' to update: %s", strings.Join(stale, ", "))
+	}
+	Success("Client in %s is up to date", dir)
+	return nil
+}
+
+// loadAppTools lists the tools of a running endpoint, or of the built
+// application started locally when url is empty
+func loadAppTools(ctx context.Context, url, token string) ([]clientgen.Tool, error) {
+	if url != "" {
+		return listMCPTools(ctx, url, token)
+	}
+
+	endpoint, stop, err := startLocalApp(ctx)
+	if err != nil {
+		return nil, err
+	}
+	defer stop()
+
+	ctx, cancel := context.WithTimeout(ctx, localAppTimeout)
+	defer cancel()
+	for {
+		tools, err := listMCPTools(ctx, endpoint, "")
+		if err == nil {
+			return tools, nil
+		}
+		select {
+		case <-ctx.Done():
+			return nil, fmt.Errorf("the application did not serve its tools in time: %w", err)
+		case <-time.After(250 * time.Millisecond):
+		}
+	}
+}
+
+// startLocalApp runs 'spin up' for the built application on a free port and
+// returns its MCP endpoint
+var startLocalApp = func(ctx context.Context) (string, func(), error) {
+	if _, err := os.Stat("spin.toml"); err != nil {
+		return "", nil, fmt.Errorf("no spin.toml found, run 'ftl build' first or pass --url")
+	}
+	if err := spin.EnsureInstalled(); err != nil {
+		return "", nil, err
+	}
+
+	address, err := freeLocalAddress()
+	if err != nil {
+		return "", nil, err
+	}
+
+	ctx, cancel := context.WithCancel(ctx)
+	cmd := exec.CommandContext(ctx, spin.DefaultBinary(), "up", "--listen", address) // #nosec G204 -- spin binary from PATH or the FTL tools directory
+	cmd.Stdout = io.Discard
+	cmd.Stderr = io.Discard
+	if err := cmd.Start(); err != nil {
+		cancel()
+		return "", nil, fmt.Errorf("failed to start spin: %w", err)
+	}
+	Debug("Started spin up on %s to list tools", address)
+
+	stop := func() {
+		cancel()
+		_ = cmd.Wait()
+	}
+	return "http://" + address + "/mcp", stop, nil
+}
+
+// listMCPTools calls tools/list on an MCP endpoint
+func listMCPTools(ctx context.Context, url, token string) ([]clientgen.Tool, error) {
+	body, err := json.Marshal(map[string]interface{}{
+		"jsonrpc": "2.0",
+		"id":      1,
+		"method":  "tools/list",
+		"params":  map[string]interface{}{},
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, url, bytes.NewReader(body))
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("Accept", "application/json")
+	if token != "" {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+
+	client := &http.Client{Timeout: 30 * time.Second}
+	resp, err := client.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list tools from %s: %w", url, err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("failed to list tools from %s: %s", url, resp.Status)
+	}
+
+	var result struct {
+		Result *struct {
+			Tools []clientgen.Tool `json:"tools"`
+		} `json:"result"`
+		Error *struct {
+			Code    int    `json:"code"`
+			Message string `json:"message"`
+		} `json:"error"`
+	}
+	if err := json.NewDecoder(io.LimitReader(resp.Body, 16<<20)).Decode(&result); err != nil {
+		return nil, fmt.Errorf("invalid tools/list response from %s: %w", url, err)
+	}
+	if result.Error != nil {
+		return nil, fmt.Errorf("tools/list failed: %s (code %d)", result.Error.Message, result.Error.Code)
+	}
+	if result.Result == nil {
+		return nil, fmt.Errorf("invalid tools/list response from %s: no result", url)
+	}
+	return result.Result.Tools, nil
+}
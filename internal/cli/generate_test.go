@@ -0,0 +1,97 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newToolsServer serves tools/list like the MCP gateway
+func newToolsServer(t *testing.T, token string) *httptest.Server {
+	t.Helper()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if token != "" && r.Header.Get("Authorization") != "Bearer "+token {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		var req struct {
+			Method string `json:"method"`
+		}
+		require.NoError(t, json.NewDecoder(r.Body).Decode(&req))
+		assert.Equal(t, "tools/list", req.Method)
+
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"jsonrpc":"2.0","id":1,"result":{"tools":[
+			{"name":"echo__echo","description":"Echo a message","inputSchema":{"type":"object","properties":{"message":{"type":"string"}},"required":["message"]}}
+		]}}`))
+	}))
+	t.Cleanup(server.Close)
+	return server
+}
+
+func TestGenerateCommand(t *testing.T) {
+	cmd := newGenerateCmd()
+	assert.Equal(t, "generate", cmd.Use)
+
+	clientCmd, _, err := cmd.Find([]string{"client"})
+	require.NoError(t, err)
+	for _, flag := range []string{"language", "out", "name", "url", "token", "check"} {
+		assert.NotNil(t, clientCmd.Flags().Lookup(flag), flag)
+	}
+}
+
+func TestListMCPTools(t *testing.T) {
+	server := newToolsServer(t, "secret")
+
+	tools, err := listMCPTools(context.Background(), server.URL, "secret")
+	require.NoError(t, err)
+	require.Len(t, tools, 1)
+	assert.Equal(t, "echo__echo", tools[0].Name)
+	assert.Equal(t, "Echo a message", tools[0].Description)
+
+	_, err = listMCPTools(context.Background(), server.URL, "")
+	assert.ErrorContains(t, err, "401")
+}
+
+func TestGenerateClient(t *testing.T) {
+	server := newToolsServer(t, "")
+	out := filepath.Join(t.TempDir(), "client")
+
+	opts := generateClientOptions{language: "ts", out: out, name: "echo-client", url: server.URL}
+	require.NoError(t, generateClient(context.Background(), opts))
+
+	index, err := os.ReadFile(filepath.Join(out, "src", "index.ts"))
+	require.NoError(t, err)
+	assert.Contains(t, string(index), "async echoEcho(input: EchoEchoInput)")
+	assert.FileExists(t, filepath.Join(out, "package.json"))
+
+	// The written client is up to date until it changes
+	opts.check = true
+	require.NoError(t, generateClient(context.Background(), opts))
+
+	require.NoError(t, os.WriteFile(filepath.Join(out, "src", "index.ts"), []byte("// edited"), 0600))
+	assert.ErrorContains(t, generateClient(context.Background(), opts), "client is out of date")
+}
+
+func TestGenerateClientFromLocalApp(t *testing.T) {
+	server := newToolsServer(t, "")
+
+	oldStart := startLocalApp
+	t.Cleanup(func() { startLocalApp = oldStart })
+	stopped := false
+	startLocalApp = func(ctx context.Context) (string, func(), error) {
+		return server.URL, func() { stopped = true }, nil
+	}
+
+	tools, err := loadAppTools(context.Background(), "", "")
+	require.NoError(t, err)
+	assert.Len(t, tools, 1)
+	assert.True(t, stopped)
+}
@@ -1,12 +1,17 @@
 package cli
 
 import (
+	"context"
 	"fmt"
 	"os"
+	"path/filepath"
+	"strings"
 
 	"github.com/AlecAivazis/survey/v2"
 	"github.com/spf13/cobra"
 
+	"github.com/fastertools/ftl/internal/examples"
+	"github.com/fastertools/ftl/internal/manifest"
 	"github.com/fastertools/ftl/internal/scaffold"
 )
 
@@ -18,6 +23,12 @@ type InitOptions struct {
 	Language      string // Configuration language: yaml, go, cue, json
 	NoInteractive bool
 	Force         bool
+	CI            string // CI provider to generate a pipeline for: github, gitlab
+
+	// Example gallery options
+	FromExample    string
+	ListExamples   bool
+	ExamplesSource string
 }
 
 // newInitCmd creates the init command
@@ -32,12 +43,27 @@ func newInitCmd() *cobra.Command {
 This command creates a new FTL project directory with:
 - ftl.yaml configuration file
 - Basic project structure
-- Example components (optional)`,
+- Example components (optional)
+
+Use --from-example to start from a working example in the FTL example
+gallery, and --list-examples to see what is available.
+
+Use --ci github or --ci gitlab to also generate a CI pipeline that runs
+'ftl build' and 'ftl test', and 'ftl deploy' on the main branch once the
+FTL_CLIENT_ID and FTL_CLIENT_SECRET secrets are configured. The pipeline
+installs the toolchains of the languages found in the project, or Rust
+when there are no components yet.`,
 		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
 			if len(args) > 0 {
 				opts.Name = args[0]
 			}
+			if opts.ListExamples {
+				return runListExamples(cmd.Context(), opts)
+			}
+			if opts.FromExample != "" {
+				return runInitFromExample(cmd.Context(), opts)
+			}
 			return runInit(opts)
 		},
 	}
@@ -47,11 +73,19 @@ This command creates a new FTL project directory with:
 	cmd.Flags().StringVarP(&opts.Language, "language", "l", "", "configuration language (yaml, go, cue, json)")
 	cmd.Flags().BoolVar(&opts.NoInteractive, "no-interactive", false, "disable interactive prompts")
 	cmd.Flags().BoolVarP(&opts.Force, "force", "f", false, "overwrite existing files")
+	cmd.Flags().StringVar(&opts.CI, "ci", "", "generate a CI pipeline (github, gitlab)")
+	cmd.Flags().StringVar(&opts.FromExample, "from-example", "", "scaffold the project from an example in the FTL example gallery")
+	cmd.Flags().BoolVar(&opts.ListExamples, "list-examples", false, "list the examples available to --from-example")
+	cmd.Flags().StringVar(&opts.ExamplesSource, "examples-source", examples.DefaultSource, "example gallery archive URL or local gallery directory")
 
 	return cmd
 }
 
 func runInit(opts *InitOptions) error {
+	if err := validateCIProvider(opts.CI); err != nil {
+		return err
+	}
+
 	// Validate or prompt for name
 	if opts.Name == "" {
 		if opts.NoInteractive {
@@ -118,6 +152,10 @@ func runInit(opts *InitOptions) error {
 	}
 	Success("Created .gitignore")
 
+	if err := generateCI(scaffolder, projectDir, opts.CI); err != nil {
+		return err
+	}
+
 	// Print next steps based on format
 	fmt.Println()
 	Info("Next steps:")
@@ -141,6 +179,137 @@ func runInit(opts *InitOptions) error {
 	return nil
 }
 
+func runListExamples(ctx context.Context, opts *InitOptions) error {
+	gallery, err := examples.Load(ctx, opts.ExamplesSource)
+	if err != nil {
+		return err
+	}
+
+	dw := NewDataWriter(os.Stdout, "table")
+	tb := NewTableBuilder("EXAMPLE", "PATH", "BUILD")
+	for _, ex := range gallery.List() {
+		tb.AddRow(ex.Name, ex.Path, ex.BuildCommand)
+	}
+	return tb.Write(dw)
+}
+
+func runInitFromExample(ctx context.Context, opts *InitOptions) error {
+	if err := validateCIProvider(opts.CI); err != nil {
+		return err
+	}
+
+	Info("Fetching example gallery")
+	gallery, err := examples.Load(ctx, opts.ExamplesSource)
+	if err != nil {
+		return err
+	}
+
+	example, err := gallery.Get(opts.FromExample)
+	if err != nil {
+		return err
+	}
+
+	// Default the project name to the example name
+	if opts.Name == "" {
+		opts.Name = example.Name
+	}
+	if err := scaffold.ValidateComponentName(opts.Name); err != nil {
+		return fmt.Errorf("invalid project name: %w", err)
+	}
+
+	projectDir := opts.Name
+	if !opts.Force {
+		if _, err := os.Stat(projectDir); err == nil {
+			return fmt.Errorf("directory %s already exists (use --force to overwrite)", projectDir)
+		}
+	}
+	if err := os.MkdirAll(projectDir, 0750); err != nil {
+		return fmt.Errorf("failed to create project directory: %w", err)
+	}
+
+	Info("Initializing FTL project '%s' from example '%s'", opts.Name, example.Name)
+
+	description := opts.Description
+	if description == "" {
+		description = fmt.Sprintf("%s - An FTL application based on the %s example", opts.Name, example.Name)
+	}
+
+	scaffolder, err := scaffold.NewScaffolder()
+	if err != nil {
+		return fmt.Errorf("failed to initialize scaffolder: %w", err)
+	}
+	if err := scaffolder.GenerateProject(projectDir, opts.Name, description, "yaml"); err != nil {
+		return fmt.Errorf("failed to generate project: %w", err)
+	}
+
+	count, err := gallery.Extract(example, projectDir)
+	if err != nil {
+		return fmt.Errorf("failed to extract example: %w", err)
+	}
+	Success("Copied %d files from %s", count, example.Name)
+
+	configPath := filepath.Join(projectDir, "ftl.yaml")
+	m, err := manifest.Load(configPath)
+	if err != nil {
+		return err
+	}
+	if err := m.AddComponent(example.Component()); err != nil {
+		return err
+	}
+	if err := m.Save(configPath); err != nil {
+		return err
+	}
+	Success("Created ftl.yaml")
+
+	if err := generateCI(scaffolder, projectDir, opts.CI); err != nil {
+		return err
+	}
+
+	fmt.Println()
+	Info("Next steps:")
+	fmt.Println("  1. cd", opts.Name)
+	fmt.Println("  2. ftl build")
+	fmt.Println("  3. ftl up")
+
+	return nil
+}
+
+// validateCIProvider checks the --ci flag before anything is created
+func validateCIProvider(provider string) error {
+	if provider == "" {
+		return nil
+	}
+	for _, p := range scaffold.CIProviders {
+		if p == provider {
+			return nil
+		}
+	}
+	return fmt.Errorf("invalid --ci value '%s': must be one of %s", provider, strings.Join(scaffold.CIProviders, ", "))
+}
+
+// generateCI writes the CI pipeline for the project's languages, when a
+// provider was chosen
+func generateCI(scaffolder *scaffold.Scaffolder, projectDir, provider string) error {
+	if provider == "" {
+		return nil
+	}
+
+	languages := scaffold.DetectLanguages(projectDir)
+	if len(languages) == 0 {
+		// Rust is the default language of new components
+		languages = []string{"rust"}
+	}
+
+	files, err := scaffolder.GenerateCI(projectDir, provider, languages)
+	if err != nil {
+		return fmt.Errorf("failed to generate CI pipeline: %w", err)
+	}
+	for _, file := range files {
+		Success("Created %s (%s)", file, strings.Join(languages, ", "))
+	}
+	return nil
+}
+
 func promptForName(opts *InitOptions) error {
 	prompt := &survey.Input{
 		Message: "Project name:",
@@ -1,6 +1,7 @@
 package cli
 
 import (
+	"context"
 	"os"
 	"path/filepath"
 	"testing"
@@ -202,6 +203,37 @@ func TestYAMLProjectGeneration(t *testing.T) {
 	assert.Equal(t, "Test application", manifest["description"])
 }
 
+func TestInitWithCI(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	if err := os.Chdir(tmpDir); err != nil {
+		t.Fatal(err)
+	}
+
+	err := runInit(&InitOptions{Name: "ci-app", Language: "yaml", NoInteractive: true, CI: "github"})
+	require.NoError(t, err)
+
+	// Without components the pipeline sets up Rust
+	data, err := os.ReadFile(filepath.Join(tmpDir, "ci-app", ".github", "workflows", "ftl.yml"))
+	require.NoError(t, err)
+	assert.Contains(t, string(data), "wasm32-wasip1")
+	assert.Contains(t, string(data), "ftl deploy --yes")
+
+	// Go configurations need Go in CI to synthesize the manifest
+	err = runInit(&InitOptions{Name: "ci-go-app", Language: "go", NoInteractive: true, CI: "gitlab"})
+	require.NoError(t, err)
+	data, err = os.ReadFile(filepath.Join(tmpDir, "ci-go-app", ".gitlab-ci.yml"))
+	require.NoError(t, err)
+	assert.Contains(t, string(data), "go.dev/dl")
+	assert.NotContains(t, string(data), "rustup")
+
+	err = runInit(&InitOptions{Name: "bad-ci", Language: "yaml", NoInteractive: true, CI: "travis"})
+	assert.EqualError(t, err, "invalid --ci value 'travis': must be one of github, gitlab")
+	assert.NoDirExists(t, filepath.Join(tmpDir, "bad-ci"))
+}
+
 func TestGitignoreGeneration(t *testing.T) {
 	tmpDir := t.TempDir()
 
@@ -273,3 +305,44 @@ func TestGoProjectGeneration(t *testing.T) {
 	goModPath := filepath.Join(tmpDir, "go-app", "go.mod")
 	assert.FileExists(t, goModPath)
 }
+
+func TestInitFromExample(t *testing.T) {
+	gallery, err := filepath.Abs(filepath.Join("..", "..", "examples", "demo"))
+	require.NoError(t, err)
+
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	require.NoError(t, os.Chdir(tmpDir))
+
+	opts := &InitOptions{
+		Name:           "my-weather",
+		FromExample:    "weather-rs",
+		ExamplesSource: gallery,
+		NoInteractive:  true,
+	}
+	require.NoError(t, runInitFromExample(context.Background(), opts))
+
+	assert.FileExists(t, filepath.Join(tmpDir, "my-weather", "weather-rs", "Cargo.toml"))
+	assert.FileExists(t, filepath.Join(tmpDir, "my-weather", ".gitignore"))
+
+	data, err := os.ReadFile(filepath.Join(tmpDir, "my-weather", "ftl.yaml"))
+	require.NoError(t, err)
+
+	var config map[string]interface{}
+	require.NoError(t, yaml.Unmarshal(data, &config))
+	assert.Equal(t, "my-weather", config["name"])
+
+	components, ok := config["components"].([]interface{})
+	require.True(t, ok)
+	require.Len(t, components, 1)
+	comp := components[0].(map[string]interface{})
+	assert.Equal(t, "weather-rs", comp["id"])
+	assert.Equal(t, "weather-rs/target/wasm32-wasip1/release/weather_rs.wasm", comp["source"])
+	assert.NotEmpty(t, comp["allowed_outbound_hosts"])
+
+	// Unknown examples are rejected before anything is written
+	opts = &InitOptions{FromExample: "nope", ExamplesSource: gallery}
+	err = runInitFromExample(context.Background(), opts)
+	assert.ErrorContains(t, err, "unknown example")
+}
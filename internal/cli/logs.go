@@ -4,6 +4,7 @@ import (
 	"context"
 	"fmt"
 	"net/http"
+	"os"
 	"strings"
 
 	"github.com/fatih/color"
@@ -32,6 +33,21 @@ type LogsOptions struct {
 	// Tail limits the number of log lines returned.
 	// Valid range: 1-1000, default: 100
 	Tail string
+
+	// Filters are key=value pairs that structured (JSON) lines must match.
+	// "component" also matches the component_id field.
+	Filters []string
+
+	// Grep is a regular expression lines must match
+	Grep string
+
+	// Level selects lines by level: "warn" for warnings only, "warn+" for
+	// warnings and more severe levels
+	Level string
+
+	// Format is the output format: "table" renders JSON lines as columns,
+	// "json" writes them untouched
+	Format string
 }
 
 func newLogsCmd() *cobra.Command {
@@ -58,7 +74,17 @@ Examples:
   ftl logs my-app --tail 500
 
   # Get logs from the last 30 minutes, showing only last 50 lines
-  ftl logs my-app --since 30m --tail 50`,
+  ftl logs my-app --since 30m --tail 50
+
+  # Warnings and errors from one component
+  ftl logs my-app --filter component=weather --level warn+
+
+  # Lines matching a pattern, as JSON lines
+  ftl logs my-app --grep 'timeout|refused' -o json
+
+Filters apply to the lines fetched with --since and --tail. JSON log lines are
+shown as timestamp, level, component and message columns followed by their
+other fields.`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			ctx := context.Background()
 
@@ -73,6 +99,10 @@ Examples:
 
 	cmd.Flags().StringVar(&opts.Since, "since", "7d", "Time range for logs (e.g., '30m', '1h', '7d', or RFC3339/Unix timestamp)")
 	cmd.Flags().StringVar(&opts.Tail, "tail", "100", "Number of log lines from the end (1-1000)")
+	cmd.Flags().StringArrayVar(&opts.Filters, "filter", nil, "Only show JSON lines with a field value, as key=value (e.g. component=weather)")
+	cmd.Flags().StringVar(&opts.Grep, "grep", "", "Only show lines matching a regular expression")
+	cmd.Flags().StringVar(&opts.Level, "level", "", "Only show lines of a level (e.g. 'error'), or of a level and above (e.g. 'warn+')")
+	cmd.Flags().StringVarP(&opts.Format, "output", "o", "table", "Output format (table, json)")
 
 	return cmd
 }
@@ -83,6 +113,11 @@ func runLogs(ctx context.Context, opts *LogsOptions) error {
 	if err := validateLogsOptions(opts); err != nil {
 		return err
 	}
+	filter, err := newLogFilter(opts.Filters, opts.Grep, opts.Level)
+	if err != nil {
+		return err
+	}
+	jsonOutput := opts.Format == "json"
 
 	// Get auth manager
 	store, err := auth.NewKeyringStore()
@@ -165,7 +200,9 @@ func runLogs(ctx context.Context, opts *LogsOptions) error {
 	}
 
 	// Get logs
-	Info("Fetching logs for app %s...", appID)
+	if !jsonOutput {
+		Info("Fetching logs for app %s...", appID)
+	}
 
 	params := &api.GetAppLogsParams{
 		Since: &opts.Since,
@@ -177,17 +214,8 @@ func runLogs(ctx context.Context, opts *LogsOptions) error {
 		return fmt.Errorf("failed to get logs: %w", err)
 	}
 
-	if resp.StatusCode() != 200 {
-		if resp.JSON401 != nil && resp.JSON401.Message != "" {
-			return fmt.Errorf("unauthorized: %s", resp.JSON401.Message)
-		}
-		if resp.JSON404 != nil && resp.JSON404.Message != "" {
-			return fmt.Errorf("app not found: %s", resp.JSON404.Message)
-		}
-		if resp.JSON500 != nil && resp.JSON500.Message != "" {
-			return fmt.Errorf("server error: %s", resp.JSON500.Message)
-		}
-		return fmt.Errorf("failed to get logs: status %d", resp.StatusCode())
+	if resp.StatusCode() != http.StatusOK {
+		return fmt.Errorf("failed to get logs: %w", api.NewAPIError(resp.StatusCode(), resp.Body))
 	}
 
 	if resp.JSON200 == nil {
@@ -196,6 +224,12 @@ func runLogs(ctx context.Context, opts *LogsOptions) error {
 
 	logsResp := resp.JSON200
 
+	// JSON output is only the log lines, so it can be piped
+	if jsonOutput {
+		_, err = writeLogs(os.Stdout, logsResp.Logs, filter, opts.Format)
+		return err
+	}
+
 	// Display logs
 	if logsResp.Logs == "" {
 		Info("No logs found for the specified time range")
@@ -208,7 +242,13 @@ func runLogs(ctx context.Context, opts *LogsOptions) error {
 	fmt.Println(strings.Repeat("─", 80))
 
 	// Print the logs
-	fmt.Println(logsResp.Logs)
+	shown, err := writeLogs(os.Stdout, logsResp.Logs, filter, opts.Format)
+	if err != nil {
+		return err
+	}
+	if shown == 0 {
+		Info("No log lines match the filters")
+	}
 
 	return nil
 }
@@ -234,6 +274,10 @@ func validateLogsOptions(opts *LogsOptions) error {
 		}
 	}
 
+	if opts.Format != "" && opts.Format != "table" && opts.Format != "json" {
+		return fmt.Errorf("--output must be table or json, got: %s", opts.Format)
+	}
+
 	// Since parameter validation is done server-side as it's more complex
 	// (relative times, RFC3339, Unix timestamps)
 
@@ -0,0 +1,285 @@
+package cli
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/fatih/color"
+)
+
+// logLevels orders the levels accepted by --level from least to most severe
+var logLevels = []string{"trace", "debug", "info", "warn", "error", "fatal"}
+
+// logLevelAliases maps other spellings to a level in logLevels
+var logLevelAliases = map[string]string{
+	"warning":  "warn",
+	"err":      "error",
+	"critical": "fatal",
+	"panic":    "fatal",
+}
+
+// Field names used for the standard columns of structured log lines, in order
+// of preference
+var (
+	logTimestampFields = []string{"timestamp", "time", "ts", "@timestamp"}
+	logLevelFields     = []string{"level", "lvl", "severity"}
+	logComponentFields = []string{"component", "component_id", "app_component"}
+	logMessageFields   = []string{"message", "msg"}
+)
+
+// plainLevelPattern finds a level word in unstructured log lines
+var plainLevelPattern = regexp.MustCompile(`(?i)\b(trace|debug|info|warn|warning|error|fatal|critical|panic)\b`)
+
+// logFilter selects the log lines to show
+type logFilter struct {
+	// fields must all match the line's structured fields
+	fields map[string]string
+	// grep must match the raw line, if set
+	grep *regexp.Regexp
+	// minLevel and exact select lines by level; -1 shows all levels
+	minLevel int
+	exact    bool
+}
+
+// parsedLogLine is a single log line, with its fields when it is a JSON object
+type parsedLogLine struct {
+	raw    string
+	fields map[string]interface{}
+}
+
+// newLogFilter builds a filter from the --filter, --grep and --level flags
+func newLogFilter(filters []string, grep, level string) (*logFilter, error) {
+	f := &logFilter{fields: map[string]string{}, minLevel: -1}
+
+	for _, filter := range filters {
+		key, value, ok := strings.Cut(filter, "=")
+		if !ok || key == "" {
+			return nil, fmt.Errorf("--filter must be key=value, got: %s", filter)
+		}
+		f.fields[key] = value
+	}
+
+	if grep != "" {
+		re, err := regexp.Compile(grep)
+		if err != nil {
+			return nil, fmt.Errorf("invalid --grep pattern: %w", err)
+		}
+		f.grep = re
+	}
+
+	if level != "" {
+		name := strings.ToLower(strings.TrimSuffix(level, "+"))
+		index := logLevelIndex(name)
+		if index < 0 {
+			return nil, fmt.Errorf("--level must be one of %s, optionally followed by +, got: %s", strings.Join(logLevels, ", "), level)
+		}
+		f.minLevel = index
+		f.exact = !strings.HasSuffix(level, "+")
+	}
+
+	return f, nil
+}
+
+// logLevelIndex returns the position of a level name in logLevels, or -1
+func logLevelIndex(name string) int {
+	name = strings.ToLower(name)
+	if alias, ok := logLevelAliases[name]; ok {
+		name = alias
+	}
+	for i, level := range logLevels {
+		if level == name {
+			return i
+		}
+	}
+	return -1
+}
+
+// parseLogLine decodes a line holding a JSON object
+func parseLogLine(raw string) parsedLogLine {
+	line := parsedLogLine{raw: raw}
+	trimmed := strings.TrimSpace(raw)
+	if !strings.HasPrefix(trimmed, "{") {
+		return line
+	}
+	decoder := json.NewDecoder(strings.NewReader(trimmed))
+	decoder.UseNumber()
+	var fields map[string]interface{}
+	if err := decoder.Decode(&fields); err == nil {
+		line.fields = fields
+	}
+	return line
+}
+
+// field returns the first of names set on the line, looking inside a
+// nested "fields" object as written by tracing's JSON formatter
+func (l parsedLogLine) field(names ...string) (string, bool) {
+	nested, _ := l.fields["fields"].(map[string]interface{})
+	for _, name := range names {
+		if value, ok := l.fields[name]; ok {
+			return logValue(value), true
+		}
+		if value, ok := nested[name]; ok {
+			return logValue(value), true
+		}
+	}
+	return "", false
+}
+
+// level returns the line's level index, or -1 when it has none
+func (l parsedLogLine) level() int {
+	if l.fields != nil {
+		value, _ := l.field(logLevelFields...)
+		return logLevelIndex(value)
+	}
+	if match := plainLevelPattern.FindString(l.raw); match != "" {
+		return logLevelIndex(match)
+	}
+	return -1
+}
+
+// matches reports whether the filter selects the line. Field filters only
+// match structured lines; "component" also matches its usual aliases.
+func (f *logFilter) matches(line parsedLogLine) bool {
+	if f.grep != nil && !f.grep.MatchString(line.raw) {
+		return false
+	}
+
+	for key, want := range f.fields {
+		names := []string{key}
+		if key == "component" {
+			names = logComponentFields
+		}
+		got, ok := line.field(names...)
+		if !ok || got != want {
+			return false
+		}
+	}
+
+	if f.minLevel >= 0 {
+		level := line.level()
+		if level < 0 || level < f.minLevel || (f.exact && level != f.minLevel) {
+			return false
+		}
+	}
+	return true
+}
+
+// writeLogs writes the lines of logs selected by filter. In JSON output,
+// structured lines are written untouched and plain lines are wrapped in an
+// object with a message field, so the result is JSON lines. Otherwise
+// structured lines are rendered as columns.
+func writeLogs(w io.Writer, logs string, filter *logFilter, format string) (int, error) {
+	shown := 0
+	for _, raw := range strings.Split(strings.TrimRight(logs, "\n"), "\n") {
+		if strings.TrimSpace(raw) == "" {
+			continue
+		}
+		line := parseLogLine(raw)
+		if !filter.matches(line) {
+			continue
+		}
+		shown++
+
+		var out string
+		switch {
+		case format == "json" && line.fields != nil:
+			out = strings.TrimSpace(raw)
+		case format == "json":
+			data, err := json.Marshal(map[string]string{"message": raw})
+			if err != nil {
+				return shown, err
+			}
+			out = string(data)
+		case line.fields != nil:
+			out = renderLogLine(line)
+		default:
+			out = raw
+		}
+		if _, err := fmt.Fprintln(w, out); err != nil {
+			return shown, err
+		}
+	}
+	return shown, nil
+}
+
+// renderLogLine formats a structured line as timestamp, level, component and
+// message columns followed by its other fields
+func renderLogLine(line parsedLogLine) string {
+	timestamp, _ := line.field(logTimestampFields...)
+	level, _ := line.field(logLevelFields...)
+	component, _ := line.field(logComponentFields...)
+	message, _ := line.field(logMessageFields...)
+
+	var columns []string
+	if timestamp != "" {
+		columns = append(columns, timestamp)
+	}
+	if level != "" {
+		levelText := fmt.Sprintf("%-5s", strings.ToUpper(level))
+		switch index := logLevelIndex(level); {
+		case index >= logLevelIndex("error"):
+			levelText = color.RedString(levelText)
+		case index == logLevelIndex("warn"):
+			levelText = color.YellowString(levelText)
+		case index >= 0 && index <= logLevelIndex("debug"):
+			levelText = color.New(color.Faint).Sprint(levelText)
+		}
+		columns = append(columns, levelText)
+	}
+	if component != "" {
+		columns = append(columns, color.CyanString(component))
+	}
+	if message != "" {
+		columns = append(columns, message)
+	}
+
+	standard := map[string]bool{"fields": true}
+	for _, names := range [][]string{logTimestampFields, logLevelFields, logComponentFields, logMessageFields} {
+		for _, name := range names {
+			standard[name] = true
+		}
+	}
+	var extra []string
+	for key, value := range line.fields {
+		if !standard[key] {
+			extra = append(extra, key+"="+logValue(value))
+		}
+	}
+	if nested, ok := line.fields["fields"].(map[string]interface{}); ok {
+		for key, value := range nested {
+			if !standard[key] {
+				extra = append(extra, key+"="+logValue(value))
+			}
+		}
+	}
+	sort.Strings(extra)
+
+	return strings.Join(append(columns, extra...), "  ")
+}
+
+// logValue formats a field value, keeping nested values as compact JSON
+func logValue(value interface{}) string {
+	switch v := value.(type) {
+	case string:
+		return v
+	case json.Number:
+		return v.String()
+	case nil:
+		return "null"
+	case bool:
+		return fmt.Sprint(v)
+	default:
+		var buf bytes.Buffer
+		encoder := json.NewEncoder(&buf)
+		encoder.SetEscapeHTML(false)
+		if err := encoder.Encode(v); err != nil {
+			return fmt.Sprint(v)
+		}
+		return strings.TrimSpace(buf.String())
+	}
+}
@@ -1,9 +1,13 @@
 package cli
 
 import (
+	"bytes"
+	"strings"
 	"testing"
 
+	"github.com/fatih/color"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 func TestIsUUID(t *testing.T) {
@@ -165,3 +169,103 @@ func TestLogsCommand_TimeRangeFormats(t *testing.T) {
 		})
 	}
 }
+
+const testLogs = `{"timestamp":"2024-01-15T10:00:00Z","level":"INFO","component":"weather","message":"fetching forecast","city":"Paris"}
+{"timestamp":"2024-01-15T10:00:01Z","level":"WARN","component":"weather","message":"upstream slow","latency_ms":1200}
+{"timestamp":"2024-01-15T10:00:02Z","level":"ERROR","component_id":"search","msg":"connection refused"}
+2024-01-15T10:00:03Z ERROR plain text failure in weather
+plain line without a level
+`
+
+func TestNewLogFilter_Errors(t *testing.T) {
+	_, err := newLogFilter([]string{"component"}, "", "")
+	assert.ErrorContains(t, err, "--filter must be key=value")
+
+	_, err = newLogFilter(nil, "(unclosed", "")
+	assert.ErrorContains(t, err, "invalid --grep pattern")
+
+	_, err = newLogFilter(nil, "", "loud+")
+	assert.ErrorContains(t, err, "--level must be one of")
+}
+
+func TestWriteLogs_Filters(t *testing.T) {
+	tests := []struct {
+		name    string
+		filters []string
+		grep    string
+		level   string
+		want    []string
+	}{
+		{"all", nil, "", "", []string{"fetching forecast", "upstream slow", "connection refused", "plain text failure", "plain line"}},
+		{"component", []string{"component=weather"}, "", "", []string{"fetching forecast", "upstream slow"}},
+		{"component alias", []string{"component=search"}, "", "", []string{"connection refused"}},
+		{"field", []string{"city=Paris"}, "", "", []string{"fetching forecast"}},
+		{"grep", nil, "refused|failure", "", []string{"connection refused", "plain text failure"}},
+		{"level and above", nil, "", "warn+", []string{"upstream slow", "connection refused", "plain text failure"}},
+		{"exact level", nil, "", "warning", []string{"upstream slow"}},
+		{"combined", []string{"component=weather"}, "", "warn+", []string{"upstream slow"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			filter, err := newLogFilter(tt.filters, tt.grep, tt.level)
+			require.NoError(t, err)
+
+			var buf bytes.Buffer
+			shown, err := writeLogs(&buf, testLogs, filter, "table")
+			require.NoError(t, err)
+			assert.Equal(t, len(tt.want), shown)
+
+			lines := strings.Split(strings.TrimSpace(buf.String()), "\n")
+			require.Len(t, lines, len(tt.want))
+			for i, want := range tt.want {
+				assert.Contains(t, lines[i], want)
+			}
+		})
+	}
+}
+
+func TestWriteLogs_Rendering(t *testing.T) {
+	oldNoColor := color.NoColor
+	color.NoColor = true
+	t.Cleanup(func() { color.NoColor = oldNoColor })
+
+	filter, err := newLogFilter(nil, "", "")
+	require.NoError(t, err)
+
+	var buf bytes.Buffer
+	_, err = writeLogs(&buf, testLogs, filter, "table")
+	require.NoError(t, err)
+	lines := strings.Split(strings.TrimSpace(buf.String()), "\n")
+	assert.Equal(t, "2024-01-15T10:00:01Z  WARN   weather  upstream slow  latency_ms=1200", lines[1])
+	assert.Equal(t, "plain line without a level", lines[4])
+
+	// JSON output passes structured lines through and wraps plain ones
+	buf.Reset()
+	_, err = writeLogs(&buf, testLogs, filter, "json")
+	require.NoError(t, err)
+	lines = strings.Split(strings.TrimSpace(buf.String()), "\n")
+	assert.Equal(t, strings.Split(testLogs, "\n")[0], lines[0])
+	assert.JSONEq(t, `{"message":"plain line without a level"}`, lines[4])
+}
+
+func TestWriteLogs_TracingFields(t *testing.T) {
+	oldNoColor := color.NoColor
+	color.NoColor = true
+	t.Cleanup(func() { color.NoColor = oldNoColor })
+
+	filter, err := newLogFilter(nil, "", "info+")
+	require.NoError(t, err)
+
+	var buf bytes.Buffer
+	logs := `{"timestamp":"2024-01-15T10:00:00Z","level":"INFO","fields":{"message":"tool called","tool":"forecast"},"target":"weather"}`
+	_, err = writeLogs(&buf, logs, filter, "table")
+	require.NoError(t, err)
+	assert.Equal(t, "2024-01-15T10:00:00Z  INFO   tool called  target=weather  tool=forecast\n", buf.String())
+}
+
+func TestValidateLogsOptions_Output(t *testing.T) {
+	err := validateLogsOptions(&LogsOptions{AppID: "my-app", Format: "yaml"})
+	assert.ErrorContains(t, err, "--output must be table or json")
+	assert.NoError(t, validateLogsOptions(&LogsOptions{AppID: "my-app", Format: "json"}))
+}
@@ -0,0 +1,157 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/migrate"
+)
+
+// MigrateOptions holds options for the migrate command
+type MigrateOptions struct {
+	File   string
+	DryRun bool
+	Yes    bool
+}
+
+func newMigrateCmd() *cobra.Command {
+	opts := &MigrateOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "migrate [file]",
+		Short: "Upgrade ftl.yaml or ftl.json to the current schema",
+		Long: `Upgrade an FTL configuration written for an older version of the schema.
+
+The schema version of the configuration is detected and every migration
+since that version is applied. The changes are shown as a diff before the
+file is written, and anything that can't be migrated automatically is listed
+for manual attention. YAML comments are kept.
+
+Go and CUE configurations are not migrated, update them by hand.
+
+Examples:
+  # Preview the migration of ./ftl.yaml
+  ftl migrate --dry-run
+
+  # Migrate a specific file without confirming
+  ftl migrate ftl.json --yes`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if len(args) > 0 {
+				opts.File = args[0]
+			}
+			return runMigrate(opts)
+		},
+	}
+
+	cmd.Flags().BoolVar(&opts.DryRun, "dry-run", false, "Show the changes without writing them")
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Skip the confirmation prompt")
+
+	return cmd
+}
+
+func runMigrate(opts *MigrateOptions) error {
+	path := opts.File
+	if path == "" {
+		var err error
+		if path, err = findConfigFile(); err != nil {
+			return err
+		}
+	}
+	path = filepath.Clean(path)
+
+	ext := strings.ToLower(filepath.Ext(path))
+	if ext != ".yaml" && ext != ".yml" && ext != ".json" {
+		return fmt.Errorf("only ftl.yaml and ftl.json can be migrated, update %s by hand", path)
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	result, err := migrate.Migrate(data, ext == ".json")
+	if err != nil {
+		return fmt.Errorf("failed to migrate %s: %w", path, err)
+	}
+
+	if len(result.Applied) == 0 {
+		Success("%s is up to date (schema version %d)", path, result.To)
+		return nil
+	}
+
+	Info("Migrating %s from schema version %d to %d", path, result.From, result.To)
+	for _, migration := range result.Applied {
+		fmt.Printf("  • v%d: %s\n", migration.To, migration.Description)
+	}
+	fmt.Println()
+	printMigrationDiff(migrate.Diff(path, data, result.Data))
+
+	if len(result.Notes) > 0 {
+		fmt.Println()
+		Warn("Needs manual attention:")
+		for _, note := range result.Notes {
+			fmt.Printf("  • %s\n", note)
+		}
+	}
+
+	if opts.DryRun {
+		fmt.Println()
+		Info("Dry run, %s was not changed", path)
+		return nil
+	}
+
+	fmt.Println()
+	confirmed, err := confirmChange(opts.Yes, fmt.Sprintf("Write the migrated %s?", path))
+	if err != nil || !confirmed {
+		return err
+	}
+
+	info, err := os.Stat(path)
+	if err != nil {
+		return fmt.Errorf("failed to stat %s: %w", path, err)
+	}
+	if err := os.WriteFile(path, result.Data, info.Mode().Perm()); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	Success("Migrated %s to schema version %d", path, result.To)
+	return nil
+}
+
+// printMigrationDiff prints a unified diff, coloring added and removed lines
+func printMigrationDiff(diff string) {
+	red := color.New(color.FgRed)
+	green := color.New(color.FgGreen)
+	cyan := color.New(color.FgCyan)
+	for _, line := range strings.Split(strings.TrimSuffix(diff, "\n"), "\n") {
+		switch {
+		case strings.HasPrefix(line, "---"), strings.HasPrefix(line, "+++"):
+			fmt.Println(line)
+		case strings.HasPrefix(line, "@@"):
+			fmt.Println(cyan.Sprint(line))
+		case strings.HasPrefix(line, "-"):
+			fmt.Println(red.Sprint(line))
+		case strings.HasPrefix(line, "+"):
+			fmt.Println(green.Sprint(line))
+		default:
+			fmt.Println(line)
+		}
+	}
+}
+
+// migrationHint adds a pointer to 'ftl migrate' to the error of a
+// configuration written for an older schema version
+func migrationHint(data []byte, err error) error {
+	if err == nil {
+		return nil
+	}
+	if version, detectErr := migrate.Detect(data); detectErr == nil && version < migrate.CurrentVersion {
+		return fmt.Errorf("%w\n\nThe configuration uses schema version %d, run 'ftl migrate' to upgrade it to version %d",
+			err, version, migrate.CurrentVersion)
+	}
+	return err
+}
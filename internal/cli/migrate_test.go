@@ -0,0 +1,60 @@
+package cli
+
+import (
+	"errors"
+	"os"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const legacyMigrateManifest = `application:
+  name: test-app
+  version: "0.1.0"
+components: []
+triggers: []
+`
+
+func TestRunMigrate(t *testing.T) {
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	require.NoError(t, os.Chdir(tmpDir))
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte(legacyMigrateManifest), 0600))
+
+	// A dry run leaves the file alone
+	require.NoError(t, runMigrate(&MigrateOptions{DryRun: true}))
+	data, err := os.ReadFile("ftl.yaml")
+	require.NoError(t, err)
+	assert.Equal(t, legacyMigrateManifest, string(data))
+
+	require.NoError(t, runMigrate(&MigrateOptions{Yes: true}))
+	data, err = os.ReadFile("ftl.yaml")
+	require.NoError(t, err)
+	assert.Contains(t, string(data), "name: test-app\n")
+	assert.NotContains(t, string(data), "application:")
+	assert.NotContains(t, string(data), "triggers:")
+
+	// Migrating again is a no-op
+	require.NoError(t, runMigrate(&MigrateOptions{File: "ftl.yaml", Yes: true}))
+	migrated, err := os.ReadFile("ftl.yaml")
+	require.NoError(t, err)
+	assert.Equal(t, string(data), string(migrated))
+}
+
+func TestRunMigrate_UnsupportedFormat(t *testing.T) {
+	err := runMigrate(&MigrateOptions{File: "app.cue"})
+	assert.ErrorContains(t, err, "update app.cue by hand")
+}
+
+func TestMigrationHint(t *testing.T) {
+	assert.NoError(t, migrationHint([]byte(legacyMigrateManifest), nil))
+
+	err := migrationHint([]byte(legacyMigrateManifest), errors.New("validation failed"))
+	assert.ErrorContains(t, err, "validation failed")
+	assert.ErrorContains(t, err, "run 'ftl migrate'")
+
+	err = migrationHint([]byte("name: test-app\n"), errors.New("validation failed"))
+	assert.EqualError(t, err, "validation failed")
+}
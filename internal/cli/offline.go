@@ -0,0 +1,159 @@
+package cli
+
+import (
+	"fmt"
+	"net"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// offlineEnv forces offline mode on ("1", "true") or off ("0", "false"),
+// overriding the user config
+const offlineEnv = "FTL_OFFLINE"
+
+// defaultProbeTimeout is how long offline auto-detection waits for the API
+const defaultProbeTimeout = time.Second
+
+// networkCommands are the commands that can't work without network access,
+// by command path
+var networkCommands = []string{
+	"auth login",
+	"component publish",
+	"delete",
+	"deploy",
+	"eng",
+	"list",
+	"logs",
+	"org list",
+	"org set",
+	"registry list",
+	"registry mirror",
+	"registry pull",
+	"registry push",
+	"status",
+	"toolchain install",
+}
+
+// offline is set by the global --offline flag
+var offline bool
+
+var (
+	// For testing - allows overriding the user's network settings
+	networkSettings = func() config.NetworkConfig {
+		cfg, err := config.Load()
+		if err != nil || cfg.Network == nil {
+			return config.NetworkConfig{}
+		}
+		return *cfg.Network
+	}
+
+	// For testing - allows overriding the connectivity probe
+	probeNetwork = func(timeout time.Duration) error {
+		u, err := url.Parse(api.DefaultAPIBaseURL)
+		if err != nil {
+			return err
+		}
+		host := u.Host
+		if u.Port() == "" {
+			host = net.JoinHostPort(u.Hostname(), "443")
+		}
+		conn, err := net.DialTimeout("tcp", host, timeout)
+		if err != nil {
+			return err
+		}
+		return conn.Close()
+	}
+)
+
+// offlineStatus is the resolved offline mode and why it is on
+type offlineStatus struct {
+	offline bool
+	reason  string
+}
+
+// currentOffline caches the offline mode of the running command, so the
+// network is probed at most once. Tests reset it to nil.
+var currentOffline *offlineStatus
+
+// offlineMode reports whether FTL runs without network access. Offline mode is
+// on with --offline, FTL_OFFLINE or the network.offline setting, or when
+// network.auto_detect is set and the FTL API can't be reached. Offline,
+// registry components are resolved from the local mirror, connectivity
+// checks are skipped and commands needing the network fail fast.
+func offlineMode() bool {
+	return resolveOffline().offline
+}
+
+// resolveOffline resolves the offline mode once per run
+func resolveOffline() offlineStatus {
+	if currentOffline == nil {
+		status := detectOffline()
+		currentOffline = &status
+	}
+	return *currentOffline
+}
+
+func detectOffline() offlineStatus {
+	if offline {
+		return offlineStatus{offline: true, reason: "--offline"}
+	}
+	// The environment overrides the user config either way
+	if on, err := strconv.ParseBool(os.Getenv(offlineEnv)); err == nil {
+		if on {
+			return offlineStatus{offline: true, reason: offlineEnv + " is set"}
+		}
+		return offlineStatus{}
+	}
+
+	settings := networkSettings()
+	if settings.Offline {
+		return offlineStatus{offline: true, reason: "network.offline is set"}
+	}
+	if !settings.AutoDetect {
+		return offlineStatus{}
+	}
+
+	timeout := defaultProbeTimeout
+	if settings.ProbeTimeout != "" {
+		if d, err := time.ParseDuration(settings.ProbeTimeout); err == nil && d > 0 {
+			timeout = d
+		}
+	}
+	if err := probeNetwork(timeout); err != nil {
+		Debug("Network probe failed: %v", err)
+		return offlineStatus{offline: true, reason: "the FTL API is unreachable"}
+	}
+	return offlineStatus{}
+}
+
+// isNetworkCommand reports whether cmd needs network access
+func isNetworkCommand(cmd *cobra.Command) bool {
+	path := strings.TrimPrefix(cmd.CommandPath(), "ftl ")
+	for _, name := range networkCommands {
+		if path == name || strings.HasPrefix(path, name+" ") {
+			return true
+		}
+	}
+	return false
+}
+
+// checkNetworkAccess fails commands that need the network while offline,
+// instead of letting them wait for timeouts
+func checkNetworkAccess(cmd *cobra.Command) error {
+	if !isNetworkCommand(cmd) {
+		return nil
+	}
+	if status := resolveOffline(); status.offline {
+		return fmt.Errorf("'ftl %s' needs network access, but FTL is offline (%s)",
+			strings.TrimPrefix(cmd.CommandPath(), "ftl "), status.reason)
+	}
+	return nil
+}
@@ -0,0 +1,133 @@
+package cli
+
+import (
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/stretchr/testify/assert"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// withOfflineSettings resets the resolved offline mode and replaces the
+// network settings and probe for a test
+func withOfflineSettings(t *testing.T, settings config.NetworkConfig, probe func(time.Duration) error) {
+	t.Helper()
+
+	oldSettings, oldProbe, oldFlag := networkSettings, probeNetwork, offline
+	t.Cleanup(func() {
+		networkSettings, probeNetwork, offline = oldSettings, oldProbe, oldFlag
+		currentOffline = nil
+	})
+
+	networkSettings = func() config.NetworkConfig { return settings }
+	probeNetwork = probe
+	offline = false
+	currentOffline = nil
+}
+
+func TestOfflineMode(t *testing.T) {
+	probed := false
+	withOfflineSettings(t, config.NetworkConfig{}, func(time.Duration) error {
+		probed = true
+		return nil
+	})
+
+	t.Setenv("FTL_OFFLINE", "")
+	assert.False(t, offlineMode())
+
+	currentOffline = nil
+	offline = true
+	assert.True(t, offlineMode())
+	offline = false
+
+	currentOffline = nil
+	t.Setenv("FTL_OFFLINE", "1")
+	assert.True(t, offlineMode())
+
+	currentOffline = nil
+	t.Setenv("FTL_OFFLINE", "false")
+	assert.False(t, offlineMode())
+
+	// Without auto-detection the network is never probed
+	assert.False(t, probed)
+}
+
+func TestOfflineMode_Config(t *testing.T) {
+	withOfflineSettings(t, config.NetworkConfig{Offline: true}, nil)
+
+	t.Setenv("FTL_OFFLINE", "")
+	assert.True(t, offlineMode())
+	assert.Equal(t, "network.offline is set", resolveOffline().reason)
+
+	// The environment overrides the config
+	currentOffline = nil
+	t.Setenv("FTL_OFFLINE", "0")
+	assert.False(t, offlineMode())
+}
+
+func TestOfflineMode_AutoDetect(t *testing.T) {
+	t.Setenv("FTL_OFFLINE", "")
+
+	probes := 0
+	var timeout time.Duration
+	probeErr := errors.New("dial tcp: i/o timeout")
+	withOfflineSettings(t, config.NetworkConfig{AutoDetect: true, ProbeTimeout: "250ms"}, func(d time.Duration) error {
+		probes++
+		timeout = d
+		return probeErr
+	})
+
+	assert.True(t, offlineMode())
+	assert.Equal(t, "the FTL API is unreachable", resolveOffline().reason)
+	assert.Equal(t, 250*time.Millisecond, timeout)
+
+	// The result is cached for the run
+	assert.True(t, offlineMode())
+	assert.Equal(t, 1, probes)
+
+	currentOffline = nil
+	probeErr = nil
+	assert.False(t, offlineMode())
+}
+
+func TestOfflineMode_AutoDetectDefaultTimeout(t *testing.T) {
+	t.Setenv("FTL_OFFLINE", "")
+
+	var timeout time.Duration
+	withOfflineSettings(t, config.NetworkConfig{AutoDetect: true, ProbeTimeout: "soon"}, func(d time.Duration) error {
+		timeout = d
+		return nil
+	})
+
+	assert.False(t, offlineMode())
+	assert.Equal(t, defaultProbeTimeout, timeout)
+}
+
+func TestCheckNetworkAccess(t *testing.T) {
+	withOfflineSettings(t, config.NetworkConfig{}, nil)
+	t.Setenv("FTL_OFFLINE", "1")
+
+	root := &cobra.Command{Use: "ftl"}
+	deploy := &cobra.Command{Use: "deploy"}
+	build := &cobra.Command{Use: "build"}
+	org := &cobra.Command{Use: "org"}
+	orgList := &cobra.Command{Use: "list"}
+	orgCurrent := &cobra.Command{Use: "current"}
+	org.AddCommand(orgList, orgCurrent)
+	root.AddCommand(deploy, build, org)
+
+	err := checkNetworkAccess(deploy)
+	assert.EqualError(t, err, "'ftl deploy' needs network access, but FTL is offline (FTL_OFFLINE is set)")
+	assert.ErrorContains(t, checkNetworkAccess(orgList), "'ftl org list' needs network access")
+
+	// Local commands still run
+	assert.NoError(t, checkNetworkAccess(build))
+	assert.NoError(t, checkNetworkAccess(orgCurrent))
+
+	currentOffline = nil
+	t.Setenv("FTL_OFFLINE", "")
+	assert.NoError(t, checkNetworkAccess(deploy))
+}
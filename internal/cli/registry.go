@@ -12,7 +12,7 @@ func newRegistryCmd() *cobra.Command {
 	cmd := &cobra.Command{
 		Use:   "registry",
 		Short: "Manage registry operations",
-		Long:  `Manage registry operations including push, pull, and list.`,
+		Long:  `Manage registry operations including push, pull, list, and mirroring for offline builds.`,
 	}
 
 	// Add subcommands
@@ -20,6 +20,9 @@ func newRegistryCmd() *cobra.Command {
 		newRegistryPushCmd(),
 		newRegistryPullCmd(),
 		newRegistryListCmd(),
+		newRegistryMirrorCmd(),
+		newRegistrySetCmd(),
+		newRegistryUnsetCmd(),
 	)
 
 	return cmd
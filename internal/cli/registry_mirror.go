@@ -0,0 +1,114 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+)
+
+// componentMirror fetches registry components into a local mirror
+type componentMirror interface {
+	Fetch(ctx context.Context, ref oci.MirrorRef) (*oci.MirrorEntry, error)
+}
+
+// For testing - allows replacing the mirror
+var newComponentMirror = func(dir string) componentMirror {
+	return oci.NewMirror(dir)
+}
+
+func newRegistryMirrorCmd() *cobra.Command {
+	var dir string
+	var format string
+
+	cmd := &cobra.Command{
+		Use:   "mirror REFS_FILE",
+		Short: "Prefetch registry components for offline builds",
+		Long: `Prefetch registry components into a local mirror for offline builds.
+
+REFS_FILE lists one component per line as registry/namespace:package@version,
+optionally followed by the expected manifest digest:
+
+  # Platform components
+  ghcr.io/fastertools:mcp-gateway@0.0.13
+  ghcr.io/fastertools:mcp-authorizer@0.0.15 sha256:4f1c...
+
+Components are stored by digest in the mirror directory (FTL_MIRROR_DIR, or
+~/.cache/ftl/mirror by default). Run 'ftl build --offline' or 'ftl up --offline'
+(or set FTL_OFFLINE=1) to resolve registry components from the mirror only,
+verifying each one against its recorded digest.
+
+Example:
+  ftl registry mirror components.txt
+  ftl registry mirror components.txt --dir /srv/ftl-mirror`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if dir == "" {
+				dir = oci.DefaultMirrorDir()
+			}
+			return runRegistryMirror(cmd.Context(), newComponentMirror(dir), cmd.OutOrStdout(), args[0], format)
+		},
+	}
+
+	cmd.Flags().StringVar(&dir, "dir", "", "Mirror directory (defaults to FTL_MIRROR_DIR or ~/.cache/ftl/mirror)")
+	cmd.Flags().StringVarP(&format, "output", "o", "table", "Output format (table, json)")
+
+	return cmd
+}
+
+func runRegistryMirror(ctx context.Context, mirror componentMirror, w io.Writer, refsFile, format string) error {
+	f, err := os.Open(filepath.Clean(refsFile))
+	if err != nil {
+		return fmt.Errorf("failed to open refs file: %w", err)
+	}
+	defer func() { _ = f.Close() }()
+
+	refs, err := oci.ParseMirrorRefs(f)
+	if err != nil {
+		return fmt.Errorf("invalid refs file %s: %w", refsFile, err)
+	}
+	if len(refs) == 0 {
+		return fmt.Errorf("no components listed in %s", refsFile)
+	}
+
+	entries := make([]*oci.MirrorEntry, 0, len(refs))
+	var failed []string
+	for _, ref := range refs {
+		Info("Mirroring %s", ref)
+		entry, err := mirror.Fetch(ctx, ref)
+		if err != nil {
+			Error("Failed to mirror %s: %v", ref, err)
+			failed = append(failed, ref.String())
+			continue
+		}
+		entries = append(entries, entry)
+	}
+
+	dw := NewDataWriter(w, format)
+	if dw.format == OutputFormatJSON {
+		if err := dw.WriteStruct(entries); err != nil {
+			return err
+		}
+	} else {
+		tb := NewTableBuilder("COMPONENT", "DIGEST", "SIZE")
+		for _, entry := range entries {
+			tb.AddRow(entry.Ref, entry.Digest, commands.FormatSize(entry.Size))
+		}
+		if err := tb.Write(dw); err != nil {
+			return err
+		}
+	}
+
+	if len(failed) > 0 {
+		return fmt.Errorf("failed to mirror %d of %d components: %s", len(failed), len(refs), strings.Join(failed, ", "))
+	}
+	Success("Mirrored %d components", len(entries))
+	return nil
+}
@@ -0,0 +1,77 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/oci"
+)
+
+type fakeComponentMirror struct {
+	fetched []string
+	fail    map[string]bool
+}
+
+func (f *fakeComponentMirror) Fetch(_ context.Context, ref oci.MirrorRef) (*oci.MirrorEntry, error) {
+	f.fetched = append(f.fetched, ref.String())
+	if f.fail[ref.String()] {
+		return nil, errors.New("unauthorized")
+	}
+	return &oci.MirrorEntry{Ref: ref.String(), Digest: "sha256:abc", Layer: "sha256:def", Size: 2048}, nil
+}
+
+func writeRefsFile(t *testing.T, content string) string {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "components.txt")
+	require.NoError(t, os.WriteFile(path, []byte(content), 0600))
+	return path
+}
+
+func TestRegistryMirrorCommand(t *testing.T) {
+	cmd := newRegistryCmd()
+	mirror, _, err := cmd.Find([]string{"mirror"})
+	require.NoError(t, err)
+	assert.Equal(t, "mirror", mirror.Name())
+	assert.NotNil(t, mirror.Flags().Lookup("dir"))
+	assert.NotNil(t, mirror.Flags().Lookup("output"))
+}
+
+func TestRunRegistryMirror(t *testing.T) {
+	refs := writeRefsFile(t, "# Platform\nghcr.io/fastertools:mcp-gateway@0.0.13\n\nghcr.io/fastertools:mcp-authorizer@0.0.15\n")
+	mirror := &fakeComponentMirror{}
+
+	var buf bytes.Buffer
+	require.NoError(t, runRegistryMirror(context.Background(), mirror, &buf, refs, "json"))
+	assert.Equal(t, []string{"ghcr.io/fastertools:mcp-gateway@0.0.13", "ghcr.io/fastertools:mcp-authorizer@0.0.15"}, mirror.fetched)
+
+	var entries []oci.MirrorEntry
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &entries))
+	require.Len(t, entries, 2)
+	assert.Equal(t, "sha256:abc", entries[0].Digest)
+}
+
+func TestRunRegistryMirror_Failures(t *testing.T) {
+	refs := writeRefsFile(t, "ghcr.io/fastertools:mcp-gateway@0.0.13\nghcr.io/fastertools:mcp-authorizer@0.0.15\n")
+	mirror := &fakeComponentMirror{fail: map[string]bool{"ghcr.io/fastertools:mcp-gateway@0.0.13": true}}
+
+	var buf bytes.Buffer
+	err := runRegistryMirror(context.Background(), mirror, &buf, refs, "table")
+	assert.ErrorContains(t, err, "failed to mirror 1 of 2 components: ghcr.io/fastertools:mcp-gateway@0.0.13")
+	// The remaining components are still mirrored
+	assert.Len(t, mirror.fetched, 2)
+	assert.Contains(t, buf.String(), "mcp-authorizer")
+
+	err = runRegistryMirror(context.Background(), mirror, &buf, writeRefsFile(t, "# nothing yet\n"), "table")
+	assert.ErrorContains(t, err, "no components listed")
+
+	err = runRegistryMirror(context.Background(), mirror, &buf, writeRefsFile(t, "mcp-gateway\n"), "table")
+	assert.ErrorContains(t, err, "invalid refs file")
+}
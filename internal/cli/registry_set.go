@@ -0,0 +1,185 @@
+package cli
+
+import (
+	"fmt"
+	"path/filepath"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/config"
+	"github.com/fastertools/ftl/oci"
+)
+
+// RegistrySetOptions holds options for the registry set command
+type RegistrySetOptions struct {
+	Namespace        string
+	CredentialHelper string
+	Default          bool
+	Project          bool
+}
+
+func newRegistrySetCmd() *cobra.Command {
+	opts := &RegistrySetOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "set <registry>",
+		Short: "Configure a registry",
+		Long: `Configure a registry components are published to and pulled from.
+
+Registries are configured in the user config, or for everyone working on the
+project in .ftl/registries.toml with --project. Project settings override user
+settings for the same registry.
+
+A credential helper is a command implementing the Docker credential helper
+protocol (e.g. docker-credential-ecr-login). It is run for every registry
+operation instead of reading the credentials stored by 'docker login', so it
+can hand out short-lived tokens.`,
+		Example: `  # Publish to ECR by default, authenticating with the ECR credential helper
+  ftl registry set 123456789.dkr.ecr.us-west-2.amazonaws.com --default \
+    --namespace acme --credential-helper docker-credential-ecr-login
+
+  # Share a registry namespace with the project
+  ftl registry set ghcr.io --namespace acme --project`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			host, err := registryHost(args[0])
+			if err != nil {
+				return err
+			}
+			flags := cmd.Flags()
+			if !flags.Changed("namespace") && !flags.Changed("credential-helper") && !opts.Default {
+				return fmt.Errorf("nothing to set: use --namespace, --credential-helper or --default")
+			}
+
+			path, err := updateRegistries(opts.Project, func(registries *config.RegistriesConfig) {
+				if registries.Registries == nil {
+					registries.Registries = map[string]config.RegistryConfig{}
+				}
+				registry := registries.Registries[host]
+				if flags.Changed("namespace") {
+					registry.Namespace = strings.Trim(opts.Namespace, "/")
+				}
+				if flags.Changed("credential-helper") {
+					registry.CredentialHelper = strings.Fields(opts.CredentialHelper)
+				}
+				registries.Registries[host] = registry
+				if opts.Default {
+					registries.Default = host
+				}
+			})
+			if err != nil {
+				return err
+			}
+			Success("Configured %s in %s", host, path)
+			return nil
+		},
+	}
+
+	cmd.Flags().StringVar(&opts.Namespace, "namespace", "", "Namespace components are published to without --namespace")
+	cmd.Flags().StringVar(&opts.CredentialHelper, "credential-helper", "", "Credential helper command line (empty to use docker login)")
+	cmd.Flags().BoolVar(&opts.Default, "default", false, "Publish components to this registry without --registry")
+	cmd.Flags().BoolVar(&opts.Project, "project", false, "Use the project registry config")
+
+	return cmd
+}
+
+func newRegistryUnsetCmd() *cobra.Command {
+	var project bool
+
+	cmd := &cobra.Command{
+		Use:   "unset <registry>",
+		Short: "Remove a registry configuration",
+		Long: `Remove a registry configuration from the user config, or the project config
+with --project. It stops being the default registry if it was.`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			host, err := registryHost(args[0])
+			if err != nil {
+				return err
+			}
+
+			path, err := updateRegistries(project, func(registries *config.RegistriesConfig) {
+				delete(registries.Registries, host)
+				if registries.Default == host {
+					registries.Default = ""
+				}
+			})
+			if err != nil {
+				return err
+			}
+			Success("Removed %s from %s", host, path)
+			return nil
+		},
+	}
+
+	cmd.Flags().BoolVar(&project, "project", false, "Use the project registry config")
+
+	return cmd
+}
+
+// registryHost validates a registry given on the command line, which is a
+// host with an optional port
+func registryHost(registry string) (string, error) {
+	host := strings.TrimSuffix(registry, "/")
+	if host == "" || strings.Contains(host, "/") || strings.Contains(host, "://") {
+		return "", fmt.Errorf("invalid registry %q: expected a host such as ghcr.io", registry)
+	}
+	return host, nil
+}
+
+// updateRegistries applies change to the user or project registry config and
+// saves it, returning the path of the file written
+func updateRegistries(project bool, change func(*config.RegistriesConfig)) (string, error) {
+	if project {
+		registries, path, err := config.LoadProjectRegistries(".")
+		if err != nil {
+			return "", err
+		}
+		if path == "" {
+			path = filepath.Join(filepath.Dir(projectConfigPath()), filepath.Base(config.RegistriesFile))
+		}
+		change(registries)
+		if err := config.SaveProjectRegistries(path, registries); err != nil {
+			return "", err
+		}
+		return path, nil
+	}
+
+	cfg, err := loadUserConfig()
+	if err != nil {
+		return "", err
+	}
+	registries := cfg.GetRegistries()
+	change(registries)
+	if err := cfg.SetRegistries(registries); err != nil {
+		return "", err
+	}
+	path, _ := config.Path()
+	return path, nil
+}
+
+// loadRegistries returns the registry config in effect: the user's,
+// overridden by the project's
+func loadRegistries() (*config.RegistriesConfig, error) {
+	user := &config.RegistriesConfig{}
+	if cfg, err := loadUserConfig(); err == nil {
+		user = cfg.GetRegistries()
+	}
+	project, _, err := config.LoadProjectRegistries(".")
+	if err != nil {
+		return nil, err
+	}
+	return user.Merge(project), nil
+}
+
+// configureRegistryCredentials makes registry operations use the configured
+// credential helpers
+func configureRegistryCredentials() error {
+	registries, err := loadRegistries()
+	if err != nil {
+		return err
+	}
+	oci.SetCredentialHelpers(registries.CredentialHelpers())
+	return nil
+}
@@ -0,0 +1,68 @@
+package cli
+
+import (
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// runRegistryCmd runs "ftl registry" with args
+func runRegistryCmd(t *testing.T, args ...string) error {
+	t.Helper()
+	cmd := newRegistryCmd()
+	cmd.SetArgs(args)
+	return cmd.Execute()
+}
+
+func TestRegistrySet_Global(t *testing.T) {
+	cfg, _ := withConfigDirs(t)
+	const ecr = "123456789.dkr.ecr.us-west-2.amazonaws.com"
+
+	require.NoError(t, runRegistryCmd(t, "set", ecr, "--default", "--namespace", "/acme/",
+		"--credential-helper", "docker-credential-ecr-login --profile ci"))
+	require.NotNil(t, cfg.Registries)
+	assert.Equal(t, ecr, cfg.Registries.Default)
+	assert.Equal(t, config.RegistryConfig{
+		Namespace:        "acme",
+		CredentialHelper: []string{"docker-credential-ecr-login", "--profile", "ci"},
+	}, cfg.Registries.Registries[ecr])
+
+	// Only the given settings change
+	require.NoError(t, runRegistryCmd(t, "set", ecr, "--namespace", "team"))
+	assert.Equal(t, "team", cfg.Registries.Registries[ecr].Namespace)
+	assert.Len(t, cfg.Registries.Registries[ecr].CredentialHelper, 3)
+
+	require.NoError(t, runRegistryCmd(t, "unset", ecr))
+	assert.Empty(t, cfg.Registries.Default)
+	assert.NotContains(t, cfg.Registries.Registries, ecr)
+}
+
+func TestRegistrySet_Project(t *testing.T) {
+	cfg, project := withConfigDirs(t)
+
+	require.NoError(t, runRegistryCmd(t, "set", "ghcr.io", "--namespace", "acme", "--project"))
+	assert.Nil(t, cfg.Registries)
+
+	registries, path, err := config.LoadProjectRegistries(project)
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(project, config.RegistriesFile), path)
+	assert.Equal(t, "acme", registries.Registries["ghcr.io"].Namespace)
+
+	merged, err := loadRegistries()
+	require.NoError(t, err)
+	assert.Equal(t, "acme", merged.Registries["ghcr.io"].Namespace)
+}
+
+func TestRegistrySet_Errors(t *testing.T) {
+	withConfigDirs(t)
+
+	err := runRegistryCmd(t, "set", "ghcr.io")
+	assert.ErrorContains(t, err, "nothing to set")
+
+	err = runRegistryCmd(t, "set", "https://ghcr.io/acme", "--namespace", "acme")
+	assert.ErrorContains(t, err, "expected a host such as ghcr.io")
+}
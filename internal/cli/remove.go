@@ -0,0 +1,191 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/AlecAivazis/survey/v2"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/manifest"
+)
+
+// RemoveOptions holds options for the remove command
+type RemoveOptions struct {
+	Name      string
+	Yes       bool
+	KeepFiles bool
+}
+
+// For testing - allows overriding the confirmation prompt
+var confirmComponentChange = func(message string) (bool, error) {
+	confirm := false
+	prompt := &survey.Confirm{
+		Message: message,
+		Default: false,
+	}
+	if err := survey.AskOne(prompt, &confirm); err != nil {
+		return false, err
+	}
+	return confirm, nil
+}
+
+func newRemoveCmd() *cobra.Command {
+	opts := &RemoveOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "remove <component>",
+		Short: "Remove a component and its directory",
+		Long: `Remove a component from the FTL configuration and delete its directory.
+
+The component's directory is its build workdir (the directory created by
+'ftl add'). Other components allowed to call the removed component through
+its spin.internal host are reported, since their references need updating.
+
+Examples:
+  # Remove a component, confirming first
+  ftl remove weather
+
+  # Remove it from the configuration only, keeping its files
+  ftl remove weather --keep-files --yes`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.Name = args[0]
+			return runRemove(opts)
+		},
+	}
+
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Skip the confirmation prompt")
+	cmd.Flags().BoolVar(&opts.KeepFiles, "keep-files", false, "Keep the component's directory")
+
+	return cmd
+}
+
+func runRemove(opts *RemoveOptions) error {
+	// Load manifest (tries ftl.yaml, ftl.yml, ftl.json)
+	m, err := manifest.LoadAuto()
+	if err != nil {
+		return fmt.Errorf("failed to load manifest: %w", err)
+	}
+
+	if len(m.Components) == 0 {
+		return fmt.Errorf("no components to remove")
+	}
+
+	// If no name provided, show interactive selection
+	name := opts.Name
+	if name == "" {
+		var options []string
+		for _, comp := range m.Components {
+			options = append(options, comp.ID)
+		}
+
+		prompt := &survey.Select{
+			Message: "Select component to remove:",
+			Options: options,
+		}
+		if err := survey.AskOne(prompt, &name); err != nil {
+			return err
+		}
+	}
+
+	comp, _ := m.FindComponent(name)
+	if comp == nil {
+		return fmt.Errorf("component '%s' not found", name)
+	}
+
+	dir := ""
+	if !opts.KeepFiles {
+		dir = ownedComponentDir(m, comp)
+	}
+
+	message := fmt.Sprintf("Remove component '%s'?", name)
+	if dir != "" {
+		message = fmt.Sprintf("Remove component '%s' and delete %s/?", name, dir)
+	}
+	confirmed, err := confirmChange(opts.Yes, message)
+	if err != nil || !confirmed {
+		return err
+	}
+
+	if err := m.RemoveComponent(name); err != nil {
+		return err
+	}
+
+	// Save manifest (to the same file format)
+	if err := m.SaveAuto(); err != nil {
+		return fmt.Errorf("failed to save manifest: %w", err)
+	}
+
+	if dir != "" {
+		if err := os.RemoveAll(dir); err != nil {
+			return fmt.Errorf("component removed from manifest, but failed to delete %s: %w", dir, err)
+		}
+	}
+
+	color.Green("✓ Component '%s' removed", name)
+	if dir != "" {
+		fmt.Printf("  Deleted %s/\n", dir)
+	}
+	warnInternalHostReferences(m, name)
+	return nil
+}
+
+// confirmChange asks for confirmation unless yes is set. It fails outside a
+// terminal, where the prompt can't be answered.
+func confirmChange(yes bool, message string) (bool, error) {
+	if yes {
+		return true, nil
+	}
+	if !isInteractive() {
+		return false, fmt.Errorf("this change requires confirmation. Use --yes to skip confirmation in non-interactive mode")
+	}
+
+	confirmed, err := confirmComponentChange(message)
+	if err != nil {
+		return false, err
+	}
+	if !confirmed {
+		fmt.Println("Cancelled")
+	}
+	return confirmed, nil
+}
+
+// ownedComponentDir returns the directory of a component when it exists and
+// no other component uses it, so it can be deleted or moved with the component
+func ownedComponentDir(m *manifest.Manifest, comp *manifest.Component) string {
+	dir := comp.Dir()
+	if dir == "" {
+		return ""
+	}
+	if info, err := os.Stat(dir); err != nil || !info.IsDir() {
+		return ""
+	}
+
+	for _, other := range m.Components {
+		if other.ID == comp.ID {
+			continue
+		}
+		otherDir := other.Dir()
+		if otherDir == dir || isSubPath(otherDir, dir) || isSubPath(dir, otherDir) {
+			Warn("%s/ is shared with component '%s', leaving it in place", dir, other.ID)
+			return ""
+		}
+	}
+	return dir
+}
+
+// isSubPath reports whether path is inside the directory dir
+func isSubPath(path, dir string) bool {
+	return path != "" && dir != "" && strings.HasPrefix(path, dir+"/")
+}
+
+// warnInternalHostReferences warns about components still allowed to call a
+// component through its spin.internal host, which no longer exists
+func warnInternalHostReferences(m *manifest.Manifest, id string) {
+	for _, ref := range m.InternalHostReferences(id) {
+		Warn("Component '%s' still lists http://%s.spin.internal in allowed_outbound_hosts", ref, id)
+	}
+}
@@ -0,0 +1,213 @@
+package cli
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/manifest"
+)
+
+const removeTestManifest = `name: test-app
+version: "0.1.0"
+access: public
+components:
+  - id: weather
+    source: weather/weather.wasm
+    build:
+      command: make build
+      workdir: weather
+      watch:
+        - weather/src/**/*.rs
+        - Cargo.toml
+  - id: client
+    source: client/client.wasm
+    build:
+      command: make build
+      workdir: client
+    allowed_outbound_hosts:
+      - http://weather.spin.internal
+  - id: published
+    source:
+      registry: ghcr.io
+      package: acme/published
+      version: 1.0.0
+`
+
+// setupRemoveTest creates a project with component directories in a temp dir
+// and changes into it
+func setupRemoveTest(t *testing.T) {
+	t.Helper()
+
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	t.Cleanup(func() { _ = os.Chdir(oldWd) })
+	require.NoError(t, os.Chdir(tmpDir))
+
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte(removeTestManifest), 0600))
+	for _, dir := range []string{"weather", "client"} {
+		require.NoError(t, os.MkdirAll(filepath.Join(dir, "src"), 0755))
+		require.NoError(t, os.WriteFile(filepath.Join(dir, "Makefile"), []byte("build:\n"), 0600))
+	}
+}
+
+func TestRemoveCommands(t *testing.T) {
+	cmd := newRemoveCmd()
+	assert.Equal(t, "remove <component>", cmd.Use)
+	assert.NotNil(t, cmd.Flags().Lookup("yes"))
+	assert.NotNil(t, cmd.Flags().Lookup("keep-files"))
+	assert.Error(t, cmd.Args(cmd, []string{}))
+
+	cmd = newRenameCmd()
+	assert.Equal(t, "rename <old> <new>", cmd.Use)
+	assert.NotNil(t, cmd.Flags().Lookup("keep-files"))
+	assert.Error(t, cmd.Args(cmd, []string{"weather"}))
+}
+
+func TestRunRemove(t *testing.T) {
+	setupRemoveTest(t)
+
+	require.NoError(t, runRemove(&RemoveOptions{Name: "weather", Yes: true}))
+
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	comp, _ := m.FindComponent("weather")
+	assert.Nil(t, comp)
+	assert.Len(t, m.Components, 2)
+	assert.NoDirExists(t, "weather")
+	assert.DirExists(t, "client")
+}
+
+func TestRunRemove_KeepFiles(t *testing.T) {
+	setupRemoveTest(t)
+
+	require.NoError(t, runRemove(&RemoveOptions{Name: "weather", Yes: true, KeepFiles: true}))
+
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	assert.Len(t, m.Components, 2)
+	assert.DirExists(t, "weather")
+}
+
+func TestRunRemove_Cancelled(t *testing.T) {
+	if !isInteractive() {
+		t.Skip("confirmation is only asked in an interactive terminal")
+	}
+	setupRemoveTest(t)
+
+	oldConfirm := confirmComponentChange
+	defer func() { confirmComponentChange = oldConfirm }()
+	var asked string
+	confirmComponentChange = func(message string) (bool, error) {
+		asked = message
+		return false, nil
+	}
+
+	require.NoError(t, runRemove(&RemoveOptions{Name: "weather"}))
+	assert.Equal(t, "Remove component 'weather' and delete weather/?", asked)
+
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	assert.Len(t, m.Components, 3)
+	assert.DirExists(t, "weather")
+}
+
+func TestRunRemove_Errors(t *testing.T) {
+	setupRemoveTest(t)
+
+	err := runRemove(&RemoveOptions{Name: "missing", Yes: true})
+	assert.EqualError(t, err, "component 'missing' not found")
+
+	if !isInteractive() {
+		err = runRemove(&RemoveOptions{Name: "weather"})
+		assert.ErrorContains(t, err, "Use --yes")
+		assert.DirExists(t, "weather")
+	}
+}
+
+func TestRunRemove_SharedDirKept(t *testing.T) {
+	setupRemoveTest(t)
+
+	// A second component built in the same directory
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	require.NoError(t, m.AddComponent(manifest.Component{
+		ID:     "weather-admin",
+		Source: "weather/admin.wasm",
+		Build:  &manifest.BuildConfig{Command: "make admin", Workdir: "weather"},
+	}))
+	require.NoError(t, m.Save("ftl.yaml"))
+
+	require.NoError(t, runRemove(&RemoveOptions{Name: "weather", Yes: true}))
+	assert.DirExists(t, "weather")
+}
+
+func TestRunRename(t *testing.T) {
+	setupRemoveTest(t)
+
+	require.NoError(t, runRename(&RenameOptions{OldName: "weather", NewName: "forecast", Yes: true}))
+
+	assert.NoDirExists(t, "weather")
+	assert.FileExists(t, filepath.Join("forecast", "Makefile"))
+
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	old, _ := m.FindComponent("weather")
+	assert.Nil(t, old)
+	comp, index := m.FindComponent("forecast")
+	require.NotNil(t, comp)
+	assert.Equal(t, 0, index)
+	assert.Equal(t, "forecast/weather.wasm", comp.Source)
+	assert.Equal(t, "forecast", comp.Build.Workdir)
+	assert.Equal(t, []string{"forecast/src/**/*.rs", "Cargo.toml"}, comp.Build.Watch)
+
+	// References are reported, not rewritten
+	client, _ := m.FindComponent("client")
+	require.NotNil(t, client)
+	assert.Equal(t, []string{"http://weather.spin.internal"}, client.AllowedOutboundHosts)
+}
+
+func TestRunRename_KeepFiles(t *testing.T) {
+	setupRemoveTest(t)
+
+	require.NoError(t, runRename(&RenameOptions{OldName: "weather", NewName: "forecast", Yes: true, KeepFiles: true}))
+
+	assert.DirExists(t, "weather")
+	m, err := manifest.Load("ftl.yaml")
+	require.NoError(t, err)
+	comp, _ := m.FindComponent("forecast")
+	require.NotNil(t, comp)
+	assert.Equal(t, "weather", comp.Build.Workdir)
+}
+
+func TestRunRename_Errors(t *testing.T) {
+	setupRemoveTest(t)
+
+	tests := []struct {
+		name    string
+		oldName string
+		newName string
+		wantErr string
+	}{
+		{"missing component", "missing", "other", "component 'missing' not found"},
+		{"existing name", "weather", "client", "component 'client' already exists"},
+		{"invalid name", "weather", "Forecast", "component name must contain only lowercase letters, numbers, and hyphens"},
+		{"reserved name", "weather", "mcp-gateway", "component name 'mcp-gateway' is reserved"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := runRename(&RenameOptions{OldName: tt.oldName, NewName: tt.newName, Yes: true})
+			assert.EqualError(t, err, tt.wantErr)
+		})
+	}
+
+	// The target directory is never overwritten
+	require.NoError(t, os.MkdirAll("forecast", 0755))
+	err := runRename(&RenameOptions{OldName: "weather", NewName: "forecast", Yes: true})
+	assert.ErrorContains(t, err, "forecast already exists")
+	assert.DirExists(t, "weather")
+}
@@ -0,0 +1,135 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path"
+
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/internal/scaffold"
+)
+
+// reservedComponentNames are the IDs of the components FTL adds to every app
+var reservedComponentNames = []string{"mcp-gateway", "mcp-authorizer"}
+
+// RenameOptions holds options for the rename command
+type RenameOptions struct {
+	OldName   string
+	NewName   string
+	Yes       bool
+	KeepFiles bool
+}
+
+func newRenameCmd() *cobra.Command {
+	opts := &RenameOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "rename <old> <new>",
+		Short: "Rename a component and move its directory",
+		Long: `Rename a component in the FTL configuration and move its directory.
+
+When the component's directory is named after it (as created by 'ftl add'),
+the directory is moved too, and the component's source, build workdir and
+watch paths are updated to match. Other components allowed to call the
+component through its old spin.internal host are reported, since their
+references need updating.
+
+Examples:
+  # Rename a component and move weather/ to forecast/
+  ftl rename weather forecast
+
+  # Rename it in the configuration only
+  ftl rename weather forecast --keep-files --yes`,
+		Args: cobra.ExactArgs(2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.OldName = args[0]
+			opts.NewName = args[1]
+			return runRename(opts)
+		},
+	}
+
+	cmd.Flags().BoolVarP(&opts.Yes, "yes", "y", false, "Skip the confirmation prompt")
+	cmd.Flags().BoolVar(&opts.KeepFiles, "keep-files", false, "Keep the component's directory where it is")
+
+	return cmd
+}
+
+func runRename(opts *RenameOptions) error {
+	if err := scaffold.ValidateComponentName(opts.NewName); err != nil {
+		return err
+	}
+	for _, reserved := range reservedComponentNames {
+		if opts.NewName == reserved {
+			return fmt.Errorf("component name '%s' is reserved", opts.NewName)
+		}
+	}
+
+	// Load manifest (tries ftl.yaml, ftl.yml, ftl.json)
+	m, err := manifest.LoadAuto()
+	if err != nil {
+		return fmt.Errorf("failed to load manifest: %w", err)
+	}
+
+	comp, index := m.FindComponent(opts.OldName)
+	if comp == nil {
+		return fmt.Errorf("component '%s' not found", opts.OldName)
+	}
+	if existing, _ := m.FindComponent(opts.NewName); existing != nil {
+		return fmt.Errorf("component '%s' already exists", opts.NewName)
+	}
+
+	// Only directories named after the component follow its name
+	oldDir, newDir := "", ""
+	if !opts.KeepFiles {
+		if dir := ownedComponentDir(m, comp); dir != "" && path.Base(dir) == opts.OldName {
+			oldDir = dir
+			newDir = path.Join(path.Dir(dir), opts.NewName)
+		}
+	}
+	if newDir != "" {
+		if _, err := os.Stat(newDir); err == nil {
+			return fmt.Errorf("cannot move %s/ to %s/: %s already exists", oldDir, newDir, newDir)
+		}
+	}
+
+	message := fmt.Sprintf("Rename component '%s' to '%s'?", opts.OldName, opts.NewName)
+	if newDir != "" {
+		message = fmt.Sprintf("Rename component '%s' to '%s' and move %s/ to %s/?", opts.OldName, opts.NewName, oldDir, newDir)
+	}
+	confirmed, err := confirmChange(opts.Yes, message)
+	if err != nil || !confirmed {
+		return err
+	}
+
+	if err := m.RenameComponent(opts.OldName, opts.NewName); err != nil {
+		return err
+	}
+
+	if newDir != "" {
+		if err := os.Rename(oldDir, newDir); err != nil {
+			return fmt.Errorf("failed to move %s to %s: %w", oldDir, newDir, err)
+		}
+		m.Components[index].MoveDir(oldDir, newDir)
+	}
+
+	// Save manifest (to the same file format)
+	if err := m.SaveAuto(); err != nil {
+		// Keep the directory where the unchanged manifest expects it
+		if newDir != "" {
+			_ = os.Rename(newDir, oldDir)
+		}
+		return fmt.Errorf("failed to save manifest: %w", err)
+	}
+
+	color.Green("✓ Component '%s' renamed to '%s'", opts.OldName, opts.NewName)
+	if newDir != "" {
+		fmt.Printf("  Moved %s/ to %s/\n", oldDir, newDir)
+	}
+	for _, ref := range m.InternalHostReferences(opts.OldName) {
+		Warn("Component '%s' lists http://%s.spin.internal in allowed_outbound_hosts; update it to http://%s.spin.internal", ref, opts.OldName, opts.NewName)
+	}
+	return nil
+}
@@ -0,0 +1,289 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"path/filepath"
+	"reflect"
+	"slices"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+)
+
+const (
+	replayMatch = "match"
+	replayDiff  = "diff"
+	replayError = "error"
+)
+
+type replayOptions struct {
+	dir   string
+	only  []string
+	url   string
+	token string
+}
+
+// replayResult is the outcome of re-sending one recorded call
+type replayResult struct {
+	File   string   `json:"file"`
+	Tool   string   `json:"tool"`
+	Result string   `json:"result"`
+	Diffs  []string `json:"diffs,omitempty"`
+	Error  string   `json:"error,omitempty"`
+}
+
+func newReplayCmd() *cobra.Command {
+	opts := replayOptions{}
+	var output string
+
+	cmd := &cobra.Command{
+		Use:   "replay <dir>",
+		Short: "Re-send recorded tool calls and diff the responses",
+		Long: `Re-send the tools/call requests recorded with 'ftl up --record <dir>' to the
+running application and compare the responses with the recorded ones.
+
+The responses are compared as JSON, ignoring the JSON-RPC id, and each
+difference is reported with its path in the response. The command fails when
+any response differs, so a directory of recordings from a real client session
+can serve as a regression test.
+
+Authorization headers are not recorded. Pass --token when the application
+requires authentication.`,
+		Example: `  ftl up --record recordings/bug-142
+  ftl replay recordings/bug-142
+  ftl replay recordings/bug-142 --only weather__forecast
+  ftl replay recordings/bug-142 --url https://my-app.example.com/mcp --token $TOKEN`,
+		Args: cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			opts.dir = args[0]
+			results, err := replayRecordings(cmd.Context(), opts)
+			if err != nil {
+				return err
+			}
+			return writeReplayResults(NewDataWriter(cmd.OutOrStdout(), output), results)
+		},
+	}
+
+	cmd.Flags().StringArrayVar(&opts.only, "only", nil, "replay only the calls of this tool (can be specified multiple times)")
+	cmd.Flags().StringVar(&opts.url, "url", "http://"+defaultListenAddress+"/mcp", "MCP endpoint of the running application")
+	cmd.Flags().StringVar(&opts.token, "token", "", "bearer token for the MCP endpoint")
+	cmd.Flags().StringVarP(&output, "output", "o", "table", "output format (table, json)")
+
+	return cmd
+}
+
+// replayRecordings re-sends the recorded calls of a directory in recording order
+func replayRecordings(ctx context.Context, opts replayOptions) ([]replayResult, error) {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	files, err := recordingFiles(opts.dir)
+	if err != nil {
+		return nil, err
+	}
+
+	client := &http.Client{Timeout: 60 * time.Second}
+	var results []replayResult
+	for _, file := range files {
+		entry, err := loadRecording(file)
+		if err != nil {
+			return nil, err
+		}
+		if len(opts.only) > 0 && !slices.Contains(opts.only, entry.Tool) {
+			continue
+		}
+
+		result := replayResult{File: filepath.Base(file), Tool: entry.Tool}
+		status, body, err := sendRecordedCall(ctx, client, opts.url, opts.token, entry.Request)
+		if err != nil {
+			result.Result = replayError
+			result.Error = err.Error()
+		} else {
+			result.Diffs = diffReplay(entry, status, body)
+			result.Result = replayMatch
+			if len(result.Diffs) > 0 {
+				result.Result = replayDiff
+			}
+		}
+		results = append(results, result)
+	}
+
+	if len(results) == 0 {
+		return nil, fmt.Errorf("no recorded calls to replay in %s", opts.dir)
+	}
+	return results, nil
+}
+
+func sendRecordedCall(ctx context.Context, client *http.Client, url, token string, request json.RawMessage) (int, []byte, error) {
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, url, bytes.NewReader(request))
+	if err != nil {
+		return 0, nil, err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("Accept", "application/json")
+	if token != "" {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return 0, nil, fmt.Errorf("failed to call %s: %w", url, err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	body, err := io.ReadAll(io.LimitReader(resp.Body, maxRecordedBodySize))
+	if err != nil {
+		return 0, nil, fmt.Errorf("failed to read response: %w", err)
+	}
+	return resp.StatusCode, body, nil
+}
+
+// diffReplay compares a replayed response with the recorded one
+func diffReplay(entry recordedCall, status int, body []byte) []string {
+	var diffs []string
+	if status != entry.Status {
+		diffs = append(diffs, fmt.Sprintf("status: %d != %d", entry.Status, status))
+	}
+
+	want, err := normalizeResponse(entry.Response)
+	if err != nil {
+		return append(diffs, fmt.Sprintf("recorded response is not JSON: %v", err))
+	}
+	got, err := normalizeResponse(bytes.TrimSpace(body))
+	if err != nil {
+		return append(diffs, fmt.Sprintf("response is not JSON: %s", truncateReplay(string(body))))
+	}
+	return append(diffs, diffJSON("response", want, got)...)
+}
+
+// normalizeResponse decodes a JSON-RPC response without its id
+func normalizeResponse(data []byte) (interface{}, error) {
+	if len(data) == 0 {
+		return nil, nil
+	}
+	var value interface{}
+	if err := json.Unmarshal(data, &value); err != nil {
+		return nil, err
+	}
+	if obj, ok := value.(map[string]interface{}); ok {
+		delete(obj, "id")
+	}
+	return value, nil
+}
+
+// diffJSON lists the paths at which two decoded JSON values differ
+func diffJSON(path string, want, got interface{}) []string {
+	switch w := want.(type) {
+	case map[string]interface{}:
+		g, ok := got.(map[string]interface{})
+		if !ok {
+			break
+		}
+		keys := make([]string, 0, len(w)+len(g))
+		for k := range w {
+			keys = append(keys, k)
+		}
+		for k := range g {
+			if _, ok := w[k]; !ok {
+				keys = append(keys, k)
+			}
+		}
+		sort.Strings(keys)
+
+		var diffs []string
+		for _, k := range keys {
+			wv, inWant := w[k]
+			gv, inGot := g[k]
+			switch {
+			case !inGot:
+				diffs = append(diffs, fmt.Sprintf("%s.%s: missing", path, k))
+			case !inWant:
+				diffs = append(diffs, fmt.Sprintf("%s.%s: unexpected %s", path, k, compactJSON(gv)))
+			default:
+				diffs = append(diffs, diffJSON(path+"."+k, wv, gv)...)
+			}
+		}
+		return diffs
+	case []interface{}:
+		g, ok := got.([]interface{})
+		if !ok {
+			break
+		}
+		var diffs []string
+		if len(w) != len(g) {
+			diffs = append(diffs, fmt.Sprintf("%s: %d items != %d items", path, len(w), len(g)))
+		}
+		for i := 0; i < len(w) && i < len(g); i++ {
+			diffs = append(diffs, diffJSON(fmt.Sprintf("%s[%d]", path, i), w[i], g[i])...)
+		}
+		return diffs
+	}
+
+	if reflect.DeepEqual(want, got) {
+		return nil
+	}
+	return []string{fmt.Sprintf("%s: %s != %s", path, compactJSON(want), compactJSON(got))}
+}
+
+func compactJSON(v interface{}) string {
+	data, err := json.Marshal(v)
+	if err != nil {
+		return fmt.Sprint(v)
+	}
+	return truncateReplay(string(data))
+}
+
+func writeReplayResults(dw *DataWriter, results []replayResult) error {
+	failed := 0
+	for _, r := range results {
+		if r.Result != replayMatch {
+			failed++
+		}
+	}
+
+	if dw.format == OutputFormatJSON {
+		if err := dw.WriteStruct(results); err != nil {
+			return err
+		}
+	} else {
+		tb := NewTableBuilder("FILE", "TOOL", "RESULT", "DETAIL")
+		for _, r := range results {
+			tb.AddRow(r.File, r.Tool, r.Result, replayDetail(r))
+		}
+		if err := tb.Write(dw); err != nil {
+			return err
+		}
+	}
+
+	if failed > 0 {
+		return fmt.Errorf("%d of %d replayed call(s) did not match", failed, len(results))
+	}
+	if dw.format != OutputFormatJSON {
+		Success("All %d replayed call(s) matched", len(results))
+	}
+	return nil
+}
+
+func replayDetail(r replayResult) string {
+	if r.Error != "" {
+		return r.Error
+	}
+	return strings.Join(r.Diffs, "; ")
+}
+
+// truncateReplay shortens values shown in diffs
+func truncateReplay(s string) string {
+	const limit = 80
+	if len(s) <= limit {
+		return s
+	}
+	return s[:limit] + "..."
+}
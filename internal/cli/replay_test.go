@@ -0,0 +1,105 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"net/http"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func writeRecording(t *testing.T, dir, name string, entry recordedCall) {
+	t.Helper()
+	data, err := json.Marshal(entry)
+	require.NoError(t, err)
+	require.NoError(t, os.WriteFile(filepath.Join(dir, name), data, 0600))
+}
+
+func echoRecording(tool, message, response string) recordedCall {
+	return recordedCall{
+		Tool:       tool,
+		RecordedAt: time.Now().UTC(),
+		Status:     http.StatusOK,
+		Request:    json.RawMessage(`{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"` + tool + `","arguments":{"message":"` + message + `"}}}`),
+		Response:   json.RawMessage(`{"jsonrpc":"2.0","id":1,"result":{"content":[{"type":"text","text":"` + response + `"}]}}`),
+	}
+}
+
+func TestReplayCommand(t *testing.T) {
+	cmd := newReplayCmd()
+	assert.Equal(t, "replay <dir>", cmd.Use)
+	for _, flag := range []string{"only", "url", "token", "output"} {
+		assert.NotNil(t, cmd.Flags().Lookup(flag), flag)
+	}
+	assert.Equal(t, "http://localhost:3000/mcp", cmd.Flags().Lookup("url").DefValue)
+}
+
+func TestReplayRecordings(t *testing.T) {
+	app := newEchoToolServer(t, "echo: ")
+	dir := t.TempDir()
+	writeRecording(t, dir, "0001-echo__echo.json", echoRecording("echo__echo", "hi", "echo: hi"))
+	writeRecording(t, dir, "0002-echo__shout.json", echoRecording("echo__shout", "hi", "HI"))
+
+	results, err := replayRecordings(context.Background(), replayOptions{dir: dir, url: app.URL})
+	require.NoError(t, err)
+	require.Len(t, results, 2)
+
+	assert.Equal(t, replayMatch, results[0].Result)
+	assert.Empty(t, results[0].Diffs)
+
+	assert.Equal(t, replayDiff, results[1].Result)
+	assert.Equal(t, []string{`response.result.content[0].text: "HI" != "echo: hi"`}, results[1].Diffs)
+
+	var out bytes.Buffer
+	err = writeReplayResults(NewDataWriter(&out, "table"), results)
+	assert.ErrorContains(t, err, "1 of 2 replayed call(s) did not match")
+	assert.Contains(t, out.String(), "0002-echo__shout.json")
+
+	// --only limits the replay to some tools
+	results, err = replayRecordings(context.Background(), replayOptions{dir: dir, url: app.URL, only: []string{"echo__echo"}})
+	require.NoError(t, err)
+	require.Len(t, results, 1)
+	assert.NoError(t, writeReplayResults(NewDataWriter(&out, "json"), results))
+
+	_, err = replayRecordings(context.Background(), replayOptions{dir: dir, url: app.URL, only: []string{"other"}})
+	assert.ErrorContains(t, err, "no recorded calls to replay")
+}
+
+func TestReplayUnreachableApp(t *testing.T) {
+	dir := t.TempDir()
+	writeRecording(t, dir, "0001-echo__echo.json", echoRecording("echo__echo", "hi", "echo: hi"))
+
+	address, err := freeLocalAddress()
+	require.NoError(t, err)
+	results, err := replayRecordings(context.Background(), replayOptions{dir: dir, url: "http://" + address + "/mcp"})
+	require.NoError(t, err)
+	require.Len(t, results, 1)
+	assert.Equal(t, replayError, results[0].Result)
+}
+
+func TestDiffJSON(t *testing.T) {
+	decode := func(s string) interface{} {
+		var v interface{}
+		require.NoError(t, json.Unmarshal([]byte(s), &v))
+		return v
+	}
+
+	assert.Empty(t, diffJSON("response", decode(`{"a":[1,{"b":true}]}`), decode(`{"a":[1,{"b":true}]}`)))
+	assert.Equal(t, []string{
+		"response.a: 2 items != 1 items",
+		"response.c: missing",
+		`response.d: unexpected "x"`,
+	}, diffJSON("response", decode(`{"a":[1,2],"c":1}`), decode(`{"a":[1],"d":"x"}`)))
+	assert.Equal(t, []string{`response.a: {"b":1} != [1]`}, diffJSON("response", decode(`{"a":{"b":1}}`), decode(`{"a":[1]}`)))
+
+	// The JSON-RPC id is ignored
+	entry := echoRecording("echo__echo", "hi", "echo: hi")
+	assert.Empty(t, diffReplay(entry, http.StatusOK, []byte(`{"jsonrpc":"2.0","id":99,"result":{"content":[{"type":"text","text":"echo: hi"}]}}`)))
+	assert.Equal(t, []string{"status: 200 != 500"}, diffReplay(entry, http.StatusInternalServerError, []byte(`{"jsonrpc":"2.0","id":1,"result":{"content":[{"type":"text","text":"echo: hi"}]}}`)))
+}
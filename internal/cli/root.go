@@ -4,10 +4,13 @@ import (
 	"fmt"
 	"io"
 	"os"
+	"time"
 
 	"github.com/fatih/color"
 	"github.com/spf13/cobra"
 	"github.com/spf13/viper"
+
+	"github.com/fastertools/ftl/internal/config"
 )
 
 var (
@@ -29,6 +32,15 @@ var (
 
 	// For testing - allows redirecting output
 	colorOutput io.Writer = os.Stdout
+
+	// For testing - allows overriding the user's preferences
+	userPreferences = func() config.Preferences {
+		cfg, err := config.Load()
+		if err != nil {
+			return config.Preferences{ColorOutput: true}
+		}
+		return cfg.Preferences
+	}
 )
 
 // rootCmd represents the base command
@@ -38,17 +50,33 @@ var rootCmd = &cobra.Command{
 	Long: `FTL is a comprehensive toolkit for building, composing, and deploying 
 AI tools on WebAssembly. It provides everything you need to create secure,
 high-performance MCP servers that can run anywhere.`,
-	PersistentPreRun: func(cmd *cobra.Command, args []string) {
-		if noColor {
+	PersistentPreRunE: func(cmd *cobra.Command, args []string) error {
+		if err := applyCommandDefaults(cmd); err != nil {
+			return err
+		}
+		if err := configureRegistryCredentials(); err != nil {
+			return err
+		}
+		prefs := userPreferences()
+		if noColor || !prefs.ColorOutput {
 			color.NoColor = true
 		}
+		if prefs.Verbose {
+			viper.SetDefault("verbose", true)
+		}
+		preferManagedTools()
+		startCommandLog(cmd)
+		return checkNetworkAccess(cmd)
 	},
 	Version: fmt.Sprintf("%s (commit: %s, built: %s)", version, commit, buildDate),
 }
 
 // Execute runs the root command
 func Execute() error {
-	return rootCmd.Execute()
+	started := time.Now()
+	cmd, err := rootCmd.ExecuteC()
+	finishCommand(cmd, started, err)
+	return err
 }
 
 // SetVersion sets the version information
@@ -66,6 +94,7 @@ func init() {
 	rootCmd.PersistentFlags().StringVar(&cfgFile, "config", "", "config file (default is ./ftl.yaml)")
 	rootCmd.PersistentFlags().BoolVarP(&verbose, "verbose", "v", false, "verbose output")
 	rootCmd.PersistentFlags().BoolVar(&noColor, "no-color", false, "disable colored output")
+	rootCmd.PersistentFlags().BoolVar(&offline, "offline", false, "run without network access (also FTL_OFFLINE=1)")
 
 	// Bind flags to viper
 	_ = viper.BindPFlag("verbose", rootCmd.PersistentFlags().Lookup("verbose"))
@@ -75,19 +104,34 @@ func init() {
 	rootCmd.AddCommand(
 		newInitCmd(),
 		newAddCmd(),
+		newRemoveCmd(),
+		newRenameCmd(),
+		newMigrateCmd(),
+		newAdoptCmd(),
 		newBuildCmd(),
 		newTestCmd(),
 		newComponentCmd(),
 		newDeployCmd(),
+		newWsCmd(),
 		newAuthCmd(),
 		newOrgCmd(),
+		newEngCmd(),
 		newUpCmd(),
+		newReplayCmd(),
+		newDevTokenCmd(),
 		newRegistryCmd(),
 		newSynthCmd(),
 		newListCmd(),
 		newStatusCmd(),
 		newDeleteCmd(),
 		newLogsCmd(),
+		newToolchainCmd(),
+		newSetupCmd(),
+		newGenerateCmd(),
+		newSchemaCmd(),
+		newDoctorCmd(),
+		newTelemetryCmd(),
+		newConfigCmd(),
 	)
 }
 
@@ -121,6 +165,7 @@ func Success(format string, args ...interface{}) {
 
 // Error prints an error message
 func Error(format string, args ...interface{}) {
+	logLine("error: "+format, args...)
 	fmt.Fprintln(os.Stderr, errorColor.Sprintf("✗ "+format, args...))
 }
 
@@ -131,11 +176,14 @@ func Info(format string, args ...interface{}) {
 
 // Warn prints a warning message
 func Warn(format string, args ...interface{}) {
+	logLine("warn: "+format, args...)
 	fmt.Fprintln(os.Stderr, warnColor.Sprintf("⚠ "+format, args...))
 }
 
 // Debug prints a debug message if verbose mode is enabled
 func Debug(format string, args ...interface{}) {
+	// Debug messages are always logged so bug reports include them
+	logLine("debug: "+format, args...)
 	if IsVerbose() {
 		fmt.Fprintln(os.Stderr, color.New(color.FgMagenta).Sprintf("» "+format, args...))
 	}
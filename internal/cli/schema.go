@@ -0,0 +1,366 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/clientgen"
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/spin"
+)
+
+// toolCatalogCachePath is where 'ftl schema' keeps the tool metadata of the
+// built application, so it is only listed again when the build changes
+var toolCatalogCachePath = filepath.Join(".ftl", "tool-catalog.json")
+
+type schemaOptions struct {
+	format  string
+	out     string
+	build   bool
+	refresh bool
+	url     string
+	token   string
+}
+
+// toolCatalog is the merged catalog of the tools an application serves
+type toolCatalog struct {
+	Application string        `json:"application,omitempty"`
+	Tools       []catalogTool `json:"tools"`
+}
+
+// catalogTool is a tool of the catalog with the component serving it
+type catalogTool struct {
+	Name         string          `json:"name"`
+	Component    string          `json:"component,omitempty"`
+	Title        string          `json:"title,omitempty"`
+	Description  string          `json:"description,omitempty"`
+	InputSchema  json.RawMessage `json:"inputSchema,omitempty"`
+	OutputSchema json.RawMessage `json:"outputSchema,omitempty"`
+	Annotations  json.RawMessage `json:"annotations,omitempty"`
+}
+
+// cachedToolCatalog is the content of toolCatalogCachePath
+type cachedToolCatalog struct {
+	// Fingerprint identifies the build the tools were listed from
+	Fingerprint string           `json:"fingerprint"`
+	Tools       []clientgen.Tool `json:"tools"`
+}
+
+func newSchemaCmd() *cobra.Command {
+	opts := schemaOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "schema",
+		Short: "Export the catalog of the application's tools",
+		Long: `Export a machine-readable catalog of every tool the application serves,
+with its name, component, description, input and output schemas and
+annotations, for documentation and clients.
+
+The tools are read with tools/list from the built application, which is
+started briefly with 'spin up' as for 'ftl generate client'. The metadata is
+cached in .ftl/tool-catalog.json and reused until spin.toml or a built
+component changes. Use --build to build the application first, --refresh to
+ignore the cache, or --url to read the tools of a running application.
+
+The catalog is written as JSON or Markdown to stdout, or to --out.`,
+		Example: `  ftl schema
+  ftl schema --format markdown --out docs/tools.md
+  ftl schema --build --out tools.json
+  ftl schema --url https://my-app.example.com/mcp --token "$TOKEN"`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runSchema(cmd.Context(), cmd.OutOrStdout(), opts)
+		},
+	}
+
+	cmd.Flags().StringVar(&opts.format, "format", "json", "catalog format (json, markdown)")
+	cmd.Flags().StringVar(&opts.out, "out", "", "write the catalog to a file instead of stdout")
+	cmd.Flags().BoolVar(&opts.build, "build", false, "build the application first")
+	cmd.Flags().BoolVar(&opts.refresh, "refresh", false, "list the tools again instead of using cached metadata")
+	cmd.Flags().StringVar(&opts.url, "url", "", "list the tools from a running MCP endpoint instead of the built application")
+	cmd.Flags().StringVar(&opts.token, "token", "", "bearer token for --url")
+
+	return cmd
+}
+
+func runSchema(ctx context.Context, w io.Writer, opts schemaOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	format := strings.ToLower(opts.format)
+	if format == "md" {
+		format = "markdown"
+	}
+	if format != "json" && format != "markdown" {
+		return fmt.Errorf("unsupported format %q (supported: json, markdown)", opts.format)
+	}
+
+	if opts.build && opts.url == "" {
+		Info("Building application first")
+		if err := spin.Build(ctx); err != nil {
+			return fmt.Errorf("failed to build: %w", err)
+		}
+	}
+
+	var tools []clientgen.Tool
+	var err error
+	if opts.url != "" {
+		tools, err = listMCPTools(ctx, opts.url, opts.token)
+	} else {
+		tools, err = loadCatalogTools(ctx, opts.refresh)
+	}
+	if err != nil {
+		return err
+	}
+
+	name := ""
+	if m, err := manifest.LoadAuto(); err == nil {
+		name = m.Name
+	}
+	catalog := newToolCatalog(name, tools)
+
+	var data []byte
+	if format == "markdown" {
+		data = renderCatalogMarkdown(catalog)
+	} else {
+		encoded, err := json.MarshalIndent(catalog, "", "  ")
+		if err != nil {
+			return fmt.Errorf("failed to encode the tool catalog: %w", err)
+		}
+		data = append(encoded, '\n')
+	}
+
+	if opts.out == "" {
+		_, err := w.Write(data)
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(opts.out), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(opts.out), err)
+	}
+	if err := os.WriteFile(opts.out, data, 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", opts.out, err)
+	}
+	Success("Wrote the catalog of %d tool(s) to %s", len(catalog.Tools), opts.out)
+	return nil
+}
+
+// loadCatalogTools lists the tools of the built application, or returns the
+// cached ones when the build has not changed since they were listed
+func loadCatalogTools(ctx context.Context, refresh bool) ([]clientgen.Tool, error) {
+	fingerprint, err := buildFingerprint()
+	if err != nil {
+		return nil, err
+	}
+
+	if !refresh {
+		if cached, err := readToolCatalogCache(); err == nil && cached.Fingerprint == fingerprint {
+			Debug("Using the tool metadata cached in %s", toolCatalogCachePath)
+			return cached.Tools, nil
+		}
+	}
+
+	tools, err := loadAppTools(ctx, "", "")
+	if err != nil {
+		return nil, err
+	}
+	if err := writeToolCatalogCache(&cachedToolCatalog{Fingerprint: fingerprint, Tools: tools}); err != nil {
+		Warn("Tool metadata not cached: %v", err)
+	}
+	return tools, nil
+}
+
+// buildFingerprint hashes spin.toml and the components built from it
+func buildFingerprint() (string, error) {
+	data, err := os.ReadFile("spin.toml")
+	if err != nil {
+		return "", fmt.Errorf("no spin.toml found, run 'ftl build' first or pass --build or --url")
+	}
+	sources, err := commands.BuiltSources(".")
+	if err != nil {
+		return "", err
+	}
+	ids := make([]string, 0, len(sources))
+	for id := range sources {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	h := sha256.New()
+	_, _ = h.Write(data)
+	for _, id := range ids {
+		wasm, err := os.ReadFile(filepath.Clean(sources[id]))
+		if err != nil {
+			return "", fmt.Errorf("built component %s not found at %s, run 'ftl build' first or pass --build", id, sources[id])
+		}
+		_, _ = fmt.Fprintf(h, "%s %x\n", id, sha256.Sum256(wasm))
+	}
+	return hex.EncodeToString(h.Sum(nil)), nil
+}
+
+func readToolCatalogCache() (*cachedToolCatalog, error) {
+	data, err := os.ReadFile(toolCatalogCachePath)
+	if err != nil {
+		return nil, err
+	}
+	var cached cachedToolCatalog
+	if err := json.Unmarshal(data, &cached); err != nil {
+		return nil, err
+	}
+	return &cached, nil
+}
+
+func writeToolCatalogCache(cached *cachedToolCatalog) error {
+	if err := os.MkdirAll(filepath.Dir(toolCatalogCachePath), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(toolCatalogCachePath), err)
+	}
+	data, err := json.MarshalIndent(cached, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode tool metadata: %w", err)
+	}
+	if err := os.WriteFile(toolCatalogCachePath, append(data, '\n'), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", toolCatalogCachePath, err)
+	}
+	return nil
+}
+
+// newToolCatalog merges the tools into a catalog sorted by name. The gateway
+// names tools component__tool, which gives the component of each.
+func newToolCatalog(application string, tools []clientgen.Tool) *toolCatalog {
+	catalog := &toolCatalog{Application: application, Tools: make([]catalogTool, 0, len(tools))}
+	for _, tool := range tools {
+		component, _, _ := strings.Cut(tool.Name, "__")
+		if component == tool.Name {
+			component = ""
+		}
+		catalog.Tools = append(catalog.Tools, catalogTool{
+			Name:         tool.Name,
+			Component:    component,
+			Title:        tool.Title,
+			Description:  tool.Description,
+			InputSchema:  tool.InputSchema,
+			OutputSchema: tool.OutputSchema,
+			Annotations:  tool.Annotations,
+		})
+	}
+	sort.Slice(catalog.Tools, func(i, j int) bool { return catalog.Tools[i].Name < catalog.Tools[j].Name })
+	return catalog
+}
+
+// renderCatalogMarkdown renders the catalog as a Markdown document with a
+// section per tool
+func renderCatalogMarkdown(catalog *toolCatalog) []byte {
+	var b bytes.Buffer
+	title := "Tools"
+	if catalog.Application != "" {
+		title = catalog.Application + " tools"
+	}
+	_, _ = fmt.Fprintf(&b, "# %s\n\n%d tool(s).\n", title, len(catalog.Tools))
+
+	for _, tool := range catalog.Tools {
+		_, _ = fmt.Fprintf(&b, "\n## `%s`\n\n", tool.Name)
+		if tool.Title != "" {
+			_, _ = fmt.Fprintf(&b, "**%s**\n\n", tool.Title)
+		}
+		if tool.Description != "" {
+			_, _ = fmt.Fprintf(&b, "%s\n\n", tool.Description)
+		}
+		if tool.Component != "" {
+			_, _ = fmt.Fprintf(&b, "Component: `%s`\n\n", tool.Component)
+		}
+
+		_, _ = fmt.Fprint(&b, "### Input\n\n")
+		writeParametersMarkdown(&b, tool.InputSchema)
+
+		if output := indentJSON(tool.OutputSchema); output != "" {
+			_, _ = fmt.Fprintf(&b, "\n### Output\n\n```json\n%s\n```\n", output)
+		}
+
+		var annotations map[string]interface{}
+		if err := json.Unmarshal(tool.Annotations, &annotations); err == nil && len(annotations) > 0 {
+			_, _ = fmt.Fprint(&b, "\n### Annotations\n\n")
+			keys := make([]string, 0, len(annotations))
+			for key := range annotations {
+				keys = append(keys, key)
+			}
+			sort.Strings(keys)
+			for _, key := range keys {
+				value, _ := json.Marshal(annotations[key])
+				_, _ = fmt.Fprintf(&b, "- `%s`: `%s`\n", key, value)
+			}
+		}
+	}
+	return b.Bytes()
+}
+
+// writeParametersMarkdown writes a table of the top-level properties of an
+// input schema
+func writeParametersMarkdown(b *bytes.Buffer, raw json.RawMessage) {
+	var schema struct {
+		Properties map[string]struct {
+			Type        json.RawMessage `json:"type"`
+			Description string          `json:"description"`
+		} `json:"properties"`
+		Required []string `json:"required"`
+	}
+	if err := json.Unmarshal(raw, &schema); err != nil || len(schema.Properties) == 0 {
+		_, _ = fmt.Fprint(b, "No parameters.\n")
+		return
+	}
+
+	required := map[string]bool{}
+	for _, name := range schema.Required {
+		required[name] = true
+	}
+	names := make([]string, 0, len(schema.Properties))
+	for name := range schema.Properties {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	_, _ = fmt.Fprint(b, "| Parameter | Type | Required | Description |\n|---|---|---|---|\n")
+	for _, name := range names {
+		property := schema.Properties[name]
+		kind := "any"
+		var single string
+		var union []string
+		if err := json.Unmarshal(property.Type, &single); err == nil {
+			kind = single
+		} else if err := json.Unmarshal(property.Type, &union); err == nil {
+			kind = strings.Join(union, " \\| ")
+		}
+		requiredCell := "no"
+		if required[name] {
+			requiredCell = "yes"
+		}
+		_, _ = fmt.Fprintf(b, "| `%s` | %s | %s | %s |\n", name, kind, requiredCell, markdownCell(property.Description))
+	}
+}
+
+// markdownCell escapes text for a Markdown table cell
+func markdownCell(s string) string {
+	return strings.NewReplacer("|", "\\|", "\r\n", " ", "\n", " ").Replace(s)
+}
+
+// indentJSON pretty-prints a JSON value, or returns "" without one
+func indentJSON(raw json.RawMessage) string {
+	if len(bytes.TrimSpace(raw)) == 0 || string(raw) == "null" {
+		return ""
+	}
+	var out bytes.Buffer
+	if err := json.Indent(&out, raw, "", "  "); err != nil {
+		return string(raw)
+	}
+	return out.String()
+}
@@ -0,0 +1,143 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/clientgen"
+)
+
+func TestSchemaCommand(t *testing.T) {
+	cmd := newSchemaCmd()
+	assert.Equal(t, "schema", cmd.Use)
+	for _, flag := range []string{"format", "out", "build", "refresh", "url", "token"} {
+		assert.NotNil(t, cmd.Flags().Lookup(flag), flag)
+	}
+	assert.Equal(t, "json", cmd.Flags().Lookup("format").DefValue)
+}
+
+func TestNewToolCatalog(t *testing.T) {
+	catalog := newToolCatalog("weather-app", []clientgen.Tool{
+		{Name: "weather__forecast", Annotations: json.RawMessage(`{"readOnlyHint":true}`)},
+		{Name: "echo__echo", Description: "Echo a message"},
+		{Name: "standalone"},
+	})
+
+	require.Len(t, catalog.Tools, 3)
+	assert.Equal(t, "echo__echo", catalog.Tools[0].Name)
+	assert.Equal(t, "echo", catalog.Tools[0].Component)
+	assert.Equal(t, "", catalog.Tools[1].Component)
+	assert.Equal(t, "weather", catalog.Tools[2].Component)
+	assert.JSONEq(t, `{"readOnlyHint":true}`, string(catalog.Tools[2].Annotations))
+}
+
+func TestRunSchema_JSON(t *testing.T) {
+	server := newToolsServer(t, "secret")
+
+	var buf bytes.Buffer
+	require.NoError(t, runSchema(context.Background(), &buf, schemaOptions{format: "json", url: server.URL, token: "secret"}))
+
+	var catalog toolCatalog
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &catalog))
+	require.Len(t, catalog.Tools, 1)
+	assert.Equal(t, "echo__echo", catalog.Tools[0].Name)
+	assert.Equal(t, "echo", catalog.Tools[0].Component)
+	assert.Equal(t, "Echo a message", catalog.Tools[0].Description)
+	assert.Contains(t, string(catalog.Tools[0].InputSchema), `"required":["message"]`)
+
+	err := runSchema(context.Background(), &buf, schemaOptions{format: "yaml", url: server.URL})
+	assert.EqualError(t, err, `unsupported format "yaml" (supported: json, markdown)`)
+}
+
+func TestRunSchema_MarkdownToFile(t *testing.T) {
+	server := newToolsServer(t, "")
+	out := filepath.Join(t.TempDir(), "docs", "tools.md")
+
+	var buf bytes.Buffer
+	require.NoError(t, runSchema(context.Background(), &buf, schemaOptions{format: "markdown", out: out, url: server.URL}))
+	assert.Empty(t, buf.String())
+
+	data, err := os.ReadFile(out)
+	require.NoError(t, err)
+	assert.Contains(t, string(data), "## `echo__echo`\n\nEcho a message\n\nComponent: `echo`")
+	assert.Contains(t, string(data), "| `message` | string | yes |  |")
+}
+
+func TestRenderCatalogMarkdown(t *testing.T) {
+	catalog := &toolCatalog{Application: "weather-app", Tools: []catalogTool{{
+		Name:         "weather__forecast",
+		Component:    "weather",
+		Title:        "Forecast",
+		InputSchema:  json.RawMessage(`{"type":"object","properties":{"days":{"type":["integer","null"],"description":"Days | ahead"},"city":{"type":"string"}},"required":["city"]}`),
+		OutputSchema: json.RawMessage(`{"type":"object"}`),
+		Annotations:  json.RawMessage(`{"readOnlyHint":true,"openWorldHint":false}`),
+	}}}
+
+	md := string(renderCatalogMarkdown(catalog))
+	assert.Contains(t, md, "# weather-app tools\n\n1 tool(s).\n")
+	assert.Contains(t, md, "**Forecast**")
+	assert.Contains(t, md, "| `city` | string | yes |  |\n| `days` | integer \\| null | no | Days \\| ahead |\n")
+	assert.Contains(t, md, "### Output\n\n```json\n{\n  \"type\": \"object\"\n}\n```\n")
+	assert.Contains(t, md, "- `openWorldHint`: `false`\n- `readOnlyHint`: `true`\n")
+
+	md = string(renderCatalogMarkdown(&toolCatalog{Tools: []catalogTool{{Name: "ping"}}}))
+	assert.Contains(t, md, "# Tools\n")
+	assert.Contains(t, md, "### Input\n\nNo parameters.\n")
+	assert.NotContains(t, md, "### Output")
+}
+
+func TestLoadCatalogTools_Cache(t *testing.T) {
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	require.NoError(t, os.Chdir(tmpDir))
+
+	_, err := loadCatalogTools(context.Background(), false)
+	assert.ErrorContains(t, err, "no spin.toml found")
+
+	require.NoError(t, os.WriteFile("spin.toml", []byte(`
+[component.echo]
+source = "echo.wasm"
+[component.echo.build]
+command = "make"
+`), 0600))
+	_, err = loadCatalogTools(context.Background(), false)
+	assert.ErrorContains(t, err, "built component echo not found at echo.wasm")
+	require.NoError(t, os.WriteFile("echo.wasm", []byte("v1"), 0600))
+
+	server := newToolsServer(t, "")
+	oldStart := startLocalApp
+	t.Cleanup(func() { startLocalApp = oldStart })
+	started := 0
+	startLocalApp = func(ctx context.Context) (string, func(), error) {
+		started++
+		return server.URL, func() {}, nil
+	}
+
+	tools, err := loadCatalogTools(context.Background(), false)
+	require.NoError(t, err)
+	assert.Len(t, tools, 1)
+	assert.FileExists(t, toolCatalogCachePath)
+
+	// The cache is used until the build changes
+	tools, err = loadCatalogTools(context.Background(), false)
+	require.NoError(t, err)
+	assert.Len(t, tools, 1)
+	assert.Equal(t, 1, started)
+
+	_, err = loadCatalogTools(context.Background(), true)
+	require.NoError(t, err)
+	assert.Equal(t, 2, started)
+
+	require.NoError(t, os.WriteFile("echo.wasm", []byte("v2"), 0600))
+	_, err = loadCatalogTools(context.Background(), false)
+	require.NoError(t, err)
+	assert.Equal(t, 3, started)
+}
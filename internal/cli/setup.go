@@ -0,0 +1,425 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/doctor"
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/internal/toolchain"
+)
+
+// defaultToolVersions are the spin and wkg versions pinned by
+// 'ftl setup toolchain' when the project does not pin its own
+var defaultToolVersions = map[string]string{
+	"spin": "3.3.1",
+	"wkg":  "0.11.0",
+}
+
+// languageTools are the toolchains needed to build components of each language
+var languageTools = map[string][]doctor.ToolSpec{
+	"rust": {
+		{
+			Requirement: toolchain.Requirement{Tool: "cargo"},
+			Purpose:     "builds Rust tool components",
+			InstallHint: "install Rust from https://rustup.rs",
+		},
+	},
+	"typescript": {
+		{
+			Requirement: toolchain.Requirement{Tool: "node"},
+			Purpose:     "builds TypeScript tool components",
+			InstallHint: "install Node.js 22 from https://nodejs.org",
+		},
+		{
+			Requirement: toolchain.Requirement{Tool: "npm"},
+			Purpose:     "installs TypeScript dependencies",
+			InstallHint: "install Node.js 22 from https://nodejs.org",
+		},
+	},
+	"python": {
+		{
+			Requirement: toolchain.Requirement{Tool: "python3"},
+			Purpose:     "builds Python tool components with componentize-py",
+			InstallHint: "install Python 3.12 from https://www.python.org/downloads/",
+		},
+	},
+	"go": {
+		{
+			Requirement: toolchain.Requirement{Tool: "go"},
+			Purpose:     "resolves Go module dependencies",
+			InstallHint: "install Go 1.24 from https://go.dev/dl/",
+		},
+		{
+			Requirement: toolchain.Requirement{Tool: "tinygo"},
+			Purpose:     "builds Go tool components",
+			InstallHint: "install TinyGo 0.38.0 from https://tinygo.org/getting-started/install/",
+		},
+	},
+}
+
+// languageOrder is the order languages are detected and reported in
+var languageOrder = []string{"rust", "typescript", "python", "go"}
+
+// Setup action kinds
+const (
+	setupOK      = "ok"
+	setupInstall = "install"
+	setupManual  = "manual"
+)
+
+// setupAction is a single step of bootstrapping the toolchain
+type setupAction struct {
+	Tool   string `json:"tool"`
+	Action string `json:"action"`
+	Detail string `json:"detail"`
+
+	run func(ctx context.Context) error
+}
+
+// setupPlan is what 'ftl setup toolchain' will do
+type setupPlan struct {
+	// Pins are the tool versions to add to the project toolchain section
+	Pins    map[string]string `json:"pins,omitempty"`
+	Actions []setupAction     `json:"actions"`
+}
+
+// newSetupEnv returns the system access used to plan and apply the setup
+var newSetupEnv = func() (doctor.Env, error) {
+	mgr, err := toolchain.NewManager()
+	if err != nil {
+		return doctor.Env{}, err
+	}
+	return doctor.DefaultEnv(mgr), nil
+}
+
+func newSetupCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "setup",
+		Short: "Prepare this machine for FTL development",
+	}
+
+	cmd.AddCommand(newSetupToolchainCmd())
+
+	return cmd
+}
+
+func newSetupToolchainCmd() *cobra.Command {
+	var (
+		dryRun bool
+		output string
+	)
+
+	cmd := &cobra.Command{
+		Use:   "toolchain",
+		Short: "Install the tools needed to build the project",
+		Long: `Detect what is missing to build the project and install it.
+
+spin and wkg are installed into ~/.ftl/tools at the versions pinned in the
+project's toolchain section. When the project does not pin them, the versions
+FTL is tested with are installed and pinned in the project config, so every
+machine builds with the same tools.
+
+The languages of the project's components are detected from their project
+files (Cargo.toml, package.json, pyproject.toml, go.mod) and build commands:
+
+  rust        cargo and the wasm32-wasip1 target (added with rustup)
+  typescript  node and npm
+  python      python3
+  go          go and tinygo
+
+Language toolchains other than the Rust target cannot be installed by FTL;
+setup reports where to get them instead. Use --dry-run to preview the actions.`,
+		Example: `  ftl setup toolchain --dry-run
+  ftl setup toolchain
+  ftl setup toolchain -o json`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runSetupToolchain(cmd.Context(), NewDataWriter(cmd.OutOrStdout(), output), dryRun)
+		},
+	}
+
+	cmd.Flags().BoolVar(&dryRun, "dry-run", false, "show the actions without applying them")
+	cmd.Flags().StringVarP(&output, "output", "o", "table", "output format (table, json)")
+
+	return cmd
+}
+
+func runSetupToolchain(ctx context.Context, dw *DataWriter, dryRun bool) error {
+	// The project manifest is optional, setup also bootstraps spin and wkg outside a project
+	var project *manifest.Manifest
+	manifestPath := commands.FindDataConfig(".")
+	if manifestPath != "" {
+		m, err := manifest.Load(manifestPath)
+		if err != nil {
+			return fmt.Errorf("failed to load project config: %w", err)
+		}
+		project = m
+	}
+
+	var (
+		reqs      []toolchain.Requirement
+		languages []string
+	)
+	if project != nil {
+		reqs = toolchain.Requirements(project.Toolchain)
+		languages = projectLanguages(project)
+	}
+
+	env, err := newSetupEnv()
+	if err != nil {
+		return err
+	}
+	plan := planToolchainSetup(ctx, env, reqs, languages)
+	if manifestPath == "" {
+		plan.Pins = nil
+	}
+
+	if dryRun {
+		return writeSetupPlan(dw, plan)
+	}
+
+	for _, action := range plan.Actions {
+		if action.Action == setupInstall && offlineMode() {
+			return fmt.Errorf("cannot install %s while offline", action.Tool)
+		}
+	}
+
+	for i, action := range plan.Actions {
+		if action.Action != setupInstall {
+			continue
+		}
+		Info("Installing %s", action.Detail)
+		if err := action.run(ctx); err != nil {
+			return fmt.Errorf("failed to install %s: %w", action.Tool, err)
+		}
+		plan.Actions[i].Action = setupOK
+	}
+
+	if len(plan.Pins) > 0 {
+		if project.Toolchain == nil {
+			project.Toolchain = make(map[string]string, len(plan.Pins))
+		}
+		for tool, version := range plan.Pins {
+			project.Toolchain[tool] = version
+		}
+		if err := project.Save(manifestPath); err != nil {
+			return fmt.Errorf("failed to pin toolchain versions: %w", err)
+		}
+		Success("Pinned %s in %s", formatPins(plan.Pins), manifestPath)
+	}
+
+	if err := writeSetupPlan(dw, plan); err != nil {
+		return err
+	}
+
+	manual := 0
+	for _, action := range plan.Actions {
+		if action.Action == setupManual {
+			manual++
+		}
+	}
+	if manual > 0 {
+		return fmt.Errorf("%d tool(s) must be installed manually, see the details above", manual)
+	}
+	return nil
+}
+
+// planToolchainSetup works out the actions needed to satisfy the project
+// requirements and build components of the given languages
+func planToolchainSetup(ctx context.Context, env doctor.Env, reqs []toolchain.Requirement, languages []string) setupPlan {
+	plan := setupPlan{Pins: map[string]string{}}
+
+	declared := make(map[string]toolchain.Requirement, len(reqs))
+	for _, req := range reqs {
+		declared[req.Tool] = req
+	}
+
+	for _, tool := range []string{"spin", "wkg"} {
+		req, ok := declared[tool]
+		if !ok || req.Pinned() == "" {
+			version := defaultToolVersions[tool]
+			if ok && !req.Satisfied(version) {
+				plan.Actions = append(plan.Actions, setupAction{
+					Tool:   tool,
+					Action: setupManual,
+					Detail: fmt.Sprintf("pin an exact %s version satisfying %s in the project toolchain", tool, req.Constraint),
+				})
+				continue
+			}
+			req = toolchain.Requirement{Tool: tool, Constraint: version}
+			plan.Pins[tool] = version
+		}
+		plan.Actions = append(plan.Actions, planPinnedTool(ctx, env, req))
+	}
+
+	for _, lang := range languages {
+		for _, spec := range languageTools[lang] {
+			if req, ok := declared[spec.Requirement.Tool]; ok {
+				spec.Requirement = req
+			}
+			plan.Actions = append(plan.Actions, planLanguageTool(ctx, env, spec))
+		}
+		if lang == "rust" {
+			plan.Actions = append(plan.Actions, planWasmTarget(ctx, env))
+		}
+	}
+
+	if len(plan.Pins) == 0 {
+		plan.Pins = nil
+	}
+	return plan
+}
+
+// planPinnedTool installs a pinned spin or wkg into ~/.ftl/tools unless that
+// exact version is already available
+func planPinnedTool(ctx context.Context, env doctor.Env, req toolchain.Requirement) setupAction {
+	version := req.Pinned()
+	if status := env.CheckTool(ctx, req); status.OK() {
+		return setupAction{Tool: req.Tool, Action: setupOK, Detail: fmt.Sprintf("%s %s (%s)", req.Tool, status.Version, status.Path)}
+	}
+	return setupAction{
+		Tool:   req.Tool,
+		Action: setupInstall,
+		Detail: fmt.Sprintf("%s %s into ~/.ftl/tools", req.Tool, version),
+		run: func(ctx context.Context) error {
+			return env.InstallTool(ctx, req.Tool, version)
+		},
+	}
+}
+
+// planLanguageTool reports a language toolchain binary that must be installed by hand
+func planLanguageTool(ctx context.Context, env doctor.Env, spec doctor.ToolSpec) setupAction {
+	req := spec.Requirement
+	status := env.CheckTool(ctx, req)
+	switch {
+	case status.OK():
+		return setupAction{Tool: req.Tool, Action: setupOK, Detail: fmt.Sprintf("%s %s (%s)", req.Tool, status.Version, status.Path)}
+	case status.Path != "" && status.Error == nil:
+		return setupAction{
+			Tool:   req.Tool,
+			Action: setupManual,
+			Detail: fmt.Sprintf("%s %s does not satisfy %s: %s", req.Tool, status.Version, req.Constraint, spec.InstallHint),
+		}
+	default:
+		return setupAction{
+			Tool:   req.Tool,
+			Action: setupManual,
+			Detail: fmt.Sprintf("%s not found (%s): %s", req.Tool, spec.Purpose, spec.InstallHint),
+		}
+	}
+}
+
+// planWasmTarget adds the Rust wasm target with rustup when it is missing
+func planWasmTarget(ctx context.Context, env doctor.Env) setupAction {
+	tool := "rustup target " + doctor.WasmTarget
+	if _, err := env.Output(ctx, "rustup", "--version"); err != nil {
+		return setupAction{
+			Tool:   tool,
+			Action: setupManual,
+			Detail: fmt.Sprintf("rustup not found: install Rust from https://rustup.rs, then run 'rustup target add %s'", doctor.WasmTarget),
+		}
+	}
+
+	check := doctor.WasmTargetCheck(env)
+	if check.Run(ctx).Severity == doctor.SeverityOK {
+		return setupAction{Tool: tool, Action: setupOK, Detail: doctor.WasmTarget + " installed"}
+	}
+	return setupAction{
+		Tool:   tool,
+		Action: setupInstall,
+		Detail: doctor.WasmTarget + " with rustup",
+		run:    check.Fix,
+	}
+}
+
+// projectLanguages returns the languages of the project's locally built components
+func projectLanguages(m *manifest.Manifest) []string {
+	found := map[string]bool{}
+	for _, comp := range m.Components {
+		if lang := componentLanguage(comp); lang != "" {
+			found[lang] = true
+		}
+	}
+
+	languages := make([]string, 0, len(found))
+	for _, lang := range languageOrder {
+		if found[lang] {
+			languages = append(languages, lang)
+		}
+	}
+	return languages
+}
+
+// componentLanguage infers the language of a component from its project
+// files, falling back to its build command. Components without a build are
+// pulled prebuilt and need no language toolchain.
+func componentLanguage(comp manifest.Component) string {
+	if comp.Build == nil {
+		return ""
+	}
+
+	dir := comp.Dir()
+	if dir == "" {
+		dir = "."
+	}
+	for _, lang := range languageOrder {
+		if _, err := os.Stat(filepath.Join(dir, getConfigFileName(lang))); err == nil {
+			return lang
+		}
+	}
+
+	fields := strings.Fields(comp.Build.Command)
+	for _, field := range fields {
+		switch field {
+		case "cargo":
+			return "rust"
+		case "npm", "npx", "node":
+			return "typescript"
+		case "componentize-py", "python", "python3":
+			return "python"
+		case "tinygo", "go":
+			return "go"
+		}
+	}
+	return ""
+}
+
+// pinnedTools returns the pinned tools in name order
+func pinnedTools(pins map[string]string) []string {
+	tools := make([]string, 0, len(pins))
+	for tool := range pins {
+		tools = append(tools, tool)
+	}
+	sort.Strings(tools)
+	return tools
+}
+
+func formatPins(pins map[string]string) string {
+	parts := make([]string, 0, len(pins))
+	for _, tool := range pinnedTools(pins) {
+		parts = append(parts, tool+" "+pins[tool])
+	}
+	return strings.Join(parts, ", ")
+}
+
+func writeSetupPlan(dw *DataWriter, plan setupPlan) error {
+	if dw.format == OutputFormatJSON {
+		return dw.WriteStruct(plan)
+	}
+
+	tb := NewTableBuilder("TOOL", "ACTION", "DETAIL")
+	for _, tool := range pinnedTools(plan.Pins) {
+		tb.AddRow(tool, "pin", fmt.Sprintf("%s: %q in the project toolchain", tool, plan.Pins[tool]))
+	}
+	for _, action := range plan.Actions {
+		tb.AddRow(action.Tool, action.Action, action.Detail)
+	}
+	return tb.Write(dw)
+}
@@ -0,0 +1,174 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/doctor"
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/internal/toolchain"
+)
+
+// fakeSetupEnv reports the given tool versions as installed on PATH and
+// records the installs and commands run
+type fakeSetupEnv struct {
+	versions map[string]string
+	targets  string
+	rustup   bool
+	installs []string
+	commands []string
+}
+
+func (f *fakeSetupEnv) env() doctor.Env {
+	return doctor.Env{
+		CheckTool: func(ctx context.Context, req toolchain.Requirement) toolchain.Status {
+			status := toolchain.Status{Requirement: req}
+			version, ok := f.versions[req.Tool]
+			if !ok {
+				status.Error = errors.New(req.Tool + " not found on PATH")
+				return status
+			}
+			status.Path = "/usr/bin/" + req.Tool
+			status.Version = version
+			return status
+		},
+		InstallTool: func(ctx context.Context, tool, version string) error {
+			f.installs = append(f.installs, tool+"@"+version)
+			return nil
+		},
+		Output: func(ctx context.Context, name string, args ...string) (string, error) {
+			if !f.rustup {
+				return "", errors.New("rustup not found")
+			}
+			f.commands = append(f.commands, name+" "+args[0]+" "+args[len(args)-1])
+			return f.targets, nil
+		},
+	}
+}
+
+func findSetupAction(t *testing.T, plan setupPlan, tool string) setupAction {
+	t.Helper()
+	for _, action := range plan.Actions {
+		if action.Tool == tool {
+			return action
+		}
+	}
+	t.Fatalf("no setup action for %s", tool)
+	return setupAction{}
+}
+
+func TestSetupCommand(t *testing.T) {
+	cmd := newSetupCmd()
+	assert.Equal(t, "setup", cmd.Use)
+
+	toolchainCmd, _, err := cmd.Find([]string{"toolchain"})
+	require.NoError(t, err)
+	assert.NotNil(t, toolchainCmd.Flags().Lookup("dry-run"))
+	assert.NotNil(t, toolchainCmd.Flags().Lookup("output"))
+}
+
+func TestPlanToolchainSetupPinsDefaults(t *testing.T) {
+	fake := &fakeSetupEnv{versions: map[string]string{"spin": "3.2.0"}}
+
+	plan := planToolchainSetup(context.Background(), fake.env(), nil, nil)
+	assert.Equal(t, defaultToolVersions, plan.Pins)
+
+	spin := findSetupAction(t, plan, "spin")
+	assert.Equal(t, setupInstall, spin.Action)
+	assert.Equal(t, setupInstall, findSetupAction(t, plan, "wkg").Action)
+
+	require.NoError(t, spin.run(context.Background()))
+	assert.Equal(t, []string{"spin@" + defaultToolVersions["spin"]}, fake.installs)
+}
+
+func TestPlanToolchainSetupProjectPins(t *testing.T) {
+	fake := &fakeSetupEnv{versions: map[string]string{"spin": "3.4.0", "wkg": "0.11.0"}}
+	reqs := []toolchain.Requirement{
+		{Tool: "spin", Constraint: "3.4.0"},
+		{Tool: "wkg", Constraint: ">=0.12.0"},
+	}
+
+	plan := planToolchainSetup(context.Background(), fake.env(), reqs, nil)
+	assert.Nil(t, plan.Pins)
+	assert.Equal(t, setupOK, findSetupAction(t, plan, "spin").Action)
+
+	// The default wkg does not satisfy the project's minimum version
+	wkg := findSetupAction(t, plan, "wkg")
+	assert.Equal(t, setupManual, wkg.Action)
+	assert.Contains(t, wkg.Detail, ">=0.12.0")
+}
+
+func TestPlanToolchainSetupLanguages(t *testing.T) {
+	fake := &fakeSetupEnv{
+		versions: map[string]string{"spin": "3.3.1", "wkg": "0.11.0", "cargo": "1.88.0", "node": "22.1.0"},
+		rustup:   true,
+		targets:  "x86_64-unknown-linux-gnu\n",
+	}
+	reqs := []toolchain.Requirement{{Tool: "cargo", Constraint: ">=1.89.0"}}
+
+	plan := planToolchainSetup(context.Background(), fake.env(), reqs, []string{"rust", "typescript"})
+
+	cargo := findSetupAction(t, plan, "cargo")
+	assert.Equal(t, setupManual, cargo.Action)
+	assert.Contains(t, cargo.Detail, "1.88.0 does not satisfy >=1.89.0")
+	assert.Equal(t, setupOK, findSetupAction(t, plan, "node").Action)
+	assert.Contains(t, findSetupAction(t, plan, "npm").Detail, "https://nodejs.org")
+
+	target := findSetupAction(t, plan, "rustup target wasm32-wasip1")
+	assert.Equal(t, setupInstall, target.Action)
+	require.NoError(t, target.run(context.Background()))
+	assert.Contains(t, fake.commands, "rustup target wasm32-wasip1")
+
+	// Without rustup the target has to be added by hand
+	fake.rustup = false
+	plan = planToolchainSetup(context.Background(), fake.env(), nil, []string{"rust"})
+	assert.Equal(t, setupManual, findSetupAction(t, plan, "rustup target wasm32-wasip1").Action)
+}
+
+func TestProjectLanguages(t *testing.T) {
+	dir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	_ = os.Chdir(dir)
+
+	require.NoError(t, os.MkdirAll(filepath.Join(dir, "weather"), 0750))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "weather", "pyproject.toml"), []byte("[project]\n"), 0600))
+
+	m := &manifest.Manifest{
+		Components: []manifest.Component{
+			{ID: "weather", Source: "weather/app.wasm", Build: &manifest.BuildConfig{Command: "make build", Workdir: "weather"}},
+			{ID: "echo", Source: "echo/target/wasm32-wasip1/release/echo.wasm", Build: &manifest.BuildConfig{Command: "cargo build --target wasm32-wasip1 --release", Workdir: "echo"}},
+			{ID: "remote", Source: manifest.SourceRegistry{Registry: "ghcr.io", Package: "ns:remote", Version: "1.0.0"}},
+		},
+	}
+	assert.Equal(t, []string{"rust", "python"}, projectLanguages(m))
+}
+
+func TestWriteSetupPlan(t *testing.T) {
+	plan := setupPlan{
+		Pins: map[string]string{"wkg": "0.11.0", "spin": "3.3.1"},
+		Actions: []setupAction{
+			{Tool: "spin", Action: setupInstall, Detail: "spin 3.3.1 into ~/.ftl/tools"},
+			{Tool: "tinygo", Action: setupManual, Detail: "tinygo not found"},
+		},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, writeSetupPlan(NewDataWriter(&buf, "table"), plan))
+	assert.Contains(t, buf.String(), "ACTION")
+	assert.Contains(t, buf.String(), `spin: "3.3.1" in the project toolchain`)
+	assert.Contains(t, buf.String(), "tinygo not found")
+
+	buf.Reset()
+	require.NoError(t, writeSetupPlan(NewDataWriter(&buf, "json"), plan))
+	assert.Contains(t, buf.String(), `"action": "manual"`)
+	assert.Contains(t, buf.String(), `"wkg": "0.11.0"`)
+	assert.Equal(t, "spin 3.3.1, wkg 0.11.0", formatPins(plan.Pins))
+}
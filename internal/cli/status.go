@@ -2,6 +2,7 @@ package cli
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"strings"
 
@@ -60,6 +61,9 @@ func runStatusImpl(ctx context.Context, appIdentifier string, format string) err
 	if _, err := uuid.Parse(appIdentifier); err == nil {
 		// It's a UUID, get app directly
 		app, err = apiClient.GetApp(ctx, appIdentifier)
+		if errors.Is(err, api.ErrNotFound) {
+			return fmt.Errorf("application '%s' not found", appIdentifier)
+		}
 		if err != nil {
 			return fmt.Errorf("failed to get app: %w", err)
 		}
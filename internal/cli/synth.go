@@ -8,6 +8,7 @@ import (
 	"path/filepath"
 	"strings"
 
+	"github.com/fastertools/ftl/commands"
 	"github.com/fastertools/ftl/synthesis"
 	"github.com/spf13/cobra"
 )
@@ -73,6 +74,18 @@ Examples:
 				}
 			}
 
+			// Resolve local component paths relative to the config file and
+			// rewrite them for the directory the manifest is written to
+			if !strings.HasSuffix(filename, ".go") {
+				input, err = synthesis.ResolveConfigPaths(input, synthesis.PathOptions{
+					BaseDir:   filepath.Dir(filename),
+					OutputDir: filepath.Dir(outputFile),
+				})
+				if err != nil {
+					return fmt.Errorf("synthesis failed: %w", err)
+				}
+			}
+
 			// Detect format and synthesize
 			manifest, err := synthesizeFromInput(input, args)
 			if err != nil {
@@ -154,14 +167,16 @@ func detectFormat(input []byte) string {
 func synthesizeFromYAML(input []byte) (string, error) {
 	// Use CUE-first synthesizer for direct YAML processing
 	synth := synthesis.NewSynthesizer()
-	return synth.SynthesizeYAML(input)
+	manifest, err := synth.SynthesizeYAML(input)
+	return manifest, migrationHint(input, err)
 }
 
 // synthesizeFromJSON converts JSON to spin.toml
 func synthesizeFromJSON(input []byte) (string, error) {
 	// Use CUE-first synthesizer for direct JSON processing
 	synth := synthesis.NewSynthesizer()
-	return synth.SynthesizeJSON(input)
+	manifest, err := synth.SynthesizeJSON(input)
+	return manifest, migrationHint(input, err)
 }
 
 // synthesizeFromGo runs a Go file and captures its output
@@ -229,26 +244,10 @@ func synthesizeFromCUE(input []byte) (string, error) {
 
 // findConfigFile looks for FTL configuration files in priority order
 func findConfigFile() (string, error) {
-	// Define the search order for config files
-	configFiles := []string{
-		"ftl.yaml",
-		"ftl.yml",
-		"ftl.json",
-		"main.go",     // Common Go entry point
-		"platform.go", // Alternative Go name
-		"ftl.cue",
-		"app.cue", // Alternative CUE name
-	}
-
-	// Check each file in order
-	for _, file := range configFiles {
-		if _, err := os.Stat(file); err == nil {
-			// File exists
-			fmt.Fprintf(os.Stderr, "Using config file: %s\n", file)
-			return file, nil
-		}
+	file, err := commands.FindConfig(".")
+	if err != nil {
+		return "", fmt.Errorf("%w\n\nCreate one of these files or specify a file explicitly", err)
 	}
-
-	// No config file found
-	return "", fmt.Errorf("no FTL configuration file found. Looked for: %v\n\nCreate one of these files or specify a file explicitly", configFiles)
+	fmt.Fprintf(os.Stderr, "Using config file: %s\n", file)
+	return file, nil
 }
@@ -0,0 +1,227 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/AlecAivazis/survey/v2"
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/telemetry"
+)
+
+// bundleEvents is the number of recent events included in a bundle
+const bundleEvents = 50
+
+var (
+	// commandLog receives the debug log of the running command, if enabled
+	commandLog io.WriteCloser
+
+	// For testing - allows overriding the telemetry store, sinks and confirmation prompt
+	telemetryStore = telemetry.NewStore
+	telemetrySink  = defaultTelemetrySink
+	confirmBundle  = func(message string) (bool, error) {
+		confirm := false
+		prompt := &survey.Confirm{
+			Message: message,
+			Default: false,
+		}
+		err := survey.AskOne(prompt, &confirm)
+		return confirm, err
+	}
+)
+
+func newTelemetryCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "telemetry",
+		Short: "Inspect local diagnostics and create bug report bundles",
+		Long: `FTL keeps a small amount of diagnostic data on this machine: the most recent
+command runs and the debug log of the last command. Nothing is uploaded unless
+an OTLP collector is configured with 'ftl config set telemetry.otlp_endpoint'
+or FTL_TELEMETRY_OTLP_ENDPOINT, which then receives the outcome of each command
+(name, duration and error category, never error messages).
+
+Set FTL_TELEMETRY_DISABLED=1 to stop recording.`,
+	}
+
+	cmd.AddCommand(newTelemetryBundleCmd())
+
+	return cmd
+}
+
+func newTelemetryBundleCmd() *cobra.Command {
+	var (
+		output string
+		yes    bool
+		show   bool
+	)
+
+	cmd := &cobra.Command{
+		Use:   "bundle",
+		Short: "Create a redacted diagnostics bundle to attach to an issue",
+		Long: `Collect recent command runs, CLI version, OS information, the sanitized project
+config and the last command's debug log into a tarball you can attach to an issue.
+
+Tokens, credentials, variable values and your home directory are redacted.
+The contents are previewed before anything is written; use --show to print
+them in full.`,
+		Example: `  ftl telemetry bundle
+  ftl telemetry bundle --show
+  ftl telemetry bundle -o report.tar.gz --yes`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			store, err := telemetryStore()
+			if err != nil {
+				return err
+			}
+
+			bundle, err := store.Collect(telemetry.CollectOptions{
+				Version:       version,
+				Commit:        commit,
+				BuildDate:     buildDate,
+				ProjectConfig: commands.FindDataConfig("."),
+				Events:        bundleEvents,
+			})
+			if err != nil {
+				return err
+			}
+
+			if output == "" {
+				output = fmt.Sprintf("ftl-bundle-%s.tar.gz", time.Now().Format("20060102-150405"))
+			}
+			return writeTelemetryBundle(cmd.OutOrStdout(), bundle, output, yes, show)
+		},
+	}
+
+	cmd.Flags().StringVarP(&output, "output", "o", "", "path of the bundle (default ftl-bundle-<timestamp>.tar.gz)")
+	cmd.Flags().BoolVarP(&yes, "yes", "y", false, "write the bundle without confirmation")
+	cmd.Flags().BoolVar(&show, "show", false, "print the full contents of every file before writing")
+
+	return cmd
+}
+
+// writeTelemetryBundle previews the bundle, asks for consent and writes it
+func writeTelemetryBundle(w io.Writer, bundle *telemetry.Bundle, output string, yes, show bool) error {
+	_, _ = fmt.Fprintln(w, "The bundle will contain:")
+	for _, f := range bundle.Files {
+		_, _ = fmt.Fprintf(w, "  ftl-bundle/%-28s %d bytes\n", f.Name, len(f.Content))
+	}
+	if show {
+		for _, f := range bundle.Files {
+			_, _ = fmt.Fprintf(w, "\n--- %s ---\n%s", f.Name, f.Content)
+			if !strings.HasSuffix(string(f.Content), "\n") {
+				_, _ = fmt.Fprintln(w)
+			}
+		}
+	}
+	_, _ = fmt.Fprintln(w)
+
+	if !yes {
+		if !isInteractive() {
+			return fmt.Errorf("writing a bundle requires confirmation. Use --yes to skip confirmation in non-interactive mode")
+		}
+		ok, err := confirmBundle(fmt.Sprintf("Write these files to %s?", output))
+		if err != nil {
+			return err
+		}
+		if !ok {
+			Info("Bundle not written")
+			return nil
+		}
+	}
+
+	if dir := filepath.Dir(output); dir != "." {
+		if err := os.MkdirAll(dir, 0750); err != nil {
+			return fmt.Errorf("failed to create output directory: %w", err)
+		}
+	}
+	f, err := os.OpenFile(filepath.Clean(output), os.O_CREATE|os.O_TRUNC|os.O_WRONLY, 0600)
+	if err != nil {
+		return fmt.Errorf("failed to create bundle: %w", err)
+	}
+	if err := bundle.WriteArchive(f); err != nil {
+		_ = f.Close()
+		return fmt.Errorf("failed to write bundle: %w", err)
+	}
+	if err := f.Close(); err != nil {
+		return fmt.Errorf("failed to write bundle: %w", err)
+	}
+
+	Success("Bundle written to %s", output)
+	Info("Review it before attaching it to an issue")
+	return nil
+}
+
+// startCommandLog begins recording the debug log of the running command.
+// The telemetry commands are skipped so they do not overwrite the log of the
+// command being reported.
+func startCommandLog(cmd *cobra.Command) {
+	if !telemetry.Enabled() || isTelemetryCmd(cmd) {
+		return
+	}
+	store, err := telemetryStore()
+	if err != nil {
+		return
+	}
+	log, err := store.StartCommandLog(strings.TrimPrefix(cmd.CommandPath(), "ftl "))
+	if err != nil {
+		return
+	}
+	commandLog = log
+}
+
+// finishCommand closes the command log and records the run
+func finishCommand(cmd *cobra.Command, started time.Time, runErr error) {
+	if commandLog != nil {
+		if runErr != nil {
+			logLine("error: %v", runErr)
+		}
+		_ = commandLog.Close()
+		commandLog = nil
+	}
+
+	if cmd == nil || !telemetry.Enabled() || isTelemetryCmd(cmd) {
+		return
+	}
+	sink, err := telemetrySink()
+	if err != nil {
+		return
+	}
+	command := strings.TrimPrefix(cmd.CommandPath(), "ftl ")
+	// Telemetry must never break the CLI
+	_ = sink.Record(telemetry.CommandOutcome(command, version, started, runErr))
+}
+
+// defaultTelemetrySink records command outcomes to the local store, and to
+// an OTLP collector when one is configured
+func defaultTelemetrySink() (telemetry.Sink, error) {
+	store, err := telemetryStore()
+	if err != nil {
+		return nil, err
+	}
+	if endpoint := telemetry.OTLPEndpoint(); endpoint != "" {
+		return telemetry.Fanout(store, telemetry.NewOTLPSink(endpoint)), nil
+	}
+	return store, nil
+}
+
+func isTelemetryCmd(cmd *cobra.Command) bool {
+	for c := cmd; c != nil; c = c.Parent() {
+		if c.Name() == "telemetry" {
+			return true
+		}
+	}
+	return false
+}
+
+// logLine appends a line to the command log, if one is being recorded
+func logLine(format string, args ...interface{}) {
+	if commandLog == nil {
+		return
+	}
+	_, _ = fmt.Fprintf(commandLog, "%s "+format+"\n", append([]interface{}{time.Now().UTC().Format(time.RFC3339)}, args...)...)
+}
@@ -0,0 +1,142 @@
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/telemetry"
+)
+
+func TestTelemetryCommand(t *testing.T) {
+	cmd := newTelemetryCmd()
+	assert.Equal(t, "telemetry", cmd.Use)
+
+	bundle, _, err := cmd.Find([]string{"bundle"})
+	require.NoError(t, err)
+	assert.NotNil(t, bundle.Flags().Lookup("output"))
+	assert.NotNil(t, bundle.Flags().Lookup("yes"))
+	assert.NotNil(t, bundle.Flags().Lookup("show"))
+}
+
+func testBundle() *telemetry.Bundle {
+	return &telemetry.Bundle{Files: []telemetry.File{
+		{Name: "system.json", Content: []byte(`{"version": "dev"}`)},
+		{Name: "last-command.log", Content: []byte("debug: hello\n")},
+	}}
+}
+
+func TestWriteTelemetryBundle_Preview(t *testing.T) {
+	output := filepath.Join(t.TempDir(), "bundle.tar.gz")
+
+	var buf bytes.Buffer
+	require.NoError(t, writeTelemetryBundle(&buf, testBundle(), output, true, true))
+
+	assert.Contains(t, buf.String(), "ftl-bundle/system.json")
+	assert.Contains(t, buf.String(), "--- last-command.log ---\ndebug: hello")
+	_, err := os.Stat(output)
+	assert.NoError(t, err)
+}
+
+func TestWriteTelemetryBundle_Declined(t *testing.T) {
+	if !isInteractive() {
+		t.Skip("confirmation is only asked in interactive terminals")
+	}
+	oldConfirm := confirmBundle
+	defer func() { confirmBundle = oldConfirm }()
+	confirmBundle = func(string) (bool, error) { return false, nil }
+
+	output := filepath.Join(t.TempDir(), "bundle.tar.gz")
+	var buf bytes.Buffer
+	require.NoError(t, writeTelemetryBundle(&buf, testBundle(), output, false, false))
+
+	_, err := os.Stat(output)
+	assert.True(t, os.IsNotExist(err), "bundle should not be written without consent")
+}
+
+func TestWriteTelemetryBundle_RequiresConsent(t *testing.T) {
+	if isInteractive() {
+		t.Skip("test requires a non-interactive stdin")
+	}
+	output := filepath.Join(t.TempDir(), "bundle.tar.gz")
+	var buf bytes.Buffer
+	err := writeTelemetryBundle(&buf, testBundle(), output, false, false)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "--yes")
+
+	_, statErr := os.Stat(output)
+	assert.True(t, os.IsNotExist(statErr))
+}
+
+func TestFinishCommand_RecordsEvent(t *testing.T) {
+	dir := t.TempDir()
+	oldStore := telemetryStore
+	defer func() { telemetryStore = oldStore }()
+	telemetryStore = func() (*telemetry.Store, error) { return telemetry.NewStoreWithDir(dir), nil }
+	t.Setenv(telemetry.DisableEnv, "")
+
+	root := &cobra.Command{Use: "ftl"}
+	build := &cobra.Command{Use: "build"}
+	tel := &cobra.Command{Use: "telemetry"}
+	bundle := &cobra.Command{Use: "bundle"}
+	tel.AddCommand(bundle)
+	root.AddCommand(build, tel)
+
+	startCommandLog(build)
+	Debug("compiling %s", "tool")
+	finishCommand(build, time.Now(), assert.AnError)
+
+	// Telemetry commands are not recorded and keep the previous log
+	startCommandLog(bundle)
+	finishCommand(bundle, time.Now(), nil)
+
+	store := telemetry.NewStoreWithDir(dir)
+	events, err := store.RecentEvents(10)
+	require.NoError(t, err)
+	require.Len(t, events, 1)
+	assert.Equal(t, "build", events[0].Command)
+	assert.Equal(t, assert.AnError.Error(), events[0].Error)
+	assert.Equal(t, telemetry.OutcomeError, events[0].Outcome)
+	assert.Equal(t, telemetry.CategoryOther, events[0].ErrorCategory)
+
+	log, err := store.LastCommandLog()
+	require.NoError(t, err)
+	assert.Contains(t, string(log), "ftl build")
+	assert.Contains(t, string(log), "debug: compiling tool")
+}
+
+func TestFinishCommand_Sinks(t *testing.T) {
+	oldSink := telemetrySink
+	defer func() { telemetrySink = oldSink }()
+	sink := &telemetry.MemorySink{}
+	telemetrySink = func() (telemetry.Sink, error) { return sink, nil }
+	t.Setenv(telemetry.DisableEnv, "")
+
+	root := &cobra.Command{Use: "ftl"}
+	component := &cobra.Command{Use: "component"}
+	list := &cobra.Command{Use: "list"}
+	component.AddCommand(list)
+	root.AddCommand(component)
+
+	finishCommand(list, time.Now(), nil)
+	finishCommand(list, time.Now(), errors.New("unknown flag: --all"))
+
+	events := sink.Events()
+	require.Len(t, events, 2)
+	assert.Equal(t, "component list", events[0].Command)
+	assert.Equal(t, telemetry.OutcomeSuccess, events[0].Outcome)
+	assert.Equal(t, telemetry.OutcomeError, events[1].Outcome)
+	assert.Equal(t, telemetry.CategoryUsage, events[1].ErrorCategory)
+
+	// Disabled telemetry records nothing
+	t.Setenv(telemetry.DisableEnv, "1")
+	finishCommand(list, time.Now(), nil)
+	assert.Len(t, sink.Events(), 2)
+}
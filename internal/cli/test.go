@@ -1,33 +1,111 @@
 package cli
 
 import (
+	"context"
 	"fmt"
 	"os"
 	"os/exec"
+	"os/signal"
 	"path/filepath"
 	"strings"
+	"syscall"
 
 	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
 )
 
 func newTestCmd() *cobra.Command {
 	var coverage bool
 	var verbose bool
+	var watch bool
+	var components []string
+	var schemas bool
+	schemaOpts := schemaTestOptions{}
+	var ephemeral bool
+	ephemeralOpts := ephemeralTestOptions{}
 
 	cmd := &cobra.Command{
 		Use:   "test [path]",
 		Short: "Run tests for the FTL application",
 		Long: `Run tests for the FTL application and its components.
 
-Runs 'go test' on the specified path or current directory.
+In a project with an ftl.yaml or ftl.json, the tests of each component with a
+build are run in its build.workdir: 'make test' when it has a Makefile (as
+created by 'ftl add'), otherwise cargo test, go test, npm test or pytest.
+Without an FTL config, runs 'go test' on the specified path or current directory.
+
+With --watch, the tests are run again whenever files matching a component's
+build.watch patterns change, only for the components that changed.
+
+With --schemas, the input and output schemas of the application's tools are
+compared with the golden files in the schemas directory instead, so schema
+changes that would break MCP clients fail the tests. The tools are listed from
+the built application, started with the spin.toml written by 'ftl build', or
+with --url from a running application such as 'ftl up'. Run with --update to
+write the golden files after an intended change, then commit them.
+
+With --ephemeral, the application is deployed to a new sandbox engine named
+<app>-test-<suffix>, the component tests are run with its URL in FTL_TEST_URL
+(and a bearer token in FTL_TEST_TOKEN unless the app is public), and the
+engine is deleted afterwards. --keep-on-failure keeps the engine when tests
+fail, for debugging. Sandbox engines that earlier runs left behind are deleted
+by the next ephemeral run once they are older than --ttl.
 
 Examples:
-  ftl test             # Run tests in current directory
-  ftl test ./...       # Run all tests recursively
-  ftl test -c          # Run with coverage
-  ftl test -v ./pkg    # Run with verbose output`,
+  ftl test                        # Run the tests of every component
+  ftl test --component weather    # Only the weather component
+  ftl test --watch                # Re-run tests of changed components
+  ftl test --ephemeral            # Test against a sandbox deployment
+  ftl test --schemas              # Check tool schemas against schemas/
+  ftl test --schemas --update     # Accept the current tool schemas
+  ftl test ./...                  # Run go tests recursively
+  ftl test -c                     # Run with coverage
+  ftl test -v ./pkg               # Run with verbose output`,
 		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
+			if schemas {
+				if len(args) > 0 || len(components) > 0 || watch || ephemeral {
+					return fmt.Errorf("--schemas can't be combined with a path, --component, --watch or --ephemeral")
+				}
+				return runSchemaTests(cmd.Context(), cmd.OutOrStdout(), schemaOpts)
+			}
+			if schemaOpts.update || schemaOpts.url != "" || cmd.Flags().Changed("schemas-dir") {
+				return fmt.Errorf("--update, --url and --schemas-dir require --schemas")
+			}
+			if ephemeral {
+				if len(args) > 0 || watch {
+					return fmt.Errorf("--ephemeral can't be combined with a path or --watch")
+				}
+				tests, err := loadComponentTests(components)
+				if err != nil {
+					return err
+				}
+				if tests == nil {
+					return fmt.Errorf("--ephemeral requires an ftl.yaml or ftl.json with buildable components")
+				}
+				ephemeralOpts.configFile = commands.FindDataConfig(".")
+				return runEphemeralTests(cmd.Context(), cmd.OutOrStdout(), tests, ephemeralOpts)
+			}
+			if ephemeralOpts.keepOnFailure || len(ephemeralOpts.variables) > 0 || cmd.Flags().Changed("ttl") {
+				return fmt.Errorf("--keep-on-failure, --ttl and --var require --ephemeral")
+			}
+			if len(args) == 0 {
+				tests, err := loadComponentTests(components)
+				if err != nil {
+					return err
+				}
+				if tests != nil {
+					if coverage || verbose {
+						Warn("--coverage and --verbose only apply to go test")
+					}
+					return runTests(cmd.Context(), cmd, tests, watch)
+				}
+			}
+			if len(components) > 0 || watch {
+				return fmt.Errorf("--component and --watch require an ftl.yaml or ftl.json with buildable components")
+			}
+
 			path := "./..."
 			if len(args) > 0 {
 				path = args[0]
@@ -65,6 +143,54 @@ Examples:
 
 	cmd.Flags().BoolVarP(&coverage, "coverage", "c", false, "Run tests with coverage")
 	cmd.Flags().BoolVarP(&verbose, "verbose", "v", false, "Verbose test output")
+	cmd.Flags().BoolVarP(&watch, "watch", "w", false, "Re-run the tests of components whose files change")
+	cmd.Flags().StringArrayVar(&components, "component", nil, "Only test this component. Can be specified multiple times")
+	cmd.Flags().BoolVar(&schemas, "schemas", false, "Check the tool schemas against the golden files")
+	cmd.Flags().BoolVar(&schemaOpts.update, "update", false, "With --schemas, write the golden files from the current tool schemas")
+	cmd.Flags().StringVar(&schemaOpts.dir, "schemas-dir", defaultSchemasDir, "Directory of the golden schema files")
+	cmd.Flags().StringVar(&schemaOpts.url, "url", "", "With --schemas, list the tools from a running MCP endpoint instead of the built application")
+	cmd.Flags().StringVar(&schemaOpts.token, "token", "", "Bearer token for --url")
+	cmd.Flags().BoolVar(&ephemeral, "ephemeral", false, "Deploy to a sandbox engine, test against it and delete it")
+	cmd.Flags().BoolVar(&ephemeralOpts.keepOnFailure, "keep-on-failure", false, "With --ephemeral, keep the sandbox engine when tests fail")
+	cmd.Flags().DurationVar(&ephemeralOpts.ttl, "ttl", defaultSandboxTTL, "With --ephemeral, age after which sandbox engines left behind are deleted")
+	cmd.Flags().StringToStringVar(&ephemeralOpts.variables, "var", nil, "With --ephemeral, set a variable of the sandbox deployment (can be used multiple times)")
 
 	return cmd
 }
+
+// loadComponentTests returns the component tests of the FTL config in the
+// current directory, or nil when there is no config with buildable components
+func loadComponentTests(only []string) ([]componentTest, error) {
+	configFile := commands.FindDataConfig(".")
+	if configFile == "" {
+		return nil, nil
+	}
+	app, err := commands.LoadApplication(configFile)
+	if err != nil {
+		return nil, err
+	}
+	tests, err := componentTests(app, filepath.Dir(configFile), only)
+	if err != nil || len(tests) == 0 {
+		return nil, err
+	}
+	return tests, nil
+}
+
+// runTests runs the component tests once, or continuously with watch
+func runTests(ctx context.Context, cmd *cobra.Command, tests []componentTest, watch bool) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	w := cmd.OutOrStdout()
+
+	if watch {
+		ctx, stop := signal.NotifyContext(ctx, os.Interrupt, syscall.SIGTERM)
+		defer stop()
+		return watchComponentTests(ctx, w, tests)
+	}
+
+	if !printTestSummary(w, runComponentTests(ctx, w, tests)) {
+		return fmt.Errorf("tests failed")
+	}
+	return nil
+}
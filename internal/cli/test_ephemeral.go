@@ -0,0 +1,282 @@
+package cli
+
+import (
+	"context"
+	"crypto/rand"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"os"
+	"os/signal"
+	"regexp"
+	"syscall"
+	"time"
+
+	"github.com/fatih/color"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/internal/deploy"
+)
+
+const (
+	// sandboxInfix separates the application name from the random suffix of
+	// the sandbox engines deployed by ftl test --ephemeral
+	sandboxInfix = "-test-"
+
+	// defaultSandboxTTL is how long a sandbox engine left behind by a run is
+	// kept before the next ephemeral run deletes it
+	defaultSandboxTTL = time.Hour
+
+	// sandboxChangeReason is recorded with the deployments of sandbox engines
+	sandboxChangeReason = "Ephemeral test run"
+)
+
+// Environment variables passed to the tests of an ephemeral run
+const (
+	envTestURL   = "FTL_TEST_URL"
+	envTestToken = "FTL_TEST_TOKEN"
+)
+
+// ephemeralTestOptions holds the options of ftl test --ephemeral
+type ephemeralTestOptions struct {
+	configFile    string
+	ttl           time.Duration
+	keepOnFailure bool
+	variables     map[string]string
+}
+
+// sandboxClient is the subset of the FTL API used to manage sandbox engines
+type sandboxClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	DeleteApp(ctx context.Context, appID string) error
+	// Token returns the bearer token the tests send to non-public engines
+	Token(ctx context.Context) (string, error)
+}
+
+// Allow overriding for tests
+var (
+	newSandboxClient = newSandboxClientImpl
+	deploySandbox    = runDeploy
+)
+
+// ftlSandboxClient is the FTL API client along with the credentials it uses
+type ftlSandboxClient struct {
+	*api.FTLClient
+	authManager *auth.Manager
+}
+
+func (c *ftlSandboxClient) Token(ctx context.Context) (string, error) {
+	return c.authManager.GetToken(ctx)
+}
+
+func newSandboxClientImpl(ctx context.Context) (sandboxClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	// Ephemeral runs typically run in CI, so accept M2M credentials like 'ftl deploy'
+	if auth.IsM2MConfigured() {
+		if err := authManager.LoginMachine(ctx); err != nil {
+			return nil, fmt.Errorf("failed to authenticate with M2M credentials: %w", err)
+		}
+	}
+	if _, err := authManager.GetToken(ctx); err != nil {
+		token := auth.GetM2MTokenFromEnv()
+		if token == "" {
+			return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+		}
+		if err := authManager.LoginMachineWithToken(ctx, token); err != nil {
+			return nil, fmt.Errorf("failed to authenticate with M2M token: %w", err)
+		}
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return &ftlSandboxClient{FTLClient: client, authManager: authManager}, nil
+}
+
+// runEphemeralTests deploys the application to a new sandbox engine, runs
+// the component tests against it and deletes it again, unless the tests
+// failed and opts.keepOnFailure is set
+func runEphemeralTests(ctx context.Context, w io.Writer, tests []componentTest, opts ephemeralTestOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	if opts.ttl <= 0 {
+		return fmt.Errorf("--ttl must be positive")
+	}
+	app, err := commands.LoadApplication(opts.configFile)
+	if err != nil {
+		return err
+	}
+
+	// Interrupting the run still tears the sandbox engine down
+	ctx, stop := signal.NotifyContext(ctx, os.Interrupt, syscall.SIGTERM)
+	defer stop()
+
+	client, err := newSandboxClient(ctx)
+	if err != nil {
+		return err
+	}
+	deleteExpiredSandboxes(ctx, client, app.Name, opts.ttl, time.Now())
+
+	name, err := sandboxName(app.Name)
+	if err != nil {
+		return err
+	}
+	Info("Deploying %s to sandbox engine %s", app.Name, name)
+	err = deploySandbox(ctx, &DeployOptions{
+		Environment:  "test",
+		ConfigFile:   opts.configFile,
+		Yes:          true,
+		Variables:    opts.variables,
+		ChangeReason: sandboxChangeReason,
+		Strategy:     strategyDirect,
+		AutoRollback: true,
+		Name:         name,
+	})
+	if err != nil {
+		return fmt.Errorf("failed to deploy sandbox engine %s: %w", name, err)
+	}
+
+	teardown := func() {
+		deleteSandbox(context.WithoutCancel(ctx), client, name)
+	}
+	sandbox, err := findSandbox(ctx, client, name)
+	if err == nil && sandbox.url == "" {
+		err = fmt.Errorf("sandbox engine %s has no URL to test", name)
+	}
+	if err != nil {
+		teardown()
+		return err
+	}
+
+	env := []string{envTestURL + "=" + sandbox.url}
+	if sandbox.access != "" && sandbox.access != "public" {
+		token, err := client.Token(ctx)
+		if err != nil {
+			teardown()
+			return fmt.Errorf("failed to get token for the tests: %w", err)
+		}
+		env = append(env, envTestToken+"="+token)
+	}
+	for i := range tests {
+		tests[i].Env = append(tests[i].Env, env...)
+	}
+
+	_, _ = fmt.Fprintf(w, "%s Testing against %s\n", color.BlueString("→"), sandbox.url)
+	passed := printTestSummary(w, runComponentTests(ctx, w, tests))
+	if ctx.Err() != nil {
+		passed = false
+	}
+
+	if !passed && opts.keepOnFailure {
+		Warn("Kept sandbox engine %s at %s", name, sandbox.url)
+		Info("Delete it with 'ftl delete %s --force', or leave it to the next 'ftl test --ephemeral' after %s", name, opts.ttl)
+	} else {
+		teardown()
+	}
+
+	if !passed {
+		return fmt.Errorf("tests failed")
+	}
+	return nil
+}
+
+// sandboxName returns a new engine name for a sandbox of the application
+func sandboxName(app string) (string, error) {
+	suffix := make([]byte, 3)
+	if _, err := rand.Read(suffix); err != nil {
+		return "", fmt.Errorf("failed to generate sandbox name: %w", err)
+	}
+	return app + sandboxInfix + hex.EncodeToString(suffix), nil
+}
+
+// sandboxNamePattern matches the sandbox engine names of the application
+func sandboxNamePattern(app string) *regexp.Regexp {
+	return regexp.MustCompile("^" + regexp.QuoteMeta(app+sandboxInfix) + "[0-9a-f]{6}$")
+}
+
+// sandboxEngine is a deployed sandbox engine
+type sandboxEngine struct {
+	id     string
+	url    string
+	access string
+}
+
+// findSandbox looks up the deployed sandbox engine
+func findSandbox(ctx context.Context, client sandboxClient, name string) (*sandboxEngine, error) {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &name})
+	if err != nil {
+		return nil, fmt.Errorf("failed to look up sandbox engine %s: %w", name, err)
+	}
+	for _, app := range apps.Apps {
+		if app.AppName != name {
+			continue
+		}
+		engine := &sandboxEngine{id: app.AppId.String()}
+		if app.ProviderUrl != nil {
+			engine.url = *app.ProviderUrl
+		}
+		if app.AccessControl != nil {
+			engine.access = string(*app.AccessControl)
+		}
+		return engine, nil
+	}
+	return nil, fmt.Errorf("sandbox engine %s not found after deploying it", name)
+}
+
+// deleteSandbox deletes a sandbox engine and its deployment record
+func deleteSandbox(ctx context.Context, client sandboxClient, name string) {
+	sandbox, err := findSandbox(ctx, client, name)
+	if err == nil {
+		err = client.DeleteApp(ctx, sandbox.id)
+	}
+	if err != nil {
+		Warn("Failed to delete sandbox engine %s: %v. Delete it with 'ftl delete %s --force'", name, err, name)
+		return
+	}
+	removeSandboxRecord(name)
+	Success("Deleted sandbox engine %s", name)
+}
+
+// deleteExpiredSandboxes deletes the sandbox engines of the application that
+// earlier runs left behind, once they are older than ttl
+func deleteExpiredSandboxes(ctx context.Context, client sandboxClient, app string, ttl time.Duration, now time.Time) {
+	prefix := app + sandboxInfix
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &prefix})
+	if err != nil {
+		Warn("Failed to list sandbox engines left behind: %v", err)
+		return
+	}
+
+	pattern := sandboxNamePattern(app)
+	for _, sandbox := range apps.Apps {
+		if !pattern.MatchString(sandbox.AppName) {
+			continue
+		}
+		created, err := time.Parse(time.RFC3339, sandbox.CreatedAt)
+		if err != nil || now.Sub(created) < ttl {
+			continue
+		}
+		if err := client.DeleteApp(ctx, sandbox.AppId.String()); err != nil {
+			Warn("Failed to delete expired sandbox engine %s: %v", sandbox.AppName, err)
+			continue
+		}
+		removeSandboxRecord(sandbox.AppName)
+		Info("Deleted expired sandbox engine %s", sandbox.AppName)
+	}
+}
+
+// removeSandboxRecord removes the deployment record 'ftl deploy' saved for a sandbox engine
+func removeSandboxRecord(name string) {
+	if err := os.Remove(deploy.RecordPath(".", name)); err != nil && !os.IsNotExist(err) {
+		Debug("Failed to remove deployment record of %s: %v", name, err)
+	}
+}
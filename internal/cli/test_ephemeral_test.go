@@ -0,0 +1,255 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/deploy"
+)
+
+type fakeSandbox struct {
+	id        string
+	name      string
+	url       string
+	access    string
+	createdAt time.Time
+}
+
+type fakeSandboxClient struct {
+	apps    []fakeSandbox
+	deleted []string
+}
+
+func (f *fakeSandboxClient) ListApps(_ context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	var apps []map[string]interface{}
+	for _, app := range f.apps {
+		// The platform filters by partial name match
+		if params.Name != nil && !strings.Contains(app.name, *params.Name) {
+			continue
+		}
+		apps = append(apps, map[string]interface{}{
+			"appId":         app.id,
+			"appName":       app.name,
+			"providerUrl":   app.url,
+			"accessControl": app.access,
+			"createdAt":     app.createdAt.Format(time.RFC3339),
+		})
+	}
+	data, err := json.Marshal(map[string]interface{}{"apps": apps})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakeSandboxClient) DeleteApp(_ context.Context, appID string) error {
+	f.deleted = append(f.deleted, appID)
+	return nil
+}
+
+func (f *fakeSandboxClient) Token(_ context.Context) (string, error) {
+	return "test-token", nil
+}
+
+// stubSandbox replaces the API client and the deploy of ephemeral test runs.
+// Deploys add a sandbox engine with the given access control to the client.
+func stubSandbox(t *testing.T, client *fakeSandboxClient, access string) *DeployOptions {
+	t.Helper()
+	origClient, origDeploy := newSandboxClient, deploySandbox
+	t.Cleanup(func() { newSandboxClient, deploySandbox = origClient, origDeploy })
+
+	deployed := &DeployOptions{}
+	newSandboxClient = func(context.Context) (sandboxClient, error) { return client, nil }
+	deploySandbox = func(_ context.Context, opts *DeployOptions) error {
+		*deployed = *opts
+		client.apps = append(client.apps, fakeSandbox{
+			id:        stagingAppID,
+			name:      opts.Name,
+			url:       "https://" + opts.Name + ".example.com",
+			access:    access,
+			createdAt: time.Now(),
+		})
+		_, err := deploy.SaveRecord(".", &deploy.Record{App: opts.Name, AppID: stagingAppID})
+		return err
+	}
+	return deployed
+}
+
+// setupEphemeralProject creates an FTL project in a temporary working directory
+func setupEphemeralProject(t *testing.T) string {
+	t.Helper()
+	dir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	t.Cleanup(func() { _ = os.Chdir(oldWd) })
+	require.NoError(t, os.Chdir(dir))
+
+	yamlContent := `name: weather
+components:
+  - id: weather
+    source: weather
+    build:
+      command: make build
+      workdir: weather
+`
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte(yamlContent), 0600))
+	writeComponent(t, filepath.Join(dir, "weather"), "Makefile")
+	return dir
+}
+
+// stubTestRunner runs test commands with the helper process, failing them
+// when fail is set, and returns the commands run
+func stubTestRunner(t *testing.T, fail bool) *[]*exec.Cmd {
+	t.Helper()
+	orig := ExecCommand
+	t.Cleanup(func() { ExecCommand = orig })
+	cmds := &[]*exec.Cmd{}
+	ExecCommand = func(name string, args ...string) *exec.Cmd {
+		if fail {
+			// An unknown spin command makes the helper process fail
+			name, args = "spin", []string{"fail"}
+		}
+		cmd := MockExecCommandHelper(name, args...)
+		*cmds = append(*cmds, cmd)
+		return cmd
+	}
+	return cmds
+}
+
+func TestSandboxName(t *testing.T) {
+	name, err := sandboxName("weather")
+	require.NoError(t, err)
+	assert.Regexp(t, sandboxNamePattern("weather"), name)
+
+	other, err := sandboxName("weather")
+	require.NoError(t, err)
+	assert.NotEqual(t, name, other)
+
+	assert.False(t, sandboxNamePattern("weather").MatchString("weather-test-extra-abc123"))
+	assert.False(t, sandboxNamePattern("weather").MatchString("weather"))
+}
+
+func TestDeleteExpiredSandboxes(t *testing.T) {
+	setupEphemeralProject(t)
+	now := time.Now()
+	client := &fakeSandboxClient{apps: []fakeSandbox{
+		{id: prodAppID, name: "weather", createdAt: now.Add(-48 * time.Hour)},
+		{id: "expired", name: "weather-test-aaaaaa", createdAt: now.Add(-2 * time.Hour)},
+		{id: "recent", name: "weather-test-bbbbbb", createdAt: now.Add(-10 * time.Minute)},
+		{id: "other", name: "weather-test-extra-cccccc", createdAt: now.Add(-2 * time.Hour)},
+	}}
+	_, err := deploy.SaveRecord(".", &deploy.Record{App: "weather-test-aaaaaa"})
+	require.NoError(t, err)
+
+	deleteExpiredSandboxes(context.Background(), client, "weather", time.Hour, now)
+	assert.Equal(t, []string{"expired"}, client.deleted)
+	assert.NoFileExists(t, deploy.RecordPath(".", "weather-test-aaaaaa"))
+}
+
+func TestRunEphemeralTests(t *testing.T) {
+	dir := setupEphemeralProject(t)
+	client := &fakeSandboxClient{}
+	deployed := stubSandbox(t, client, "private")
+	cmds := stubTestRunner(t, false)
+
+	tests := []componentTest{{ID: "weather", Dir: filepath.Join(dir, "weather"), Command: []string{"make", "test"}}}
+	var buf bytes.Buffer
+	err := runEphemeralTests(context.Background(), &buf, tests, ephemeralTestOptions{
+		configFile: "ftl.yaml",
+		ttl:        time.Hour,
+		variables:  map[string]string{"api_key": "secret"},
+	})
+	require.NoError(t, err)
+
+	assert.Regexp(t, sandboxNamePattern("weather"), deployed.Name)
+	assert.True(t, deployed.Yes)
+	assert.True(t, deployed.AutoRollback)
+	assert.Equal(t, "ftl.yaml", deployed.ConfigFile)
+	assert.Equal(t, map[string]string{"api_key": "secret"}, deployed.Variables)
+
+	require.Len(t, *cmds, 1)
+	url := "https://" + deployed.Name + ".example.com"
+	assert.Contains(t, (*cmds)[0].Env, envTestURL+"="+url)
+	assert.Contains(t, (*cmds)[0].Env, envTestToken+"=test-token")
+	assert.Contains(t, buf.String(), "Testing against "+url)
+
+	// The sandbox engine and its deployment record are deleted after the run
+	assert.Equal(t, []string{stagingAppID}, client.deleted)
+	assert.NoFileExists(t, deploy.RecordPath(".", deployed.Name))
+}
+
+func TestRunEphemeralTests_Failure(t *testing.T) {
+	for _, tt := range []struct {
+		name string
+		keep bool
+	}{
+		{"teardown", false},
+		{"keep on failure", true},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			dir := setupEphemeralProject(t)
+			client := &fakeSandboxClient{}
+			deployed := stubSandbox(t, client, "public")
+			cmds := stubTestRunner(t, true)
+
+			tests := []componentTest{{ID: "weather", Dir: filepath.Join(dir, "weather"), Command: []string{"make", "test"}}}
+			var buf bytes.Buffer
+			err := runEphemeralTests(context.Background(), &buf, tests, ephemeralTestOptions{
+				configFile:    "ftl.yaml",
+				ttl:           time.Hour,
+				keepOnFailure: tt.keep,
+			})
+			assert.EqualError(t, err, "tests failed")
+
+			// Public engines get no token
+			require.Len(t, *cmds, 1)
+			for _, env := range (*cmds)[0].Env {
+				assert.False(t, strings.HasPrefix(env, envTestToken+"="))
+			}
+
+			if tt.keep {
+				assert.Empty(t, client.deleted)
+				assert.FileExists(t, deploy.RecordPath(".", deployed.Name))
+			} else {
+				assert.Equal(t, []string{stagingAppID}, client.deleted)
+			}
+		})
+	}
+}
+
+func TestTestCommand_EphemeralFlags(t *testing.T) {
+	setupEphemeralProject(t)
+
+	tests := []struct {
+		args     []string
+		expected string
+	}{
+		{[]string{"--ephemeral", "--watch"}, "--ephemeral can't be combined with a path or --watch"},
+		{[]string{"--ephemeral", "./..."}, "--ephemeral can't be combined with a path or --watch"},
+		{[]string{"--schemas", "--ephemeral"}, "--schemas can't be combined"},
+		{[]string{"--keep-on-failure"}, "--keep-on-failure, --ttl and --var require --ephemeral"},
+		{[]string{"--ttl", "10m"}, "--keep-on-failure, --ttl and --var require --ephemeral"},
+		{[]string{"--ephemeral", "--ttl", "0s"}, "--ttl must be positive"},
+	}
+	for _, tt := range tests {
+		t.Run(strings.Join(tt.args, " "), func(t *testing.T) {
+			cmd := newTestCmd()
+			cmd.SetOut(&bytes.Buffer{})
+			cmd.SetErr(&bytes.Buffer{})
+			cmd.SetArgs(tt.args)
+			assert.ErrorContains(t, cmd.Execute(), tt.expected)
+		})
+	}
+}
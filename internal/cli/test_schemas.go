@@ -0,0 +1,223 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"maps"
+	"os"
+	"path/filepath"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/fatih/color"
+
+	"github.com/fastertools/ftl/internal/clientgen"
+)
+
+// defaultSchemasDir is where the golden tool schemas are checked in
+const defaultSchemasDir = "schemas"
+
+// schemaTestOptions configure the contract tests of ftl test --schemas
+type schemaTestOptions struct {
+	dir    string
+	update bool
+	url    string
+	token  string
+}
+
+// goldenSchema is the content of a golden schema file
+type goldenSchema struct {
+	Name         string          `json:"name"`
+	InputSchema  json.RawMessage `json:"inputSchema,omitempty"`
+	OutputSchema json.RawMessage `json:"outputSchema,omitempty"`
+}
+
+// runSchemaTests compares the schemas of the application's tools with the
+// golden files, or rewrites the golden files with update
+func runSchemaTests(ctx context.Context, w io.Writer, opts schemaTestOptions) error {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	if opts.dir == "" {
+		opts.dir = defaultSchemasDir
+	}
+
+	tools, err := loadAppTools(ctx, opts.url, opts.token)
+	if err != nil {
+		return err
+	}
+	sort.Slice(tools, func(i, j int) bool { return tools[i].Name < tools[j].Name })
+
+	current := make(map[string][]byte, len(tools))
+	for _, tool := range tools {
+		data, err := goldenSchemaFile(tool)
+		if err != nil {
+			return fmt.Errorf("invalid schema of tool %s: %w", tool.Name, err)
+		}
+		current[schemaFileName(tool.Name)] = data
+	}
+	golden, err := readGoldenSchemas(opts.dir)
+	if err != nil {
+		return err
+	}
+
+	if opts.update {
+		return updateGoldenSchemas(w, opts.dir, current, golden)
+	}
+
+	drifted := 0
+	for _, tool := range tools {
+		file := schemaFileName(tool.Name)
+		want, ok := golden[file]
+		if !ok {
+			drifted++
+			_, _ = fmt.Fprintf(w, "%s %s: new tool, no golden file %s\n", color.RedString("✗"), tool.Name, filepath.Join(opts.dir, file))
+			continue
+		}
+		diffs, err := diffSchemas(tool.Name, want, current[file])
+		if err != nil {
+			return fmt.Errorf("invalid golden file %s: %w", filepath.Join(opts.dir, file), err)
+		}
+		if len(diffs) == 0 {
+			_, _ = fmt.Fprintf(w, "%s %s\n", color.GreenString("✓"), tool.Name)
+			continue
+		}
+		drifted++
+		_, _ = fmt.Fprintf(w, "%s %s: schema changed\n", color.RedString("✗"), tool.Name)
+		for _, diff := range diffs {
+			_, _ = fmt.Fprintf(w, "    %s\n", diff)
+		}
+	}
+	for _, file := range slices.Sorted(maps.Keys(golden)) {
+		if _, ok := current[file]; !ok {
+			drifted++
+			_, _ = fmt.Fprintf(w, "%s %s: tool removed\n", color.RedString("✗"), strings.TrimSuffix(file, ".json"))
+		}
+	}
+
+	if drifted > 0 {
+		return fmt.Errorf("%d tool schema(s) differ from the golden files in %s. "+
+			"If the changes are intended, run 'ftl test --schemas --update' and commit the result", drifted, opts.dir)
+	}
+	_, _ = fmt.Fprintf(w, "%s Schemas: %d tool(s) match the golden files\n", color.GreenString("✓"), len(tools))
+	return nil
+}
+
+// updateGoldenSchemas writes the golden files that changed and removes those
+// of tools that are gone
+func updateGoldenSchemas(w io.Writer, dir string, current, golden map[string][]byte) error {
+	if err := os.MkdirAll(dir, 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", dir, err)
+	}
+
+	changed := 0
+	for _, file := range slices.Sorted(maps.Keys(current)) {
+		if bytes.Equal(golden[file], current[file]) {
+			continue
+		}
+		path := filepath.Join(dir, file)
+		if err := os.WriteFile(path, current[file], 0600); err != nil {
+			return fmt.Errorf("failed to write %s: %w", path, err)
+		}
+		changed++
+		_, _ = fmt.Fprintf(w, "%s Updated %s\n", color.BlueString("→"), path)
+	}
+	for _, file := range slices.Sorted(maps.Keys(golden)) {
+		if _, ok := current[file]; ok {
+			continue
+		}
+		path := filepath.Join(dir, file)
+		if err := os.Remove(path); err != nil {
+			return fmt.Errorf("failed to remove %s: %w", path, err)
+		}
+		changed++
+		_, _ = fmt.Fprintf(w, "%s Removed %s\n", color.BlueString("→"), path)
+	}
+
+	_, _ = fmt.Fprintf(w, "%s Schemas: %d golden file(s) updated in %s\n", color.GreenString("✓"), changed, dir)
+	return nil
+}
+
+// readGoldenSchemas reads the golden files of a directory by file name. A
+// missing directory has no golden files.
+func readGoldenSchemas(dir string) (map[string][]byte, error) {
+	entries, err := os.ReadDir(dir)
+	if os.IsNotExist(err) {
+		return map[string][]byte{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", dir, err)
+	}
+
+	golden := make(map[string][]byte, len(entries))
+	for _, entry := range entries {
+		if entry.IsDir() || filepath.Ext(entry.Name()) != ".json" {
+			continue
+		}
+		path := filepath.Join(dir, entry.Name())
+		data, err := os.ReadFile(path) // #nosec G304 -- file inside the schemas directory
+		if err != nil {
+			return nil, fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		golden[entry.Name()] = data
+	}
+	return golden, nil
+}
+
+// goldenSchemaFile renders the golden file of a tool. Keys are sorted so the
+// file only changes when the schemas do.
+func goldenSchemaFile(tool clientgen.Tool) ([]byte, error) {
+	input, err := canonicalJSON(tool.InputSchema)
+	if err != nil {
+		return nil, err
+	}
+	output, err := canonicalJSON(tool.OutputSchema)
+	if err != nil {
+		return nil, err
+	}
+	data, err := json.MarshalIndent(goldenSchema{Name: tool.Name, InputSchema: input, OutputSchema: output}, "", "  ")
+	if err != nil {
+		return nil, err
+	}
+	return append(data, '\n'), nil
+}
+
+// canonicalJSON re-encodes a JSON value with sorted keys
+func canonicalJSON(raw json.RawMessage) (json.RawMessage, error) {
+	if len(bytes.TrimSpace(raw)) == 0 {
+		return nil, nil
+	}
+	var value interface{}
+	if err := json.Unmarshal(raw, &value); err != nil {
+		return nil, err
+	}
+	return json.Marshal(value)
+}
+
+// diffSchemas lists the paths at which the schemas of a golden file and the
+// current tool differ
+func diffSchemas(name string, want, got []byte) ([]string, error) {
+	var wantValue, gotValue map[string]interface{}
+	if err := json.Unmarshal(want, &wantValue); err != nil {
+		return nil, err
+	}
+	if err := json.Unmarshal(got, &gotValue); err != nil {
+		return nil, err
+	}
+	return diffJSON(name, wantValue, gotValue), nil
+}
+
+// schemaFileName is the golden file name of a tool
+func schemaFileName(tool string) string {
+	name := strings.Map(func(r rune) rune {
+		if r == '-' || r == '_' || r == '.' || ('a' <= r && r <= 'z') || ('A' <= r && r <= 'Z') || ('0' <= r && r <= '9') {
+			return r
+		}
+		return '_'
+	}, tool)
+	return name + ".json"
+}
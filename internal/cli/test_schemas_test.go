@@ -0,0 +1,95 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newSchemaServer serves tools/list with the tools pointed to by tools
+func newSchemaServer(t *testing.T, tools *string) *httptest.Server {
+	t.Helper()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"jsonrpc":"2.0","id":1,"result":{"tools":[` + *tools + `]}}`))
+	}))
+	t.Cleanup(server.Close)
+	return server
+}
+
+func TestRunSchemaTests(t *testing.T) {
+	tools := `{"name":"weather__forecast","inputSchema":{"type":"object","required":["city"],"properties":{"city":{"type":"string"}}}}`
+	server := newSchemaServer(t, &tools)
+	opts := schemaTestOptions{dir: filepath.Join(t.TempDir(), "schemas"), url: server.URL}
+	ctx := context.Background()
+
+	// Without golden files, every tool is new
+	var out bytes.Buffer
+	err := runSchemaTests(ctx, &out, opts)
+	assert.ErrorContains(t, err, "ftl test --schemas --update")
+	assert.Contains(t, out.String(), "weather__forecast: new tool")
+
+	opts.update = true
+	require.NoError(t, runSchemaTests(ctx, &out, opts))
+	golden, err := os.ReadFile(filepath.Join(opts.dir, "weather__forecast.json"))
+	require.NoError(t, err)
+	assert.Contains(t, string(golden), "\"name\": \"weather__forecast\"")
+	// Keys are sorted
+	assert.Less(t, bytes.Index(golden, []byte(`"properties"`)), bytes.Index(golden, []byte(`"required"`)))
+
+	opts.update = false
+	out.Reset()
+	require.NoError(t, runSchemaTests(ctx, &out, opts))
+	assert.Contains(t, out.String(), "1 tool(s) match")
+
+	// A changed schema fails with the paths that differ
+	tools = `{"name":"weather__forecast","inputSchema":{"type":"object","required":["city","units"],"properties":{"city":{"type":"string"},"units":{"type":"string"}}}}`
+	out.Reset()
+	err = runSchemaTests(ctx, &out, opts)
+	assert.ErrorContains(t, err, "1 tool schema(s) differ")
+	assert.Contains(t, out.String(), "weather__forecast: schema changed")
+	assert.Contains(t, out.String(), `weather__forecast.inputSchema.properties.units: unexpected {"type":"string"}`)
+	assert.Contains(t, out.String(), "weather__forecast.inputSchema.required: 1 items != 2 items")
+
+	// Removed tools are reported, and their golden files removed on update
+	tools = `{"name":"weather__alerts"}`
+	out.Reset()
+	err = runSchemaTests(ctx, &out, opts)
+	assert.ErrorContains(t, err, "2 tool schema(s) differ")
+	assert.Contains(t, out.String(), "weather__forecast: tool removed")
+
+	opts.update = true
+	require.NoError(t, runSchemaTests(ctx, &out, opts))
+	assert.NoFileExists(t, filepath.Join(opts.dir, "weather__forecast.json"))
+	assert.FileExists(t, filepath.Join(opts.dir, "weather__alerts.json"))
+}
+
+func TestSchemaFileName(t *testing.T) {
+	assert.Equal(t, "weather__forecast.json", schemaFileName("weather__forecast"))
+	assert.Equal(t, "a_b.json", schemaFileName("a/b"))
+}
+
+func TestTestCommand_SchemaFlags(t *testing.T) {
+	cmd := newTestCmd()
+	for _, flag := range []string{"schemas", "update", "schemas-dir", "url", "token"} {
+		assert.NotNil(t, cmd.Flags().Lookup(flag), flag)
+	}
+
+	cmd.SetArgs([]string{"--update"})
+	cmd.SetOut(&bytes.Buffer{})
+	cmd.SetErr(&bytes.Buffer{})
+	assert.ErrorContains(t, cmd.Execute(), "require --schemas")
+
+	cmd = newTestCmd()
+	cmd.SetArgs([]string{"--schemas", "--watch"})
+	cmd.SetOut(&bytes.Buffer{})
+	cmd.SetErr(&bytes.Buffer{})
+	assert.ErrorContains(t, cmd.Execute(), "can't be combined")
+}
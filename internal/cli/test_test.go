@@ -27,6 +27,11 @@ func TestTestCommand(t *testing.T) {
 	assert.NotNil(t, verboseFlag)
 	assert.Equal(t, "v", verboseFlag.Shorthand)
 	assert.Equal(t, "false", verboseFlag.DefValue)
+
+	watchFlag := cmd.Flags().Lookup("watch")
+	assert.NotNil(t, watchFlag)
+	assert.Equal(t, "w", watchFlag.Shorthand)
+	assert.NotNil(t, cmd.Flags().Lookup("component"))
 }
 
 func TestTestCommand_Help(t *testing.T) {
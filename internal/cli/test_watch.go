@@ -0,0 +1,279 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"io/fs"
+	"maps"
+	"os"
+	"path"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/fatih/color"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// testWatchInterval is how often watched component files are checked for changes
+var testWatchInterval = 500 * time.Millisecond
+
+// Directories that never contain test inputs
+var testWatchSkipDirs = map[string]bool{
+	".git":         true,
+	".ftl":         true,
+	"target":       true,
+	"node_modules": true,
+	"dist":         true,
+	"__pycache__":  true,
+	".venv":        true,
+}
+
+// componentTest describes how to run the tests of one component
+type componentTest struct {
+	ID      string
+	Dir     string
+	Command []string
+	Watch   []string
+	Env     []string // Added to the environment of the test command
+}
+
+// componentTestResult is the outcome of one component's test run
+type componentTestResult struct {
+	ID       string
+	Passed   bool
+	Duration time.Duration
+}
+
+// componentTests resolves the components to test from the application,
+// optionally limited to the given component IDs. Components without a local
+// build are skipped.
+func componentTests(app *validation.Application, configDir string, only []string) ([]componentTest, error) {
+	known := make(map[string]bool)
+	var tests []componentTest
+	for _, comp := range app.Components {
+		known[comp.ID] = true
+		if len(only) > 0 && !slices.Contains(only, comp.ID) {
+			continue
+		}
+		if comp.Build == nil {
+			continue
+		}
+
+		dir := configDir
+		if comp.Build.Workdir != "" {
+			dir = filepath.Join(configDir, comp.Build.Workdir)
+		}
+		command, err := testCommandFor(dir)
+		if err != nil {
+			return nil, fmt.Errorf("component %s: %w", comp.ID, err)
+		}
+		tests = append(tests, componentTest{
+			ID:      comp.ID,
+			Dir:     dir,
+			Command: command,
+			Watch:   comp.Build.Watch,
+		})
+	}
+
+	for _, id := range only {
+		if !known[id] {
+			return nil, fmt.Errorf("component %q not found in the FTL config", id)
+		}
+	}
+	return tests, nil
+}
+
+// testCommandFor picks the test command for a component directory: the
+// Makefile's test target created by 'ftl add', or the language's test runner
+func testCommandFor(dir string) ([]string, error) {
+	candidates := []struct {
+		file    string
+		command []string
+	}{
+		{"Makefile", []string{"make", "test"}},
+		{"Cargo.toml", []string{"cargo", "test"}},
+		{"go.mod", []string{"go", "test", "./..."}},
+		{"package.json", []string{"npm", "test"}},
+		{"pyproject.toml", []string{"pytest"}},
+	}
+	for _, candidate := range candidates {
+		if _, err := os.Stat(filepath.Join(dir, candidate.file)); err == nil {
+			return candidate.command, nil
+		}
+	}
+	return nil, fmt.Errorf("no Makefile, Cargo.toml, go.mod, package.json or pyproject.toml in %s", dir)
+}
+
+// runComponentTests runs the tests of each component in turn, streaming their output
+func runComponentTests(ctx context.Context, w io.Writer, tests []componentTest) []componentTestResult {
+	results := make([]componentTestResult, 0, len(tests))
+	for _, test := range tests {
+		if ctx.Err() != nil {
+			break
+		}
+		_, _ = fmt.Fprintf(w, "%s Testing %s: %s\n", color.BlueString("→"), test.ID, strings.Join(test.Command, " "))
+
+		start := time.Now()
+		cmd := ExecCommand(test.Command[0], test.Command[1:]...) // #nosec G204 - fixed test runners
+		cmd.Dir = test.Dir
+		if len(test.Env) > 0 {
+			cmd.Env = append(cmd.Environ(), test.Env...)
+		}
+		cmd.Stdout = w
+		cmd.Stderr = w
+		err := cmd.Run()
+
+		results = append(results, componentTestResult{
+			ID:       test.ID,
+			Passed:   err == nil,
+			Duration: time.Since(start),
+		})
+	}
+	return results
+}
+
+// testSummary formats a one-line status of a test run
+func testSummary(results []componentTestResult) string {
+	var passed int
+	var failed []string
+	var total time.Duration
+	for _, result := range results {
+		total += result.Duration
+		if result.Passed {
+			passed++
+		} else {
+			failed = append(failed, result.ID)
+		}
+	}
+
+	summary := fmt.Sprintf("%d passed", passed)
+	if len(failed) > 0 {
+		summary += fmt.Sprintf(", %d failed (%s)", len(failed), strings.Join(failed, ", "))
+	}
+	return fmt.Sprintf("%s in %s", summary, total.Round(100*time.Millisecond))
+}
+
+// printTestSummary writes the status line of a test run and reports whether all tests passed
+func printTestSummary(w io.Writer, results []componentTestResult) bool {
+	for _, result := range results {
+		if !result.Passed {
+			_, _ = fmt.Fprintf(w, "%s Tests: %s\n", color.RedString("✗"), testSummary(results))
+			return false
+		}
+	}
+	_, _ = fmt.Fprintf(w, "%s Tests: %s\n", color.GreenString("✓"), testSummary(results))
+	return true
+}
+
+// watchComponentTests runs all tests, then re-runs the tests of components
+// whose watched files change until the context is cancelled
+func watchComponentTests(ctx context.Context, w io.Writer, tests []componentTest) error {
+	snapshots := make(map[string]map[string]string, len(tests))
+	for _, test := range tests {
+		snapshots[test.ID] = snapshotWatched(test)
+	}
+	printTestSummary(w, runComponentTests(ctx, w, tests))
+	_, _ = fmt.Fprintf(w, "%s Watching %d component(s) for changes. Press Ctrl+C to stop\n", color.YellowString("ℹ"), len(tests))
+
+	ticker := time.NewTicker(testWatchInterval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-ticker.C:
+		}
+
+		var changed []componentTest
+		for _, test := range tests {
+			snapshot := snapshotWatched(test)
+			if !maps.Equal(snapshots[test.ID], snapshot) {
+				snapshots[test.ID] = snapshot
+				changed = append(changed, test)
+			}
+		}
+		if len(changed) == 0 {
+			continue
+		}
+
+		ids := make([]string, 0, len(changed))
+		for _, test := range changed {
+			ids = append(ids, test.ID)
+		}
+		_, _ = fmt.Fprintf(w, "\n%s Changes in %s\n", color.YellowString("ℹ"), strings.Join(ids, ", "))
+		printTestSummary(w, runComponentTests(ctx, w, changed))
+	}
+}
+
+// snapshotWatched fingerprints the files of a component that match its
+// build.watch patterns, or all of its files when it has none
+func snapshotWatched(test componentTest) map[string]string {
+	snapshot := make(map[string]string)
+	_ = filepath.WalkDir(test.Dir, func(p string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return nil
+		}
+		if d.IsDir() {
+			if p != test.Dir && testWatchSkipDirs[d.Name()] {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+
+		rel, err := filepath.Rel(test.Dir, p)
+		if err != nil {
+			return nil
+		}
+		rel = filepath.ToSlash(rel)
+		if len(test.Watch) > 0 && !matchesAnyWatchPattern(test.Watch, rel) {
+			return nil
+		}
+		info, err := d.Info()
+		if err != nil {
+			return nil
+		}
+		snapshot[rel] = fmt.Sprintf("%d:%d", info.ModTime().UnixNano(), info.Size())
+		return nil
+	})
+	return snapshot
+}
+
+func matchesAnyWatchPattern(patterns []string, rel string) bool {
+	for _, pattern := range patterns {
+		if matchWatchPattern(pattern, rel) {
+			return true
+		}
+	}
+	return false
+}
+
+// matchWatchPattern matches a slash-separated path against a build.watch
+// glob, where a ** segment matches any number of directories
+func matchWatchPattern(pattern, rel string) bool {
+	return matchSegments(strings.Split(strings.TrimPrefix(pattern, "./"), "/"), strings.Split(rel, "/"))
+}
+
+func matchSegments(pattern, parts []string) bool {
+	if len(pattern) == 0 {
+		return len(parts) == 0
+	}
+	if pattern[0] == "**" {
+		for i := 0; i <= len(parts); i++ {
+			if matchSegments(pattern[1:], parts[i:]) {
+				return true
+			}
+		}
+		return false
+	}
+	if len(parts) == 0 {
+		return false
+	}
+	if ok, err := path.Match(pattern[0], parts[0]); err != nil || !ok {
+		return false
+	}
+	return matchSegments(pattern[1:], parts[1:])
+}
@@ -0,0 +1,216 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// lockedBuffer is a bytes.Buffer safe for concurrent writes and reads
+type lockedBuffer struct {
+	mu  sync.Mutex
+	buf bytes.Buffer
+}
+
+func (b *lockedBuffer) Write(p []byte) (int, error) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	return b.buf.Write(p)
+}
+
+func (b *lockedBuffer) String() string {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	return b.buf.String()
+}
+
+func writeComponent(t *testing.T, dir, marker string) {
+	t.Helper()
+	require.NoError(t, os.MkdirAll(filepath.Join(dir, "src"), 0750))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, marker), []byte(""), 0600))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "src", "lib.rs"), []byte("// v1"), 0600))
+}
+
+func TestTestCommandFor(t *testing.T) {
+	tests := []struct {
+		marker   string
+		expected []string
+	}{
+		{"Makefile", []string{"make", "test"}},
+		{"Cargo.toml", []string{"cargo", "test"}},
+		{"go.mod", []string{"go", "test", "./..."}},
+		{"package.json", []string{"npm", "test"}},
+		{"pyproject.toml", []string{"pytest"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.marker, func(t *testing.T) {
+			dir := t.TempDir()
+			writeComponent(t, dir, tt.marker)
+			command, err := testCommandFor(dir)
+			require.NoError(t, err)
+			assert.Equal(t, tt.expected, command)
+		})
+	}
+
+	_, err := testCommandFor(t.TempDir())
+	assert.ErrorContains(t, err, "no Makefile")
+}
+
+func TestComponentTests(t *testing.T) {
+	root := t.TempDir()
+	writeComponent(t, filepath.Join(root, "weather"), "Makefile")
+	writeComponent(t, filepath.Join(root, "ocr"), "Cargo.toml")
+
+	app := &validation.Application{Components: []*validation.Component{
+		{ID: "weather", Build: &validation.BuildConfig{Command: "make build", Workdir: "weather", Watch: []string{"src/**/*.rs"}}},
+		{ID: "ocr", Build: &validation.BuildConfig{Command: "cargo build", Workdir: "ocr"}},
+		{ID: "gateway", Source: &validation.RegistrySource{Registry: "ghcr.io", Package: "fastertools:mcp-gateway", Version: "0.0.13"}},
+	}}
+
+	tests, err := componentTests(app, root, nil)
+	require.NoError(t, err)
+	require.Len(t, tests, 2)
+	assert.Equal(t, "weather", tests[0].ID)
+	assert.Equal(t, filepath.Join(root, "weather"), tests[0].Dir)
+	assert.Equal(t, []string{"src/**/*.rs"}, tests[0].Watch)
+	assert.Equal(t, []string{"cargo", "test"}, tests[1].Command)
+
+	tests, err = componentTests(app, root, []string{"ocr"})
+	require.NoError(t, err)
+	require.Len(t, tests, 1)
+	assert.Equal(t, "ocr", tests[0].ID)
+
+	_, err = componentTests(app, root, []string{"missing"})
+	assert.ErrorContains(t, err, `component "missing" not found`)
+}
+
+func TestMatchWatchPattern(t *testing.T) {
+	tests := []struct {
+		pattern string
+		path    string
+		matches bool
+	}{
+		{"src/**/*.rs", "src/lib.rs", true},
+		{"src/**/*.rs", "src/tools/weather.rs", true},
+		{"src/**/*.rs", "tests/lib.rs", false},
+		{"**/*.py", "main.py", true},
+		{"**/*.py", "pkg/tools/main.py", true},
+		{"Cargo.toml", "Cargo.toml", true},
+		{"./Cargo.toml", "Cargo.toml", true},
+		{"Cargo.toml", "sub/Cargo.toml", false},
+		{"src/*.ts", "src/nested/index.ts", false},
+	}
+	for _, tt := range tests {
+		assert.Equal(t, tt.matches, matchWatchPattern(tt.pattern, tt.path), "%s %s", tt.pattern, tt.path)
+	}
+}
+
+func TestTestSummary(t *testing.T) {
+	summary := testSummary([]componentTestResult{
+		{ID: "weather", Passed: true, Duration: time.Second},
+		{ID: "ocr", Passed: false, Duration: 1500 * time.Millisecond},
+	})
+	assert.Equal(t, "1 passed, 1 failed (ocr) in 2.5s", summary)
+
+	var buf bytes.Buffer
+	assert.True(t, printTestSummary(&buf, []componentTestResult{{ID: "weather", Passed: true}}))
+	assert.Contains(t, buf.String(), "Tests: 1 passed in 0s")
+}
+
+func TestRunComponentTests(t *testing.T) {
+	orig := ExecCommand
+	t.Cleanup(func() { ExecCommand = orig })
+	ExecCommand = func(name string, args ...string) *exec.Cmd {
+		if name == "cargo" {
+			// An unknown spin command makes the helper process fail
+			return MockExecCommandHelper("spin", "fail")
+		}
+		return MockExecCommandHelper(name, args...)
+	}
+
+	dir := t.TempDir()
+	var buf bytes.Buffer
+	results := runComponentTests(context.Background(), &buf, []componentTest{
+		{ID: "weather", Dir: dir, Command: []string{"make", "test"}},
+		{ID: "ocr", Dir: dir, Command: []string{"cargo", "test"}},
+	})
+
+	require.Len(t, results, 2)
+	assert.True(t, results[0].Passed)
+	assert.False(t, results[1].Passed)
+	assert.Contains(t, buf.String(), "Testing weather: make test")
+	assert.Contains(t, buf.String(), "Building target 'test'")
+	assert.False(t, printTestSummary(&buf, results))
+	assert.Contains(t, buf.String(), "1 failed (ocr)")
+}
+
+func TestWatchComponentTests_RerunsChangedComponents(t *testing.T) {
+	orig, origInterval := ExecCommand, testWatchInterval
+	t.Cleanup(func() { ExecCommand, testWatchInterval = orig, origInterval })
+	testWatchInterval = 20 * time.Millisecond
+
+	var mu sync.Mutex
+	var runs []string
+	ExecCommand = func(name string, args ...string) *exec.Cmd {
+		mu.Lock()
+		runs = append(runs, name)
+		mu.Unlock()
+		return MockExecCommandHelper("make", "test")
+	}
+	runCount := func(name string) int {
+		mu.Lock()
+		defer mu.Unlock()
+		count := 0
+		for _, run := range runs {
+			if run == name {
+				count++
+			}
+		}
+		return count
+	}
+
+	root := t.TempDir()
+	weather := filepath.Join(root, "weather")
+	ocr := filepath.Join(root, "ocr")
+	writeComponent(t, weather, "Makefile")
+	writeComponent(t, ocr, "Makefile")
+
+	// Distinct runners identify which component was re-tested
+	tests := []componentTest{
+		{ID: "weather", Dir: weather, Command: []string{"weather-runner"}, Watch: []string{"src/**/*.rs"}},
+		{ID: "ocr", Dir: ocr, Command: []string{"ocr-runner"}, Watch: []string{"src/**/*.rs"}},
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	out := &lockedBuffer{}
+	done := make(chan error, 1)
+	go func() { done <- watchComponentTests(ctx, out, tests) }()
+
+	require.Eventually(t, func() bool { return strings.Contains(out.String(), "Watching 2 component(s)") }, 5*time.Second, 10*time.Millisecond)
+	assert.Equal(t, 1, runCount("weather-runner"))
+	assert.Equal(t, 1, runCount("ocr-runner"))
+
+	// Files outside the watch patterns are ignored
+	require.NoError(t, os.WriteFile(filepath.Join(ocr, "README.md"), []byte("docs"), 0600))
+	// A watched file triggers a re-run of its component only
+	require.NoError(t, os.WriteFile(filepath.Join(weather, "src", "lib.rs"), []byte("// version 2"), 0600))
+
+	require.Eventually(t, func() bool { return runCount("weather-runner") == 2 }, 5*time.Second, 10*time.Millisecond)
+	require.Eventually(t, func() bool { return strings.Count(out.String(), "Tests: ") == 2 }, 5*time.Second, 10*time.Millisecond)
+	assert.Contains(t, out.String(), "Changes in weather")
+	assert.Equal(t, 1, runCount("ocr-runner"))
+
+	cancel()
+	require.NoError(t, <-done)
+}
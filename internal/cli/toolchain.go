@@ -0,0 +1,266 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/internal/toolchain"
+	"github.com/fastertools/ftl/spin"
+)
+
+func newToolchainCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "toolchain",
+		Short: "Check and install the tools required by the project",
+		Long: `Check and install the external tools FTL runs (spin, wkg, cargo, docker, ...).
+
+Requirements are declared in the project's toolchain section:
+
+  toolchain:
+    spin: "3.3.1"       # exact pin
+    wkg: "0.11.0"
+    cargo: ">=1.89.0"   # minimum version
+
+Pinned spin and wkg versions can be installed into ~/.ftl/tools, and are
+preferred over the binaries on PATH once installed. Downloads are verified
+against the checksums published with the release.`,
+	}
+
+	cmd.AddCommand(
+		newToolchainCheckCmd(),
+		newToolchainInstallCmd(),
+	)
+
+	return cmd
+}
+
+func newToolchainCheckCmd() *cobra.Command {
+	var output string
+
+	cmd := &cobra.Command{
+		Use:   "check",
+		Short: "Check installed tool versions against the project requirements",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			reqs, err := loadToolchainRequirements()
+			if err != nil {
+				return err
+			}
+			if len(reqs) == 0 {
+				Info("No toolchain requirements declared in the project")
+				return nil
+			}
+
+			mgr, err := toolchain.NewManager()
+			if err != nil {
+				return err
+			}
+
+			statuses := mgr.Check(cmd.Context(), reqs)
+			if err := writeToolchainStatus(NewDataWriter(cmd.OutOrStdout(), output), statuses); err != nil {
+				return err
+			}
+
+			failed := 0
+			for _, s := range statuses {
+				if !s.OK() {
+					failed++
+				}
+			}
+			if failed > 0 {
+				return fmt.Errorf("%d tool requirement(s) not satisfied; run 'ftl toolchain install' for pinned spin/wkg versions", failed)
+			}
+			return nil
+		},
+	}
+
+	cmd.Flags().StringVarP(&output, "output", "o", "table", "output format (table, json)")
+
+	return cmd
+}
+
+func newToolchainInstallCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:   "install [tool...]",
+		Short: "Install pinned tool versions into ~/.ftl/tools",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			reqs, err := loadToolchainRequirements()
+			if err != nil {
+				return err
+			}
+
+			mgr, err := toolchain.NewManager()
+			if err != nil {
+				return err
+			}
+
+			return installPinnedTools(cmd.Context(), mgr, reqs, args)
+		},
+	}
+}
+
+// installPinnedTools installs the pinned requirements, optionally limited to the named tools
+func installPinnedTools(ctx context.Context, mgr *toolchain.Manager, reqs []toolchain.Requirement, only []string) error {
+	selected := make(map[string]bool, len(only))
+	for _, tool := range only {
+		selected[tool] = true
+	}
+
+	installed := 0
+	for _, req := range reqs {
+		if len(selected) > 0 && !selected[req.Tool] {
+			continue
+		}
+		delete(selected, req.Tool)
+
+		version := req.Pinned()
+		if !toolchain.Installable(req.Tool) || version == "" {
+			if len(only) > 0 {
+				Warn("%s is not a pinned spin/wkg requirement, install it manually", req.Tool)
+			}
+			continue
+		}
+
+		Info("Installing %s %s", req.Tool, version)
+		path, err := mgr.Install(ctx, req.Tool, version)
+		if err != nil {
+			return err
+		}
+		Success("Installed %s %s at %s", req.Tool, version, path)
+		installed++
+	}
+
+	for tool := range selected {
+		Warn("%s is not declared in the project toolchain", tool)
+	}
+	if installed == 0 {
+		Info("Nothing to install")
+	}
+	return nil
+}
+
+func writeToolchainStatus(dw *DataWriter, statuses []toolchain.Status) error {
+	if dw.format == OutputFormatJSON {
+		type statusJSON struct {
+			Tool     string `json:"tool"`
+			Required string `json:"required"`
+			Version  string `json:"version,omitempty"`
+			Path     string `json:"path,omitempty"`
+			Managed  bool   `json:"managed"`
+			OK       bool   `json:"ok"`
+			Error    string `json:"error,omitempty"`
+		}
+		out := make([]statusJSON, 0, len(statuses))
+		for _, s := range statuses {
+			entry := statusJSON{
+				Tool:     s.Tool,
+				Required: s.Constraint,
+				Version:  s.Version,
+				Path:     s.Path,
+				Managed:  s.Managed,
+				OK:       s.OK(),
+			}
+			if s.Error != nil {
+				entry.Error = s.Error.Error()
+			}
+			out = append(out, entry)
+		}
+		return dw.WriteStruct(out)
+	}
+
+	tb := NewTableBuilder("TOOL", "REQUIRED", "FOUND", "STATUS", "PATH")
+	for _, s := range statuses {
+		status := "ok"
+		switch {
+		case s.Error != nil:
+			status = s.Error.Error()
+		case !s.OK():
+			status = "version mismatch"
+		}
+		required := s.Constraint
+		if required == "" {
+			required = "*"
+		}
+		tb.AddRow(s.Tool, required, s.Version, status, s.Path)
+	}
+	return tb.Write(dw)
+}
+
+// loadToolchainRequirements reads the toolchain section of the project config
+func loadToolchainRequirements() ([]toolchain.Requirement, error) {
+	m, err := manifest.LoadAuto()
+	if err != nil {
+		return nil, fmt.Errorf("failed to load project config: %w", err)
+	}
+	return toolchain.Requirements(m.Toolchain), nil
+}
+
+// preferManagedTools points FTL at the pinned spin and wkg from
+// ~/.ftl/tools when the project pins versions that have been installed
+func preferManagedTools() {
+	if _, err := os.Stat("ftl.yaml"); err != nil {
+		if _, err := os.Stat("ftl.json"); err != nil {
+			return
+		}
+	}
+
+	reqs, err := loadToolchainRequirements()
+	if err != nil {
+		return
+	}
+
+	mgr, err := toolchain.NewManager()
+	if err != nil {
+		return
+	}
+
+	useManagedTools(mgr, reqs)
+}
+
+// useManagedTools makes the installed pinned versions of the requirements
+// take precedence over PATH. Spin is run by FTL directly, while wkg is run by
+// spin and build commands, so the managed tool directories are also put first
+// on the PATH the child processes inherit.
+func useManagedTools(mgr *toolchain.Manager, reqs []toolchain.Requirement) {
+	for _, req := range reqs {
+		if !toolchain.Installable(req.Tool) || req.Pinned() == "" {
+			continue
+		}
+		path, managed, err := mgr.Resolve(req)
+		if err != nil || !managed {
+			continue
+		}
+
+		Debug("Using managed %s %s from %s", req.Tool, req.Pinned(), path)
+		if req.Tool == "spin" {
+			spin.SetDefaultBinary(path)
+		}
+		_ = os.Setenv("PATH", filepath.Dir(path)+string(os.PathListSeparator)+os.Getenv("PATH"))
+	}
+}
+
+// warnToolchainMismatches warns about unsatisfied toolchain requirements without failing
+func warnToolchainMismatches(ctx context.Context) {
+	reqs, err := loadToolchainRequirements()
+	if err != nil || len(reqs) == 0 {
+		return
+	}
+
+	mgr, err := toolchain.NewManager()
+	if err != nil {
+		return
+	}
+
+	for _, s := range mgr.Check(ctx, reqs) {
+		switch {
+		case s.Error != nil:
+			Warn("Toolchain: %v", s.Error)
+		case !s.OK():
+			Warn("Toolchain: %s %s does not satisfy %s (see 'ftl toolchain check')", s.Tool, s.Version, s.Constraint)
+		}
+	}
+}
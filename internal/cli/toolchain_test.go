@@ -0,0 +1,71 @@
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/toolchain"
+	"github.com/fastertools/ftl/spin"
+)
+
+func TestToolchainCommand(t *testing.T) {
+	cmd := newToolchainCmd()
+	assert.Equal(t, "toolchain", cmd.Use)
+
+	names := make([]string, 0, len(cmd.Commands()))
+	for _, sub := range cmd.Commands() {
+		names = append(names, sub.Name())
+	}
+	assert.ElementsMatch(t, []string{"check", "install"}, names)
+}
+
+func TestWriteToolchainStatus(t *testing.T) {
+	statuses := []toolchain.Status{
+		{Requirement: toolchain.Requirement{Tool: "spin", Constraint: "3.3.1"}, Path: "/usr/bin/spin", Version: "3.3.1"},
+		{Requirement: toolchain.Requirement{Tool: "wkg", Constraint: "0.11.0"}, Error: errors.New("wkg not found on PATH")},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, writeToolchainStatus(NewDataWriter(&buf, "table"), statuses))
+	assert.Contains(t, buf.String(), "TOOL")
+	assert.Contains(t, buf.String(), "wkg not found on PATH")
+
+	buf.Reset()
+	require.NoError(t, writeToolchainStatus(NewDataWriter(&buf, "json"), statuses))
+	assert.Contains(t, buf.String(), `"ok": true`)
+	assert.Contains(t, buf.String(), `"error": "wkg not found on PATH"`)
+}
+
+func TestUseManagedTools(t *testing.T) {
+	defer spin.SetDefaultBinary("spin")
+	t.Setenv("PATH", "/usr/bin")
+
+	mgr := toolchain.NewManagerWithDir(t.TempDir())
+	for _, tool := range []string{"spin", "wkg"} {
+		path := mgr.ManagedPath(tool, "1.0.0")
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0750))
+		require.NoError(t, os.WriteFile(path, nil, 0600))
+	}
+
+	useManagedTools(mgr, []toolchain.Requirement{
+		{Tool: "spin", Constraint: "1.0.0"},
+		{Tool: "wkg", Constraint: "1.0.0"},
+		// Not installed, PATH is used
+		{Tool: "spin", Constraint: "2.0.0"},
+		{Tool: "cargo", Constraint: "1.89.0"},
+	})
+
+	assert.Equal(t, mgr.ManagedPath("spin", "1.0.0"), spin.DefaultBinary())
+	dirs := filepath.SplitList(os.Getenv("PATH"))
+	assert.Equal(t, []string{
+		filepath.Dir(mgr.ManagedPath("wkg", "1.0.0")),
+		filepath.Dir(mgr.ManagedPath("spin", "1.0.0")),
+		"/usr/bin",
+	}, dirs)
+}
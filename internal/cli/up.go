@@ -4,7 +4,12 @@ import (
 	"context"
 	"fmt"
 	"os"
+	"os/signal"
+	"strings"
+	"syscall"
 
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
 	"github.com/fastertools/ftl/spin"
 	"github.com/fastertools/ftl/synthesis"
 	"github.com/fatih/color"
@@ -16,6 +21,13 @@ func newUpCmd() *cobra.Command {
 	var watch bool
 	var skipSynth bool
 	var configFile string
+	var expose bool
+	var components []string
+	var noGateway bool
+	var noRestart bool
+	var strict bool
+	var record string
+	var parity string
 
 	// Spin up specific flags
 	var componentIDs []string
@@ -42,7 +54,35 @@ func newUpCmd() *cobra.Command {
 	cmd := &cobra.Command{
 		Use:   "up",
 		Short: "Run the FTL application locally",
-		Long:  `Run the FTL application locally with hot reload support.`,
+		Long: `Run the FTL application locally with hot reload support.
+
+Use --component to run only some components while developing them. A minimal
+manifest with just those components and the MCP gateway is written to
+.ftl/up/spin.toml, so builds, watches and logs are scoped to them. Add
+--no-gateway to serve a single component directly.
+
+If spin crashes, it is restarted with exponential backoff until it has crashed
+5 times in a row. Use --no-restart to exit on the first crash instead.
+
+Use --record <dir> to capture every tools/call request and its response as a
+JSON file in <dir>. Spin is then served on an internal port behind a recording
+proxy on the listen address. Re-send the recorded calls against the running
+application with 'ftl replay <dir>' to check that the responses still match.
+
+Hosts listed under dev.mocks in the config are replaced with stub responses,
+each served by an extra mock component the application is pointed at.
+
+Use --parity <engine> to compare the local environment with a deployed engine
+before starting: its access mode, custom auth issuer and audience, and the
+values of its variables. Locally, a variable's value comes from its
+SPIN_VARIABLE_<NAME> environment variable or its default. Secrets are only
+compared by presence. Differences are printed as a table and don't stop the
+application from starting.`,
+		Example: `  ftl up --build
+  ftl up --component ocr --watch
+  ftl up --component ocr --no-gateway
+  ftl up --record recordings/bug-142
+  ftl up --parity my-app-prod`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			ctx := context.Background()
 
@@ -70,8 +110,33 @@ func newUpCmd() *cobra.Command {
 				}
 			}
 
+			var mirror *oci.Mirror
+			if offlineMode() {
+				mirror = oci.NewMirror(oci.DefaultMirrorDir())
+				fmt.Printf("%s Offline: resolving registry components from %s\n", yellow("ℹ"), mirror.Dir())
+			}
+
+			// Run only the selected components from a minimal manifest
+			manifestPath := ""
+			if len(components) > 0 {
+				if from != "" {
+					return fmt.Errorf("--component cannot be combined with --from")
+				}
+				if configFile == "" || skipSynth {
+					return fmt.Errorf("--component requires an FTL config to synthesize from")
+				}
+				path, err := synthesizeComponents(configFile, components, noGateway, mirror)
+				if err != nil {
+					return err
+				}
+				manifestPath = path
+				fmt.Printf("%s Generated %s for %s\n", green("✓"), manifestPath, strings.Join(components, ", "))
+			} else if noGateway {
+				return fmt.Errorf("--no-gateway requires --component")
+			}
+
 			// Check if config file exists and synthesize
-			if configFile != "" && !skipSynth {
+			if manifestPath == "" && configFile != "" && !skipSynth {
 				if _, err := os.Stat(configFile); err == nil {
 					fmt.Printf("%s Synthesizing spin.toml from %s\n", blue("→"), configFile)
 
@@ -80,6 +145,14 @@ func newUpCmd() *cobra.Command {
 					if err != nil {
 						return fmt.Errorf("synthesis failed: %w", err)
 					}
+					if manifest, err = applyDevMocks(manifest, configFile); err != nil {
+						return err
+					}
+					if mirror != nil {
+						if manifest, err = commands.ResolveOfflineSources(manifest, mirror); err != nil {
+							return err
+						}
+					}
 
 					// Write spin.toml
 					if err := os.WriteFile("spin.toml", []byte(manifest), 0600); err != nil {
@@ -96,10 +169,54 @@ func newUpCmd() *cobra.Command {
 				fmt.Printf("%s No FTL config found, using existing spin.toml\n", yellow("ℹ"))
 			}
 
+			if _, err := os.Stat(configFile); configFile != "" && !skipSynth && err == nil {
+				app, err := commands.LoadApplication(configFile)
+				if err != nil {
+					return err
+				}
+				if err := commands.CheckOutboundHosts(app, configFile, nil, strict, printProgress); err != nil {
+					return err
+				}
+
+				// Spin needs a runtime config for stores other than "default"
+				if runtimeConfigFile == "" {
+					path, err := writeKeyValueRuntimeConfig(app, kvRuntimeConfigFile)
+					if err != nil {
+						return err
+					}
+					if path != "" {
+						runtimeConfigFile = path
+						fmt.Printf("%s Generated %s for the components' key-value stores\n", green("✓"), path)
+					}
+				}
+			}
+
+			// Report differences with a deployed engine
+			if parity != "" {
+				if configFile == "" {
+					return fmt.Errorf("--parity requires an FTL config to compare")
+				}
+				app, err := commands.LoadApplication(configFile)
+				if err != nil {
+					return err
+				}
+				client, err := newParityClient(ctx)
+				if err != nil {
+					return err
+				}
+				if err := runParityCheck(ctx, client, os.Stdout, app, parity); err != nil {
+					return err
+				}
+			}
+
 			// Build if requested
 			if build {
 				fmt.Printf("%s Building application first...\n", blue("→"))
-				if err := spin.Build(ctx); err != nil {
+				var buildOptions []string
+				if manifestPath != "" {
+					buildOptions = append(buildOptions, "--from", manifestPath)
+				}
+				if err := spin.Build(ctx, buildOptions...); err != nil {
 					return fmt.Errorf("failed to build: %w", err)
 				}
 				fmt.Printf("%s Build completed\n", green("✓"))
@@ -133,6 +250,8 @@ func newUpCmd() *cobra.Command {
 			// Add from flag
 			if from != "" {
 				spinOptions = append(spinOptions, "--from", from)
+			} else if manifestPath != "" {
+				spinOptions = append(spinOptions, "--from", manifestPath)
 			}
 
 			// Add insecure flag
@@ -205,25 +324,65 @@ func newUpCmd() *cobra.Command {
 				spinOptions = append(spinOptions, "--state-dir", stateDir)
 			}
 
+			// Record tool calls through a proxy on the listen address, with
+			// spin on an internal port behind it
+			spinListen := listen
+			if record != "" {
+				if listen == "" {
+					listen = defaultListenAddress
+				}
+				internal, err := freeLocalAddress()
+				if err != nil {
+					return err
+				}
+				stopRecorder, err := startRecorder(record, listen, internal)
+				if err != nil {
+					return err
+				}
+				defer stopRecorder()
+				spinListen = internal
+				fmt.Printf("%s Recording tool calls on %s to %s\n", blue("→"), listen, record)
+			}
+
 			// Add listen address
-			if listen != "" {
-				spinOptions = append(spinOptions, "--listen", listen)
+			if spinListen != "" {
+				spinOptions = append(spinOptions, "--listen", spinListen)
+			}
+
+			// Expose through a public tunnel if requested
+			if expose {
+				tun, err := startTunnel(ctx, listen)
+				if err != nil {
+					return err
+				}
+				defer func() { _ = tun.Close() }()
 			}
 
 			// Run with watch if requested
 			if watch {
 				fmt.Printf("%s Starting with watch mode...\n", yellow("ℹ"))
-				if err := spin.Watch(ctx, spinOptions...); err != nil {
-					return fmt.Errorf("failed to start with watch: %w", err)
+			}
+			runSpin := func(ctx context.Context) error {
+				if watch {
+					if err := spin.Watch(ctx, spinOptions...); err != nil {
+						return fmt.Errorf("failed to start with watch: %w", err)
+					}
+					return nil
 				}
-			} else {
-				// Run normally
 				if err := spin.Up(ctx, spinOptions...); err != nil {
 					return fmt.Errorf("failed to start: %w", err)
 				}
+				return nil
+			}
+
+			if noRestart {
+				return runSpin(ctx)
 			}
 
-			return nil
+			// Restart spin when it crashes, until interrupted
+			ctx, stop := signal.NotifyContext(ctx, os.Interrupt, syscall.SIGTERM)
+			defer stop()
+			return superviseSpin(ctx, defaultRestartPolicy, runSpin)
 		},
 	}
 
@@ -232,6 +391,13 @@ func newUpCmd() *cobra.Command {
 	cmd.Flags().BoolVarP(&watch, "watch", "w", false, "Watch for changes and reload")
 	cmd.Flags().BoolVar(&skipSynth, "skip-synth", false, "Skip synthesis of spin.toml from FTL config")
 	cmd.Flags().StringVarP(&configFile, "config", "c", "", "Configuration file to synthesize (auto-detects if not specified)")
+	cmd.Flags().BoolVar(&expose, "expose", false, "Expose the application through a public tunnel and print the public MCP endpoint")
+	cmd.Flags().StringArrayVar(&components, "component", nil, "Run only this component (with the MCP gateway). Can be specified multiple times")
+	cmd.Flags().BoolVar(&noGateway, "no-gateway", false, "With --component, serve the single component directly without the MCP gateway")
+	cmd.Flags().BoolVar(&noRestart, "no-restart", false, "Exit when spin crashes instead of restarting it")
+	cmd.Flags().BoolVar(&strict, "strict", false, "Fail when a configured URL is not covered by allowed_outbound_hosts")
+	cmd.Flags().StringVar(&record, "record", "", "Record tools/call requests and responses to this directory for 'ftl replay'")
+	cmd.Flags().StringVar(&parity, "parity", "", "Compare variables and auth mode with this deployed engine before starting")
 
 	// Spin up pass-through flags
 	cmd.Flags().StringArrayVar(&componentIDs, "component-id", nil, "[Experimental] Component ID to run. This can be specified multiple times. The default is all components")
@@ -0,0 +1,45 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/oci"
+	"github.com/fastertools/ftl/synthesis"
+)
+
+// componentManifestDir is where ftl up --component writes its manifest
+var componentManifestDir = filepath.Join(".ftl", "up")
+
+// synthesizeComponents writes a manifest containing only the selected
+// components and returns its path. With a mirror, registry components are
+// resolved from it.
+func synthesizeComponents(configFile string, components []string, noGateway bool, mirror *oci.Mirror) (string, error) {
+	if err := os.MkdirAll(componentManifestDir, 0750); err != nil {
+		return "", fmt.Errorf("failed to create %s: %w", componentManifestDir, err)
+	}
+
+	manifest, err := synthesis.SynthesizeComponentsTo(configFile, componentManifestDir, synthesis.ComponentOptions{
+		Components: components,
+		NoGateway:  noGateway,
+	})
+	if err != nil {
+		return "", fmt.Errorf("synthesis failed: %w", err)
+	}
+	if manifest, err = applyDevMocks(manifest, configFile); err != nil {
+		return "", err
+	}
+	if mirror != nil {
+		if manifest, err = commands.ResolveOfflineSources(manifest, mirror); err != nil {
+			return "", err
+		}
+	}
+
+	path := filepath.Join(componentManifestDir, "spin.toml")
+	if err := os.WriteFile(path, []byte(manifest), 0600); err != nil {
+		return "", fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return path, nil
+}
@@ -0,0 +1,70 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/fastertools/ftl/internal/config"
+	"github.com/fastertools/ftl/internal/tunnel"
+)
+
+// defaultListenAddress is where spin up serves HTTP when --listen is not set
+const defaultListenAddress = "localhost:3000"
+
+// startTunnel exposes the local application through the configured tunnel
+// provider and prints the public MCP endpoint
+func startTunnel(ctx context.Context, listen string) (tunnel.Tunnel, error) {
+	if listen == "" {
+		listen = defaultListenAddress
+	}
+
+	var tunnelCfg *config.TunnelConfig
+	if cfg, err := config.Load(); err == nil {
+		tunnelCfg = cfg.Tunnel
+	}
+
+	provider, err := tunnel.New(tunnelCfg)
+	if err != nil {
+		return nil, err
+	}
+
+	Info("Opening %s tunnel to %s", provider.Name(), listen)
+	tun, err := provider.Open(ctx, tunnelAddress(listen))
+	if err != nil {
+		return nil, fmt.Errorf("failed to open tunnel: %w", err)
+	}
+
+	Success("Public MCP endpoint: %s/mcp", tun.URL())
+	if manifestHasAuthorizer("spin.toml") {
+		// The tunnel forwards Authorization headers untouched, so the local
+		// authorizer validates tokens exactly as it would when deployed
+		Info("Authentication is enabled: clients must send 'Authorization: Bearer <token>'")
+		Info("OAuth metadata: %s/.well-known/oauth-protected-resource", tun.URL())
+	} else {
+		Warn("The application is public: anyone with this URL can call its tools")
+	}
+
+	return tun, nil
+}
+
+// tunnelAddress normalizes a listen address into a dialable host:port
+func tunnelAddress(listen string) string {
+	switch {
+	case strings.HasPrefix(listen, ":"):
+		return "localhost" + listen
+	case strings.HasPrefix(listen, "0.0.0.0:"):
+		return "localhost" + strings.TrimPrefix(listen, "0.0.0.0")
+	}
+	return listen
+}
+
+// manifestHasAuthorizer reports whether the spin manifest includes the MCP authorizer
+func manifestHasAuthorizer(path string) bool {
+	data, err := os.ReadFile(path) // #nosec G304 -- manifest in the project directory
+	if err != nil {
+		return false
+	}
+	return strings.Contains(string(data), "[component.mcp-authorizer]")
+}
@@ -0,0 +1,65 @@
+package cli
+
+import (
+	"bytes"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/BurntSushi/toml"
+	"github.com/fastertools/ftl/validation"
+)
+
+// kvRuntimeConfigFile is the Spin runtime config generated by ftl up for the
+// key-value stores declared by components
+const kvRuntimeConfigFile = ".spin/ftl-runtime-config.toml"
+
+// writeKeyValueRuntimeConfig writes a Spin runtime config defining the
+// key-value stores declared by the components of an app. Spin only provides
+// the "default" store without one. Each store is kept in a SQLite file next
+// to the config. Returns "" without writing when no component declares a
+// store.
+func writeKeyValueRuntimeConfig(app *validation.Application, path string) (string, error) {
+	seen := map[string]bool{}
+	var stores []string
+	if app != nil {
+		for _, comp := range app.Components {
+			for _, store := range comp.KeyValueStores {
+				if !seen[store] {
+					seen[store] = true
+					stores = append(stores, store)
+				}
+			}
+		}
+	}
+	if len(stores) == 0 {
+		return "", nil
+	}
+	sort.Strings(stores)
+
+	dir, err := filepath.Abs(filepath.Dir(path))
+	if err != nil {
+		return "", err
+	}
+	tables := make(map[string]map[string]string, len(stores))
+	for _, store := range stores {
+		tables[store] = map[string]string{
+			"type": "spin",
+			"path": filepath.Join(dir, "kv-"+store+".db"),
+		}
+	}
+
+	var buf bytes.Buffer
+	buf.WriteString("# Generated by ftl up for the key_value_stores of the components\n")
+	if err := toml.NewEncoder(&buf).Encode(map[string]interface{}{"key_value_store": tables}); err != nil {
+		return "", fmt.Errorf("failed to encode runtime config: %w", err)
+	}
+	if err := os.MkdirAll(dir, 0750); err != nil {
+		return "", fmt.Errorf("failed to create %s: %w", dir, err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0600); err != nil {
+		return "", fmt.Errorf("failed to write runtime config: %w", err)
+	}
+	return path, nil
+}
@@ -0,0 +1,124 @@
+package cli
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"mime"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/fastertools/ftl/commands"
+	"github.com/fastertools/ftl/synthesis"
+	"github.com/fastertools/ftl/validation"
+	"github.com/fatih/color"
+)
+
+// applyDevMocks adds the mocks declared in the config's dev.mocks section to
+// a synthesized manifest. Configs without mocks leave it unchanged.
+func applyDevMocks(manifest, configFile string) (string, error) {
+	app, err := commands.LoadApplication(configFile)
+	if err != nil {
+		return "", err
+	}
+	if app == nil || app.Dev == nil || len(app.Dev.Mocks) == 0 {
+		return manifest, nil
+	}
+
+	mocks, err := devMocks(app.Dev.Mocks, filepath.Dir(configFile))
+	if err != nil {
+		return "", err
+	}
+	manifest, err = synthesis.ApplyMocks(manifest, mocks)
+	if err != nil {
+		return "", err
+	}
+
+	hosts := make([]string, 0, len(mocks))
+	for host := range mocks {
+		hosts = append(hosts, host)
+	}
+	sort.Strings(hosts)
+	yellow := color.New(color.FgYellow).SprintFunc()
+	for _, host := range hosts {
+		fmt.Printf("%s Mocking %s with %d route(s) (%s)\n", yellow("ℹ"), host, len(mocks[host]), synthesis.MockComponentID(host))
+	}
+	return manifest, nil
+}
+
+// devMocks converts the mock routes of a config into the routes served by
+// the mock components. Files are read relative to configDir.
+func devMocks(mocks map[string][]validation.MockRoute, configDir string) (map[string][]synthesis.MockRoute, error) {
+	compiled := make(map[string][]synthesis.MockRoute, len(mocks))
+	for host, routes := range mocks {
+		for i, route := range routes {
+			mock, err := devMockRoute(route, configDir)
+			if err != nil {
+				return nil, fmt.Errorf("mock %s route %d (%s): %w", host, i+1, route.Path, err)
+			}
+			compiled[host] = append(compiled[host], mock)
+		}
+	}
+	return compiled, nil
+}
+
+func devMockRoute(route validation.MockRoute, configDir string) (synthesis.MockRoute, error) {
+	mock := synthesis.MockRoute{
+		Method:  strings.ToUpper(route.Method),
+		Path:    route.Path,
+		Status:  route.Status,
+		Headers: map[string]string{},
+	}
+	for name, value := range route.Headers {
+		mock.Headers[name] = value
+	}
+
+	contentType := ""
+	switch {
+	case route.JSON != nil && route.Body == nil && route.File == "":
+		body, err := json.Marshal(route.JSON)
+		if err != nil {
+			return mock, fmt.Errorf("invalid json: %w", err)
+		}
+		mock.Body = string(body)
+		contentType = "application/json"
+	case route.JSON == nil && route.Body != nil && route.File == "":
+		mock.Body = *route.Body
+		mock.Template = true
+	case route.JSON == nil && route.Body == nil && route.File != "":
+		path := route.File
+		if !filepath.IsAbs(path) {
+			path = filepath.Join(configDir, path)
+		}
+		data, err := os.ReadFile(filepath.Clean(path))
+		if err != nil {
+			return mock, fmt.Errorf("failed to read mock file: %w", err)
+		}
+		mock.BodyBase64 = base64.StdEncoding.EncodeToString(data)
+		if contentType = mime.TypeByExtension(filepath.Ext(path)); contentType == "" {
+			contentType = http.DetectContentType(data)
+		}
+	default:
+		return mock, fmt.Errorf("exactly one of json, body or file is required")
+	}
+
+	if contentType != "" && !hasHeader(mock.Headers, "Content-Type") {
+		mock.Headers["Content-Type"] = contentType
+	}
+	if len(mock.Headers) == 0 {
+		mock.Headers = nil
+	}
+	return mock, nil
+}
+
+func hasHeader(headers map[string]string, name string) bool {
+	for header := range headers {
+		if strings.EqualFold(header, name) {
+			return true
+		}
+	}
+	return false
+}
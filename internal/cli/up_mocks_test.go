@@ -0,0 +1,104 @@
+package cli
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/BurntSushi/toml"
+	"github.com/fastertools/ftl/synthesis"
+	"github.com/fastertools/ftl/validation"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const mockedAppConfig = `name: weather-app
+components:
+  - id: weather
+    source: ./weather.wasm
+    allowed_outbound_hosts:
+      - https://api.weather.com
+    variables:
+      api_url: https://api.weather.com/v1
+dev:
+  mocks:
+    api.weather.com:
+      - method: GET
+        path: /v1/forecast
+        json: {temperature: 21}
+      - path: /v1/echo/{word}
+        status: 201
+        body: "{{ path.word }}"
+      - path: /v1/radar.png
+        file: fixtures/radar.png
+`
+
+func TestApplyDevMocks(t *testing.T) {
+	dir := t.TempDir()
+	configFile := filepath.Join(dir, "ftl.yaml")
+	require.NoError(t, os.WriteFile(configFile, []byte(mockedAppConfig), 0600))
+	require.NoError(t, os.MkdirAll(filepath.Join(dir, "fixtures"), 0750))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "fixtures", "radar.png"), []byte("\x89PNG"), 0600))
+
+	manifest, err := synthesis.SynthesizeFromConfig(configFile)
+	require.NoError(t, err)
+	manifest, err = applyDevMocks(manifest, configFile)
+	require.NoError(t, err)
+
+	var doc map[string]interface{}
+	_, err = toml.Decode(manifest, &doc)
+	require.NoError(t, err)
+	components := doc["component"].(map[string]interface{})
+
+	weather := components["weather"].(map[string]interface{})
+	assert.Equal(t, "http://mock-api-weather-com.spin.internal/v1", weather["variables"].(map[string]interface{})["api_url"])
+
+	mock := components["mock-api-weather-com"].(map[string]interface{})
+	encoded, err := base64.StdEncoding.DecodeString(mock["variables"].(map[string]interface{})["routes"].(string))
+	require.NoError(t, err)
+	var routes []synthesis.MockRoute
+	require.NoError(t, json.Unmarshal(encoded, &routes))
+	require.Len(t, routes, 3)
+
+	assert.Equal(t, "GET", routes[0].Method)
+	assert.JSONEq(t, `{"temperature": 21}`, routes[0].Body)
+	assert.False(t, routes[0].Template)
+	assert.Equal(t, "application/json", routes[0].Headers["Content-Type"])
+
+	assert.Equal(t, 201, routes[1].Status)
+	assert.Equal(t, "{{ path.word }}", routes[1].Body)
+	assert.True(t, routes[1].Template)
+
+	assert.Equal(t, base64.StdEncoding.EncodeToString([]byte("\x89PNG")), routes[2].BodyBase64)
+	assert.Equal(t, "image/png", routes[2].Headers["Content-Type"])
+}
+
+func TestApplyDevMocks_NoMocks(t *testing.T) {
+	dir := t.TempDir()
+	configFile := filepath.Join(dir, "ftl.yaml")
+	require.NoError(t, os.WriteFile(configFile, []byte("name: plain-app\n"), 0600))
+
+	manifest, err := applyDevMocks("spin_manifest_version = 2\n", configFile)
+	require.NoError(t, err)
+	assert.Equal(t, "spin_manifest_version = 2\n", manifest)
+}
+
+func TestDevMockRoute(t *testing.T) {
+	body := "hello"
+
+	route, err := devMockRoute(validation.MockRoute{
+		Path:    "/",
+		Body:    &body,
+		Headers: map[string]string{"content-type": "text/plain"},
+	}, t.TempDir())
+	require.NoError(t, err)
+	assert.Equal(t, map[string]string{"content-type": "text/plain"}, route.Headers)
+
+	_, err = devMockRoute(validation.MockRoute{Path: "/", Body: &body, JSON: map[string]interface{}{}}, t.TempDir())
+	assert.ErrorContains(t, err, "exactly one of json, body or file")
+
+	_, err = devMockRoute(validation.MockRoute{Path: "/", File: "missing.json"}, t.TempDir())
+	assert.ErrorContains(t, err, "failed to read mock file")
+}
@@ -0,0 +1,204 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/internal/auth"
+	"github.com/fastertools/ftl/validation"
+)
+
+// parityClient is the subset of the FTL API used to compare the local
+// environment with a deployed engine
+type parityClient interface {
+	ListApps(ctx context.Context, params *api.ListAppsParams) (*api.ListAppsResponseBody, error)
+	GetApp(ctx context.Context, appID string) (*api.App, error)
+	GetAppVariables(ctx context.Context, appID string) (*api.GetAppVariablesResponseBody, error)
+}
+
+// Allow overriding for tests
+var newParityClient = newParityClientImpl
+
+func newParityClientImpl(ctx context.Context) (parityClient, error) {
+	store, err := auth.NewKeyringStore()
+	if err != nil {
+		return nil, fmt.Errorf("failed to initialize credential store: %w", err)
+	}
+	authManager := auth.NewManager(store, nil)
+
+	if _, err := authManager.GetToken(ctx); err != nil {
+		return nil, fmt.Errorf("not logged in to FTL. Run 'ftl auth login' first")
+	}
+
+	client, err := api.NewFTLClient(authManager, "")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create API client: %w", err)
+	}
+	return client, nil
+}
+
+const (
+	parityUnset  = "(not set)"
+	paritySecret = "(secret)"
+)
+
+// parityDiff is a setting that differs between the local environment and a
+// deployed engine
+type parityDiff struct {
+	Setting  string
+	Local    string
+	Deployed string
+}
+
+// runParityCheck compares the variables and auth mode of the local app with
+// those of a deployed engine and prints the differences. Differences don't
+// stop ftl up, they are only reported.
+func runParityCheck(ctx context.Context, client parityClient, w io.Writer, app *validation.Application, engine string) error {
+	apps, err := client.ListApps(ctx, &api.ListAppsParams{Name: &engine})
+	if err != nil {
+		return fmt.Errorf("failed to look up %s: %w", engine, err)
+	}
+	var appID string
+	for _, a := range apps.Apps {
+		if a.AppName == engine {
+			appID = a.AppId.String()
+			break
+		}
+	}
+	if appID == "" {
+		return fmt.Errorf("engine %s not found", engine)
+	}
+
+	deployed, err := client.GetApp(ctx, appID)
+	if err != nil {
+		return fmt.Errorf("failed to get %s: %w", engine, err)
+	}
+	variables, err := client.GetAppVariables(ctx, appID)
+	if err != nil {
+		return fmt.Errorf("failed to get the variables of %s: %w", engine, err)
+	}
+
+	diffs := append(authParity(app, deployed), variableParity(app, variables.Variables, os.LookupEnv)...)
+	if len(diffs) == 0 {
+		Success("Local environment matches %s", engine)
+		return nil
+	}
+
+	Warn("Local environment differs from %s in %d setting(s)", engine, len(diffs))
+	tb := NewTableBuilder("SETTING", "LOCAL", "DEPLOYED")
+	for _, d := range diffs {
+		tb.AddRow(d.Setting, d.Local, d.Deployed)
+	}
+	return tb.Write(NewDataWriter(w, "table"))
+}
+
+// authParity compares the access mode of the local app, and the issuer and
+// audience of custom auth, with a deployed engine
+func authParity(app *validation.Application, deployed *api.App) []parityDiff {
+	localAccess := app.Access
+	if localAccess == "" {
+		localAccess = "public"
+	}
+	deployedAccess := parityUnset
+	if deployed.AccessControl != nil {
+		deployedAccess = string(*deployed.AccessControl)
+	}
+
+	var diffs []parityDiff
+	if localAccess != deployedAccess {
+		diffs = append(diffs, parityDiff{"access", localAccess, deployedAccess})
+	}
+	if localAccess != "custom" || deployedAccess != "custom" {
+		return diffs
+	}
+
+	var localIssuer, localAudience, deployedIssuer, deployedAudience string
+	if app.Auth != nil {
+		localIssuer, localAudience = app.Auth.JWTIssuer, app.Auth.JWTAudience
+	}
+	if deployed.CustomAuth != nil {
+		deployedIssuer, deployedAudience = deployed.CustomAuth.Issuer, deployed.CustomAuth.Audience
+	}
+	if localIssuer != deployedIssuer {
+		diffs = append(diffs, parityDiff{"auth.jwt_issuer", parityValue(localIssuer), parityValue(deployedIssuer)})
+	}
+	if localAudience != deployedAudience {
+		diffs = append(diffs, parityDiff{"auth.jwt_audience", parityValue(localAudience), parityValue(deployedAudience)})
+	}
+	return diffs
+}
+
+// variableParity compares the variables of the local app with those of a
+// deployed engine. Locally, a variable takes its value from the
+// SPIN_VARIABLE_<NAME> environment variable that ftl up passes on to Spin,
+// falling back to its default. Secrets are only compared by presence, since
+// their deployed values are never returned.
+func variableParity(app *validation.Application, deployed []api.AppVariable, lookupEnv func(string) (string, bool)) []parityDiff {
+	local := map[string]string{}
+	for name, variable := range app.Variables {
+		switch {
+		case variable.Secret || variable.ValueFrom != "":
+			local[name] = paritySecret
+		default:
+			if value, ok := lookupEnv("SPIN_VARIABLE_" + strings.ToUpper(name)); ok {
+				local[name] = value
+			} else if variable.Default != nil {
+				local[name] = *variable.Default
+			} else {
+				local[name] = parityUnset
+			}
+		}
+	}
+
+	remote := map[string]string{}
+	for _, variable := range deployed {
+		switch {
+		case variable.Secret:
+			remote[variable.Name] = paritySecret
+		case variable.Value != nil:
+			remote[variable.Name] = *variable.Value
+		default:
+			remote[variable.Name] = ""
+		}
+	}
+
+	names := make([]string, 0, len(local)+len(remote))
+	for name := range local {
+		names = append(names, name)
+	}
+	for name := range remote {
+		if _, ok := local[name]; !ok {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+
+	var diffs []parityDiff
+	for _, name := range names {
+		localValue, inLocal := local[name]
+		remoteValue, inRemote := remote[name]
+		if !inLocal {
+			localValue = parityUnset
+		}
+		if !inRemote {
+			remoteValue = parityUnset
+		}
+		if localValue != remoteValue {
+			diffs = append(diffs, parityDiff{"variables." + name, localValue, remoteValue})
+		}
+	}
+	return diffs
+}
+
+// parityValue shows empty settings as not set
+func parityValue(value string) string {
+	if value == "" {
+		return parityUnset
+	}
+	return value
+}
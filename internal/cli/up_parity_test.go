@@ -0,0 +1,129 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/api"
+	"github.com/fastertools/ftl/validation"
+)
+
+type fakeParityClient struct {
+	app       api.App
+	variables []api.AppVariable
+}
+
+func (f *fakeParityClient) ListApps(_ context.Context, _ *api.ListAppsParams) (*api.ListAppsResponseBody, error) {
+	data, err := json.Marshal(map[string]interface{}{
+		"apps": []map[string]interface{}{
+			{"appId": prodAppID, "appName": "my-app-prod"},
+		},
+	})
+	if err != nil {
+		return nil, err
+	}
+	var resp api.ListAppsResponseBody
+	err = json.Unmarshal(data, &resp)
+	return &resp, err
+}
+
+func (f *fakeParityClient) GetApp(_ context.Context, appID string) (*api.App, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	return &f.app, nil
+}
+
+func (f *fakeParityClient) GetAppVariables(_ context.Context, appID string) (*api.GetAppVariablesResponseBody, error) {
+	if appID != prodAppID {
+		return nil, assert.AnError
+	}
+	return &api.GetAppVariablesResponseBody{Variables: f.variables}, nil
+}
+
+func parityTestApp() *validation.Application {
+	return &validation.Application{
+		Name:   "my-app",
+		Access: "private",
+		Variables: map[string]validation.Variable{
+			"log_level": validation.VariableWithDefault("debug"),
+			"region":    {Required: true},
+			"api_key":   {ValueFrom: "engine-secret:API_KEY"},
+			"timeout":   validation.VariableWithDefault("30"),
+		},
+	}
+}
+
+func TestVariableParity(t *testing.T) {
+	deployed := []api.AppVariable{
+		{Name: "api_key", Secret: true},
+		{Name: "log_level", Value: ptr("warn")},
+		{Name: "region", Value: ptr("eu-west-1")},
+		{Name: "timeout", Value: ptr("30")},
+		{Name: "feature_flags", Value: ptr("beta")},
+	}
+	env := map[string]string{"SPIN_VARIABLE_REGION": "eu-west-1"}
+	lookupEnv := func(key string) (string, bool) {
+		value, ok := env[key]
+		return value, ok
+	}
+
+	diffs := variableParity(parityTestApp(), deployed, lookupEnv)
+	assert.Equal(t, []parityDiff{
+		{"variables.feature_flags", parityUnset, "beta"},
+		{"variables.log_level", "debug", "warn"},
+	}, diffs)
+
+	// A required variable without a local value
+	delete(env, "SPIN_VARIABLE_REGION")
+	diffs = variableParity(parityTestApp(), deployed, lookupEnv)
+	assert.Contains(t, diffs, parityDiff{"variables.region", parityUnset, "eu-west-1"})
+}
+
+func TestAuthParity(t *testing.T) {
+	private := api.AppAccessControlPrivate
+	assert.Empty(t, authParity(parityTestApp(), &api.App{AccessControl: &private}))
+
+	public := api.AppAccessControlPublic
+	assert.Equal(t, []parityDiff{{"access", "private", "public"}},
+		authParity(parityTestApp(), &api.App{AccessControl: &public}))
+
+	custom := api.AppAccessControlCustom
+	app := parityTestApp()
+	app.Access = "custom"
+	app.Auth = &validation.AuthConfig{JWTIssuer: "https://auth.example.com", JWTAudience: "dev"}
+	deployed := &api.App{AccessControl: &custom}
+	deployed.CustomAuth = &struct {
+		Audience string `json:"audience"`
+		Issuer   string `json:"issuer"`
+	}{Audience: "prod", Issuer: "https://auth.example.com"}
+	assert.Equal(t, []parityDiff{{"auth.jwt_audience", "dev", "prod"}}, authParity(app, deployed))
+}
+
+func TestRunParityCheck(t *testing.T) {
+	t.Setenv("SPIN_VARIABLE_REGION", "eu-west-1")
+	private := api.AppAccessControlPrivate
+	client := &fakeParityClient{
+		app: api.App{AccessControl: &private},
+		variables: []api.AppVariable{
+			{Name: "api_key", Secret: true},
+			{Name: "log_level", Value: ptr("warn")},
+			{Name: "region", Value: ptr("eu-west-1")},
+			{Name: "timeout", Value: ptr("30")},
+		},
+	}
+
+	var buf bytes.Buffer
+	require.NoError(t, runParityCheck(context.Background(), client, &buf, parityTestApp(), "my-app-prod"))
+	assert.Contains(t, buf.String(), "SETTING")
+	assert.Contains(t, buf.String(), "variables.log_level")
+	assert.NotContains(t, buf.String(), "variables.region")
+
+	err := runParityCheck(context.Background(), client, &buf, parityTestApp(), "missing")
+	assert.ErrorContains(t, err, "engine missing not found")
+}
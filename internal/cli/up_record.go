@@ -0,0 +1,280 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"net/http/httputil"
+	"net/url"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sync"
+	"time"
+)
+
+// maxRecordedBodySize caps the request and response bodies inspected by the recorder
+const maxRecordedBodySize = 16 << 20
+
+var unsafeFileChars = regexp.MustCompile(`[^A-Za-z0-9_.-]+`)
+
+// recordedCall is a tools/call request and its response, as captured by
+// `ftl up --record` and re-sent by `ftl replay`
+type recordedCall struct {
+	Tool       string          `json:"tool"`
+	RecordedAt time.Time       `json:"recorded_at"`
+	DurationMS int64           `json:"duration_ms"`
+	Status     int             `json:"status"`
+	Request    json.RawMessage `json:"request"`
+	Response   json.RawMessage `json:"response,omitempty"`
+}
+
+// rpcMessage is the part of a JSON-RPC message the recorder looks at
+type rpcMessage struct {
+	ID     json.RawMessage `json:"id,omitempty"`
+	Method string          `json:"method,omitempty"`
+	Params struct {
+		Name string `json:"name"`
+	} `json:"params"`
+}
+
+// toolCall is a tools/call message of a request
+type toolCall struct {
+	tool string
+	id   string
+	raw  json.RawMessage
+}
+
+type recordingKey struct{}
+
+// recording is attached to proxied requests that contain tool calls
+type recording struct {
+	calls   []toolCall
+	started time.Time
+}
+
+// toolRecorder writes the tool calls passing through a proxy to a directory
+type toolRecorder struct {
+	dir string
+
+	mu  sync.Mutex
+	seq int
+}
+
+// newToolRecorder creates the recording directory. Numbering continues after
+// the recordings already in it.
+func newToolRecorder(dir string) (*toolRecorder, error) {
+	if err := os.MkdirAll(dir, 0750); err != nil {
+		return nil, fmt.Errorf("failed to create recording directory: %w", err)
+	}
+	existing, err := filepath.Glob(filepath.Join(dir, "*.json"))
+	if err != nil {
+		return nil, err
+	}
+	return &toolRecorder{dir: dir, seq: len(existing)}, nil
+}
+
+// proxy returns a reverse proxy to target that records tool calls
+func (r *toolRecorder) proxy(target *url.URL) http.Handler {
+	rp := httputil.NewSingleHostReverseProxy(target)
+	rp.ModifyResponse = r.recordResponse
+
+	return http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		if req.Method == http.MethodPost && req.Body != nil {
+			body, err := io.ReadAll(io.LimitReader(req.Body, maxRecordedBodySize+1))
+			if err != nil {
+				http.Error(w, "failed to read request body", http.StatusBadRequest)
+				return
+			}
+			// Larger bodies are forwarded without being recorded
+			req.Body = rewindBody(body, req.Body)
+
+			if len(body) > maxRecordedBodySize {
+				rp.ServeHTTP(w, req)
+				return
+			}
+			if calls := parseToolCalls(body); len(calls) > 0 {
+				req = req.WithContext(context.WithValue(req.Context(), recordingKey{}, &recording{
+					calls:   calls,
+					started: time.Now(),
+				}))
+			}
+		}
+		rp.ServeHTTP(w, req)
+	})
+}
+
+// recordResponse writes one recording per tool call of the request
+func (r *toolRecorder) recordResponse(resp *http.Response) error {
+	rec, ok := resp.Request.Context().Value(recordingKey{}).(*recording)
+	if !ok {
+		return nil
+	}
+
+	body, err := io.ReadAll(io.LimitReader(resp.Body, maxRecordedBodySize+1))
+	if err != nil {
+		return err
+	}
+	resp.Body = rewindBody(body, resp.Body)
+
+	var responses map[string]json.RawMessage
+	if len(body) <= maxRecordedBodySize {
+		responses = responsesByID(body)
+	}
+	for _, call := range rec.calls {
+		entry := recordedCall{
+			Tool:       call.tool,
+			RecordedAt: rec.started.UTC(),
+			DurationMS: time.Since(rec.started).Milliseconds(),
+			Status:     resp.StatusCode,
+			Request:    call.raw,
+			Response:   responses[call.id],
+		}
+		if err := r.write(entry); err != nil {
+			Warn("Failed to record %s call: %v", call.tool, err)
+		}
+	}
+	return nil
+}
+
+// rewindBody puts the bytes read from a body back in front of the rest of it
+func rewindBody(read []byte, body io.ReadCloser) io.ReadCloser {
+	return struct {
+		io.Reader
+		io.Closer
+	}{io.MultiReader(bytes.NewReader(read), body), body}
+}
+
+func (r *toolRecorder) write(entry recordedCall) error {
+	data, err := json.MarshalIndent(entry, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.seq++
+	name := fmt.Sprintf("%04d-%s.json", r.seq, unsafeFileChars.ReplaceAllString(entry.Tool, "_"))
+	return os.WriteFile(filepath.Join(r.dir, name), append(data, '\n'), 0600)
+}
+
+// parseToolCalls returns the tools/call messages of a JSON-RPC request or batch
+func parseToolCalls(body []byte) []toolCall {
+	var raws []json.RawMessage
+	trimmed := bytes.TrimSpace(body)
+	if len(trimmed) > 0 && trimmed[0] == '[' {
+		if err := json.Unmarshal(trimmed, &raws); err != nil {
+			return nil
+		}
+	} else {
+		raws = []json.RawMessage{trimmed}
+	}
+
+	var calls []toolCall
+	for _, raw := range raws {
+		var msg rpcMessage
+		if err := json.Unmarshal(raw, &msg); err != nil || msg.Method != "tools/call" || msg.Params.Name == "" {
+			continue
+		}
+		calls = append(calls, toolCall{tool: msg.Params.Name, id: string(msg.ID), raw: raw})
+	}
+	return calls
+}
+
+// responsesByID indexes a JSON-RPC response or batch response by message id
+func responsesByID(body []byte) map[string]json.RawMessage {
+	var raws []json.RawMessage
+	trimmed := bytes.TrimSpace(body)
+	if len(trimmed) > 0 && trimmed[0] == '[' {
+		if err := json.Unmarshal(trimmed, &raws); err != nil {
+			return nil
+		}
+	} else if json.Valid(trimmed) {
+		raws = []json.RawMessage{trimmed}
+	}
+
+	byID := make(map[string]json.RawMessage, len(raws))
+	for _, raw := range raws {
+		var msg rpcMessage
+		if err := json.Unmarshal(raw, &msg); err == nil {
+			byID[string(msg.ID)] = raw
+		}
+	}
+	return byID
+}
+
+// startRecorder serves a recording proxy on listen in front of the app on target
+func startRecorder(dir, listen, target string) (func(), error) {
+	recorder, err := newToolRecorder(dir)
+	if err != nil {
+		return nil, err
+	}
+
+	ln, err := net.Listen("tcp", listen)
+	if err != nil {
+		return nil, fmt.Errorf("failed to listen on %s: %w", listen, err)
+	}
+
+	server := &http.Server{
+		Handler:           recorder.proxy(&url.URL{Scheme: "http", Host: target}),
+		ReadHeaderTimeout: 10 * time.Second,
+	}
+	go func() {
+		if err := server.Serve(ln); err != nil && !errors.Is(err, http.ErrServerClosed) {
+			Warn("Recorder stopped: %v", err)
+		}
+	}()
+
+	return func() {
+		ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+		defer cancel()
+		_ = server.Shutdown(ctx)
+	}, nil
+}
+
+// freeLocalAddress returns a loopback address with a port that is currently free
+func freeLocalAddress() (string, error) {
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		return "", fmt.Errorf("failed to find a free port: %w", err)
+	}
+	address := ln.Addr().String()
+	_ = ln.Close()
+	return address, nil
+}
+
+// recordingFiles lists the recordings of a directory in recording order
+func recordingFiles(dir string) ([]string, error) {
+	files, err := filepath.Glob(filepath.Join(dir, "*.json"))
+	if err != nil {
+		return nil, err
+	}
+	if len(files) == 0 {
+		if _, err := os.Stat(dir); err != nil {
+			return nil, fmt.Errorf("recording directory %s not found", dir)
+		}
+	}
+	// Glob sorts the names, which start with the sequence number
+	return files, nil
+}
+
+// loadRecording reads a recorded call
+func loadRecording(path string) (recordedCall, error) {
+	var entry recordedCall
+	data, err := os.ReadFile(path) // #nosec G304 -- recording chosen by the user
+	if err != nil {
+		return entry, err
+	}
+	if err := json.Unmarshal(data, &entry); err != nil {
+		return entry, fmt.Errorf("invalid recording %s: %w", filepath.Base(path), err)
+	}
+	if entry.Tool == "" || len(entry.Request) == 0 {
+		return entry, fmt.Errorf("invalid recording %s: missing tool or request", filepath.Base(path))
+	}
+	return entry, nil
+}
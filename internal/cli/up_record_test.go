@@ -0,0 +1,124 @@
+package cli
+
+import (
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newEchoToolServer answers tools/call requests like the MCP gateway, echoing
+// the message argument with the given prefix
+func newEchoToolServer(t *testing.T, prefix string) *httptest.Server {
+	t.Helper()
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		var req struct {
+			ID     json.RawMessage `json:"id"`
+			Method string          `json:"method"`
+			Params struct {
+				Arguments struct {
+					Message string `json:"message"`
+				} `json:"arguments"`
+			} `json:"params"`
+		}
+		require.NoError(t, json.NewDecoder(r.Body).Decode(&req))
+
+		w.Header().Set("Content-Type", "application/json")
+		_ = json.NewEncoder(w).Encode(map[string]interface{}{
+			"jsonrpc": "2.0",
+			"id":      req.ID,
+			"result": map[string]interface{}{
+				"content": []interface{}{
+					map[string]interface{}{"type": "text", "text": prefix + req.Params.Arguments.Message},
+				},
+			},
+		})
+	}))
+	t.Cleanup(server.Close)
+	return server
+}
+
+func postJSON(t *testing.T, endpoint, body string) string {
+	t.Helper()
+	resp, err := http.Post(endpoint, "application/json", strings.NewReader(body)) // #nosec G107 -- test server
+	require.NoError(t, err)
+	defer func() { _ = resp.Body.Close() }()
+	data, err := io.ReadAll(resp.Body)
+	require.NoError(t, err)
+	return string(data)
+}
+
+func TestParseToolCalls(t *testing.T) {
+	calls := parseToolCalls([]byte(`{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"echo__echo"}}`))
+	require.Len(t, calls, 1)
+	assert.Equal(t, "echo__echo", calls[0].tool)
+	assert.Equal(t, "7", calls[0].id)
+
+	calls = parseToolCalls([]byte(`[
+		{"jsonrpc":"2.0","id":1,"method":"tools/list"},
+		{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"a"}},
+		{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"b"}}
+	]`))
+	require.Len(t, calls, 2)
+	assert.Equal(t, "a", calls[0].tool)
+	assert.Equal(t, "b", calls[1].tool)
+
+	assert.Empty(t, parseToolCalls([]byte(`{"jsonrpc":"2.0","id":1,"method":"tools/list"}`)))
+	assert.Empty(t, parseToolCalls([]byte(`not json`)))
+}
+
+func TestToolRecorderProxy(t *testing.T) {
+	app := newEchoToolServer(t, "echo: ")
+	target, err := url.Parse(app.URL)
+	require.NoError(t, err)
+
+	dir := filepath.Join(t.TempDir(), "recordings")
+	recorder, err := newToolRecorder(dir)
+	require.NoError(t, err)
+	proxy := httptest.NewServer(recorder.proxy(target))
+	t.Cleanup(proxy.Close)
+
+	// Responses pass through unchanged
+	body := postJSON(t, proxy.URL+"/mcp", `{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"echo__echo","arguments":{"message":"hi"}}}`)
+	assert.Contains(t, body, "echo: hi")
+
+	// Other methods are proxied without being recorded
+	postJSON(t, proxy.URL+"/mcp", `{"jsonrpc":"2.0","id":2,"method":"tools/list"}`)
+
+	files, err := recordingFiles(dir)
+	require.NoError(t, err)
+	require.Len(t, files, 1)
+	assert.Equal(t, "0001-echo__echo.json", filepath.Base(files[0]))
+
+	entry, err := loadRecording(files[0])
+	require.NoError(t, err)
+	assert.Equal(t, "echo__echo", entry.Tool)
+	assert.Equal(t, http.StatusOK, entry.Status)
+	assert.Contains(t, string(entry.Request), `"message":"hi"`)
+	assert.Contains(t, string(entry.Response), "echo: hi")
+
+	// A new recorder continues the numbering
+	recorder, err = newToolRecorder(dir)
+	require.NoError(t, err)
+	assert.Equal(t, 1, recorder.seq)
+}
+
+func TestLoadRecordingRejectsInvalidFiles(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "0001-x.json")
+	require.NoError(t, os.WriteFile(path, []byte(`{"tool":""}`), 0600))
+
+	_, err := loadRecording(path)
+	assert.ErrorContains(t, err, "missing tool or request")
+
+	_, err = recordingFiles(filepath.Join(dir, "missing"))
+	assert.ErrorContains(t, err, "not found")
+}
@@ -0,0 +1,85 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+)
+
+// restartPolicy controls how `ftl up` restarts spin after it crashes
+type restartPolicy struct {
+	// MaxCrashes is the number of consecutive crashes after which ftl up gives up
+	MaxCrashes int
+	// InitialBackoff is the delay before the first restart, doubled after each crash
+	InitialBackoff time.Duration
+	// MaxBackoff caps the delay between restarts
+	MaxBackoff time.Duration
+	// StableAfter is how long spin must run for a crash not to count as consecutive
+	StableAfter time.Duration
+}
+
+var defaultRestartPolicy = restartPolicy{
+	MaxCrashes:     5,
+	InitialBackoff: time.Second,
+	MaxBackoff:     30 * time.Second,
+	StableAfter:    time.Minute,
+}
+
+// superviseSpin runs spin until it exits cleanly or ctx is cancelled,
+// restarting it with exponential backoff when it crashes
+func superviseSpin(ctx context.Context, policy restartPolicy, run func(context.Context) error) error {
+	crashes := 0
+	restarts := 0
+	backoff := policy.InitialBackoff
+
+	for {
+		started := time.Now()
+		err := run(ctx)
+		if err == nil || ctx.Err() != nil {
+			// Clean exit or interrupted by the user
+			return nil
+		}
+
+		// A crash after a stable run starts a new series
+		if time.Since(started) >= policy.StableAfter {
+			crashes = 0
+			backoff = policy.InitialBackoff
+		}
+		crashes++
+		if crashes >= policy.MaxCrashes {
+			return fmt.Errorf("spin crashed %d times in a row, giving up: %w", crashes, err)
+		}
+
+		restarts++
+		Warn("Spin exited unexpectedly: %v", err)
+		Info("Restarting (%s time) in %s...", ordinal(restarts), backoff)
+
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-time.After(backoff):
+		}
+
+		backoff *= 2
+		if backoff > policy.MaxBackoff {
+			backoff = policy.MaxBackoff
+		}
+	}
+}
+
+// ordinal formats n as an English ordinal: 1st, 2nd, 3rd, 4th, 11th, 21st...
+func ordinal(n int) string {
+	suffix := "th"
+	switch n % 10 {
+	case 1:
+		suffix = "st"
+	case 2:
+		suffix = "nd"
+	case 3:
+		suffix = "rd"
+	}
+	if n%100 >= 11 && n%100 <= 13 {
+		suffix = "th"
+	}
+	return fmt.Sprintf("%d%s", n, suffix)
+}
@@ -2,9 +2,15 @@ package cli
 
 import (
 	"bytes"
+	"context"
+	"errors"
 	"os"
+	"path/filepath"
 	"testing"
+	"time"
 
+	"github.com/BurntSushi/toml"
+	"github.com/fastertools/ftl/validation"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
 )
@@ -37,6 +43,33 @@ func TestUpCommand(t *testing.T) {
 	assert.NotNil(t, configFlag)
 	assert.Equal(t, "c", configFlag.Shorthand)
 	assert.Equal(t, "", configFlag.DefValue)
+
+	exposeFlag := cmd.Flags().Lookup("expose")
+	assert.NotNil(t, exposeFlag)
+	assert.Equal(t, "false", exposeFlag.DefValue)
+
+	noRestartFlag := cmd.Flags().Lookup("no-restart")
+	assert.NotNil(t, noRestartFlag)
+	assert.Equal(t, "false", noRestartFlag.DefValue)
+}
+
+func TestTunnelAddress(t *testing.T) {
+	assert.Equal(t, "localhost:3000", tunnelAddress("localhost:3000"))
+	assert.Equal(t, "localhost:8080", tunnelAddress(":8080"))
+	assert.Equal(t, "localhost:8080", tunnelAddress("0.0.0.0:8080"))
+	assert.Equal(t, "127.0.0.1:3000", tunnelAddress("127.0.0.1:3000"))
+}
+
+func TestManifestHasAuthorizer(t *testing.T) {
+	dir := t.TempDir()
+	private := filepath.Join(dir, "private.toml")
+	public := filepath.Join(dir, "public.toml")
+	require.NoError(t, os.WriteFile(private, []byte("[component.mcp-authorizer]\nsource = \"x.wasm\"\n"), 0600))
+	require.NoError(t, os.WriteFile(public, []byte("[component.mcp-gateway]\nsource = \"x.wasm\"\n"), 0600))
+
+	assert.True(t, manifestHasAuthorizer(private))
+	assert.False(t, manifestHasAuthorizer(public))
+	assert.False(t, manifestHasAuthorizer(filepath.Join(dir, "missing.toml")))
 }
 
 func TestUpCommand_Help(t *testing.T) {
@@ -229,3 +262,130 @@ func BenchmarkUpCommand(b *testing.B) {
 		_ = cmd.Execute()
 	}
 }
+
+func TestSynthesizeComponents(t *testing.T) {
+	dir := t.TempDir()
+	oldDir := componentManifestDir
+	componentManifestDir = filepath.Join(dir, ".ftl", "up")
+	defer func() { componentManifestDir = oldDir }()
+
+	configFile := filepath.Join(dir, "ftl.yaml")
+	config := `name: test-app
+components:
+  - id: ocr
+    source: ./ocr.wasm
+  - id: search
+    source: ./search.wasm
+`
+	require.NoError(t, os.WriteFile(configFile, []byte(config), 0600))
+
+	path, err := synthesizeComponents(configFile, []string{"ocr"}, true, nil)
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(componentManifestDir, "spin.toml"), path)
+
+	data, err := os.ReadFile(path)
+	require.NoError(t, err)
+	manifest := string(data)
+	assert.Contains(t, manifest, "[component.ocr]")
+	assert.NotContains(t, manifest, "search")
+	assert.NotContains(t, manifest, "mcp-gateway")
+
+	_, err = synthesizeComponents(configFile, []string{"missing"}, false, nil)
+	assert.ErrorContains(t, err, "not found")
+}
+
+func TestSuperviseSpin(t *testing.T) {
+	policy := restartPolicy{
+		MaxCrashes:     3,
+		InitialBackoff: time.Millisecond,
+		MaxBackoff:     2 * time.Millisecond,
+		StableAfter:    time.Hour,
+	}
+	ctx := context.Background()
+
+	// Restarted after crashes until a clean exit
+	runs := 0
+	err := superviseSpin(ctx, policy, func(context.Context) error {
+		runs++
+		if runs < 3 {
+			return errors.New("exit code 101")
+		}
+		return nil
+	})
+	require.NoError(t, err)
+	assert.Equal(t, 3, runs)
+
+	// Gives up after consecutive crashes
+	runs = 0
+	err = superviseSpin(ctx, policy, func(context.Context) error {
+		runs++
+		return errors.New("exit code 101")
+	})
+	assert.ErrorContains(t, err, "spin crashed 3 times in a row")
+	assert.Equal(t, 3, runs)
+
+	// Crashes after a stable run are not consecutive
+	policy.StableAfter = 0
+	runs = 0
+	err = superviseSpin(ctx, policy, func(context.Context) error {
+		runs++
+		if runs < 6 {
+			return errors.New("exit code 101")
+		}
+		return nil
+	})
+	require.NoError(t, err)
+	assert.Equal(t, 6, runs)
+
+	// Not restarted when interrupted
+	cancelled, cancel := context.WithCancel(ctx)
+	runs = 0
+	err = superviseSpin(cancelled, policy, func(context.Context) error {
+		runs++
+		cancel()
+		return errors.New("signal: interrupt")
+	})
+	require.NoError(t, err)
+	assert.Equal(t, 1, runs)
+}
+
+func TestOrdinal(t *testing.T) {
+	for n, want := range map[int]string{1: "1st", 2: "2nd", 3: "3rd", 4: "4th", 11: "11th", 12: "12th", 13: "13th", 21: "21st", 102: "102nd"} {
+		assert.Equal(t, want, ordinal(n))
+	}
+}
+
+func TestWriteKeyValueRuntimeConfig(t *testing.T) {
+	path := filepath.Join(t.TempDir(), ".spin", "runtime-config.toml")
+
+	// Nothing is written when no component declares a store
+	written, err := writeKeyValueRuntimeConfig(&validation.Application{
+		Components: []*validation.Component{{ID: "echo"}},
+	}, path)
+	require.NoError(t, err)
+	assert.Empty(t, written)
+	assert.NoFileExists(t, path)
+
+	written, err = writeKeyValueRuntimeConfig(&validation.Application{
+		Components: []*validation.Component{
+			{ID: "notes", KeyValueStores: []string{"notes", "cache"}},
+			{ID: "weather", KeyValueStores: []string{"cache"}},
+		},
+	}, path)
+	require.NoError(t, err)
+	assert.Equal(t, path, written)
+
+	var config struct {
+		KeyValueStore map[string]struct {
+			Type string `toml:"type"`
+			Path string `toml:"path"`
+		} `toml:"key_value_store"`
+	}
+	_, err = toml.DecodeFile(path, &config)
+	require.NoError(t, err)
+	assert.Len(t, config.KeyValueStore, 2)
+	for _, store := range []string{"notes", "cache"} {
+		assert.Equal(t, "spin", config.KeyValueStore[store].Type)
+		assert.Equal(t, filepath.Join(filepath.Dir(path), "kv-"+store+".db"), config.KeyValueStore[store].Path)
+	}
+}
@@ -0,0 +1,316 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"os/exec"
+	"path"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/fastertools/ftl/commands"
+)
+
+const (
+	wsStatusOK     = "ok"
+	wsStatusFailed = "failed"
+
+	// defaultWsConcurrency is how many projects are processed at once
+	defaultWsConcurrency = 4
+)
+
+// wsSkipDirs are never searched for projects, in addition to hidden directories
+var wsSkipDirs = map[string]bool{
+	"node_modules": true,
+	"target":       true,
+	"vendor":       true,
+	"dist":         true,
+}
+
+type wsOptions struct {
+	root        string
+	projects    []string
+	concurrency int
+	output      string
+}
+
+// wsResult is the outcome of running a command in one project
+type wsResult struct {
+	Project  string  `json:"project"`
+	Status   string  `json:"status"`
+	ExitCode int     `json:"exit_code"`
+	Duration float64 `json:"duration_secs"`
+	Error    string  `json:"error,omitempty"`
+	// Output of the command, kept for failed projects only
+	Output string `json:"output,omitempty"`
+}
+
+func newWsCmd() *cobra.Command {
+	opts := wsOptions{}
+
+	cmd := &cobra.Command{
+		Use:   "ws",
+		Short: "Run FTL commands across the projects of a workspace",
+		Long: `Run FTL commands across the projects of a workspace.
+
+A workspace is any directory tree holding several FTL projects, such as a
+monorepo. Every directory under --root with an ftl.yaml, ftl.yml or ftl.json is
+a project; hidden directories, node_modules, target, vendor and dist are not
+searched. --projects selects projects whose path relative to the root, or whose
+directory name, matches a glob.
+
+The command runs in each project with bounded concurrency. The output of
+failed projects is printed once all projects are done, followed by a summary
+table with the exit code of each project.`,
+	}
+
+	cmd.PersistentFlags().StringVar(&opts.root, "root", ".", "directory searched for FTL projects")
+	cmd.PersistentFlags().StringArrayVar(&opts.projects, "projects", nil, "only projects whose path or name matches this glob (can be specified multiple times)")
+	cmd.PersistentFlags().IntVarP(&opts.concurrency, "concurrency", "j", defaultWsConcurrency, "number of projects processed at once")
+	cmd.PersistentFlags().StringVarP(&opts.output, "output", "o", "table", "summary format (table, json)")
+
+	cmd.AddCommand(
+		newWsRunCmd("build", "Build every project of the workspace", &opts),
+		newWsRunCmd("test", "Test every project of the workspace", &opts),
+		newWsRunCmd("deploy", "Deploy every project of the workspace", &opts),
+	)
+
+	return cmd
+}
+
+// newWsRunCmd creates a subcommand running 'ftl <command>' in each project
+func newWsRunCmd(command, short string, opts *wsOptions) *cobra.Command {
+	return &cobra.Command{
+		Use:   command + " [-- args...]",
+		Short: short,
+		Long: fmt.Sprintf(`%s.
+
+Runs 'ftl %s' in each project, passing the arguments after -- through.
+Commands run without a terminal, so prompts can't be answered.`, short, command),
+		Example: fmt.Sprintf(`  ftl ws %[1]s
+  ftl ws %[1]s --projects 'apps/*'
+  ftl ws %[1]s --root services -j 2 -- --help`, command),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if opts.concurrency < 1 {
+				return fmt.Errorf("--concurrency must be at least 1")
+			}
+			projects, err := discoverProjects(opts.root, opts.projects)
+			if err != nil {
+				return err
+			}
+
+			dw := NewDataWriter(cmd.OutOrStdout(), opts.output)
+			var report func(wsResult)
+			if dw.format != OutputFormatJSON {
+				Info("Running 'ftl %s' in %d project(s)", command, len(projects))
+				report = reportWsResult
+			}
+			results := runWorkspace(cmd.Context(), opts.root, projects, append([]string{command}, args...), opts.concurrency, report)
+			return writeWsResults(dw, results)
+		},
+	}
+}
+
+// discoverProjects returns the directories under root holding an FTL config,
+// relative to root and in lexical order, keeping those matching any pattern
+func discoverProjects(root string, patterns []string) ([]string, error) {
+	for _, pattern := range patterns {
+		if _, err := path.Match(pattern, ""); err != nil {
+			return nil, fmt.Errorf("invalid --projects pattern %q: %w", pattern, err)
+		}
+	}
+
+	var projects []string
+	err := filepath.WalkDir(root, func(dir string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		if !d.IsDir() {
+			return nil
+		}
+		if dir != root && (strings.HasPrefix(d.Name(), ".") || wsSkipDirs[d.Name()]) {
+			return filepath.SkipDir
+		}
+		if commands.FindDataConfig(dir) == "" {
+			return nil
+		}
+
+		rel, err := filepath.Rel(root, dir)
+		if err != nil {
+			return err
+		}
+		rel = filepath.ToSlash(rel)
+		if matchesProject(rel, patterns) {
+			projects = append(projects, rel)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to search %s for FTL projects: %w", root, err)
+	}
+
+	if len(projects) == 0 {
+		if len(patterns) > 0 {
+			return nil, fmt.Errorf("no FTL projects under %s match %s", root, strings.Join(patterns, ", "))
+		}
+		return nil, fmt.Errorf("no FTL projects found under %s", root)
+	}
+	return projects, nil
+}
+
+// matchesProject reports whether a project path or its directory name
+// matches any of the patterns. Every project matches when there are none.
+func matchesProject(project string, patterns []string) bool {
+	if len(patterns) == 0 {
+		return true
+	}
+	for _, pattern := range patterns {
+		if ok, _ := path.Match(pattern, project); ok {
+			return true
+		}
+		if ok, _ := path.Match(pattern, path.Base(project)); ok {
+			return true
+		}
+	}
+	return false
+}
+
+// Allow overriding for tests
+var runProjectCommand = runProjectCommandImpl
+
+// runProjectCommandImpl runs ftl with args in dir and returns its combined
+// output and exit code
+func runProjectCommandImpl(ctx context.Context, dir string, args []string) ([]byte, int, error) {
+	executable, err := os.Executable()
+	if err != nil {
+		executable = "ftl"
+	}
+
+	cmd := exec.CommandContext(ctx, executable, args...) // #nosec G204 -- the ftl binary itself with a fixed subcommand
+	cmd.Dir = dir
+	var output bytes.Buffer
+	cmd.Stdout = &output
+	cmd.Stderr = &output
+
+	err = cmd.Run()
+	var exitErr *exec.ExitError
+	if errors.As(err, &exitErr) {
+		return output.Bytes(), exitErr.ExitCode(), nil
+	}
+	if err != nil {
+		return output.Bytes(), -1, err
+	}
+	return output.Bytes(), 0, nil
+}
+
+// runWorkspace runs ftl with args in each project, at most concurrency at a
+// time. Results are in the order of projects; report, when set, is called as
+// each project finishes.
+func runWorkspace(ctx context.Context, root string, projects []string, args []string, concurrency int, report func(wsResult)) []wsResult {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+
+	results := make([]wsResult, len(projects))
+	slots := make(chan struct{}, concurrency)
+	var wg sync.WaitGroup
+	var mu sync.Mutex
+
+	for i, project := range projects {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			slots <- struct{}{}
+			defer func() { <-slots }()
+
+			started := time.Now()
+			output, code, err := runProjectCommand(ctx, filepath.Join(root, filepath.FromSlash(project)), args)
+			result := wsResult{
+				Project:  project,
+				Status:   wsStatusOK,
+				ExitCode: code,
+				Duration: time.Since(started).Round(time.Millisecond).Seconds(),
+			}
+			if err != nil || code != 0 {
+				result.Status = wsStatusFailed
+				result.Output = string(output)
+				if err != nil {
+					result.Error = err.Error()
+				}
+			}
+			results[i] = result
+
+			if report != nil {
+				mu.Lock()
+				report(result)
+				mu.Unlock()
+			}
+		}()
+	}
+	wg.Wait()
+
+	return results
+}
+
+// reportWsResult prints a status line for a finished project
+func reportWsResult(r wsResult) {
+	if r.Status == wsStatusOK {
+		Success("%s (%.1fs)", r.Project, r.Duration)
+		return
+	}
+	Error("%s failed with exit code %d (%.1fs)", r.Project, r.ExitCode, r.Duration)
+}
+
+func writeWsResults(dw *DataWriter, results []wsResult) error {
+	failed := 0
+	for _, r := range results {
+		if r.Status != wsStatusOK {
+			failed++
+		}
+	}
+
+	if dw.format == OutputFormatJSON {
+		if err := dw.WriteStruct(results); err != nil {
+			return err
+		}
+	} else {
+		for _, r := range results {
+			if r.Status == wsStatusOK {
+				continue
+			}
+			_, _ = fmt.Fprintf(dw.output, "\n--- %s ---\n", r.Project)
+			if r.Error != "" {
+				_, _ = fmt.Fprintln(dw.output, r.Error)
+			}
+			_, _ = fmt.Fprint(dw.output, r.Output)
+		}
+		if failed > 0 {
+			_, _ = fmt.Fprintln(dw.output)
+		}
+
+		tb := NewTableBuilder("PROJECT", "STATUS", "EXIT CODE", "DURATION")
+		for _, r := range results {
+			tb.AddRow(r.Project, r.Status, strconv.Itoa(r.ExitCode), fmt.Sprintf("%.1fs", r.Duration))
+		}
+		if err := tb.Write(dw); err != nil {
+			return err
+		}
+	}
+
+	if failed > 0 {
+		return fmt.Errorf("%d of %d project(s) failed", failed, len(results))
+	}
+	if dw.format != OutputFormatJSON {
+		Success("All %d project(s) succeeded", len(results))
+	}
+	return nil
+}
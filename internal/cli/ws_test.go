@@ -0,0 +1,156 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/fatih/color"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func writeWorkspace(t *testing.T, files ...string) string {
+	t.Helper()
+	root := t.TempDir()
+	for _, file := range files {
+		full := filepath.Join(root, filepath.FromSlash(file))
+		require.NoError(t, os.MkdirAll(filepath.Dir(full), 0750))
+		require.NoError(t, os.WriteFile(full, []byte("name: app\n"), 0600))
+	}
+	return root
+}
+
+func TestDiscoverProjects(t *testing.T) {
+	root := writeWorkspace(t,
+		"apps/weather/ftl.yaml",
+		"apps/weather/components/forecast/Makefile",
+		"apps/search/ftl.json",
+		"tools/ocr/ftl.yml",
+		"tools/ocr/node_modules/dep/ftl.yaml",
+		".archive/old/ftl.yaml",
+		"docs/README.md",
+	)
+
+	projects, err := discoverProjects(root, nil)
+	require.NoError(t, err)
+	assert.Equal(t, []string{"apps/search", "apps/weather", "tools/ocr"}, projects)
+
+	projects, err = discoverProjects(root, []string{"apps/*"})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"apps/search", "apps/weather"}, projects)
+
+	// Patterns also match the directory name
+	projects, err = discoverProjects(root, []string{"ocr", "w*"})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"apps/weather", "tools/ocr"}, projects)
+
+	_, err = discoverProjects(root, []string{"billing-*"})
+	assert.ErrorContains(t, err, "no FTL projects under")
+
+	_, err = discoverProjects(root, []string{"apps/["})
+	assert.ErrorContains(t, err, "invalid --projects pattern")
+
+	_, err = discoverProjects(t.TempDir(), nil)
+	assert.ErrorContains(t, err, "no FTL projects found")
+}
+
+func TestDiscoverProjects_RootProject(t *testing.T) {
+	root := writeWorkspace(t, "ftl.yaml")
+
+	projects, err := discoverProjects(root, nil)
+	require.NoError(t, err)
+	assert.Equal(t, []string{"."}, projects)
+}
+
+func TestRunWorkspace(t *testing.T) {
+	original := runProjectCommand
+	defer func() { runProjectCommand = original }()
+
+	var mu sync.Mutex
+	running, maxRunning := 0, 0
+	var dirs []string
+	runProjectCommand = func(ctx context.Context, dir string, args []string) ([]byte, int, error) {
+		mu.Lock()
+		running++
+		maxRunning = max(maxRunning, running)
+		dirs = append(dirs, dir)
+		mu.Unlock()
+
+		time.Sleep(20 * time.Millisecond)
+
+		mu.Lock()
+		running--
+		mu.Unlock()
+
+		assert.Equal(t, []string{"test", "--component", "api"}, args)
+		switch filepath.Base(dir) {
+		case "search":
+			return []byte("FAIL search_test\n"), 2, nil
+		case "ocr":
+			return nil, -1, fmt.Errorf("exec: not found")
+		}
+		return []byte("ok\n"), 0, nil
+	}
+
+	projects := []string{"apps/billing", "apps/search", "apps/weather", "tools/ocr"}
+	var reported []string
+	results := runWorkspace(context.Background(), "/repo", projects, []string{"test", "--component", "api"}, 2, func(r wsResult) {
+		reported = append(reported, r.Project)
+	})
+
+	assert.LessOrEqual(t, maxRunning, 2)
+	assert.Len(t, dirs, 4)
+	assert.Contains(t, dirs, filepath.Join("/repo", "apps", "weather"))
+	assert.ElementsMatch(t, projects, reported)
+
+	require.Len(t, results, 4)
+	for i, project := range projects {
+		assert.Equal(t, project, results[i].Project)
+	}
+	assert.Equal(t, wsStatusOK, results[0].Status)
+	assert.Empty(t, results[0].Output)
+	assert.Equal(t, wsStatusFailed, results[1].Status)
+	assert.Equal(t, 2, results[1].ExitCode)
+	assert.Equal(t, "FAIL search_test\n", results[1].Output)
+	assert.Equal(t, wsStatusFailed, results[3].Status)
+	assert.Equal(t, "exec: not found", results[3].Error)
+}
+
+func TestWriteWsResults(t *testing.T) {
+	color.NoColor = true
+	defer func() { color.NoColor = false }()
+
+	results := []wsResult{
+		{Project: "apps/search", Status: wsStatusFailed, ExitCode: 2, Duration: 1.3, Output: "FAIL search_test\n"},
+		{Project: "apps/weather", Status: wsStatusOK, Duration: 0.5},
+	}
+
+	var buf bytes.Buffer
+	err := writeWsResults(NewDataWriter(&buf, "table"), results)
+	assert.EqualError(t, err, "1 of 2 project(s) failed")
+	out := buf.String()
+	assert.Contains(t, out, "--- apps/search ---\nFAIL search_test")
+	assert.Contains(t, out, "PROJECT")
+	assert.Contains(t, out, "EXIT CODE")
+	assert.Contains(t, out, "apps/weather")
+	assert.Contains(t, out, "1.3s")
+
+	buf.Reset()
+	err = writeWsResults(NewDataWriter(&buf, "json"), results)
+	assert.Error(t, err)
+	var decoded []map[string]interface{}
+	require.NoError(t, json.Unmarshal(buf.Bytes(), &decoded))
+	require.Len(t, decoded, 2)
+	assert.Equal(t, float64(2), decoded[0]["exit_code"])
+	assert.NotContains(t, decoded[1], "output")
+
+	buf.Reset()
+	assert.NoError(t, writeWsResults(NewDataWriter(&buf, "table"), results[1:]))
+}
@@ -0,0 +1,241 @@
+// Package clientgen generates typed client libraries for the tools an FTL
+// application serves, from the tool metadata listed by its MCP gateway
+package clientgen
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+)
+
+// Language is a target language of the generated client
+type Language string
+
+const (
+	Rust       Language = "rust"
+	TypeScript Language = "typescript"
+)
+
+// Tool is the metadata of a tool as returned by tools/list
+type Tool struct {
+	Name         string          `json:"name"`
+	Title        string          `json:"title,omitempty"`
+	Description  string          `json:"description,omitempty"`
+	InputSchema  json.RawMessage `json:"inputSchema,omitempty"`
+	OutputSchema json.RawMessage `json:"outputSchema,omitempty"`
+	Annotations  json.RawMessage `json:"annotations,omitempty"`
+}
+
+// Options configure the generated library
+type Options struct {
+	Language Language
+	// Package is the name of the generated package or crate
+	Package string
+}
+
+// File is a generated file, relative to the output directory
+type File struct {
+	Path    string
+	Content []byte
+}
+
+// Generate returns the files of a client library for the tools
+func Generate(opts Options, tools []Tool) ([]File, error) {
+	if opts.Package == "" {
+		return nil, fmt.Errorf("package name is required")
+	}
+
+	sorted := append([]Tool(nil), tools...)
+	sort.Slice(sorted, func(i, j int) bool { return sorted[i].Name < sorted[j].Name })
+
+	parsed := make([]tool, 0, len(sorted))
+	for _, t := range sorted {
+		input, err := parseSchema(t.InputSchema)
+		if err != nil {
+			return nil, fmt.Errorf("invalid input schema of tool %s: %w", t.Name, err)
+		}
+		output, err := parseSchema(t.OutputSchema)
+		if err != nil {
+			return nil, fmt.Errorf("invalid output schema of tool %s: %w", t.Name, err)
+		}
+		parsed = append(parsed, tool{Tool: t, input: input, output: output})
+	}
+
+	switch opts.Language {
+	case Rust:
+		return generateRust(opts.Package, parsed), nil
+	case TypeScript:
+		return generateTypeScript(opts.Package, parsed), nil
+	default:
+		return nil, fmt.Errorf("unsupported language %q (supported: rust, typescript)", opts.Language)
+	}
+}
+
+// tool is a tool with its parsed schemas
+type tool struct {
+	Tool
+	input  *schema
+	output *schema
+}
+
+// description is the tool description, falling back to its title
+func (t tool) description() string {
+	if t.Description != "" {
+		return t.Description
+	}
+	return t.Title
+}
+
+// schema is the subset of JSON Schema mapped to client types
+type schema struct {
+	Type        json.RawMessage    `json:"type,omitempty"`
+	Description string             `json:"description,omitempty"`
+	Properties  map[string]*schema `json:"properties,omitempty"`
+	Required    []string           `json:"required,omitempty"`
+	Items       *schema            `json:"items,omitempty"`
+	Enum        []json.RawMessage  `json:"enum,omitempty"`
+}
+
+func parseSchema(raw json.RawMessage) (*schema, error) {
+	if len(raw) == 0 || string(raw) == "null" {
+		return nil, nil
+	}
+	var s schema
+	if err := json.Unmarshal(raw, &s); err != nil {
+		return nil, err
+	}
+	return &s, nil
+}
+
+// kind returns the JSON type of the schema, ignoring "null" in type unions.
+// Schemas without a single type map to a dynamic JSON value.
+func (s *schema) kind() string {
+	if s == nil {
+		return ""
+	}
+
+	var single string
+	if err := json.Unmarshal(s.Type, &single); err == nil {
+		return single
+	}
+
+	var union []string
+	if err := json.Unmarshal(s.Type, &union); err != nil {
+		if len(s.Properties) > 0 {
+			return "object"
+		}
+		return ""
+	}
+	kind := ""
+	for _, t := range union {
+		if t == "null" {
+			continue
+		}
+		if kind != "" {
+			return ""
+		}
+		kind = t
+	}
+	return kind
+}
+
+// hasProperties reports whether the schema is an object with declared properties
+func (s *schema) hasProperties() bool {
+	return s.kind() == "object" && len(s.Properties) > 0
+}
+
+// propertyNames returns the property names in a stable order
+func (s *schema) propertyNames() []string {
+	names := make([]string, 0, len(s.Properties))
+	for name := range s.Properties {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+func (s *schema) isRequired(name string) bool {
+	for _, r := range s.Required {
+		if r == name {
+			return true
+		}
+	}
+	return false
+}
+
+// words splits an identifier like "weather__get_forecast" or "maxResults" into lowercase words
+func words(name string) []string {
+	var (
+		out     []string
+		current []rune
+	)
+	flush := func() {
+		if len(current) > 0 {
+			out = append(out, strings.ToLower(string(current)))
+			current = nil
+		}
+	}
+
+	runes := []rune(name)
+	for i, r := range runes {
+		switch {
+		case !unicode.IsLetter(r) && !unicode.IsDigit(r):
+			flush()
+		case unicode.IsUpper(r) && i > 0 && (unicode.IsLower(runes[i-1]) || unicode.IsDigit(runes[i-1])):
+			flush()
+			current = append(current, r)
+		default:
+			current = append(current, r)
+		}
+	}
+	flush()
+
+	if len(out) == 0 {
+		return []string{"value"}
+	}
+	return out
+}
+
+func snakeCase(name string) string {
+	return strings.Join(words(name), "_")
+}
+
+func pascalCase(name string) string {
+	var b strings.Builder
+	for _, w := range words(name) {
+		b.WriteString(strings.ToUpper(w[:1]) + w[1:])
+	}
+	s := b.String()
+	if unicode.IsDigit(rune(s[0])) {
+		s = "T" + s
+	}
+	return s
+}
+
+func camelCase(name string) string {
+	p := pascalCase(name)
+	return strings.ToLower(p[:1]) + p[1:]
+}
+
+// typeNames hands out unique type names
+type typeNames map[string]bool
+
+func (n typeNames) unique(name string) string {
+	candidate := name
+	for i := 2; n[candidate]; i++ {
+		candidate = fmt.Sprintf("%s%d", name, i)
+	}
+	n[candidate] = true
+	return candidate
+}
+
+// docLines splits a description into comment lines
+func docLines(text string) []string {
+	text = strings.TrimSpace(text)
+	if text == "" {
+		return nil
+	}
+	return strings.Split(text, "\n")
+}
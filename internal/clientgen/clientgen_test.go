@@ -0,0 +1,117 @@
+package clientgen
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+var testTools = []Tool{
+	{
+		Name:        "weather__get_forecast",
+		Description: "Get the forecast for a city",
+		InputSchema: json.RawMessage(`{
+			"type": "object",
+			"properties": {
+				"city": {"type": "string", "description": "City name"},
+				"days": {"type": ["integer", "null"]},
+				"units": {"type": "string", "enum": ["metric", "imperial"]},
+				"location": {"type": "object", "properties": {"lat": {"type": "number"}, "lon": {"type": "number"}}, "required": ["lat", "lon"]},
+				"type": {"type": "string"}
+			},
+			"required": ["city"]
+		}`),
+		OutputSchema: json.RawMessage(`{
+			"type": "object",
+			"properties": {"temperatures": {"type": "array", "items": {"type": "number"}}}
+		}`),
+	},
+	{
+		Name:        "echo__echo",
+		InputSchema: json.RawMessage(`{"type": "object", "properties": {"message": {"type": "string"}}, "required": ["message"]}`),
+	},
+	{
+		Name:        "echo__ping",
+		InputSchema: json.RawMessage(`{"type": "object"}`),
+	},
+}
+
+func files(t *testing.T, opts Options) map[string]string {
+	t.Helper()
+	generated, err := Generate(opts, testTools)
+	require.NoError(t, err)
+
+	out := make(map[string]string, len(generated))
+	for _, f := range generated {
+		out[f.Path] = string(f.Content)
+	}
+	return out
+}
+
+func TestGenerateRust(t *testing.T) {
+	out := files(t, Options{Language: Rust, Package: "weather-client"})
+	require.Contains(t, out, "Cargo.toml")
+	assert.Contains(t, out["Cargo.toml"], `name = "weather-client"`)
+
+	lib := out["src/lib.rs"]
+	assert.Contains(t, lib, "pub struct WeatherGetForecastInput {")
+	assert.Contains(t, lib, "    /// City name\n    pub city: String,")
+	assert.Contains(t, lib, "pub days: Option<i64>,")
+	assert.Contains(t, lib, "pub location: Option<WeatherGetForecastInputLocation>,")
+	assert.Contains(t, lib, "    pub lat: f64,")
+	assert.Contains(t, lib, `#[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]`+"\n    pub r#type: Option<String>,")
+	assert.Contains(t, lib, "pub temperatures: Option<Vec<f64>>,")
+
+	// Nested types are defined before the types using them
+	assert.Less(t, strings.Index(lib, "pub struct WeatherGetForecastInputLocation"), strings.Index(lib, "pub struct WeatherGetForecastInput {"))
+
+	assert.Contains(t, lib, "pub async fn echo_echo(&self, input: &EchoEchoInput) -> Result<ToolResult, Error> {")
+	assert.Contains(t, lib, "pub async fn echo_ping(&self) -> Result<ToolResult, Error> {")
+	assert.Contains(t, lib, "pub async fn weather_get_forecast(&self, input: &WeatherGetForecastInput) -> Result<WeatherGetForecastOutput, Error> {")
+	assert.Contains(t, lib, "    /// Get the forecast for a city\n    ///\n    /// Calls the `weather__get_forecast` tool.")
+}
+
+func TestGenerateTypeScript(t *testing.T) {
+	out := files(t, Options{Language: TypeScript, Package: "@acme/weather-client"})
+	assert.Contains(t, out["package.json"], `"name": "@acme/weather-client"`)
+	require.Contains(t, out, "tsconfig.json")
+
+	index := out["src/index.ts"]
+	assert.Contains(t, index, "export interface WeatherGetForecastInput {")
+	assert.Contains(t, index, "  /**\n   * City name\n   */\n  city: string;")
+	assert.Contains(t, index, "  days?: number;")
+	assert.Contains(t, index, `  units?: "metric" | "imperial";`)
+	assert.Contains(t, index, "  location?: WeatherGetForecastInputLocation;")
+	assert.Contains(t, index, "  temperatures?: number[];")
+
+	assert.Contains(t, index, "  async echoEcho(input: EchoEchoInput): Promise<ToolResult> {")
+	assert.Contains(t, index, `    return this.callTool("echo__ping", {});`)
+	assert.Contains(t, index, "  async weatherGetForecast(input: WeatherGetForecastInput): Promise<WeatherGetForecastOutput> {")
+}
+
+func TestGenerateErrors(t *testing.T) {
+	_, err := Generate(Options{Language: "java", Package: "client"}, testTools)
+	assert.ErrorContains(t, err, `unsupported language "java"`)
+
+	_, err = Generate(Options{Language: Rust}, testTools)
+	assert.ErrorContains(t, err, "package name is required")
+
+	_, err = Generate(Options{Language: Rust, Package: "client"}, []Tool{{Name: "bad", InputSchema: json.RawMessage(`[1]`)}})
+	assert.ErrorContains(t, err, "invalid input schema of tool bad")
+}
+
+func TestNames(t *testing.T) {
+	assert.Equal(t, "weather_get_forecast", snakeCase("weather__getForecast"))
+	assert.Equal(t, "MaxResults", pascalCase("max-results"))
+	assert.Equal(t, "T3dModel", pascalCase("3d_model"))
+	assert.Equal(t, "echoEcho", camelCase("echo__echo"))
+	assert.Equal(t, "r#type", rustIdent("type"))
+	assert.Equal(t, `"max-results"`, tsProperty("max-results"))
+
+	names := typeNames{}
+	assert.Equal(t, "Input", names.unique("Input"))
+	assert.Equal(t, "Input2", names.unique("Input"))
+}
@@ -0,0 +1,284 @@
+package clientgen
+
+import (
+	"fmt"
+	"strings"
+)
+
+// rustKeywords are the identifiers that must be written as raw identifiers
+var rustKeywords = map[string]bool{
+	"as": true, "async": true, "await": true, "break": true, "const": true, "continue": true,
+	"crate": true, "dyn": true, "else": true, "enum": true, "extern": true, "false": true,
+	"fn": true, "for": true, "if": true, "impl": true, "in": true, "let": true, "loop": true,
+	"match": true, "mod": true, "move": true, "mut": true, "pub": true, "ref": true,
+	"return": true, "static": true, "struct": true, "trait": true, "true": true,
+	"type": true, "unsafe": true, "use": true, "where": true, "while": true, "abstract": true,
+	"become": true, "box": true, "do": true, "final": true, "macro": true, "override": true,
+	"priv": true, "typeof": true, "unsized": true, "virtual": true, "yield": true, "try": true,
+	"gen": true,
+}
+
+// rustIdent returns a snake_case field or method name
+func rustIdent(name string) string {
+	ident := snakeCase(name)
+	if ident[0] >= '0' && ident[0] <= '9' {
+		ident = "_" + ident
+	}
+	if rustKeywords[ident] {
+		return "r#" + ident
+	}
+	return ident
+}
+
+type rustGen struct {
+	types typeNames
+	defs  strings.Builder
+}
+
+// typeOf returns the Rust type of a schema, defining structs for objects with properties
+func (g *rustGen) typeOf(s *schema, name string) string {
+	switch s.kind() {
+	case "string":
+		return "String"
+	case "integer":
+		return "i64"
+	case "number":
+		return "f64"
+	case "boolean":
+		return "bool"
+	case "array":
+		return "Vec<" + g.typeOf(s.Items, name+"Item") + ">"
+	case "object":
+		if !s.hasProperties() {
+			return "serde_json::Map<String, serde_json::Value>"
+		}
+		return g.structDef(s, name)
+	default:
+		return "serde_json::Value"
+	}
+}
+
+// structDef defines a struct for an object schema and returns its name
+func (g *rustGen) structDef(s *schema, name string) string {
+	name = g.types.unique(name)
+
+	var b strings.Builder
+	for _, line := range docLines(s.Description) {
+		fmt.Fprintf(&b, "/// %s\n", line)
+	}
+	b.WriteString("#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]\n")
+	fmt.Fprintf(&b, "pub struct %s {\n", name)
+	for _, prop := range s.propertyNames() {
+		field := s.Properties[prop]
+		ident := rustIdent(prop)
+		typ := g.typeOf(field, name+pascalCase(prop))
+
+		for _, line := range docLines(field.Description) {
+			fmt.Fprintf(&b, "    /// %s\n", line)
+		}
+		var attrs []string
+		if strings.TrimPrefix(ident, "r#") != prop {
+			attrs = append(attrs, fmt.Sprintf("rename = %q", prop))
+		}
+		if !s.isRequired(prop) {
+			attrs = append(attrs, `default`, `skip_serializing_if = "Option::is_none"`)
+			typ = "Option<" + typ + ">"
+		}
+		if len(attrs) > 0 {
+			fmt.Fprintf(&b, "    #[serde(%s)]\n", strings.Join(attrs, ", "))
+		}
+		fmt.Fprintf(&b, "    pub %s: %s,\n", ident, typ)
+	}
+	b.WriteString("}\n\n")
+
+	// Nested types are defined while resolving the fields, before this one
+	g.defs.WriteString(b.String())
+	return name
+}
+
+func generateRust(pkg string, tools []tool) []File {
+	g := &rustGen{types: typeNames{"Client": true, "Error": true, "ToolResult": true}}
+	methodNames := typeNames{"new": true, "with_token": true, "call_tool": true}
+
+	var methods strings.Builder
+	for _, t := range tools {
+		method := methodNames.unique(rustIdent(t.Name))
+		base := pascalCase(t.Name)
+
+		methods.WriteString("\n")
+		for _, line := range docLines(t.description()) {
+			fmt.Fprintf(&methods, "    /// %s\n", line)
+		}
+		if len(docLines(t.description())) > 0 {
+			methods.WriteString("    ///\n")
+		}
+		fmt.Fprintf(&methods, "    /// Calls the `%s` tool.\n", t.Name)
+
+		params, args := "", "serde_json::Value::Object(serde_json::Map::new())"
+		if t.input.hasProperties() {
+			params = ", input: &" + g.structDef(t.input, base+"Input")
+			args = "serde_json::to_value(input)?"
+		}
+
+		if t.output.hasProperties() {
+			output := g.structDef(t.output, base+"Output")
+			fmt.Fprintf(&methods, "    pub async fn %s(&self%s) -> Result<%s, Error> {\n", method, params, output)
+			fmt.Fprintf(&methods, "        self.call_tool(%q, %s).await?.structured()\n", t.Name, args)
+		} else {
+			fmt.Fprintf(&methods, "    pub async fn %s(&self%s) -> Result<ToolResult, Error> {\n", method, params)
+			fmt.Fprintf(&methods, "        self.call_tool(%q, %s).await\n", t.Name, args)
+		}
+		methods.WriteString("    }\n")
+	}
+
+	lib := fmt.Sprintf(rustLibTemplate, g.defs.String(), methods.String())
+	cargo := fmt.Sprintf(rustCargoTemplate, pkg)
+
+	return []File{
+		{Path: "Cargo.toml", Content: []byte(cargo)},
+		{Path: "src/lib.rs", Content: []byte(lib)},
+	}
+}
+
+const rustCargoTemplate = `# Generated by ftl generate client. Do not edit.
+[package]
+name = %q
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+reqwest = { version = "0.12", default-features = false, features = ["json", "rustls-tls"] }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+thiserror = "2"
+`
+
+const rustLibTemplate = `//! Typed client for the MCP tools of an FTL application.
+//!
+//! Generated by ftl generate client from the tool schemas. Do not edit,
+//! regenerate the client when the tools change.
+
+#![allow(clippy::all)]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Errors returned by tool calls
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("JSON-RPC error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("tool error: {0}")]
+    Tool(String),
+    #[error("tool returned no structured content")]
+    MissingStructuredContent,
+}
+
+/// Result of a tool call
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolResult {
+    #[serde(default)]
+    pub content: Vec<serde_json::Value>,
+    #[serde(
+        rename = "structuredContent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub structured_content: Option<serde_json::Value>,
+    #[serde(rename = "isError", default)]
+    pub is_error: bool,
+}
+
+impl ToolResult {
+    /// The text content blocks, joined by newlines
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|block| block.get("text").and_then(|text| text.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Deserialize the structured content
+    pub fn structured<T: DeserializeOwned>(self) -> Result<T, Error> {
+        let value = self
+            .structured_content
+            .ok_or(Error::MissingStructuredContent)?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+%s/// Client for the MCP endpoint of the application
+pub struct Client {
+    http: reqwest::Client,
+    endpoint: String,
+    token: Option<String>,
+    next_id: AtomicU64,
+}
+
+impl Client {
+    /// Create a client for an MCP endpoint, e.g. ` + "`https://my-app.example.com/mcp`" + `
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            endpoint: endpoint.into(),
+            token: None,
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Send a bearer token with every request
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Call a tool by name with raw JSON arguments
+    pub async fn call_tool(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<ToolResult, Error> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id.fetch_add(1, Ordering::Relaxed),
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments },
+        });
+
+        let mut builder = self
+            .http
+            .post(&self.endpoint)
+            .header("accept", "application/json")
+            .json(&request);
+        if let Some(token) = &self.token {
+            builder = builder.bearer_auth(token);
+        }
+        let response: serde_json::Value = builder.send().await?.error_for_status()?.json().await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(Error::Rpc {
+                code: error.get("code").and_then(|c| c.as_i64()).unwrap_or_default(),
+                message: error
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+
+        let result: ToolResult =
+            serde_json::from_value(response.get("result").cloned().unwrap_or_default())?;
+        if result.is_error {
+            return Err(Error::Tool(result.text()));
+        }
+        Ok(result)
+    }
+%s}
+`
@@ -0,0 +1,278 @@
+package clientgen
+
+import (
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"strings"
+)
+
+var tsIdentPattern = regexp.MustCompile(`^[A-Za-z_$][A-Za-z0-9_$]*$`)
+
+// tsProperty quotes property names that are not valid identifiers
+func tsProperty(name string) string {
+	if tsIdentPattern.MatchString(name) {
+		return name
+	}
+	return fmt.Sprintf("%q", name)
+}
+
+type tsGen struct {
+	types typeNames
+	defs  strings.Builder
+}
+
+// typeOf returns the TypeScript type of a schema, defining interfaces for objects with properties
+func (g *tsGen) typeOf(s *schema, name string) string {
+	if literals := g.enumLiterals(s); literals != "" {
+		return literals
+	}
+
+	switch s.kind() {
+	case "string":
+		return "string"
+	case "integer", "number":
+		return "number"
+	case "boolean":
+		return "boolean"
+	case "array":
+		item := g.typeOf(s.Items, name+"Item")
+		if strings.Contains(item, " ") {
+			return "Array<" + item + ">"
+		}
+		return item + "[]"
+	case "object":
+		if !s.hasProperties() {
+			return "Record<string, unknown>"
+		}
+		return g.interfaceDef(s, name)
+	default:
+		return "unknown"
+	}
+}
+
+// enumLiterals returns a union of the enum values of a schema, or "" when it has none
+func (g *tsGen) enumLiterals(s *schema) string {
+	if s == nil || len(s.Enum) == 0 {
+		return ""
+	}
+	literals := make([]string, 0, len(s.Enum))
+	for _, v := range s.Enum {
+		var scalar interface{}
+		if err := json.Unmarshal(v, &scalar); err != nil {
+			return ""
+		}
+		switch scalar.(type) {
+		case string, float64, bool, nil:
+			literals = append(literals, string(v))
+		default:
+			return ""
+		}
+	}
+	return strings.Join(literals, " | ")
+}
+
+// interfaceDef defines an interface for an object schema and returns its name
+func (g *tsGen) interfaceDef(s *schema, name string) string {
+	name = g.types.unique(name)
+
+	var b strings.Builder
+	writeTSDoc(&b, "", s.Description)
+	fmt.Fprintf(&b, "export interface %s {\n", name)
+	for _, prop := range s.propertyNames() {
+		field := s.Properties[prop]
+		typ := g.typeOf(field, name+pascalCase(prop))
+
+		writeTSDoc(&b, "  ", field.Description)
+		optional := ""
+		if !s.isRequired(prop) {
+			optional = "?"
+		}
+		fmt.Fprintf(&b, "  %s%s: %s;\n", tsProperty(prop), optional, typ)
+	}
+	b.WriteString("}\n\n")
+
+	// Nested types are defined while resolving the fields, before this one
+	g.defs.WriteString(b.String())
+	return name
+}
+
+func writeTSDoc(b *strings.Builder, indent, text string) {
+	lines := docLines(text)
+	if len(lines) == 0 {
+		return
+	}
+	fmt.Fprintf(b, "%s/**\n", indent)
+	for _, line := range lines {
+		fmt.Fprintf(b, "%s * %s\n", indent, strings.ReplaceAll(line, "*/", "*\\/"))
+	}
+	fmt.Fprintf(b, "%s */\n", indent)
+}
+
+func generateTypeScript(pkg string, tools []tool) []File {
+	g := &tsGen{types: typeNames{"Client": true, "ClientOptions": true, "ToolResult": true, "ToolError": true}}
+	methodNames := typeNames{"callTool": true, "constructor": true}
+
+	var methods strings.Builder
+	for _, t := range tools {
+		method := methodNames.unique(camelCase(t.Name))
+		base := pascalCase(t.Name)
+
+		doc := t.description()
+		if doc != "" {
+			doc += "\n\n"
+		}
+		methods.WriteString("\n")
+		writeTSDoc(&methods, "  ", doc+fmt.Sprintf("Calls the `%s` tool.", t.Name))
+
+		params, args := "", "{}"
+		if t.input.hasProperties() {
+			params = "input: " + g.interfaceDef(t.input, base+"Input")
+			args = "input"
+		}
+
+		if t.output.hasProperties() {
+			output := g.interfaceDef(t.output, base+"Output")
+			fmt.Fprintf(&methods, "  async %s(%s): Promise<%s> {\n", method, params, output)
+			fmt.Fprintf(&methods, "    const result = await this.callTool(%q, %s);\n", t.Name, args)
+			fmt.Fprintf(&methods, "    if (result.structuredContent === undefined) {\n")
+			fmt.Fprintf(&methods, "      throw new ToolError(%q, \"tool returned no structured content\");\n", t.Name)
+			fmt.Fprintf(&methods, "    }\n")
+			fmt.Fprintf(&methods, "    return result.structuredContent as %s;\n", output)
+		} else {
+			fmt.Fprintf(&methods, "  async %s(%s): Promise<ToolResult> {\n", method, params)
+			fmt.Fprintf(&methods, "    return this.callTool(%q, %s);\n", t.Name, args)
+		}
+		methods.WriteString("  }\n")
+	}
+
+	index := fmt.Sprintf(tsIndexTemplate, g.defs.String(), methods.String())
+	pkgJSON := fmt.Sprintf(tsPackageTemplate, pkg)
+
+	return []File{
+		{Path: "package.json", Content: []byte(pkgJSON)},
+		{Path: "tsconfig.json", Content: []byte(tsConfigTemplate)},
+		{Path: "src/index.ts", Content: []byte(index)},
+	}
+}
+
+const tsPackageTemplate = `{
+  "name": %q,
+  "version": "0.1.0",
+  "description": "Typed client for the MCP tools of an FTL application (generated by ftl generate client)",
+  "type": "module",
+  "main": "dist/index.js",
+  "types": "dist/index.d.ts",
+  "files": ["dist"],
+  "scripts": {
+    "build": "tsc"
+  },
+  "devDependencies": {
+    "typescript": "^5.5.0"
+  }
+}
+`
+
+const tsConfigTemplate = `{
+  "compilerOptions": {
+    "target": "ES2022",
+    "module": "ES2022",
+    "moduleResolution": "bundler",
+    "declaration": true,
+    "strict": true,
+    "outDir": "dist",
+    "rootDir": "src"
+  },
+  "include": ["src"]
+}
+`
+
+const tsIndexTemplate = `// Typed client for the MCP tools of an FTL application.
+//
+// Generated by ftl generate client from the tool schemas. Do not edit,
+// regenerate the client when the tools change.
+
+/**
+ * Result of a tool call
+ */
+export interface ToolResult {
+  content: Array<{ type: string; text?: string; [key: string]: unknown }>;
+  structuredContent?: unknown;
+  isError?: boolean;
+}
+
+/**
+ * Error returned by a tool or the MCP endpoint
+ */
+export class ToolError extends Error {
+  constructor(
+    readonly tool: string,
+    message: string,
+    readonly code?: number,
+  ) {
+    super(message);
+    this.name = "ToolError";
+  }
+}
+
+export interface ClientOptions {
+  /** Bearer token sent with every request */
+  token?: string;
+  /** fetch implementation, defaults to the global fetch */
+  fetch?: typeof fetch;
+}
+
+%s/**
+ * Client for the MCP endpoint of the application
+ */
+export class Client {
+  private nextId = 1;
+
+  /**
+   * @param endpoint MCP endpoint, e.g. https://my-app.example.com/mcp
+   */
+  constructor(
+    private readonly endpoint: string,
+    private readonly options: ClientOptions = {},
+  ) {}
+
+  /**
+   * Call a tool by name with raw arguments
+   */
+  async callTool(name: string, args: object): Promise<ToolResult> {
+    const headers: Record<string, string> = {
+      "content-type": "application/json",
+      accept: "application/json",
+    };
+    if (this.options.token) {
+      headers.authorization = ` + "`Bearer ${this.options.token}`" + `;
+    }
+
+    const fetchImpl = this.options.fetch ?? fetch;
+    const response = await fetchImpl(this.endpoint, {
+      method: "POST",
+      headers,
+      body: JSON.stringify({
+        jsonrpc: "2.0",
+        id: this.nextId++,
+        method: "tools/call",
+        params: { name, arguments: args },
+      }),
+    });
+    if (!response.ok) {
+      throw new ToolError(name, ` + "`request failed: ${response.status} ${response.statusText}`" + `);
+    }
+
+    const body = await response.json();
+    if (body.error) {
+      throw new ToolError(name, body.error.message, body.error.code);
+    }
+    const result = body.result as ToolResult;
+    if (result.isError) {
+      const text = result.content.map((block) => block.text ?? "").join("\n");
+      throw new ToolError(name, text);
+    }
+    return result;
+  }
+%s}
+`
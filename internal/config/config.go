@@ -20,7 +20,21 @@ type Config struct {
 	// Organizations stores metadata about known organizations
 	Organizations map[string]OrgInfo `json:"organizations,omitempty"`
 
-	// Preferences stores user preferences
+	// NetworkConfig configures offline mode, for restricted networks
+type NetworkConfig struct {
+	// Offline always runs offline, like --offline or FTL_OFFLINE=1
+	Offline bool `json:"offline,omitempty"`
+
+	// AutoDetect runs offline when the FTL API can't be reached within
+	// ProbeTimeout
+	AutoDetect bool `json:"auto_detect,omitempty"`
+
+	// ProbeTimeout is how long auto-detection waits for a connection
+	// (a duration such as "1s", default 1s)
+	ProbeTimeout string `json:"probe_timeout,omitempty"`
+}
+
+// Preferences stores user preferences
 	Preferences Preferences `json:"preferences,omitempty"`
 
 	// LastUpdateCheck tracks when we last checked for updates
@@ -29,6 +43,23 @@ type Config struct {
 	// CurrentUser stores info about the logged-in user
 	CurrentUser *UserInfo `json:"current_user,omitempty"`
 
+	// Tunnel configures the provider used by `ftl up --expose`
+	Tunnel *TunnelConfig `json:"tunnel,omitempty"`
+
+	// Telemetry configures local diagnostics recording
+	Telemetry *TelemetryConfig `json:"telemetry,omitempty"`
+
+	// Network configures offline mode
+	Network *NetworkConfig `json:"network,omitempty"`
+
+	// Registries configures the registries components are published to and
+	// pulled from. A project's .ftl/registries.toml overrides it.
+	Registries *RegistriesConfig `json:"registries,omitempty"`
+
+	// Defaults are flag defaults keyed by command path, in the same layout as
+	// the project config. They take precedence over the project's defaults.
+	Defaults map[string]interface{} `json:"defaults,omitempty"`
+
 	// Version of the config schema
 	Version string `json:"version"`
 }
@@ -50,6 +81,30 @@ type OrgInfo struct {
 	Environment string `json:"environment,omitempty"` // Default env for this org
 }
 
+// TunnelConfig selects and configures the tunnel provider for `ftl up --expose`
+type TunnelConfig struct {
+	// Provider is the tunnel provider name (default "cloudflared")
+	Provider string `json:"provider,omitempty"`
+
+	// Command is the command line for the "command" provider. The
+	// placeholders {addr}, {host} and {port} are replaced with the local address.
+	Command []string `json:"command,omitempty"`
+
+	// URLPattern is a regular expression matching the public URL in the
+	// command output (defaults to the first https:// URL)
+	URLPattern string `json:"url_pattern,omitempty"`
+}
+
+// TelemetryConfig configures local diagnostics recording
+type TelemetryConfig struct {
+	// Disabled stops recording, like setting FTL_TELEMETRY_DISABLED
+	Disabled bool `json:"disabled,omitempty"`
+
+	// OTLPEndpoint is an OTLP/HTTP collector command outcome events are
+	// exported to, like setting FTL_TELEMETRY_OTLP_ENDPOINT
+	OTLPEndpoint string `json:"otlp_endpoint,omitempty"`
+}
+
 // Preferences stores user preferences
 type Preferences struct {
 	// ColorOutput controls whether to use colored output
@@ -91,6 +146,21 @@ func configPath() (string, error) {
 	return filepath.Join(ftlDir, "config.json"), nil
 }
 
+// Path returns the location of the user config file
+func Path() (string, error) {
+	return configPath()
+}
+
+// Validate checks that the config file at path parses
+func Validate(path string) error {
+	data, err := os.ReadFile(path) // #nosec G304 - path is the user config file
+	if err != nil {
+		return err
+	}
+	var cfg Config
+	return json.Unmarshal(data, &cfg)
+}
+
 // Load loads the configuration from disk or creates a new one
 func Load() (*Config, error) {
 	var err error
@@ -128,8 +198,9 @@ func load() (*Config, error) {
 		return nil, fmt.Errorf("failed to read config: %w", err)
 	}
 
-	var cfg Config
-	if err := json.Unmarshal(data, &cfg); err != nil {
+	// Settings missing from the file keep their defaults
+	cfg := defaultConfig()
+	if err := json.Unmarshal(data, cfg); err != nil {
 		return nil, fmt.Errorf("failed to parse config: %w", err)
 	}
 
@@ -138,7 +209,7 @@ func load() (*Config, error) {
 		cfg.Organizations = make(map[string]OrgInfo)
 	}
 
-	return &cfg, nil
+	return cfg, nil
 }
 
 // defaultConfig returns a default configuration
@@ -193,6 +264,25 @@ func (c *Config) Save() error {
 	return nil
 }
 
+// GetRegistries returns the user's registry config, empty if there is none
+func (c *Config) GetRegistries() *RegistriesConfig {
+	mu.RLock()
+	defer mu.RUnlock()
+	if c.Registries == nil {
+		return &RegistriesConfig{}
+	}
+	return c.Registries
+}
+
+// SetRegistries replaces the user's registry config
+func (c *Config) SetRegistries(registries *RegistriesConfig) error {
+	mu.Lock()
+	c.Registries = registries
+	mu.Unlock()
+
+	return c.Save()
+}
+
 // GetCurrentOrg returns the currently selected organization
 func (c *Config) GetCurrentOrg() string {
 	mu.RLock()
@@ -0,0 +1,115 @@
+package config
+
+import (
+	"bytes"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/BurntSushi/toml"
+)
+
+// ProjectFile is the path of the project config, relative to the project root
+const ProjectFile = ".ftl/project.toml"
+
+// ProjectConfig holds command defaults shared by everyone working on a project.
+//
+// Each table is named after a command path and maps flag names to values:
+//
+//	[deploy]
+//	access-control = "private"
+//
+//	[component.list]
+//	registry = "ghcr.io/acme"
+type ProjectConfig struct {
+	// Path is the file the config was loaded from
+	Path string
+
+	// Defaults are the flag defaults, keyed by command path
+	Defaults map[string]interface{}
+}
+
+// FindProject returns the project config in dir or its closest parent
+// directory, or "" if there is none
+func FindProject(dir string) string {
+	return findProjectFile(dir, ProjectFile)
+}
+
+// findProjectFile returns the file at the project-relative path file in dir
+// or its closest parent directory, or "" if there is none
+func findProjectFile(dir, file string) string {
+	dir, err := filepath.Abs(dir)
+	if err != nil {
+		return ""
+	}
+	for {
+		path := filepath.Join(dir, file)
+		if info, err := os.Stat(path); err == nil && !info.IsDir() {
+			return path
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return ""
+		}
+		dir = parent
+	}
+}
+
+// LoadProject loads the project config found from dir. A project without a
+// config file returns an empty config.
+func LoadProject(dir string) (*ProjectConfig, error) {
+	path := FindProject(dir)
+	if path == "" {
+		return &ProjectConfig{}, nil
+	}
+
+	var defaults map[string]interface{}
+	if _, err := toml.DecodeFile(path, &defaults); err != nil {
+		return nil, fmt.Errorf("failed to parse %s: %w", path, err)
+	}
+	return &ProjectConfig{Path: path, Defaults: defaults}, nil
+}
+
+// Lookup returns the project default for a flag of the command at path
+// (e.g. ["component", "list"])
+func (p *ProjectConfig) Lookup(path []string, flag string) (interface{}, bool) {
+	return LookupDefault(p.Defaults, path, flag)
+}
+
+// LookupDefault finds the value of flag in the table for the command at path
+// within nested command defaults
+func LookupDefault(defaults map[string]interface{}, path []string, flag string) (interface{}, bool) {
+	table := defaults
+	for _, name := range path {
+		next, ok := table[name].(map[string]interface{})
+		if !ok {
+			return nil, false
+		}
+		table = next
+	}
+	value, ok := table[flag]
+	if !ok {
+		return nil, false
+	}
+	// A nested table is a subcommand, not a flag value
+	if _, isTable := value.(map[string]interface{}); isTable {
+		return nil, false
+	}
+	return value, true
+}
+
+// SaveProject writes command defaults to the project config at path
+func SaveProject(path string, defaults map[string]interface{}) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(defaults); err != nil {
+		return fmt.Errorf("failed to encode project config: %w", err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
@@ -0,0 +1,101 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func writeProjectFile(t *testing.T, dir, content string) string {
+	t.Helper()
+	path := filepath.Join(dir, ProjectFile)
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(path, []byte(content), 0600); err != nil {
+		t.Fatal(err)
+	}
+	return path
+}
+
+func TestLoadProject(t *testing.T) {
+	root := t.TempDir()
+	path := writeProjectFile(t, root, `
+[build]
+skip-synth = true
+
+[deploy]
+access-control = "private"
+allowed-roles = ["admin", "dev"]
+
+[component.list]
+registry = "ghcr.io/acme"
+limit = 5
+`)
+
+	// Found from a subdirectory of the project
+	sub := filepath.Join(root, "components", "echo")
+	if err := os.MkdirAll(sub, 0750); err != nil {
+		t.Fatal(err)
+	}
+
+	project, err := LoadProject(sub)
+	if err != nil {
+		t.Fatalf("LoadProject failed: %v", err)
+	}
+	if project.Path != path {
+		t.Errorf("Expected path %s, got %s", path, project.Path)
+	}
+
+	tests := []struct {
+		path []string
+		flag string
+		want interface{}
+	}{
+		{[]string{"build"}, "skip-synth", true},
+		{[]string{"deploy"}, "access-control", "private"},
+		{[]string{"component", "list"}, "registry", "ghcr.io/acme"},
+		{[]string{"component", "list"}, "limit", int64(5)},
+	}
+	for _, tt := range tests {
+		got, ok := project.Lookup(tt.path, tt.flag)
+		if !ok || got != tt.want {
+			t.Errorf("Lookup(%v, %s) = %v, %v; want %v", tt.path, tt.flag, got, ok, tt.want)
+		}
+	}
+
+	roles, ok := project.Lookup([]string{"deploy"}, "allowed-roles")
+	if list, isList := roles.([]interface{}); !ok || !isList || len(list) != 2 {
+		t.Errorf("Expected allowed-roles list, got %v", roles)
+	}
+
+	// Subcommand tables are not flag values
+	if _, ok := project.Lookup(nil, "component"); ok {
+		t.Error("Expected table to not be returned as a flag value")
+	}
+	if _, ok := project.Lookup([]string{"up"}, "build"); ok {
+		t.Error("Expected no default for a command without a table")
+	}
+}
+
+func TestLoadProjectMissing(t *testing.T) {
+	project, err := LoadProject(t.TempDir())
+	if err != nil {
+		t.Fatalf("LoadProject failed: %v", err)
+	}
+	if project.Path != "" {
+		t.Errorf("Expected no project path, got %s", project.Path)
+	}
+	if _, ok := project.Lookup([]string{"deploy"}, "access-control"); ok {
+		t.Error("Expected no defaults without a project config")
+	}
+}
+
+func TestLoadProjectInvalid(t *testing.T) {
+	root := t.TempDir()
+	writeProjectFile(t, root, "[deploy\naccess-control = ")
+
+	if _, err := LoadProject(root); err == nil {
+		t.Error("Expected an error for an invalid project config")
+	}
+}
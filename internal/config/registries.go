@@ -0,0 +1,110 @@
+package config
+
+import (
+	"bytes"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/BurntSushi/toml"
+)
+
+// RegistriesFile is the path of the project registry config, relative to the
+// project root
+const RegistriesFile = ".ftl/registries.toml"
+
+// RegistriesConfig configures the registries components are published to and
+// pulled from. In the project config:
+//
+//	default = "123456789.dkr.ecr.us-west-2.amazonaws.com"
+//
+//	[registry."123456789.dkr.ecr.us-west-2.amazonaws.com"]
+//	namespace = "acme"
+//	credential_helper = ["docker-credential-ecr-login"]
+type RegistriesConfig struct {
+	// Default is the registry components are published to without --registry
+	Default string `json:"default,omitempty" toml:"default,omitempty"`
+
+	// Registries configures registries by host
+	Registries map[string]RegistryConfig `json:"registries,omitempty" toml:"registry,omitempty"`
+}
+
+// RegistryConfig configures a registry
+type RegistryConfig struct {
+	// Namespace is the namespace components are published to without
+	// --namespace
+	Namespace string `json:"namespace,omitempty" toml:"namespace,omitempty"`
+
+	// CredentialHelper is the command line of a Docker credential helper
+	// returning the registry's credentials, used instead of docker login
+	CredentialHelper []string `json:"credential_helper,omitempty" toml:"credential_helper,omitempty"`
+}
+
+// FindProjectRegistries returns the project registry config in dir or its
+// closest parent directory, or "" if there is none
+func FindProjectRegistries(dir string) string {
+	return findProjectFile(dir, RegistriesFile)
+}
+
+// LoadProjectRegistries loads the project registry config found from dir,
+// returning it with its path. A project without one returns an empty config
+// and "".
+func LoadProjectRegistries(dir string) (*RegistriesConfig, string, error) {
+	path := FindProjectRegistries(dir)
+	if path == "" {
+		return &RegistriesConfig{}, "", nil
+	}
+
+	var registries RegistriesConfig
+	if _, err := toml.DecodeFile(path, &registries); err != nil {
+		return nil, "", fmt.Errorf("failed to parse %s: %w", path, err)
+	}
+	return &registries, path, nil
+}
+
+// SaveProjectRegistries writes a project registry config to path
+func SaveProjectRegistries(path string, registries *RegistriesConfig) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(registries); err != nil {
+		return fmt.Errorf("failed to encode registry config: %w", err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
+
+// Merge returns the registry config of c overridden by override: its default
+// registry when set, and each registry it configures as a whole
+func (c *RegistriesConfig) Merge(override *RegistriesConfig) *RegistriesConfig {
+	merged := &RegistriesConfig{Default: c.Default, Registries: map[string]RegistryConfig{}}
+	for host, registry := range c.Registries {
+		merged.Registries[host] = registry
+	}
+	if override == nil {
+		return merged
+	}
+	if override.Default != "" {
+		merged.Default = override.Default
+	}
+	for host, registry := range override.Registries {
+		merged.Registries[host] = registry
+	}
+	return merged
+}
+
+// CredentialHelpers returns the credential helper command line of each
+// registry that has one, by host
+func (c *RegistriesConfig) CredentialHelpers() map[string][]string {
+	helpers := map[string][]string{}
+	for host, registry := range c.Registries {
+		if len(registry.CredentialHelper) > 0 {
+			helpers[host] = registry.CredentialHelper
+		}
+	}
+	return helpers
+}
@@ -0,0 +1,118 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+)
+
+func TestLoadProjectRegistries(t *testing.T) {
+	root := t.TempDir()
+	path := filepath.Join(root, RegistriesFile)
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(path, []byte(`
+default = "123456789.dkr.ecr.us-west-2.amazonaws.com"
+
+[registry."123456789.dkr.ecr.us-west-2.amazonaws.com"]
+namespace = "acme"
+credential_helper = ["docker-credential-ecr-login"]
+`), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	// Found from a subdirectory of the project
+	sub := filepath.Join(root, "components", "echo")
+	if err := os.MkdirAll(sub, 0750); err != nil {
+		t.Fatal(err)
+	}
+
+	registries, found, err := LoadProjectRegistries(sub)
+	if err != nil {
+		t.Fatalf("LoadProjectRegistries failed: %v", err)
+	}
+	if found != path {
+		t.Errorf("Expected path %s, got %s", path, found)
+	}
+	want := &RegistriesConfig{
+		Default: "123456789.dkr.ecr.us-west-2.amazonaws.com",
+		Registries: map[string]RegistryConfig{
+			"123456789.dkr.ecr.us-west-2.amazonaws.com": {
+				Namespace:        "acme",
+				CredentialHelper: []string{"docker-credential-ecr-login"},
+			},
+		},
+	}
+	if !reflect.DeepEqual(registries, want) {
+		t.Errorf("Expected %+v, got %+v", want, registries)
+	}
+
+	// Round trips through SaveProjectRegistries
+	saved := filepath.Join(t.TempDir(), RegistriesFile)
+	if err := SaveProjectRegistries(saved, registries); err != nil {
+		t.Fatalf("SaveProjectRegistries failed: %v", err)
+	}
+	reloaded, _, err := LoadProjectRegistries(filepath.Dir(filepath.Dir(saved)))
+	if err != nil {
+		t.Fatalf("LoadProjectRegistries failed: %v", err)
+	}
+	if !reflect.DeepEqual(reloaded, want) {
+		t.Errorf("Expected %+v after saving, got %+v", want, reloaded)
+	}
+}
+
+func TestLoadProjectRegistriesMissing(t *testing.T) {
+	registries, path, err := LoadProjectRegistries(t.TempDir())
+	if err != nil {
+		t.Fatalf("LoadProjectRegistries failed: %v", err)
+	}
+	if path != "" || registries.Default != "" || len(registries.Registries) != 0 {
+		t.Errorf("Expected an empty config, got %+v at %q", registries, path)
+	}
+}
+
+func TestRegistriesMerge(t *testing.T) {
+	user := &RegistriesConfig{
+		Default: "ghcr.io",
+		Registries: map[string]RegistryConfig{
+			"ghcr.io":              {Namespace: "me"},
+			"registry.example.com": {Namespace: "me", CredentialHelper: []string{"docker-credential-pass"}},
+		},
+	}
+	project := &RegistriesConfig{
+		Registries: map[string]RegistryConfig{
+			"registry.example.com": {Namespace: "acme"},
+		},
+	}
+
+	merged := user.Merge(project)
+	if merged.Default != "ghcr.io" {
+		t.Errorf("Expected the user default without a project default, got %s", merged.Default)
+	}
+	// Project registries replace user registries as a whole
+	if got := merged.Registries["registry.example.com"]; got.Namespace != "acme" || len(got.CredentialHelper) != 0 {
+		t.Errorf("Expected the project registry, got %+v", got)
+	}
+	if got := merged.Registries["ghcr.io"]; got.Namespace != "me" {
+		t.Errorf("Expected the user registry, got %+v", got)
+	}
+	if user.Registries["registry.example.com"].Namespace != "me" {
+		t.Error("Expected Merge to leave the user config unchanged")
+	}
+
+	project.Default = "registry.example.com"
+	if merged := user.Merge(project); merged.Default != "registry.example.com" {
+		t.Errorf("Expected the project default, got %s", merged.Default)
+	}
+	if merged := user.Merge(nil); !reflect.DeepEqual(merged, user) {
+		t.Errorf("Expected the user config, got %+v", merged)
+	}
+
+	helpers := user.CredentialHelpers()
+	want := map[string][]string{"registry.example.com": {"docker-credential-pass"}}
+	if !reflect.DeepEqual(helpers, want) {
+		t.Errorf("Expected helpers %v, got %v", want, helpers)
+	}
+}
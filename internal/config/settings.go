@@ -0,0 +1,247 @@
+package config
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// DefaultsKey is the key prefix of command flag defaults
+// (e.g. "defaults.component.list.registry")
+const DefaultsKey = "defaults"
+
+// ValueType is the type of a setting's value
+type ValueType string
+
+// Setting value types
+const (
+	TypeString   ValueType = "string"
+	TypeBool     ValueType = "bool"
+	TypeInt      ValueType = "int"
+	TypeFloat    ValueType = "float"
+	TypeDuration ValueType = "duration"
+	TypeList     ValueType = "list"
+)
+
+// Setting describes a user config key that can be changed with `ftl config`
+type Setting struct {
+	Key         string
+	Type        ValueType
+	Description string
+}
+
+// Settings are the user config keys outside of command defaults
+var Settings = []Setting{
+	{Key: "default_environment", Type: TypeString, Description: "Default deployment environment"},
+	{Key: "preferences.color_output", Type: TypeBool, Description: "Use colored output"},
+	{Key: "preferences.verbose", Type: TypeBool, Description: "Verbose output"},
+	{Key: "preferences.auto_update", Type: TypeBool, Description: "Check for updates automatically"},
+	{Key: "preferences.confirm_deploy", Type: TypeBool, Description: "Confirm before deploying"},
+	{Key: "telemetry.disabled", Type: TypeBool, Description: "Stop recording local diagnostics"},
+	{Key: "telemetry.otlp_endpoint", Type: TypeString, Description: "OTLP/HTTP collector receiving command outcome events"},
+	{Key: "network.offline", Type: TypeBool, Description: "Always run offline"},
+	{Key: "network.auto_detect", Type: TypeBool, Description: "Run offline when the FTL API is unreachable"},
+	{Key: "network.probe_timeout", Type: TypeDuration, Description: "How long auto-detection waits for the network"},
+	{Key: "tunnel.provider", Type: TypeString, Description: "Tunnel provider for `ftl up --expose`"},
+	{Key: "tunnel.command", Type: TypeList, Description: "Command line of the \"command\" tunnel provider"},
+	{Key: "tunnel.url_pattern", Type: TypeString, Description: "Pattern matching the tunnel's public URL"},
+}
+
+// LookupSetting returns the setting for key
+func LookupSetting(key string) (Setting, bool) {
+	for _, setting := range Settings {
+		if setting.Key == key {
+			return setting, true
+		}
+	}
+	return Setting{}, false
+}
+
+// ParseValue converts a command line value to a value of type t. List values
+// may be given as several arguments or comma-separated.
+func ParseValue(t ValueType, args []string) (interface{}, error) {
+	if t == TypeList {
+		values := []interface{}{}
+		for _, arg := range args {
+			for _, item := range strings.Split(arg, ",") {
+				if item = strings.TrimSpace(item); item != "" {
+					values = append(values, item)
+				}
+			}
+		}
+		return values, nil
+	}
+
+	if len(args) != 1 {
+		return nil, fmt.Errorf("expected a single %s value", t)
+	}
+	raw := args[0]
+
+	switch t {
+	case TypeBool:
+		v, err := strconv.ParseBool(raw)
+		if err != nil {
+			return nil, fmt.Errorf("invalid bool %q", raw)
+		}
+		return v, nil
+	case TypeInt:
+		v, err := strconv.ParseInt(raw, 10, 64)
+		if err != nil {
+			return nil, fmt.Errorf("invalid integer %q", raw)
+		}
+		return v, nil
+	case TypeFloat:
+		v, err := strconv.ParseFloat(raw, 64)
+		if err != nil {
+			return nil, fmt.Errorf("invalid number %q", raw)
+		}
+		return v, nil
+	case TypeDuration:
+		if _, err := time.ParseDuration(raw); err != nil {
+			return nil, fmt.Errorf("invalid duration %q", raw)
+		}
+		return raw, nil
+	default:
+		return raw, nil
+	}
+}
+
+// FormatValue formats a value the way it is given on the command line
+func FormatValue(value interface{}) string {
+	switch v := value.(type) {
+	case []interface{}:
+		items := make([]string, 0, len(v))
+		for _, item := range v {
+			items = append(items, FormatValue(item))
+		}
+		return strings.Join(items, ",")
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64)
+	default:
+		return fmt.Sprint(v)
+	}
+}
+
+// GetKey returns the value at a dotted key in nested tables
+func GetKey(tree map[string]interface{}, key string) (interface{}, bool) {
+	parts := strings.Split(key, ".")
+	table := tree
+	for _, part := range parts[:len(parts)-1] {
+		next, ok := table[part].(map[string]interface{})
+		if !ok {
+			return nil, false
+		}
+		table = next
+	}
+	value, ok := table[parts[len(parts)-1]]
+	return value, ok
+}
+
+// SetKey sets the value at a dotted key, creating tables as needed
+func SetKey(tree map[string]interface{}, key string, value interface{}) error {
+	parts := strings.Split(key, ".")
+	table := tree
+	for i, part := range parts[:len(parts)-1] {
+		switch next := table[part].(type) {
+		case map[string]interface{}:
+			table = next
+		case nil:
+			created := map[string]interface{}{}
+			table[part] = created
+			table = created
+		default:
+			return fmt.Errorf("%s is not a table", strings.Join(parts[:i+1], "."))
+		}
+	}
+	table[parts[len(parts)-1]] = value
+	return nil
+}
+
+// UnsetKey removes the value at a dotted key along with any tables left
+// empty, and reports whether it was set
+func UnsetKey(tree map[string]interface{}, key string) bool {
+	parts := strings.SplitN(key, ".", 2)
+	if len(parts) == 1 {
+		_, ok := tree[key]
+		delete(tree, key)
+		return ok
+	}
+	table, ok := tree[parts[0]].(map[string]interface{})
+	if !ok || !UnsetKey(table, parts[1]) {
+		return false
+	}
+	if len(table) == 0 {
+		delete(tree, parts[0])
+	}
+	return true
+}
+
+// Flatten returns the leaf values of nested tables keyed by dotted key
+func Flatten(tree map[string]interface{}) map[string]interface{} {
+	flat := map[string]interface{}{}
+	var walk func(prefix string, table map[string]interface{})
+	walk = func(prefix string, table map[string]interface{}) {
+		for name, value := range table {
+			key := prefix + name
+			if nested, ok := value.(map[string]interface{}); ok {
+				walk(key+".", nested)
+				continue
+			}
+			flat[key] = value
+		}
+	}
+	walk("", tree)
+	return flat
+}
+
+// SortedKeys returns the keys of values in order
+func SortedKeys(values map[string]interface{}) []string {
+	keys := make([]string, 0, len(values))
+	for key := range values {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+// Values returns the config as nested tables keyed like its JSON file
+func (c *Config) Values() (map[string]interface{}, error) {
+	mu.RLock()
+	data, err := json.Marshal(c)
+	mu.RUnlock()
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal config: %w", err)
+	}
+
+	var tree map[string]interface{}
+	if err := json.Unmarshal(data, &tree); err != nil {
+		return nil, fmt.Errorf("failed to read config: %w", err)
+	}
+	return tree, nil
+}
+
+// SetValues replaces the config with values in the layout returned by
+// Values and saves it. Settings missing from values are reset to their defaults.
+func (c *Config) SetValues(tree map[string]interface{}) error {
+	data, err := json.Marshal(tree)
+	if err != nil {
+		return fmt.Errorf("failed to marshal config: %w", err)
+	}
+
+	updated := defaultConfig()
+	if err := json.Unmarshal(data, updated); err != nil {
+		return fmt.Errorf("invalid config: %w", err)
+	}
+	if updated.Organizations == nil {
+		updated.Organizations = make(map[string]OrgInfo)
+	}
+
+	mu.Lock()
+	*c = *updated
+	mu.Unlock()
+
+	return c.Save()
+}
@@ -0,0 +1,127 @@
+package config
+
+import (
+	"path/filepath"
+	"reflect"
+	"testing"
+)
+
+func TestParseValue(t *testing.T) {
+	tests := []struct {
+		valueType ValueType
+		args      []string
+		want      interface{}
+		wantErr   bool
+	}{
+		{TypeString, []string{"ghcr.io/acme"}, "ghcr.io/acme", false},
+		{TypeBool, []string{"false"}, false, false},
+		{TypeBool, []string{"maybe"}, nil, true},
+		{TypeInt, []string{"4"}, int64(4), false},
+		{TypeInt, []string{"four"}, nil, true},
+		{TypeFloat, []string{"0.5"}, 0.5, false},
+		{TypeDuration, []string{"30s"}, "30s", false},
+		{TypeDuration, []string{"30"}, nil, true},
+		{TypeList, []string{"admin,dev", "ops"}, []interface{}{"admin", "dev", "ops"}, false},
+		{TypeString, []string{"a", "b"}, nil, true},
+	}
+
+	for _, tt := range tests {
+		got, err := ParseValue(tt.valueType, tt.args)
+		if tt.wantErr {
+			if err == nil {
+				t.Errorf("ParseValue(%s, %v) expected an error", tt.valueType, tt.args)
+			}
+			continue
+		}
+		if err != nil {
+			t.Errorf("ParseValue(%s, %v) failed: %v", tt.valueType, tt.args, err)
+			continue
+		}
+		if !reflect.DeepEqual(got, tt.want) {
+			t.Errorf("ParseValue(%s, %v) = %#v, want %#v", tt.valueType, tt.args, got, tt.want)
+		}
+	}
+}
+
+func TestKeys(t *testing.T) {
+	tree := map[string]interface{}{}
+	if err := SetKey(tree, "defaults.component.list.registry", "ghcr.io/acme"); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetKey(tree, "defaults.deploy.yes", true); err != nil {
+		t.Fatal(err)
+	}
+
+	if value, ok := GetKey(tree, "defaults.component.list.registry"); !ok || value != "ghcr.io/acme" {
+		t.Errorf("Expected registry default, got %v", value)
+	}
+	if err := SetKey(tree, "defaults.deploy.yes.no", true); err == nil {
+		t.Error("Expected an error setting a key below a value")
+	}
+
+	flat := Flatten(tree)
+	if len(flat) != 2 || flat["defaults.deploy.yes"] != true {
+		t.Errorf("Unexpected flattened keys: %v", flat)
+	}
+
+	// Removing the last key of a table removes the table
+	if !UnsetKey(tree, "defaults.component.list.registry") {
+		t.Error("Expected registry default to be unset")
+	}
+	if _, ok := GetKey(tree, "defaults.component"); ok {
+		t.Error("Expected empty tables to be removed")
+	}
+	if UnsetKey(tree, "defaults.component.list.registry") {
+		t.Error("Expected unsetting a missing key to report false")
+	}
+}
+
+func TestSetValues(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+
+	cfg := defaultConfig()
+	tree, err := cfg.Values()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := SetKey(tree, "telemetry.disabled", true); err != nil {
+		t.Fatal(err)
+	}
+	UnsetKey(tree, "preferences.color_output")
+	if err := cfg.SetValues(tree); err != nil {
+		t.Fatal(err)
+	}
+
+	if cfg.Telemetry == nil || !cfg.Telemetry.Disabled {
+		t.Error("Expected telemetry to be disabled")
+	}
+	if !cfg.Preferences.ColorOutput {
+		t.Error("Expected an unset preference to return to its default")
+	}
+
+	path, err := Path()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := Validate(path); err != nil {
+		t.Errorf("Expected saved config at %s to be valid: %v", filepath.Base(path), err)
+	}
+}
+
+func TestSaveProject(t *testing.T) {
+	path := filepath.Join(t.TempDir(), ProjectFile)
+	defaults := map[string]interface{}{
+		"deploy": map[string]interface{}{"access-control": "private", "jobs": int64(4)},
+	}
+	if err := SaveProject(path, defaults); err != nil {
+		t.Fatal(err)
+	}
+
+	project, err := LoadProject(filepath.Dir(filepath.Dir(path)))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if value, ok := project.Lookup([]string{"deploy"}, "jobs"); !ok || value != int64(4) {
+		t.Errorf("Expected jobs default of 4, got %v", value)
+	}
+}
@@ -0,0 +1,98 @@
+package deploy
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+)
+
+// RecordDir is where deployment records are kept, relative to the project root
+const RecordDir = ".ftl/deployments"
+
+// ComponentPin identifies the exact artifact deployed for a component
+type ComponentPin struct {
+	ID       string `json:"id"`
+	Registry string `json:"registry"`
+	Package  string `json:"package"` // Spin package name (namespace:component)
+	Version  string `json:"version"`
+	Digest   string `json:"digest"`
+
+	// FilesDigest pins the artifact holding the directories mounted into the
+	// component, tagged FilesVersion(Version) in the same repository
+	FilesDigest string `json:"files_digest,omitempty"`
+}
+
+// Repository returns the OCI repository of the pinned component
+func (p ComponentPin) Repository() string {
+	return fmt.Sprintf("%s/%s", p.Registry, strings.Replace(p.Package, ":", "/", 1))
+}
+
+// FilesVersion returns the tag of the files artifact pushed alongside a
+// component version
+func FilesVersion(version string) string {
+	return version + "-files"
+}
+
+// Record describes a successful deployment, so that it can be promoted to
+// another engine without rebuilding or re-pushing its components
+type Record struct {
+	App          string         `json:"app"`
+	AppID        string         `json:"app_id"`
+	OrgID        string         `json:"org_id,omitempty"`
+	Environment  string         `json:"environment"`
+	DeploymentID string         `json:"deployment_id,omitempty"`
+	DeployedAt   time.Time      `json:"deployed_at"`
+	ChangeReason string         `json:"change_reason,omitempty"`
+	Components   []ComponentPin `json:"components"`
+
+	// Request is the deployment request sent to the platform
+	Request map[string]interface{} `json:"request"`
+}
+
+// RecordPath returns the path of the record for app within dir
+func RecordPath(dir, app string) string {
+	return filepath.Join(dir, RecordDir, app+".json")
+}
+
+// SaveRecord writes the record for its app within dir, replacing any
+// previous record, and returns the path written
+func SaveRecord(dir string, record *Record) (string, error) {
+	path := RecordPath(dir, record.App)
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return "", fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+
+	data, err := json.MarshalIndent(record, "", "  ")
+	if err != nil {
+		return "", fmt.Errorf("failed to encode deployment record: %w", err)
+	}
+	if err := os.WriteFile(path, append(data, '\n'), 0600); err != nil {
+		return "", fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return path, nil
+}
+
+// LoadRecord reads a deployment record
+func LoadRecord(path string) (*Record, error) {
+	data, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return nil, err
+	}
+
+	var record Record
+	if err := json.Unmarshal(data, &record); err != nil {
+		return nil, fmt.Errorf("failed to parse deployment record %s: %w", path, err)
+	}
+	if len(record.Components) == 0 {
+		return nil, fmt.Errorf("deployment record %s has no components", path)
+	}
+	for _, pin := range record.Components {
+		if pin.Digest == "" {
+			return nil, fmt.Errorf("deployment record %s has no digest for component %s", path, pin.ID)
+		}
+	}
+	return &record, nil
+}
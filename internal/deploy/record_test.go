@@ -0,0 +1,51 @@
+package deploy
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestSaveAndLoadRecord(t *testing.T) {
+	dir := t.TempDir()
+	record := &Record{
+		App:          "my-app-staging",
+		AppID:        "app-123",
+		Environment:  "production",
+		DeploymentID: "dep-456",
+		DeployedAt:   time.Date(2025, 8, 1, 12, 0, 0, 0, time.UTC),
+		Components: []ComponentPin{{
+			ID:       "weather",
+			Registry: "123.dkr.ecr.us-west-2.amazonaws.com",
+			Package:  "ns-staging:weather",
+			Version:  "1.0.0",
+			Digest:   "sha256:abc",
+		}},
+		Request: map[string]interface{}{"name": "my-app-staging"},
+	}
+
+	path, err := SaveRecord(dir, record)
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(dir, ".ftl", "deployments", "my-app-staging.json"), path)
+
+	loaded, err := LoadRecord(path)
+	require.NoError(t, err)
+	assert.Equal(t, record, loaded)
+	assert.Equal(t, "123.dkr.ecr.us-west-2.amazonaws.com/ns-staging/weather", loaded.Components[0].Repository())
+}
+
+func TestLoadRecord_RequiresDigests(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "app.json")
+	require.NoError(t, os.WriteFile(path, []byte(`{"app":"app","components":[{"id":"weather"}]}`), 0600))
+
+	_, err := LoadRecord(path)
+	assert.ErrorContains(t, err, "no digest for component weather")
+
+	require.NoError(t, os.WriteFile(path, []byte(`{"app":"app","components":[]}`), 0600))
+	_, err = LoadRecord(path)
+	assert.ErrorContains(t, err, "has no components")
+}
@@ -0,0 +1,348 @@
+// Package doctor diagnoses the local FTL development environment and applies
+// automatic fixes where possible
+package doctor
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"os"
+	"os/exec"
+	"strings"
+	"time"
+
+	"github.com/fastertools/ftl/internal/toolchain"
+)
+
+// WasmTarget is the Rust target FTL components are compiled for
+const WasmTarget = "wasm32-wasip1"
+
+// Severity is the outcome of a check
+type Severity string
+
+const (
+	SeverityOK   Severity = "ok"
+	SeverityWarn Severity = "warn"
+	SeverityFail Severity = "fail"
+)
+
+// Result is the outcome of a single check
+type Result struct {
+	Name     string   `json:"name"`
+	Severity Severity `json:"status"`
+	Message  string   `json:"message"`
+	// Suggestion is an actionable next step when the check did not pass
+	Suggestion string `json:"suggestion,omitempty"`
+	// Fixed is set when the problem was remediated by Run
+	Fixed bool `json:"fixed,omitempty"`
+}
+
+// Check is a single diagnostic
+type Check struct {
+	Name string
+	Run  func(ctx context.Context) Result
+	// Fix remediates a failed check; nil when the fix is manual
+	Fix func(ctx context.Context) error
+}
+
+// Run executes the checks in order. With fix set, checks that did not pass and
+// have an automatic fix are remediated and re-run.
+func Run(ctx context.Context, checks []Check, fix bool) []Result {
+	results := make([]Result, 0, len(checks))
+	for _, check := range checks {
+		result := check.Run(ctx)
+		result.Name = check.Name
+
+		if fix && result.Severity != SeverityOK && check.Fix != nil {
+			if err := check.Fix(ctx); err != nil {
+				result.Message = fmt.Sprintf("%s (automatic fix failed: %v)", result.Message, err)
+			} else {
+				result = check.Run(ctx)
+				result.Name = check.Name
+				result.Fixed = result.Severity == SeverityOK
+			}
+		}
+
+		results = append(results, result)
+	}
+	return results
+}
+
+// Failed reports whether any result is a failure
+func Failed(results []Result) bool {
+	for _, r := range results {
+		if r.Severity == SeverityFail {
+			return true
+		}
+	}
+	return false
+}
+
+// Env provides the system access used by the built-in checks, so tests can
+// substitute fakes
+type Env struct {
+	// CheckTool resolves a tool requirement and reports its version
+	CheckTool func(ctx context.Context, req toolchain.Requirement) toolchain.Status
+	// InstallTool installs a pinned tool version
+	InstallTool func(ctx context.Context, tool, version string) error
+	// Output runs a command and returns its combined output
+	Output func(ctx context.Context, name string, args ...string) (string, error)
+	// Probe issues a GET request and returns the response status code
+	Probe func(ctx context.Context, url string) (int, error)
+}
+
+// DefaultEnv returns an Env backed by the real system
+func DefaultEnv(mgr *toolchain.Manager) Env {
+	return Env{
+		CheckTool: func(ctx context.Context, req toolchain.Requirement) toolchain.Status {
+			return mgr.Check(ctx, []toolchain.Requirement{req})[0]
+		},
+		InstallTool: func(ctx context.Context, tool, version string) error {
+			_, err := mgr.Install(ctx, tool, version)
+			return err
+		},
+		Output: func(ctx context.Context, name string, args ...string) (string, error) {
+			if _, err := exec.LookPath(name); err != nil {
+				return "", err
+			}
+			out, err := exec.CommandContext(ctx, name, args...).CombinedOutput() // #nosec G204 -- fixed diagnostic commands
+			return string(out), err
+		},
+		Probe: func(ctx context.Context, url string) (int, error) {
+			ctx, cancel := context.WithTimeout(ctx, 10*time.Second)
+			defer cancel()
+			req, err := http.NewRequestWithContext(ctx, http.MethodGet, url, nil)
+			if err != nil {
+				return 0, err
+			}
+			resp, err := http.DefaultClient.Do(req)
+			if err != nil {
+				return 0, err
+			}
+			_ = resp.Body.Close()
+			return resp.StatusCode, nil
+		},
+	}
+}
+
+// ToolSpec describes a tool the doctor checks for
+type ToolSpec struct {
+	Requirement toolchain.Requirement
+	// Required tools fail the check when missing, optional ones only warn
+	Required bool
+	// Purpose explains what the tool is needed for
+	Purpose string
+	// InstallHint tells the user how to install the tool manually
+	InstallHint string
+}
+
+// DefaultTools are the tools FTL shells out to, with the minimum versions it supports
+func DefaultTools() []ToolSpec {
+	return []ToolSpec{
+		{
+			Requirement: toolchain.Requirement{Tool: "spin", Constraint: ">=3.0.0"},
+			Required:    true,
+			Purpose:     "runs and builds FTL applications",
+			InstallHint: "install Spin from https://developer.fermyon.com/spin/install",
+		},
+		{
+			Requirement: toolchain.Requirement{Tool: "cargo"},
+			Purpose:     "builds Rust tool components",
+			InstallHint: "install Rust from https://rustup.rs",
+		},
+		{
+			Requirement: toolchain.Requirement{Tool: "wkg"},
+			Purpose:     "fetches registry components and WIT packages",
+			InstallHint: "run 'cargo install wkg'",
+		},
+		{
+			Requirement: toolchain.Requirement{Tool: "docker"},
+			Purpose:     "used for registry logins and container workflows",
+			InstallHint: "install Docker from https://docs.docker.com/get-docker/",
+		},
+	}
+}
+
+// MergeRequirements applies project toolchain requirements to the default
+// tools. Tools pinned by the project become required.
+func MergeRequirements(tools []ToolSpec, reqs []toolchain.Requirement) []ToolSpec {
+	merged := append([]ToolSpec(nil), tools...)
+	for _, req := range reqs {
+		found := false
+		for i := range merged {
+			if merged[i].Requirement.Tool == req.Tool {
+				merged[i].Requirement.Constraint = req.Constraint
+				merged[i].Required = true
+				found = true
+				break
+			}
+		}
+		if !found {
+			merged = append(merged, ToolSpec{Requirement: req, Required: true, Purpose: "required by the project toolchain"})
+		}
+	}
+	return merged
+}
+
+// ToolCheck verifies that a tool is installed and satisfies its version requirement
+func ToolCheck(env Env, spec ToolSpec) Check {
+	req := spec.Requirement
+	check := Check{
+		Name: "tool: " + req.Tool,
+		Run: func(ctx context.Context) Result {
+			missing := SeverityWarn
+			if spec.Required {
+				missing = SeverityFail
+			}
+
+			status := env.CheckTool(ctx, req)
+			switch {
+			case status.Path == "":
+				return Result{
+					Severity:   missing,
+					Message:    fmt.Sprintf("%s not found (%s)", req.Tool, spec.Purpose),
+					Suggestion: installSuggestion(spec),
+				}
+			case status.Error != nil:
+				return Result{
+					Severity:   missing,
+					Message:    status.Error.Error(),
+					Suggestion: installSuggestion(spec),
+				}
+			case !status.OK():
+				return Result{
+					Severity:   missing,
+					Message:    fmt.Sprintf("%s %s does not satisfy %s", req.Tool, status.Version, req.Constraint),
+					Suggestion: installSuggestion(spec),
+				}
+			}
+			return Result{Severity: SeverityOK, Message: fmt.Sprintf("%s %s (%s)", req.Tool, status.Version, status.Path)}
+		},
+	}
+
+	if version := req.Pinned(); version != "" && toolchain.Installable(req.Tool) {
+		check.Fix = func(ctx context.Context) error {
+			return env.InstallTool(ctx, req.Tool, version)
+		}
+	}
+	return check
+}
+
+func installSuggestion(spec ToolSpec) string {
+	req := spec.Requirement
+	if version := req.Pinned(); version != "" && toolchain.Installable(req.Tool) {
+		return fmt.Sprintf("run 'ftl doctor --fix' or 'ftl toolchain install %s' to install %s %s", req.Tool, req.Tool, version)
+	}
+	if req.Constraint != "" && req.Constraint != "*" {
+		return fmt.Sprintf("%s (%s %s)", spec.InstallHint, req.Tool, req.Constraint)
+	}
+	return spec.InstallHint
+}
+
+// WasmTargetCheck verifies the Rust wasm32-wasip1 target is installed
+func WasmTargetCheck(env Env) Check {
+	return Check{
+		Name: "rust target: " + WasmTarget,
+		Run: func(ctx context.Context) Result {
+			out, err := env.Output(ctx, "rustup", "target", "list", "--installed")
+			if err != nil {
+				return Result{
+					Severity:   SeverityWarn,
+					Message:    "rustup not available, cannot check installed targets",
+					Suggestion: fmt.Sprintf("install Rust from https://rustup.rs, then run 'rustup target add %s'", WasmTarget),
+				}
+			}
+			for _, line := range strings.Split(out, "\n") {
+				if strings.TrimSpace(line) == WasmTarget {
+					return Result{Severity: SeverityOK, Message: WasmTarget + " installed"}
+				}
+			}
+			return Result{
+				Severity:   SeverityFail,
+				Message:    WasmTarget + " target not installed, Rust components cannot be built",
+				Suggestion: fmt.Sprintf("run 'ftl doctor --fix' or 'rustup target add %s'", WasmTarget),
+			}
+		},
+		Fix: func(ctx context.Context) error {
+			out, err := env.Output(ctx, "rustup", "target", "add", WasmTarget)
+			if err != nil {
+				return fmt.Errorf("rustup target add %s: %w: %s", WasmTarget, err, strings.TrimSpace(out))
+			}
+			return nil
+		},
+	}
+}
+
+// RegistryCheck verifies an OCI registry is reachable
+func RegistryCheck(env Env, registry string) Check {
+	return Check{
+		Name: "registry: " + registry,
+		Run: func(ctx context.Context) Result {
+			// The OCI distribution base endpoint answers 200 or 401 when reachable
+			status, err := env.Probe(ctx, "https://"+registry+"/v2/")
+			if err != nil {
+				return Result{
+					Severity:   SeverityFail,
+					Message:    fmt.Sprintf("cannot reach %s: %v", registry, err),
+					Suggestion: "check your network connection and any HTTPS_PROXY settings",
+				}
+			}
+			if status >= http.StatusInternalServerError {
+				return Result{
+					Severity:   SeverityWarn,
+					Message:    fmt.Sprintf("%s responded with status %d", registry, status),
+					Suggestion: "the registry may be degraded, retry later",
+				}
+			}
+			return Result{Severity: SeverityOK, Message: fmt.Sprintf("%s reachable", registry)}
+		},
+	}
+}
+
+// SkippedCheck reports a check that was not run, such as a connectivity check
+// while offline
+func SkippedCheck(name, reason string) Check {
+	return Check{
+		Name: name,
+		Run: func(ctx context.Context) Result {
+			return Result{Severity: SeverityOK, Message: "skipped: " + reason}
+		},
+	}
+}
+
+// KeyringCheck verifies the OS keyring used for credentials is usable
+func KeyringCheck(probe func() error) Check {
+	return Check{
+		Name: "keyring",
+		Run: func(ctx context.Context) Result {
+			if err := probe(); err != nil {
+				return Result{
+					Severity:   SeverityFail,
+					Message:    fmt.Sprintf("OS keyring unavailable: %v", err),
+					Suggestion: "unlock the login keychain, or start a Secret Service provider such as gnome-keyring on Linux",
+				}
+			}
+			return Result{Severity: SeverityOK, Message: "OS keyring available"}
+		},
+	}
+}
+
+// FileCheck verifies that a config file, when present, parses
+func FileCheck(name, path string, parse func(path string) error) Check {
+	return Check{
+		Name: name,
+		Run: func(ctx context.Context) Result {
+			if _, err := os.Stat(path); os.IsNotExist(err) {
+				return Result{Severity: SeverityOK, Message: fmt.Sprintf("%s not present, defaults apply", path)}
+			}
+			if err := parse(path); err != nil {
+				return Result{
+					Severity:   SeverityFail,
+					Message:    fmt.Sprintf("%s is invalid: %v", path, err),
+					Suggestion: fmt.Sprintf("fix or remove %s", path),
+				}
+			}
+			return Result{Severity: SeverityOK, Message: fmt.Sprintf("%s is valid", path)}
+		},
+	}
+}
@@ -0,0 +1,258 @@
+package doctor
+
+import (
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/toolchain"
+)
+
+func TestRun(t *testing.T) {
+	fixed := false
+	checks := []Check{
+		{
+			Name: "passes",
+			Run:  func(context.Context) Result { return Result{Severity: SeverityOK} },
+		},
+		{
+			Name: "fixable",
+			Run: func(context.Context) Result {
+				if fixed {
+					return Result{Severity: SeverityOK, Message: "now fine"}
+				}
+				return Result{Severity: SeverityFail, Message: "broken"}
+			},
+			Fix: func(context.Context) error {
+				fixed = true
+				return nil
+			},
+		},
+		{
+			Name: "manual",
+			Run:  func(context.Context) Result { return Result{Severity: SeverityWarn, Suggestion: "do it by hand"} },
+		},
+	}
+
+	t.Run("without fix", func(t *testing.T) {
+		results := Run(context.Background(), checks, false)
+		require.Len(t, results, 3)
+		assert.Equal(t, "passes", results[0].Name)
+		assert.Equal(t, SeverityFail, results[1].Severity)
+		assert.False(t, fixed)
+		assert.True(t, Failed(results))
+	})
+
+	t.Run("with fix", func(t *testing.T) {
+		results := Run(context.Background(), checks, true)
+		assert.Equal(t, SeverityOK, results[1].Severity)
+		assert.Equal(t, "fixable", results[1].Name)
+		assert.True(t, results[1].Fixed)
+		assert.Equal(t, SeverityWarn, results[2].Severity)
+		assert.False(t, Failed(results))
+	})
+
+	t.Run("fix error", func(t *testing.T) {
+		failing := Check{
+			Name: "failing",
+			Run:  func(context.Context) Result { return Result{Severity: SeverityFail, Message: "broken"} },
+			Fix:  func(context.Context) error { return errors.New("no permission") },
+		}
+		results := Run(context.Background(), []Check{failing}, true)
+		assert.Equal(t, SeverityFail, results[0].Severity)
+		assert.Contains(t, results[0].Message, "automatic fix failed: no permission")
+		assert.False(t, results[0].Fixed)
+	})
+}
+
+func TestToolCheck(t *testing.T) {
+	statuses := map[string]toolchain.Status{}
+	var installed []string
+	env := Env{
+		CheckTool: func(_ context.Context, req toolchain.Requirement) toolchain.Status {
+			status := statuses[req.Tool]
+			status.Requirement = req
+			return status
+		},
+		InstallTool: func(_ context.Context, tool, version string) error {
+			installed = append(installed, tool+"@"+version)
+			statuses[tool] = toolchain.Status{Path: "/tools/" + tool, Version: version}
+			return nil
+		},
+	}
+
+	t.Run("ok", func(t *testing.T) {
+		statuses["cargo"] = toolchain.Status{Path: "/usr/bin/cargo", Version: "1.89.0"}
+		result := ToolCheck(env, ToolSpec{Requirement: toolchain.Requirement{Tool: "cargo"}}).Run(context.Background())
+		assert.Equal(t, SeverityOK, result.Severity)
+		assert.Contains(t, result.Message, "1.89.0")
+	})
+
+	t.Run("missing optional tool warns", func(t *testing.T) {
+		spec := ToolSpec{Requirement: toolchain.Requirement{Tool: "docker"}, InstallHint: "install docker"}
+		result := ToolCheck(env, spec).Run(context.Background())
+		assert.Equal(t, SeverityWarn, result.Severity)
+		assert.Equal(t, "install docker", result.Suggestion)
+	})
+
+	t.Run("version mismatch on required tool fails", func(t *testing.T) {
+		statuses["spin"] = toolchain.Status{Path: "/usr/bin/spin", Version: "2.7.0"}
+		spec := ToolSpec{Requirement: toolchain.Requirement{Tool: "spin", Constraint: ">=3.0.0"}, Required: true, InstallHint: "install spin"}
+		check := ToolCheck(env, spec)
+		assert.Nil(t, check.Fix)
+
+		result := check.Run(context.Background())
+		assert.Equal(t, SeverityFail, result.Severity)
+		assert.Contains(t, result.Message, "does not satisfy >=3.0.0")
+		assert.Equal(t, "install spin (spin >=3.0.0)", result.Suggestion)
+	})
+
+	t.Run("pinned tool is fixable", func(t *testing.T) {
+		spec := ToolSpec{Requirement: toolchain.Requirement{Tool: "wkg", Constraint: "0.11.0"}, Required: true}
+		check := ToolCheck(env, spec)
+		require.NotNil(t, check.Fix)
+
+		results := Run(context.Background(), []Check{check}, true)
+		assert.True(t, results[0].Fixed)
+		assert.Equal(t, []string{"wkg@0.11.0"}, installed)
+	})
+}
+
+func TestWasmTargetCheck(t *testing.T) {
+	targets := "x86_64-unknown-linux-gnu\n"
+	env := Env{
+		Output: func(_ context.Context, name string, args ...string) (string, error) {
+			if args[1] == "add" {
+				targets += args[2] + "\n"
+				return "", nil
+			}
+			return targets, nil
+		},
+	}
+
+	check := WasmTargetCheck(env)
+	result := check.Run(context.Background())
+	assert.Equal(t, SeverityFail, result.Severity)
+	assert.Contains(t, result.Suggestion, "rustup target add wasm32-wasip1")
+
+	results := Run(context.Background(), []Check{check}, true)
+	assert.Equal(t, SeverityOK, results[0].Severity)
+	assert.True(t, results[0].Fixed)
+	assert.Contains(t, targets, WasmTarget)
+}
+
+func TestWasmTargetCheck_NoRustup(t *testing.T) {
+	env := Env{
+		Output: func(context.Context, string, ...string) (string, error) {
+			return "", errors.New("executable file not found")
+		},
+	}
+
+	result := WasmTargetCheck(env).Run(context.Background())
+	assert.Equal(t, SeverityWarn, result.Severity)
+	assert.Contains(t, result.Suggestion, "rustup.rs")
+}
+
+func TestRegistryCheck(t *testing.T) {
+	tests := []struct {
+		name     string
+		status   int
+		err      error
+		expected Severity
+	}{
+		{name: "reachable", status: 200, expected: SeverityOK},
+		{name: "requires auth", status: 401, expected: SeverityOK},
+		{name: "degraded", status: 503, expected: SeverityWarn},
+		{name: "unreachable", err: errors.New("dial tcp: no such host"), expected: SeverityFail},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			var probed string
+			env := Env{
+				Probe: func(_ context.Context, url string) (int, error) {
+					probed = url
+					return tt.status, tt.err
+				},
+			}
+
+			result := RegistryCheck(env, "ghcr.io").Run(context.Background())
+			assert.Equal(t, tt.expected, result.Severity)
+			assert.Equal(t, "https://ghcr.io/v2/", probed)
+		})
+	}
+}
+
+func TestSkippedCheck(t *testing.T) {
+	check := SkippedCheck("registry: ghcr.io", "offline")
+	assert.Equal(t, "registry: ghcr.io", check.Name)
+	assert.Nil(t, check.Fix)
+
+	result := check.Run(context.Background())
+	assert.Equal(t, SeverityOK, result.Severity)
+	assert.Equal(t, "skipped: offline", result.Message)
+}
+
+func TestKeyringCheck(t *testing.T) {
+	result := KeyringCheck(func() error { return nil }).Run(context.Background())
+	assert.Equal(t, SeverityOK, result.Severity)
+
+	result = KeyringCheck(func() error { return errors.New("locked") }).Run(context.Background())
+	assert.Equal(t, SeverityFail, result.Severity)
+	assert.Contains(t, result.Message, "locked")
+	assert.NotEmpty(t, result.Suggestion)
+}
+
+func TestFileCheck(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "config.json")
+	parse := func(p string) error {
+		data, err := os.ReadFile(p)
+		if err != nil {
+			return err
+		}
+		if string(data) != "valid" {
+			return errors.New("unexpected content")
+		}
+		return nil
+	}
+
+	result := FileCheck("config", path, parse).Run(context.Background())
+	assert.Equal(t, SeverityOK, result.Severity)
+	assert.Contains(t, result.Message, "not present")
+
+	require.NoError(t, os.WriteFile(path, []byte("valid"), 0600))
+	result = FileCheck("config", path, parse).Run(context.Background())
+	assert.Equal(t, SeverityOK, result.Severity)
+
+	require.NoError(t, os.WriteFile(path, []byte("garbage"), 0600))
+	result = FileCheck("config", path, parse).Run(context.Background())
+	assert.Equal(t, SeverityFail, result.Severity)
+	assert.Contains(t, result.Message, "unexpected content")
+}
+
+func TestMergeRequirements(t *testing.T) {
+	tools := []ToolSpec{
+		{Requirement: toolchain.Requirement{Tool: "spin", Constraint: ">=3.0.0"}, Required: true},
+		{Requirement: toolchain.Requirement{Tool: "wkg"}},
+	}
+
+	merged := MergeRequirements(tools, []toolchain.Requirement{
+		{Tool: "wkg", Constraint: "0.11.0"},
+		{Tool: "tinygo", Constraint: ">=0.38.0"},
+	})
+
+	require.Len(t, merged, 3)
+	assert.Equal(t, ">=3.0.0", merged[0].Requirement.Constraint)
+	assert.Equal(t, "0.11.0", merged[1].Requirement.Constraint)
+	assert.True(t, merged[1].Required)
+	assert.Equal(t, "tinygo", merged[2].Requirement.Tool)
+
+	// The defaults are not modified
+	assert.Empty(t, tools[1].Requirement.Constraint)
+}
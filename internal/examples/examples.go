@@ -0,0 +1,298 @@
+// Package examples provides access to the FTL example gallery
+package examples
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"net/http"
+	"os"
+	"path"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/BurntSushi/toml"
+
+	"github.com/fastertools/ftl/internal/manifest"
+)
+
+// DefaultSource is the archive of the FTL repository the gallery is read from
+const DefaultSource = "https://codeload.github.com/fastertools/ftl/tar.gz/refs/heads/main"
+
+const (
+	// galleryDir is the location of the gallery inside the repository
+	galleryDir = "examples/demo"
+	// galleryManifest describes every example in the gallery
+	galleryManifest = "ftl.toml"
+	// maxArchiveSize caps how much of a downloaded archive is read
+	maxArchiveSize = 256 << 20
+)
+
+var templateVarPattern = regexp.MustCompile(`^\{\{\s*([a-zA-Z0-9_]+)\s*\}\}$`)
+
+// Example is a single component from the gallery
+type Example struct {
+	Name                 string
+	Path                 string
+	Wasm                 string
+	BuildCommand         string
+	Watch                []string
+	Variables            map[string]string
+	AllowedOutboundHosts []string
+}
+
+// Gallery holds the gallery files and the examples described by its manifest
+type Gallery struct {
+	files    map[string][]byte
+	examples map[string]*Example
+}
+
+// galleryConfig mirrors the parts of the gallery ftl.toml used to scaffold projects
+type galleryConfig struct {
+	Variables map[string]struct {
+		Default string `toml:"default"`
+	} `toml:"variables"`
+	Component map[string]struct {
+		Path                 string            `toml:"path"`
+		Wasm                 string            `toml:"wasm"`
+		AllowedOutboundHosts []string          `toml:"allowed_outbound_hosts"`
+		Variables            map[string]string `toml:"variables"`
+		Build                struct {
+			Command string   `toml:"command"`
+			Watch   []string `toml:"watch"`
+		} `toml:"build"`
+	} `toml:"component"`
+}
+
+// Load reads the gallery from source, which is either an http(s) URL of a
+// repository tar.gz archive or a local gallery directory
+func Load(ctx context.Context, source string) (*Gallery, error) {
+	var files map[string][]byte
+	var err error
+	if strings.HasPrefix(source, "http://") || strings.HasPrefix(source, "https://") {
+		files, err = fetchArchive(ctx, source)
+	} else {
+		files, err = readDir(source)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	data, ok := files[galleryManifest]
+	if !ok {
+		return nil, fmt.Errorf("example gallery has no %s", galleryManifest)
+	}
+
+	var cfg galleryConfig
+	if err := toml.Unmarshal(data, &cfg); err != nil {
+		return nil, fmt.Errorf("failed to parse gallery %s: %w", galleryManifest, err)
+	}
+
+	g := &Gallery{files: files, examples: make(map[string]*Example)}
+	for name, comp := range cfg.Component {
+		ex := &Example{
+			Name:                 name,
+			Path:                 comp.Path,
+			Wasm:                 comp.Wasm,
+			BuildCommand:         comp.Build.Command,
+			Watch:                comp.Build.Watch,
+			AllowedOutboundHosts: comp.AllowedOutboundHosts,
+		}
+
+		// Inline application-level defaults so the example runs standalone
+		if len(comp.Variables) > 0 {
+			ex.Variables = make(map[string]string, len(comp.Variables))
+			for k, v := range comp.Variables {
+				if m := templateVarPattern.FindStringSubmatch(v); m != nil {
+					if appVar, ok := cfg.Variables[m[1]]; ok {
+						v = appVar.Default
+					}
+				}
+				ex.Variables[k] = v
+			}
+		}
+		g.examples[name] = ex
+	}
+
+	return g, nil
+}
+
+// List returns the gallery examples sorted by name
+func (g *Gallery) List() []*Example {
+	list := make([]*Example, 0, len(g.examples))
+	for _, ex := range g.examples {
+		list = append(list, ex)
+	}
+	sort.Slice(list, func(i, j int) bool { return list[i].Name < list[j].Name })
+	return list
+}
+
+// Get returns the named example
+func (g *Gallery) Get(name string) (*Example, error) {
+	ex, ok := g.examples[name]
+	if !ok {
+		names := make([]string, 0, len(g.examples))
+		for _, e := range g.List() {
+			names = append(names, e.Name)
+		}
+		return nil, fmt.Errorf("unknown example %q (available: %s)", name, strings.Join(names, ", "))
+	}
+	return ex, nil
+}
+
+// Extract copies the example's files into projectDir, keeping their path
+// relative to the gallery root, and returns the number of files written
+func (g *Gallery) Extract(ex *Example, projectDir string) (int, error) {
+	prefix := strings.TrimSuffix(ex.Path, "/") + "/"
+	written := 0
+	for name, data := range g.files {
+		if !strings.HasPrefix(name, prefix) {
+			continue
+		}
+
+		dest := filepath.Join(projectDir, filepath.FromSlash(name))
+		if err := os.MkdirAll(filepath.Dir(dest), 0750); err != nil {
+			return written, err
+		}
+		if err := os.WriteFile(dest, data, 0600); err != nil {
+			return written, err
+		}
+		written++
+	}
+
+	if written == 0 {
+		return 0, fmt.Errorf("example %s has no files", ex.Name)
+	}
+	return written, nil
+}
+
+// Component returns the manifest component for the example
+func (ex *Example) Component() manifest.Component {
+	comp := manifest.Component{
+		ID:                   ex.Name,
+		Source:               ex.Wasm,
+		Variables:            ex.Variables,
+		AllowedOutboundHosts: ex.AllowedOutboundHosts,
+	}
+	if ex.BuildCommand != "" {
+		comp.Build = &manifest.BuildConfig{
+			Command: ex.BuildCommand,
+			Workdir: ex.Path,
+			Watch:   ex.Watch,
+		}
+	}
+	return comp
+}
+
+// fetchArchive downloads a repository tar.gz and returns the gallery files in it
+func fetchArchive(ctx context.Context, url string) (map[string][]byte, error) {
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Minute)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, url, nil)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("failed to download examples: %w", err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("failed to download examples: %s", resp.Status)
+	}
+
+	return readArchive(io.LimitReader(resp.Body, maxArchiveSize))
+}
+
+// readArchive extracts the gallery files from a repository tar.gz stream.
+// Archive entries are prefixed by a single top-level directory (e.g. ftl-main/).
+func readArchive(r io.Reader) (map[string][]byte, error) {
+	gz, err := gzip.NewReader(r)
+	if err != nil {
+		return nil, fmt.Errorf("invalid examples archive: %w", err)
+	}
+	defer func() { _ = gz.Close() }()
+
+	files := make(map[string][]byte)
+	tr := tar.NewReader(gz)
+	for {
+		hdr, err := tr.Next()
+		if errors.Is(err, io.EOF) {
+			break
+		}
+		if err != nil {
+			return nil, fmt.Errorf("invalid examples archive: %w", err)
+		}
+		if hdr.Typeflag != tar.TypeReg {
+			continue
+		}
+
+		_, rel, ok := strings.Cut(path.Clean(hdr.Name), "/")
+		if !ok {
+			continue
+		}
+		rel, ok = strings.CutPrefix(rel, galleryDir+"/")
+		if !ok || !isSafePath(rel) {
+			continue
+		}
+
+		var buf bytes.Buffer
+		if _, err := io.Copy(&buf, tr); err != nil {
+			return nil, fmt.Errorf("invalid examples archive: %w", err)
+		}
+		files[rel] = buf.Bytes()
+	}
+
+	return files, nil
+}
+
+// readDir reads a local gallery directory
+func readDir(root string) (map[string][]byte, error) {
+	files := make(map[string][]byte)
+	err := filepath.WalkDir(root, func(p string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		if d.IsDir() {
+			switch d.Name() {
+			case "target", "node_modules", "dist", ".venv":
+				return filepath.SkipDir
+			}
+			return nil
+		}
+		if !d.Type().IsRegular() {
+			return nil
+		}
+
+		rel, err := filepath.Rel(root, p)
+		if err != nil {
+			return err
+		}
+		data, err := os.ReadFile(filepath.Clean(p))
+		if err != nil {
+			return err
+		}
+		files[filepath.ToSlash(rel)] = data
+		return nil
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to read examples from %s: %w", root, err)
+	}
+	return files, nil
+}
+
+// isSafePath rejects archive paths that would escape the project directory
+func isSafePath(p string) bool {
+	return p != "" && !path.IsAbs(p) && p != ".." && !strings.HasPrefix(p, "../")
+}
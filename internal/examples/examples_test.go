@@ -0,0 +1,158 @@
+package examples
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const testGalleryManifest = `[variables]
+api_token = { default = "demo-token" }
+
+[component.echo-rs]
+path = "echo-rs"
+wasm = "echo-rs/target/wasm32-wasip1/release/echo_rs.wasm"
+allowed_outbound_hosts = ["https://api.example.com"]
+variables = { token = "{{ api_token }}", mode = "fast" }
+
+[component.echo-rs.build]
+command = "cargo build --target wasm32-wasip1 --release"
+watch = ["src/**/*.rs", "Cargo.toml"]
+
+[component.echo-go]
+path = "echo-go"
+wasm = "echo-go/dist/echo-go.wasm"
+`
+
+func writeGallery(t *testing.T) string {
+	t.Helper()
+	root := t.TempDir()
+	files := map[string]string{
+		"ftl.toml":              testGalleryManifest,
+		"echo-rs/Cargo.toml":    "[package]\nname = \"echo_rs\"\n",
+		"echo-rs/src/lib.rs":    "// echo\n",
+		"echo-rs/target/out":    "build output",
+		"echo-go/main.go":       "package main\n",
+		"echo-go/dist/echo.txt": "build output",
+	}
+	for name, content := range files {
+		p := filepath.Join(root, filepath.FromSlash(name))
+		require.NoError(t, os.MkdirAll(filepath.Dir(p), 0750))
+		require.NoError(t, os.WriteFile(p, []byte(content), 0600))
+	}
+	return root
+}
+
+func TestLoad_LocalDirectory(t *testing.T) {
+	g, err := Load(context.Background(), writeGallery(t))
+	require.NoError(t, err)
+
+	list := g.List()
+	require.Len(t, list, 2)
+	assert.Equal(t, "echo-go", list[0].Name)
+	assert.Equal(t, "echo-rs", list[1].Name)
+
+	ex, err := g.Get("echo-rs")
+	require.NoError(t, err)
+	assert.Equal(t, "cargo build --target wasm32-wasip1 --release", ex.BuildCommand)
+	assert.Equal(t, []string{"https://api.example.com"}, ex.AllowedOutboundHosts)
+	// Application variable references are replaced with their defaults
+	assert.Equal(t, map[string]string{"token": "demo-token", "mode": "fast"}, ex.Variables)
+
+	_, err = g.Get("missing")
+	assert.ErrorContains(t, err, "available: echo-go, echo-rs")
+}
+
+func TestExtract(t *testing.T) {
+	g, err := Load(context.Background(), writeGallery(t))
+	require.NoError(t, err)
+
+	ex, err := g.Get("echo-rs")
+	require.NoError(t, err)
+
+	projectDir := t.TempDir()
+	count, err := g.Extract(ex, projectDir)
+	require.NoError(t, err)
+	assert.Equal(t, 2, count)
+	assert.FileExists(t, filepath.Join(projectDir, "echo-rs", "Cargo.toml"))
+	assert.FileExists(t, filepath.Join(projectDir, "echo-rs", "src", "lib.rs"))
+	assert.NoFileExists(t, filepath.Join(projectDir, "echo-rs", "target", "out"))
+	assert.NoDirExists(t, filepath.Join(projectDir, "echo-go"))
+}
+
+func TestExampleComponent(t *testing.T) {
+	ex := &Example{
+		Name:         "echo-rs",
+		Path:         "echo-rs",
+		Wasm:         "echo-rs/target/wasm32-wasip1/release/echo_rs.wasm",
+		BuildCommand: "cargo build",
+		Watch:        []string{"src/**/*.rs"},
+	}
+
+	comp := ex.Component()
+	assert.Equal(t, "echo-rs", comp.ID)
+	assert.Equal(t, ex.Wasm, comp.Source)
+	require.NotNil(t, comp.Build)
+	assert.Equal(t, "cargo build", comp.Build.Command)
+	assert.Equal(t, "echo-rs", comp.Build.Workdir)
+
+	ex.BuildCommand = ""
+	assert.Nil(t, ex.Component().Build)
+}
+
+func TestLoad_Archive(t *testing.T) {
+	var buf bytes.Buffer
+	gz := gzip.NewWriter(&buf)
+	tw := tar.NewWriter(gz)
+	entries := map[string]string{
+		"ftl-main/examples/demo/ftl.toml":            testGalleryManifest,
+		"ftl-main/examples/demo/echo-go/main.go":     "package main\n",
+		"ftl-main/examples/demo/../../../etc/passwd": "nope",
+		"ftl-main/README.md":                         "not part of the gallery",
+	}
+	for name, content := range entries {
+		require.NoError(t, tw.WriteHeader(&tar.Header{
+			Name:     name,
+			Mode:     0600,
+			Size:     int64(len(content)),
+			Typeflag: tar.TypeReg,
+		}))
+		_, err := tw.Write([]byte(content))
+		require.NoError(t, err)
+	}
+	require.NoError(t, tw.Close())
+	require.NoError(t, gz.Close())
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		_, _ = w.Write(buf.Bytes())
+	}))
+	defer server.Close()
+
+	g, err := Load(context.Background(), server.URL)
+	require.NoError(t, err)
+	assert.Len(t, g.List(), 2)
+	assert.Contains(t, g.files, "echo-go/main.go")
+	assert.NotContains(t, g.files, "README.md")
+	for name := range g.files {
+		assert.True(t, isSafePath(name), name)
+	}
+}
+
+func TestLoad_ArchiveNotFound(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusNotFound)
+	}))
+	defer server.Close()
+
+	_, err := Load(context.Background(), server.URL)
+	assert.ErrorContains(t, err, "404")
+}
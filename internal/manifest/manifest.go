@@ -14,13 +14,20 @@ import (
 
 // Manifest represents an FTL application manifest
 type Manifest struct {
-	Name        string                 `yaml:"name" json:"name"`
-	Version     string                 `yaml:"version,omitempty" json:"version,omitempty"`
-	Description string                 `yaml:"description,omitempty" json:"description,omitempty"`
-	Access      string                 `yaml:"access,omitempty" json:"access,omitempty"`
-	Auth        *validation.AuthConfig `yaml:"auth,omitempty" json:"auth,omitempty"`
-	Components  []Component            `yaml:"components,omitempty" json:"components,omitempty"`
-	Variables   map[string]string      `yaml:"variables,omitempty" json:"variables,omitempty"`
+	Name        string                         `yaml:"name" json:"name"`
+	Version     string                         `yaml:"version,omitempty" json:"version,omitempty"`
+	Description string                         `yaml:"description,omitempty" json:"description,omitempty"`
+	Access      string                         `yaml:"access,omitempty" json:"access,omitempty"`
+	Auth        *validation.AuthConfig         `yaml:"auth,omitempty" json:"auth,omitempty"`
+	Components  []Component                    `yaml:"components,omitempty" json:"components,omitempty"`
+	Variables   map[string]validation.Variable `yaml:"variables,omitempty" json:"variables,omitempty"`
+	Toolchain   map[string]string              `yaml:"toolchain,omitempty" json:"toolchain,omitempty"`
+
+	// Profiles are named build profiles selected with `ftl build --profile`
+	Profiles map[string]validation.BuildProfile `yaml:"profiles,omitempty" json:"profiles,omitempty"`
+
+	// AllowExternalPaths permits local component paths outside the project directory
+	AllowExternalPaths bool `yaml:"allow_external_paths,omitempty" json:"allow_external_paths,omitempty"`
 }
 
 // Component represents a component in the manifest
@@ -29,6 +36,27 @@ type Component struct {
 	Source    interface{}       `yaml:"source" json:"source"` // Can be string or SourceRegistry
 	Build     *BuildConfig      `yaml:"build,omitempty" json:"build,omitempty"`
 	Variables map[string]string `yaml:"variables,omitempty" json:"variables,omitempty"`
+
+	// AllowedOutboundHosts lists the hosts the component may call
+	AllowedOutboundHosts []string `yaml:"allowed_outbound_hosts,omitempty" json:"allowed_outbound_hosts,omitempty"`
+
+	// Capabilities lists the MCP capabilities served besides tools (resources, prompts)
+	Capabilities []string `yaml:"capabilities,omitempty" json:"capabilities,omitempty"`
+
+	// Limits holds the limits the gateway enforces on calls to the component's tools
+	Limits *validation.ComponentLimits `yaml:"limits,omitempty" json:"limits,omitempty"`
+
+	// SizeBudget caps the size of the built Wasm, checked by `ftl build`
+	SizeBudget *validation.SizeBudget `yaml:"size_budget,omitempty" json:"size_budget,omitempty"`
+
+	// Schedules lists the scheduled handlers run by cron triggers
+	Schedules []validation.Schedule `yaml:"schedules,omitempty" json:"schedules,omitempty"`
+
+	// Files lists the local directories mounted into the component
+	Files []validation.FileMount `yaml:"files,omitempty" json:"files,omitempty"`
+
+	// KeyValueStores lists the key-value stores the component opens
+	KeyValueStores []string `yaml:"key_value_stores,omitempty" json:"key_value_stores,omitempty"`
 }
 
 // UnmarshalYAML implements custom YAML unmarshaling for Component
@@ -184,9 +212,11 @@ type SourceRegistry struct {
 
 // BuildConfig represents build configuration
 type BuildConfig struct {
-	Command string   `yaml:"command" json:"command"`
-	Workdir string   `yaml:"workdir,omitempty" json:"workdir,omitempty"`
-	Watch   []string `yaml:"watch,omitempty" json:"watch,omitempty"`
+	Command  string          `yaml:"command" json:"command"`
+	Workdir  string          `yaml:"workdir,omitempty" json:"workdir,omitempty"`
+	Watch    []string        `yaml:"watch,omitempty" json:"watch,omitempty"`
+	Strategy string          `yaml:"strategy,omitempty" json:"strategy,omitempty"`
+	Registry *SourceRegistry `yaml:"registry,omitempty" json:"registry,omitempty"`
 }
 
 // Load reads and parses an FTL manifest file (supports both YAML and JSON)
@@ -332,6 +362,106 @@ func (m *Manifest) RemoveComponent(id string) error {
 	return nil
 }
 
+// RenameComponent changes the ID of a component
+func (m *Manifest) RenameComponent(oldID, newID string) error {
+	_, index := m.FindComponent(oldID)
+	if index == -1 {
+		return fmt.Errorf("component '%s' not found", oldID)
+	}
+	if existing, _ := m.FindComponent(newID); existing != nil {
+		return fmt.Errorf("component '%s' already exists", newID)
+	}
+
+	m.Components[index].ID = newID
+	return nil
+}
+
+// InternalHostReferences returns the IDs of the other components whose
+// allowed_outbound_hosts include the spin.internal host of a component
+func (m *Manifest) InternalHostReferences(id string) []string {
+	internalHost := id + ".spin.internal"
+
+	var refs []string
+	for _, comp := range m.Components {
+		if comp.ID == id {
+			continue
+		}
+		for _, host := range comp.AllowedOutboundHosts {
+			// Strip the scheme, port and path, e.g. http://calc.spin.internal:80
+			if _, rest, ok := strings.Cut(host, "://"); ok {
+				host = rest
+			}
+			if i := strings.IndexAny(host, ":/"); i >= 0 {
+				host = host[:i]
+			}
+			if host == internalHost {
+				refs = append(refs, comp.ID)
+				break
+			}
+		}
+	}
+	return refs
+}
+
+// Dir returns the project directory holding the component's files: its build
+// workdir, or the top directory of a local source named after the component
+// (as created by `ftl add`). It is empty when the component has no directory
+// of its own inside the project.
+func (c *Component) Dir() string {
+	var dir string
+	if c.Build != nil && c.Build.Workdir != "" {
+		dir = cleanRelativePath(c.Build.Workdir)
+	} else if source, ok := c.Source.(string); ok {
+		top, _, _ := strings.Cut(cleanRelativePath(source), "/")
+		if top == c.ID {
+			dir = top
+		}
+	}
+
+	if dir == "." || dir == ".." || strings.HasPrefix(dir, "../") || filepath.IsAbs(dir) {
+		return ""
+	}
+	return dir
+}
+
+// MoveDir rewrites the local source, build workdir and watch patterns of the
+// component that are inside the directory from to point inside to
+func (c *Component) MoveDir(from, to string) {
+	from = cleanRelativePath(from)
+	to = cleanRelativePath(to)
+
+	if source, ok := c.Source.(string); ok {
+		c.Source = movePath(source, from, to)
+	}
+	if c.Build != nil {
+		c.Build.Workdir = movePath(c.Build.Workdir, from, to)
+		for i, pattern := range c.Build.Watch {
+			c.Build.Watch[i] = movePath(pattern, from, to)
+		}
+	}
+}
+
+// movePath replaces the directory from at the start of path with to, leaving
+// other paths unchanged
+func movePath(path, from, to string) string {
+	cleaned := cleanRelativePath(path)
+	switch {
+	case path == "":
+		return path
+	case cleaned == from:
+		return to
+	case strings.HasPrefix(cleaned, from+"/"):
+		return to + strings.TrimPrefix(cleaned, from)
+	default:
+		return path
+	}
+}
+
+// cleanRelativePath cleans a manifest path, using forward slashes
+func cleanRelativePath(path string) string {
+	return filepath.ToSlash(filepath.Clean(filepath.FromSlash(path)))
+}
+
 // ParseRegistrySource parses a registry string into a SourceRegistry
 // Format: registry/namespace:package@version
 func ParseRegistrySource(registry string) (*SourceRegistry, error) {
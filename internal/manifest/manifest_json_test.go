@@ -4,9 +4,12 @@ import (
 	"encoding/json"
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
 
 	"gopkg.in/yaml.v3"
+
+	"github.com/fastertools/ftl/validation"
 )
 
 func TestJSONSupport(t *testing.T) {
@@ -211,8 +214,8 @@ func TestJSONRoundTrip(t *testing.T) {
 				},
 			},
 		},
-		Variables: map[string]string{
-			"ENV": "production",
+		Variables: map[string]validation.Variable{
+			"ENV": validation.VariableWithDefault("production"),
 		},
 	}
 
@@ -286,3 +289,45 @@ func TestYAMLRoundTrip(t *testing.T) {
 		}
 	}
 }
+
+func TestVariablesYAMLRoundTrip(t *testing.T) {
+	yamlData := []byte(`name: vars-app
+variables:
+  log_level: info
+  region:
+    required: true
+  api_key:
+    value_from: engine-secret:API_KEY
+`)
+
+	var m Manifest
+	if err := yaml.Unmarshal(yamlData, &m); err != nil {
+		t.Fatalf("Failed to unmarshal: %v", err)
+	}
+	if v := m.Variables["log_level"]; v.Default == nil || *v.Default != "info" {
+		t.Errorf("Plain variable should be a default value, got %+v", v)
+	}
+	if !m.Variables["region"].Required {
+		t.Errorf("region should be required")
+	}
+	if name, ok := m.Variables["api_key"].SecretName(); !ok || name != "API_KEY" {
+		t.Errorf("api_key should reference API_KEY, got %q", name)
+	}
+
+	out, err := yaml.Marshal(&m)
+	if err != nil {
+		t.Fatalf("Failed to marshal: %v", err)
+	}
+	// Plain variables are written back as strings
+	if !strings.Contains(string(out), "log_level: info") {
+		t.Errorf("Plain variable not written as a string:\n%s", out)
+	}
+
+	var loaded Manifest
+	if err := yaml.Unmarshal(out, &loaded); err != nil {
+		t.Fatalf("Failed to unmarshal: %v", err)
+	}
+	if len(loaded.Variables) != 3 || !loaded.Variables["region"].Required {
+		t.Errorf("Variables changed in YAML roundtrip: %+v", loaded.Variables)
+	}
+}
@@ -147,3 +147,90 @@ func TestRegistrySourceYAML(t *testing.T) {
 		t.Errorf("Expected local-comp, got %s", loaded.Components[1].ID)
 	}
 }
+
+func TestRenameComponent(t *testing.T) {
+	m := Manifest{
+		Components: []Component{
+			{ID: "weather", Source: "weather/weather.wasm"},
+			{ID: "client", Source: "client/client.wasm"},
+		},
+	}
+
+	if err := m.RenameComponent("weather", "forecast"); err != nil {
+		t.Fatalf("Failed to rename component: %v", err)
+	}
+	if m.Components[0].ID != "forecast" {
+		t.Errorf("Expected forecast, got %s", m.Components[0].ID)
+	}
+
+	if err := m.RenameComponent("missing", "other"); err == nil {
+		t.Error("Expected error renaming non-existent component")
+	}
+	if err := m.RenameComponent("forecast", "client"); err == nil {
+		t.Error("Expected error renaming to an existing component")
+	}
+}
+
+func TestComponentDir(t *testing.T) {
+	tests := []struct {
+		name string
+		comp Component
+		want string
+	}{
+		{"build workdir", Component{ID: "a", Source: "a/a.wasm", Build: &BuildConfig{Workdir: "tools/a/"}}, "tools/a"},
+		{"source named after component", Component{ID: "a", Source: "./a/target/a.wasm"}, "a"},
+		{"source in another directory", Component{ID: "a", Source: "build/a.wasm"}, ""},
+		{"registry source", Component{ID: "a", Source: SourceRegistry{Registry: "ghcr.io"}}, ""},
+		{"project root", Component{ID: "a", Source: "a.wasm", Build: &BuildConfig{Workdir: "."}}, ""},
+		{"outside project", Component{ID: "a", Source: "a.wasm", Build: &BuildConfig{Workdir: "../a"}}, ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.comp.Dir(); got != tt.want {
+				t.Errorf("Dir() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestComponentMoveDir(t *testing.T) {
+	comp := Component{
+		ID:     "weather",
+		Source: "./weather/target/weather.wasm",
+		Build: &BuildConfig{
+			Workdir: "weather",
+			Watch:   []string{"weather/src/**/*.rs", "src/**/*.rs", "weatherman/x"},
+		},
+	}
+
+	comp.MoveDir("weather", "forecast")
+
+	if comp.Source != "forecast/target/weather.wasm" {
+		t.Errorf("Unexpected source: %v", comp.Source)
+	}
+	if comp.Build.Workdir != "forecast" {
+		t.Errorf("Unexpected workdir: %s", comp.Build.Workdir)
+	}
+	want := []string{"forecast/src/**/*.rs", "src/**/*.rs", "weatherman/x"}
+	for i, pattern := range want {
+		if comp.Build.Watch[i] != pattern {
+			t.Errorf("Watch[%d] = %q, want %q", i, comp.Build.Watch[i], pattern)
+		}
+	}
+}
+
+func TestInternalHostReferences(t *testing.T) {
+	m := Manifest{
+		Components: []Component{
+			{ID: "weather", AllowedOutboundHosts: []string{"http://weather.spin.internal"}},
+			{ID: "client", AllowedOutboundHosts: []string{"https://api.example.com", "http://weather.spin.internal:80"}},
+			{ID: "other", AllowedOutboundHosts: []string{"http://weather.spin.internal.example.com", "http://*.spin.internal"}},
+		},
+	}
+
+	refs := m.InternalHostReferences("weather")
+	if len(refs) != 1 || refs[0] != "client" {
+		t.Errorf("Expected [client], got %v", refs)
+	}
+}
@@ -0,0 +1,224 @@
+// Package migrate upgrades ftl.yaml and ftl.json files written for older
+// versions of the configuration schema
+package migrate
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+
+	"gopkg.in/yaml.v3"
+
+	"github.com/fastertools/ftl/validation"
+)
+
+// CurrentVersion is the configuration schema version this FTL reads
+const CurrentVersion = 2
+
+// Migration upgrades a configuration by one schema version
+type Migration struct {
+	// To is the schema version the migration produces
+	To          int
+	// Description summarizes what the migration changes
+	Description string
+	// Detect reports whether a configuration still needs the migration
+	Detect      func(root *yaml.Node) bool
+	// Apply migrates a configuration in place and returns the changes that
+	// need manual attention
+	Apply       func(root *yaml.Node) []Note
+}
+
+// Note is something a migration could not change automatically
+type Note struct {
+	// Path is the dotted path of the affected key
+	Path    string
+	Message string
+}
+
+func (n Note) String() string {
+	if n.Path == "" {
+		return n.Message
+	}
+	return n.Path + ": " + n.Message
+}
+
+// Result is the outcome of migrating a configuration
+type Result struct {
+	// From is the detected schema version
+	From    int
+	// To is the schema version of the migrated configuration
+	To      int
+	// Applied are the migrations that ran, oldest first
+	Applied []Migration
+	// Notes are the changes that need manual attention
+	Notes   []Note
+	// Data is the migrated configuration, unchanged when no migration ran
+	Data    []byte
+}
+
+// Detect returns the schema version of a configuration
+func Detect(data []byte) (int, error) {
+	doc, err := parse(data)
+	if err != nil {
+		return 0, err
+	}
+	return detect(doc.Content[0]), nil
+}
+
+// Migrate upgrades a YAML or JSON configuration to CurrentVersion. JSON
+// configurations are written back as JSON, YAML ones keep their comments.
+func Migrate(data []byte, isJSON bool) (*Result, error) {
+	doc, err := parse(data)
+	if err != nil {
+		return nil, err
+	}
+	root := doc.Content[0]
+
+	result := &Result{From: detect(root), To: CurrentVersion, Data: data}
+	for _, migration := range Migrations {
+		if migration.To <= result.From {
+			continue
+		}
+		result.Notes = append(result.Notes, migration.Apply(root)...)
+		result.Applied = append(result.Applied, migration)
+	}
+	if len(result.Applied) == 0 {
+		return result, nil
+	}
+
+	if isJSON {
+		result.Data, err = encodeJSON(root)
+	} else {
+		result.Data, err = encodeYAML(doc)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	// Anything the migrations could not fix is left for the user
+	v := validation.New()
+	if isJSON {
+		_, err = v.ValidateJSON(result.Data)
+	} else {
+		_, err = v.ValidateYAML(result.Data)
+	}
+	if err != nil {
+		result.Notes = append(result.Notes, Note{Message: fmt.Sprintf("the migrated configuration is still invalid: %v", err)})
+	}
+	return result, nil
+}
+
+// detect returns the version before the oldest migration a configuration
+// still needs
+func detect(root *yaml.Node) int {
+	for _, migration := range Migrations {
+		if migration.Detect(root) {
+			return migration.To - 1
+		}
+	}
+	return CurrentVersion
+}
+
+// parse returns the document node of a configuration, whose content is its
+// top-level mapping
+func parse(data []byte) (*yaml.Node, error) {
+	// JSON is parsed as YAML, which does not allow tabs for indentation
+	if trimmed := bytes.TrimSpace(data); len(trimmed) > 0 && trimmed[0] == '{' {
+		var compact bytes.Buffer
+		if err := json.Compact(&compact, trimmed); err == nil {
+			data = compact.Bytes()
+		}
+	}
+
+	var doc yaml.Node
+	if err := yaml.Unmarshal(data, &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse configuration: %w", err)
+	}
+	if doc.Kind != yaml.DocumentNode || len(doc.Content) == 0 || doc.Content[0].Kind != yaml.MappingNode {
+		return nil, fmt.Errorf("configuration must be a mapping")
+	}
+	return &doc, nil
+}
+
+func encodeYAML(doc *yaml.Node) ([]byte, error) {
+	var buf bytes.Buffer
+	encoder := yaml.NewEncoder(&buf)
+	encoder.SetIndent(2)
+	if err := encoder.Encode(doc); err != nil {
+		return nil, fmt.Errorf("failed to encode configuration: %w", err)
+	}
+	if err := encoder.Close(); err != nil {
+		return nil, fmt.Errorf("failed to encode configuration: %w", err)
+	}
+	return buf.Bytes(), nil
+}
+
+// encodeJSON writes a node as indented JSON, keeping the order of keys
+func encodeJSON(root *yaml.Node) ([]byte, error) {
+	var buf bytes.Buffer
+	if err := writeJSON(&buf, root); err != nil {
+		return nil, fmt.Errorf("failed to encode configuration: %w", err)
+	}
+	var out bytes.Buffer
+	if err := json.Indent(&out, buf.Bytes(), "", "  "); err != nil {
+		return nil, fmt.Errorf("failed to encode configuration: %w", err)
+	}
+	out.WriteByte('\n')
+	return out.Bytes(), nil
+}
+
+func writeJSON(buf *bytes.Buffer, node *yaml.Node) error {
+	switch node.Kind {
+	case yaml.MappingNode:
+		buf.WriteByte('{')
+		for i := 0; i+1 < len(node.Content); i += 2 {
+			if i > 0 {
+				buf.WriteByte(',')
+			}
+			key, err := json.Marshal(node.Content[i].Value)
+			if err != nil {
+				return err
+			}
+			buf.Write(key)
+			buf.WriteByte(':')
+			if err := writeJSON(buf, node.Content[i+1]); err != nil {
+				return err
+			}
+		}
+		buf.WriteByte('}')
+	case yaml.SequenceNode:
+		buf.WriteByte('[')
+		for i, item := range node.Content {
+			if i > 0 {
+				buf.WriteByte(',')
+			}
+			if err := writeJSON(buf, item); err != nil {
+				return err
+			}
+		}
+		buf.WriteByte(']')
+	case yaml.AliasNode:
+		return writeJSON(buf, node.Alias)
+	default:
+		var value interface{}
+		if err := node.Decode(&value); err != nil {
+			return err
+		}
+		data, err := json.Marshal(value)
+		if err != nil {
+			return err
+		}
+		buf.Write(data)
+	}
+	return nil
+}
+
+// lookup returns the index of a key of a mapping node, or -1
+func lookup(mapping *yaml.Node, key string) int {
+	for i := 0; i+1 < len(mapping.Content); i += 2 {
+		if mapping.Content[i].Value == key {
+			return i
+		}
+	}
+	return -1
+}
@@ -0,0 +1,127 @@
+package migrate
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const legacyYAML = `# Weather tools
+application:
+  # Shown in the registry
+  name: weather-app
+  version: "0.2.0"
+  description: Weather tools
+components: []
+triggers: []
+`
+
+func TestDetect(t *testing.T) {
+	version, err := Detect([]byte(legacyYAML))
+	require.NoError(t, err)
+	assert.Equal(t, 1, version)
+
+	version, err = Detect([]byte("name: weather-app\ncomponents: []\n"))
+	require.NoError(t, err)
+	assert.Equal(t, CurrentVersion, version)
+
+	_, err = Detect([]byte("- not\n- a mapping\n"))
+	assert.ErrorContains(t, err, "configuration must be a mapping")
+}
+
+func TestMigrateYAML(t *testing.T) {
+	result, err := Migrate([]byte(legacyYAML), false)
+	require.NoError(t, err)
+
+	assert.Equal(t, 1, result.From)
+	assert.Equal(t, CurrentVersion, result.To)
+	require.Len(t, result.Applied, 1)
+	assert.Empty(t, result.Notes)
+
+	migrated := string(result.Data)
+	assert.True(t, strings.HasPrefix(migrated, "# Weather tools\n"))
+	assert.Contains(t, migrated, "# Shown in the registry\nname: weather-app\n")
+	assert.Contains(t, migrated, "version: \"0.2.0\"\n")
+	assert.Contains(t, migrated, "description: Weather tools\n")
+	assert.NotContains(t, migrated, "application")
+	assert.NotContains(t, migrated, "triggers")
+
+	version, err := Detect(result.Data)
+	require.NoError(t, err)
+	assert.Equal(t, CurrentVersion, version)
+}
+
+func TestMigrateNotes(t *testing.T) {
+	legacy := `name: weather-app
+application:
+  name: old-name
+  version: "0.2.0"
+  owner: weather-team
+components: []
+triggers:
+  - route: /weather/...
+    component: weather
+`
+	result, err := Migrate([]byte(legacy), false)
+	require.NoError(t, err)
+
+	require.Len(t, result.Notes, 3)
+	assert.Equal(t, "application.name", result.Notes[0].Path)
+	assert.Contains(t, result.Notes[0].Message, "already set at the top level")
+	assert.Equal(t, "triggers", result.Notes[1].Path)
+	assert.Contains(t, result.Notes[1].Message, "removed 1 trigger(s)")
+	// The unknown owner key is left for the user
+	assert.Empty(t, result.Notes[2].Path)
+	assert.Contains(t, result.Notes[2].Message, "still invalid")
+
+	assert.Contains(t, string(result.Data), "name: weather-app\n")
+	assert.Contains(t, string(result.Data), "owner: weather-team\n")
+	assert.NotContains(t, string(result.Data), "old-name")
+}
+
+func TestMigrateJSON(t *testing.T) {
+	legacy := "{\n\t\"application\": {\n\t\t\"name\": \"weather-app\",\n\t\t\"version\": \"0.2.0\"\n\t},\n\t\"components\": [],\n\t\"triggers\": []\n}\n"
+	result, err := Migrate([]byte(legacy), true)
+	require.NoError(t, err)
+	assert.Empty(t, result.Notes)
+
+	var config map[string]interface{}
+	require.NoError(t, json.Unmarshal(result.Data, &config))
+	assert.Equal(t, map[string]interface{}{
+		"name":       "weather-app",
+		"version":    "0.2.0",
+		"components": []interface{}{},
+	}, config)
+
+	// Keys keep their order
+	migrated := string(result.Data)
+	assert.Less(t, strings.Index(migrated, `"name"`), strings.Index(migrated, `"components"`))
+	assert.True(t, strings.HasPrefix(migrated, "{\n  \"name\": \"weather-app\",\n"))
+}
+
+func TestMigrateCurrent(t *testing.T) {
+	current := "name: weather-app\n# unchanged\ncomponents: []\n"
+	result, err := Migrate([]byte(current), false)
+	require.NoError(t, err)
+	assert.Empty(t, result.Applied)
+	assert.Equal(t, CurrentVersion, result.From)
+	assert.Equal(t, current, string(result.Data))
+}
+
+func TestDiff(t *testing.T) {
+	assert.Empty(t, Diff("ftl.yaml", []byte("a\nb\n"), []byte("a\nb\n")))
+
+	diff := Diff("ftl.yaml", []byte("a\nb\nc\n"), []byte("a\nB\nc\nd\n"))
+	assert.Equal(t, "--- ftl.yaml\n+++ ftl.yaml\n@@ -1,3 +1,4 @@\n a\n-b\n+B\n c\n+d\n", diff)
+
+	// Distant changes get their own hunks
+	before := "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n"
+	after := "one\n2\n3\n4\n5\n6\n7\n8\n9\nten\n"
+	diff = Diff("ftl.yaml", []byte(before), []byte(after))
+	assert.Equal(t, "--- ftl.yaml\n+++ ftl.yaml\n"+
+		"@@ -1,4 +1,4 @@\n-1\n+one\n 2\n 3\n 4\n"+
+		"@@ -7,4 +7,4 @@\n 7\n 8\n 9\n-10\n+ten\n", diff)
+}
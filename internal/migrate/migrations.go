@@ -0,0 +1,70 @@
+package migrate
+
+import (
+	"fmt"
+	"slices"
+
+	"gopkg.in/yaml.v3"
+)
+
+// Migrations are the schema migrations, oldest first. Each one upgrades the
+// version before its To; add new migrations at the end and bump
+// CurrentVersion.
+var Migrations = []Migration{
+	{
+		To:          2,
+		Description: "Move the application section to the top level and remove triggers",
+		Detect: func(root *yaml.Node) bool {
+			return lookup(root, "application") >= 0 || lookup(root, "triggers") >= 0
+		},
+		Apply: flattenApplication,
+	},
+}
+
+// flattenApplication migrates the version 1 layout, where the name, version
+// and description were nested under application and triggers were listed
+// next to the components
+func flattenApplication(root *yaml.Node) []Note {
+	var notes []Note
+
+	if i := lookup(root, "application"); i >= 0 {
+		section := root.Content[i+1]
+		if section.Kind != yaml.MappingNode {
+			return append(notes, Note{Path: "application", Message: "expected a mapping, move its settings to the top level by hand"})
+		}
+
+		// Hoist the section's keys into its place, keeping their order and comments
+		var hoisted []*yaml.Node
+		for j := 0; j+1 < len(section.Content); j += 2 {
+			key, value := section.Content[j], section.Content[j+1]
+			if lookup(root, key.Value) >= 0 {
+				notes = append(notes, Note{
+					Path:    "application." + key.Value,
+					Message: fmt.Sprintf("removed, %s is already set at the top level", key.Value),
+				})
+				continue
+			}
+			hoisted = append(hoisted, key, value)
+		}
+		if comment := root.Content[i].HeadComment; len(hoisted) > 0 && comment != "" {
+			if hoisted[0].HeadComment != "" {
+				comment += "\n" + hoisted[0].HeadComment
+			}
+			hoisted[0].HeadComment = comment
+		}
+		root.Content = slices.Replace(root.Content, i, i+2, hoisted...)
+	}
+
+	if i := lookup(root, "triggers"); i >= 0 {
+		if triggers := root.Content[i+1]; triggers.Kind == yaml.SequenceNode && len(triggers.Content) > 0 {
+			notes = append(notes, Note{
+				Path:    "triggers",
+				Message: fmt.Sprintf("removed %d trigger(s). Tools are routed through the gateway automatically, "+
+					"declare cron jobs with a component's schedules", len(triggers.Content)),
+			})
+		}
+		root.Content = slices.Delete(root.Content, i, i+2)
+	}
+
+	return notes
+}
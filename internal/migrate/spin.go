@@ -0,0 +1,420 @@
+package migrate
+
+import (
+	"fmt"
+	"maps"
+	"regexp"
+	"slices"
+	"strings"
+
+	"github.com/BurntSushi/toml"
+	"gopkg.in/yaml.v3"
+
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/validation"
+)
+
+// platformComponents are the components FTL adds to every synthesized Spin
+// manifest
+var platformComponents = []string{"mcp-gateway", "mcp-authorizer"}
+
+// scheduleVariable names the handler run by a component FTL generates for a
+// scheduled handler
+const scheduleVariable = "ftl_schedule"
+
+// componentKeys is the order of the keys of adopted components
+var componentKeys = []string{
+	"id", "source", "build", "variables", "allowed_outbound_hosts", "files", "key_value_stores", "schedules",
+}
+
+var idPattern = regexp.MustCompile(`^[a-z][a-z0-9-]*$`)
+
+// Adoption is an FTL configuration converted from a Spin manifest
+type Adoption struct {
+	// Manifest is the converted configuration
+	Manifest *manifest.Manifest
+	// Notes are the parts of the Spin manifest FTL can't represent, which
+	// were left out
+	Notes    []Note
+	// Data is the configuration as YAML
+	Data     []byte
+}
+
+// FromSpin converts a version 2 Spin manifest into an FTL configuration.
+// The components run by HTTP triggers are adopted with their build,
+// variables, outbound hosts, files and key-value stores, and the components
+// FTL generates for scheduled handlers become schedules again. FTL serves
+// components privately behind its MCP gateway, so public routes and the
+// gateway and authorizer components are left out.
+func FromSpin(data []byte) (*Adoption, error) {
+	var doc map[string]interface{}
+	if _, err := toml.Decode(string(data), &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse Spin manifest: %w", err)
+	}
+	if version, _ := doc["spin_manifest_version"].(int64); version != 2 {
+		return nil, fmt.Errorf("only version 2 Spin manifests can be adopted, run 'spin doctor' to upgrade it")
+	}
+
+	a := &spinAdopter{manifest: &manifest.Manifest{}}
+	for _, key := range slices.Sorted(maps.Keys(doc)) {
+		switch key {
+		case "spin_manifest_version", "component", "trigger":
+		case "application":
+			a.application(table(doc[key]))
+		case "variables":
+			a.variables(table(doc[key]))
+		default:
+			a.unsupported(key)
+		}
+	}
+	// The triggers decide which components are adopted
+	a.components(table(doc["component"]), table(doc["trigger"]))
+
+	out, err := encodeAdoption(a.manifest)
+	if err != nil {
+		return nil, err
+	}
+	return &Adoption{Manifest: a.manifest, Notes: a.notes, Data: out}, nil
+}
+
+type spinAdopter struct {
+	manifest *manifest.Manifest
+	notes    []Note
+}
+
+func (a *spinAdopter) note(path, format string, args ...interface{}) {
+	a.notes = append(a.notes, Note{Path: path, Message: fmt.Sprintf(format, args...)})
+}
+
+func (a *spinAdopter) unsupported(path string) {
+	a.note(path, "not supported by FTL, left out")
+}
+
+func (a *spinAdopter) application(app map[string]interface{}) {
+	for _, key := range slices.Sorted(maps.Keys(app)) {
+		value, _ := app[key].(string)
+		switch key {
+		case "name":
+			a.manifest.Name = adoptName(value)
+			if a.manifest.Name != value {
+				a.note("application.name", "renamed to %s, FTL application names are lowercase with hyphens", a.manifest.Name)
+			}
+		case "version":
+			a.manifest.Version = value
+		case "description":
+			a.manifest.Description = value
+		default:
+			a.unsupported("application." + key)
+		}
+	}
+}
+
+func (a *spinAdopter) variables(vars map[string]interface{}) {
+	for _, name := range slices.Sorted(maps.Keys(vars)) {
+		settings := table(vars[name])
+		var variable validation.Variable
+		for _, key := range slices.Sorted(maps.Keys(settings)) {
+			switch key {
+			case "default":
+				if value, ok := settings[key].(string); ok {
+					variable.Default = &value
+				}
+			case "required":
+				variable.Required, _ = settings[key].(bool)
+			case "secret":
+				variable.Secret, _ = settings[key].(bool)
+			default:
+				a.unsupported("variables." + name + "." + key)
+			}
+		}
+		if a.manifest.Variables == nil {
+			a.manifest.Variables = map[string]validation.Variable{}
+		}
+		a.manifest.Variables[name] = variable
+	}
+}
+
+// components adopts the components run by HTTP triggers, then folds the
+// components generated for scheduled handlers into their parent's schedules
+func (a *spinAdopter) components(components, triggers map[string]interface{}) {
+	routed := map[string]bool{}
+	crons := map[string]string{}
+	for _, kind := range slices.Sorted(maps.Keys(triggers)) {
+		for i, trigger := range tables(triggers[kind]) {
+			path := fmt.Sprintf("trigger.%s[%d]", kind, i)
+			id, ok := trigger["component"].(string)
+			if !ok {
+				a.note(path, "inline components are not supported, left out")
+				continue
+			}
+			switch kind {
+			case "http":
+				a.httpTrigger(path, id, trigger)
+				routed[id] = true
+			case "cron":
+				crons[id], _ = trigger["cron_expression"].(string)
+			default:
+				a.note(path, "%s triggers are not supported, left out", kind)
+			}
+		}
+	}
+
+	for _, id := range slices.Sorted(maps.Keys(components)) {
+		switch {
+		case id == "mcp-authorizer":
+			a.note("component."+id, "provided by FTL, left out, set access to keep authenticating callers")
+		case slices.Contains(platformComponents, id):
+			a.note("component."+id, "provided by FTL, left out")
+		case routed[id]:
+			if component, ok := a.component(id, table(components[id])); ok {
+				a.manifest.Components = append(a.manifest.Components, component)
+			}
+		case crons[id] == "":
+			a.note("component."+id, "not run by an HTTP trigger, left out")
+		}
+	}
+
+	for _, id := range slices.Sorted(maps.Keys(crons)) {
+		handler, _ := table(table(components[id])["variables"])[scheduleVariable].(string)
+		switch parent := a.scheduleParent(id, handler); {
+		case parent != nil:
+			parent.Schedules = append(parent.Schedules, validation.Schedule{Handler: handler, Cron: crons[id]})
+		case routed[id]:
+			a.note("component."+id, "cron trigger left out, FTL only runs the scheduled handlers of tool components")
+		default:
+			a.note("component."+id, "run by a cron trigger, FTL only runs the scheduled handlers of tool components, left out")
+		}
+	}
+}
+
+func (a *spinAdopter) httpTrigger(path, id string, trigger map[string]interface{}) {
+	for _, key := range slices.Sorted(maps.Keys(trigger)) {
+		switch key {
+		case "component":
+		case "route":
+			if route, public := trigger[key].(string); public && !slices.Contains(platformComponents, id) {
+				a.note(path+".route", "public route %s of %s left out, FTL serves components privately behind the MCP gateway", route, id)
+			}
+		default:
+			a.unsupported(path + "." + key)
+		}
+	}
+}
+
+// scheduleParent returns the adopted component that a component FTL
+// generated for one of its scheduled handlers belongs to, or nil
+func (a *spinAdopter) scheduleParent(id, handler string) *manifest.Component {
+	if handler == "" {
+		return nil
+	}
+	parentID, ok := strings.CutSuffix(id, "-schedule-"+strings.ReplaceAll(handler, "_", "-"))
+	if !ok {
+		return nil
+	}
+	for i := range a.manifest.Components {
+		if a.manifest.Components[i].ID == parentID {
+			return &a.manifest.Components[i]
+		}
+	}
+	return nil
+}
+
+func (a *spinAdopter) component(id string, settings map[string]interface{}) (manifest.Component, bool) {
+	path := "component." + id
+	component := manifest.Component{ID: id}
+	if !idPattern.MatchString(id) {
+		a.note(path, "FTL component ids start with a letter and are lowercase with hyphens, left out")
+		return component, false
+	}
+
+	for _, key := range slices.Sorted(maps.Keys(settings)) {
+		value := settings[key]
+		switch key {
+		case "source":
+			source, ok := adoptSource(value)
+			if !ok {
+				a.note(path+".source", "only local files and registry packages are supported, download the Wasm and point source at it; component left out")
+				return component, false
+			}
+			component.Source = source
+		case "build":
+			component.Build = a.build(path+".build", table(value))
+		case "variables":
+			for name, v := range table(value) {
+				if component.Variables == nil {
+					component.Variables = map[string]string{}
+				}
+				component.Variables[name], _ = v.(string)
+			}
+		case "allowed_outbound_hosts":
+			component.AllowedOutboundHosts = stringList(value)
+		case "files":
+			component.Files = a.files(path+".files", value)
+		case "key_value_stores":
+			for _, store := range stringList(value) {
+				if store == "default" {
+					a.note(path+".key_value_stores", "the default store is reserved for the MCP gateway, open the component's data under another store name")
+					continue
+				}
+				component.KeyValueStores = append(component.KeyValueStores, store)
+			}
+		default:
+			a.unsupported(path + "." + key)
+		}
+	}
+
+	if component.Source == nil {
+		a.note(path, "no source, left out")
+		return component, false
+	}
+	return component, true
+}
+
+func (a *spinAdopter) build(path string, settings map[string]interface{}) *manifest.BuildConfig {
+	build := &manifest.BuildConfig{}
+	for _, key := range slices.Sorted(maps.Keys(settings)) {
+		switch key {
+		case "command":
+			// A list of commands runs them in turn
+			if commands := stringList(settings[key]); len(commands) > 0 {
+				build.Command = strings.Join(commands, " && ")
+			} else {
+				build.Command, _ = settings[key].(string)
+			}
+		case "workdir":
+			build.Workdir, _ = settings[key].(string)
+		case "watch":
+			build.Watch = stringList(settings[key])
+		default:
+			a.unsupported(path + "." + key)
+		}
+	}
+	return build
+}
+
+func (a *spinAdopter) files(path string, value interface{}) []validation.FileMount {
+	items, _ := value.([]interface{})
+	var mounts []validation.FileMount
+	for _, item := range items {
+		if pattern, ok := item.(string); ok {
+			a.note(path, "pattern %s left out, FTL mounts directories given as source and destination", pattern)
+			continue
+		}
+		mount := table(item)
+		source, _ := mount["source"].(string)
+		destination, _ := mount["destination"].(string)
+		mounts = append(mounts, validation.FileMount{Source: source, Destination: destination})
+	}
+	return mounts
+}
+
+// adoptSource converts a component source: a local path, or a registry
+// package. URL sources have no FTL equivalent.
+func adoptSource(value interface{}) (interface{}, bool) {
+	switch source := value.(type) {
+	case string:
+		return source, true
+	case map[string]interface{}:
+		registry, _ := source["registry"].(string)
+		pkg, _ := source["package"].(string)
+		version, _ := source["version"].(string)
+		if registry != "" && pkg != "" && version != "" {
+			return manifest.SourceRegistry{Registry: registry, Package: pkg, Version: version}, true
+		}
+	}
+	return nil, false
+}
+
+// adoptName turns a Spin application name into a valid FTL one: lowercase
+// letters, digits and hyphens, starting with a letter
+func adoptName(name string) string {
+	var b strings.Builder
+	for _, r := range strings.ToLower(name) {
+		switch {
+		case r >= 'a' && r <= 'z', r >= '0' && r <= '9':
+			b.WriteRune(r)
+		case b.Len() > 0 && !strings.HasSuffix(b.String(), "-"):
+			b.WriteByte('-')
+		}
+	}
+	adopted := strings.TrimSuffix(b.String(), "-")
+	if adopted == "" {
+		return "app"
+	}
+	if !idPattern.MatchString(adopted) {
+		adopted = "app-" + adopted
+	}
+	return adopted
+}
+
+// encodeAdoption writes an adopted configuration as YAML, with the id and
+// source of each component first
+func encodeAdoption(m *manifest.Manifest) ([]byte, error) {
+	var root yaml.Node
+	if err := root.Encode(m); err != nil {
+		return nil, fmt.Errorf("failed to encode configuration: %w", err)
+	}
+	if i := lookup(&root, "components"); i >= 0 {
+		for _, component := range root.Content[i+1].Content {
+			orderKeys(component, componentKeys)
+		}
+	}
+	return encodeYAML(&yaml.Node{Kind: yaml.DocumentNode, Content: []*yaml.Node{&root}})
+}
+
+// orderKeys sorts the keys of a mapping node by their position in order,
+// keeping other keys last
+func orderKeys(mapping *yaml.Node, order []string) {
+	rank := func(key *yaml.Node) int {
+		if i := slices.Index(order, key.Value); i >= 0 {
+			return i
+		}
+		return len(order)
+	}
+	pairs := make([][2]*yaml.Node, 0, len(mapping.Content)/2)
+	for i := 0; i+1 < len(mapping.Content); i += 2 {
+		pairs = append(pairs, [2]*yaml.Node{mapping.Content[i], mapping.Content[i+1]})
+	}
+	slices.SortStableFunc(pairs, func(x, y [2]*yaml.Node) int {
+		return rank(x[0]) - rank(y[0])
+	})
+	mapping.Content = mapping.Content[:0]
+	for _, pair := range pairs {
+		mapping.Content = append(mapping.Content, pair[0], pair[1])
+	}
+}
+
+// table returns a TOML table, or nil when the value is not one
+func table(value interface{}) map[string]interface{} {
+	t, _ := value.(map[string]interface{})
+	return t
+}
+
+// tables returns the tables of an array of tables
+func tables(value interface{}) []map[string]interface{} {
+	switch items := value.(type) {
+	case []map[string]interface{}:
+		return items
+	case []interface{}:
+		var result []map[string]interface{}
+		for _, item := range items {
+			if t, ok := item.(map[string]interface{}); ok {
+				result = append(result, t)
+			}
+		}
+		return result
+	}
+	return nil
+}
+
+// stringList returns the strings of an array, or nil when the value is not one
+func stringList(value interface{}) []string {
+	items, _ := value.([]interface{})
+	var result []string
+	for _, item := range items {
+		if s, ok := item.(string); ok {
+			result = append(result, s)
+		}
+	}
+	return result
+}
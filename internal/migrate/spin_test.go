@@ -0,0 +1,160 @@
+package migrate
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"gopkg.in/yaml.v3"
+
+	"github.com/fastertools/ftl/internal/manifest"
+	"github.com/fastertools/ftl/validation"
+)
+
+const spinManifest = `spin_manifest_version = 2
+
+[application]
+name = "Weather Tools"
+version = "0.2.0"
+description = "Weather lookups"
+authors = ["Jo <jo@example.com>"]
+
+[variables]
+api_key = { required = true, secret = true }
+units = { default = "metric" }
+
+[[trigger.http]]
+route = "/..."
+component = "mcp-gateway"
+
+[[trigger.http]]
+route = { private = true }
+component = "weather"
+
+[[trigger.http]]
+route = "/legacy/..."
+component = "legacy"
+
+[[trigger.cron]]
+component = "weather-schedule-refresh-cache"
+cron_expression = "0 */15 * * * *"
+
+[[trigger.cron]]
+component = "cleanup"
+cron_expression = "0 0 * * * *"
+
+[[trigger.redis]]
+channel = "events"
+component = "events"
+
+[component.mcp-gateway]
+source = { registry = "ghcr.io", package = "fastertools:mcp-gateway", version = "0.0.13" }
+
+[component.weather]
+source = "weather/target/wasm32-wasip1/release/weather.wasm"
+allowed_outbound_hosts = ["https://api.weather.gov"]
+key_value_stores = ["default", "cache"]
+files = [{ source = "weather/data", destination = "/data" }, "static/**"]
+environment = { RUST_LOG = "info" }
+
+[component.weather.variables]
+api_key = "{{ api_key }}"
+
+[component.weather.build]
+command = ["cargo build --target wasm32-wasip1 --release"]
+workdir = "weather"
+watch = ["src/**/*.rs"]
+
+[component.weather-schedule-refresh-cache]
+source = "weather/target/wasm32-wasip1/release/weather.wasm"
+variables = { api_key = "{{ api_key }}", ftl_schedule = "refresh_cache" }
+
+[component.legacy]
+source = { url = "https://example.com/legacy.wasm", digest = "sha256:abc" }
+
+[component.cleanup]
+source = "cleanup.wasm"
+
+[component.events]
+source = "events.wasm"
+
+[component.unused]
+source = "unused.wasm"
+`
+
+func TestFromSpin(t *testing.T) {
+	adoption, err := FromSpin([]byte(spinManifest))
+	require.NoError(t, err)
+
+	m := adoption.Manifest
+	assert.Equal(t, "weather-tools", m.Name)
+	assert.Equal(t, "0.2.0", m.Version)
+	assert.Equal(t, "Weather lookups", m.Description)
+	assert.Equal(t, map[string]validation.Variable{
+		"api_key": {Required: true, Secret: true},
+		"units":   validation.VariableWithDefault("metric"),
+	}, m.Variables)
+
+	require.Len(t, m.Components, 1)
+	assert.Equal(t, manifest.Component{
+		ID:     "weather",
+		Source: "weather/target/wasm32-wasip1/release/weather.wasm",
+		Build: &manifest.BuildConfig{
+			Command: "cargo build --target wasm32-wasip1 --release",
+			Workdir: "weather",
+			Watch:   []string{"src/**/*.rs"},
+		},
+		Variables:            map[string]string{"api_key": "{{ api_key }}"},
+		AllowedOutboundHosts: []string{"https://api.weather.gov"},
+		Files:                []validation.FileMount{{Source: "weather/data", Destination: "/data"}},
+		KeyValueStores:       []string{"cache"},
+		Schedules:            []validation.Schedule{{Handler: "refresh_cache", Cron: "0 */15 * * * *"}},
+	}, m.Components[0])
+
+	var paths []string
+	for _, note := range adoption.Notes {
+		paths = append(paths, note.Path)
+	}
+	assert.Equal(t, []string{
+		"application.authors",
+		"application.name",
+		"trigger.http[2].route",
+		"trigger.redis[0]",
+		"component.events",
+		"component.legacy.source",
+		"component.mcp-gateway",
+		"component.unused",
+		"component.weather.environment",
+		"component.weather.files",
+		"component.weather.key_value_stores",
+		"component.cleanup",
+	}, paths)
+	assert.Contains(t, adoption.Notes[2].Message, "public route /legacy/... of legacy left out")
+	assert.Contains(t, adoption.Notes[3].Message, "redis triggers are not supported")
+	assert.Contains(t, adoption.Notes[9].Message, "pattern static/** left out")
+	assert.Contains(t, adoption.Notes[11].Message, "run by a cron trigger")
+
+	// The YAML reads back as the same configuration, ids first
+	data := string(adoption.Data)
+	assert.Less(t, strings.Index(data, "id: weather"), strings.Index(data, "source: weather/"))
+	assert.Less(t, strings.Index(data, "source: weather/"), strings.Index(data, "allowed_outbound_hosts"))
+	var decoded manifest.Manifest
+	require.NoError(t, yaml.Unmarshal(adoption.Data, &decoded))
+	assert.Equal(t, *m, decoded)
+}
+
+func TestFromSpin_Errors(t *testing.T) {
+	_, err := FromSpin([]byte("spin_manifest_version = \"1\"\n"))
+	assert.ErrorContains(t, err, "only version 2 Spin manifests can be adopted")
+
+	_, err = FromSpin([]byte("spin_manifest_version = "))
+	assert.ErrorContains(t, err, "failed to parse Spin manifest")
+}
+
+func TestAdoptName(t *testing.T) {
+	assert.Equal(t, "weather-tools", adoptName("Weather Tools"))
+	assert.Equal(t, "my-app", adoptName("my_app!"))
+	assert.Equal(t, "app-2048", adoptName("2048"))
+	assert.Equal(t, "app", adoptName("!!!"))
+}
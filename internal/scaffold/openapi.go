@@ -0,0 +1,434 @@
+package scaffold
+
+import (
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"unicode"
+
+	"gopkg.in/yaml.v3"
+)
+
+// openAPIMethods are the HTTP methods tools can be generated for, in the
+// order operations of a path are listed
+var openAPIMethods = []string{"get", "put", "post", "delete", "patch"}
+
+// OpenAPISpec is the part of an OpenAPI 3 document tools are generated from
+type OpenAPISpec struct {
+	OpenAPI    string                      `yaml:"openapi"`
+	Swagger    string                      `yaml:"swagger"`
+	Info       OpenAPIInfo                 `yaml:"info"`
+	Servers    []OpenAPIServer             `yaml:"servers"`
+	Paths      orderedMap[OpenAPIPathItem] `yaml:"paths"`
+	Components OpenAPIComponents           `yaml:"components"`
+}
+
+// OpenAPIInfo describes the API
+type OpenAPIInfo struct {
+	Title string `yaml:"title"`
+}
+
+// OpenAPIServer is a base URL of the API, with optional {variables}
+type OpenAPIServer struct {
+	URL       string                           `yaml:"url"`
+	Variables map[string]OpenAPIServerVariable `yaml:"variables"`
+}
+
+// OpenAPIServerVariable is a variable of a server URL
+type OpenAPIServerVariable struct {
+	Default string `yaml:"default"`
+}
+
+// OpenAPIComponents holds the reusable objects $refs point to
+type OpenAPIComponents struct {
+	Schemas       map[string]*OpenAPISchema      `yaml:"schemas"`
+	Parameters    map[string]*OpenAPIParameter   `yaml:"parameters"`
+	RequestBodies map[string]*OpenAPIRequestBody `yaml:"requestBodies"`
+}
+
+// OpenAPIPathItem holds the operations of a path
+type OpenAPIPathItem struct {
+	Parameters []*OpenAPIParameter `yaml:"parameters"`
+	Get        *OpenAPIOperation   `yaml:"get"`
+	Put        *OpenAPIOperation   `yaml:"put"`
+	Post       *OpenAPIOperation   `yaml:"post"`
+	Delete     *OpenAPIOperation   `yaml:"delete"`
+	Patch      *OpenAPIOperation   `yaml:"patch"`
+}
+
+func (p OpenAPIPathItem) operation(method string) *OpenAPIOperation {
+	switch method {
+	case "get":
+		return p.Get
+	case "put":
+		return p.Put
+	case "post":
+		return p.Post
+	case "delete":
+		return p.Delete
+	case "patch":
+		return p.Patch
+	}
+	return nil
+}
+
+// OpenAPIOperation is a single API operation
+type OpenAPIOperation struct {
+	OperationID string              `yaml:"operationId"`
+	Summary     string              `yaml:"summary"`
+	Description string              `yaml:"description"`
+	Parameters  []*OpenAPIParameter `yaml:"parameters"`
+	RequestBody *OpenAPIRequestBody `yaml:"requestBody"`
+}
+
+// OpenAPIParameter is a path, query, header or cookie parameter
+type OpenAPIParameter struct {
+	Ref         string         `yaml:"$ref"`
+	Name        string         `yaml:"name"`
+	In          string         `yaml:"in"`
+	Description string         `yaml:"description"`
+	Required    bool           `yaml:"required"`
+	Schema      *OpenAPISchema `yaml:"schema"`
+}
+
+// OpenAPIRequestBody is the body of an operation, by media type
+type OpenAPIRequestBody struct {
+	Ref         string                       `yaml:"$ref"`
+	Description string                       `yaml:"description"`
+	Required    bool                         `yaml:"required"`
+	Content     orderedMap[OpenAPIMediaType] `yaml:"content"`
+}
+
+// OpenAPIMediaType holds the schema of a body media type
+type OpenAPIMediaType struct {
+	Schema *OpenAPISchema `yaml:"schema"`
+}
+
+// OpenAPISchema is the subset of JSON Schema tool inputs are generated from
+type OpenAPISchema struct {
+	Ref         string                     `yaml:"$ref"`
+	Type        interface{}                `yaml:"type"`
+	Description string                     `yaml:"description"`
+	Enum        []interface{}              `yaml:"enum"`
+	Items       *OpenAPISchema             `yaml:"items"`
+	Properties  orderedMap[*OpenAPISchema] `yaml:"properties"`
+	Required    []string                   `yaml:"required"`
+}
+
+// typeName returns the schema's type. Of OpenAPI 3.1 type lists, the first
+// type other than null is used.
+func (s *OpenAPISchema) typeName() string {
+	switch t := s.Type.(type) {
+	case string:
+		return t
+	case []interface{}:
+		for _, item := range t {
+			if name, ok := item.(string); ok && name != "null" {
+				return name
+			}
+		}
+	}
+	if len(s.Properties.Keys) > 0 {
+		return "object"
+	}
+	return ""
+}
+
+// orderedMap is a YAML mapping that keeps the order of its keys, so tools
+// and fields are generated in the order of the spec
+type orderedMap[T any] struct {
+	Keys   []string
+	Values map[string]T
+}
+
+// UnmarshalYAML implements yaml.Unmarshaler
+func (m *orderedMap[T]) UnmarshalYAML(node *yaml.Node) error {
+	if node.Kind != yaml.MappingNode {
+		return fmt.Errorf("line %d: expected a mapping", node.Line)
+	}
+	m.Values = make(map[string]T, len(node.Content)/2)
+	for i := 0; i+1 < len(node.Content); i += 2 {
+		key := node.Content[i].Value
+		var value T
+		if err := node.Content[i+1].Decode(&value); err != nil {
+			return err
+		}
+		m.Keys = append(m.Keys, key)
+		m.Values[key] = value
+	}
+	return nil
+}
+
+// LoadOpenAPISpec reads an OpenAPI 3 spec in YAML or JSON
+func LoadOpenAPISpec(path string) (*OpenAPISpec, error) {
+	data, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return nil, fmt.Errorf("failed to read OpenAPI spec: %w", err)
+	}
+
+	var spec OpenAPISpec
+	if err := yaml.Unmarshal(data, &spec); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI spec %s: %w", path, err)
+	}
+	if spec.Swagger != "" {
+		return nil, fmt.Errorf("%s is a Swagger %s spec, convert it to OpenAPI 3 first", path, spec.Swagger)
+	}
+	if !strings.HasPrefix(spec.OpenAPI, "3.") {
+		return nil, fmt.Errorf("%s is not an OpenAPI 3 spec", path)
+	}
+	return &spec, nil
+}
+
+// ServerURL returns the first server URL of the spec, with variables set to
+// their defaults
+func (s *OpenAPISpec) ServerURL() (string, error) {
+	if len(s.Servers) == 0 {
+		return "", fmt.Errorf("the spec lists no servers, set the server URL explicitly")
+	}
+	server := s.Servers[0]
+	serverURL := server.URL
+	for name, variable := range server.Variables {
+		serverURL = strings.ReplaceAll(serverURL, "{"+name+"}", variable.Default)
+	}
+	return serverURL, nil
+}
+
+// OutboundHost returns the allowed_outbound_hosts entry of a server URL
+func OutboundHost(serverURL string) (string, error) {
+	u, err := url.Parse(serverURL)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return "", fmt.Errorf("server URL %q must be an absolute http or https URL", serverURL)
+	}
+	return u.Scheme + "://" + u.Host, nil
+}
+
+// OpenAPIOperationRef is an operation with its path, method and resolved
+// parameters
+type OpenAPIOperationRef struct {
+	// ID is the operationId, or derived from the method and path
+	ID     string
+	Method string
+	Path   string
+	*OpenAPIOperation
+	Parameters  []*OpenAPIParameter
+	RequestBody *OpenAPIRequestBody
+}
+
+// Operations returns the operations of the spec in the order they are
+// listed
+func (s *OpenAPISpec) Operations() ([]*OpenAPIOperationRef, error) {
+	var operations []*OpenAPIOperationRef
+	for _, path := range s.Paths.Keys {
+		item := s.Paths.Values[path]
+		for _, method := range openAPIMethods {
+			op := item.operation(method)
+			if op == nil {
+				continue
+			}
+			ref, err := s.resolveOperation(path, method, item, op)
+			if err != nil {
+				return nil, err
+			}
+			operations = append(operations, ref)
+		}
+	}
+	return operations, nil
+}
+
+// SelectOperations returns the operations with the given IDs, in the given
+// order, or all operations when ids is empty
+func (s *OpenAPISpec) SelectOperations(ids []string) ([]*OpenAPIOperationRef, error) {
+	operations, err := s.Operations()
+	if err != nil {
+		return nil, err
+	}
+	if len(operations) == 0 {
+		return nil, fmt.Errorf("the spec has no GET, PUT, POST, DELETE or PATCH operations")
+	}
+	if len(ids) == 0 {
+		return operations, nil
+	}
+
+	byID := make(map[string]*OpenAPIOperationRef, len(operations))
+	available := make([]string, 0, len(operations))
+	for _, op := range operations {
+		byID[op.ID] = op
+		available = append(available, op.ID)
+	}
+
+	selected := make([]*OpenAPIOperationRef, 0, len(ids))
+	seen := make(map[string]bool)
+	for _, id := range ids {
+		id = strings.TrimSpace(id)
+		if id == "" || seen[id] {
+			continue
+		}
+		op, ok := byID[id]
+		if !ok {
+			return nil, fmt.Errorf("operation '%s' not found in the spec, available operations: %s", id, strings.Join(available, ", "))
+		}
+		seen[id] = true
+		selected = append(selected, op)
+	}
+	return selected, nil
+}
+
+func (s *OpenAPISpec) resolveOperation(path, method string, item OpenAPIPathItem, op *OpenAPIOperation) (*OpenAPIOperationRef, error) {
+	id := op.OperationID
+	if id == "" {
+		id = method + " " + path
+	}
+
+	// Operation parameters override path parameters of the same name and location
+	var parameters []*OpenAPIParameter
+	index := make(map[string]int)
+	for _, params := range [][]*OpenAPIParameter{item.Parameters, op.Parameters} {
+		for _, param := range params {
+			resolved, err := s.resolveParameter(param)
+			if err != nil {
+				return nil, fmt.Errorf("operation %s: %w", id, err)
+			}
+			if resolved.In == "cookie" {
+				continue
+			}
+			key := resolved.In + ":" + resolved.Name
+			if i, ok := index[key]; ok {
+				parameters[i] = resolved
+				continue
+			}
+			index[key] = len(parameters)
+			parameters = append(parameters, resolved)
+		}
+	}
+
+	body := op.RequestBody
+	if body != nil && body.Ref != "" {
+		ref := body.Ref
+		name, err := componentName(ref, "requestBodies")
+		if err != nil {
+			return nil, fmt.Errorf("operation %s: %w", id, err)
+		}
+		if body = s.Components.RequestBodies[name]; body == nil {
+			return nil, fmt.Errorf("operation %s: request body %s not found", id, ref)
+		}
+	}
+
+	return &OpenAPIOperationRef{
+		ID:               toolName(id),
+		Method:           method,
+		Path:             path,
+		OpenAPIOperation: op,
+		Parameters:       parameters,
+		RequestBody:      body,
+	}, nil
+}
+
+func (s *OpenAPISpec) resolveParameter(param *OpenAPIParameter) (*OpenAPIParameter, error) {
+	if param.Ref == "" {
+		return param, nil
+	}
+	name, err := componentName(param.Ref, "parameters")
+	if err != nil {
+		return nil, err
+	}
+	resolved := s.Components.Parameters[name]
+	if resolved == nil {
+		return nil, fmt.Errorf("parameter %s not found", param.Ref)
+	}
+	return resolved, nil
+}
+
+// resolveSchema returns the schema a $ref points to and its name
+func (s *OpenAPISpec) resolveSchema(ref string) (*OpenAPISchema, string, error) {
+	name, err := componentName(ref, "schemas")
+	if err != nil {
+		return nil, "", err
+	}
+	schema := s.Components.Schemas[name]
+	if schema == nil {
+		return nil, "", fmt.Errorf("schema %s not found", ref)
+	}
+	return schema, name, nil
+}
+
+// deref follows the $refs of a schema
+func (s *OpenAPISpec) deref(schema *OpenAPISchema) (*OpenAPISchema, error) {
+	for seen := 0; schema != nil && schema.Ref != ""; seen++ {
+		if seen == len(s.Components.Schemas) {
+			return nil, fmt.Errorf("schema %s refers to itself", schema.Ref)
+		}
+		target, _, err := s.resolveSchema(schema.Ref)
+		if err != nil {
+			return nil, err
+		}
+		schema = target
+	}
+	return schema, nil
+}
+
+// componentName returns the name of a local #/components/<kind>/<name> ref
+func componentName(ref, kind string) (string, error) {
+	prefix := "#/components/" + kind + "/"
+	if !strings.HasPrefix(ref, prefix) {
+		return "", fmt.Errorf("unsupported $ref %s, only refs to #/components/%s in the same file are supported", ref, kind)
+	}
+	return strings.TrimPrefix(ref, prefix), nil
+}
+
+// toolName returns the operation ID unchanged when it is a usable identifier,
+// so it can be matched against --operations; method-and-path IDs become
+// get_pets_pet_id style names
+func toolName(id string) string {
+	if !strings.ContainsAny(id, " /{}") {
+		return id
+	}
+	return snakeCase(id)
+}
+
+// splitWords splits an identifier or phrase into lowercase words, at
+// non-alphanumeric characters and camelCase boundaries
+func splitWords(s string) []string {
+	var words []string
+	var current []rune
+	runes := []rune(s)
+	flush := func() {
+		if len(current) > 0 {
+			words = append(words, strings.ToLower(string(current)))
+			current = nil
+		}
+	}
+	for i, r := range runes {
+		if !unicode.IsLetter(r) && !unicode.IsDigit(r) {
+			flush()
+			continue
+		}
+		if unicode.IsUpper(r) && len(current) > 0 {
+			prev := runes[i-1]
+			nextLower := i+1 < len(runes) && unicode.IsLower(runes[i+1])
+			if unicode.IsLower(prev) || unicode.IsDigit(prev) || (unicode.IsUpper(prev) && nextLower) {
+				flush()
+			}
+		}
+		current = append(current, r)
+	}
+	flush()
+	return words
+}
+
+// snakeCase converts an identifier to snake_case
+func snakeCase(s string) string {
+	return strings.Join(splitWords(s), "_")
+}
+
+// pascalCase converts an identifier to PascalCase
+func pascalCase(s string) string {
+	words := splitWords(s)
+	for i, word := range words {
+		runes := []rune(word)
+		runes[0] = unicode.ToUpper(runes[0])
+		words[i] = string(runes)
+	}
+	return strings.Join(words, "")
+}
@@ -0,0 +1,516 @@
+package scaffold
+
+import (
+	"fmt"
+	"strings"
+)
+
+// rustKeywords can't be used as field names without the r# prefix
+var rustKeywords = map[string]bool{
+	"as": true, "async": true, "await": true, "break": true, "const": true, "continue": true,
+	"dyn": true, "else": true, "enum": true, "extern": true, "false": true, "fn": true,
+	"for": true, "if": true, "impl": true, "in": true, "let": true, "loop": true,
+	"match": true, "mod": true, "move": true, "mut": true, "pub": true, "ref": true,
+	"return": true, "static": true, "struct": true, "trait": true, "true": true,
+	"type": true, "unsafe": true, "use": true, "where": true, "while": true,
+	"abstract": true, "become": true, "box": true, "do": true, "final": true, "gen": true,
+	"macro": true, "override": true, "priv": true, "try": true, "typeof": true,
+	"unsized": true, "virtual": true, "yield": true,
+}
+
+// rustGenerator writes the tools of OpenAPI operations as a Rust component
+type rustGenerator struct {
+	spec *OpenAPISpec
+	// structs are the generated type definitions, dependencies first
+	structs []string
+	// types are the names of the generated types
+	types map[string]bool
+	// refs maps schema refs to their generated type
+	refs map[string]string
+	// resolving are the schema refs being generated, to stop at recursion
+	resolving map[string]bool
+	// serialize is whether any struct is generated for a JSON body
+	serialize bool
+	// pathParams is whether any operation has path parameters
+	pathParams bool
+}
+
+// rustField is a field of a generated struct
+type rustField struct {
+	// name is the JSON name
+	name  string
+	ident string
+	typ   string
+	doc   string
+}
+
+// GenerateRustTools returns the src/lib.rs of a component with one tool per
+// operation, each calling the operation at baseURL
+func GenerateRustTools(spec *OpenAPISpec, operations []*OpenAPIOperationRef, baseURL string) (string, error) {
+	g := &rustGenerator{
+		spec:      spec,
+		types:     make(map[string]bool),
+		refs:      make(map[string]string),
+		resolving: make(map[string]bool),
+	}
+
+	var tools []string
+	names := make(map[string]bool)
+	for _, op := range operations {
+		name := snakeCase(op.ID)
+		if name == "" || names[name] {
+			return "", fmt.Errorf("operation '%s' has no usable unique tool name", op.ID)
+		}
+		if rustKeywords[name] || (name[0] >= '0' && name[0] <= '9') {
+			name = "op_" + name
+		}
+		names[name] = true
+
+		tool, err := g.tool(name, op)
+		if err != nil {
+			return "", fmt.Errorf("operation %s: %w", op.ID, err)
+		}
+		tools = append(tools, tool)
+	}
+
+	var b strings.Builder
+	title := spec.Info.Title
+	if title == "" {
+		title = "an OpenAPI spec"
+	}
+	fmt.Fprintf(&b, "//! Tools calling %s, generated by `ftl add --from-openapi`\n\n", singleLine(title))
+	b.WriteString("use ftl_sdk::http::{Client, SpinTransport};\n")
+	b.WriteString("use ftl_sdk::{ToolContext, ToolResponse, tools};\n")
+	b.WriteString("use schemars::JsonSchema;\n")
+	if g.serialize {
+		b.WriteString("use serde::{Deserialize, Serialize};\n")
+	} else {
+		b.WriteString("use serde::Deserialize;\n")
+	}
+	b.WriteString("\n/// Base URL of the API\n")
+	fmt.Fprintf(&b, "const BASE_URL: &str = %s;\n\n", rustString(baseURL))
+	b.WriteString("/// Client for the API, propagating the caller's trace id. Add credentials\n")
+	b.WriteString("/// here, e.g. `.bearer_auth(token)`.\n")
+	b.WriteString("fn client(ctx: &ToolContext) -> Client<SpinTransport> {\n")
+	b.WriteString("    Client::new().base_url(BASE_URL).context(ctx)\n")
+	b.WriteString("}\n")
+	if g.pathParams {
+		b.WriteString("\n/// Percent-encode a path parameter\n")
+		b.WriteString("fn path_param(value: impl std::fmt::Display) -> String {\n")
+		b.WriteString("    let mut encoded = String::new();\n")
+		b.WriteString("    for byte in value.to_string().bytes() {\n")
+		b.WriteString("        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {\n")
+		b.WriteString("            encoded.push(char::from(byte));\n")
+		b.WriteString("        } else {\n")
+		b.WriteString("            encoded.push_str(&format!(\"%{byte:02X}\"));\n")
+		b.WriteString("        }\n")
+		b.WriteString("    }\n")
+		b.WriteString("    encoded\n")
+		b.WriteString("}\n")
+	}
+	for _, def := range g.structs {
+		b.WriteString("\n")
+		b.WriteString(def)
+	}
+	b.WriteString("\ntools! {\n")
+	b.WriteString(strings.Join(tools, "\n"))
+	b.WriteString("}\n")
+	return b.String(), nil
+}
+
+// tool generates the input struct of an operation and returns its tool
+func (g *rustGenerator) tool(name string, op *OpenAPIOperationRef) (string, error) {
+	inputName := g.typeName(pascalCase(name) + "Input")
+	idents := make(map[string]bool)
+
+	var fields []rustField
+	var stmts []string
+	for _, param := range op.Parameters {
+		typ, err := g.paramType(param.Schema)
+		if err != nil {
+			return "", fmt.Errorf("parameter %s: %w", param.Name, err)
+		}
+		required := param.Required || param.In == "path"
+		if !required {
+			typ = "Option<" + typ + ">"
+		}
+		doc := param.Description
+		if schema, _ := g.spec.deref(param.Schema); schema != nil {
+			doc = enumDoc(doc, schema.Enum)
+		}
+		field := rustField{name: param.Name, ident: uniqueIdent(param.Name, idents), typ: typ, doc: doc}
+		fields = append(fields, field)
+
+		access := "input." + field.ident
+		switch param.In {
+		case "query":
+			stmts = append(stmts, paramStatement(access, typ, required, func(value string) string {
+				return fmt.Sprintf("request = request.query(%s, %s);", rustString(param.Name), value)
+			}))
+		case "header":
+			stmts = append(stmts, paramStatement(access, typ, required, func(value string) string {
+				return fmt.Sprintf("request = request.header(%s, %s.to_string());", rustString(param.Name), value)
+			}))
+		}
+	}
+
+	// The request path, with path parameters percent-encoded
+	path := op.Path
+	var pathArgs []string
+	for i, param := range op.Parameters {
+		placeholder := "{" + param.Name + "}"
+		if param.In != "path" || !strings.Contains(path, placeholder) {
+			continue
+		}
+		path = strings.Replace(path, placeholder, "\x00", 1)
+		pathArgs = append(pathArgs, fmt.Sprintf("path_param(&input.%s)", fields[i].ident))
+	}
+	pathExpr := rustString(path)
+	if len(pathArgs) > 0 {
+		g.pathParams = true
+		format := strings.ReplaceAll(strings.ReplaceAll(path, "{", "{{"), "}", "}}")
+		format = strings.ReplaceAll(format, "\x00", "{}")
+		pathExpr = fmt.Sprintf("&format!(%s, %s)", rustString(format), strings.Join(pathArgs, ", "))
+	}
+
+	// The request body, sent as JSON when the operation accepts JSON
+	if body := op.RequestBody; body != nil && len(body.Content.Keys) > 0 {
+		mediaType, isJSON := bodyMediaType(body)
+		field := rustField{name: "body", ident: uniqueIdent("body", idents), doc: body.Description}
+		if isJSON {
+			typ, err := g.rustType(body.Content.Values[mediaType].Schema, pascalCase(name)+"Body")
+			if err != nil {
+				return "", fmt.Errorf("request body: %w", err)
+			}
+			field.typ = typ
+		} else {
+			field.typ = "String"
+			stmts = append(stmts, fmt.Sprintf("request = request.header(\"content-type\", %s);", rustString(mediaType)))
+		}
+		if !body.Required {
+			field.typ = "Option<" + field.typ + ">"
+		}
+		fields = append(fields, field)
+
+		send := func(value string) string {
+			if isJSON {
+				return fmt.Sprintf("request = request.json_body(%s);", value)
+			}
+			return fmt.Sprintf("request = request.body(%s);", value)
+		}
+		access := "input." + field.ident
+		switch {
+		case body.Required && isJSON:
+			stmts = append(stmts, send("&"+access))
+		case body.Required:
+			stmts = append(stmts, send(access))
+		case isJSON:
+			stmts = append(stmts, fmt.Sprintf("if let Some(body) = &%s {\n    %s\n}", access, send("body")))
+		default:
+			stmts = append(stmts, fmt.Sprintf("if let Some(body) = %s {\n    %s\n}", access, send("body")))
+		}
+	}
+
+	g.structs = append(g.structs, rustStruct(inputName, "Input of the `"+name+"` tool", fields, false))
+
+	var b strings.Builder
+	writeDoc(&b, "    ", operationDoc(op))
+	fmt.Fprintf(&b, "    async fn %s(input: %s, ctx: ToolContext) -> ToolResponse {\n", name, inputName)
+	b.WriteString("        let client = client(&ctx);\n")
+	if len(stmts) == 0 {
+		fmt.Fprintf(&b, "        let request = client.%s(%s);\n", op.Method, pathExpr)
+	} else {
+		fmt.Fprintf(&b, "        let mut request = client.%s(%s);\n", op.Method, pathExpr)
+		for _, stmt := range stmts {
+			for _, line := range strings.Split(stmt, "\n") {
+				b.WriteString("        " + line + "\n")
+			}
+		}
+	}
+	b.WriteString("        match request.text().await {\n")
+	b.WriteString("            Ok(body) => ToolResponse::text(body),\n")
+	b.WriteString("            Err(e) => e.into(),\n")
+	b.WriteString("        }\n")
+	b.WriteString("    }\n")
+	return b.String(), nil
+}
+
+// paramStatement returns the statements adding a parameter to the request,
+// repeating array parameters and skipping absent optional ones
+func paramStatement(access, typ string, required bool, add func(value string) string) string {
+	inner, value := typ, "&"+access
+	if !required {
+		inner, value = strings.TrimSuffix(strings.TrimPrefix(typ, "Option<"), ">"), "value"
+	}
+	stmt := add(value)
+	if strings.HasPrefix(inner, "Vec<") {
+		stmt = fmt.Sprintf("for item in %s {\n    %s\n}", value, add("item"))
+	}
+	if required {
+		return stmt
+	}
+	return fmt.Sprintf("if let Some(value) = &%s {\n%s\n}", access, indent(stmt, "    "))
+}
+
+// rustType returns the Rust type of a schema, generating structs for objects
+func (g *rustGenerator) rustType(schema *OpenAPISchema, hint string) (string, error) {
+	if schema == nil {
+		return "serde_json::Value", nil
+	}
+	if schema.Ref != "" {
+		if typ, ok := g.refs[schema.Ref]; ok {
+			return typ, nil
+		}
+		if g.resolving[schema.Ref] {
+			// Recursive types are passed through untyped
+			return "serde_json::Value", nil
+		}
+		target, name, err := g.spec.resolveSchema(schema.Ref)
+		if err != nil {
+			return "", err
+		}
+		g.resolving[schema.Ref] = true
+		typ, err := g.rustType(target, pascalCase(name))
+		delete(g.resolving, schema.Ref)
+		if err != nil {
+			return "", err
+		}
+		g.refs[schema.Ref] = typ
+		return typ, nil
+	}
+
+	switch schema.typeName() {
+	case "string":
+		return "String", nil
+	case "integer":
+		return "i64", nil
+	case "number":
+		return "f64", nil
+	case "boolean":
+		return "bool", nil
+	case "array":
+		item, err := g.rustType(schema.Items, hint+"Item")
+		if err != nil {
+			return "", err
+		}
+		return "Vec<" + item + ">", nil
+	case "object":
+		if len(schema.Properties.Keys) == 0 {
+			return "serde_json::Value", nil
+		}
+		return g.objectType(schema, hint)
+	}
+	return "serde_json::Value", nil
+}
+
+// paramType returns the Rust type of a parameter. Parameters are sent as
+// text, so objects are taken as JSON values.
+func (g *rustGenerator) paramType(schema *OpenAPISchema) (string, error) {
+	schema, err := g.spec.deref(schema)
+	if err != nil || schema == nil {
+		return "serde_json::Value", err
+	}
+	switch schema.typeName() {
+	case "string", "integer", "number", "boolean":
+		return g.rustType(schema, "")
+	case "array":
+		item, err := g.spec.deref(schema.Items)
+		if err != nil {
+			return "", err
+		}
+		if item != nil && item.typeName() != "array" && item.typeName() != "object" && item.typeName() != "" {
+			typ, err := g.rustType(item, "")
+			return "Vec<" + typ + ">", err
+		}
+	}
+	return "serde_json::Value", nil
+}
+
+// objectType generates a struct for an object schema
+func (g *rustGenerator) objectType(schema *OpenAPISchema, hint string) (string, error) {
+	name := g.typeName(hint)
+	required := make(map[string]bool, len(schema.Required))
+	for _, prop := range schema.Required {
+		required[prop] = true
+	}
+
+	idents := make(map[string]bool)
+	var fields []rustField
+	for _, prop := range schema.Properties.Keys {
+		propSchema := schema.Properties.Values[prop]
+		typ, err := g.rustType(propSchema, name+pascalCase(prop))
+		if err != nil {
+			return "", fmt.Errorf("property %s: %w", prop, err)
+		}
+		if !required[prop] {
+			typ = "Option<" + typ + ">"
+		}
+		doc := ""
+		if propSchema != nil {
+			doc = enumDoc(propSchema.Description, propSchema.Enum)
+		}
+		fields = append(fields, rustField{name: prop, ident: uniqueIdent(prop, idents), typ: typ, doc: doc})
+	}
+
+	g.serialize = true
+	g.structs = append(g.structs, rustStruct(name, schema.Description, fields, true))
+	return name, nil
+}
+
+// typeName returns an unused type name based on hint
+func (g *rustGenerator) typeName(hint string) string {
+	if hint == "" || (hint[0] >= '0' && hint[0] <= '9') {
+		hint = "Type" + hint
+	}
+	name := hint
+	for i := 2; g.types[name]; i++ {
+		name = fmt.Sprintf("%s%d", hint, i)
+	}
+	g.types[name] = true
+	return name
+}
+
+// rustStruct returns the definition of a struct. Body types are serialized
+// too, skipping absent optional fields.
+func rustStruct(name, doc string, fields []rustField, body bool) string {
+	var b strings.Builder
+	writeDoc(&b, "", doc)
+	if body {
+		b.WriteString("#[derive(Debug, Serialize, Deserialize, JsonSchema)]\n")
+	} else {
+		b.WriteString("#[derive(Debug, Deserialize, JsonSchema)]\n")
+	}
+	if len(fields) == 0 {
+		fmt.Fprintf(&b, "pub struct %s {}\n", name)
+		return b.String()
+	}
+	fmt.Fprintf(&b, "pub struct %s {\n", name)
+	for _, field := range fields {
+		writeDoc(&b, "    ", field.doc)
+		var attrs []string
+		if strings.TrimPrefix(field.ident, "r#") != field.name {
+			attrs = append(attrs, "rename = "+rustString(field.name))
+		}
+		if body && strings.HasPrefix(field.typ, "Option<") {
+			attrs = append(attrs, `skip_serializing_if = "Option::is_none"`)
+		}
+		if len(attrs) > 0 {
+			fmt.Fprintf(&b, "    #[serde(%s)]\n", strings.Join(attrs, ", "))
+		}
+		fmt.Fprintf(&b, "    pub %s: %s,\n", field.ident, field.typ)
+	}
+	b.WriteString("}\n")
+	return b.String()
+}
+
+// uniqueIdent returns an unused snake_case field name for a JSON name
+func uniqueIdent(name string, used map[string]bool) string {
+	base := snakeCase(name)
+	switch {
+	case base == "":
+		base = "field"
+	case base[0] >= '0' && base[0] <= '9':
+		base = "field_" + base
+	}
+	ident := base
+	for i := 2; used[ident]; i++ {
+		ident = fmt.Sprintf("%s_%d", base, i)
+	}
+	used[ident] = true
+	switch {
+	case ident == "self" || ident == "super" || ident == "crate":
+		// These can't be raw identifiers
+		return ident + "_"
+	case rustKeywords[ident]:
+		return "r#" + ident
+	}
+	return ident
+}
+
+// bodyMediaType returns the media type a body is sent as, preferring JSON
+func bodyMediaType(body *OpenAPIRequestBody) (string, bool) {
+	for _, mediaType := range body.Content.Keys {
+		if mediaType == "application/json" || strings.HasSuffix(mediaType, "+json") {
+			return mediaType, true
+		}
+	}
+	return body.Content.Keys[0], false
+}
+
+// operationDoc describes an operation for its tool description
+func operationDoc(op *OpenAPIOperationRef) string {
+	switch {
+	case op.Summary != "":
+		return op.Summary
+	case op.Description != "":
+		return op.Description
+	}
+	return strings.ToUpper(op.Method) + " " + op.Path
+}
+
+// enumDoc appends the values of an enum to a description
+func enumDoc(doc string, enum []interface{}) string {
+	if len(enum) > 0 {
+		values := make([]string, 0, len(enum))
+		for _, value := range enum {
+			values = append(values, fmt.Sprint(value))
+		}
+		if doc != "" {
+			doc += "\n\n"
+		}
+		doc += "One of: " + strings.Join(values, ", ")
+	}
+	return doc
+}
+
+// writeDoc writes text as /// comment lines
+func writeDoc(b *strings.Builder, prefix, text string) {
+	text = strings.TrimSpace(text)
+	if text == "" {
+		return
+	}
+	for _, line := range strings.Split(text, "\n") {
+		line = strings.TrimRight(line, " \t\r")
+		if line == "" {
+			b.WriteString(prefix + "///\n")
+			continue
+		}
+		b.WriteString(prefix + "/// " + line + "\n")
+	}
+}
+
+// rustString returns s as a Rust string literal
+func rustString(s string) string {
+	var b strings.Builder
+	b.WriteByte('"')
+	for _, r := range s {
+		switch r {
+		case '"':
+			b.WriteString(`\"`)
+		case '\\':
+			b.WriteString(`\\`)
+		case '\n':
+			b.WriteString(`\n`)
+		case '\r':
+			b.WriteString(`\r`)
+		case '\t':
+			b.WriteString(`\t`)
+		default:
+			b.WriteRune(r)
+		}
+	}
+	b.WriteByte('"')
+	return b.String()
+}
+
+func singleLine(s string) string {
+	return strings.Join(strings.Fields(s), " ")
+}
+
+func indent(s, prefix string) string {
+	lines := strings.Split(s, "\n")
+	for i, line := range lines {
+		lines[i] = prefix + line
+	}
+	return strings.Join(lines, "\n")
+}
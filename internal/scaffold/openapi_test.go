@@ -0,0 +1,248 @@
+package scaffold
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"gopkg.in/yaml.v3"
+)
+
+const petstoreSpec = `openapi: 3.0.3
+info:
+  title: Swagger Petstore
+servers:
+  - url: https://{region}.petstore.example/api/v3
+    variables:
+      region:
+        default: eu
+paths:
+  /pet:
+    post:
+      operationId: addPet
+      summary: Add a new pet to the store
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/Pet'
+  /pet/findByStatus:
+    get:
+      operationId: findPetsByStatus
+      summary: Finds pets by status
+      parameters:
+        - name: status
+          in: query
+          description: Status values to filter by
+          schema:
+            type: string
+            enum: [available, pending, sold]
+        - name: tags
+          in: query
+          schema:
+            type: array
+            items:
+              type: string
+  /pet/{petId}:
+    parameters:
+      - $ref: '#/components/parameters/PetId'
+    get:
+      operationId: getPetById
+      description: Returns a single pet
+      parameters:
+        - name: X-Request-Id
+          in: header
+          required: true
+          schema:
+            type: string
+    delete:
+      operationId: deletePet
+  /store/inventory:
+    get:
+      summary: Returns pet inventories
+components:
+  parameters:
+    PetId:
+      name: petId
+      in: path
+      required: true
+      description: ID of the pet
+      schema:
+        type: integer
+  schemas:
+    Pet:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+        photoUrls:
+          type: array
+          items:
+            type: string
+        category:
+          type: object
+          properties:
+            id:
+              type: integer
+        type:
+          type: string
+        parent:
+          $ref: '#/components/schemas/Pet'
+`
+
+func loadPetstore(t *testing.T) *OpenAPISpec {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "petstore.yaml")
+	require.NoError(t, os.WriteFile(path, []byte(petstoreSpec), 0600))
+	spec, err := LoadOpenAPISpec(path)
+	require.NoError(t, err)
+	return spec
+}
+
+func TestLoadOpenAPISpec(t *testing.T) {
+	spec := loadPetstore(t)
+	assert.Equal(t, "Swagger Petstore", spec.Info.Title)
+
+	serverURL, err := spec.ServerURL()
+	require.NoError(t, err)
+	assert.Equal(t, "https://eu.petstore.example/api/v3", serverURL)
+
+	dir := t.TempDir()
+	swagger := filepath.Join(dir, "swagger.json")
+	require.NoError(t, os.WriteFile(swagger, []byte(`{"swagger": "2.0", "paths": {}}`), 0600))
+	_, err = LoadOpenAPISpec(swagger)
+	assert.ErrorContains(t, err, "convert it to OpenAPI 3")
+}
+
+func TestSelectOperations(t *testing.T) {
+	spec := loadPetstore(t)
+
+	all, err := spec.SelectOperations(nil)
+	require.NoError(t, err)
+	var ids []string
+	for _, op := range all {
+		ids = append(ids, op.ID)
+	}
+	// Spec order, with IDs derived for operations without an operationId
+	assert.Equal(t, []string{"addPet", "findPetsByStatus", "getPetById", "deletePet", "get_store_inventory"}, ids)
+
+	selected, err := spec.SelectOperations([]string{"getPetById", "addPet"})
+	require.NoError(t, err)
+	require.Len(t, selected, 2)
+	assert.Equal(t, "getPetById", selected[0].ID)
+	assert.Equal(t, "/pet/{petId}", selected[0].Path)
+	// Path-level parameters are merged with the operation's
+	require.Len(t, selected[0].Parameters, 2)
+	assert.Equal(t, "petId", selected[0].Parameters[0].Name)
+	assert.Equal(t, "X-Request-Id", selected[0].Parameters[1].Name)
+
+	_, err = spec.SelectOperations([]string{"getPet"})
+	assert.ErrorContains(t, err, "operation 'getPet' not found")
+	assert.ErrorContains(t, err, "getPetById")
+}
+
+func TestOutboundHost(t *testing.T) {
+	host, err := OutboundHost("https://eu.petstore.example/api/v3")
+	require.NoError(t, err)
+	assert.Equal(t, "https://eu.petstore.example", host)
+
+	host, err = OutboundHost("http://localhost:8080/")
+	require.NoError(t, err)
+	assert.Equal(t, "http://localhost:8080", host)
+
+	_, err = OutboundHost("/api/v3")
+	assert.ErrorContains(t, err, "absolute http or https URL")
+}
+
+func TestCaseConversion(t *testing.T) {
+	assert.Equal(t, "get_pet_by_id", snakeCase("getPetById"))
+	assert.Equal(t, "get_http_status", snakeCase("getHTTPStatus"))
+	assert.Equal(t, "x_request_id", snakeCase("X-Request-Id"))
+	assert.Equal(t, "GetPetByIdInput", pascalCase("get_pet_by_id_input"))
+	assert.Equal(t, "PhotoUrls", pascalCase("photoUrls"))
+}
+
+func TestGenerateRustTools(t *testing.T) {
+	spec := loadPetstore(t)
+	operations, err := spec.SelectOperations(nil)
+	require.NoError(t, err)
+
+	source, err := GenerateRustTools(spec, operations, "https://eu.petstore.example/api/v3")
+	require.NoError(t, err)
+
+	assert.Contains(t, source, `const BASE_URL: &str = "https://eu.petstore.example/api/v3";`)
+	assert.Contains(t, source, "use serde::{Deserialize, Serialize};")
+
+	// Request body structs, with recursive refs left untyped
+	assert.Contains(t, source, "pub struct Pet {\n")
+	assert.Contains(t, source, "    pub name: String,\n")
+	assert.Contains(t, source, "    #[serde(rename = \"photoUrls\", skip_serializing_if = \"Option::is_none\")]\n    pub photo_urls: Option<Vec<String>>,\n")
+	assert.Contains(t, source, "    pub category: Option<PetCategory>,\n")
+	assert.Contains(t, source, "    pub r#type: Option<String>,\n")
+	assert.Contains(t, source, "    pub parent: Option<serde_json::Value>,\n")
+
+	// Inputs and calls
+	assert.Contains(t, source, "pub struct AddPetInput {\n    pub body: Pet,\n}")
+	assert.Contains(t, source, "    /// Add a new pet to the store\n    async fn add_pet(input: AddPetInput, ctx: ToolContext) -> ToolResponse {\n")
+	assert.Contains(t, source, "request = request.json_body(&input.body);")
+
+	assert.Contains(t, source, "    /// Status values to filter by\n    ///\n    /// One of: available, pending, sold\n")
+	assert.Contains(t, source, "        if let Some(value) = &input.status {\n            request = request.query(\"status\", value);\n        }\n")
+	assert.Contains(t, source, "            for item in value {\n                request = request.query(\"tags\", item);\n            }\n")
+
+	assert.Contains(t, source, "    #[serde(rename = \"petId\")]\n    pub pet_id: i64,\n")
+	assert.Contains(t, source, `let mut request = client.get(&format!("/pet/{}", path_param(&input.pet_id)));`)
+	assert.Contains(t, source, `request = request.header("X-Request-Id", &input.x_request_id.to_string());`)
+	assert.Contains(t, source, "fn path_param(value: impl std::fmt::Display) -> String {")
+
+	assert.Contains(t, source, `let request = client.delete(&format!("/pet/{}", path_param(&input.pet_id)));`)
+	assert.Contains(t, source, "pub struct GetStoreInventoryInput {}")
+	assert.Contains(t, source, "    /// Returns pet inventories\n    async fn get_store_inventory(")
+	assert.Contains(t, source, `let request = client.get("/store/inventory");`)
+}
+
+func TestGenerateOpenAPIComponent(t *testing.T) {
+	scaffolder, err := NewScaffolder()
+	require.NoError(t, err)
+	spec := loadPetstore(t)
+	operations, err := spec.SelectOperations([]string{"getPetById"})
+	require.NoError(t, err)
+
+	tmpDir := t.TempDir()
+	oldWd, _ := os.Getwd()
+	defer func() { _ = os.Chdir(oldWd) }()
+	require.NoError(t, os.Chdir(tmpDir))
+	require.NoError(t, os.WriteFile("ftl.yaml", []byte("name: test-app\nversion: 0.1.0\n"), 0600))
+
+	require.NoError(t, scaffolder.GenerateOpenAPIComponent("petstore", spec, operations, ""))
+
+	source, err := os.ReadFile("petstore/src/lib.rs")
+	require.NoError(t, err)
+	assert.Contains(t, string(source), "async fn get_pet_by_id(")
+	assert.NotContains(t, string(source), "example_tool")
+
+	cargo, err := os.ReadFile("petstore/Cargo.toml")
+	require.NoError(t, err)
+	assert.Contains(t, string(cargo), `features = ["macros", "spin"]`)
+
+	data, err := os.ReadFile("ftl.yaml")
+	require.NoError(t, err)
+	var config map[string]interface{}
+	require.NoError(t, yaml.Unmarshal(data, &config))
+	components := config["components"].([]interface{})
+	require.Len(t, components, 1)
+	component := components[0].(map[string]interface{})
+	assert.Equal(t, "petstore", component["id"])
+	assert.Equal(t, []interface{}{"https://eu.petstore.example"}, component["allowed_outbound_hosts"])
+
+	// Relative server URLs need an explicit base URL
+	require.NoError(t, os.Chdir(t.TempDir()))
+	spec.Servers = []OpenAPIServer{{URL: "/api/v3"}}
+	err = scaffolder.GenerateOpenAPIComponent("petstore", spec, operations, "")
+	assert.ErrorContains(t, err, "absolute http or https URL")
+	assert.NoDirExists(t, "petstore")
+}
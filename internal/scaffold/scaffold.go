@@ -120,7 +120,62 @@ func (s *Scaffolder) GenerateComponent(name, language string) error {
 	}
 
 	// Update ftl.yaml
-	if err := s.updateFTLConfig(name, component); err != nil {
+	if err := s.updateFTLConfig(name, component, nil); err != nil {
+		return fmt.Errorf("failed to update ftl.yaml: %w", err)
+	}
+
+	return nil
+}
+
+// GenerateOpenAPIComponent creates a Rust component with one tool per
+// operation of an OpenAPI spec, calling the API at serverURL (the spec's
+// first server when empty), and adds it to ftl.yaml with the API's host in
+// allowed_outbound_hosts
+func (s *Scaffolder) GenerateOpenAPIComponent(name string, spec *OpenAPISpec, operations []*OpenAPIOperationRef, serverURL string) error {
+	if err := s.validateInputs(name, "rust"); err != nil {
+		return err
+	}
+
+	if serverURL == "" {
+		var err error
+		if serverURL, err = spec.ServerURL(); err != nil {
+			return err
+		}
+	}
+	host, err := OutboundHost(serverURL)
+	if err != nil {
+		return err
+	}
+
+	// Generate the tools before writing anything, so invalid specs leave no files behind
+	source, err := GenerateRustTools(spec, operations, serverURL)
+	if err != nil {
+		return fmt.Errorf("failed to generate tools: %w", err)
+	}
+
+	component, err := s.createComponentInstance(name, "rust")
+	if err != nil {
+		return fmt.Errorf("failed to create component instance: %w", err)
+	}
+	if err := s.generateFiles(name, component); err != nil {
+		return fmt.Errorf("failed to generate files: %w", err)
+	}
+
+	// Replace the example tool and enable the SDK's outbound HTTP client
+	if err := os.WriteFile(filepath.Join(name, "src", "lib.rs"), []byte(source), 0600); err != nil {
+		return fmt.Errorf("failed to write tools: %w", err)
+	}
+	cargoPath := filepath.Join(name, "Cargo.toml")
+	cargo, err := os.ReadFile(filepath.Clean(cargoPath))
+	if err != nil {
+		return fmt.Errorf("failed to read Cargo.toml: %w", err)
+	}
+	cargo = bytes.Replace(cargo, []byte(`features = ["macros"]`), []byte(`features = ["macros", "spin"]`), 1)
+	if err := os.WriteFile(cargoPath, cargo, 0600); err != nil {
+		return fmt.Errorf("failed to write Cargo.toml: %w", err)
+	}
+
+	if err := s.updateFTLConfig(name, component, []string{host}); err != nil {
 		return fmt.Errorf("failed to update ftl.yaml: %w", err)
 	}
 
@@ -184,6 +239,103 @@ func (s *Scaffolder) GenerateProject(projectDir, name, description, format strin
 	return nil
 }
 
+// CIProviders are the CI systems GenerateCI can create pipelines for
+var CIProviders = []string{"github", "gitlab"}
+
+// GenerateCI creates a CI pipeline for the project that builds, tests and
+// deploys it, setting up the toolchains of the given component languages.
+// It returns the paths of the generated files, relative to projectDir.
+func (s *Scaffolder) GenerateCI(projectDir, provider string, languages []string) ([]string, error) {
+	valid := false
+	for _, p := range CIProviders {
+		if p == provider {
+			valid = true
+			break
+		}
+	}
+	if !valid {
+		return nil, fmt.Errorf("invalid CI provider '%s': must be one of %v", provider, CIProviders)
+	}
+
+	// Get CI template
+	templatePath := fmt.Sprintf("#CITemplates.%s", provider)
+	templateValue := s.templates.LookupPath(cue.ParsePath(templatePath))
+	if templateValue.Err() != nil {
+		return nil, fmt.Errorf("failed to get CI template: %w", templateValue.Err())
+	}
+
+	if languages == nil {
+		languages = []string{}
+	}
+	filled := templateValue.FillPath(cue.ParsePath("languages"), languages)
+	if filled.Err() != nil {
+		return nil, fmt.Errorf("failed to fill CI template: %w", filled.Err())
+	}
+
+	iter, err := filled.LookupPath(cue.ParsePath("files")).Fields()
+	if err != nil {
+		return nil, fmt.Errorf("failed to iterate CI files: %w", err)
+	}
+
+	var written []string
+	for iter.Next() {
+		filename := iter.Selector().Unquoted()
+		content, err := iter.Value().String()
+		if err != nil {
+			return nil, fmt.Errorf("failed to extract content for %s: %w", filename, err)
+		}
+
+		filePath := filepath.Join(projectDir, filepath.FromSlash(filename))
+		if err := os.MkdirAll(filepath.Dir(filePath), 0750); err != nil {
+			return nil, fmt.Errorf("failed to create directory for %s: %w", filename, err)
+		}
+		if err := os.WriteFile(filePath, []byte(content+"\n"), 0600); err != nil {
+			return nil, fmt.Errorf("failed to write %s: %w", filename, err)
+		}
+		written = append(written, filename)
+	}
+
+	return written, nil
+}
+
+// languageMarkers maps the files that identify a component's language
+var languageMarkers = map[string]string{
+	"Cargo.toml":     "rust",
+	"package.json":   "typescript",
+	"pyproject.toml": "python",
+	"go.mod":         "go",
+}
+
+// DetectLanguages returns the languages used in a project, by looking for
+// language marker files in the project directory and its direct
+// subdirectories
+func DetectLanguages(projectDir string) []string {
+	found := make(map[string]bool)
+	check := func(dir string) {
+		for marker, language := range languageMarkers {
+			if _, err := os.Stat(filepath.Join(dir, marker)); err == nil {
+				found[language] = true
+			}
+		}
+	}
+
+	check(projectDir)
+	entries, _ := os.ReadDir(projectDir)
+	for _, entry := range entries {
+		if entry.IsDir() && !strings.HasPrefix(entry.Name(), ".") && entry.Name() != "node_modules" {
+			check(filepath.Join(projectDir, entry.Name()))
+		}
+	}
+
+	var languages []string
+	for _, language := range []string{"rust", "typescript", "python", "go"} {
+		if found[language] {
+			languages = append(languages, language)
+		}
+	}
+	return languages
+}
+
 // validateInputs checks that the name and language are valid
 func (s *Scaffolder) validateInputs(name, language string) error {
 	// Validate component name
@@ -316,8 +468,9 @@ func (s *Scaffolder) generateFiles(name string, component cue.Value) error {
 	return nil
 }
 
-// updateFTLConfig adds the new component to ftl.yaml or ftl.json
-func (s *Scaffolder) updateFTLConfig(name string, component cue.Value) error {
+// updateFTLConfig adds the new component to ftl.yaml or ftl.json, allowed to
+// call allowedHosts
+func (s *Scaffolder) updateFTLConfig(name string, component cue.Value, allowedHosts []string) error {
 	// Detect configuration format
 	format, configPath, err := s.detectConfigFormat()
 	if err != nil {
@@ -330,18 +483,26 @@ func (s *Scaffolder) updateFTLConfig(name string, component cue.Value) error {
 		language, _ := component.LookupPath(cue.ParsePath("language")).String()
 		wasmPath := s.getWasmPath(name, language)
 
+		hosts := ""
+		if len(allowedHosts) > 0 {
+			hosts = fmt.Sprintf(" and allow it to call %s", strings.Join(allowedHosts, ", "))
+		}
 		return fmt.Errorf("go-based configurations require manual component registration - "+
 			"add this to your main.go: "+
 			"app.AddComponent(\"%s\")."+
 			"FromLocal(\"./%s\")."+
 			"WithBuild(\"cd %s && make build\")."+
-			"Build()", name, wasmPath, name)
+			"Build()%s", name, wasmPath, name, hosts)
 	}
 
 	if format == "cue" {
+		hosts := ""
+		if len(allowedHosts) > 0 {
+			hosts = fmt.Sprintf(" allowed_outbound_hosts=%q", allowedHosts)
+		}
 		return fmt.Errorf("cue configurations require manual component registration - "+
-			"add component with id=%s source=./%s/%s.wasm build.command='make build' build.workdir=%s to your app.cue components array",
-			name, name, name, name)
+			"add component with id=%s source=./%s/%s.wasm build.command='make build' build.workdir=%s%s to your app.cue components array",
+			name, name, name, name, hosts)
 	}
 
 	// Read existing config
@@ -407,6 +568,7 @@ func (s *Scaffolder) updateFTLConfig(name string, component cue.Value) error {
 			Workdir: name,
 			Watch:   watchPatterns,
 		},
+		AllowedOutboundHosts: allowedHosts,
 	}
 
 	// Check for duplicate
@@ -569,6 +731,10 @@ func convertToFlatStructure(app *validation.Application) map[string]interface{}
 				c["variables"] = comp.Variables
 			}
 
+			if len(comp.AllowedOutboundHosts) > 0 {
+				c["allowed_outbound_hosts"] = comp.AllowedOutboundHosts
+			}
+
 			components = append(components, c)
 		}
 		result["components"] = components
@@ -272,11 +272,24 @@ func TestGenerateComponent_TypeScript(t *testing.T) {
 	assert.FileExists(t, "ts-tool/package.json")
 	assert.FileExists(t, "ts-tool/tsconfig.json")
 	assert.FileExists(t, "ts-tool/src/index.ts")
+	assert.FileExists(t, "ts-tool/src/schemas.ts")
 	assert.FileExists(t, "ts-tool/Makefile")
 
 	// Check package.json
 	pkgContent, _ := os.ReadFile("ts-tool/package.json")
 	assert.Contains(t, string(pkgContent), `"name": "ts-tool"`)
+
+	// Tools take their schemas from the shared module and validate with them
+	indexContent, _ := os.ReadFile("ts-tool/src/index.ts")
+	assert.Contains(t, string(indexContent), `from './schemas'`)
+	assert.Contains(t, string(indexContent), "inputValidator: ExampleToolSchema")
+	schemasContent, _ := os.ReadFile("ts-tool/src/schemas.ts")
+	assert.Contains(t, string(schemasContent), "export const ExampleToolSchema")
+
+	// The Makefile picks the package manager of the project
+	makefile, _ := os.ReadFile("ts-tool/Makefile")
+	assert.Contains(t, string(makefile), "pnpm-lock.yaml")
+	assert.Contains(t, string(makefile), "\t$(PM) run build")
 }
 
 func TestGenerateComponent_Python(t *testing.T) {
@@ -590,3 +603,81 @@ func TestGenerateComponent_CreatesCorrectStructure(t *testing.T) {
 		})
 	}
 }
+
+func TestGenerateCI_GitHub(t *testing.T) {
+	scaffolder, err := NewScaffolder()
+	require.NoError(t, err)
+
+	tmpDir := t.TempDir()
+	files, err := scaffolder.GenerateCI(tmpDir, "github", []string{"rust", "python"})
+	require.NoError(t, err)
+	assert.Equal(t, []string{".github/workflows/ftl.yml"}, files)
+
+	data, err := os.ReadFile(filepath.Join(tmpDir, ".github", "workflows", "ftl.yml"))
+	require.NoError(t, err)
+	content := string(data)
+	assert.Contains(t, content, "targets: wasm32-wasip1")
+	assert.Contains(t, content, "actions/setup-python")
+	assert.NotContains(t, content, "actions/setup-node")
+	assert.NotContains(t, content, "setup-tinygo")
+	assert.Contains(t, content, "--version cli-v"+scaffolder.versions.FTLCli)
+	assert.Contains(t, content, "run: ftl build")
+	assert.Contains(t, content, "run: ftl test")
+	assert.Contains(t, content, "run: ftl deploy --yes")
+	assert.Contains(t, content, "${{ secrets.FTL_CLIENT_SECRET }}")
+
+	var workflow map[string]interface{}
+	require.NoError(t, yaml.Unmarshal(data, &workflow))
+	jobs := workflow["jobs"].(map[string]interface{})
+	steps := jobs["ftl"].(map[string]interface{})["steps"].([]interface{})
+	assert.Len(t, steps, 8)
+}
+
+func TestGenerateCI_GitLab(t *testing.T) {
+	scaffolder, err := NewScaffolder()
+	require.NoError(t, err)
+
+	tmpDir := t.TempDir()
+	files, err := scaffolder.GenerateCI(tmpDir, "gitlab", []string{"typescript", "go"})
+	require.NoError(t, err)
+	assert.Equal(t, []string{".gitlab-ci.yml"}, files)
+
+	data, err := os.ReadFile(filepath.Join(tmpDir, ".gitlab-ci.yml"))
+	require.NoError(t, err)
+	content := string(data)
+	assert.Contains(t, content, "nodejs npm")
+	assert.Contains(t, content, "tinygo")
+	assert.NotContains(t, content, "rustup")
+
+	var pipeline map[string]interface{}
+	require.NoError(t, yaml.Unmarshal(data, &pipeline))
+	build := pipeline["build"].(map[string]interface{})
+	assert.Equal(t, []interface{}{"ftl build", "ftl test"}, build["script"])
+	deploy := pipeline["deploy"].(map[string]interface{})
+	assert.Equal(t, []interface{}{"ftl deploy --yes"}, deploy["script"])
+}
+
+func TestGenerateCI_InvalidProvider(t *testing.T) {
+	scaffolder, err := NewScaffolder()
+	require.NoError(t, err)
+
+	_, err = scaffolder.GenerateCI(t.TempDir(), "jenkins", nil)
+	assert.ErrorContains(t, err, "invalid CI provider 'jenkins'")
+}
+
+func TestDetectLanguages(t *testing.T) {
+	tmpDir := t.TempDir()
+	assert.Empty(t, DetectLanguages(tmpDir))
+
+	for dir, marker := range map[string]string{
+		"weather":      "pyproject.toml",
+		"calc":         "Cargo.toml",
+		"node_modules": "package.json",
+	} {
+		require.NoError(t, os.MkdirAll(filepath.Join(tmpDir, dir), 0750))
+		require.NoError(t, os.WriteFile(filepath.Join(tmpDir, dir, marker), nil, 0600))
+	}
+	require.NoError(t, os.WriteFile(filepath.Join(tmpDir, "go.mod"), nil, 0600))
+
+	assert.Equal(t, []string{"rust", "python", "go"}, DetectLanguages(tmpDir))
+}
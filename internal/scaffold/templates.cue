@@ -1,5 +1,7 @@
 package scaffold
 
+import "strings"
+
 // Language type constraint
 #Language: "rust" | "typescript" | "python" | "go"
 
@@ -217,13 +219,23 @@ package scaffold
 			
 			## Adding Tools
 			
-			Edit `src/index.ts` and add new tools in the `createTools` call:
+			Define the input of the tool in `src/schemas.ts`, reusing the schemas
+			shared with other tools:
+			```typescript
+			export const YourToolSchema = z.object({
+			  message: Message
+			})
+			export type YourToolInput = z.infer<typeof YourToolSchema>
+			```
+			
+			Then add the tool in the `createTools` call of `src/index.ts`:
 			```typescript
 			const handle = createTools({
 			  yourTool: {
 			    description: 'Tool description',
-			    inputSchema: z.toJSONSchema(YourSchema),
-			    handler: async (input) => {
+			    inputSchema: z.toJSONSchema(YourToolSchema),
+			    inputValidator: YourToolSchema,
+			    handler: async (input: YourToolInput) => {
 			      // Tool implementation
 			      return ToolResponse.text('Response')
 			    }
@@ -280,53 +292,69 @@ package scaffold
 		"Makefile": """
 			.PHONY: build clean test install format lint
 
+			# Use pnpm in pnpm projects and workspaces, npm otherwise
+			PM := $(shell [ -f pnpm-lock.yaml ] || [ -f ../pnpm-lock.yaml ] && echo pnpm || echo npm)
+
 			install:
-			\tnpm install
+			\t$(PM) install
 
 			build: install
-			\tnpm run build
+			\t$(PM) run build
 
 			clean:
 			\trm -rf dist node_modules build
 
 			test: install
-			\tnpm test
+			\t$(PM) test
 
 			format: install
-			\tnpm run format || echo "No format script defined"
+			\t$(PM) run format || echo "No format script defined"
 
 			lint: install
-			\tnpm run lint || echo "No lint script defined"
+			\t$(PM) run lint || echo "No lint script defined"
 
 			dev: install format lint test
 			\t@echo "Development checks passed!"
 			"""
 		
-		"src/index.ts": """
-			import { createTools, ToolResponse } from 'ftl-sdk'
+		"src/schemas.ts": """
 			import * as z from 'zod'
 
-			// Define the schema using Zod
-			const ExampleToolSchema = z.object({
-			  message: z.string().describe('The input message to process')
+			// Schemas shared by the tools of this component. Define each type once
+			// here and reuse it in the input of every tool that needs it.
+
+			export const Message = z.string().describe('The input message to process')
+
+			export const ExampleToolSchema = z.object({
+			  message: Message
 			})
+			export type ExampleToolInput = z.infer<typeof ExampleToolSchema>
+			"""
+		
+		"src/index.ts": """
+			import { createTools, ToolResponse } from 'ftl-sdk'
+			import * as z from 'zod'
+			import { ExampleToolSchema, type ExampleToolInput } from './schemas'
 
 			const handle = createTools({
 			  // Replace 'exampleTool' with your actual tool name
 			  exampleTool: {
 			    description: 'An example tool that processes messages',
 			    inputSchema: z.toJSONSchema(ExampleToolSchema),
-			    handler: async (input: z.infer<typeof ExampleToolSchema>) => {
+			    // Validate the input with the schema before the handler runs
+			    inputValidator: ExampleToolSchema,
+			    handler: async (input: ExampleToolInput) => {
 			      // TODO: Implement your tool logic here
 			      return ToolResponse.text(`Processed: ${input.message}`)
 			    }
 			  }
 			  
-			  // Add more tools here as needed:
+			  // Add more tools here as needed, with their schemas in schemas.ts:
 			  // anotherTool: {
 			  //   description: 'Another tool description',
 			  //   inputSchema: z.toJSONSchema(AnotherSchema),
-			  //   handler: async (input: z.infer<typeof AnotherSchema>) => {
+			  //   inputValidator: AnotherSchema,
+			  //   handler: async (input: AnotherInput) => {
 			  //     return ToolResponse.text('Another response')
 			  //   }
 			  // }
@@ -885,7 +913,8 @@ package scaffold
 	.spin/
 	spin.toml
 	*.wasm
-	.ftl/
+	.ftl/*
+	!.ftl/project.toml
 	.env
 	.env.local
 	target/
@@ -1052,4 +1081,176 @@ package scaffold
 	json: #JSONProject
 	cue:  #CUEProject
 	go:   #GoProject
-}
\ No newline at end of file
+}
+
+// ===========================================================================
+// CI Pipeline Templates
+// ===========================================================================
+
+// CI provider types
+#CIProvider: "github" | "gitlab"
+
+// CI pipeline structure
+#CIPipeline: {
+	provider: #CIProvider
+	// Component languages that need a toolchain in CI
+	languages: [...#Language]
+
+	// Files to generate
+	files: [string]: string
+}
+
+// Installs the FTL CLI release that generated the pipeline
+_ciInstallFTL: "curl -o- https://raw.githubusercontent.com/fastertools/ftl/main/install.sh | bash -s -- --version cli-v\(_versions.ftl_cli)"
+
+// GitHub Actions workflow
+#GitHubCI: #CIPipeline & {
+	provider: "github"
+	languages: [...#Language]
+
+	_setup: {
+		rust: """
+			      - name: Install Rust
+			        uses: dtolnay/rust-toolchain@stable
+			        with:
+			          targets: wasm32-wasip1
+			"""
+		typescript: """
+			      - name: Install Node.js
+			        uses: actions/setup-node@v4
+			        with:
+			          node-version: "22"
+			"""
+		python: """
+			      - name: Install Python
+			        uses: actions/setup-python@v5
+			        with:
+			          python-version: "3.12"
+			"""
+		go: """
+			      - name: Install Go
+			        uses: actions/setup-go@v5
+			        with:
+			          go-version: "1.24"
+			      - name: Install TinyGo
+			        uses: acifani/setup-tinygo@v2
+			        with:
+			          tinygo-version: "0.38.0"
+			"""
+	}
+
+	_steps: strings.Join([
+		"""
+			      - uses: actions/checkout@v4
+			""",
+		for l in languages {_setup[l]},
+		"""
+			      - name: Install Spin
+			        uses: fermyon/actions/spin/setup@v1
+			      - name: Install FTL
+			        run: \(_ciInstallFTL)
+			      - name: Build
+			        run: ftl build
+			      - name: Test
+			        run: ftl test
+			      - name: Deploy
+			        if: github.event_name == 'push' && github.ref == 'refs/heads/main' && env.FTL_DEPLOY == 'true'
+			        run: ftl deploy --yes
+			        env:
+			          FTL_CLIENT_ID: ${{ secrets.FTL_CLIENT_ID }}
+			          FTL_CLIENT_SECRET: ${{ secrets.FTL_CLIENT_SECRET }}
+			          FTL_ORG_ID: ${{ secrets.FTL_ORG_ID }}
+			""",
+	], "\n")
+
+	files: ".github/workflows/ftl.yml": """
+		name: FTL
+
+		on:
+		  push:
+		    branches: [main]
+		  pull_request:
+
+		jobs:
+		  ftl:
+		    runs-on: ubuntu-latest
+		    env:
+		      # Deploys run on pushes to main once the FTL_CLIENT_SECRET secret is set
+		      FTL_DEPLOY: ${{ secrets.FTL_CLIENT_SECRET != '' }}
+		    steps:
+		\(_steps)
+		"""
+}
+
+// GitLab CI pipeline
+#GitLabCI: #CIPipeline & {
+	provider: "gitlab"
+	languages: [...#Language]
+
+	_setup: {
+		rust: """
+			    - curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh -s -- -y --target wasm32-wasip1
+			    - . "$HOME/.cargo/env"
+			"""
+		typescript: """
+			    - apt-get install -y --no-install-recommends nodejs npm
+			"""
+		python: """
+			    - apt-get install -y --no-install-recommends python3 python3-pip python3-venv
+			"""
+		go: """
+			    - curl -fsSL https://go.dev/dl/go1.24.5.linux-amd64.tar.gz | tar -C /usr/local -xz
+			    - export PATH="/usr/local/go/bin:$PATH"
+			    - curl -fsSLO https://github.com/tinygo-org/tinygo/releases/download/v0.38.0/tinygo_0.38.0_amd64.deb
+			    - dpkg -i tinygo_0.38.0_amd64.deb
+			"""
+	}
+
+	_beforeScript: strings.Join([
+		"""
+			    - apt-get update
+			    - apt-get install -y --no-install-recommends build-essential ca-certificates curl git
+			""",
+		for l in languages {_setup[l]},
+		"""
+			    - curl -fsSL https://developer.fermyon.com/downloads/install.sh | bash && mv spin /usr/local/bin/
+			    - \(_ciInstallFTL)
+			""",
+	], "\n")
+
+	files: ".gitlab-ci.yml": """
+		stages:
+		  - build
+		  - deploy
+
+		variables:
+		  DEBIAN_FRONTEND: noninteractive
+
+		default:
+		  image: ubuntu:24.04
+		  before_script:
+		\(_beforeScript)
+
+		build:
+		  stage: build
+		  script:
+		    - ftl build
+		    - ftl test
+
+		# Deploys run on the default branch once the FTL_CLIENT_ID and
+		# FTL_CLIENT_SECRET CI/CD variables are set (and FTL_ORG_ID, to pick an
+		# organization)
+		deploy:
+		  stage: deploy
+		  script:
+		    - ftl deploy --yes
+		  rules:
+		    - if: $CI_COMMIT_BRANCH == $CI_DEFAULT_BRANCH && $FTL_CLIENT_SECRET
+		"""
+}
+
+// CI template selector
+#CITemplates: {
+	github: #GitHubCI
+	gitlab: #GitLabCI
+}
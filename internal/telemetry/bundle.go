@@ -0,0 +1,230 @@
+package telemetry
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"regexp"
+	"runtime"
+	"strings"
+	"time"
+
+	"gopkg.in/yaml.v3"
+)
+
+const redacted = "[REDACTED]"
+
+var (
+	bearerPattern = regexp.MustCompile(`(?i)(bearer\s+)[A-Za-z0-9._~+/=-]+`)
+	jwtPattern    = regexp.MustCompile(`eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*`)
+	secretPattern = regexp.MustCompile(`(?i)((?:token|secret|password|passwd|api[_-]?key|client[_-]?secret|authorization)["']?\s*[:=]\s*["']?)[^\s"',}]+`)
+	urlCredential = regexp.MustCompile(`(://)[^/\s:@]+:[^/\s@]+@`)
+)
+
+// sensitiveKeys are config keys whose values are always redacted
+var sensitiveKeys = []string{"secret", "token", "password", "passwd", "key", "credential", "policy_data"}
+
+// Redact removes tokens, credentials and the home directory from free-form text
+func Redact(s string) string {
+	s = bearerPattern.ReplaceAllString(s, "${1}"+redacted)
+	s = jwtPattern.ReplaceAllString(s, redacted)
+	s = secretPattern.ReplaceAllString(s, "${1}"+redacted)
+	s = urlCredential.ReplaceAllString(s, "${1}"+redacted+"@")
+	if home, err := os.UserHomeDir(); err == nil && len(home) > 1 {
+		s = strings.ReplaceAll(s, home, "~")
+	}
+	return s
+}
+
+// SanitizeConfig returns an FTL project config (YAML or JSON) as YAML with
+// variable values and sensitive fields redacted
+func SanitizeConfig(data []byte) ([]byte, error) {
+	var doc map[string]interface{}
+	if err := yaml.Unmarshal(data, &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse project config: %w", err)
+	}
+	sanitizeValue(doc, false)
+	return yaml.Marshal(doc)
+}
+
+// sanitizeValue redacts in place; under variables every leaf value is redacted
+func sanitizeValue(v interface{}, redactAll bool) interface{} {
+	switch value := v.(type) {
+	case map[string]interface{}:
+		for k, child := range value {
+			switch {
+			case k == "variables":
+				value[k] = sanitizeValue(child, true)
+			case redactAll || isSensitiveKey(k):
+				value[k] = redactLeaves(child)
+			default:
+				value[k] = sanitizeValue(child, false)
+			}
+		}
+		return value
+	case []interface{}:
+		for i, child := range value {
+			value[i] = sanitizeValue(child, redactAll)
+		}
+		return value
+	case string:
+		if redactAll {
+			return redacted
+		}
+		return Redact(value)
+	default:
+		if redactAll && value != nil {
+			return redacted
+		}
+		return value
+	}
+}
+
+func redactLeaves(v interface{}) interface{} {
+	switch value := v.(type) {
+	case map[string]interface{}, []interface{}:
+		return sanitizeValue(value, true)
+	case nil:
+		return nil
+	default:
+		return redacted
+	}
+}
+
+func isSensitiveKey(key string) bool {
+	key = strings.ToLower(key)
+	for _, s := range sensitiveKeys {
+		if strings.Contains(key, s) {
+			return true
+		}
+	}
+	return false
+}
+
+// File is a single file in a bundle
+type File struct {
+	Name    string
+	Content []byte
+}
+
+// Bundle is the set of redacted files written to a report archive
+type Bundle struct {
+	Files []File
+}
+
+// SystemInfo describes the CLI build and host
+type SystemInfo struct {
+	Version   string `json:"version"`
+	Commit    string `json:"commit"`
+	BuildDate string `json:"build_date"`
+	OS        string `json:"os"`
+	Arch      string `json:"arch"`
+	GoVersion string `json:"go_version"`
+	NumCPU    int    `json:"num_cpu"`
+	Created   string `json:"created"`
+}
+
+// CollectOptions selects what goes into a bundle
+type CollectOptions struct {
+	Version   string
+	Commit    string
+	BuildDate string
+	// ProjectConfig is the path of the project config to include, empty to skip
+	ProjectConfig string
+	// Events is the number of recent events to include
+	Events int
+}
+
+// Collect gathers the redacted bundle contents
+func (s *Store) Collect(opts CollectOptions) (*Bundle, error) {
+	bundle := &Bundle{}
+
+	info := SystemInfo{
+		Version:   opts.Version,
+		Commit:    opts.Commit,
+		BuildDate: opts.BuildDate,
+		OS:        runtime.GOOS,
+		Arch:      runtime.GOARCH,
+		GoVersion: runtime.Version(),
+		NumCPU:    runtime.NumCPU(),
+		Created:   time.Now().UTC().Format(time.RFC3339),
+	}
+	data, err := json.MarshalIndent(info, "", "  ")
+	if err != nil {
+		return nil, err
+	}
+	bundle.Files = append(bundle.Files, File{Name: "system.json", Content: append(data, '\n')})
+
+	events, err := s.RecentEvents(opts.Events)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read telemetry events: %w", err)
+	}
+	if len(events) > 0 {
+		var b strings.Builder
+		for _, event := range events {
+			event.Command = Redact(event.Command)
+			event.Error = Redact(event.Error)
+			line, err := json.Marshal(event)
+			if err != nil {
+				return nil, err
+			}
+			b.Write(line)
+			b.WriteByte('\n')
+		}
+		bundle.Files = append(bundle.Files, File{Name: eventsFile, Content: []byte(b.String())})
+	}
+
+	log, err := s.LastCommandLog()
+	if err != nil {
+		return nil, fmt.Errorf("failed to read last command log: %w", err)
+	}
+	if len(log) > 0 {
+		bundle.Files = append(bundle.Files, File{Name: logFile, Content: []byte(Redact(string(log)))})
+	}
+
+	if opts.ProjectConfig != "" {
+		raw, err := os.ReadFile(filepath.Clean(opts.ProjectConfig))
+		if err != nil {
+			return nil, fmt.Errorf("failed to read project config: %w", err)
+		}
+		sanitized, err := SanitizeConfig(raw)
+		if err != nil {
+			return nil, err
+		}
+		name := strings.TrimSuffix(filepath.Base(opts.ProjectConfig), filepath.Ext(opts.ProjectConfig)) + ".sanitized.yaml"
+		bundle.Files = append(bundle.Files, File{Name: name, Content: sanitized})
+	}
+
+	return bundle, nil
+}
+
+// WriteArchive writes the bundle as a gzipped tarball under an ftl-bundle/ directory
+func (b *Bundle) WriteArchive(w io.Writer) error {
+	gz := gzip.NewWriter(w)
+	tw := tar.NewWriter(gz)
+
+	now := time.Now()
+	for _, f := range b.Files {
+		header := &tar.Header{
+			Name:    "ftl-bundle/" + f.Name,
+			Mode:    0600,
+			Size:    int64(len(f.Content)),
+			ModTime: now,
+		}
+		if err := tw.WriteHeader(header); err != nil {
+			return err
+		}
+		if _, err := tw.Write(f.Content); err != nil {
+			return err
+		}
+	}
+
+	if err := tw.Close(); err != nil {
+		return err
+	}
+	return gz.Close()
+}
@@ -0,0 +1,236 @@
+package telemetry
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// OTLPEndpointEnv sets the OTLP/HTTP endpoint command outcome events are
+// exported to, overriding the telemetry.otlp_endpoint user setting
+const OTLPEndpointEnv = "FTL_TELEMETRY_OTLP_ENDPOINT"
+
+// Command outcomes
+const (
+	OutcomeSuccess = "success"
+	OutcomeError   = "error"
+)
+
+// Error categories of failed commands
+const (
+	CategoryUsage    = "usage"
+	CategoryAuth     = "auth"
+	CategoryNetwork  = "network"
+	CategoryCanceled = "canceled"
+	CategoryConfig   = "config"
+	CategoryBuild    = "build"
+	CategoryOther    = "other"
+)
+
+// Sink receives command outcome events
+type Sink interface {
+	Record(event Event) error
+}
+
+// Record appends the event to the store, making a Store the file sink
+func (s *Store) Record(event Event) error {
+	return s.RecordEvent(event)
+}
+
+// CommandOutcome returns the standardized outcome event of a command run
+func CommandOutcome(command, version string, started time.Time, runErr error) Event {
+	event := Event{
+		Time:       started.UTC(),
+		Command:    command,
+		Version:    version,
+		DurationMs: time.Since(started).Milliseconds(),
+		Outcome:    OutcomeSuccess,
+	}
+	if runErr != nil {
+		event.Outcome = OutcomeError
+		event.Error = runErr.Error()
+		event.ErrorCategory = Categorize(runErr)
+	}
+	return event
+}
+
+// Categorize returns the error category of a failed command
+func Categorize(err error) string {
+	if errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) {
+		return CategoryCanceled
+	}
+	var netErr net.Error
+	if errors.As(err, &netErr) {
+		return CategoryNetwork
+	}
+
+	msg := strings.ToLower(err.Error())
+	switch {
+	case containsAny(msg, "unknown command", "unknown flag", "unknown shorthand flag", "required flag", "accepts ", "requires at least", "invalid argument"):
+		return CategoryUsage
+	case containsAny(msg, "not logged in", "unauthorized", "forbidden", "token expired", "authentication"):
+		return CategoryAuth
+	case containsAny(msg, "connection refused", "no such host", "i/o timeout", "network is unreachable", "tls handshake"):
+		return CategoryNetwork
+	case containsAny(msg, "synthesis failed", "failed to validate manifest", "ftl.yaml", "ftl.json", "app.cue", "spin.toml found"):
+		return CategoryConfig
+	case containsAny(msg, "failed to build", "build failed"):
+		return CategoryBuild
+	}
+	return CategoryOther
+}
+
+func containsAny(s string, substrings ...string) bool {
+	for _, sub := range substrings {
+		if strings.Contains(s, sub) {
+			return true
+		}
+	}
+	return false
+}
+
+// Fanout returns a sink recording every event to all sinks. A failing sink
+// does not stop the others; their errors are joined.
+func Fanout(sinks ...Sink) Sink {
+	return fanout(sinks)
+}
+
+type fanout []Sink
+
+func (f fanout) Record(event Event) error {
+	var errs []error
+	for _, sink := range f {
+		if err := sink.Record(event); err != nil {
+			errs = append(errs, err)
+		}
+	}
+	return errors.Join(errs...)
+}
+
+// MemorySink keeps events in memory, for tests
+type MemorySink struct {
+	mu     sync.Mutex
+	events []Event
+}
+
+// Record appends the event
+func (m *MemorySink) Record(event Event) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.events = append(m.events, event)
+	return nil
+}
+
+// Events returns the recorded events, oldest first
+func (m *MemorySink) Events() []Event {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return append([]Event(nil), m.events...)
+}
+
+// OTLPSink exports events as OTLP/HTTP JSON log records. Error messages are
+// not exported, only their category.
+type OTLPSink struct {
+	// Endpoint is the collector's base URL, e.g. http://localhost:4318
+	Endpoint string
+	Client   *http.Client
+}
+
+// NewOTLPSink returns a sink exporting to endpoint with a short timeout, so
+// an unreachable collector never holds up the CLI
+func NewOTLPSink(endpoint string) *OTLPSink {
+	return &OTLPSink{
+		Endpoint: endpoint,
+		Client:   &http.Client{Timeout: 2 * time.Second},
+	}
+}
+
+// OTLPEndpoint returns the configured OTLP endpoint, empty when exporting is
+// disabled
+func OTLPEndpoint() string {
+	if endpoint := os.Getenv(OTLPEndpointEnv); endpoint != "" {
+		return endpoint
+	}
+	cfg, err := config.Load()
+	if err != nil || cfg.Telemetry == nil {
+		return ""
+	}
+	return cfg.Telemetry.OTLPEndpoint
+}
+
+// Record sends the event to the collector's /v1/logs endpoint
+func (o *OTLPSink) Record(event Event) error {
+	body, err := json.Marshal(otlpLogs(event))
+	if err != nil {
+		return err
+	}
+	url := strings.TrimSuffix(o.Endpoint, "/") + "/v1/logs"
+	resp, err := o.Client.Post(url, "application/json", bytes.NewReader(body)) // #nosec G107 - endpoint is configured by the user
+	if err != nil {
+		return fmt.Errorf("failed to export telemetry: %w", err)
+	}
+	_ = resp.Body.Close()
+	if resp.StatusCode >= 300 {
+		return fmt.Errorf("failed to export telemetry: %s", resp.Status)
+	}
+	return nil
+}
+
+type otlpAttribute struct {
+	Key   string            `json:"key"`
+	Value map[string]string `json:"value"`
+}
+
+func stringAttribute(key, value string) otlpAttribute {
+	return otlpAttribute{Key: key, Value: map[string]string{"stringValue": value}}
+}
+
+// otlpLogs wraps an event in an OTLP ExportLogsServiceRequest
+func otlpLogs(event Event) map[string]interface{} {
+	attributes := []otlpAttribute{
+		stringAttribute("ftl.command", event.Command),
+		stringAttribute("ftl.outcome", event.Outcome),
+		// OTLP JSON encodes 64-bit integers as strings
+		{Key: "ftl.duration_ms", Value: map[string]string{"intValue": strconv.FormatInt(event.DurationMs, 10)}},
+	}
+	if event.ErrorCategory != "" {
+		attributes = append(attributes, stringAttribute("ftl.error_category", event.ErrorCategory))
+	}
+
+	severity, severityNumber := "INFO", 9
+	if event.Outcome == OutcomeError {
+		severity, severityNumber = "ERROR", 17
+	}
+
+	return map[string]interface{}{
+		"resourceLogs": []interface{}{map[string]interface{}{
+			"resource": map[string]interface{}{
+				"attributes": []otlpAttribute{
+					stringAttribute("service.name", "ftl-cli"),
+					stringAttribute("service.version", event.Version),
+				},
+			},
+			"scopeLogs": []interface{}{map[string]interface{}{
+				"scope": map[string]string{"name": "ftl"},
+				"logRecords": []interface{}{map[string]interface{}{
+					"timeUnixNano":   strconv.FormatInt(event.Time.UnixNano(), 10),
+					"severityText":   severity,
+					"severityNumber": severityNumber,
+					"body":           map[string]string{"stringValue": fmt.Sprintf("ftl %s %s", event.Command, event.Outcome)},
+					"attributes":     attributes,
+				}},
+			}},
+		}},
+	}
+}
@@ -0,0 +1,124 @@
+package telemetry
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type failingSink struct{}
+
+func (failingSink) Record(Event) error { return errors.New("sink unavailable") }
+
+func TestCommandOutcome(t *testing.T) {
+	started := time.Now().Add(-time.Second)
+
+	event := CommandOutcome("build", "1.2.3", started, nil)
+	assert.Equal(t, "build", event.Command)
+	assert.Equal(t, "1.2.3", event.Version)
+	assert.Equal(t, OutcomeSuccess, event.Outcome)
+	assert.GreaterOrEqual(t, event.DurationMs, int64(1000))
+	assert.Empty(t, event.Error)
+	assert.Empty(t, event.ErrorCategory)
+
+	event = CommandOutcome("deploy", "1.2.3", started, errors.New("not logged in"))
+	assert.Equal(t, OutcomeError, event.Outcome)
+	assert.Equal(t, "not logged in", event.Error)
+	assert.Equal(t, CategoryAuth, event.ErrorCategory)
+}
+
+func TestCategorize(t *testing.T) {
+	tests := []struct {
+		err  error
+		want string
+	}{
+		{fmt.Errorf("waiting: %w", context.Canceled), CategoryCanceled},
+		{&net.OpError{Op: "dial", Err: errors.New("refused")}, CategoryNetwork},
+		{errors.New(`unknown flag: --colour`), CategoryUsage},
+		{errors.New("accepts 1 arg(s), received 2"), CategoryUsage},
+		{errors.New("API error: 401 Unauthorized"), CategoryAuth},
+		{errors.New("dial tcp: lookup api.example.com: no such host"), CategoryNetwork},
+		{errors.New("synthesis failed: components.0.id: invalid value"), CategoryConfig},
+		{errors.New("failed to build: exit status 1"), CategoryBuild},
+		{errors.New("something else"), CategoryOther},
+	}
+	for _, tt := range tests {
+		assert.Equal(t, tt.want, Categorize(tt.err), tt.err.Error())
+	}
+}
+
+func TestFanout(t *testing.T) {
+	first, second := &MemorySink{}, &MemorySink{}
+	sink := Fanout(first, failingSink{}, second)
+
+	err := sink.Record(Event{Command: "up"})
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "sink unavailable")
+
+	// A failing sink does not stop the others
+	require.Len(t, first.Events(), 1)
+	require.Len(t, second.Events(), 1)
+	assert.Equal(t, "up", second.Events()[0].Command)
+}
+
+func TestStoreIsASink(t *testing.T) {
+	store := NewStoreWithDir(t.TempDir())
+	var sink Sink = store
+	require.NoError(t, sink.Record(Event{Command: "build", Outcome: OutcomeSuccess}))
+
+	events, err := store.RecentEvents(1)
+	require.NoError(t, err)
+	require.Len(t, events, 1)
+	assert.Equal(t, OutcomeSuccess, events[0].Outcome)
+}
+
+func TestOTLPSink(t *testing.T) {
+	var path string
+	var payload map[string]interface{}
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		path = r.URL.Path
+		body, _ := io.ReadAll(r.Body)
+		_ = json.Unmarshal(body, &payload)
+	}))
+	defer server.Close()
+
+	sink := NewOTLPSink(server.URL + "/")
+	event := CommandOutcome("deploy", "1.2.3", time.Now(), errors.New("failed to build: token=secret"))
+	require.NoError(t, sink.Record(event))
+
+	assert.Equal(t, "/v1/logs", path)
+	encoded, err := json.Marshal(payload)
+	require.NoError(t, err)
+	assert.Contains(t, string(encoded), `"ftl.command"`)
+	assert.Contains(t, string(encoded), `"stringValue":"deploy"`)
+	assert.Contains(t, string(encoded), `"stringValue":"build"`)
+	assert.Contains(t, string(encoded), `"severityText":"ERROR"`)
+	// Error messages never leave the machine
+	assert.NotContains(t, string(encoded), "secret")
+}
+
+func TestOTLPSink_Error(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusServiceUnavailable)
+	}))
+	defer server.Close()
+
+	err := NewOTLPSink(server.URL).Record(Event{Command: "up"})
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "503")
+}
+
+func TestOTLPEndpoint_Env(t *testing.T) {
+	t.Setenv(OTLPEndpointEnv, "http://localhost:4318")
+	assert.Equal(t, "http://localhost:4318", OTLPEndpoint())
+}
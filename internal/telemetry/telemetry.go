@@ -0,0 +1,174 @@
+// Package telemetry records local diagnostics about CLI runs and packages them
+// into redacted bundles users can attach to bug reports. Command outcomes are
+// only exported when an OTLP endpoint is configured.
+package telemetry
+
+import (
+	"bufio"
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+const (
+	eventsFile = "events.jsonl"
+	logFile    = "last-command.log"
+
+	// MaxEvents is the number of recent events kept on disk
+	MaxEvents = 200
+
+	// DisableEnv disables local telemetry recording when set to a non-empty value
+	DisableEnv = "FTL_TELEMETRY_DISABLED"
+)
+
+// Event records a single CLI command run
+type Event struct {
+	Time          time.Time `json:"time"`
+	Command       string    `json:"command"`
+	Version       string    `json:"version"`
+	DurationMs    int64     `json:"duration_ms"`
+	Outcome       string    `json:"outcome,omitempty"`
+	Error         string    `json:"error,omitempty"`
+	ErrorCategory string    `json:"error_category,omitempty"`
+}
+
+// Store reads and writes telemetry in a local directory
+type Store struct {
+	dir string
+}
+
+// DefaultDir returns the telemetry directory next to the user config file
+func DefaultDir() (string, error) {
+	path, err := config.Path()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(filepath.Dir(path), "telemetry"), nil
+}
+
+// NewStore returns a store in the default telemetry directory
+func NewStore() (*Store, error) {
+	dir, err := DefaultDir()
+	if err != nil {
+		return nil, err
+	}
+	return NewStoreWithDir(dir), nil
+}
+
+// NewStoreWithDir returns a store in dir
+func NewStoreWithDir(dir string) *Store {
+	return &Store{dir: dir}
+}
+
+// Enabled reports whether local recording is enabled. It is disabled by
+// DisableEnv or the telemetry.disabled user setting.
+func Enabled() bool {
+	if os.Getenv(DisableEnv) != "" {
+		return false
+	}
+	cfg, err := config.Load()
+	return err != nil || cfg.Telemetry == nil || !cfg.Telemetry.Disabled
+}
+
+// Dir returns the directory the store writes to
+func (s *Store) Dir() string {
+	return s.dir
+}
+
+// RecordEvent appends an event, keeping only the most recent MaxEvents
+func (s *Store) RecordEvent(event Event) error {
+	if err := os.MkdirAll(s.dir, 0700); err != nil {
+		return err
+	}
+
+	event.Error = Redact(event.Error)
+	line, err := json.Marshal(event)
+	if err != nil {
+		return err
+	}
+
+	lines, err := s.readEventLines()
+	if err != nil {
+		return err
+	}
+	lines = append(lines, string(line))
+	if len(lines) > MaxEvents {
+		lines = lines[len(lines)-MaxEvents:]
+	}
+
+	return os.WriteFile(filepath.Join(s.dir, eventsFile), []byte(strings.Join(lines, "\n")+"\n"), 0600)
+}
+
+// RecentEvents returns up to n of the most recent events, oldest first
+func (s *Store) RecentEvents(n int) ([]Event, error) {
+	lines, err := s.readEventLines()
+	if err != nil {
+		return nil, err
+	}
+	if len(lines) > n {
+		lines = lines[len(lines)-n:]
+	}
+
+	events := make([]Event, 0, len(lines))
+	for _, line := range lines {
+		var event Event
+		if err := json.Unmarshal([]byte(line), &event); err != nil {
+			// Skip lines corrupted by concurrent runs
+			continue
+		}
+		events = append(events, event)
+	}
+	return events, nil
+}
+
+func (s *Store) readEventLines() ([]string, error) {
+	data, err := os.ReadFile(filepath.Join(s.dir, eventsFile)) // #nosec G304 - path is inside the telemetry directory
+	if err != nil {
+		if os.IsNotExist(err) {
+			return nil, nil
+		}
+		return nil, err
+	}
+
+	var lines []string
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	for scanner.Scan() {
+		if line := strings.TrimSpace(scanner.Text()); line != "" {
+			lines = append(lines, line)
+		}
+	}
+	return lines, scanner.Err()
+}
+
+// StartCommandLog truncates the last command log and returns a writer for it.
+// The caller closes the returned writer when the command finishes.
+func (s *Store) StartCommandLog(command string) (io.WriteCloser, error) {
+	if err := os.MkdirAll(s.dir, 0700); err != nil {
+		return nil, err
+	}
+	f, err := os.OpenFile(filepath.Join(s.dir, logFile), os.O_CREATE|os.O_TRUNC|os.O_WRONLY, 0600) // #nosec G304 - path is inside the telemetry directory
+	if err != nil {
+		return nil, err
+	}
+	if _, err := fmt.Fprintf(f, "%s ftl %s\n", time.Now().UTC().Format(time.RFC3339), command); err != nil {
+		_ = f.Close()
+		return nil, err
+	}
+	return f, nil
+}
+
+// LastCommandLog returns the debug log of the last command, if any
+func (s *Store) LastCommandLog() ([]byte, error) {
+	data, err := os.ReadFile(filepath.Join(s.dir, logFile)) // #nosec G304 - path is inside the telemetry directory
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	return data, err
+}
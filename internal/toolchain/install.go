@@ -0,0 +1,250 @@
+package toolchain
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"time"
+)
+
+// maxDownloadSize caps the size of a downloaded tool release
+const maxDownloadSize = 512 << 20
+
+// installer describes how to fetch a release of a downloadable tool
+type installer struct {
+	// url returns the release asset URL for a version, os and arch
+	url func(version, goos, goarch string) (string, error)
+	// archived is true when the asset is a tar.gz containing the binary
+	archived bool
+	// repo is the GitHub repository publishing the releases, used to look up
+	// asset digests when there is no checksums file
+	repo string
+	// checksums returns the URL of the release's checksums file, if the
+	// release publishes one. Otherwise the asset digest GitHub publishes for
+	// the release is used.
+	checksums func(version string) string
+}
+
+var installers = map[string]installer{
+	"spin": {
+		url: func(version, goos, goarch string) (string, error) {
+			osName := map[string]string{"linux": "linux", "darwin": "macos"}[goos]
+			arch := map[string]string{"amd64": "amd64", "arm64": "aarch64"}[goarch]
+			if osName == "" || arch == "" {
+				return "", fmt.Errorf("no spin release for %s/%s", goos, goarch)
+			}
+			return fmt.Sprintf("https://github.com/spinframework/spin/releases/download/v%[1]s/spin-v%[1]s-%s-%s.tar.gz",
+				version, osName, arch), nil
+		},
+		archived: true,
+		checksums: func(version string) string {
+			return fmt.Sprintf("https://github.com/spinframework/spin/releases/download/v%[1]s/checksums-v%[1]s.txt", version)
+		},
+	},
+	"wkg": {
+		url: func(version, goos, goarch string) (string, error) {
+			target := map[string]string{"linux": "unknown-linux-gnu", "darwin": "apple-darwin"}[goos]
+			arch := map[string]string{"amd64": "x86_64", "arm64": "aarch64"}[goarch]
+			if target == "" || arch == "" {
+				return "", fmt.Errorf("no wkg release for %s/%s", goos, goarch)
+			}
+			return fmt.Sprintf("https://github.com/bytecodealliance/wasm-pkg-tools/releases/download/v%s/wkg-%s-%s",
+				version, arch, target), nil
+		},
+		repo: "bytecodealliance/wasm-pkg-tools",
+	},
+}
+
+// Installable reports whether the manager can download the tool
+func Installable(tool string) bool {
+	_, ok := installers[tool]
+	return ok
+}
+
+// Install downloads a pinned tool version into the tools directory and
+// returns the installed binary path. Already installed versions are reused.
+func (m *Manager) Install(ctx context.Context, tool, version string) (string, error) {
+	inst, ok := installers[tool]
+	if !ok {
+		return "", fmt.Errorf("%s cannot be installed automatically", tool)
+	}
+	if version == "" {
+		return "", fmt.Errorf("%s requires a pinned version to install", tool)
+	}
+
+	dest := m.ManagedPath(tool, version)
+	if _, err := os.Stat(dest); err == nil {
+		return dest, nil
+	}
+
+	url, err := inst.url(version, runtime.GOOS, runtime.GOARCH)
+	if err != nil {
+		return "", err
+	}
+
+	data, err := m.download(ctx, url)
+	if err != nil {
+		return "", fmt.Errorf("failed to download %s %s: %w", tool, version, err)
+	}
+
+	if err := m.verify(ctx, inst, version, url, data); err != nil {
+		return "", fmt.Errorf("failed to verify %s %s: %w", tool, version, err)
+	}
+
+	if inst.archived {
+		data, err = extractBinary(data, tool)
+		if err != nil {
+			return "", fmt.Errorf("failed to extract %s %s: %w", tool, version, err)
+		}
+	}
+
+	if err := writeExecutable(dest, data); err != nil {
+		return "", fmt.Errorf("failed to install %s %s: %w", tool, version, err)
+	}
+	return dest, nil
+}
+
+// verify checks a downloaded release asset against the SHA-256 checksum
+// published with the release
+func (m *Manager) verify(ctx context.Context, inst installer, version, url string, data []byte) error {
+	asset := path.Base(url)
+
+	var want string
+	var err error
+	if inst.checksums != nil {
+		want, err = m.checksumFromFile(ctx, inst.checksums(version), asset)
+	} else {
+		want, err = m.checksumFromRelease(ctx, inst.repo, version, asset)
+	}
+	if err != nil {
+		return err
+	}
+
+	sum := sha256.Sum256(data)
+	if got := hex.EncodeToString(sum[:]); !strings.EqualFold(got, want) {
+		return fmt.Errorf("checksum mismatch for %s: expected %s, got %s", asset, want, got)
+	}
+	return nil
+}
+
+// checksumFromFile looks up an asset in a checksums file in the format
+// written by sha256sum
+func (m *Manager) checksumFromFile(ctx context.Context, url, asset string) (string, error) {
+	data, err := m.download(ctx, url)
+	if err != nil {
+		return "", fmt.Errorf("failed to download checksums: %w", err)
+	}
+
+	for _, line := range strings.Split(string(data), "\n") {
+		fields := strings.Fields(line)
+		if len(fields) == 2 && strings.TrimPrefix(fields[1], "*") == asset {
+			return fields[0], nil
+		}
+	}
+	return "", fmt.Errorf("no published checksum for %s", asset)
+}
+
+// checksumFromRelease looks up the digest GitHub publishes for a release asset
+func (m *Manager) checksumFromRelease(ctx context.Context, repo, version, asset string) (string, error) {
+	url := fmt.Sprintf("https://api.github.com/repos/%s/releases/tags/v%s", repo, version)
+	data, err := m.download(ctx, url)
+	if err != nil {
+		return "", fmt.Errorf("failed to fetch release: %w", err)
+	}
+
+	var release struct {
+		Assets []struct {
+			Name   string `json:"name"`
+			Digest string `json:"digest"`
+		} `json:"assets"`
+	}
+	if err := json.Unmarshal(data, &release); err != nil {
+		return "", fmt.Errorf("failed to parse release: %w", err)
+	}
+
+	for _, a := range release.Assets {
+		if a.Name != asset {
+			continue
+		}
+		if digest, ok := strings.CutPrefix(a.Digest, "sha256:"); ok {
+			return digest, nil
+		}
+	}
+	return "", fmt.Errorf("no published checksum for %s", asset)
+}
+
+// extractBinary returns the named binary from a tar.gz archive
+func extractBinary(archive []byte, name string) ([]byte, error) {
+	gz, err := gzip.NewReader(bytes.NewReader(archive))
+	if err != nil {
+		return nil, err
+	}
+	defer func() { _ = gz.Close() }()
+
+	tr := tar.NewReader(gz)
+	for {
+		hdr, err := tr.Next()
+		if errors.Is(err, io.EOF) {
+			return nil, fmt.Errorf("%s not found in archive", name)
+		}
+		if err != nil {
+			return nil, err
+		}
+		if hdr.Typeflag == tar.TypeReg && path.Base(hdr.Name) == name {
+			return io.ReadAll(io.LimitReader(tr, maxDownloadSize))
+		}
+	}
+}
+
+// writeExecutable atomically writes an executable file
+func writeExecutable(dest string, data []byte) error {
+	if err := os.MkdirAll(filepath.Dir(dest), 0750); err != nil {
+		return err
+	}
+
+	tmp := dest + ".tmp"
+	if err := os.WriteFile(tmp, data, 0700); err != nil { // #nosec G306 -- tool binaries must be executable
+		return err
+	}
+	if err := os.Rename(tmp, dest); err != nil {
+		_ = os.Remove(tmp)
+		return err
+	}
+	return nil
+}
+
+// httpDownload fetches a URL into memory
+func httpDownload(ctx context.Context, url string) ([]byte, error) {
+	ctx, cancel := context.WithTimeout(ctx, 5*time.Minute)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, url, nil)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("GET %s: %s", url, resp.Status)
+	}
+
+	return io.ReadAll(io.LimitReader(resp.Body, maxDownloadSize))
+}
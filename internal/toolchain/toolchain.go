@@ -0,0 +1,208 @@
+// Package toolchain manages the external tools FTL shells out to (spin, wkg,
+// cargo, docker, ...): checking installed versions against project
+// requirements and installing pinned versions into ~/.ftl/tools
+package toolchain
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"runtime"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+var versionPattern = regexp.MustCompile(`\d+\.\d+\.\d+`)
+
+// Requirement is a version requirement for a single tool
+//
+// Constraint is either an exact version ("3.3.1", optionally prefixed with "=" or "v"),
+// a minimum version (">=3.0.0"), or empty / "*" for any version.
+type Requirement struct {
+	Tool       string
+	Constraint string
+}
+
+// Pinned returns the exact version required, or "" if the requirement is not a pin
+func (r Requirement) Pinned() string {
+	c := strings.TrimSpace(r.Constraint)
+	if c == "" || c == "*" || strings.HasPrefix(c, ">=") {
+		return ""
+	}
+	return strings.TrimPrefix(strings.TrimPrefix(c, "="), "v")
+}
+
+// Satisfied reports whether version meets the requirement
+func (r Requirement) Satisfied(version string) bool {
+	c := strings.TrimSpace(r.Constraint)
+	switch {
+	case c == "" || c == "*":
+		return true
+	case strings.HasPrefix(c, ">="):
+		return CompareVersions(version, strings.TrimSpace(strings.TrimPrefix(c, ">="))) >= 0
+	default:
+		return CompareVersions(version, r.Pinned()) == 0
+	}
+}
+
+// Status is the result of checking a single requirement
+type Status struct {
+	Requirement
+	// Path is the binary that would be used, empty if the tool was not found
+	Path string
+	// Version is the detected version, empty if unknown
+	Version string
+	// Managed is true when Path points into the FTL tools directory
+	Managed bool
+	// Error describes why the tool could not be checked
+	Error error
+}
+
+// OK reports whether the tool was found and satisfies the requirement
+func (s Status) OK() bool {
+	return s.Error == nil && s.Path != "" && s.Satisfied(s.Version)
+}
+
+// Manager resolves and installs tools
+type Manager struct {
+	toolsDir string
+
+	// Overridable for testing
+	lookPath   func(string) (string, error)
+	runVersion func(ctx context.Context, binary string) (string, error)
+	download   func(ctx context.Context, url string) ([]byte, error)
+}
+
+// DefaultToolsDir returns ~/.ftl/tools
+func DefaultToolsDir() (string, error) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to get home directory: %w", err)
+	}
+	return filepath.Join(home, ".ftl", "tools"), nil
+}
+
+// NewManager creates a manager using the default tools directory
+func NewManager() (*Manager, error) {
+	dir, err := DefaultToolsDir()
+	if err != nil {
+		return nil, err
+	}
+	return NewManagerWithDir(dir), nil
+}
+
+// NewManagerWithDir creates a manager using a custom tools directory
+func NewManagerWithDir(dir string) *Manager {
+	return &Manager{
+		toolsDir:   dir,
+		lookPath:   exec.LookPath,
+		runVersion: runVersion,
+		download:   httpDownload,
+	}
+}
+
+// ToolsDir returns the directory managed tools are installed into
+func (m *Manager) ToolsDir() string {
+	return m.toolsDir
+}
+
+// ManagedPath returns where a pinned tool version lives in the tools directory
+func (m *Manager) ManagedPath(tool, version string) string {
+	name := tool
+	if runtime.GOOS == "windows" {
+		name += ".exe"
+	}
+	return filepath.Join(m.toolsDir, tool, version, name)
+}
+
+// Resolve returns the binary to run for a requirement: the managed copy of a
+// pinned version when installed, otherwise the tool found on PATH
+func (m *Manager) Resolve(req Requirement) (path string, managed bool, err error) {
+	if pinned := req.Pinned(); pinned != "" {
+		p := m.ManagedPath(req.Tool, pinned)
+		if info, err := os.Stat(p); err == nil && !info.IsDir() {
+			return p, true, nil
+		}
+	}
+
+	p, err := m.lookPath(req.Tool)
+	if err != nil {
+		return "", false, fmt.Errorf("%s not found on PATH", req.Tool)
+	}
+	return p, false, nil
+}
+
+// Check resolves every requirement and reports its version status
+func (m *Manager) Check(ctx context.Context, reqs []Requirement) []Status {
+	statuses := make([]Status, 0, len(reqs))
+	for _, req := range reqs {
+		status := Status{Requirement: req}
+		status.Path, status.Managed, status.Error = m.Resolve(req)
+		if status.Error == nil {
+			status.Version, status.Error = m.runVersion(ctx, status.Path)
+		}
+		statuses = append(statuses, status)
+	}
+	return statuses
+}
+
+// Requirements converts a tool -> constraint map (as found in the project
+// `toolchain` section) into a sorted list of requirements
+func Requirements(cfg map[string]string) []Requirement {
+	reqs := make([]Requirement, 0, len(cfg))
+	for tool, constraint := range cfg {
+		reqs = append(reqs, Requirement{Tool: tool, Constraint: constraint})
+	}
+	sort.Slice(reqs, func(i, j int) bool { return reqs[i].Tool < reqs[j].Tool })
+	return reqs
+}
+
+// runVersion runs `<binary> --version` and extracts the first semantic version
+func runVersion(ctx context.Context, binary string) (string, error) {
+	out, err := exec.CommandContext(ctx, binary, "--version").Output() // #nosec G204 -- binary resolved from PATH or the FTL tools directory
+	if err != nil {
+		return "", fmt.Errorf("failed to get %s version: %w", filepath.Base(binary), err)
+	}
+	return ParseVersion(string(out))
+}
+
+// ParseVersion extracts the first x.y.z version from tool output
+func ParseVersion(output string) (string, error) {
+	v := versionPattern.FindString(output)
+	if v == "" {
+		return "", fmt.Errorf("unexpected version output: %s", strings.TrimSpace(output))
+	}
+	return v, nil
+}
+
+// CompareVersions compares two x.y.z versions, returning -1, 0 or 1.
+// Pre-release and build suffixes are ignored.
+func CompareVersions(a, b string) int {
+	pa, pb := versionParts(a), versionParts(b)
+	for i := 0; i < 3; i++ {
+		if pa[i] != pb[i] {
+			if pa[i] < pb[i] {
+				return -1
+			}
+			return 1
+		}
+	}
+	return 0
+}
+
+func versionParts(v string) [3]int {
+	var parts [3]int
+	v = strings.TrimPrefix(strings.TrimSpace(v), "v")
+	if i := strings.IndexAny(v, "-+"); i >= 0 {
+		v = v[:i]
+	}
+	for i, p := range strings.SplitN(v, ".", 3) {
+		n, _ := strconv.Atoi(p)
+		parts[i] = n
+	}
+	return parts
+}
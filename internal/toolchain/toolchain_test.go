@@ -0,0 +1,268 @@
+package toolchain
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"os"
+	"path"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestRequirement(t *testing.T) {
+	tests := []struct {
+		constraint string
+		version    string
+		pinned     string
+		satisfied  bool
+	}{
+		{constraint: "3.3.1", version: "3.3.1", pinned: "3.3.1", satisfied: true},
+		{constraint: "v3.3.1", version: "3.3.1", pinned: "3.3.1", satisfied: true},
+		{constraint: "=3.3.1", version: "3.4.0", pinned: "3.3.1", satisfied: false},
+		{constraint: ">=1.89.0", version: "1.90.2", pinned: "", satisfied: true},
+		{constraint: ">=1.89.0", version: "1.88.9", pinned: "", satisfied: false},
+		{constraint: "", version: "0.0.1", pinned: "", satisfied: true},
+		{constraint: "*", version: "0.0.1", pinned: "", satisfied: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.constraint+"/"+tt.version, func(t *testing.T) {
+			req := Requirement{Tool: "spin", Constraint: tt.constraint}
+			assert.Equal(t, tt.pinned, req.Pinned())
+			assert.Equal(t, tt.satisfied, req.Satisfied(tt.version))
+		})
+	}
+}
+
+func TestCompareVersions(t *testing.T) {
+	assert.Equal(t, 0, CompareVersions("1.2.3", "v1.2.3"))
+	assert.Equal(t, -1, CompareVersions("1.2.3", "1.10.0"))
+	assert.Equal(t, 1, CompareVersions("2.0.0", "1.99.99"))
+	assert.Equal(t, 0, CompareVersions("3.3.1-alpha.1", "3.3.1"))
+}
+
+func TestParseVersion(t *testing.T) {
+	v, err := ParseVersion("spin 3.3.1 (6a1b6b3 2025-06-25)\n")
+	require.NoError(t, err)
+	assert.Equal(t, "3.3.1", v)
+
+	v, err = ParseVersion("cargo 1.89.0 (c24e10642 2025-06-23)")
+	require.NoError(t, err)
+	assert.Equal(t, "1.89.0", v)
+
+	_, err = ParseVersion("no version here")
+	assert.Error(t, err)
+}
+
+func TestRequirements_Sorted(t *testing.T) {
+	reqs := Requirements(map[string]string{"wkg": "0.11.0", "cargo": ">=1.89.0", "spin": "3.3.1"})
+	require.Len(t, reqs, 3)
+	assert.Equal(t, "cargo", reqs[0].Tool)
+	assert.Equal(t, "spin", reqs[1].Tool)
+	assert.Equal(t, "wkg", reqs[2].Tool)
+}
+
+func newTestManager(t *testing.T) *Manager {
+	t.Helper()
+	m := NewManagerWithDir(t.TempDir())
+	m.lookPath = func(tool string) (string, error) {
+		if tool == "spin" || tool == "cargo" {
+			return "/usr/bin/" + tool, nil
+		}
+		return "", errors.New("not found")
+	}
+	m.runVersion = func(_ context.Context, binary string) (string, error) {
+		switch binary {
+		case "/usr/bin/spin":
+			return "3.0.0", nil
+		case "/usr/bin/cargo":
+			return "1.90.0", nil
+		}
+		return "3.3.1", nil
+	}
+	return m
+}
+
+func TestManager_ResolvePrefersManaged(t *testing.T) {
+	m := newTestManager(t)
+	req := Requirement{Tool: "spin", Constraint: "3.3.1"}
+
+	path, managed, err := m.Resolve(req)
+	require.NoError(t, err)
+	assert.False(t, managed)
+	assert.Equal(t, "/usr/bin/spin", path)
+
+	require.NoError(t, writeExecutable(m.ManagedPath("spin", "3.3.1"), []byte("bin")))
+
+	path, managed, err = m.Resolve(req)
+	require.NoError(t, err)
+	assert.True(t, managed)
+	assert.Equal(t, m.ManagedPath("spin", "3.3.1"), path)
+}
+
+func TestManager_Check(t *testing.T) {
+	m := newTestManager(t)
+	statuses := m.Check(context.Background(), []Requirement{
+		{Tool: "cargo", Constraint: ">=1.89.0"},
+		{Tool: "spin", Constraint: "3.3.1"},
+		{Tool: "wkg", Constraint: "0.11.0"},
+	})
+	require.Len(t, statuses, 3)
+
+	assert.True(t, statuses[0].OK())
+	assert.Equal(t, "1.90.0", statuses[0].Version)
+
+	assert.False(t, statuses[1].OK())
+	assert.NoError(t, statuses[1].Error)
+	assert.Equal(t, "3.0.0", statuses[1].Version)
+
+	assert.False(t, statuses[2].OK())
+	assert.ErrorContains(t, statuses[2].Error, "not found")
+}
+
+// spinArchive returns a spin release archive holding content as the binary
+func spinArchive(t *testing.T, content []byte) []byte {
+	t.Helper()
+	var archive bytes.Buffer
+	gz := gzip.NewWriter(&archive)
+	tw := tar.NewWriter(gz)
+	require.NoError(t, tw.WriteHeader(&tar.Header{Name: "spin", Mode: 0755, Size: int64(len(content)), Typeflag: tar.TypeReg}))
+	_, err := tw.Write(content)
+	require.NoError(t, err)
+	require.NoError(t, tw.Close())
+	require.NoError(t, gz.Close())
+	return archive.Bytes()
+}
+
+func sha256Hex(data []byte) string {
+	sum := sha256.Sum256(data)
+	return hex.EncodeToString(sum[:])
+}
+
+func TestManager_Install(t *testing.T) {
+	content := []byte("#!/bin/sh\necho spin 3.3.1\n")
+	archive := spinArchive(t, content)
+
+	m := newTestManager(t)
+	var downloads []string
+	m.download = func(_ context.Context, url string) ([]byte, error) {
+		downloads = append(downloads, url)
+		if strings.HasSuffix(url, "/checksums-v3.3.1.txt") {
+			return []byte(fmt.Sprintf("%s  spin-v3.3.1-other-arch.tar.gz\n%s  %s\n",
+				sha256Hex(nil), sha256Hex(archive), path.Base(downloads[0]))), nil
+		}
+		return archive, nil
+	}
+
+	installed, err := m.Install(context.Background(), "spin", "3.3.1")
+	if err != nil {
+		// Unsupported platforms have no release asset
+		assert.Contains(t, err.Error(), "no spin release")
+		return
+	}
+	assert.Equal(t, m.ManagedPath("spin", "3.3.1"), installed)
+	require.Len(t, downloads, 2)
+	assert.Contains(t, downloads[0], "/v3.3.1/spin-v3.3.1-")
+	assert.Equal(t, "https://github.com/spinframework/spin/releases/download/v3.3.1/checksums-v3.3.1.txt", downloads[1])
+
+	data, err := os.ReadFile(filepath.Clean(installed))
+	require.NoError(t, err)
+	assert.Equal(t, content, data)
+
+	// Installed versions are reused
+	_, err = m.Install(context.Background(), "spin", "3.3.1")
+	require.NoError(t, err)
+	assert.Len(t, downloads, 2)
+}
+
+func TestManager_InstallVerifiesChecksum(t *testing.T) {
+	archive := spinArchive(t, []byte("tampered"))
+
+	tests := []struct {
+		name      string
+		checksums func(asset string) string
+		wantErr   string
+	}{
+		{
+			name:      "mismatch",
+			checksums: func(asset string) string { return sha256Hex([]byte("original")) + "  " + asset + "\n" },
+			wantErr:   "checksum mismatch",
+		},
+		{
+			name:      "missing",
+			checksums: func(string) string { return sha256Hex(archive) + "  spin-v3.3.1-other-arch.tar.gz\n" },
+			wantErr:   "no published checksum",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			m := newTestManager(t)
+			var asset string
+			m.download = func(_ context.Context, url string) ([]byte, error) {
+				if strings.HasSuffix(url, ".txt") {
+					return []byte(tt.checksums(asset)), nil
+				}
+				asset = path.Base(url)
+				return archive, nil
+			}
+
+			_, err := m.Install(context.Background(), "spin", "3.3.1")
+			if err != nil && strings.Contains(err.Error(), "no spin release") {
+				return
+			}
+			assert.ErrorContains(t, err, tt.wantErr)
+			assert.NoFileExists(t, m.ManagedPath("spin", "3.3.1"))
+		})
+	}
+}
+
+func TestManager_InstallVerifiesReleaseDigest(t *testing.T) {
+	binary := []byte("wkg")
+
+	for _, digest := range []string{sha256Hex(binary), sha256Hex([]byte("other"))} {
+		m := newTestManager(t)
+		var downloads []string
+		m.download = func(_ context.Context, url string) ([]byte, error) {
+			downloads = append(downloads, url)
+			if strings.HasPrefix(url, "https://api.github.com/") {
+				return []byte(fmt.Sprintf(`{"assets":[{"name":%q,"digest":"sha256:%s"}]}`,
+					path.Base(downloads[0]), digest)), nil
+			}
+			return binary, nil
+		}
+
+		installed, err := m.Install(context.Background(), "wkg", "0.11.0")
+		if err != nil && strings.Contains(err.Error(), "no wkg release") {
+			return
+		}
+		require.Len(t, downloads, 2)
+		assert.Equal(t, "https://api.github.com/repos/bytecodealliance/wasm-pkg-tools/releases/tags/v0.11.0", downloads[1])
+		if digest != sha256Hex(binary) {
+			assert.ErrorContains(t, err, "checksum mismatch")
+			continue
+		}
+		require.NoError(t, err)
+		assert.Equal(t, m.ManagedPath("wkg", "0.11.0"), installed)
+	}
+}
+
+func TestManager_InstallRejectsUnsupported(t *testing.T) {
+	m := newTestManager(t)
+
+	_, err := m.Install(context.Background(), "cargo", "1.89.0")
+	assert.ErrorContains(t, err, "cannot be installed automatically")
+
+	_, err = m.Install(context.Background(), "spin", "")
+	assert.ErrorContains(t, err, "pinned version")
+}
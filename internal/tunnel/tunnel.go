@@ -0,0 +1,248 @@
+// Package tunnel exposes a local address through a public tunnel so hosted
+// MCP clients can reach an application started with `ftl up --expose`
+package tunnel
+
+import (
+	"bufio"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"os/exec"
+	"regexp"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+// DefaultProvider is used when no tunnel provider is configured
+const DefaultProvider = "cloudflared"
+
+// DefaultStartTimeout bounds how long a provider may take to report its public URL
+const DefaultStartTimeout = 30 * time.Second
+
+// Tunnel is an established tunnel
+type Tunnel interface {
+	// URL returns the public base URL forwarding to the local address
+	URL() string
+	// Close tears the tunnel down
+	Close() error
+}
+
+// Provider opens tunnels to a local address
+type Provider interface {
+	// Name returns the provider name
+	Name() string
+	// Open starts a tunnel forwarding to localAddr (host:port)
+	Open(ctx context.Context, localAddr string) (Tunnel, error)
+}
+
+// Factory creates a provider from the user's tunnel configuration
+type Factory func(cfg config.TunnelConfig) (Provider, error)
+
+var (
+	providersMu sync.RWMutex
+	providers   = map[string]Factory{
+		"cloudflared": newCloudflared,
+		"command":     newCommand,
+	}
+)
+
+// Register makes a tunnel provider available by name
+func Register(name string, factory Factory) {
+	providersMu.Lock()
+	defer providersMu.Unlock()
+	providers[name] = factory
+}
+
+// Providers returns the registered provider names
+func Providers() []string {
+	providersMu.RLock()
+	defer providersMu.RUnlock()
+
+	names := make([]string, 0, len(providers))
+	for name := range providers {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// New creates the provider selected by cfg, falling back to DefaultProvider
+func New(cfg *config.TunnelConfig) (Provider, error) {
+	var c config.TunnelConfig
+	if cfg != nil {
+		c = *cfg
+	}
+	if c.Provider == "" {
+		c.Provider = DefaultProvider
+	}
+
+	providersMu.RLock()
+	factory, ok := providers[c.Provider]
+	providersMu.RUnlock()
+	if !ok {
+		return nil, fmt.Errorf("unknown tunnel provider %q (available: %s)", c.Provider, strings.Join(Providers(), ", "))
+	}
+	return factory(c)
+}
+
+// newCloudflared uses a Cloudflare quick tunnel, which needs no account
+func newCloudflared(_ config.TunnelConfig) (Provider, error) {
+	return &CommandProvider{
+		name:    "cloudflared",
+		args:    []string{"cloudflared", "tunnel", "--no-autoupdate", "--url", "http://{addr}"},
+		pattern: regexp.MustCompile(`https://[a-z0-9-]+\.trycloudflare\.com`),
+		timeout: DefaultStartTimeout,
+	}, nil
+}
+
+// newCommand runs a user supplied tunnel command
+func newCommand(cfg config.TunnelConfig) (Provider, error) {
+	if len(cfg.Command) == 0 {
+		return nil, errors.New("the command tunnel provider requires tunnel.command in the FTL config")
+	}
+
+	pattern := `https://[^\s"'<>]+`
+	if cfg.URLPattern != "" {
+		pattern = cfg.URLPattern
+	}
+	re, err := regexp.Compile(pattern)
+	if err != nil {
+		return nil, fmt.Errorf("invalid tunnel url_pattern: %w", err)
+	}
+
+	return &CommandProvider{
+		name:    "command",
+		args:    cfg.Command,
+		pattern: re,
+		timeout: DefaultStartTimeout,
+	}, nil
+}
+
+// CommandProvider runs a tunnel client as a child process and reads the
+// public URL from its output
+type CommandProvider struct {
+	name    string
+	args    []string
+	pattern *regexp.Regexp
+	timeout time.Duration
+}
+
+// Name returns the provider name
+func (p *CommandProvider) Name() string {
+	return p.name
+}
+
+// Open starts the tunnel command and waits for it to print a public URL
+func (p *CommandProvider) Open(ctx context.Context, localAddr string) (Tunnel, error) {
+	host, port, err := net.SplitHostPort(localAddr)
+	if err != nil {
+		return nil, fmt.Errorf("invalid local address %q: %w", localAddr, err)
+	}
+
+	replacer := strings.NewReplacer("{addr}", localAddr, "{host}", host, "{port}", port)
+	args := make([]string, len(p.args))
+	for i, arg := range p.args {
+		args[i] = replacer.Replace(arg)
+	}
+
+	if _, err := exec.LookPath(args[0]); err != nil {
+		return nil, fmt.Errorf("%s not found on PATH; install it or configure another tunnel provider", args[0])
+	}
+
+	cmd := exec.CommandContext(ctx, args[0], args[1:]...) // #nosec G204 -- command comes from the user's own FTL config
+	out, w := io.Pipe()
+	cmd.Stdout = w
+	cmd.Stderr = w
+	// Don't wait on output held open by grandchildren once the client is killed
+	cmd.WaitDelay = time.Second
+	if err := cmd.Start(); err != nil {
+		return nil, fmt.Errorf("failed to start %s: %w", args[0], err)
+	}
+
+	t := &processTunnel{cmd: cmd, done: make(chan struct{})}
+	go func() {
+		_ = cmd.Wait()
+		_ = w.Close()
+	}()
+
+	found := make(chan string, 1)
+	var tail []string
+	var tailMu sync.Mutex
+	go func() {
+		scanner := bufio.NewScanner(out)
+		for scanner.Scan() {
+			line := scanner.Text()
+			tailMu.Lock()
+			tail = append(tail, line)
+			if len(tail) > 5 {
+				tail = tail[1:]
+			}
+			tailMu.Unlock()
+			if url := p.pattern.FindString(line); url != "" {
+				select {
+				case found <- url:
+				default:
+				}
+			}
+		}
+		// Keep draining so the tunnel process never blocks on a full pipe
+		_, _ = io.Copy(io.Discard, out)
+		close(t.done)
+	}()
+
+	timer := time.NewTimer(p.timeout)
+	defer timer.Stop()
+
+	select {
+	case url := <-found:
+		t.url = strings.TrimRight(url, "/")
+		return t, nil
+	case <-t.done:
+		tailMu.Lock()
+		defer tailMu.Unlock()
+		return nil, fmt.Errorf("%s exited before reporting a public URL: %s", args[0], strings.Join(tail, "\n"))
+	case <-timer.C:
+		_ = t.Close()
+		return nil, fmt.Errorf("timed out after %s waiting for %s to report a public URL", p.timeout, args[0])
+	case <-ctx.Done():
+		_ = t.Close()
+		return nil, ctx.Err()
+	}
+}
+
+// processTunnel is a tunnel backed by a child process
+type processTunnel struct {
+	cmd *exec.Cmd
+	url string
+	// done is closed once the process has exited and its output is drained
+	done chan struct{}
+	once sync.Once
+}
+
+// URL returns the public URL
+func (t *processTunnel) URL() string {
+	return t.url
+}
+
+// Close stops the tunnel process
+func (t *processTunnel) Close() error {
+	var err error
+	t.once.Do(func() {
+		select {
+		case <-t.done:
+			return
+		default:
+		}
+		if t.cmd.Process != nil {
+			err = t.cmd.Process.Kill()
+		}
+		<-t.done
+	})
+	return err
+}
@@ -0,0 +1,86 @@
+package tunnel
+
+import (
+	"context"
+	"regexp"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/fastertools/ftl/internal/config"
+)
+
+func TestNew_DefaultProvider(t *testing.T) {
+	p, err := New(nil)
+	require.NoError(t, err)
+	assert.Equal(t, DefaultProvider, p.Name())
+
+	_, err = New(&config.TunnelConfig{Provider: "missing"})
+	assert.ErrorContains(t, err, "available: cloudflared, command")
+}
+
+func TestNew_CommandProviderValidation(t *testing.T) {
+	_, err := New(&config.TunnelConfig{Provider: "command"})
+	assert.ErrorContains(t, err, "requires tunnel.command")
+
+	_, err = New(&config.TunnelConfig{Provider: "command", Command: []string{"sh"}, URLPattern: "("})
+	assert.ErrorContains(t, err, "invalid tunnel url_pattern")
+}
+
+func TestCommandProvider_Open(t *testing.T) {
+	p, err := New(&config.TunnelConfig{
+		Provider: "command",
+		Command:  []string{"sh", "-c", "echo connecting to {host} port {port}; echo 'your url is: https://abc.tunnel.test/ (forwarding {addr})'; sleep 30"},
+	})
+	require.NoError(t, err)
+
+	tun, err := p.Open(context.Background(), "127.0.0.1:3000")
+	require.NoError(t, err)
+	assert.Equal(t, "https://abc.tunnel.test", tun.URL())
+	assert.NoError(t, tun.Close())
+	assert.NoError(t, tun.Close())
+}
+
+func TestCommandProvider_ExitsWithoutURL(t *testing.T) {
+	p, err := New(&config.TunnelConfig{
+		Provider: "command",
+		Command:  []string{"sh", "-c", "echo 'authentication failed'; exit 1"},
+	})
+	require.NoError(t, err)
+
+	_, err = p.Open(context.Background(), "localhost:3000")
+	assert.ErrorContains(t, err, "authentication failed")
+}
+
+func TestCommandProvider_Timeout(t *testing.T) {
+	p := &CommandProvider{
+		name:    "command",
+		args:    []string{"sh", "-c", "sleep 30"},
+		pattern: regexp.MustCompile(`https://\S+`),
+		timeout: 100 * time.Millisecond,
+	}
+
+	_, err := p.Open(context.Background(), "localhost:3000")
+	assert.ErrorContains(t, err, "timed out")
+}
+
+func TestCommandProvider_InvalidAddress(t *testing.T) {
+	p, err := New(&config.TunnelConfig{Provider: "command", Command: []string{"sh"}})
+	require.NoError(t, err)
+
+	_, err = p.Open(context.Background(), "localhost")
+	assert.ErrorContains(t, err, "invalid local address")
+}
+
+func TestRegister(t *testing.T) {
+	Register("test-provider", func(cfg config.TunnelConfig) (Provider, error) {
+		return &CommandProvider{name: "test-provider"}, nil
+	})
+
+	p, err := New(&config.TunnelConfig{Provider: "test-provider"})
+	require.NoError(t, err)
+	assert.Equal(t, "test-provider", p.Name())
+	assert.Contains(t, Providers(), "test-provider")
+}
@@ -0,0 +1,621 @@
+package oci
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// WASM binary kinds
+const (
+	WASMKindComponent = "component"
+	WASMKindModule    = "module"
+)
+
+var wasmMagic = []byte{0x00, 'a', 's', 'm'}
+
+// Section ids of the component model binary format
+const (
+	componentSectionCustom     = 0
+	componentSectionCoreModule = 1
+	componentSectionComponent  = 4
+	componentSectionImport     = 10
+	componentSectionExport     = 11
+)
+
+// Section ids of the core module binary format
+const (
+	moduleSectionImport = 2
+	moduleSectionExport = 7
+)
+
+// Names of the non-custom sections, by id
+var (
+	componentSectionNames = map[byte]string{
+		1: "module", 2: "core instance", 3: "core type", 4: "component", 5: "instance",
+		6: "alias", 7: "type", 8: "canon", 9: "start", 10: "import", 11: "export", 12: "value",
+	}
+	moduleSectionNames = map[byte]string{
+		1: "type", 2: "import", 3: "function", 4: "table", 5: "memory", 6: "global",
+		7: "export", 8: "start", 9: "element", 10: "code", 11: "data", 12: "data count", 13: "tag",
+	}
+)
+
+// WASMInfo describes the top-level interface of a WASM component or core module
+type WASMInfo struct {
+	Kind           string              `json:"kind"`
+	Imports        []WASMItem          `json:"imports"`
+	Exports        []WASMItem          `json:"exports"`
+	Capabilities   []string            `json:"capabilities"`
+	CustomSections []CustomSection     `json:"custom_sections"`
+	Producers      map[string][]string `json:"producers,omitempty"`
+}
+
+// WASMItem is an imported or exported item, e.g. the instance
+// wasi:http/outgoing-handler@0.2.0
+type WASMItem struct {
+	Name string `json:"name"`
+	Kind string `json:"kind"`
+}
+
+// CustomSection is an embedded custom section
+type CustomSection struct {
+	Name string `json:"name"`
+	Size int    `json:"size"`
+}
+
+// WASMSection is a section of a WASM binary and the size of its contents
+type WASMSection struct {
+	Name string `json:"name"`
+	Size int    `json:"size"`
+}
+
+// capabilityInterfaces maps prefixes of imported interface names to the
+// capability they grant. Entries ending in / match a whole package.
+var capabilityInterfaces = []struct {
+	prefix     string
+	capability string
+}{
+	{"wasi:http/outgoing-handler", "outbound-http"},
+	{"wasi:sockets/", "sockets"},
+	{"wasi:filesystem/", "filesystem"},
+	{"wasi:cli/environment", "environment"},
+	{"wasi:cli/std", "stdio"},
+	{"wasi:cli/terminal-", "stdio"},
+	{"wasi:clocks/", "clocks"},
+	{"wasi:random/", "random"},
+	{"wasi:keyvalue/", "key-value"},
+	{"wasi:config/", "variables"},
+	{"fermyon:spin/key-value", "key-value"},
+	{"fermyon:spin/variables", "variables"},
+	{"fermyon:spin/sqlite", "sqlite"},
+	{"fermyon:spin/llm", "llm"},
+	{"fermyon:spin/redis", "redis"},
+	{"fermyon:spin/mysql", "mysql"},
+	{"fermyon:spin/postgres", "postgres"},
+	{"fermyon:spin/mqtt", "mqtt"},
+	{"spin:postgres/", "postgres"},
+	{"spin:sqlite/", "sqlite"},
+	{"spin:mqtt/", "mqtt"},
+}
+
+// InspectWASM parses the top-level imports, exports and custom sections of a
+// WASM component or core module
+func InspectWASM(data []byte) (*WASMInfo, error) {
+	kind, err := wasmKind(data)
+	if err != nil {
+		return nil, err
+	}
+	info := &WASMInfo{Kind: kind, Imports: []WASMItem{}, Exports: []WASMItem{}, CustomSections: []CustomSection{}}
+
+	r := &wasmReader{data: data, pos: 8}
+	for !r.done() {
+		id, err := r.byte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := r.u32()
+		if err != nil {
+			return nil, err
+		}
+		body, err := r.bytes(int(size))
+		if err != nil {
+			return nil, fmt.Errorf("section %d: %w", id, err)
+		}
+
+		section := &wasmReader{data: body}
+		switch {
+		case id == componentSectionCustom:
+			err = info.readCustomSection(section)
+		case info.Kind == WASMKindComponent && id == componentSectionImport:
+			err = info.readComponentImports(section)
+		case info.Kind == WASMKindComponent && id == componentSectionExport:
+			err = info.readComponentExports(section)
+		case info.Kind == WASMKindModule && id == moduleSectionImport:
+			err = info.readModuleImports(section)
+		case info.Kind == WASMKindModule && id == moduleSectionExport:
+			err = info.readModuleExports(section)
+		}
+		if err != nil {
+			return nil, fmt.Errorf("section %d: %w", id, err)
+		}
+	}
+
+	info.Capabilities = info.capabilities()
+	return info, nil
+}
+
+// wasmKind returns whether a WASM binary is a component or a core module
+func wasmKind(data []byte) (string, error) {
+	if len(data) < 8 || !bytes.Equal(data[:4], wasmMagic) {
+		return "", errors.New("not a WASM binary")
+	}
+	switch {
+	case data[6] == 0x01 && data[7] == 0x00:
+		return WASMKindComponent, nil
+	case data[6] == 0x00 && data[7] == 0x00:
+		return WASMKindModule, nil
+	default:
+		return "", fmt.Errorf("unsupported WASM binary layer %d", data[6])
+	}
+}
+
+// WASMSections lists the sections of a WASM component or core module with
+// their sizes. The core modules and components a component embeds are listed
+// by their own sections, named after their position, e.g. "module 0/code" or
+// "module 1/custom .debug_info".
+func WASMSections(data []byte) ([]WASMSection, error) {
+	kind, err := wasmKind(data)
+	if err != nil {
+		return nil, err
+	}
+	names := moduleSectionNames
+	if kind == WASMKindComponent {
+		names = componentSectionNames
+	}
+
+	sections := []WASMSection{}
+	nested := map[byte]int{}
+	r := &wasmReader{data: data, pos: 8}
+	for !r.done() {
+		id, err := r.byte()
+		if err != nil {
+			return nil, err
+		}
+		size, err := r.u32()
+		if err != nil {
+			return nil, err
+		}
+		body, err := r.bytes(int(size))
+		if err != nil {
+			return nil, fmt.Errorf("section %d: %w", id, err)
+		}
+
+		switch {
+		case id == componentSectionCustom:
+			name, err := (&wasmReader{data: body}).name()
+			if err != nil {
+				return nil, fmt.Errorf("section %d: %w", id, err)
+			}
+			sections = append(sections, WASMSection{Name: "custom " + name, Size: len(body)})
+		case kind == WASMKindComponent && (id == componentSectionCoreModule || id == componentSectionComponent):
+			embedded, err := WASMSections(body)
+			if err != nil {
+				return nil, fmt.Errorf("section %d: %w", id, err)
+			}
+			prefix := fmt.Sprintf("%s %d/", names[id], nested[id])
+			nested[id]++
+			for _, section := range embedded {
+				sections = append(sections, WASMSection{Name: prefix + section.Name, Size: section.Size})
+			}
+		default:
+			name, ok := names[id]
+			if !ok {
+				name = fmt.Sprintf("section %d", id)
+			}
+			sections = append(sections, WASMSection{Name: name, Size: len(body)})
+		}
+	}
+	return sections, nil
+}
+
+// capabilities derives the sorted capabilities the imports require
+func (info *WASMInfo) capabilities() []string {
+	capabilities := []string{}
+	add := func(capability string) {
+		if !slices.Contains(capabilities, capability) {
+			capabilities = append(capabilities, capability)
+		}
+	}
+
+	for _, item := range info.Imports {
+		if info.Kind == WASMKindModule {
+			if module, _, _ := strings.Cut(item.Name, "."); module == "wasi_snapshot_preview1" {
+				add("wasi-preview1")
+			}
+			continue
+		}
+		for _, entry := range capabilityInterfaces {
+			if strings.HasPrefix(item.Name, entry.prefix) {
+				add(entry.capability)
+				break
+			}
+		}
+	}
+
+	slices.Sort(capabilities)
+	return capabilities
+}
+
+func (info *WASMInfo) readCustomSection(r *wasmReader) error {
+	name, err := r.name()
+	if err != nil {
+		return err
+	}
+	info.CustomSections = append(info.CustomSections, CustomSection{Name: name, Size: len(r.data) - r.pos})
+
+	// Producers are informative only, a malformed section is not an error
+	if name == "producers" {
+		if producers, err := readProducers(r); err == nil {
+			info.Producers = producers
+		}
+	}
+	return nil
+}
+
+// readProducers decodes the producers section: fields such as language or
+// processed-by, each listing tools with their version
+func readProducers(r *wasmReader) (map[string][]string, error) {
+	producers := make(map[string][]string)
+	fields, err := r.u32()
+	if err != nil {
+		return nil, err
+	}
+	for range fields {
+		field, err := r.name()
+		if err != nil {
+			return nil, err
+		}
+		values, err := r.u32()
+		if err != nil {
+			return nil, err
+		}
+		for range values {
+			tool, err := r.name()
+			if err != nil {
+				return nil, err
+			}
+			version, err := r.name()
+			if err != nil {
+				return nil, err
+			}
+			producers[field] = append(producers[field], strings.TrimSpace(tool+" "+version))
+		}
+	}
+	return producers, nil
+}
+
+func (info *WASMInfo) readComponentImports(r *wasmReader) error {
+	count, err := r.u32()
+	if err != nil {
+		return err
+	}
+	for range count {
+		name, err := r.externName()
+		if err != nil {
+			return err
+		}
+		kind, err := r.externDesc()
+		if err != nil {
+			return fmt.Errorf("import %s: %w", name, err)
+		}
+		info.Imports = append(info.Imports, WASMItem{Name: name, Kind: kind})
+	}
+	return nil
+}
+
+func (info *WASMInfo) readComponentExports(r *wasmReader) error {
+	count, err := r.u32()
+	if err != nil {
+		return err
+	}
+	for range count {
+		name, err := r.externName()
+		if err != nil {
+			return err
+		}
+		kind, err := r.sortIdx()
+		if err != nil {
+			return fmt.Errorf("export %s: %w", name, err)
+		}
+		// An optional type ascription follows
+		ascribed, err := r.byte()
+		if err != nil {
+			return err
+		}
+		if ascribed == 0x01 {
+			if kind, err = r.externDesc(); err != nil {
+				return fmt.Errorf("export %s: %w", name, err)
+			}
+		}
+		info.Exports = append(info.Exports, WASMItem{Name: name, Kind: kind})
+	}
+	return nil
+}
+
+func (info *WASMInfo) readModuleImports(r *wasmReader) error {
+	count, err := r.u32()
+	if err != nil {
+		return err
+	}
+	for range count {
+		module, err := r.name()
+		if err != nil {
+			return err
+		}
+		field, err := r.name()
+		if err != nil {
+			return err
+		}
+		kind, err := r.moduleImportDesc()
+		if err != nil {
+			return fmt.Errorf("import %s.%s: %w", module, field, err)
+		}
+		info.Imports = append(info.Imports, WASMItem{Name: module + "." + field, Kind: kind})
+	}
+	return nil
+}
+
+func (info *WASMInfo) readModuleExports(r *wasmReader) error {
+	count, err := r.u32()
+	if err != nil {
+		return err
+	}
+	for range count {
+		name, err := r.name()
+		if err != nil {
+			return err
+		}
+		kind, err := r.byte()
+		if err != nil {
+			return err
+		}
+		if _, err := r.u32(); err != nil {
+			return err
+		}
+		info.Exports = append(info.Exports, WASMItem{Name: name, Kind: moduleExternKind(kind)})
+	}
+	return nil
+}
+
+// wasmReader reads the primitive encodings of the WASM binary format
+type wasmReader struct {
+	data []byte
+	pos  int
+}
+
+var errTruncated = errors.New("unexpected end of WASM binary")
+
+func (r *wasmReader) done() bool {
+	return r.pos >= len(r.data)
+}
+
+func (r *wasmReader) byte() (byte, error) {
+	if r.done() {
+		return 0, errTruncated
+	}
+	b := r.data[r.pos]
+	r.pos++
+	return b, nil
+}
+
+func (r *wasmReader) bytes(n int) ([]byte, error) {
+	if n < 0 || len(r.data)-r.pos < n {
+		return nil, errTruncated
+	}
+	b := r.data[r.pos : r.pos+n]
+	r.pos += n
+	return b, nil
+}
+
+// leb reads an unsigned LEB128 integer, which also skips signed ones
+func (r *wasmReader) leb() (uint64, error) {
+	var result uint64
+	for shift := uint(0); shift < 64; shift += 7 {
+		b, err := r.byte()
+		if err != nil {
+			return 0, err
+		}
+		result |= uint64(b&0x7f) << shift
+		if b&0x80 == 0 {
+			return result, nil
+		}
+	}
+	return 0, errors.New("malformed LEB128 integer")
+}
+
+func (r *wasmReader) u32() (uint32, error) {
+	v, err := r.leb()
+	if err != nil {
+		return 0, err
+	}
+	if v > 0xffffffff {
+		return 0, errors.New("integer too large")
+	}
+	return uint32(v), nil
+}
+
+func (r *wasmReader) name() (string, error) {
+	n, err := r.u32()
+	if err != nil {
+		return "", err
+	}
+	b, err := r.bytes(int(n))
+	if err != nil {
+		return "", err
+	}
+	return string(b), nil
+}
+
+// externName reads an import or export name. The 0x01 form carries a
+// trailing string (a URL or version suffix) that is not part of the name.
+func (r *wasmReader) externName() (string, error) {
+	form, err := r.byte()
+	if err != nil {
+		return "", err
+	}
+	name, err := r.name()
+	if err != nil {
+		return "", err
+	}
+	switch form {
+	case 0x00:
+	case 0x01:
+		if _, err := r.name(); err != nil {
+			return "", err
+		}
+	default:
+		return "", fmt.Errorf("unsupported extern name form 0x%02x", form)
+	}
+	return name, nil
+}
+
+// externDesc reads the type of an imported or exported item and returns its kind
+func (r *wasmReader) externDesc() (string, error) {
+	kind, err := r.byte()
+	if err != nil {
+		return "", err
+	}
+	switch kind {
+	case 0x00:
+		// Core module: 0x11 followed by the core type index
+		if _, err := r.byte(); err != nil {
+			return "", err
+		}
+		_, err = r.u32()
+		return "module", err
+	case 0x01, 0x04, 0x05:
+		_, err = r.u32()
+		return componentSortKind(kind), err
+	case 0x02, 0x03:
+		// Value or type bound: an equality bound carries an index, a
+		// value bound a value type, and 0x01 type bounds are resources
+		bound, err := r.byte()
+		if err != nil {
+			return "", err
+		}
+		if bound == 0x00 || kind == 0x02 {
+			_, err = r.leb()
+		}
+		return componentSortKind(kind), err
+	default:
+		return "", fmt.Errorf("unsupported extern descriptor 0x%02x", kind)
+	}
+}
+
+// sortIdx reads a sort and index, returning the kind of the item
+func (r *wasmReader) sortIdx() (string, error) {
+	sort, err := r.byte()
+	if err != nil {
+		return "", err
+	}
+	kind := componentSortKind(sort)
+	if sort == 0x00 {
+		coreSort, err := r.byte()
+		if err != nil {
+			return "", err
+		}
+		kind = "core " + moduleExternKind(coreSort)
+	}
+	_, err = r.u32()
+	return kind, err
+}
+
+// moduleImportDesc reads the descriptor of a core module import and returns its kind
+func (r *wasmReader) moduleImportDesc() (string, error) {
+	kind, err := r.byte()
+	if err != nil {
+		return "", err
+	}
+	switch kind {
+	case 0x00, 0x04:
+		if kind == 0x04 {
+			// Tag attribute
+			if _, err := r.byte(); err != nil {
+				return "", err
+			}
+		}
+		_, err = r.u32()
+	case 0x01:
+		if _, err := r.leb(); err != nil {
+			return "", err
+		}
+		err = r.limits()
+	case 0x02:
+		err = r.limits()
+	case 0x03:
+		if _, err := r.leb(); err != nil {
+			return "", err
+		}
+		_, err = r.byte()
+	default:
+		return "", fmt.Errorf("unsupported import descriptor 0x%02x", kind)
+	}
+	return moduleExternKind(kind), err
+}
+
+func (r *wasmReader) limits() error {
+	flags, err := r.byte()
+	if err != nil {
+		return err
+	}
+	if _, err := r.leb(); err != nil {
+		return err
+	}
+	if flags&0x01 != 0 {
+		_, err = r.leb()
+	}
+	return err
+}
+
+func componentSortKind(sort byte) string {
+	switch sort {
+	case 0x01:
+		return "func"
+	case 0x02:
+		return "value"
+	case 0x03:
+		return "type"
+	case 0x04:
+		return "component"
+	case 0x05:
+		return "instance"
+	default:
+		return "unknown"
+	}
+}
+
+func moduleExternKind(kind byte) string {
+	switch kind {
+	case 0x00:
+		return "func"
+	case 0x01:
+		return "table"
+	case 0x02:
+		return "memory"
+	case 0x03:
+		return "global"
+	case 0x04:
+		return "tag"
+	case 0x10:
+		return "type"
+	case 0x11:
+		return "module"
+	case 0x12:
+		return "instance"
+	default:
+		return "unknown"
+	}
+}
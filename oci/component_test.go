@@ -0,0 +1,144 @@
+package oci
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func uleb(n int) []byte {
+	var out []byte
+	for {
+		b := byte(n & 0x7f)
+		n >>= 7
+		if n == 0 {
+			return append(out, b)
+		}
+		out = append(out, b|0x80)
+	}
+}
+
+func wasmString(s string) []byte {
+	return append(uleb(len(s)), s...)
+}
+
+func wasmSection(id byte, parts ...[]byte) []byte {
+	var body []byte
+	for _, part := range parts {
+		body = append(body, part...)
+	}
+	return append(append([]byte{id}, uleb(len(body))...), body...)
+}
+
+func concat(parts ...[]byte) []byte {
+	var out []byte
+	for _, part := range parts {
+		out = append(out, part...)
+	}
+	return out
+}
+
+// testComponent encodes a component importing outbound HTTP, key-value and a
+// resource type, and exporting the incoming HTTP handler
+func testComponent() []byte {
+	return concat(
+		[]byte{0x00, 'a', 's', 'm', 0x0d, 0x00, 0x01, 0x00},
+		// A nested core module is skipped
+		wasmSection(1, []byte{0x00, 'a', 's', 'm', 0x01, 0x00, 0x00, 0x00}),
+		wasmSection(10, uleb(3),
+			[]byte{0x00}, wasmString("wasi:http/outgoing-handler@0.2.0"), []byte{0x05, 0x00},
+			[]byte{0x00}, wasmString("fermyon:spin/key-value@2.0.0"), []byte{0x05, 0x01},
+			[]byte{0x00}, wasmString("pollable"), []byte{0x03, 0x01},
+		),
+		wasmSection(11, uleb(1),
+			[]byte{0x00}, wasmString("wasi:http/incoming-handler@0.2.0"), []byte{0x05, 0x02, 0x00},
+		),
+		wasmSection(0, wasmString("producers"), uleb(2),
+			wasmString("language"), uleb(1), wasmString("Rust"), wasmString(""),
+			wasmString("processed-by"), uleb(1), wasmString("wit-component"), wasmString("0.215.0"),
+		),
+		wasmSection(0, wasmString("registry-metadata"), []byte("{}")),
+	)
+}
+
+func TestInspectWASM_Component(t *testing.T) {
+	info, err := InspectWASM(testComponent())
+	require.NoError(t, err)
+
+	assert.Equal(t, WASMKindComponent, info.Kind)
+	assert.Equal(t, []WASMItem{
+		{Name: "wasi:http/outgoing-handler@0.2.0", Kind: "instance"},
+		{Name: "fermyon:spin/key-value@2.0.0", Kind: "instance"},
+		{Name: "pollable", Kind: "type"},
+	}, info.Imports)
+	assert.Equal(t, []WASMItem{{Name: "wasi:http/incoming-handler@0.2.0", Kind: "instance"}}, info.Exports)
+	assert.Equal(t, []string{"key-value", "outbound-http"}, info.Capabilities)
+	assert.Equal(t, []CustomSection{{Name: "producers", Size: 53}, {Name: "registry-metadata", Size: 2}}, info.CustomSections)
+	assert.Equal(t, map[string][]string{
+		"language":     {"Rust"},
+		"processed-by": {"wit-component 0.215.0"},
+	}, info.Producers)
+}
+
+func TestInspectWASM_Module(t *testing.T) {
+	module := concat(
+		[]byte{0x00, 'a', 's', 'm', 0x01, 0x00, 0x00, 0x00},
+		wasmSection(2, uleb(2),
+			wasmString("wasi_snapshot_preview1"), wasmString("fd_write"), []byte{0x00, 0x00},
+			wasmString("env"), wasmString("memory"), []byte{0x02, 0x01, 0x01, 0x10},
+		),
+		wasmSection(7, uleb(1), wasmString("_start"), []byte{0x00, 0x01}),
+	)
+
+	info, err := InspectWASM(module)
+	require.NoError(t, err)
+
+	assert.Equal(t, WASMKindModule, info.Kind)
+	assert.Equal(t, []WASMItem{
+		{Name: "wasi_snapshot_preview1.fd_write", Kind: "func"},
+		{Name: "env.memory", Kind: "memory"},
+	}, info.Imports)
+	assert.Equal(t, []WASMItem{{Name: "_start", Kind: "func"}}, info.Exports)
+	assert.Equal(t, []string{"wasi-preview1"}, info.Capabilities)
+	assert.Empty(t, info.CustomSections)
+}
+
+func TestInspectWASM_Invalid(t *testing.T) {
+	_, err := InspectWASM([]byte("not wasm"))
+	assert.ErrorContains(t, err, "not a WASM binary")
+
+	// Section size larger than the remaining bytes
+	truncated := concat([]byte{0x00, 'a', 's', 'm', 0x0d, 0x00, 0x01, 0x00}, []byte{10, 0x20, 0x01})
+	_, err = InspectWASM(truncated)
+	assert.ErrorContains(t, err, "unexpected end of WASM binary")
+}
+
+func TestWASMSections(t *testing.T) {
+	moduleHeader := []byte{0x00, 'a', 's', 'm', 0x01, 0x00, 0x00, 0x00}
+	component := concat(
+		[]byte{0x00, 'a', 's', 'm', 0x0d, 0x00, 0x01, 0x00},
+		wasmSection(1, moduleHeader,
+			wasmSection(10, make([]byte, 40)),
+			wasmSection(11, make([]byte, 12)),
+			wasmSection(0, wasmString(".debug_info"), make([]byte, 100)),
+		),
+		wasmSection(1, moduleHeader, wasmSection(10, make([]byte, 4))),
+		wasmSection(8, make([]byte, 5)),
+		wasmSection(0, wasmString("producers"), make([]byte, 3)),
+	)
+
+	sections, err := WASMSections(component)
+	require.NoError(t, err)
+	assert.Equal(t, []WASMSection{
+		{Name: "module 0/code", Size: 40},
+		{Name: "module 0/data", Size: 12},
+		{Name: "module 0/custom .debug_info", Size: 112},
+		{Name: "module 1/code", Size: 4},
+		{Name: "canon", Size: 5},
+		{Name: "custom producers", Size: 13},
+	}, sections)
+
+	_, err = WASMSections(concat([]byte{0x00, 'a', 's', 'm', 0x0d, 0x00, 0x01, 0x00}, wasmSection(1, []byte("not wasm"))))
+	assert.ErrorContains(t, err, "section 1: not a WASM binary")
+}
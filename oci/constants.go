@@ -18,3 +18,17 @@ const (
 	// WASMOS is the OS field value for WASM components (wasip2)
 	WASMOS = "wasip2"
 )
+
+// Media types of the artifact holding the files mounted into a component
+const (
+	// FilesLayerMediaType is the media type of a layer holding one mounted
+	// directory as a gzipped tarball
+	FilesLayerMediaType = "application/vnd.ftl.files.layer.v1.tar+gzip"
+
+	// FilesConfigMediaType is the media type of the files artifact config
+	FilesConfigMediaType = "application/vnd.ftl.files.config.v1+json"
+
+	// FilesDestinationAnnotation is the layer annotation holding the
+	// absolute path the layer's directory is mounted at
+	FilesDestinationAnnotation = "sh.ftl.files.destination"
+)
@@ -0,0 +1,43 @@
+package oci
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+)
+
+// CopyByDigest copies the manifest at src (registry/repository@digest) to
+// dst (registry/repository:tag) without pulling the component locally.
+// Layers already present in the target registry are mounted rather than
+// uploaded again, and the raw manifest is preserved so dst resolves to the
+// same digest as src.
+func CopyByDigest(ctx context.Context, src string, srcAuth *ECRAuth, dst string, dstAuth *ECRAuth) error {
+	srcRef, err := name.NewDigest(src)
+	if err != nil {
+		return fmt.Errorf("invalid source reference %s: %w", src, err)
+	}
+	dstRef, err := name.NewTag(dst)
+	if err != nil {
+		return fmt.Errorf("invalid target reference %s: %w", dst, err)
+	}
+
+	img, err := remote.Image(srcRef, remote.WithAuth(srcAuth.Authenticator()), remote.WithContext(ctx))
+	if err != nil {
+		return fmt.Errorf("failed to fetch %s: %w", src, err)
+	}
+
+	if err := remote.Write(dstRef, img, remote.WithAuth(dstAuth.Authenticator()), remote.WithContext(ctx)); err != nil {
+		return fmt.Errorf("failed to copy to %s: %w", dst, err)
+	}
+
+	digest, err := img.Digest()
+	if err != nil {
+		return fmt.Errorf("failed to compute manifest digest: %w", err)
+	}
+	if digest.String() != srcRef.DigestStr() {
+		return fmt.Errorf("copied manifest digest %s does not match %s", digest, srcRef.DigestStr())
+	}
+	return nil
+}
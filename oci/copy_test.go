@@ -0,0 +1,52 @@
+package oci
+
+import (
+	"context"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/google/go-containerregistry/pkg/registry"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestCopyByDigest(t *testing.T) {
+	server := httptest.NewServer(registry.New())
+	defer server.Close()
+
+	host := strings.TrimPrefix(server.URL, "http://")
+	auth := &ECRAuth{Registry: host, Username: "AWS", Password: "secret"}
+
+	wasmPath := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(wasmPath, []byte("\x00asm\x01\x00\x00\x00"), 0600))
+
+	digest, err := NewWASMPusher(auth).PushWithDigest(context.Background(), wasmPath, "staging/tool", "1.0.0")
+	require.NoError(t, err)
+	assert.True(t, strings.HasPrefix(digest, "sha256:"))
+
+	src := host + "/staging/tool@" + digest
+	dst := host + "/prod/tool:1.0.0"
+	require.NoError(t, CopyByDigest(context.Background(), src, auth, dst, auth))
+
+	ref, err := name.NewTag(dst)
+	require.NoError(t, err)
+	desc, err := remote.Head(ref)
+	require.NoError(t, err)
+	assert.Equal(t, digest, desc.Digest.String())
+}
+
+func TestCopyByDigest_InvalidReferences(t *testing.T) {
+	auth := &ECRAuth{Registry: "registry.example.com"}
+
+	err := CopyByDigest(context.Background(), "registry.example.com/staging/tool:1.0.0", auth, "registry.example.com/prod/tool:1.0.0", auth)
+	assert.ErrorContains(t, err, "invalid source reference")
+
+	src := "registry.example.com/staging/tool@sha256:" + strings.Repeat("a", 64)
+	err = CopyByDigest(context.Background(), src, auth, "registry.example.com/prod/tool@sha256:"+strings.Repeat("a", 64), auth)
+	assert.ErrorContains(t, err, "invalid target reference")
+}
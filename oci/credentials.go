@@ -0,0 +1,120 @@
+package oci
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"os/exec"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/google/go-containerregistry/pkg/authn"
+)
+
+// credentialHelperTimeout bounds a credential helper invocation
+const credentialHelperTimeout = 30 * time.Second
+
+// identityTokenUsername is the username with which credential helpers return
+// an identity token instead of a password
+const identityTokenUsername = "<token>"
+
+var (
+	keychainMu sync.RWMutex
+	keychain   authn.Keychain = authn.DefaultKeychain
+)
+
+// Keychain returns the keychain registry operations authenticate with: the
+// local Docker credentials (docker login), unless credential helpers are
+// configured with SetCredentialHelpers
+func Keychain() authn.Keychain {
+	keychainMu.RLock()
+	defer keychainMu.RUnlock()
+	return keychain
+}
+
+// SetCredentialHelpers makes registry operations authenticate with the
+// credential helper configured for a registry host. Other registries keep
+// using the local Docker credentials.
+func SetCredentialHelpers(helpers map[string][]string) {
+	keychainMu.Lock()
+	defer keychainMu.Unlock()
+	if len(helpers) == 0 {
+		keychain = authn.DefaultKeychain
+		return
+	}
+	keychain = NewHelperKeychain(helpers, authn.DefaultKeychain)
+}
+
+// HelperKeychain resolves the credentials of registries by running their
+// credential helper, and those of other registries with a fallback keychain.
+//
+// Helpers implement the "get" command of the Docker credential helper
+// protocol: they are run with "get" appended to their command line and the
+// registry host on stdin, and print {"Username": ..., "Secret": ...}. They are
+// run for every authentication, so they can hand out short-lived tokens.
+type HelperKeychain struct {
+	helpers  map[string][]string
+	fallback authn.Keychain
+}
+
+// NewHelperKeychain creates a keychain running the helper command line
+// configured for each registry host
+func NewHelperKeychain(helpers map[string][]string, fallback authn.Keychain) *HelperKeychain {
+	return &HelperKeychain{helpers: helpers, fallback: fallback}
+}
+
+// Resolve implements authn.Keychain
+func (k *HelperKeychain) Resolve(target authn.Resource) (authn.Authenticator, error) {
+	if helper, ok := k.helpers[target.RegistryStr()]; ok && len(helper) > 0 {
+		return &helperAuthenticator{helper: helper, registry: target.RegistryStr()}, nil
+	}
+	return k.fallback.Resolve(target)
+}
+
+// helperAuthenticator runs a credential helper when credentials are needed
+type helperAuthenticator struct {
+	helper   []string
+	registry string
+}
+
+// Authorization implements authn.Authenticator
+func (a *helperAuthenticator) Authorization() (*authn.AuthConfig, error) {
+	return RunCredentialHelper(a.helper, a.registry)
+}
+
+// RunCredentialHelper gets the credentials of a registry from a credential
+// helper
+func RunCredentialHelper(helper []string, registry string) (*authn.AuthConfig, error) {
+	if len(helper) == 0 {
+		return nil, fmt.Errorf("empty credential helper for %s", registry)
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), credentialHelperTimeout)
+	defer cancel()
+
+	var stdout, stderr bytes.Buffer
+	cmd := exec.CommandContext(ctx, helper[0], append(helper[1:], "get")...) // #nosec G204 -- the helper is configured by the user
+	cmd.Stdin = strings.NewReader(registry)
+	cmd.Stdout = &stdout
+	cmd.Stderr = &stderr
+	if err := cmd.Run(); err != nil {
+		if msg := strings.TrimSpace(stderr.String()); msg != "" {
+			return nil, fmt.Errorf("credential helper %s failed for %s: %w: %s", helper[0], registry, err, msg)
+		}
+		return nil, fmt.Errorf("credential helper %s failed for %s: %w", helper[0], registry, err)
+	}
+
+	var creds struct {
+		Username string `json:"Username"`
+		Secret   string `json:"Secret"`
+	}
+	if err := json.Unmarshal(stdout.Bytes(), &creds); err != nil {
+		return nil, fmt.Errorf("credential helper %s returned invalid credentials for %s: %w", helper[0], registry, err)
+	}
+	if creds.Username == identityTokenUsername {
+		return &authn.AuthConfig{IdentityToken: creds.Secret}, nil
+	}
+	return &authn.AuthConfig{Username: creds.Username, Password: creds.Secret}, nil
+}
@@ -0,0 +1,107 @@
+package oci
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+
+	"github.com/google/go-containerregistry/pkg/authn"
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// writeCredentialHelper writes a credential helper script that records its
+// arguments and stdin next to itself and runs body
+func writeCredentialHelper(t *testing.T, body string) (string, string) {
+	t.Helper()
+	if runtime.GOOS == "windows" {
+		t.Skip("credential helper scripts need a POSIX shell")
+	}
+	dir := t.TempDir()
+	path := filepath.Join(dir, "docker-credential-test")
+	script := "#!/bin/sh\necho \"$@\" > \"$(dirname \"$0\")/args\"\ncat > \"$(dirname \"$0\")/stdin\"\n" + body + "\n"
+	require.NoError(t, os.WriteFile(path, []byte(script), 0700)) // #nosec G306 -- test helper must be executable
+	return path, dir
+}
+
+func TestRunCredentialHelper(t *testing.T) {
+	helper, dir := writeCredentialHelper(t, `echo '{"ServerURL":"registry.example.com","Username":"AWS","Secret":"s3cret"}'`)
+
+	auth, err := RunCredentialHelper([]string{helper, "--profile", "ci"}, "registry.example.com")
+	require.NoError(t, err)
+	assert.Equal(t, &authn.AuthConfig{Username: "AWS", Password: "s3cret"}, auth)
+
+	args, err := os.ReadFile(filepath.Join(dir, "args"))
+	require.NoError(t, err)
+	assert.Equal(t, "--profile ci get\n", string(args))
+	stdin, err := os.ReadFile(filepath.Join(dir, "stdin"))
+	require.NoError(t, err)
+	assert.Equal(t, "registry.example.com", string(stdin))
+}
+
+func TestRunCredentialHelper_IdentityToken(t *testing.T) {
+	helper, _ := writeCredentialHelper(t, `echo '{"Username":"<token>","Secret":"refresh-token"}'`)
+
+	auth, err := RunCredentialHelper([]string{helper}, "registry.example.com")
+	require.NoError(t, err)
+	assert.Equal(t, &authn.AuthConfig{IdentityToken: "refresh-token"}, auth)
+}
+
+func TestRunCredentialHelper_Errors(t *testing.T) {
+	failing, _ := writeCredentialHelper(t, "echo 'credentials not found' >&2\nexit 1")
+	_, err := RunCredentialHelper([]string{failing}, "registry.example.com")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "failed for registry.example.com")
+	assert.Contains(t, err.Error(), "credentials not found")
+
+	invalid, _ := writeCredentialHelper(t, "echo not-json")
+	_, err = RunCredentialHelper([]string{invalid}, "registry.example.com")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "returned invalid credentials")
+
+	_, err = RunCredentialHelper(nil, "registry.example.com")
+	require.Error(t, err)
+}
+
+// staticKeychain resolves every registry to the same credentials
+type staticKeychain struct{ auth authn.AuthConfig }
+
+func (k staticKeychain) Resolve(authn.Resource) (authn.Authenticator, error) {
+	return authn.FromConfig(k.auth), nil
+}
+
+func TestHelperKeychain(t *testing.T) {
+	helper, _ := writeCredentialHelper(t, `echo '{"Username":"helper","Secret":"from-helper"}'`)
+	keychain := NewHelperKeychain(
+		map[string][]string{"registry.example.com": {helper}},
+		staticKeychain{auth: authn.AuthConfig{Username: "docker", Password: "from-docker"}},
+	)
+
+	for _, tt := range []struct {
+		ref      string
+		expected authn.AuthConfig
+	}{
+		{"registry.example.com/acme/calc:1.0.0", authn.AuthConfig{Username: "helper", Password: "from-helper"}},
+		{"ghcr.io/acme/calc:1.0.0", authn.AuthConfig{Username: "docker", Password: "from-docker"}},
+	} {
+		ref, err := name.ParseReference(tt.ref)
+		require.NoError(t, err)
+		authenticator, err := keychain.Resolve(ref.Context())
+		require.NoError(t, err)
+		auth, err := authenticator.Authorization()
+		require.NoError(t, err)
+		assert.Equal(t, tt.expected, *auth, tt.ref)
+	}
+}
+
+func TestSetCredentialHelpers(t *testing.T) {
+	t.Cleanup(func() { SetCredentialHelpers(nil) })
+
+	SetCredentialHelpers(map[string][]string{"registry.example.com": {"docker-credential-test"}})
+	assert.IsType(t, &HelperKeychain{}, Keychain())
+
+	SetCredentialHelpers(nil)
+	assert.Equal(t, authn.DefaultKeychain, Keychain())
+}
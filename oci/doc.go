@@ -6,7 +6,8 @@
 //   - WASM OCI image creation with proper layerDigests field for Spin compatibility
 //   - Registry push/pull operations for WASM components
 //   - ECR (Elastic Container Registry) authentication support
-//   - Caching for pulled WASM artifacts
+//   - Caching for pulled WASM artifacts, and a content-addressed store shared
+//     by all projects
 //
 // The implementation follows the WASM OCI artifact specification used by tools like
 // wkg (WebAssembly Package Manager) and Spin Framework, ensuring compatibility with
@@ -4,6 +4,8 @@ import (
 	"encoding/base64"
 	"fmt"
 	"strings"
+
+	"github.com/google/go-containerregistry/pkg/authn"
 )
 
 // ECRAuth holds parsed ECR authentication details
@@ -32,3 +34,11 @@ func ParseECRToken(registryURI, authToken string) (*ECRAuth, error) {
 		Password: parts[1],
 	}, nil
 }
+
+// Authenticator returns registry credentials for use with remote operations
+func (a *ECRAuth) Authenticator() authn.Authenticator {
+	return authn.FromConfig(authn.AuthConfig{
+		Username: a.Username,
+		Password: a.Password,
+	})
+}
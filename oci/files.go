@@ -0,0 +1,142 @@
+package oci
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"time"
+
+	v1 "github.com/google/go-containerregistry/pkg/v1"
+	"github.com/google/go-containerregistry/pkg/v1/empty"
+	"github.com/google/go-containerregistry/pkg/v1/mutate"
+	"github.com/google/go-containerregistry/pkg/v1/static"
+	"github.com/google/go-containerregistry/pkg/v1/types"
+)
+
+// FileMount is a local directory mounted into a component's filesystem
+type FileMount struct {
+	// Source is the local directory whose contents are mounted
+	Source string
+	// Destination is the absolute path the directory is mounted at
+	Destination string
+}
+
+// PushFiles uploads the directories mounted into a component as an OCI
+// artifact with one gzipped tar layer per mount, in order, each annotated
+// with its destination. Archives are reproducible, so pushing unchanged
+// files yields the same digest. It returns the digest of the pushed manifest.
+func (p *WASMPusher) PushFiles(ctx context.Context, mounts []FileMount, packageName, version string) (string, error) {
+	img, err := createFilesImage(mounts, version)
+	if err != nil {
+		return "", fmt.Errorf("failed to create files image: %w", err)
+	}
+	return p.write(ctx, img, packageName, version)
+}
+
+// createFilesImage archives each mount into a layer of a files artifact
+func createFilesImage(mounts []FileMount, version string) (v1.Image, error) {
+	if len(mounts) == 0 {
+		return nil, fmt.Errorf("no file mounts")
+	}
+
+	adds := make([]mutate.Addendum, 0, len(mounts))
+	for _, m := range mounts {
+		data, err := archiveDirectory(m.Source)
+		if err != nil {
+			return nil, fmt.Errorf("failed to archive %s: %w", m.Source, err)
+		}
+		adds = append(adds, mutate.Addendum{
+			Layer: static.NewLayer(data, FilesLayerMediaType),
+			Annotations: map[string]string{
+				FilesDestinationAnnotation: m.Destination,
+			},
+		})
+	}
+
+	img, err := mutate.Append(empty.Image, adds...)
+	if err != nil {
+		return nil, err
+	}
+	img = mutate.MediaType(img, types.OCIManifestSchema1)
+	img = mutate.ConfigMediaType(img, FilesConfigMediaType)
+	// No creation time, which would change the digest on every push
+	annotated, ok := mutate.Annotations(img, map[string]string{
+		"org.opencontainers.image.version": version,
+	}).(v1.Image)
+	if !ok {
+		return nil, fmt.Errorf("failed to annotate files image")
+	}
+	return annotated, nil
+}
+
+// archiveDirectory returns a gzipped tarball of the contents of dir. Entries
+// are sorted, and ownership and timestamps are cleared, so the archive only
+// changes when the files do. Only regular files and directories are allowed.
+func archiveDirectory(dir string) ([]byte, error) {
+	var buf bytes.Buffer
+	gz := gzip.NewWriter(&buf)
+	tw := tar.NewWriter(gz)
+
+	err := filepath.WalkDir(dir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		rel, err := filepath.Rel(dir, path)
+		if err != nil {
+			return err
+		}
+		if rel == "." {
+			return nil
+		}
+
+		info, err := d.Info()
+		if err != nil {
+			return err
+		}
+		if !info.Mode().IsRegular() && !info.IsDir() {
+			return fmt.Errorf("%s: only regular files and directories can be mounted", rel)
+		}
+
+		header := &tar.Header{
+			Name:    filepath.ToSlash(rel),
+			Mode:    0644,
+			ModTime: time.Unix(0, 0),
+		}
+		if info.IsDir() {
+			header.Typeflag = tar.TypeDir
+			header.Name += "/"
+			header.Mode = 0755
+			return tw.WriteHeader(header)
+		}
+
+		header.Typeflag = tar.TypeReg
+		header.Size = info.Size()
+		if err := tw.WriteHeader(header); err != nil {
+			return err
+		}
+		file, err := os.Open(filepath.Clean(path))
+		if err != nil {
+			return err
+		}
+		defer func() { _ = file.Close() }()
+		_, err = io.Copy(tw, file)
+		return err
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	if err := tw.Close(); err != nil {
+		return nil, err
+	}
+	if err := gz.Close(); err != nil {
+		return nil, err
+	}
+	return buf.Bytes(), nil
+}
@@ -0,0 +1,121 @@
+package oci
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"context"
+	"io"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/google/go-containerregistry/pkg/registry"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+	"github.com/google/go-containerregistry/pkg/v1/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func writeFiles(t *testing.T, files map[string]string) string {
+	t.Helper()
+	dir := t.TempDir()
+	for path, content := range files {
+		full := filepath.Join(dir, path)
+		require.NoError(t, os.MkdirAll(filepath.Dir(full), 0750))
+		require.NoError(t, os.WriteFile(full, []byte(content), 0600))
+	}
+	return dir
+}
+
+func TestArchiveDirectory(t *testing.T) {
+	dir := writeFiles(t, map[string]string{
+		"words.txt":        "apple\nbanana\n",
+		"templates/a.html": "<p>{{ name }}</p>",
+	})
+
+	data, err := archiveDirectory(dir)
+	require.NoError(t, err)
+
+	gz, err := gzip.NewReader(bytes.NewReader(data))
+	require.NoError(t, err)
+	tr := tar.NewReader(gz)
+	var names []string
+	contents := map[string]string{}
+	for {
+		header, err := tr.Next()
+		if err == io.EOF {
+			break
+		}
+		require.NoError(t, err)
+		assert.Equal(t, int64(0), header.ModTime.Unix())
+		names = append(names, header.Name)
+		body, err := io.ReadAll(tr)
+		require.NoError(t, err)
+		contents[header.Name] = string(body)
+	}
+	assert.Equal(t, []string{"templates/", "templates/a.html", "words.txt"}, names)
+	assert.Equal(t, "apple\nbanana\n", contents["words.txt"])
+
+	// The archive only depends on the files
+	later := time.Now().Add(time.Hour)
+	require.NoError(t, os.Chtimes(filepath.Join(dir, "words.txt"), later, later))
+	again, err := archiveDirectory(dir)
+	require.NoError(t, err)
+	assert.Equal(t, data, again)
+}
+
+func TestArchiveDirectory_RejectsSymlinks(t *testing.T) {
+	dir := writeFiles(t, map[string]string{"words.txt": "apple"})
+	if err := os.Symlink("/etc/passwd", filepath.Join(dir, "passwd")); err != nil {
+		t.Skipf("symlinks not supported: %v", err)
+	}
+
+	_, err := archiveDirectory(dir)
+	assert.ErrorContains(t, err, "only regular files and directories")
+}
+
+func TestPushFiles(t *testing.T) {
+	server := httptest.NewServer(registry.New())
+	defer server.Close()
+
+	host := strings.TrimPrefix(server.URL, "http://")
+	auth := &ECRAuth{Registry: host, Username: "AWS", Password: "secret"}
+	mounts := []FileMount{
+		{Source: writeFiles(t, map[string]string{"en.txt": "hello"}), Destination: "/dictionaries"},
+		{Source: writeFiles(t, map[string]string{"page.html": "<p></p>"}), Destination: "/templates"},
+	}
+
+	pusher := NewWASMPusher(auth)
+	digest, err := pusher.PushFiles(context.Background(), mounts, "staging/tool", "1.0.0-files")
+	require.NoError(t, err)
+	assert.True(t, strings.HasPrefix(digest, "sha256:"))
+
+	ref, err := name.NewTag(host + "/staging/tool:1.0.0-files")
+	require.NoError(t, err)
+	img, err := remote.Image(ref)
+	require.NoError(t, err)
+	manifest, err := img.Manifest()
+	require.NoError(t, err)
+	assert.Equal(t, types.OCIManifestSchema1, manifest.MediaType)
+	assert.Equal(t, types.MediaType(FilesConfigMediaType), manifest.Config.MediaType)
+	require.Len(t, manifest.Layers, 2)
+	for i, layer := range manifest.Layers {
+		assert.Equal(t, types.MediaType(FilesLayerMediaType), layer.MediaType)
+		assert.Equal(t, mounts[i].Destination, layer.Annotations[FilesDestinationAnnotation])
+	}
+
+	// Pushing the same files again yields the same artifact
+	again, err := pusher.PushFiles(context.Background(), mounts, "staging/tool", "1.0.0-files")
+	require.NoError(t, err)
+	assert.Equal(t, digest, again)
+}
+
+func TestPushFiles_NoMounts(t *testing.T) {
+	_, err := NewWASMPusher(&ECRAuth{Registry: "registry.example.com"}).PushFiles(context.Background(), nil, "staging/tool", "1.0.0-files")
+	assert.ErrorContains(t, err, "no file mounts")
+}
@@ -0,0 +1,327 @@
+package oci
+
+import (
+	"bufio"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+)
+
+// ErrNotMirrored is returned when a component has not been mirrored
+var ErrNotMirrored = errors.New("component is not in the local mirror")
+
+// mirrorIndexFile maps component references to their mirrored blobs
+const mirrorIndexFile = "index.json"
+
+// MirrorRef identifies a registry component to mirror
+type MirrorRef struct {
+	Registry string
+	Package  string // Spin package name (namespace:package)
+	Version  string
+	Digest   string // Expected manifest digest, if pinned
+}
+
+// String returns the reference in registry/namespace:package@version form
+func (r MirrorRef) String() string {
+	return mirrorKey(r.Registry, r.Package, r.Version)
+}
+
+func mirrorKey(registry, packageName, version string) string {
+	return fmt.Sprintf("%s/%s@%s", registry, packageName, version)
+}
+
+// ParseMirrorRef parses a registry/namespace:package@version reference
+func ParseMirrorRef(ref string) (MirrorRef, error) {
+	at := strings.LastIndex(ref, "@")
+	if at <= 0 || at == len(ref)-1 {
+		return MirrorRef{}, fmt.Errorf("invalid reference %q: expected registry/namespace:package@version", ref)
+	}
+	location, version := ref[:at], ref[at+1:]
+
+	slash := strings.LastIndex(location, "/")
+	if slash <= 0 || !strings.Contains(location[slash+1:], ":") {
+		return MirrorRef{}, fmt.Errorf("invalid reference %q: expected registry/namespace:package@version", ref)
+	}
+
+	return MirrorRef{
+		Registry: location[:slash],
+		Package:  location[slash+1:],
+		Version:  version,
+	}, nil
+}
+
+// ParseMirrorRefs reads a refs file: one reference per line, optionally
+// followed by the expected manifest digest. Blank lines and lines starting
+// with # are ignored.
+func ParseMirrorRefs(r io.Reader) ([]MirrorRef, error) {
+	var refs []MirrorRef
+	scanner := bufio.NewScanner(r)
+	line := 0
+	for scanner.Scan() {
+		line++
+		text := strings.TrimSpace(scanner.Text())
+		if text == "" || strings.HasPrefix(text, "#") {
+			continue
+		}
+
+		fields := strings.Fields(text)
+		if len(fields) > 2 {
+			return nil, fmt.Errorf("line %d: expected a reference and an optional digest", line)
+		}
+		ref, err := ParseMirrorRef(fields[0])
+		if err != nil {
+			return nil, fmt.Errorf("line %d: %w", line, err)
+		}
+		if len(fields) == 2 {
+			if !strings.HasPrefix(fields[1], "sha256:") {
+				return nil, fmt.Errorf("line %d: invalid digest %q", line, fields[1])
+			}
+			ref.Digest = fields[1]
+		}
+		refs = append(refs, ref)
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+	return refs, nil
+}
+
+// MirrorEntry records a mirrored component
+type MirrorEntry struct {
+	Ref        string    `json:"ref"`
+	Digest     string    `json:"digest"` // Manifest digest
+	Layer      string    `json:"layer"`  // Digest of the WASM layer, which names the blob
+	Size       int64     `json:"size"`
+	MirroredAt time.Time `json:"mirrored_at"`
+}
+
+// Mirror is a local copy of registry components for builds without network
+// access. Blobs are stored by digest and verified every time they are resolved.
+type Mirror struct {
+	dir string
+	mu  sync.Mutex
+}
+
+// DefaultMirrorDir returns the mirror directory, which can be overridden with FTL_MIRROR_DIR
+func DefaultMirrorDir() string {
+	if dir := os.Getenv("FTL_MIRROR_DIR"); dir != "" {
+		return dir
+	}
+	return filepath.Join(os.Getenv("HOME"), ".cache", "ftl", "mirror")
+}
+
+// NewMirror returns the mirror stored in dir
+func NewMirror(dir string) *Mirror {
+	return &Mirror{dir: dir}
+}
+
+// Dir returns the directory the mirror is stored in
+func (m *Mirror) Dir() string {
+	return m.dir
+}
+
+// Fetch pulls a component from its registry into the mirror. When the
+// reference is pinned, the pulled manifest must match its digest.
+func (m *Mirror) Fetch(ctx context.Context, ref MirrorRef) (*MirrorEntry, error) {
+	ociRef := fmt.Sprintf("%s/%s:%s", ref.Registry, strings.Replace(ref.Package, ":", "/", 1), ref.Version)
+	tag, err := name.ParseReference(ociRef)
+	if err != nil {
+		return nil, fmt.Errorf("invalid reference %s: %w", ociRef, err)
+	}
+
+	img, err := remote.Image(tag, remote.WithAuthFromKeychain(Keychain()), remote.WithContext(ctx))
+	if err != nil {
+		return nil, fmt.Errorf("failed to pull %s: %w", ref, err)
+	}
+	digest, err := img.Digest()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get manifest digest: %w", err)
+	}
+	if ref.Digest != "" && digest.String() != ref.Digest {
+		return nil, fmt.Errorf("%s resolved to %s, expected %s", ref, digest, ref.Digest)
+	}
+
+	layers, err := img.Layers()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get layers: %w", err)
+	}
+	if len(layers) == 0 {
+		return nil, fmt.Errorf("no layers found in %s", ref)
+	}
+	layerDigest, err := layers[0].Digest()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get layer digest: %w", err)
+	}
+	content, err := layers[0].Compressed()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get layer content: %w", err)
+	}
+	defer func() { _ = content.Close() }()
+
+	size, err := m.writeBlob(layerDigest.Hex, content)
+	if err != nil {
+		return nil, err
+	}
+
+	entry := &MirrorEntry{
+		Ref:        ref.String(),
+		Digest:     digest.String(),
+		Layer:      layerDigest.String(),
+		Size:       size,
+		MirroredAt: time.Now().UTC(),
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	index, err := m.loadIndex()
+	if err != nil {
+		return nil, err
+	}
+	index[entry.Ref] = *entry
+	if err := m.saveIndex(index); err != nil {
+		return nil, err
+	}
+	return entry, nil
+}
+
+// Resolve returns the path of a mirrored component after verifying its
+// content against the recorded digest. Components that were never mirrored
+// return an error wrapping ErrNotMirrored.
+func (m *Mirror) Resolve(registry, packageName, version string) (string, error) {
+	key := mirrorKey(registry, packageName, version)
+
+	m.mu.Lock()
+	index, err := m.loadIndex()
+	m.mu.Unlock()
+	if err != nil {
+		return "", err
+	}
+
+	entry, ok := index[key]
+	if !ok {
+		return "", fmt.Errorf("%s: %w", key, ErrNotMirrored)
+	}
+
+	hexDigest := strings.TrimPrefix(entry.Layer, "sha256:")
+	path := m.blobPath(hexDigest)
+	actual, err := fileSHA256(path)
+	if err != nil {
+		return "", fmt.Errorf("%s: mirrored blob is missing, mirror it again: %w", key, err)
+	}
+	if actual != hexDigest {
+		return "", fmt.Errorf("%s: mirrored blob is corrupt (sha256:%s, expected %s), mirror it again", key, actual, entry.Layer)
+	}
+	return path, nil
+}
+
+// Entries lists the mirrored components, sorted by reference
+func (m *Mirror) Entries() ([]MirrorEntry, error) {
+	m.mu.Lock()
+	index, err := m.loadIndex()
+	m.mu.Unlock()
+	if err != nil {
+		return nil, err
+	}
+
+	entries := make([]MirrorEntry, 0, len(index))
+	for _, entry := range index {
+		entries = append(entries, entry)
+	}
+	sort.Slice(entries, func(i, j int) bool { return entries[i].Ref < entries[j].Ref })
+	return entries, nil
+}
+
+func (m *Mirror) blobPath(hexDigest string) string {
+	return filepath.Join(m.dir, "blobs", "sha256", hexDigest+".wasm")
+}
+
+// writeBlob stores content under its digest, verifying it while writing
+func (m *Mirror) writeBlob(hexDigest string, content io.Reader) (int64, error) {
+	return writeVerifiedBlob(m.blobPath(hexDigest), hexDigest, content)
+}
+
+// writeVerifiedBlob writes content to path through a temporary file, which
+// is only moved into place when the content matches hexDigest
+func writeVerifiedBlob(path, hexDigest string, content io.Reader) (int64, error) {
+	if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+		return 0, fmt.Errorf("failed to create blob directory: %w", err)
+	}
+
+	tmp, err := os.CreateTemp(filepath.Dir(path), hexDigest+".*.tmp")
+	if err != nil {
+		return 0, fmt.Errorf("failed to create blob: %w", err)
+	}
+	defer func() { _ = os.Remove(tmp.Name()) }()
+
+	hash := sha256.New()
+	size, err := io.Copy(io.MultiWriter(tmp, hash), content)
+	if closeErr := tmp.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		return 0, fmt.Errorf("failed to write blob: %w", err)
+	}
+	if actual := hex.EncodeToString(hash.Sum(nil)); actual != hexDigest {
+		return 0, fmt.Errorf("downloaded content has digest sha256:%s, expected sha256:%s", actual, hexDigest)
+	}
+
+	if err := os.Rename(tmp.Name(), path); err != nil {
+		return 0, fmt.Errorf("failed to store blob: %w", err)
+	}
+	return size, nil
+}
+
+func (m *Mirror) loadIndex() (map[string]MirrorEntry, error) {
+	index := make(map[string]MirrorEntry)
+	data, err := os.ReadFile(filepath.Join(m.dir, mirrorIndexFile))
+	if os.IsNotExist(err) {
+		return index, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read mirror index: %w", err)
+	}
+	if err := json.Unmarshal(data, &index); err != nil {
+		return nil, fmt.Errorf("failed to parse mirror index: %w", err)
+	}
+	return index, nil
+}
+
+func (m *Mirror) saveIndex(index map[string]MirrorEntry) error {
+	data, err := json.MarshalIndent(index, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode mirror index: %w", err)
+	}
+	path := filepath.Join(m.dir, mirrorIndexFile)
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0600); err != nil {
+		return fmt.Errorf("failed to write mirror index: %w", err)
+	}
+	return os.Rename(tmp, path)
+}
+
+func fileSHA256(path string) (string, error) {
+	f, err := os.Open(filepath.Clean(path))
+	if err != nil {
+		return "", err
+	}
+	defer func() { _ = f.Close() }()
+
+	hash := sha256.New()
+	if _, err := io.Copy(hash, f); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(hash.Sum(nil)), nil
+}
@@ -0,0 +1,109 @@
+package oci
+
+import (
+	"context"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/google/go-containerregistry/pkg/registry"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+var testWASM = []byte("\x00asm\x01\x00\x00\x00")
+
+// seedRegistry pushes a WASM component to an in-memory registry and returns
+// the registry host and manifest digest
+func seedRegistry(t *testing.T) (string, string) {
+	t.Helper()
+	server := httptest.NewServer(registry.New())
+	t.Cleanup(server.Close)
+
+	host := strings.TrimPrefix(server.URL, "http://")
+	wasmPath := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(wasmPath, testWASM, 0600))
+
+	digest, err := NewWASMPusher(&ECRAuth{Registry: host}).PushWithDigest(context.Background(), wasmPath, "acme/tool", "1.0.0")
+	require.NoError(t, err)
+	return host, digest
+}
+
+func TestMirror_FetchAndResolve(t *testing.T) {
+	host, digest := seedRegistry(t)
+	mirror := NewMirror(t.TempDir())
+
+	entry, err := mirror.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0", Digest: digest})
+	require.NoError(t, err)
+	assert.Equal(t, host+"/acme:tool@1.0.0", entry.Ref)
+	assert.Equal(t, digest, entry.Digest)
+	assert.Equal(t, int64(len(testWASM)), entry.Size)
+
+	path, err := mirror.Resolve(host, "acme:tool", "1.0.0")
+	require.NoError(t, err)
+	content, err := os.ReadFile(path)
+	require.NoError(t, err)
+	assert.Equal(t, testWASM, content)
+
+	entries, err := mirror.Entries()
+	require.NoError(t, err)
+	require.Len(t, entries, 1)
+	assert.Equal(t, entry.Layer, entries[0].Layer)
+
+	// A second mirror over the same directory sees the component
+	_, err = NewMirror(mirror.Dir()).Resolve(host, "acme:tool", "1.0.0")
+	assert.NoError(t, err)
+}
+
+func TestMirror_FetchDigestMismatch(t *testing.T) {
+	host, _ := seedRegistry(t)
+	mirror := NewMirror(t.TempDir())
+
+	pinned := "sha256:" + strings.Repeat("a", 64)
+	_, err := mirror.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0", Digest: pinned})
+	assert.ErrorContains(t, err, "expected "+pinned)
+
+	_, err = mirror.Resolve(host, "acme:tool", "1.0.0")
+	assert.ErrorIs(t, err, ErrNotMirrored)
+}
+
+func TestMirror_ResolveVerifiesContent(t *testing.T) {
+	host, _ := seedRegistry(t)
+	mirror := NewMirror(t.TempDir())
+
+	entry, err := mirror.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0"})
+	require.NoError(t, err)
+
+	blob := mirror.blobPath(strings.TrimPrefix(entry.Layer, "sha256:"))
+	require.NoError(t, os.WriteFile(blob, []byte("tampered"), 0600))
+	_, err = mirror.Resolve(host, "acme:tool", "1.0.0")
+	assert.ErrorContains(t, err, "corrupt")
+
+	require.NoError(t, os.Remove(blob))
+	_, err = mirror.Resolve(host, "acme:tool", "1.0.0")
+	assert.ErrorContains(t, err, "missing")
+}
+
+func TestParseMirrorRefs(t *testing.T) {
+	digest := "sha256:" + strings.Repeat("b", 64)
+	refs, err := ParseMirrorRefs(strings.NewReader(`
+# Platform components
+ghcr.io/fastertools:mcp-gateway@0.0.13
+ghcr.io/fastertools:mcp-authorizer@0.0.15 ` + digest + `
+`))
+	require.NoError(t, err)
+	assert.Equal(t, []MirrorRef{
+		{Registry: "ghcr.io", Package: "fastertools:mcp-gateway", Version: "0.0.13"},
+		{Registry: "ghcr.io", Package: "fastertools:mcp-authorizer", Version: "0.0.15", Digest: digest},
+	}, refs)
+
+	_, err = ParseMirrorRefs(strings.NewReader("ghcr.io/fastertools:mcp-gateway@0.0.13 latest"))
+	assert.ErrorContains(t, err, "line 1: invalid digest")
+
+	for _, ref := range []string{"ghcr.io/fastertools:mcp-gateway", "fastertools:mcp-gateway@0.0.13", "ghcr.io/mcp-gateway@0.0.13"} {
+		_, err := ParseMirrorRef(ref)
+		assert.Error(t, err, ref)
+	}
+}
@@ -5,8 +5,10 @@ import (
 	"crypto/sha256"
 	"encoding/hex"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"io"
+	"net/http"
 	"os"
 	"path/filepath"
 	"strings"
@@ -17,12 +19,15 @@ import (
 	"github.com/google/go-containerregistry/pkg/name"
 	v1 "github.com/google/go-containerregistry/pkg/v1"
 	"github.com/google/go-containerregistry/pkg/v1/remote"
+	"github.com/google/go-containerregistry/pkg/v1/remote/transport"
 	"github.com/google/go-containerregistry/pkg/v1/static"
 )
 
 // WASMPuller handles pulling WASM components from OCI registries
 type WASMPuller struct {
 	cacheDir string
+	mirror   *Mirror
+	store    *Store
 	mu       sync.Mutex
 }
 
@@ -48,9 +53,30 @@ func NewWASMPullerWithCache(cacheDir string) *WASMPuller {
 	}
 }
 
+// WithMirror makes the puller resolve components only from a local mirror,
+// for builds without network access
+func (p *WASMPuller) WithMirror(mirror *Mirror) *WASMPuller {
+	p.mirror = mirror
+	return p
+}
+
+// WithStore makes the puller keep components in a content-addressed store
+// shared by all projects, so each component is downloaded once
+func (p *WASMPuller) WithStore(store *Store) *WASMPuller {
+	p.store = store
+	return p
+}
+
 // Pull downloads a WASM component from a registry
 // Parameters are now explicit instead of using a types package
 func (p *WASMPuller) Pull(ctx context.Context, registry, packageName, version string) (string, error) {
+	if p.mirror != nil {
+		return p.mirror.Resolve(registry, packageName, version)
+	}
+	if p.store != nil {
+		return p.store.Pull(ctx, registry, packageName, version)
+	}
+
 	// Convert Spin-style package name (namespace:package) to OCI format (namespace/package)
 	// This handles cases like "bowlofarugula:fluid" -> "bowlofarugula/fluid"
 	ociPackageName := strings.Replace(packageName, ":", "/", 1)
@@ -66,7 +92,7 @@ func (p *WASMPuller) Pull(ctx context.Context, registry, packageName, version st
 	}
 
 	// Pull the image
-	img, err := remote.Image(tag, remote.WithAuthFromKeychain(authn.DefaultKeychain))
+	img, err := remote.Image(tag, remote.WithAuthFromKeychain(Keychain()))
 	if err != nil {
 		return "", fmt.Errorf("failed to pull %s: %w", ref, err)
 	}
@@ -144,7 +170,8 @@ func (p *WASMPuller) Pull(ctx context.Context, registry, packageName, version st
 
 // WASMPusher handles pushing WASM components to OCI registries
 type WASMPusher struct {
-	auth *ECRAuth
+	auth     *ECRAuth
+	keychain authn.Keychain
 }
 
 // NewWASMPusher creates a new WASM component pusher
@@ -152,46 +179,88 @@ func NewWASMPusher(auth *ECRAuth) *WASMPusher {
 	return &WASMPusher{auth: auth}
 }
 
+// NewKeychainPusher creates a WASM component pusher for a registry that
+// authenticates with Keychain(): the local Docker credentials (docker login)
+// or the registry's credential helper
+func NewKeychainPusher(registry string) *WASMPusher {
+	return &WASMPusher{auth: &ECRAuth{Registry: registry}, keychain: Keychain()}
+}
+
 // Push uploads a WASM component to a registry as an OCI artifact
 // Following the CNCF TAG Runtime WASM OCI Artifact specification
 func (p *WASMPusher) Push(ctx context.Context, wasmPath, packageName, version string) error {
+	_, err := p.PushWithDigest(ctx, wasmPath, packageName, version)
+	return err
+}
+
+// PushWithDigest uploads a WASM component like Push and returns the digest of
+// the pushed manifest, which pins the exact artifact regardless of later tags
+func (p *WASMPusher) PushWithDigest(ctx context.Context, wasmPath, packageName, version string) (string, error) {
 	// Clean the WASM file path
 	wasmPath = filepath.Clean(wasmPath)
 
 	// Read the WASM file
 	wasmContent, err := os.ReadFile(wasmPath)
 	if err != nil {
-		return fmt.Errorf("failed to read WASM file: %w", err)
+		return "", fmt.Errorf("failed to read WASM file: %w", err)
 	}
 
 	// Create and push the WASM OCI image
 	img, err := p.createWASMImage(wasmContent, version)
 	if err != nil {
-		return fmt.Errorf("failed to create WASM image: %w", err)
+		return "", fmt.Errorf("failed to create WASM image: %w", err)
+	}
+	return p.write(ctx, img, packageName, version)
+}
+
+// Exists reports whether packageName:version is already in the registry
+func (p *WASMPusher) Exists(ctx context.Context, packageName, version string) (bool, error) {
+	ref := fmt.Sprintf("%s/%s:%s", p.auth.Registry, packageName, version)
+	tag, err := name.ParseReference(ref)
+	if err != nil {
+		return false, fmt.Errorf("invalid reference %s: %w", ref, err)
+	}
+
+	if _, err := remote.Head(tag, p.authOption(), remote.WithContext(ctx)); err != nil {
+		var terr *transport.Error
+		if errors.As(err, &terr) && terr.StatusCode == http.StatusNotFound {
+			return false, nil
+		}
+		return false, fmt.Errorf("failed to check %s: %w", ref, err)
+	}
+	return true, nil
+}
+
+// authOption authenticates with the keychain when the pusher has one, or
+// with its static credentials
+func (p *WASMPusher) authOption() remote.Option {
+	if p.keychain != nil {
+		return remote.WithAuthFromKeychain(p.keychain)
 	}
+	return remote.WithAuth(p.auth.Authenticator())
+}
 
+// write pushes an image to packageName:version and returns its manifest digest
+func (p *WASMPusher) write(ctx context.Context, img v1.Image, packageName, version string) (string, error) {
 	// Construct the registry reference
 	ref := fmt.Sprintf("%s/%s:%s", p.auth.Registry, packageName, version)
 
 	// Parse the reference
 	tag, err := name.ParseReference(ref)
 	if err != nil {
-		return fmt.Errorf("invalid reference %s: %w", ref, err)
-	}
-
-	// Create authenticator
-	authConfig := authn.AuthConfig{
-		Username: p.auth.Username,
-		Password: p.auth.Password,
+		return "", fmt.Errorf("invalid reference %s: %w", ref, err)
 	}
-	authenticator := authn.FromConfig(authConfig)
 
 	// Push the image
-	if err := remote.Write(tag, img, remote.WithAuth(authenticator)); err != nil {
-		return fmt.Errorf("failed to push to registry: %w", err)
+	if err := remote.Write(tag, img, p.authOption(), remote.WithContext(ctx)); err != nil {
+		return "", fmt.Errorf("failed to push to registry: %w", err)
 	}
 
-	return nil
+	digest, err := img.Digest()
+	if err != nil {
+		return "", fmt.Errorf("failed to compute manifest digest: %w", err)
+	}
+	return digest.String(), nil
 }
 
 // createWASMImage creates a WASM OCI image from content
@@ -274,3 +274,31 @@ func TestWASMPuller_Pull_CacheWriteError(t *testing.T) {
 	assert.Error(t, err)
 	assert.Contains(t, err.Error(), "failed to create cache file")
 }
+
+func TestWASMPusher_Exists_Integration(t *testing.T) {
+	s := httptest.NewServer(registry.New())
+	defer s.Close()
+
+	pusher := NewWASMPusher(&ECRAuth{
+		Registry: strings.TrimPrefix(s.URL, "http://"),
+		Username: "test",
+		Password: "test",
+	})
+	ctx := context.Background()
+
+	wasmPath := filepath.Join(t.TempDir(), "component.wasm")
+	require.NoError(t, os.WriteFile(wasmPath, []byte{0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00}, 0644))
+	require.NoError(t, pusher.Push(ctx, wasmPath, "test/exists", "1.0.0"))
+
+	exists, err := pusher.Exists(ctx, "test/exists", "1.0.0")
+	require.NoError(t, err)
+	assert.True(t, exists)
+
+	exists, err = pusher.Exists(ctx, "test/exists", "1.0.1")
+	require.NoError(t, err)
+	assert.False(t, exists)
+
+	exists, err = pusher.Exists(ctx, "test/missing", "1.0.0")
+	require.NoError(t, err)
+	assert.False(t, exists)
+}
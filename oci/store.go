@@ -0,0 +1,305 @@
+package oci
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+)
+
+// storeIndexFile maps pulled component references to their stored blobs
+const storeIndexFile = "refs.json"
+
+// staleTempAge is how old a temporary file left by an interrupted pull must be
+// before garbage collection removes it, so pulls in progress are left alone
+const staleTempAge = time.Hour
+
+// StoreEntry records a component pulled into the store
+type StoreEntry struct {
+	Ref      string    `json:"ref"`
+	Digest   string    `json:"digest"` // Manifest digest
+	Layer    string    `json:"layer"`  // Digest of the WASM layer, which names the blob
+	Size     int64     `json:"size"`
+	PulledAt time.Time `json:"pulled_at"`
+	UsedAt   time.Time `json:"used_at"`
+}
+
+// StoreGCResult describes what garbage collection removed from the store
+type StoreGCResult struct {
+	RemovedRefs  []string `json:"removed_refs"`
+	RemovedBlobs int      `json:"removed_blobs"`
+	FreedBytes   int64    `json:"freed_bytes"`
+}
+
+// Store is a content-addressed local store of pulled components shared by
+// all projects. Blobs are named by the digest of their WASM layer, so a
+// component is downloaded once however many references and projects use it.
+// Blobs are verified every time they are reused and fetched again when damaged.
+type Store struct {
+	dir string
+	mu  sync.Mutex
+	now func() time.Time
+}
+
+// DefaultStoreDir returns the store directory, which can be overridden with FTL_STORE_DIR
+func DefaultStoreDir() string {
+	if dir := os.Getenv("FTL_STORE_DIR"); dir != "" {
+		return dir
+	}
+	return filepath.Join(os.Getenv("HOME"), ".ftl", "store")
+}
+
+// NewStore returns the store kept in dir
+func NewStore(dir string) *Store {
+	return &Store{dir: dir, now: time.Now}
+}
+
+// Dir returns the directory the store is kept in
+func (s *Store) Dir() string {
+	return s.dir
+}
+
+// Pull resolves a component and returns the path of its stored WASM blob
+func (s *Store) Pull(ctx context.Context, registry, packageName, version string) (string, error) {
+	entry, err := s.Fetch(ctx, MirrorRef{Registry: registry, Package: packageName, Version: version})
+	if err != nil {
+		return "", err
+	}
+	return s.BlobPath(entry), nil
+}
+
+// Fetch resolves a component's manifest in its registry and stores its WASM
+// layer. The layer is only downloaded when the store doesn't hold an intact
+// copy yet. When the reference is pinned, the manifest must match its digest.
+func (s *Store) Fetch(ctx context.Context, ref MirrorRef) (*StoreEntry, error) {
+	ociRef := fmt.Sprintf("%s/%s:%s", ref.Registry, strings.Replace(ref.Package, ":", "/", 1), ref.Version)
+	tag, err := name.ParseReference(ociRef)
+	if err != nil {
+		return nil, fmt.Errorf("invalid reference %s: %w", ociRef, err)
+	}
+
+	img, err := remote.Image(tag, remote.WithAuthFromKeychain(Keychain()), remote.WithContext(ctx))
+	if err != nil {
+		return nil, fmt.Errorf("failed to pull %s: %w", ref, err)
+	}
+	digest, err := img.Digest()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get manifest digest: %w", err)
+	}
+	if ref.Digest != "" && digest.String() != ref.Digest {
+		return nil, fmt.Errorf("%s resolved to %s, expected %s", ref, digest, ref.Digest)
+	}
+
+	manifest, err := img.Manifest()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get manifest: %w", err)
+	}
+	if len(manifest.Layers) == 0 {
+		return nil, fmt.Errorf("no layers found in %s", ref)
+	}
+	layer := manifest.Layers[0].Digest
+	path := s.blobPath(layer.Hex)
+
+	size := manifest.Layers[0].Size
+	if actual, err := fileSHA256(path); err != nil || actual != layer.Hex {
+		l, err := img.LayerByDigest(layer)
+		if err != nil {
+			return nil, fmt.Errorf("failed to get layer: %w", err)
+		}
+		content, err := l.Compressed()
+		if err != nil {
+			return nil, fmt.Errorf("failed to get layer content: %w", err)
+		}
+		defer func() { _ = content.Close() }()
+
+		if size, err = writeVerifiedBlob(path, layer.Hex, content); err != nil {
+			return nil, err
+		}
+		// Blobs are hardlinked into projects, where writing to them would
+		// change every copy
+		if err := os.Chmod(path, 0444); err != nil {
+			return nil, fmt.Errorf("failed to protect blob: %w", err)
+		}
+	}
+
+	now := s.now().UTC()
+	entry := &StoreEntry{
+		Ref:      ref.String(),
+		Digest:   digest.String(),
+		Layer:    layer.String(),
+		Size:     size,
+		PulledAt: now,
+		UsedAt:   now,
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	index, err := s.loadIndex()
+	if err != nil {
+		return nil, err
+	}
+	// Keep when the reference was first pulled at this digest
+	if previous, ok := index[entry.Ref]; ok && previous.Digest == entry.Digest {
+		entry.PulledAt = previous.PulledAt
+	}
+	index[entry.Ref] = *entry
+	if err := s.saveIndex(index); err != nil {
+		return nil, err
+	}
+	return entry, nil
+}
+
+// BlobPath returns the path of an entry's WASM blob
+func (s *Store) BlobPath(entry *StoreEntry) string {
+	return s.blobPath(strings.TrimPrefix(entry.Layer, "sha256:"))
+}
+
+// Entries lists the stored components, sorted by reference
+func (s *Store) Entries() ([]StoreEntry, error) {
+	s.mu.Lock()
+	index, err := s.loadIndex()
+	s.mu.Unlock()
+	if err != nil {
+		return nil, err
+	}
+
+	entries := make([]StoreEntry, 0, len(index))
+	for _, entry := range index {
+		entries = append(entries, entry)
+	}
+	sort.Slice(entries, func(i, j int) bool { return entries[i].Ref < entries[j].Ref })
+	return entries, nil
+}
+
+// GC forgets references not used within unusedFor, keeping all of them when
+// it is 0, then removes the blobs no remaining reference needs and temporary
+// files left by interrupted pulls. With dryRun, nothing is removed.
+func (s *Store) GC(unusedFor time.Duration, dryRun bool) (*StoreGCResult, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	index, err := s.loadIndex()
+	if err != nil {
+		return nil, err
+	}
+
+	result := &StoreGCResult{RemovedRefs: []string{}}
+	now := s.now()
+	live := make(map[string]bool)
+	for ref, entry := range index {
+		if unusedFor > 0 && now.Sub(entry.UsedAt) > unusedFor {
+			result.RemovedRefs = append(result.RemovedRefs, ref)
+			delete(index, ref)
+			continue
+		}
+		live[strings.TrimPrefix(entry.Layer, "sha256:")+".wasm"] = true
+	}
+	sort.Strings(result.RemovedRefs)
+
+	blobDir := filepath.Dir(s.blobPath(""))
+	files, err := os.ReadDir(blobDir)
+	if err != nil && !errors.Is(err, fs.ErrNotExist) {
+		return nil, fmt.Errorf("failed to read store: %w", err)
+	}
+	for _, file := range files {
+		info, err := file.Info()
+		if err != nil || !info.Mode().IsRegular() || live[file.Name()] {
+			continue
+		}
+		if strings.HasSuffix(file.Name(), ".tmp") && now.Sub(info.ModTime()) < staleTempAge {
+			continue
+		}
+		if !dryRun {
+			if err := os.Remove(filepath.Join(blobDir, file.Name())); err != nil {
+				return nil, fmt.Errorf("failed to remove %s: %w", file.Name(), err)
+			}
+		}
+		if strings.HasSuffix(file.Name(), ".wasm") {
+			result.RemovedBlobs++
+		}
+		result.FreedBytes += info.Size()
+	}
+
+	if !dryRun && len(result.RemovedRefs) > 0 {
+		if err := s.saveIndex(index); err != nil {
+			return nil, err
+		}
+	}
+	return result, nil
+}
+
+func (s *Store) blobPath(hexDigest string) string {
+	return filepath.Join(s.dir, "blobs", "sha256", hexDigest+".wasm")
+}
+
+func (s *Store) loadIndex() (map[string]StoreEntry, error) {
+	index := make(map[string]StoreEntry)
+	data, err := os.ReadFile(filepath.Join(s.dir, storeIndexFile))
+	if os.IsNotExist(err) {
+		return index, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read store index: %w", err)
+	}
+	if err := json.Unmarshal(data, &index); err != nil {
+		return nil, fmt.Errorf("failed to parse store index: %w", err)
+	}
+	return index, nil
+}
+
+func (s *Store) saveIndex(index map[string]StoreEntry) error {
+	data, err := json.MarshalIndent(index, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode store index: %w", err)
+	}
+	if err := os.MkdirAll(s.dir, 0750); err != nil {
+		return fmt.Errorf("failed to create store directory: %w", err)
+	}
+	path := filepath.Join(s.dir, storeIndexFile)
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0600); err != nil {
+		return fmt.Errorf("failed to write store index: %w", err)
+	}
+	return os.Rename(tmp, path)
+}
+
+// LinkOrCopy places the file at src at dst, replacing any existing file. It
+// hardlinks when both paths are on the same filesystem and copies otherwise.
+func LinkOrCopy(src, dst string) error {
+	if err := os.MkdirAll(filepath.Dir(dst), 0750); err != nil {
+		return err
+	}
+	if err := os.Remove(dst); err != nil && !errors.Is(err, fs.ErrNotExist) {
+		return err
+	}
+	if err := os.Link(src, dst); err == nil {
+		return nil
+	}
+
+	in, err := os.Open(filepath.Clean(src))
+	if err != nil {
+		return err
+	}
+	defer func() { _ = in.Close() }()
+
+	out, err := os.OpenFile(filepath.Clean(dst), os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0600)
+	if err != nil {
+		return err
+	}
+	if _, err := io.Copy(out, in); err != nil {
+		_ = out.Close()
+		return err
+	}
+	return out.Close()
+}
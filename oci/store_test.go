@@ -0,0 +1,143 @@
+package oci
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestStore_FetchReusesBlobs(t *testing.T) {
+	host, digest := seedRegistry(t)
+	store := NewStore(t.TempDir())
+
+	entry, err := store.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0", Digest: digest})
+	require.NoError(t, err)
+	assert.Equal(t, host+"/acme:tool@1.0.0", entry.Ref)
+	assert.Equal(t, digest, entry.Digest)
+	assert.Equal(t, int64(len(testWASM)), entry.Size)
+
+	path := store.BlobPath(entry)
+	content, err := os.ReadFile(path)
+	require.NoError(t, err)
+	assert.Equal(t, testWASM, content)
+	info, err := os.Stat(path)
+	require.NoError(t, err)
+	assert.Equal(t, os.FileMode(0444), info.Mode().Perm())
+
+	// An intact blob isn't downloaded again
+	old := time.Now().Add(-24 * time.Hour)
+	require.NoError(t, os.Chtimes(path, old, old))
+	again, err := store.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0"})
+	require.NoError(t, err)
+	assert.True(t, entry.PulledAt.Equal(again.PulledAt))
+	info, err = os.Stat(path)
+	require.NoError(t, err)
+	assert.True(t, info.ModTime().Equal(old))
+
+	// Another version with the same content shares the blob
+	wasmPath := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(wasmPath, testWASM, 0600))
+	_, err = NewWASMPusher(&ECRAuth{Registry: host}).PushWithDigest(context.Background(), wasmPath, "acme/tool", "1.0.1")
+	require.NoError(t, err)
+	pulled, err := store.Pull(context.Background(), host, "acme:tool", "1.0.1")
+	require.NoError(t, err)
+	assert.Equal(t, path, pulled)
+
+	entries, err := NewStore(store.Dir()).Entries()
+	require.NoError(t, err)
+	require.Len(t, entries, 2)
+	assert.Equal(t, host+"/acme:tool@1.0.0", entries[0].Ref)
+	assert.Equal(t, entries[0].Layer, entries[1].Layer)
+}
+
+func TestStore_FetchReplacesDamagedBlob(t *testing.T) {
+	host, _ := seedRegistry(t)
+	store := NewStore(t.TempDir())
+	ref := MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0"}
+
+	entry, err := store.Fetch(context.Background(), ref)
+	require.NoError(t, err)
+	path := store.BlobPath(entry)
+	require.NoError(t, os.Chmod(path, 0600))
+	require.NoError(t, os.WriteFile(path, []byte("tampered"), 0600))
+
+	_, err = store.Fetch(context.Background(), ref)
+	require.NoError(t, err)
+	content, err := os.ReadFile(path)
+	require.NoError(t, err)
+	assert.Equal(t, testWASM, content)
+}
+
+func TestStore_FetchDigestMismatch(t *testing.T) {
+	host, _ := seedRegistry(t)
+	store := NewStore(t.TempDir())
+
+	_, err := store.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0", Digest: "sha256:0000"})
+	assert.ErrorContains(t, err, "expected sha256:0000")
+
+	entries, err := store.Entries()
+	require.NoError(t, err)
+	assert.Empty(t, entries)
+}
+
+func TestStore_GC(t *testing.T) {
+	host, _ := seedRegistry(t)
+	store := NewStore(t.TempDir())
+
+	entry, err := store.Fetch(context.Background(), MirrorRef{Registry: host, Package: "acme:tool", Version: "1.0.0"})
+	require.NoError(t, err)
+	blobDir := filepath.Dir(store.BlobPath(entry))
+
+	orphan := filepath.Join(blobDir, "0123.wasm")
+	require.NoError(t, os.WriteFile(orphan, []byte("orphan"), 0600))
+	freshTemp := filepath.Join(blobDir, "4567.wasm.tmp")
+	require.NoError(t, os.WriteFile(freshTemp, []byte("partial"), 0600))
+	staleTemp := filepath.Join(blobDir, "89ab.wasm.tmp")
+	require.NoError(t, os.WriteFile(staleTemp, []byte("partial"), 0600))
+	old := time.Now().Add(-2 * staleTempAge)
+	require.NoError(t, os.Chtimes(staleTemp, old, old))
+
+	result, err := store.GC(0, false)
+	require.NoError(t, err)
+	assert.Empty(t, result.RemovedRefs)
+	assert.Equal(t, 1, result.RemovedBlobs)
+	assert.Equal(t, int64(len("orphan")+len("partial")), result.FreedBytes)
+	assert.NoFileExists(t, orphan)
+	assert.NoFileExists(t, staleTemp)
+	assert.FileExists(t, freshTemp)
+	assert.FileExists(t, store.BlobPath(entry))
+
+	// References unused for longer than the cutoff are forgotten
+	store.now = func() time.Time { return time.Now().Add(48 * time.Hour) }
+	result, err = store.GC(24*time.Hour, true)
+	require.NoError(t, err)
+	assert.Equal(t, []string{entry.Ref}, result.RemovedRefs)
+	assert.Equal(t, 1, result.RemovedBlobs)
+	assert.FileExists(t, store.BlobPath(entry))
+
+	_, err = store.GC(24*time.Hour, false)
+	require.NoError(t, err)
+	assert.NoFileExists(t, store.BlobPath(entry))
+	entries, err := store.Entries()
+	require.NoError(t, err)
+	assert.Empty(t, entries)
+}
+
+func TestLinkOrCopy(t *testing.T) {
+	src := filepath.Join(t.TempDir(), "tool.wasm")
+	require.NoError(t, os.WriteFile(src, testWASM, 0444))
+
+	dst := filepath.Join(t.TempDir(), "build", "tool.wasm")
+	require.NoError(t, os.MkdirAll(filepath.Dir(dst), 0750))
+	require.NoError(t, os.WriteFile(dst, []byte("stale build"), 0600))
+
+	require.NoError(t, LinkOrCopy(src, dst))
+	content, err := os.ReadFile(dst)
+	require.NoError(t, err)
+	assert.Equal(t, testWASM, content)
+}
@@ -0,0 +1,245 @@
+package oci
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/google/go-containerregistry/pkg/name"
+	v1 "github.com/google/go-containerregistry/pkg/v1"
+	"github.com/google/go-containerregistry/pkg/v1/remote"
+)
+
+// CreatedAnnotation is the OCI annotation holding the artifact creation time
+const CreatedAnnotation = "org.opencontainers.image.created"
+
+// describeConcurrency bounds the number of manifest requests made in parallel
+const describeConcurrency = 8
+
+// TagInfo describes a tagged artifact in a repository
+type TagInfo struct {
+	Tag    string `json:"tag"`
+	Digest string `json:"digest,omitempty"`
+	// Size is the total size of the manifest, config and layers in bytes
+	Size    int64      `json:"size,omitempty"`
+	Created *time.Time `json:"created,omitempty"`
+	// Error is set when the manifest for this tag could not be fetched
+	Error string `json:"error,omitempty"`
+}
+
+// TagLister lists tags and their manifest metadata in OCI repositories
+type TagLister struct {
+	options []remote.Option
+}
+
+// NewTagLister creates a tag lister that authenticates with Keychain()
+func NewTagLister(options ...remote.Option) *TagLister {
+	return &TagLister{
+		options: append([]remote.Option{remote.WithAuthFromKeychain(Keychain())}, options...),
+	}
+}
+
+// ListTags returns every tag in a repository (registry/namespace/package),
+// sorted with SortTags. The registry API is paginated transparently.
+func (l *TagLister) ListTags(ctx context.Context, repository string) ([]string, error) {
+	repo, err := name.NewRepository(repository)
+	if err != nil {
+		return nil, fmt.Errorf("invalid repository %s: %w", repository, err)
+	}
+
+	tags, err := remote.List(repo, append(l.options, remote.WithContext(ctx))...)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list tags for %s: %w", repository, err)
+	}
+
+	SortTags(tags)
+	return tags, nil
+}
+
+// Describe fetches the manifest metadata of each tag, preserving the order of
+// tags. Failures are reported per tag rather than failing the whole listing.
+func (l *TagLister) Describe(ctx context.Context, repository string, tags []string) []TagInfo {
+	infos := make([]TagInfo, len(tags))
+	sem := make(chan struct{}, describeConcurrency)
+	var wg sync.WaitGroup
+
+	for i, tag := range tags {
+		wg.Add(1)
+		go func(i int, tag string) {
+			defer wg.Done()
+			sem <- struct{}{}
+			defer func() { <-sem }()
+			infos[i] = l.describe(ctx, repository, tag)
+		}(i, tag)
+	}
+
+	wg.Wait()
+	return infos
+}
+
+func (l *TagLister) describe(ctx context.Context, repository, tag string) TagInfo {
+	info := TagInfo{Tag: tag}
+
+	ref, err := name.ParseReference(repository + ":" + tag)
+	if err != nil {
+		info.Error = err.Error()
+		return info
+	}
+
+	desc, err := remote.Get(ref, append(l.options, remote.WithContext(ctx))...)
+	if err != nil {
+		info.Error = err.Error()
+		return info
+	}
+	info.Digest = desc.Digest.String()
+	info.Size = desc.Size
+
+	// Index manifests (multi-platform) have no config or layers to add up
+	manifest, err := v1.ParseManifest(bytes.NewReader(desc.Manifest))
+	if err != nil {
+		return info
+	}
+	info.Size += manifest.Config.Size
+	for _, layer := range manifest.Layers {
+		info.Size += layer.Size
+	}
+	if created, ok := manifest.Annotations[CreatedAnnotation]; ok {
+		if t, err := time.Parse(time.RFC3339, created); err == nil {
+			info.Created = &t
+		}
+	}
+
+	return info
+}
+
+// SortTags sorts tags in place: "latest" first, then semantic versions from
+// newest to oldest, then any other tags alphabetically.
+func SortTags(tags []string) {
+	sort.SliceStable(tags, func(i, j int) bool {
+		return tagLess(tags[i], tags[j])
+	})
+}
+
+func tagLess(a, b string) bool {
+	if a == "latest" || b == "latest" {
+		return a == "latest" && b != "latest"
+	}
+
+	va, okA := parseSemver(a)
+	vb, okB := parseSemver(b)
+	switch {
+	case okA && okB:
+		if c := compareSemver(va, vb); c != 0 {
+			return c > 0
+		}
+		return a < b
+	case okA != okB:
+		return okA
+	default:
+		return a < b
+	}
+}
+
+// semver is a parsed semantic version; build metadata is ignored
+type semver struct {
+	major, minor, patch int
+	prerelease          []string
+}
+
+// parseSemver parses versions such as 1.2.3, v1.2.3 and 1.2.3-rc.1+build.
+// Missing minor and patch components default to zero.
+func parseSemver(tag string) (semver, bool) {
+	v := strings.TrimPrefix(tag, "v")
+	if i := strings.IndexByte(v, '+'); i >= 0 {
+		v = v[:i]
+	}
+
+	var pre string
+	if i := strings.IndexByte(v, '-'); i >= 0 {
+		v, pre = v[:i], v[i+1:]
+		if pre == "" {
+			return semver{}, false
+		}
+	}
+
+	parts := strings.Split(v, ".")
+	if len(parts) > 3 {
+		return semver{}, false
+	}
+	var nums [3]int
+	for i, part := range parts {
+		n, err := strconv.Atoi(part)
+		if err != nil || n < 0 {
+			return semver{}, false
+		}
+		nums[i] = n
+	}
+
+	s := semver{major: nums[0], minor: nums[1], patch: nums[2]}
+	if pre != "" {
+		s.prerelease = strings.Split(pre, ".")
+	}
+	return s, true
+}
+
+// compareSemver returns -1, 0 or 1 following semver precedence rules
+func compareSemver(a, b semver) int {
+	for _, pair := range [][2]int{{a.major, b.major}, {a.minor, b.minor}, {a.patch, b.patch}} {
+		if pair[0] != pair[1] {
+			if pair[0] < pair[1] {
+				return -1
+			}
+			return 1
+		}
+	}
+
+	// A release has higher precedence than any of its pre-releases
+	switch {
+	case len(a.prerelease) == 0 && len(b.prerelease) == 0:
+		return 0
+	case len(a.prerelease) == 0:
+		return 1
+	case len(b.prerelease) == 0:
+		return -1
+	}
+
+	for i := 0; i < len(a.prerelease) && i < len(b.prerelease); i++ {
+		if c := comparePrerelease(a.prerelease[i], b.prerelease[i]); c != 0 {
+			return c
+		}
+	}
+	switch {
+	case len(a.prerelease) < len(b.prerelease):
+		return -1
+	case len(a.prerelease) > len(b.prerelease):
+		return 1
+	}
+	return 0
+}
+
+// comparePrerelease compares pre-release identifiers: numeric identifiers
+// compare numerically and sort before alphanumeric ones
+func comparePrerelease(a, b string) int {
+	na, errA := strconv.Atoi(a)
+	nb, errB := strconv.Atoi(b)
+	switch {
+	case errA == nil && errB == nil:
+		switch {
+		case na < nb:
+			return -1
+		case na > nb:
+			return 1
+		}
+		return 0
+	case errA == nil:
+		return -1
+	case errB == nil:
+		return 1
+	}
+	return strings.Compare(a, b)
+}
@@ -0,0 +1,116 @@
+package oci
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestSortTags(t *testing.T) {
+	tags := []string{"0.9.0", "dev", "1.10.0", "latest", "1.2.0", "v1.9.0", "1.10.0-rc.2", "1.10.0-rc.10", "1.10.0-beta", "main"}
+	SortTags(tags)
+	assert.Equal(t, []string{
+		"latest",
+		"1.10.0",
+		"1.10.0-rc.10",
+		"1.10.0-rc.2",
+		"1.10.0-beta",
+		"v1.9.0",
+		"1.2.0",
+		"0.9.0",
+		"dev",
+		"main",
+	}, tags)
+}
+
+func TestParseSemver(t *testing.T) {
+	v, ok := parseSemver("v1.2.3-rc.1+build.5")
+	require.True(t, ok)
+	assert.Equal(t, semver{major: 1, minor: 2, patch: 3, prerelease: []string{"rc", "1"}}, v)
+
+	v, ok = parseSemver("2.1")
+	require.True(t, ok)
+	assert.Equal(t, semver{major: 2, minor: 1}, v)
+
+	for _, tag := range []string{"latest", "1.2.3.4", "1.x", "1.2.3-", "sha-abc123"} {
+		_, ok := parseSemver(tag)
+		assert.False(t, ok, tag)
+	}
+}
+
+// newTagRegistry serves a repository with the given tags, paginated two at a time
+func newTagRegistry(t *testing.T, tags []string) *httptest.Server {
+	t.Helper()
+	manifest := `{
+		"schemaVersion": 2,
+		"mediaType": "application/vnd.oci.image.manifest.v1+json",
+		"config": {"mediaType": "application/vnd.wasm.config.v0+json", "size": 100, "digest": "sha256:44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"},
+		"layers": [{"mediaType": "application/wasm", "size": 2000, "digest": "sha256:44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"}],
+		"annotations": {"org.opencontainers.image.created": "2025-08-01T12:00:00Z"}
+	}`
+
+	return httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/v2/":
+			w.WriteHeader(http.StatusOK)
+		case r.URL.Path == "/v2/test/tool/tags/list":
+			start := 0
+			if last := r.URL.Query().Get("last"); last != "" {
+				for i, tag := range tags {
+					if tag == last {
+						start = i + 1
+					}
+				}
+			}
+			end := start + 2
+			if end >= len(tags) {
+				end = len(tags)
+			} else {
+				w.Header().Set("Link", fmt.Sprintf(`</v2/test/tool/tags/list?n=2&last=%s>; rel="next"`, tags[end-1]))
+			}
+			_ = json.NewEncoder(w).Encode(map[string]interface{}{"name": "test/tool", "tags": tags[start:end]})
+		case strings.HasPrefix(r.URL.Path, "/v2/test/tool/manifests/"):
+			if strings.HasSuffix(r.URL.Path, "/missing") {
+				w.WriteHeader(http.StatusNotFound)
+				return
+			}
+			w.Header().Set("Content-Type", "application/vnd.oci.image.manifest.v1+json")
+			_, _ = w.Write([]byte(manifest))
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+}
+
+func TestTagLister(t *testing.T) {
+	server := newTagRegistry(t, []string{"0.1.0", "latest", "0.2.0", "missing", "0.10.0"})
+	defer server.Close()
+
+	repository := strings.TrimPrefix(server.URL, "http://") + "/test/tool"
+	lister := NewTagLister()
+	ctx := context.Background()
+
+	tags, err := lister.ListTags(ctx, repository)
+	require.NoError(t, err)
+	assert.Equal(t, []string{"latest", "0.10.0", "0.2.0", "0.1.0", "missing"}, tags)
+
+	infos := lister.Describe(ctx, repository, []string{"0.10.0", "missing"})
+	require.Len(t, infos, 2)
+
+	assert.Equal(t, "0.10.0", infos[0].Tag)
+	assert.True(t, strings.HasPrefix(infos[0].Digest, "sha256:"))
+	assert.Greater(t, infos[0].Size, int64(2100))
+	require.NotNil(t, infos[0].Created)
+	assert.Equal(t, 2025, infos[0].Created.Year())
+	assert.Empty(t, infos[0].Error)
+
+	assert.Equal(t, "missing", infos[1].Tag)
+	assert.NotEmpty(t, infos[1].Error)
+}
@@ -112,6 +112,10 @@ type ToolResponse struct {
 
 	// Indicates if this response represents an error
 	IsError bool `json:"isError,omitempty"`
+
+	// Optional metadata passed through to the client, such as pagination
+	// cursors or provenance
+	Meta map[string]interface{} `json:"_meta,omitempty"`
 }
 
 // ToolContent represents content that can be returned by tools
@@ -237,6 +241,18 @@ func WithStructured(text string, structured interface{}) ToolResponse {
 	}
 }
 
+// WithMeta returns a copy of the response with an entry added to its _meta,
+// which the gateway passes through to the client
+func (r ToolResponse) WithMeta(key string, value interface{}) ToolResponse {
+	meta := make(map[string]interface{}, len(r.Meta)+1)
+	for k, v := range r.Meta {
+		meta[k] = v
+	}
+	meta[key] = value
+	r.Meta = meta
+	return r
+}
+
 // TextContent creates a text content item
 func TextContent(text string, annotations *ContentAnnotations) ToolContent {
 	return ToolContent{
@@ -1,6 +1,7 @@
 package ftl
 
 import (
+	"encoding/json"
 	"testing"
 )
 
@@ -161,6 +162,28 @@ func TestWithStructured(t *testing.T) {
 	}
 }
 
+func TestWithMeta(t *testing.T) {
+	base := Text("Page 1")
+	resp := base.WithMeta("nextCursor", "page-2").WithMeta("source", "cache")
+
+	if base.Meta != nil {
+		t.Errorf("Expected the original response to be unchanged, got %v", base.Meta)
+	}
+
+	data, err := json.Marshal(resp)
+	if err != nil {
+		t.Fatalf("Failed to marshal response: %v", err)
+	}
+	var decoded map[string]interface{}
+	if err := json.Unmarshal(data, &decoded); err != nil {
+		t.Fatalf("Failed to unmarshal response: %v", err)
+	}
+	meta, ok := decoded["_meta"].(map[string]interface{})
+	if !ok || meta["nextCursor"] != "page-2" || meta["source"] != "cache" {
+		t.Errorf("Expected _meta with both entries, got %v", decoded["_meta"])
+	}
+}
+
 // Test content creation functions
 
 func TestContentCreators(t *testing.T) {
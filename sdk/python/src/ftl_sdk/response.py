@@ -31,6 +31,16 @@ class ToolResponse:
         """Create a response with structured content."""
         return {"content": [{"type": "text", "text": text}], "structuredContent": structured}
 
+    @staticmethod
+    def with_meta(response: dict[str, Any], **meta: Any) -> dict[str, Any]:
+        """
+        Add entries to the _meta of a response, which the gateway passes through to the client.
+
+        Example:
+            return ToolResponse.with_meta(ToolResponse.text("10 of 42 results"), nextCursor="page-2")
+        """
+        return {**response, "_meta": {**response.get("_meta", {}), **meta}}
+
 
 class ToolResult:
     """
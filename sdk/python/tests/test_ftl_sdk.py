@@ -49,6 +49,16 @@ def test_tool_response_with_structured() -> None:
     }
 
 
+def test_tool_response_with_meta() -> None:
+    """Test adding _meta entries to a response."""
+    response = ToolResponse.with_meta(ToolResponse.text("Page 1"), nextCursor="page-2")
+    response = ToolResponse.with_meta(response, source="cache")
+    assert response == {
+        "content": [{"type": "text", "text": "Page 1"}],
+        "_meta": {"nextCursor": "page-2", "source": "cache"},
+    }
+
+
 def test_tool_content_text() -> None:
     """Test text content creation."""
     content = ToolContent.text("Hello")
@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{FnArg, ItemFn, parse_macro_input};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, FnArg, ItemFn, Pat, PatType, Type, parse_macro_input};
 
 /// Define multiple tools in a single component.
 ///
@@ -24,41 +24,118 @@ use syn::{FnArg, ItemFn, parse_macro_input};
 ///     }
 /// }
 /// ```
+///
+/// A tool can take a `ToolContext` parameter to receive the caller context
+/// (verified identity, scopes, trace id and component variables):
+///
+/// ```ignore
+/// tools! {
+///     /// Greet the authenticated caller
+///     fn whoami(input: WhoamiInput, ctx: ToolContext) -> ToolResponse {
+///         match ctx.user_id {
+///             Some(user) => ToolResponse::text(format!("Hello, {user}")),
+///             None => ToolResponse::error("Not authenticated"),
+///         }
+///     }
+/// }
+/// ```
+///
+/// Instead of an input struct, a tool can take named parameters. The macro
+/// synthesizes the input struct and its schema, with one property per
+/// parameter. Doc comments and `serde`/`schemars` attributes on parameters
+/// are applied to the generated properties:
+///
+/// ```ignore
+/// tools! {
+///     /// Add two numbers
+///     fn add(
+///         /// First number
+///         a: f64,
+///         /// Second number
+///         b: f64,
+///     ) -> ToolResponse {
+///         ToolResponse::text(format!("{}", a + b))
+///     }
+/// }
+/// ```
+///
+/// A tool can take a `CancellationToken`, which is cancelled once the gateway
+/// stops waiting for the call, and cap how many of its calls run at once with
+/// `#[tool(max_concurrency = N)]`. The cap is published in the tool's `_meta`
+/// and enforced by the gateway, which rejects calls over it as retryable:
+///
+/// ```ignore
+/// tools! {
+///     /// Summarize a document
+///     #[tool(max_concurrency = 2)]
+///     async fn summarize(input: SummarizeInput, cancel: CancellationToken) -> ToolResponse {
+///         let pages = fetch_pages(&input.url).await;
+///         if cancel.is_cancelled() {
+///             return ToolResponse::error("Cancelled");
+///         }
+///         ToolResponse::text(summarize_pages(&pages).await)
+///     }
+/// }
+/// ```
+///
+/// `#[tool(required_scopes = ["..."])]` lists scopes the caller must be
+/// granted, published in the tool's `_meta`. The gateway rejects calls from
+/// callers missing any of them before the tool runs:
+///
+/// ```ignore
+/// tools! {
+///     /// Delete a user account
+///     #[tool(required_scopes = ["users:write", "admin"])]
+///     fn delete_user(input: DeleteUserInput) -> ToolResponse {
+///         ToolResponse::text(format!("Deleted {}", input.user_id))
+///     }
+/// }
+/// ```
 #[proc_macro]
 pub fn tools(input: TokenStream) -> TokenStream {
     let tools = parse_macro_input!(input as ToolsDefinition);
+    expand_tools(&tools).into()
+}
 
+// Generate the tool functions and the HTTP component handler routing to them
+fn expand_tools(tools: &ToolsDefinition) -> proc_macro2::TokenStream {
     // Collect all tool functions
     let tool_fns: Vec<_> = tools.functions.iter().collect();
 
     // Generate metadata for each tool
-    let metadata_items: Vec<_> = tool_fns.iter().map(|func| {
-        let name = &func.sig.ident;
-        let name_str = name.to_string();
+    let metadata_items: Vec<_> = tool_fns
+        .iter()
+        .map(|func| {
+            let name = &func.sig.ident;
+            let name_str = name.to_string();
 
-        // Extract doc comment
-        let description = extract_doc_comment(&func.attrs)
-            .map(|d| quote!(Some(#d.to_string())))
-            .unwrap_or(quote!(None));
+            // Extract doc comment
+            let description = extract_doc_comment(&func.attrs)
+                .map(|d| quote!(Some(#d.to_string())))
+                .unwrap_or(quote!(None));
 
-        // Get input type
-        let input_type = match func.sig.inputs.first() {
-            Some(FnArg::Typed(pat_type)) => &pat_type.ty,
-            _ => panic!("Tool function must have exactly one typed argument"),
-        };
+            // Get input type, synthesizing it from the parameters if needed
+            let input = tool_input(func);
+            let input_struct = input_struct(func, &input);
+            let input_type = input_type(func, &input);
+            let meta = tool_meta(&tool_options(func));
 
-        quote! {
-            ::ftl_sdk::ToolMetadata {
-                name: #name_str.to_string(),
-                title: None,
-                description: #description,
-                input_schema: ::serde_json::to_value(::schemars::schema_for!(#input_type)).unwrap(),
-                output_schema: None,
-                annotations: None,
-                meta: None,
+            quote! {
+                ::ftl_sdk::ToolMetadata {
+                    name: #name_str.to_string(),
+                    title: None,
+                    description: #description,
+                    input_schema: {
+                        #input_struct
+                        ::serde_json::to_value(::schemars::schema_for!(#input_type)).unwrap()
+                    },
+                    output_schema: None,
+                    annotations: None,
+                    meta: #meta,
+                }
             }
-        }
-    }).collect();
+        })
+        .collect();
 
     // Generate routing cases for POST requests
     let routing_cases: Vec<_> = tool_fns.iter().map(|func| {
@@ -66,22 +143,56 @@ pub fn tools(input: TokenStream) -> TokenStream {
         let name_str = name.to_string();
         let is_async = func.sig.asyncness.is_some();
 
-        // Get input type
-        let input_type = match func.sig.inputs.first() {
-            Some(FnArg::Typed(pat_type)) => &pat_type.ty,
-            _ => panic!("Tool function must have exactly one typed argument"),
-        };
+        // Get input type, synthesizing it from the parameters if needed
+        let input = tool_input(func);
+        let input_struct = input_struct(func, &input);
+        let input_type = input_type(func, &input);
+        let input_pattern = input_pattern(&input_type, &input);
+
+        // Pass the input, the caller context and the cancellation token in the
+        // order they are declared
+        let args: Vec<_> = func.sig.inputs.iter().map(|arg| match arg {
+            FnArg::Typed(pat_type) if is_context_type(&pat_type.ty) => match &*pat_type.ty {
+                Type::Reference(_) => quote!(&ctx),
+                _ => quote!(ctx),
+            },
+            FnArg::Typed(pat_type) if is_cancellation_type(&pat_type.ty) => match &*pat_type.ty {
+                Type::Reference(_) => quote!(&__cancellation),
+                _ => quote!(__cancellation.clone()),
+            },
+            FnArg::Typed(pat_type) => match &input {
+                ToolInput::Struct(_) => quote!(input),
+                ToolInput::Params(_) => {
+                    let binding = param_binding(param_ident(pat_type));
+                    quote!(#binding)
+                }
+            },
+            FnArg::Receiver(_) => panic!("Tool functions cannot take self"),
+        }).collect();
 
         let fn_call = if is_async {
-            quote!(#name(input).await)
+            quote!(#name(#(#args),*).await)
+        } else {
+            quote!(#name(#(#args),*))
+        };
+
+        // The token is cancelled at the deadline the gateway passes along
+        let cancellation_init = if has_cancellation_arg(func) {
+            quote! {
+                let __cancellation = ::ftl_sdk::CancellationToken::from_headers(
+                    req.headers().filter_map(|(name, value)| value.as_str().map(|value| (name, value)))
+                );
+            }
         } else {
-            quote!(#name(input))
+            quote!()
         };
 
         quote! {
             #name_str => {
+                #input_struct
                 match ::serde_json::from_slice::<#input_type>(body) {
-                    Ok(input) => {
+                    Ok(#input_pattern) => {
+                        #cancellation_init
                         let response = #fn_call;
                         match ::serde_json::to_vec(&response) {
                             Ok(body) => Response::builder()
@@ -116,9 +227,24 @@ pub fn tools(input: TokenStream) -> TokenStream {
         }
     }).collect();
 
+    // Build the caller context only when a tool asks for it
+    let context_init = if tools.functions.iter().any(has_context_arg) {
+        quote! {
+            let ctx = ::ftl_sdk::ToolContext::from_headers(
+                req.headers().filter_map(|(name, value)| value.as_str().map(|value| (name, value)))
+            )
+            .with_variables(|name| ::spin_sdk::variables::get(name).ok());
+        }
+    } else {
+        quote!()
+    };
+
+    // Parameter attributes only apply to the synthesized input structs
+    let tool_defs: Vec<_> = tools.functions.iter().map(strip_param_attrs).collect();
+
     let output = quote! {
         // Define all tool functions
-        #(#tool_fns)*
+        #(#tool_defs)*
 
         // Generate the HTTP component handler
         #[::spin_sdk::http_component]
@@ -126,6 +252,7 @@ pub fn tools(input: TokenStream) -> TokenStream {
             use ::spin_sdk::http::{Method, Response};
 
             let path = req.path();
+            #context_init
 
             match req.method() {
                 &Method::Get if path == "/" => {
@@ -174,7 +301,556 @@ pub fn tools(input: TokenStream) -> TokenStream {
         }
     };
 
-    output.into()
+    output
+}
+
+/// Define scheduled handlers run by cron triggers.
+///
+/// This macro generates the Spin cron component handler. Each handler is
+/// named in the component's `schedules` in `ftl.yaml`, which FTL maps to a
+/// cron trigger running the same component with the `ftl_schedule` variable
+/// set to the handler's name. The component needs a `spin-cron-sdk`
+/// dependency, and `scheduled!` can be used beside `tools!`.
+///
+/// Handlers return `()` or a `Result<(), E>` whose error implements
+/// `Display`, and can take a `ScheduledEvent` parameter:
+///
+/// ```ignore
+/// scheduled! {
+///     /// Refresh the cached exchange rates
+///     async fn refresh_cache(event: ScheduledEvent) -> Result<(), String> {
+///         let url = event.variable("rates_url").ok_or("rates_url is not set")?;
+///         fetch_rates(&url).await
+///     }
+///
+///     fn nightly() {
+///         println!("Running nightly cleanup");
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn scheduled(input: TokenStream) -> TokenStream {
+    let scheduled = parse_macro_input!(input as ScheduledDefinition);
+    expand_scheduled(&scheduled).into()
+}
+
+// Generate the scheduled handlers and the cron component handler dispatching
+// to them
+fn expand_scheduled(scheduled: &ScheduledDefinition) -> proc_macro2::TokenStream {
+    // Dispatch to the handler named by the schedule
+    let cases: Vec<_> = scheduled
+        .functions
+        .iter()
+        .map(|func| {
+            let name = &func.sig.ident;
+            let name_str = name.to_string();
+            let args: Vec<_> = func
+                .sig
+                .inputs
+                .iter()
+                .map(|arg| match arg {
+                    FnArg::Typed(pat_type) if is_scheduled_event_type(&pat_type.ty) => {
+                        match &*pat_type.ty {
+                            Type::Reference(_) => quote!(&event),
+                            _ => quote!(event),
+                        }
+                    }
+                    _ => panic!("Scheduled handlers take no parameters or a ScheduledEvent"),
+                })
+                .collect();
+
+            let fn_call = if func.sig.asyncness.is_some() {
+                quote!(#name(#(#args),*).await)
+            } else {
+                quote!(#name(#(#args),*))
+            };
+
+            quote! {
+                #name_str => ::ftl_sdk::IntoScheduledResult::into_scheduled_result(#fn_call),
+            }
+        })
+        .collect();
+
+    // A component with a single handler runs it without an ftl_schedule variable
+    let default_handler = match scheduled.functions.as_slice() {
+        [func] => {
+            let name_str = func.sig.ident.to_string();
+            quote!(.or_else(|| Some(#name_str.to_string())))
+        }
+        _ => quote!(),
+    };
+
+    // Build the event only when a handler asks for it
+    let event_init = if scheduled
+        .functions
+        .iter()
+        .any(|func| !func.sig.inputs.is_empty())
+    {
+        quote! {
+            let event = ::ftl_sdk::ScheduledEvent::new(handler.clone(), metadata.timestamp)
+                .with_variables(|name| ::spin_sdk::variables::get(name).ok());
+        }
+    } else {
+        quote!(let _ = metadata;)
+    };
+
+    let handler_defs = &scheduled.functions;
+    let output = quote! {
+        // Define all scheduled handlers
+        #(#handler_defs)*
+
+        // Generate the cron component handler
+        #[::spin_cron_sdk::cron_component]
+        async fn handle_scheduled_event(
+            metadata: ::spin_cron_sdk::Metadata,
+        ) -> ::std::result::Result<(), ::spin_cron_sdk::Error> {
+            let handler = ::spin_sdk::variables::get(::ftl_sdk::SCHEDULE_VARIABLE)
+                .ok()
+                .filter(|name| !name.is_empty())
+                #default_handler
+                .unwrap_or_default();
+            #event_init
+
+            let result: ::std::result::Result<(), String> = match handler.as_str() {
+                #(#cases)*
+                _ => Err(format!("Scheduled handler '{}' not found", handler)),
+            };
+            result.map_err(::spin_cron_sdk::Error::Other)
+        }
+    };
+
+    output
+}
+
+/// Turn an enum into the input of a tool with several operations.
+///
+/// Each variant is an operation, selected by an `operation` property holding
+/// the snake_case variant name (or its `#[serde(rename)]`). The macro derives
+/// `Deserialize` and `JsonSchema` with serde's internal tagging, so the input
+/// schema is a `oneOf` with one branch per operation, and generates a
+/// `dispatch` method calling the handler function named after each variant.
+/// Struct variants pass their fields in order, newtype variants their value
+/// and unit variants nothing:
+///
+/// ```ignore
+/// #[tool_operations]
+/// enum MathInput {
+///     /// Add two numbers
+///     Add { a: f64, b: f64 },
+///     /// Negate a number
+///     Negate(NegateInput),
+///     /// Return pi
+///     Pi,
+/// }
+///
+/// fn add(a: f64, b: f64) -> ToolResponse { text!("{}", a + b) }
+/// fn negate(input: NegateInput) -> ToolResponse { text!("{}", -input.value) }
+/// fn pi() -> ToolResponse { text!("{}", std::f64::consts::PI) }
+///
+/// tools! {
+///     /// Basic arithmetic
+///     fn math(input: MathInput) -> ToolResponse {
+///         input.dispatch()
+///     }
+/// }
+/// ```
+///
+/// `#[tool_operations(tag = "op")]` changes the name of the operation
+/// property, and `#[tool_operations(async)]` makes `dispatch` await async
+/// handlers.
+#[proc_macro_attribute]
+pub fn tool_operations(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut tag = "operation".to_string();
+    let mut is_async = false;
+    let args_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("tag") {
+            tag = meta.value()?.parse::<syn::LitStr>()?.value();
+            Ok(())
+        } else if meta.path.is_ident("async") {
+            is_async = true;
+            Ok(())
+        } else {
+            Err(meta.error("expected `tag = \"...\"` or `async`"))
+        }
+    });
+    parse_macro_input!(args with args_parser);
+
+    let item = parse_macro_input!(input as DeriveInput);
+    expand_tool_operations(&item, &tag, is_async).into()
+}
+
+// Generate the derives and the dispatch methods of an operations enum
+fn expand_tool_operations(
+    item: &DeriveInput,
+    tag: &str,
+    is_async: bool,
+) -> proc_macro2::TokenStream {
+    let Data::Enum(data) = &item.data else {
+        panic!("tool_operations can only be applied to enums");
+    };
+    if data.variants.is_empty() {
+        panic!("tool_operations needs at least one operation");
+    }
+    if item
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("serde") && serde_args(attr).contains_key("rename_all"))
+    {
+        panic!(
+            "Operations are named after their variants, use #[serde(rename)] on a variant instead of rename_all"
+        );
+    }
+
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let mut names = Vec::new();
+    let mut name_arms = Vec::new();
+    let mut dispatch_arms = Vec::new();
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let handler = format_ident!("{}", snake_case(&variant_ident.to_string()));
+        let name = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+            .find_map(|attr| serde_args(attr).remove("rename"))
+            .unwrap_or_else(|| snake_case(&variant_ident.to_string()));
+
+        let (pattern, args) = match &variant.fields {
+            Fields::Named(fields) => {
+                let idents: Vec<_> = fields.named.iter().map(|f| f.ident.clone()).collect();
+                (quote!({ #(#idents),* }), quote!(#(#idents),*))
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                (quote!((input)), quote!(input))
+            }
+            Fields::Unnamed(_) => panic!(
+                "Operation {variant_ident} must have named fields or a single value, tuple variants cannot be tagged"
+            ),
+            Fields::Unit => (quote!(), quote!()),
+        };
+
+        let call = if is_async {
+            quote!(#handler(#args).await)
+        } else {
+            quote!(#handler(#args))
+        };
+        name_arms.push(quote!(Self::#variant_ident { .. } => #name,));
+        dispatch_arms.push(quote!(Self::#variant_ident #pattern => #call,));
+        names.push(name);
+    }
+
+    let dispatch_sig = if is_async {
+        quote!(pub async fn dispatch(self) -> ::ftl_sdk::ToolResponse)
+    } else {
+        quote!(pub fn dispatch(self) -> ::ftl_sdk::ToolResponse)
+    };
+
+    let output = quote! {
+        #[derive(::serde::Deserialize, ::schemars::JsonSchema)]
+        #[serde(tag = #tag, rename_all = "snake_case")]
+        #item
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Names of the operations, as given in the operation property
+            pub const OPERATIONS: &'static [&'static str] = &[#(#names),*];
+
+            /// Name of the requested operation
+            pub fn operation(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            /// Run the handler of the requested operation
+            #dispatch_sig {
+                match self {
+                    #(#dispatch_arms)*
+                }
+            }
+        }
+    };
+
+    output
+}
+
+/// How a tool function receives its input
+enum ToolInput<'a> {
+    /// A single parameter of the tool's own type holds the whole input
+    Struct(&'a Type),
+    /// Named parameters are flattened into a synthesized input struct
+    Params(Vec<&'a PatType>),
+}
+
+// Types that are never used as a whole input struct
+const SCALAR_TYPES: &[&str] = &[
+    "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+    "u64", "u128", "usize", "String", "Option", "Vec", "HashMap", "BTreeMap", "HashSet",
+    "BTreeSet", "Value",
+];
+
+// Get the last path segment of a type, e.g. `ToolContext` for `ftl_sdk::ToolContext`
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        Type::Group(group) => type_name(&group.elem),
+        Type::Paren(paren) => type_name(&paren.elem),
+        _ => None,
+    }
+}
+
+// Whether a parameter receives the ToolContext
+fn is_context_type(ty: &Type) -> bool {
+    type_name(ty).as_deref() == Some("ToolContext")
+}
+
+// Whether a parameter receives the CancellationToken
+fn is_cancellation_type(ty: &Type) -> bool {
+    type_name(ty).as_deref() == Some("CancellationToken")
+}
+
+// Whether a parameter receives the ScheduledEvent
+fn is_scheduled_event_type(ty: &Type) -> bool {
+    type_name(ty).as_deref() == Some("ScheduledEvent")
+}
+
+// Whether a parameter type is a scalar or collection rather than an input struct
+fn is_scalar_type(ty: &Type) -> bool {
+    match ty {
+        Type::Array(_) | Type::Slice(_) | Type::Tuple(_) => true,
+        _ => type_name(ty).is_some_and(|name| SCALAR_TYPES.contains(&name.as_str())),
+    }
+}
+
+// Get how a tool function takes its input. A single parameter of a non-scalar
+// type is the input struct; any other parameters become input properties.
+fn tool_input(func: &ItemFn) -> ToolInput<'_> {
+    let mut params = Vec::new();
+    for arg in &func.sig.inputs {
+        match arg {
+            FnArg::Typed(pat_type)
+                if is_context_type(&pat_type.ty) || is_cancellation_type(&pat_type.ty) => {}
+            FnArg::Typed(pat_type) => params.push(pat_type),
+            FnArg::Receiver(_) => panic!("Tool functions cannot take self"),
+        }
+    }
+
+    if let [param] = params.as_slice()
+        && !is_scalar_type(&param.ty)
+        && param.attrs.is_empty()
+    {
+        return ToolInput::Struct(&param.ty);
+    }
+
+    for param in &params {
+        if matches!(&*param.ty, Type::Reference(_)) {
+            panic!(
+                "Tool parameters must be owned types, only ToolContext and CancellationToken can be borrowed"
+            );
+        }
+        // Fail early on patterns that cannot become property names
+        let _ = param_ident(param);
+    }
+    ToolInput::Params(params)
+}
+
+// Get the name of a tool parameter
+fn param_ident(param: &PatType) -> &syn::Ident {
+    match &*param.pat {
+        Pat::Ident(pat_ident) => &pat_ident.ident,
+        _ => panic!("Tool parameters must be plain identifiers, e.g. `a: f64`"),
+    }
+}
+
+// Local variable holding a deserialized parameter, so parameter names cannot
+// shadow variables of the generated handler
+fn param_binding(ident: &syn::Ident) -> syn::Ident {
+    format_ident!("__param_{}", ident)
+}
+
+// Name of the input struct synthesized for a tool, e.g. `AddNumbersInput`
+fn input_struct_ident(func: &ItemFn) -> syn::Ident {
+    let camel: String = func
+        .sig
+        .ident
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    format_ident!("{}Input", camel)
+}
+
+// Get the type deserialized from the request body
+fn input_type(func: &ItemFn, input: &ToolInput) -> proc_macro2::TokenStream {
+    match input {
+        ToolInput::Struct(ty) => quote!(#ty),
+        ToolInput::Params(_) => {
+            let ident = input_struct_ident(func);
+            quote!(#ident)
+        }
+    }
+}
+
+// Define the input struct synthesized from the tool parameters, if any
+fn input_struct(func: &ItemFn, input: &ToolInput) -> proc_macro2::TokenStream {
+    let ToolInput::Params(params) = input else {
+        return quote!();
+    };
+    let ident = input_struct_ident(func);
+    let fields = params.iter().map(|param| {
+        let attrs = &param.attrs;
+        let name = param_ident(param);
+        let ty = &param.ty;
+        quote! {
+            #(#attrs)*
+            #name: #ty,
+        }
+    });
+
+    quote! {
+        #[derive(::serde::Deserialize, ::schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct #ident {
+            #(#fields)*
+        }
+    }
+}
+
+// Pattern binding the deserialized input
+fn input_pattern(
+    input_type: &proc_macro2::TokenStream,
+    input: &ToolInput,
+) -> proc_macro2::TokenStream {
+    match input {
+        ToolInput::Struct(_) => quote!(input),
+        ToolInput::Params(params) => {
+            let fields = params.iter().map(|param| {
+                let name = param_ident(param);
+                let binding = param_binding(name);
+                quote!(#name: #binding)
+            });
+            quote!(#input_type { #(#fields),* })
+        }
+    }
+}
+
+// Whether a tool function takes the ToolContext
+fn has_context_arg(func: &ItemFn) -> bool {
+    func.sig
+        .inputs
+        .iter()
+        .any(|arg| matches!(arg, FnArg::Typed(pat_type) if is_context_type(&pat_type.ty)))
+}
+
+// Whether a tool function takes the CancellationToken
+fn has_cancellation_arg(func: &ItemFn) -> bool {
+    func.sig
+        .inputs
+        .iter()
+        .any(|arg| matches!(arg, FnArg::Typed(pat_type) if is_cancellation_type(&pat_type.ty)))
+}
+
+// Options set with `#[tool(...)]` on a tool function
+#[derive(Default)]
+struct ToolOptions {
+    /// Calls of the tool that may run at once, enforced by the gateway
+    max_concurrency: Option<syn::LitInt>,
+    /// Scopes the caller must be granted, enforced by the gateway
+    required_scopes: Option<Vec<syn::LitStr>>,
+}
+
+// The `_meta` of a tool's metadata, publishing the options the gateway
+// enforces
+fn tool_meta(options: &ToolOptions) -> proc_macro2::TokenStream {
+    let mut entries = Vec::new();
+    if let Some(scopes) = &options.required_scopes {
+        entries.push(quote!((::ftl_sdk::REQUIRED_SCOPES_META): [#(#scopes),*]));
+    }
+    if let Some(max) = &options.max_concurrency {
+        entries.push(quote!((::ftl_sdk::MAX_CONCURRENCY_META): #max));
+    }
+    if entries.is_empty() {
+        quote!(None)
+    } else {
+        quote!(Some(::serde_json::json!({ #(#entries),* })))
+    }
+}
+
+// Parse the `#[tool(...)]` attributes of a tool function
+fn tool_options(func: &ItemFn) -> ToolOptions {
+    let mut options = ToolOptions::default();
+    for attr in func
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("tool"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("max_concurrency") {
+                let max = meta.value()?.parse::<syn::LitInt>()?;
+                if max.base10_parse::<usize>()? == 0 {
+                    return Err(meta.error("max_concurrency must be at least 1"));
+                }
+                options.max_concurrency = Some(max);
+                Ok(())
+            } else if meta.path.is_ident("required_scopes") {
+                let scopes = meta.value()?.parse::<syn::ExprArray>()?;
+                let scopes = scopes
+                    .elems
+                    .into_iter()
+                    .map(|elem| match elem {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(scope),
+                            ..
+                        }) if !scope.value().trim().is_empty() => Ok(scope),
+                        other => Err(syn::Error::new_spanned(
+                            other,
+                            "required_scopes must be non-empty string literals",
+                        )),
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                options.required_scopes = Some(scopes);
+                Ok(())
+            } else {
+                Err(meta.error("expected `max_concurrency = N` or `required_scopes = [\"...\"]`"))
+            }
+        });
+        if let Err(e) = result {
+            panic!("Invalid #[tool] attribute on {}: {e}", func.sig.ident);
+        }
+    }
+    options
+}
+
+// Remove the `#[tool(...)]` attributes, which are handled by the macro, and
+// the attributes of input parameters, which are moved to the synthesized
+// input struct and are not valid on function parameters
+fn strip_param_attrs(func: &ItemFn) -> ItemFn {
+    let mut func = func.clone();
+    func.attrs.retain(|attr| !attr.path().is_ident("tool"));
+    if matches!(tool_input(&func), ToolInput::Params(_)) {
+        for arg in &mut func.sig.inputs {
+            if let FnArg::Typed(pat_type) = arg
+                && !is_context_type(&pat_type.ty)
+                && !is_cancellation_type(&pat_type.ty)
+            {
+                pat_type.attrs.clear();
+            }
+        }
+    }
+    func
 }
 
 // Parse multiple function definitions
@@ -201,6 +877,30 @@ impl syn::parse::Parse for ToolsDefinition {
     }
 }
 
+// Parse multiple scheduled handler definitions
+struct ScheduledDefinition {
+    functions: Vec<ItemFn>,
+}
+
+impl syn::parse::Parse for ScheduledDefinition {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut functions = Vec::new();
+
+        while !input.is_empty() {
+            functions.push(input.parse::<ItemFn>()?);
+        }
+
+        if functions.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "At least one scheduled handler must be defined",
+            ));
+        }
+
+        Ok(ScheduledDefinition { functions })
+    }
+}
+
 // Extract the first line of doc comments from attributes
 fn extract_doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
     attrs
@@ -219,3 +919,420 @@ fn extract_doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
         })
         .next()
 }
+
+// Convert a variant name to snake_case the way `#[serde(rename_all = "snake_case")]` does
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in name.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+        snake.push(ch.to_ascii_lowercase());
+    }
+    snake
+}
+
+// Get the `name = "value"` arguments of a `#[serde(...)]` attribute
+fn serde_args(attr: &syn::Attribute) -> std::collections::HashMap<String, String> {
+    let Ok(metas) = attr.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+    ) else {
+        return std::collections::HashMap::new();
+    };
+    metas
+        .iter()
+        .filter_map(|meta| match meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) => Some((nv.path.get_ident()?.to_string(), s.value())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    fn tool_fn(tokens: proc_macro2::TokenStream) -> ItemFn {
+        syn::parse2(tokens).expect("tool function should parse")
+    }
+
+    #[test]
+    fn test_tool_meta_publishes_gateway_options() {
+        let func = tool_fn(quote! {
+            #[tool(max_concurrency = 4, required_scopes = ["reports:write"])]
+            async fn render(input: ReportInput) -> ToolResponse {}
+        });
+        let meta = tool_meta(&tool_options(&func)).to_string();
+        assert!(meta.contains("REQUIRED_SCOPES_META"));
+        assert!(meta.contains("\"reports:write\""));
+        assert!(meta.contains("MAX_CONCURRENCY_META"));
+        assert!(meta.contains('4'));
+
+        let func = tool_fn(quote!(
+            fn ping() -> ToolResponse {}
+        ));
+        assert_eq!(tool_meta(&tool_options(&func)).to_string(), "None");
+    }
+
+    #[test]
+    #[should_panic(expected = "max_concurrency must be at least 1")]
+    fn test_zero_max_concurrency_is_rejected() {
+        let func = tool_fn(quote! {
+            #[tool(max_concurrency = 0)]
+            fn render(input: ReportInput) -> ToolResponse {}
+        });
+        let _ = tool_options(&func);
+    }
+
+    #[test]
+    fn test_cancellation_token_is_not_input() {
+        let func = tool_fn(quote! {
+            async fn crawl(input: CrawlInput, cancel: CancellationToken) -> ToolResponse {}
+        });
+        assert!(has_cancellation_arg(&func));
+        assert!(matches!(tool_input(&func), ToolInput::Struct(_)));
+
+        let func = tool_fn(quote! {
+            fn crawl(url: String, cancel: &CancellationToken) -> ToolResponse {}
+        });
+        assert!(has_cancellation_arg(&func));
+        let ToolInput::Params(params) = tool_input(&func) else {
+            panic!("parameters should be input properties");
+        };
+        assert_eq!(params.len(), 1);
+
+        let func = tool_fn(quote!(
+            fn crawl(url: String) -> ToolResponse {}
+        ));
+        assert!(!has_cancellation_arg(&func));
+    }
+
+    #[test]
+    fn test_single_struct_param_is_the_input() {
+        let func = tool_fn(quote!(
+            fn echo(input: EchoInput) -> ToolResponse {}
+        ));
+        let input = tool_input(&func);
+        assert!(matches!(input, ToolInput::Struct(_)));
+        assert!(input_struct(&func, &input).is_empty());
+        let input_type = input_type(&func, &input);
+        assert_eq!(input_type.to_string(), "EchoInput");
+        assert_eq!(input_pattern(&input_type, &input).to_string(), "input");
+
+        // The caller context is passed separately
+        let func = tool_fn(quote! {
+            fn echo(ctx: &ToolContext, input: ftl::EchoInput) -> ToolResponse {}
+        });
+        assert!(matches!(tool_input(&func), ToolInput::Struct(_)));
+    }
+
+    #[test]
+    fn test_scalar_and_multiple_params_are_properties() {
+        for func in [
+            quote!(
+                fn shout(text: String) -> ToolResponse {}
+            ),
+            quote!(
+                fn tag(tags: Vec<String>) -> ToolResponse {}
+            ),
+            quote!(
+                fn pair(pair: (f64, f64)) -> ToolResponse {}
+            ),
+            quote!(
+                fn lookup(id: Option<u64>) -> ToolResponse {}
+            ),
+            // Attributes only apply to properties
+            quote!(
+                fn configure(#[serde(default)] options: Options) -> ToolResponse {}
+            ),
+        ] {
+            let func = tool_fn(func);
+            let ToolInput::Params(params) = tool_input(&func) else {
+                panic!("{} should take its parameter as a property", func.sig.ident);
+            };
+            assert_eq!(params.len(), 1);
+        }
+
+        let func = tool_fn(quote!(
+            fn add(a: f64, b: Options) -> ToolResponse {}
+        ));
+        let ToolInput::Params(params) = tool_input(&func) else {
+            panic!("parameters should be input properties");
+        };
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn test_input_struct_ident() {
+        let ident = |func| input_struct_ident(&tool_fn(func)).to_string();
+        assert_eq!(
+            ident(quote!(
+                fn add_numbers() {}
+            )),
+            "AddNumbersInput"
+        );
+        assert_eq!(
+            ident(quote!(
+                fn ping() {}
+            )),
+            "PingInput"
+        );
+        assert_eq!(
+            ident(quote!(
+                fn get__weather_() {}
+            )),
+            "GetWeatherInput"
+        );
+        assert_eq!(
+            ident(quote!(
+                fn v2_search() {}
+            )),
+            "V2SearchInput"
+        );
+    }
+
+    #[test]
+    fn test_zero_param_tool() {
+        let func = tool_fn(quote!(
+            fn ping() -> ToolResponse {}
+        ));
+        let input = tool_input(&func);
+        assert!(matches!(&input, ToolInput::Params(params) if params.is_empty()));
+
+        let input_struct: syn::ItemStruct =
+            syn::parse2(input_struct(&func, &input)).expect("input struct should parse");
+        assert_eq!(input_struct.ident, "PingInput");
+        assert!(input_struct.fields.is_empty());
+
+        let input_type = input_type(&func, &input);
+        assert_eq!(
+            input_pattern(&input_type, &input).to_string(),
+            quote!(PingInput {}).to_string()
+        );
+    }
+
+    #[test]
+    fn test_input_struct_carries_param_attrs() {
+        let func = tool_fn(quote! {
+            fn add(
+                /// First number
+                a: f64,
+                #[serde(default)]
+                b: f64,
+                ctx: ToolContext,
+            ) -> ToolResponse {}
+        });
+        let input = tool_input(&func);
+        let input_struct: syn::ItemStruct =
+            syn::parse2(input_struct(&func, &input)).expect("input struct should parse");
+        assert_eq!(input_struct.ident, "AddInput");
+
+        let fields: Vec<_> = input_struct.fields.iter().collect();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(
+            fields
+                .first()
+                .and_then(|field| field.ident.as_ref())
+                .map(ToString::to_string),
+            Some("a".to_string())
+        );
+        assert!(
+            fields
+                .first()
+                .is_some_and(|field| field.attrs.iter().any(|attr| attr.path().is_ident("doc")))
+        );
+        assert!(
+            fields
+                .get(1)
+                .is_some_and(|field| field.attrs.iter().any(|attr| attr.path().is_ident("serde")))
+        );
+
+        let input_type = input_type(&func, &input);
+        assert_eq!(
+            input_pattern(&input_type, &input).to_string(),
+            quote!(AddInput {
+                a: __param_a,
+                b: __param_b
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_strip_param_attrs() {
+        let func = tool_fn(quote! {
+            /// Add two numbers
+            #[tool(required_scopes = ["math"])]
+            fn add(
+                /// First number
+                a: f64,
+                #[serde(default)]
+                b: f64,
+            ) -> ToolResponse {}
+        });
+        let stripped = strip_param_attrs(&func);
+        assert_eq!(stripped.attrs.len(), 1);
+        assert!(
+            stripped
+                .attrs
+                .iter()
+                .all(|attr| attr.path().is_ident("doc"))
+        );
+        assert!(stripped.sig.inputs.iter().all(|arg| match arg {
+            FnArg::Typed(pat_type) => pat_type.attrs.is_empty(),
+            FnArg::Receiver(_) => false,
+        }));
+
+        // Input structs keep their parameter as is
+        let func = tool_fn(quote!(
+            fn echo(input: EchoInput) -> ToolResponse {}
+        ));
+        assert_eq!(
+            strip_param_attrs(&func).to_token_stream().to_string(),
+            func.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be owned types")]
+    fn test_borrowed_params_are_rejected() {
+        let func = tool_fn(quote!(
+            fn shout(text: &str, times: u32) -> ToolResponse {}
+        ));
+        let _ = tool_input(&func);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be plain identifiers")]
+    fn test_param_patterns_are_rejected() {
+        let func = tool_fn(quote!(
+            fn add((a, b): (f64, f64), c: f64) -> ToolResponse {}
+        ));
+        let _ = tool_input(&func);
+    }
+
+    #[test]
+    fn test_expand_tools() {
+        let tools: ToolsDefinition = syn::parse2(quote! {
+            /// Add two numbers
+            fn add(a: f64, b: f64) -> ToolResponse {}
+
+            /// Crawl a site
+            #[tool(max_concurrency = 2)]
+            async fn crawl(input: CrawlInput, cancel: CancellationToken) -> ToolResponse {}
+        })
+        .expect("tools should parse");
+        let output = expand_tools(&tools);
+        let file: syn::File = syn::parse2(output.clone()).expect("expansion should parse");
+
+        let fns: Vec<String> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Fn(func) => Some(func.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fns, ["add", "crawl", "handle_tool_component"]);
+
+        let output = output.to_string();
+        assert!(output.contains("\"add\" =>"));
+        assert!(output.contains("\"crawl\" =>"));
+        assert!(output.contains("struct AddInput"));
+        assert!(output.contains("CancellationToken :: from_headers"));
+        // No tool takes the caller context
+        assert!(!output.contains("ToolContext :: from_headers"));
+        assert!(!output.contains("# [tool"));
+    }
+
+    #[test]
+    fn test_expand_scheduled() {
+        let single: ScheduledDefinition = syn::parse2(quote!(
+            fn nightly() {}
+        ))
+        .expect("handler should parse");
+        let output = expand_scheduled(&single).to_string();
+        assert!(output.contains("\"nightly\" =>"));
+        // A single handler runs without the schedule variable
+        assert!(output.contains("or_else"));
+        assert!(!output.contains("ScheduledEvent :: new"));
+
+        let several: ScheduledDefinition = syn::parse2(quote! {
+            async fn refresh(event: ScheduledEvent) -> Result<(), String> {}
+            fn nightly() {}
+        })
+        .expect("handlers should parse");
+        let output = expand_scheduled(&several);
+        let _: syn::File = syn::parse2(output.clone()).expect("expansion should parse");
+        let output = output.to_string();
+        assert!(output.contains("refresh (event) . await"));
+        assert!(output.contains("ScheduledEvent :: new"));
+        assert!(!output.contains("or_else"));
+    }
+
+    #[test]
+    #[should_panic(expected = "take no parameters or a ScheduledEvent")]
+    fn test_scheduled_params_are_rejected() {
+        let scheduled: ScheduledDefinition = syn::parse2(quote!(
+            fn nightly(limit: u32) {}
+        ))
+        .expect("handler should parse");
+        let _ = expand_scheduled(&scheduled);
+    }
+
+    #[test]
+    fn test_expand_tool_operations() {
+        let item: DeriveInput = syn::parse2(quote! {
+            enum MathInput {
+                Add { a: f64, b: f64 },
+                Negate(NegateInput),
+                #[serde(rename = "pi_value")]
+                Pi,
+            }
+        })
+        .expect("enum should parse");
+        let output = expand_tool_operations(&item, "op", false);
+        let _: syn::File = syn::parse2(output.clone()).expect("expansion should parse");
+        let output = output.to_string();
+        assert!(output.contains("tag = \"op\""));
+        assert!(output.contains(&quote!(&["add", "negate", "pi_value"]).to_string()));
+        assert!(output.contains(&quote!(Self::Add { a, b } => add(a, b),).to_string()));
+        assert!(output.contains(&quote!(Self::Negate(input) => negate(input),).to_string()));
+        assert!(output.contains(&quote!(Self::Pi => pi(),).to_string()));
+        assert!(output.contains("pub fn dispatch"));
+
+        let output = expand_tool_operations(&item, "operation", true).to_string();
+        assert!(output.contains("pub async fn dispatch"));
+        assert!(output.contains("add (a , b) . await"));
+    }
+
+    #[test]
+    #[should_panic(expected = "use #[serde(rename)] on a variant instead of rename_all")]
+    fn test_tool_operations_reject_rename_all() {
+        let item: DeriveInput = syn::parse2(quote! {
+            #[serde(rename_all = "camelCase")]
+            enum MathInput { Add { a: f64 } }
+        })
+        .expect("enum should parse");
+        let _ = expand_tool_operations(&item, "operation", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "tuple variants cannot be tagged")]
+    fn test_tool_operations_reject_tuple_variants() {
+        let item: DeriveInput = syn::parse2(quote!(
+            enum MathInput {
+                Add(f64, f64),
+            }
+        ))
+        .expect("enum should parse");
+        let _ = expand_tool_operations(&item, "operation", false);
+    }
+}
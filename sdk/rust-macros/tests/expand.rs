@@ -0,0 +1,7 @@
+//! Compile the macro expansions against the SDK
+
+#[test]
+fn test_expansions_compile() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/*.rs");
+}
@@ -0,0 +1,71 @@
+use ftl_sdk::{CancellationToken, ToolContext, ToolResponse};
+use ftl_sdk_macros::{tool_operations, tools};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+struct EchoInput {
+    /// The message to echo back
+    message: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct NegateInput {
+    value: f64,
+}
+
+/// Run a math operation
+#[tool_operations]
+enum MathInput {
+    /// Add two numbers
+    Add { a: f64, b: f64 },
+    Negate(NegateInput),
+    #[serde(rename = "pi_value")]
+    Pi,
+}
+
+fn add(a: f64, b: f64) -> ToolResponse {
+    ToolResponse::text(format!("{}", a + b))
+}
+
+fn negate(input: NegateInput) -> ToolResponse {
+    ToolResponse::text(format!("{}", -input.value))
+}
+
+fn pi() -> ToolResponse {
+    ToolResponse::text(format!("{}", std::f64::consts::PI))
+}
+
+tools! {
+    /// Echo back the input message
+    fn echo(input: EchoInput) -> ToolResponse {
+        ToolResponse::text(input.message)
+    }
+
+    /// Greet the caller
+    #[tool(required_scopes = ["greet"], max_concurrency = 2)]
+    fn greet(
+        /// Name to greet
+        name: String,
+        #[serde(default)]
+        excited: bool,
+        ctx: &ToolContext,
+    ) -> ToolResponse {
+        let _ = ctx;
+        ToolResponse::text(if excited { format!("Hi {name}!") } else { format!("Hi {name}") })
+    }
+
+    /// Check the gateway is up
+    async fn ping(cancel: CancellationToken) -> ToolResponse {
+        ToolResponse::text(if cancel.is_cancelled() { "late" } else { "pong" })
+    }
+
+    /// Run a math operation
+    fn math(input: MathInput) -> ToolResponse {
+        input.dispatch()
+    }
+}
+
+fn main() {
+    assert_eq!(MathInput::OPERATIONS, ["add", "negate", "pi_value"]);
+}
@@ -0,0 +1,64 @@
+//! Allocator presets for small tools.
+//!
+//! The default allocator of Rust's wasm targets is a port of dlmalloc.
+//! [`talc`] is smaller and faster on wasm, and
+//! [`global_allocator!`](crate::global_allocator) installs it as the global
+//! allocator of a tool:
+//!
+//! ```ignore
+//! // Static arena of `ARENA_SIZE` bytes
+//! ftl_sdk::global_allocator!();
+//!
+//! // Static arena of 32 KiB
+//! ftl_sdk::global_allocator!(arena = 32 * 1024);
+//!
+//! // No arena, the heap grows into wasm linear memory as needed
+//! ftl_sdk::global_allocator!(grow);
+//! ```
+//!
+//! A static arena adds no code to claim memory but fails allocations once it
+//! is full, so pick a size that covers the largest request and response the
+//! tool handles. The macro expands to `unsafe` code in the calling crate.
+
+pub use talc;
+
+/// Size in bytes of the arena of `global_allocator!()`: 64 KiB with the
+/// `talc-arena-small` feature, 4 MiB with `talc-arena-large` and 256 KiB
+/// otherwise. When both features are enabled the smaller arena wins.
+pub const ARENA_SIZE: usize = if cfg!(feature = "talc-arena-small") {
+    64 * 1024
+} else if cfg!(feature = "talc-arena-large") {
+    4 * 1024 * 1024
+} else {
+    256 * 1024
+};
+
+/// Install [`talc`] as the global allocator, with a static arena of
+/// [`ARENA_SIZE`] bytes, a static arena of the given size, or growing into
+/// wasm linear memory. See the [module documentation](self).
+#[macro_export]
+macro_rules! global_allocator {
+    () => {
+        $crate::global_allocator!(arena = $crate::allocator::ARENA_SIZE);
+    };
+    (arena = $size:expr) => {
+        #[global_allocator]
+        static FTL_ALLOCATOR: $crate::allocator::talc::Talck<
+            $crate::allocator::talc::locking::AssumeUnlockable,
+            $crate::allocator::talc::ClaimOnOom,
+        > = {
+            static mut FTL_ARENA: [u8; $size] = [0; $size];
+            $crate::allocator::talc::Talc::new(unsafe {
+                $crate::allocator::talc::ClaimOnOom::new($crate::allocator::talc::Span::from_array(
+                    ::core::ptr::addr_of_mut!(FTL_ARENA),
+                ))
+            })
+            .lock()
+        };
+    };
+    (grow) => {
+        #[global_allocator]
+        static FTL_ALLOCATOR: $crate::allocator::talc::TalckWasm =
+            unsafe { $crate::allocator::talc::TalckWasm::new_global() };
+    };
+}
@@ -0,0 +1,162 @@
+//! Cancellation and concurrency limits for tools.
+//!
+//! With the `tools!` macro, a tool can declare a `cancel: CancellationToken`
+//! parameter. The gateway stops waiting for a call once it runs over the
+//! component's `max_duration_secs`, and passes that deadline to the component
+//! in the [`DEADLINE_HEADER`](crate::DEADLINE_HEADER). The token is cancelled
+//! once the deadline passes, so a tool can stop early or return what it has
+//! instead of working for a caller that is gone.
+//!
+//! Cancellation is deadline-only: a client cancelling the request or going
+//! away does not reach the component, and a component without a
+//! `max_duration_secs` limit gets no deadline, so its tokens are only
+//! cancelled with [`CancellationToken::cancel`]:
+//!
+//! ```ignore
+//! tools! {
+//!     /// Crawl a site
+//!     #[tool(max_concurrency = 4)]
+//!     async fn crawl(input: CrawlInput, cancel: CancellationToken) -> ToolResponse {
+//!         let mut pages = Vec::new();
+//!         for url in input.urls {
+//!             if cancel.is_cancelled() {
+//!                 break;
+//!             }
+//!             pages.push(fetch(&url).await);
+//!         }
+//!         text!("Crawled {} pages", pages.len())
+//!     }
+//! }
+//! ```
+//!
+//! `#[tool(max_concurrency = N)]` publishes a cap on the calls of a tool
+//! running at once in the tool's `_meta`, under
+//! [`MAX_CONCURRENCY_META`](crate::MAX_CONCURRENCY_META). Each call runs in a
+//! fresh component instance, so the gateway counts the calls in flight and
+//! rejects calls over the cap as retryable.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::DEADLINE_HEADER;
+
+/// Signals that a tool call was abandoned.
+///
+/// The gateway only abandons a call at its deadline, see the
+/// [module documentation](self).
+///
+/// Clones share the same state, so cancelling one cancels all of them.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    deadline: Option<SystemTime>,
+}
+
+impl CancellationToken {
+    /// Create a token that is only cancelled with [`Self::cancel`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a token that is cancelled once `deadline` passes
+    pub fn with_deadline(deadline: SystemTime) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                cancelled: AtomicBool::new(false),
+                deadline: Some(deadline),
+            }),
+        }
+    }
+
+    /// Create a token from the headers of a tool call, cancelled at the
+    /// deadline the gateway sets in the [`DEADLINE_HEADER`], if any
+    pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        headers
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(DEADLINE_HEADER))
+            .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+            .map_or_else(Self::new, |millis| {
+                Self::with_deadline(UNIX_EPOCH + Duration::from_millis(millis))
+            })
+    }
+
+    /// Cancel the token
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Whether the token was cancelled or its deadline passed
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire) || self.remaining() == Some(Duration::ZERO)
+    }
+
+    /// Time at which the token is cancelled, if it has a deadline
+    pub fn deadline(&self) -> Option<SystemTime> {
+        self.inner.deadline
+    }
+
+    /// Time left until the deadline, zero once it passed, e.g. to bound the
+    /// requests a tool sends
+    pub fn remaining(&self) -> Option<Duration> {
+        self.inner.deadline.map(|deadline| {
+            deadline
+                .duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        assert_eq!(token.deadline(), None);
+        assert_eq!(token.remaining(), None);
+
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn test_deadline() {
+        let now = SystemTime::now();
+        let passed = CancellationToken::with_deadline(now - Duration::from_secs(1));
+        assert!(passed.is_cancelled());
+        assert_eq!(passed.remaining(), Some(Duration::ZERO));
+
+        let pending = CancellationToken::with_deadline(now + Duration::from_secs(60));
+        assert!(!pending.is_cancelled());
+        assert!(pending.remaining() > Some(Duration::from_secs(30)));
+        pending.cancel();
+        assert!(pending.is_cancelled());
+    }
+
+    #[test]
+    fn test_from_headers() {
+        let token = CancellationToken::from_headers([
+            ("content-type", "application/json"),
+            ("X-FTL-Deadline", "1700000000000"),
+        ]);
+        assert_eq!(
+            token.deadline(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        assert!(token.is_cancelled());
+
+        assert_eq!(CancellationToken::from_headers([]).deadline(), None);
+        let invalid = CancellationToken::from_headers([(DEADLINE_HEADER, "soon")]);
+        assert_eq!(invalid.deadline(), None);
+        assert!(!invalid.is_cancelled());
+    }
+}
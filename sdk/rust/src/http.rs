@@ -0,0 +1,770 @@
+//! Outbound HTTP client for tools.
+//!
+//! [`Client`] adds retries with backoff, JSON helpers and propagation of the
+//! caller's trace id and identity assertion on top of a [`Transport`]. With the
+//! `spin` feature, [`Client::new`] sends requests with Spin outbound HTTP:
+//!
+//! ```ignore
+//! use ftl_sdk::http::Client;
+//!
+//! let client = Client::new()
+//!     .base_url("https://api.weather.example")
+//!     .context(&ctx);
+//! let forecast: Forecast = match client.get("/forecast").query("city", &input.city).json().await {
+//!     Ok(forecast) => forecast,
+//!     Err(e) => return e.into(),
+//! };
+//! ```
+//!
+//! Destination hosts must still be listed in the component's
+//! `allowed_outbound_hosts`.
+
+use std::fmt::{self, Write};
+use std::future::Future;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{ASSERTION_HEADER, TRACE_ID_HEADER, ToolContext, ToolResponse};
+
+/// Maximum number of response body characters included in status errors
+const MAX_ERROR_BODY_CHARS: usize = 512;
+
+/// HTTP request method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// GET
+    Get,
+    /// HEAD
+    Head,
+    /// POST
+    Post,
+    /// PUT
+    Put,
+    /// PATCH
+    Patch,
+    /// DELETE
+    Delete,
+}
+
+impl Method {
+    /// The method name, e.g. `GET`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Head => "HEAD",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+
+    /// Whether repeating the request has the same effect as sending it once.
+    /// Only idempotent requests are retried unless marked retryable.
+    pub const fn is_idempotent(self) -> bool {
+        matches!(self, Self::Get | Self::Head | Self::Put | Self::Delete)
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An outbound request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// Request method
+    pub method: Method,
+    /// Absolute URL, including the query string
+    pub url: String,
+    /// Request headers
+    pub headers: Vec<(String, String)>,
+    /// Request body
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// The first value of a header (names are matched case-insensitively)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// A response to an outbound request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// The first value of a header (names are matched case-insensitively)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Whether the status is 2xx
+    pub const fn is_success(&self) -> bool {
+        self.status >= 200 && self.status < 300
+    }
+
+    /// The body as text, replacing invalid UTF-8
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Decode the body as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_slice(&self.body).map_err(|e| Error::Decode(e.to_string()))
+    }
+
+    /// Turn a non-2xx response into [`Error::Status`]
+    pub fn error_for_status(self, url: &str) -> Result<Self, Error> {
+        if self.is_success() {
+            return Ok(self);
+        }
+        let body: String = self.text().chars().take(MAX_ERROR_BODY_CHARS).collect();
+        Err(Error::Status {
+            status: self.status,
+            url: url.to_string(),
+            body,
+        })
+    }
+
+    /// Delay requested by a `Retry-After` header given in seconds
+    fn retry_after(&self) -> Option<Duration> {
+        self.header("retry-after")?
+            .trim()
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+    }
+}
+
+/// A failed outbound request.
+///
+/// Converts into an error [`ToolResponse`], so tools can return it directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The URL could not be resolved against the client's base URL
+    Url(String),
+    /// The request body could not be encoded
+    Encode(String),
+    /// The request could not be sent or no response was received
+    Transport(String),
+    /// The server responded with a non-2xx status
+    Status {
+        /// HTTP status code
+        status: u16,
+        /// URL of the request
+        url: String,
+        /// Start of the response body
+        body: String,
+    },
+    /// The response body could not be decoded
+    Decode(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Url(url) => write!(f, "invalid request URL '{url}': no base URL set"),
+            Self::Encode(e) => write!(f, "failed to encode request body: {e}"),
+            Self::Transport(e) => write!(f, "request failed: {e}"),
+            Self::Status { status, url, body } if body.trim().is_empty() => {
+                write!(f, "request to {url} returned {status}")
+            }
+            Self::Status { status, url, body } => {
+                write!(f, "request to {url} returned {status}: {}", body.trim())
+            }
+            Self::Decode(e) => write!(f, "invalid JSON response: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for ToolResponse {
+    fn from(error: Error) -> Self {
+        Self::error(error.to_string())
+    }
+}
+
+/// Retry behavior for failed requests.
+///
+/// Transport errors and 429, 502, 503 and 504 responses are retried with
+/// exponential backoff, honoring `Retry-After` up to `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub const fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Delay before the given retry (0 for the first retry)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
+
+/// Whether a response status is worth retrying
+const fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 502 | 503 | 504)
+}
+
+/// Sends requests for a [`Client`]
+pub trait Transport {
+    /// Send a request, returning the response or a description of why none
+    /// was received
+    fn send(&self, request: &Request) -> impl Future<Output = Result<Response, String>>;
+}
+
+/// Outbound HTTP client with retries, JSON helpers and header propagation
+#[derive(Debug, Clone)]
+pub struct Client<T> {
+    transport: T,
+    base_url: Option<String>,
+    headers: Vec<(String, String)>,
+    retry: RetryPolicy,
+    sleep: fn(Duration),
+}
+
+/// Sends requests with Spin outbound HTTP
+#[cfg(feature = "spin")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpinTransport;
+
+#[cfg(feature = "spin")]
+#[allow(clippy::future_not_send)]
+impl Transport for SpinTransport {
+    async fn send(&self, request: &Request) -> Result<Response, String> {
+        let method = match request.method {
+            Method::Get => spin_sdk::http::Method::Get,
+            Method::Head => spin_sdk::http::Method::Head,
+            Method::Post => spin_sdk::http::Method::Post,
+            Method::Put => spin_sdk::http::Method::Put,
+            Method::Patch => spin_sdk::http::Method::Patch,
+            Method::Delete => spin_sdk::http::Method::Delete,
+        };
+
+        let mut builder = spin_sdk::http::Request::builder();
+        builder.method(method).uri(request.url.as_str());
+        for (name, value) in &request.headers {
+            builder.header(name.as_str(), value.as_str());
+        }
+        builder.body(request.body.clone());
+
+        let response: spin_sdk::http::Response = spin_sdk::http::send(builder.build())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(Response {
+            status: *response.status(),
+            headers: response
+                .headers()
+                .filter_map(|(name, value)| {
+                    value
+                        .as_str()
+                        .map(|value| (name.to_string(), value.to_string()))
+                })
+                .collect(),
+            body: response.into_body(),
+        })
+    }
+}
+
+#[cfg(feature = "spin")]
+impl Client<SpinTransport> {
+    /// Create a client sending requests with Spin outbound HTTP
+    pub fn new() -> Self {
+        Self::with_transport(SpinTransport)
+    }
+}
+
+#[cfg(feature = "spin")]
+impl Default for Client<SpinTransport> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Transport> Client<T> {
+    /// Create a client sending requests with a transport
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport,
+            base_url: None,
+            headers: Vec::new(),
+            retry: RetryPolicy::default(),
+            sleep: std::thread::sleep,
+        }
+    }
+
+    /// Resolve relative request URLs against a base URL
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Send a header with every request
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send a bearer token with every request
+    pub fn bearer_auth(self, token: impl fmt::Display) -> Self {
+        self.header("authorization", format!("Bearer {token}"))
+    }
+
+    /// Set the retry policy
+    pub const fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Set the function used to wait between attempts
+    pub fn with_sleep(mut self, sleep: fn(Duration)) -> Self {
+        self.sleep = sleep;
+        self
+    }
+
+    /// Propagate the caller's trace id, so upstream logs can be correlated
+    /// with the tool call
+    pub fn context(self, ctx: &ToolContext) -> Self {
+        match &ctx.trace_id {
+            Some(trace_id) => self.header(TRACE_ID_HEADER, trace_id.as_str()),
+            None => self,
+        }
+    }
+
+    /// Forward the caller's signed identity assertion, so upstream services
+    /// can verify who the tool acts for. Only forward it to services you
+    /// trust with the caller's identity.
+    pub fn forward_identity(self, ctx: &ToolContext) -> Self {
+        match &ctx.assertion {
+            Some(assertion) => self.header(ASSERTION_HEADER, assertion.as_str()),
+            None => self,
+        }
+    }
+
+    /// Start a GET request
+    pub fn get(&self, url: &str) -> RequestBuilder<'_, T> {
+        self.request(Method::Get, url)
+    }
+
+    /// Start a POST request
+    pub fn post(&self, url: &str) -> RequestBuilder<'_, T> {
+        self.request(Method::Post, url)
+    }
+
+    /// Start a PUT request
+    pub fn put(&self, url: &str) -> RequestBuilder<'_, T> {
+        self.request(Method::Put, url)
+    }
+
+    /// Start a PATCH request
+    pub fn patch(&self, url: &str) -> RequestBuilder<'_, T> {
+        self.request(Method::Patch, url)
+    }
+
+    /// Start a DELETE request
+    pub fn delete(&self, url: &str) -> RequestBuilder<'_, T> {
+        self.request(Method::Delete, url)
+    }
+
+    /// Start a request
+    pub fn request(&self, method: Method, url: &str) -> RequestBuilder<'_, T> {
+        let (url, error) = match self.resolve(url) {
+            Ok(url) => (url, None),
+            Err(e) => (url.to_string(), Some(e)),
+        };
+        RequestBuilder {
+            client: self,
+            request: Request {
+                method,
+                url,
+                headers: self.headers.clone(),
+                body: Vec::new(),
+            },
+            retryable: method.is_idempotent(),
+            error,
+        }
+    }
+
+    /// Resolve a URL against the base URL
+    fn resolve(&self, url: &str) -> Result<String, Error> {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            return Ok(url.to_string());
+        }
+        let base = self
+            .base_url
+            .as_deref()
+            .ok_or_else(|| Error::Url(url.to_string()))?;
+        Ok(format!(
+            "{}/{}",
+            base.trim_end_matches('/'),
+            url.trim_start_matches('/')
+        ))
+    }
+}
+
+/// A request being built by a [`Client`]
+#[derive(Debug)]
+pub struct RequestBuilder<'a, T> {
+    client: &'a Client<T>,
+    request: Request,
+    retryable: bool,
+    error: Option<Error>,
+}
+
+#[allow(clippy::future_not_send)] // Spin runs components on a single thread
+impl<T: Transport> RequestBuilder<'_, T> {
+    /// Add a header, replacing client headers of the same name
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.request
+            .headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.request.headers.push((name, value.into()));
+        self
+    }
+
+    /// Send a bearer token
+    pub fn bearer_auth(self, token: impl fmt::Display) -> Self {
+        self.header("authorization", format!("Bearer {token}"))
+    }
+
+    /// Append a query parameter, percent-encoding the name and value
+    pub fn query(mut self, name: &str, value: impl fmt::Display) -> Self {
+        let separator = if self.request.url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        self.request.url = format!(
+            "{}{separator}{}={}",
+            self.request.url,
+            encode_query(name),
+            encode_query(&value.to_string())
+        );
+        self
+    }
+
+    /// Send a raw body
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.request.body = body.into();
+        self
+    }
+
+    /// Send a value encoded as JSON
+    pub fn json_body(mut self, value: &impl Serialize) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => {
+                self.request.body = body;
+                self.header("content-type", "application/json")
+            }
+            Err(e) => {
+                self.error
+                    .get_or_insert_with(|| Error::Encode(e.to_string()));
+                self
+            }
+        }
+    }
+
+    /// Whether the request may be retried. Defaults to whether the method is
+    /// idempotent; mark POST and PATCH requests retryable when the upstream
+    /// deduplicates them.
+    pub const fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Send the request, retrying per the client's policy. Responses of any
+    /// status are returned; see [`Response::error_for_status`].
+    pub async fn send(self) -> Result<Response, Error> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let client = self.client;
+        let retries = if self.retryable {
+            client.retry.max_retries
+        } else {
+            0
+        };
+
+        let mut retry = 0;
+        loop {
+            let result = client.transport.send(&self.request).await;
+            let retry_after = match &result {
+                Ok(response) if !is_retryable_status(response.status) => None,
+                Ok(response) => Some(response.retry_after()),
+                Err(_) => Some(None),
+            };
+            let Some(retry_after) = retry_after.filter(|_| retry < retries) else {
+                return result.map_err(Error::Transport);
+            };
+
+            let delay = retry_after.map_or_else(
+                || client.retry.backoff(retry),
+                |delay| delay.min(client.retry.max_backoff),
+            );
+            (client.sleep)(delay);
+            retry += 1;
+        }
+    }
+
+    /// Send the request and return the body as text, failing on non-2xx
+    /// responses
+    pub async fn text(self) -> Result<String, Error> {
+        let url = self.request.url.clone();
+        Ok(self.send().await?.error_for_status(&url)?.text())
+    }
+
+    /// Send the request and decode the JSON response, failing on non-2xx
+    /// responses
+    pub async fn json<R: DeserializeOwned>(self) -> Result<R, Error> {
+        let url = self.request.url.clone();
+        let request = self.header("accept", "application/json");
+        request.send().await?.error_for_status(&url)?.json()
+    }
+}
+
+/// The first value of a header in a list (names are matched case-insensitively)
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(existing, _)| existing.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Percent-encode a query string component
+fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Transport answering with canned results and recording requests
+    #[derive(Default)]
+    struct MockTransport {
+        responses: RefCell<VecDeque<Result<Response, String>>>,
+        requests: RefCell<Vec<Request>>,
+    }
+
+    impl MockTransport {
+        fn new(responses: Vec<Result<Response, String>>) -> Self {
+            Self {
+                responses: RefCell::new(responses.into()),
+                requests: RefCell::default(),
+            }
+        }
+    }
+
+    #[allow(clippy::future_not_send)]
+    impl Transport for MockTransport {
+        async fn send(&self, request: &Request) -> Result<Response, String> {
+            self.requests.borrow_mut().push(request.clone());
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .unwrap_or_else(|| Err("no more responses".to_string()))
+        }
+    }
+
+    fn response(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            headers: Vec::new(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    /// Run a future that never waits on I/O to completion
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn test_client(transport: MockTransport) -> Client<MockTransport> {
+        Client::with_transport(transport)
+            .base_url("https://api.example.com/v1/")
+            .with_sleep(|_| {})
+    }
+
+    #[test]
+    fn test_request_building() {
+        let ctx = ToolContext::from_headers([
+            ("x-trace-id", "trace-1"),
+            ("x-auth-assertion", "signed.jwt.value"),
+        ]);
+        let client = test_client(MockTransport::new(vec![Ok(response(200, "{}"))]))
+            .header("x-api-key", "key")
+            .context(&ctx)
+            .forward_identity(&ctx);
+
+        let result = block_on(
+            client
+                .post("/notes")
+                .query("q", "a b&c")
+                .query("limit", 10)
+                .header("X-Api-Key", "override")
+                .json_body(&json!({"title": "hi"}))
+                .send(),
+        );
+        assert!(result.is_ok());
+
+        let requests = client.transport.requests.borrow();
+        let Some(request) = requests.first() else {
+            assert!(!requests.is_empty(), "no request sent");
+            return;
+        };
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.url,
+            "https://api.example.com/v1/notes?q=a%20b%26c&limit=10"
+        );
+        assert_eq!(request.header("x-trace-id"), Some("trace-1"));
+        assert_eq!(request.header("x-auth-assertion"), Some("signed.jwt.value"));
+        assert_eq!(request.header("x-api-key"), Some("override"));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(request.body, br#"{"title":"hi"}"#);
+    }
+
+    #[test]
+    fn test_relative_url_without_base() {
+        let client = Client::with_transport(MockTransport::default());
+        let result = block_on(client.get("/notes").send());
+        assert_eq!(result, Err(Error::Url("/notes".to_string())));
+        assert!(client.transport.requests.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_retries() {
+        let limited = Response {
+            status: 429,
+            headers: vec![("Retry-After".to_string(), "1".to_string())],
+            body: Vec::new(),
+        };
+        let client = test_client(MockTransport::new(vec![
+            Err("connection reset".to_string()),
+            Ok(limited),
+            Ok(response(200, r#"{"ok":true}"#)),
+        ]));
+
+        let value: Result<serde_json::Value, _> = block_on(client.get("status").json());
+        assert_eq!(value, Ok(json!({"ok": true})));
+        assert_eq!(client.transport.requests.borrow().len(), 3);
+
+        // Non-idempotent requests are not retried unless marked retryable
+        let client = test_client(MockTransport::new(vec![
+            Ok(response(503, "")),
+            Ok(response(200, "")),
+        ]));
+        let result = block_on(client.post("jobs").send());
+        assert_eq!(result.map(|r| r.status), Ok(503));
+        let result = block_on(client.post("jobs").retryable(true).send());
+        assert_eq!(result.map(|r| r.status), Ok(200));
+
+        // Retries stop after max_retries
+        let client = test_client(MockTransport::new(vec![
+            Err("refused".to_string()),
+            Err("refused".to_string()),
+            Err("refused".to_string()),
+            Ok(response(200, "")),
+        ]));
+        let result = block_on(client.get("status").send());
+        assert_eq!(result, Err(Error::Transport("refused".to_string())));
+        assert_eq!(client.transport.requests.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(10), Duration::from_secs(2));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_errors() {
+        let client = test_client(MockTransport::new(vec![
+            Ok(response(404, "  note not found\n")),
+            Ok(response(200, "not json")),
+        ]));
+
+        let result = block_on(client.get("notes/1").text());
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err(
+                "request to https://api.example.com/v1/notes/1 returned 404: note not found"
+                    .to_string()
+            )
+        );
+
+        let result: Result<serde_json::Value, _> = block_on(client.get("notes/1").json());
+        assert!(matches!(result, Err(Error::Decode(_))));
+
+        let response = ToolResponse::from(Error::Transport("timed out".to_string()));
+        assert_eq!(response.is_error, Some(true));
+        assert!(matches!(
+            response.content.first(),
+            Some(crate::ToolContent::Text { text, .. }) if text == "request failed: timed out"
+        ));
+    }
+}
@@ -6,221 +6,245 @@
 //!
 //! # Features
 //!
-//! - `macros` - Enables the `tools!` macro for simplified tool development
+//! - `std` (default) - Enables the [`concurrency`] module
+//! - `json` (default) - Enables the `serde` based protocol types such as
+//!   [`ToolResponse`] and [`ToolMetadata`], and the [`http`] and [`state`]
+//!   modules
+//! - `macros` - Enables the `tools!` and `scheduled!` macros and the
+//!   `#[tool_operations]` attribute for simplified tool development
+//! - `spin` - Enables [`http::Client::new`], which sends outbound requests
+//!   with Spin, and [`state::State::open`], which stores state in a Spin
+//!   key-value store
+//! - `talc` - Enables the `global_allocator!` macro, which installs the
+//!   [talc](https://docs.rs/talc) allocator. Its default static arena is
+//!   256 KiB, or 64 KiB with `talc-arena-small` and 4 MiB with
+//!   `talc-arena-large`.
+//!
+//! Without default features the crate is `no_std` (it still needs `alloc`)
+//! and does not depend on `serde`. Responses and metadata can then be
+//! written with the [`minimal`] builders.
+//!
+//! Tools that need the caller identity can take a [`ToolContext`], and tools
+//! that should stop once the gateway gives up on them a
+//! [`CancellationToken`]. Scheduled handlers receive a [`ScheduledEvent`].
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 // Re-export macros when the feature is enabled
 #[cfg(feature = "macros")]
-pub use ftl_sdk_macros::tools;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-
-/// Tool metadata returned by GET requests to tool endpoints
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolMetadata {
-    /// The name of the tool (must be unique within the gateway)
-    pub name: String,
-
-    /// Optional human-readable title for the tool
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-
-    /// Optional description of what the tool does
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-
-    /// JSON Schema describing the expected input parameters
-    #[serde(rename = "inputSchema")]
-    pub input_schema: Value,
-
-    /// Optional JSON Schema describing the output format
-    #[serde(rename = "outputSchema", skip_serializing_if = "Option::is_none")]
-    pub output_schema: Option<Value>,
-
-    /// Optional annotations providing hints about tool behavior
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub annotations: Option<ToolAnnotations>,
-
-    /// Optional metadata for tool-specific extensions
-    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
-    pub meta: Option<Value>,
+pub use ftl_sdk_macros::{scheduled, tool_operations, tools};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+#[cfg(feature = "talc")]
+pub mod allocator;
+#[cfg(feature = "std")]
+pub mod concurrency;
+#[cfg(feature = "json")]
+pub mod http;
+pub mod minimal;
+#[cfg(feature = "json")]
+mod protocol;
+#[cfg(feature = "json")]
+pub mod state;
+
+#[cfg(feature = "std")]
+pub use concurrency::CancellationToken;
+#[cfg(feature = "json")]
+pub use protocol::{
+    ContentAnnotations, ResourceContents, ToolAnnotations, ToolContent, ToolMetadata, ToolResponse,
+};
+
+/// Header carrying the verified OAuth client id, set by the authorizer
+pub const CLIENT_ID_HEADER: &str = "x-auth-client-id";
+/// Header carrying the verified user (subject) id, set by the authorizer
+pub const USER_ID_HEADER: &str = "x-auth-user-id";
+/// Header carrying the verified token issuer, set by the authorizer
+pub const ISSUER_HEADER: &str = "x-auth-issuer";
+/// Header carrying the space-separated granted scopes, set by the authorizer
+pub const SCOPES_HEADER: &str = "x-auth-scopes";
+/// Header carrying the signed identity assertion, set by the authorizer
+pub const ASSERTION_HEADER: &str = "x-auth-assertion";
+/// Header carrying the request trace id
+pub const TRACE_ID_HEADER: &str = "x-trace-id";
+/// Header carrying the session key, set by the gateway
+pub const SESSION_KEY_HEADER: &str = "x-session-key";
+/// Header carrying the Unix time in milliseconds after which the gateway
+/// abandons the call, set by the gateway for components with a
+/// `max_duration_secs` limit
+pub const DEADLINE_HEADER: &str = "x-ftl-deadline";
+
+/// Per-call context available to tool functions.
+///
+/// The identity fields are populated from the headers the authorizer adds
+/// after verifying the caller's token, and are empty for public applications.
+/// With the `tools!` macro, declare a second `ctx: ToolContext` parameter to
+/// receive it.
+#[derive(Debug, Clone)]
+pub struct ToolContext {
+    /// OAuth client id of the caller
+    pub client_id: Option<String>,
+
+    /// User (subject) id of the caller
+    pub user_id: Option<String>,
+
+    /// Issuer of the caller's token
+    pub issuer: Option<String>,
+
+    /// Scopes granted to the caller
+    pub scopes: Vec<String>,
+
+    /// Signed identity assertion of the caller, when the authorizer forwards
+    /// one
+    pub assertion: Option<String>,
+
+    /// Trace id of the request
+    pub trace_id: Option<String>,
+
+    /// Opaque key that is stable across the calls of an MCP session, or of
+    /// the authenticated caller when the client does not use sessions. Scope
+    /// per-session state with it.
+    pub session_key: Option<String>,
+
+    /// Lookup for component variables
+    variables: fn(&str) -> Option<String>,
 }
 
-/// Annotations providing hints about tool behavior
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolAnnotations {
-    /// Optional title annotation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-
-    /// Hint that the tool is read-only (doesn't modify state)
-    #[serde(rename = "readOnlyHint", skip_serializing_if = "Option::is_none")]
-    pub read_only_hint: Option<bool>,
-
-    /// Hint that the tool may perform destructive operations
-    #[serde(rename = "destructiveHint", skip_serializing_if = "Option::is_none")]
-    pub destructive_hint: Option<bool>,
-
-    /// Hint that the tool is idempotent (same input → same output)
-    #[serde(rename = "idempotentHint", skip_serializing_if = "Option::is_none")]
-    pub idempotent_hint: Option<bool>,
-
-    /// Hint that the tool accepts open-world inputs
-    #[serde(rename = "openWorldHint", skip_serializing_if = "Option::is_none")]
-    pub open_world_hint: Option<bool>,
+impl Default for ToolContext {
+    fn default() -> Self {
+        Self {
+            client_id: None,
+            user_id: None,
+            issuer: None,
+            scopes: Vec::new(),
+            assertion: None,
+            trace_id: None,
+            session_key: None,
+            variables: |_| None,
+        }
+    }
 }
 
-/// Response format for tool execution (POST requests)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolResponse {
-    /// Array of content items returned by the tool
-    pub content: Vec<ToolContent>,
+impl ToolContext {
+    /// Build a context from request headers (names are matched case-insensitively)
+    pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut ctx = Self::default();
+        for (name, value) in headers {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            if name.eq_ignore_ascii_case(CLIENT_ID_HEADER) {
+                ctx.client_id = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(USER_ID_HEADER) {
+                ctx.user_id = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(ISSUER_HEADER) {
+                ctx.issuer = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(SCOPES_HEADER) {
+                ctx.scopes = value.split_whitespace().map(ToString::to_string).collect();
+            } else if name.eq_ignore_ascii_case(ASSERTION_HEADER) {
+                ctx.assertion = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(TRACE_ID_HEADER) {
+                ctx.trace_id = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(SESSION_KEY_HEADER) {
+                ctx.session_key = Some(value.to_string());
+            }
+        }
+        ctx
+    }
+
+    /// Set the function used to look up component variables
+    pub fn with_variables(mut self, lookup: fn(&str) -> Option<String>) -> Self {
+        self.variables = lookup;
+        self
+    }
 
-    /// Optional structured content matching the outputSchema
-    #[serde(rename = "structuredContent", skip_serializing_if = "Option::is_none")]
-    pub structured_content: Option<Value>,
+    /// Get a component variable, or `None` if it is not set
+    pub fn variable(&self, name: &str) -> Option<String> {
+        (self.variables)(name)
+    }
 
-    /// Indicates if this response represents an error
-    #[serde(rename = "isError", skip_serializing_if = "Option::is_none")]
-    pub is_error: Option<bool>,
-}
+    /// Whether the caller was authenticated by the authorizer
+    pub fn is_authenticated(&self) -> bool {
+        self.client_id.is_some() || self.user_id.is_some()
+    }
 
-/// Content types that can be returned by tools
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type")]
-pub enum ToolContent {
-    /// Text content
-    #[serde(rename = "text")]
-    Text {
-        /// The text content
-        text: String,
-        /// Optional annotations for this content
-        #[serde(skip_serializing_if = "Option::is_none")]
-        annotations: Option<ContentAnnotations>,
-    },
-
-    /// Image content
-    #[serde(rename = "image")]
-    Image {
-        /// Base64-encoded image data
-        data: String,
-        /// MIME type of the image (e.g., "image/png")
-        #[serde(rename = "mimeType")]
-        mime_type: String,
-        /// Optional annotations for this content
-        #[serde(skip_serializing_if = "Option::is_none")]
-        annotations: Option<ContentAnnotations>,
-    },
-
-    /// Audio content
-    #[serde(rename = "audio")]
-    Audio {
-        /// Base64-encoded audio data
-        data: String,
-        /// MIME type of the audio (e.g., "audio/wav")
-        #[serde(rename = "mimeType")]
-        mime_type: String,
-        /// Optional annotations for this content
-        #[serde(skip_serializing_if = "Option::is_none")]
-        annotations: Option<ContentAnnotations>,
-    },
-
-    /// Resource reference
-    #[serde(rename = "resource")]
-    Resource {
-        /// The resource contents
-        resource: ResourceContents,
-        /// Optional annotations for this content
-        #[serde(skip_serializing_if = "Option::is_none")]
-        annotations: Option<ContentAnnotations>,
-    },
+    /// Whether the caller was granted a scope
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
 }
 
-/// Annotations for content items
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContentAnnotations {
-    /// Target audience for this content
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub audience: Option<Vec<String>>,
+/// Key of the scopes a caller must be granted to call a tool, in the tool's
+/// `_meta`. The gateway rejects calls from callers missing any of them.
+pub const REQUIRED_SCOPES_META: &str = "requiredScopes";
 
-    /// Priority of this content (0.0 to 1.0)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<f32>,
-}
+/// Key of the number of calls of a tool that may run at once, in the tool's
+/// `_meta`. The gateway rejects calls over it as retryable.
+pub const MAX_CONCURRENCY_META: &str = "maxConcurrency";
 
-/// Resource contents for resource-type content
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceContents {
-    /// URI of the resource
-    pub uri: String,
+/// Component variable naming the scheduled handler a cron trigger runs, set
+/// by FTL on the component generated for each entry of `schedules`
+pub const SCHEDULE_VARIABLE: &str = "ftl_schedule";
 
-    /// MIME type of the resource
-    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
-    pub mime_type: Option<String>,
+/// A run of a scheduled handler.
+///
+/// With the `scheduled!` macro, declare an `event: ScheduledEvent` parameter
+/// to receive it.
+#[derive(Debug, Clone)]
+pub struct ScheduledEvent {
+    /// Name of the handler being run
+    pub handler: String,
 
-    /// Text content of the resource
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub text: Option<String>,
+    /// Time the cron trigger fired, in seconds since the Unix epoch
+    pub timestamp: u64,
 
-    /// Base64-encoded binary content of the resource
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub blob: Option<String>,
+    /// Lookup for component variables
+    variables: fn(&str) -> Option<String>,
 }
 
-// Convenience constructors
-impl ToolResponse {
-    /// Create a simple text response
-    pub fn text(text: impl Into<String>) -> Self {
+impl ScheduledEvent {
+    /// Create an event for a handler run at a timestamp
+    pub fn new(handler: impl Into<String>, timestamp: u64) -> Self {
         Self {
-            content: vec![ToolContent::Text {
-                text: text.into(),
-                annotations: None,
-            }],
-            structured_content: None,
-            is_error: None,
+            handler: handler.into(),
+            timestamp,
+            variables: |_| None,
         }
     }
 
-    /// Create an error response
-    pub fn error(error: impl Into<String>) -> Self {
-        Self {
-            content: vec![ToolContent::Text {
-                text: error.into(),
-                annotations: None,
-            }],
-            structured_content: None,
-            is_error: Some(true),
-        }
+    /// Set the function used to look up component variables
+    pub fn with_variables(mut self, lookup: fn(&str) -> Option<String>) -> Self {
+        self.variables = lookup;
+        self
     }
 
-    /// Create a response with structured content
-    pub fn with_structured(text: impl Into<String>, structured: Value) -> Self {
-        Self {
-            content: vec![ToolContent::Text {
-                text: text.into(),
-                annotations: None,
-            }],
-            structured_content: Some(structured),
-            is_error: None,
-        }
+    /// Get a component variable, or `None` if it is not set
+    pub fn variable(&self, name: &str) -> Option<String> {
+        (self.variables)(name)
     }
 }
 
-impl ToolContent {
-    /// Create a text content item
-    pub fn text(text: impl Into<String>) -> Self {
-        Self::Text {
-            text: text.into(),
-            annotations: None,
-        }
+/// Return types of scheduled handlers: `()` or `Result<(), E>` where the
+/// error is reported to the cron trigger with its `Display` output
+pub trait IntoScheduledResult {
+    /// Convert the handler's return value into the result of the run
+    fn into_scheduled_result(self) -> Result<(), String>;
+}
+
+impl IntoScheduledResult for () {
+    fn into_scheduled_result(self) -> Result<(), String> {
+        Ok(())
     }
+}
 
-    /// Create an image content item
-    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
-        Self::Image {
-            data: data.into(),
-            mime_type: mime_type.into(),
-            annotations: None,
-        }
+impl<E: Display> IntoScheduledResult for Result<(), E> {
+    fn into_scheduled_result(self) -> Result<(), String> {
+        self.map_err(|e| e.to_string())
     }
 }
 
@@ -249,53 +273,95 @@ macro_rules! structured {
     };
 }
 
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! image {
+    ($data:expr, $mime_type:expr) => {
+        $crate::ToolResponse::image($data, $mime_type)
+    };
+}
+
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! audio {
+    ($data:expr, $mime_type:expr) => {
+        $crate::ToolResponse::audio($data, $mime_type)
+    };
+}
+
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! resource_link {
+    ($uri:expr, $name:expr) => {
+        $crate::ToolResponse::with_content(vec![$crate::ToolContent::resource_link($uri, $name)])
+    };
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "macros")]
     use serde_json::json;
 
     use super::*;
 
     #[test]
-    fn test_tool_response_text() {
-        let response = ToolResponse::text("Hello, world!");
-        assert_eq!(response.content.len(), 1);
-        assert!(response.is_error.is_none());
+    fn test_tool_context_from_headers() {
+        let ctx = ToolContext::from_headers([
+            ("X-Auth-Client-Id", "client_123"),
+            ("x-auth-user-id", "user_456"),
+            ("x-auth-issuer", "https://tenant.authkit.app"),
+            ("x-auth-scopes", "read  write"),
+            ("x-trace-id", "trace-1"),
+            ("x-auth-assertion", "signed.jwt.value"),
+            ("x-session-key", "9c82999e1e7450ec948f2fbc5b2b4091"),
+            ("content-type", "application/json"),
+        ]);
+
+        assert_eq!(ctx.client_id.as_deref(), Some("client_123"));
+        assert_eq!(ctx.user_id.as_deref(), Some("user_456"));
+        assert_eq!(ctx.issuer.as_deref(), Some("https://tenant.authkit.app"));
+        assert_eq!(ctx.scopes, vec!["read", "write"]);
+        assert_eq!(ctx.trace_id.as_deref(), Some("trace-1"));
+        assert_eq!(ctx.assertion.as_deref(), Some("signed.jwt.value"));
+        assert_eq!(
+            ctx.session_key.as_deref(),
+            Some("9c82999e1e7450ec948f2fbc5b2b4091")
+        );
+        assert!(ctx.is_authenticated());
+        assert!(ctx.has_scope("write"));
+        assert!(!ctx.has_scope("admin"));
     }
 
     #[test]
-    fn test_tool_response_error() {
-        let response = ToolResponse::error("Something went wrong");
-        assert_eq!(response.is_error, Some(true));
+    fn test_tool_context_public() {
+        let ctx = ToolContext::from_headers([("x-auth-client-id", " ")]);
+        assert!(!ctx.is_authenticated());
+        assert!(ctx.scopes.is_empty());
+        assert_eq!(ctx.variable("api_key"), None);
+
+        let ctx = ctx.with_variables(|name| (name == "api_key").then(|| "secret".to_string()));
+        assert_eq!(ctx.variable("api_key").as_deref(), Some("secret"));
+        assert_eq!(ctx.variable("other"), None);
     }
 
     #[test]
-    fn test_serialization() {
-        let metadata = ToolMetadata {
-            name: "test-tool".to_string(),
-            title: None,
-            description: Some("A test tool".to_string()),
-            input_schema: json!({
-                "type": "object",
-                "properties": {
-                    "input": { "type": "string" }
-                }
-            }),
-            output_schema: None,
-            annotations: None,
-            meta: None,
-        };
+    fn test_scheduled_event() {
+        let event = ScheduledEvent::new("refresh_cache", 1_700_000_000);
+        assert_eq!(event.handler, "refresh_cache");
+        assert_eq!(event.variable("region"), None);
 
-        let Ok(json) = serde_json::to_string(&metadata) else {
-            // In tests, we can use assert! with a condition that will fail
-            assert!(
-                serde_json::to_string(&metadata).is_ok(),
-                "Failed to serialize metadata"
-            );
-            return;
-        };
-        assert!(json.contains("\"name\":\"test-tool\""));
-        assert!(!json.contains("\"title\""));
-        assert!(json.contains("\"description\":\"A test tool\""));
+        let event = event.with_variables(|name| (name == "region").then(|| "eu".to_string()));
+        assert_eq!(event.variable("region").as_deref(), Some("eu"));
+    }
+
+    #[test]
+    fn test_into_scheduled_result() {
+        assert_eq!(().into_scheduled_result(), Ok(()));
+        assert_eq!(Ok::<(), String>(()).into_scheduled_result(), Ok(()));
+        assert_eq!(
+            Err::<(), _>("upstream unavailable").into_scheduled_result(),
+            Err("upstream unavailable".to_string())
+        );
     }
 
     #[cfg(all(test, feature = "macros"))]
@@ -322,5 +388,25 @@ mod tests {
         let data = json!({"status": "ok"});
         let response = structured!(data.clone(), "Operation {}", "successful");
         assert_eq!(response.structured_content, Some(data));
+
+        // Test _meta entries
+        let response = text!("Page 1")
+            .with_meta("nextCursor", json!("page-2"))
+            .with_meta("source", json!("cache"));
+        let Ok(value) = serde_json::to_value(&response) else {
+            panic!("tool response should serialize");
+        };
+        assert_eq!(
+            value.get("_meta"),
+            Some(&json!({ "nextCursor": "page-2", "source": "cache" }))
+        );
+
+        // Test image! macro
+        let response = image!("iVBORw0KGgo=", "image/png");
+        assert!(matches!(
+            response.content.first(),
+            Some(ToolContent::Image { mime_type, .. }) if mime_type == "image/png"
+        ));
+        assert!(response.validate().is_ok());
     }
 }
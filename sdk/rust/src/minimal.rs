@@ -0,0 +1,350 @@
+//! Tool responses and metadata written without `serde`.
+//!
+//! [`Response`] and [`Metadata`] produce the same JSON as
+//! [`ToolResponse`](crate::ToolResponse) and
+//! [`ToolMetadata`](crate::ToolMetadata), but write it directly instead of
+//! going through `serde_json`. Structured content, `_meta` values and schemas
+//! are passed as JSON text, so a tool whose schema is written out at compile
+//! time needs neither `serde` nor `schemars`. Both are available without
+//! default features.
+//!
+//! ```
+//! use ftl_sdk::minimal::{Metadata, Response};
+//!
+//! let metadata = Metadata::new("echo", r#"{"type":"object"}"#).with_description("Echo a message");
+//! assert_eq!(
+//!     metadata.to_json(),
+//!     r#"{"name":"echo","description":"Echo a message","inputSchema":{"type":"object"}}"#
+//! );
+//!
+//! let response = Response::text("hello").with_structured_json(r#"{"echoed":true}"#);
+//! assert_eq!(
+//!     response.to_json(),
+//!     r#"{"content":[{"type":"text","text":"hello"}],"structuredContent":{"echoed":true}}"#
+//! );
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::REQUIRED_SCOPES_META;
+
+/// Response to a tool call, written as JSON without `serde`
+#[derive(Debug, Clone, Default)]
+pub struct Response {
+    /// JSON of the content items, separated by commas
+    content: String,
+
+    /// JSON of the structured content
+    structured: Option<String>,
+
+    /// Whether the response represents an error
+    is_error: bool,
+
+    /// Keys and JSON values of the response's `_meta`
+    meta: Vec<(String, String)>,
+}
+
+impl Response {
+    /// Create a simple text response
+    pub fn text(text: &str) -> Self {
+        Self::default().with_text(text)
+    }
+
+    /// Create an error response
+    pub fn error(message: &str) -> Self {
+        Self {
+            is_error: true,
+            ..Self::text(message)
+        }
+    }
+
+    /// Create an image response from base64-encoded data
+    pub fn image(data: &str, mime_type: &str) -> Self {
+        Self::default().with_binary("image", data, mime_type)
+    }
+
+    /// Create an audio response from base64-encoded data
+    pub fn audio(data: &str, mime_type: &str) -> Self {
+        Self::default().with_binary("audio", data, mime_type)
+    }
+
+    /// Add a text content item
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.start_item("text");
+        self.content.push_str(",\"text\":");
+        push_string(&mut self.content, text);
+        self.content.push('}');
+        self
+    }
+
+    /// Set the structured content, given as JSON text matching the tool's
+    /// output schema. The JSON is written as is, without validation.
+    pub fn with_structured_json(mut self, json: impl Into<String>) -> Self {
+        self.structured = Some(json.into());
+        self
+    }
+
+    /// Add an entry to the response's `_meta`, with its value given as JSON
+    /// text. The JSON is written as is, without validation.
+    pub fn with_meta_json(mut self, key: impl Into<String>, json: impl Into<String>) -> Self {
+        self.meta.push((key.into(), json.into()));
+        self
+    }
+
+    /// Write the response as JSON
+    pub fn to_json(&self) -> String {
+        let mut out = String::with_capacity(self.content.len() + 16);
+        out.push_str("{\"content\":[");
+        out.push_str(&self.content);
+        out.push(']');
+        if let Some(structured) = &self.structured {
+            out.push_str(",\"structuredContent\":");
+            out.push_str(structured);
+        }
+        if self.is_error {
+            out.push_str(",\"isError\":true");
+        }
+        push_meta(&mut out, &self.meta);
+        out.push('}');
+        out
+    }
+
+    fn with_binary(mut self, kind: &str, data: &str, mime_type: &str) -> Self {
+        self.start_item(kind);
+        self.content.push_str(",\"data\":");
+        push_string(&mut self.content, data);
+        self.content.push_str(",\"mimeType\":");
+        push_string(&mut self.content, mime_type);
+        self.content.push('}');
+        self
+    }
+
+    fn start_item(&mut self, kind: &str) {
+        if !self.content.is_empty() {
+            self.content.push(',');
+        }
+        self.content.push_str("{\"type\":");
+        push_string(&mut self.content, kind);
+    }
+}
+
+/// Tool metadata returned by GET requests to tool endpoints, written as JSON
+/// without `serde`
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    name: String,
+    title: Option<String>,
+    description: Option<String>,
+    input_schema: String,
+    output_schema: Option<String>,
+    meta: Vec<(String, String)>,
+}
+
+impl Metadata {
+    /// Create metadata for a tool, with its input schema given as JSON text
+    pub fn new(name: impl Into<String>, input_schema: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            title: None,
+            description: None,
+            input_schema: input_schema.into(),
+            output_schema: None,
+            meta: Vec::new(),
+        }
+    }
+
+    /// Set the human-readable title of the tool
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description of what the tool does
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the output schema of the tool, given as JSON text
+    pub fn with_output_schema(mut self, output_schema: impl Into<String>) -> Self {
+        self.output_schema = Some(output_schema.into());
+        self
+    }
+
+    /// Add an entry to the tool's `_meta`, with its value given as JSON text
+    pub fn with_meta_json(mut self, key: impl Into<String>, json: impl Into<String>) -> Self {
+        self.meta.push((key.into(), json.into()));
+        self
+    }
+
+    /// Require callers to be granted all of `scopes` to call the tool
+    pub fn with_required_scopes<'a>(self, scopes: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut json = String::from("[");
+        for (index, scope) in scopes.into_iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            push_string(&mut json, scope);
+        }
+        json.push(']');
+        self.with_meta_json(REQUIRED_SCOPES_META, json)
+    }
+
+    /// Write the metadata as JSON
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"name\":");
+        push_string(&mut out, &self.name);
+        if let Some(title) = &self.title {
+            out.push_str(",\"title\":");
+            push_string(&mut out, title);
+        }
+        if let Some(description) = &self.description {
+            out.push_str(",\"description\":");
+            push_string(&mut out, description);
+        }
+        out.push_str(",\"inputSchema\":");
+        out.push_str(&self.input_schema);
+        if let Some(output_schema) = &self.output_schema {
+            out.push_str(",\"outputSchema\":");
+            out.push_str(output_schema);
+        }
+        push_meta(&mut out, &self.meta);
+        out.push('}');
+        out
+    }
+}
+
+/// Write the metadata of the tools of a component as a JSON array
+pub fn metadata_json(tools: &[Metadata]) -> String {
+    let mut out = String::from("[");
+    for (index, tool) in tools.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&tool.to_json());
+    }
+    out.push(']');
+    out
+}
+
+/// Write the `_meta` member of an object, if it has any entries
+fn push_meta(out: &mut String, meta: &[(String, String)]) {
+    if meta.is_empty() {
+        return;
+    }
+    out.push_str(",\"_meta\":{");
+    for (index, (key, json)) in meta.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        push_string(out, key);
+        out.push(':');
+        out.push_str(json);
+    }
+    out.push('}');
+}
+
+/// Write `value` as a JSON string, escaped the way `serde_json` does
+fn push_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let code = u32::from(c);
+                out.push_str("\\u00");
+                for digit in [code >> 4, code & 0xf] {
+                    out.push(char::from_digit(digit, 16).unwrap_or('0'));
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn parse(json: &str) -> Value {
+        serde_json::from_str(json).unwrap_or(Value::Null)
+    }
+
+    #[test]
+    fn test_response_json() {
+        assert_eq!(
+            parse(&Response::text("Hello, world!").to_json()),
+            json!({"content": [{"type": "text", "text": "Hello, world!"}]})
+        );
+        assert_eq!(
+            parse(&Response::error("boom").to_json()),
+            json!({"content": [{"type": "text", "text": "boom"}], "isError": true})
+        );
+
+        let response = Response::image("iVBORw0KGgo=", "image/png")
+            .with_text("A pixel")
+            .with_structured_json(r#"{"width":1}"#)
+            .with_meta_json("nextCursor", r#""page-2""#);
+        assert_eq!(
+            parse(&response.to_json()),
+            json!({
+                "content": [
+                    {"type": "image", "data": "iVBORw0KGgo=", "mimeType": "image/png"},
+                    {"type": "text", "text": "A pixel"}
+                ],
+                "structuredContent": {"width": 1},
+                "_meta": {"nextCursor": "page-2"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_metadata_json() {
+        let echo = Metadata::new("echo", r#"{"type":"object"}"#)
+            .with_title("Echo")
+            .with_description("Echo a message")
+            .with_output_schema(r#"{"type":"string"}"#)
+            .with_required_scopes(["tools:read", "tools:echo"]);
+        let ping = Metadata::new("ping", "{}");
+        assert_eq!(
+            parse(&metadata_json(&[echo, ping])),
+            json!([
+                {
+                    "name": "echo",
+                    "title": "Echo",
+                    "description": "Echo a message",
+                    "inputSchema": {"type": "object"},
+                    "outputSchema": {"type": "string"},
+                    "_meta": {"requiredScopes": ["tools:read", "tools:echo"]}
+                },
+                {"name": "ping", "inputSchema": {}}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        for value in [
+            "plain",
+            "say \"hi\"",
+            "a\\b",
+            "line\nbreak\ttab\r",
+            "\u{0}\u{8}\u{c}\u{1f}",
+            "héllo ✓",
+        ] {
+            let mut out = String::new();
+            push_string(&mut out, value);
+            assert_eq!(out, serde_json::to_string(value).unwrap_or_default());
+        }
+    }
+}
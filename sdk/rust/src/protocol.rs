@@ -0,0 +1,635 @@
+//! MCP protocol types exchanged with the gateway.
+//!
+//! These types derive their JSON representation with `serde`, so they are
+//! only available with the `json` feature, which is enabled by default.
+//! Tools built without it can write responses with [`crate::minimal`].
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{MAX_CONCURRENCY_META, REQUIRED_SCOPES_META};
+
+/// Tool metadata returned by GET requests to tool endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolMetadata {
+    /// The name of the tool (must be unique within the gateway)
+    pub name: String,
+
+    /// Optional human-readable title for the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Optional description of what the tool does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// JSON Schema describing the expected input parameters
+    #[serde(rename = "inputSchema")]
+    pub input_schema: Value,
+
+    /// Optional JSON Schema describing the output format
+    #[serde(rename = "outputSchema", skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<Value>,
+
+    /// Optional annotations providing hints about tool behavior
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+
+    /// Optional metadata for tool-specific extensions
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+impl ToolMetadata {
+    /// Require callers to be granted all of `scopes` to call the tool
+    #[must_use]
+    pub fn with_required_scopes<S: Into<String>>(
+        self,
+        scopes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let scopes: Vec<Value> = scopes
+            .into_iter()
+            .map(|scope| Value::String(scope.into()))
+            .collect();
+        self.with_meta(REQUIRED_SCOPES_META, Value::Array(scopes))
+    }
+
+    /// Cap the calls of the tool running at once
+    #[must_use]
+    pub fn with_max_concurrency(self, max: u64) -> Self {
+        self.with_meta(MAX_CONCURRENCY_META, Value::from(max))
+    }
+
+    /// Set a key of `_meta`, keeping the others
+    fn with_meta(mut self, key: &str, value: Value) -> Self {
+        let mut meta = match self.meta.take() {
+            Some(Value::Object(meta)) => meta,
+            _ => serde_json::Map::new(),
+        };
+        meta.insert(key.to_string(), value);
+        self.meta = Some(Value::Object(meta));
+        self
+    }
+
+    /// Scopes a caller must be granted to call the tool
+    pub fn required_scopes(&self) -> Vec<&str> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.get(REQUIRED_SCOPES_META))
+            .and_then(Value::as_array)
+            .map(|scopes| scopes.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Calls of the tool that may run at once, if capped
+    pub fn max_concurrency(&self) -> Option<u64> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.get(MAX_CONCURRENCY_META))
+            .and_then(Value::as_u64)
+            .filter(|max| *max > 0)
+    }
+}
+
+/// Annotations providing hints about tool behavior
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolAnnotations {
+    /// Optional title annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Hint that the tool is read-only (doesn't modify state)
+    #[serde(rename = "readOnlyHint", skip_serializing_if = "Option::is_none")]
+    pub read_only_hint: Option<bool>,
+
+    /// Hint that the tool may perform destructive operations
+    #[serde(rename = "destructiveHint", skip_serializing_if = "Option::is_none")]
+    pub destructive_hint: Option<bool>,
+
+    /// Hint that the tool is idempotent (same input → same output)
+    #[serde(rename = "idempotentHint", skip_serializing_if = "Option::is_none")]
+    pub idempotent_hint: Option<bool>,
+
+    /// Hint that the tool accepts open-world inputs
+    #[serde(rename = "openWorldHint", skip_serializing_if = "Option::is_none")]
+    pub open_world_hint: Option<bool>,
+}
+
+/// Response format for tool execution (POST requests)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolResponse {
+    /// Array of content items returned by the tool
+    pub content: Vec<ToolContent>,
+
+    /// Optional structured content matching the outputSchema
+    #[serde(rename = "structuredContent", skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<Value>,
+
+    /// Indicates if this response represents an error
+    #[serde(rename = "isError", skip_serializing_if = "Option::is_none")]
+    pub is_error: Option<bool>,
+
+    /// Optional metadata passed through to the client, such as pagination
+    /// cursors or provenance
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+/// Content types that can be returned by tools
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ToolContent {
+    /// Text content
+    #[serde(rename = "text")]
+    Text {
+        /// The text content
+        text: String,
+        /// Optional annotations for this content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<ContentAnnotations>,
+    },
+
+    /// Image content
+    #[serde(rename = "image")]
+    Image {
+        /// Base64-encoded image data
+        data: String,
+        /// MIME type of the image (e.g., "image/png")
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Optional annotations for this content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<ContentAnnotations>,
+    },
+
+    /// Audio content
+    #[serde(rename = "audio")]
+    Audio {
+        /// Base64-encoded audio data
+        data: String,
+        /// MIME type of the audio (e.g., "audio/wav")
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Optional annotations for this content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<ContentAnnotations>,
+    },
+
+    /// Embedded resource
+    #[serde(rename = "resource")]
+    Resource {
+        /// The resource contents
+        resource: ResourceContents,
+        /// Optional annotations for this content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<ContentAnnotations>,
+    },
+
+    /// Link to a resource the client can fetch separately
+    #[serde(rename = "resource_link")]
+    ResourceLink {
+        /// URI of the resource
+        uri: String,
+        /// Name of the resource
+        name: String,
+        /// Optional human-readable title
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        /// Optional description of the resource
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// Optional MIME type of the resource
+        #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        /// Optional size of the resource in bytes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size: Option<u64>,
+        /// Optional annotations for this content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<ContentAnnotations>,
+    },
+}
+
+/// Annotations for content items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentAnnotations {
+    /// Target audience for this content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<Vec<String>>,
+
+    /// Priority of this content (0.0 to 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f32>,
+}
+
+/// Resource contents for resource-type content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceContents {
+    /// URI of the resource
+    pub uri: String,
+
+    /// MIME type of the resource
+    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Text content of the resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Base64-encoded binary content of the resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+}
+
+// Convenience constructors
+impl ToolResponse {
+    /// Create a simple text response
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            content: vec![ToolContent::Text {
+                text: text.into(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        }
+    }
+
+    /// Create an error response
+    pub fn error(error: impl Into<String>) -> Self {
+        Self {
+            content: vec![ToolContent::Text {
+                text: error.into(),
+                annotations: None,
+            }],
+            structured_content: None,
+            is_error: Some(true),
+            meta: None,
+        }
+    }
+
+    /// Create a response from content items
+    pub fn with_content(content: Vec<ToolContent>) -> Self {
+        Self {
+            content,
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        }
+    }
+
+    /// Create an image response from base64-encoded data
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::with_content(vec![ToolContent::image(data, mime_type)])
+    }
+
+    /// Create an audio response from base64-encoded data
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::with_content(vec![ToolContent::audio(data, mime_type)])
+    }
+
+    /// Check that every content item is well-formed.
+    ///
+    /// The error names the offending item, e.g. `content[1]: image data is not valid base64`.
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, item) in self.content.iter().enumerate() {
+            item.validate()
+                .map_err(|e| format!("content[{index}]: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// Create a response with structured content
+    pub fn with_structured(text: impl Into<String>, structured: Value) -> Self {
+        Self {
+            content: vec![ToolContent::Text {
+                text: text.into(),
+                annotations: None,
+            }],
+            structured_content: Some(structured),
+            is_error: None,
+            meta: None,
+        }
+    }
+
+    /// Add an entry to the response's `_meta`, which the gateway passes
+    /// through to the client
+    ///
+    /// ```
+    /// use ftl_sdk::ToolResponse;
+    /// use serde_json::json;
+    ///
+    /// let response = ToolResponse::text("10 of 42 results").with_meta("nextCursor", json!("page-2"));
+    /// assert_eq!(response.meta, Some(json!({ "nextCursor": "page-2" })));
+    /// ```
+    #[must_use]
+    pub fn with_meta(mut self, key: impl Into<String>, value: Value) -> Self {
+        let meta = self
+            .meta
+            .get_or_insert_with(|| Value::Object(serde_json::Map::new()));
+        if let Value::Object(entries) = meta {
+            entries.insert(key.into(), value);
+        }
+        self
+    }
+}
+
+impl ToolContent {
+    /// Create a text content item
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create an image content item from base64-encoded data
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: data.into(),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create an audio content item from base64-encoded data
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create an embedded text resource
+    pub fn resource_text(
+        uri: impl Into<String>,
+        text: impl Into<String>,
+        mime_type: Option<String>,
+    ) -> Self {
+        Self::Resource {
+            resource: ResourceContents {
+                uri: uri.into(),
+                mime_type,
+                text: Some(text.into()),
+                blob: None,
+            },
+            annotations: None,
+        }
+    }
+
+    /// Create an embedded binary resource from base64-encoded data
+    pub fn resource_blob(
+        uri: impl Into<String>,
+        blob: impl Into<String>,
+        mime_type: Option<String>,
+    ) -> Self {
+        Self::Resource {
+            resource: ResourceContents {
+                uri: uri.into(),
+                mime_type,
+                text: None,
+                blob: Some(blob.into()),
+            },
+            annotations: None,
+        }
+    }
+
+    /// Create a link to a resource
+    pub fn resource_link(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self::ResourceLink {
+            uri: uri.into(),
+            name: name.into(),
+            title: None,
+            description: None,
+            mime_type: None,
+            size: None,
+            annotations: None,
+        }
+    }
+
+    /// Check that the content item is well-formed: binary data is base64,
+    /// MIME types match the content type and resources have a URI and
+    /// exactly one of `text` or `blob`.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Text { .. } => Ok(()),
+            Self::Image {
+                data, mime_type, ..
+            } => validate_binary("image", data, mime_type),
+            Self::Audio {
+                data, mime_type, ..
+            } => validate_binary("audio", data, mime_type),
+            Self::Resource { resource, .. } => {
+                if resource.uri.trim().is_empty() {
+                    return Err("resource uri is empty".to_string());
+                }
+                match (&resource.text, &resource.blob) {
+                    (Some(_), None) => Ok(()),
+                    (None, Some(blob)) if is_base64(blob) => Ok(()),
+                    (None, Some(_)) => Err("resource blob is not valid base64".to_string()),
+                    _ => Err("resource must have exactly one of text or blob".to_string()),
+                }
+            }
+            Self::ResourceLink { uri, name, .. } => {
+                if uri.trim().is_empty() {
+                    return Err("resource_link uri is empty".to_string());
+                }
+                if name.trim().is_empty() {
+                    return Err("resource_link name is empty".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Validate base64 data and a MIME type of the given top-level type
+fn validate_binary(kind: &str, data: &str, mime_type: &str) -> Result<(), String> {
+    let expected = format!("{kind}/");
+    if !mime_type.starts_with(&expected) || mime_type.len() == expected.len() {
+        return Err(format!(
+            "{kind} mimeType '{mime_type}' must be of the form '{kind}/<subtype>'"
+        ));
+    }
+    if data.is_empty() {
+        return Err(format!("{kind} data is empty"));
+    }
+    if !is_base64(data) {
+        return Err(format!("{kind} data is not valid base64"));
+    }
+    Ok(())
+}
+
+/// Whether `data` is standard base64, with or without padding
+fn is_base64(data: &str) -> bool {
+    let unpadded = data.trim_end_matches('=');
+    let padding = data.len() - unpadded.len();
+    if padding > 2 || (padding > 0 && !data.len().is_multiple_of(4)) || unpadded.len() % 4 == 1 {
+        return false;
+    }
+    unpadded
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'/')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_tool_response_text() {
+        let response = ToolResponse::text("Hello, world!");
+        assert_eq!(response.content.len(), 1);
+        assert!(response.is_error.is_none());
+    }
+
+    #[test]
+    fn test_tool_response_error() {
+        let response = ToolResponse::error("Something went wrong");
+        assert_eq!(response.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let metadata = ToolMetadata {
+            name: "test-tool".to_string(),
+            title: None,
+            description: Some("A test tool".to_string()),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "input": { "type": "string" }
+                }
+            }),
+            output_schema: None,
+            annotations: None,
+            meta: None,
+        };
+
+        let Ok(json) = serde_json::to_string(&metadata) else {
+            // In tests, we can use assert! with a condition that will fail
+            assert!(
+                serde_json::to_string(&metadata).is_ok(),
+                "Failed to serialize metadata"
+            );
+            return;
+        };
+        assert!(json.contains("\"name\":\"test-tool\""));
+        assert!(!json.contains("\"title\""));
+        assert!(json.contains("\"description\":\"A test tool\""));
+    }
+
+    #[test]
+    fn test_required_scopes() {
+        let metadata = ToolMetadata {
+            name: "delete_user".to_string(),
+            title: None,
+            description: None,
+            input_schema: json!({"type": "object"}),
+            output_schema: None,
+            annotations: None,
+            meta: Some(json!({"owner": "billing"})),
+        };
+        assert!(metadata.required_scopes().is_empty());
+
+        let metadata = metadata.with_required_scopes(["users:write", "admin"]);
+        assert_eq!(metadata.required_scopes(), vec!["users:write", "admin"]);
+        assert_eq!(
+            metadata.meta,
+            Some(json!({"owner": "billing", "requiredScopes": ["users:write", "admin"]}))
+        );
+    }
+
+    #[test]
+    fn test_max_concurrency() {
+        let metadata = ToolMetadata {
+            name: "crawl".to_string(),
+            title: None,
+            description: None,
+            input_schema: json!({"type": "object"}),
+            output_schema: None,
+            annotations: None,
+            meta: None,
+        };
+        assert_eq!(metadata.max_concurrency(), None);
+
+        let metadata = metadata.with_max_concurrency(4);
+        assert_eq!(metadata.max_concurrency(), Some(4));
+        assert_eq!(metadata.meta, Some(json!({"maxConcurrency": 4})));
+
+        let metadata = metadata.with_max_concurrency(0);
+        assert_eq!(metadata.max_concurrency(), None);
+    }
+
+    #[test]
+    fn test_content_validation() {
+        assert!(ToolContent::text("hello").validate().is_ok());
+        assert!(
+            ToolContent::image("iVBORw0KGgo=", "image/png")
+                .validate()
+                .is_ok()
+        );
+        assert!(ToolContent::audio("UklGRg", "audio/wav").validate().is_ok());
+        assert!(
+            ToolContent::resource_blob("file:///a.bin", "AAEC", None)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            ToolContent::resource_link("https://example.com/a.pdf", "a.pdf")
+                .validate()
+                .is_ok()
+        );
+
+        assert!(
+            ToolContent::image("not base64!", "image/png")
+                .validate()
+                .is_err()
+        );
+        assert!(ToolContent::image("AAEC", "text/plain").validate().is_err());
+        assert!(ToolContent::image("AAEC", "image/").validate().is_err());
+        assert!(ToolContent::audio("", "audio/wav").validate().is_err());
+        assert!(
+            ToolContent::resource_text("", "text", None)
+                .validate()
+                .is_err()
+        );
+        assert!(ToolContent::resource_link("uri", " ").validate().is_err());
+
+        let both = ToolContent::Resource {
+            resource: ResourceContents {
+                uri: "file:///a".to_string(),
+                mime_type: None,
+                text: Some("a".to_string()),
+                blob: Some("AAEC".to_string()),
+            },
+            annotations: None,
+        };
+        assert!(both.validate().is_err());
+
+        let response = ToolResponse::with_content(vec![
+            ToolContent::text("ok"),
+            ToolContent::image("A", "image/png"),
+        ]);
+        assert_eq!(
+            response.validate(),
+            Err("content[1]: image data is not valid base64".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_base64() {
+        assert!(is_base64("AAEC"));
+        assert!(is_base64("AAE="));
+        assert!(is_base64("AA=="));
+        assert!(is_base64("AAE"));
+        assert!(!is_base64("A"));
+        assert!(!is_base64("AA="));
+        assert!(!is_base64("A==="));
+        assert!(!is_base64("AA-_"));
+    }
+}
@@ -0,0 +1,411 @@
+//! Namespaced key-value state for tools.
+//!
+//! [`State`] stores serde values under a namespace, usually the tool name, so
+//! tools sharing a key-value store can't overwrite each other's keys. Values
+//! can expire after a TTL and be updated with compare-and-swap. With the
+//! `spin` feature, [`State::open`] uses a Spin key-value store:
+//!
+//! ```ignore
+//! use ftl_sdk::state::State;
+//!
+//! let state = State::open("cache", "forecast")?;
+//! let hits: u64 = state.get("hits")?.unwrap_or(0);
+//! state.set("hits", &(hits + 1))?;
+//! state.set_with_ttl(&input.city, &forecast, Duration::from_secs(600))?;
+//! ```
+//!
+//! The store must be listed in the component's `key_value_stores` in
+//! `ftl.yaml`.
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::ToolResponse;
+
+/// Separator between the namespace and the key in stored keys
+const NAMESPACE_SEPARATOR: char = ':';
+
+/// A key-value store holding raw values
+pub trait Backend {
+    /// Value of a key, `None` when the key doesn't exist
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String>;
+
+    /// Set the value of a key
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), String>;
+
+    /// Delete a key, succeeding when it doesn't exist
+    fn delete(&self, key: &str) -> Result<(), String>;
+
+    /// All keys of the store
+    fn keys(&self) -> Result<Vec<String>, String>;
+
+    /// Set the value of a key only if it is still `current` (`None` when the
+    /// key must not exist), returning whether it was set.
+    ///
+    /// The default implementation reads then writes, which is not atomic
+    /// across concurrently running instances. Stores with atomic operations
+    /// should override it.
+    fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        if self.get(key)?.as_deref() != current {
+            return Ok(false);
+        }
+        self.set(key, value)?;
+        Ok(true)
+    }
+}
+
+/// Error of a state operation.
+///
+/// Converts into an error [`ToolResponse`], so tools can return it directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The namespace is empty or contains the separator `:`
+    Namespace(String),
+    /// The store could not be opened or accessed
+    Store(String),
+    /// The value could not be encoded
+    Encode(String),
+    /// The stored value could not be decoded into the requested type
+    Decode(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Namespace(namespace) => write!(
+                f,
+                "invalid state namespace '{namespace}': must be non-empty and not contain '{NAMESPACE_SEPARATOR}'"
+            ),
+            Self::Store(e) => write!(f, "key-value store error: {e}"),
+            Self::Encode(e) => write!(f, "failed to encode state value: {e}"),
+            Self::Decode(e) => write!(f, "failed to decode state value: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for ToolResponse {
+    fn from(error: Error) -> Self {
+        Self::error(error.to_string())
+    }
+}
+
+/// Stored form of a value, with its expiry in milliseconds since the epoch
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<u64>,
+}
+
+/// Typed state of one namespace in a key-value store
+#[derive(Debug, Clone)]
+pub struct State<B> {
+    backend: B,
+    prefix: String,
+    now: fn() -> u64,
+}
+
+/// A Spin key-value store
+#[cfg(feature = "spin")]
+pub struct SpinStore(spin_sdk::key_value::Store);
+
+#[cfg(feature = "spin")]
+impl fmt::Debug for SpinStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SpinStore")
+    }
+}
+
+#[cfg(feature = "spin")]
+impl Backend for SpinStore {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        self.0.get(key).map_err(|e| e.to_string())
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), String> {
+        self.0.set(key, value).map_err(|e| e.to_string())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), String> {
+        self.0.delete(key).map_err(|e| e.to_string())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        self.0.get_keys().map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "spin")]
+impl State<SpinStore> {
+    /// Open the namespace of a Spin key-value store, by its label in
+    /// `key_value_stores`
+    pub fn open(store: &str, namespace: &str) -> Result<Self, Error> {
+        let store =
+            spin_sdk::key_value::Store::open(store).map_err(|e| Error::Store(e.to_string()))?;
+        Self::with_backend(SpinStore(store), namespace)
+    }
+}
+
+/// Current time in milliseconds since the epoch
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
+impl<B: Backend> State<B> {
+    /// Use a namespace of a store
+    pub fn with_backend(backend: B, namespace: &str) -> Result<Self, Error> {
+        if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+            return Err(Error::Namespace(namespace.to_string()));
+        }
+        Ok(Self {
+            backend,
+            prefix: format!("{namespace}{NAMESPACE_SEPARATOR}"),
+            now: unix_millis,
+        })
+    }
+
+    /// Set the function returning the current time in milliseconds since the
+    /// epoch, used to expire values
+    pub fn with_clock(mut self, now: fn() -> u64) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// Key of a value in the store
+    fn store_key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+
+    /// Read the raw and decoded entry of a key. Expired entries are deleted
+    /// and read as missing.
+    fn read(&self, key: &str) -> Result<Option<(Vec<u8>, Entry)>, Error> {
+        let Some(raw) = self.backend.get(key).map_err(Error::Store)? else {
+            return Ok(None);
+        };
+        let entry: Entry =
+            serde_json::from_slice(&raw).map_err(|e| Error::Decode(e.to_string()))?;
+        if entry.expires_at.is_some_and(|at| at <= (self.now)()) {
+            self.backend.delete(key).map_err(Error::Store)?;
+            return Ok(None);
+        }
+        Ok(Some((raw, entry)))
+    }
+
+    fn encode(value: &impl Serialize, expires_at: Option<u64>) -> Result<Vec<u8>, Error> {
+        let value = serde_json::to_value(value).map_err(|e| Error::Encode(e.to_string()))?;
+        serde_json::to_vec(&Entry { value, expires_at }).map_err(|e| Error::Encode(e.to_string()))
+    }
+
+    /// Value of a key, `None` when it doesn't exist or has expired
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, Error> {
+        self.read(&self.store_key(key))?
+            .map(|(_, entry)| {
+                serde_json::from_value(entry.value).map_err(|e| Error::Decode(e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Set the value of a key
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<(), Error> {
+        let data = Self::encode(value, None)?;
+        self.backend
+            .set(&self.store_key(key), &data)
+            .map_err(Error::Store)
+    }
+
+    /// Set the value of a key, which expires after `ttl`.
+    ///
+    /// Expired values read as missing and are deleted when read; Spin stores
+    /// have no expiry of their own.
+    pub fn set_with_ttl<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        ttl: Duration,
+    ) -> Result<(), Error> {
+        let ttl = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        let data = Self::encode(value, Some((self.now)().saturating_add(ttl)))?;
+        self.backend
+            .set(&self.store_key(key), &data)
+            .map_err(Error::Store)
+    }
+
+    /// Delete a key
+    pub fn delete(&self, key: &str) -> Result<(), Error> {
+        self.backend
+            .delete(&self.store_key(key))
+            .map_err(Error::Store)
+    }
+
+    /// Set the value of a key only if its value is still `current` (`None`
+    /// when the key must not exist or has expired), returning whether it was
+    /// set. The new value doesn't expire.
+    ///
+    /// See [`Backend::compare_and_swap`] for the atomicity of the swap.
+    pub fn compare_and_swap<T: Serialize>(
+        &self,
+        key: &str,
+        current: Option<&T>,
+        value: &T,
+    ) -> Result<bool, Error> {
+        let key = self.store_key(key);
+        let stored = self.read(&key)?;
+        let expected = current
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::Encode(e.to_string()))?;
+        if stored.as_ref().map(|(_, entry)| &entry.value) != expected.as_ref() {
+            return Ok(false);
+        }
+
+        let data = Self::encode(value, None)?;
+        self.backend
+            .compare_and_swap(&key, stored.as_ref().map(|(raw, _)| raw.as_slice()), &data)
+            .map_err(Error::Store)
+    }
+
+    /// Keys of the namespace, including expired keys that haven't been read
+    /// since they expired
+    pub fn keys(&self) -> Result<Vec<String>, Error> {
+        Ok(self
+            .backend
+            .keys()
+            .map_err(Error::Store)?
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&self.prefix).map(ToString::to_string))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Store keeping values in memory
+    #[derive(Default)]
+    struct MemoryStore(RefCell<BTreeMap<String, Vec<u8>>>);
+
+    impl Backend for &MemoryStore {
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+            Ok(self.0.borrow().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: &[u8]) -> Result<(), String> {
+            self.0.borrow_mut().insert(key.to_string(), value.to_vec());
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), String> {
+            self.0.borrow_mut().remove(key);
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, String> {
+            Ok(self.0.borrow().keys().cloned().collect())
+        }
+    }
+
+    #[test]
+    fn test_namespaces() -> Result<(), Error> {
+        let store = MemoryStore::default();
+        let weather = State::with_backend(&store, "weather")?;
+        let notes = State::with_backend(&store, "notes")?;
+
+        assert_eq!(weather.set("count", &1), Ok(()));
+        assert_eq!(notes.set("count", &json!({"n": 2})), Ok(()));
+        assert_eq!(weather.get::<u32>("count"), Ok(Some(1)));
+        assert_eq!(notes.get("count"), Ok(Some(json!({"n": 2}))));
+        assert_eq!(weather.keys(), Ok(vec!["count".to_string()]));
+
+        assert_eq!(weather.delete("count"), Ok(()));
+        assert_eq!(weather.get::<u32>("count"), Ok(None));
+        assert_eq!(notes.keys(), Ok(vec!["count".to_string()]));
+
+        assert!(matches!(notes.get::<u32>("count"), Err(Error::Decode(_))));
+        assert!(matches!(
+            State::with_backend(&store, "a:b"),
+            Err(Error::Namespace(_))
+        ));
+        assert!(matches!(
+            State::with_backend(&store, ""),
+            Err(Error::Namespace(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ttl() -> Result<(), Error> {
+        thread_local! {
+            static NOW: Cell<u64> = const { Cell::new(1_000) };
+        }
+        let store = MemoryStore::default();
+        let state = State::with_backend(&store, "cache")?.with_clock(|| NOW.with(Cell::get));
+
+        assert_eq!(
+            state.set_with_ttl("city", &"Paris", Duration::from_secs(1)),
+            Ok(())
+        );
+        NOW.with(|now| now.set(1_999));
+        assert_eq!(state.get("city"), Ok(Some("Paris".to_string())));
+
+        // Expired values read as missing and are removed
+        NOW.with(|now| now.set(2_000));
+        assert_eq!(state.get::<String>("city"), Ok(None));
+        assert!(store.0.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_and_swap() -> Result<(), Error> {
+        let store = MemoryStore::default();
+        let state = State::with_backend(&store, "counter")?;
+
+        assert_eq!(state.compare_and_swap("n", None, &1), Ok(true));
+        assert_eq!(state.compare_and_swap("n", None, &5), Ok(false));
+        assert_eq!(state.compare_and_swap("n", Some(&2), &5), Ok(false));
+        assert_eq!(state.compare_and_swap("n", Some(&1), &2), Ok(true));
+        assert_eq!(state.get::<u32>("n"), Ok(Some(2)));
+
+        // An expired value counts as missing
+        let state = state.with_clock(|| 0);
+        assert_eq!(
+            state.set_with_ttl("lock", &"a", Duration::from_millis(1)),
+            Ok(())
+        );
+        let state = state.with_clock(|| 10);
+        assert_eq!(state.compare_and_swap("lock", Some(&"a"), &"b"), Ok(false));
+        assert_eq!(state.compare_and_swap("lock", None, &"b"), Ok(true));
+        assert_eq!(state.get("lock"), Ok(Some("b".to_string())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_response() {
+        let response = ToolResponse::from(Error::Store("no such store".to_string()));
+        assert_eq!(response.is_error, Some(true));
+        assert!(matches!(
+            response.content.first(),
+            Some(crate::ToolContent::Text { text, .. }) if text == "key-value store error: no such store"
+        ));
+    }
+}
@@ -290,6 +290,7 @@ fn test_round_trip_serialization() {
         ],
         structured_content: Some(json!({ "complex": { "nested": "data" } })),
         is_error: Some(false),
+        meta: None,
     };
 
     // Serialize to JSON
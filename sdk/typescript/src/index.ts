@@ -67,6 +67,9 @@ export interface ToolResponse {
 
   /** Indicates if this response represents an error */
   isError?: boolean
+
+  /** Optional metadata passed through to the client, such as pagination cursors */
+  _meta?: Record<string, unknown>
 }
 
 /**
@@ -206,6 +209,17 @@ export const ToolResponse = {
       structuredContent: structured,
     }
   },
+
+  /**
+   * Add entries to the _meta of a response, which the gateway passes through
+   * to the client
+   */
+  withMeta(response: ToolResponse, meta: Record<string, unknown>): ToolResponse {
+    return {
+      ...response,
+      _meta: { ...response._meta, ...meta },
+    }
+  },
 }
 
 /**
@@ -276,6 +290,27 @@ export function isResourceContent(content: ToolContent): content is ResourceCont
   return content.type === 'resource'
 }
 
+/**
+ * Validator applied to the input of a tool before its handler runs.
+ *
+ * Zod schemas implement this interface, so a schema shared between tools
+ * can be passed as is:
+ *
+ * ```typescript
+ * inputValidator: EchoSchema
+ * ```
+ */
+export interface InputValidator<T = unknown> {
+  safeParse(input: unknown): ValidationResult<T>
+}
+
+/**
+ * Outcome of validating tool input
+ */
+export type ValidationResult<T> =
+  | { success: true; data: T }
+  | { success: false; error: { message: string } }
+
 /**
  * Tool definition for createTools.
  *
@@ -310,6 +345,13 @@ export interface ToolDefinition {
   /** Optional metadata for tool-specific extensions */
   _meta?: Record<string, unknown>
 
+  /**
+   * Optional validator of the input, such as the Zod schema the inputSchema
+   * was generated from. Invalid input is rejected with an error response,
+   * and the handler receives the parsed data.
+   */
+  inputValidator?: InputValidator
+
   /**
    * Handler function for tool execution.
    * Uses `any` to allow users to specify their own input types for better DX.
@@ -330,6 +372,7 @@ function camelToSnake(str: string): string {
  * This helper provides a clean way to create a multi-tool component that:
  * - Returns all tool metadata on GET / requests
  * - Routes to specific tools based on the path for POST requests
+ * - Validates input with the tool's inputValidator, if any
  * - Handles errors gracefully
  *
  * @example
@@ -415,7 +458,18 @@ export function createTools<T extends Record<string, ToolDefinition>>(
       const [, tool] = toolEntry
 
       try {
-        const input = await request.json()
+        let input: unknown = await request.json()
+        if (tool.inputValidator !== undefined) {
+          const result = tool.inputValidator.safeParse(input)
+          if (!result.success) {
+            const errorResponse = ToolResponse.error(`Invalid input: ${result.error.message}`)
+            return new Response(JSON.stringify(errorResponse), {
+              status: 400,
+              headers: { 'Content-Type': 'application/json' },
+            })
+          }
+          input = result.data
+        }
         const response = await tool.handler(input)
         return new Response(JSON.stringify(response), {
           status: 200,
@@ -4,6 +4,7 @@ import type {
   TextContent,
   ToolResponse as ToolResponseType,
   ResourceContent,
+  InputValidator,
 } from '../src/index'
 import {
   ToolResponse,
@@ -44,6 +45,16 @@ describe('ToolResponse convenience methods', () => {
     expect(response.structuredContent).toEqual(structured)
     expect(response.isError).toBeUndefined()
   })
+
+  test('withMeta() adds _meta entries', () => {
+    const response = ToolResponse.withMeta(
+      ToolResponse.withMeta(ToolResponse.text('Page 1'), { nextCursor: 'page-2' }),
+      { source: 'cache' },
+    )
+
+    expect(response.content).toHaveLength(1)
+    expect(response._meta).toEqual({ nextCursor: 'page-2', source: 'cache' })
+  })
 })
 
 describe('ToolContent convenience methods', () => {
@@ -369,6 +380,46 @@ describe('createTools helper', () => {
     expect((body.content[0] as TextContent).text).toContain('Tool execution failed')
   })
 
+  test('validates input with the inputValidator', async () => {
+    // Shaped like a Zod schema
+    const validator: InputValidator<{ count: number }> = {
+      safeParse: (input) => {
+        const count = Number((input as { count?: unknown }).count)
+        return Number.isNaN(count)
+          ? { success: false, error: { message: 'count must be a number' } }
+          : { success: true, data: { count } }
+      },
+    }
+    const handle = createTools({
+      double: {
+        inputSchema: {},
+        inputValidator: validator,
+        handler: (input: { count: number }) => ToolResponse.text(`${input.count * 2}`),
+      },
+    })
+
+    const valid = await handle(
+      new Request('http://localhost/double', {
+        method: 'POST',
+        body: JSON.stringify({ count: '21' }),
+      }),
+    )
+    expect(valid.status).toBe(200)
+    const body = (await valid.json()) as ToolResponseType
+    expect((body.content[0] as TextContent).text).toBe('42')
+
+    const invalid = await handle(
+      new Request('http://localhost/double', {
+        method: 'POST',
+        body: JSON.stringify({ count: 'many' }),
+      }),
+    )
+    expect(invalid.status).toBe(400)
+    const error = (await invalid.json()) as ToolResponseType
+    expect(error.isError).toBe(true)
+    expect((error.content[0] as TextContent).text).toBe('Invalid input: count must be a number')
+  })
+
   test('returns 404 for unknown tool', async () => {
     const handle = createTools({
       knownTool: {
@@ -35,10 +35,27 @@ type executor struct {
 	stdin  io.Reader
 }
 
+// defaultBinary is the Spin binary used by executors created without WithBinary
+var defaultBinary = "spin"
+
+// SetDefaultBinary changes the Spin binary used by default, e.g. to prefer a
+// pinned version from the FTL tools directory over the one on PATH.
+// The same restrictions as WithBinary apply.
+func SetDefaultBinary(binary string) {
+	e := &executor{binary: defaultBinary}
+	WithBinary(binary)(e)
+	defaultBinary = e.binary
+}
+
+// DefaultBinary returns the Spin binary used by default
+func DefaultBinary() string {
+	return defaultBinary
+}
+
 // NewExecutor creates a new Spin executor
 func NewExecutor(options ...Option) Executor {
 	e := &executor{
-		binary: "spin",
+		binary: defaultBinary,
 		stdout: os.Stdout,
 		stderr: os.Stderr,
 		stdin:  os.Stdin,
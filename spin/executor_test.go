@@ -166,6 +166,20 @@ func TestExecutor_RealImplementation_ValidationOnly(t *testing.T) {
 	})
 }
 
+func TestSetDefaultBinary(t *testing.T) {
+	defer SetDefaultBinary("spin")
+
+	SetDefaultBinary("/home/user/.ftl/tools/spin/3.3.1/spin")
+	assert.Equal(t, "/home/user/.ftl/tools/spin/3.3.1/spin", DefaultBinary())
+
+	e := NewExecutor().(*executor)
+	assert.Equal(t, "/home/user/.ftl/tools/spin/3.3.1/spin", e.binary)
+
+	// Non-spin binaries are rejected just like WithBinary
+	SetDefaultBinary("/usr/bin/echo")
+	assert.Equal(t, "/home/user/.ftl/tools/spin/3.3.1/spin", DefaultBinary())
+}
+
 func TestMockExecutor_Helpers(t *testing.T) {
 	t.Run("tracks multiple calls", func(t *testing.T) {
 		mock := NewMockExecutor()
@@ -0,0 +1,83 @@
+package synthesis
+
+import (
+	"os"
+	"path/filepath"
+)
+
+// packageWatch are the watch globs of components built from a package.json
+// whose config sets none
+var packageWatch = []string{"src/**/*.ts", "src/**/*.js", "package.json", "tsconfig.json"}
+
+// packageManagers maps lockfiles to the package manager that writes them, in
+// order of precedence
+var packageManagers = []struct {
+	lockfile string
+	manager  string
+}{
+	{"pnpm-lock.yaml", "pnpm"},
+	{"yarn.lock", "yarn"},
+	{"package-lock.json", "npm"},
+}
+
+// inferPackageBuild fills in the build command and watch globs of a local
+// component built from a package.json, such as a TypeScript component, when
+// its config leaves them out. The component directory is its build workdir,
+// or else the directory of its source (the parent of a dist directory). The
+// command installs dependencies and runs the package's build script with the
+// package manager whose lockfile is found in the component directory or one
+// of its parents within the project. It reports whether the config changed.
+func (r pathResolver) inferPackageBuild(comp map[string]interface{}, source string) bool {
+	build, _ := comp["build"].(map[string]interface{})
+	dir, _ := build["workdir"].(string)
+	if dir == "" {
+		dir = filepath.Dir(source)
+		if filepath.Base(dir) == "dist" {
+			dir = filepath.Dir(dir)
+		}
+	}
+	if _, err := os.Stat(filepath.Join(r.absolute(dir), "package.json")); err != nil {
+		return false
+	}
+
+	changed := false
+	if build == nil {
+		build = map[string]interface{}{}
+		if dir != "." {
+			build["workdir"] = filepath.ToSlash(dir)
+		}
+		comp["build"] = build
+	}
+	if command, _ := build["command"].(string); command == "" {
+		manager := r.packageManager(r.absolute(dir))
+		build["command"] = manager + " install && " + manager + " run build"
+		changed = true
+	}
+	if watch, _ := build["watch"].([]interface{}); len(watch) == 0 {
+		globs := make([]interface{}, len(packageWatch))
+		for i, glob := range packageWatch {
+			globs[i] = glob
+		}
+		build["watch"] = globs
+		changed = true
+	}
+	return changed
+}
+
+// packageManager returns the package manager of a JavaScript package
+// directory, looking for lockfiles up to the project directory since
+// workspaces keep theirs at the root, and defaults to npm
+func (r pathResolver) packageManager(dir string) string {
+	for {
+		for _, pm := range packageManagers {
+			if _, err := os.Stat(filepath.Join(dir, pm.lockfile)); err == nil {
+				return pm.manager
+			}
+		}
+		parent := filepath.Dir(dir)
+		if dir == r.baseDir || !isWithin(r.baseDir, parent) || parent == dir {
+			return "npm"
+		}
+		dir = parent
+	}
+}
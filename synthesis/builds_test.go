@@ -0,0 +1,104 @@
+package synthesis
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+// writeFiles creates empty files relative to dir
+func writeFiles(t *testing.T, dir string, names ...string) {
+	t.Helper()
+	for _, name := range names {
+		path := filepath.Join(dir, name)
+		if err := os.MkdirAll(filepath.Dir(path), 0750); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte("{}"), 0600); err != nil {
+			t.Fatal(err)
+		}
+	}
+}
+
+func TestResolveLocalPaths_InfersPackageBuild(t *testing.T) {
+	_, project := setupWorkspace(t)
+	writeFiles(t, project, "pnpm-lock.yaml", "ts-tool/package.json", "npm-tool/package.json", "npm-tool/package-lock.json")
+
+	app := map[string]interface{}{
+		"name": "test-app",
+		"components": []interface{}{
+			map[string]interface{}{
+				"id":     "ts-tool",
+				"source": "ts-tool/dist/ts-tool.wasm",
+			},
+			map[string]interface{}{
+				"id":     "npm-tool",
+				"source": "npm-tool/dist/npm-tool.wasm",
+				"build": map[string]interface{}{
+					"command": "make build",
+					"workdir": "npm-tool",
+				},
+			},
+			map[string]interface{}{
+				"id":     "rust-tool",
+				"source": "rust-tool/rust_tool.wasm",
+			},
+		},
+	}
+
+	changed, err := ResolveLocalPaths(app, PathOptions{BaseDir: project, OutputDir: project})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if !changed {
+		t.Error("expected the config to change")
+	}
+
+	components := app["components"].([]interface{})
+	ts := components[0].(map[string]interface{})["build"].(map[string]interface{})
+	// The lockfile at the project root makes it a pnpm workspace
+	if ts["command"] != "pnpm install && pnpm run build" {
+		t.Errorf("unexpected command: %v", ts["command"])
+	}
+	if ts["workdir"] != "ts-tool" {
+		t.Errorf("unexpected workdir: %v", ts["workdir"])
+	}
+	if watch := ts["watch"].([]interface{}); len(watch) != len(packageWatch) || watch[0] != "src/**/*.ts" {
+		t.Errorf("unexpected watch: %v", watch)
+	}
+
+	// Explicit commands are kept, only the watch globs are added
+	npm := components[1].(map[string]interface{})["build"].(map[string]interface{})
+	if npm["command"] != "make build" {
+		t.Errorf("unexpected command: %v", npm["command"])
+	}
+	if _, ok := npm["watch"].([]interface{}); !ok {
+		t.Errorf("expected watch globs, got %v", npm["watch"])
+	}
+
+	if _, ok := components[2].(map[string]interface{})["build"]; ok {
+		t.Error("components without a package.json should not get a build")
+	}
+}
+
+func TestPackageManager(t *testing.T) {
+	_, project := setupWorkspace(t)
+	writeFiles(t, project, "npm-tool/package-lock.json", "yarn-tool/yarn.lock", "plain-tool/package.json")
+	r := pathResolver{baseDir: project, outputDir: project}
+
+	for dir, want := range map[string]string{
+		"npm-tool":   "npm",
+		"yarn-tool":  "yarn",
+		"plain-tool": "npm",
+	} {
+		if got := r.packageManager(filepath.Join(project, dir)); got != want {
+			t.Errorf("%s: expected %s, got %s", dir, want, got)
+		}
+	}
+
+	// Lockfiles outside the project are not considered
+	writeFiles(t, filepath.Dir(project), "pnpm-lock.yaml")
+	if got := r.packageManager(filepath.Join(project, "plain-tool")); got != "npm" {
+		t.Errorf("expected npm, got %s", got)
+	}
+}
@@ -0,0 +1,215 @@
+package synthesis
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/BurntSushi/toml"
+)
+
+// Platform components added to every synthesized manifest
+const (
+	gatewayComponent    = "mcp-gateway"
+	authorizerComponent = "mcp-authorizer"
+)
+
+// ComponentOptions selects the part of an application to synthesize
+type ComponentOptions struct {
+	// Components are the IDs of the user components to keep
+	Components []string
+	// NoGateway serves a single component directly on "/...", without the
+	// MCP gateway or authorizer in front of it
+	NoGateway bool
+}
+
+// SynthesizeComponentsTo synthesizes a Spin manifest containing only the
+// selected components of a YAML, JSON or CUE config, to be written to
+// outputDir. Local paths are rewritten as in SynthesizeFromConfigTo.
+func SynthesizeComponentsTo(configPath, outputDir string, opts ComponentOptions) (string, error) {
+	configPath = filepath.Clean(configPath)
+	if strings.ToLower(filepath.Ext(configPath)) == ".go" {
+		return "", fmt.Errorf("selecting components is not supported for Go configs")
+	}
+	if len(opts.Components) == 0 {
+		return "", fmt.Errorf("no components selected")
+	}
+	if opts.NoGateway && len(opts.Components) != 1 {
+		return "", fmt.Errorf("exactly one component can be served without the gateway, got %d", len(opts.Components))
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		return "", fmt.Errorf("failed to read config file: %w", err)
+	}
+	app := decodeConfig(data)
+	if app == nil {
+		return "", fmt.Errorf("failed to parse config file %s", configPath)
+	}
+
+	if err := SelectComponents(app, opts.Components); err != nil {
+		return "", err
+	}
+	if _, err := ResolveLocalPaths(app, PathOptions{
+		BaseDir:   filepath.Dir(configPath),
+		OutputDir: outputDir,
+	}); err != nil {
+		return "", err
+	}
+	if opts.NoGateway {
+		// Nothing sits in front of the component to enforce auth
+		app["access"] = "public"
+		delete(app, "auth")
+	}
+
+	input, err := json.Marshal(app)
+	if err != nil {
+		return "", fmt.Errorf("failed to encode config: %w", err)
+	}
+	manifest, err := NewSynthesizer().SynthesizeJSON(input)
+	if err != nil {
+		return "", err
+	}
+
+	if opts.NoGateway {
+		return StandaloneManifest(manifest, opts.Components[0])
+	}
+	return manifest, nil
+}
+
+// SelectComponents keeps only the listed components of a decoded FTL config.
+// Unknown IDs are an error listing the available components.
+func SelectComponents(app map[string]interface{}, ids []string) error {
+	components, _ := app["components"].([]interface{})
+
+	byID := make(map[string]interface{}, len(components))
+	available := make([]string, 0, len(components))
+	for _, c := range components {
+		comp, ok := c.(map[string]interface{})
+		if !ok {
+			continue
+		}
+		if id, _ := comp["id"].(string); id != "" {
+			byID[id] = comp
+			available = append(available, id)
+		}
+	}
+
+	selected := make([]interface{}, 0, len(ids))
+	seen := make(map[string]bool, len(ids))
+	for _, id := range ids {
+		comp, ok := byID[id]
+		if !ok {
+			sort.Strings(available)
+			return fmt.Errorf("component %q not found (available: %s)", id, strings.Join(available, ", "))
+		}
+		if !seen[id] {
+			seen[id] = true
+			selected = append(selected, comp)
+		}
+	}
+
+	app["components"] = selected
+	return nil
+}
+
+// StandaloneManifest removes the gateway and authorizer from a synthesized
+// manifest and routes all requests directly to component
+func StandaloneManifest(manifest, component string) (string, error) {
+	var doc map[string]interface{}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		return "", fmt.Errorf("failed to parse manifest: %w", err)
+	}
+
+	components, _ := doc["component"].(map[string]interface{})
+	if _, ok := components[component]; !ok {
+		return "", fmt.Errorf("component %q not found in manifest", component)
+	}
+	delete(components, gatewayComponent)
+	delete(components, authorizerComponent)
+
+	doc["trigger"] = map[string]interface{}{
+		"http": []map[string]interface{}{
+			{"route": "/...", "component": component},
+		},
+	}
+
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(doc); err != nil {
+		return "", fmt.Errorf("failed to encode manifest: %w", err)
+	}
+	return buf.String(), nil
+}
+
+// ResolveRegistrySources rewrites the registry sources of a synthesized
+// manifest to the local files returned by resolve, so Spin never contacts a
+// registry. Components with local sources are left unchanged.
+func ResolveRegistrySources(manifest string, resolve func(registry, packageName, version string) (string, error)) (string, error) {
+	var doc map[string]interface{}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		return "", fmt.Errorf("failed to parse manifest: %w", err)
+	}
+
+	components, _ := doc["component"].(map[string]interface{})
+	ids := make([]string, 0, len(components))
+	for id := range components {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	for _, id := range ids {
+		comp, ok := components[id].(map[string]interface{})
+		if !ok {
+			continue
+		}
+		source, ok := comp["source"].(map[string]interface{})
+		if !ok {
+			continue
+		}
+		registry, _ := source["registry"].(string)
+		pkg, _ := source["package"].(string)
+		version, _ := source["version"].(string)
+		if registry == "" || pkg == "" || version == "" {
+			continue
+		}
+
+		path, err := resolve(registry, pkg, version)
+		if err != nil {
+			return "", fmt.Errorf("component %q: %w", id, err)
+		}
+		comp["source"] = path
+	}
+
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(doc); err != nil {
+		return "", fmt.Errorf("failed to encode manifest: %w", err)
+	}
+	return buf.String(), nil
+}
+
+// BuiltSources returns the local Wasm paths of the components a synthesized
+// manifest builds, keyed by component ID
+func BuiltSources(manifest string) (map[string]string, error) {
+	var doc map[string]interface{}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse manifest: %w", err)
+	}
+
+	sources := map[string]string{}
+	components, _ := doc["component"].(map[string]interface{})
+	for id, value := range components {
+		comp, ok := value.(map[string]interface{})
+		if !ok {
+			continue
+		}
+		source, isLocal := comp["source"].(string)
+		if _, builds := comp["build"].(map[string]interface{}); isLocal && builds {
+			sources[id] = source
+		}
+	}
+	return sources, nil
+}
@@ -0,0 +1,171 @@
+package synthesis
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/BurntSushi/toml"
+)
+
+const multiComponentConfig = `name: test-app
+access: private
+components:
+  - id: ocr
+    source: ./ocr/ocr.wasm
+    build:
+      command: cargo build
+      workdir: ocr
+  - id: search
+    source: ./search/search.wasm
+`
+
+func writeMultiComponentConfig(t *testing.T) (project, configPath string) {
+	t.Helper()
+	project = t.TempDir()
+	configPath = filepath.Join(project, "ftl.yaml")
+	if err := os.WriteFile(configPath, []byte(multiComponentConfig), 0600); err != nil {
+		t.Fatal(err)
+	}
+	return project, configPath
+}
+
+func decodeManifest(t *testing.T, manifest string) map[string]interface{} {
+	t.Helper()
+	var doc map[string]interface{}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("invalid manifest: %v\n%s", err, manifest)
+	}
+	return doc
+}
+
+func TestSynthesizeComponentsTo_WithGateway(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+	outputDir := filepath.Join(project, ".ftl", "up")
+
+	manifest, err := SynthesizeComponentsTo(configPath, outputDir, ComponentOptions{Components: []string{"ocr"}})
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	components := decodeManifest(t, manifest)["component"].(map[string]interface{})
+	if _, ok := components["search"]; ok {
+		t.Error("unselected component should not be in the manifest")
+	}
+	for _, id := range []string{"ocr", "mcp-gateway", "mcp-authorizer"} {
+		if _, ok := components[id]; !ok {
+			t.Errorf("expected component %s in the manifest", id)
+		}
+	}
+	if !strings.Contains(manifest, `component_names = "ocr"`) {
+		t.Errorf("gateway should only route to the selected component:\n%s", manifest)
+	}
+	// Paths are relative to the output directory
+	if !strings.Contains(manifest, `source = "../../ocr/ocr.wasm"`) {
+		t.Errorf("source should be relative to the output directory:\n%s", manifest)
+	}
+}
+
+func TestSynthesizeComponentsTo_NoGateway(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+
+	manifest, err := SynthesizeComponentsTo(configPath, project, ComponentOptions{
+		Components: []string{"search"},
+		NoGateway:  true,
+	})
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	doc := decodeManifest(t, manifest)
+	components := doc["component"].(map[string]interface{})
+	if len(components) != 1 || components["search"] == nil {
+		t.Errorf("expected only the search component, got %v", components)
+	}
+
+	routes := doc["trigger"].(map[string]interface{})["http"].([]map[string]interface{})
+	if len(routes) != 1 || routes[0]["route"] != "/..." || routes[0]["component"] != "search" {
+		t.Errorf("expected a single public route to search, got %v", routes)
+	}
+}
+
+func TestSynthesizeComponentsTo_Errors(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+
+	_, err := SynthesizeComponentsTo(configPath, project, ComponentOptions{Components: []string{"missing"}})
+	if err == nil || !strings.Contains(err.Error(), "available: ocr, search") {
+		t.Errorf("expected unknown component error listing available components, got %v", err)
+	}
+
+	_, err = SynthesizeComponentsTo(configPath, project, ComponentOptions{
+		Components: []string{"ocr", "search"},
+		NoGateway:  true,
+	})
+	if err == nil {
+		t.Error("expected an error when serving several components without the gateway")
+	}
+}
+
+func TestResolveRegistrySources(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+	manifest, err := SynthesizeFromConfigTo(configPath, project)
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	var resolved []string
+	manifest, err = ResolveRegistrySources(manifest, func(registry, packageName, version string) (string, error) {
+		resolved = append(resolved, registry+"/"+packageName)
+		return "/mirror/" + packageName + "@" + version + ".wasm", nil
+	})
+	if err != nil {
+		t.Fatalf("failed to resolve: %v", err)
+	}
+
+	want := []string{"ghcr.io/fastertools:mcp-authorizer", "ghcr.io/fastertools:mcp-gateway"}
+	if strings.Join(resolved, ",") != strings.Join(want, ",") {
+		t.Errorf("expected the platform components to be resolved, got %v", resolved)
+	}
+
+	components := decodeManifest(t, manifest)["component"].(map[string]interface{})
+	gateway := components["mcp-gateway"].(map[string]interface{})
+	if source, _ := gateway["source"].(string); !strings.HasPrefix(source, "/mirror/fastertools:mcp-gateway@") {
+		t.Errorf("gateway source should point at the mirror, got %v", gateway["source"])
+	}
+	if source := components["ocr"].(map[string]interface{})["source"]; source != "./ocr/ocr.wasm" {
+		t.Errorf("local sources should be unchanged, got %v", source)
+	}
+}
+
+func TestResolveRegistrySources_Error(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+	manifest, err := SynthesizeFromConfigTo(configPath, project)
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	_, err = ResolveRegistrySources(manifest, func(string, string, string) (string, error) {
+		return "", os.ErrNotExist
+	})
+	if err == nil || !strings.Contains(err.Error(), `component "mcp-authorizer"`) {
+		t.Errorf("expected the failing component to be named, got %v", err)
+	}
+}
+
+func TestBuiltSources(t *testing.T) {
+	project, configPath := writeMultiComponentConfig(t)
+	manifest, err := SynthesizeFromConfigTo(configPath, project)
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	sources, err := BuiltSources(manifest)
+	if err != nil {
+		t.Fatalf("failed to read sources: %v", err)
+	}
+	// Prebuilt and registry components are not built
+	if len(sources) != 1 || sources["ocr"] != "./ocr/ocr.wasm" {
+		t.Errorf("expected only the ocr component to be built, got %v", sources)
+	}
+}
@@ -11,8 +11,16 @@ import (
 	"gopkg.in/yaml.v3"
 )
 
-// SynthesizeFromConfig reads a config file and synthesizes it to a Spin manifest
+// SynthesizeFromConfig reads a config file and synthesizes it to a Spin
+// manifest written to the current directory
 func SynthesizeFromConfig(configPath string) (string, error) {
+	return SynthesizeFromConfigTo(configPath, ".")
+}
+
+// SynthesizeFromConfigTo reads a config file and synthesizes it to a Spin
+// manifest written to outputDir. Local component paths are resolved relative
+// to the config file and rewritten relative to outputDir.
+func SynthesizeFromConfigTo(configPath, outputDir string) (string, error) {
 	// Clean the path to prevent directory traversal
 	configPath = filepath.Clean(configPath)
 	// Read the config file
@@ -25,6 +33,16 @@ func SynthesizeFromConfig(configPath string) (string, error) {
 	ext := strings.ToLower(filepath.Ext(configPath))
 	synth := NewSynthesizer()
 
+	if ext != ".go" {
+		data, err = ResolveConfigPaths(data, PathOptions{
+			BaseDir:   filepath.Dir(configPath),
+			OutputDir: outputDir,
+		})
+		if err != nil {
+			return "", err
+		}
+	}
+
 	switch ext {
 	case ".yaml", ".yml":
 		return synth.SynthesizeYAML(data)
@@ -0,0 +1,242 @@
+package synthesis
+
+import (
+	"bytes"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/BurntSushi/toml"
+)
+
+// Registry package serving mocked upstream hosts during `ftl up`
+const (
+	MockRegistry = "ghcr.io"
+	MockPackage  = "fastertools:upstream-mock"
+	MockVersion  = "0.1.0" // x-release-please-version
+)
+
+// MockRoute is a stub response served by a mock component, in the form the
+// upstream-mock component reads from its routes variable
+type MockRoute struct {
+	// Method to match, any method when empty
+	Method string `json:"method,omitempty"`
+	// Path pattern with literal segments, {name} segments and a trailing *
+	Path    string            `json:"path"`
+	Status  int               `json:"status,omitempty"`
+	Headers map[string]string `json:"headers,omitempty"`
+	// Body is the response body, rendered per request when Template is set
+	Body     string `json:"body,omitempty"`
+	Template bool   `json:"template,omitempty"`
+	// BodyBase64 is a binary response body used instead of Body
+	BodyBase64 string `json:"body_base64,omitempty"`
+}
+
+// MockComponentID returns the ID of the component mocking host, e.g.
+// "mock-api-example-com" for "api.example.com"
+func MockComponentID(host string) string {
+	var b strings.Builder
+	b.WriteString("mock")
+	dash := true
+	for _, r := range strings.ToLower(host) {
+		if (r >= 'a' && r <= 'z') || (r >= '0' && r <= '9') {
+			if dash {
+				b.WriteByte('-')
+				dash = false
+			}
+			b.WriteRune(r)
+		} else {
+			dash = true
+		}
+	}
+	return b.String()
+}
+
+// ApplyMocks adds a mock component serving routes for each host of mocks to
+// a synthesized manifest and points the other components at it: allowed
+// outbound hosts and URL-valued variables for a mocked host are rewritten to
+// the mock's http://<id>.spin.internal address. Hosts are matched with their
+// port, and a host without a port matches any port.
+func ApplyMocks(manifest string, mocks map[string][]MockRoute) (string, error) {
+	if len(mocks) == 0 {
+		return manifest, nil
+	}
+
+	var doc map[string]interface{}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		return "", fmt.Errorf("failed to parse manifest: %w", err)
+	}
+
+	hosts := make([]string, 0, len(mocks))
+	for host := range mocks {
+		hosts = append(hosts, host)
+	}
+	sort.Strings(hosts)
+
+	components, _ := doc["component"].(map[string]interface{})
+	if components == nil {
+		components = map[string]interface{}{}
+		doc["component"] = components
+	}
+	ids := make([]string, 0, len(components))
+	for id := range components {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+
+	targets := make(map[string]string, len(hosts))
+	mocked := make(map[string]string, len(hosts))
+	for _, host := range hosts {
+		id := MockComponentID(host)
+		if _, exists := components[id]; exists {
+			return "", fmt.Errorf("mock for %s: component %q already exists", host, id)
+		}
+		// Hosts differing only in punctuation or case share an ID
+		if other, exists := mocked[id]; exists {
+			return "", fmt.Errorf("mock for %s: component %q already mocks %s", host, id, other)
+		}
+		mocked[id] = host
+		targets[strings.ToLower(host)] = "http://" + id + ".spin.internal"
+	}
+
+	// Point the existing components at the mocks
+	for _, id := range ids {
+		comp, ok := components[id].(map[string]interface{})
+		if !ok {
+			continue
+		}
+		if allowed, ok := comp["allowed_outbound_hosts"].([]interface{}); ok {
+			comp["allowed_outbound_hosts"] = mockOutboundHosts(allowed, targets)
+		}
+		if variables, ok := comp["variables"].(map[string]interface{}); ok {
+			for name, value := range variables {
+				if s, ok := value.(string); ok {
+					variables[name] = mockURL(s, targets)
+				}
+			}
+		}
+	}
+	if variables, ok := doc["variables"].(map[string]interface{}); ok {
+		for _, value := range variables {
+			variable, ok := value.(map[string]interface{})
+			if !ok {
+				continue
+			}
+			if s, ok := variable["default"].(string); ok {
+				variable["default"] = mockURL(s, targets)
+			}
+		}
+	}
+
+	// Add the mocks, each reachable only through Spin's internal network
+	trigger, _ := doc["trigger"].(map[string]interface{})
+	if trigger == nil {
+		trigger = map[string]interface{}{}
+		doc["trigger"] = trigger
+	}
+	routes := httpTriggers(trigger["http"])
+	for _, host := range hosts {
+		id := MockComponentID(host)
+		encoded, err := json.Marshal(mocks[host])
+		if err != nil {
+			return "", fmt.Errorf("mock for %s: %w", host, err)
+		}
+		components[id] = map[string]interface{}{
+			"source": map[string]interface{}{
+				"registry": MockRegistry,
+				"package":  MockPackage,
+				"version":  MockVersion,
+			},
+			"variables": map[string]interface{}{
+				// Encoded so Spin does not read {{ }} templates in the routes
+				"routes": base64.StdEncoding.EncodeToString(encoded),
+			},
+		}
+		routes = append(routes, map[string]interface{}{
+			"route":     map[string]interface{}{"private": true},
+			"component": id,
+		})
+	}
+	trigger["http"] = routes
+
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(doc); err != nil {
+		return "", fmt.Errorf("failed to encode manifest: %w", err)
+	}
+	return buf.String(), nil
+}
+
+// httpTriggers returns the decoded [[trigger.http]] tables of a manifest
+func httpTriggers(value interface{}) []map[string]interface{} {
+	switch triggers := value.(type) {
+	case []map[string]interface{}:
+		return triggers
+	case []interface{}:
+		routes := make([]map[string]interface{}, 0, len(triggers))
+		for _, t := range triggers {
+			if route, ok := t.(map[string]interface{}); ok {
+				routes = append(routes, route)
+			}
+		}
+		return routes
+	}
+	return nil
+}
+
+// mockOutboundHosts replaces the allowed outbound hosts of mocked hosts with
+// the address of their mock, keeping the order of the others
+func mockOutboundHosts(allowed []interface{}, targets map[string]string) []interface{} {
+	rewritten := make([]interface{}, 0, len(allowed))
+	seen := map[string]bool{}
+	for _, value := range allowed {
+		entry, ok := value.(string)
+		if !ok {
+			rewritten = append(rewritten, value)
+			continue
+		}
+		if _, authority, found := strings.Cut(entry, "://"); found {
+			if target, mocked := mockTarget(authority, targets); mocked {
+				entry = target
+			}
+		}
+		if !seen[entry] {
+			seen[entry] = true
+			rewritten = append(rewritten, entry)
+		}
+	}
+	return rewritten
+}
+
+// mockURL rewrites an http(s) URL of a mocked host to the mock's address,
+// keeping its path and query. Other values are returned unchanged.
+func mockURL(value string, targets map[string]string) string {
+	scheme, rest, found := strings.Cut(value, "://")
+	if !found || (scheme != "http" && scheme != "https") {
+		return value
+	}
+	end := strings.IndexAny(rest, "/?#")
+	if end < 0 {
+		end = len(rest)
+	}
+	target, mocked := mockTarget(rest[:end], targets)
+	if !mocked {
+		return value
+	}
+	return target + rest[end:]
+}
+
+// mockTarget returns the mock address serving authority ("host[:port]")
+func mockTarget(authority string, targets map[string]string) (string, bool) {
+	authority = strings.ToLower(authority)
+	if target, ok := targets[authority]; ok {
+		return target, true
+	}
+	if host, _, hasPort := strings.Cut(authority, ":"); hasPort {
+		if target, ok := targets[host]; ok {
+			return target, true
+		}
+	}
+	return "", false
+}
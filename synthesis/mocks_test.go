@@ -0,0 +1,151 @@
+package synthesis
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+const mockedConfig = `name: weather-app
+variables:
+  weather_api: https://api.weather.com
+components:
+  - id: weather
+    source: ./weather.wasm
+    allowed_outbound_hosts:
+      - https://api.weather.com
+      - https://api.weather.com:8443
+      - https://maps.example.com
+    variables:
+      forecast_url: https://api.weather.com/v1/forecast?units=metric
+      maps_url: https://maps.example.com/tiles
+`
+
+func TestApplyMocks(t *testing.T) {
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(mockedConfig))
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+
+	routes := []MockRoute{
+		{Method: "GET", Path: "/v1/forecast", Body: `{"temperature": {{ query.units }}}`, Template: true},
+	}
+	manifest, err = ApplyMocks(manifest, map[string][]MockRoute{"api.weather.com": routes})
+	if err != nil {
+		t.Fatalf("failed to apply mocks: %v", err)
+	}
+
+	doc := decodeManifest(t, manifest)
+	components := doc["component"].(map[string]interface{})
+	weather := components["weather"].(map[string]interface{})
+
+	allowed := weather["allowed_outbound_hosts"].([]interface{})
+	want := []interface{}{"http://mock-api-weather-com.spin.internal", "https://maps.example.com"}
+	if !reflect.DeepEqual(allowed, want) {
+		t.Errorf("allowed_outbound_hosts = %v, want %v", allowed, want)
+	}
+
+	variables := weather["variables"].(map[string]interface{})
+	if got := variables["forecast_url"]; got != "http://mock-api-weather-com.spin.internal/v1/forecast?units=metric" {
+		t.Errorf("forecast_url = %v", got)
+	}
+	if got := variables["maps_url"]; got != "https://maps.example.com/tiles" {
+		t.Errorf("unmocked maps_url should be unchanged, got %v", got)
+	}
+	appVariable := doc["variables"].(map[string]interface{})["weather_api"].(map[string]interface{})
+	if got := appVariable["default"]; got != "http://mock-api-weather-com.spin.internal" {
+		t.Errorf("weather_api default = %v", got)
+	}
+
+	mock, ok := components["mock-api-weather-com"].(map[string]interface{})
+	if !ok {
+		t.Fatalf("expected a mock component:\n%s", manifest)
+	}
+	source := mock["source"].(map[string]interface{})
+	if source["package"] != MockPackage || source["version"] != MockVersion {
+		t.Errorf("unexpected mock source %v", source)
+	}
+	encoded, err := base64.StdEncoding.DecodeString(mock["variables"].(map[string]interface{})["routes"].(string))
+	if err != nil {
+		t.Fatalf("routes should be base64: %v", err)
+	}
+	var decoded []MockRoute
+	if err := json.Unmarshal(encoded, &decoded); err != nil || !reflect.DeepEqual(decoded, routes) {
+		t.Errorf("routes = %s, want %v", encoded, routes)
+	}
+
+	// The mock is only reachable through Spin's internal network
+	if !strings.Contains(manifest, `component = "mock-api-weather-com"`) {
+		t.Errorf("expected an HTTP trigger for the mock:\n%s", manifest)
+	}
+	for _, trigger := range httpTriggers(doc["trigger"].(map[string]interface{})["http"]) {
+		if trigger["component"] == "mock-api-weather-com" {
+			if route, ok := trigger["route"].(map[string]interface{}); !ok || route["private"] != true {
+				t.Errorf("mock route should be private, got %v", trigger["route"])
+			}
+		}
+	}
+}
+
+func TestApplyMocks_NoMocks(t *testing.T) {
+	manifest := "spin_manifest_version = 2\n"
+	got, err := ApplyMocks(manifest, nil)
+	if err != nil || got != manifest {
+		t.Errorf("ApplyMocks without mocks = %q, %v", got, err)
+	}
+}
+
+func TestApplyMocks_ComponentIDCollision(t *testing.T) {
+	manifest := `spin_manifest_version = 2
+
+[component.mock-api-example-com]
+source = "tool.wasm"
+`
+	_, err := ApplyMocks(manifest, map[string][]MockRoute{"api.example.com": {{Path: "/"}}})
+	if err == nil || !strings.Contains(err.Error(), "already exists") {
+		t.Errorf("expected a collision error, got %v", err)
+	}
+
+	// Mocks can't share an ID either
+	_, err = ApplyMocks("spin_manifest_version = 2\n", map[string][]MockRoute{
+		"api.example.com": {{Path: "/"}},
+		"api-example.com": {{Path: "/"}},
+	})
+	if err == nil || !strings.Contains(err.Error(), `mock for api.example.com: component "mock-api-example-com" already mocks api-example.com`) {
+		t.Errorf("expected a collision error, got %v", err)
+	}
+}
+
+func TestMockComponentID(t *testing.T) {
+	tests := map[string]string{
+		"api.example.com":  "mock-api-example-com",
+		"API.Example.com":  "mock-api-example-com",
+		"localhost:8080":   "mock-localhost-8080",
+		"my--service.test": "mock-my-service-test",
+	}
+	for host, want := range tests {
+		if got := MockComponentID(host); got != want {
+			t.Errorf("MockComponentID(%q) = %q, want %q", host, got, want)
+		}
+	}
+}
+
+func TestMockURL(t *testing.T) {
+	targets := map[string]string{"api.example.com": "http://mock-api-example-com.spin.internal"}
+	tests := map[string]string{
+		"https://api.example.com":         "http://mock-api-example-com.spin.internal",
+		"http://api.example.com/v1?x=1":   "http://mock-api-example-com.spin.internal/v1?x=1",
+		"https://API.example.com:443/v1":  "http://mock-api-example-com.spin.internal/v1",
+		"https://api.example.com.evil/v1": "https://api.example.com.evil/v1",
+		"wss://api.example.com":           "wss://api.example.com",
+		"api.example.com":                 "api.example.com",
+		"{{ base_url }}":                  "{{ base_url }}",
+	}
+	for value, want := range tests {
+		if got := mockURL(value, targets); got != want {
+			t.Errorf("mockURL(%q) = %q, want %q", value, got, want)
+		}
+	}
+}
@@ -0,0 +1,325 @@
+package synthesis
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"cuelang.org/go/cue/cuecontext"
+	"gopkg.in/yaml.v3"
+)
+
+// PathOptions controls how local component paths in an FTL config are resolved
+type PathOptions struct {
+	// BaseDir is the directory local paths are relative to, normally the
+	// directory containing the config file
+	BaseDir string
+	// OutputDir is the directory the generated spin.toml is written to
+	OutputDir string
+	// AllowExternal permits paths outside BaseDir. Setting
+	// allow_external_paths in the config has the same effect.
+	AllowExternal bool
+}
+
+// ResolveConfigPaths validates the local component paths of a YAML, JSON or
+// CUE config and rewrites them relative to opts.OutputDir. The input is
+// returned unchanged when no path needs rewriting; otherwise the rewritten
+// config is returned as JSON, which all three formats accept.
+func ResolveConfigPaths(data []byte, opts PathOptions) ([]byte, error) {
+	app := decodeConfig(data)
+	if app == nil {
+		// Not a config we can inspect, synthesis reports any syntax errors
+		return data, nil
+	}
+
+	changed, err := ResolveLocalPaths(app, opts)
+	if err != nil || !changed {
+		return data, err
+	}
+	return json.Marshal(app)
+}
+
+// decodeConfig decodes a config into generic data, or returns nil when the
+// input is neither YAML/JSON nor concrete CUE with a components list
+func decodeConfig(data []byte) map[string]interface{} {
+	var app map[string]interface{}
+	if err := yaml.Unmarshal(data, &app); err == nil {
+		if _, ok := app["components"].([]interface{}); ok {
+			return app
+		}
+	}
+
+	app = nil
+	value := cuecontext.New().CompileBytes(data)
+	if value.Err() != nil || value.Decode(&app) != nil {
+		return nil
+	}
+	if _, ok := app["components"].([]interface{}); !ok {
+		return nil
+	}
+	return app
+}
+
+// ResolveLocalPaths validates the local source, build workdir, dependencies
+// and mounted directories of every component in a decoded FTL config, and rewrites them
+// relative to opts.OutputDir. Dependency directories are added to the watch
+// globs of the component's build, and components built from a package.json
+// get a build command and watch globs when they have none. Paths are canonicalized (including symlinks) before being
+// checked, and those that escape opts.BaseDir are rejected unless external
+// paths are allowed. It reports whether any path was rewritten.
+func ResolveLocalPaths(app map[string]interface{}, opts PathOptions) (bool, error) {
+	baseDir, err := canonicalPath(opts.BaseDir)
+	if err != nil {
+		return false, fmt.Errorf("failed to resolve project directory: %w", err)
+	}
+	outputDir, err := canonicalPath(opts.OutputDir)
+	if err != nil {
+		return false, fmt.Errorf("failed to resolve output directory: %w", err)
+	}
+
+	allowExternal := opts.AllowExternal
+	if allow, ok := app["allow_external_paths"].(bool); ok && allow {
+		allowExternal = true
+	}
+
+	r := pathResolver{baseDir: baseDir, outputDir: outputDir, allowExternal: allowExternal}
+	changed := false
+
+	components, _ := app["components"].([]interface{})
+	for _, c := range components {
+		comp, ok := c.(map[string]interface{})
+		if !ok {
+			continue
+		}
+		id, _ := comp["id"].(string)
+
+		// Directories mounted into the component, including those of
+		// components from a registry
+		files, _ := comp["files"].([]interface{})
+		for _, f := range files {
+			mount, ok := f.(map[string]interface{})
+			if !ok {
+				continue
+			}
+			dir, ok := mount["source"].(string)
+			if !ok || dir == "" {
+				continue
+			}
+			resolved, err := r.resolveDirectory(dir)
+			if err != nil {
+				return false, fmt.Errorf("component %q: files source %w", id, err)
+			}
+			if resolved != dir {
+				mount["source"] = resolved
+				changed = true
+			}
+		}
+
+		source, ok := comp["source"].(string)
+		if !ok {
+			// Registry sources have no local paths
+			continue
+		}
+		resolved, err := r.resolve(source)
+		if err != nil {
+			return false, fmt.Errorf("component %q: source %w", id, err)
+		}
+		if resolved != source {
+			comp["source"] = resolved
+			changed = true
+		}
+
+		// Local directories shared with other components, such as a library
+		// crate the component depends on by path
+		var dependencies []string
+		deps, _ := comp["dependencies"].([]interface{})
+		for i, d := range deps {
+			dep, ok := d.(string)
+			if !ok {
+				continue
+			}
+			resolved, err := r.resolve(dep)
+			if err != nil {
+				return false, fmt.Errorf("component %q: dependency %w", id, err)
+			}
+			if resolved != dep {
+				deps[i] = resolved
+				changed = true
+			}
+			dependencies = append(dependencies, dep)
+		}
+
+		// Packages such as TypeScript components get their build inferred
+		if r.inferPackageBuild(comp, source) {
+			changed = true
+		}
+
+		build, ok := comp["build"].(map[string]interface{})
+		if !ok {
+			continue
+		}
+		workdir, _ := build["workdir"].(string)
+		if command, _ := build["command"].(string); command != "" && len(dependencies) > 0 {
+			// Changes to dependencies rebuild the component under spin watch
+			if r.watchDependencies(build, workdir, dependencies) {
+				changed = true
+			}
+		}
+		if workdir != "" {
+			resolved, err := r.resolve(workdir)
+			if err != nil {
+				return false, fmt.Errorf("component %q: build workdir %w", id, err)
+			}
+			if resolved != workdir {
+				build["workdir"] = resolved
+				changed = true
+			}
+		} else if command, _ := build["command"].(string); command != "" && r.relocated() {
+			// Spin runs build commands from the manifest directory by default,
+			// keep running them from the project directory instead
+			build["workdir"] = r.relative(baseDir)
+			changed = true
+		}
+	}
+
+	return changed, nil
+}
+
+// pathResolver checks and rewrites paths relative to a project directory
+type pathResolver struct {
+	baseDir       string
+	outputDir     string
+	allowExternal bool
+}
+
+// relocated reports whether the manifest is written outside the project directory
+func (r pathResolver) relocated() bool {
+	return r.baseDir != r.outputDir
+}
+
+// resolve validates a config path and returns it relative to the output directory
+func (r pathResolver) resolve(path string) (string, error) {
+	abs := r.absolute(path)
+	canonical, err := canonicalPath(abs)
+	if err != nil {
+		return "", fmt.Errorf("%q: %w", path, err)
+	}
+	if !r.allowExternal && !isWithin(r.baseDir, canonical) {
+		return "", fmt.Errorf("%q is outside the project directory %s; set allow_external_paths: true to allow it", path, r.baseDir)
+	}
+
+	// Absolute paths stay absolute, relative ones only change when the
+	// manifest is written to a different directory
+	if filepath.IsAbs(path) || !r.relocated() {
+		return path, nil
+	}
+	return r.relative(filepath.Clean(abs)), nil
+}
+
+// resolveDirectory resolves a path like resolve, and checks that it is an
+// existing directory
+func (r pathResolver) resolveDirectory(path string) (string, error) {
+	resolved, err := r.resolve(path)
+	if err != nil {
+		return "", err
+	}
+	info, err := os.Stat(r.absolute(path))
+	if os.IsNotExist(err) {
+		return "", fmt.Errorf("%q does not exist", path)
+	}
+	if err != nil {
+		return "", fmt.Errorf("%q: %w", path, err)
+	}
+	if !info.IsDir() {
+		return "", fmt.Errorf("%q is not a directory", path)
+	}
+	return resolved, nil
+}
+
+// watchDependencies adds a watch glob covering each dependency directory to a
+// build config, and reports whether any was added. Spin resolves watch globs
+// from the build workdir, so they are relative to it rather than to the
+// output directory.
+func (r pathResolver) watchDependencies(build map[string]interface{}, workdir string, dependencies []string) bool {
+	buildDir := r.absolute(workdir)
+	watch, _ := build["watch"].([]interface{})
+	added := false
+	for _, dep := range dependencies {
+		glob := "**/*"
+		if rel, err := filepath.Rel(buildDir, r.absolute(dep)); err != nil {
+			glob = filepath.ToSlash(r.absolute(dep)) + "/" + glob
+		} else if rel != "." {
+			glob = filepath.ToSlash(rel) + "/" + glob
+		}
+		if slices.Contains(watch, interface{}(glob)) {
+			continue
+		}
+		watch = append(watch, glob)
+		added = true
+	}
+	if added {
+		build["watch"] = watch
+	}
+	return added
+}
+
+// absolute returns a config path as an absolute path, relative paths being
+// relative to the project directory
+func (r pathResolver) absolute(path string) string {
+	if filepath.IsAbs(path) {
+		return filepath.Clean(path)
+	}
+	return filepath.Join(r.baseDir, path)
+}
+
+// relative returns path relative to the output directory, in the slash-separated form Spin expects
+func (r pathResolver) relative(path string) string {
+	rel, err := filepath.Rel(r.outputDir, path)
+	if err != nil {
+		return filepath.ToSlash(path)
+	}
+	return filepath.ToSlash(rel)
+}
+
+// canonicalPath returns the absolute path with symlinks resolved. Paths that
+// do not exist yet (such as build outputs) are resolved through their
+// deepest existing parent.
+func canonicalPath(path string) (string, error) {
+	if path == "" {
+		path = "."
+	}
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return "", err
+	}
+
+	var missing []string
+	current := abs
+	for {
+		resolved, err := filepath.EvalSymlinks(current)
+		if err == nil {
+			return filepath.Join(append([]string{resolved}, missing...)...), nil
+		}
+		if !os.IsNotExist(err) {
+			return "", err
+		}
+		parent := filepath.Dir(current)
+		if parent == current {
+			return abs, nil
+		}
+		missing = append([]string{filepath.Base(current)}, missing...)
+		current = parent
+	}
+}
+
+// isWithin reports whether path is root or inside it
+func isWithin(root, path string) bool {
+	rel, err := filepath.Rel(root, path)
+	if err != nil {
+		return false
+	}
+	return rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator))
+}
@@ -0,0 +1,285 @@
+package synthesis
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// setupWorkspace creates a workspace with an app project and a sibling shared component
+func setupWorkspace(t *testing.T) (workspace, project string) {
+	t.Helper()
+	workspace = t.TempDir()
+	project = filepath.Join(workspace, "app")
+	for _, dir := range []string{project, filepath.Join(workspace, "shared", "tool")} {
+		if err := os.MkdirAll(dir, 0750); err != nil {
+			t.Fatal(err)
+		}
+	}
+	return workspace, project
+}
+
+func testApp(source, workdir string, extra string) []byte {
+	return []byte(`name: test-app
+` + extra + `components:
+  - id: tool
+    source: ` + source + `
+    build:
+      command: cargo build
+      workdir: ` + workdir + `
+`)
+}
+
+func TestResolveConfigPaths_InsideProjectUnchanged(t *testing.T) {
+	_, project := setupWorkspace(t)
+	input := testApp("./tool/tool.wasm", "tool", "")
+
+	output, err := ResolveConfigPaths(input, PathOptions{BaseDir: project, OutputDir: project})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if string(output) != string(input) {
+		t.Errorf("expected input to be unchanged, got %s", output)
+	}
+}
+
+func TestResolveConfigPaths_RejectsExternalPaths(t *testing.T) {
+	_, project := setupWorkspace(t)
+
+	_, err := ResolveConfigPaths(
+		testApp("../shared/tool/tool.wasm", "../shared/tool", ""),
+		PathOptions{BaseDir: project, OutputDir: project},
+	)
+	if err == nil {
+		t.Fatal("expected an error for a source outside the project")
+	}
+	if !strings.Contains(err.Error(), "outside the project directory") {
+		t.Errorf("unexpected error: %v", err)
+	}
+	if !strings.Contains(err.Error(), "allow_external_paths") {
+		t.Errorf("error should explain how to allow external paths: %v", err)
+	}
+}
+
+func TestResolveConfigPaths_RejectsSymlinkEscape(t *testing.T) {
+	workspace, project := setupWorkspace(t)
+	if err := os.Symlink(filepath.Join(workspace, "shared"), filepath.Join(project, "shared")); err != nil {
+		t.Skipf("symlinks not supported: %v", err)
+	}
+
+	_, err := ResolveConfigPaths(
+		testApp("./shared/tool/tool.wasm", "tool", ""),
+		PathOptions{BaseDir: project, OutputDir: project},
+	)
+	if err == nil {
+		t.Fatal("expected an error for a symlink pointing outside the project")
+	}
+}
+
+func TestResolveConfigPaths_AllowExternal(t *testing.T) {
+	_, project := setupWorkspace(t)
+	input := testApp("../shared/tool/tool.wasm", "../shared/tool", "allow_external_paths: true\n")
+
+	output, err := ResolveConfigPaths(input, PathOptions{BaseDir: project, OutputDir: project})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if string(output) != string(input) {
+		t.Errorf("paths should not be rewritten when the manifest is written to the project, got %s", output)
+	}
+
+	// The option can also be enabled by the caller
+	_, err = ResolveConfigPaths(
+		testApp("../shared/tool/tool.wasm", "../shared/tool", ""),
+		PathOptions{BaseDir: project, OutputDir: project, AllowExternal: true},
+	)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+}
+
+func TestResolveConfigPaths_RewritesForOutputDir(t *testing.T) {
+	workspace, project := setupWorkspace(t)
+	outputDir := filepath.Join(workspace, "out")
+
+	app := map[string]interface{}{
+		"name":                 "test-app",
+		"allow_external_paths": true,
+		"components": []interface{}{
+			map[string]interface{}{
+				"id":     "shared",
+				"source": "../shared/tool/tool.wasm",
+				"build":  map[string]interface{}{"command": "cargo build", "workdir": "../shared/tool"},
+			},
+			map[string]interface{}{
+				"id":     "local",
+				"source": "local.wasm",
+				"build":  map[string]interface{}{"command": "make"},
+			},
+			map[string]interface{}{
+				"id":     "remote",
+				"source": map[string]interface{}{"registry": "ghcr.io", "package": "ns:remote", "version": "1.0.0"},
+			},
+		},
+	}
+
+	changed, err := ResolveLocalPaths(app, PathOptions{BaseDir: project, OutputDir: outputDir})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if !changed {
+		t.Fatal("expected paths to be rewritten")
+	}
+
+	components := app["components"].([]interface{})
+	shared := components[0].(map[string]interface{})
+	if shared["source"] != "../shared/tool/tool.wasm" {
+		t.Errorf("unexpected shared source: %v", shared["source"])
+	}
+	if got := shared["build"].(map[string]interface{})["workdir"]; got != "../shared/tool" {
+		t.Errorf("unexpected shared workdir: %v", got)
+	}
+
+	local := components[1].(map[string]interface{})
+	if local["source"] != "../app/local.wasm" {
+		t.Errorf("unexpected local source: %v", local["source"])
+	}
+	// Build commands keep running from the project directory
+	if got := local["build"].(map[string]interface{})["workdir"]; got != "../app" {
+		t.Errorf("unexpected local workdir: %v", got)
+	}
+}
+
+func TestSynthesizeFromConfigTo_ExternalComponent(t *testing.T) {
+	workspace, project := setupWorkspace(t)
+	configPath := filepath.Join(project, "ftl.yaml")
+	config := testApp("../shared/tool/tool.wasm", "../shared/tool", "allow_external_paths: true\n")
+	if err := os.WriteFile(configPath, config, 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	manifest, err := SynthesizeFromConfigTo(configPath, workspace)
+	if err != nil {
+		t.Fatalf("failed to synthesize: %v", err)
+	}
+	if !strings.Contains(manifest, `source = "shared/tool/tool.wasm"`) {
+		t.Errorf("source should be relative to the output directory:\n%s", manifest)
+	}
+	if !strings.Contains(manifest, `workdir = "shared/tool"`) {
+		t.Errorf("workdir should be relative to the output directory:\n%s", manifest)
+	}
+}
+
+func TestResolveLocalPaths_DependenciesWatched(t *testing.T) {
+	workspace, project := setupWorkspace(t)
+	if err := os.MkdirAll(filepath.Join(project, "shared", "geo"), 0750); err != nil {
+		t.Fatal(err)
+	}
+
+	app := map[string]interface{}{
+		"name": "test-app",
+		"components": []interface{}{
+			map[string]interface{}{
+				"id":           "weather",
+				"source":       "weather/weather.wasm",
+				"dependencies": []interface{}{"shared/geo"},
+				"build": map[string]interface{}{
+					"command": "cargo build",
+					"workdir": "weather",
+					"watch":   []interface{}{"src/**/*.rs", "../shared/geo/**/*"},
+				},
+			},
+			map[string]interface{}{
+				"id":           "root",
+				"source":       "root.wasm",
+				"dependencies": []interface{}{"shared/geo"},
+				"build":        map[string]interface{}{"command": "make"},
+			},
+		},
+	}
+
+	outputDir := filepath.Join(workspace, "out")
+	if _, err := ResolveLocalPaths(app, PathOptions{BaseDir: project, OutputDir: outputDir}); err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+
+	components := app["components"].([]interface{})
+	weather := components[0].(map[string]interface{})
+	// Watch globs are relative to the build workdir, and not duplicated
+	watch := weather["build"].(map[string]interface{})["watch"].([]interface{})
+	if len(watch) != 2 || watch[1] != "../shared/geo/**/*" {
+		t.Errorf("unexpected weather watch: %v", watch)
+	}
+	if got := weather["dependencies"].([]interface{})[0]; got != "../app/shared/geo" {
+		t.Errorf("dependency should be relative to the output directory, got %v", got)
+	}
+
+	root := components[1].(map[string]interface{})
+	watch, _ = root["build"].(map[string]interface{})["watch"].([]interface{})
+	if len(watch) != 1 || watch[0] != "shared/geo/**/*" {
+		t.Errorf("unexpected root watch: %v", watch)
+	}
+}
+
+func TestResolveConfigPaths_RejectsExternalDependency(t *testing.T) {
+	_, project := setupWorkspace(t)
+	input := []byte(`name: test-app
+components:
+  - id: tool
+    source: tool/tool.wasm
+    dependencies: [../shared/tool]
+`)
+
+	_, err := ResolveConfigPaths(input, PathOptions{BaseDir: project, OutputDir: project})
+	if err == nil || !strings.Contains(err.Error(), "dependency") {
+		t.Fatalf("expected an error for a dependency outside the project, got %v", err)
+	}
+}
+
+func TestResolveLocalPaths_Files(t *testing.T) {
+	workspace, project := setupWorkspace(t)
+	if err := os.MkdirAll(filepath.Join(project, "data", "dictionaries"), 0750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(project, "data", "words.txt"), []byte("apple"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	app := func(dir string) map[string]interface{} {
+		return map[string]interface{}{
+			"name": "test-app",
+			"components": []interface{}{
+				map[string]interface{}{
+					"id":     "spell",
+					"source": "spell.wasm",
+					"files": []interface{}{
+						map[string]interface{}{"source": dir, "destination": "/dictionaries"},
+					},
+				},
+			},
+		}
+	}
+
+	resolved := app("data/dictionaries")
+	outputDir := filepath.Join(workspace, "out")
+	if _, err := ResolveLocalPaths(resolved, PathOptions{BaseDir: project, OutputDir: outputDir}); err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	comp := resolved["components"].([]interface{})[0].(map[string]interface{})
+	mount := comp["files"].([]interface{})[0].(map[string]interface{})
+	if mount["source"] != "../app/data/dictionaries" {
+		t.Errorf("files source should be relative to the output directory, got %v", mount["source"])
+	}
+
+	for dir, want := range map[string]string{
+		"data/missing":   "does not exist",
+		"data/words.txt": "is not a directory",
+		"../shared/tool": "outside the project directory",
+	} {
+		_, err := ResolveLocalPaths(app(dir), PathOptions{BaseDir: project, OutputDir: project})
+		if err == nil || !strings.Contains(err.Error(), "files source") || !strings.Contains(err.Error(), want) {
+			t.Errorf("expected %q error for %s, got %v", want, dir, err)
+		}
+	}
+}
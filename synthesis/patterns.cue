@@ -3,6 +3,7 @@ package ftl
 import (
 	"strings"
 	"list"
+	"regexp"
 	"encoding/json"
 )
 
@@ -22,13 +23,111 @@ import (
 	// - custom: User-provided auth and policy
 	access:       "public" | "private" | "org" | "custom" | *"public"
 	auth?:        #AuthConfig  // Required only for "custom" access
+	// Required tool versions, e.g. {spin: "3.3.1", cargo: ">=1.89.0"}
+	toolchain?:   {[string]: string}
+	// Allow local component paths outside the project directory, e.g. "../shared/tool"
+	allow_external_paths?: bool
+	// Application variables, transpiled to Spin's top-level [variables]
+	variables?: {[=~"^[a-z][a-z0-9_]*$"]: #Variable}
+	// Remote MCP servers whose tools the gateway serves, keyed by the
+	// namespace they are served under like a component's
+	federation?: {[=~"^[a-z][a-z0-9-]*$"]: #FederatedServer}
+	// Named build profiles selected with `ftl build --profile NAME`
+	profiles?: {[=~"^[a-z][a-z0-9-]*$"]: #BuildProfile}
+	// Local development settings used by `ftl up`, ignored on deploy
+	dev?: #DevConfig
+}
+
+// A variable is a plain string (its default value) or exactly one of:
+// - default: value used when none is provided at deploy time
+// - required: must be provided at deploy time (ftl deploy --var name=value)
+// - value_from: reference to a platform-stored secret, e.g. "engine-secret:API_KEY"
+#Variable: string | #DefaultVariable | #RequiredVariable | #SecretRefVariable
+#DefaultVariable: {
+	default!: string
+	secret?:  bool
+}
+#RequiredVariable: {
+	required!: true
+	secret?:   bool
+}
+#SecretRefVariable: {
+	value_from!: string & =~"^engine-secret:[A-Za-z_][A-Za-z0-9_]*$"
+	// Platform secrets are always secret
+	secret?: true
+}
+
+// A remote MCP server federated into the gateway
+#FederatedServer: {
+	// Streamable HTTP endpoint, e.g. "https://api.githubcopilot.com/mcp/"
+	url!: string & =~"^https?://[^/?#]+([/?#].*)?$"
+	// Secret application variable holding the bearer token sent to the server
+	token_variable?: string & =~"^[a-z][a-z0-9_]*$"
+	// Headers sent with every request to the server
+	headers?: {[string]: string}
+	// Listing or calling tools still running after this many seconds fails
+	timeout_secs?: int & >=1
 }
 
 #Component: {
-	id!: string & =~"^[a-z][a-z0-9-]*$"
+	// mcp-gateway and mcp-authorizer are the ids of the built-in components
+	id!: string & =~"^[a-z][a-z0-9-]*$" & !="mcp-gateway" & !="mcp-authorizer"
 	source!: #ComponentSource
 	build: #BuildConfig | *{command: "", workdir: "", watch: []}
+	// Local directories the build depends on besides its own, such as a
+	// shared library crate. Changes to them rebuild the component.
+	dependencies?: [...string]
 	variables?: {[string]: string}
+	// Outbound hosts the component may call (Spin's allowed_outbound_hosts)
+	allowed_outbound_hosts?: [...string]
+	// MCP capabilities served besides tools, advertised by the gateway on initialize
+	capabilities?: [...("resources" | "prompts")]
+	// Limits the gateway enforces on calls to the component's tools
+	limits?: #ComponentLimits
+	// Largest size of the built Wasm, checked by `ftl build`
+	size_budget?: #SizeBudget
+	// Scheduled handlers, each run by a cron trigger (requires Spin's trigger-cron plugin)
+	schedules?: [...#Schedule]
+	// Local directories mounted into the component's filesystem, such as
+	// dictionaries or templates. They ship with the component on deploy.
+	files?: [...#FileMount]
+	// Key-value stores the component opens (Spin's key_value_stores). The
+	// "default" store is reserved for the gateway and authorizer.
+	key_value_stores?: [...(string & =~"^[a-z][a-z0-9_-]*$" & !="default")]
+}
+
+// Tool call limits, each optional
+#ComponentLimits: {
+	// Calls still running after this many seconds are cancelled
+	max_duration_secs?: int & >=1
+	// Tool responses larger than this are discarded
+	max_response_bytes?: int & >=1
+	// Calls beyond this many in flight are rejected as retryable
+	max_concurrent?: int & >=1
+}
+
+// Size budget of a component's built Wasm
+#SizeBudget: {
+	// Bytes, or a size such as "1.5MB" (1000-based) or "800KiB" (1024-based)
+	max!: (int & >=1) | (string & =~"^[0-9]+(\\.[0-9]+)? ?([KMG]i?B|B)?$")
+	// Whether exceeding the budget fails the build (default) or only warns
+	on_exceed?: "error" | "warn"
+}
+
+// A local directory mounted read-only into a component
+#FileMount: {
+	// Directory relative to the project, e.g. "data/dictionaries"
+	source!: string & !=""
+	// Absolute path the directory is mounted at, e.g. "/dictionaries"
+	destination!: string & =~"^/" & !~"(^|/)\\.\\.(/|$)"
+}
+
+// A scheduled handler declared with the SDK's scheduled! macro
+#Schedule: {
+	// Handler function name, e.g. "refresh_cache"
+	handler!: string & =~"^[a-z_][a-z0-9_]*$"
+	// Cron expression with seconds: "sec min hour day month weekday [year]"
+	cron!: string & =~"^\\S+( \\S+){5,6}$"
 }
 
 // Component source exactly matches Spin's format - no transformation needed
@@ -45,6 +144,62 @@ import (
 	command!: string
 	workdir?: string
 	watch?: [...string]
+	// Build strategy:
+	// - build: always build locally (default)
+	// - pull-or-build: pull the published artifact from `registry` and only
+	//   fall back to a local build when the pull fails
+	strategy?: "build" | "pull-or-build"
+	// Published registry equivalent of this component (used by pull-or-build)
+	registry?: #RegistrySource
+}
+
+// A build profile changes how `ftl build` builds components without
+// changing their build commands
+#BuildProfile: {
+	// Optimization goal, setting each toolchain's default flags (e.g. Cargo's
+	// release opt-level, LTO and codegen units)
+	optimize?: "size" | "speed"
+	// Environment variables set for every build command, e.g. RUSTFLAGS
+	env?: {[=~"^[A-Za-z_][A-Za-z0-9_]*$"]: string}
+	// wasm-opt arguments for a pass over each built component, e.g. ["-Oz"].
+	// Skipped with a warning when wasm-opt is not installed.
+	wasm_opt?: [...string]
+}
+
+// Settings that only apply to `ftl up`
+#DevConfig: {
+	// Stub responses for outbound hosts, keyed by "host" or "host:port".
+	// Each mocked host is served by an extra component that the outbound
+	// hosts and URL variables of the application are pointed at.
+	mocks?: {[=~"^[A-Za-z0-9.-]+(:[0-9]+)?$"]: [...#MockRoute]}
+}
+
+// A mock route answers matching requests with a static JSON value, a
+// templated body or the contents of a file
+#MockRoute: #JSONMockRoute | #BodyMockRoute | #FileMockRoute
+#MockRouteMatch: {
+	// HTTP method to match, any method when omitted
+	method?: string & =~"^[A-Za-z]+$"
+	// Path to match, with {name} segments and a trailing /* wildcard,
+	// e.g. "/v1/users/{id}"
+	path!: string & =~"^/"
+	status?: int & >=100 & <=599
+	headers?: {[string]: string}
+}
+#JSONMockRoute: {
+	#MockRouteMatch
+	json!: _
+}
+#BodyMockRoute: {
+	#MockRouteMatch
+	// Rendered per request: {{ method }}, {{ path }}, {{ path.NAME }},
+	// {{ query.NAME }}, {{ header.NAME }}, {{ body }} and {{ body.a.b }}
+	body!: string
+}
+#FileMockRoute: {
+	#MockRouteMatch
+	// File relative to the project, served as is
+	file!: string & !=""
 }
 
 #AuthConfig: {
@@ -111,6 +266,16 @@ import (
 		if input.auth != _|_ {
 			auth: input.auth
 		}
+		
+		// Pass through variables if present
+		if input.variables != _|_ {
+			variables: input.variables
+		}
+		
+		// Pass through federated servers if present
+		if input.federation != _|_ {
+			federation: input.federation
+		}
 	}
 	
 	// Transform to Spin manifest
@@ -135,6 +300,70 @@ import (
 	_gatewayVersion: platform.gateway_version
 	_authorizerVersion: platform.authorizer_version
 	
+	// Capabilities declared by user components (component id -> capabilities)
+	_declaredCapabilities: {
+		for c in input.components if c.capabilities != _|_ {
+			"\(c.id)": c.capabilities
+		}
+	}
+	
+	// Tool call limits of user components (component id -> limits)
+	_componentLimits: {
+		for c in input.components if c.limits != _|_ {
+			"\(c.id)": c.limits
+		}
+	}
+	
+	// Names of the secret application variables
+	_secretVariables: {
+		if input.variables != _|_ {
+			for name, v in input.variables if (v & string) == _|_ {
+				if v.value_from != _|_ || (v.secret & true) != _|_ {
+					"\(name)": name
+				}
+			}
+		}
+	}
+	
+	// The gateway's federated_servers document. Tokens are templates of
+	// secret variables, so credentials stay out of the manifest, and a
+	// token_variable naming no secret variable fails synthesis.
+	_federation: {
+		if input.federation != _|_ {
+			for ns, s in input.federation {
+				"\(ns)": {
+					url: s.url
+					if s.token_variable != _|_ {
+						token: "{{ \(_secretVariables[s.token_variable]) }}"
+					}
+					if s.headers != _|_ {
+						headers: s.headers
+					}
+					if s.timeout_secs != _|_ {
+						timeout_secs: s.timeout_secs
+					}
+				}
+			}
+		}
+	}
+	
+	// Origins of the federated servers, each listed once
+	_federatedOrigins: {
+		for s in _federation {
+			"\(regexp.FindSubmatch("^(https?://[^/?#]+)", s.url)[1])": true
+		}
+	}
+	
+	// Cron triggers for scheduled handlers. Each schedule gets its own Spin
+	// component running the same Wasm, told which handler to run by the
+	// ftl_schedule variable.
+	_schedules: [for comp in input.components if comp.schedules != _|_ for s in comp.schedules {
+		component: "\(comp.id)-schedule-\(strings.Replace(s.handler, "_", "-", -1))"
+		handler:   s.handler
+		cron:      s.cron
+		parent:    comp
+	}]
+	
 	output: {
 		spin_manifest_version: 2
 		
@@ -146,15 +375,46 @@ import (
 			}
 		}
 		
+		// Application variables: value_from secrets are resolved by the
+		// platform, so Spin sees them as required secrets
+		if input.variables != _|_ {
+			variables: {
+				for name, v in input.variables {
+					if (v & string) != _|_ {
+						"\(name)": default: v
+					}
+					if (v & string) == _|_ {
+						"\(name)": {
+							if v.default != _|_ {
+								default: v.default
+							}
+							if v.required != _|_ || v.value_from != _|_ {
+								required: true
+							}
+							if v.value_from != _|_ {
+								secret: true
+							}
+							if v.secret != _|_ {
+								secret: v.secret
+							}
+						}
+					}
+				}
+			}
+		}
+		
 		// Build components map
 		component: {
 			// User components
 			// IMPORTANT: User components are intentionally restricted from accessing:
-			// - key_value_stores: KV access is only granted to platform components
+			// - the "default" key-value store: it holds platform component state,
+			//   so users declare stores of their own
 			// - sqlite_databases: Database access is not exposed to users
 			// - ai_models: AI model access is not exposed to users
 			// This ensures proper isolation and prevents resource abuse.
-			// Only the following fields are copied from user configuration:
+			// Only the following fields are copied from user configuration
+			// (source, build, variables, allowed_outbound_hosts, files,
+			// key_value_stores):
 			for comp in input.components {
 				"\(comp.id)": {
 					// Pass through source directly - no transformation needed
@@ -163,14 +423,57 @@ import (
 					// Only include build for local sources (string type)
 					if (comp.source & string) != _|_ {
 						if comp.build.command != "" {
-							build: comp.build  
+							// Only forward the fields Spin understands
+							build: {
+								command: comp.build.command
+								if comp.build.workdir != _|_ {
+									workdir: comp.build.workdir
+								}
+								if comp.build.watch != _|_ {
+									watch: comp.build.watch
+								}
+							}
 						}
 					}
 					
 					if comp.variables != _|_ {
 						variables: comp.variables
 					}
-					// NOTE: No key_value_stores, sqlite_databases, or ai_models
+					if comp.allowed_outbound_hosts != _|_ {
+						allowed_outbound_hosts: comp.allowed_outbound_hosts
+					}
+					if comp.files != _|_ {
+						files: [for f in comp.files {source: f.source, destination: f.destination}]
+					}
+					if comp.key_value_stores != _|_ {
+						key_value_stores: comp.key_value_stores
+					}
+					// NOTE: No sqlite_databases or ai_models
+				}
+			}
+			
+			// Scheduled handler components (same source and restrictions as
+			// their parent, which is responsible for building the Wasm)
+			for s in _schedules {
+				"\(s.component)": {
+					source: s.parent.source
+					variables: {
+						if s.parent.variables != _|_ {
+							for k, v in s.parent.variables {
+								"\(k)": v
+							}
+						}
+						ftl_schedule: s.handler
+					}
+					if s.parent.allowed_outbound_hosts != _|_ {
+						allowed_outbound_hosts: s.parent.allowed_outbound_hosts
+					}
+					if s.parent.files != _|_ {
+						files: [for f in s.parent.files {source: f.source, destination: f.destination}]
+					}
+					if s.parent.key_value_stores != _|_ {
+						key_value_stores: s.parent.key_value_stores
+					}
 				}
 			}
 			
@@ -181,11 +484,31 @@ import (
 					package:  "fastertools:mcp-gateway"
 					version: _gatewayVersion
 				}
-				allowed_outbound_hosts: ["http://*.spin.internal"]
-				// Add component_names if there are user components
-				if len(input.components) > 0 {
+				// The gateway only calls the configured tool components and
+				// federated servers
+				allowed_outbound_hosts: list.Concat([
+					[for c in input.components {"http://\(c.id).spin.internal"}],
+					[for origin, v in _federatedOrigins {origin}],
+				])
+				// KV holds the gateway's sessions, rate limits, concurrency
+				// slots and diagnostics counters
+				key_value_stores: ["default"]
+				// Add the routing variables if there are user components or
+				// federated servers
+				if len(input.components) > 0 || len(_federation) > 0 {
 					variables: {
-						component_names: strings.Join([for c in input.components {c.id}], ",")
+						if len(input.components) > 0 {
+							component_names: strings.Join([for c in input.components {c.id}], ",")
+						}
+						if len(_declaredCapabilities) > 0 {
+							component_capabilities: json.Marshal(_declaredCapabilities)
+						}
+						if len(_componentLimits) > 0 {
+							component_limits: json.Marshal(_componentLimits)
+						}
+						if len(_federation) > 0 {
+							federated_servers: json.Marshal(_federation)
+						}
 					}
 				}
 			}
@@ -200,7 +523,7 @@ import (
 						version: _authorizerVersion
 					}
 					allowed_outbound_hosts: [
-						"http://*.spin.internal",
+						"http://mcp-gateway.spin.internal",
 						"https://*.authkit.app",
 						"https://*.workos.com",
 					]
@@ -288,6 +611,14 @@ import (
 			if !_needsAuth {
 				http: list.Concat([_publicRoutes, _componentRoutes])
 			}
+			
+			// Cron triggers, only emitted when there are scheduled handlers
+			if len(_schedules) > 0 {
+				cron: [for s in _schedules {
+					component:       s.component
+					cron_expression: s.cron
+				}]
+			}
 		}
 	}
 }
\ No newline at end of file
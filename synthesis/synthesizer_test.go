@@ -1,8 +1,13 @@
 package synthesis
 
 import (
+	"encoding/json"
+	"reflect"
+	"regexp"
 	"strings"
 	"testing"
+
+	"github.com/BurntSushi/toml"
 )
 
 func TestSynthesizer_DirectYAML(t *testing.T) {
@@ -145,3 +150,496 @@ func TestSynthesizer_WithOverrides(t *testing.T) {
 		t.Error("Result should contain authorizer for private app")
 	}
 }
+
+func TestSynthesizer_ApplicationVariables(t *testing.T) {
+	yamlInput := `
+name: vars-app
+components:
+  - id: tool1
+    source: ./tool1.wasm
+    variables:
+      api_key: "{{ api_key }}"
+variables:
+  log_level: info
+  region:
+    required: true
+  api_key:
+    value_from: engine-secret:API_KEY
+  webhook_token:
+    default: dev-token
+    secret: true
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize variables: %v", err)
+	}
+
+	type spinVariable struct {
+		Default  *string `toml:"default"`
+		Required bool    `toml:"required"`
+		Secret   bool    `toml:"secret"`
+	}
+	var doc struct {
+		Variables map[string]spinVariable `toml:"variables"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	if v := doc.Variables["log_level"]; v.Default == nil || *v.Default != "info" || v.Required {
+		t.Errorf("log_level should have default 'info', got %+v", v)
+	}
+	if v := doc.Variables["region"]; !v.Required || v.Default != nil || v.Secret {
+		t.Errorf("region should be required, got %+v", v)
+	}
+	if v := doc.Variables["api_key"]; !v.Required || !v.Secret {
+		t.Errorf("api_key should be a required secret, got %+v", v)
+	}
+	if v := doc.Variables["webhook_token"]; v.Default == nil || !v.Secret {
+		t.Errorf("webhook_token should be a secret with a default, got %+v", v)
+	}
+	if strings.Contains(manifest, "value_from") || strings.Contains(manifest, "engine-secret") {
+		t.Error("Platform secret references must not reach the Spin manifest")
+	}
+}
+
+func TestSynthesizer_InvalidApplicationVariables(t *testing.T) {
+	tests := map[string]string{
+		"required with default": `
+    required: true
+    default: x`,
+		"bad secret reference": `
+    value_from: vault:API_KEY`,
+		"unknown field": `
+    optional: true`,
+	}
+
+	for name, spec := range tests {
+		t.Run(name, func(t *testing.T) {
+			yamlInput := "name: vars-app\nvariables:\n  api_key:" + spec + "\n"
+			if _, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput)); err == nil {
+				t.Errorf("Expected an error for %s", name)
+			}
+		})
+	}
+
+	if _, err := NewSynthesizer().SynthesizeYAML([]byte("name: vars-app\nvariables:\n  API_KEY: x\n")); err == nil {
+		t.Error("Expected an error for a variable name Spin does not accept")
+	}
+}
+
+func TestSynthesizer_ComponentCapabilities(t *testing.T) {
+	yamlInput := `
+name: caps-app
+components:
+  - id: docs
+    source: ./docs.wasm
+    capabilities: [resources, prompts]
+  - id: tool1
+    source: ./tool1.wasm
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize capabilities: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			Variables map[string]string `toml:"variables"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	got := doc.Component["mcp-gateway"].Variables["component_capabilities"]
+	if got != `{"docs":["resources","prompts"]}` {
+		t.Errorf("Unexpected gateway component_capabilities: %q", got)
+	}
+
+	if _, err := NewSynthesizer().SynthesizeYAML([]byte(strings.Replace(yamlInput, "prompts]", "sampling]", 1))); err == nil {
+		t.Error("Expected an error for an unknown capability")
+	}
+}
+
+func TestSynthesizer_ComponentLimits(t *testing.T) {
+	yamlInput := `
+name: limits-app
+components:
+  - id: research
+    source: ./research.wasm
+    limits:
+      max_duration_secs: 30
+      max_response_bytes: 1048576
+      max_concurrent: 4
+  - id: tool1
+    source: ./tool1.wasm
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize limits: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			Variables map[string]string `toml:"variables"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	got := doc.Component["mcp-gateway"].Variables["component_limits"]
+	want := `{"research":{"max_duration_secs":30,"max_response_bytes":1048576,"max_concurrent":4}}`
+	if got != want {
+		t.Errorf("Unexpected gateway component_limits: %q", got)
+	}
+	if _, ok := doc.Component["research"].Variables["component_limits"]; ok {
+		t.Error("Limits should only configure the gateway")
+	}
+
+	for _, invalid := range []string{"max_concurrent: 0", "max_duration_secs: 1.5", "max_memory: 64"} {
+		input := strings.Replace(yamlInput, "max_concurrent: 4", invalid, 1)
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+			t.Errorf("Expected an error for limits with %s", invalid)
+		}
+	}
+}
+
+func TestSynthesizer_ComponentSizeBudget(t *testing.T) {
+	yamlInput := `
+name: budget-app
+components:
+  - id: research
+    source: ./research.wasm
+    size_budget:
+      max: 1.5MB
+      on_exceed: warn
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize a size budget: %v", err)
+	}
+	if strings.Contains(manifest, "size_budget") || strings.Contains(manifest, "1.5MB") {
+		t.Errorf("Size budgets are checked by ftl build and should not reach spin.toml:\n%s", manifest)
+	}
+
+	for _, valid := range []string{"max: 2000000", "max: 800 KiB", `max: "512"`} {
+		input := strings.Replace(yamlInput, "max: 1.5MB", valid, 1)
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err != nil {
+			t.Errorf("Unexpected error for a size budget with %s: %v", valid, err)
+		}
+	}
+	for _, invalid := range []string{"max: 0", "max: 2 megabytes", "max: 1.5mb", "on_exceed: ignore"} {
+		key, _, _ := strings.Cut(invalid, ":")
+		input := regexp.MustCompile(key+": .*").ReplaceAllString(yamlInput, invalid)
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+			t.Errorf("Expected an error for a size budget with %s", invalid)
+		}
+	}
+}
+
+func TestSynthesizer_ComponentSchedules(t *testing.T) {
+	yamlInput := `
+name: sched-app
+components:
+  - id: reports
+    source: ./reports.wasm
+    build:
+      command: cargo build --release
+    variables:
+      region: eu
+    allowed_outbound_hosts: ["https://api.example.com"]
+    schedules:
+      - handler: refresh_cache
+        cron: "0 */5 * * * *"
+      - handler: nightly
+        cron: "0 0 2 * * *"
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize schedules: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			Build                *struct{}         `toml:"build"`
+			Variables            map[string]string `toml:"variables"`
+			AllowedOutboundHosts []string          `toml:"allowed_outbound_hosts"`
+		} `toml:"component"`
+		Trigger struct {
+			Cron []struct {
+				Component      string `toml:"component"`
+				CronExpression string `toml:"cron_expression"`
+			} `toml:"cron"`
+		} `toml:"trigger"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	scheduled, ok := doc.Component["reports-schedule-refresh-cache"]
+	if !ok {
+		t.Fatalf("Missing scheduled component:\n%s", manifest)
+	}
+	if scheduled.Build != nil {
+		t.Error("Scheduled component should not rebuild its parent's Wasm")
+	}
+	if scheduled.Variables["ftl_schedule"] != "refresh_cache" || scheduled.Variables["region"] != "eu" {
+		t.Errorf("Unexpected scheduled component variables: %v", scheduled.Variables)
+	}
+	if len(scheduled.AllowedOutboundHosts) != 1 {
+		t.Errorf("Scheduled component should keep its parent's outbound hosts: %v", scheduled.AllowedOutboundHosts)
+	}
+	if _, ok := doc.Component["reports"].Variables["ftl_schedule"]; ok {
+		t.Error("Parent component should not name a schedule")
+	}
+
+	if len(doc.Trigger.Cron) != 2 {
+		t.Fatalf("Expected 2 cron triggers, got %d", len(doc.Trigger.Cron))
+	}
+	if doc.Trigger.Cron[0].Component != "reports-schedule-refresh-cache" || doc.Trigger.Cron[0].CronExpression != "0 */5 * * * *" {
+		t.Errorf("Unexpected cron trigger: %+v", doc.Trigger.Cron[0])
+	}
+
+	// No cron triggers without schedules
+	plain, err := NewSynthesizer().SynthesizeYAML([]byte("name: plain-app\ncomponents:\n  - id: tool1\n    source: ./tool1.wasm\n"))
+	if err != nil {
+		t.Fatalf("Failed to synthesize: %v", err)
+	}
+	if strings.Contains(plain, "trigger.cron") {
+		t.Errorf("Unexpected cron trigger:\n%s", plain)
+	}
+
+	if _, err := NewSynthesizer().SynthesizeYAML([]byte(strings.Replace(yamlInput, `"0 0 2 * * *"`, `"0 2 * * *"`, 1))); err == nil {
+		t.Error("Expected an error for a cron expression without seconds")
+	}
+}
+
+func TestSynthesizer_ComponentFiles(t *testing.T) {
+	yamlInput := `
+name: files-app
+components:
+  - id: spell
+    source: ./spell.wasm
+    files:
+      - source: data/dictionaries
+        destination: /dictionaries
+    schedules:
+      - handler: reindex
+        cron: "0 0 * * * *"
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize files: %v", err)
+	}
+
+	type mount struct {
+		Source      string `toml:"source"`
+		Destination string `toml:"destination"`
+	}
+	var doc struct {
+		Component map[string]struct {
+			Files []mount `toml:"files"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	want := []mount{{Source: "data/dictionaries", Destination: "/dictionaries"}}
+	for _, id := range []string{"spell", "spell-schedule-reindex"} {
+		if got := doc.Component[id].Files; len(got) != 1 || got[0] != want[0] {
+			t.Errorf("Unexpected files for %s: %+v", id, got)
+		}
+	}
+	if files := doc.Component["mcp-gateway"].Files; len(files) != 0 {
+		t.Errorf("Gateway should not mount component files: %+v", files)
+	}
+
+	for _, destination := range []string{"dictionaries", "/data/../etc"} {
+		input := strings.Replace(yamlInput, "destination: /dictionaries", "destination: "+destination, 1)
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+			t.Errorf("Expected an error for destination %q", destination)
+		}
+	}
+}
+
+func TestSynthesizer_ComponentKeyValueStores(t *testing.T) {
+	yamlInput := `
+name: kv-app
+components:
+  - id: notes
+    source: ./notes.wasm
+    key_value_stores: [notes, cache]
+    schedules:
+      - handler: compact
+        cron: "0 0 * * * *"
+  - id: echo
+    source: ./echo.wasm
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize key-value stores: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			KeyValueStores []string `toml:"key_value_stores"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	for _, id := range []string{"notes", "notes-schedule-compact"} {
+		if got := strings.Join(doc.Component[id].KeyValueStores, ","); got != "notes,cache" {
+			t.Errorf("Unexpected key-value stores for %s: %q", id, got)
+		}
+	}
+	if stores := doc.Component["echo"].KeyValueStores; len(stores) != 0 {
+		t.Errorf("Components without stores should not get any: %v", stores)
+	}
+	if got := strings.Join(doc.Component["mcp-gateway"].KeyValueStores, ","); got != "default" {
+		t.Errorf("Unexpected gateway key-value stores: %q", got)
+	}
+
+	// The default store holds the gateway's and authorizer's state
+	for _, store := range []string{"default", "Notes"} {
+		input := strings.Replace(yamlInput, "[notes, cache]", "["+store+"]", 1)
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+			t.Errorf("Expected an error for store %q", store)
+		}
+	}
+}
+
+func TestSynthesizer_InternalHosts(t *testing.T) {
+	yamlInput := `
+name: isolated-app
+access: private
+components:
+  - id: weather
+    source: ./weather.wasm
+  - id: search
+    source: ./search.wasm
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			AllowedOutboundHosts []string `toml:"allowed_outbound_hosts"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	// The gateway may only call the configured tool components
+	gatewayHosts := strings.Join(doc.Component["mcp-gateway"].AllowedOutboundHosts, ",")
+	if gatewayHosts != "http://weather.spin.internal,http://search.spin.internal" {
+		t.Errorf("Unexpected gateway outbound hosts: %s", gatewayHosts)
+	}
+	// The authorizer may only call the gateway internally
+	for _, host := range doc.Component["mcp-authorizer"].AllowedOutboundHosts {
+		if strings.HasSuffix(host, ".spin.internal") && host != "http://mcp-gateway.spin.internal" {
+			t.Errorf("Authorizer should only reach the gateway internally, got %s", host)
+		}
+	}
+
+	// Tool components cannot take the ids of the built-in components
+	for _, id := range []string{"mcp-gateway", "mcp-authorizer"} {
+		input := "name: clash-app\ncomponents:\n  - id: " + id + "\n    source: ./tool.wasm\n"
+		if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+			t.Errorf("Expected an error for component id %s", id)
+		}
+	}
+}
+
+func TestSynthesizer_Federation(t *testing.T) {
+	yamlInput := `
+name: federated-app
+components:
+  - id: weather
+    source: ./weather.wasm
+variables:
+  log_level: info
+  github_token:
+    value_from: engine-secret:GITHUB_TOKEN
+federation:
+  github:
+    url: https://api.githubcopilot.com/mcp/
+    token_variable: github_token
+    headers:
+      X-Org: acme
+    timeout_secs: 10
+  docs:
+    url: http://localhost:8080/mcp
+`
+
+	manifest, err := NewSynthesizer().SynthesizeYAML([]byte(yamlInput))
+	if err != nil {
+		t.Fatalf("Failed to synthesize federation: %v", err)
+	}
+
+	var doc struct {
+		Component map[string]struct {
+			AllowedOutboundHosts []string          `toml:"allowed_outbound_hosts"`
+			Variables            map[string]string `toml:"variables"`
+		} `toml:"component"`
+	}
+	if _, err := toml.Decode(manifest, &doc); err != nil {
+		t.Fatalf("Generated manifest is not valid TOML: %v\n%s", err, manifest)
+	}
+
+	// The gateway may call the origins of the remote servers
+	gateway := doc.Component["mcp-gateway"]
+	gatewayHosts := strings.Join(gateway.AllowedOutboundHosts, ",")
+	if gatewayHosts != "http://weather.spin.internal,https://api.githubcopilot.com,http://localhost:8080" {
+		t.Errorf("Unexpected gateway outbound hosts: %s", gatewayHosts)
+	}
+
+	// The token is a template of the secret variable, resolved by Spin
+	var servers map[string]map[string]interface{}
+	if err := json.Unmarshal([]byte(gateway.Variables["federated_servers"]), &servers); err != nil {
+		t.Fatalf("Invalid gateway federated_servers: %v", err)
+	}
+	want := map[string]map[string]interface{}{
+		"github": {
+			"url":          "https://api.githubcopilot.com/mcp/",
+			"token":        "{{ github_token }}",
+			"headers":      map[string]interface{}{"X-Org": "acme"},
+			"timeout_secs": float64(10),
+		},
+		"docs": {"url": "http://localhost:8080/mcp"},
+	}
+	if !reflect.DeepEqual(servers, want) {
+		t.Errorf("Unexpected gateway federated_servers: %v", servers)
+	}
+
+	invalid := []struct{ name, from, to string }{
+		{"plain token variable", "token_variable: github_token", "token_variable: log_level"},
+		{"unknown token variable", "token_variable: github_token", "token_variable: gitlab_token"},
+		{"non-http url", "url: https://api.githubcopilot.com/mcp/", "url: ftp://api.githubcopilot.com/mcp/"},
+		{"zero timeout", "timeout_secs: 10", "timeout_secs: 0"},
+		{"invalid namespace", "github:", "GitHub:"},
+	}
+	for _, tc := range invalid {
+		t.Run(tc.name, func(t *testing.T) {
+			input := strings.Replace(yamlInput, tc.from, tc.to, 1)
+			if _, err := NewSynthesizer().SynthesizeYAML([]byte(input)); err == nil {
+				t.Errorf("Expected an error for %s", tc.name)
+			}
+		})
+	}
+}
@@ -5,6 +5,9 @@ import (
 	"bytes"
 	"encoding/json"
 	"fmt"
+	"maps"
+	"slices"
+	"strconv"
 
 	"cuelang.org/go/cue"
 	"cuelang.org/go/cue/cuecontext"
@@ -161,21 +164,84 @@ func ExtractApplication(v cue.Value) (*Application, error) {
 		app.Auth = auth
 	}
 
-	// Extract variables
+	// Extract variables (plain string defaults or variable objects)
 	varsValue := v.LookupPath(cue.ParsePath("variables"))
 	if varsValue.Exists() {
-		app.Variables = make(map[string]string)
+		app.Variables = make(map[string]Variable)
 		iter, _ := varsValue.Fields()
+		for iter.Next() {
+			name := iter.Selector().Unquoted()
+			variable := extractVariable(iter.Value())
+			if err := variable.Validate(); err != nil {
+				return nil, fmt.Errorf("variable %s: %w", name, err)
+			}
+			app.Variables[name] = variable
+		}
+	}
+
+	// Extract federated MCP servers
+	federationValue := v.LookupPath(cue.ParsePath("federation"))
+	if federationValue.Exists() {
+		if err := federationValue.Decode(&app.Federation); err != nil {
+			return nil, fmt.Errorf("failed to decode federation: %w", err)
+		}
+		if err := app.validateFederation(); err != nil {
+			return nil, err
+		}
+	}
+
+	// Extract toolchain requirements
+	toolchainValue := v.LookupPath(cue.ParsePath("toolchain"))
+	if toolchainValue.Exists() {
+		app.Toolchain = make(map[string]string)
+		iter, _ := toolchainValue.Fields()
 		for iter.Next() {
 			if val, err := iter.Value().String(); err == nil {
-				app.Variables[iter.Selector().Unquoted()] = val
+				app.Toolchain[iter.Selector().Unquoted()] = val
 			}
 		}
 	}
 
+	// Extract build profiles
+	profilesValue := v.LookupPath(cue.ParsePath("profiles"))
+	if profilesValue.Exists() {
+		if err := profilesValue.Decode(&app.Profiles); err != nil {
+			return nil, fmt.Errorf("failed to decode build profiles: %w", err)
+		}
+	}
+
+	// Extract local development settings
+	devValue := v.LookupPath(cue.ParsePath("dev"))
+	if devValue.Exists() {
+		if err := devValue.Decode(&app.Dev); err != nil {
+			return nil, fmt.Errorf("failed to decode dev settings: %w", err)
+		}
+	}
+
 	return app, nil
 }
 
+func extractVariable(v cue.Value) Variable {
+	if value, err := v.String(); err == nil {
+		return VariableWithDefault(value)
+	}
+
+	variable := Variable{}
+	if value, err := v.LookupPath(cue.ParsePath("default")).String(); err == nil {
+		variable.Default = &value
+	}
+	if required, err := v.LookupPath(cue.ParsePath("required")).Bool(); err == nil {
+		variable.Required = required
+	}
+	if secret, err := v.LookupPath(cue.ParsePath("secret")).Bool(); err == nil {
+		variable.Secret = secret
+	}
+	if valueFrom, err := v.LookupPath(cue.ParsePath("value_from")).String(); err == nil {
+		variable.ValueFrom = valueFrom
+	}
+	return variable
+}
+
 func extractComponent(v cue.Value) (*Component, error) {
 	comp := &Component{}
 
@@ -220,9 +286,35 @@ func extractComponent(v cue.Value) (*Component, error) {
 				build.Watch = append(build.Watch, pattern)
 			}
 		}
+		if strategy, err := buildValue.LookupPath(cue.ParsePath("strategy")).String(); err == nil {
+			build.Strategy = strategy
+		}
+		registryValue := buildValue.LookupPath(cue.ParsePath("registry"))
+		if registryValue.Exists() {
+			reg := &RegistrySource{}
+			if r, err := registryValue.LookupPath(cue.ParsePath("registry")).String(); err == nil {
+				reg.Registry = r
+			}
+			if p, err := registryValue.LookupPath(cue.ParsePath("package")).String(); err == nil {
+				reg.Package = p
+			}
+			if ver, err := registryValue.LookupPath(cue.ParsePath("version")).String(); err == nil {
+				reg.Version = ver
+			}
+			build.Registry = reg
+		}
 		comp.Build = build
 	}
 
+	// Extract local dependency directories
+	if depsIter, err := v.LookupPath(cue.ParsePath("dependencies")).List(); err == nil {
+		for depsIter.Next() {
+			if dep, err := depsIter.Value().String(); err == nil {
+				comp.Dependencies = append(comp.Dependencies, dep)
+			}
+		}
+	}
+
 	// Extract variables
 	varsValue := v.LookupPath(cue.ParsePath("variables"))
 	if varsValue.Exists() {
@@ -235,19 +327,109 @@ func extractComponent(v cue.Value) (*Component, error) {
 		}
 	}
 
+	// Extract allowed outbound hosts
+	if hostsIter, err := v.LookupPath(cue.ParsePath("allowed_outbound_hosts")).List(); err == nil {
+		for hostsIter.Next() {
+			if host, err := hostsIter.Value().String(); err == nil {
+				comp.AllowedOutboundHosts = append(comp.AllowedOutboundHosts, host)
+			}
+		}
+	}
+
+	// Extract MCP capabilities served besides tools
+	if capsIter, err := v.LookupPath(cue.ParsePath("capabilities")).List(); err == nil {
+		for capsIter.Next() {
+			if capability, err := capsIter.Value().String(); err == nil {
+				comp.Capabilities = append(comp.Capabilities, capability)
+			}
+		}
+	}
+
+	// Extract tool call limits
+	limitsValue := v.LookupPath(cue.ParsePath("limits"))
+	if limitsValue.Exists() {
+		comp.Limits = &ComponentLimits{}
+		if err := limitsValue.Decode(comp.Limits); err != nil {
+			return nil, fmt.Errorf("failed to decode limits of component %s: %w", comp.ID, err)
+		}
+	}
+
+	// Extract the size budget of the built Wasm, whose max is bytes or a size
+	budgetValue := v.LookupPath(cue.ParsePath("size_budget"))
+	if budgetValue.Exists() {
+		comp.SizeBudget = &SizeBudget{}
+		maxValue := budgetValue.LookupPath(cue.ParsePath("max"))
+		if n, err := maxValue.Int64(); err == nil {
+			comp.SizeBudget.Max = strconv.FormatInt(n, 10)
+		} else if size, err := maxValue.String(); err == nil {
+			comp.SizeBudget.Max = size
+		}
+		if onExceed, err := budgetValue.LookupPath(cue.ParsePath("on_exceed")).String(); err == nil {
+			comp.SizeBudget.OnExceed = onExceed
+		}
+	}
+
+	// Extract scheduled handlers
+	if schedIter, err := v.LookupPath(cue.ParsePath("schedules")).List(); err == nil {
+		for schedIter.Next() {
+			var schedule Schedule
+			if handler, err := schedIter.Value().LookupPath(cue.ParsePath("handler")).String(); err == nil {
+				schedule.Handler = handler
+			}
+			if cron, err := schedIter.Value().LookupPath(cue.ParsePath("cron")).String(); err == nil {
+				schedule.Cron = cron
+			}
+			comp.Schedules = append(comp.Schedules, schedule)
+		}
+	}
+
+	// Extract directories mounted into the component
+	if filesIter, err := v.LookupPath(cue.ParsePath("files")).List(); err == nil {
+		for filesIter.Next() {
+			var mount FileMount
+			if source, err := filesIter.Value().LookupPath(cue.ParsePath("source")).String(); err == nil {
+				mount.Source = source
+			}
+			if destination, err := filesIter.Value().LookupPath(cue.ParsePath("destination")).String(); err == nil {
+				mount.Destination = destination
+			}
+			comp.Files = append(comp.Files, mount)
+		}
+	}
+
+	// Extract key-value stores
+	if storesIter, err := v.LookupPath(cue.ParsePath("key_value_stores")).List(); err == nil {
+		for storesIter.Next() {
+			if store, err := storesIter.Value().String(); err == nil {
+				comp.KeyValueStores = append(comp.KeyValueStores, store)
+			}
+		}
+	}
+
 	return comp, nil
 }
 
 // Application represents a validated FTL application
 // These are strongly-typed, validated structures derived from CUE
 type Application struct {
-	Name        string            `json:"name,omitempty"`
-	Version     string            `json:"version,omitempty"`
-	Description string            `json:"description,omitempty"`
-	Access      string            `json:"access,omitempty"`
-	Auth        *AuthConfig       `json:"auth,omitempty"`
-	Components  []*Component      `json:"components,omitempty"`
-	Variables   map[string]string `json:"variables,omitempty"`
+	Name        string              `json:"name,omitempty"`
+	Version     string              `json:"version,omitempty"`
+	Description string              `json:"description,omitempty"`
+	Access      string              `json:"access,omitempty"`
+	Auth        *AuthConfig         `json:"auth,omitempty"`
+	Components  []*Component        `json:"components,omitempty"`
+	Variables   map[string]Variable `json:"variables,omitempty"`
+	Toolchain   map[string]string   `json:"toolchain,omitempty"`
+
+	// Federation holds the remote MCP servers whose tools the gateway serves,
+	// keyed by namespace
+	Federation map[string]FederatedServer `json:"federation,omitempty"`
+
+	// Profiles are the named build profiles declared by the application
+	Profiles map[string]BuildProfile `json:"profiles,omitempty"`
+
+	// Dev holds the settings only used by `ftl up`
+	Dev *DevConfig `json:"dev,omitempty"`
 }
 
 // Component represents a validated component
@@ -256,6 +438,31 @@ type Component struct {
 	Source    ComponentSource   `json:"-"` // Exclude from automatic JSON marshaling
 	Build     *BuildConfig      `json:"build,omitempty"`
 	Variables map[string]string `json:"variables,omitempty"`
+
+	// Dependencies lists local directories shared with other components that
+	// the build depends on, such as a library crate
+	Dependencies []string `json:"dependencies,omitempty"`
+
+	// AllowedOutboundHosts lists the hosts the component may call
+	AllowedOutboundHosts []string `json:"allowed_outbound_hosts,omitempty"`
+
+	// Capabilities lists the MCP capabilities served besides tools (resources, prompts)
+	Capabilities []string `json:"capabilities,omitempty"`
+
+	// Limits holds the limits the gateway enforces on calls to the component's tools
+	Limits *ComponentLimits `json:"limits,omitempty"`
+
+	// SizeBudget caps the size of the built Wasm, checked by `ftl build`
+	SizeBudget *SizeBudget `json:"size_budget,omitempty"`
+
+	// Schedules lists the scheduled handlers run by cron triggers
+	Schedules []Schedule `json:"schedules,omitempty"`
+
+	// Files lists the local directories mounted into the component
+	Files []FileMount `json:"files,omitempty"`
+
+	// KeyValueStores lists the key-value stores the component opens
+	KeyValueStores []string `json:"key_value_stores,omitempty"`
 }
 
 // MarshalJSON implements custom JSON marshaling for Component to handle the Source interface
@@ -300,11 +507,127 @@ type RegistrySource struct {
 
 func (RegistrySource) isComponentSource() {}
 
+// Build strategies supported in a component's build configuration
+const (
+	BuildStrategyBuild       = "build"
+	BuildStrategyPullOrBuild = "pull-or-build"
+)
+
+// ComponentLimits represents the limits of the tool calls to a component.
+// Unset limits are not enforced.
+type ComponentLimits struct {
+	MaxDurationSecs  int `json:"max_duration_secs,omitempty" yaml:"max_duration_secs,omitempty"`
+	MaxResponseBytes int `json:"max_response_bytes,omitempty" yaml:"max_response_bytes,omitempty"`
+	MaxConcurrent    int `json:"max_concurrent,omitempty" yaml:"max_concurrent,omitempty"`
+}
+
+// What `ftl build` does when a component exceeds its size budget
+const (
+	SizeBudgetError = "error"
+	SizeBudgetWarn  = "warn"
+)
+
+// SizeBudget represents the largest size the built Wasm of a component may
+// have. Max is a number of bytes or a size such as "1.5MB" or "800KiB".
+type SizeBudget struct {
+	Max      string `json:"max" yaml:"max"`
+	OnExceed string `json:"on_exceed,omitempty" yaml:"on_exceed,omitempty"`
+}
+
+// Schedule represents a scheduled handler and the cron expression that runs it
+type Schedule struct {
+	Handler string `json:"handler" yaml:"handler"`
+	Cron    string `json:"cron" yaml:"cron"`
+}
+
+// FileMount represents a local directory mounted into a component's filesystem
+type FileMount struct {
+	Source      string `json:"source" yaml:"source"`
+	Destination string `json:"destination" yaml:"destination"`
+}
+
 // BuildConfig represents build configuration
 type BuildConfig struct {
-	Command string   `json:"command"`
-	Workdir string   `json:"workdir,omitempty"`
-	Watch   []string `json:"watch,omitempty"`
+	Command  string          `json:"command"`
+	Workdir  string          `json:"workdir,omitempty"`
+	Watch    []string        `json:"watch,omitempty"`
+	Strategy string          `json:"strategy,omitempty"`
+	Registry *RegistrySource `json:"registry,omitempty"`
+}
+
+// Optimization goals of a build profile
+const (
+	OptimizeSize  = "size"
+	OptimizeSpeed = "speed"
+)
+
+// BuildProfile represents a named build profile selected with `ftl build --profile`
+type BuildProfile struct {
+	// Optimize sets each toolchain's default flags for "size" or "speed"
+	Optimize string `json:"optimize,omitempty" yaml:"optimize,omitempty"`
+
+	// Env holds environment variables set for every build command
+	Env map[string]string `json:"env,omitempty" yaml:"env,omitempty"`
+
+	// WasmOpt holds the arguments of a wasm-opt pass over each built component
+	WasmOpt []string `json:"wasm_opt,omitempty" yaml:"wasm_opt,omitempty"`
+}
+
+// FederatedServer represents a remote MCP server whose tools the gateway
+// serves under a namespace. TokenVariable names the secret variable holding
+// the bearer token sent to the server.
+type FederatedServer struct {
+	URL           string            `json:"url" yaml:"url"`
+	TokenVariable string            `json:"token_variable,omitempty" yaml:"token_variable,omitempty"`
+	Headers       map[string]string `json:"headers,omitempty" yaml:"headers,omitempty"`
+	TimeoutSecs   int               `json:"timeout_secs,omitempty" yaml:"timeout_secs,omitempty"`
+}
+
+// validateFederation checks that namespaces don't name a component, as they
+// share the tool name prefixes of components, and that tokens come from
+// secret variables
+func (a *Application) validateFederation() error {
+	for _, namespace := range slices.Sorted(maps.Keys(a.Federation)) {
+		for _, comp := range a.Components {
+			if comp.ID == namespace {
+				return fmt.Errorf("federation %s: namespace is already a component", namespace)
+			}
+		}
+		name := a.Federation[namespace].TokenVariable
+		if name == "" {
+			continue
+		}
+		variable, ok := a.Variables[name]
+		if !ok {
+			return fmt.Errorf("federation %s: token_variable %s is not a declared variable", namespace, name)
+		}
+		if !variable.Secret && variable.ValueFrom == "" {
+			return fmt.Errorf("federation %s: token_variable %s must be a secret variable", namespace, name)
+		}
+	}
+	return nil
+}
+
+// DevConfig represents the local development settings of an application
+type DevConfig struct {
+	// Mocks holds the stub routes served in place of each mocked host
+	Mocks map[string][]MockRoute `json:"mocks,omitempty" yaml:"mocks,omitempty"`
+}
+
+// MockRoute represents a stub response for requests to a mocked host.
+// Exactly one of JSON, Body and File is set.
+type MockRoute struct {
+	Method  string            `json:"method,omitempty" yaml:"method,omitempty"`
+	Path    string            `json:"path" yaml:"path"`
+	Status  int               `json:"status,omitempty" yaml:"status,omitempty"`
+	Headers map[string]string `json:"headers,omitempty" yaml:"headers,omitempty"`
+
+	// JSON is a static value returned as application/json
+	JSON interface{} `json:"json,omitempty" yaml:"json,omitempty"`
+	// Body is a template rendered per request
+	Body *string `json:"body,omitempty" yaml:"body,omitempty"`
+	// File is a file relative to the project returned as is
+	File string `json:"file,omitempty" yaml:"file,omitempty"`
 }
 
 // AuthConfig represents authentication configuration
@@ -0,0 +1,161 @@
+package validation
+
+import (
+	"encoding/json"
+	"fmt"
+	"maps"
+	"slices"
+	"strings"
+)
+
+// SecretRefPrefix prefixes value_from references to secrets stored on the platform
+const SecretRefPrefix = "engine-secret:"
+
+// Variable is an application variable. In configuration it is either a plain
+// string, which is its default value, or an object:
+//
+//	variables:
+//	  log_level: info
+//	  region:
+//	    required: true
+//	  api_key:
+//	    value_from: engine-secret:API_KEY
+type Variable struct {
+	// Default is the value used when none is provided at deploy time
+	Default *string `json:"default,omitempty" yaml:"default,omitempty"`
+	// Required variables must be provided at deploy time with --var
+	Required bool `json:"required,omitempty" yaml:"required,omitempty"`
+	// Secret variables are never shown and are stored as Spin secrets
+	Secret bool `json:"secret,omitempty" yaml:"secret,omitempty"`
+	// ValueFrom references a platform-stored secret, e.g. engine-secret:API_KEY
+	ValueFrom string `json:"value_from,omitempty" yaml:"value_from,omitempty"`
+}
+
+// VariableWithDefault returns a variable with a default value, the
+// equivalent of a plain string in configuration
+func VariableWithDefault(value string) Variable {
+	return Variable{Default: &value}
+}
+
+// IsPlain reports whether the variable is only a default value
+func (v Variable) IsPlain() bool {
+	return v.Default != nil && !v.Required && !v.Secret && v.ValueFrom == ""
+}
+
+// SecretName returns the name of the platform-stored secret the variable
+// references, if any
+func (v Variable) SecretName() (string, bool) {
+	return strings.CutPrefix(v.ValueFrom, SecretRefPrefix)
+}
+
+// Validate checks that the variable has exactly one source of value:
+// a default, a value provided at deploy time, or a platform secret
+func (v Variable) Validate() error {
+	sources := 0
+	if v.Default != nil {
+		sources++
+	}
+	if v.Required {
+		sources++
+	}
+	if v.ValueFrom != "" {
+		sources++
+		if name, ok := v.SecretName(); !ok || name == "" {
+			return fmt.Errorf("value_from must be in the form %s<NAME>, got %q", SecretRefPrefix, v.ValueFrom)
+		}
+	}
+	if sources != 1 {
+		return fmt.Errorf("a variable needs exactly one of default, required or value_from")
+	}
+	return nil
+}
+
+// MarshalJSON writes plain variables as strings
+func (v Variable) MarshalJSON() ([]byte, error) {
+	if v.IsPlain() {
+		return json.Marshal(*v.Default)
+	}
+	type variableAlias Variable
+	return json.Marshal(variableAlias(v))
+}
+
+// UnmarshalJSON accepts a string default value or a variable object
+func (v *Variable) UnmarshalJSON(data []byte) error {
+	var value string
+	if err := json.Unmarshal(data, &value); err == nil {
+		*v = VariableWithDefault(value)
+		return nil
+	}
+	type variableAlias Variable
+	return json.Unmarshal(data, (*variableAlias)(v))
+}
+
+// MarshalYAML writes plain variables as strings
+func (v Variable) MarshalYAML() (interface{}, error) {
+	if v.IsPlain() {
+		return *v.Default, nil
+	}
+	type variableAlias Variable
+	return variableAlias(v), nil
+}
+
+// UnmarshalYAML accepts a string default value or a variable object
+func (v *Variable) UnmarshalYAML(unmarshal func(interface{}) error) error {
+	var value string
+	if err := unmarshal(&value); err == nil {
+		*v = VariableWithDefault(value)
+		return nil
+	}
+	type variableAlias Variable
+	return unmarshal((*variableAlias)(v))
+}
+
+// VariableDefaults returns the default values of the application variables
+func (a *Application) VariableDefaults() map[string]string {
+	defaults := make(map[string]string)
+	for name, variable := range a.Variables {
+		if variable.Default != nil {
+			defaults[name] = *variable.Default
+		}
+	}
+	return defaults
+}
+
+// VariableSources returns the value_from reference of each variable backed by
+// a platform-stored secret
+func (a *Application) VariableSources() map[string]string {
+	sources := make(map[string]string)
+	for name, variable := range a.Variables {
+		if variable.ValueFrom != "" {
+			sources[name] = variable.ValueFrom
+		}
+	}
+	return sources
+}
+
+// SecretVariables returns the sorted names of the secret variables
+func (a *Application) SecretVariables() []string {
+	var names []string
+	for name, variable := range a.Variables {
+		if variable.Secret || variable.ValueFrom != "" {
+			names = append(names, name)
+		}
+	}
+	slices.Sort(names)
+	return names
+}
+
+// MissingVariables returns the sorted names of the required variables that
+// are not in provided
+func (a *Application) MissingVariables(provided map[string]string) []string {
+	var missing []string
+	for _, name := range slices.Sorted(maps.Keys(a.Variables)) {
+		if !a.Variables[name].Required {
+			continue
+		}
+		if _, ok := provided[name]; !ok {
+			missing = append(missing, name)
+		}
+	}
+	return missing
+}